@@ -2,7 +2,7 @@
 extern crate test;
 
 use lpn::{
-    bkw::partition_reduce,
+    bkw::{bkw_compressed_table, partition_reduce},
     lf1::xor_reduce,
     oracle::{LpnOracle, MAX_K},
 };
@@ -31,6 +31,22 @@ fn bench_partition_reduce(b: &mut Bencher) {
 
     b.iter(|| partition_reduce(&mut (oracle.clone()), 7));
 }
+#[bench]
+fn bench_partition_reduce_b16(b: &mut Bencher) {
+    let mut oracle = LpnOracle::new(LARGE_K, 1.0 / 8.0);
+    oracle.get_samples(200_000);
+
+    b.iter(|| partition_reduce(&mut (oracle.clone()), 16));
+}
+
+#[bench]
+fn bench_compressed_table_b16(b: &mut Bencher) {
+    let mut oracle = LpnOracle::new(LARGE_K, 1.0 / 8.0);
+    oracle.get_samples(200_000);
+
+    b.iter(|| bkw_compressed_table(&mut (oracle.clone()), 16, 10));
+}
+
 #[bench]
 fn bench_xor_reduce(b: &mut Bencher) {
     let mut oracle = LpnOracle::new(LARGE_K, 1.0 / 8.0);
@@ -0,0 +1,75 @@
+#![feature(test)]
+extern crate test;
+
+use lpn::{
+    bkw::{bkw_reduce_inplace_step, bkw_reduce_sorted_step},
+    oracle::LpnOracle,
+};
+use test::Bencher;
+
+const K: u32 = 64;
+
+fn get_oracle(n: usize) -> LpnOracle {
+    let mut oracle = LpnOracle::new(K, 1.0 / 8.0);
+    oracle.get_samples(n);
+    oracle
+}
+
+/// Fraction of samples of which the product is wrong
+fn noise_rate(oracle: &LpnOracle) -> f64 {
+    let k = oracle.get_k();
+    let secret = oracle.secret.as_binvector(k);
+    let wrong = oracle
+        .samples
+        .iter()
+        .filter(|q| &q.as_binvector(k) * &secret != q.get_product())
+        .count();
+    wrong as f64 / oracle.samples.len() as f64
+}
+
+#[test]
+fn steps_are_equivalent() {
+    let oracle = get_oracle(200_000);
+    for &b in &[6, 12] {
+        let mut inplace = oracle.clone();
+        bkw_reduce_inplace_step(&mut inplace, 1, b);
+        let mut sorted = oracle.clone();
+        bkw_reduce_sorted_step(&mut sorted, 1, b);
+
+        // both drop one sample per class, the first sample is never a pivot in place
+        let difference = inplace.samples.len() as isize - sorted.samples.len() as isize;
+        assert!((0..=1).contains(&difference), "{}", difference);
+        // the sum of two samples has noise rate 2 tau (1 - tau) = 0.22
+        let (rate_inplace, rate_sorted) = (noise_rate(&inplace), noise_rate(&sorted));
+        assert!((rate_inplace - 0.22).abs() < 0.01, "{}", rate_inplace);
+        assert!((rate_sorted - 0.22).abs() < 0.01, "{}", rate_sorted);
+    }
+}
+
+#[bench]
+fn bench_inplace_step_b8(b: &mut Bencher) {
+    let oracle = get_oracle(100_000);
+
+    b.iter(|| bkw_reduce_inplace_step(&mut (oracle.clone()), 1, 8));
+}
+
+#[bench]
+fn bench_sorted_step_b8(b: &mut Bencher) {
+    let oracle = get_oracle(100_000);
+
+    b.iter(|| bkw_reduce_sorted_step(&mut (oracle.clone()), 1, 8));
+}
+
+#[bench]
+fn bench_inplace_step_b16(b: &mut Bencher) {
+    let oracle = get_oracle(200_000);
+
+    b.iter(|| bkw_reduce_inplace_step(&mut (oracle.clone()), 1, 16));
+}
+
+#[bench]
+fn bench_sorted_step_b16(b: &mut Bencher) {
+    let oracle = get_oracle(200_000);
+
+    b.iter(|| bkw_reduce_sorted_step(&mut (oracle.clone()), 1, 16));
+}
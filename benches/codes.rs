@@ -3,7 +3,6 @@ extern crate lpn;
 extern crate m4ri_rust;
 extern crate test;
 
-#[cfg(any(feature = "hamming", feature = "golay", feature = "bogosrnd"))]
 macro_rules! bench_code {
     ($name:ident, $code:expr) => {
         #[cfg(feature = "codes")]
@@ -59,6 +58,8 @@ macro_rules! bench_code {
     };
 }
 
+bench_code!(repetition_3_1, RepetitionCode::new(3));
+bench_code!(repetition_1024_1, RepetitionCode::new(1024));
 #[cfg(feature = "hamming")]
 bench_code!(hamming_3_1, HammingCode3_1);
 #[cfg(feature = "hamming")]
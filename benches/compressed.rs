@@ -0,0 +1,26 @@
+#![feature(test)]
+extern crate test;
+
+use lpn::compressed::CompressedBkwOracle;
+use lpn::oracle::LpnOracle;
+use test::Bencher;
+
+#[bench]
+fn bench_compress_samples(b: &mut Bencher) {
+    let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+    oracle.get_samples(100_000);
+    let compressed = CompressedBkwOracle::new(oracle.clone(), 4);
+    b.bytes = compressed.uncompressed_size() as u64;
+
+    b.iter(|| CompressedBkwOracle::new(oracle.clone(), 4));
+}
+
+#[bench]
+fn bench_decompress_samples(b: &mut Bencher) {
+    let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+    oracle.get_samples(100_000);
+    let compressed = CompressedBkwOracle::new(oracle, 4);
+    b.bytes = compressed.uncompressed_size() as u64;
+
+    b.iter(|| compressed.iter().count());
+}
@@ -0,0 +1,58 @@
+#![feature(test)]
+extern crate lpn;
+extern crate m4ri_rust;
+extern crate test;
+
+#[cfg(feature = "codes")]
+mod bench {
+    use crate::test::Bencher;
+    use lpn::codes::*;
+    use m4ri_rust::friendly::*;
+
+    const BATCH: usize = 10_000;
+
+    fn get_code() -> LinearCode {
+        let generator = loop {
+            let generator = BinMatrix::random(20, 36);
+            if generator.rank() == 20 {
+                break generator;
+            }
+        };
+        LinearCode::from_generator(generator)
+    }
+
+    fn get_vectors(code: &LinearCode) -> Vec<BinVector> {
+        (0..BATCH)
+            .map(|_| BinVector::random(code.length()))
+            .collect()
+    }
+
+    #[bench]
+    fn decode_serial(b: &mut Bencher) {
+        let code = get_code();
+        let vectors = get_vectors(&code);
+
+        b.iter(|| {
+            vectors
+                .iter()
+                .map(|v| code.decode_to_code(v))
+                .collect::<Vec<_>>()
+        });
+    }
+
+    #[bench]
+    fn decode_batch(b: &mut Bencher) {
+        let code = get_code();
+        let vectors = get_vectors(&code);
+
+        b.iter(|| code.decode_batch(&vectors));
+    }
+
+    #[bench]
+    fn decode_batch_matrix(b: &mut Bencher) {
+        let code = get_code();
+        let queries = BinMatrix::new(get_vectors(&code));
+
+        b.iter(|| code.decode_batch_matrix(&queries));
+    }
+}
@@ -6,7 +6,7 @@ use rand::prelude::*;
 
 use test::Bencher;
 
-use lpn::lf1::{fwht, parfwht};
+use lpn::fwht::{fwht, parfwht};
 
 const BITS: u32 = 10;
 
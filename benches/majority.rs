@@ -0,0 +1,42 @@
+#![feature(test)]
+extern crate test;
+
+use lpn::bkw::{majority, majority_sorted};
+use lpn::oracle::LpnOracle;
+use test::Bencher;
+
+fn reduced_oracle() -> LpnOracle {
+    let mut oracle = LpnOracle::new(16, 1.0 / 8.0);
+    oracle.get_samples(1_000_000);
+    oracle
+}
+
+#[bench]
+fn bench_majority(b: &mut Bencher) {
+    let oracle = reduced_oracle();
+    b.iter(|| majority(oracle.clone()));
+}
+
+#[bench]
+fn bench_majority_sorted(b: &mut Bencher) {
+    let oracle = reduced_oracle();
+    b.iter(|| majority_sorted(oracle.clone()));
+}
+
+#[bench]
+fn bench_weight_one_filter(b: &mut Bencher) {
+    let oracle = reduced_oracle();
+    b.iter(|| oracle.samples.iter().filter(|q| q.is_unit_vector()).count());
+}
+
+#[bench]
+fn bench_weight_one_filter_by_count(b: &mut Bencher) {
+    let oracle = reduced_oracle();
+    b.iter(|| {
+        oracle
+            .samples
+            .iter()
+            .filter(|q| q.hamming_weight() == 1)
+            .count()
+    });
+}
@@ -37,7 +37,7 @@ fn main() {
     println!("Actual:        {:?}", secret);
     let solution = fwht_solve(oracle.clone());
     println!("Found (FWHT):  {:?}", solution);
-    let solution = pooled_gauss_solve(oracle);
+    let solution = pooled_gauss_solve(oracle).expect("Pooled Gauss failed");
     println!("Found (Gauss): {:?}", solution);
 }
 
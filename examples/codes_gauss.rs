@@ -29,7 +29,7 @@ fn main() {
     let secret = oracle.secret.clone();
     code_reduce(&mut oracle, &concatenated);
 
-    let solution = pooled_gauss_solve(oracle);
+    let solution = pooled_gauss_solve(oracle).expect("Pooled Gauss failed");
 
     println!("Found:  {:?}", solution);
     println!("Actual: {:?}", secret);
@@ -33,7 +33,7 @@ fn main() {
     println!("Found (FWHT):   {:?}", fwht_solution);
 
     // solve with pooled gauss
-    let gauss_solution = pooled_gauss_solve(oracle.clone());
+    let gauss_solution = pooled_gauss_solve(oracle.clone()).expect("Pooled Gauss failed");
     println!("Found (Gauss):  {:?}", gauss_solution);
 }
 
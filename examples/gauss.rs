@@ -7,7 +7,7 @@ fn main() {
     let mut oracle: LpnOracle = LpnOracle::new(32, 1.0 / 32.0);
     oracle.get_samples(40555);
     let secret = oracle.secret.clone();
-    let solution = pooled_gauss_solve(oracle);
+    let solution = pooled_gauss_solve(oracle).expect("Pooled Gauss failed");
 
     println!("Found:  {:?}", solution);
     println!("Actual: {:?}", secret.as_binvector(32));
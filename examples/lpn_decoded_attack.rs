@@ -28,7 +28,7 @@ fn main() {
     partition_reduce(&mut oracle, 31);
 
     let secret = oracle.secret.as_binvector(oracle.get_k());
-    let solution = pooled_gauss_solve(oracle);
+    let solution = pooled_gauss_solve(oracle).expect("Pooled Gauss failed");
 
     println!("Found:  {:?}", solution);
     println!("Actual: {:?}", secret);
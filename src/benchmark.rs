@@ -0,0 +1,107 @@
+//! Throughput measurements for the decoding routines of the implemented codes
+use crate::codes::*;
+use m4ri_rust::friendly::BinVector;
+use std::time::Instant;
+
+/// Decoding throughput of a single code
+#[derive(Debug, Clone, Serialize)]
+pub struct CodeBenchmark {
+    /// Name of the code, as given by `BinaryCode::name`
+    pub name: String,
+    /// Length of the code
+    pub n: usize,
+    /// Dimension of the code
+    pub k: usize,
+    /// Millions of vectors passed through `decode_to_code` per second
+    pub throughput_mvectors_per_sec: f64,
+}
+
+/// Result of `benchmark_syndrome_computation`
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkResult {
+    /// One entry per benchmarked code, in the order they were measured
+    pub entries: Vec<CodeBenchmark>,
+}
+
+impl BenchmarkResult {
+    /// Find the entry for the code with this name
+    pub fn get(&self, name: &str) -> Option<&CodeBenchmark> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+}
+
+/// All codes that are compiled in with the current feature set.
+///
+/// The trivial identity and repetition codes are included at a few lengths,
+/// followed by the fixed codes that are enabled by features, see [`fixed_code_constructors`].
+fn available_codes() -> Vec<Box<dyn BinaryCode>> {
+    let mut codes: Vec<Box<dyn BinaryCode>> = Vec::new();
+    for &k in &[3, 64, 1024] {
+        codes.push(Box::new(IdentityCode::new(k)));
+        codes.push(Box::new(RepetitionCode::new(k)));
+    }
+    codes.extend(
+        fixed_code_constructors()
+            .into_iter()
+            .map(|constructor| constructor()),
+    );
+    codes
+}
+
+/// Measure the throughput of `decode_to_code` for every available code.
+///
+/// For each code, `num_vectors` random vectors of the code length are
+/// generated up front; only the decoding itself is timed.
+pub fn benchmark_syndrome_computation(num_vectors: usize) -> BenchmarkResult {
+    let entries = available_codes()
+        .into_iter()
+        .map(|code| {
+            let inputs: Vec<BinVector> = (0..num_vectors)
+                .map(|_| BinVector::random(code.length()))
+                .collect();
+            let start = Instant::now();
+            for v in inputs.iter() {
+                let decoded = code.decode_to_code(v).expect("decoding failed");
+                debug_assert_eq!(decoded.len(), code.length());
+            }
+            let elapsed = start.elapsed().as_secs_f64().max(f64::MIN_POSITIVE);
+            CodeBenchmark {
+                name: code.name(),
+                n: code.length(),
+                k: code.dimension(),
+                throughput_mvectors_per_sec: num_vectors as f64 / elapsed / 1e6,
+            }
+        })
+        .collect();
+
+    BenchmarkResult { entries }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn throughput_is_positive() {
+        let result = benchmark_syndrome_computation(1000);
+        assert!(!result.entries.is_empty());
+        for entry in result.entries.iter() {
+            assert!(
+                entry.throughput_mvectors_per_sec > 0.0,
+                "{} has no throughput",
+                entry.name
+            );
+            assert!(entry.k <= entry.n);
+        }
+    }
+
+    #[test]
+    fn smaller_codes_need_less_work() {
+        let small = RepetitionCode::new(3);
+        let large = RepetitionCode::new(1024);
+        assert!(small.decoding_complexity() < large.decoding_complexity());
+        let result = benchmark_syndrome_computation(10);
+        assert!(result.get(&small.name()).is_some());
+        assert!(result.get(&large.name()).is_some());
+    }
+}
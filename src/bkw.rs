@@ -1,7 +1,10 @@
 //! Defines the algorithms from the classic Blum, Kalai and Wasserman paper
 use crate::oracle::*;
+use crate::random::lpn_thread_rng;
 use fnv::FnvHashMap;
+use itertools::Itertools;
 use m4ri_rust::friendly::BinVector;
+use rand::seq::index;
 use std::{default::Default, num::NonZeroUsize, ops};
 
 use rayon::iter::{Chain, FilterMap, Once, RepeatN, Zip};
@@ -20,6 +23,479 @@ pub fn bkw(mut oracle: LpnOracle, a: u32, b: u32) -> BinVector {
     majority(oracle)
 }
 
+/// BKW with restarts on fresh samples.
+///
+/// Runs [`bkw`] on an oracle obtained from `oracle_factory` and checks the
+/// recovered bits against the secret of that oracle. If they don't match,
+/// a new oracle is requested and BKW is tried again, at most `max_restarts` times.
+///
+/// Returns `None` if none of the attempts recovered the secret.
+pub fn bkw_multi_start(
+    oracle_factory: impl Fn() -> LpnOracle,
+    a: u32,
+    b: u32,
+    max_restarts: usize,
+) -> Option<BinVector> {
+    for attempt in 0..=max_restarts {
+        let oracle = oracle_factory();
+        let mut secret = oracle.secret.as_binvector(oracle.get_k());
+        let solution = bkw(oracle, a, b);
+        secret.truncate(solution.len());
+        if solution == secret {
+            return Some(solution);
+        }
+        log::info!(
+            "BKW attempt {} of {} failed, restarting with fresh samples",
+            attempt + 1,
+            max_restarts + 1
+        );
+    }
+    None
+}
+
+/// BKW amplified by voting over independent trials.
+///
+/// Runs [`bkw`] on `num_trials` oracles obtained from `oracle_factory` in parallel,
+/// and returns the secret recovered most often. Ties are broken in favour of the
+/// candidate closest to the bitwise majority of all trials.
+///
+/// The vote needs at least two successful trials to be reliable. If every trial succeeds
+/// with probability $p = 0.7$, five trials have at most one success with probability
+/// 0.031. The failed trials of BKW mostly return candidates close to the secret, so they
+/// often agree with each other and outvote a single success. Five such trials thus
+/// succeed about 96% of the time, while fifteen trials get above 99%.
+pub fn bkw_amplified(
+    oracle_factory: impl Fn() -> LpnOracle + Sync,
+    a: u32,
+    b: u32,
+    num_trials: usize,
+) -> BinVector {
+    assert!(num_trials > 0, "Need at least one trial");
+    let solutions: Vec<BinVector> = (0..num_trials)
+        .into_par_iter()
+        .map(|_| bkw(oracle_factory(), a, b))
+        .collect();
+
+    let mut votes: FnvHashMap<&BinVector, usize> = FnvHashMap::default();
+    for solution in solutions.iter() {
+        *votes.entry(solution).or_insert(0) += 1;
+    }
+    let len = solutions[0].len();
+    let bitwise_majority = BinVector::from_bools(
+        &(0..len)
+            .map(|i| 2 * solutions.iter().filter(|solution| solution[i]).count() > num_trials)
+            .collect::<Vec<bool>>(),
+    );
+    log::debug!(
+        "{} distinct solutions in {} trials",
+        votes.len(),
+        num_trials
+    );
+    solutions
+        .iter()
+        .max_by_key(|&solution| {
+            let distance = (solution + &bitwise_majority).count_ones();
+            (votes[solution], len - distance as usize)
+        })
+        .unwrap()
+        .clone()
+}
+
+/// BKW for secrets that are a row of the $2^m \times 2^m$ Hadamard matrix.
+///
+/// With the Sylvester construction, such a secret satisfies
+/// $s_j = \langle i, j \rangle \oplus c$ for some $i \in \mathbb{F}_2^m$ and $c \in \mathbb{F}_2$,
+/// where the index $j$ is read as a vector in $\mathbb{F}_2^m$.
+/// Projecting each query $a$ onto the $m + 1$ generating rows of the Hadamard matrix gives
+/// $a' = (\bigoplus_{j : a_j = 1} j, \mathrm{wt}(a) \bmod 2)$ with
+/// $\langle a, s \rangle = \langle a', (i, c) \rangle$.
+/// Samples are grouped by this projection and $(i, c)$ is recovered with [`majority`],
+/// so no reduction steps are needed and far fewer samples suffice than for plain BKW.
+///
+/// Requires $k = 2^m$.
+pub fn bkw_hadamard(oracle: LpnOracle, m: usize) -> BinVector {
+    let k = oracle.get_k();
+    assert_eq!(k, 1 << m, "k should be 2^m");
+
+    // the secret of the projected problem, only kept for bookkeeping
+    let secret = oracle.secret.as_binvector(k);
+    let mut projected_secret = BinVector::with_capacity(m + 1);
+    for t in 0..m {
+        projected_secret.push(secret[1 << t] ^ secret[0]);
+    }
+    projected_secret.push(secret[0]);
+
+    let tau = (1.0 - oracle.delta) / 2.0;
+    let mut projected = LpnOracle::new_with_secret(
+        Sample::from_binvector(&projected_secret, false),
+        (m + 1) as u32,
+        tau,
+    );
+    projected.samples = oracle
+        .samples
+        .into_par_iter()
+        .map(|q| {
+            let mut index_sum: StorageBlock = 0;
+            let mut weight: StorageBlock = 0;
+            for block in 0..blocks_required(k) {
+                let mut word = q.get_block(block);
+                while word != 0 {
+                    index_sum ^= (block * bits_per_block()) as StorageBlock
+                        + StorageBlock::from(word.trailing_zeros());
+                    weight ^= 1;
+                    word &= word - 1;
+                }
+            }
+            let mut sample = Sample::new();
+            sample.get_sample_mut()[0] = index_sum | (weight << m);
+            sample.set_product(q.get_product());
+            sample
+        })
+        .collect();
+
+    let solution = majority(projected);
+    let (i, c) = (solution.get_storage()[0] & ((1 << m) - 1), solution[m]);
+    let bits = (0..k)
+        .map(|j| ((i & j).count_ones() % 2 == 1) ^ c)
+        .collect::<Vec<bool>>();
+    BinVector::from_bools(&bits)
+}
+
+/// Find BKW parameters $(a, b)$ for a problem of size `k` with `n` samples and bias `delta`.
+///
+/// Returns the smallest $a$ (and thus the least amplified noise) with $b = \lfloor k / a \rfloor$
+/// for which, after the $a - 1$ reductions, every bucket used by [`majority`] is expected to hold
+/// at least $8 / \delta'^2$ samples, with $\delta' = \delta^{2^{a-1}}$.
+/// Returns `None` if no such parameters exist.
+pub fn bkw_optimal_params(k: usize, n: usize, delta: f64) -> Option<(u32, u32)> {
+    for a in 1..=k {
+        let b = k / a;
+        let k_final = k - (a - 1) * b;
+        if k_final > 20 {
+            continue;
+        }
+        let delta_final = delta.powf(2f64.powi((a - 1) as i32));
+        if delta_final < f64::EPSILON {
+            break;
+        }
+        let removed = (a - 1).saturating_mul(1usize.checked_shl(b as u32).unwrap_or(usize::MAX));
+        let n_final = n.saturating_sub(removed);
+        if n_final as f64 / 2f64.powi(k_final as i32) >= 8.0 / delta_final.powi(2) {
+            return Some((a as u32, b as u32));
+        }
+    }
+    None
+}
+
+/// BKW that measures the noise rate instead of trusting `oracle.delta`.
+///
+/// A twentieth of the samples is held out as a validation set, after which the first
+/// [`partition_reduce`] step with `b_initial` is done. The noise rate of the validation set is
+/// then measured against the secret of the oracle, and the parameters for the remaining
+/// at most `a - 1` blocks are picked by [`bkw_optimal_params`] using the measured bias.
+pub fn bkw_adaptive_noise(mut oracle: LpnOracle, a: u32, b_initial: u32) -> BinVector {
+    assert!(a >= 2, "Need at least one reduction step");
+    let k = oracle.get_k();
+    let secret = oracle.secret.as_binvector(k);
+    let validation_size = oracle.samples.len() / 20;
+    let validation = oracle
+        .samples
+        .split_off(oracle.samples.len() - validation_size);
+
+    let assumed_tau = oracle.effective_noise();
+    partition_reduce(&mut oracle, b_initial);
+
+    let errors = validation
+        .par_iter()
+        .filter(|q| &q.as_binvector(k) * &secret != q.get_product())
+        .count();
+    let tau = errors as f64 / validation.len() as f64;
+    log::info!(
+        "Measured τ={:0.5} on {} samples, assumed τ={:0.5}",
+        tau,
+        validation.len(),
+        assumed_tau
+    );
+    // the first reduction step squared the bias
+    oracle.delta = (1.0 - 2.0 * tau).powi(2);
+
+    let k_reduced = oracle.get_k();
+    let (a_rest, b_rest) = bkw_optimal_params(k_reduced, oracle.samples.len(), oracle.delta)
+        .filter(|&(a_rest, _)| a_rest < a)
+        .unwrap_or((a - 1, k_reduced as u32 / (a - 1)));
+    log::info!("Continuing BKW with a={}, b={}", a_rest, b_rest);
+    bkw(oracle, a_rest, b_rest)
+}
+
+/// BKW that does reduction steps until `stop_fn` is satisfied.
+///
+/// Before every [`partition_reduce`] step with `b`, `stop_fn` is called on the oracle.
+/// Once it returns true, the remaining bits are recovered with [`majority`].
+/// Every step squares `oracle.delta`, so the bias can be used as a stopping criterion.
+/// As at least one bit has to remain, at most $\lfloor (k - 1) / b \rfloor$ steps are done.
+pub fn bkw_until(mut oracle: LpnOracle, b: u32, stop_fn: impl Fn(&LpnOracle) -> bool) -> BinVector {
+    let max_iterations = (oracle.get_k() - 1) / b as usize;
+    for iteration in 0..max_iterations {
+        if stop_fn(&oracle) {
+            log::info!("Stopping BKW after {} iterations", iteration);
+            break;
+        }
+        partition_reduce(&mut oracle, b);
+    }
+    majority(oracle)
+}
+
+/// BKW with a different block size per tier of reduction steps.
+///
+/// `tiers[i] = (b_i, a_i)` does $a_i$ applications of [`partition_reduce`] with $b_i$,
+/// after which the next tier continues on the reduced problem. The remaining bits are
+/// recovered with [`majority`]. [`bkw`] with $a$ and $b$ corresponds to the single tier
+/// $(b, a - 1)$. Smaller blocks in later tiers need smaller tables and lose fewer samples.
+///
+/// $k' = k - \sum_i a_i b_i$
+/// $n' = n - \sum_i a_i 2^{b_i}$
+/// $d' = delta^{2^{\sum_i a_i}}$
+pub fn bkw_hierarchical(mut oracle: LpnOracle, tiers: &[(u32, u32)]) -> BinVector {
+    let reduced_bits: u32 = tiers.iter().map(|&(b, a)| a * b).sum();
+    assert!(
+        (reduced_bits as usize) < oracle.get_k(),
+        "The tiers should leave at least one bit"
+    );
+    for (tier, &(b, a)) in tiers.iter().enumerate() {
+        log::info!("BKW tier {}: {} steps with b={}", tier, a, b);
+        for _ in 0..a {
+            partition_reduce(&mut oracle, b);
+        }
+    }
+    majority(oracle)
+}
+
+/// Number of samples used to check the candidates in [`guess_then_reduce_bkw`]
+const GUESS_SAMPLES: usize = 1000;
+
+/// Recover a secret of known Hamming weight by guessing its top block.
+///
+/// The secret bits on the top `b` positions are guessed, starting with the guesses of
+/// lowest weight, which are the most likely ones for a sparse secret. A guess is fixed
+/// by adding its inner product with the top block of every query to the product, which
+/// leaves a problem of size $k - b$ on which [`bkw`] needs one reduction step less, so
+/// it works with less noise. The middle bits that neither the guess nor BKW cover get
+/// the remaining weight, and a candidate is accepted as soon as it agrees with clearly
+/// more than half of the first `GUESS_SAMPLES` samples, which stops the search early.
+///
+/// Unlike [`bkw`], this returns all $k$ bits of the secret. If no candidate is accepted,
+/// the one that agrees with the most samples is returned.
+pub fn guess_then_reduce_bkw(oracle: LpnOracle, a: u32, b: u32, secret_weight: usize) -> BinVector {
+    let k = oracle.get_k();
+    assert!(a >= 2, "a >= 2, the guess replaces one reduction step");
+    assert!(((a - 1) * b) < k as u32, "(a-1)*b < k");
+    assert!(secret_weight <= k, "The secret weight should be at most k");
+    let top = (k - b as usize)..k;
+    let queries: Vec<(BinVector, bool)> = oracle.samples[..GUESS_SAMPLES.min(oracle.samples.len())]
+        .iter()
+        .map(|q| (q.as_binvector(k), q.get_product()))
+        .collect();
+    // the secret agrees with a (1 + delta) / 2 fraction of the samples, a wrong candidate
+    // with about half of them
+    let threshold = ((2.0 + oracle.delta) / 4.0 * queries.len() as f64) as usize;
+
+    let guesses =
+        (0..=secret_weight.min(top.len())).flat_map(|weight| top.clone().combinations(weight));
+    let mut best: Option<(usize, BinVector)> = None;
+    for guess in guesses {
+        let mask = guess
+            .iter()
+            .fold(0u64, |mask, &i| mask | 1 << (i - top.start));
+        let mut reduced = oracle.clone();
+        reduced.samples.par_iter_mut().for_each(|q| {
+            let fixed = (query_bits_range(q, top.clone()) & mask).count_ones() % 2 == 1;
+            q.set_product(q.get_product() ^ fixed);
+        });
+        reduced.truncate(top.start);
+        let low = bkw(reduced, a - 1, b);
+        let rest = match secret_weight.checked_sub(guess.len() + low.count_ones() as usize) {
+            Some(rest) => rest,
+            None => continue,
+        };
+
+        for middle in (low.len()..top.start).combinations(rest) {
+            let candidate = BinVector::from_bools(
+                &(0..k)
+                    .map(|i| {
+                        if i < low.len() {
+                            low[i]
+                        } else {
+                            middle.contains(&i) || guess.contains(&i)
+                        }
+                    })
+                    .collect::<Vec<bool>>(),
+            );
+            let agreeing = queries
+                .iter()
+                .filter(|(query, product)| query * &candidate == *product)
+                .count();
+            if agreeing >= threshold {
+                log::debug!(
+                    "Accepted candidate consistent with {} of {} samples",
+                    agreeing,
+                    queries.len()
+                );
+                return candidate;
+            }
+            if best.as_ref().map_or(true, |(score, _)| agreeing > *score) {
+                best = Some((agreeing, candidate));
+            }
+        }
+    }
+    best.expect("No candidate has the given weight").1
+}
+
+/// BKW on a stream of samples.
+///
+/// Every reduction step keeps a table of at most $2^b$ pivot samples. A sample from
+/// `oracle` is stored as the pivot of its block in the first step if that entry is
+/// still empty, otherwise it is XORed with the pivot and passed on to the next step.
+/// Samples that come out of the last step are counted for [`majority`] right away.
+/// Only $(a - 1) 2^b$ samples are kept in memory, so `oracle` may produce more samples
+/// than fit in memory.
+///
+/// `k` is the size of the problem, the samples should not have bits set beyond it.
+pub fn bkw_lazy(oracle: impl Iterator<Item = Sample>, a: u32, b: u32, k: usize) -> BinVector {
+    let (a, b) = (a as usize, b as usize);
+    assert!(a >= 1, "a >= 1");
+    assert!((a - 1) * b < k, "(a-1)*b < k");
+    let k_final = k - (a - 1) * b;
+    debug_assert!(k_final <= 20, "Don't run BKW on too-large b!");
+
+    let mut pivots: Vec<Vec<Option<Sample>>> = vec![vec![None; 1 << b]; a - 1];
+    let mut counts = vec![(0u64, 0u64); k_final];
+    let mut num_samples = 0usize;
+    'samples: for mut sample in oracle {
+        num_samples += 1;
+        for (i, table) in pivots.iter_mut().enumerate() {
+            let idx = query_bits_range(&sample, (k - b * (i + 1))..(k - b * i)) as usize;
+            if let Some(pivot) = &table[idx] {
+                sample.xor_into(pivot);
+            } else {
+                table[idx] = Some(sample);
+                continue 'samples;
+            }
+        }
+        if sample.is_unit_vector() {
+            let count = &mut counts[sample.get_block(0).trailing_zeros() as usize];
+            count.0 += 1;
+            if sample.get_product() {
+                count.1 += 1;
+            }
+        }
+    }
+    log::info!("Lazy BKW processed {} samples", num_samples);
+
+    BinVector::from_bools(
+        &counts
+            .into_iter()
+            .map(|(count, sum)| {
+                assert!(count > 0, "this bucket can't be empty!");
+                count < 2 * sum
+            })
+            .collect::<Vec<bool>>(),
+    )
+}
+
+/// Relevance of `sample` for the bits in `bit_range`
+///
+/// The fraction of the bits of the sample in `bit_range` that are set.
+/// A score of zero means the sample is already reduced on these bits.
+pub fn sample_relevance_score(sample: &Sample, bit_range: &ops::Range<usize>) -> f64 {
+    let set_bits: u32 = bit_range
+        .clone()
+        .step_by(64)
+        .map(|start| query_bits_range(sample, start..(start + 64).min(bit_range.end)).count_ones())
+        .sum();
+    set_bits as f64 / bit_range.len() as f64
+}
+
+/// One reduction step on the top `b` bits that uses the freshest samples as pivots
+///
+/// `depths[i]` is the number of original samples that were added up to `oracle.samples[i]`,
+/// which has bias $\delta^{depths[i]}$ for the bias $\delta$ of the original samples.
+/// The samples are sorted by depth, so the first sample of every block is the least
+/// noisy one and becomes the pivot the others are added to. Among samples of the same
+/// depth, the one with the lowest [`sample_relevance_score`] on the bits that are left
+/// after this step comes first, so the pivot changes as few of these bits as possible.
+/// Samples that are already zero on the top `b` bits are kept as they are instead of
+/// being added to a pivot.
+fn score_ordered_reduce(oracle: &mut LpnOracle, depths: &mut Vec<u32>, b: u32) {
+    let k = oracle.get_k();
+    let b = b as usize;
+    assert!(b < k, "b < k");
+    assert_eq!(
+        depths.len(),
+        oracle.samples.len(),
+        "Need a depth for every sample"
+    );
+    let bit_range: ops::Range<usize> = (k - b)..k;
+    let remaining: ops::Range<usize> = 0..(k - b);
+
+    let mut samples: Vec<(Sample, u32)> = std::mem::take(&mut oracle.samples)
+        .into_iter()
+        .zip(depths.drain(..))
+        .collect();
+    samples.par_sort_by(|(x, x_depth), (y, y_depth)| {
+        x_depth.cmp(y_depth).then_with(|| {
+            sample_relevance_score(x, &remaining)
+                .partial_cmp(&sample_relevance_score(y, &remaining))
+                .unwrap()
+        })
+    });
+
+    let mut pivots: Vec<Option<(Sample, u32)>> = vec![None; 1 << b];
+    let (reduced, reduced_depths): (Vec<Sample>, Vec<u32>) = samples
+        .into_iter()
+        .filter_map(|(mut q, depth)| {
+            let idx = query_bits_range(&q, bit_range.clone()) as usize;
+            if idx == 0 {
+                return Some((q, depth));
+            }
+            if let Some((pivot, pivot_depth)) = &pivots[idx] {
+                q.xor_into(pivot);
+                Some((q, depth + pivot_depth))
+            } else {
+                pivots[idx] = Some((q, depth));
+                None
+            }
+        })
+        .unzip();
+    oracle.samples = reduced;
+    *depths = reduced_depths;
+    log::info!(
+        "BKW iteration, {} samples left after freshness ordered reduction",
+        oracle.samples.len()
+    );
+    oracle.truncate(k - b);
+}
+
+/// BKW that uses the freshest sample of every block as pivot
+///
+/// Does `a-1` reduction steps and solves via majority. Every sample is scored by the
+/// number of original samples it is the sum of, and the samples are sorted by this
+/// score before every step, see `score_ordered_reduce`. Compared to [`bkw`] every step
+/// keeps the samples that are already reduced, so it loses one sample less per step,
+/// and the pivots are less noisy. The bias of the samples is at least `delta`.
+///
+/// $k' = k - (a-1) * b$
+/// $n' = n - (a-1)*(2^b - 1)$
+pub fn bkw_score_ordered(mut oracle: LpnOracle, a: u32, b: u32) -> BinVector {
+    assert!(a >= 1, "a >= 1");
+    assert!(((a - 1) * b) < oracle.get_k() as u32, "(a-1)*b < k");
+    let mut depths = vec![1; oracle.samples.len()];
+    for _ in 1..a {
+        score_ordered_reduce(&mut oracle, &mut depths, b);
+        oracle.delta = oracle.delta.powi(2);
+    }
+    majority(oracle)
+}
+
 pub(crate) fn create_pivots(
     oracle_samples: &mut [Sample],
     bitrange: &std::ops::Range<usize>,
@@ -88,11 +564,180 @@ pub(crate) fn create_partitions<'data, 'pivots>(
     partitions
 }
 
+/// Statistics collected by [`bkw_reduce_monitored`]
+#[derive(Debug, Clone, Default)]
+pub struct BkwStats {
+    /// The fraction of noisy samples after every reduction step
+    pub per_iteration_noise_estimates: Vec<f64>,
+    /// 95% confidence interval on the noise rate after every reduction step
+    pub per_iteration_confidence_intervals: Vec<(f64, f64)>,
+    /// The number of samples with independent noise the estimates are based on
+    pub per_iteration_independent_samples: Vec<usize>,
+}
+
+/// Probability of at most `k` successes in `n` trials with success probability `p`
+fn binomial_cdf(k: usize, n: usize, p: f64) -> f64 {
+    if p <= 0.0 {
+        return 1.0;
+    }
+    if p >= 1.0 {
+        return if k >= n { 1.0 } else { 0.0 };
+    }
+    let (log_p, log_q) = (p.ln(), (1.0 - p).ln());
+    let mut log_binomial = 0f64;
+    let mut cdf = 0f64;
+    for i in 0..=k.min(n) {
+        if i > 0 {
+            log_binomial += ((n - i + 1) as f64).ln() - (i as f64).ln();
+        }
+        cdf += (log_binomial + i as f64 * log_p + (n - i) as f64 * log_q).exp();
+    }
+    cdf.min(1.0)
+}
+
+/// Clopper-Pearson 95% confidence interval for `k` successes in `n` trials
+fn clopper_pearson(k: usize, n: usize) -> (f64, f64) {
+    const ALPHA: f64 = 0.05;
+    if n == 0 {
+        return (0.0, 1.0);
+    }
+    // find p in [0, 1] where the decreasing function f crosses ALPHA / 2
+    let bisect = |f: &dyn Fn(f64) -> f64| {
+        let (mut low, mut high) = (0f64, 1f64);
+        for _ in 0..64 {
+            let mid = (low + high) / 2.0;
+            if f(mid) > ALPHA / 2.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        (low + high) / 2.0
+    };
+    let lower = if k == 0 {
+        0.0
+    } else {
+        bisect(&|p| binomial_cdf(k - 1, n, p))
+    };
+    let upper = if k == n {
+        1.0
+    } else {
+        bisect(&|p| binomial_cdf(k, n, p))
+    };
+    (lower, upper)
+}
+
+/// BKW reduction that estimates the noise rate after every step
+///
+/// Performs the same `a-1` reduction steps as [`bkw`] and uses the secret of the
+/// oracle to count the noisy samples. Reduced samples that share a pivot don't have
+/// independent noise, so the estimates only use samples that are sums of $2^i$
+/// distinct original samples, disjoint from those of the other samples used. Their
+/// noise is independent, which gives a Clopper-Pearson interval on the noise rate
+/// $(1 - \delta^{2^i}) / 2$ of step $i$.
+///
+/// $k' = k - (a-1) * b$
+/// $n' = n - (a-1)*2^b$
+/// $d' = d^{2^{(a-1)}}$
+pub fn bkw_reduce_monitored(oracle: &mut LpnOracle, a: u32, b: u32) -> BkwStats {
+    let k = oracle.get_k();
+    let (a, b) = (a as usize, b as usize);
+    assert!(a * b <= k, "a*b <= k");
+    let secret = oracle.secret.as_binvector(k);
+
+    // the indices of the original samples that every sample is the sum of
+    let num_originals = oracle.samples.len();
+    let mut origins: Vec<Vec<u32>> = (0..num_originals as u32).map(|i| vec![i]).collect();
+    let mut stats = BkwStats::default();
+
+    for i in 1..a {
+        let bitrange: ops::Range<usize> = (k - (b * i))..(k - (b * (i - 1)));
+        let mut pivots: Vec<Option<(Sample, Vec<u32>)>> = vec![None; 1 << b];
+        let samples = std::mem::take(&mut oracle.samples);
+        let mut reduced_origins = Vec::with_capacity(samples.len());
+        oracle.samples.reserve(samples.len());
+        for (mut q, mut origin) in samples.into_iter().zip(origins.into_iter()) {
+            let idx = query_bits_range(&q, bitrange.clone()) as usize;
+            if let Some((pivot, pivot_origin)) = &pivots[idx] {
+                q.xor_into(pivot);
+                origin.extend_from_slice(pivot_origin);
+                oracle.samples.push(q);
+                reduced_origins.push(origin);
+            } else {
+                pivots[idx] = Some((q, origin));
+            }
+        }
+        origins = reduced_origins;
+
+        let mut used = vec![false; num_originals];
+        let (mut independent, mut noisy) = (0, 0);
+        for (q, origin) in oracle.samples.iter().zip(origins.iter()) {
+            if origin.iter().any(|&o| used[o as usize]) {
+                continue;
+            }
+            // a pivot that was added twice cancels out, so this has fewer noise terms
+            let mut sorted = origin.clone();
+            sorted.sort_unstable();
+            if sorted.windows(2).any(|w| w[0] == w[1]) {
+                continue;
+            }
+            origin.iter().for_each(|&o| used[o as usize] = true);
+            independent += 1;
+            if q.get_product() != &q.as_binvector(k) * &secret {
+                noisy += 1;
+            }
+        }
+        let (lower, upper) = clopper_pearson(noisy, independent);
+        log::info!(
+            "BKW iteration {}, {} samples left, noise rate in [{:0.4}, {:0.4}] from {} samples",
+            i,
+            oracle.samples.len(),
+            lower,
+            upper,
+            independent
+        );
+        stats
+            .per_iteration_noise_estimates
+            .push(noisy as f64 / independent.max(1) as f64);
+        stats
+            .per_iteration_confidence_intervals
+            .push((lower, upper));
+        stats.per_iteration_independent_samples.push(independent);
+    }
+
+    oracle.truncate(k - (a - 1) * b);
+    oracle.delta = oracle.delta.powi(1 << (a - 1));
+    stats
+}
+
 /// Reduces the LPN problem size using the reduction from Blum, Kalai and Wasserman.
+///
+/// $k' = k - b$
+/// $n' = n - 2^b$
+/// $d' = d^2$
 pub fn partition_reduce(oracle: &mut LpnOracle, b: u32) {
     bkw_reduce(oracle, 2, b);
 }
 
+/// Cancel the `i`-th block of `b` bits from the top with a lookup table of $2^b$ entries
+///
+/// This is one iteration of [`partition_reduce`] without truncating the oracle, so the
+/// blocks of earlier iterations are still part of the samples. It is fastest for $b < 10$,
+/// see [`bkw_reduce_sorted_step`] for larger blocks.
+#[cfg(feature = "unstable")]
+pub fn bkw_reduce_inplace_step(oracle: &mut LpnOracle, i: usize, b: usize) {
+    bkw_reduce_inplace(oracle, i, b);
+}
+
+/// Cancel the `i`-th block of `b` bits from the top by sorting the samples
+///
+/// Like [`bkw_reduce_inplace_step`], but the samples are sorted by their block, which
+/// does not need a table of $2^b$ entries.
+#[cfg(feature = "unstable")]
+pub fn bkw_reduce_sorted_step(oracle: &mut LpnOracle, i: usize, b: usize) {
+    bkw_reduce_sorted(oracle, i, b);
+}
+
 fn bkw_reduce_inplace(oracle: &mut LpnOracle, i: usize, b: usize) {
     let num_samples = oracle.samples.len();
     let k = oracle.get_k() as usize;
@@ -192,6 +837,71 @@ fn bkw_reduce_sorted(oracle: &mut LpnOracle, i: usize, b: usize) {
     }
 }
 
+/// Reduce the problem by `b` bits with a lookup table that is processed in chunks
+///
+/// This performs the same reduction as [`partition_reduce`], but instead of a table with
+/// $2^b$ entries it uses a table of $2^{chunk\_bits}$ entries. The table is rebuilt for
+/// every chunk of the partitions, which takes $2^{b - chunk\_bits}$ passes over the samples.
+/// This allows to use larger $b$ (15 <= b <= 25) without requiring a huge table.
+///
+/// $k' = k - b$
+/// $n' = n - 2^b$
+/// $d' = d^2$
+pub fn bkw_compressed_table(oracle: &mut LpnOracle, b: u32, chunk_bits: u32) {
+    let k = oracle.get_k();
+    let b = b as usize;
+    let chunk_bits = chunk_bits as usize;
+    assert!(b <= k, "b <= k");
+    assert!(b <= 32, "Don't use too large b! b = {}", b);
+    assert!(0 < chunk_bits && chunk_bits <= b, "0 < chunk_bits <= b");
+
+    let bitrange: ops::Range<usize> = (k - b)..k;
+    let num_chunks = 1u64 << (b - chunk_bits);
+    let chunk_mask = (1u64 << chunk_bits) - 1;
+    println!(
+        "BKW iteration, {} samples left, processing table in {} chunks of {} entries",
+        oracle.samples.len(),
+        num_chunks,
+        1usize << chunk_bits
+    );
+
+    let keys = oracle
+        .samples
+        .par_iter()
+        .map(|q| query_bits_range(q, bitrange.clone()))
+        .collect::<Vec<u64>>();
+    let mut is_pivot = vec![false; keys.len()];
+    let mut table: Vec<Option<usize>> = vec![None; 1 << chunk_bits];
+
+    for chunk in 0..num_chunks {
+        table.iter_mut().for_each(|entry| *entry = None);
+        for (idx, key) in keys.iter().enumerate() {
+            if key >> chunk_bits != chunk {
+                continue;
+            }
+            match table[(key & chunk_mask) as usize] {
+                Some(pivot) => {
+                    // the pivot is always the first sample we saw with this key
+                    let (left, right) = oracle.samples.split_at_mut(idx);
+                    right[0].xor_into(&left[pivot]);
+                }
+                None => {
+                    table[(key & chunk_mask) as usize] = Some(idx);
+                    is_pivot[idx] = true;
+                }
+            }
+        }
+    }
+
+    let mut idx = 0;
+    oracle.samples.retain(|_| {
+        idx += 1;
+        !is_pivot[idx - 1]
+    });
+    oracle.truncate(k - b);
+    oracle.delta = oracle.delta.powi(2);
+}
+
 /// Performs the BKW reduction algorithm, see [`partition_reduce`] for public usage
 fn bkw_reduce(oracle: &mut LpnOracle, a: u32, b: u32) {
     let k = oracle.get_k();
@@ -211,6 +921,7 @@ fn bkw_reduce(oracle: &mut LpnOracle, a: u32, b: u32) {
 
     // Set the new k
     oracle.truncate(k - (a - 1) * b);
+    oracle.delta = oracle.delta.powi(1 << (a - 1));
     println!(
         "BKW iterations done, {} samples left, k' = {}",
         oracle.samples.len(),
@@ -218,7 +929,46 @@ fn bkw_reduce(oracle: &mut LpnOracle, a: u32, b: u32) {
     );
 }
 
+/// Coded-BKW: BKW reduction that cancels blocks up to a codeword of `code`
+///
+/// Performs $a - 1$ rounds of [`crate::lf::lf1_reduce`] on the top $b$ bits: samples
+/// are partitioned by the codeword nearest to their block instead of by the block itself,
+/// so there are only $2^{\dim(C)}$ classes rather than $2^b$. The block of a sum is the
+/// residual $e_1 + e_2$, which is dropped and adds $\langle e_1 + e_2, s_B \rangle$ to
+/// the noise. This only pays off for secrets of low weight, i.e. after
+/// [`crate::covering_codes::sparse_secret_reduce`].
+///
+/// Like [`partition_reduce`], every round only loses one sample per class, where
+/// [`crate::covering_codes::bkw_coset_reduce`] halves the number of samples.
+///
+/// $k' = k - (a-1) \cdot b$
+/// $n' = n - (a-1) \cdot 2^{\dim(C)}$
+/// $\delta' = \delta^{2^{(a-1)}} \cdot \prod \mathrm{avg}(\delta_s^{w})$
+#[cfg(feature = "codes")]
+pub fn coded_bkw_reduce(
+    oracle: &mut LpnOracle,
+    a: u32,
+    b: u32,
+    code: &dyn crate::codes::BinaryCode,
+) {
+    assert_eq!(code.length(), b as usize, "The code should have length b");
+    assert!((a * b) as usize <= oracle.get_k(), "a*b <= k");
+    for _ in 1..a {
+        crate::lf::lf1_reduce(oracle, b, code);
+    }
+    log::debug!(
+        "Coded-BKW iterations done, {} samples left, k' = {}",
+        oracle.samples.len(),
+        oracle.get_k()
+    );
+}
+
 /// Recover the secret using the majority strategy from BKW
+///
+/// Only the samples of weight one are used. For every unit vector $e_i$ their
+/// products are summed as $\pm 1$ into a dense array, bit $i$ of the secret is one if
+/// this bias is negative. [`crate::fwht::fwht_solve`] uses all samples through a
+/// Walsh-Hadamard transform, at the cost of $O(k 2^k)$ time.
 pub fn majority(oracle: LpnOracle) -> BinVector {
     println!("BKW Solver: majority");
     let b = oracle.get_k();
@@ -230,36 +980,103 @@ pub fn majority(oracle: LpnOracle) -> BinVector {
     let samples = oracle
         .samples
         .into_iter()
-        .filter_map(|q| if q.count_ones() == 1 { Some(q) } else { None })
+        .filter(|q| q.is_unit_vector())
         .collect::<Vec<Sample>>();
 
-    // allocate smaller vec
-    let mut count_sum: FnvHashMap<StorageBlock, (u64, u64)> =
-        FnvHashMap::with_capacity_and_hasher(b, Default::default());
+    // the bias and the number of samples for every unit vector e_i
+    let mut bias = vec![0i64; b];
+    let mut counts = vec![0u64; b];
 
     println!(
         "Sorting out and counting {} samples for majority selection",
         samples.len()
     );
     for query in samples.into_iter() {
-        debug_assert_eq!(query.count_ones(), 1);
-        let count_sum = count_sum.entry(query.get_block(0)).or_insert((0, 0));
-        count_sum.0 += 1;
-        if query.get_product() {
-            count_sum.1 += 1;
-        }
+        debug_assert_eq!(query.hamming_weight(), 1);
+        let i = query.get_block(0).trailing_zeros() as usize;
+        counts[i] += 1;
+        bias[i] += if query.get_product() { -1 } else { 1 };
     }
 
     let mut result = BinVector::with_capacity(b as usize);
-    let mut i = 1;
-    while i < 1 << b {
-        let (count, sum) = count_sum.get(&i).expect("this bucket can't be empty!");
-        result.push(*count < 2 * sum);
-        i <<= 1;
+    for (bias, count) in bias.into_iter().zip(counts) {
+        assert!(count > 0, "this bucket can't be empty!");
+        result.push(bias < 0);
+    }
+    result
+}
+
+/// Recover the secret using the majority strategy, counting on sorted samples
+///
+/// Gives the same result as [`majority`]. Instead of counting per unit vector, the
+/// samples of weight one are sorted, after which the $k$ runs of equal samples are
+/// counted in one scan.
+pub fn majority_sorted(oracle: LpnOracle) -> BinVector {
+    let b = oracle.get_k();
+    debug_assert!(b <= 20, "Don't run BKW on too-large b!");
+    let mut samples = oracle
+        .samples
+        .into_iter()
+        .filter(|q| q.is_unit_vector())
+        .collect::<Vec<Sample>>();
+    samples.par_sort_unstable_by_key(|q| q.get_block(0));
+
+    let mut result = BinVector::with_capacity(b);
+    let mut start = 0;
+    for bit in 0..b {
+        let block: StorageBlock = 1 << bit;
+        // skip to the first sample of this block, then find the end of its run
+        while start < samples.len() && samples[start].get_block(0) < block {
+            start += 1;
+        }
+        let mut end = start;
+        let mut sum = 0;
+        while end < samples.len() && samples[end].get_block(0) == block {
+            if samples[end].get_product() {
+                sum += 1;
+            }
+            end += 1;
+        }
+        let count = end - start;
+        assert!(count > 0, "this bucket can't be empty!");
+        result.push(count < 2 * sum);
+        start = end;
     }
     result
 }
 
+/// Number of samples summed in every test of [`probabilistic_verify`]
+const VERIFY_COMBINATION_SIZE: usize = 3;
+
+/// Check a candidate secret against random combinations of the samples
+///
+/// Every test sums `VERIFY_COMBINATION_SIZE` random samples and checks the product
+/// of the sum against `candidate`. Returns the fraction of tests that pass.
+///
+/// Such a sum has noise rate $(1 - \delta^3) / 2$, so the correct secret passes a
+/// $(1 + \delta^3) / 2$ fraction of the tests, a wrong one about half of them.
+/// Only use this for low noise rates.
+pub fn probabilistic_verify(oracle: &LpnOracle, candidate: &BinVector, num_tests: usize) -> f64 {
+    let k = oracle.get_k();
+    assert_eq!(candidate.len(), k, "The candidate needs k bits");
+    assert!(
+        oracle.samples.len() >= VERIFY_COMBINATION_SIZE,
+        "Not enough samples to combine"
+    );
+    let candidate = Sample::from_binvector(candidate, false);
+    let mut rng = lpn_thread_rng();
+    let passed = (0..num_tests)
+        .filter(|_| {
+            let mut sum = Sample::new();
+            for idx in index::sample(&mut rng, oracle.samples.len(), VERIFY_COMBINATION_SIZE) {
+                sum.xor_into(&oracle.samples[idx]);
+            }
+            sum.vector_product(&candidate, k) == sum.get_product()
+        })
+        .count();
+    passed as f64 / num_tests as f64
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -269,20 +1086,240 @@ mod test {
         let a = 4;
         let b = 8;
 
-        let mut oracle: LpnOracle = LpnOracle::new(32, 1.0 / 32.0);
+        let mut secret = BinVector::random(32);
+        let mut oracle: LpnOracle = LpnOracle::from_secret(32, 1.0 / 32.0, secret.clone());
         oracle.get_samples(400_000);
 
-        // get secret for checking
-        let secret = &oracle.secret;
-        println!("{:x?}", secret);
-        let mut secret = secret.as_binvector(oracle.get_k());
-
         // run bkw
         let solution = bkw(oracle, a, b);
         secret.truncate(solution.len());
         assert_eq!(solution, secret);
     }
 
+    #[test]
+    fn test_bkw_multi_start() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        // the first oracle lies about its secret, so that attempt must fail
+        let factory = || {
+            calls.set(calls.get() + 1);
+            let mut oracle = LpnOracle::new(32, 1.0 / 32.0);
+            oracle.get_samples(400_000);
+            if calls.get() == 1 {
+                let wrong = &oracle.secret.as_binvector(32) + &BinVector::from_elem(32, true);
+                oracle.secret = Sample::from_binvector(&wrong, false);
+            }
+            oracle
+        };
+
+        assert!(bkw_multi_start(&factory, 4, 8, 0).is_none());
+        calls.set(0);
+        assert!(bkw_multi_start(&factory, 4, 8, 5).is_some());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn bkw_multi_start_success_rate() {
+        use rand::prelude::*;
+        use rand_chacha::ChaCha8Rng;
+        use std::cell::Cell;
+
+        let runs = 200;
+        let (k, tau, n) = (12, 1.0 / 4.0, 2500);
+        let mut rng = ChaCha8Rng::seed_from_u64(1171);
+        let seed = Cell::new(0);
+        let (mut single_successes, mut restart_successes) = (0, 0);
+        for _ in 0..runs {
+            let bits: Vec<bool> = (0..k).map(|_| rng.gen()).collect();
+            let secret = BinVector::from_bools(&bits);
+            let factory = || {
+                seed.set(seed.get() + 1);
+                let mut oracle = LpnOracle::from_secret(k, tau, secret.clone());
+                oracle.get_samples_seeded(n, seed.get());
+                oracle
+            };
+            if bkw_multi_start(&factory, 2, 6, 0).is_some() {
+                single_successes += 1;
+            }
+            if bkw_multi_start(&factory, 2, 6, 5).is_some() {
+                restart_successes += 1;
+            }
+        }
+        let single = single_successes as f64 / runs as f64;
+        let restarts = restart_successes as f64 / runs as f64;
+        // a borderline instance: a single attempt succeeds about 2/3 of the time
+        assert!(0.5 < single && single < 0.8, "single attempt: {}", single);
+        // six attempts all fail with probability below 0.4^6 = 0.4%
+        assert!(restarts > 0.98, "five restarts: {}", restarts);
+    }
+
+    #[test]
+    fn test_bkw_hadamard() {
+        let m = 5;
+        let k = 1 << m;
+        // row i of the Hadamard matrix, complemented if c is set
+        let (i, c) = (0b10110usize, true);
+        let bits = (0..k)
+            .map(|j| ((i & j).count_ones() % 2 == 1) ^ c)
+            .collect::<Vec<bool>>();
+        let secret = BinVector::from_bools(&bits);
+        let new_oracle = |n| {
+            let mut oracle = LpnOracle::from_secret(k, 1.0 / 32.0, secret.clone());
+            oracle.get_samples(n);
+            oracle
+        };
+
+        // plain BKW needs on the order of 400 000 samples for this problem, see test_bkw
+        assert_eq!(bkw(new_oracle(400_000), 4, 8), {
+            let mut s = secret.clone();
+            s.truncate(8);
+            s
+        });
+        // the structured variant recovers the full secret from a hundred times fewer
+        assert_eq!(bkw_hadamard(new_oracle(4_000), m), secret);
+    }
+
+    #[test]
+    fn test_bkw_optimal_params() {
+        assert_eq!(bkw_optimal_params(24, 20_000, 0.875), Some((3, 8)));
+        assert_eq!(
+            bkw_optimal_params(16, 19_000, 0.875f64.powi(2)),
+            Some((2, 8))
+        );
+        // with the noise rate overestimated by a factor of two this looks infeasible
+        assert_eq!(bkw_optimal_params(16, 19_000, 0.75f64.powi(2)), None);
+        assert_eq!(bkw_optimal_params(24, 100, 0.875), None);
+    }
+
+    #[test]
+    fn test_bkw_adaptive_noise() {
+        let tau = 1.0 / 16.0;
+        let n = 20_000;
+        let (a, b) = bkw_optimal_params(24, n, 1.0 - 2.0 * tau).unwrap();
+        let mut correct_adaptive = 0;
+        let mut correct_true_rate = 0;
+        for _ in 0..100 {
+            let mut oracle = LpnOracle::new(24, tau);
+            oracle.get_samples(n);
+            let secret = oracle.secret.as_binvector(24);
+            let check = |solution: BinVector| {
+                let mut secret = secret.clone();
+                secret.truncate(solution.len());
+                solution == secret
+            };
+
+            let mut wrong_rate = oracle.clone();
+            wrong_rate.delta = 1.0 - 2.0 * (2.0 * tau);
+            if check(bkw_adaptive_noise(wrong_rate, a, b)) {
+                correct_adaptive += 1;
+            }
+            if check(bkw(oracle, a, b)) {
+                correct_true_rate += 1;
+            }
+        }
+        assert_eq!(correct_adaptive, correct_true_rate);
+
+        // with little noise the measured rate allows fewer, larger steps than planned:
+        // after the first step only one step with b=10 is needed instead of two with b=8
+        let mut oracle = LpnOracle::new(28, 1.0 / 32.0);
+        oracle.get_samples(100_000);
+        let mut secret = oracle.secret.as_binvector(28);
+        let planned = bkw(oracle.clone(), 4, 8);
+        let adaptive = bkw_adaptive_noise(oracle, 4, 8);
+        assert_eq!(planned.len(), 4);
+        assert_eq!(adaptive.len(), 10);
+        secret.truncate(10);
+        assert_eq!(adaptive, secret);
+        secret.truncate(4);
+        assert_eq!(planned, secret);
+    }
+
+    #[test]
+    fn test_bkw_until() {
+        use std::cell::Cell;
+
+        let new_oracle = |k| {
+            let secret = BinVector::random(k);
+            let mut oracle = LpnOracle::from_secret(k, 1.0 / 32.0, secret.clone());
+            oracle.get_samples(400_000);
+            (oracle, secret)
+        };
+        let check = |solution: BinVector, mut secret: BinVector, len| {
+            assert_eq!(solution.len(), len);
+            secret.truncate(len);
+            assert_eq!(solution, secret);
+        };
+
+        // target size
+        let (oracle, secret) = new_oracle(32);
+        check(bkw_until(oracle, 8, |o| o.get_k() <= 8), secret, 8);
+
+        // noise rate after three steps is about 0.2, a fourth step would still fit
+        let (oracle, secret) = new_oracle(32);
+        let solution = bkw_until(oracle, 7, |o| (1.0 - o.delta) / 2.0 > 0.15);
+        check(solution, secret, 11);
+
+        // out of time: a clock that ticks on every check runs out before the third step
+        let (oracle, secret) = new_oracle(32);
+        let ticks = Cell::new(0);
+        let solution = bkw_until(oracle, 10, |_| {
+            ticks.set(ticks.get() + 1);
+            ticks.get() >= 3
+        });
+        check(solution, secret, 12);
+        assert_eq!(ticks.get(), 3);
+
+        // never stops by itself
+        let (oracle, secret) = new_oracle(32);
+        check(bkw_until(oracle, 8, |_| false), secret, 8);
+    }
+
+    #[test]
+    fn test_partition_reduce_delta() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(200_000);
+        let measured_bias = |oracle: &LpnOracle| {
+            let secret = oracle.secret.as_binvector(oracle.get_k());
+            let correct = oracle
+                .samples
+                .iter()
+                .filter(|q| &q.as_binvector(oracle.get_k()) * &secret == q.get_product())
+                .count();
+            2.0 * correct as f64 / oracle.samples.len() as f64 - 1.0
+        };
+
+        // the sum of two samples is correct if both or neither are noisy
+        partition_reduce(&mut oracle, 8);
+        assert_eq!(oracle.delta, 0.75f64.powi(2));
+        let bias = measured_bias(&oracle);
+        assert!((bias - oracle.delta).abs() < 0.01, "bias {}", bias);
+
+        // every further step of bkw_reduce squares it again
+        bkw_reduce(&mut oracle, 3, 8);
+        assert_eq!(oracle.delta, 0.75f64.powi(8));
+        let bias = measured_bias(&oracle);
+        assert!((bias - oracle.delta).abs() < 0.01, "bias {}", bias);
+    }
+
+    #[test]
+    fn test_bkw_compressed_table() {
+        let mut oracle = LpnOracle::new(32, 0.0);
+        oracle.get_samples(50_000);
+        let num_samples = oracle.samples.len();
+
+        bkw_compressed_table(&mut oracle, 20, 10);
+
+        assert_eq!(oracle.get_k(), 12);
+        assert!(oracle.samples.len() < num_samples);
+        // without noise every reduced sample should be consistent with the secret
+        let secret = oracle.secret.as_binvector(12);
+        for q in oracle.samples.iter() {
+            assert_eq!(query_bits_range(q, 12..32), 0);
+            assert_eq!(&q.as_binvector(12) * &secret, q.get_product());
+        }
+    }
+
     #[test]
     fn test_partition() {
         let k = MAX_K - 10;
@@ -307,4 +1344,298 @@ mod test {
         }
         assert!(!failed);
     }
+
+    #[test]
+    fn test_bkw_hierarchical() {
+        let mut secret = BinVector::random(48);
+        let mut oracle: LpnOracle = LpnOracle::from_secret(48, 1.0 / 64.0, secret.clone());
+        oracle.get_samples(400_000);
+        secret.truncate(8);
+
+        let hierarchical = bkw_hierarchical(oracle.clone(), &[(8, 4), (4, 2)]);
+        assert_eq!(hierarchical, secret);
+        assert_eq!(bkw(oracle, 6, 8), secret);
+    }
+
+    #[test]
+    fn test_bkw_amplified() {
+        let runs = 1000;
+        let (k, tau, n) = (12, 1.0 / 4.0, 2500);
+        let mut single_successes = 0;
+        let mut amplified_successes = 0;
+        let mut many_trials_successes = 0;
+        for _ in 0..runs {
+            let secret = LpnOracle::new(k, tau).secret;
+            let factory = || {
+                let mut oracle = LpnOracle::new_with_secret(secret.clone(), k, tau);
+                oracle.get_samples(n);
+                oracle
+            };
+            let mut expected = secret.as_binvector(k as usize);
+            expected.truncate(6);
+            if bkw(factory(), 2, 6) == expected {
+                single_successes += 1;
+            }
+            if bkw_amplified(&factory, 2, 6, 5) == expected {
+                amplified_successes += 1;
+            }
+            if bkw_amplified(&factory, 2, 6, 15) == expected {
+                many_trials_successes += 1;
+            }
+        }
+        let single = single_successes as f64 / runs as f64;
+        let amplified = amplified_successes as f64 / runs as f64;
+        let many_trials = many_trials_successes as f64 / runs as f64;
+        // a borderline instance: a single trial succeeds about 2/3 of the time
+        assert!(0.55 < single && single < 0.8, "single trial: {}", single);
+        // the vote fails if at most one trial succeeds, (1/3)^5 + 5 (2/3) (1/3)^4 = 4.5% for
+        // five trials, and sometimes with two successes if the failed trials agree. With
+        // fifteen trials at most one succeeds with probability 2e-6, so only ties are left
+        assert!(amplified > 0.94, "five trials: {}", amplified);
+        assert!(many_trials > 0.99, "fifteen trials: {}", many_trials);
+    }
+
+    #[test]
+    fn test_guess_then_reduce_bkw() {
+        let k = 32;
+        // without and with a bit in the guessed top block
+        for (seed, secret) in [
+            BinVector::from_function(k, |i| i == 3 || i == 20),
+            BinVector::from_function(k, |i| i == 3 || i == 27),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut oracle = LpnOracle::from_secret(k, 1.0 / 8.0, secret.clone());
+            // three reduction steps would leave a bias of 0.75^8 = 0.1, after guessing the
+            // top block two steps leave 0.75^4 = 0.32
+            oracle.get_samples_seeded(100_000, seed as u64);
+            assert_eq!(&guess_then_reduce_bkw(oracle, 4, 8, 2), secret);
+        }
+    }
+
+    #[test]
+    fn test_bkw_lazy() {
+        use rand::distributions::{Bernoulli, Distribution};
+        use rand::prelude::*;
+        use rand_chacha::ChaCha8Rng;
+
+        let (k, n, tau) = (32, 1_000_000, 1.0 / 32.0);
+        let oracle = LpnOracle::new(k as u32, tau);
+        let secret = oracle.secret.as_binvector(k);
+        let samples = |seed| {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let noise = Bernoulli::new(tau).unwrap();
+            let secret = &secret;
+            (0..n).map(move |_| {
+                let query = BinVector::from_bools(&(0..k).map(|_| rng.gen()).collect::<Vec<_>>());
+                let product = (&query * secret) ^ noise.sample(&mut rng);
+                Sample::from_binvector(&query, product)
+            })
+        };
+        let mut expected = secret.clone();
+        expected.truncate(8);
+
+        assert_eq!(bkw_lazy(samples(42), 4, 8, k), expected);
+        assert_eq!(
+            bkw_lazy(oracle.clone().into_streaming().take(n), 4, 8, k),
+            expected
+        );
+
+        let mut eager = oracle.clone();
+        eager.samples = samples(42).collect();
+        assert_eq!(bkw(eager, 4, 8), expected);
+    }
+
+    #[test]
+    fn test_sample_relevance_score() {
+        let bits = BinVector::from_function(32, |i| i == 1 || i == 24 || i == 25 || i == 31);
+        let sample = Sample::from_binvector(&bits, false);
+        assert_eq!(sample_relevance_score(&sample, &(24..32)), 3.0 / 8.0);
+        assert_eq!(sample_relevance_score(&sample, &(0..24)), 1.0 / 24.0);
+        assert_eq!(sample_relevance_score(&sample, &(8..24)), 0.0);
+        // ranges longer than a block
+        let bits = BinVector::from_function(100, |i| i % 10 == 0);
+        let sample = Sample::from_binvector(&bits, false);
+        assert_eq!(sample_relevance_score(&sample, &(0..100)), 0.1);
+    }
+
+    #[test]
+    fn test_bkw_score_ordered() {
+        let mut secret = BinVector::random(32);
+        let mut oracle: LpnOracle = LpnOracle::from_secret(32, 1.0 / 32.0, secret.clone());
+        oracle.get_samples(400_000);
+        let noise_rate = |oracle: &LpnOracle| {
+            let secret = oracle.secret.as_binvector(oracle.get_k());
+            let errors = oracle
+                .samples
+                .iter()
+                .filter(|q| &q.as_binvector(oracle.get_k()) * &secret != q.get_product())
+                .count();
+            errors as f64 / oracle.samples.len() as f64
+        };
+
+        let mut plain = oracle.clone();
+        let mut ordered = oracle.clone();
+        let mut depths = vec![1; ordered.samples.len()];
+        for step in 1..4 {
+            let (plain_before, ordered_before) = (plain.samples.len(), ordered.samples.len());
+            partition_reduce(&mut plain, 8);
+            score_ordered_reduce(&mut ordered, &mut depths, 8);
+            // the samples that are already reduced are not used up as pivots
+            let plain_wasted = plain_before - plain.samples.len();
+            let ordered_wasted = ordered_before - ordered.samples.len();
+            assert!(
+                ordered_wasted < plain_wasted,
+                "step {}: {} >= {}",
+                step,
+                ordered_wasted,
+                plain_wasted
+            );
+            assert_eq!(ordered.get_k(), 32 - 8 * step);
+            assert_eq!(depths.len(), ordered.samples.len());
+        }
+        // all samples of plain BKW are sums of 8 samples, with fresh pivots most are sums
+        // of 5 samples
+        assert!(depths.iter().all(|&depth| depth <= 8));
+        let (plain_noise, ordered_noise) = (noise_rate(&plain), noise_rate(&ordered));
+        assert!(
+            ordered_noise + 0.03 < plain_noise,
+            "noise rate with fresh pivots {}, without {}",
+            ordered_noise,
+            plain_noise
+        );
+
+        let solution = bkw_score_ordered(oracle, 4, 8);
+        secret.truncate(solution.len());
+        assert_eq!(solution, secret);
+    }
+
+    #[test]
+    fn test_bkw_reduce_monitored() {
+        let tau = 0.05;
+        let delta: f64 = 1.0 - 2.0 * tau;
+        let true_rate = |iteration: usize| (1.0 - delta.powi(1 << (iteration + 1))) / 2.0;
+
+        let mut covered = 0;
+        let mut total = 0;
+        for _ in 0..1000 {
+            let mut oracle = LpnOracle::new(24, tau);
+            oracle.get_samples(2000);
+            let stats = bkw_reduce_monitored(&mut oracle, 4, 6);
+            assert_eq!(oracle.get_k(), 6);
+            assert_eq!(stats.per_iteration_confidence_intervals.len(), 3);
+            for (i, &(lower, upper)) in stats.per_iteration_confidence_intervals.iter().enumerate()
+            {
+                total += 1;
+                if lower <= true_rate(i) && true_rate(i) <= upper {
+                    covered += 1;
+                }
+            }
+        }
+        assert!(
+            covered as f64 >= 0.95 * total as f64,
+            "{} of {} intervals contain the noise rate",
+            covered,
+            total
+        );
+
+        // more samples give more samples with independent noise
+        let mean_widths = |n: usize| {
+            let mut widths = vec![0.0; 3];
+            for _ in 0..100 {
+                let mut oracle = LpnOracle::new(24, tau);
+                oracle.get_samples(n);
+                let stats = bkw_reduce_monitored(&mut oracle, 4, 6);
+                for (width, (lower, upper)) in widths
+                    .iter_mut()
+                    .zip(stats.per_iteration_confidence_intervals)
+                {
+                    *width += (upper - lower) / 100.0;
+                }
+            }
+            widths
+        };
+        let (few, many) = (mean_widths(150), mean_widths(20_000));
+        for (i, (few, many)) in few.iter().zip(many.iter()).enumerate() {
+            assert!(few > many, "iteration {}: {} <= {}", i, few, many);
+        }
+    }
+
+    #[test]
+    fn test_majority_sorted() {
+        for &tau in &[0.0, 1.0 / 8.0, 1.0 / 4.0] {
+            let mut oracle: LpnOracle = LpnOracle::new(32, tau);
+            oracle.get_samples(100_000);
+            partition_reduce(&mut oracle, 10);
+            partition_reduce(&mut oracle, 10);
+            assert_eq!(majority_sorted(oracle.clone()), majority(oracle));
+        }
+    }
+
+    #[test]
+    fn test_probabilistic_verify() {
+        let secret = BinVector::random(64);
+        let mut oracle: LpnOracle = LpnOracle::from_secret(64, 1.0 / 100.0, secret.clone());
+        oracle.get_samples(10_000);
+
+        // (1 + 0.98^3) / 2 = 0.97
+        let correct = probabilistic_verify(&oracle, &secret, 2000);
+        assert!(correct > 0.93, "correct secret passed {}", correct);
+        for _ in 0..10 {
+            let wrong = probabilistic_verify(&oracle, &BinVector::random(64), 2000);
+            assert!(
+                (0.44..0.56).contains(&wrong),
+                "wrong secret passed {}",
+                wrong
+            );
+        }
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn test_coded_bkw_reduce() {
+        use crate::codes::HammingCode7_4;
+        let (a, b) = (3, 7);
+        // the secret is zero on the blocks that get cancelled, so the residuals add no noise
+        let secret =
+            BinVector::from_bools(&(0..24).map(|i| i < 10 && i % 3 == 0).collect::<Vec<bool>>());
+        let mut oracle = LpnOracle::from_secret(24, 0.0, secret);
+        oracle.get_samples(10_000);
+        coded_bkw_reduce(&mut oracle, a, b, &HammingCode7_4);
+        assert_eq!(oracle.get_k(), 10);
+        // at most 2^4 classes per round
+        assert!(oracle.samples.len() >= 10_000 - 2 * 16);
+        let secret = oracle.secret.as_binvector(10);
+        for q in oracle.samples.iter() {
+            assert_eq!(&q.as_binvector(10) * &secret, q.get_product());
+        }
+    }
+
+    #[test]
+    fn test_effective_noise() {
+        let secret = BinVector::random(32);
+        let mut oracle: LpnOracle = LpnOracle::from_secret(32, 1.0 / 8.0, secret.clone());
+        assert!((oracle.effective_noise() - 1.0 / 8.0).abs() < 1e-12);
+        oracle.get_samples(200_000);
+        partition_reduce(&mut oracle, 8);
+        partition_reduce(&mut oracle, 8);
+        let expected = (1.0 - 0.75f64.powi(4)) / 2.0;
+        assert!((oracle.effective_noise() - expected).abs() < 1e-12);
+
+        let k = oracle.get_k();
+        let secret = BinVector::from_bools(&(0..k).map(|i| secret[i]).collect::<Vec<bool>>());
+        let errors = oracle
+            .samples
+            .iter()
+            .filter(|q| &q.as_binvector(k) * &secret != q.get_product())
+            .count();
+        let measured = errors as f64 / oracle.samples.len() as f64;
+        assert!(
+            (measured - expected).abs() < 0.02,
+            "{} vs {}",
+            measured,
+            expected
+        );
+    }
 }
@@ -0,0 +1,128 @@
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::*;
+
+/// Affine code: the coset $C + v$ of a linear code $C$ with coset leader $v$
+///
+/// Messages are encoded as $mG + v$.
+pub struct AffineCode {
+    linear_code: Box<dyn BinaryCode>,
+    coset_leader: BinVector,
+}
+
+impl AffineCode {
+    /// Construct the coset `linear_code + coset_leader`
+    pub fn new(linear_code: Box<dyn BinaryCode>, coset_leader: BinVector) -> AffineCode {
+        assert_eq!(
+            coset_leader.len(),
+            linear_code.length(),
+            "The coset leader should have the length of the code"
+        );
+        AffineCode {
+            linear_code,
+            coset_leader,
+        }
+    }
+
+    /// The coset leader $v$
+    pub fn coset_leader(&self) -> &BinVector {
+        &self.coset_leader
+    }
+
+    /// The linear code $C$
+    pub fn linear_code(&self) -> &dyn BinaryCode {
+        self.linear_code.as_ref()
+    }
+
+    /// Check if `c` is an element of the coset
+    pub fn contains(&self, c: &BinVector) -> bool {
+        let shifted = c + &self.coset_leader;
+        (&shifted * &self.linear_code.parity_check_matrix().transposed()).count_ones() == 0
+    }
+}
+
+impl BinaryCode for AffineCode {
+    fn name(&self) -> String {
+        format!("Coset of {}", self.linear_code.name())
+    }
+
+    fn length(&self) -> usize {
+        self.linear_code.length()
+    }
+
+    fn dimension(&self) -> usize {
+        self.linear_code.dimension()
+    }
+
+    /// Generator matrix of the linear code $C$
+    fn generator_matrix(&self) -> &BinMatrix {
+        self.linear_code.generator_matrix()
+    }
+
+    /// Parity check matrix of the linear code $C$
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        self.linear_code.parity_check_matrix()
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let shifted = c + &self.coset_leader;
+        Ok(&self.linear_code.decode_to_code(&shifted)? + &self.coset_leader)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let shifted = c + &self.coset_leader;
+        self.linear_code.decode_to_message(&shifted)
+    }
+
+    fn encode(&self, c: &BinVector) -> BinVector {
+        &self.linear_code.encode(c) + &self.coset_leader
+    }
+
+    /// Check if `v` is an element of the coset, not of the linear code
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        self.contains(v)
+    }
+}
+
+#[cfg(all(test, feature = "hamming"))]
+mod test {
+    use super::*;
+    use crate::codes::HammingCode7_4;
+
+    fn messages() -> impl Iterator<Item = BinVector> {
+        (0..16u8).map(|m| {
+            let bits: Vec<bool> = (0..4).map(|i| (m >> i) & 1 == 1).collect();
+            BinVector::from_bools(&bits)
+        })
+    }
+
+    #[test]
+    fn hamming_coset() {
+        let leader = BinVector::from_bools(&[true, false, false, false, false, false, false]);
+        let code = AffineCode::new(Box::new(HammingCode7_4), leader.clone());
+        // the leader is not a codeword, so this is a proper coset
+        assert!(!code.contains(&BinVector::from_elem(7, false)));
+
+        let coset: Vec<BinVector> = messages().map(|m| code.encode(&m)).collect();
+        assert!(!coset.is_empty());
+        for (m, element) in messages().zip(coset.iter()) {
+            assert_eq!(element, &(&HammingCode7_4.encode(&m) + &leader));
+            assert!(code.contains(element));
+            assert!(code.is_codeword(element));
+            assert!(!code.is_codeword(&HammingCode7_4.encode(&m)));
+            assert_eq!(code.decode_to_code(element), Ok(element.clone()));
+            assert_eq!(code.decode_to_message(element), Ok(m));
+        }
+
+        for _ in 0..1000 {
+            let v = BinVector::random(7);
+            let decoded = code.decode_to_code(&v).unwrap();
+            assert!(code.contains(&decoded));
+            // the Hamming code is perfect with covering radius 1
+            assert!((&decoded + &v).count_ones() <= 1);
+            let message = code.decode_to_message(&v).unwrap();
+            let reencoded = code.encode(&message);
+            assert!(coset.contains(&reencoded));
+            assert_eq!(reencoded, decoded);
+        }
+    }
+}
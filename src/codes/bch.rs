@@ -0,0 +1,398 @@
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::*;
+
+/// Primitive polynomials for $GF(2^m)$, indexed by $m$, with the bit $i$ the coefficient of $x^i$
+const PRIMITIVE_POLYNOMIALS: [usize; 17] = [
+    0,
+    0,
+    0b111,
+    0b1011,
+    0b1_0011,
+    0b10_0101,
+    0b100_0011,
+    0b1000_1001,
+    0b1_0001_1101,
+    0b10_0001_0001,
+    0b100_0000_1001,
+    0b1000_0000_0101,
+    0b1_0000_0101_0011,
+    0b10_0000_0001_1011,
+    0b100_0100_0100_0011,
+    0b1000_0000_0000_0011,
+    0b1_0001_0000_0000_1011,
+];
+
+/// Log and antilog tables of $GF(2^m)$ for a primitive element $\alpha$
+#[derive(Clone, Serialize)]
+pub(super) struct BinaryExtensionField {
+    /// The order $2^m - 1$ of the multiplicative group
+    order: usize,
+    exp: Vec<usize>,
+    log: Vec<usize>,
+}
+
+impl BinaryExtensionField {
+    pub(super) fn new(m: usize) -> BinaryExtensionField {
+        let order = (1 << m) - 1;
+        let mut exp = vec![0; order];
+        let mut log = vec![0; order + 1];
+        let mut x = 1;
+        for (i, power) in exp.iter_mut().enumerate() {
+            *power = x;
+            log[x] = i;
+            x <<= 1;
+            if x >> m != 0 {
+                x ^= PRIMITIVE_POLYNOMIALS[m];
+            }
+        }
+        debug_assert_eq!(x, 1, "The polynomial should be primitive");
+        BinaryExtensionField { order, exp, log }
+    }
+
+    /// $\alpha^e$, for any integer $e$
+    fn alpha_pow(&self, e: isize) -> usize {
+        self.exp[e.rem_euclid(self.order as isize) as usize]
+    }
+
+    pub(super) fn mul(&self, a: usize, b: usize) -> usize {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[(self.log[a] + self.log[b]) % self.order]
+        }
+    }
+
+    pub(super) fn div(&self, a: usize, b: usize) -> usize {
+        debug_assert_ne!(b, 0, "Division by zero");
+        if a == 0 {
+            0
+        } else {
+            self.exp[(self.log[a] + self.order - self.log[b]) % self.order]
+        }
+    }
+}
+
+/// Narrow-sense primitive BCH code
+///
+/// The generator polynomial $g(x)$ is the least common multiple of the minimal polynomials
+/// of $\alpha, \alpha^2, \ldots, \alpha^{\delta - 1}$ for a primitive element $\alpha$ of
+/// $GF(2^m)$. This gives an $[n = 2^m - 1, k = n - \deg g]$ code of minimum distance at
+/// least the designed distance $\delta$.
+///
+/// The generator matrix is systematic: position $p$ of a codeword holds the coefficient of
+/// $x^{p + n - k \bmod n}$, so the message is in the first $k$ positions.
+/// Decodes up to $\lfloor (\delta - 1) / 2 \rfloor$ errors with Berlekamp-Massey,
+/// fails for vectors further away from the code.
+#[derive(Clone, Serialize)]
+pub struct BchCode {
+    length: usize,
+    dimension: usize,
+    designed_distance: usize,
+    field: BinaryExtensionField,
+    generator: BinMatrix,
+    parity_check: BinMatrix,
+}
+
+/// Product of two polynomials over GF(2), coefficients from low to high degree
+fn poly_mul(a: &[bool], b: &[bool]) -> Vec<bool> {
+    let mut product = vec![false; a.len() + b.len() - 1];
+    for (i, _) in a.iter().enumerate().filter(|&(_, &x)| x) {
+        for (j, _) in b.iter().enumerate().filter(|&(_, &y)| y) {
+            product[i + j] ^= true;
+        }
+    }
+    product
+}
+
+impl BchCode {
+    /// Construct the BCH code of length `n` $= 2^m - 1$ with designed distance `delta`
+    ///
+    /// The dimension follows from $n$ and $\delta$.
+    /// Panics if $n$ is not of this form with $2 \le m \le 16$, or if the code would be empty.
+    pub fn new(n: usize, delta: usize) -> BchCode {
+        let m = (n + 1).trailing_zeros() as usize;
+        assert!(
+            (n + 1).is_power_of_two() && (2..=16).contains(&m),
+            "n should be 2^m - 1 with 2 <= m <= 16"
+        );
+        assert!((2..=n).contains(&delta), "2 <= delta <= n");
+        let field = BinaryExtensionField::new(m);
+
+        // multiply the minimal polynomials of the distinct cyclotomic cosets
+        let mut generator_poly = vec![true];
+        let mut seen = vec![false; n];
+        for i in 1..delta {
+            if seen[i % n] {
+                continue;
+            }
+            let mut minimal_poly = vec![1];
+            let mut j = i % n;
+            while !seen[j] {
+                seen[j] = true;
+                // multiply by (x + alpha^j)
+                let root = field.exp[j];
+                let mut next = vec![0; minimal_poly.len() + 1];
+                for (t, &coefficient) in minimal_poly.iter().enumerate() {
+                    next[t + 1] ^= coefficient;
+                    next[t] ^= field.mul(root, coefficient);
+                }
+                minimal_poly = next;
+                j = 2 * j % n;
+            }
+            debug_assert!(minimal_poly.iter().all(|&c| c <= 1));
+            let minimal_poly: Vec<bool> = minimal_poly.into_iter().map(|c| c == 1).collect();
+            generator_poly = poly_mul(&generator_poly, &minimal_poly);
+        }
+        let redundancy = generator_poly.len() - 1;
+        assert!(redundancy < n, "The code would only contain zero");
+        let k = n - redundancy;
+
+        // row i encodes x^{n-k+i} as itself plus its remainder modulo g(x)
+        let mut rows = Vec::with_capacity(k);
+        let mut remainder = vec![false; redundancy];
+        if redundancy > 0 {
+            remainder[0] = true;
+        }
+        for degree in 0..n {
+            if degree >= redundancy {
+                let mut row = vec![false; n];
+                row[degree - redundancy] = true;
+                for (e, _) in remainder.iter().enumerate().filter(|&(_, &r)| r) {
+                    row[e + k] = true;
+                }
+                rows.push(row);
+            }
+            if redundancy > 0 {
+                // multiply by x and reduce
+                let top = remainder[redundancy - 1];
+                remainder.rotate_right(1);
+                remainder[0] = false;
+                if top {
+                    remainder
+                        .iter_mut()
+                        .zip(generator_poly.iter())
+                        .for_each(|(r, &g)| *r ^= g);
+                }
+            }
+        }
+        let generator = BinMatrix::new(rows.iter().map(|row| BinVector::from_bools(row)).collect());
+
+        // G = [I | P] gives H = [P^T | I]
+        let parity_check = BinMatrix::new(
+            (0..redundancy)
+                .map(|j| {
+                    BinVector::from_bools(
+                        &(0..n)
+                            .map(|p| if p < k { rows[p][k + j] } else { p == k + j })
+                            .collect::<Vec<bool>>(),
+                    )
+                })
+                .collect(),
+        );
+
+        BchCode {
+            length: n,
+            dimension: k,
+            designed_distance: delta,
+            field,
+            generator,
+            parity_check,
+        }
+    }
+
+    /// The designed distance $\delta$
+    pub fn designed_distance(&self) -> usize {
+        self.designed_distance
+    }
+
+    /// The exponent of $x$ of which position `p` holds the coefficient
+    fn exponent(&self, p: usize) -> usize {
+        (p + self.length - self.dimension) % self.length
+    }
+
+    /// The syndromes $c(\alpha^j)$ for $j = 1, \ldots, \delta - 1$
+    fn syndromes(&self, c: &BinVector) -> Vec<usize> {
+        let mut syndromes = vec![0; self.designed_distance - 1];
+        for p in (0..self.length).filter(|&p| c[p]) {
+            let e = self.exponent(p) as isize;
+            for (j, syndrome) in syndromes.iter_mut().enumerate() {
+                *syndrome ^= self.field.alpha_pow((j as isize + 1) * e);
+            }
+        }
+        syndromes
+    }
+
+    /// The error locator polynomial from the syndromes, by Berlekamp-Massey
+    fn error_locator(&self, syndromes: &[usize]) -> Vec<usize> {
+        let mut locator = vec![0; syndromes.len() + 1];
+        locator[0] = 1;
+        let mut previous = locator.clone();
+        let mut degree = 0;
+        let mut shift = 1;
+        let mut previous_discrepancy = 1;
+        for (r, &syndrome) in syndromes.iter().enumerate() {
+            let discrepancy = (1..=degree).fold(syndrome, |d, i| {
+                d ^ self.field.mul(locator[i], syndromes[r - i])
+            });
+            if discrepancy == 0 {
+                shift += 1;
+                continue;
+            }
+            let factor = self.field.div(discrepancy, previous_discrepancy);
+            let old = locator.clone();
+            locator[shift..]
+                .iter_mut()
+                .zip(previous.iter())
+                .for_each(|(l, &b)| *l ^= self.field.mul(factor, b));
+            if 2 * degree <= r {
+                degree = r + 1 - degree;
+                previous = old;
+                previous_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+        locator.truncate(degree + 1);
+        locator
+    }
+}
+
+impl BinaryCode for BchCode {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] BCH code with designed distance {}",
+            self.length, self.dimension, self.designed_distance
+        )
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(c.len(), self.length, "Vector of the wrong length");
+        let syndromes = self.syndromes(c);
+        if syndromes.iter().all(|&s| s == 0) {
+            return Ok(c.clone());
+        }
+        let locator = self.error_locator(&syndromes);
+
+        // Chien search: an error at exponent e is a root alpha^{-e} of the locator
+        let mut result = c.clone();
+        let mut errors = 0;
+        for e in 0..self.length {
+            let value = locator
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, &coefficient)| {
+                    acc ^ self
+                        .field
+                        .mul(coefficient, self.field.alpha_pow(-((e * i) as isize)))
+                });
+            if value == 0 {
+                let p = (e + self.dimension) % self.length;
+                result.set(p, !result[p]);
+                errors += 1;
+            }
+        }
+        if errors + 1 != locator.len() {
+            return Err("Too many errors to decode");
+        }
+        Ok(result)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let mut codeword = self.decode_to_code(c)?;
+        codeword.truncate(self.dimension);
+        Ok(codeword)
+    }
+
+    /// Berlekamp-Massey corrects up to half the designed distance
+    fn error_correction_capability(&self) -> usize {
+        (self.designed_distance - 1) / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::index;
+
+    #[test]
+    fn dimensions() {
+        for &(n, delta, k) in &[
+            (7, 3, 4),
+            (15, 3, 11),
+            (15, 5, 7),
+            (15, 7, 5),
+            (31, 5, 21),
+            (31, 7, 16),
+            (63, 11, 36),
+            (127, 9, 99),
+        ] {
+            let code = BchCode::new(n, delta);
+            assert_eq!(code.dimension(), k, "{}", code.name());
+            let product = code.generator_matrix() * &code.parity_check_matrix().transposed();
+            assert_eq!(product, BinMatrix::zero(k, n - k));
+        }
+    }
+
+    #[test]
+    fn corrects_errors() {
+        let mut rng = crate::random::lpn_thread_rng();
+        for &(n, delta) in &[(7, 3), (15, 5), (15, 7), (31, 7), (63, 11), (127, 9)] {
+            let code = BchCode::new(n, delta);
+            let t = (delta - 1) / 2;
+            for weight in 0..=t {
+                for _ in 0..50 {
+                    let message = BinVector::random(code.dimension());
+                    let codeword = code.encode(&message);
+                    let mut received = codeword.clone();
+                    for p in index::sample(&mut rng, n, weight).into_iter() {
+                        received.set(p, !received[p]);
+                    }
+                    assert_eq!(code.decode_to_code(&received).unwrap(), codeword);
+                    assert_eq!(code.decode_to_message(&received).unwrap(), message);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hamming_code() {
+        // the BCH code with designed distance 3 is the Hamming code
+        let code = BchCode::new(15, 3);
+        assert_eq!(crate::codes::minimum_distance(&code), 3);
+        assert!(crate::codes::verify_perfect_decoding(&code));
+        assert_eq!(code.covering_radius(), 1);
+    }
+
+    #[test]
+    fn bias_of_bounded_distance_decoder() {
+        // the [15, 7] code has 1, 15, 105 and 135 coset leaders of weight 0 to 3, but the
+        // decoder fails on the cosets of weight 3
+        let code = BchCode::new(15, 5);
+        assert_eq!(
+            code.coset_leader_weight_distribution(),
+            vec![1, 15, 105, 135]
+        );
+        let delta = 0.5;
+        let decoded = (1.0 + 15.0 * delta + 105.0 * delta.powi(2)) / 256.0;
+        let bias = code.bias(delta);
+        assert!((bias - decoded).abs() < 0.01, "bias {}", bias);
+        assert_eq!(bias, code.bias(delta));
+    }
+}
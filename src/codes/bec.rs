@@ -0,0 +1,128 @@
+//! Decoding over the binary erasure channel
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::BinVector;
+use rand::distributions::{Bernoulli, Distribution};
+
+/// Estimate the probability of successful decoding over the binary erasure channel
+///
+/// Every bit of a random codeword is erased independently with probability `erasure_prob`.
+/// The message is then recovered from the remaining bits, see [`decode_erasures`].
+pub fn bec_decoding_performance(code: &dyn BinaryCode, erasure_prob: f64, trials: usize) -> f64 {
+    assert!(
+        (0.0..=1.0).contains(&erasure_prob),
+        "0 <= erasure_prob <= 1"
+    );
+    assert!(trials > 0, "Need at least one trial");
+    let mut rng = rand::thread_rng();
+    let dist = Bernoulli::new(erasure_prob).unwrap();
+
+    let successes = (0..trials)
+        .filter(|_| {
+            let message = BinVector::random(code.dimension());
+            let codeword = code.encode(&message);
+            let erasures = (0..code.length())
+                .map(|_| dist.sample(&mut rng))
+                .collect::<Vec<bool>>();
+            decode_erasures(code, &codeword, &erasures).map_or(false, |m| m == message)
+        })
+        .count();
+
+    successes as f64 / trials as f64
+}
+
+/// Recover the message from a received word of which the bits marked in `erasures` are unknown
+///
+/// Every bit that wasn't erased gives a linear equation $\\langle m, G_j \\rangle = c_j$
+/// in the unknown message bits. This system is solved through Gaussian elimination.
+///
+/// Returns `None` if the remaining bits do not determine the message uniquely.
+pub fn decode_erasures(
+    code: &dyn BinaryCode,
+    received: &BinVector,
+    erasures: &[bool],
+) -> Option<BinVector> {
+    let k = code.dimension();
+    debug_assert_eq!(received.len(), code.length());
+    debug_assert_eq!(erasures.len(), code.length());
+    let generator = code.generator_matrix();
+
+    // augmented rows [G_j | c_j]
+    let mut rows = (0..code.length())
+        .filter(|&j| !erasures[j])
+        .map(|j| {
+            let mut row = BinVector::with_capacity(k + 1);
+            for i in 0..k {
+                row.push(generator.bit(i, j));
+            }
+            row.push(received.get(j).unwrap());
+            row
+        })
+        .collect::<Vec<BinVector>>();
+
+    for col in 0..k {
+        let pivot = (col..rows.len()).find(|&r| rows[r].get(col) == Some(true))?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col && row.get(col) == Some(true) {
+                *row += &pivot_row;
+            }
+        }
+    }
+
+    Some(BinVector::from_bools(
+        &(0..k)
+            .map(|i| rows[i].get(k).unwrap())
+            .collect::<Vec<bool>>(),
+    ))
+}
+
+#[cfg(feature = "hamming")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::{HammingCode15_11, HammingCode7_4};
+    use itertools::Itertools;
+
+    fn check_all_erasure_patterns(code: &dyn BinaryCode, max_erasures: usize) {
+        let n = code.length();
+        for num_erasures in 0..=max_erasures {
+            for positions in (0..n).combinations(num_erasures) {
+                let mut erasures = vec![false; n];
+                positions.iter().for_each(|&p| erasures[p] = true);
+                let message = BinVector::random(code.dimension());
+                let codeword = code.encode(&message);
+                assert_eq!(
+                    decode_erasures(code, &codeword, &erasures),
+                    Some(message),
+                    "failed to recover erasures at {:?}",
+                    positions
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hamming_corrects_erasures() {
+        // A code with minimum distance d recovers any d - 1 erasures
+        check_all_erasure_patterns(&HammingCode7_4, 2);
+        check_all_erasure_patterns(&HammingCode15_11, 2);
+    }
+
+    #[test]
+    fn too_many_erasures() {
+        let code = HammingCode7_4;
+        let codeword = code.encode(&BinVector::random(4));
+        assert_eq!(decode_erasures(&code, &codeword, &[true; 7]), None);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn bec_performance() {
+        let code = HammingCode7_4;
+        assert_eq!(bec_decoding_performance(&code, 0.0, 100), 1.0);
+        assert_eq!(bec_decoding_performance(&code, 1.0, 100), 0.0);
+        let p = bec_decoding_performance(&code, 0.2, 1000);
+        assert!(p > 0.5 && p < 1.0, "p = {}", p);
+    }
+}
@@ -0,0 +1,409 @@
+use crate::codes::{matrix_rows, BinaryCode};
+use m4ri_rust::friendly::{BinMatrix, BinVector};
+
+/// The Gilbert-Varshamov lower bound on the minimum distance of an $[n, k]$ code
+///
+/// A linear code with minimum distance $d$ exists if
+/// $\sum_{i=0}^{d-2} \binom{n-1}{i} < 2^{n-k}$; this returns the largest such $d$.
+pub fn gv_lower_bound(n: usize, k: usize) -> usize {
+    assert!(0 < k && k <= n, "0 < k <= n");
+    let bound = 2f64.powi((n - k) as i32);
+    // sum_{i=0}^{d-2} C(n-1, i) and C(n-1, d-1)
+    let mut sum = 0f64;
+    let mut binomial = 1f64;
+    let mut d = 1;
+    while d < n && sum + binomial < bound {
+        sum += binomial;
+        binomial = binomial * (n - d) as f64 / d as f64;
+        d += 1;
+    }
+    d
+}
+
+/// Compute the minimum distance of `code` by enumerating all codewords
+///
+/// Walks the codewords in Gray code order, so every step adds one row of the
+/// generator matrix.
+pub fn minimum_distance<C: BinaryCode + ?Sized>(code: &C) -> usize {
+    minimum_weight_of_span(code.generator_matrix())
+}
+
+/// The smallest weight of a nonzero vector spanned by the linearly independent rows of `matrix`
+///
+/// Walks the span in Gray code order with [`Codewords`], so every step adds one row.
+pub(crate) fn minimum_weight_of_span(matrix: &BinMatrix) -> usize {
+    assert!(matrix.nrows() < 32, "Too many codewords to enumerate");
+    Codewords::new(matrix)
+        .skip(1)
+        .map(|codeword| codeword.count_ones() as usize)
+        .min()
+        .unwrap_or_else(|| matrix.ncols())
+}
+
+/// The number of vectors of every weight spanned by the linearly independent rows of `matrix`
+///
+/// Walks the span in Gray code order, like [`minimum_weight_of_span`].
+pub(crate) fn weight_distribution_of_span(matrix: &BinMatrix) -> Vec<u64> {
+    assert!(matrix.nrows() < 32, "Too many codewords to enumerate");
+    let mut distribution = vec![0u64; matrix.ncols() + 1];
+    for codeword in Codewords::new(matrix) {
+        distribution[codeword.count_ones() as usize] += 1;
+    }
+    distribution
+}
+
+/// Iterator over the span of the rows of a matrix, see [`BinaryCode::all_codewords`]
+pub struct Codewords {
+    rows: Vec<BinVector>,
+    codeword: BinVector,
+    index: usize,
+}
+
+impl Codewords {
+    pub(crate) fn new(matrix: &BinMatrix) -> Codewords {
+        let rows = matrix_rows(matrix)
+            .iter()
+            .map(|row| BinVector::from_bools(row))
+            .collect();
+        Codewords {
+            rows,
+            codeword: BinVector::from_elem(matrix.ncols(), false),
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for Codewords {
+    type Item = BinVector;
+
+    fn next(&mut self) -> Option<BinVector> {
+        if self.index >= 1 << self.rows.len() {
+            return None;
+        }
+        if self.index > 0 {
+            self.codeword += &self.rows[self.index.trailing_zeros() as usize];
+        }
+        self.index += 1;
+        Some(self.codeword.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (1 << self.rows.len()) - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Codewords {}
+
+/// Check that `code` decodes every vector to a codeword at distance at most one
+///
+/// This holds for perfect codes with covering radius one, like the Hamming codes.
+/// Iterates over all $2^n$ vectors, so only use this for $n \le 20$ or so.
+pub fn verify_perfect_decoding(code: &dyn BinaryCode) -> bool {
+    let n = code.length();
+    assert!(n < 32, "Too many vectors to enumerate");
+    let h_transposed = code.parity_check_matrix().transposed();
+    (0..(1usize << n)).all(|x| {
+        let v = BinVector::from_bools(&(0..n).map(|i| (x >> i) & 1 == 1).collect::<Vec<bool>>());
+        match code.decode_to_code(&v) {
+            Ok(c) => (&c * &h_transposed).count_ones() == 0 && (&v + &c).count_ones() <= 1,
+            Err(_) => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::codes::kernel_basis;
+    use m4ri_rust::friendly::BinMatrix;
+
+    #[test]
+    fn bound_values() {
+        // sum_{i=0}^{d-2} C(14, i) = 1, 15, 106 against 2^5 = 32
+        assert_eq!(gv_lower_bound(15, 10), 3);
+        assert_eq!(gv_lower_bound(15, 11), 3);
+        assert_eq!(gv_lower_bound(15, 12), 2);
+        assert_eq!(gv_lower_bound(24, 8), 7);
+        assert_eq!(gv_lower_bound(10, 10), 1);
+        // the Hamming codes are perfect: C(2^r - 2, 0) + C(2^r - 2, 1) = 2^r - 1
+        assert_eq!(gv_lower_bound(7, 4), 3);
+        assert_eq!(gv_lower_bound(31, 26), 3);
+    }
+
+    #[cfg(feature = "guava_15")]
+    #[test]
+    fn guava_codes_meet_bound() {
+        use crate::codes::{GuavaCode15_10, GuavaCode15_11, GuavaCode15_12};
+        let codes: Vec<Box<dyn BinaryCode>> = vec![
+            Box::new(GuavaCode15_10),
+            Box::new(GuavaCode15_11),
+            Box::new(GuavaCode15_12),
+        ];
+        for code in codes.iter() {
+            assert!(code.meets_gv_bound(), "{}", code.name());
+        }
+    }
+
+    /// Code from a random generator matrix, only for its parameters
+    struct RandomCode {
+        generator: BinMatrix,
+        parity_check: BinMatrix,
+    }
+
+    impl RandomCode {
+        fn new(n: usize, k: usize) -> RandomCode {
+            loop {
+                let generator = BinMatrix::random(k, n);
+                if generator.rank() < k {
+                    continue;
+                }
+                let rows = (0..k)
+                    .map(|i| (0..n).map(|j| generator.bit(i, j)).collect())
+                    .collect();
+                let (_, checks) = kernel_basis(rows, n);
+                let parity_check = BinMatrix::new(
+                    checks
+                        .iter()
+                        .map(|row| BinVector::from_bools(row))
+                        .collect(),
+                );
+                return RandomCode {
+                    generator,
+                    parity_check,
+                };
+            }
+        }
+    }
+
+    impl BinaryCode for RandomCode {
+        fn name(&self) -> String {
+            "Random code".to_owned()
+        }
+
+        fn length(&self) -> usize {
+            self.generator.ncols()
+        }
+
+        fn dimension(&self) -> usize {
+            self.generator.nrows()
+        }
+
+        fn generator_matrix(&self) -> &BinMatrix {
+            &self.generator
+        }
+
+        fn parity_check_matrix(&self) -> &BinMatrix {
+            &self.parity_check
+        }
+
+        fn decode_to_message(&self, _c: &BinVector) -> Result<BinVector, &str> {
+            Err("Random codes can not be decoded")
+        }
+    }
+
+    #[test]
+    fn random_codes() {
+        // Random codes reach the bound for large n, for short codes only some do.
+        // For [20, 10] about 30% meet it and almost all are within one of it.
+        let trials = 200;
+        let bound = gv_lower_bound(20, 10);
+        let distances: Vec<usize> = (0..trials)
+            .map(|_| minimum_distance(&RandomCode::new(20, 10)))
+            .collect();
+        let meets = distances.iter().filter(|&&d| d >= bound).count();
+        let close = distances.iter().filter(|&&d| d + 1 >= bound).count();
+        assert!(
+            meets > trials * 15 / 100,
+            "{} of {} meet the bound",
+            meets,
+            trials
+        );
+        assert!(
+            close > trials * 70 / 100,
+            "{} of {} close to the bound",
+            close,
+            trials
+        );
+    }
+
+    #[test]
+    fn dual_distance() {
+        for _ in 0..20 {
+            let code = RandomCode::new(20, 12);
+            let dual = RandomCode {
+                generator: code.parity_check.clone(),
+                parity_check: code.generator.clone(),
+            };
+            assert_eq!(code.dual_distance(), minimum_distance(&dual));
+        }
+
+        // both rows have weight three, their sum has weight two
+        let code = RandomCode {
+            generator: BinMatrix::zero(1, 7),
+            parity_check: BinMatrix::from_slices(&[&[0b000_0111u64], &[0b000_1110]], 7),
+        };
+        assert_eq!(code.dual_distance(), 2);
+    }
+
+    #[test]
+    fn parity_check_systematic() {
+        fn check(code: &RandomCode) {
+            let (n, k) = (code.length(), code.dimension());
+            let (h, perm) = code.parity_check_systematic();
+            assert_eq!((h.nrows(), h.ncols()), (n - k, n));
+            for row in 0..n - k {
+                for col in k..n {
+                    assert_eq!(h.bit(row, col), row + k == col);
+                }
+            }
+            let perm = perm.unwrap_or_else(|| (0..n).collect());
+            let generator = BinMatrix::new(
+                (0..k)
+                    .map(|row| {
+                        let bits: Vec<bool> = perm
+                            .iter()
+                            .map(|&col| code.generator.bit(row, col))
+                            .collect();
+                        BinVector::from_bools(&bits)
+                    })
+                    .collect(),
+            );
+            assert_eq!(&generator * &h.transposed(), BinMatrix::zero(k, n - k));
+        }
+
+        for _ in 0..10 {
+            check(&RandomCode::new(20, 12));
+        }
+
+        let rows = |rows: &[[bool; 4]]| {
+            BinMatrix::new(rows.iter().map(|row| BinVector::from_bools(row)).collect())
+        };
+        let (t, f) = (true, false);
+        // the third row is the sum of the others and is dropped
+        let code = RandomCode {
+            generator: rows(&[[t, f, f, t], [f, t, t, f]]),
+            parity_check: rows(&[[f, t, t, f], [t, f, f, t], [t, t, t, t]]),
+        };
+        assert_eq!(code.parity_check_systematic().1, None);
+        check(&code);
+        // the last two columns are equal, so column 1 is moved to the end
+        let code = RandomCode {
+            generator: rows(&[[t, t, t, f], [t, t, f, t]]),
+            parity_check: rows(&[[t, f, t, t], [f, t, t, t]]),
+        };
+        assert_eq!(code.parity_check_systematic().1, Some(vec![0, 2, 1, 3]));
+        check(&code);
+    }
+
+    #[test]
+    fn weight_distribution() {
+        let code = crate::codes::HammingCode::new(3);
+        assert_eq!(code.weight_distribution(), vec![1, 0, 0, 7, 7, 0, 0, 1]);
+        let code = RandomCode::new(20, 10);
+        let distribution = code.weight_distribution();
+        assert_eq!(distribution.iter().sum::<u64>(), 1 << 10);
+        let distance = distribution.iter().skip(1).position(|&count| count > 0);
+        assert_eq!(distance.map(|idx| idx + 1), Some(minimum_distance(&code)));
+    }
+
+    #[test]
+    fn all_codewords() {
+        let code = crate::codes::HammingCode::new(3);
+        let codewords: Vec<BinVector> = code.all_codewords().collect();
+        assert_eq!(codewords.len(), 16);
+        assert_eq!(codewords[0], BinVector::from_elem(7, false));
+        let distinct: std::collections::HashSet<&BinVector> = codewords.iter().collect();
+        assert_eq!(distinct.len(), 16);
+        let mut distribution = vec![0u64; 8];
+        for codeword in codewords.iter() {
+            assert!(code.is_codeword(codeword));
+            distribution[codeword.count_ones() as usize] += 1;
+        }
+        assert_eq!(distribution, code.weight_distribution());
+
+        let code = RandomCode::new(20, 10);
+        let mut codewords = code.all_codewords();
+        assert_eq!(codewords.len(), 1 << 10);
+        codewords.next();
+        assert_eq!(codewords.len(), (1 << 10) - 1);
+        assert!(codewords.all(|codeword| code.is_codeword(&codeword)));
+    }
+
+    #[test]
+    #[should_panic(expected = "k <= 24")]
+    fn all_codewords_too_many() {
+        crate::codes::IdentityCode::new(25).all_codewords();
+    }
+
+    #[cfg(feature = "guava_15")]
+    #[test]
+    fn guava_weight_distribution() {
+        use crate::codes::{GuavaCode15_10, GuavaCode15_11, GuavaCode15_12};
+        let codes: [&dyn BinaryCode; 3] = [&GuavaCode15_10, &GuavaCode15_11, &GuavaCode15_12];
+        for code in codes.iter() {
+            assert_eq!(
+                code.weight_distribution(),
+                weight_distribution_of_span(code.generator_matrix()),
+                "{}",
+                code.name()
+            );
+        }
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn perfect_decoding() {
+        use crate::codes::{HammingCode15_11, HammingCode3_1, HammingCode7_4};
+        assert!(verify_perfect_decoding(&HammingCode3_1));
+        assert!(verify_perfect_decoding(&HammingCode7_4));
+        assert!(verify_perfect_decoding(&HammingCode15_11));
+    }
+
+    #[cfg(feature = "guava_15")]
+    #[test]
+    fn imperfect_decoding() {
+        use crate::codes::GuavaCode15_10;
+        // the covering radius of this code is larger than one
+        assert!(!verify_perfect_decoding(&GuavaCode15_10));
+    }
+
+    /// Decodes everything to the zero codeword
+    #[cfg(feature = "hamming")]
+    struct BrokenCode(crate::codes::HammingCode7_4);
+
+    #[cfg(feature = "hamming")]
+    impl BinaryCode for BrokenCode {
+        fn name(&self) -> String {
+            "Broken code".to_owned()
+        }
+
+        fn length(&self) -> usize {
+            self.0.length()
+        }
+
+        fn dimension(&self) -> usize {
+            self.0.dimension()
+        }
+
+        fn generator_matrix(&self) -> &BinMatrix {
+            self.0.generator_matrix()
+        }
+
+        fn parity_check_matrix(&self) -> &BinMatrix {
+            self.0.parity_check_matrix()
+        }
+
+        fn decode_to_message(&self, _c: &BinVector) -> Result<BinVector, &str> {
+            Ok(BinVector::from_elem(self.dimension(), false))
+        }
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn broken_decoding() {
+        assert!(!verify_perfect_decoding(&BrokenCode(
+            crate::codes::HammingCode7_4
+        )));
+    }
+}
@@ -0,0 +1,136 @@
+//! Look up codes by their parameters
+use crate::codes::*;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Constructs a code of the registry
+pub(crate) type CodeConstructor = fn() -> Box<dyn BinaryCode>;
+
+/// The fixed codes compiled into this build, by $[n, k]$
+static REGISTRY: OnceLock<HashMap<(usize, usize), CodeConstructor>> = OnceLock::new();
+
+/// Add the constructor of a code without fields
+#[allow(unused_macros)]
+macro_rules! register {
+    ($constructors:ident, $code:ident) => {
+        $constructors.push(|| Box::new($code) as Box<dyn BinaryCode>);
+    };
+}
+
+/// Constructors for all fixed codes compiled into this build
+///
+/// The Guava codes of [`guava_codes`] come last, they have the best known minimum
+/// distance for their parameters.
+pub(crate) fn fixed_code_constructors() -> Vec<CodeConstructor> {
+    #[allow(unused_mut)]
+    let mut constructors: Vec<CodeConstructor> = Vec::new();
+    #[cfg(feature = "hamming")]
+    {
+        register!(constructors, HammingCode3_1);
+        register!(constructors, HammingCode7_4);
+        register!(constructors, HammingCode15_11);
+        register!(constructors, HammingCode31_26);
+        register!(constructors, HammingCode63_57);
+        register!(constructors, HammingCode127_120);
+    }
+    #[cfg(feature = "mds")]
+    {
+        register!(constructors, MdsCode3_2);
+        register!(constructors, MdsCode4_3);
+        register!(constructors, MdsCode5_4);
+    }
+    #[cfg(feature = "custom")]
+    register!(constructors, CustomCode5_3);
+    #[cfg(feature = "bogosrnd_18")]
+    register!(constructors, BogosrndCode18_6);
+    #[cfg(feature = "bogosrnd_19")]
+    register!(constructors, BogosrndCode19_6);
+    #[cfg(feature = "bogosrnd_19")]
+    register!(constructors, BogosrndCode19_7);
+    #[cfg(feature = "wagner_20")]
+    register!(constructors, WagnerCode20_11);
+    #[cfg(feature = "wagner_22")]
+    register!(constructors, WagnerCode22_13);
+    #[cfg(feature = "wagner_23")]
+    register!(constructors, WagnerCode23_14);
+    #[cfg(feature = "wagner_25")]
+    register!(constructors, WagnerCode25_15);
+    #[cfg(feature = "wagner_26")]
+    register!(constructors, WagnerCode26_16);
+    #[cfg(feature = "wagner_27")]
+    register!(constructors, WagnerCode27_17);
+    #[cfg(feature = "wagner_28")]
+    register!(constructors, WagnerCode28_18);
+    #[cfg(feature = "wagner_29")]
+    register!(constructors, WagnerCode29_19);
+    #[cfg(feature = "wagner_30")]
+    register!(constructors, WagnerCode30_20);
+    #[cfg(feature = "wagner_32")]
+    register!(constructors, WagnerCode32_21);
+    #[cfg(feature = "golay")]
+    {
+        register!(constructors, GolayCode23_12);
+        register!(constructors, GolayCode24_12);
+    }
+
+    constructors.extend(guava_code_constructors());
+    constructors
+}
+
+/// The registry of fixed codes, later codes replace earlier ones with the same parameters
+fn registry() -> &'static HashMap<(usize, usize), CodeConstructor> {
+    REGISTRY.get_or_init(|| {
+        let mut registry: HashMap<(usize, usize), CodeConstructor> = HashMap::new();
+        for constructor in fixed_code_constructors() {
+            let code = constructor();
+            registry.insert((code.length(), code.dimension()), constructor);
+        }
+        registry
+    })
+}
+
+/// The best available $[n, k]$ code, if there is one
+///
+/// The fixed codes enabled by the features of this build are preferred, the Guava
+/// codes above all others. Otherwise the trivial $[k, k]$ code, the $[n, 1]$ repetition
+/// code or a Hamming code is constructed if the parameters fit.
+pub fn lookup(n: usize, k: usize) -> Option<Box<dyn BinaryCode>> {
+    if let Some(constructor) = registry().get(&(n, k)) {
+        return Some(constructor());
+    }
+    if n == k && k > 0 {
+        Some(Box::new(IdentityCode::new(k)))
+    } else if k == 1 && n > 1 {
+        Some(Box::new(RepetitionCode::new(n)))
+    } else if k < n && (2..=10).contains(&(n - k)) && n == (1 << (n - k)) - 1 {
+        Some(Box::new(HammingCode::new((n - k) as u32)))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_parameters() {
+        for &(n, k) in &[(7, 4), (15, 11), (3, 1), (9, 9), (9, 1), (255, 247)] {
+            let code = lookup(n, k).unwrap();
+            assert_eq!((code.length(), code.dimension()), (n, k), "{}", code.name());
+        }
+        assert!(lookup(6, 3).is_none());
+        assert!(lookup(0, 0).is_none());
+        assert!(lookup(3, 4).is_none());
+        assert_eq!(
+            lookup(1023, 1013).unwrap().name(),
+            "[1023, 1013] Hamming code"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "guava_23")]
+    fn lookup_prefers_guava() {
+        assert_eq!(lookup(23, 12).unwrap().name(), GuavaCode23_12.name());
+    }
+}
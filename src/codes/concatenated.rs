@@ -1,10 +1,41 @@
 #![allow(clippy::mutex_atomic)]
-use crate::codes::BinaryCode;
+use crate::codes::{guava_codes, guava_distance_spectrum, BinaryCode};
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
 use std::cell::UnsafeCell;
-use std::ptr;
 use std::sync::Mutex;
+use std::{error, fmt, ptr};
+
+/// Reasons why codes can not be combined into a [`ConcatenatedCode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeError {
+    /// No codes were given
+    Empty,
+    /// The lengths of the codes add up to `found` instead of `expected`
+    LengthMismatch { expected: usize, found: usize },
+    /// The dimensions of the codes add up to `found` instead of `expected`
+    DimensionMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::Empty => write!(f, "no codes given"),
+            CodeError::LengthMismatch { expected, found } => write!(
+                f,
+                "the codes have total length {}, expected {}",
+                found, expected
+            ),
+            CodeError::DimensionMismatch { expected, found } => write!(
+                f,
+                "the codes have total dimension {}, expected {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl error::Error for CodeError {}
 
 /// 'Concatenated' Linear Codes
 ///
@@ -52,6 +83,105 @@ impl<'codes> ConcatenatedCode<'codes> {
             generator: UnsafeCell::new(ptr::null_mut()),
         }
     }
+
+    /// Construct the direct sum of `codes`, checking that it is an $[n, k]$ code
+    ///
+    /// Unlike [`ConcatenatedCode::new`], this returns an error if there are no codes or
+    /// if their lengths and dimensions do not add up to `length` and `dimension`, e.g.
+    /// the block size and target size of a reduction.
+    pub fn try_new(
+        codes: Vec<&'codes dyn BinaryCode>,
+        length: usize,
+        dimension: usize,
+    ) -> Result<ConcatenatedCode<'codes>, CodeError> {
+        if codes.is_empty() {
+            return Err(CodeError::Empty);
+        }
+        let code = ConcatenatedCode::new(codes);
+        if code.length() != length {
+            return Err(CodeError::LengthMismatch {
+                expected: length,
+                found: code.length(),
+            });
+        }
+        if code.dimension() != dimension {
+            return Err(CodeError::DimensionMismatch {
+                expected: dimension,
+                found: code.dimension(),
+            });
+        }
+        Ok(code)
+    }
+
+    /// Compute the minimum distance from the minimum distances of the parts
+    ///
+    /// A codeword of the direct sum is nonzero if any of its parts is, so the
+    /// minimum distance is $\min_i d_i$. This only enumerates the codewords of
+    /// every part, rather than all $2^k$ codewords as [`minimum_distance`] would.
+    /// The product $d_{outer} d_{inner}$ is the distance of a serial concatenation
+    /// of an outer code with an inner code, which is not what this type constructs.
+    ///
+    /// [`minimum_distance`]: crate::codes::minimum_distance
+    pub fn minimum_distance(&self) -> usize {
+        self.codes
+            .iter()
+            .map(|&code| crate::codes::minimum_distance(code))
+            .min()
+            .expect("A concatenated code needs at least one code")
+    }
+}
+
+/// Largest difference between the requested and the constructed length
+const LENGTH_SLACK: usize = 2;
+
+/// Minimum distance of a Guava code, from its distance spectrum
+fn guava_minimum_distance(code: &dyn BinaryCode) -> usize {
+    guava_distance_spectrum(code.length(), code.dimension())
+        .and_then(|spectrum| spectrum.iter().skip(1).position(|&count| count > 0))
+        .map(|idx| idx + 1)
+        .expect("No distance spectrum for this code")
+}
+
+/// Build a code of about `target_length` from an inner and outer Guava code
+///
+/// Considers all pairs of codes from [`guava_codes`] with total length within
+/// `LENGTH_SLACK` of `target_length` and total dimension at least `target_dimension`.
+/// [`ConcatenatedCode`] is a direct sum with minimum distance $\min(d_{inner}, d_{outer})$,
+/// so the pair with the largest smaller distance is picked. Ties go to the pair closest
+/// to the target length, then to the larger dimension.
+///
+/// Panics if no pair of the enabled Guava codes fits the target.
+pub fn build_optimal_concatenated_code(
+    target_length: usize,
+    target_dimension: usize,
+) -> ConcatenatedCode<'static> {
+    let codes: Vec<(&'static dyn BinaryCode, usize)> = guava_codes()
+        .into_iter()
+        .map(|code| (code, guava_minimum_distance(code)))
+        .collect();
+    let mut best: Option<((usize, usize, usize), [&'static dyn BinaryCode; 2])> = None;
+    for (i, &(inner, d_inner)) in codes.iter().enumerate() {
+        for &(outer, d_outer) in codes.iter().skip(i) {
+            let length = inner.length() + outer.length();
+            let dimension = inner.dimension() + outer.dimension();
+            let deviation = (length as isize - target_length as isize).abs() as usize;
+            if deviation > LENGTH_SLACK || dimension < target_dimension {
+                continue;
+            }
+            let score = (d_inner.min(d_outer), LENGTH_SLACK - deviation, dimension);
+            if best.map_or(true, |(best_score, _)| score > best_score) {
+                best = Some((score, [inner, outer]));
+            }
+        }
+    }
+    let (_, [inner, outer]) = best.unwrap_or_else(|| {
+        panic!(
+            "No pair of Guava codes of length about {} and dimension at least {}",
+            target_length, target_dimension
+        )
+    });
+    log::debug!("Selected {} and {}", inner.name(), outer.name());
+    ConcatenatedCode::new(vec![inner, outer])
 }
 
 impl<'codes> BinaryCode for ConcatenatedCode<'codes> {
@@ -110,6 +240,21 @@ impl<'codes> BinaryCode for ConcatenatedCode<'codes> {
         panic!("Not yet implemented");
     }
 
+    /// A vector is a codeword if every part is a codeword of its code
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        debug_assert_eq!(v.len(), self.length(), "the length doesn't match the code");
+        let mut start = 0;
+        self.codes.iter().all(|code| {
+            let part = BinVector::from_bools(
+                &(start..start + code.length())
+                    .map(|i| v[i])
+                    .collect::<Vec<bool>>(),
+            );
+            start += code.length();
+            code.is_codeword(&part)
+        })
+    }
+
     fn encode(&self, c: &BinVector) -> BinVector {
         let mut encoded = BinVector::with_capacity(self.dimension());
         let mut slice = c.clone();
@@ -164,6 +309,11 @@ impl<'codes> BinaryCode for ConcatenatedCode<'codes> {
         c[write_start_bit / 64] &= (1 << (write_start_bit % 64)) - 1;
     }
 
+    /// The covering radius of a direct sum is the sum of the covering radii
+    fn covering_radius(&self) -> usize {
+        self.codes.iter().map(|code| code.covering_radius()).sum()
+    }
+
     fn bias(&self, delta: f64) -> f64 {
         self.codes
             .iter()
@@ -225,4 +375,128 @@ mod tests {
             assert!((v + cw).count_ones() < 5);
         }
     }
+
+    #[test]
+    fn test_try_new() {
+        let code = ConcatenatedCode::try_new(vec![&HammingCode7_4, &HammingCode3_1], 10, 5);
+        assert_eq!(code.map(|code| code.name()), Ok(get_code().name()));
+        assert_eq!(
+            ConcatenatedCode::try_new(vec![], 0, 0).err(),
+            Some(CodeError::Empty)
+        );
+        assert_eq!(
+            ConcatenatedCode::try_new(vec![&HammingCode7_4, &HammingCode3_1], 11, 5).err(),
+            Some(CodeError::LengthMismatch {
+                expected: 11,
+                found: 10
+            })
+        );
+        assert_eq!(
+            ConcatenatedCode::try_new(vec![&HammingCode7_4, &HammingCode3_1], 10, 4).err(),
+            Some(CodeError::DimensionMismatch {
+                expected: 4,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_covering_radius() {
+        assert_eq!(get_code().covering_radius(), 2);
+    }
+
+    #[test]
+    fn test_is_codeword() {
+        let code = get_code();
+        for _ in 0..100 {
+            let codeword = code.encode(&BinVector::random(code.dimension()));
+            assert!(code.is_codeword(&codeword));
+            let received = BinVector::random(code.length());
+            assert_eq!(
+                code.is_codeword(&received),
+                code.decode_to_code(&received).unwrap() == received
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_distance() {
+        let code = get_code();
+        assert_eq!(code.minimum_distance(), 3);
+        assert_eq!(
+            code.minimum_distance(),
+            crate::codes::minimum_distance(&code)
+        );
+    }
+}
+
+#[cfg(feature = "guava")]
+#[cfg(test)]
+mod optimal_tests {
+    use super::*;
+    use crate::codes::minimum_distance;
+
+    /// Sphere packing bound on the minimum distance of any $[n, k]$ code
+    fn hamming_upper_bound(n: usize, k: usize) -> usize {
+        let volume = 2f64.powi((n - k) as i32);
+        let mut sum = 1f64;
+        let mut binomial = 1f64;
+        let mut t = 0;
+        loop {
+            binomial = binomial * (n - t) as f64 / (t + 1) as f64;
+            if sum + binomial > volume {
+                return 2 * t + 2;
+            }
+            sum += binomial;
+            t += 1;
+        }
+    }
+
+    #[test]
+    fn optimal_parameters() {
+        for &(n, k) in &[(30, 16), (36, 24), (40, 20), (44, 36)] {
+            let code = build_optimal_concatenated_code(n, k);
+            assert!(
+                (code.length() as isize - n as isize).abs() as usize <= LENGTH_SLACK,
+                "{}",
+                code.name()
+            );
+            assert!(code.dimension() >= k, "{}", code.name());
+            // the direct sum can't beat the best single [n, k] code
+            assert!(
+                code.minimum_distance() <= hamming_upper_bound(code.length(), code.dimension()),
+                "{}",
+                code.name()
+            );
+        }
+    }
+
+    #[test]
+    fn optimal_is_best_pair() {
+        let code = build_optimal_concatenated_code(32, 20);
+        let best = code.minimum_distance();
+        let codes = guava_codes();
+        for a in codes.iter() {
+            for b in codes.iter() {
+                let length = a.length() + b.length();
+                if (30..=34).contains(&length) && a.dimension() + b.dimension() >= 20 {
+                    let distance = guava_minimum_distance(*a).min(guava_minimum_distance(*b));
+                    assert!(distance <= best);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn direct_sum_distance() {
+        let code = build_optimal_concatenated_code(28, 16);
+        let expected = code
+            .codes
+            .iter()
+            .map(|&c| guava_minimum_distance(c))
+            .min()
+            .unwrap();
+        assert_eq!(minimum_distance(&code), expected);
+        assert_eq!(code.minimum_distance(), expected);
+    }
 }
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -16,49 +15,50 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct CustomCode5_3;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 25 ],
-                &[ 26 ],
-                &[ 28 ],
-                
-            ], 5));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 23 ],
-                &[ 24 ],
-                
-            ], 5));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 25 ],
+            &[ 26 ],
+            &[ 28 ],
+            
+        ], 5)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(4, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[8]);     // 2 => [8]
-            map.insert(3, &[16]);     // 3 => [16]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 23 ],
+            &[ 24 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 5)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(4, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[8]);     // 2 => [8]
+        map.insert(3, &[16]);     // 3 => [16]
+        map
+    })
 }
 
 impl CustomCode5_3 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -76,24 +76,15 @@ impl BinaryCode for CustomCode5_3 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(5);
@@ -118,14 +109,19 @@ impl BinaryCode for CustomCode5_3 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[5 / 64] & !((1 << 5) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -320,8 +316,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, true, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -0,0 +1,106 @@
+use crate::codes::{BinaryCode, LinearCode};
+use m4ri_rust::friendly::BinMatrix;
+use m4ri_rust::friendly::BinVector;
+use std::sync::OnceLock;
+
+/// The dual of a code
+///
+/// The dual of an $[n, k]$ code is the $[n, n - k]$ code spanned by its parity check
+/// matrix, so the generator and parity check matrices swap roles. The first call to a
+/// decoding method builds a [`LinearCode`] from the generator matrix, which needs a
+/// syndrome table with $2^k$ entries.
+pub struct DualCode<C: BinaryCode> {
+    /// The code of which this is the dual
+    pub code: C,
+    decoder: OnceLock<LinearCode>,
+}
+
+impl<C: BinaryCode> DualCode<C> {
+    /// The dual of `code`
+    pub fn new(code: C) -> DualCode<C> {
+        DualCode {
+            code,
+            decoder: OnceLock::new(),
+        }
+    }
+
+    fn decoder(&self) -> &LinearCode {
+        self.decoder
+            .get_or_init(|| LinearCode::from_generator(self.generator_matrix().clone()))
+    }
+}
+
+impl<C: BinaryCode> BinaryCode for DualCode<C> {
+    fn name(&self) -> String {
+        format!("Dual of {}", self.code.name())
+    }
+
+    fn length(&self) -> usize {
+        self.code.length()
+    }
+
+    fn dimension(&self) -> usize {
+        self.code.length() - self.code.dimension()
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        self.code.parity_check_matrix()
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        self.code.generator_matrix()
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        self.decoder().decode_to_code(c)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        self.decoder().decode_to_message(c)
+    }
+
+    fn covering_radius(&self) -> usize {
+        self.decoder().covering_radius()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::{minimum_distance, HammingCode};
+
+    #[test]
+    fn simplex_code() {
+        // the dual of the [7, 4] Hamming code is the [7, 3, 4] simplex code
+        let code = DualCode::new(HammingCode::new(3));
+        assert_eq!((code.length(), code.dimension()), (7, 3));
+        assert_eq!(minimum_distance(&code), 4);
+        assert_eq!(code.dual_distance(), 3);
+        for _ in 0..100 {
+            let message = BinVector::random(3);
+            let codeword = code.encode(&message);
+            assert!(code.is_codeword(&codeword));
+            assert_eq!(code.decode_to_message(&codeword).unwrap(), message);
+            // corrects a single error
+            let mut received = codeword.clone();
+            let pos = rand::random::<usize>() % 7;
+            received.set(pos, !received[pos]);
+            assert_eq!(code.decode_to_code(&received).unwrap(), codeword);
+        }
+    }
+
+    #[test]
+    fn double_dual() {
+        let hamming = HammingCode::new(4);
+        let code = DualCode::new(DualCode::new(hamming.clone()));
+        assert_eq!(code.dimension(), hamming.dimension());
+        assert_eq!(code.generator_matrix(), hamming.generator_matrix());
+        for _ in 0..100 {
+            let received = BinVector::random(15);
+            assert_eq!(
+                code.decode_to_code(&received),
+                hamming.decode_to_code(&received)
+            );
+        }
+    }
+}
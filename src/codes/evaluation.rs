@@ -0,0 +1,396 @@
+use crate::codes::{kernel_basis, BinaryCode};
+use m4ri_rust::friendly::*;
+
+/// Arithmetic in $GF(2^m)$
+///
+/// Elements are polynomials over $GF(2)$, stored in the bits of a `u64`.
+#[derive(Clone, Copy, Debug, Serialize)]
+struct BinaryField {
+    degree: u32,
+    /// Irreducible polynomial of degree `degree`
+    modulus: u64,
+}
+
+/// Remainder of `a` divided by `b` as polynomials over $GF(2)$
+fn poly_mod(mut a: u64, b: u64) -> u64 {
+    let degree_b = 63 - b.leading_zeros();
+    while a != 0 && 63 - a.leading_zeros() >= degree_b {
+        a ^= b << (63 - a.leading_zeros() - degree_b);
+    }
+    a
+}
+
+impl BinaryField {
+    fn new(degree: u32) -> BinaryField {
+        // p is irreducible if no polynomial of degree at most degree/2 divides it
+        let modulus = ((1u64 << degree)..(1u64 << (degree + 1)))
+            .find(|&p| (2..(1u64 << (degree / 2 + 1))).all(|d| poly_mod(p, d) != 0))
+            .expect("There are irreducible polynomials of every degree");
+        BinaryField { degree, modulus }
+    }
+
+    fn size(&self) -> u64 {
+        1 << self.degree
+    }
+
+    fn mul(&self, mut a: u64, mut b: u64) -> u64 {
+        let mut result = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                result ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if (a >> self.degree) & 1 == 1 {
+                a ^= self.modulus;
+            }
+        }
+        result
+    }
+
+    fn pow(&self, mut a: u64, mut exponent: u64) -> u64 {
+        let mut result = 1;
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, a);
+            }
+            a = self.mul(a, a);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn inv(&self, a: u64) -> u64 {
+        debug_assert_ne!(a, 0, "Zero has no inverse");
+        self.pow(a, self.size() - 2)
+    }
+
+    /// Evaluate the polynomial with coefficients `polynomial` (constant term first) in `x`
+    fn evaluate(&self, polynomial: &[u64], x: u64) -> u64 {
+        polynomial
+            .iter()
+            .rev()
+            .fold(0, |acc, &c| self.mul(acc, x) ^ c)
+    }
+
+    /// Divide by a monic polynomial, returns the quotient and the remainder
+    fn divide(&self, mut numerator: Vec<u64>, denominator: &[u64]) -> (Vec<u64>, Vec<u64>) {
+        let degree = denominator.len() - 1;
+        debug_assert_eq!(denominator[degree], 1, "Denominator should be monic");
+        if numerator.len() <= degree {
+            return (vec![], numerator);
+        }
+        let mut quotient = vec![0; numerator.len() - degree];
+        for (i, q) in quotient.iter_mut().enumerate().rev() {
+            let c = numerator[i + degree];
+            *q = c;
+            for (j, &d) in denominator.iter().enumerate() {
+                numerator[i + j] ^= self.mul(c, d);
+            }
+        }
+        numerator.truncate(degree);
+        (quotient, numerator)
+    }
+
+    /// Solve the linear system given by its augmented rows
+    ///
+    /// Free variables are set to zero. Returns `None` if the system is inconsistent.
+    fn solve(&self, mut rows: Vec<Vec<u64>>, unknowns: usize) -> Option<Vec<u64>> {
+        let mut pivots = Vec::with_capacity(unknowns);
+        for col in 0..unknowns {
+            let rank = pivots.len();
+            if let Some(row) = (rank..rows.len()).find(|&row| rows[row][col] != 0) {
+                rows.swap(rank, row);
+                let inverse = self.inv(rows[rank][col]);
+                rows[rank]
+                    .iter_mut()
+                    .for_each(|x| *x = self.mul(*x, inverse));
+                let pivot_row = rows[rank].clone();
+                for (other, row) in rows.iter_mut().enumerate() {
+                    let factor = row[col];
+                    if other != rank && factor != 0 {
+                        row.iter_mut()
+                            .zip(pivot_row.iter())
+                            .for_each(|(x, &p)| *x ^= self.mul(factor, p));
+                    }
+                }
+                pivots.push(col);
+            }
+        }
+        if rows[pivots.len()..].iter().any(|row| row[unknowns] != 0) {
+            return None;
+        }
+        let mut solution = vec![0; unknowns];
+        for (row, &col) in pivots.iter().enumerate() {
+            solution[col] = rows[row][unknowns];
+        }
+        Some(solution)
+    }
+}
+
+/// Evaluation (Reed-Solomon) code over $GF(2^m)$
+///
+/// A message is a polynomial $f$ of degree less than `degree` over $GF(2^m)$, which is
+/// encoded as its evaluations $f(x_1), \ldots, f(x_n)$ in the evaluation points.
+/// Over the symbols this is an $[n, \mathrm{degree}, n - \mathrm{degree} + 1]$ MDS code.
+///
+/// As a binary code, every symbol is written as the $m$ bits of its coefficients.
+/// This map is only $GF(2)$-linear in characteristic 2, so the field size has to be a
+/// power of two. With field size 2 the code is a Reed-Muller code of length at most 2.
+/// Decoding uses the Berlekamp-Welch algorithm, which corrects up to
+/// $\lfloor (n - \mathrm{degree}) / 2 \rfloor$ symbol errors.
+#[derive(Clone, Serialize)]
+pub struct EvaluationCode {
+    field: BinaryField,
+    evaluation_points: Vec<u64>,
+    degree: usize,
+    generator: BinMatrix,
+    parity_check: BinMatrix,
+}
+
+impl EvaluationCode {
+    /// Construct the code evaluating polynomials of degree less than `degree` in
+    /// `evaluation_points`, which should be distinct elements of $GF(\mathrm{field\_size})$
+    pub fn new(evaluation_points: Vec<u64>, degree: usize, field_size: u64) -> EvaluationCode {
+        assert!(
+            field_size >= 2 && field_size.is_power_of_two(),
+            "The field size should be a power of two"
+        );
+        assert!(
+            field_size <= 1 << 16,
+            "The field size should be at most 2^16"
+        );
+        let n = evaluation_points.len();
+        assert!(0 < degree && degree < n, "0 < degree < number of points");
+        assert!(
+            evaluation_points.iter().all(|&x| x < field_size),
+            "The evaluation points should be field elements"
+        );
+        let mut sorted = evaluation_points.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), n, "The evaluation points should be distinct");
+
+        let field = BinaryField::new(field_size.trailing_zeros());
+        let m = field.degree as usize;
+        // the message bit t of coefficient j is the element with only bit t set
+        let generator_rows: Vec<Vec<bool>> = (0..degree)
+            .flat_map(|j| (0..m).map(move |t| (j, t)))
+            .map(|(j, t)| {
+                let mut polynomial = vec![0; degree];
+                polynomial[j] = 1 << t;
+                let symbols: Vec<u64> = evaluation_points
+                    .iter()
+                    .map(|&x| field.evaluate(&polynomial, x))
+                    .collect();
+                symbols_to_bits(&symbols, m)
+            })
+            .collect();
+        let generator = BinMatrix::new(
+            generator_rows
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        let (_, parity_checks) = kernel_basis(generator_rows, n * m);
+        let parity_check = BinMatrix::new(
+            parity_checks
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+
+        EvaluationCode {
+            field,
+            evaluation_points,
+            degree,
+            generator,
+            parity_check,
+        }
+    }
+
+    /// The size of the field
+    pub fn field_size(&self) -> u64 {
+        self.field.size()
+    }
+
+    /// The points in which the polynomials are evaluated
+    pub fn evaluation_points(&self) -> &[u64] {
+        &self.evaluation_points
+    }
+
+    /// Length of the code in field symbols
+    pub fn symbol_length(&self) -> usize {
+        self.evaluation_points.len()
+    }
+
+    /// Dimension of the code in field symbols
+    pub fn symbol_dimension(&self) -> usize {
+        self.degree
+    }
+
+    /// Minimum distance of the code in field symbols
+    pub fn symbol_distance(&self) -> usize {
+        self.symbol_length() - self.degree + 1
+    }
+
+    /// Evaluate the polynomial with coefficients `polynomial` in all evaluation points
+    pub fn encode_symbols(&self, polynomial: &[u64]) -> Vec<u64> {
+        assert!(polynomial.len() <= self.degree, "The degree is too large");
+        self.evaluation_points
+            .iter()
+            .map(|&x| self.field.evaluate(polynomial, x))
+            .collect()
+    }
+
+    /// Recover the polynomial from its evaluations with Berlekamp-Welch
+    ///
+    /// Finds the error locator $E$ of degree $e$ and $Q = fE$ with
+    /// $Q(x_i) = y_i E(x_i)$, after which $f = Q / E$.
+    pub fn decode_symbols(&self, received: &[u64]) -> Option<Vec<u64>> {
+        let n = self.symbol_length();
+        assert_eq!(received.len(), n, "Should receive n symbols");
+        let k = self.degree;
+        let e = (n - k) / 2;
+        let unknowns = k + 2 * e;
+        let rows = self
+            .evaluation_points
+            .iter()
+            .zip(received.iter())
+            .map(|(&x, &y)| {
+                let mut row = Vec::with_capacity(unknowns + 1);
+                row.extend((0..e + k).map(|j| self.field.pow(x, j as u64)));
+                row.extend((0..e).map(|j| self.field.mul(y, self.field.pow(x, j as u64))));
+                row.push(self.field.mul(y, self.field.pow(x, e as u64)));
+                row
+            })
+            .collect();
+        let solution = self.field.solve(rows, unknowns)?;
+        let (q, error_locator) = solution.split_at(e + k);
+        let mut error_locator = error_locator.to_vec();
+        error_locator.push(1);
+        let (mut f, remainder) = self.field.divide(q.to_vec(), &error_locator);
+        if remainder.iter().any(|&c| c != 0) {
+            return None;
+        }
+        f.resize(k, 0);
+        Some(f)
+    }
+}
+
+/// Write every symbol as its `m` bits, least significant first
+fn symbols_to_bits(symbols: &[u64], m: usize) -> Vec<bool> {
+    symbols
+        .iter()
+        .flat_map(|&s| (0..m).map(move |t| (s >> t) & 1 == 1))
+        .collect()
+}
+
+/// Read symbols of `m` bits from `c`
+fn bits_to_symbols(c: &BinVector, m: usize) -> Vec<u64> {
+    (0..c.len() / m)
+        .map(|i| (0..m).fold(0, |acc, t| acc | (u64::from(c[i * m + t]) << t)))
+        .collect()
+}
+
+impl BinaryCode for EvaluationCode {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] evaluation code over GF({})",
+            self.symbol_length(),
+            self.symbol_dimension(),
+            self.field_size()
+        )
+    }
+
+    fn length(&self) -> usize {
+        self.symbol_length() * self.field.degree as usize
+    }
+
+    fn dimension(&self) -> usize {
+        self.degree * self.field.degree as usize
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let m = self.field.degree as usize;
+        let f = self
+            .decode_symbols(&bits_to_symbols(c, m))
+            .ok_or("Too many symbol errors to decode")?;
+        Ok(BinVector::from_bools(&symbols_to_bits(&f, m)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn message(code: &EvaluationCode, i: usize) -> BinVector {
+        BinVector::from_bools(
+            &(0..code.dimension())
+                .map(|t| (i >> t) & 1 == 1)
+                .collect::<Vec<bool>>(),
+        )
+    }
+
+    #[test]
+    fn reed_solomon_parameters() {
+        let code = EvaluationCode::new((0..8).collect(), 3, 8);
+        assert_eq!(
+            (
+                code.symbol_length(),
+                code.symbol_dimension(),
+                code.symbol_distance()
+            ),
+            (8, 3, 6)
+        );
+        assert_eq!(code.length(), 24);
+        assert_eq!(code.dimension(), 9);
+        assert_eq!(code.parity_check_matrix().nrows(), 15);
+
+        let h_transposed = code.parity_check_matrix().transposed();
+        let mut min_distance = code.symbol_length();
+        for i in 1..(1 << code.dimension()) {
+            let codeword = code.encode(&message(&code, i));
+            assert_eq!((&codeword * &h_transposed).count_ones(), 0);
+            let symbols = bits_to_symbols(&codeword, 3);
+            let weight = symbols.iter().filter(|&&s| s != 0).count();
+            assert!(codeword.count_ones() as usize >= weight);
+            min_distance = min_distance.min(weight);
+        }
+        assert_eq!(min_distance, code.symbol_distance());
+    }
+
+    #[test]
+    fn corrects_symbol_errors() {
+        let code = EvaluationCode::new((1..16).collect(), 5, 16);
+        for i in 0..100 {
+            let m = BinVector::random(code.dimension());
+            let codeword = code.encode(&m);
+            let mut symbols = bits_to_symbols(&codeword, 4);
+            // (15 - 5) / 2 errors can be corrected
+            for j in 0..5 {
+                symbols[(i + 3 * j) % 15] ^= 1 + (i as u64 + j as u64) % 15;
+            }
+            let received = BinVector::from_bools(&symbols_to_bits(&symbols, 4));
+            assert_eq!(code.decode_to_message(&received), Ok(m));
+            assert_eq!(code.decode_to_code(&received), Ok(codeword));
+        }
+    }
+
+    #[test]
+    fn binary_field_is_reed_muller() {
+        let code = EvaluationCode::new(vec![0, 1], 1, 2);
+        assert_eq!((code.length(), code.dimension()), (2, 1));
+        let one = BinVector::from_elem(1, true);
+        assert_eq!(code.encode(&one), BinVector::from_elem(2, true));
+        assert_eq!(code.symbol_distance(), 2);
+    }
+}
@@ -104,10 +104,13 @@ def generate_code_implementation(name, code, comment=None):
     max_error = code.decoder().maximum_error_weight()
 
     syndrome_map = {}
+    covering_radius = 0
     for (he, error) in cs.decoder().syndrome_table().items():
         syndrome_map[ZZ(list(he), base=2)] = bools_to_binvec(error)
+        covering_radius = max(covering_radius, error.hamming_weight())
 
     info['syndrome_map'] = syndrome_map
+    info['covering_radius'] = covering_radius
     info['syndrome_map_itemlen'] = len(list(syndrome_map.values())[0])
 
     assert max(syndrome_map) < 2**64, "sydrome map too big!"
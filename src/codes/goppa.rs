@@ -0,0 +1,395 @@
+use crate::codes::{bch::BinaryExtensionField, kernel_basis, BinaryCode};
+use m4ri_rust::friendly::*;
+
+/// Polynomials over $GF(2^m)$, with the coefficients from low to high degree
+///
+/// Polynomials are kept without leading zero coefficients, so the zero polynomial is empty.
+type Polynomial = Vec<usize>;
+
+fn trim(mut p: Polynomial) -> Polynomial {
+    while p.last() == Some(&0) {
+        p.pop();
+    }
+    p
+}
+
+fn poly_add(a: &[usize], b: &[usize]) -> Polynomial {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = long.to_vec();
+    sum.iter_mut().zip(short.iter()).for_each(|(s, &c)| *s ^= c);
+    trim(sum)
+}
+
+fn poly_mul(field: &BinaryExtensionField, a: &[usize], b: &[usize]) -> Polynomial {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] ^= field.mul(x, y);
+        }
+    }
+    trim(product)
+}
+
+/// Quotient and remainder of `a` divided by the nonzero polynomial `b`
+fn poly_divmod(field: &BinaryExtensionField, a: &[usize], b: &[usize]) -> (Polynomial, Polynomial) {
+    debug_assert!(!b.is_empty(), "Division by zero");
+    let mut remainder = trim(a.to_vec());
+    if remainder.len() < b.len() {
+        return (Vec::new(), remainder);
+    }
+    let mut quotient = vec![0; remainder.len() - b.len() + 1];
+    let lead = b[b.len() - 1];
+    while remainder.len() >= b.len() {
+        let shift = remainder.len() - b.len();
+        let factor = field.div(remainder[remainder.len() - 1], lead);
+        quotient[shift] = factor;
+        for (i, &c) in b.iter().enumerate() {
+            remainder[shift + i] ^= field.mul(factor, c);
+        }
+        remainder = trim(remainder);
+    }
+    (trim(quotient), remainder)
+}
+
+fn poly_eval(field: &BinaryExtensionField, p: &[usize], x: usize) -> usize {
+    p.iter().rev().fold(0, |acc, &c| field.mul(acc, x) ^ c)
+}
+
+/// $p^{2^e} \bmod g$
+fn frobenius(field: &BinaryExtensionField, p: &[usize], e: usize, g: &[usize]) -> Polynomial {
+    (0..e).fold(p.to_vec(), |p, _| {
+        poly_divmod(field, &poly_mul(field, &p, &p), g).1
+    })
+}
+
+/// Ben-Or's test: $g$ is irreducible if $\gcd(g, x^{q^i} - x) = 1$ for $i \le t / 2$
+fn is_irreducible(field: &BinaryExtensionField, m: usize, g: &[usize]) -> bool {
+    let t = g.len() - 1;
+    let mut power = vec![0, 1];
+    for _ in 0..(t / 2) {
+        power = frobenius(field, &power, m, g);
+        let (mut a, mut b) = (g.to_vec(), poly_add(&power, &[0, 1]));
+        while !b.is_empty() {
+            let remainder = poly_divmod(field, &a, &b).1;
+            a = b;
+            b = remainder;
+        }
+        if a.len() != 1 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Classical binary Goppa code
+///
+/// For an irreducible Goppa polynomial $g(x)$ of degree $t$ over $GF(2^m)$, the codewords
+/// are the $c \in GF(2)^n$ with $\sum_j c_j / (x - L_j) \equiv 0 \bmod g(x)$, where the
+/// support $L_0, \ldots, L_{n-1}$ is all of $GF(2^m)$. This gives a
+/// $[2^m, \ge 2^m - mt, \ge 2t + 1]$ code, e.g. $[64, 40, 9]$ for $m = 6$ and $t = 4$.
+///
+/// Decodes up to $t$ errors with Patterson's algorithm and fails for vectors further
+/// away from the code, so the covering radius is larger than what the decoder reaches.
+#[derive(Clone, Serialize)]
+pub struct GoppaCode {
+    m: usize,
+    t: usize,
+    /// The monic Goppa polynomial $g(x)$, from low to high degree
+    goppa_poly_coeffs: Vec<usize>,
+    field: BinaryExtensionField,
+    /// The coefficients of $1 / (x - L_j) \bmod g(x)$ for every position $j$
+    syndrome_columns: Vec<Polynomial>,
+    /// The positions that hold the message
+    information_set: Vec<usize>,
+    generator: BinMatrix,
+    parity_check: BinMatrix,
+}
+
+impl GoppaCode {
+    /// Construct the Goppa code of length $2^m$ for the first irreducible polynomial of degree `t`
+    ///
+    /// The candidates $x^t + \sum_{i < t} c_i x^i$ are tried in the order of the number
+    /// with digits $c_i$ in base $2^m$. Panics unless $2 \le m \le 16$, $t \ge 2$ and
+    /// $mt < 2^m$.
+    pub fn new(m: usize, t: usize) -> GoppaCode {
+        assert!((2..=16).contains(&m), "2 <= m <= 16");
+        let field = BinaryExtensionField::new(m);
+        let field_size = 1usize << m;
+        let goppa_poly_coeffs = (1..)
+            .map(|i: usize| {
+                let mut coefficients: Vec<usize> = (0..t)
+                    .map(|digit| (i >> (digit * m)) % field_size)
+                    .collect();
+                coefficients.push(1);
+                coefficients
+            })
+            .find(|candidate| is_irreducible(&field, m, candidate))
+            .unwrap();
+        Self::with_polynomial(m, goppa_poly_coeffs)
+    }
+
+    /// Construct the Goppa code of length $2^m$ with Goppa polynomial `goppa_poly_coeffs`
+    ///
+    /// The coefficients are elements of $GF(2^m)$, from low to high degree, and the
+    /// polynomial should be monic and irreducible.
+    pub fn with_polynomial(m: usize, goppa_poly_coeffs: Vec<usize>) -> GoppaCode {
+        assert!((2..=16).contains(&m), "2 <= m <= 16");
+        let n = 1 << m;
+        assert!(
+            goppa_poly_coeffs.iter().all(|&c| c < n),
+            "The coefficients should be elements of GF(2^m)"
+        );
+        assert_eq!(
+            goppa_poly_coeffs.last(),
+            Some(&1),
+            "The Goppa polynomial should be monic"
+        );
+        let t = goppa_poly_coeffs.len() - 1;
+        assert!(t >= 2, "The Goppa polynomial should have degree at least 2");
+        assert!(m * t < n, "The code would only contain zero");
+        let field = BinaryExtensionField::new(m);
+        assert!(
+            is_irreducible(&field, m, &goppa_poly_coeffs),
+            "The Goppa polynomial should be irreducible"
+        );
+
+        // 1 / (x - L) = (g(x) - g(L)) / (x - L) / g(L), by synthetic division
+        let syndrome_columns: Vec<Polynomial> = (0..n)
+            .map(|support| {
+                let mut quotient = vec![0; t];
+                let mut carry = 0;
+                for i in (1..=t).rev() {
+                    carry = goppa_poly_coeffs[i] ^ field.mul(support, carry);
+                    quotient[i - 1] = carry;
+                }
+                let value = goppa_poly_coeffs[0] ^ field.mul(support, carry);
+                debug_assert_ne!(value, 0, "An irreducible polynomial has no roots");
+                trim(quotient.iter().map(|&q| field.div(q, value)).collect())
+            })
+            .collect();
+
+        // every coefficient of the syndrome gives m binary parity checks
+        let checks: Vec<Vec<bool>> = (0..t * m)
+            .map(|row| {
+                syndrome_columns
+                    .iter()
+                    .map(|column| {
+                        let coefficient = column.get(row / m).copied().unwrap_or(0);
+                        (coefficient >> (row % m)) & 1 == 1
+                    })
+                    .collect()
+            })
+            .collect();
+        let (information_set, rows) = kernel_basis(checks, n);
+        let (_, parity_checks) = kernel_basis(rows.clone(), n);
+        let generator = BinMatrix::new(rows.iter().map(|row| BinVector::from_bools(row)).collect());
+        let parity_check = BinMatrix::new(
+            parity_checks
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+
+        GoppaCode {
+            m,
+            t,
+            goppa_poly_coeffs,
+            field,
+            syndrome_columns,
+            information_set,
+            generator,
+            parity_check,
+        }
+    }
+
+    /// The degree $t$ of the Goppa polynomial, the number of errors that can be corrected
+    pub fn correctable_errors(&self) -> usize {
+        self.t
+    }
+
+    /// The Goppa polynomial, from low to high degree
+    pub fn goppa_polynomial(&self) -> &[usize] {
+        &self.goppa_poly_coeffs
+    }
+
+    /// Run the extended Euclidean algorithm on $g$ and $p$ until the remainder has at
+    /// most degree `max_degree`
+    ///
+    /// Returns the remainder $r$ and $s$ with $r \equiv s p \bmod g$.
+    fn partial_gcd(&self, p: &[usize], max_degree: usize) -> (Polynomial, Polynomial) {
+        let g = &self.goppa_poly_coeffs;
+        let (mut r_prev, mut r) = (g.to_vec(), trim(p.to_vec()));
+        let (mut s_prev, mut s) = (Vec::new(), vec![1]);
+        while r.len() > max_degree + 1 {
+            let (quotient, remainder) = poly_divmod(&self.field, &r_prev, &r);
+            let next_s = poly_add(&s_prev, &poly_mul(&self.field, &quotient, &s));
+            r_prev = std::mem::replace(&mut r, remainder);
+            s_prev = std::mem::replace(&mut s, next_s);
+        }
+        (r, s)
+    }
+
+    /// The syndrome $\sum_j c_j / (x - L_j) \bmod g(x)$
+    fn syndrome(&self, c: &BinVector) -> Polynomial {
+        let mut syndrome = vec![0; self.t];
+        for (j, column) in self.syndrome_columns.iter().enumerate() {
+            if c[j] {
+                syndrome
+                    .iter_mut()
+                    .zip(column.iter())
+                    .for_each(|(s, &coefficient)| *s ^= coefficient);
+            }
+        }
+        trim(syndrome)
+    }
+
+    /// The error locator polynomial with Patterson's algorithm
+    ///
+    /// With $T = S^{-1}$ and $R = \sqrt{T + x} \bmod g$, the locator is
+    /// $a^2 + x b^2$ for $a \equiv b R$ with $\deg a \le t / 2$ and $\deg b < t / 2$.
+    fn error_locator(&self, syndrome: &[usize]) -> Polynomial {
+        // the remainder of g and S is a constant, so this gives the inverse of S
+        let (constant, s) = self.partial_gcd(syndrome, 0);
+        debug_assert_eq!(constant.len(), 1, "g is irreducible, S is invertible");
+        let inverse: Polynomial = s.iter().map(|&c| self.field.div(c, constant[0])).collect();
+        let t_plus_x = poly_add(&inverse, &[0, 1]);
+        if t_plus_x.is_empty() {
+            // a single error at L = 0
+            return vec![0, 1];
+        }
+        // squaring is a permutation of GF(2^m)[x] / g with order mt
+        let root = frobenius(
+            &self.field,
+            &t_plus_x,
+            self.m * self.t - 1,
+            &self.goppa_poly_coeffs,
+        );
+        let (a, b) = self.partial_gcd(&root, self.t / 2);
+        poly_add(
+            &poly_mul(&self.field, &a, &a),
+            &poly_mul(&self.field, &[0, 1], &poly_mul(&self.field, &b, &b)),
+        )
+    }
+}
+
+impl BinaryCode for GoppaCode {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] Goppa code with t = {}",
+            self.length(),
+            self.dimension(),
+            self.t
+        )
+    }
+
+    fn length(&self) -> usize {
+        1 << self.m
+    }
+
+    fn dimension(&self) -> usize {
+        self.information_set.len()
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(c.len(), self.length(), "Vector of the wrong length");
+        let syndrome = self.syndrome(c);
+        if syndrome.is_empty() {
+            return Ok(c.clone());
+        }
+        let locator = self.error_locator(&syndrome);
+
+        // the roots of the locator are the error positions
+        let mut result = c.clone();
+        let mut errors = 0;
+        for j in 0..self.length() {
+            if poly_eval(&self.field, &locator, j) == 0 {
+                result.set(j, !result[j]);
+                errors += 1;
+            }
+        }
+        if errors + 1 != locator.len() {
+            return Err("Too many errors to decode");
+        }
+        Ok(result)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let codeword = self.decode_to_code(c)?;
+        Ok(BinVector::from_bools(
+            &self
+                .information_set
+                .iter()
+                .map(|&p| codeword[p])
+                .collect::<Vec<bool>>(),
+        ))
+    }
+
+    fn error_correction_capability(&self) -> usize {
+        self.t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::index;
+
+    #[test]
+    fn parameters() {
+        for &(m, t) in &[(3, 2), (4, 2), (4, 3), (5, 2), (5, 3), (6, 4)] {
+            let code = GoppaCode::new(m, t);
+            let n = 1 << m;
+            assert_eq!(code.length(), n);
+            assert!(code.dimension() >= n - m * t, "{}", code.name());
+            assert_eq!(code.goppa_polynomial().len(), t + 1);
+            let product = code.generator_matrix() * &code.parity_check_matrix().transposed();
+            assert_eq!(
+                product,
+                BinMatrix::zero(code.dimension(), n - code.dimension())
+            );
+            if code.dimension() <= 16 {
+                assert!(crate::codes::minimum_distance(&code) > 2 * t);
+            }
+        }
+    }
+
+    #[test]
+    fn corrects_errors() {
+        let mut rng = crate::random::lpn_thread_rng();
+        for &(m, t) in &[(4, 2), (5, 3), (6, 4), (8, 5)] {
+            let code = GoppaCode::new(m, t);
+            let n = code.length();
+            for weight in 0..=t {
+                for _ in 0..20 {
+                    let message = BinVector::random(code.dimension());
+                    let codeword = code.encode(&message);
+                    assert!(code.is_codeword(&codeword));
+                    let mut received = codeword.clone();
+                    for p in index::sample(&mut rng, n, weight).into_iter() {
+                        received.set(p, !received[p]);
+                    }
+                    assert_eq!(code.decode_to_code(&received).unwrap(), codeword);
+                    assert_eq!(code.decode_to_message(&received).unwrap(), message);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "irreducible")]
+    fn reducible_polynomial() {
+        // x^2 + x = x (x + 1)
+        GoppaCode::with_polynomial(3, vec![0, 1, 1]);
+    }
+}
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,62 +16,63 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode14_11;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 2049 ],
-                &[ 2050 ],
-                &[ 2052 ],
-                &[ 2056 ],
-                &[ 2064 ],
-                &[ 2080 ],
-                &[ 2112 ],
-                &[ 2176 ],
-                &[ 2304 ],
-                &[ 2560 ],
-                &[ 3072 ],
-                
-            ], 14));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 4095 ],
-                &[ 4096 ],
-                &[ 8192 ],
-                
-            ], 14));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(8, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[4096]);     // 2 => [4096]
-            map.insert(4, &[8192]);     // 4 => [8192]
-            map.insert(3, &[4097]);     // 3 => [4097]
-            map.insert(5, &[8193]);     // 5 => [8193]
-            map.insert(6, &[12288]);     // 6 => [12288]
-            map.insert(7, &[12289]);     // 7 => [12289]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 2049 ],
+            &[ 2050 ],
+            &[ 2052 ],
+            &[ 2056 ],
+            &[ 2064 ],
+            &[ 2080 ],
+            &[ 2112 ],
+            &[ 2176 ],
+            &[ 2304 ],
+            &[ 2560 ],
+            &[ 3072 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 14)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 4095 ],
+            &[ 4096 ],
+            &[ 8192 ],
+            
+        ], 14)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(8, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[4096]);     // 2 => [4096]
+        map.insert(4, &[8192]);     // 4 => [8192]
+        map.insert(3, &[4097]);     // 3 => [4097]
+        map.insert(5, &[8193]);     // 5 => [8193]
+        map.insert(6, &[12288]);     // 6 => [12288]
+        map.insert(7, &[12289]);     // 7 => [12289]
+        map
+    })
 }
 
 impl GuavaCode14_11 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -90,24 +90,15 @@ impl BinaryCode for GuavaCode14_11 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(14);
@@ -132,14 +123,26 @@ impl BinaryCode for GuavaCode14_11 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(14, 11).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[14 / 64] & !((1 << 14) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -334,8 +337,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, true, false, false ]);
         assert_eq!(vector, first_row.as_vector());
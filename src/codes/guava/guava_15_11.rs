@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,71 +16,72 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode15_11;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 24577 ],
-                &[ 20482 ],
-                &[ 12292 ],
-                &[ 18440 ],
-                &[ 10256 ],
-                &[ 6176 ],
-                &[ 30784 ],
-                &[ 14464 ],
-                &[ 22784 ],
-                &[ 27136 ],
-                &[ 29696 ],
-                
-            ], 15));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 22869 ],
-                &[ 27238 ],
-                &[ 29816 ],
-                &[ 32640 ],
-                
-            ], 15));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(16, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(3, &[4]);     // 3 => [4]
-            map.insert(4, &[8]);     // 4 => [8]
-            map.insert(5, &[16]);     // 5 => [16]
-            map.insert(6, &[32]);     // 6 => [32]
-            map.insert(7, &[64]);     // 7 => [64]
-            map.insert(8, &[128]);     // 8 => [128]
-            map.insert(9, &[256]);     // 9 => [256]
-            map.insert(10, &[512]);     // 10 => [512]
-            map.insert(12, &[1024]);     // 12 => [1024]
-            map.insert(11, &[2048]);     // 11 => [2048]
-            map.insert(13, &[4096]);     // 13 => [4096]
-            map.insert(14, &[8192]);     // 14 => [8192]
-            map.insert(15, &[16384]);     // 15 => [16384]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 24577 ],
+            &[ 20482 ],
+            &[ 12292 ],
+            &[ 18440 ],
+            &[ 10256 ],
+            &[ 6176 ],
+            &[ 30784 ],
+            &[ 14464 ],
+            &[ 22784 ],
+            &[ 27136 ],
+            &[ 29696 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 15)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 22869 ],
+            &[ 27238 ],
+            &[ 29816 ],
+            &[ 32640 ],
+            
+        ], 15)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(16, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(3, &[4]);     // 3 => [4]
+        map.insert(4, &[8]);     // 4 => [8]
+        map.insert(5, &[16]);     // 5 => [16]
+        map.insert(6, &[32]);     // 6 => [32]
+        map.insert(7, &[64]);     // 7 => [64]
+        map.insert(8, &[128]);     // 8 => [128]
+        map.insert(9, &[256]);     // 9 => [256]
+        map.insert(10, &[512]);     // 10 => [512]
+        map.insert(12, &[1024]);     // 12 => [1024]
+        map.insert(11, &[2048]);     // 11 => [2048]
+        map.insert(13, &[4096]);     // 13 => [4096]
+        map.insert(14, &[8192]);     // 14 => [8192]
+        map.insert(15, &[16384]);     // 15 => [16384]
+        map
+    })
 }
 
 impl GuavaCode15_11 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -99,24 +99,15 @@ impl BinaryCode for GuavaCode15_11 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(15);
@@ -141,14 +132,26 @@ impl BinaryCode for GuavaCode15_11 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(15, 11).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[15 / 64] & !((1 << 15) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -343,8 +346,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, true, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,120 +16,121 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode16_10;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 7169 ],
-                &[ 11266 ],
-                &[ 19460 ],
-                &[ 35848 ],
-                &[ 37904 ],
-                &[ 42016 ],
-                &[ 50240 ],
-                &[ 39040 ],
-                &[ 43264 ],
-                &[ 51712 ],
-                
-            ], 16));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 8033 ],
-                &[ 8482 ],
-                &[ 16964 ],
-                &[ 35848 ],
-                &[ 62576 ],
-                &[ 64384 ],
-                
-            ], 16));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(19, &[32]);     // 19 => [32]
-            map.insert(21, &[64]);     // 21 => [64]
-            map.insert(32, &[128]);     // 32 => [128]
-            map.insert(35, &[256]);     // 35 => [256]
-            map.insert(37, &[512]);     // 37 => [512]
-            map.insert(25, &[1024]);     // 25 => [1024]
-            map.insert(41, &[2048]);     // 41 => [2048]
-            map.insert(49, &[4096]);     // 49 => [4096]
-            map.insert(50, &[8192]);     // 50 => [8192]
-            map.insert(52, &[16384]);     // 52 => [16384]
-            map.insert(56, &[32768]);     // 56 => [32768]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(18, &[33]);     // 18 => [33]
-            map.insert(20, &[65]);     // 20 => [65]
-            map.insert(33, &[129]);     // 33 => [129]
-            map.insert(34, &[257]);     // 34 => [257]
-            map.insert(36, &[513]);     // 36 => [513]
-            map.insert(24, &[1025]);     // 24 => [1025]
-            map.insert(40, &[2049]);     // 40 => [2049]
-            map.insert(48, &[4097]);     // 48 => [4097]
-            map.insert(51, &[8193]);     // 51 => [8193]
-            map.insert(53, &[16385]);     // 53 => [16385]
-            map.insert(57, &[32769]);     // 57 => [32769]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(23, &[66]);     // 23 => [66]
-            map.insert(39, &[514]);     // 39 => [514]
-            map.insert(27, &[1026]);     // 27 => [1026]
-            map.insert(43, &[2050]);     // 43 => [2050]
-            map.insert(54, &[16386]);     // 54 => [16386]
-            map.insert(58, &[32770]);     // 58 => [32770]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(29, &[1028]);     // 29 => [1028]
-            map.insert(45, &[2052]);     // 45 => [2052]
-            map.insert(60, &[32772]);     // 60 => [32772]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(22, &[67]);     // 22 => [67]
-            map.insert(38, &[515]);     // 38 => [515]
-            map.insert(26, &[1027]);     // 26 => [1027]
-            map.insert(42, &[2051]);     // 42 => [2051]
-            map.insert(55, &[16387]);     // 55 => [16387]
-            map.insert(59, &[32771]);     // 59 => [32771]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(28, &[1029]);     // 28 => [1029]
-            map.insert(44, &[2053]);     // 44 => [2053]
-            map.insert(61, &[32773]);     // 61 => [32773]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(31, &[1030]);     // 31 => [1030]
-            map.insert(47, &[2054]);     // 47 => [2054]
-            map.insert(62, &[32774]);     // 62 => [32774]
-            map.insert(15, &[15]);     // 15 => [15]
-            map.insert(30, &[1031]);     // 30 => [1031]
-            map.insert(46, &[2055]);     // 46 => [2055]
-            map.insert(63, &[32775]);     // 63 => [32775]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 7169 ],
+            &[ 11266 ],
+            &[ 19460 ],
+            &[ 35848 ],
+            &[ 37904 ],
+            &[ 42016 ],
+            &[ 50240 ],
+            &[ 39040 ],
+            &[ 43264 ],
+            &[ 51712 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 16)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 8033 ],
+            &[ 8482 ],
+            &[ 16964 ],
+            &[ 35848 ],
+            &[ 62576 ],
+            &[ 64384 ],
+            
+        ], 16)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(19, &[32]);     // 19 => [32]
+        map.insert(21, &[64]);     // 21 => [64]
+        map.insert(32, &[128]);     // 32 => [128]
+        map.insert(35, &[256]);     // 35 => [256]
+        map.insert(37, &[512]);     // 37 => [512]
+        map.insert(25, &[1024]);     // 25 => [1024]
+        map.insert(41, &[2048]);     // 41 => [2048]
+        map.insert(49, &[4096]);     // 49 => [4096]
+        map.insert(50, &[8192]);     // 50 => [8192]
+        map.insert(52, &[16384]);     // 52 => [16384]
+        map.insert(56, &[32768]);     // 56 => [32768]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(18, &[33]);     // 18 => [33]
+        map.insert(20, &[65]);     // 20 => [65]
+        map.insert(33, &[129]);     // 33 => [129]
+        map.insert(34, &[257]);     // 34 => [257]
+        map.insert(36, &[513]);     // 36 => [513]
+        map.insert(24, &[1025]);     // 24 => [1025]
+        map.insert(40, &[2049]);     // 40 => [2049]
+        map.insert(48, &[4097]);     // 48 => [4097]
+        map.insert(51, &[8193]);     // 51 => [8193]
+        map.insert(53, &[16385]);     // 53 => [16385]
+        map.insert(57, &[32769]);     // 57 => [32769]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(23, &[66]);     // 23 => [66]
+        map.insert(39, &[514]);     // 39 => [514]
+        map.insert(27, &[1026]);     // 27 => [1026]
+        map.insert(43, &[2050]);     // 43 => [2050]
+        map.insert(54, &[16386]);     // 54 => [16386]
+        map.insert(58, &[32770]);     // 58 => [32770]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(29, &[1028]);     // 29 => [1028]
+        map.insert(45, &[2052]);     // 45 => [2052]
+        map.insert(60, &[32772]);     // 60 => [32772]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(22, &[67]);     // 22 => [67]
+        map.insert(38, &[515]);     // 38 => [515]
+        map.insert(26, &[1027]);     // 26 => [1027]
+        map.insert(42, &[2051]);     // 42 => [2051]
+        map.insert(55, &[16387]);     // 55 => [16387]
+        map.insert(59, &[32771]);     // 59 => [32771]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(28, &[1029]);     // 28 => [1029]
+        map.insert(44, &[2053]);     // 44 => [2053]
+        map.insert(61, &[32773]);     // 61 => [32773]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(31, &[1030]);     // 31 => [1030]
+        map.insert(47, &[2054]);     // 47 => [2054]
+        map.insert(62, &[32774]);     // 62 => [32774]
+        map.insert(15, &[15]);     // 15 => [15]
+        map.insert(30, &[1031]);     // 30 => [1031]
+        map.insert(46, &[2055]);     // 46 => [2055]
+        map.insert(63, &[32775]);     // 63 => [32775]
+        map
+    })
 }
 
 impl GuavaCode16_10 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -148,24 +148,15 @@ impl BinaryCode for GuavaCode16_10 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(16);
@@ -190,14 +181,26 @@ impl BinaryCode for GuavaCode16_10 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        4
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(16, 10).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[16 / 64] & !((1 << 16) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -392,8 +395,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, true, true, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
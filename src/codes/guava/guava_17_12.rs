@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,89 +16,90 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode17_12;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 61441 ],
-                &[ 126978 ],
-                &[ 28676 ],
-                &[ 45064 ],
-                &[ 77840 ],
-                &[ 53280 ],
-                &[ 86080 ],
-                &[ 102528 ],
-                &[ 57600 ],
-                &[ 90624 ],
-                &[ 107520 ],
-                &[ 116736 ],
-                
-            ], 17));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 126721 ],
-                &[ 69330 ],
-                &[ 40276 ],
-                &[ 23448 ],
-                &[ 14304 ],
-                
-            ], 17));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(14, &[16]);     // 14 => [16]
-            map.insert(16, &[32]);     // 16 => [32]
-            map.insert(22, &[64]);     // 22 => [64]
-            map.insert(26, &[128]);     // 26 => [128]
-            map.insert(29, &[256]);     // 29 => [256]
-            map.insert(27, &[512]);     // 27 => [512]
-            map.insert(23, &[1024]);     // 23 => [1024]
-            map.insert(15, &[2048]);     // 15 => [2048]
-            map.insert(28, &[4096]);     // 28 => [4096]
-            map.insert(17, &[8192]);     // 17 => [8192]
-            map.insert(9, &[16384]);     // 9 => [16384]
-            map.insert(5, &[32768]);     // 5 => [32768]
-            map.insert(3, &[65536]);     // 3 => [65536]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(12, &[18]);     // 12 => [18]
-            map.insert(18, &[34]);     // 18 => [34]
-            map.insert(20, &[66]);     // 20 => [66]
-            map.insert(24, &[130]);     // 24 => [130]
-            map.insert(31, &[258]);     // 31 => [258]
-            map.insert(25, &[514]);     // 25 => [514]
-            map.insert(21, &[1026]);     // 21 => [1026]
-            map.insert(13, &[2050]);     // 13 => [2050]
-            map.insert(30, &[4098]);     // 30 => [4098]
-            map.insert(19, &[8194]);     // 19 => [8194]
-            map.insert(11, &[16386]);     // 11 => [16386]
-            map.insert(7, &[32770]);     // 7 => [32770]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 61441 ],
+            &[ 126978 ],
+            &[ 28676 ],
+            &[ 45064 ],
+            &[ 77840 ],
+            &[ 53280 ],
+            &[ 86080 ],
+            &[ 102528 ],
+            &[ 57600 ],
+            &[ 90624 ],
+            &[ 107520 ],
+            &[ 116736 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 17)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 126721 ],
+            &[ 69330 ],
+            &[ 40276 ],
+            &[ 23448 ],
+            &[ 14304 ],
+            
+        ], 17)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(14, &[16]);     // 14 => [16]
+        map.insert(16, &[32]);     // 16 => [32]
+        map.insert(22, &[64]);     // 22 => [64]
+        map.insert(26, &[128]);     // 26 => [128]
+        map.insert(29, &[256]);     // 29 => [256]
+        map.insert(27, &[512]);     // 27 => [512]
+        map.insert(23, &[1024]);     // 23 => [1024]
+        map.insert(15, &[2048]);     // 15 => [2048]
+        map.insert(28, &[4096]);     // 28 => [4096]
+        map.insert(17, &[8192]);     // 17 => [8192]
+        map.insert(9, &[16384]);     // 9 => [16384]
+        map.insert(5, &[32768]);     // 5 => [32768]
+        map.insert(3, &[65536]);     // 3 => [65536]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(12, &[18]);     // 12 => [18]
+        map.insert(18, &[34]);     // 18 => [34]
+        map.insert(20, &[66]);     // 20 => [66]
+        map.insert(24, &[130]);     // 24 => [130]
+        map.insert(31, &[258]);     // 31 => [258]
+        map.insert(25, &[514]);     // 25 => [514]
+        map.insert(21, &[1026]);     // 21 => [1026]
+        map.insert(13, &[2050]);     // 13 => [2050]
+        map.insert(30, &[4098]);     // 30 => [4098]
+        map.insert(19, &[8194]);     // 19 => [8194]
+        map.insert(11, &[16386]);     // 11 => [16386]
+        map.insert(7, &[32770]);     // 7 => [32770]
+        map
+    })
 }
 
 impl GuavaCode17_12 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -117,24 +117,15 @@ impl BinaryCode for GuavaCode17_12 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(17);
@@ -159,14 +150,26 @@ impl BinaryCode for GuavaCode17_12 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        2
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(17, 12).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[17 / 64] & !((1 << 17) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -361,8 +364,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, true, true, true, true, false ]);
         assert_eq!(vector, first_row.as_vector());
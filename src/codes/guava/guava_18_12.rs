@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,122 +16,123 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode18_12;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 200705 ],
-                &[ 253954 ],
-                &[ 57348 ],
-                &[ 90120 ],
-                &[ 155664 ],
-                &[ 106528 ],
-                &[ 172096 ],
-                &[ 204928 ],
-                &[ 114944 ],
-                &[ 180736 ],
-                &[ 214016 ],
-                &[ 231424 ],
-                
-            ], 18));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 4097 ],
-                &[ 138962 ],
-                &[ 193108 ],
-                &[ 222360 ],
-                &[ 239840 ],
-                &[ 249600 ],
-                
-            ], 18));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(14, &[16]);     // 14 => [16]
-            map.insert(16, &[32]);     // 16 => [32]
-            map.insert(22, &[64]);     // 22 => [64]
-            map.insert(26, &[128]);     // 26 => [128]
-            map.insert(32, &[256]);     // 32 => [256]
-            map.insert(38, &[512]);     // 38 => [512]
-            map.insert(42, &[1024]);     // 42 => [1024]
-            map.insert(50, &[2048]);     // 50 => [2048]
-            map.insert(7, &[4096]);     // 7 => [4096]
-            map.insert(28, &[8192]);     // 28 => [8192]
-            map.insert(44, &[16384]);     // 44 => [16384]
-            map.insert(52, &[32768]);     // 52 => [32768]
-            map.insert(56, &[65536]);     // 56 => [65536]
-            map.insert(62, &[131072]);     // 62 => [131072]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(15, &[17]);     // 15 => [17]
-            map.insert(17, &[33]);     // 17 => [33]
-            map.insert(23, &[65]);     // 23 => [65]
-            map.insert(27, &[129]);     // 27 => [129]
-            map.insert(33, &[257]);     // 33 => [257]
-            map.insert(39, &[513]);     // 39 => [513]
-            map.insert(43, &[1025]);     // 43 => [1025]
-            map.insert(51, &[2049]);     // 51 => [2049]
-            map.insert(6, &[4097]);     // 6 => [4097]
-            map.insert(29, &[8193]);     // 29 => [8193]
-            map.insert(45, &[16385]);     // 45 => [16385]
-            map.insert(53, &[32769]);     // 53 => [32769]
-            map.insert(57, &[65537]);     // 57 => [65537]
-            map.insert(63, &[131073]);     // 63 => [131073]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(12, &[18]);     // 12 => [18]
-            map.insert(18, &[34]);     // 18 => [34]
-            map.insert(20, &[66]);     // 20 => [66]
-            map.insert(24, &[130]);     // 24 => [130]
-            map.insert(34, &[258]);     // 34 => [258]
-            map.insert(36, &[514]);     // 36 => [514]
-            map.insert(40, &[1026]);     // 40 => [1026]
-            map.insert(48, &[2050]);     // 48 => [2050]
-            map.insert(30, &[8194]);     // 30 => [8194]
-            map.insert(46, &[16386]);     // 46 => [16386]
-            map.insert(54, &[32770]);     // 54 => [32770]
-            map.insert(58, &[65538]);     // 58 => [65538]
-            map.insert(60, &[131074]);     // 60 => [131074]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(13, &[19]);     // 13 => [19]
-            map.insert(19, &[35]);     // 19 => [35]
-            map.insert(21, &[67]);     // 21 => [67]
-            map.insert(25, &[131]);     // 25 => [131]
-            map.insert(35, &[259]);     // 35 => [259]
-            map.insert(37, &[515]);     // 37 => [515]
-            map.insert(41, &[1027]);     // 41 => [1027]
-            map.insert(49, &[2051]);     // 49 => [2051]
-            map.insert(31, &[8195]);     // 31 => [8195]
-            map.insert(47, &[16387]);     // 47 => [16387]
-            map.insert(55, &[32771]);     // 55 => [32771]
-            map.insert(59, &[65539]);     // 59 => [65539]
-            map.insert(61, &[131075]);     // 61 => [131075]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 200705 ],
+            &[ 253954 ],
+            &[ 57348 ],
+            &[ 90120 ],
+            &[ 155664 ],
+            &[ 106528 ],
+            &[ 172096 ],
+            &[ 204928 ],
+            &[ 114944 ],
+            &[ 180736 ],
+            &[ 214016 ],
+            &[ 231424 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 18)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 4097 ],
+            &[ 138962 ],
+            &[ 193108 ],
+            &[ 222360 ],
+            &[ 239840 ],
+            &[ 249600 ],
+            
+        ], 18)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(14, &[16]);     // 14 => [16]
+        map.insert(16, &[32]);     // 16 => [32]
+        map.insert(22, &[64]);     // 22 => [64]
+        map.insert(26, &[128]);     // 26 => [128]
+        map.insert(32, &[256]);     // 32 => [256]
+        map.insert(38, &[512]);     // 38 => [512]
+        map.insert(42, &[1024]);     // 42 => [1024]
+        map.insert(50, &[2048]);     // 50 => [2048]
+        map.insert(7, &[4096]);     // 7 => [4096]
+        map.insert(28, &[8192]);     // 28 => [8192]
+        map.insert(44, &[16384]);     // 44 => [16384]
+        map.insert(52, &[32768]);     // 52 => [32768]
+        map.insert(56, &[65536]);     // 56 => [65536]
+        map.insert(62, &[131072]);     // 62 => [131072]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(15, &[17]);     // 15 => [17]
+        map.insert(17, &[33]);     // 17 => [33]
+        map.insert(23, &[65]);     // 23 => [65]
+        map.insert(27, &[129]);     // 27 => [129]
+        map.insert(33, &[257]);     // 33 => [257]
+        map.insert(39, &[513]);     // 39 => [513]
+        map.insert(43, &[1025]);     // 43 => [1025]
+        map.insert(51, &[2049]);     // 51 => [2049]
+        map.insert(6, &[4097]);     // 6 => [4097]
+        map.insert(29, &[8193]);     // 29 => [8193]
+        map.insert(45, &[16385]);     // 45 => [16385]
+        map.insert(53, &[32769]);     // 53 => [32769]
+        map.insert(57, &[65537]);     // 57 => [65537]
+        map.insert(63, &[131073]);     // 63 => [131073]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(12, &[18]);     // 12 => [18]
+        map.insert(18, &[34]);     // 18 => [34]
+        map.insert(20, &[66]);     // 20 => [66]
+        map.insert(24, &[130]);     // 24 => [130]
+        map.insert(34, &[258]);     // 34 => [258]
+        map.insert(36, &[514]);     // 36 => [514]
+        map.insert(40, &[1026]);     // 40 => [1026]
+        map.insert(48, &[2050]);     // 48 => [2050]
+        map.insert(30, &[8194]);     // 30 => [8194]
+        map.insert(46, &[16386]);     // 46 => [16386]
+        map.insert(54, &[32770]);     // 54 => [32770]
+        map.insert(58, &[65538]);     // 58 => [65538]
+        map.insert(60, &[131074]);     // 60 => [131074]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(13, &[19]);     // 13 => [19]
+        map.insert(19, &[35]);     // 19 => [35]
+        map.insert(21, &[67]);     // 21 => [67]
+        map.insert(25, &[131]);     // 25 => [131]
+        map.insert(35, &[259]);     // 35 => [259]
+        map.insert(37, &[515]);     // 37 => [515]
+        map.insert(41, &[1027]);     // 41 => [1027]
+        map.insert(49, &[2051]);     // 49 => [2051]
+        map.insert(31, &[8195]);     // 31 => [8195]
+        map.insert(47, &[16387]);     // 47 => [16387]
+        map.insert(55, &[32771]);     // 55 => [32771]
+        map.insert(59, &[65539]);     // 59 => [65539]
+        map.insert(61, &[131075]);     // 61 => [131075]
+        map
+    })
 }
 
 impl GuavaCode18_12 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -150,24 +150,15 @@ impl BinaryCode for GuavaCode18_12 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(18);
@@ -192,14 +183,26 @@ impl BinaryCode for GuavaCode18_12 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(18, 12).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[18 / 64] & !((1 << 18) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -394,8 +397,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false, true, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,90 +16,91 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode18_13;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 188417 ],
-                &[ 122882 ],
-                &[ 253956 ],
-                &[ 57352 ],
-                &[ 90128 ],
-                &[ 155680 ],
-                &[ 106560 ],
-                &[ 172160 ],
-                &[ 205056 ],
-                &[ 115200 ],
-                &[ 181248 ],
-                &[ 215040 ],
-                &[ 233472 ],
-                
-            ], 18));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 80553 ],
-                &[ 189610 ],
-                &[ 206604 ],
-                &[ 46896 ],
-                &[ 28608 ],
-                
-            ], 18));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(7, &[8]);     // 7 => [8]
-            map.insert(8, &[16]);     // 8 => [16]
-            map.insert(11, &[32]);     // 11 => [32]
-            map.insert(16, &[64]);     // 16 => [64]
-            map.insert(19, &[128]);     // 19 => [128]
-            map.insert(28, &[256]);     // 28 => [256]
-            map.insert(29, &[512]);     // 29 => [512]
-            map.insert(30, &[1024]);     // 30 => [1024]
-            map.insert(17, &[2048]);     // 17 => [2048]
-            map.insert(9, &[4096]);     // 9 => [4096]
-            map.insert(31, &[8192]);     // 31 => [8192]
-            map.insert(18, &[16384]);     // 18 => [16384]
-            map.insert(10, &[32768]);     // 10 => [32768]
-            map.insert(5, &[65536]);     // 5 => [65536]
-            map.insert(6, &[131072]);     // 6 => [131072]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(12, &[20]);     // 12 => [20]
-            map.insert(15, &[36]);     // 15 => [36]
-            map.insert(20, &[68]);     // 20 => [68]
-            map.insert(23, &[132]);     // 23 => [132]
-            map.insert(24, &[260]);     // 24 => [260]
-            map.insert(25, &[516]);     // 25 => [516]
-            map.insert(26, &[1028]);     // 26 => [1028]
-            map.insert(21, &[2052]);     // 21 => [2052]
-            map.insert(13, &[4100]);     // 13 => [4100]
-            map.insert(27, &[8196]);     // 27 => [8196]
-            map.insert(22, &[16388]);     // 22 => [16388]
-            map.insert(14, &[32772]);     // 14 => [32772]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 188417 ],
+            &[ 122882 ],
+            &[ 253956 ],
+            &[ 57352 ],
+            &[ 90128 ],
+            &[ 155680 ],
+            &[ 106560 ],
+            &[ 172160 ],
+            &[ 205056 ],
+            &[ 115200 ],
+            &[ 181248 ],
+            &[ 215040 ],
+            &[ 233472 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 18)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 80553 ],
+            &[ 189610 ],
+            &[ 206604 ],
+            &[ 46896 ],
+            &[ 28608 ],
+            
+        ], 18)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(7, &[8]);     // 7 => [8]
+        map.insert(8, &[16]);     // 8 => [16]
+        map.insert(11, &[32]);     // 11 => [32]
+        map.insert(16, &[64]);     // 16 => [64]
+        map.insert(19, &[128]);     // 19 => [128]
+        map.insert(28, &[256]);     // 28 => [256]
+        map.insert(29, &[512]);     // 29 => [512]
+        map.insert(30, &[1024]);     // 30 => [1024]
+        map.insert(17, &[2048]);     // 17 => [2048]
+        map.insert(9, &[4096]);     // 9 => [4096]
+        map.insert(31, &[8192]);     // 31 => [8192]
+        map.insert(18, &[16384]);     // 18 => [16384]
+        map.insert(10, &[32768]);     // 10 => [32768]
+        map.insert(5, &[65536]);     // 5 => [65536]
+        map.insert(6, &[131072]);     // 6 => [131072]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(12, &[20]);     // 12 => [20]
+        map.insert(15, &[36]);     // 15 => [36]
+        map.insert(20, &[68]);     // 20 => [68]
+        map.insert(23, &[132]);     // 23 => [132]
+        map.insert(24, &[260]);     // 24 => [260]
+        map.insert(25, &[516]);     // 25 => [516]
+        map.insert(26, &[1028]);     // 26 => [1028]
+        map.insert(21, &[2052]);     // 21 => [2052]
+        map.insert(13, &[4100]);     // 13 => [4100]
+        map.insert(27, &[8196]);     // 27 => [8196]
+        map.insert(22, &[16388]);     // 22 => [16388]
+        map.insert(14, &[32772]);     // 14 => [32772]
+        map
+    })
 }
 
 impl GuavaCode18_13 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -118,24 +118,15 @@ impl BinaryCode for GuavaCode18_13 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(18);
@@ -160,14 +151,26 @@ impl BinaryCode for GuavaCode18_13 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        2
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(18, 13).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[18 / 64] & !((1 << 18) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -362,8 +365,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, true, true, true, false, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,74 +16,75 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode18_14;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 16385 ],
-                &[ 16386 ],
-                &[ 16388 ],
-                &[ 16392 ],
-                &[ 16400 ],
-                &[ 16416 ],
-                &[ 16448 ],
-                &[ 16512 ],
-                &[ 16640 ],
-                &[ 16896 ],
-                &[ 17408 ],
-                &[ 18432 ],
-                &[ 20480 ],
-                &[ 24576 ],
-                
-            ], 18));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 32767 ],
-                &[ 32768 ],
-                &[ 65536 ],
-                &[ 131072 ],
-                
-            ], 18));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(16, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[32768]);     // 2 => [32768]
-            map.insert(4, &[65536]);     // 4 => [65536]
-            map.insert(8, &[131072]);     // 8 => [131072]
-            map.insert(3, &[32769]);     // 3 => [32769]
-            map.insert(5, &[65537]);     // 5 => [65537]
-            map.insert(9, &[131073]);     // 9 => [131073]
-            map.insert(6, &[98304]);     // 6 => [98304]
-            map.insert(10, &[163840]);     // 10 => [163840]
-            map.insert(12, &[196608]);     // 12 => [196608]
-            map.insert(7, &[98305]);     // 7 => [98305]
-            map.insert(11, &[163841]);     // 11 => [163841]
-            map.insert(13, &[196609]);     // 13 => [196609]
-            map.insert(14, &[229376]);     // 14 => [229376]
-            map.insert(15, &[229377]);     // 15 => [229377]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 16385 ],
+            &[ 16386 ],
+            &[ 16388 ],
+            &[ 16392 ],
+            &[ 16400 ],
+            &[ 16416 ],
+            &[ 16448 ],
+            &[ 16512 ],
+            &[ 16640 ],
+            &[ 16896 ],
+            &[ 17408 ],
+            &[ 18432 ],
+            &[ 20480 ],
+            &[ 24576 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 18)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 32767 ],
+            &[ 32768 ],
+            &[ 65536 ],
+            &[ 131072 ],
+            
+        ], 18)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(16, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[32768]);     // 2 => [32768]
+        map.insert(4, &[65536]);     // 4 => [65536]
+        map.insert(8, &[131072]);     // 8 => [131072]
+        map.insert(3, &[32769]);     // 3 => [32769]
+        map.insert(5, &[65537]);     // 5 => [65537]
+        map.insert(9, &[131073]);     // 9 => [131073]
+        map.insert(6, &[98304]);     // 6 => [98304]
+        map.insert(10, &[163840]);     // 10 => [163840]
+        map.insert(12, &[196608]);     // 12 => [196608]
+        map.insert(7, &[98305]);     // 7 => [98305]
+        map.insert(11, &[163841]);     // 11 => [163841]
+        map.insert(13, &[196609]);     // 13 => [196609]
+        map.insert(14, &[229376]);     // 14 => [229376]
+        map.insert(15, &[229377]);     // 15 => [229377]
+        map
+    })
 }
 
 impl GuavaCode18_14 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -102,24 +102,15 @@ impl BinaryCode for GuavaCode18_14 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(18);
@@ -144,14 +135,26 @@ impl BinaryCode for GuavaCode18_14 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        4
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(18, 14).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[18 / 64] & !((1 << 18) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -346,8 +349,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,1084 +16,1085 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode20_10;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 464897 ],
-                &[ 676866 ],
-                &[ 745476 ],
-                &[ 388104 ],
-                &[ 570384 ],
-                &[ 982048 ],
-                &[ 867392 ],
-                &[ 885888 ],
-                &[ 913664 ],
-                &[ 958976 ],
-                
-            ], 20));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 741441 ],
-                &[ 806978 ],
-                &[ 70724 ],
-                &[ 377928 ],
-                &[ 1036304 ],
-                &[ 521312 ],
-                &[ 111744 ],
-                &[ 996608 ],
-                &[ 254464 ],
-                &[ 616448 ],
-                
-            ], 20));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 464897 ],
+            &[ 676866 ],
+            &[ 745476 ],
+            &[ 388104 ],
+            &[ 570384 ],
+            &[ 982048 ],
+            &[ 867392 ],
+            &[ 885888 ],
+            &[ 913664 ],
+            &[ 958976 ],
+            
+        ], 20)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(1024, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(47, &[64]);     // 47 => [64]
-            map.insert(64, &[128]);     // 64 => [128]
-            map.insert(128, &[256]);     // 128 => [256]
-            map.insert(256, &[512]);     // 256 => [512]
-            map.insert(236, &[1024]);     // 236 => [1024]
-            map.insert(512, &[2048]);     // 512 => [2048]
-            map.insert(247, &[4096]);     // 247 => [4096]
-            map.insert(992, &[8192]);     // 992 => [8192]
-            map.insert(827, &[16384]);     // 827 => [16384]
-            map.insert(376, &[32768]);     // 376 => [32768]
-            map.insert(1021, &[65536]);     // 1021 => [65536]
-            map.insert(433, &[131072]);     // 433 => [131072]
-            map.insert(186, &[262144]);     // 186 => [262144]
-            map.insert(659, &[524288]);     // 659 => [524288]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(46, &[65]);     // 46 => [65]
-            map.insert(65, &[129]);     // 65 => [129]
-            map.insert(129, &[257]);     // 129 => [257]
-            map.insert(257, &[513]);     // 257 => [513]
-            map.insert(237, &[1025]);     // 237 => [1025]
-            map.insert(513, &[2049]);     // 513 => [2049]
-            map.insert(246, &[4097]);     // 246 => [4097]
-            map.insert(993, &[8193]);     // 993 => [8193]
-            map.insert(826, &[16385]);     // 826 => [16385]
-            map.insert(377, &[32769]);     // 377 => [32769]
-            map.insert(1020, &[65537]);     // 1020 => [65537]
-            map.insert(432, &[131073]);     // 432 => [131073]
-            map.insert(187, &[262145]);     // 187 => [262145]
-            map.insert(658, &[524289]);     // 658 => [524289]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(45, &[66]);     // 45 => [66]
-            map.insert(66, &[130]);     // 66 => [130]
-            map.insert(130, &[258]);     // 130 => [258]
-            map.insert(258, &[514]);     // 258 => [514]
-            map.insert(238, &[1026]);     // 238 => [1026]
-            map.insert(514, &[2050]);     // 514 => [2050]
-            map.insert(245, &[4098]);     // 245 => [4098]
-            map.insert(994, &[8194]);     // 994 => [8194]
-            map.insert(825, &[16386]);     // 825 => [16386]
-            map.insert(378, &[32770]);     // 378 => [32770]
-            map.insert(1023, &[65538]);     // 1023 => [65538]
-            map.insert(435, &[131074]);     // 435 => [131074]
-            map.insert(184, &[262146]);     // 184 => [262146]
-            map.insert(657, &[524290]);     // 657 => [524290]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(43, &[68]);     // 43 => [68]
-            map.insert(68, &[132]);     // 68 => [132]
-            map.insert(132, &[260]);     // 132 => [260]
-            map.insert(260, &[516]);     // 260 => [516]
-            map.insert(232, &[1028]);     // 232 => [1028]
-            map.insert(516, &[2052]);     // 516 => [2052]
-            map.insert(243, &[4100]);     // 243 => [4100]
-            map.insert(996, &[8196]);     // 996 => [8196]
-            map.insert(831, &[16388]);     // 831 => [16388]
-            map.insert(380, &[32772]);     // 380 => [32772]
-            map.insert(1017, &[65540]);     // 1017 => [65540]
-            map.insert(437, &[131076]);     // 437 => [131076]
-            map.insert(190, &[262148]);     // 190 => [262148]
-            map.insert(663, &[524292]);     // 663 => [524292]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(39, &[72]);     // 39 => [72]
-            map.insert(72, &[136]);     // 72 => [136]
-            map.insert(136, &[264]);     // 136 => [264]
-            map.insert(264, &[520]);     // 264 => [520]
-            map.insert(228, &[1032]);     // 228 => [1032]
-            map.insert(520, &[2056]);     // 520 => [2056]
-            map.insert(255, &[4104]);     // 255 => [4104]
-            map.insert(1000, &[8200]);     // 1000 => [8200]
-            map.insert(819, &[16392]);     // 819 => [16392]
-            map.insert(368, &[32776]);     // 368 => [32776]
-            map.insert(1013, &[65544]);     // 1013 => [65544]
-            map.insert(441, &[131080]);     // 441 => [131080]
-            map.insert(178, &[262152]);     // 178 => [262152]
-            map.insert(667, &[524296]);     // 667 => [524296]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(63, &[80]);     // 63 => [80]
-            map.insert(80, &[144]);     // 80 => [144]
-            map.insert(144, &[272]);     // 144 => [272]
-            map.insert(272, &[528]);     // 272 => [528]
-            map.insert(252, &[1040]);     // 252 => [1040]
-            map.insert(528, &[2064]);     // 528 => [2064]
-            map.insert(231, &[4112]);     // 231 => [4112]
-            map.insert(1008, &[8208]);     // 1008 => [8208]
-            map.insert(811, &[16400]);     // 811 => [16400]
-            map.insert(360, &[32784]);     // 360 => [32784]
-            map.insert(1005, &[65552]);     // 1005 => [65552]
-            map.insert(417, &[131088]);     // 417 => [131088]
-            map.insert(170, &[262160]);     // 170 => [262160]
-            map.insert(643, &[524304]);     // 643 => [524304]
-            map.insert(15, &[96]);     // 15 => [96]
-            map.insert(96, &[160]);     // 96 => [160]
-            map.insert(160, &[288]);     // 160 => [288]
-            map.insert(288, &[544]);     // 288 => [544]
-            map.insert(204, &[1056]);     // 204 => [1056]
-            map.insert(544, &[2080]);     // 544 => [2080]
-            map.insert(215, &[4128]);     // 215 => [4128]
-            map.insert(960, &[8224]);     // 960 => [8224]
-            map.insert(795, &[16416]);     // 795 => [16416]
-            map.insert(344, &[32800]);     // 344 => [32800]
-            map.insert(989, &[65568]);     // 989 => [65568]
-            map.insert(401, &[131104]);     // 401 => [131104]
-            map.insert(154, &[262176]);     // 154 => [262176]
-            map.insert(691, &[524320]);     // 691 => [524320]
-            map.insert(111, &[192]);     // 111 => [192]
-            map.insert(175, &[320]);     // 175 => [320]
-            map.insert(303, &[576]);     // 303 => [576]
-            map.insert(195, &[1088]);     // 195 => [1088]
-            map.insert(559, &[2112]);     // 559 => [2112]
-            map.insert(216, &[4160]);     // 216 => [4160]
-            map.insert(975, &[8256]);     // 975 => [8256]
-            map.insert(788, &[16448]);     // 788 => [16448]
-            map.insert(343, &[32832]);     // 343 => [32832]
-            map.insert(978, &[65600]);     // 978 => [65600]
-            map.insert(414, &[131136]);     // 414 => [131136]
-            map.insert(149, &[262208]);     // 149 => [262208]
-            map.insert(700, &[524352]);     // 700 => [524352]
-            map.insert(192, &[384]);     // 192 => [384]
-            map.insert(320, &[640]);     // 320 => [640]
-            map.insert(172, &[1152]);     // 172 => [1152]
-            map.insert(576, &[2176]);     // 576 => [2176]
-            map.insert(183, &[4224]);     // 183 => [4224]
-            map.insert(928, &[8320]);     // 928 => [8320]
-            map.insert(891, &[16512]);     // 891 => [16512]
-            map.insert(312, &[32896]);     // 312 => [32896]
-            map.insert(957, &[65664]);     // 957 => [65664]
-            map.insert(497, &[131200]);     // 497 => [131200]
-            map.insert(250, &[262272]);     // 250 => [262272]
-            map.insert(723, &[524416]);     // 723 => [524416]
-            map.insert(384, &[768]);     // 384 => [768]
-            map.insert(108, &[1280]);     // 108 => [1280]
-            map.insert(640, &[2304]);     // 640 => [2304]
-            map.insert(119, &[4352]);     // 119 => [4352]
-            map.insert(864, &[8448]);     // 864 => [8448]
-            map.insert(955, &[16640]);     // 955 => [16640]
-            map.insert(504, &[33024]);     // 504 => [33024]
-            map.insert(893, &[65792]);     // 893 => [65792]
-            map.insert(305, &[131328]);     // 305 => [131328]
-            map.insert(58, &[262400]);     // 58 => [262400]
-            map.insert(531, &[524544]);     // 531 => [524544]
-            map.insert(492, &[1536]);     // 492 => [1536]
-            map.insert(768, &[2560]);     // 768 => [2560]
-            map.insert(503, &[4608]);     // 503 => [4608]
-            map.insert(736, &[8704]);     // 736 => [8704]
-            map.insert(571, &[16896]);     // 571 => [16896]
-            map.insert(120, &[33280]);     // 120 => [33280]
-            map.insert(765, &[66048]);     // 765 => [66048]
-            map.insert(177, &[131584]);     // 177 => [131584]
-            map.insert(442, &[262656]);     // 442 => [262656]
-            map.insert(915, &[524800]);     // 915 => [524800]
-            map.insert(748, &[3072]);     // 748 => [3072]
-            map.insert(27, &[5120]);     // 27 => [5120]
-            map.insert(780, &[9216]);     // 780 => [9216]
-            map.insert(983, &[17408]);     // 983 => [17408]
-            map.insert(404, &[33792]);     // 404 => [33792]
-            map.insert(785, &[66560]);     // 785 => [66560]
-            map.insert(349, &[132096]);     // 349 => [132096]
-            map.insert(86, &[263168]);     // 86 => [263168]
-            map.insert(639, &[525312]);     // 639 => [525312]
-            map.insert(759, &[6144]);     // 759 => [6144]
-            map.insert(480, &[10240]);     // 480 => [10240]
-            map.insert(315, &[18432]);     // 315 => [18432]
-            map.insert(888, &[34816]);     // 888 => [34816]
-            map.insert(509, &[67584]);     // 509 => [67584]
-            map.insert(945, &[133120]);     // 945 => [133120]
-            map.insert(698, &[264192]);     // 698 => [264192]
-            map.insert(147, &[526336]);     // 147 => [526336]
-            map.insert(791, &[12288]);     // 791 => [12288]
-            map.insert(972, &[20480]);     // 972 => [20480]
-            map.insert(399, &[36864]);     // 399 => [36864]
-            map.insert(778, &[69632]);     // 778 => [69632]
-            map.insert(326, &[135168]);     // 326 => [135168]
-            map.insert(77, &[266240]);     // 77 => [266240]
-            map.insert(612, &[528384]);     // 612 => [528384]
-            map.insert(219, &[24576]);     // 219 => [24576]
-            map.insert(664, &[40960]);     // 664 => [40960]
-            map.insert(29, &[73728]);     // 29 => [73728]
-            map.insert(593, &[139264]);     // 593 => [139264]
-            map.insert(858, &[270336]);     // 858 => [270336]
-            map.insert(371, &[532480]);     // 371 => [532480]
-            map.insert(579, &[49152]);     // 579 => [49152]
-            map.insert(198, &[81920]);     // 198 => [81920]
-            map.insert(650, &[147456]);     // 650 => [147456]
-            map.insert(897, &[278528]);     // 897 => [278528]
-            map.insert(424, &[540672]);     // 424 => [540672]
-            map.insert(645, &[98304]);     // 645 => [98304]
-            map.insert(201, &[163840]);     // 201 => [163840]
-            map.insert(450, &[294912]);     // 450 => [294912]
-            map.insert(1003, &[557056]);     // 1003 => [557056]
-            map.insert(588, &[196608]);     // 588 => [196608]
-            map.insert(839, &[327680]);     // 839 => [327680]
-            map.insert(366, &[589824]);     // 366 => [589824]
-            map.insert(267, &[393216]);     // 267 => [393216]
-            map.insert(802, &[655360]);     // 802 => [655360]
-            map.insert(553, &[786432]);     // 553 => [786432]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(19, &[19]);     // 19 => [19]
-            map.insert(35, &[35]);     // 35 => [35]
-            map.insert(44, &[67]);     // 44 => [67]
-            map.insert(67, &[131]);     // 67 => [131]
-            map.insert(131, &[259]);     // 131 => [259]
-            map.insert(259, &[515]);     // 259 => [515]
-            map.insert(239, &[1027]);     // 239 => [1027]
-            map.insert(515, &[2051]);     // 515 => [2051]
-            map.insert(244, &[4099]);     // 244 => [4099]
-            map.insert(995, &[8195]);     // 995 => [8195]
-            map.insert(824, &[16387]);     // 824 => [16387]
-            map.insert(379, &[32771]);     // 379 => [32771]
-            map.insert(1022, &[65539]);     // 1022 => [65539]
-            map.insert(434, &[131075]);     // 434 => [131075]
-            map.insert(185, &[262147]);     // 185 => [262147]
-            map.insert(656, &[524291]);     // 656 => [524291]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(42, &[69]);     // 42 => [69]
-            map.insert(69, &[133]);     // 69 => [133]
-            map.insert(133, &[261]);     // 133 => [261]
-            map.insert(261, &[517]);     // 261 => [517]
-            map.insert(233, &[1029]);     // 233 => [1029]
-            map.insert(517, &[2053]);     // 517 => [2053]
-            map.insert(242, &[4101]);     // 242 => [4101]
-            map.insert(997, &[8197]);     // 997 => [8197]
-            map.insert(830, &[16389]);     // 830 => [16389]
-            map.insert(381, &[32773]);     // 381 => [32773]
-            map.insert(1016, &[65541]);     // 1016 => [65541]
-            map.insert(436, &[131077]);     // 436 => [131077]
-            map.insert(191, &[262149]);     // 191 => [262149]
-            map.insert(662, &[524293]);     // 662 => [524293]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(41, &[41]);     // 41 => [41]
-            map.insert(38, &[73]);     // 38 => [73]
-            map.insert(73, &[137]);     // 73 => [137]
-            map.insert(137, &[265]);     // 137 => [265]
-            map.insert(265, &[521]);     // 265 => [521]
-            map.insert(229, &[1033]);     // 229 => [1033]
-            map.insert(521, &[2057]);     // 521 => [2057]
-            map.insert(254, &[4105]);     // 254 => [4105]
-            map.insert(1001, &[8201]);     // 1001 => [8201]
-            map.insert(818, &[16393]);     // 818 => [16393]
-            map.insert(369, &[32777]);     // 369 => [32777]
-            map.insert(1012, &[65545]);     // 1012 => [65545]
-            map.insert(440, &[131081]);     // 440 => [131081]
-            map.insert(179, &[262153]);     // 179 => [262153]
-            map.insert(666, &[524297]);     // 666 => [524297]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(62, &[81]);     // 62 => [81]
-            map.insert(81, &[145]);     // 81 => [145]
-            map.insert(145, &[273]);     // 145 => [273]
-            map.insert(273, &[529]);     // 273 => [529]
-            map.insert(253, &[1041]);     // 253 => [1041]
-            map.insert(529, &[2065]);     // 529 => [2065]
-            map.insert(230, &[4113]);     // 230 => [4113]
-            map.insert(1009, &[8209]);     // 1009 => [8209]
-            map.insert(810, &[16401]);     // 810 => [16401]
-            map.insert(361, &[32785]);     // 361 => [32785]
-            map.insert(1004, &[65553]);     // 1004 => [65553]
-            map.insert(416, &[131089]);     // 416 => [131089]
-            map.insert(171, &[262161]);     // 171 => [262161]
-            map.insert(642, &[524305]);     // 642 => [524305]
-            map.insert(14, &[97]);     // 14 => [97]
-            map.insert(97, &[161]);     // 97 => [161]
-            map.insert(161, &[289]);     // 161 => [289]
-            map.insert(289, &[545]);     // 289 => [545]
-            map.insert(205, &[1057]);     // 205 => [1057]
-            map.insert(545, &[2081]);     // 545 => [2081]
-            map.insert(214, &[4129]);     // 214 => [4129]
-            map.insert(961, &[8225]);     // 961 => [8225]
-            map.insert(794, &[16417]);     // 794 => [16417]
-            map.insert(345, &[32801]);     // 345 => [32801]
-            map.insert(988, &[65569]);     // 988 => [65569]
-            map.insert(400, &[131105]);     // 400 => [131105]
-            map.insert(155, &[262177]);     // 155 => [262177]
-            map.insert(690, &[524321]);     // 690 => [524321]
-            map.insert(110, &[193]);     // 110 => [193]
-            map.insert(174, &[321]);     // 174 => [321]
-            map.insert(302, &[577]);     // 302 => [577]
-            map.insert(194, &[1089]);     // 194 => [1089]
-            map.insert(558, &[2113]);     // 558 => [2113]
-            map.insert(217, &[4161]);     // 217 => [4161]
-            map.insert(974, &[8257]);     // 974 => [8257]
-            map.insert(789, &[16449]);     // 789 => [16449]
-            map.insert(342, &[32833]);     // 342 => [32833]
-            map.insert(979, &[65601]);     // 979 => [65601]
-            map.insert(415, &[131137]);     // 415 => [131137]
-            map.insert(148, &[262209]);     // 148 => [262209]
-            map.insert(701, &[524353]);     // 701 => [524353]
-            map.insert(193, &[385]);     // 193 => [385]
-            map.insert(321, &[641]);     // 321 => [641]
-            map.insert(173, &[1153]);     // 173 => [1153]
-            map.insert(577, &[2177]);     // 577 => [2177]
-            map.insert(182, &[4225]);     // 182 => [4225]
-            map.insert(929, &[8321]);     // 929 => [8321]
-            map.insert(890, &[16513]);     // 890 => [16513]
-            map.insert(313, &[32897]);     // 313 => [32897]
-            map.insert(956, &[65665]);     // 956 => [65665]
-            map.insert(496, &[131201]);     // 496 => [131201]
-            map.insert(251, &[262273]);     // 251 => [262273]
-            map.insert(722, &[524417]);     // 722 => [524417]
-            map.insert(385, &[769]);     // 385 => [769]
-            map.insert(109, &[1281]);     // 109 => [1281]
-            map.insert(641, &[2305]);     // 641 => [2305]
-            map.insert(118, &[4353]);     // 118 => [4353]
-            map.insert(865, &[8449]);     // 865 => [8449]
-            map.insert(954, &[16641]);     // 954 => [16641]
-            map.insert(505, &[33025]);     // 505 => [33025]
-            map.insert(892, &[65793]);     // 892 => [65793]
-            map.insert(304, &[131329]);     // 304 => [131329]
-            map.insert(59, &[262401]);     // 59 => [262401]
-            map.insert(530, &[524545]);     // 530 => [524545]
-            map.insert(493, &[1537]);     // 493 => [1537]
-            map.insert(769, &[2561]);     // 769 => [2561]
-            map.insert(502, &[4609]);     // 502 => [4609]
-            map.insert(737, &[8705]);     // 737 => [8705]
-            map.insert(570, &[16897]);     // 570 => [16897]
-            map.insert(121, &[33281]);     // 121 => [33281]
-            map.insert(764, &[66049]);     // 764 => [66049]
-            map.insert(176, &[131585]);     // 176 => [131585]
-            map.insert(443, &[262657]);     // 443 => [262657]
-            map.insert(914, &[524801]);     // 914 => [524801]
-            map.insert(749, &[3073]);     // 749 => [3073]
-            map.insert(26, &[5121]);     // 26 => [5121]
-            map.insert(781, &[9217]);     // 781 => [9217]
-            map.insert(982, &[17409]);     // 982 => [17409]
-            map.insert(405, &[33793]);     // 405 => [33793]
-            map.insert(784, &[66561]);     // 784 => [66561]
-            map.insert(348, &[132097]);     // 348 => [132097]
-            map.insert(87, &[263169]);     // 87 => [263169]
-            map.insert(638, &[525313]);     // 638 => [525313]
-            map.insert(758, &[6145]);     // 758 => [6145]
-            map.insert(481, &[10241]);     // 481 => [10241]
-            map.insert(314, &[18433]);     // 314 => [18433]
-            map.insert(889, &[34817]);     // 889 => [34817]
-            map.insert(508, &[67585]);     // 508 => [67585]
-            map.insert(944, &[133121]);     // 944 => [133121]
-            map.insert(699, &[264193]);     // 699 => [264193]
-            map.insert(146, &[526337]);     // 146 => [526337]
-            map.insert(790, &[12289]);     // 790 => [12289]
-            map.insert(973, &[20481]);     // 973 => [20481]
-            map.insert(398, &[36865]);     // 398 => [36865]
-            map.insert(779, &[69633]);     // 779 => [69633]
-            map.insert(327, &[135169]);     // 327 => [135169]
-            map.insert(76, &[266241]);     // 76 => [266241]
-            map.insert(613, &[528385]);     // 613 => [528385]
-            map.insert(218, &[24577]);     // 218 => [24577]
-            map.insert(665, &[40961]);     // 665 => [40961]
-            map.insert(28, &[73729]);     // 28 => [73729]
-            map.insert(592, &[139265]);     // 592 => [139265]
-            map.insert(859, &[270337]);     // 859 => [270337]
-            map.insert(370, &[532481]);     // 370 => [532481]
-            map.insert(578, &[49153]);     // 578 => [49153]
-            map.insert(199, &[81921]);     // 199 => [81921]
-            map.insert(651, &[147457]);     // 651 => [147457]
-            map.insert(896, &[278529]);     // 896 => [278529]
-            map.insert(425, &[540673]);     // 425 => [540673]
-            map.insert(644, &[98305]);     // 644 => [98305]
-            map.insert(200, &[163841]);     // 200 => [163841]
-            map.insert(451, &[294913]);     // 451 => [294913]
-            map.insert(1002, &[557057]);     // 1002 => [557057]
-            map.insert(589, &[196609]);     // 589 => [196609]
-            map.insert(838, &[327681]);     // 838 => [327681]
-            map.insert(367, &[589825]);     // 367 => [589825]
-            map.insert(266, &[393217]);     // 266 => [393217]
-            map.insert(803, &[655361]);     // 803 => [655361]
-            map.insert(552, &[786433]);     // 552 => [786433]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(70, &[134]);     // 70 => [134]
-            map.insert(134, &[262]);     // 134 => [262]
-            map.insert(262, &[518]);     // 262 => [518]
-            map.insert(234, &[1030]);     // 234 => [1030]
-            map.insert(518, &[2054]);     // 518 => [2054]
-            map.insert(241, &[4102]);     // 241 => [4102]
-            map.insert(998, &[8198]);     // 998 => [8198]
-            map.insert(829, &[16390]);     // 829 => [16390]
-            map.insert(382, &[32774]);     // 382 => [32774]
-            map.insert(1019, &[65542]);     // 1019 => [65542]
-            map.insert(439, &[131078]);     // 439 => [131078]
-            map.insert(188, &[262150]);     // 188 => [262150]
-            map.insert(661, &[524294]);     // 661 => [524294]
-            map.insert(74, &[138]);     // 74 => [138]
-            map.insert(138, &[266]);     // 138 => [266]
-            map.insert(522, &[2058]);     // 522 => [2058]
-            map.insert(817, &[16394]);     // 817 => [16394]
-            map.insert(1015, &[65546]);     // 1015 => [65546]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(61, &[82]);     // 61 => [82]
-            map.insert(82, &[146]);     // 82 => [146]
-            map.insert(274, &[530]);     // 274 => [530]
-            map.insert(1010, &[8210]);     // 1010 => [8210]
-            map.insert(809, &[16402]);     // 809 => [16402]
-            map.insert(362, &[32786]);     // 362 => [32786]
-            map.insert(1007, &[65554]);     // 1007 => [65554]
-            map.insert(419, &[131090]);     // 419 => [131090]
-            map.insert(168, &[262162]);     // 168 => [262162]
-            map.insert(98, &[162]);     // 98 => [162]
-            map.insert(162, &[290]);     // 162 => [290]
-            map.insert(290, &[546]);     // 290 => [546]
-            map.insert(206, &[1058]);     // 206 => [1058]
-            map.insert(546, &[2082]);     // 546 => [2082]
-            map.insert(213, &[4130]);     // 213 => [4130]
-            map.insert(962, &[8226]);     // 962 => [8226]
-            map.insert(793, &[16418]);     // 793 => [16418]
-            map.insert(346, &[32802]);     // 346 => [32802]
-            map.insert(991, &[65570]);     // 991 => [65570]
-            map.insert(403, &[131106]);     // 403 => [131106]
-            map.insert(152, &[262178]);     // 152 => [262178]
-            map.insert(689, &[524322]);     // 689 => [524322]
-            map.insert(301, &[578]);     // 301 => [578]
-            map.insert(557, &[2114]);     // 557 => [2114]
-            map.insert(341, &[32834]);     // 341 => [32834]
-            map.insert(976, &[65602]);     // 976 => [65602]
-            map.insert(412, &[131138]);     // 412 => [131138]
-            map.insert(151, &[262210]);     // 151 => [262210]
-            map.insert(702, &[524354]);     // 702 => [524354]
-            map.insert(322, &[642]);     // 322 => [642]
-            map.insert(181, &[4226]);     // 181 => [4226]
-            map.insert(930, &[8322]);     // 930 => [8322]
-            map.insert(959, &[65666]);     // 959 => [65666]
-            map.insert(499, &[131202]);     // 499 => [131202]
-            map.insert(248, &[262274]);     // 248 => [262274]
-            map.insert(721, &[524418]);     // 721 => [524418]
-            map.insert(386, &[770]);     // 386 => [770]
-            map.insert(117, &[4354]);     // 117 => [4354]
-            map.insert(866, &[8450]);     // 866 => [8450]
-            map.insert(953, &[16642]);     // 953 => [16642]
-            map.insert(506, &[33026]);     // 506 => [33026]
-            map.insert(895, &[65794]);     // 895 => [65794]
-            map.insert(307, &[131330]);     // 307 => [131330]
-            map.insert(56, &[262402]);     // 56 => [262402]
-            map.insert(494, &[1538]);     // 494 => [1538]
-            map.insert(770, &[2562]);     // 770 => [2562]
-            map.insert(501, &[4610]);     // 501 => [4610]
-            map.insert(738, &[8706]);     // 738 => [8706]
-            map.insert(569, &[16898]);     // 569 => [16898]
-            map.insert(122, &[33282]);     // 122 => [33282]
-            map.insert(767, &[66050]);     // 767 => [66050]
-            map.insert(913, &[524802]);     // 913 => [524802]
-            map.insert(750, &[3074]);     // 750 => [3074]
-            map.insert(782, &[9218]);     // 782 => [9218]
-            map.insert(981, &[17410]);     // 981 => [17410]
-            map.insert(406, &[33794]);     // 406 => [33794]
-            map.insert(787, &[66562]);     // 787 => [66562]
-            map.insert(351, &[132098]);     // 351 => [132098]
-            map.insert(84, &[263170]);     // 84 => [263170]
-            map.insert(637, &[525314]);     // 637 => [525314]
-            map.insert(757, &[6146]);     // 757 => [6146]
-            map.insert(482, &[10242]);     // 482 => [10242]
-            map.insert(511, &[67586]);     // 511 => [67586]
-            map.insert(947, &[133122]);     // 947 => [133122]
-            map.insert(696, &[264194]);     // 696 => [264194]
-            map.insert(397, &[36866]);     // 397 => [36866]
-            map.insert(776, &[69634]);     // 776 => [69634]
-            map.insert(324, &[135170]);     // 324 => [135170]
-            map.insert(79, &[266242]);     // 79 => [266242]
-            map.insert(614, &[528386]);     // 614 => [528386]
-            map.insert(31, &[73730]);     // 31 => [73730]
-            map.insert(595, &[139266]);     // 595 => [139266]
-            map.insert(856, &[270338]);     // 856 => [270338]
-            map.insert(196, &[81922]);     // 196 => [81922]
-            map.insert(648, &[147458]);     // 648 => [147458]
-            map.insert(899, &[278530]);     // 899 => [278530]
-            map.insert(426, &[540674]);     // 426 => [540674]
-            map.insert(647, &[98306]);     // 647 => [98306]
-            map.insert(203, &[163842]);     // 203 => [163842]
-            map.insert(448, &[294914]);     // 448 => [294914]
-            map.insert(590, &[196610]);     // 590 => [196610]
-            map.insert(837, &[327682]);     // 837 => [327682]
-            map.insert(364, &[589826]);     // 364 => [589826]
-            map.insert(800, &[655362]);     // 800 => [655362]
-            map.insert(555, &[786434]);     // 555 => [786434]
-            map.insert(140, &[268]);     // 140 => [268]
-            map.insert(268, &[524]);     // 268 => [524]
-            map.insert(224, &[1036]);     // 224 => [1036]
-            map.insert(524, &[2060]);     // 524 => [2060]
-            map.insert(823, &[16396]);     // 823 => [16396]
-            map.insert(372, &[32780]);     // 372 => [32780]
-            map.insert(445, &[131084]);     // 445 => [131084]
-            map.insert(671, &[524300]);     // 671 => [524300]
-            map.insert(52, &[52]);     // 52 => [52]
-            map.insert(276, &[532]);     // 276 => [532]
-            map.insert(532, &[2068]);     // 532 => [2068]
-            map.insert(227, &[4116]);     // 227 => [4116]
-            map.insert(815, &[16404]);     // 815 => [16404]
-            map.insert(421, &[131092]);     // 421 => [131092]
-            map.insert(100, &[164]);     // 100 => [164]
-            map.insert(164, &[292]);     // 164 => [292]
-            map.insert(292, &[548]);     // 292 => [548]
-            map.insert(548, &[2084]);     // 548 => [2084]
-            map.insert(211, &[4132]);     // 211 => [4132]
-            map.insert(964, &[8228]);     // 964 => [8228]
-            map.insert(799, &[16420]);     // 799 => [16420]
-            map.insert(985, &[65572]);     // 985 => [65572]
-            map.insert(158, &[262180]);     // 158 => [262180]
-            map.insert(695, &[524324]);     // 695 => [524324]
-            map.insert(107, &[196]);     // 107 => [196]
-            map.insert(299, &[580]);     // 299 => [580]
-            map.insert(220, &[4164]);     // 220 => [4164]
-            map.insert(971, &[8260]);     // 971 => [8260]
-            map.insert(339, &[32836]);     // 339 => [32836]
-            map.insert(410, &[131140]);     // 410 => [131140]
-            map.insert(580, &[2180]);     // 580 => [2180]
-            map.insert(932, &[8324]);     // 932 => [8324]
-            map.insert(316, &[32900]);     // 316 => [32900]
-            map.insert(727, &[524420]);     // 727 => [524420]
-            map.insert(388, &[772]);     // 388 => [772]
-            map.insert(104, &[1284]);     // 104 => [1284]
-            map.insert(115, &[4356]);     // 115 => [4356]
-            map.insert(868, &[8452]);     // 868 => [8452]
-            map.insert(309, &[131332]);     // 309 => [131332]
-            map.insert(535, &[524548]);     // 535 => [524548]
-            map.insert(488, &[1540]);     // 488 => [1540]
-            map.insert(772, &[2564]);     // 772 => [2564]
-            map.insert(740, &[8708]);     // 740 => [8708]
-            map.insert(575, &[16900]);     // 575 => [16900]
-            map.insert(124, &[33284]);     // 124 => [33284]
-            map.insert(761, &[66052]);     // 761 => [66052]
-            map.insert(446, &[262660]);     // 446 => [262660]
-            map.insert(919, &[524804]);     // 919 => [524804]
-            map.insert(744, &[3076]);     // 744 => [3076]
-            map.insert(635, &[525316]);     // 635 => [525316]
-            map.insert(755, &[6148]);     // 755 => [6148]
-            map.insert(484, &[10244]);     // 484 => [10244]
-            map.insert(319, &[18436]);     // 319 => [18436]
-            map.insert(949, &[133124]);     // 949 => [133124]
-            map.insert(968, &[20484]);     // 968 => [20484]
-            map.insert(395, &[36868]);     // 395 => [36868]
-            map.insert(608, &[528388]);     // 608 => [528388]
-            map.insert(223, &[24580]);     // 223 => [24580]
-            map.insert(668, &[40964]);     // 668 => [40964]
-            map.insert(597, &[139268]);     // 597 => [139268]
-            map.insert(862, &[270340]);     // 862 => [270340]
-            map.insert(375, &[532484]);     // 375 => [532484]
-            map.insert(583, &[49156]);     // 583 => [49156]
-            map.insert(654, &[147460]);     // 654 => [147460]
-            map.insert(901, &[278532]);     // 901 => [278532]
-            map.insert(428, &[540676]);     // 428 => [540676]
-            map.insert(454, &[294916]);     // 454 => [294916]
-            map.insert(584, &[196612]);     // 584 => [196612]
-            map.insert(835, &[327684]);     // 835 => [327684]
-            map.insert(271, &[393220]);     // 271 => [393220]
-            map.insert(806, &[655364]);     // 806 => [655364]
-            map.insert(55, &[88]);     // 55 => [88]
-            map.insert(88, &[152]);     // 88 => [152]
-            map.insert(280, &[536]);     // 280 => [536]
-            map.insert(536, &[2072]);     // 536 => [2072]
-            map.insert(352, &[32792]);     // 352 => [32792]
-            map.insert(296, &[552]);     // 296 => [552]
-            map.insert(336, &[32808]);     // 336 => [32808]
-            map.insert(409, &[131112]);     // 409 => [131112]
-            map.insert(103, &[200]);     // 103 => [200]
-            map.insert(167, &[328]);     // 167 => [328]
-            map.insert(295, &[584]);     // 295 => [584]
-            map.insert(551, &[2120]);     // 551 => [2120]
-            map.insert(208, &[4168]);     // 208 => [4168]
-            map.insert(967, &[8264]);     // 967 => [8264]
-            map.insert(796, &[16456]);     // 796 => [16456]
-            map.insert(986, &[65608]);     // 986 => [65608]
-            map.insert(157, &[262216]);     // 157 => [262216]
-            map.insert(692, &[524360]);     // 692 => [524360]
-            map.insert(328, &[648]);     // 328 => [648]
-            map.insert(936, &[8328]);     // 936 => [8328]
-            map.insert(883, &[16520]);     // 883 => [16520]
-            map.insert(731, &[524424]);     // 731 => [524424]
-            map.insert(392, &[776]);     // 392 => [776]
-            map.insert(127, &[4360]);     // 127 => [4360]
-            map.insert(872, &[8456]);     // 872 => [8456]
-            map.insert(885, &[65800]);     // 885 => [65800]
-            map.insert(539, &[524552]);     // 539 => [524552]
-            map.insert(563, &[16904]);     // 563 => [16904]
-            map.insert(112, &[33288]);     // 112 => [33288]
-            map.insert(923, &[524808]);     // 923 => [524808]
-            map.insert(94, &[263176]);     // 94 => [263176]
-            map.insert(631, &[525320]);     // 631 => [525320]
-            map.insert(880, &[34824]);     // 880 => [34824]
-            map.insert(391, &[36872]);     // 391 => [36872]
-            map.insert(334, &[135176]);     // 334 => [135176]
-            map.insert(620, &[528392]);     // 620 => [528392]
-            map.insert(601, &[139272]);     // 601 => [139272]
-            map.insert(850, &[270344]);     // 850 => [270344]
-            map.insert(587, &[49160]);     // 587 => [49160]
-            map.insert(905, &[278536]);     // 905 => [278536]
-            map.insert(653, &[98312]);     // 653 => [98312]
-            map.insert(458, &[294920]);     // 458 => [294920]
-            map.insert(847, &[327688]);     // 847 => [327688]
-            map.insert(358, &[589832]);     // 358 => [589832]
-            map.insert(560, &[2096]);     // 560 => [2096]
-            map.insert(675, &[524336]);     // 675 => [524336]
-            map.insert(684, &[524368]);     // 684 => [524368]
-            map.insert(875, &[16528]);     // 875 => [16528]
-            map.insert(941, &[65680]);     // 941 => [65680]
-            map.insert(707, &[524432]);     // 707 => [524432]
-            map.insert(939, &[16656]);     // 939 => [16656]
-            map.insert(877, &[65808]);     // 877 => [65808]
-            map.insert(487, &[4624]);     // 487 => [4624]
-            map.insert(752, &[8720]);     // 752 => [8720]
-            map.insert(333, &[132112]);     // 333 => [132112]
-            map.insert(623, &[525328]);     // 623 => [525328]
-            map.insert(743, &[6160]);     // 743 => [6160]
-            map.insert(682, &[264208]);     // 682 => [264208]
-            map.insert(775, &[12304]);     // 775 => [12304]
-            map.insert(93, &[266256]);     // 93 => [266256]
-            map.insert(628, &[528400]);     // 628 => [528400]
-            map.insert(842, &[270352]);     // 842 => [270352]
-            map.insert(355, &[532496]);     // 355 => [532496]
-            map.insert(466, &[294928]);     // 466 => [294928]
-            map.insert(604, &[196624]);     // 604 => [196624]
-            map.insert(855, &[327696]);     // 855 => [327696]
-            map.insert(283, &[393232]);     // 283 => [393232]
-            map.insert(143, &[352]);     // 143 => [352]
-            map.insert(527, &[2144]);     // 527 => [2144]
-            map.insert(820, &[16480]);     // 820 => [16480]
-            map.insert(925, &[65696]);     // 925 => [65696]
-            map.insert(465, &[131232]);     // 465 => [131232]
-            map.insert(672, &[2336]);     // 672 => [2336]
-            map.insert(832, &[8480]);     // 832 => [8480]
-            map.insert(472, &[33056]);     // 472 => [33056]
-            map.insert(861, &[65824]);     // 861 => [65824]
-            map.insert(460, &[1568]);     // 460 => [1568]
-            map.insert(471, &[4640]);     // 471 => [4640]
-            map.insert(704, &[8736]);     // 704 => [8736]
-            map.insert(733, &[66080]);     // 733 => [66080]
-            map.insert(716, &[3104]);     // 716 => [3104]
-            map.insert(812, &[9248]);     // 812 => [9248]
-            map.insert(607, &[525344]);     // 607 => [525344]
-            map.insert(477, &[67616]);     // 477 => [67616]
-            map.insert(431, &[36896]);     // 431 => [36896]
-            map.insert(625, &[139296]);     // 625 => [139296]
-            map.insert(611, &[49184]);     // 611 => [49184]
-            map.insert(677, &[98336]);     // 677 => [98336]
-            map.insert(871, &[327712]);     // 871 => [327712]
-            map.insert(911, &[8384]);     // 911 => [8384]
-            map.insert(852, &[16576]);     // 852 => [16576]
-            map.insert(279, &[32960]);     // 279 => [32960]
-            map.insert(478, &[131264]);     // 478 => [131264]
-            map.insert(687, &[2368]);     // 687 => [2368]
-            map.insert(916, &[16704]);     // 916 => [16704]
-            map.insert(286, &[131392]);     // 286 => [131392]
-            map.insert(572, &[524608]);     // 572 => [524608]
-            map.insert(719, &[8768]);     // 719 => [8768]
-            map.insert(728, &[6208]);     // 728 => [6208]
-            map.insert(463, &[10304]);     // 463 => [10304]
-            map.insert(926, &[133184]);     // 926 => [133184]
-            map.insert(805, &[69696]);     // 805 => [69696]
-            map.insert(942, &[278592]);     // 942 => [278592]
-            map.insert(91, &[5248]);     // 91 => [5248]
-            map.insert(844, &[9344]);     // 844 => [9344]
-            map.insert(468, &[33920]);     // 468 => [33920]
-            map.insert(849, &[66688]);     // 849 => [66688]
-            map.insert(285, &[132224]);     // 285 => [132224]
-            map.insert(762, &[264320]);     // 762 => [264320]
-            map.insert(908, &[20608]);     // 908 => [20608]
-            map.insert(714, &[147584]);     // 714 => [147584]
-            map.insert(709, &[98432]);     // 709 => [98432]
-            map.insert(331, &[393344]);     // 331 => [393344]
-            map.insert(617, &[786560]);     // 617 => [786560]
-            map.insert(906, &[69888]);     // 906 => [69888]
-            map.insert(681, &[786688]);     // 681 => [786688]
-            map.insert(632, &[35328]);     // 632 => [35328]
-            map.insert(475, &[25088]);     // 475 => [25088]
-            map.insert(920, &[41472]);     // 920 => [41472]
-            map.insert(602, &[270848]);     // 602 => [270848]
-            map.insert(457, &[164352]);     // 457 => [164352]
-            map.insert(747, &[557568]);     // 747 => [557568]
-            map.insert(598, &[265216]);     // 598 => [265216]
-            map.insert(950, &[271360]);     // 950 => [271360]
-            map.insert(541, &[75776]);     // 541 => [75776]
-            map.insert(710, &[83968]);     // 710 => [83968]
-            map.insert(713, &[165888]);     // 713 => [165888]
-            map.insert(491, &[559104]);     // 491 => [559104]
-            map.insert(878, &[591872]);     // 878 => [591872]
-            map.insert(678, &[143360]);     // 678 => [143360]
-            map.insert(886, &[282624]);     // 886 => [282624]
-            map.insert(626, &[102400]);     // 626 => [102400]
-            map.insert(734, &[790528]);     // 734 => [790528]
-            map.insert(357, &[106496]);     // 357 => [106496]
-            map.insert(23, &[23]);     // 23 => [23]
-            map.insert(71, &[135]);     // 71 => [135]
-            map.insert(135, &[263]);     // 135 => [263]
-            map.insert(263, &[519]);     // 263 => [519]
-            map.insert(235, &[1031]);     // 235 => [1031]
-            map.insert(519, &[2055]);     // 519 => [2055]
-            map.insert(240, &[4103]);     // 240 => [4103]
-            map.insert(999, &[8199]);     // 999 => [8199]
-            map.insert(828, &[16391]);     // 828 => [16391]
-            map.insert(383, &[32775]);     // 383 => [32775]
-            map.insert(1018, &[65543]);     // 1018 => [65543]
-            map.insert(438, &[131079]);     // 438 => [131079]
-            map.insert(189, &[262151]);     // 189 => [262151]
-            map.insert(660, &[524295]);     // 660 => [524295]
-            map.insert(75, &[139]);     // 75 => [139]
-            map.insert(139, &[267]);     // 139 => [267]
-            map.insert(523, &[2059]);     // 523 => [2059]
-            map.insert(816, &[16395]);     // 816 => [16395]
-            map.insert(1014, &[65547]);     // 1014 => [65547]
-            map.insert(51, &[51]);     // 51 => [51]
-            map.insert(60, &[83]);     // 60 => [83]
-            map.insert(83, &[147]);     // 83 => [147]
-            map.insert(275, &[531]);     // 275 => [531]
-            map.insert(1011, &[8211]);     // 1011 => [8211]
-            map.insert(808, &[16403]);     // 808 => [16403]
-            map.insert(363, &[32787]);     // 363 => [32787]
-            map.insert(1006, &[65555]);     // 1006 => [65555]
-            map.insert(418, &[131091]);     // 418 => [131091]
-            map.insert(169, &[262163]);     // 169 => [262163]
-            map.insert(99, &[163]);     // 99 => [163]
-            map.insert(163, &[291]);     // 163 => [291]
-            map.insert(291, &[547]);     // 291 => [547]
-            map.insert(207, &[1059]);     // 207 => [1059]
-            map.insert(547, &[2083]);     // 547 => [2083]
-            map.insert(212, &[4131]);     // 212 => [4131]
-            map.insert(963, &[8227]);     // 963 => [8227]
-            map.insert(792, &[16419]);     // 792 => [16419]
-            map.insert(347, &[32803]);     // 347 => [32803]
-            map.insert(990, &[65571]);     // 990 => [65571]
-            map.insert(402, &[131107]);     // 402 => [131107]
-            map.insert(153, &[262179]);     // 153 => [262179]
-            map.insert(688, &[524323]);     // 688 => [524323]
-            map.insert(300, &[579]);     // 300 => [579]
-            map.insert(556, &[2115]);     // 556 => [2115]
-            map.insert(340, &[32835]);     // 340 => [32835]
-            map.insert(977, &[65603]);     // 977 => [65603]
-            map.insert(413, &[131139]);     // 413 => [131139]
-            map.insert(150, &[262211]);     // 150 => [262211]
-            map.insert(703, &[524355]);     // 703 => [524355]
-            map.insert(323, &[643]);     // 323 => [643]
-            map.insert(180, &[4227]);     // 180 => [4227]
-            map.insert(931, &[8323]);     // 931 => [8323]
-            map.insert(958, &[65667]);     // 958 => [65667]
-            map.insert(498, &[131203]);     // 498 => [131203]
-            map.insert(249, &[262275]);     // 249 => [262275]
-            map.insert(720, &[524419]);     // 720 => [524419]
-            map.insert(387, &[771]);     // 387 => [771]
-            map.insert(116, &[4355]);     // 116 => [4355]
-            map.insert(867, &[8451]);     // 867 => [8451]
-            map.insert(952, &[16643]);     // 952 => [16643]
-            map.insert(507, &[33027]);     // 507 => [33027]
-            map.insert(894, &[65795]);     // 894 => [65795]
-            map.insert(306, &[131331]);     // 306 => [131331]
-            map.insert(57, &[262403]);     // 57 => [262403]
-            map.insert(495, &[1539]);     // 495 => [1539]
-            map.insert(771, &[2563]);     // 771 => [2563]
-            map.insert(500, &[4611]);     // 500 => [4611]
-            map.insert(739, &[8707]);     // 739 => [8707]
-            map.insert(568, &[16899]);     // 568 => [16899]
-            map.insert(123, &[33283]);     // 123 => [33283]
-            map.insert(766, &[66051]);     // 766 => [66051]
-            map.insert(912, &[524803]);     // 912 => [524803]
-            map.insert(751, &[3075]);     // 751 => [3075]
-            map.insert(783, &[9219]);     // 783 => [9219]
-            map.insert(980, &[17411]);     // 980 => [17411]
-            map.insert(407, &[33795]);     // 407 => [33795]
-            map.insert(786, &[66563]);     // 786 => [66563]
-            map.insert(350, &[132099]);     // 350 => [132099]
-            map.insert(85, &[263171]);     // 85 => [263171]
-            map.insert(636, &[525315]);     // 636 => [525315]
-            map.insert(756, &[6147]);     // 756 => [6147]
-            map.insert(483, &[10243]);     // 483 => [10243]
-            map.insert(510, &[67587]);     // 510 => [67587]
-            map.insert(946, &[133123]);     // 946 => [133123]
-            map.insert(697, &[264195]);     // 697 => [264195]
-            map.insert(396, &[36867]);     // 396 => [36867]
-            map.insert(777, &[69635]);     // 777 => [69635]
-            map.insert(325, &[135171]);     // 325 => [135171]
-            map.insert(78, &[266243]);     // 78 => [266243]
-            map.insert(615, &[528387]);     // 615 => [528387]
-            map.insert(30, &[73731]);     // 30 => [73731]
-            map.insert(594, &[139267]);     // 594 => [139267]
-            map.insert(857, &[270339]);     // 857 => [270339]
-            map.insert(197, &[81923]);     // 197 => [81923]
-            map.insert(649, &[147459]);     // 649 => [147459]
-            map.insert(898, &[278531]);     // 898 => [278531]
-            map.insert(427, &[540675]);     // 427 => [540675]
-            map.insert(646, &[98307]);     // 646 => [98307]
-            map.insert(202, &[163843]);     // 202 => [163843]
-            map.insert(449, &[294915]);     // 449 => [294915]
-            map.insert(591, &[196611]);     // 591 => [196611]
-            map.insert(836, &[327683]);     // 836 => [327683]
-            map.insert(365, &[589827]);     // 365 => [589827]
-            map.insert(801, &[655363]);     // 801 => [655363]
-            map.insert(554, &[786435]);     // 554 => [786435]
-            map.insert(141, &[269]);     // 141 => [269]
-            map.insert(269, &[525]);     // 269 => [525]
-            map.insert(225, &[1037]);     // 225 => [1037]
-            map.insert(525, &[2061]);     // 525 => [2061]
-            map.insert(822, &[16397]);     // 822 => [16397]
-            map.insert(373, &[32781]);     // 373 => [32781]
-            map.insert(444, &[131085]);     // 444 => [131085]
-            map.insert(670, &[524301]);     // 670 => [524301]
-            map.insert(53, &[53]);     // 53 => [53]
-            map.insert(277, &[533]);     // 277 => [533]
-            map.insert(533, &[2069]);     // 533 => [2069]
-            map.insert(226, &[4117]);     // 226 => [4117]
-            map.insert(814, &[16405]);     // 814 => [16405]
-            map.insert(420, &[131093]);     // 420 => [131093]
-            map.insert(101, &[165]);     // 101 => [165]
-            map.insert(165, &[293]);     // 165 => [293]
-            map.insert(293, &[549]);     // 293 => [549]
-            map.insert(549, &[2085]);     // 549 => [2085]
-            map.insert(210, &[4133]);     // 210 => [4133]
-            map.insert(965, &[8229]);     // 965 => [8229]
-            map.insert(798, &[16421]);     // 798 => [16421]
-            map.insert(984, &[65573]);     // 984 => [65573]
-            map.insert(159, &[262181]);     // 159 => [262181]
-            map.insert(694, &[524325]);     // 694 => [524325]
-            map.insert(106, &[197]);     // 106 => [197]
-            map.insert(298, &[581]);     // 298 => [581]
-            map.insert(221, &[4165]);     // 221 => [4165]
-            map.insert(970, &[8261]);     // 970 => [8261]
-            map.insert(338, &[32837]);     // 338 => [32837]
-            map.insert(411, &[131141]);     // 411 => [131141]
-            map.insert(581, &[2181]);     // 581 => [2181]
-            map.insert(933, &[8325]);     // 933 => [8325]
-            map.insert(317, &[32901]);     // 317 => [32901]
-            map.insert(726, &[524421]);     // 726 => [524421]
-            map.insert(389, &[773]);     // 389 => [773]
-            map.insert(105, &[1285]);     // 105 => [1285]
-            map.insert(114, &[4357]);     // 114 => [4357]
-            map.insert(869, &[8453]);     // 869 => [8453]
-            map.insert(308, &[131333]);     // 308 => [131333]
-            map.insert(534, &[524549]);     // 534 => [524549]
-            map.insert(489, &[1541]);     // 489 => [1541]
-            map.insert(773, &[2565]);     // 773 => [2565]
-            map.insert(741, &[8709]);     // 741 => [8709]
-            map.insert(574, &[16901]);     // 574 => [16901]
-            map.insert(125, &[33285]);     // 125 => [33285]
-            map.insert(760, &[66053]);     // 760 => [66053]
-            map.insert(447, &[262661]);     // 447 => [262661]
-            map.insert(918, &[524805]);     // 918 => [524805]
-            map.insert(745, &[3077]);     // 745 => [3077]
-            map.insert(634, &[525317]);     // 634 => [525317]
-            map.insert(754, &[6149]);     // 754 => [6149]
-            map.insert(485, &[10245]);     // 485 => [10245]
-            map.insert(318, &[18437]);     // 318 => [18437]
-            map.insert(948, &[133125]);     // 948 => [133125]
-            map.insert(969, &[20485]);     // 969 => [20485]
-            map.insert(394, &[36869]);     // 394 => [36869]
-            map.insert(609, &[528389]);     // 609 => [528389]
-            map.insert(222, &[24581]);     // 222 => [24581]
-            map.insert(669, &[40965]);     // 669 => [40965]
-            map.insert(596, &[139269]);     // 596 => [139269]
-            map.insert(863, &[270341]);     // 863 => [270341]
-            map.insert(374, &[532485]);     // 374 => [532485]
-            map.insert(582, &[49157]);     // 582 => [49157]
-            map.insert(655, &[147461]);     // 655 => [147461]
-            map.insert(900, &[278533]);     // 900 => [278533]
-            map.insert(429, &[540677]);     // 429 => [540677]
-            map.insert(455, &[294917]);     // 455 => [294917]
-            map.insert(585, &[196613]);     // 585 => [196613]
-            map.insert(834, &[327685]);     // 834 => [327685]
-            map.insert(270, &[393221]);     // 270 => [393221]
-            map.insert(807, &[655365]);     // 807 => [655365]
-            map.insert(54, &[89]);     // 54 => [89]
-            map.insert(89, &[153]);     // 89 => [153]
-            map.insert(281, &[537]);     // 281 => [537]
-            map.insert(537, &[2073]);     // 537 => [2073]
-            map.insert(353, &[32793]);     // 353 => [32793]
-            map.insert(297, &[553]);     // 297 => [553]
-            map.insert(337, &[32809]);     // 337 => [32809]
-            map.insert(408, &[131113]);     // 408 => [131113]
-            map.insert(102, &[201]);     // 102 => [201]
-            map.insert(166, &[329]);     // 166 => [329]
-            map.insert(294, &[585]);     // 294 => [585]
-            map.insert(550, &[2121]);     // 550 => [2121]
-            map.insert(209, &[4169]);     // 209 => [4169]
-            map.insert(966, &[8265]);     // 966 => [8265]
-            map.insert(797, &[16457]);     // 797 => [16457]
-            map.insert(987, &[65609]);     // 987 => [65609]
-            map.insert(156, &[262217]);     // 156 => [262217]
-            map.insert(693, &[524361]);     // 693 => [524361]
-            map.insert(329, &[649]);     // 329 => [649]
-            map.insert(937, &[8329]);     // 937 => [8329]
-            map.insert(882, &[16521]);     // 882 => [16521]
-            map.insert(730, &[524425]);     // 730 => [524425]
-            map.insert(393, &[777]);     // 393 => [777]
-            map.insert(126, &[4361]);     // 126 => [4361]
-            map.insert(873, &[8457]);     // 873 => [8457]
-            map.insert(884, &[65801]);     // 884 => [65801]
-            map.insert(538, &[524553]);     // 538 => [524553]
-            map.insert(562, &[16905]);     // 562 => [16905]
-            map.insert(113, &[33289]);     // 113 => [33289]
-            map.insert(922, &[524809]);     // 922 => [524809]
-            map.insert(95, &[263177]);     // 95 => [263177]
-            map.insert(630, &[525321]);     // 630 => [525321]
-            map.insert(881, &[34825]);     // 881 => [34825]
-            map.insert(390, &[36873]);     // 390 => [36873]
-            map.insert(335, &[135177]);     // 335 => [135177]
-            map.insert(621, &[528393]);     // 621 => [528393]
-            map.insert(600, &[139273]);     // 600 => [139273]
-            map.insert(851, &[270345]);     // 851 => [270345]
-            map.insert(586, &[49161]);     // 586 => [49161]
-            map.insert(904, &[278537]);     // 904 => [278537]
-            map.insert(652, &[98313]);     // 652 => [98313]
-            map.insert(459, &[294921]);     // 459 => [294921]
-            map.insert(846, &[327689]);     // 846 => [327689]
-            map.insert(359, &[589833]);     // 359 => [589833]
-            map.insert(561, &[2097]);     // 561 => [2097]
-            map.insert(674, &[524337]);     // 674 => [524337]
-            map.insert(685, &[524369]);     // 685 => [524369]
-            map.insert(874, &[16529]);     // 874 => [16529]
-            map.insert(940, &[65681]);     // 940 => [65681]
-            map.insert(706, &[524433]);     // 706 => [524433]
-            map.insert(938, &[16657]);     // 938 => [16657]
-            map.insert(876, &[65809]);     // 876 => [65809]
-            map.insert(486, &[4625]);     // 486 => [4625]
-            map.insert(753, &[8721]);     // 753 => [8721]
-            map.insert(332, &[132113]);     // 332 => [132113]
-            map.insert(622, &[525329]);     // 622 => [525329]
-            map.insert(742, &[6161]);     // 742 => [6161]
-            map.insert(683, &[264209]);     // 683 => [264209]
-            map.insert(774, &[12305]);     // 774 => [12305]
-            map.insert(92, &[266257]);     // 92 => [266257]
-            map.insert(629, &[528401]);     // 629 => [528401]
-            map.insert(843, &[270353]);     // 843 => [270353]
-            map.insert(354, &[532497]);     // 354 => [532497]
-            map.insert(467, &[294929]);     // 467 => [294929]
-            map.insert(605, &[196625]);     // 605 => [196625]
-            map.insert(854, &[327697]);     // 854 => [327697]
-            map.insert(282, &[393233]);     // 282 => [393233]
-            map.insert(142, &[353]);     // 142 => [353]
-            map.insert(526, &[2145]);     // 526 => [2145]
-            map.insert(821, &[16481]);     // 821 => [16481]
-            map.insert(924, &[65697]);     // 924 => [65697]
-            map.insert(464, &[131233]);     // 464 => [131233]
-            map.insert(673, &[2337]);     // 673 => [2337]
-            map.insert(833, &[8481]);     // 833 => [8481]
-            map.insert(473, &[33057]);     // 473 => [33057]
-            map.insert(860, &[65825]);     // 860 => [65825]
-            map.insert(461, &[1569]);     // 461 => [1569]
-            map.insert(470, &[4641]);     // 470 => [4641]
-            map.insert(705, &[8737]);     // 705 => [8737]
-            map.insert(732, &[66081]);     // 732 => [66081]
-            map.insert(717, &[3105]);     // 717 => [3105]
-            map.insert(813, &[9249]);     // 813 => [9249]
-            map.insert(606, &[525345]);     // 606 => [525345]
-            map.insert(476, &[67617]);     // 476 => [67617]
-            map.insert(430, &[36897]);     // 430 => [36897]
-            map.insert(624, &[139297]);     // 624 => [139297]
-            map.insert(610, &[49185]);     // 610 => [49185]
-            map.insert(676, &[98337]);     // 676 => [98337]
-            map.insert(870, &[327713]);     // 870 => [327713]
-            map.insert(910, &[8385]);     // 910 => [8385]
-            map.insert(853, &[16577]);     // 853 => [16577]
-            map.insert(278, &[32961]);     // 278 => [32961]
-            map.insert(479, &[131265]);     // 479 => [131265]
-            map.insert(686, &[2369]);     // 686 => [2369]
-            map.insert(917, &[16705]);     // 917 => [16705]
-            map.insert(287, &[131393]);     // 287 => [131393]
-            map.insert(573, &[524609]);     // 573 => [524609]
-            map.insert(718, &[8769]);     // 718 => [8769]
-            map.insert(729, &[6209]);     // 729 => [6209]
-            map.insert(462, &[10305]);     // 462 => [10305]
-            map.insert(927, &[133185]);     // 927 => [133185]
-            map.insert(804, &[69697]);     // 804 => [69697]
-            map.insert(943, &[278593]);     // 943 => [278593]
-            map.insert(90, &[5249]);     // 90 => [5249]
-            map.insert(845, &[9345]);     // 845 => [9345]
-            map.insert(469, &[33921]);     // 469 => [33921]
-            map.insert(848, &[66689]);     // 848 => [66689]
-            map.insert(284, &[132225]);     // 284 => [132225]
-            map.insert(763, &[264321]);     // 763 => [264321]
-            map.insert(909, &[20609]);     // 909 => [20609]
-            map.insert(715, &[147585]);     // 715 => [147585]
-            map.insert(708, &[98433]);     // 708 => [98433]
-            map.insert(330, &[393345]);     // 330 => [393345]
-            map.insert(616, &[786561]);     // 616 => [786561]
-            map.insert(907, &[69889]);     // 907 => [69889]
-            map.insert(680, &[786689]);     // 680 => [786689]
-            map.insert(633, &[35329]);     // 633 => [35329]
-            map.insert(474, &[25089]);     // 474 => [25089]
-            map.insert(921, &[41473]);     // 921 => [41473]
-            map.insert(603, &[270849]);     // 603 => [270849]
-            map.insert(456, &[164353]);     // 456 => [164353]
-            map.insert(746, &[557569]);     // 746 => [557569]
-            map.insert(599, &[265217]);     // 599 => [265217]
-            map.insert(951, &[271361]);     // 951 => [271361]
-            map.insert(540, &[75777]);     // 540 => [75777]
-            map.insert(711, &[83969]);     // 711 => [83969]
-            map.insert(712, &[165889]);     // 712 => [165889]
-            map.insert(490, &[559105]);     // 490 => [559105]
-            map.insert(879, &[591873]);     // 879 => [591873]
-            map.insert(679, &[143361]);     // 679 => [143361]
-            map.insert(887, &[282625]);     // 887 => [282625]
-            map.insert(627, &[102401]);     // 627 => [102401]
-            map.insert(735, &[790529]);     // 735 => [790529]
-            map.insert(356, &[106497]);     // 356 => [106497]
-            map.insert(423, &[131094]);     // 423 => [131094]
-            map.insert(934, &[8326]);     // 934 => [8326]
-            map.insert(725, &[524422]);     // 725 => [524422]
-            map.insert(311, &[131334]);     // 311 => [131334]
-            map.insert(903, &[278534]);     // 903 => [278534]
-            map.insert(452, &[294918]);     // 452 => [294918]
-            map.insert(840, &[270354]);     // 840 => [270354]
-            map.insert(619, &[786562]);     // 619 => [786562]
-            map.insert(543, &[75778]);     // 543 => [75778]
-            map.insert(567, &[16908]);     // 567 => [16908]
-            map.insert(564, &[2100]);     // 564 => [2100]
-            map.insert(422, &[131095]);     // 422 => [131095]
-            map.insert(935, &[8327]);     // 935 => [8327]
-            map.insert(724, &[524423]);     // 724 => [524423]
-            map.insert(310, &[131335]);     // 310 => [131335]
-            map.insert(902, &[278535]);     // 902 => [278535]
-            map.insert(453, &[294919]);     // 453 => [294919]
-            map.insert(841, &[270355]);     // 841 => [270355]
-            map.insert(618, &[786563]);     // 618 => [786563]
-            map.insert(542, &[75779]);     // 542 => [75779]
-            map.insert(566, &[16909]);     // 566 => [16909]
-            map.insert(565, &[2101]);     // 565 => [2101]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 741441 ],
+            &[ 806978 ],
+            &[ 70724 ],
+            &[ 377928 ],
+            &[ 1036304 ],
+            &[ 521312 ],
+            &[ 111744 ],
+            &[ 996608 ],
+            &[ 254464 ],
+            &[ 616448 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 20)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(1024, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(47, &[64]);     // 47 => [64]
+        map.insert(64, &[128]);     // 64 => [128]
+        map.insert(128, &[256]);     // 128 => [256]
+        map.insert(256, &[512]);     // 256 => [512]
+        map.insert(236, &[1024]);     // 236 => [1024]
+        map.insert(512, &[2048]);     // 512 => [2048]
+        map.insert(247, &[4096]);     // 247 => [4096]
+        map.insert(992, &[8192]);     // 992 => [8192]
+        map.insert(827, &[16384]);     // 827 => [16384]
+        map.insert(376, &[32768]);     // 376 => [32768]
+        map.insert(1021, &[65536]);     // 1021 => [65536]
+        map.insert(433, &[131072]);     // 433 => [131072]
+        map.insert(186, &[262144]);     // 186 => [262144]
+        map.insert(659, &[524288]);     // 659 => [524288]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(46, &[65]);     // 46 => [65]
+        map.insert(65, &[129]);     // 65 => [129]
+        map.insert(129, &[257]);     // 129 => [257]
+        map.insert(257, &[513]);     // 257 => [513]
+        map.insert(237, &[1025]);     // 237 => [1025]
+        map.insert(513, &[2049]);     // 513 => [2049]
+        map.insert(246, &[4097]);     // 246 => [4097]
+        map.insert(993, &[8193]);     // 993 => [8193]
+        map.insert(826, &[16385]);     // 826 => [16385]
+        map.insert(377, &[32769]);     // 377 => [32769]
+        map.insert(1020, &[65537]);     // 1020 => [65537]
+        map.insert(432, &[131073]);     // 432 => [131073]
+        map.insert(187, &[262145]);     // 187 => [262145]
+        map.insert(658, &[524289]);     // 658 => [524289]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(45, &[66]);     // 45 => [66]
+        map.insert(66, &[130]);     // 66 => [130]
+        map.insert(130, &[258]);     // 130 => [258]
+        map.insert(258, &[514]);     // 258 => [514]
+        map.insert(238, &[1026]);     // 238 => [1026]
+        map.insert(514, &[2050]);     // 514 => [2050]
+        map.insert(245, &[4098]);     // 245 => [4098]
+        map.insert(994, &[8194]);     // 994 => [8194]
+        map.insert(825, &[16386]);     // 825 => [16386]
+        map.insert(378, &[32770]);     // 378 => [32770]
+        map.insert(1023, &[65538]);     // 1023 => [65538]
+        map.insert(435, &[131074]);     // 435 => [131074]
+        map.insert(184, &[262146]);     // 184 => [262146]
+        map.insert(657, &[524290]);     // 657 => [524290]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(43, &[68]);     // 43 => [68]
+        map.insert(68, &[132]);     // 68 => [132]
+        map.insert(132, &[260]);     // 132 => [260]
+        map.insert(260, &[516]);     // 260 => [516]
+        map.insert(232, &[1028]);     // 232 => [1028]
+        map.insert(516, &[2052]);     // 516 => [2052]
+        map.insert(243, &[4100]);     // 243 => [4100]
+        map.insert(996, &[8196]);     // 996 => [8196]
+        map.insert(831, &[16388]);     // 831 => [16388]
+        map.insert(380, &[32772]);     // 380 => [32772]
+        map.insert(1017, &[65540]);     // 1017 => [65540]
+        map.insert(437, &[131076]);     // 437 => [131076]
+        map.insert(190, &[262148]);     // 190 => [262148]
+        map.insert(663, &[524292]);     // 663 => [524292]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(39, &[72]);     // 39 => [72]
+        map.insert(72, &[136]);     // 72 => [136]
+        map.insert(136, &[264]);     // 136 => [264]
+        map.insert(264, &[520]);     // 264 => [520]
+        map.insert(228, &[1032]);     // 228 => [1032]
+        map.insert(520, &[2056]);     // 520 => [2056]
+        map.insert(255, &[4104]);     // 255 => [4104]
+        map.insert(1000, &[8200]);     // 1000 => [8200]
+        map.insert(819, &[16392]);     // 819 => [16392]
+        map.insert(368, &[32776]);     // 368 => [32776]
+        map.insert(1013, &[65544]);     // 1013 => [65544]
+        map.insert(441, &[131080]);     // 441 => [131080]
+        map.insert(178, &[262152]);     // 178 => [262152]
+        map.insert(667, &[524296]);     // 667 => [524296]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(63, &[80]);     // 63 => [80]
+        map.insert(80, &[144]);     // 80 => [144]
+        map.insert(144, &[272]);     // 144 => [272]
+        map.insert(272, &[528]);     // 272 => [528]
+        map.insert(252, &[1040]);     // 252 => [1040]
+        map.insert(528, &[2064]);     // 528 => [2064]
+        map.insert(231, &[4112]);     // 231 => [4112]
+        map.insert(1008, &[8208]);     // 1008 => [8208]
+        map.insert(811, &[16400]);     // 811 => [16400]
+        map.insert(360, &[32784]);     // 360 => [32784]
+        map.insert(1005, &[65552]);     // 1005 => [65552]
+        map.insert(417, &[131088]);     // 417 => [131088]
+        map.insert(170, &[262160]);     // 170 => [262160]
+        map.insert(643, &[524304]);     // 643 => [524304]
+        map.insert(15, &[96]);     // 15 => [96]
+        map.insert(96, &[160]);     // 96 => [160]
+        map.insert(160, &[288]);     // 160 => [288]
+        map.insert(288, &[544]);     // 288 => [544]
+        map.insert(204, &[1056]);     // 204 => [1056]
+        map.insert(544, &[2080]);     // 544 => [2080]
+        map.insert(215, &[4128]);     // 215 => [4128]
+        map.insert(960, &[8224]);     // 960 => [8224]
+        map.insert(795, &[16416]);     // 795 => [16416]
+        map.insert(344, &[32800]);     // 344 => [32800]
+        map.insert(989, &[65568]);     // 989 => [65568]
+        map.insert(401, &[131104]);     // 401 => [131104]
+        map.insert(154, &[262176]);     // 154 => [262176]
+        map.insert(691, &[524320]);     // 691 => [524320]
+        map.insert(111, &[192]);     // 111 => [192]
+        map.insert(175, &[320]);     // 175 => [320]
+        map.insert(303, &[576]);     // 303 => [576]
+        map.insert(195, &[1088]);     // 195 => [1088]
+        map.insert(559, &[2112]);     // 559 => [2112]
+        map.insert(216, &[4160]);     // 216 => [4160]
+        map.insert(975, &[8256]);     // 975 => [8256]
+        map.insert(788, &[16448]);     // 788 => [16448]
+        map.insert(343, &[32832]);     // 343 => [32832]
+        map.insert(978, &[65600]);     // 978 => [65600]
+        map.insert(414, &[131136]);     // 414 => [131136]
+        map.insert(149, &[262208]);     // 149 => [262208]
+        map.insert(700, &[524352]);     // 700 => [524352]
+        map.insert(192, &[384]);     // 192 => [384]
+        map.insert(320, &[640]);     // 320 => [640]
+        map.insert(172, &[1152]);     // 172 => [1152]
+        map.insert(576, &[2176]);     // 576 => [2176]
+        map.insert(183, &[4224]);     // 183 => [4224]
+        map.insert(928, &[8320]);     // 928 => [8320]
+        map.insert(891, &[16512]);     // 891 => [16512]
+        map.insert(312, &[32896]);     // 312 => [32896]
+        map.insert(957, &[65664]);     // 957 => [65664]
+        map.insert(497, &[131200]);     // 497 => [131200]
+        map.insert(250, &[262272]);     // 250 => [262272]
+        map.insert(723, &[524416]);     // 723 => [524416]
+        map.insert(384, &[768]);     // 384 => [768]
+        map.insert(108, &[1280]);     // 108 => [1280]
+        map.insert(640, &[2304]);     // 640 => [2304]
+        map.insert(119, &[4352]);     // 119 => [4352]
+        map.insert(864, &[8448]);     // 864 => [8448]
+        map.insert(955, &[16640]);     // 955 => [16640]
+        map.insert(504, &[33024]);     // 504 => [33024]
+        map.insert(893, &[65792]);     // 893 => [65792]
+        map.insert(305, &[131328]);     // 305 => [131328]
+        map.insert(58, &[262400]);     // 58 => [262400]
+        map.insert(531, &[524544]);     // 531 => [524544]
+        map.insert(492, &[1536]);     // 492 => [1536]
+        map.insert(768, &[2560]);     // 768 => [2560]
+        map.insert(503, &[4608]);     // 503 => [4608]
+        map.insert(736, &[8704]);     // 736 => [8704]
+        map.insert(571, &[16896]);     // 571 => [16896]
+        map.insert(120, &[33280]);     // 120 => [33280]
+        map.insert(765, &[66048]);     // 765 => [66048]
+        map.insert(177, &[131584]);     // 177 => [131584]
+        map.insert(442, &[262656]);     // 442 => [262656]
+        map.insert(915, &[524800]);     // 915 => [524800]
+        map.insert(748, &[3072]);     // 748 => [3072]
+        map.insert(27, &[5120]);     // 27 => [5120]
+        map.insert(780, &[9216]);     // 780 => [9216]
+        map.insert(983, &[17408]);     // 983 => [17408]
+        map.insert(404, &[33792]);     // 404 => [33792]
+        map.insert(785, &[66560]);     // 785 => [66560]
+        map.insert(349, &[132096]);     // 349 => [132096]
+        map.insert(86, &[263168]);     // 86 => [263168]
+        map.insert(639, &[525312]);     // 639 => [525312]
+        map.insert(759, &[6144]);     // 759 => [6144]
+        map.insert(480, &[10240]);     // 480 => [10240]
+        map.insert(315, &[18432]);     // 315 => [18432]
+        map.insert(888, &[34816]);     // 888 => [34816]
+        map.insert(509, &[67584]);     // 509 => [67584]
+        map.insert(945, &[133120]);     // 945 => [133120]
+        map.insert(698, &[264192]);     // 698 => [264192]
+        map.insert(147, &[526336]);     // 147 => [526336]
+        map.insert(791, &[12288]);     // 791 => [12288]
+        map.insert(972, &[20480]);     // 972 => [20480]
+        map.insert(399, &[36864]);     // 399 => [36864]
+        map.insert(778, &[69632]);     // 778 => [69632]
+        map.insert(326, &[135168]);     // 326 => [135168]
+        map.insert(77, &[266240]);     // 77 => [266240]
+        map.insert(612, &[528384]);     // 612 => [528384]
+        map.insert(219, &[24576]);     // 219 => [24576]
+        map.insert(664, &[40960]);     // 664 => [40960]
+        map.insert(29, &[73728]);     // 29 => [73728]
+        map.insert(593, &[139264]);     // 593 => [139264]
+        map.insert(858, &[270336]);     // 858 => [270336]
+        map.insert(371, &[532480]);     // 371 => [532480]
+        map.insert(579, &[49152]);     // 579 => [49152]
+        map.insert(198, &[81920]);     // 198 => [81920]
+        map.insert(650, &[147456]);     // 650 => [147456]
+        map.insert(897, &[278528]);     // 897 => [278528]
+        map.insert(424, &[540672]);     // 424 => [540672]
+        map.insert(645, &[98304]);     // 645 => [98304]
+        map.insert(201, &[163840]);     // 201 => [163840]
+        map.insert(450, &[294912]);     // 450 => [294912]
+        map.insert(1003, &[557056]);     // 1003 => [557056]
+        map.insert(588, &[196608]);     // 588 => [196608]
+        map.insert(839, &[327680]);     // 839 => [327680]
+        map.insert(366, &[589824]);     // 366 => [589824]
+        map.insert(267, &[393216]);     // 267 => [393216]
+        map.insert(802, &[655360]);     // 802 => [655360]
+        map.insert(553, &[786432]);     // 553 => [786432]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(19, &[19]);     // 19 => [19]
+        map.insert(35, &[35]);     // 35 => [35]
+        map.insert(44, &[67]);     // 44 => [67]
+        map.insert(67, &[131]);     // 67 => [131]
+        map.insert(131, &[259]);     // 131 => [259]
+        map.insert(259, &[515]);     // 259 => [515]
+        map.insert(239, &[1027]);     // 239 => [1027]
+        map.insert(515, &[2051]);     // 515 => [2051]
+        map.insert(244, &[4099]);     // 244 => [4099]
+        map.insert(995, &[8195]);     // 995 => [8195]
+        map.insert(824, &[16387]);     // 824 => [16387]
+        map.insert(379, &[32771]);     // 379 => [32771]
+        map.insert(1022, &[65539]);     // 1022 => [65539]
+        map.insert(434, &[131075]);     // 434 => [131075]
+        map.insert(185, &[262147]);     // 185 => [262147]
+        map.insert(656, &[524291]);     // 656 => [524291]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(42, &[69]);     // 42 => [69]
+        map.insert(69, &[133]);     // 69 => [133]
+        map.insert(133, &[261]);     // 133 => [261]
+        map.insert(261, &[517]);     // 261 => [517]
+        map.insert(233, &[1029]);     // 233 => [1029]
+        map.insert(517, &[2053]);     // 517 => [2053]
+        map.insert(242, &[4101]);     // 242 => [4101]
+        map.insert(997, &[8197]);     // 997 => [8197]
+        map.insert(830, &[16389]);     // 830 => [16389]
+        map.insert(381, &[32773]);     // 381 => [32773]
+        map.insert(1016, &[65541]);     // 1016 => [65541]
+        map.insert(436, &[131077]);     // 436 => [131077]
+        map.insert(191, &[262149]);     // 191 => [262149]
+        map.insert(662, &[524293]);     // 662 => [524293]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(41, &[41]);     // 41 => [41]
+        map.insert(38, &[73]);     // 38 => [73]
+        map.insert(73, &[137]);     // 73 => [137]
+        map.insert(137, &[265]);     // 137 => [265]
+        map.insert(265, &[521]);     // 265 => [521]
+        map.insert(229, &[1033]);     // 229 => [1033]
+        map.insert(521, &[2057]);     // 521 => [2057]
+        map.insert(254, &[4105]);     // 254 => [4105]
+        map.insert(1001, &[8201]);     // 1001 => [8201]
+        map.insert(818, &[16393]);     // 818 => [16393]
+        map.insert(369, &[32777]);     // 369 => [32777]
+        map.insert(1012, &[65545]);     // 1012 => [65545]
+        map.insert(440, &[131081]);     // 440 => [131081]
+        map.insert(179, &[262153]);     // 179 => [262153]
+        map.insert(666, &[524297]);     // 666 => [524297]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(62, &[81]);     // 62 => [81]
+        map.insert(81, &[145]);     // 81 => [145]
+        map.insert(145, &[273]);     // 145 => [273]
+        map.insert(273, &[529]);     // 273 => [529]
+        map.insert(253, &[1041]);     // 253 => [1041]
+        map.insert(529, &[2065]);     // 529 => [2065]
+        map.insert(230, &[4113]);     // 230 => [4113]
+        map.insert(1009, &[8209]);     // 1009 => [8209]
+        map.insert(810, &[16401]);     // 810 => [16401]
+        map.insert(361, &[32785]);     // 361 => [32785]
+        map.insert(1004, &[65553]);     // 1004 => [65553]
+        map.insert(416, &[131089]);     // 416 => [131089]
+        map.insert(171, &[262161]);     // 171 => [262161]
+        map.insert(642, &[524305]);     // 642 => [524305]
+        map.insert(14, &[97]);     // 14 => [97]
+        map.insert(97, &[161]);     // 97 => [161]
+        map.insert(161, &[289]);     // 161 => [289]
+        map.insert(289, &[545]);     // 289 => [545]
+        map.insert(205, &[1057]);     // 205 => [1057]
+        map.insert(545, &[2081]);     // 545 => [2081]
+        map.insert(214, &[4129]);     // 214 => [4129]
+        map.insert(961, &[8225]);     // 961 => [8225]
+        map.insert(794, &[16417]);     // 794 => [16417]
+        map.insert(345, &[32801]);     // 345 => [32801]
+        map.insert(988, &[65569]);     // 988 => [65569]
+        map.insert(400, &[131105]);     // 400 => [131105]
+        map.insert(155, &[262177]);     // 155 => [262177]
+        map.insert(690, &[524321]);     // 690 => [524321]
+        map.insert(110, &[193]);     // 110 => [193]
+        map.insert(174, &[321]);     // 174 => [321]
+        map.insert(302, &[577]);     // 302 => [577]
+        map.insert(194, &[1089]);     // 194 => [1089]
+        map.insert(558, &[2113]);     // 558 => [2113]
+        map.insert(217, &[4161]);     // 217 => [4161]
+        map.insert(974, &[8257]);     // 974 => [8257]
+        map.insert(789, &[16449]);     // 789 => [16449]
+        map.insert(342, &[32833]);     // 342 => [32833]
+        map.insert(979, &[65601]);     // 979 => [65601]
+        map.insert(415, &[131137]);     // 415 => [131137]
+        map.insert(148, &[262209]);     // 148 => [262209]
+        map.insert(701, &[524353]);     // 701 => [524353]
+        map.insert(193, &[385]);     // 193 => [385]
+        map.insert(321, &[641]);     // 321 => [641]
+        map.insert(173, &[1153]);     // 173 => [1153]
+        map.insert(577, &[2177]);     // 577 => [2177]
+        map.insert(182, &[4225]);     // 182 => [4225]
+        map.insert(929, &[8321]);     // 929 => [8321]
+        map.insert(890, &[16513]);     // 890 => [16513]
+        map.insert(313, &[32897]);     // 313 => [32897]
+        map.insert(956, &[65665]);     // 956 => [65665]
+        map.insert(496, &[131201]);     // 496 => [131201]
+        map.insert(251, &[262273]);     // 251 => [262273]
+        map.insert(722, &[524417]);     // 722 => [524417]
+        map.insert(385, &[769]);     // 385 => [769]
+        map.insert(109, &[1281]);     // 109 => [1281]
+        map.insert(641, &[2305]);     // 641 => [2305]
+        map.insert(118, &[4353]);     // 118 => [4353]
+        map.insert(865, &[8449]);     // 865 => [8449]
+        map.insert(954, &[16641]);     // 954 => [16641]
+        map.insert(505, &[33025]);     // 505 => [33025]
+        map.insert(892, &[65793]);     // 892 => [65793]
+        map.insert(304, &[131329]);     // 304 => [131329]
+        map.insert(59, &[262401]);     // 59 => [262401]
+        map.insert(530, &[524545]);     // 530 => [524545]
+        map.insert(493, &[1537]);     // 493 => [1537]
+        map.insert(769, &[2561]);     // 769 => [2561]
+        map.insert(502, &[4609]);     // 502 => [4609]
+        map.insert(737, &[8705]);     // 737 => [8705]
+        map.insert(570, &[16897]);     // 570 => [16897]
+        map.insert(121, &[33281]);     // 121 => [33281]
+        map.insert(764, &[66049]);     // 764 => [66049]
+        map.insert(176, &[131585]);     // 176 => [131585]
+        map.insert(443, &[262657]);     // 443 => [262657]
+        map.insert(914, &[524801]);     // 914 => [524801]
+        map.insert(749, &[3073]);     // 749 => [3073]
+        map.insert(26, &[5121]);     // 26 => [5121]
+        map.insert(781, &[9217]);     // 781 => [9217]
+        map.insert(982, &[17409]);     // 982 => [17409]
+        map.insert(405, &[33793]);     // 405 => [33793]
+        map.insert(784, &[66561]);     // 784 => [66561]
+        map.insert(348, &[132097]);     // 348 => [132097]
+        map.insert(87, &[263169]);     // 87 => [263169]
+        map.insert(638, &[525313]);     // 638 => [525313]
+        map.insert(758, &[6145]);     // 758 => [6145]
+        map.insert(481, &[10241]);     // 481 => [10241]
+        map.insert(314, &[18433]);     // 314 => [18433]
+        map.insert(889, &[34817]);     // 889 => [34817]
+        map.insert(508, &[67585]);     // 508 => [67585]
+        map.insert(944, &[133121]);     // 944 => [133121]
+        map.insert(699, &[264193]);     // 699 => [264193]
+        map.insert(146, &[526337]);     // 146 => [526337]
+        map.insert(790, &[12289]);     // 790 => [12289]
+        map.insert(973, &[20481]);     // 973 => [20481]
+        map.insert(398, &[36865]);     // 398 => [36865]
+        map.insert(779, &[69633]);     // 779 => [69633]
+        map.insert(327, &[135169]);     // 327 => [135169]
+        map.insert(76, &[266241]);     // 76 => [266241]
+        map.insert(613, &[528385]);     // 613 => [528385]
+        map.insert(218, &[24577]);     // 218 => [24577]
+        map.insert(665, &[40961]);     // 665 => [40961]
+        map.insert(28, &[73729]);     // 28 => [73729]
+        map.insert(592, &[139265]);     // 592 => [139265]
+        map.insert(859, &[270337]);     // 859 => [270337]
+        map.insert(370, &[532481]);     // 370 => [532481]
+        map.insert(578, &[49153]);     // 578 => [49153]
+        map.insert(199, &[81921]);     // 199 => [81921]
+        map.insert(651, &[147457]);     // 651 => [147457]
+        map.insert(896, &[278529]);     // 896 => [278529]
+        map.insert(425, &[540673]);     // 425 => [540673]
+        map.insert(644, &[98305]);     // 644 => [98305]
+        map.insert(200, &[163841]);     // 200 => [163841]
+        map.insert(451, &[294913]);     // 451 => [294913]
+        map.insert(1002, &[557057]);     // 1002 => [557057]
+        map.insert(589, &[196609]);     // 589 => [196609]
+        map.insert(838, &[327681]);     // 838 => [327681]
+        map.insert(367, &[589825]);     // 367 => [589825]
+        map.insert(266, &[393217]);     // 266 => [393217]
+        map.insert(803, &[655361]);     // 803 => [655361]
+        map.insert(552, &[786433]);     // 552 => [786433]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(70, &[134]);     // 70 => [134]
+        map.insert(134, &[262]);     // 134 => [262]
+        map.insert(262, &[518]);     // 262 => [518]
+        map.insert(234, &[1030]);     // 234 => [1030]
+        map.insert(518, &[2054]);     // 518 => [2054]
+        map.insert(241, &[4102]);     // 241 => [4102]
+        map.insert(998, &[8198]);     // 998 => [8198]
+        map.insert(829, &[16390]);     // 829 => [16390]
+        map.insert(382, &[32774]);     // 382 => [32774]
+        map.insert(1019, &[65542]);     // 1019 => [65542]
+        map.insert(439, &[131078]);     // 439 => [131078]
+        map.insert(188, &[262150]);     // 188 => [262150]
+        map.insert(661, &[524294]);     // 661 => [524294]
+        map.insert(74, &[138]);     // 74 => [138]
+        map.insert(138, &[266]);     // 138 => [266]
+        map.insert(522, &[2058]);     // 522 => [2058]
+        map.insert(817, &[16394]);     // 817 => [16394]
+        map.insert(1015, &[65546]);     // 1015 => [65546]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(61, &[82]);     // 61 => [82]
+        map.insert(82, &[146]);     // 82 => [146]
+        map.insert(274, &[530]);     // 274 => [530]
+        map.insert(1010, &[8210]);     // 1010 => [8210]
+        map.insert(809, &[16402]);     // 809 => [16402]
+        map.insert(362, &[32786]);     // 362 => [32786]
+        map.insert(1007, &[65554]);     // 1007 => [65554]
+        map.insert(419, &[131090]);     // 419 => [131090]
+        map.insert(168, &[262162]);     // 168 => [262162]
+        map.insert(98, &[162]);     // 98 => [162]
+        map.insert(162, &[290]);     // 162 => [290]
+        map.insert(290, &[546]);     // 290 => [546]
+        map.insert(206, &[1058]);     // 206 => [1058]
+        map.insert(546, &[2082]);     // 546 => [2082]
+        map.insert(213, &[4130]);     // 213 => [4130]
+        map.insert(962, &[8226]);     // 962 => [8226]
+        map.insert(793, &[16418]);     // 793 => [16418]
+        map.insert(346, &[32802]);     // 346 => [32802]
+        map.insert(991, &[65570]);     // 991 => [65570]
+        map.insert(403, &[131106]);     // 403 => [131106]
+        map.insert(152, &[262178]);     // 152 => [262178]
+        map.insert(689, &[524322]);     // 689 => [524322]
+        map.insert(301, &[578]);     // 301 => [578]
+        map.insert(557, &[2114]);     // 557 => [2114]
+        map.insert(341, &[32834]);     // 341 => [32834]
+        map.insert(976, &[65602]);     // 976 => [65602]
+        map.insert(412, &[131138]);     // 412 => [131138]
+        map.insert(151, &[262210]);     // 151 => [262210]
+        map.insert(702, &[524354]);     // 702 => [524354]
+        map.insert(322, &[642]);     // 322 => [642]
+        map.insert(181, &[4226]);     // 181 => [4226]
+        map.insert(930, &[8322]);     // 930 => [8322]
+        map.insert(959, &[65666]);     // 959 => [65666]
+        map.insert(499, &[131202]);     // 499 => [131202]
+        map.insert(248, &[262274]);     // 248 => [262274]
+        map.insert(721, &[524418]);     // 721 => [524418]
+        map.insert(386, &[770]);     // 386 => [770]
+        map.insert(117, &[4354]);     // 117 => [4354]
+        map.insert(866, &[8450]);     // 866 => [8450]
+        map.insert(953, &[16642]);     // 953 => [16642]
+        map.insert(506, &[33026]);     // 506 => [33026]
+        map.insert(895, &[65794]);     // 895 => [65794]
+        map.insert(307, &[131330]);     // 307 => [131330]
+        map.insert(56, &[262402]);     // 56 => [262402]
+        map.insert(494, &[1538]);     // 494 => [1538]
+        map.insert(770, &[2562]);     // 770 => [2562]
+        map.insert(501, &[4610]);     // 501 => [4610]
+        map.insert(738, &[8706]);     // 738 => [8706]
+        map.insert(569, &[16898]);     // 569 => [16898]
+        map.insert(122, &[33282]);     // 122 => [33282]
+        map.insert(767, &[66050]);     // 767 => [66050]
+        map.insert(913, &[524802]);     // 913 => [524802]
+        map.insert(750, &[3074]);     // 750 => [3074]
+        map.insert(782, &[9218]);     // 782 => [9218]
+        map.insert(981, &[17410]);     // 981 => [17410]
+        map.insert(406, &[33794]);     // 406 => [33794]
+        map.insert(787, &[66562]);     // 787 => [66562]
+        map.insert(351, &[132098]);     // 351 => [132098]
+        map.insert(84, &[263170]);     // 84 => [263170]
+        map.insert(637, &[525314]);     // 637 => [525314]
+        map.insert(757, &[6146]);     // 757 => [6146]
+        map.insert(482, &[10242]);     // 482 => [10242]
+        map.insert(511, &[67586]);     // 511 => [67586]
+        map.insert(947, &[133122]);     // 947 => [133122]
+        map.insert(696, &[264194]);     // 696 => [264194]
+        map.insert(397, &[36866]);     // 397 => [36866]
+        map.insert(776, &[69634]);     // 776 => [69634]
+        map.insert(324, &[135170]);     // 324 => [135170]
+        map.insert(79, &[266242]);     // 79 => [266242]
+        map.insert(614, &[528386]);     // 614 => [528386]
+        map.insert(31, &[73730]);     // 31 => [73730]
+        map.insert(595, &[139266]);     // 595 => [139266]
+        map.insert(856, &[270338]);     // 856 => [270338]
+        map.insert(196, &[81922]);     // 196 => [81922]
+        map.insert(648, &[147458]);     // 648 => [147458]
+        map.insert(899, &[278530]);     // 899 => [278530]
+        map.insert(426, &[540674]);     // 426 => [540674]
+        map.insert(647, &[98306]);     // 647 => [98306]
+        map.insert(203, &[163842]);     // 203 => [163842]
+        map.insert(448, &[294914]);     // 448 => [294914]
+        map.insert(590, &[196610]);     // 590 => [196610]
+        map.insert(837, &[327682]);     // 837 => [327682]
+        map.insert(364, &[589826]);     // 364 => [589826]
+        map.insert(800, &[655362]);     // 800 => [655362]
+        map.insert(555, &[786434]);     // 555 => [786434]
+        map.insert(140, &[268]);     // 140 => [268]
+        map.insert(268, &[524]);     // 268 => [524]
+        map.insert(224, &[1036]);     // 224 => [1036]
+        map.insert(524, &[2060]);     // 524 => [2060]
+        map.insert(823, &[16396]);     // 823 => [16396]
+        map.insert(372, &[32780]);     // 372 => [32780]
+        map.insert(445, &[131084]);     // 445 => [131084]
+        map.insert(671, &[524300]);     // 671 => [524300]
+        map.insert(52, &[52]);     // 52 => [52]
+        map.insert(276, &[532]);     // 276 => [532]
+        map.insert(532, &[2068]);     // 532 => [2068]
+        map.insert(227, &[4116]);     // 227 => [4116]
+        map.insert(815, &[16404]);     // 815 => [16404]
+        map.insert(421, &[131092]);     // 421 => [131092]
+        map.insert(100, &[164]);     // 100 => [164]
+        map.insert(164, &[292]);     // 164 => [292]
+        map.insert(292, &[548]);     // 292 => [548]
+        map.insert(548, &[2084]);     // 548 => [2084]
+        map.insert(211, &[4132]);     // 211 => [4132]
+        map.insert(964, &[8228]);     // 964 => [8228]
+        map.insert(799, &[16420]);     // 799 => [16420]
+        map.insert(985, &[65572]);     // 985 => [65572]
+        map.insert(158, &[262180]);     // 158 => [262180]
+        map.insert(695, &[524324]);     // 695 => [524324]
+        map.insert(107, &[196]);     // 107 => [196]
+        map.insert(299, &[580]);     // 299 => [580]
+        map.insert(220, &[4164]);     // 220 => [4164]
+        map.insert(971, &[8260]);     // 971 => [8260]
+        map.insert(339, &[32836]);     // 339 => [32836]
+        map.insert(410, &[131140]);     // 410 => [131140]
+        map.insert(580, &[2180]);     // 580 => [2180]
+        map.insert(932, &[8324]);     // 932 => [8324]
+        map.insert(316, &[32900]);     // 316 => [32900]
+        map.insert(727, &[524420]);     // 727 => [524420]
+        map.insert(388, &[772]);     // 388 => [772]
+        map.insert(104, &[1284]);     // 104 => [1284]
+        map.insert(115, &[4356]);     // 115 => [4356]
+        map.insert(868, &[8452]);     // 868 => [8452]
+        map.insert(309, &[131332]);     // 309 => [131332]
+        map.insert(535, &[524548]);     // 535 => [524548]
+        map.insert(488, &[1540]);     // 488 => [1540]
+        map.insert(772, &[2564]);     // 772 => [2564]
+        map.insert(740, &[8708]);     // 740 => [8708]
+        map.insert(575, &[16900]);     // 575 => [16900]
+        map.insert(124, &[33284]);     // 124 => [33284]
+        map.insert(761, &[66052]);     // 761 => [66052]
+        map.insert(446, &[262660]);     // 446 => [262660]
+        map.insert(919, &[524804]);     // 919 => [524804]
+        map.insert(744, &[3076]);     // 744 => [3076]
+        map.insert(635, &[525316]);     // 635 => [525316]
+        map.insert(755, &[6148]);     // 755 => [6148]
+        map.insert(484, &[10244]);     // 484 => [10244]
+        map.insert(319, &[18436]);     // 319 => [18436]
+        map.insert(949, &[133124]);     // 949 => [133124]
+        map.insert(968, &[20484]);     // 968 => [20484]
+        map.insert(395, &[36868]);     // 395 => [36868]
+        map.insert(608, &[528388]);     // 608 => [528388]
+        map.insert(223, &[24580]);     // 223 => [24580]
+        map.insert(668, &[40964]);     // 668 => [40964]
+        map.insert(597, &[139268]);     // 597 => [139268]
+        map.insert(862, &[270340]);     // 862 => [270340]
+        map.insert(375, &[532484]);     // 375 => [532484]
+        map.insert(583, &[49156]);     // 583 => [49156]
+        map.insert(654, &[147460]);     // 654 => [147460]
+        map.insert(901, &[278532]);     // 901 => [278532]
+        map.insert(428, &[540676]);     // 428 => [540676]
+        map.insert(454, &[294916]);     // 454 => [294916]
+        map.insert(584, &[196612]);     // 584 => [196612]
+        map.insert(835, &[327684]);     // 835 => [327684]
+        map.insert(271, &[393220]);     // 271 => [393220]
+        map.insert(806, &[655364]);     // 806 => [655364]
+        map.insert(55, &[88]);     // 55 => [88]
+        map.insert(88, &[152]);     // 88 => [152]
+        map.insert(280, &[536]);     // 280 => [536]
+        map.insert(536, &[2072]);     // 536 => [2072]
+        map.insert(352, &[32792]);     // 352 => [32792]
+        map.insert(296, &[552]);     // 296 => [552]
+        map.insert(336, &[32808]);     // 336 => [32808]
+        map.insert(409, &[131112]);     // 409 => [131112]
+        map.insert(103, &[200]);     // 103 => [200]
+        map.insert(167, &[328]);     // 167 => [328]
+        map.insert(295, &[584]);     // 295 => [584]
+        map.insert(551, &[2120]);     // 551 => [2120]
+        map.insert(208, &[4168]);     // 208 => [4168]
+        map.insert(967, &[8264]);     // 967 => [8264]
+        map.insert(796, &[16456]);     // 796 => [16456]
+        map.insert(986, &[65608]);     // 986 => [65608]
+        map.insert(157, &[262216]);     // 157 => [262216]
+        map.insert(692, &[524360]);     // 692 => [524360]
+        map.insert(328, &[648]);     // 328 => [648]
+        map.insert(936, &[8328]);     // 936 => [8328]
+        map.insert(883, &[16520]);     // 883 => [16520]
+        map.insert(731, &[524424]);     // 731 => [524424]
+        map.insert(392, &[776]);     // 392 => [776]
+        map.insert(127, &[4360]);     // 127 => [4360]
+        map.insert(872, &[8456]);     // 872 => [8456]
+        map.insert(885, &[65800]);     // 885 => [65800]
+        map.insert(539, &[524552]);     // 539 => [524552]
+        map.insert(563, &[16904]);     // 563 => [16904]
+        map.insert(112, &[33288]);     // 112 => [33288]
+        map.insert(923, &[524808]);     // 923 => [524808]
+        map.insert(94, &[263176]);     // 94 => [263176]
+        map.insert(631, &[525320]);     // 631 => [525320]
+        map.insert(880, &[34824]);     // 880 => [34824]
+        map.insert(391, &[36872]);     // 391 => [36872]
+        map.insert(334, &[135176]);     // 334 => [135176]
+        map.insert(620, &[528392]);     // 620 => [528392]
+        map.insert(601, &[139272]);     // 601 => [139272]
+        map.insert(850, &[270344]);     // 850 => [270344]
+        map.insert(587, &[49160]);     // 587 => [49160]
+        map.insert(905, &[278536]);     // 905 => [278536]
+        map.insert(653, &[98312]);     // 653 => [98312]
+        map.insert(458, &[294920]);     // 458 => [294920]
+        map.insert(847, &[327688]);     // 847 => [327688]
+        map.insert(358, &[589832]);     // 358 => [589832]
+        map.insert(560, &[2096]);     // 560 => [2096]
+        map.insert(675, &[524336]);     // 675 => [524336]
+        map.insert(684, &[524368]);     // 684 => [524368]
+        map.insert(875, &[16528]);     // 875 => [16528]
+        map.insert(941, &[65680]);     // 941 => [65680]
+        map.insert(707, &[524432]);     // 707 => [524432]
+        map.insert(939, &[16656]);     // 939 => [16656]
+        map.insert(877, &[65808]);     // 877 => [65808]
+        map.insert(487, &[4624]);     // 487 => [4624]
+        map.insert(752, &[8720]);     // 752 => [8720]
+        map.insert(333, &[132112]);     // 333 => [132112]
+        map.insert(623, &[525328]);     // 623 => [525328]
+        map.insert(743, &[6160]);     // 743 => [6160]
+        map.insert(682, &[264208]);     // 682 => [264208]
+        map.insert(775, &[12304]);     // 775 => [12304]
+        map.insert(93, &[266256]);     // 93 => [266256]
+        map.insert(628, &[528400]);     // 628 => [528400]
+        map.insert(842, &[270352]);     // 842 => [270352]
+        map.insert(355, &[532496]);     // 355 => [532496]
+        map.insert(466, &[294928]);     // 466 => [294928]
+        map.insert(604, &[196624]);     // 604 => [196624]
+        map.insert(855, &[327696]);     // 855 => [327696]
+        map.insert(283, &[393232]);     // 283 => [393232]
+        map.insert(143, &[352]);     // 143 => [352]
+        map.insert(527, &[2144]);     // 527 => [2144]
+        map.insert(820, &[16480]);     // 820 => [16480]
+        map.insert(925, &[65696]);     // 925 => [65696]
+        map.insert(465, &[131232]);     // 465 => [131232]
+        map.insert(672, &[2336]);     // 672 => [2336]
+        map.insert(832, &[8480]);     // 832 => [8480]
+        map.insert(472, &[33056]);     // 472 => [33056]
+        map.insert(861, &[65824]);     // 861 => [65824]
+        map.insert(460, &[1568]);     // 460 => [1568]
+        map.insert(471, &[4640]);     // 471 => [4640]
+        map.insert(704, &[8736]);     // 704 => [8736]
+        map.insert(733, &[66080]);     // 733 => [66080]
+        map.insert(716, &[3104]);     // 716 => [3104]
+        map.insert(812, &[9248]);     // 812 => [9248]
+        map.insert(607, &[525344]);     // 607 => [525344]
+        map.insert(477, &[67616]);     // 477 => [67616]
+        map.insert(431, &[36896]);     // 431 => [36896]
+        map.insert(625, &[139296]);     // 625 => [139296]
+        map.insert(611, &[49184]);     // 611 => [49184]
+        map.insert(677, &[98336]);     // 677 => [98336]
+        map.insert(871, &[327712]);     // 871 => [327712]
+        map.insert(911, &[8384]);     // 911 => [8384]
+        map.insert(852, &[16576]);     // 852 => [16576]
+        map.insert(279, &[32960]);     // 279 => [32960]
+        map.insert(478, &[131264]);     // 478 => [131264]
+        map.insert(687, &[2368]);     // 687 => [2368]
+        map.insert(916, &[16704]);     // 916 => [16704]
+        map.insert(286, &[131392]);     // 286 => [131392]
+        map.insert(572, &[524608]);     // 572 => [524608]
+        map.insert(719, &[8768]);     // 719 => [8768]
+        map.insert(728, &[6208]);     // 728 => [6208]
+        map.insert(463, &[10304]);     // 463 => [10304]
+        map.insert(926, &[133184]);     // 926 => [133184]
+        map.insert(805, &[69696]);     // 805 => [69696]
+        map.insert(942, &[278592]);     // 942 => [278592]
+        map.insert(91, &[5248]);     // 91 => [5248]
+        map.insert(844, &[9344]);     // 844 => [9344]
+        map.insert(468, &[33920]);     // 468 => [33920]
+        map.insert(849, &[66688]);     // 849 => [66688]
+        map.insert(285, &[132224]);     // 285 => [132224]
+        map.insert(762, &[264320]);     // 762 => [264320]
+        map.insert(908, &[20608]);     // 908 => [20608]
+        map.insert(714, &[147584]);     // 714 => [147584]
+        map.insert(709, &[98432]);     // 709 => [98432]
+        map.insert(331, &[393344]);     // 331 => [393344]
+        map.insert(617, &[786560]);     // 617 => [786560]
+        map.insert(906, &[69888]);     // 906 => [69888]
+        map.insert(681, &[786688]);     // 681 => [786688]
+        map.insert(632, &[35328]);     // 632 => [35328]
+        map.insert(475, &[25088]);     // 475 => [25088]
+        map.insert(920, &[41472]);     // 920 => [41472]
+        map.insert(602, &[270848]);     // 602 => [270848]
+        map.insert(457, &[164352]);     // 457 => [164352]
+        map.insert(747, &[557568]);     // 747 => [557568]
+        map.insert(598, &[265216]);     // 598 => [265216]
+        map.insert(950, &[271360]);     // 950 => [271360]
+        map.insert(541, &[75776]);     // 541 => [75776]
+        map.insert(710, &[83968]);     // 710 => [83968]
+        map.insert(713, &[165888]);     // 713 => [165888]
+        map.insert(491, &[559104]);     // 491 => [559104]
+        map.insert(878, &[591872]);     // 878 => [591872]
+        map.insert(678, &[143360]);     // 678 => [143360]
+        map.insert(886, &[282624]);     // 886 => [282624]
+        map.insert(626, &[102400]);     // 626 => [102400]
+        map.insert(734, &[790528]);     // 734 => [790528]
+        map.insert(357, &[106496]);     // 357 => [106496]
+        map.insert(23, &[23]);     // 23 => [23]
+        map.insert(71, &[135]);     // 71 => [135]
+        map.insert(135, &[263]);     // 135 => [263]
+        map.insert(263, &[519]);     // 263 => [519]
+        map.insert(235, &[1031]);     // 235 => [1031]
+        map.insert(519, &[2055]);     // 519 => [2055]
+        map.insert(240, &[4103]);     // 240 => [4103]
+        map.insert(999, &[8199]);     // 999 => [8199]
+        map.insert(828, &[16391]);     // 828 => [16391]
+        map.insert(383, &[32775]);     // 383 => [32775]
+        map.insert(1018, &[65543]);     // 1018 => [65543]
+        map.insert(438, &[131079]);     // 438 => [131079]
+        map.insert(189, &[262151]);     // 189 => [262151]
+        map.insert(660, &[524295]);     // 660 => [524295]
+        map.insert(75, &[139]);     // 75 => [139]
+        map.insert(139, &[267]);     // 139 => [267]
+        map.insert(523, &[2059]);     // 523 => [2059]
+        map.insert(816, &[16395]);     // 816 => [16395]
+        map.insert(1014, &[65547]);     // 1014 => [65547]
+        map.insert(51, &[51]);     // 51 => [51]
+        map.insert(60, &[83]);     // 60 => [83]
+        map.insert(83, &[147]);     // 83 => [147]
+        map.insert(275, &[531]);     // 275 => [531]
+        map.insert(1011, &[8211]);     // 1011 => [8211]
+        map.insert(808, &[16403]);     // 808 => [16403]
+        map.insert(363, &[32787]);     // 363 => [32787]
+        map.insert(1006, &[65555]);     // 1006 => [65555]
+        map.insert(418, &[131091]);     // 418 => [131091]
+        map.insert(169, &[262163]);     // 169 => [262163]
+        map.insert(99, &[163]);     // 99 => [163]
+        map.insert(163, &[291]);     // 163 => [291]
+        map.insert(291, &[547]);     // 291 => [547]
+        map.insert(207, &[1059]);     // 207 => [1059]
+        map.insert(547, &[2083]);     // 547 => [2083]
+        map.insert(212, &[4131]);     // 212 => [4131]
+        map.insert(963, &[8227]);     // 963 => [8227]
+        map.insert(792, &[16419]);     // 792 => [16419]
+        map.insert(347, &[32803]);     // 347 => [32803]
+        map.insert(990, &[65571]);     // 990 => [65571]
+        map.insert(402, &[131107]);     // 402 => [131107]
+        map.insert(153, &[262179]);     // 153 => [262179]
+        map.insert(688, &[524323]);     // 688 => [524323]
+        map.insert(300, &[579]);     // 300 => [579]
+        map.insert(556, &[2115]);     // 556 => [2115]
+        map.insert(340, &[32835]);     // 340 => [32835]
+        map.insert(977, &[65603]);     // 977 => [65603]
+        map.insert(413, &[131139]);     // 413 => [131139]
+        map.insert(150, &[262211]);     // 150 => [262211]
+        map.insert(703, &[524355]);     // 703 => [524355]
+        map.insert(323, &[643]);     // 323 => [643]
+        map.insert(180, &[4227]);     // 180 => [4227]
+        map.insert(931, &[8323]);     // 931 => [8323]
+        map.insert(958, &[65667]);     // 958 => [65667]
+        map.insert(498, &[131203]);     // 498 => [131203]
+        map.insert(249, &[262275]);     // 249 => [262275]
+        map.insert(720, &[524419]);     // 720 => [524419]
+        map.insert(387, &[771]);     // 387 => [771]
+        map.insert(116, &[4355]);     // 116 => [4355]
+        map.insert(867, &[8451]);     // 867 => [8451]
+        map.insert(952, &[16643]);     // 952 => [16643]
+        map.insert(507, &[33027]);     // 507 => [33027]
+        map.insert(894, &[65795]);     // 894 => [65795]
+        map.insert(306, &[131331]);     // 306 => [131331]
+        map.insert(57, &[262403]);     // 57 => [262403]
+        map.insert(495, &[1539]);     // 495 => [1539]
+        map.insert(771, &[2563]);     // 771 => [2563]
+        map.insert(500, &[4611]);     // 500 => [4611]
+        map.insert(739, &[8707]);     // 739 => [8707]
+        map.insert(568, &[16899]);     // 568 => [16899]
+        map.insert(123, &[33283]);     // 123 => [33283]
+        map.insert(766, &[66051]);     // 766 => [66051]
+        map.insert(912, &[524803]);     // 912 => [524803]
+        map.insert(751, &[3075]);     // 751 => [3075]
+        map.insert(783, &[9219]);     // 783 => [9219]
+        map.insert(980, &[17411]);     // 980 => [17411]
+        map.insert(407, &[33795]);     // 407 => [33795]
+        map.insert(786, &[66563]);     // 786 => [66563]
+        map.insert(350, &[132099]);     // 350 => [132099]
+        map.insert(85, &[263171]);     // 85 => [263171]
+        map.insert(636, &[525315]);     // 636 => [525315]
+        map.insert(756, &[6147]);     // 756 => [6147]
+        map.insert(483, &[10243]);     // 483 => [10243]
+        map.insert(510, &[67587]);     // 510 => [67587]
+        map.insert(946, &[133123]);     // 946 => [133123]
+        map.insert(697, &[264195]);     // 697 => [264195]
+        map.insert(396, &[36867]);     // 396 => [36867]
+        map.insert(777, &[69635]);     // 777 => [69635]
+        map.insert(325, &[135171]);     // 325 => [135171]
+        map.insert(78, &[266243]);     // 78 => [266243]
+        map.insert(615, &[528387]);     // 615 => [528387]
+        map.insert(30, &[73731]);     // 30 => [73731]
+        map.insert(594, &[139267]);     // 594 => [139267]
+        map.insert(857, &[270339]);     // 857 => [270339]
+        map.insert(197, &[81923]);     // 197 => [81923]
+        map.insert(649, &[147459]);     // 649 => [147459]
+        map.insert(898, &[278531]);     // 898 => [278531]
+        map.insert(427, &[540675]);     // 427 => [540675]
+        map.insert(646, &[98307]);     // 646 => [98307]
+        map.insert(202, &[163843]);     // 202 => [163843]
+        map.insert(449, &[294915]);     // 449 => [294915]
+        map.insert(591, &[196611]);     // 591 => [196611]
+        map.insert(836, &[327683]);     // 836 => [327683]
+        map.insert(365, &[589827]);     // 365 => [589827]
+        map.insert(801, &[655363]);     // 801 => [655363]
+        map.insert(554, &[786435]);     // 554 => [786435]
+        map.insert(141, &[269]);     // 141 => [269]
+        map.insert(269, &[525]);     // 269 => [525]
+        map.insert(225, &[1037]);     // 225 => [1037]
+        map.insert(525, &[2061]);     // 525 => [2061]
+        map.insert(822, &[16397]);     // 822 => [16397]
+        map.insert(373, &[32781]);     // 373 => [32781]
+        map.insert(444, &[131085]);     // 444 => [131085]
+        map.insert(670, &[524301]);     // 670 => [524301]
+        map.insert(53, &[53]);     // 53 => [53]
+        map.insert(277, &[533]);     // 277 => [533]
+        map.insert(533, &[2069]);     // 533 => [2069]
+        map.insert(226, &[4117]);     // 226 => [4117]
+        map.insert(814, &[16405]);     // 814 => [16405]
+        map.insert(420, &[131093]);     // 420 => [131093]
+        map.insert(101, &[165]);     // 101 => [165]
+        map.insert(165, &[293]);     // 165 => [293]
+        map.insert(293, &[549]);     // 293 => [549]
+        map.insert(549, &[2085]);     // 549 => [2085]
+        map.insert(210, &[4133]);     // 210 => [4133]
+        map.insert(965, &[8229]);     // 965 => [8229]
+        map.insert(798, &[16421]);     // 798 => [16421]
+        map.insert(984, &[65573]);     // 984 => [65573]
+        map.insert(159, &[262181]);     // 159 => [262181]
+        map.insert(694, &[524325]);     // 694 => [524325]
+        map.insert(106, &[197]);     // 106 => [197]
+        map.insert(298, &[581]);     // 298 => [581]
+        map.insert(221, &[4165]);     // 221 => [4165]
+        map.insert(970, &[8261]);     // 970 => [8261]
+        map.insert(338, &[32837]);     // 338 => [32837]
+        map.insert(411, &[131141]);     // 411 => [131141]
+        map.insert(581, &[2181]);     // 581 => [2181]
+        map.insert(933, &[8325]);     // 933 => [8325]
+        map.insert(317, &[32901]);     // 317 => [32901]
+        map.insert(726, &[524421]);     // 726 => [524421]
+        map.insert(389, &[773]);     // 389 => [773]
+        map.insert(105, &[1285]);     // 105 => [1285]
+        map.insert(114, &[4357]);     // 114 => [4357]
+        map.insert(869, &[8453]);     // 869 => [8453]
+        map.insert(308, &[131333]);     // 308 => [131333]
+        map.insert(534, &[524549]);     // 534 => [524549]
+        map.insert(489, &[1541]);     // 489 => [1541]
+        map.insert(773, &[2565]);     // 773 => [2565]
+        map.insert(741, &[8709]);     // 741 => [8709]
+        map.insert(574, &[16901]);     // 574 => [16901]
+        map.insert(125, &[33285]);     // 125 => [33285]
+        map.insert(760, &[66053]);     // 760 => [66053]
+        map.insert(447, &[262661]);     // 447 => [262661]
+        map.insert(918, &[524805]);     // 918 => [524805]
+        map.insert(745, &[3077]);     // 745 => [3077]
+        map.insert(634, &[525317]);     // 634 => [525317]
+        map.insert(754, &[6149]);     // 754 => [6149]
+        map.insert(485, &[10245]);     // 485 => [10245]
+        map.insert(318, &[18437]);     // 318 => [18437]
+        map.insert(948, &[133125]);     // 948 => [133125]
+        map.insert(969, &[20485]);     // 969 => [20485]
+        map.insert(394, &[36869]);     // 394 => [36869]
+        map.insert(609, &[528389]);     // 609 => [528389]
+        map.insert(222, &[24581]);     // 222 => [24581]
+        map.insert(669, &[40965]);     // 669 => [40965]
+        map.insert(596, &[139269]);     // 596 => [139269]
+        map.insert(863, &[270341]);     // 863 => [270341]
+        map.insert(374, &[532485]);     // 374 => [532485]
+        map.insert(582, &[49157]);     // 582 => [49157]
+        map.insert(655, &[147461]);     // 655 => [147461]
+        map.insert(900, &[278533]);     // 900 => [278533]
+        map.insert(429, &[540677]);     // 429 => [540677]
+        map.insert(455, &[294917]);     // 455 => [294917]
+        map.insert(585, &[196613]);     // 585 => [196613]
+        map.insert(834, &[327685]);     // 834 => [327685]
+        map.insert(270, &[393221]);     // 270 => [393221]
+        map.insert(807, &[655365]);     // 807 => [655365]
+        map.insert(54, &[89]);     // 54 => [89]
+        map.insert(89, &[153]);     // 89 => [153]
+        map.insert(281, &[537]);     // 281 => [537]
+        map.insert(537, &[2073]);     // 537 => [2073]
+        map.insert(353, &[32793]);     // 353 => [32793]
+        map.insert(297, &[553]);     // 297 => [553]
+        map.insert(337, &[32809]);     // 337 => [32809]
+        map.insert(408, &[131113]);     // 408 => [131113]
+        map.insert(102, &[201]);     // 102 => [201]
+        map.insert(166, &[329]);     // 166 => [329]
+        map.insert(294, &[585]);     // 294 => [585]
+        map.insert(550, &[2121]);     // 550 => [2121]
+        map.insert(209, &[4169]);     // 209 => [4169]
+        map.insert(966, &[8265]);     // 966 => [8265]
+        map.insert(797, &[16457]);     // 797 => [16457]
+        map.insert(987, &[65609]);     // 987 => [65609]
+        map.insert(156, &[262217]);     // 156 => [262217]
+        map.insert(693, &[524361]);     // 693 => [524361]
+        map.insert(329, &[649]);     // 329 => [649]
+        map.insert(937, &[8329]);     // 937 => [8329]
+        map.insert(882, &[16521]);     // 882 => [16521]
+        map.insert(730, &[524425]);     // 730 => [524425]
+        map.insert(393, &[777]);     // 393 => [777]
+        map.insert(126, &[4361]);     // 126 => [4361]
+        map.insert(873, &[8457]);     // 873 => [8457]
+        map.insert(884, &[65801]);     // 884 => [65801]
+        map.insert(538, &[524553]);     // 538 => [524553]
+        map.insert(562, &[16905]);     // 562 => [16905]
+        map.insert(113, &[33289]);     // 113 => [33289]
+        map.insert(922, &[524809]);     // 922 => [524809]
+        map.insert(95, &[263177]);     // 95 => [263177]
+        map.insert(630, &[525321]);     // 630 => [525321]
+        map.insert(881, &[34825]);     // 881 => [34825]
+        map.insert(390, &[36873]);     // 390 => [36873]
+        map.insert(335, &[135177]);     // 335 => [135177]
+        map.insert(621, &[528393]);     // 621 => [528393]
+        map.insert(600, &[139273]);     // 600 => [139273]
+        map.insert(851, &[270345]);     // 851 => [270345]
+        map.insert(586, &[49161]);     // 586 => [49161]
+        map.insert(904, &[278537]);     // 904 => [278537]
+        map.insert(652, &[98313]);     // 652 => [98313]
+        map.insert(459, &[294921]);     // 459 => [294921]
+        map.insert(846, &[327689]);     // 846 => [327689]
+        map.insert(359, &[589833]);     // 359 => [589833]
+        map.insert(561, &[2097]);     // 561 => [2097]
+        map.insert(674, &[524337]);     // 674 => [524337]
+        map.insert(685, &[524369]);     // 685 => [524369]
+        map.insert(874, &[16529]);     // 874 => [16529]
+        map.insert(940, &[65681]);     // 940 => [65681]
+        map.insert(706, &[524433]);     // 706 => [524433]
+        map.insert(938, &[16657]);     // 938 => [16657]
+        map.insert(876, &[65809]);     // 876 => [65809]
+        map.insert(486, &[4625]);     // 486 => [4625]
+        map.insert(753, &[8721]);     // 753 => [8721]
+        map.insert(332, &[132113]);     // 332 => [132113]
+        map.insert(622, &[525329]);     // 622 => [525329]
+        map.insert(742, &[6161]);     // 742 => [6161]
+        map.insert(683, &[264209]);     // 683 => [264209]
+        map.insert(774, &[12305]);     // 774 => [12305]
+        map.insert(92, &[266257]);     // 92 => [266257]
+        map.insert(629, &[528401]);     // 629 => [528401]
+        map.insert(843, &[270353]);     // 843 => [270353]
+        map.insert(354, &[532497]);     // 354 => [532497]
+        map.insert(467, &[294929]);     // 467 => [294929]
+        map.insert(605, &[196625]);     // 605 => [196625]
+        map.insert(854, &[327697]);     // 854 => [327697]
+        map.insert(282, &[393233]);     // 282 => [393233]
+        map.insert(142, &[353]);     // 142 => [353]
+        map.insert(526, &[2145]);     // 526 => [2145]
+        map.insert(821, &[16481]);     // 821 => [16481]
+        map.insert(924, &[65697]);     // 924 => [65697]
+        map.insert(464, &[131233]);     // 464 => [131233]
+        map.insert(673, &[2337]);     // 673 => [2337]
+        map.insert(833, &[8481]);     // 833 => [8481]
+        map.insert(473, &[33057]);     // 473 => [33057]
+        map.insert(860, &[65825]);     // 860 => [65825]
+        map.insert(461, &[1569]);     // 461 => [1569]
+        map.insert(470, &[4641]);     // 470 => [4641]
+        map.insert(705, &[8737]);     // 705 => [8737]
+        map.insert(732, &[66081]);     // 732 => [66081]
+        map.insert(717, &[3105]);     // 717 => [3105]
+        map.insert(813, &[9249]);     // 813 => [9249]
+        map.insert(606, &[525345]);     // 606 => [525345]
+        map.insert(476, &[67617]);     // 476 => [67617]
+        map.insert(430, &[36897]);     // 430 => [36897]
+        map.insert(624, &[139297]);     // 624 => [139297]
+        map.insert(610, &[49185]);     // 610 => [49185]
+        map.insert(676, &[98337]);     // 676 => [98337]
+        map.insert(870, &[327713]);     // 870 => [327713]
+        map.insert(910, &[8385]);     // 910 => [8385]
+        map.insert(853, &[16577]);     // 853 => [16577]
+        map.insert(278, &[32961]);     // 278 => [32961]
+        map.insert(479, &[131265]);     // 479 => [131265]
+        map.insert(686, &[2369]);     // 686 => [2369]
+        map.insert(917, &[16705]);     // 917 => [16705]
+        map.insert(287, &[131393]);     // 287 => [131393]
+        map.insert(573, &[524609]);     // 573 => [524609]
+        map.insert(718, &[8769]);     // 718 => [8769]
+        map.insert(729, &[6209]);     // 729 => [6209]
+        map.insert(462, &[10305]);     // 462 => [10305]
+        map.insert(927, &[133185]);     // 927 => [133185]
+        map.insert(804, &[69697]);     // 804 => [69697]
+        map.insert(943, &[278593]);     // 943 => [278593]
+        map.insert(90, &[5249]);     // 90 => [5249]
+        map.insert(845, &[9345]);     // 845 => [9345]
+        map.insert(469, &[33921]);     // 469 => [33921]
+        map.insert(848, &[66689]);     // 848 => [66689]
+        map.insert(284, &[132225]);     // 284 => [132225]
+        map.insert(763, &[264321]);     // 763 => [264321]
+        map.insert(909, &[20609]);     // 909 => [20609]
+        map.insert(715, &[147585]);     // 715 => [147585]
+        map.insert(708, &[98433]);     // 708 => [98433]
+        map.insert(330, &[393345]);     // 330 => [393345]
+        map.insert(616, &[786561]);     // 616 => [786561]
+        map.insert(907, &[69889]);     // 907 => [69889]
+        map.insert(680, &[786689]);     // 680 => [786689]
+        map.insert(633, &[35329]);     // 633 => [35329]
+        map.insert(474, &[25089]);     // 474 => [25089]
+        map.insert(921, &[41473]);     // 921 => [41473]
+        map.insert(603, &[270849]);     // 603 => [270849]
+        map.insert(456, &[164353]);     // 456 => [164353]
+        map.insert(746, &[557569]);     // 746 => [557569]
+        map.insert(599, &[265217]);     // 599 => [265217]
+        map.insert(951, &[271361]);     // 951 => [271361]
+        map.insert(540, &[75777]);     // 540 => [75777]
+        map.insert(711, &[83969]);     // 711 => [83969]
+        map.insert(712, &[165889]);     // 712 => [165889]
+        map.insert(490, &[559105]);     // 490 => [559105]
+        map.insert(879, &[591873]);     // 879 => [591873]
+        map.insert(679, &[143361]);     // 679 => [143361]
+        map.insert(887, &[282625]);     // 887 => [282625]
+        map.insert(627, &[102401]);     // 627 => [102401]
+        map.insert(735, &[790529]);     // 735 => [790529]
+        map.insert(356, &[106497]);     // 356 => [106497]
+        map.insert(423, &[131094]);     // 423 => [131094]
+        map.insert(934, &[8326]);     // 934 => [8326]
+        map.insert(725, &[524422]);     // 725 => [524422]
+        map.insert(311, &[131334]);     // 311 => [131334]
+        map.insert(903, &[278534]);     // 903 => [278534]
+        map.insert(452, &[294918]);     // 452 => [294918]
+        map.insert(840, &[270354]);     // 840 => [270354]
+        map.insert(619, &[786562]);     // 619 => [786562]
+        map.insert(543, &[75778]);     // 543 => [75778]
+        map.insert(567, &[16908]);     // 567 => [16908]
+        map.insert(564, &[2100]);     // 564 => [2100]
+        map.insert(422, &[131095]);     // 422 => [131095]
+        map.insert(935, &[8327]);     // 935 => [8327]
+        map.insert(724, &[524423]);     // 724 => [524423]
+        map.insert(310, &[131335]);     // 310 => [131335]
+        map.insert(902, &[278535]);     // 902 => [278535]
+        map.insert(453, &[294919]);     // 453 => [294919]
+        map.insert(841, &[270355]);     // 841 => [270355]
+        map.insert(618, &[786563]);     // 618 => [786563]
+        map.insert(542, &[75779]);     // 542 => [75779]
+        map.insert(566, &[16909]);     // 566 => [16909]
+        map.insert(565, &[2101]);     // 565 => [2101]
+        map
+    })
 }
 
 impl GuavaCode20_10 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -1112,24 +1112,15 @@ impl BinaryCode for GuavaCode20_10 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(20);
@@ -1154,14 +1145,26 @@ impl BinaryCode for GuavaCode20_10 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        5
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(20, 10).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[20 / 64] & !((1 << 20) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -1356,8 +1359,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, true, true, false, false, false, true, true, true, false ]);
         assert_eq!(vector, first_row.as_vector());
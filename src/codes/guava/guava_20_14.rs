@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,124 +16,125 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode20_14;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 409601 ],
-                &[ 671746 ],
-                &[ 802820 ],
-                &[ 1015816 ],
-                &[ 229392 ],
-                &[ 360480 ],
-                &[ 622656 ],
-                &[ 426112 ],
-                &[ 688384 ],
-                &[ 819712 ],
-                &[ 459776 ],
-                &[ 722944 ],
-                &[ 856064 ],
-                &[ 925696 ],
-                
-            ], 20));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 416561 ],
-                &[ 772434 ],
-                &[ 905828 ],
-                &[ 925816 ],
-                &[ 959360 ],
-                &[ 998400 ],
-                
-            ], 20));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(11, &[16]);     // 11 => [16]
-            map.insert(13, &[32]);     // 13 => [32]
-            map.insert(14, &[64]);     // 14 => [64]
-            map.insert(16, &[128]);     // 16 => [128]
-            map.insert(19, &[256]);     // 19 => [256]
-            map.insert(21, &[512]);     // 21 => [512]
-            map.insert(32, &[1024]);     // 32 => [1024]
-            map.insert(35, &[2048]);     // 35 => [2048]
-            map.insert(37, &[4096]);     // 37 => [4096]
-            map.insert(56, &[8192]);     // 56 => [8192]
-            map.insert(7, &[16384]);     // 7 => [16384]
-            map.insert(22, &[32768]);     // 22 => [32768]
-            map.insert(38, &[65536]);     // 38 => [65536]
-            map.insert(59, &[131072]);     // 59 => [131072]
-            map.insert(61, &[262144]);     // 61 => [262144]
-            map.insert(62, &[524288]);     // 62 => [524288]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(10, &[17]);     // 10 => [17]
-            map.insert(12, &[33]);     // 12 => [33]
-            map.insert(15, &[65]);     // 15 => [65]
-            map.insert(17, &[129]);     // 17 => [129]
-            map.insert(18, &[257]);     // 18 => [257]
-            map.insert(20, &[513]);     // 20 => [513]
-            map.insert(33, &[1025]);     // 33 => [1025]
-            map.insert(34, &[2049]);     // 34 => [2049]
-            map.insert(36, &[4097]);     // 36 => [4097]
-            map.insert(57, &[8193]);     // 57 => [8193]
-            map.insert(6, &[16385]);     // 6 => [16385]
-            map.insert(23, &[32769]);     // 23 => [32769]
-            map.insert(39, &[65537]);     // 39 => [65537]
-            map.insert(58, &[131073]);     // 58 => [131073]
-            map.insert(60, &[262145]);     // 60 => [262145]
-            map.insert(63, &[524289]);     // 63 => [524289]
-            map.insert(24, &[136]);     // 24 => [136]
-            map.insert(27, &[264]);     // 27 => [264]
-            map.insert(29, &[520]);     // 29 => [520]
-            map.insert(40, &[1032]);     // 40 => [1032]
-            map.insert(43, &[2056]);     // 43 => [2056]
-            map.insert(45, &[4104]);     // 45 => [4104]
-            map.insert(48, &[8200]);     // 48 => [8200]
-            map.insert(30, &[32776]);     // 30 => [32776]
-            map.insert(46, &[65544]);     // 46 => [65544]
-            map.insert(51, &[131080]);     // 51 => [131080]
-            map.insert(53, &[262152]);     // 53 => [262152]
-            map.insert(54, &[524296]);     // 54 => [524296]
-            map.insert(25, &[137]);     // 25 => [137]
-            map.insert(26, &[265]);     // 26 => [265]
-            map.insert(28, &[521]);     // 28 => [521]
-            map.insert(41, &[1033]);     // 41 => [1033]
-            map.insert(42, &[2057]);     // 42 => [2057]
-            map.insert(44, &[4105]);     // 44 => [4105]
-            map.insert(49, &[8201]);     // 49 => [8201]
-            map.insert(31, &[32777]);     // 31 => [32777]
-            map.insert(47, &[65545]);     // 47 => [65545]
-            map.insert(50, &[131081]);     // 50 => [131081]
-            map.insert(52, &[262153]);     // 52 => [262153]
-            map.insert(55, &[524297]);     // 55 => [524297]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 409601 ],
+            &[ 671746 ],
+            &[ 802820 ],
+            &[ 1015816 ],
+            &[ 229392 ],
+            &[ 360480 ],
+            &[ 622656 ],
+            &[ 426112 ],
+            &[ 688384 ],
+            &[ 819712 ],
+            &[ 459776 ],
+            &[ 722944 ],
+            &[ 856064 ],
+            &[ 925696 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 20)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 416561 ],
+            &[ 772434 ],
+            &[ 905828 ],
+            &[ 925816 ],
+            &[ 959360 ],
+            &[ 998400 ],
+            
+        ], 20)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(11, &[16]);     // 11 => [16]
+        map.insert(13, &[32]);     // 13 => [32]
+        map.insert(14, &[64]);     // 14 => [64]
+        map.insert(16, &[128]);     // 16 => [128]
+        map.insert(19, &[256]);     // 19 => [256]
+        map.insert(21, &[512]);     // 21 => [512]
+        map.insert(32, &[1024]);     // 32 => [1024]
+        map.insert(35, &[2048]);     // 35 => [2048]
+        map.insert(37, &[4096]);     // 37 => [4096]
+        map.insert(56, &[8192]);     // 56 => [8192]
+        map.insert(7, &[16384]);     // 7 => [16384]
+        map.insert(22, &[32768]);     // 22 => [32768]
+        map.insert(38, &[65536]);     // 38 => [65536]
+        map.insert(59, &[131072]);     // 59 => [131072]
+        map.insert(61, &[262144]);     // 61 => [262144]
+        map.insert(62, &[524288]);     // 62 => [524288]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(10, &[17]);     // 10 => [17]
+        map.insert(12, &[33]);     // 12 => [33]
+        map.insert(15, &[65]);     // 15 => [65]
+        map.insert(17, &[129]);     // 17 => [129]
+        map.insert(18, &[257]);     // 18 => [257]
+        map.insert(20, &[513]);     // 20 => [513]
+        map.insert(33, &[1025]);     // 33 => [1025]
+        map.insert(34, &[2049]);     // 34 => [2049]
+        map.insert(36, &[4097]);     // 36 => [4097]
+        map.insert(57, &[8193]);     // 57 => [8193]
+        map.insert(6, &[16385]);     // 6 => [16385]
+        map.insert(23, &[32769]);     // 23 => [32769]
+        map.insert(39, &[65537]);     // 39 => [65537]
+        map.insert(58, &[131073]);     // 58 => [131073]
+        map.insert(60, &[262145]);     // 60 => [262145]
+        map.insert(63, &[524289]);     // 63 => [524289]
+        map.insert(24, &[136]);     // 24 => [136]
+        map.insert(27, &[264]);     // 27 => [264]
+        map.insert(29, &[520]);     // 29 => [520]
+        map.insert(40, &[1032]);     // 40 => [1032]
+        map.insert(43, &[2056]);     // 43 => [2056]
+        map.insert(45, &[4104]);     // 45 => [4104]
+        map.insert(48, &[8200]);     // 48 => [8200]
+        map.insert(30, &[32776]);     // 30 => [32776]
+        map.insert(46, &[65544]);     // 46 => [65544]
+        map.insert(51, &[131080]);     // 51 => [131080]
+        map.insert(53, &[262152]);     // 53 => [262152]
+        map.insert(54, &[524296]);     // 54 => [524296]
+        map.insert(25, &[137]);     // 25 => [137]
+        map.insert(26, &[265]);     // 26 => [265]
+        map.insert(28, &[521]);     // 28 => [521]
+        map.insert(41, &[1033]);     // 41 => [1033]
+        map.insert(42, &[2057]);     // 42 => [2057]
+        map.insert(44, &[4105]);     // 44 => [4105]
+        map.insert(49, &[8201]);     // 49 => [8201]
+        map.insert(31, &[32777]);     // 31 => [32777]
+        map.insert(47, &[65545]);     // 47 => [65545]
+        map.insert(50, &[131081]);     // 50 => [131081]
+        map.insert(52, &[262153]);     // 52 => [262153]
+        map.insert(55, &[524297]);     // 55 => [524297]
+        map
+    })
 }
 
 impl GuavaCode20_14 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -152,24 +152,15 @@ impl BinaryCode for GuavaCode20_14 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(20);
@@ -194,14 +185,26 @@ impl BinaryCode for GuavaCode20_14 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(20, 14).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[20 / 64] & !((1 << 20) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -396,8 +399,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, true, true, false ]);
         assert_eq!(vector, first_row.as_vector());
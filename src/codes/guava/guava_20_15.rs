@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,92 +16,93 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode20_15;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 98305 ],
-                &[ 884738 ],
-                &[ 753668 ],
-                &[ 491528 ],
-                &[ 1015824 ],
-                &[ 229408 ],
-                &[ 360512 ],
-                &[ 622720 ],
-                &[ 426240 ],
-                &[ 688640 ],
-                &[ 820224 ],
-                &[ 460800 ],
-                &[ 724992 ],
-                &[ 860160 ],
-                &[ 933888 ],
-                
-            ], 20));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 609897 ],
-                &[ 758442 ],
-                &[ 894156 ],
-                &[ 934128 ],
-                &[ 114432 ],
-                
-            ], 20));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(7, &[8]);     // 7 => [8]
-            map.insert(8, &[16]);     // 8 => [16]
-            map.insert(11, &[32]);     // 11 => [32]
-            map.insert(13, &[64]);     // 13 => [64]
-            map.insert(14, &[128]);     // 14 => [128]
-            map.insert(16, &[256]);     // 16 => [256]
-            map.insert(19, &[512]);     // 19 => [512]
-            map.insert(21, &[1024]);     // 21 => [1024]
-            map.insert(17, &[2048]);     // 17 => [2048]
-            map.insert(18, &[4096]);     // 18 => [4096]
-            map.insert(20, &[8192]);     // 20 => [8192]
-            map.insert(9, &[16384]);     // 9 => [16384]
-            map.insert(22, &[32768]);     // 22 => [32768]
-            map.insert(23, &[65536]);     // 23 => [65536]
-            map.insert(10, &[131072]);     // 10 => [131072]
-            map.insert(12, &[262144]);     // 12 => [262144]
-            map.insert(15, &[524288]);     // 15 => [524288]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[9]);     // 6 => [9]
-            map.insert(24, &[272]);     // 24 => [272]
-            map.insert(27, &[528]);     // 27 => [528]
-            map.insert(29, &[1040]);     // 29 => [1040]
-            map.insert(25, &[2064]);     // 25 => [2064]
-            map.insert(26, &[4112]);     // 26 => [4112]
-            map.insert(28, &[8208]);     // 28 => [8208]
-            map.insert(30, &[32784]);     // 30 => [32784]
-            map.insert(31, &[65552]);     // 31 => [65552]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 98305 ],
+            &[ 884738 ],
+            &[ 753668 ],
+            &[ 491528 ],
+            &[ 1015824 ],
+            &[ 229408 ],
+            &[ 360512 ],
+            &[ 622720 ],
+            &[ 426240 ],
+            &[ 688640 ],
+            &[ 820224 ],
+            &[ 460800 ],
+            &[ 724992 ],
+            &[ 860160 ],
+            &[ 933888 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 20)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 609897 ],
+            &[ 758442 ],
+            &[ 894156 ],
+            &[ 934128 ],
+            &[ 114432 ],
+            
+        ], 20)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(7, &[8]);     // 7 => [8]
+        map.insert(8, &[16]);     // 8 => [16]
+        map.insert(11, &[32]);     // 11 => [32]
+        map.insert(13, &[64]);     // 13 => [64]
+        map.insert(14, &[128]);     // 14 => [128]
+        map.insert(16, &[256]);     // 16 => [256]
+        map.insert(19, &[512]);     // 19 => [512]
+        map.insert(21, &[1024]);     // 21 => [1024]
+        map.insert(17, &[2048]);     // 17 => [2048]
+        map.insert(18, &[4096]);     // 18 => [4096]
+        map.insert(20, &[8192]);     // 20 => [8192]
+        map.insert(9, &[16384]);     // 9 => [16384]
+        map.insert(22, &[32768]);     // 22 => [32768]
+        map.insert(23, &[65536]);     // 23 => [65536]
+        map.insert(10, &[131072]);     // 10 => [131072]
+        map.insert(12, &[262144]);     // 12 => [262144]
+        map.insert(15, &[524288]);     // 15 => [524288]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[9]);     // 6 => [9]
+        map.insert(24, &[272]);     // 24 => [272]
+        map.insert(27, &[528]);     // 27 => [528]
+        map.insert(29, &[1040]);     // 29 => [1040]
+        map.insert(25, &[2064]);     // 25 => [2064]
+        map.insert(26, &[4112]);     // 26 => [4112]
+        map.insert(28, &[8208]);     // 28 => [8208]
+        map.insert(30, &[32784]);     // 30 => [32784]
+        map.insert(31, &[65552]);     // 31 => [65552]
+        map
+    })
 }
 
 impl GuavaCode20_15 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -120,24 +120,15 @@ impl BinaryCode for GuavaCode20_15 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(20);
@@ -162,14 +153,26 @@ impl BinaryCode for GuavaCode20_15 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        2
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(20, 15).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[20 / 64] & !((1 << 20) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -364,8 +367,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
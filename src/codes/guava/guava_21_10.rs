@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,2109 +16,2110 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode21_10;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1387521 ],
-                &[ 756738 ],
-                &[ 1494020 ],
-                &[ 1939464 ],
-                &[ 1781776 ],
-                &[ 504864 ],
-                &[ 1009728 ],
-                &[ 970880 ],
-                &[ 1864960 ],
-                &[ 1632768 ],
-                
-            ], 21));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1527809 ],
-                &[ 651266 ],
-                &[ 353284 ],
-                &[ 929800 ],
-                &[ 713744 ],
-                &[ 504864 ],
-                &[ 1884224 ],
-                &[ 2032768 ],
-                &[ 1864960 ],
-                &[ 1742336 ],
-                &[ 1288192 ],
-                
-            ], 21));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1387521 ],
+            &[ 756738 ],
+            &[ 1494020 ],
+            &[ 1939464 ],
+            &[ 1781776 ],
+            &[ 504864 ],
+            &[ 1009728 ],
+            &[ 970880 ],
+            &[ 1864960 ],
+            &[ 1632768 ],
+            
+        ], 21)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(2048, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(64, &[64]);     // 64 => [64]
-            map.insert(128, &[128]);     // 128 => [128]
-            map.insert(256, &[256]);     // 256 => [256]
-            map.insert(512, &[512]);     // 512 => [512]
-            map.insert(948, &[1024]);     // 948 => [1024]
-            map.insert(1024, &[2048]);     // 1024 => [2048]
-            map.insert(811, &[4096]);     // 811 => [4096]
-            map.insert(1342, &[8192]);     // 1342 => [8192]
-            map.insert(343, &[16384]);     // 343 => [16384]
-            map.insert(1650, &[32768]);     // 1650 => [32768]
-            map.insert(1191, &[65536]);     // 1191 => [65536]
-            map.insert(1721, &[131072]);     // 1721 => [131072]
-            map.insert(493, &[262144]);     // 493 => [262144]
-            map.insert(986, &[524288]);     // 986 => [524288]
-            map.insert(1985, &[1048576]);     // 1985 => [1048576]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(65, &[65]);     // 65 => [65]
-            map.insert(129, &[129]);     // 129 => [129]
-            map.insert(257, &[257]);     // 257 => [257]
-            map.insert(513, &[513]);     // 513 => [513]
-            map.insert(949, &[1025]);     // 949 => [1025]
-            map.insert(1025, &[2049]);     // 1025 => [2049]
-            map.insert(810, &[4097]);     // 810 => [4097]
-            map.insert(1343, &[8193]);     // 1343 => [8193]
-            map.insert(342, &[16385]);     // 342 => [16385]
-            map.insert(1651, &[32769]);     // 1651 => [32769]
-            map.insert(1190, &[65537]);     // 1190 => [65537]
-            map.insert(1720, &[131073]);     // 1720 => [131073]
-            map.insert(492, &[262145]);     // 492 => [262145]
-            map.insert(987, &[524289]);     // 987 => [524289]
-            map.insert(1984, &[1048577]);     // 1984 => [1048577]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(66, &[66]);     // 66 => [66]
-            map.insert(130, &[130]);     // 130 => [130]
-            map.insert(258, &[258]);     // 258 => [258]
-            map.insert(514, &[514]);     // 514 => [514]
-            map.insert(950, &[1026]);     // 950 => [1026]
-            map.insert(1026, &[2050]);     // 1026 => [2050]
-            map.insert(809, &[4098]);     // 809 => [4098]
-            map.insert(1340, &[8194]);     // 1340 => [8194]
-            map.insert(341, &[16386]);     // 341 => [16386]
-            map.insert(1648, &[32770]);     // 1648 => [32770]
-            map.insert(1189, &[65538]);     // 1189 => [65538]
-            map.insert(1723, &[131074]);     // 1723 => [131074]
-            map.insert(495, &[262146]);     // 495 => [262146]
-            map.insert(984, &[524290]);     // 984 => [524290]
-            map.insert(1987, &[1048578]);     // 1987 => [1048578]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(68, &[68]);     // 68 => [68]
-            map.insert(132, &[132]);     // 132 => [132]
-            map.insert(260, &[260]);     // 260 => [260]
-            map.insert(516, &[516]);     // 516 => [516]
-            map.insert(944, &[1028]);     // 944 => [1028]
-            map.insert(1028, &[2052]);     // 1028 => [2052]
-            map.insert(815, &[4100]);     // 815 => [4100]
-            map.insert(1338, &[8196]);     // 1338 => [8196]
-            map.insert(339, &[16388]);     // 339 => [16388]
-            map.insert(1654, &[32772]);     // 1654 => [32772]
-            map.insert(1187, &[65540]);     // 1187 => [65540]
-            map.insert(1725, &[131076]);     // 1725 => [131076]
-            map.insert(489, &[262148]);     // 489 => [262148]
-            map.insert(990, &[524292]);     // 990 => [524292]
-            map.insert(1989, &[1048580]);     // 1989 => [1048580]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(72, &[72]);     // 72 => [72]
-            map.insert(136, &[136]);     // 136 => [136]
-            map.insert(264, &[264]);     // 264 => [264]
-            map.insert(520, &[520]);     // 520 => [520]
-            map.insert(956, &[1032]);     // 956 => [1032]
-            map.insert(1032, &[2056]);     // 1032 => [2056]
-            map.insert(803, &[4104]);     // 803 => [4104]
-            map.insert(1334, &[8200]);     // 1334 => [8200]
-            map.insert(351, &[16392]);     // 351 => [16392]
-            map.insert(1658, &[32776]);     // 1658 => [32776]
-            map.insert(1199, &[65544]);     // 1199 => [65544]
-            map.insert(1713, &[131080]);     // 1713 => [131080]
-            map.insert(485, &[262152]);     // 485 => [262152]
-            map.insert(978, &[524296]);     // 978 => [524296]
-            map.insert(1993, &[1048584]);     // 1993 => [1048584]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(80, &[80]);     // 80 => [80]
-            map.insert(144, &[144]);     // 144 => [144]
-            map.insert(272, &[272]);     // 272 => [272]
-            map.insert(528, &[528]);     // 528 => [528]
-            map.insert(932, &[1040]);     // 932 => [1040]
-            map.insert(1040, &[2064]);     // 1040 => [2064]
-            map.insert(827, &[4112]);     // 827 => [4112]
-            map.insert(1326, &[8208]);     // 1326 => [8208]
-            map.insert(327, &[16400]);     // 327 => [16400]
-            map.insert(1634, &[32784]);     // 1634 => [32784]
-            map.insert(1207, &[65552]);     // 1207 => [65552]
-            map.insert(1705, &[131088]);     // 1705 => [131088]
-            map.insert(509, &[262160]);     // 509 => [262160]
-            map.insert(970, &[524304]);     // 970 => [524304]
-            map.insert(2001, &[1048592]);     // 2001 => [1048592]
-            map.insert(96, &[96]);     // 96 => [96]
-            map.insert(160, &[160]);     // 160 => [160]
-            map.insert(288, &[288]);     // 288 => [288]
-            map.insert(544, &[544]);     // 544 => [544]
-            map.insert(916, &[1056]);     // 916 => [1056]
-            map.insert(1056, &[2080]);     // 1056 => [2080]
-            map.insert(779, &[4128]);     // 779 => [4128]
-            map.insert(1310, &[8224]);     // 1310 => [8224]
-            map.insert(375, &[16416]);     // 375 => [16416]
-            map.insert(1618, &[32800]);     // 1618 => [32800]
-            map.insert(1159, &[65568]);     // 1159 => [65568]
-            map.insert(1689, &[131104]);     // 1689 => [131104]
-            map.insert(461, &[262176]);     // 461 => [262176]
-            map.insert(1018, &[524320]);     // 1018 => [524320]
-            map.insert(2017, &[1048608]);     // 2017 => [1048608]
-            map.insert(192, &[192]);     // 192 => [192]
-            map.insert(320, &[320]);     // 320 => [320]
-            map.insert(576, &[576]);     // 576 => [576]
-            map.insert(1012, &[1088]);     // 1012 => [1088]
-            map.insert(1088, &[2112]);     // 1088 => [2112]
-            map.insert(875, &[4160]);     // 875 => [4160]
-            map.insert(1406, &[8256]);     // 1406 => [8256]
-            map.insert(279, &[16448]);     // 279 => [16448]
-            map.insert(1586, &[32832]);     // 1586 => [32832]
-            map.insert(1255, &[65600]);     // 1255 => [65600]
-            map.insert(1785, &[131136]);     // 1785 => [131136]
-            map.insert(429, &[262208]);     // 429 => [262208]
-            map.insert(922, &[524352]);     // 922 => [524352]
-            map.insert(1921, &[1048640]);     // 1921 => [1048640]
-            map.insert(384, &[384]);     // 384 => [384]
-            map.insert(640, &[640]);     // 640 => [640]
-            map.insert(820, &[1152]);     // 820 => [1152]
-            map.insert(1152, &[2176]);     // 1152 => [2176]
-            map.insert(939, &[4224]);     // 939 => [4224]
-            map.insert(1470, &[8320]);     // 1470 => [8320]
-            map.insert(471, &[16512]);     // 471 => [16512]
-            map.insert(1778, &[32896]);     // 1778 => [32896]
-            map.insert(1063, &[65664]);     // 1063 => [65664]
-            map.insert(1593, &[131200]);     // 1593 => [131200]
-            map.insert(365, &[262272]);     // 365 => [262272]
-            map.insert(858, &[524416]);     // 858 => [524416]
-            map.insert(1857, &[1048704]);     // 1857 => [1048704]
-            map.insert(768, &[768]);     // 768 => [768]
-            map.insert(692, &[1280]);     // 692 => [1280]
-            map.insert(1280, &[2304]);     // 1280 => [2304]
-            map.insert(555, &[4352]);     // 555 => [4352]
-            map.insert(1086, &[8448]);     // 1086 => [8448]
-            map.insert(87, &[16640]);     // 87 => [16640]
-            map.insert(1906, &[33024]);     // 1906 => [33024]
-            map.insert(1447, &[65792]);     // 1447 => [65792]
-            map.insert(1977, &[131328]);     // 1977 => [131328]
-            map.insert(237, &[262400]);     // 237 => [262400]
-            map.insert(730, &[524544]);     // 730 => [524544]
-            map.insert(1729, &[1048832]);     // 1729 => [1048832]
-            map.insert(436, &[1536]);     // 436 => [1536]
-            map.insert(1536, &[2560]);     // 1536 => [2560]
-            map.insert(299, &[4608]);     // 299 => [4608]
-            map.insert(1854, &[8704]);     // 1854 => [8704]
-            map.insert(855, &[16896]);     // 855 => [16896]
-            map.insert(1138, &[33280]);     // 1138 => [33280]
-            map.insert(1703, &[66048]);     // 1703 => [66048]
-            map.insert(1209, &[131584]);     // 1209 => [131584]
-            map.insert(1005, &[262656]);     // 1005 => [262656]
-            map.insert(474, &[524800]);     // 474 => [524800]
-            map.insert(1473, &[1049088]);     // 1473 => [1049088]
-            map.insert(1972, &[3072]);     // 1972 => [3072]
-            map.insert(159, &[5120]);     // 159 => [5120]
-            map.insert(1674, &[9216]);     // 1674 => [9216]
-            map.insert(739, &[17408]);     // 739 => [17408]
-            map.insert(1478, &[33792]);     // 1478 => [33792]
-            map.insert(1811, &[66560]);     // 1811 => [66560]
-            map.insert(1293, &[132096]);     // 1293 => [132096]
-            map.insert(601, &[263168]);     // 601 => [263168]
-            map.insert(110, &[525312]);     // 110 => [525312]
-            map.insert(1141, &[1049600]);     // 1141 => [1049600]
-            map.insert(1835, &[6144]);     // 1835 => [6144]
-            map.insert(318, &[10240]);     // 318 => [10240]
-            map.insert(1367, &[18432]);     // 1367 => [18432]
-            map.insert(626, &[34816]);     // 626 => [34816]
-            map.insert(167, &[67584]);     // 167 => [67584]
-            map.insert(697, &[133120]);     // 697 => [133120]
-            map.insert(1517, &[264192]);     // 1517 => [264192]
-            map.insert(2010, &[526336]);     // 2010 => [526336]
-            map.insert(961, &[1050624]);     // 961 => [1050624]
-            map.insert(1557, &[12288]);     // 1557 => [12288]
-            map.insert(636, &[20480]);     // 636 => [20480]
-            map.insert(1369, &[36864]);     // 1369 => [36864]
-            map.insert(1932, &[69632]);     // 1932 => [69632]
-            map.insert(1426, &[135168]);     // 1426 => [135168]
-            map.insert(710, &[266240]);     // 710 => [266240]
-            map.insert(241, &[528384]);     // 241 => [528384]
-            map.insert(1258, &[1052672]);     // 1258 => [1052672]
-            map.insert(1129, &[24576]);     // 1129 => [24576]
-            map.insert(844, &[40960]);     // 844 => [40960]
-            map.insert(409, &[73728]);     // 409 => [73728]
-            map.insert(903, &[139264]);     // 903 => [139264]
-            map.insert(1235, &[270336]);     // 1235 => [270336]
-            map.insert(1764, &[532480]);     // 1764 => [532480]
-            map.insert(767, &[1056768]);     // 767 => [1056768]
-            map.insert(1829, &[49152]);     // 1829 => [49152]
-            map.insert(1520, &[81920]);     // 1520 => [81920]
-            map.insert(2030, &[147456]);     // 2030 => [147456]
-            map.insert(186, &[278528]);     // 186 => [278528]
-            map.insert(653, &[540672]);     // 653 => [540672]
-            map.insert(1686, &[1064960]);     // 1686 => [1064960]
-            map.insert(725, &[98304]);     // 725 => [98304]
-            map.insert(203, &[163840]);     // 203 => [163840]
-            map.insert(1951, &[294912]);     // 1951 => [294912]
-            map.insert(1448, &[557056]);     // 1448 => [557056]
-            map.insert(435, &[1081344]);     // 435 => [1081344]
-            map.insert(542, &[196608]);     // 542 => [196608]
-            map.insert(1354, &[327680]);     // 1354 => [327680]
-            map.insert(1917, &[589824]);     // 1917 => [589824]
-            map.insert(870, &[1114112]);     // 870 => [1114112]
-            map.insert(1876, &[393216]);     // 1876 => [393216]
-            map.insert(1379, &[655360]);     // 1379 => [655360]
-            map.insert(376, &[1179648]);     // 376 => [1179648]
-            map.insert(567, &[786432]);     // 567 => [786432]
-            map.insert(1580, &[1310720]);     // 1580 => [1310720]
-            map.insert(1051, &[1572864]);     // 1051 => [1572864]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(19, &[19]);     // 19 => [19]
-            map.insert(35, &[35]);     // 35 => [35]
-            map.insert(67, &[67]);     // 67 => [67]
-            map.insert(131, &[131]);     // 131 => [131]
-            map.insert(259, &[259]);     // 259 => [259]
-            map.insert(515, &[515]);     // 515 => [515]
-            map.insert(951, &[1027]);     // 951 => [1027]
-            map.insert(1027, &[2051]);     // 1027 => [2051]
-            map.insert(808, &[4099]);     // 808 => [4099]
-            map.insert(1341, &[8195]);     // 1341 => [8195]
-            map.insert(340, &[16387]);     // 340 => [16387]
-            map.insert(1649, &[32771]);     // 1649 => [32771]
-            map.insert(1188, &[65539]);     // 1188 => [65539]
-            map.insert(1722, &[131075]);     // 1722 => [131075]
-            map.insert(494, &[262147]);     // 494 => [262147]
-            map.insert(985, &[524291]);     // 985 => [524291]
-            map.insert(1986, &[1048579]);     // 1986 => [1048579]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(69, &[69]);     // 69 => [69]
-            map.insert(133, &[133]);     // 133 => [133]
-            map.insert(261, &[261]);     // 261 => [261]
-            map.insert(517, &[517]);     // 517 => [517]
-            map.insert(945, &[1029]);     // 945 => [1029]
-            map.insert(1029, &[2053]);     // 1029 => [2053]
-            map.insert(814, &[4101]);     // 814 => [4101]
-            map.insert(1339, &[8197]);     // 1339 => [8197]
-            map.insert(338, &[16389]);     // 338 => [16389]
-            map.insert(1655, &[32773]);     // 1655 => [32773]
-            map.insert(1186, &[65541]);     // 1186 => [65541]
-            map.insert(1724, &[131077]);     // 1724 => [131077]
-            map.insert(488, &[262149]);     // 488 => [262149]
-            map.insert(991, &[524293]);     // 991 => [524293]
-            map.insert(1988, &[1048581]);     // 1988 => [1048581]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(41, &[41]);     // 41 => [41]
-            map.insert(73, &[73]);     // 73 => [73]
-            map.insert(137, &[137]);     // 137 => [137]
-            map.insert(265, &[265]);     // 265 => [265]
-            map.insert(521, &[521]);     // 521 => [521]
-            map.insert(957, &[1033]);     // 957 => [1033]
-            map.insert(1033, &[2057]);     // 1033 => [2057]
-            map.insert(802, &[4105]);     // 802 => [4105]
-            map.insert(1335, &[8201]);     // 1335 => [8201]
-            map.insert(350, &[16393]);     // 350 => [16393]
-            map.insert(1659, &[32777]);     // 1659 => [32777]
-            map.insert(1198, &[65545]);     // 1198 => [65545]
-            map.insert(1712, &[131081]);     // 1712 => [131081]
-            map.insert(484, &[262153]);     // 484 => [262153]
-            map.insert(979, &[524297]);     // 979 => [524297]
-            map.insert(1992, &[1048585]);     // 1992 => [1048585]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(81, &[81]);     // 81 => [81]
-            map.insert(145, &[145]);     // 145 => [145]
-            map.insert(273, &[273]);     // 273 => [273]
-            map.insert(529, &[529]);     // 529 => [529]
-            map.insert(933, &[1041]);     // 933 => [1041]
-            map.insert(1041, &[2065]);     // 1041 => [2065]
-            map.insert(826, &[4113]);     // 826 => [4113]
-            map.insert(1327, &[8209]);     // 1327 => [8209]
-            map.insert(326, &[16401]);     // 326 => [16401]
-            map.insert(1635, &[32785]);     // 1635 => [32785]
-            map.insert(1206, &[65553]);     // 1206 => [65553]
-            map.insert(1704, &[131089]);     // 1704 => [131089]
-            map.insert(508, &[262161]);     // 508 => [262161]
-            map.insert(971, &[524305]);     // 971 => [524305]
-            map.insert(2000, &[1048593]);     // 2000 => [1048593]
-            map.insert(97, &[97]);     // 97 => [97]
-            map.insert(161, &[161]);     // 161 => [161]
-            map.insert(289, &[289]);     // 289 => [289]
-            map.insert(545, &[545]);     // 545 => [545]
-            map.insert(917, &[1057]);     // 917 => [1057]
-            map.insert(1057, &[2081]);     // 1057 => [2081]
-            map.insert(778, &[4129]);     // 778 => [4129]
-            map.insert(1311, &[8225]);     // 1311 => [8225]
-            map.insert(374, &[16417]);     // 374 => [16417]
-            map.insert(1619, &[32801]);     // 1619 => [32801]
-            map.insert(1158, &[65569]);     // 1158 => [65569]
-            map.insert(1688, &[131105]);     // 1688 => [131105]
-            map.insert(460, &[262177]);     // 460 => [262177]
-            map.insert(1019, &[524321]);     // 1019 => [524321]
-            map.insert(2016, &[1048609]);     // 2016 => [1048609]
-            map.insert(193, &[193]);     // 193 => [193]
-            map.insert(321, &[321]);     // 321 => [321]
-            map.insert(577, &[577]);     // 577 => [577]
-            map.insert(1013, &[1089]);     // 1013 => [1089]
-            map.insert(1089, &[2113]);     // 1089 => [2113]
-            map.insert(874, &[4161]);     // 874 => [4161]
-            map.insert(1407, &[8257]);     // 1407 => [8257]
-            map.insert(278, &[16449]);     // 278 => [16449]
-            map.insert(1587, &[32833]);     // 1587 => [32833]
-            map.insert(1254, &[65601]);     // 1254 => [65601]
-            map.insert(1784, &[131137]);     // 1784 => [131137]
-            map.insert(428, &[262209]);     // 428 => [262209]
-            map.insert(923, &[524353]);     // 923 => [524353]
-            map.insert(1920, &[1048641]);     // 1920 => [1048641]
-            map.insert(385, &[385]);     // 385 => [385]
-            map.insert(641, &[641]);     // 641 => [641]
-            map.insert(821, &[1153]);     // 821 => [1153]
-            map.insert(1153, &[2177]);     // 1153 => [2177]
-            map.insert(938, &[4225]);     // 938 => [4225]
-            map.insert(1471, &[8321]);     // 1471 => [8321]
-            map.insert(470, &[16513]);     // 470 => [16513]
-            map.insert(1779, &[32897]);     // 1779 => [32897]
-            map.insert(1062, &[65665]);     // 1062 => [65665]
-            map.insert(1592, &[131201]);     // 1592 => [131201]
-            map.insert(364, &[262273]);     // 364 => [262273]
-            map.insert(859, &[524417]);     // 859 => [524417]
-            map.insert(1856, &[1048705]);     // 1856 => [1048705]
-            map.insert(769, &[769]);     // 769 => [769]
-            map.insert(693, &[1281]);     // 693 => [1281]
-            map.insert(1281, &[2305]);     // 1281 => [2305]
-            map.insert(554, &[4353]);     // 554 => [4353]
-            map.insert(1087, &[8449]);     // 1087 => [8449]
-            map.insert(86, &[16641]);     // 86 => [16641]
-            map.insert(1907, &[33025]);     // 1907 => [33025]
-            map.insert(1446, &[65793]);     // 1446 => [65793]
-            map.insert(1976, &[131329]);     // 1976 => [131329]
-            map.insert(236, &[262401]);     // 236 => [262401]
-            map.insert(731, &[524545]);     // 731 => [524545]
-            map.insert(1728, &[1048833]);     // 1728 => [1048833]
-            map.insert(437, &[1537]);     // 437 => [1537]
-            map.insert(1537, &[2561]);     // 1537 => [2561]
-            map.insert(298, &[4609]);     // 298 => [4609]
-            map.insert(1855, &[8705]);     // 1855 => [8705]
-            map.insert(854, &[16897]);     // 854 => [16897]
-            map.insert(1139, &[33281]);     // 1139 => [33281]
-            map.insert(1702, &[66049]);     // 1702 => [66049]
-            map.insert(1208, &[131585]);     // 1208 => [131585]
-            map.insert(1004, &[262657]);     // 1004 => [262657]
-            map.insert(475, &[524801]);     // 475 => [524801]
-            map.insert(1472, &[1049089]);     // 1472 => [1049089]
-            map.insert(1973, &[3073]);     // 1973 => [3073]
-            map.insert(158, &[5121]);     // 158 => [5121]
-            map.insert(1675, &[9217]);     // 1675 => [9217]
-            map.insert(738, &[17409]);     // 738 => [17409]
-            map.insert(1479, &[33793]);     // 1479 => [33793]
-            map.insert(1810, &[66561]);     // 1810 => [66561]
-            map.insert(1292, &[132097]);     // 1292 => [132097]
-            map.insert(600, &[263169]);     // 600 => [263169]
-            map.insert(111, &[525313]);     // 111 => [525313]
-            map.insert(1140, &[1049601]);     // 1140 => [1049601]
-            map.insert(1834, &[6145]);     // 1834 => [6145]
-            map.insert(319, &[10241]);     // 319 => [10241]
-            map.insert(1366, &[18433]);     // 1366 => [18433]
-            map.insert(627, &[34817]);     // 627 => [34817]
-            map.insert(166, &[67585]);     // 166 => [67585]
-            map.insert(696, &[133121]);     // 696 => [133121]
-            map.insert(1516, &[264193]);     // 1516 => [264193]
-            map.insert(2011, &[526337]);     // 2011 => [526337]
-            map.insert(960, &[1050625]);     // 960 => [1050625]
-            map.insert(1556, &[12289]);     // 1556 => [12289]
-            map.insert(637, &[20481]);     // 637 => [20481]
-            map.insert(1368, &[36865]);     // 1368 => [36865]
-            map.insert(1933, &[69633]);     // 1933 => [69633]
-            map.insert(1427, &[135169]);     // 1427 => [135169]
-            map.insert(711, &[266241]);     // 711 => [266241]
-            map.insert(240, &[528385]);     // 240 => [528385]
-            map.insert(1259, &[1052673]);     // 1259 => [1052673]
-            map.insert(1128, &[24577]);     // 1128 => [24577]
-            map.insert(845, &[40961]);     // 845 => [40961]
-            map.insert(408, &[73729]);     // 408 => [73729]
-            map.insert(902, &[139265]);     // 902 => [139265]
-            map.insert(1234, &[270337]);     // 1234 => [270337]
-            map.insert(1765, &[532481]);     // 1765 => [532481]
-            map.insert(766, &[1056769]);     // 766 => [1056769]
-            map.insert(1828, &[49153]);     // 1828 => [49153]
-            map.insert(1521, &[81921]);     // 1521 => [81921]
-            map.insert(2031, &[147457]);     // 2031 => [147457]
-            map.insert(187, &[278529]);     // 187 => [278529]
-            map.insert(652, &[540673]);     // 652 => [540673]
-            map.insert(1687, &[1064961]);     // 1687 => [1064961]
-            map.insert(724, &[98305]);     // 724 => [98305]
-            map.insert(202, &[163841]);     // 202 => [163841]
-            map.insert(1950, &[294913]);     // 1950 => [294913]
-            map.insert(1449, &[557057]);     // 1449 => [557057]
-            map.insert(434, &[1081345]);     // 434 => [1081345]
-            map.insert(543, &[196609]);     // 543 => [196609]
-            map.insert(1355, &[327681]);     // 1355 => [327681]
-            map.insert(1916, &[589825]);     // 1916 => [589825]
-            map.insert(871, &[1114113]);     // 871 => [1114113]
-            map.insert(1877, &[393217]);     // 1877 => [393217]
-            map.insert(1378, &[655361]);     // 1378 => [655361]
-            map.insert(377, &[1179649]);     // 377 => [1179649]
-            map.insert(566, &[786433]);     // 566 => [786433]
-            map.insert(1581, &[1310721]);     // 1581 => [1310721]
-            map.insert(1050, &[1572865]);     // 1050 => [1572865]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(70, &[70]);     // 70 => [70]
-            map.insert(134, &[134]);     // 134 => [134]
-            map.insert(262, &[262]);     // 262 => [262]
-            map.insert(518, &[518]);     // 518 => [518]
-            map.insert(946, &[1030]);     // 946 => [1030]
-            map.insert(1030, &[2054]);     // 1030 => [2054]
-            map.insert(813, &[4102]);     // 813 => [4102]
-            map.insert(1336, &[8198]);     // 1336 => [8198]
-            map.insert(337, &[16390]);     // 337 => [16390]
-            map.insert(1652, &[32774]);     // 1652 => [32774]
-            map.insert(1185, &[65542]);     // 1185 => [65542]
-            map.insert(1727, &[131078]);     // 1727 => [131078]
-            map.insert(491, &[262150]);     // 491 => [262150]
-            map.insert(988, &[524294]);     // 988 => [524294]
-            map.insert(1991, &[1048582]);     // 1991 => [1048582]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(42, &[42]);     // 42 => [42]
-            map.insert(74, &[74]);     // 74 => [74]
-            map.insert(138, &[138]);     // 138 => [138]
-            map.insert(266, &[266]);     // 266 => [266]
-            map.insert(522, &[522]);     // 522 => [522]
-            map.insert(958, &[1034]);     // 958 => [1034]
-            map.insert(1034, &[2058]);     // 1034 => [2058]
-            map.insert(801, &[4106]);     // 801 => [4106]
-            map.insert(1332, &[8202]);     // 1332 => [8202]
-            map.insert(349, &[16394]);     // 349 => [16394]
-            map.insert(1656, &[32778]);     // 1656 => [32778]
-            map.insert(1197, &[65546]);     // 1197 => [65546]
-            map.insert(1715, &[131082]);     // 1715 => [131082]
-            map.insert(487, &[262154]);     // 487 => [262154]
-            map.insert(976, &[524298]);     // 976 => [524298]
-            map.insert(1995, &[1048586]);     // 1995 => [1048586]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(82, &[82]);     // 82 => [82]
-            map.insert(146, &[146]);     // 146 => [146]
-            map.insert(274, &[274]);     // 274 => [274]
-            map.insert(530, &[530]);     // 530 => [530]
-            map.insert(934, &[1042]);     // 934 => [1042]
-            map.insert(1042, &[2066]);     // 1042 => [2066]
-            map.insert(825, &[4114]);     // 825 => [4114]
-            map.insert(1324, &[8210]);     // 1324 => [8210]
-            map.insert(325, &[16402]);     // 325 => [16402]
-            map.insert(1632, &[32786]);     // 1632 => [32786]
-            map.insert(1205, &[65554]);     // 1205 => [65554]
-            map.insert(1707, &[131090]);     // 1707 => [131090]
-            map.insert(511, &[262162]);     // 511 => [262162]
-            map.insert(968, &[524306]);     // 968 => [524306]
-            map.insert(2003, &[1048594]);     // 2003 => [1048594]
-            map.insert(98, &[98]);     // 98 => [98]
-            map.insert(162, &[162]);     // 162 => [162]
-            map.insert(290, &[290]);     // 290 => [290]
-            map.insert(546, &[546]);     // 546 => [546]
-            map.insert(918, &[1058]);     // 918 => [1058]
-            map.insert(1058, &[2082]);     // 1058 => [2082]
-            map.insert(777, &[4130]);     // 777 => [4130]
-            map.insert(1308, &[8226]);     // 1308 => [8226]
-            map.insert(373, &[16418]);     // 373 => [16418]
-            map.insert(1616, &[32802]);     // 1616 => [32802]
-            map.insert(1157, &[65570]);     // 1157 => [65570]
-            map.insert(1691, &[131106]);     // 1691 => [131106]
-            map.insert(463, &[262178]);     // 463 => [262178]
-            map.insert(1016, &[524322]);     // 1016 => [524322]
-            map.insert(2019, &[1048610]);     // 2019 => [1048610]
-            map.insert(194, &[194]);     // 194 => [194]
-            map.insert(322, &[322]);     // 322 => [322]
-            map.insert(578, &[578]);     // 578 => [578]
-            map.insert(1014, &[1090]);     // 1014 => [1090]
-            map.insert(1090, &[2114]);     // 1090 => [2114]
-            map.insert(873, &[4162]);     // 873 => [4162]
-            map.insert(1404, &[8258]);     // 1404 => [8258]
-            map.insert(277, &[16450]);     // 277 => [16450]
-            map.insert(1584, &[32834]);     // 1584 => [32834]
-            map.insert(1253, &[65602]);     // 1253 => [65602]
-            map.insert(1787, &[131138]);     // 1787 => [131138]
-            map.insert(431, &[262210]);     // 431 => [262210]
-            map.insert(920, &[524354]);     // 920 => [524354]
-            map.insert(1923, &[1048642]);     // 1923 => [1048642]
-            map.insert(386, &[386]);     // 386 => [386]
-            map.insert(642, &[642]);     // 642 => [642]
-            map.insert(822, &[1154]);     // 822 => [1154]
-            map.insert(1154, &[2178]);     // 1154 => [2178]
-            map.insert(937, &[4226]);     // 937 => [4226]
-            map.insert(1468, &[8322]);     // 1468 => [8322]
-            map.insert(469, &[16514]);     // 469 => [16514]
-            map.insert(1776, &[32898]);     // 1776 => [32898]
-            map.insert(1061, &[65666]);     // 1061 => [65666]
-            map.insert(1595, &[131202]);     // 1595 => [131202]
-            map.insert(367, &[262274]);     // 367 => [262274]
-            map.insert(856, &[524418]);     // 856 => [524418]
-            map.insert(1859, &[1048706]);     // 1859 => [1048706]
-            map.insert(770, &[770]);     // 770 => [770]
-            map.insert(694, &[1282]);     // 694 => [1282]
-            map.insert(1282, &[2306]);     // 1282 => [2306]
-            map.insert(553, &[4354]);     // 553 => [4354]
-            map.insert(1084, &[8450]);     // 1084 => [8450]
-            map.insert(85, &[16642]);     // 85 => [16642]
-            map.insert(1904, &[33026]);     // 1904 => [33026]
-            map.insert(1445, &[65794]);     // 1445 => [65794]
-            map.insert(1979, &[131330]);     // 1979 => [131330]
-            map.insert(239, &[262402]);     // 239 => [262402]
-            map.insert(728, &[524546]);     // 728 => [524546]
-            map.insert(1731, &[1048834]);     // 1731 => [1048834]
-            map.insert(438, &[1538]);     // 438 => [1538]
-            map.insert(1538, &[2562]);     // 1538 => [2562]
-            map.insert(297, &[4610]);     // 297 => [4610]
-            map.insert(1852, &[8706]);     // 1852 => [8706]
-            map.insert(853, &[16898]);     // 853 => [16898]
-            map.insert(1136, &[33282]);     // 1136 => [33282]
-            map.insert(1701, &[66050]);     // 1701 => [66050]
-            map.insert(1211, &[131586]);     // 1211 => [131586]
-            map.insert(1007, &[262658]);     // 1007 => [262658]
-            map.insert(472, &[524802]);     // 472 => [524802]
-            map.insert(1475, &[1049090]);     // 1475 => [1049090]
-            map.insert(1974, &[3074]);     // 1974 => [3074]
-            map.insert(157, &[5122]);     // 157 => [5122]
-            map.insert(1672, &[9218]);     // 1672 => [9218]
-            map.insert(737, &[17410]);     // 737 => [17410]
-            map.insert(1476, &[33794]);     // 1476 => [33794]
-            map.insert(1809, &[66562]);     // 1809 => [66562]
-            map.insert(1295, &[132098]);     // 1295 => [132098]
-            map.insert(603, &[263170]);     // 603 => [263170]
-            map.insert(108, &[525314]);     // 108 => [525314]
-            map.insert(1143, &[1049602]);     // 1143 => [1049602]
-            map.insert(1833, &[6146]);     // 1833 => [6146]
-            map.insert(316, &[10242]);     // 316 => [10242]
-            map.insert(1365, &[18434]);     // 1365 => [18434]
-            map.insert(624, &[34818]);     // 624 => [34818]
-            map.insert(165, &[67586]);     // 165 => [67586]
-            map.insert(699, &[133122]);     // 699 => [133122]
-            map.insert(1519, &[264194]);     // 1519 => [264194]
-            map.insert(2008, &[526338]);     // 2008 => [526338]
-            map.insert(963, &[1050626]);     // 963 => [1050626]
-            map.insert(1559, &[12290]);     // 1559 => [12290]
-            map.insert(638, &[20482]);     // 638 => [20482]
-            map.insert(1371, &[36866]);     // 1371 => [36866]
-            map.insert(1934, &[69634]);     // 1934 => [69634]
-            map.insert(1424, &[135170]);     // 1424 => [135170]
-            map.insert(708, &[266242]);     // 708 => [266242]
-            map.insert(243, &[528386]);     // 243 => [528386]
-            map.insert(1256, &[1052674]);     // 1256 => [1052674]
-            map.insert(1131, &[24578]);     // 1131 => [24578]
-            map.insert(846, &[40962]);     // 846 => [40962]
-            map.insert(411, &[73730]);     // 411 => [73730]
-            map.insert(901, &[139266]);     // 901 => [139266]
-            map.insert(1233, &[270338]);     // 1233 => [270338]
-            map.insert(1766, &[532482]);     // 1766 => [532482]
-            map.insert(765, &[1056770]);     // 765 => [1056770]
-            map.insert(1831, &[49154]);     // 1831 => [49154]
-            map.insert(1522, &[81922]);     // 1522 => [81922]
-            map.insert(2028, &[147458]);     // 2028 => [147458]
-            map.insert(184, &[278530]);     // 184 => [278530]
-            map.insert(655, &[540674]);     // 655 => [540674]
-            map.insert(1684, &[1064962]);     // 1684 => [1064962]
-            map.insert(727, &[98306]);     // 727 => [98306]
-            map.insert(201, &[163842]);     // 201 => [163842]
-            map.insert(1949, &[294914]);     // 1949 => [294914]
-            map.insert(1450, &[557058]);     // 1450 => [557058]
-            map.insert(433, &[1081346]);     // 433 => [1081346]
-            map.insert(540, &[196610]);     // 540 => [196610]
-            map.insert(1352, &[327682]);     // 1352 => [327682]
-            map.insert(1919, &[589826]);     // 1919 => [589826]
-            map.insert(868, &[1114114]);     // 868 => [1114114]
-            map.insert(1878, &[393218]);     // 1878 => [393218]
-            map.insert(1377, &[655362]);     // 1377 => [655362]
-            map.insert(378, &[1179650]);     // 378 => [1179650]
-            map.insert(565, &[786434]);     // 565 => [786434]
-            map.insert(1582, &[1310722]);     // 1582 => [1310722]
-            map.insert(1049, &[1572866]);     // 1049 => [1572866]
-            map.insert(28, &[28]);     // 28 => [28]
-            map.insert(44, &[44]);     // 44 => [44]
-            map.insert(76, &[76]);     // 76 => [76]
-            map.insert(140, &[140]);     // 140 => [140]
-            map.insert(268, &[268]);     // 268 => [268]
-            map.insert(524, &[524]);     // 524 => [524]
-            map.insert(952, &[1036]);     // 952 => [1036]
-            map.insert(1036, &[2060]);     // 1036 => [2060]
-            map.insert(807, &[4108]);     // 807 => [4108]
-            map.insert(1330, &[8204]);     // 1330 => [8204]
-            map.insert(347, &[16396]);     // 347 => [16396]
-            map.insert(1662, &[32780]);     // 1662 => [32780]
-            map.insert(1195, &[65548]);     // 1195 => [65548]
-            map.insert(1717, &[131084]);     // 1717 => [131084]
-            map.insert(481, &[262156]);     // 481 => [262156]
-            map.insert(982, &[524300]);     // 982 => [524300]
-            map.insert(1997, &[1048588]);     // 1997 => [1048588]
-            map.insert(52, &[52]);     // 52 => [52]
-            map.insert(84, &[84]);     // 84 => [84]
-            map.insert(148, &[148]);     // 148 => [148]
-            map.insert(276, &[276]);     // 276 => [276]
-            map.insert(532, &[532]);     // 532 => [532]
-            map.insert(928, &[1044]);     // 928 => [1044]
-            map.insert(1044, &[2068]);     // 1044 => [2068]
-            map.insert(831, &[4116]);     // 831 => [4116]
-            map.insert(1322, &[8212]);     // 1322 => [8212]
-            map.insert(323, &[16404]);     // 323 => [16404]
-            map.insert(1638, &[32788]);     // 1638 => [32788]
-            map.insert(1203, &[65556]);     // 1203 => [65556]
-            map.insert(1709, &[131092]);     // 1709 => [131092]
-            map.insert(505, &[262164]);     // 505 => [262164]
-            map.insert(974, &[524308]);     // 974 => [524308]
-            map.insert(2005, &[1048596]);     // 2005 => [1048596]
-            map.insert(100, &[100]);     // 100 => [100]
-            map.insert(164, &[164]);     // 164 => [164]
-            map.insert(292, &[292]);     // 292 => [292]
-            map.insert(548, &[548]);     // 548 => [548]
-            map.insert(912, &[1060]);     // 912 => [1060]
-            map.insert(1060, &[2084]);     // 1060 => [2084]
-            map.insert(783, &[4132]);     // 783 => [4132]
-            map.insert(1306, &[8228]);     // 1306 => [8228]
-            map.insert(371, &[16420]);     // 371 => [16420]
-            map.insert(1622, &[32804]);     // 1622 => [32804]
-            map.insert(1155, &[65572]);     // 1155 => [65572]
-            map.insert(1693, &[131108]);     // 1693 => [131108]
-            map.insert(457, &[262180]);     // 457 => [262180]
-            map.insert(1022, &[524324]);     // 1022 => [524324]
-            map.insert(2021, &[1048612]);     // 2021 => [1048612]
-            map.insert(196, &[196]);     // 196 => [196]
-            map.insert(324, &[324]);     // 324 => [324]
-            map.insert(580, &[580]);     // 580 => [580]
-            map.insert(1008, &[1092]);     // 1008 => [1092]
-            map.insert(1092, &[2116]);     // 1092 => [2116]
-            map.insert(879, &[4164]);     // 879 => [4164]
-            map.insert(1402, &[8260]);     // 1402 => [8260]
-            map.insert(275, &[16452]);     // 275 => [16452]
-            map.insert(1590, &[32836]);     // 1590 => [32836]
-            map.insert(1251, &[65604]);     // 1251 => [65604]
-            map.insert(1789, &[131140]);     // 1789 => [131140]
-            map.insert(425, &[262212]);     // 425 => [262212]
-            map.insert(926, &[524356]);     // 926 => [524356]
-            map.insert(1925, &[1048644]);     // 1925 => [1048644]
-            map.insert(388, &[388]);     // 388 => [388]
-            map.insert(644, &[644]);     // 644 => [644]
-            map.insert(816, &[1156]);     // 816 => [1156]
-            map.insert(1156, &[2180]);     // 1156 => [2180]
-            map.insert(943, &[4228]);     // 943 => [4228]
-            map.insert(1466, &[8324]);     // 1466 => [8324]
-            map.insert(467, &[16516]);     // 467 => [16516]
-            map.insert(1782, &[32900]);     // 1782 => [32900]
-            map.insert(1059, &[65668]);     // 1059 => [65668]
-            map.insert(1597, &[131204]);     // 1597 => [131204]
-            map.insert(361, &[262276]);     // 361 => [262276]
-            map.insert(862, &[524420]);     // 862 => [524420]
-            map.insert(1861, &[1048708]);     // 1861 => [1048708]
-            map.insert(772, &[772]);     // 772 => [772]
-            map.insert(688, &[1284]);     // 688 => [1284]
-            map.insert(1284, &[2308]);     // 1284 => [2308]
-            map.insert(559, &[4356]);     // 559 => [4356]
-            map.insert(1082, &[8452]);     // 1082 => [8452]
-            map.insert(83, &[16644]);     // 83 => [16644]
-            map.insert(1910, &[33028]);     // 1910 => [33028]
-            map.insert(1443, &[65796]);     // 1443 => [65796]
-            map.insert(1981, &[131332]);     // 1981 => [131332]
-            map.insert(233, &[262404]);     // 233 => [262404]
-            map.insert(734, &[524548]);     // 734 => [524548]
-            map.insert(1733, &[1048836]);     // 1733 => [1048836]
-            map.insert(432, &[1540]);     // 432 => [1540]
-            map.insert(1540, &[2564]);     // 1540 => [2564]
-            map.insert(303, &[4612]);     // 303 => [4612]
-            map.insert(1850, &[8708]);     // 1850 => [8708]
-            map.insert(851, &[16900]);     // 851 => [16900]
-            map.insert(1142, &[33284]);     // 1142 => [33284]
-            map.insert(1699, &[66052]);     // 1699 => [66052]
-            map.insert(1213, &[131588]);     // 1213 => [131588]
-            map.insert(1001, &[262660]);     // 1001 => [262660]
-            map.insert(478, &[524804]);     // 478 => [524804]
-            map.insert(1477, &[1049092]);     // 1477 => [1049092]
-            map.insert(1968, &[3076]);     // 1968 => [3076]
-            map.insert(155, &[5124]);     // 155 => [5124]
-            map.insert(1678, &[9220]);     // 1678 => [9220]
-            map.insert(743, &[17412]);     // 743 => [17412]
-            map.insert(1474, &[33796]);     // 1474 => [33796]
-            map.insert(1815, &[66564]);     // 1815 => [66564]
-            map.insert(1289, &[132100]);     // 1289 => [132100]
-            map.insert(605, &[263172]);     // 605 => [263172]
-            map.insert(106, &[525316]);     // 106 => [525316]
-            map.insert(1137, &[1049604]);     // 1137 => [1049604]
-            map.insert(1839, &[6148]);     // 1839 => [6148]
-            map.insert(314, &[10244]);     // 314 => [10244]
-            map.insert(1363, &[18436]);     // 1363 => [18436]
-            map.insert(630, &[34820]);     // 630 => [34820]
-            map.insert(163, &[67588]);     // 163 => [67588]
-            map.insert(701, &[133124]);     // 701 => [133124]
-            map.insert(1513, &[264196]);     // 1513 => [264196]
-            map.insert(2014, &[526340]);     // 2014 => [526340]
-            map.insert(965, &[1050628]);     // 965 => [1050628]
-            map.insert(1553, &[12292]);     // 1553 => [12292]
-            map.insert(632, &[20484]);     // 632 => [20484]
-            map.insert(1373, &[36868]);     // 1373 => [36868]
-            map.insert(1928, &[69636]);     // 1928 => [69636]
-            map.insert(1430, &[135172]);     // 1430 => [135172]
-            map.insert(706, &[266244]);     // 706 => [266244]
-            map.insert(245, &[528388]);     // 245 => [528388]
-            map.insert(1262, &[1052676]);     // 1262 => [1052676]
-            map.insert(1133, &[24580]);     // 1133 => [24580]
-            map.insert(840, &[40964]);     // 840 => [40964]
-            map.insert(413, &[73732]);     // 413 => [73732]
-            map.insert(899, &[139268]);     // 899 => [139268]
-            map.insert(1239, &[270340]);     // 1239 => [270340]
-            map.insert(1760, &[532484]);     // 1760 => [532484]
-            map.insert(763, &[1056772]);     // 763 => [1056772]
-            map.insert(1825, &[49156]);     // 1825 => [49156]
-            map.insert(1524, &[81924]);     // 1524 => [81924]
-            map.insert(2026, &[147460]);     // 2026 => [147460]
-            map.insert(190, &[278532]);     // 190 => [278532]
-            map.insert(649, &[540676]);     // 649 => [540676]
-            map.insert(1682, &[1064964]);     // 1682 => [1064964]
-            map.insert(721, &[98308]);     // 721 => [98308]
-            map.insert(207, &[163844]);     // 207 => [163844]
-            map.insert(1947, &[294916]);     // 1947 => [294916]
-            map.insert(1452, &[557060]);     // 1452 => [557060]
-            map.insert(439, &[1081348]);     // 439 => [1081348]
-            map.insert(538, &[196612]);     // 538 => [196612]
-            map.insert(1358, &[327684]);     // 1358 => [327684]
-            map.insert(1913, &[589828]);     // 1913 => [589828]
-            map.insert(866, &[1114116]);     // 866 => [1114116]
-            map.insert(1872, &[393220]);     // 1872 => [393220]
-            map.insert(1383, &[655364]);     // 1383 => [655364]
-            map.insert(380, &[1179652]);     // 380 => [1179652]
-            map.insert(563, &[786436]);     // 563 => [786436]
-            map.insert(1576, &[1310724]);     // 1576 => [1310724]
-            map.insert(1055, &[1572868]);     // 1055 => [1572868]
-            map.insert(56, &[56]);     // 56 => [56]
-            map.insert(88, &[88]);     // 88 => [88]
-            map.insert(152, &[152]);     // 152 => [152]
-            map.insert(280, &[280]);     // 280 => [280]
-            map.insert(536, &[536]);     // 536 => [536]
-            map.insert(940, &[1048]);     // 940 => [1048]
-            map.insert(1048, &[2072]);     // 1048 => [2072]
-            map.insert(819, &[4120]);     // 819 => [4120]
-            map.insert(1318, &[8216]);     // 1318 => [8216]
-            map.insert(335, &[16408]);     // 335 => [16408]
-            map.insert(1642, &[32792]);     // 1642 => [32792]
-            map.insert(1215, &[65560]);     // 1215 => [65560]
-            map.insert(1697, &[131096]);     // 1697 => [131096]
-            map.insert(501, &[262168]);     // 501 => [262168]
-            map.insert(962, &[524312]);     // 962 => [524312]
-            map.insert(2009, &[1048600]);     // 2009 => [1048600]
-            map.insert(104, &[104]);     // 104 => [104]
-            map.insert(168, &[168]);     // 168 => [168]
-            map.insert(296, &[296]);     // 296 => [296]
-            map.insert(552, &[552]);     // 552 => [552]
-            map.insert(924, &[1064]);     // 924 => [1064]
-            map.insert(1064, &[2088]);     // 1064 => [2088]
-            map.insert(771, &[4136]);     // 771 => [4136]
-            map.insert(1302, &[8232]);     // 1302 => [8232]
-            map.insert(383, &[16424]);     // 383 => [16424]
-            map.insert(1626, &[32808]);     // 1626 => [32808]
-            map.insert(1167, &[65576]);     // 1167 => [65576]
-            map.insert(1681, &[131112]);     // 1681 => [131112]
-            map.insert(453, &[262184]);     // 453 => [262184]
-            map.insert(1010, &[524328]);     // 1010 => [524328]
-            map.insert(2025, &[1048616]);     // 2025 => [1048616]
-            map.insert(200, &[200]);     // 200 => [200]
-            map.insert(328, &[328]);     // 328 => [328]
-            map.insert(584, &[584]);     // 584 => [584]
-            map.insert(1020, &[1096]);     // 1020 => [1096]
-            map.insert(1096, &[2120]);     // 1096 => [2120]
-            map.insert(867, &[4168]);     // 867 => [4168]
-            map.insert(1398, &[8264]);     // 1398 => [8264]
-            map.insert(287, &[16456]);     // 287 => [16456]
-            map.insert(1594, &[32840]);     // 1594 => [32840]
-            map.insert(1263, &[65608]);     // 1263 => [65608]
-            map.insert(1777, &[131144]);     // 1777 => [131144]
-            map.insert(421, &[262216]);     // 421 => [262216]
-            map.insert(914, &[524360]);     // 914 => [524360]
-            map.insert(1929, &[1048648]);     // 1929 => [1048648]
-            map.insert(392, &[392]);     // 392 => [392]
-            map.insert(648, &[648]);     // 648 => [648]
-            map.insert(828, &[1160]);     // 828 => [1160]
-            map.insert(1160, &[2184]);     // 1160 => [2184]
-            map.insert(931, &[4232]);     // 931 => [4232]
-            map.insert(1462, &[8328]);     // 1462 => [8328]
-            map.insert(479, &[16520]);     // 479 => [16520]
-            map.insert(1786, &[32904]);     // 1786 => [32904]
-            map.insert(1071, &[65672]);     // 1071 => [65672]
-            map.insert(1585, &[131208]);     // 1585 => [131208]
-            map.insert(357, &[262280]);     // 357 => [262280]
-            map.insert(850, &[524424]);     // 850 => [524424]
-            map.insert(1865, &[1048712]);     // 1865 => [1048712]
-            map.insert(776, &[776]);     // 776 => [776]
-            map.insert(700, &[1288]);     // 700 => [1288]
-            map.insert(1288, &[2312]);     // 1288 => [2312]
-            map.insert(547, &[4360]);     // 547 => [4360]
-            map.insert(1078, &[8456]);     // 1078 => [8456]
-            map.insert(95, &[16648]);     // 95 => [16648]
-            map.insert(1914, &[33032]);     // 1914 => [33032]
-            map.insert(1455, &[65800]);     // 1455 => [65800]
-            map.insert(1969, &[131336]);     // 1969 => [131336]
-            map.insert(229, &[262408]);     // 229 => [262408]
-            map.insert(722, &[524552]);     // 722 => [524552]
-            map.insert(1737, &[1048840]);     // 1737 => [1048840]
-            map.insert(444, &[1544]);     // 444 => [1544]
-            map.insert(1544, &[2568]);     // 1544 => [2568]
-            map.insert(291, &[4616]);     // 291 => [4616]
-            map.insert(1846, &[8712]);     // 1846 => [8712]
-            map.insert(863, &[16904]);     // 863 => [16904]
-            map.insert(1146, &[33288]);     // 1146 => [33288]
-            map.insert(1711, &[66056]);     // 1711 => [66056]
-            map.insert(1201, &[131592]);     // 1201 => [131592]
-            map.insert(997, &[262664]);     // 997 => [262664]
-            map.insert(466, &[524808]);     // 466 => [524808]
-            map.insert(1481, &[1049096]);     // 1481 => [1049096]
-            map.insert(1980, &[3080]);     // 1980 => [3080]
-            map.insert(151, &[5128]);     // 151 => [5128]
-            map.insert(1666, &[9224]);     // 1666 => [9224]
-            map.insert(747, &[17416]);     // 747 => [17416]
-            map.insert(1486, &[33800]);     // 1486 => [33800]
-            map.insert(1819, &[66568]);     // 1819 => [66568]
-            map.insert(1285, &[132104]);     // 1285 => [132104]
-            map.insert(593, &[263176]);     // 593 => [263176]
-            map.insert(102, &[525320]);     // 102 => [525320]
-            map.insert(1149, &[1049608]);     // 1149 => [1049608]
-            map.insert(1827, &[6152]);     // 1827 => [6152]
-            map.insert(310, &[10248]);     // 310 => [10248]
-            map.insert(1375, &[18440]);     // 1375 => [18440]
-            map.insert(634, &[34824]);     // 634 => [34824]
-            map.insert(175, &[67592]);     // 175 => [67592]
-            map.insert(689, &[133128]);     // 689 => [133128]
-            map.insert(1509, &[264200]);     // 1509 => [264200]
-            map.insert(2002, &[526344]);     // 2002 => [526344]
-            map.insert(969, &[1050632]);     // 969 => [1050632]
-            map.insert(1565, &[12296]);     // 1565 => [12296]
-            map.insert(628, &[20488]);     // 628 => [20488]
-            map.insert(1361, &[36872]);     // 1361 => [36872]
-            map.insert(1924, &[69640]);     // 1924 => [69640]
-            map.insert(1434, &[135176]);     // 1434 => [135176]
-            map.insert(718, &[266248]);     // 718 => [266248]
-            map.insert(249, &[528392]);     // 249 => [528392]
-            map.insert(1250, &[1052680]);     // 1250 => [1052680]
-            map.insert(1121, &[24584]);     // 1121 => [24584]
-            map.insert(836, &[40968]);     // 836 => [40968]
-            map.insert(401, &[73736]);     // 401 => [73736]
-            map.insert(911, &[139272]);     // 911 => [139272]
-            map.insert(1243, &[270344]);     // 1243 => [270344]
-            map.insert(1772, &[532488]);     // 1772 => [532488]
-            map.insert(759, &[1056776]);     // 759 => [1056776]
-            map.insert(1837, &[49160]);     // 1837 => [49160]
-            map.insert(1528, &[81928]);     // 1528 => [81928]
-            map.insert(2022, &[147464]);     // 2022 => [147464]
-            map.insert(178, &[278536]);     // 178 => [278536]
-            map.insert(645, &[540680]);     // 645 => [540680]
-            map.insert(1694, &[1064968]);     // 1694 => [1064968]
-            map.insert(733, &[98312]);     // 733 => [98312]
-            map.insert(195, &[163848]);     // 195 => [163848]
-            map.insert(1943, &[294920]);     // 1943 => [294920]
-            map.insert(1440, &[557064]);     // 1440 => [557064]
-            map.insert(443, &[1081352]);     // 443 => [1081352]
-            map.insert(534, &[196616]);     // 534 => [196616]
-            map.insert(1346, &[327688]);     // 1346 => [327688]
-            map.insert(1909, &[589832]);     // 1909 => [589832]
-            map.insert(878, &[1114120]);     // 878 => [1114120]
-            map.insert(1884, &[393224]);     // 1884 => [393224]
-            map.insert(1387, &[655368]);     // 1387 => [655368]
-            map.insert(368, &[1179656]);     // 368 => [1179656]
-            map.insert(575, &[786440]);     // 575 => [786440]
-            map.insert(1572, &[1310728]);     // 1572 => [1310728]
-            map.insert(1043, &[1572872]);     // 1043 => [1572872]
-            map.insert(112, &[112]);     // 112 => [112]
-            map.insert(176, &[176]);     // 176 => [176]
-            map.insert(304, &[304]);     // 304 => [304]
-            map.insert(560, &[560]);     // 560 => [560]
-            map.insert(900, &[1072]);     // 900 => [1072]
-            map.insert(1072, &[2096]);     // 1072 => [2096]
-            map.insert(795, &[4144]);     // 795 => [4144]
-            map.insert(1294, &[8240]);     // 1294 => [8240]
-            map.insert(359, &[16432]);     // 359 => [16432]
-            map.insert(1602, &[32816]);     // 1602 => [32816]
-            map.insert(1175, &[65584]);     // 1175 => [65584]
-            map.insert(1673, &[131120]);     // 1673 => [131120]
-            map.insert(477, &[262192]);     // 477 => [262192]
-            map.insert(1002, &[524336]);     // 1002 => [524336]
-            map.insert(2033, &[1048624]);     // 2033 => [1048624]
-            map.insert(208, &[208]);     // 208 => [208]
-            map.insert(336, &[336]);     // 336 => [336]
-            map.insert(592, &[592]);     // 592 => [592]
-            map.insert(996, &[1104]);     // 996 => [1104]
-            map.insert(1104, &[2128]);     // 1104 => [2128]
-            map.insert(891, &[4176]);     // 891 => [4176]
-            map.insert(1390, &[8272]);     // 1390 => [8272]
-            map.insert(263, &[16464]);     // 263 => [16464]
-            map.insert(1570, &[32848]);     // 1570 => [32848]
-            map.insert(1271, &[65616]);     // 1271 => [65616]
-            map.insert(1769, &[131152]);     // 1769 => [131152]
-            map.insert(445, &[262224]);     // 445 => [262224]
-            map.insert(906, &[524368]);     // 906 => [524368]
-            map.insert(1937, &[1048656]);     // 1937 => [1048656]
-            map.insert(400, &[400]);     // 400 => [400]
-            map.insert(656, &[656]);     // 656 => [656]
-            map.insert(804, &[1168]);     // 804 => [1168]
-            map.insert(1168, &[2192]);     // 1168 => [2192]
-            map.insert(955, &[4240]);     // 955 => [4240]
-            map.insert(1454, &[8336]);     // 1454 => [8336]
-            map.insert(455, &[16528]);     // 455 => [16528]
-            map.insert(1762, &[32912]);     // 1762 => [32912]
-            map.insert(1079, &[65680]);     // 1079 => [65680]
-            map.insert(1577, &[131216]);     // 1577 => [131216]
-            map.insert(381, &[262288]);     // 381 => [262288]
-            map.insert(842, &[524432]);     // 842 => [524432]
-            map.insert(1873, &[1048720]);     // 1873 => [1048720]
-            map.insert(784, &[784]);     // 784 => [784]
-            map.insert(676, &[1296]);     // 676 => [1296]
-            map.insert(1296, &[2320]);     // 1296 => [2320]
-            map.insert(571, &[4368]);     // 571 => [4368]
-            map.insert(1070, &[8464]);     // 1070 => [8464]
-            map.insert(71, &[16656]);     // 71 => [16656]
-            map.insert(1890, &[33040]);     // 1890 => [33040]
-            map.insert(1463, &[65808]);     // 1463 => [65808]
-            map.insert(1961, &[131344]);     // 1961 => [131344]
-            map.insert(253, &[262416]);     // 253 => [262416]
-            map.insert(714, &[524560]);     // 714 => [524560]
-            map.insert(1745, &[1048848]);     // 1745 => [1048848]
-            map.insert(420, &[1552]);     // 420 => [1552]
-            map.insert(1552, &[2576]);     // 1552 => [2576]
-            map.insert(315, &[4624]);     // 315 => [4624]
-            map.insert(1838, &[8720]);     // 1838 => [8720]
-            map.insert(839, &[16912]);     // 839 => [16912]
-            map.insert(1122, &[33296]);     // 1122 => [33296]
-            map.insert(1719, &[66064]);     // 1719 => [66064]
-            map.insert(1193, &[131600]);     // 1193 => [131600]
-            map.insert(1021, &[262672]);     // 1021 => [262672]
-            map.insert(458, &[524816]);     // 458 => [524816]
-            map.insert(1489, &[1049104]);     // 1489 => [1049104]
-            map.insert(1956, &[3088]);     // 1956 => [3088]
-            map.insert(143, &[5136]);     // 143 => [5136]
-            map.insert(1690, &[9232]);     // 1690 => [9232]
-            map.insert(755, &[17424]);     // 755 => [17424]
-            map.insert(1494, &[33808]);     // 1494 => [33808]
-            map.insert(1795, &[66576]);     // 1795 => [66576]
-            map.insert(1309, &[132112]);     // 1309 => [132112]
-            map.insert(585, &[263184]);     // 585 => [263184]
-            map.insert(126, &[525328]);     // 126 => [525328]
-            map.insert(1125, &[1049616]);     // 1125 => [1049616]
-            map.insert(1851, &[6160]);     // 1851 => [6160]
-            map.insert(302, &[10256]);     // 302 => [10256]
-            map.insert(1351, &[18448]);     // 1351 => [18448]
-            map.insert(610, &[34832]);     // 610 => [34832]
-            map.insert(183, &[67600]);     // 183 => [67600]
-            map.insert(681, &[133136]);     // 681 => [133136]
-            map.insert(1533, &[264208]);     // 1533 => [264208]
-            map.insert(1994, &[526352]);     // 1994 => [526352]
-            map.insert(977, &[1050640]);     // 977 => [1050640]
-            map.insert(1541, &[12304]);     // 1541 => [12304]
-            map.insert(620, &[20496]);     // 620 => [20496]
-            map.insert(1353, &[36880]);     // 1353 => [36880]
-            map.insert(1948, &[69648]);     // 1948 => [69648]
-            map.insert(1410, &[135184]);     // 1410 => [135184]
-            map.insert(726, &[266256]);     // 726 => [266256]
-            map.insert(225, &[528400]);     // 225 => [528400]
-            map.insert(1274, &[1052688]);     // 1274 => [1052688]
-            map.insert(1145, &[24592]);     // 1145 => [24592]
-            map.insert(860, &[40976]);     // 860 => [40976]
-            map.insert(393, &[73744]);     // 393 => [73744]
-            map.insert(919, &[139280]);     // 919 => [139280]
-            map.insert(1219, &[270352]);     // 1219 => [270352]
-            map.insert(1780, &[532496]);     // 1780 => [532496]
-            map.insert(751, &[1056784]);     // 751 => [1056784]
-            map.insert(1845, &[49168]);     // 1845 => [49168]
-            map.insert(1504, &[81936]);     // 1504 => [81936]
-            map.insert(2046, &[147472]);     // 2046 => [147472]
-            map.insert(170, &[278544]);     // 170 => [278544]
-            map.insert(669, &[540688]);     // 669 => [540688]
-            map.insert(1670, &[1064976]);     // 1670 => [1064976]
-            map.insert(709, &[98320]);     // 709 => [98320]
-            map.insert(219, &[163856]);     // 219 => [163856]
-            map.insert(1935, &[294928]);     // 1935 => [294928]
-            map.insert(1464, &[557072]);     // 1464 => [557072]
-            map.insert(419, &[1081360]);     // 419 => [1081360]
-            map.insert(526, &[196624]);     // 526 => [196624]
-            map.insert(1370, &[327696]);     // 1370 => [327696]
-            map.insert(1901, &[589840]);     // 1901 => [589840]
-            map.insert(886, &[1114128]);     // 886 => [1114128]
-            map.insert(1860, &[393232]);     // 1860 => [393232]
-            map.insert(1395, &[655376]);     // 1395 => [655376]
-            map.insert(360, &[1179664]);     // 360 => [1179664]
-            map.insert(551, &[786448]);     // 551 => [786448]
-            map.insert(1596, &[1310736]);     // 1596 => [1310736]
-            map.insert(1035, &[1572880]);     // 1035 => [1572880]
-            map.insert(224, &[224]);     // 224 => [224]
-            map.insert(352, &[352]);     // 352 => [352]
-            map.insert(608, &[608]);     // 608 => [608]
-            map.insert(980, &[1120]);     // 980 => [1120]
-            map.insert(1120, &[2144]);     // 1120 => [2144]
-            map.insert(843, &[4192]);     // 843 => [4192]
-            map.insert(1374, &[8288]);     // 1374 => [8288]
-            map.insert(311, &[16480]);     // 311 => [16480]
-            map.insert(1554, &[32864]);     // 1554 => [32864]
-            map.insert(1223, &[65632]);     // 1223 => [65632]
-            map.insert(1753, &[131168]);     // 1753 => [131168]
-            map.insert(397, &[262240]);     // 397 => [262240]
-            map.insert(954, &[524384]);     // 954 => [524384]
-            map.insert(1953, &[1048672]);     // 1953 => [1048672]
-            map.insert(416, &[416]);     // 416 => [416]
-            map.insert(672, &[672]);     // 672 => [672]
-            map.insert(788, &[1184]);     // 788 => [1184]
-            map.insert(1184, &[2208]);     // 1184 => [2208]
-            map.insert(907, &[4256]);     // 907 => [4256]
-            map.insert(1438, &[8352]);     // 1438 => [8352]
-            map.insert(503, &[16544]);     // 503 => [16544]
-            map.insert(1746, &[32928]);     // 1746 => [32928]
-            map.insert(1031, &[65696]);     // 1031 => [65696]
-            map.insert(1561, &[131232]);     // 1561 => [131232]
-            map.insert(333, &[262304]);     // 333 => [262304]
-            map.insert(890, &[524448]);     // 890 => [524448]
-            map.insert(1889, &[1048736]);     // 1889 => [1048736]
-            map.insert(800, &[800]);     // 800 => [800]
-            map.insert(660, &[1312]);     // 660 => [1312]
-            map.insert(1312, &[2336]);     // 1312 => [2336]
-            map.insert(523, &[4384]);     // 523 => [4384]
-            map.insert(1054, &[8480]);     // 1054 => [8480]
-            map.insert(119, &[16672]);     // 119 => [16672]
-            map.insert(1874, &[33056]);     // 1874 => [33056]
-            map.insert(1415, &[65824]);     // 1415 => [65824]
-            map.insert(1945, &[131360]);     // 1945 => [131360]
-            map.insert(205, &[262432]);     // 205 => [262432]
-            map.insert(762, &[524576]);     // 762 => [524576]
-            map.insert(1761, &[1048864]);     // 1761 => [1048864]
-            map.insert(404, &[1568]);     // 404 => [1568]
-            map.insert(1568, &[2592]);     // 1568 => [2592]
-            map.insert(267, &[4640]);     // 267 => [4640]
-            map.insert(1822, &[8736]);     // 1822 => [8736]
-            map.insert(887, &[16928]);     // 887 => [16928]
-            map.insert(1106, &[33312]);     // 1106 => [33312]
-            map.insert(1671, &[66080]);     // 1671 => [66080]
-            map.insert(1177, &[131616]);     // 1177 => [131616]
-            map.insert(973, &[262688]);     // 973 => [262688]
-            map.insert(506, &[524832]);     // 506 => [524832]
-            map.insert(1505, &[1049120]);     // 1505 => [1049120]
-            map.insert(1940, &[3104]);     // 1940 => [3104]
-            map.insert(191, &[5152]);     // 191 => [5152]
-            map.insert(1706, &[9248]);     // 1706 => [9248]
-            map.insert(707, &[17440]);     // 707 => [17440]
-            map.insert(1510, &[33824]);     // 1510 => [33824]
-            map.insert(1843, &[66592]);     // 1843 => [66592]
-            map.insert(1325, &[132128]);     // 1325 => [132128]
-            map.insert(633, &[263200]);     // 633 => [263200]
-            map.insert(78, &[525344]);     // 78 => [525344]
-            map.insert(1109, &[1049632]);     // 1109 => [1049632]
-            map.insert(1803, &[6176]);     // 1803 => [6176]
-            map.insert(286, &[10272]);     // 286 => [10272]
-            map.insert(1399, &[18464]);     // 1399 => [18464]
-            map.insert(594, &[34848]);     // 594 => [34848]
-            map.insert(135, &[67616]);     // 135 => [67616]
-            map.insert(665, &[133152]);     // 665 => [133152]
-            map.insert(1485, &[264224]);     // 1485 => [264224]
-            map.insert(2042, &[526368]);     // 2042 => [526368]
-            map.insert(993, &[1050656]);     // 993 => [1050656]
-            map.insert(1589, &[12320]);     // 1589 => [12320]
-            map.insert(604, &[20512]);     // 604 => [20512]
-            map.insert(1401, &[36896]);     // 1401 => [36896]
-            map.insert(1964, &[69664]);     // 1964 => [69664]
-            map.insert(1458, &[135200]);     // 1458 => [135200]
-            map.insert(742, &[266272]);     // 742 => [266272]
-            map.insert(209, &[528416]);     // 209 => [528416]
-            map.insert(1226, &[1052704]);     // 1226 => [1052704]
-            map.insert(1097, &[24608]);     // 1097 => [24608]
-            map.insert(876, &[40992]);     // 876 => [40992]
-            map.insert(441, &[73760]);     // 441 => [73760]
-            map.insert(935, &[139296]);     // 935 => [139296]
-            map.insert(1267, &[270368]);     // 1267 => [270368]
-            map.insert(1732, &[532512]);     // 1732 => [532512]
-            map.insert(735, &[1056800]);     // 735 => [1056800]
-            map.insert(1797, &[49184]);     // 1797 => [49184]
-            map.insert(1488, &[81952]);     // 1488 => [81952]
-            map.insert(1998, &[147488]);     // 1998 => [147488]
-            map.insert(154, &[278560]);     // 154 => [278560]
-            map.insert(685, &[540704]);     // 685 => [540704]
-            map.insert(1718, &[1064992]);     // 1718 => [1064992]
-            map.insert(757, &[98336]);     // 757 => [98336]
-            map.insert(235, &[163872]);     // 235 => [163872]
-            map.insert(1983, &[294944]);     // 1983 => [294944]
-            map.insert(1416, &[557088]);     // 1416 => [557088]
-            map.insert(403, &[1081376]);     // 403 => [1081376]
-            map.insert(574, &[196640]);     // 574 => [196640]
-            map.insert(1386, &[327712]);     // 1386 => [327712]
-            map.insert(1885, &[589856]);     // 1885 => [589856]
-            map.insert(838, &[1114144]);     // 838 => [1114144]
-            map.insert(1908, &[393248]);     // 1908 => [393248]
-            map.insert(1347, &[655392]);     // 1347 => [655392]
-            map.insert(344, &[1179680]);     // 344 => [1179680]
-            map.insert(535, &[786464]);     // 535 => [786464]
-            map.insert(1548, &[1310752]);     // 1548 => [1310752]
-            map.insert(1083, &[1572896]);     // 1083 => [1572896]
-            map.insert(448, &[448]);     // 448 => [448]
-            map.insert(704, &[704]);     // 704 => [704]
-            map.insert(884, &[1216]);     // 884 => [1216]
-            map.insert(1216, &[2240]);     // 1216 => [2240]
-            map.insert(1003, &[4288]);     // 1003 => [4288]
-            map.insert(1534, &[8384]);     // 1534 => [8384]
-            map.insert(407, &[16576]);     // 407 => [16576]
-            map.insert(1714, &[32960]);     // 1714 => [32960]
-            map.insert(1127, &[65728]);     // 1127 => [65728]
-            map.insert(1657, &[131264]);     // 1657 => [131264]
-            map.insert(301, &[262336]);     // 301 => [262336]
-            map.insert(794, &[524480]);     // 794 => [524480]
-            map.insert(1793, &[1048768]);     // 1793 => [1048768]
-            map.insert(832, &[832]);     // 832 => [832]
-            map.insert(756, &[1344]);     // 756 => [1344]
-            map.insert(1344, &[2368]);     // 1344 => [2368]
-            map.insert(619, &[4416]);     // 619 => [4416]
-            map.insert(1150, &[8512]);     // 1150 => [8512]
-            map.insert(23, &[16704]);     // 23 => [16704]
-            map.insert(1842, &[33088]);     // 1842 => [33088]
-            map.insert(1511, &[65856]);     // 1511 => [65856]
-            map.insert(2041, &[131392]);     // 2041 => [131392]
-            map.insert(173, &[262464]);     // 173 => [262464]
-            map.insert(666, &[524608]);     // 666 => [524608]
-            map.insert(1665, &[1048896]);     // 1665 => [1048896]
-            map.insert(500, &[1600]);     // 500 => [1600]
-            map.insert(1600, &[2624]);     // 1600 => [2624]
-            map.insert(363, &[4672]);     // 363 => [4672]
-            map.insert(1918, &[8768]);     // 1918 => [8768]
-            map.insert(791, &[16960]);     // 791 => [16960]
-            map.insert(1074, &[33344]);     // 1074 => [33344]
-            map.insert(1767, &[66112]);     // 1767 => [66112]
-            map.insert(1273, &[131648]);     // 1273 => [131648]
-            map.insert(941, &[262720]);     // 941 => [262720]
-            map.insert(410, &[524864]);     // 410 => [524864]
-            map.insert(1409, &[1049152]);     // 1409 => [1049152]
-            map.insert(2036, &[3136]);     // 2036 => [3136]
-            map.insert(223, &[5184]);     // 223 => [5184]
-            map.insert(1738, &[9280]);     // 1738 => [9280]
-            map.insert(675, &[17472]);     // 675 => [17472]
-            map.insert(1414, &[33856]);     // 1414 => [33856]
-            map.insert(1875, &[66624]);     // 1875 => [66624]
-            map.insert(1357, &[132160]);     // 1357 => [132160]
-            map.insert(537, &[263232]);     // 537 => [263232]
-            map.insert(46, &[525376]);     // 46 => [525376]
-            map.insert(1077, &[1049664]);     // 1077 => [1049664]
-            map.insert(1899, &[6208]);     // 1899 => [6208]
-            map.insert(382, &[10304]);     // 382 => [10304]
-            map.insert(1303, &[18496]);     // 1303 => [18496]
-            map.insert(562, &[34880]);     // 562 => [34880]
-            map.insert(231, &[67648]);     // 231 => [67648]
-            map.insert(761, &[133184]);     // 761 => [133184]
-            map.insert(1453, &[264256]);     // 1453 => [264256]
-            map.insert(1946, &[526400]);     // 1946 => [526400]
-            map.insert(897, &[1050688]);     // 897 => [1050688]
-            map.insert(1621, &[12352]);     // 1621 => [12352]
-            map.insert(572, &[20544]);     // 572 => [20544]
-            map.insert(1305, &[36928]);     // 1305 => [36928]
-            map.insert(1996, &[69696]);     // 1996 => [69696]
-            map.insert(1490, &[135232]);     // 1490 => [135232]
-            map.insert(646, &[266304]);     // 646 => [266304]
-            map.insert(177, &[528448]);     // 177 => [528448]
-            map.insert(1194, &[1052736]);     // 1194 => [1052736]
-            map.insert(1065, &[24640]);     // 1065 => [24640]
-            map.insert(780, &[41024]);     // 780 => [41024]
-            map.insert(473, &[73792]);     // 473 => [73792]
-            map.insert(967, &[139328]);     // 967 => [139328]
-            map.insert(1171, &[270400]);     // 1171 => [270400]
-            map.insert(1700, &[532544]);     // 1700 => [532544]
-            map.insert(703, &[1056832]);     // 703 => [1056832]
-            map.insert(1893, &[49216]);     // 1893 => [49216]
-            map.insert(1456, &[81984]);     // 1456 => [81984]
-            map.insert(1966, &[147520]);     // 1966 => [147520]
-            map.insert(250, &[278592]);     // 250 => [278592]
-            map.insert(717, &[540736]);     // 717 => [540736]
-            map.insert(1750, &[1065024]);     // 1750 => [1065024]
-            map.insert(661, &[98368]);     // 661 => [98368]
-            map.insert(139, &[163904]);     // 139 => [163904]
-            map.insert(2015, &[294976]);     // 2015 => [294976]
-            map.insert(1512, &[557120]);     // 1512 => [557120]
-            map.insert(499, &[1081408]);     // 499 => [1081408]
-            map.insert(606, &[196672]);     // 606 => [196672]
-            map.insert(1290, &[327744]);     // 1290 => [327744]
-            map.insert(1853, &[589888]);     // 1853 => [589888]
-            map.insert(806, &[1114176]);     // 806 => [1114176]
-            map.insert(1812, &[393280]);     // 1812 => [393280]
-            map.insert(1315, &[655424]);     // 1315 => [655424]
-            map.insert(312, &[1179712]);     // 312 => [1179712]
-            map.insert(631, &[786496]);     // 631 => [786496]
-            map.insert(1644, &[1310784]);     // 1644 => [1310784]
-            map.insert(1115, &[1572928]);     // 1115 => [1572928]
-            map.insert(896, &[896]);     // 896 => [896]
-            map.insert(564, &[1408]);     // 564 => [1408]
-            map.insert(1408, &[2432]);     // 1408 => [2432]
-            map.insert(683, &[4480]);     // 683 => [4480]
-            map.insert(1214, &[8576]);     // 1214 => [8576]
-            map.insert(215, &[16768]);     // 215 => [16768]
-            map.insert(2034, &[33152]);     // 2034 => [33152]
-            map.insert(1319, &[65920]);     // 1319 => [65920]
-            map.insert(1849, &[131456]);     // 1849 => [131456]
-            map.insert(109, &[262528]);     // 109 => [262528]
-            map.insert(602, &[524672]);     // 602 => [524672]
-            map.insert(1601, &[1048960]);     // 1601 => [1048960]
-            map.insert(308, &[1664]);     // 308 => [1664]
-            map.insert(1664, &[2688]);     // 1664 => [2688]
-            map.insert(427, &[4736]);     // 427 => [4736]
-            map.insert(1982, &[8832]);     // 1982 => [8832]
-            map.insert(983, &[17024]);     // 983 => [17024]
-            map.insert(1266, &[33408]);     // 1266 => [33408]
-            map.insert(1575, &[66176]);     // 1575 => [66176]
-            map.insert(1081, &[131712]);     // 1081 => [131712]
-            map.insert(877, &[262784]);     // 877 => [262784]
-            map.insert(346, &[524928]);     // 346 => [524928]
-            map.insert(1345, &[1049216]);     // 1345 => [1049216]
-            map.insert(1844, &[3200]);     // 1844 => [3200]
-            map.insert(31, &[5248]);     // 31 => [5248]
-            map.insert(1546, &[9344]);     // 1546 => [9344]
-            map.insert(611, &[17536]);     // 611 => [17536]
-            map.insert(1350, &[33920]);     // 1350 => [33920]
-            map.insert(1939, &[66688]);     // 1939 => [66688]
-            map.insert(1421, &[132224]);     // 1421 => [132224]
-            map.insert(729, &[263296]);     // 729 => [263296]
-            map.insert(238, &[525440]);     // 238 => [525440]
-            map.insert(1269, &[1049728]);     // 1269 => [1049728]
-            map.insert(1963, &[6272]);     // 1963 => [6272]
-            map.insert(446, &[10368]);     // 446 => [10368]
-            map.insert(1495, &[18560]);     // 1495 => [18560]
-            map.insert(754, &[34944]);     // 754 => [34944]
-            map.insert(39, &[67712]);     // 39 => [67712]
-            map.insert(569, &[133248]);     // 569 => [133248]
-            map.insert(1389, &[264320]);     // 1389 => [264320]
-            map.insert(1882, &[526464]);     // 1882 => [526464]
-            map.insert(833, &[1050752]);     // 833 => [1050752]
-            map.insert(1685, &[12416]);     // 1685 => [12416]
-            map.insert(764, &[20608]);     // 764 => [20608]
-            map.insert(1497, &[36992]);     // 1497 => [36992]
-            map.insert(1804, &[69760]);     // 1804 => [69760]
-            map.insert(1298, &[135296]);     // 1298 => [135296]
-            map.insert(582, &[266368]);     // 582 => [266368]
-            map.insert(113, &[528512]);     // 113 => [528512]
-            map.insert(1130, &[1052800]);     // 1130 => [1052800]
-            map.insert(1257, &[24704]);     // 1257 => [24704]
-            map.insert(972, &[41088]);     // 972 => [41088]
-            map.insert(281, &[73856]);     // 281 => [73856]
-            map.insert(775, &[139392]);     // 775 => [139392]
-            map.insert(1107, &[270464]);     // 1107 => [270464]
-            map.insert(1636, &[532608]);     // 1636 => [532608]
-            map.insert(639, &[1056896]);     // 639 => [1056896]
-            map.insert(1957, &[49280]);     // 1957 => [49280]
-            map.insert(1392, &[82048]);     // 1392 => [82048]
-            map.insert(1902, &[147584]);     // 1902 => [147584]
-            map.insert(58, &[278656]);     // 58 => [278656]
-            map.insert(525, &[540800]);     // 525 => [540800]
-            map.insert(1558, &[1065088]);     // 1558 => [1065088]
-            map.insert(597, &[98432]);     // 597 => [98432]
-            map.insert(75, &[163968]);     // 75 => [163968]
-            map.insert(1823, &[295040]);     // 1823 => [295040]
-            map.insert(1320, &[557184]);     // 1320 => [557184]
-            map.insert(307, &[1081472]);     // 307 => [1081472]
-            map.insert(670, &[196736]);     // 670 => [196736]
-            map.insert(1482, &[327808]);     // 1482 => [327808]
-            map.insert(2045, &[589952]);     // 2045 => [589952]
-            map.insert(998, &[1114240]);     // 998 => [1114240]
-            map.insert(2004, &[393344]);     // 2004 => [393344]
-            map.insert(1507, &[655488]);     // 1507 => [655488]
-            map.insert(504, &[1179776]);     // 504 => [1179776]
-            map.insert(695, &[786560]);     // 695 => [786560]
-            map.insert(1708, &[1310848]);     // 1708 => [1310848]
-            map.insert(1179, &[1572992]);     // 1179 => [1572992]
-            map.insert(180, &[1792]);     // 180 => [1792]
-            map.insert(1792, &[2816]);     // 1792 => [2816]
-            map.insert(43, &[4864]);     // 43 => [4864]
-            map.insert(1598, &[8960]);     // 1598 => [8960]
-            map.insert(599, &[17152]);     // 599 => [17152]
-            map.insert(1394, &[33536]);     // 1394 => [33536]
-            map.insert(1959, &[66304]);     // 1959 => [66304]
-            map.insert(1465, &[131840]);     // 1465 => [131840]
-            map.insert(749, &[262912]);     // 749 => [262912]
-            map.insert(218, &[525056]);     // 218 => [525056]
-            map.insert(1217, &[1049344]);     // 1217 => [1049344]
-            map.insert(1716, &[3328]);     // 1716 => [3328]
-            map.insert(415, &[5376]);     // 415 => [5376]
-            map.insert(1930, &[9472]);     // 1930 => [9472]
-            map.insert(995, &[17664]);     // 995 => [17664]
-            map.insert(1222, &[34048]);     // 1222 => [34048]
-            map.insert(1555, &[66816]);     // 1555 => [66816]
-            map.insert(1037, &[132352]);     // 1037 => [132352]
-            map.insert(857, &[263424]);     // 857 => [263424]
-            map.insert(366, &[525568]);     // 366 => [525568]
-            map.insert(1397, &[1049856]);     // 1397 => [1049856]
-            map.insert(1579, &[6400]);     // 1579 => [6400]
-            map.insert(62, &[10496]);     // 62 => [10496]
-            map.insert(1111, &[18688]);     // 1111 => [18688]
-            map.insert(882, &[35072]);     // 882 => [35072]
-            map.insert(423, &[67840]);     // 423 => [67840]
-            map.insert(953, &[133376]);     // 953 => [133376]
-            map.insert(1261, &[264448]);     // 1261 => [264448]
-            map.insert(1754, &[526592]);     // 1754 => [526592]
-            map.insert(705, &[1050880]);     // 705 => [1050880]
-            map.insert(1813, &[12544]);     // 1813 => [12544]
-            map.insert(892, &[20736]);     // 892 => [20736]
-            map.insert(1113, &[37120]);     // 1113 => [37120]
-            map.insert(1676, &[69888]);     // 1676 => [69888]
-            map.insert(1170, &[135424]);     // 1170 => [135424]
-            map.insert(966, &[266496]);     // 966 => [266496]
-            map.insert(497, &[528640]);     // 497 => [528640]
-            map.insert(1514, &[1052928]);     // 1514 => [1052928]
-            map.insert(1385, &[24832]);     // 1385 => [24832]
-            map.insert(588, &[41216]);     // 588 => [41216]
-            map.insert(153, &[73984]);     // 153 => [73984]
-            map.insert(647, &[139520]);     // 647 => [139520]
-            map.insert(1491, &[270592]);     // 1491 => [270592]
-            map.insert(2020, &[532736]);     // 2020 => [532736]
-            map.insert(1023, &[1057024]);     // 1023 => [1057024]
-            map.insert(1573, &[49408]);     // 1573 => [49408]
-            map.insert(1264, &[82176]);     // 1264 => [82176]
-            map.insert(1774, &[147712]);     // 1774 => [147712]
-            map.insert(442, &[278784]);     // 442 => [278784]
-            map.insert(909, &[540928]);     // 909 => [540928]
-            map.insert(1942, &[1065216]);     // 1942 => [1065216]
-            map.insert(981, &[98560]);     // 981 => [98560]
-            map.insert(459, &[164096]);     // 459 => [164096]
-            map.insert(1695, &[295168]);     // 1695 => [295168]
-            map.insert(1192, &[557312]);     // 1192 => [557312]
-            map.insert(179, &[1081600]);     // 179 => [1081600]
-            map.insert(798, &[196864]);     // 798 => [196864]
-            map.insert(1098, &[327936]);     // 1098 => [327936]
-            map.insert(1661, &[590080]);     // 1661 => [590080]
-            map.insert(614, &[1114368]);     // 614 => [1114368]
-            map.insert(1620, &[393472]);     // 1620 => [393472]
-            map.insert(1123, &[655616]);     // 1123 => [655616]
-            map.insert(120, &[1179904]);     // 120 => [1179904]
-            map.insert(823, &[786688]);     // 823 => [786688]
-            map.insert(1836, &[1310976]);     // 1836 => [1310976]
-            map.insert(1307, &[1573120]);     // 1307 => [1573120]
-            map.insert(1460, &[3584]);     // 1460 => [3584]
-            map.insert(671, &[5632]);     // 671 => [5632]
-            map.insert(1162, &[9728]);     // 1162 => [9728]
-            map.insert(227, &[17920]);     // 227 => [17920]
-            map.insert(1990, &[34304]);     // 1990 => [34304]
-            map.insert(1299, &[67072]);     // 1299 => [67072]
-            map.insert(1805, &[132608]);     // 1805 => [132608]
-            map.insert(89, &[263680]);     // 89 => [263680]
-            map.insert(622, &[525824]);     // 622 => [525824]
-            map.insert(1653, &[1050112]);     // 1653 => [1050112]
-            map.insert(1323, &[6656]);     // 1323 => [6656]
-            map.insert(830, &[10752]);     // 830 => [10752]
-            map.insert(1879, &[18944]);     // 1879 => [18944]
-            map.insert(114, &[35328]);     // 114 => [35328]
-            map.insert(679, &[68096]);     // 679 => [68096]
-            map.insert(185, &[133632]);     // 185 => [133632]
-            map.insert(2029, &[264704]);     // 2029 => [264704]
-            map.insert(1498, &[526848]);     // 1498 => [526848]
-            map.insert(449, &[1051136]);     // 449 => [1051136]
-            map.insert(1045, &[12800]);     // 1045 => [12800]
-            map.insert(124, &[20992]);     // 124 => [20992]
-            map.insert(1881, &[37376]);     // 1881 => [37376]
-            map.insert(1420, &[70144]);     // 1420 => [70144]
-            map.insert(1938, &[135680]);     // 1938 => [135680]
-            map.insert(198, &[266752]);     // 198 => [266752]
-            map.insert(753, &[528896]);     // 753 => [528896]
-            map.insert(1770, &[1053184]);     // 1770 => [1053184]
-            map.insert(1641, &[25088]);     // 1641 => [25088]
-            map.insert(332, &[41472]);     // 332 => [41472]
-            map.insert(921, &[74240]);     // 921 => [74240]
-            map.insert(391, &[139776]);     // 391 => [139776]
-            map.insert(1747, &[270848]);     // 1747 => [270848]
-            map.insert(1252, &[532992]);     // 1252 => [532992]
-            map.insert(255, &[1057280]);     // 255 => [1057280]
-            map.insert(1317, &[49664]);     // 1317 => [49664]
-            map.insert(2032, &[82432]);     // 2032 => [82432]
-            map.insert(1518, &[147968]);     // 1518 => [147968]
-            map.insert(698, &[279040]);     // 698 => [279040]
-            map.insert(141, &[541184]);     // 141 => [541184]
-            map.insert(1174, &[1065472]);     // 1174 => [1065472]
-            map.insert(213, &[98816]);     // 213 => [98816]
-            map.insert(715, &[164352]);     // 715 => [164352]
-            map.insert(1439, &[295424]);     // 1439 => [295424]
-            map.insert(1960, &[557568]);     // 1960 => [557568]
-            map.insert(947, &[1081856]);     // 947 => [1081856]
-            map.insert(30, &[197120]);     // 30 => [197120]
-            map.insert(1866, &[328192]);     // 1866 => [328192]
-            map.insert(1405, &[590336]);     // 1405 => [590336]
-            map.insert(358, &[1114624]);     // 358 => [1114624]
-            map.insert(1364, &[393728]);     // 1364 => [393728]
-            map.insert(1891, &[655872]);     // 1891 => [655872]
-            map.insert(888, &[1180160]);     // 888 => [1180160]
-            map.insert(55, &[786944]);     // 55 => [786944]
-            map.insert(1068, &[1311232]);     // 1068 => [1311232]
-            map.insert(1563, &[1573376]);     // 1563 => [1573376]
-            map.insert(1183, &[7168]);     // 1183 => [7168]
-            map.insert(650, &[11264]);     // 650 => [11264]
-            map.insert(1763, &[19456]);     // 1763 => [19456]
-            map.insert(454, &[35840]);     // 454 => [35840]
-            map.insert(787, &[68608]);     // 787 => [68608]
-            map.insert(269, &[134144]);     // 269 => [134144]
-            map.insert(1625, &[265216]);     // 1625 => [265216]
-            map.insert(1134, &[527360]);     // 1134 => [527360]
-            map.insert(117, &[1051648]);     // 117 => [1051648]
-            map.insert(1441, &[13312]);     // 1441 => [13312]
-            map.insert(456, &[21504]);     // 456 => [21504]
-            map.insert(1773, &[37888]);     // 1773 => [37888]
-            map.insert(1080, &[70656]);     // 1080 => [70656]
-            map.insert(1574, &[136192]);     // 1574 => [136192]
-            map.insert(370, &[267264]);     // 370 => [267264]
-            map.insert(837, &[529408]);     // 837 => [529408]
-            map.insert(1886, &[1053696]);     // 1886 => [1053696]
-            map.insert(2013, &[25600]);     // 2013 => [25600]
-            map.insert(248, &[41984]);     // 248 => [41984]
-            map.insert(557, &[74752]);     // 557 => [74752]
-            map.insert(51, &[140288]);     // 51 => [140288]
-            map.insert(1895, &[271360]);     // 1895 => [271360]
-            map.insert(1360, &[533504]);     // 1360 => [533504]
-            map.insert(331, &[1057792]);     // 331 => [1057792]
-            map.insert(1169, &[50176]);     // 1169 => [50176]
-            map.insert(1604, &[82944]);     // 1604 => [82944]
-            map.insert(1114, &[148480]);     // 1114 => [148480]
-            map.insert(782, &[279552]);     // 782 => [279552]
-            map.insert(313, &[541696]);     // 313 => [541696]
-            map.insert(1314, &[1065984]);     // 1314 => [1065984]
-            map.insert(353, &[99328]);     // 353 => [99328]
-            map.insert(895, &[164864]);     // 895 => [164864]
-            map.insert(1067, &[295936]);     // 1067 => [295936]
-            map.insert(1564, &[558080]);     // 1564 => [558080]
-            map.insert(519, &[1082368]);     // 519 => [1082368]
-            map.insert(426, &[197632]);     // 426 => [197632]
-            map.insert(1790, &[328704]);     // 1790 => [328704]
-            map.insert(1225, &[590848]);     // 1225 => [590848]
-            map.insert(210, &[1115136]);     // 210 => [1115136]
-            map.insert(1248, &[394240]);     // 1248 => [394240]
-            map.insert(1751, &[656384]);     // 1751 => [656384]
-            map.insert(716, &[1180672]);     // 716 => [1180672]
-            map.insert(387, &[787456]);     // 387 => [787456]
-            map.insert(1432, &[1311744]);     // 1432 => [1311744]
-            map.insert(1967, &[1573888]);     // 1967 => [1573888]
-            map.insert(533, &[14336]);     // 533 => [14336]
-            map.insert(1660, &[22528]);     // 1660 => [22528]
-            map.insert(345, &[38912]);     // 345 => [38912]
-            map.insert(908, &[71680]);     // 908 => [71680]
-            map.insert(402, &[137216]);     // 402 => [137216]
-            map.insert(1734, &[268288]);     // 1734 => [268288]
-            map.insert(1265, &[530432]);     // 1265 => [530432]
-            map.insert(234, &[1054720]);     // 234 => [1054720]
-            map.insert(105, &[26624]);     // 105 => [26624]
-            map.insert(1868, &[43008]);     // 1868 => [43008]
-            map.insert(1433, &[75776]);     // 1433 => [75776]
-            map.insert(1927, &[141312]);     // 1927 => [141312]
-            map.insert(211, &[272384]);     // 211 => [272384]
-            map.insert(740, &[534528]);     // 740 => [534528]
-            map.insert(1791, &[1058816]);     // 1791 => [1058816]
-            map.insert(805, &[51200]);     // 805 => [51200]
-            map.insert(496, &[83968]);     // 496 => [83968]
-            map.insert(1006, &[149504]);     // 1006 => [149504]
-            map.insert(1210, &[280576]);     // 1210 => [280576]
-            map.insert(1677, &[542720]);     // 1677 => [542720]
-            map.insert(662, &[1067008]);     // 662 => [1067008]
-            map.insert(1749, &[100352]);     // 1749 => [100352]
-            map.insert(1227, &[165888]);     // 1227 => [165888]
-            map.insert(927, &[296960]);     // 927 => [296960]
-            map.insert(424, &[559104]);     // 424 => [559104]
-            map.insert(1459, &[1083392]);     // 1459 => [1083392]
-            map.insert(1566, &[198656]);     // 1566 => [198656]
-            map.insert(330, &[329728]);     // 330 => [329728]
-            map.insert(893, &[591872]);     // 893 => [591872]
-            map.insert(1894, &[1116160]);     // 1894 => [1116160]
-            map.insert(852, &[395264]);     // 852 => [395264]
-            map.insert(355, &[657408]);     // 355 => [657408]
-            map.insert(1400, &[1181696]);     // 1400 => [1181696]
-            map.insert(1591, &[788480]);     // 1591 => [788480]
-            map.insert(556, &[1312768]);     // 556 => [1312768]
-            map.insert(27, &[1574912]);     // 27 => [1574912]
-            map.insert(1858, &[28672]);     // 1858 => [28672]
-            map.insert(103, &[45056]);     // 103 => [45056]
-            map.insert(690, &[77824]);     // 690 => [77824]
-            map.insert(172, &[143360]);     // 172 => [143360]
-            map.insert(2040, &[274432]);     // 2040 => [274432]
-            map.insert(1487, &[536576]);     // 1487 => [536576]
-            map.insert(468, &[1060864]);     // 468 => [1060864]
-            map.insert(1038, &[53248]);     // 1038 => [53248]
-            map.insert(1755, &[86016]);     // 1755 => [86016]
-            map.insert(1221, &[151552]);     // 1221 => [151552]
-            map.insert(913, &[282624]);     // 913 => [282624]
-            map.insert(422, &[544768]);     // 422 => [544768]
-            map.insert(1469, &[1069056]);     // 1469 => [1069056]
-            map.insert(510, &[102400]);     // 510 => [102400]
-            map.insert(992, &[167936]);     // 992 => [167936]
-            map.insert(1204, &[299008]);     // 1204 => [299008]
-            map.insert(1667, &[561152]);     // 1667 => [561152]
-            map.insert(664, &[1085440]);     // 664 => [1085440]
-            map.insert(309, &[200704]);     // 309 => [200704]
-            map.insert(1633, &[331776]);     // 1633 => [331776]
-            map.insert(1110, &[593920]);     // 1110 => [593920]
-            map.insert(77, &[1118208]);     // 77 => [1118208]
-            map.insert(1151, &[397312]);     // 1151 => [397312]
-            map.insert(1608, &[659456]);     // 1608 => [659456]
-            map.insert(595, &[1183744]);     // 595 => [1183744]
-            map.insert(284, &[790528]);     // 284 => [790528]
-            map.insert(1287, &[1314816]);     // 1287 => [1314816]
-            map.insert(1840, &[1576960]);     // 1840 => [1576960]
-            map.insert(539, &[57344]);     // 539 => [57344]
-            map.insert(206, &[90112]);     // 206 => [90112]
-            map.insert(720, &[155648]);     // 720 => [155648]
-            map.insert(1412, &[286720]);     // 1412 => [286720]
-            map.insert(1971, &[548864]);     // 1971 => [548864]
-            map.insert(936, &[1073152]);     // 936 => [1073152]
-            map.insert(2027, &[106496]);     // 2027 => [106496]
-            map.insert(1525, &[172032]);     // 1525 => [172032]
-            map.insert(673, &[303104]);     // 673 => [303104]
-            map.insert(150, &[565248]);     // 150 => [565248]
-            map.insert(1165, &[1089536]);     // 1165 => [1089536]
-            map.insert(1824, &[204800]);     // 1824 => [204800]
-            map.insert(116, &[335872]);     // 116 => [335872]
-            map.insert(579, &[598016]);     // 579 => [598016]
-            map.insert(1624, &[1122304]);     // 1624 => [1122304]
-            map.insert(618, &[401408]);     // 618 => [401408]
-            map.insert(93, &[663552]);     // 93 => [663552]
-            map.insert(1094, &[1187840]);     // 1094 => [1187840]
-            map.insert(1801, &[794624]);     // 1801 => [794624]
-            map.insert(786, &[1318912]);     // 786 => [1318912]
-            map.insert(293, &[1581056]);     // 293 => [1581056]
-            map.insert(898, &[114688]);     // 898 => [114688]
-            map.insert(412, &[180224]);     // 412 => [180224]
-            map.insert(1736, &[311296]);     // 1736 => [311296]
-            map.insert(1279, &[573440]);     // 1279 => [573440]
-            map.insert(228, &[1097728]);     // 228 => [1097728]
-            map.insert(841, &[212992]);     // 841 => [212992]
-            map.insert(1053, &[344064]);     // 1053 => [344064]
-            map.insert(1578, &[606208]);     // 1578 => [606208]
-            map.insert(561, &[1130496]);     // 561 => [1130496]
-            map.insert(1539, &[409600]);     // 1539 => [409600]
-            map.insert(1076, &[671744]);     // 1076 => [671744]
-            map.insert(47, &[1196032]);     // 47 => [1196032]
-            map.insert(864, &[802816]);     // 864 => [802816]
-            map.insert(1915, &[1327104]);     // 1915 => [1327104]
-            map.insert(1356, &[1589248]);     // 1356 => [1589248]
-            map.insert(1132, &[229376]);     // 1132 => [229376]
-            map.insert(824, &[360448]);     // 824 => [360448]
-            map.insert(271, &[622592]);     // 271 => [622592]
-            map.insert(1300, &[1146880]);     // 1300 => [1146880]
-            map.insert(294, &[425984]);     // 294 => [425984]
-            map.insert(785, &[688128]);     // 785 => [688128]
-            map.insert(1802, &[1212416]);     // 1802 => [1212416]
-            map.insert(1093, &[819200]);     // 1093 => [819200]
-            map.insert(94, &[1343488]);     // 94 => [1343488]
-            map.insert(617, &[1605632]);     // 617 => [1605632]
-            map.insert(1011, &[458752]);     // 1011 => [458752]
-            map.insert(452, &[720896]);     // 452 => [720896]
-            map.insert(1503, &[1245184]);     // 1503 => [1245184]
-            map.insert(1680, &[851968]);     // 1680 => [851968]
-            map.insert(651, &[1376256]);     // 651 => [1376256]
-            map.insert(188, &[1638400]);     // 188 => [1638400]
-            map.insert(1166, &[917504]);     // 1166 => [917504]
-            map.insert(149, &[1441792]);     // 149 => [1441792]
-            map.insert(674, &[1703936]);     // 674 => [1703936]
-            map.insert(1526, &[1835008]);     // 1526 => [1835008]
-            map.insert(15, &[15]);     // 15 => [15]
-            map.insert(812, &[4103]);     // 812 => [4103]
-            map.insert(1337, &[8199]);     // 1337 => [8199]
-            map.insert(1726, &[131079]);     // 1726 => [131079]
-            map.insert(490, &[262151]);     // 490 => [262151]
-            map.insert(989, &[524295]);     // 989 => [524295]
-            map.insert(959, &[1035]);     // 959 => [1035]
-            map.insert(1333, &[8203]);     // 1333 => [8203]
-            map.insert(348, &[16395]);     // 348 => [16395]
-            map.insert(1196, &[65547]);     // 1196 => [65547]
-            map.insert(486, &[262155]);     // 486 => [262155]
-            map.insert(147, &[147]);     // 147 => [147]
-            map.insert(531, &[531]);     // 531 => [531]
-            map.insert(99, &[99]);     // 99 => [99]
-            map.insert(372, &[16419]);     // 372 => [16419]
-            map.insert(1617, &[32803]);     // 1617 => [32803]
-            map.insert(462, &[262179]);     // 462 => [262179]
-            map.insert(1017, &[524323]);     // 1017 => [524323]
-            map.insert(2018, &[1048611]);     // 2018 => [1048611]
-            map.insert(1015, &[1091]);     // 1015 => [1091]
-            map.insert(1091, &[2115]);     // 1091 => [2115]
-            map.insert(872, &[4163]);     // 872 => [4163]
-            map.insert(430, &[262211]);     // 430 => [262211]
-            map.insert(1922, &[1048643]);     // 1922 => [1048643]
-            map.insert(643, &[643]);     // 643 => [643]
-            map.insert(1283, &[2307]);     // 1283 => [2307]
-            map.insert(1085, &[8451]);     // 1085 => [8451]
-            map.insert(1905, &[33027]);     // 1905 => [33027]
-            map.insert(1444, &[65795]);     // 1444 => [65795]
-            map.insert(1978, &[131331]);     // 1978 => [131331]
-            map.insert(1730, &[1048835]);     // 1730 => [1048835]
-            map.insert(1975, &[3075]);     // 1975 => [3075]
-            map.insert(156, &[5123]);     // 156 => [5123]
-            map.insert(736, &[17411]);     // 736 => [17411]
-            map.insert(1808, &[66563]);     // 1808 => [66563]
-            map.insert(1832, &[6147]);     // 1832 => [6147]
-            map.insert(317, &[10243]);     // 317 => [10243]
-            map.insert(625, &[34819]);     // 625 => [34819]
-            map.insert(1425, &[135171]);     // 1425 => [135171]
-            map.insert(242, &[528387]);     // 242 => [528387]
-            map.insert(847, &[40963]);     // 847 => [40963]
-            map.insert(1232, &[270339]);     // 1232 => [270339]
-            map.insert(1830, &[49155]);     // 1830 => [49155]
-            map.insert(1523, &[81923]);     // 1523 => [81923]
-            map.insert(654, &[540675]);     // 654 => [540675]
-            map.insert(1451, &[557059]);     // 1451 => [557059]
-            map.insert(541, &[196611]);     // 541 => [196611]
-            map.insert(869, &[1114115]);     // 869 => [1114115]
-            map.insert(1376, &[655363]);     // 1376 => [655363]
-            map.insert(379, &[1179651]);     // 379 => [1179651]
-            map.insert(1583, &[1310723]);     // 1583 => [1310723]
-            map.insert(29, &[29]);     // 29 => [29]
-            map.insert(45, &[45]);     // 45 => [45]
-            map.insert(1331, &[8205]);     // 1331 => [8205]
-            map.insert(1663, &[32781]);     // 1663 => [32781]
-            map.insert(480, &[262157]);     // 480 => [262157]
-            map.insert(53, &[53]);     // 53 => [53]
-            map.insert(929, &[1045]);     // 929 => [1045]
-            map.insert(1639, &[32789]);     // 1639 => [32789]
-            map.insert(1202, &[65557]);     // 1202 => [65557]
-            map.insert(975, &[524309]);     // 975 => [524309]
-            map.insert(101, &[101]);     // 101 => [101]
-            map.insert(549, &[549]);     // 549 => [549]
-            map.insert(1623, &[32805]);     // 1623 => [32805]
-            map.insert(1692, &[131109]);     // 1692 => [131109]
-            map.insert(197, &[197]);     // 197 => [197]
-            map.insert(581, &[581]);     // 581 => [581]
-            map.insert(1009, &[1093]);     // 1009 => [1093]
-            map.insert(1403, &[8261]);     // 1403 => [8261]
-            map.insert(1788, &[131141]);     // 1788 => [131141]
-            map.insert(389, &[389]);     // 389 => [389]
-            map.insert(817, &[1157]);     // 817 => [1157]
-            map.insert(942, &[4229]);     // 942 => [4229]
-            map.insert(1467, &[8325]);     // 1467 => [8325]
-            map.insert(1783, &[32901]);     // 1783 => [32901]
-            map.insert(773, &[773]);     // 773 => [773]
-            map.insert(558, &[4357]);     // 558 => [4357]
-            map.insert(1911, &[33029]);     // 1911 => [33029]
-            map.insert(1442, &[65797]);     // 1442 => [65797]
-            map.insert(232, &[262405]);     // 232 => [262405]
-            map.insert(1698, &[66053]);     // 1698 => [66053]
-            map.insert(1212, &[131589]);     // 1212 => [131589]
-            map.insert(1000, &[262661]);     // 1000 => [262661]
-            map.insert(1679, &[9221]);     // 1679 => [9221]
-            map.insert(1814, &[66565]);     // 1814 => [66565]
-            map.insert(107, &[525317]);     // 107 => [525317]
-            map.insert(1362, &[18437]);     // 1362 => [18437]
-            map.insert(964, &[1050629]);     // 964 => [1050629]
-            map.insert(1372, &[36869]);     // 1372 => [36869]
-            map.insert(1431, &[135173]);     // 1431 => [135173]
-            map.insert(244, &[528389]);     // 244 => [528389]
-            map.insert(1238, &[270341]);     // 1238 => [270341]
-            map.insert(1683, &[1064965]);     // 1683 => [1064965]
-            map.insert(1359, &[327685]);     // 1359 => [327685]
-            map.insert(1912, &[589829]);     // 1912 => [589829]
-            map.insert(1382, &[655365]);     // 1382 => [655365]
-            map.insert(57, &[57]);     // 57 => [57]
-            map.insert(818, &[4121]);     // 818 => [4121]
-            map.insert(334, &[16409]);     // 334 => [16409]
-            map.insert(1643, &[32793]);     // 1643 => [32793]
-            map.insert(1696, &[131097]);     // 1696 => [131097]
-            map.insert(169, &[169]);     // 169 => [169]
-            map.insert(925, &[1065]);     // 925 => [1065]
-            map.insert(1627, &[32809]);     // 1627 => [32809]
-            map.insert(2024, &[1048617]);     // 2024 => [1048617]
-            map.insert(329, &[329]);     // 329 => [329]
-            map.insert(915, &[524361]);     // 915 => [524361]
-            map.insert(829, &[1161]);     // 829 => [1161]
-            map.insert(1161, &[2185]);     // 1161 => [2185]
-            map.insert(930, &[4233]);     // 930 => [4233]
-            map.insert(356, &[262281]);     // 356 => [262281]
-            map.insert(1864, &[1048713]);     // 1864 => [1048713]
-            map.insert(723, &[524553]);     // 723 => [524553]
-            map.insert(1545, &[2569]);     // 1545 => [2569]
-            map.insert(1847, &[8713]);     // 1847 => [8713]
-            map.insert(1147, &[33289]);     // 1147 => [33289]
-            map.insert(1710, &[66057]);     // 1710 => [66057]
-            map.insert(1200, &[131593]);     // 1200 => [131593]
-            map.insert(1480, &[1049097]);     // 1480 => [1049097]
-            map.insert(746, &[17417]);     // 746 => [17417]
-            map.insert(1818, &[66569]);     // 1818 => [66569]
-            map.insert(1148, &[1049609]);     // 1148 => [1049609]
-            map.insert(1826, &[6153]);     // 1826 => [6153]
-            map.insert(635, &[34825]);     // 635 => [34825]
-            map.insert(174, &[67593]);     // 174 => [67593]
-            map.insert(1508, &[264201]);     // 1508 => [264201]
-            map.insert(629, &[20489]);     // 629 => [20489]
-            map.insert(1435, &[135177]);     // 1435 => [135177]
-            map.insert(719, &[266249]);     // 719 => [266249]
-            map.insert(910, &[139273]);     // 910 => [139273]
-            map.insert(1242, &[270345]);     // 1242 => [270345]
-            map.insert(758, &[1056777]);     // 758 => [1056777]
-            map.insert(1529, &[81929]);     // 1529 => [81929]
-            map.insert(2023, &[147465]);     // 2023 => [147465]
-            map.insert(732, &[98313]);     // 732 => [98313]
-            map.insert(369, &[1179657]);     // 369 => [1179657]
-            map.insert(305, &[305]);     // 305 => [305]
-            map.insert(1073, &[2097]);     // 1073 => [2097]
-            map.insert(1603, &[32817]);     // 1603 => [32817]
-            map.insert(476, &[262193]);     // 476 => [262193]
-            map.insert(1105, &[2129]);     // 1105 => [2129]
-            map.insert(1391, &[8273]);     // 1391 => [8273]
-            map.insert(1571, &[32849]);     // 1571 => [32849]
-            map.insert(1270, &[65617]);     // 1270 => [65617]
-            map.insert(1768, &[131153]);     // 1768 => [131153]
-            map.insert(1936, &[1048657]);     // 1936 => [1048657]
-            map.insert(657, &[657]);     // 657 => [657]
-            map.insert(677, &[1297]);     // 677 => [1297]
-            map.insert(1297, &[2321]);     // 1297 => [2321]
-            map.insert(570, &[4369]);     // 570 => [4369]
-            map.insert(252, &[262417]);     // 252 => [262417]
-            map.insert(1744, &[1048849]);     // 1744 => [1048849]
-            map.insert(142, &[5137]);     // 142 => [5137]
-            map.insert(1794, &[66577]);     // 1794 => [66577]
-            map.insert(127, &[525329]);     // 127 => [525329]
-            map.insert(1124, &[1049617]);     // 1124 => [1049617]
-            map.insert(182, &[67601]);     // 182 => [67601]
-            map.insert(680, &[133137]);     // 680 => [133137]
-            map.insert(1532, &[264209]);     // 1532 => [264209]
-            map.insert(621, &[20497]);     // 621 => [20497]
-            map.insert(1411, &[135185]);     // 1411 => [135185]
-            map.insert(1275, &[1052689]);     // 1275 => [1052689]
-            map.insert(1144, &[24593]);     // 1144 => [24593]
-            map.insert(861, &[40977]);     // 861 => [40977]
-            map.insert(1218, &[270353]);     // 1218 => [270353]
-            map.insert(1781, &[532497]);     // 1781 => [532497]
-            map.insert(750, &[1056785]);     // 750 => [1056785]
-            map.insert(2047, &[147473]);     // 2047 => [147473]
-            map.insert(171, &[278545]);     // 171 => [278545]
-            map.insert(668, &[540689]);     // 668 => [540689]
-            map.insert(418, &[1081361]);     // 418 => [1081361]
-            map.insert(527, &[196625]);     // 527 => [196625]
-            map.insert(1900, &[589841]);     // 1900 => [589841]
-            map.insert(550, &[786449]);     // 550 => [786449]
-            map.insert(609, &[609]);     // 609 => [609]
-            map.insert(1752, &[131169]);     // 1752 => [131169]
-            map.insert(396, &[262241]);     // 396 => [262241]
-            map.insert(1952, &[1048673]);     // 1952 => [1048673]
-            map.insert(417, &[417]);     // 417 => [417]
-            map.insert(789, &[1185]);     // 789 => [1185]
-            map.insert(502, &[16545]);     // 502 => [16545]
-            map.insert(1560, &[131233]);     // 1560 => [131233]
-            map.insert(1888, &[1048737]);     // 1888 => [1048737]
-            map.insert(1313, &[2337]);     // 1313 => [2337]
-            map.insert(118, &[16673]);     // 118 => [16673]
-            map.insert(1944, &[131361]);     // 1944 => [131361]
-            map.insert(204, &[262433]);     // 204 => [262433]
-            map.insert(405, &[1569]);     // 405 => [1569]
-            map.insert(1569, &[2593]);     // 1569 => [2593]
-            map.insert(1176, &[131617]);     // 1176 => [131617]
-            map.insert(507, &[524833]);     // 507 => [524833]
-            map.insert(1941, &[3105]);     // 1941 => [3105]
-            map.insert(79, &[525345]);     // 79 => [525345]
-            map.insert(1108, &[1049633]);     // 1108 => [1049633]
-            map.insert(1484, &[264225]);     // 1484 => [264225]
-            map.insert(2043, &[526369]);     // 2043 => [526369]
-            map.insert(1588, &[12321]);     // 1588 => [12321]
-            map.insert(1965, &[69665]);     // 1965 => [69665]
-            map.insert(440, &[73761]);     // 440 => [73761]
-            map.insert(1796, &[49185]);     // 1796 => [49185]
-            map.insert(1999, &[147489]);     // 1999 => [147489]
-            map.insert(684, &[540705]);     // 684 => [540705]
-            map.insert(1417, &[557089]);     // 1417 => [557089]
-            map.insert(1549, &[1310753]);     // 1549 => [1310753]
-            map.insert(885, &[1217]);     // 885 => [1217]
-            map.insert(1535, &[8385]);     // 1535 => [8385]
-            map.insert(406, &[16577]);     // 406 => [16577]
-            map.insert(1126, &[65729]);     // 1126 => [65729]
-            map.insert(300, &[262337]);     // 300 => [262337]
-            map.insert(667, &[524609]);     // 667 => [524609]
-            map.insert(362, &[4673]);     // 362 => [4673]
-            map.insert(790, &[16961]);     // 790 => [16961]
-            map.insert(1075, &[33345]);     // 1075 => [33345]
-            map.insert(1272, &[131649]);     // 1272 => [131649]
-            map.insert(2037, &[3137]);     // 2037 => [3137]
-            map.insert(222, &[5185]);     // 222 => [5185]
-            map.insert(1739, &[9281]);     // 1739 => [9281]
-            map.insert(1898, &[6209]);     // 1898 => [6209]
-            map.insert(230, &[67649]);     // 230 => [67649]
-            map.insert(760, &[133185]);     // 760 => [133185]
-            map.insert(573, &[20545]);     // 573 => [20545]
-            map.insert(1304, &[36929]);     // 1304 => [36929]
-            map.insert(781, &[41025]);     // 781 => [41025]
-            map.insert(702, &[1056833]);     // 702 => [1056833]
-            map.insert(1892, &[49217]);     // 1892 => [49217]
-            map.insert(1457, &[81985]);     // 1457 => [81985]
-            map.insert(251, &[278593]);     // 251 => [278593]
-            map.insert(498, &[1081409]);     // 498 => [1081409]
-            map.insert(607, &[196673]);     // 607 => [196673]
-            map.insert(1291, &[327745]);     // 1291 => [327745]
-            map.insert(1645, &[1310785]);     // 1645 => [1310785]
-            map.insert(682, &[4481]);     // 682 => [4481]
-            map.insert(214, &[16769]);     // 214 => [16769]
-            map.insert(2035, &[33153]);     // 2035 => [33153]
-            map.insert(1848, &[131457]);     // 1848 => [131457]
-            map.insert(1547, &[9345]);     // 1547 => [9345]
-            map.insert(1268, &[1049729]);     // 1268 => [1049729]
-            map.insert(1962, &[6273]);     // 1962 => [6273]
-            map.insert(447, &[10369]);     // 447 => [10369]
-            map.insert(568, &[133249]);     // 568 => [133249]
-            map.insert(1388, &[264321]);     // 1388 => [264321]
-            map.insert(1883, &[526465]);     // 1883 => [526465]
-            map.insert(1496, &[36993]);     // 1496 => [36993]
-            map.insert(583, &[266369]);     // 583 => [266369]
-            map.insert(774, &[139393]);     // 774 => [139393]
-            map.insert(1637, &[532609]);     // 1637 => [532609]
-            map.insert(1393, &[82049]);     // 1393 => [82049]
-            map.insert(1903, &[147585]);     // 1903 => [147585]
-            map.insert(59, &[278657]);     // 59 => [278657]
-            map.insert(596, &[98433]);     // 596 => [98433]
-            map.insert(1321, &[557185]);     // 1321 => [557185]
-            map.insert(306, &[1081473]);     // 306 => [1081473]
-            map.insert(1483, &[327809]);     // 1483 => [327809]
-            map.insert(2044, &[589953]);     // 2044 => [589953]
-            map.insert(999, &[1114241]);     // 999 => [1114241]
-            map.insert(1506, &[655489]);     // 1506 => [655489]
-            map.insert(1178, &[1572993]);     // 1178 => [1572993]
-            map.insert(181, &[1793]);     // 181 => [1793]
-            map.insert(1599, &[8961]);     // 1599 => [8961]
-            map.insert(598, &[17153]);     // 598 => [17153]
-            map.insert(1958, &[66305]);     // 1958 => [66305]
-            map.insert(748, &[262913]);     // 748 => [262913]
-            map.insert(414, &[5377]);     // 414 => [5377]
-            map.insert(1931, &[9473]);     // 1931 => [9473]
-            map.insert(994, &[17665]);     // 994 => [17665]
-            map.insert(1396, &[1049857]);     // 1396 => [1049857]
-            map.insert(63, &[10497]);     // 63 => [10497]
-            map.insert(883, &[35073]);     // 883 => [35073]
-            map.insert(1260, &[264449]);     // 1260 => [264449]
-            map.insert(1112, &[37121]);     // 1112 => [37121]
-            map.insert(1515, &[1052929]);     // 1515 => [1052929]
-            map.insert(1384, &[24833]);     // 1384 => [24833]
-            map.insert(589, &[41217]);     // 589 => [41217]
-            map.insert(1775, &[147713]);     // 1775 => [147713]
-            map.insert(799, &[196865]);     // 799 => [196865]
-            map.insert(1099, &[327937]);     // 1099 => [327937]
-            map.insert(615, &[1114369]);     // 615 => [1114369]
-            map.insert(121, &[1179905]);     // 121 => [1179905]
-            map.insert(1461, &[3585]);     // 1461 => [3585]
-            map.insert(1163, &[9729]);     // 1163 => [9729]
-            map.insert(226, &[17921]);     // 226 => [17921]
-            map.insert(623, &[525825]);     // 623 => [525825]
-            map.insert(115, &[35329]);     // 115 => [35329]
-            map.insert(678, &[68097]);     // 678 => [68097]
-            map.insert(1499, &[526849]);     // 1499 => [526849]
-            map.insert(125, &[20993]);     // 125 => [20993]
-            map.insert(1880, &[37377]);     // 1880 => [37377]
-            map.insert(199, &[266753]);     // 199 => [266753]
-            map.insert(752, &[528897]);     // 752 => [528897]
-            map.insert(1771, &[1053185]);     // 1771 => [1053185]
-            map.insert(1640, &[25089]);     // 1640 => [25089]
-            map.insert(390, &[139777]);     // 390 => [139777]
-            map.insert(254, &[1057281]);     // 254 => [1057281]
-            map.insert(1316, &[49665]);     // 1316 => [49665]
-            map.insert(212, &[98817]);     // 212 => [98817]
-            map.insert(1867, &[328193]);     // 1867 => [328193]
-            map.insert(889, &[1180161]);     // 889 => [1180161]
-            map.insert(54, &[786945]);     // 54 => [786945]
-            map.insert(1069, &[1311233]);     // 1069 => [1311233]
-            map.insert(1562, &[1573377]);     // 1562 => [1573377]
-            map.insert(1182, &[7169]);     // 1182 => [7169]
-            map.insert(1135, &[527361]);     // 1135 => [527361]
-            map.insert(1887, &[1053697]);     // 1887 => [1053697]
-            map.insert(2012, &[25601]);     // 2012 => [25601]
-            map.insert(1605, &[82945]);     // 1605 => [82945]
-            map.insert(894, &[164865]);     // 894 => [164865]
-            map.insert(1066, &[295937]);     // 1066 => [295937]
-            map.insert(1224, &[590849]);     // 1224 => [590849]
-            map.insert(1249, &[394241]);     // 1249 => [394241]
-            map.insert(1735, &[268289]);     // 1735 => [268289]
-            map.insert(1869, &[43009]);     // 1869 => [43009]
-            map.insert(1926, &[141313]);     // 1926 => [141313]
-            map.insert(741, &[534529]);     // 741 => [534529]
-            map.insert(663, &[1067009]);     // 663 => [1067009]
-            map.insert(1748, &[100353]);     // 1748 => [100353]
-            map.insert(1567, &[198657]);     // 1567 => [198657]
-            map.insert(354, &[657409]);     // 354 => [657409]
-            map.insert(691, &[77825]);     // 691 => [77825]
-            map.insert(1039, &[53249]);     // 1039 => [53249]
-            map.insert(1220, &[151553]);     // 1220 => [151553]
-            map.insert(1609, &[659457]);     // 1609 => [659457]
-            map.insert(285, &[790529]);     // 285 => [790529]
-            map.insert(1286, &[1314817]);     // 1286 => [1314817]
-            map.insert(1841, &[1576961]);     // 1841 => [1576961]
-            map.insert(1413, &[286721]);     // 1413 => [286721]
-            map.insert(1970, &[548865]);     // 1970 => [548865]
-            map.insert(1164, &[1089537]);     // 1164 => [1089537]
-            map.insert(92, &[663553]);     // 92 => [663553]
-            map.insert(1095, &[1187841]);     // 1095 => [1187841]
-            map.insert(1800, &[794625]);     // 1800 => [794625]
-            map.insert(1278, &[573441]);     // 1278 => [573441]
-            map.insert(1052, &[344065]);     // 1052 => [344065]
-            map.insert(865, &[802817]);     // 865 => [802817]
-            map.insert(270, &[622593]);     // 270 => [622593]
-            map.insert(1301, &[1146881]);     // 1301 => [1146881]
-            map.insert(295, &[425985]);     // 295 => [425985]
-            map.insert(616, &[1605633]);     // 616 => [1605633]
-            map.insert(1502, &[1245185]);     // 1502 => [1245185]
-            map.insert(189, &[1638401]);     // 189 => [1638401]
-            map.insert(1527, &[1835009]);     // 1527 => [1835009]
-            map.insert(1328, &[8206]);     // 1328 => [8206]
-            map.insert(483, &[262158]);     // 483 => [262158]
-            map.insert(1046, &[2070]);     // 1046 => [2070]
-            map.insert(2007, &[1048598]);     // 2007 => [1048598]
-            map.insert(465, &[16518]);     // 465 => [16518]
-            map.insert(1863, &[1048710]);     // 1863 => [1048710]
-            map.insert(1542, &[2566]);     // 1542 => [2566]
-            map.insert(849, &[16902]);     // 849 => [16902]
-            map.insert(1428, &[135174]);     // 1428 => [135174]
-            map.insert(247, &[528390]);     // 247 => [528390]
-            map.insert(1237, &[270342]);     // 1237 => [270342]
-            map.insert(1381, &[655366]);     // 1381 => [655366]
-            map.insert(90, &[90]);     // 90 => [90]
-            map.insert(282, &[282]);     // 282 => [282]
-            map.insert(586, &[586]);     // 586 => [586]
-            map.insert(394, &[394]);     // 394 => [394]
-            map.insert(848, &[524426]);     // 848 => [524426]
-            map.insert(464, &[524810]);     // 464 => [524810]
-            map.insert(745, &[17418]);     // 745 => [17418]
-            map.insert(1817, &[66570]);     // 1817 => [66570]
-            map.insert(1241, &[270346]);     // 1241 => [270346]
-            map.insert(1530, &[81930]);     // 1530 => [81930]
-            map.insert(793, &[4146]);     // 793 => [4146]
-            map.insert(1173, &[65586]);     // 1173 => [65586]
-            map.insert(904, &[524370]);     // 904 => [524370]
-            map.insert(658, &[658]);     // 658 => [658]
-            map.insert(712, &[524562]);     // 712 => [524562]
-            map.insert(1492, &[33810]);     // 1492 => [33810]
-            map.insert(587, &[263186]);     // 587 => [263186]
-            map.insert(1349, &[18450]);     // 1349 => [18450]
-            map.insert(1543, &[12306]);     // 1543 => [12306]
-            map.insert(395, &[73746]);     // 395 => [73746]
-            map.insert(1668, &[1064978]);     // 1668 => [1064978]
-            map.insert(217, &[163858]);     // 217 => [163858]
-            map.insert(1862, &[393234]);     // 1862 => [393234]
-            map.insert(399, &[262242]);     // 399 => [262242]
-            map.insert(1955, &[1048674]);     // 1955 => [1048674]
-            map.insert(905, &[4258]);     // 905 => [4258]
-            map.insert(1436, &[8354]);     // 1436 => [8354]
-            map.insert(1820, &[8738]);     // 1820 => [8738]
-            map.insert(1669, &[66082]);     // 1669 => [66082]
-            map.insert(1799, &[49186]);     // 1799 => [49186]
-            map.insert(687, &[540706]);     // 687 => [540706]
-            map.insert(1418, &[557090]);     // 1418 => [557090]
-            map.insert(1550, &[1310754]);     // 1550 => [1310754]
-            map.insert(450, &[450]);     // 450 => [450]
-            map.insert(792, &[524482]);     // 792 => [524482]
-            map.insert(834, &[834]);     // 834 => [834]
-            map.insert(2038, &[3138]);     // 2038 => [3138]
-            map.insert(221, &[5186]);     // 221 => [5186]
-            map.insert(1897, &[6210]);     // 1897 => [6210]
-            map.insert(1646, &[1310786]);     // 1646 => [1310786]
-            map.insert(1348, &[33922]);     // 1348 => [33922]
-            map.insert(1423, &[132226]);     // 1423 => [132226]
-            map.insert(1493, &[18562]);     // 1493 => [18562]
-            map.insert(835, &[1050754]);     // 835 => [1050754]
-            map.insert(1806, &[69762]);     // 1806 => [69762]
-            map.insert(283, &[73858]);     // 283 => [73858]
-            map.insert(1821, &[295042]);     // 1821 => [295042]
-            map.insert(2006, &[393346]);     // 2006 => [393346]
-            map.insert(216, &[525058]);     // 216 => [525058]
-            map.insert(60, &[10498]);     // 60 => [10498]
-            map.insert(880, &[35074]);     // 880 => [35074]
-            map.insert(590, &[41218]);     // 590 => [41218]
-            map.insert(796, &[196866]);     // 796 => [196866]
-            map.insert(612, &[1114370]);     // 612 => [1114370]
-            map.insert(122, &[1179906]);     // 122 => [1179906]
-            map.insert(1807, &[132610]);     // 1807 => [132610]
-            map.insert(91, &[263682]);     // 91 => [263682]
-            map.insert(451, &[1051138]);     // 451 => [1051138]
-            map.insert(1047, &[12802]);     // 1047 => [12802]
-            map.insert(1422, &[70146]);     // 1422 => [70146]
-            map.insert(1172, &[1065474]);     // 1172 => [1065474]
-            map.insert(713, &[164354]);     // 713 => [164354]
-            map.insert(1437, &[295426]);     // 1437 => [295426]
-            map.insert(1181, &[7170]);     // 1181 => [7170]
-            map.insert(1606, &[82946]);     // 1606 => [82946]
-            map.insert(1870, &[43010]);     // 1870 => [43010]
-            map.insert(1610, &[659458]);     // 1610 => [659458]
-            map.insert(1277, &[573442]);     // 1277 => [573442]
-            map.insert(1501, &[1245186]);     // 1501 => [1245186]
-            map.insert(1630, &[32812]);     // 1630 => [32812]
-            map.insert(1100, &[2124]);     // 1100 => [2124]
-            map.insert(1741, &[1048844]);     // 1741 => [1048844]
-            map.insert(1247, &[270348]);     // 1247 => [270348]
-            map.insert(1757, &[131172]);     // 1757 => [131172]
-            map.insert(1230, &[1052708]);     // 1230 => [1052708]
-            map.insert(1101, &[24612]);     // 1101 => [24612]
-            map.insert(1742, &[9284]);     // 1742 => [9284]
-            map.insert(1119, &[1572932]);     // 1119 => [1572932]
-            map.insert(1758, &[526596]);     // 1758 => [526596]
-            map.insert(1117, &[37124]);     // 1117 => [37124]
-            map.insert(1102, &[327940]);     // 1102 => [327940]
-            map.insert(1629, &[265220]);     // 1629 => [265220]
-            map.insert(1118, &[148484]);     // 1118 => [148484]
-            map.insert(1229, &[590852]);     // 1229 => [590852]
-            map.insert(1231, &[165892]);     // 1231 => [165892]
-            map.insert(1759, &[86020]);     // 1759 => [86020]
-            map.insert(1612, &[659460]);     // 1612 => [659460]
-            map.insert(1628, &[1122308]);     // 1628 => [1122308]
-            map.insert(1740, &[311300]);     // 1740 => [311300]
-            map.insert(1329, &[8207]);     // 1329 => [8207]
-            map.insert(482, &[262159]);     // 482 => [262159]
-            map.insert(1429, &[135175]);     // 1429 => [135175]
-            map.insert(246, &[528391]);     // 246 => [528391]
-            map.insert(1236, &[270343]);     // 1236 => [270343]
-            map.insert(1380, &[655367]);     // 1380 => [655367]
-            map.insert(744, &[17419]);     // 744 => [17419]
-            map.insert(1816, &[66571]);     // 1816 => [66571]
-            map.insert(1240, &[270347]);     // 1240 => [270347]
-            map.insert(1531, &[81931]);     // 1531 => [81931]
-            map.insert(659, &[659]);     // 659 => [659]
-            map.insert(398, &[262243]);     // 398 => [262243]
-            map.insert(1954, &[1048675]);     // 1954 => [1048675]
-            map.insert(1798, &[49187]);     // 1798 => [49187]
-            map.insert(686, &[540707]);     // 686 => [540707]
-            map.insert(1419, &[557091]);     // 1419 => [557091]
-            map.insert(1551, &[1310755]);     // 1551 => [1310755]
-            map.insert(2039, &[3139]);     // 2039 => [3139]
-            map.insert(220, &[5187]);     // 220 => [5187]
-            map.insert(1896, &[6211]);     // 1896 => [6211]
-            map.insert(1647, &[1310787]);     // 1647 => [1310787]
-            map.insert(61, &[10499]);     // 61 => [10499]
-            map.insert(881, &[35075]);     // 881 => [35075]
-            map.insert(591, &[41219]);     // 591 => [41219]
-            map.insert(797, &[196867]);     // 797 => [196867]
-            map.insert(613, &[1114371]);     // 613 => [1114371]
-            map.insert(123, &[1179907]);     // 123 => [1179907]
-            map.insert(1180, &[7171]);     // 1180 => [7171]
-            map.insert(1607, &[82947]);     // 1607 => [82947]
-            map.insert(1871, &[43011]);     // 1871 => [43011]
-            map.insert(1611, &[659459]);     // 1611 => [659459]
-            map.insert(1276, &[573443]);     // 1276 => [573443]
-            map.insert(1500, &[1245187]);     // 1500 => [1245187]
-            map.insert(1631, &[32813]);     // 1631 => [32813]
-            map.insert(1246, &[270349]);     // 1246 => [270349]
-            map.insert(1756, &[131173]);     // 1756 => [131173]
-            map.insert(1743, &[9285]);     // 1743 => [9285]
-            map.insert(1116, &[37125]);     // 1116 => [37125]
-            map.insert(1103, &[327941]);     // 1103 => [327941]
-            map.insert(1228, &[590853]);     // 1228 => [590853]
-            map.insert(1613, &[659461]);     // 1613 => [659461]
-            map.insert(1245, &[270350]);     // 1245 => [270350]
-            map.insert(1614, &[659462]);     // 1614 => [659462]
-            map.insert(1244, &[270351]);     // 1244 => [270351]
-            map.insert(1615, &[659463]);     // 1615 => [659463]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1527809 ],
+            &[ 651266 ],
+            &[ 353284 ],
+            &[ 929800 ],
+            &[ 713744 ],
+            &[ 504864 ],
+            &[ 1884224 ],
+            &[ 2032768 ],
+            &[ 1864960 ],
+            &[ 1742336 ],
+            &[ 1288192 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 21)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(2048, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(64, &[64]);     // 64 => [64]
+        map.insert(128, &[128]);     // 128 => [128]
+        map.insert(256, &[256]);     // 256 => [256]
+        map.insert(512, &[512]);     // 512 => [512]
+        map.insert(948, &[1024]);     // 948 => [1024]
+        map.insert(1024, &[2048]);     // 1024 => [2048]
+        map.insert(811, &[4096]);     // 811 => [4096]
+        map.insert(1342, &[8192]);     // 1342 => [8192]
+        map.insert(343, &[16384]);     // 343 => [16384]
+        map.insert(1650, &[32768]);     // 1650 => [32768]
+        map.insert(1191, &[65536]);     // 1191 => [65536]
+        map.insert(1721, &[131072]);     // 1721 => [131072]
+        map.insert(493, &[262144]);     // 493 => [262144]
+        map.insert(986, &[524288]);     // 986 => [524288]
+        map.insert(1985, &[1048576]);     // 1985 => [1048576]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(65, &[65]);     // 65 => [65]
+        map.insert(129, &[129]);     // 129 => [129]
+        map.insert(257, &[257]);     // 257 => [257]
+        map.insert(513, &[513]);     // 513 => [513]
+        map.insert(949, &[1025]);     // 949 => [1025]
+        map.insert(1025, &[2049]);     // 1025 => [2049]
+        map.insert(810, &[4097]);     // 810 => [4097]
+        map.insert(1343, &[8193]);     // 1343 => [8193]
+        map.insert(342, &[16385]);     // 342 => [16385]
+        map.insert(1651, &[32769]);     // 1651 => [32769]
+        map.insert(1190, &[65537]);     // 1190 => [65537]
+        map.insert(1720, &[131073]);     // 1720 => [131073]
+        map.insert(492, &[262145]);     // 492 => [262145]
+        map.insert(987, &[524289]);     // 987 => [524289]
+        map.insert(1984, &[1048577]);     // 1984 => [1048577]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(66, &[66]);     // 66 => [66]
+        map.insert(130, &[130]);     // 130 => [130]
+        map.insert(258, &[258]);     // 258 => [258]
+        map.insert(514, &[514]);     // 514 => [514]
+        map.insert(950, &[1026]);     // 950 => [1026]
+        map.insert(1026, &[2050]);     // 1026 => [2050]
+        map.insert(809, &[4098]);     // 809 => [4098]
+        map.insert(1340, &[8194]);     // 1340 => [8194]
+        map.insert(341, &[16386]);     // 341 => [16386]
+        map.insert(1648, &[32770]);     // 1648 => [32770]
+        map.insert(1189, &[65538]);     // 1189 => [65538]
+        map.insert(1723, &[131074]);     // 1723 => [131074]
+        map.insert(495, &[262146]);     // 495 => [262146]
+        map.insert(984, &[524290]);     // 984 => [524290]
+        map.insert(1987, &[1048578]);     // 1987 => [1048578]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(68, &[68]);     // 68 => [68]
+        map.insert(132, &[132]);     // 132 => [132]
+        map.insert(260, &[260]);     // 260 => [260]
+        map.insert(516, &[516]);     // 516 => [516]
+        map.insert(944, &[1028]);     // 944 => [1028]
+        map.insert(1028, &[2052]);     // 1028 => [2052]
+        map.insert(815, &[4100]);     // 815 => [4100]
+        map.insert(1338, &[8196]);     // 1338 => [8196]
+        map.insert(339, &[16388]);     // 339 => [16388]
+        map.insert(1654, &[32772]);     // 1654 => [32772]
+        map.insert(1187, &[65540]);     // 1187 => [65540]
+        map.insert(1725, &[131076]);     // 1725 => [131076]
+        map.insert(489, &[262148]);     // 489 => [262148]
+        map.insert(990, &[524292]);     // 990 => [524292]
+        map.insert(1989, &[1048580]);     // 1989 => [1048580]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(72, &[72]);     // 72 => [72]
+        map.insert(136, &[136]);     // 136 => [136]
+        map.insert(264, &[264]);     // 264 => [264]
+        map.insert(520, &[520]);     // 520 => [520]
+        map.insert(956, &[1032]);     // 956 => [1032]
+        map.insert(1032, &[2056]);     // 1032 => [2056]
+        map.insert(803, &[4104]);     // 803 => [4104]
+        map.insert(1334, &[8200]);     // 1334 => [8200]
+        map.insert(351, &[16392]);     // 351 => [16392]
+        map.insert(1658, &[32776]);     // 1658 => [32776]
+        map.insert(1199, &[65544]);     // 1199 => [65544]
+        map.insert(1713, &[131080]);     // 1713 => [131080]
+        map.insert(485, &[262152]);     // 485 => [262152]
+        map.insert(978, &[524296]);     // 978 => [524296]
+        map.insert(1993, &[1048584]);     // 1993 => [1048584]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(80, &[80]);     // 80 => [80]
+        map.insert(144, &[144]);     // 144 => [144]
+        map.insert(272, &[272]);     // 272 => [272]
+        map.insert(528, &[528]);     // 528 => [528]
+        map.insert(932, &[1040]);     // 932 => [1040]
+        map.insert(1040, &[2064]);     // 1040 => [2064]
+        map.insert(827, &[4112]);     // 827 => [4112]
+        map.insert(1326, &[8208]);     // 1326 => [8208]
+        map.insert(327, &[16400]);     // 327 => [16400]
+        map.insert(1634, &[32784]);     // 1634 => [32784]
+        map.insert(1207, &[65552]);     // 1207 => [65552]
+        map.insert(1705, &[131088]);     // 1705 => [131088]
+        map.insert(509, &[262160]);     // 509 => [262160]
+        map.insert(970, &[524304]);     // 970 => [524304]
+        map.insert(2001, &[1048592]);     // 2001 => [1048592]
+        map.insert(96, &[96]);     // 96 => [96]
+        map.insert(160, &[160]);     // 160 => [160]
+        map.insert(288, &[288]);     // 288 => [288]
+        map.insert(544, &[544]);     // 544 => [544]
+        map.insert(916, &[1056]);     // 916 => [1056]
+        map.insert(1056, &[2080]);     // 1056 => [2080]
+        map.insert(779, &[4128]);     // 779 => [4128]
+        map.insert(1310, &[8224]);     // 1310 => [8224]
+        map.insert(375, &[16416]);     // 375 => [16416]
+        map.insert(1618, &[32800]);     // 1618 => [32800]
+        map.insert(1159, &[65568]);     // 1159 => [65568]
+        map.insert(1689, &[131104]);     // 1689 => [131104]
+        map.insert(461, &[262176]);     // 461 => [262176]
+        map.insert(1018, &[524320]);     // 1018 => [524320]
+        map.insert(2017, &[1048608]);     // 2017 => [1048608]
+        map.insert(192, &[192]);     // 192 => [192]
+        map.insert(320, &[320]);     // 320 => [320]
+        map.insert(576, &[576]);     // 576 => [576]
+        map.insert(1012, &[1088]);     // 1012 => [1088]
+        map.insert(1088, &[2112]);     // 1088 => [2112]
+        map.insert(875, &[4160]);     // 875 => [4160]
+        map.insert(1406, &[8256]);     // 1406 => [8256]
+        map.insert(279, &[16448]);     // 279 => [16448]
+        map.insert(1586, &[32832]);     // 1586 => [32832]
+        map.insert(1255, &[65600]);     // 1255 => [65600]
+        map.insert(1785, &[131136]);     // 1785 => [131136]
+        map.insert(429, &[262208]);     // 429 => [262208]
+        map.insert(922, &[524352]);     // 922 => [524352]
+        map.insert(1921, &[1048640]);     // 1921 => [1048640]
+        map.insert(384, &[384]);     // 384 => [384]
+        map.insert(640, &[640]);     // 640 => [640]
+        map.insert(820, &[1152]);     // 820 => [1152]
+        map.insert(1152, &[2176]);     // 1152 => [2176]
+        map.insert(939, &[4224]);     // 939 => [4224]
+        map.insert(1470, &[8320]);     // 1470 => [8320]
+        map.insert(471, &[16512]);     // 471 => [16512]
+        map.insert(1778, &[32896]);     // 1778 => [32896]
+        map.insert(1063, &[65664]);     // 1063 => [65664]
+        map.insert(1593, &[131200]);     // 1593 => [131200]
+        map.insert(365, &[262272]);     // 365 => [262272]
+        map.insert(858, &[524416]);     // 858 => [524416]
+        map.insert(1857, &[1048704]);     // 1857 => [1048704]
+        map.insert(768, &[768]);     // 768 => [768]
+        map.insert(692, &[1280]);     // 692 => [1280]
+        map.insert(1280, &[2304]);     // 1280 => [2304]
+        map.insert(555, &[4352]);     // 555 => [4352]
+        map.insert(1086, &[8448]);     // 1086 => [8448]
+        map.insert(87, &[16640]);     // 87 => [16640]
+        map.insert(1906, &[33024]);     // 1906 => [33024]
+        map.insert(1447, &[65792]);     // 1447 => [65792]
+        map.insert(1977, &[131328]);     // 1977 => [131328]
+        map.insert(237, &[262400]);     // 237 => [262400]
+        map.insert(730, &[524544]);     // 730 => [524544]
+        map.insert(1729, &[1048832]);     // 1729 => [1048832]
+        map.insert(436, &[1536]);     // 436 => [1536]
+        map.insert(1536, &[2560]);     // 1536 => [2560]
+        map.insert(299, &[4608]);     // 299 => [4608]
+        map.insert(1854, &[8704]);     // 1854 => [8704]
+        map.insert(855, &[16896]);     // 855 => [16896]
+        map.insert(1138, &[33280]);     // 1138 => [33280]
+        map.insert(1703, &[66048]);     // 1703 => [66048]
+        map.insert(1209, &[131584]);     // 1209 => [131584]
+        map.insert(1005, &[262656]);     // 1005 => [262656]
+        map.insert(474, &[524800]);     // 474 => [524800]
+        map.insert(1473, &[1049088]);     // 1473 => [1049088]
+        map.insert(1972, &[3072]);     // 1972 => [3072]
+        map.insert(159, &[5120]);     // 159 => [5120]
+        map.insert(1674, &[9216]);     // 1674 => [9216]
+        map.insert(739, &[17408]);     // 739 => [17408]
+        map.insert(1478, &[33792]);     // 1478 => [33792]
+        map.insert(1811, &[66560]);     // 1811 => [66560]
+        map.insert(1293, &[132096]);     // 1293 => [132096]
+        map.insert(601, &[263168]);     // 601 => [263168]
+        map.insert(110, &[525312]);     // 110 => [525312]
+        map.insert(1141, &[1049600]);     // 1141 => [1049600]
+        map.insert(1835, &[6144]);     // 1835 => [6144]
+        map.insert(318, &[10240]);     // 318 => [10240]
+        map.insert(1367, &[18432]);     // 1367 => [18432]
+        map.insert(626, &[34816]);     // 626 => [34816]
+        map.insert(167, &[67584]);     // 167 => [67584]
+        map.insert(697, &[133120]);     // 697 => [133120]
+        map.insert(1517, &[264192]);     // 1517 => [264192]
+        map.insert(2010, &[526336]);     // 2010 => [526336]
+        map.insert(961, &[1050624]);     // 961 => [1050624]
+        map.insert(1557, &[12288]);     // 1557 => [12288]
+        map.insert(636, &[20480]);     // 636 => [20480]
+        map.insert(1369, &[36864]);     // 1369 => [36864]
+        map.insert(1932, &[69632]);     // 1932 => [69632]
+        map.insert(1426, &[135168]);     // 1426 => [135168]
+        map.insert(710, &[266240]);     // 710 => [266240]
+        map.insert(241, &[528384]);     // 241 => [528384]
+        map.insert(1258, &[1052672]);     // 1258 => [1052672]
+        map.insert(1129, &[24576]);     // 1129 => [24576]
+        map.insert(844, &[40960]);     // 844 => [40960]
+        map.insert(409, &[73728]);     // 409 => [73728]
+        map.insert(903, &[139264]);     // 903 => [139264]
+        map.insert(1235, &[270336]);     // 1235 => [270336]
+        map.insert(1764, &[532480]);     // 1764 => [532480]
+        map.insert(767, &[1056768]);     // 767 => [1056768]
+        map.insert(1829, &[49152]);     // 1829 => [49152]
+        map.insert(1520, &[81920]);     // 1520 => [81920]
+        map.insert(2030, &[147456]);     // 2030 => [147456]
+        map.insert(186, &[278528]);     // 186 => [278528]
+        map.insert(653, &[540672]);     // 653 => [540672]
+        map.insert(1686, &[1064960]);     // 1686 => [1064960]
+        map.insert(725, &[98304]);     // 725 => [98304]
+        map.insert(203, &[163840]);     // 203 => [163840]
+        map.insert(1951, &[294912]);     // 1951 => [294912]
+        map.insert(1448, &[557056]);     // 1448 => [557056]
+        map.insert(435, &[1081344]);     // 435 => [1081344]
+        map.insert(542, &[196608]);     // 542 => [196608]
+        map.insert(1354, &[327680]);     // 1354 => [327680]
+        map.insert(1917, &[589824]);     // 1917 => [589824]
+        map.insert(870, &[1114112]);     // 870 => [1114112]
+        map.insert(1876, &[393216]);     // 1876 => [393216]
+        map.insert(1379, &[655360]);     // 1379 => [655360]
+        map.insert(376, &[1179648]);     // 376 => [1179648]
+        map.insert(567, &[786432]);     // 567 => [786432]
+        map.insert(1580, &[1310720]);     // 1580 => [1310720]
+        map.insert(1051, &[1572864]);     // 1051 => [1572864]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(19, &[19]);     // 19 => [19]
+        map.insert(35, &[35]);     // 35 => [35]
+        map.insert(67, &[67]);     // 67 => [67]
+        map.insert(131, &[131]);     // 131 => [131]
+        map.insert(259, &[259]);     // 259 => [259]
+        map.insert(515, &[515]);     // 515 => [515]
+        map.insert(951, &[1027]);     // 951 => [1027]
+        map.insert(1027, &[2051]);     // 1027 => [2051]
+        map.insert(808, &[4099]);     // 808 => [4099]
+        map.insert(1341, &[8195]);     // 1341 => [8195]
+        map.insert(340, &[16387]);     // 340 => [16387]
+        map.insert(1649, &[32771]);     // 1649 => [32771]
+        map.insert(1188, &[65539]);     // 1188 => [65539]
+        map.insert(1722, &[131075]);     // 1722 => [131075]
+        map.insert(494, &[262147]);     // 494 => [262147]
+        map.insert(985, &[524291]);     // 985 => [524291]
+        map.insert(1986, &[1048579]);     // 1986 => [1048579]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(69, &[69]);     // 69 => [69]
+        map.insert(133, &[133]);     // 133 => [133]
+        map.insert(261, &[261]);     // 261 => [261]
+        map.insert(517, &[517]);     // 517 => [517]
+        map.insert(945, &[1029]);     // 945 => [1029]
+        map.insert(1029, &[2053]);     // 1029 => [2053]
+        map.insert(814, &[4101]);     // 814 => [4101]
+        map.insert(1339, &[8197]);     // 1339 => [8197]
+        map.insert(338, &[16389]);     // 338 => [16389]
+        map.insert(1655, &[32773]);     // 1655 => [32773]
+        map.insert(1186, &[65541]);     // 1186 => [65541]
+        map.insert(1724, &[131077]);     // 1724 => [131077]
+        map.insert(488, &[262149]);     // 488 => [262149]
+        map.insert(991, &[524293]);     // 991 => [524293]
+        map.insert(1988, &[1048581]);     // 1988 => [1048581]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(41, &[41]);     // 41 => [41]
+        map.insert(73, &[73]);     // 73 => [73]
+        map.insert(137, &[137]);     // 137 => [137]
+        map.insert(265, &[265]);     // 265 => [265]
+        map.insert(521, &[521]);     // 521 => [521]
+        map.insert(957, &[1033]);     // 957 => [1033]
+        map.insert(1033, &[2057]);     // 1033 => [2057]
+        map.insert(802, &[4105]);     // 802 => [4105]
+        map.insert(1335, &[8201]);     // 1335 => [8201]
+        map.insert(350, &[16393]);     // 350 => [16393]
+        map.insert(1659, &[32777]);     // 1659 => [32777]
+        map.insert(1198, &[65545]);     // 1198 => [65545]
+        map.insert(1712, &[131081]);     // 1712 => [131081]
+        map.insert(484, &[262153]);     // 484 => [262153]
+        map.insert(979, &[524297]);     // 979 => [524297]
+        map.insert(1992, &[1048585]);     // 1992 => [1048585]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(81, &[81]);     // 81 => [81]
+        map.insert(145, &[145]);     // 145 => [145]
+        map.insert(273, &[273]);     // 273 => [273]
+        map.insert(529, &[529]);     // 529 => [529]
+        map.insert(933, &[1041]);     // 933 => [1041]
+        map.insert(1041, &[2065]);     // 1041 => [2065]
+        map.insert(826, &[4113]);     // 826 => [4113]
+        map.insert(1327, &[8209]);     // 1327 => [8209]
+        map.insert(326, &[16401]);     // 326 => [16401]
+        map.insert(1635, &[32785]);     // 1635 => [32785]
+        map.insert(1206, &[65553]);     // 1206 => [65553]
+        map.insert(1704, &[131089]);     // 1704 => [131089]
+        map.insert(508, &[262161]);     // 508 => [262161]
+        map.insert(971, &[524305]);     // 971 => [524305]
+        map.insert(2000, &[1048593]);     // 2000 => [1048593]
+        map.insert(97, &[97]);     // 97 => [97]
+        map.insert(161, &[161]);     // 161 => [161]
+        map.insert(289, &[289]);     // 289 => [289]
+        map.insert(545, &[545]);     // 545 => [545]
+        map.insert(917, &[1057]);     // 917 => [1057]
+        map.insert(1057, &[2081]);     // 1057 => [2081]
+        map.insert(778, &[4129]);     // 778 => [4129]
+        map.insert(1311, &[8225]);     // 1311 => [8225]
+        map.insert(374, &[16417]);     // 374 => [16417]
+        map.insert(1619, &[32801]);     // 1619 => [32801]
+        map.insert(1158, &[65569]);     // 1158 => [65569]
+        map.insert(1688, &[131105]);     // 1688 => [131105]
+        map.insert(460, &[262177]);     // 460 => [262177]
+        map.insert(1019, &[524321]);     // 1019 => [524321]
+        map.insert(2016, &[1048609]);     // 2016 => [1048609]
+        map.insert(193, &[193]);     // 193 => [193]
+        map.insert(321, &[321]);     // 321 => [321]
+        map.insert(577, &[577]);     // 577 => [577]
+        map.insert(1013, &[1089]);     // 1013 => [1089]
+        map.insert(1089, &[2113]);     // 1089 => [2113]
+        map.insert(874, &[4161]);     // 874 => [4161]
+        map.insert(1407, &[8257]);     // 1407 => [8257]
+        map.insert(278, &[16449]);     // 278 => [16449]
+        map.insert(1587, &[32833]);     // 1587 => [32833]
+        map.insert(1254, &[65601]);     // 1254 => [65601]
+        map.insert(1784, &[131137]);     // 1784 => [131137]
+        map.insert(428, &[262209]);     // 428 => [262209]
+        map.insert(923, &[524353]);     // 923 => [524353]
+        map.insert(1920, &[1048641]);     // 1920 => [1048641]
+        map.insert(385, &[385]);     // 385 => [385]
+        map.insert(641, &[641]);     // 641 => [641]
+        map.insert(821, &[1153]);     // 821 => [1153]
+        map.insert(1153, &[2177]);     // 1153 => [2177]
+        map.insert(938, &[4225]);     // 938 => [4225]
+        map.insert(1471, &[8321]);     // 1471 => [8321]
+        map.insert(470, &[16513]);     // 470 => [16513]
+        map.insert(1779, &[32897]);     // 1779 => [32897]
+        map.insert(1062, &[65665]);     // 1062 => [65665]
+        map.insert(1592, &[131201]);     // 1592 => [131201]
+        map.insert(364, &[262273]);     // 364 => [262273]
+        map.insert(859, &[524417]);     // 859 => [524417]
+        map.insert(1856, &[1048705]);     // 1856 => [1048705]
+        map.insert(769, &[769]);     // 769 => [769]
+        map.insert(693, &[1281]);     // 693 => [1281]
+        map.insert(1281, &[2305]);     // 1281 => [2305]
+        map.insert(554, &[4353]);     // 554 => [4353]
+        map.insert(1087, &[8449]);     // 1087 => [8449]
+        map.insert(86, &[16641]);     // 86 => [16641]
+        map.insert(1907, &[33025]);     // 1907 => [33025]
+        map.insert(1446, &[65793]);     // 1446 => [65793]
+        map.insert(1976, &[131329]);     // 1976 => [131329]
+        map.insert(236, &[262401]);     // 236 => [262401]
+        map.insert(731, &[524545]);     // 731 => [524545]
+        map.insert(1728, &[1048833]);     // 1728 => [1048833]
+        map.insert(437, &[1537]);     // 437 => [1537]
+        map.insert(1537, &[2561]);     // 1537 => [2561]
+        map.insert(298, &[4609]);     // 298 => [4609]
+        map.insert(1855, &[8705]);     // 1855 => [8705]
+        map.insert(854, &[16897]);     // 854 => [16897]
+        map.insert(1139, &[33281]);     // 1139 => [33281]
+        map.insert(1702, &[66049]);     // 1702 => [66049]
+        map.insert(1208, &[131585]);     // 1208 => [131585]
+        map.insert(1004, &[262657]);     // 1004 => [262657]
+        map.insert(475, &[524801]);     // 475 => [524801]
+        map.insert(1472, &[1049089]);     // 1472 => [1049089]
+        map.insert(1973, &[3073]);     // 1973 => [3073]
+        map.insert(158, &[5121]);     // 158 => [5121]
+        map.insert(1675, &[9217]);     // 1675 => [9217]
+        map.insert(738, &[17409]);     // 738 => [17409]
+        map.insert(1479, &[33793]);     // 1479 => [33793]
+        map.insert(1810, &[66561]);     // 1810 => [66561]
+        map.insert(1292, &[132097]);     // 1292 => [132097]
+        map.insert(600, &[263169]);     // 600 => [263169]
+        map.insert(111, &[525313]);     // 111 => [525313]
+        map.insert(1140, &[1049601]);     // 1140 => [1049601]
+        map.insert(1834, &[6145]);     // 1834 => [6145]
+        map.insert(319, &[10241]);     // 319 => [10241]
+        map.insert(1366, &[18433]);     // 1366 => [18433]
+        map.insert(627, &[34817]);     // 627 => [34817]
+        map.insert(166, &[67585]);     // 166 => [67585]
+        map.insert(696, &[133121]);     // 696 => [133121]
+        map.insert(1516, &[264193]);     // 1516 => [264193]
+        map.insert(2011, &[526337]);     // 2011 => [526337]
+        map.insert(960, &[1050625]);     // 960 => [1050625]
+        map.insert(1556, &[12289]);     // 1556 => [12289]
+        map.insert(637, &[20481]);     // 637 => [20481]
+        map.insert(1368, &[36865]);     // 1368 => [36865]
+        map.insert(1933, &[69633]);     // 1933 => [69633]
+        map.insert(1427, &[135169]);     // 1427 => [135169]
+        map.insert(711, &[266241]);     // 711 => [266241]
+        map.insert(240, &[528385]);     // 240 => [528385]
+        map.insert(1259, &[1052673]);     // 1259 => [1052673]
+        map.insert(1128, &[24577]);     // 1128 => [24577]
+        map.insert(845, &[40961]);     // 845 => [40961]
+        map.insert(408, &[73729]);     // 408 => [73729]
+        map.insert(902, &[139265]);     // 902 => [139265]
+        map.insert(1234, &[270337]);     // 1234 => [270337]
+        map.insert(1765, &[532481]);     // 1765 => [532481]
+        map.insert(766, &[1056769]);     // 766 => [1056769]
+        map.insert(1828, &[49153]);     // 1828 => [49153]
+        map.insert(1521, &[81921]);     // 1521 => [81921]
+        map.insert(2031, &[147457]);     // 2031 => [147457]
+        map.insert(187, &[278529]);     // 187 => [278529]
+        map.insert(652, &[540673]);     // 652 => [540673]
+        map.insert(1687, &[1064961]);     // 1687 => [1064961]
+        map.insert(724, &[98305]);     // 724 => [98305]
+        map.insert(202, &[163841]);     // 202 => [163841]
+        map.insert(1950, &[294913]);     // 1950 => [294913]
+        map.insert(1449, &[557057]);     // 1449 => [557057]
+        map.insert(434, &[1081345]);     // 434 => [1081345]
+        map.insert(543, &[196609]);     // 543 => [196609]
+        map.insert(1355, &[327681]);     // 1355 => [327681]
+        map.insert(1916, &[589825]);     // 1916 => [589825]
+        map.insert(871, &[1114113]);     // 871 => [1114113]
+        map.insert(1877, &[393217]);     // 1877 => [393217]
+        map.insert(1378, &[655361]);     // 1378 => [655361]
+        map.insert(377, &[1179649]);     // 377 => [1179649]
+        map.insert(566, &[786433]);     // 566 => [786433]
+        map.insert(1581, &[1310721]);     // 1581 => [1310721]
+        map.insert(1050, &[1572865]);     // 1050 => [1572865]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(70, &[70]);     // 70 => [70]
+        map.insert(134, &[134]);     // 134 => [134]
+        map.insert(262, &[262]);     // 262 => [262]
+        map.insert(518, &[518]);     // 518 => [518]
+        map.insert(946, &[1030]);     // 946 => [1030]
+        map.insert(1030, &[2054]);     // 1030 => [2054]
+        map.insert(813, &[4102]);     // 813 => [4102]
+        map.insert(1336, &[8198]);     // 1336 => [8198]
+        map.insert(337, &[16390]);     // 337 => [16390]
+        map.insert(1652, &[32774]);     // 1652 => [32774]
+        map.insert(1185, &[65542]);     // 1185 => [65542]
+        map.insert(1727, &[131078]);     // 1727 => [131078]
+        map.insert(491, &[262150]);     // 491 => [262150]
+        map.insert(988, &[524294]);     // 988 => [524294]
+        map.insert(1991, &[1048582]);     // 1991 => [1048582]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(42, &[42]);     // 42 => [42]
+        map.insert(74, &[74]);     // 74 => [74]
+        map.insert(138, &[138]);     // 138 => [138]
+        map.insert(266, &[266]);     // 266 => [266]
+        map.insert(522, &[522]);     // 522 => [522]
+        map.insert(958, &[1034]);     // 958 => [1034]
+        map.insert(1034, &[2058]);     // 1034 => [2058]
+        map.insert(801, &[4106]);     // 801 => [4106]
+        map.insert(1332, &[8202]);     // 1332 => [8202]
+        map.insert(349, &[16394]);     // 349 => [16394]
+        map.insert(1656, &[32778]);     // 1656 => [32778]
+        map.insert(1197, &[65546]);     // 1197 => [65546]
+        map.insert(1715, &[131082]);     // 1715 => [131082]
+        map.insert(487, &[262154]);     // 487 => [262154]
+        map.insert(976, &[524298]);     // 976 => [524298]
+        map.insert(1995, &[1048586]);     // 1995 => [1048586]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(82, &[82]);     // 82 => [82]
+        map.insert(146, &[146]);     // 146 => [146]
+        map.insert(274, &[274]);     // 274 => [274]
+        map.insert(530, &[530]);     // 530 => [530]
+        map.insert(934, &[1042]);     // 934 => [1042]
+        map.insert(1042, &[2066]);     // 1042 => [2066]
+        map.insert(825, &[4114]);     // 825 => [4114]
+        map.insert(1324, &[8210]);     // 1324 => [8210]
+        map.insert(325, &[16402]);     // 325 => [16402]
+        map.insert(1632, &[32786]);     // 1632 => [32786]
+        map.insert(1205, &[65554]);     // 1205 => [65554]
+        map.insert(1707, &[131090]);     // 1707 => [131090]
+        map.insert(511, &[262162]);     // 511 => [262162]
+        map.insert(968, &[524306]);     // 968 => [524306]
+        map.insert(2003, &[1048594]);     // 2003 => [1048594]
+        map.insert(98, &[98]);     // 98 => [98]
+        map.insert(162, &[162]);     // 162 => [162]
+        map.insert(290, &[290]);     // 290 => [290]
+        map.insert(546, &[546]);     // 546 => [546]
+        map.insert(918, &[1058]);     // 918 => [1058]
+        map.insert(1058, &[2082]);     // 1058 => [2082]
+        map.insert(777, &[4130]);     // 777 => [4130]
+        map.insert(1308, &[8226]);     // 1308 => [8226]
+        map.insert(373, &[16418]);     // 373 => [16418]
+        map.insert(1616, &[32802]);     // 1616 => [32802]
+        map.insert(1157, &[65570]);     // 1157 => [65570]
+        map.insert(1691, &[131106]);     // 1691 => [131106]
+        map.insert(463, &[262178]);     // 463 => [262178]
+        map.insert(1016, &[524322]);     // 1016 => [524322]
+        map.insert(2019, &[1048610]);     // 2019 => [1048610]
+        map.insert(194, &[194]);     // 194 => [194]
+        map.insert(322, &[322]);     // 322 => [322]
+        map.insert(578, &[578]);     // 578 => [578]
+        map.insert(1014, &[1090]);     // 1014 => [1090]
+        map.insert(1090, &[2114]);     // 1090 => [2114]
+        map.insert(873, &[4162]);     // 873 => [4162]
+        map.insert(1404, &[8258]);     // 1404 => [8258]
+        map.insert(277, &[16450]);     // 277 => [16450]
+        map.insert(1584, &[32834]);     // 1584 => [32834]
+        map.insert(1253, &[65602]);     // 1253 => [65602]
+        map.insert(1787, &[131138]);     // 1787 => [131138]
+        map.insert(431, &[262210]);     // 431 => [262210]
+        map.insert(920, &[524354]);     // 920 => [524354]
+        map.insert(1923, &[1048642]);     // 1923 => [1048642]
+        map.insert(386, &[386]);     // 386 => [386]
+        map.insert(642, &[642]);     // 642 => [642]
+        map.insert(822, &[1154]);     // 822 => [1154]
+        map.insert(1154, &[2178]);     // 1154 => [2178]
+        map.insert(937, &[4226]);     // 937 => [4226]
+        map.insert(1468, &[8322]);     // 1468 => [8322]
+        map.insert(469, &[16514]);     // 469 => [16514]
+        map.insert(1776, &[32898]);     // 1776 => [32898]
+        map.insert(1061, &[65666]);     // 1061 => [65666]
+        map.insert(1595, &[131202]);     // 1595 => [131202]
+        map.insert(367, &[262274]);     // 367 => [262274]
+        map.insert(856, &[524418]);     // 856 => [524418]
+        map.insert(1859, &[1048706]);     // 1859 => [1048706]
+        map.insert(770, &[770]);     // 770 => [770]
+        map.insert(694, &[1282]);     // 694 => [1282]
+        map.insert(1282, &[2306]);     // 1282 => [2306]
+        map.insert(553, &[4354]);     // 553 => [4354]
+        map.insert(1084, &[8450]);     // 1084 => [8450]
+        map.insert(85, &[16642]);     // 85 => [16642]
+        map.insert(1904, &[33026]);     // 1904 => [33026]
+        map.insert(1445, &[65794]);     // 1445 => [65794]
+        map.insert(1979, &[131330]);     // 1979 => [131330]
+        map.insert(239, &[262402]);     // 239 => [262402]
+        map.insert(728, &[524546]);     // 728 => [524546]
+        map.insert(1731, &[1048834]);     // 1731 => [1048834]
+        map.insert(438, &[1538]);     // 438 => [1538]
+        map.insert(1538, &[2562]);     // 1538 => [2562]
+        map.insert(297, &[4610]);     // 297 => [4610]
+        map.insert(1852, &[8706]);     // 1852 => [8706]
+        map.insert(853, &[16898]);     // 853 => [16898]
+        map.insert(1136, &[33282]);     // 1136 => [33282]
+        map.insert(1701, &[66050]);     // 1701 => [66050]
+        map.insert(1211, &[131586]);     // 1211 => [131586]
+        map.insert(1007, &[262658]);     // 1007 => [262658]
+        map.insert(472, &[524802]);     // 472 => [524802]
+        map.insert(1475, &[1049090]);     // 1475 => [1049090]
+        map.insert(1974, &[3074]);     // 1974 => [3074]
+        map.insert(157, &[5122]);     // 157 => [5122]
+        map.insert(1672, &[9218]);     // 1672 => [9218]
+        map.insert(737, &[17410]);     // 737 => [17410]
+        map.insert(1476, &[33794]);     // 1476 => [33794]
+        map.insert(1809, &[66562]);     // 1809 => [66562]
+        map.insert(1295, &[132098]);     // 1295 => [132098]
+        map.insert(603, &[263170]);     // 603 => [263170]
+        map.insert(108, &[525314]);     // 108 => [525314]
+        map.insert(1143, &[1049602]);     // 1143 => [1049602]
+        map.insert(1833, &[6146]);     // 1833 => [6146]
+        map.insert(316, &[10242]);     // 316 => [10242]
+        map.insert(1365, &[18434]);     // 1365 => [18434]
+        map.insert(624, &[34818]);     // 624 => [34818]
+        map.insert(165, &[67586]);     // 165 => [67586]
+        map.insert(699, &[133122]);     // 699 => [133122]
+        map.insert(1519, &[264194]);     // 1519 => [264194]
+        map.insert(2008, &[526338]);     // 2008 => [526338]
+        map.insert(963, &[1050626]);     // 963 => [1050626]
+        map.insert(1559, &[12290]);     // 1559 => [12290]
+        map.insert(638, &[20482]);     // 638 => [20482]
+        map.insert(1371, &[36866]);     // 1371 => [36866]
+        map.insert(1934, &[69634]);     // 1934 => [69634]
+        map.insert(1424, &[135170]);     // 1424 => [135170]
+        map.insert(708, &[266242]);     // 708 => [266242]
+        map.insert(243, &[528386]);     // 243 => [528386]
+        map.insert(1256, &[1052674]);     // 1256 => [1052674]
+        map.insert(1131, &[24578]);     // 1131 => [24578]
+        map.insert(846, &[40962]);     // 846 => [40962]
+        map.insert(411, &[73730]);     // 411 => [73730]
+        map.insert(901, &[139266]);     // 901 => [139266]
+        map.insert(1233, &[270338]);     // 1233 => [270338]
+        map.insert(1766, &[532482]);     // 1766 => [532482]
+        map.insert(765, &[1056770]);     // 765 => [1056770]
+        map.insert(1831, &[49154]);     // 1831 => [49154]
+        map.insert(1522, &[81922]);     // 1522 => [81922]
+        map.insert(2028, &[147458]);     // 2028 => [147458]
+        map.insert(184, &[278530]);     // 184 => [278530]
+        map.insert(655, &[540674]);     // 655 => [540674]
+        map.insert(1684, &[1064962]);     // 1684 => [1064962]
+        map.insert(727, &[98306]);     // 727 => [98306]
+        map.insert(201, &[163842]);     // 201 => [163842]
+        map.insert(1949, &[294914]);     // 1949 => [294914]
+        map.insert(1450, &[557058]);     // 1450 => [557058]
+        map.insert(433, &[1081346]);     // 433 => [1081346]
+        map.insert(540, &[196610]);     // 540 => [196610]
+        map.insert(1352, &[327682]);     // 1352 => [327682]
+        map.insert(1919, &[589826]);     // 1919 => [589826]
+        map.insert(868, &[1114114]);     // 868 => [1114114]
+        map.insert(1878, &[393218]);     // 1878 => [393218]
+        map.insert(1377, &[655362]);     // 1377 => [655362]
+        map.insert(378, &[1179650]);     // 378 => [1179650]
+        map.insert(565, &[786434]);     // 565 => [786434]
+        map.insert(1582, &[1310722]);     // 1582 => [1310722]
+        map.insert(1049, &[1572866]);     // 1049 => [1572866]
+        map.insert(28, &[28]);     // 28 => [28]
+        map.insert(44, &[44]);     // 44 => [44]
+        map.insert(76, &[76]);     // 76 => [76]
+        map.insert(140, &[140]);     // 140 => [140]
+        map.insert(268, &[268]);     // 268 => [268]
+        map.insert(524, &[524]);     // 524 => [524]
+        map.insert(952, &[1036]);     // 952 => [1036]
+        map.insert(1036, &[2060]);     // 1036 => [2060]
+        map.insert(807, &[4108]);     // 807 => [4108]
+        map.insert(1330, &[8204]);     // 1330 => [8204]
+        map.insert(347, &[16396]);     // 347 => [16396]
+        map.insert(1662, &[32780]);     // 1662 => [32780]
+        map.insert(1195, &[65548]);     // 1195 => [65548]
+        map.insert(1717, &[131084]);     // 1717 => [131084]
+        map.insert(481, &[262156]);     // 481 => [262156]
+        map.insert(982, &[524300]);     // 982 => [524300]
+        map.insert(1997, &[1048588]);     // 1997 => [1048588]
+        map.insert(52, &[52]);     // 52 => [52]
+        map.insert(84, &[84]);     // 84 => [84]
+        map.insert(148, &[148]);     // 148 => [148]
+        map.insert(276, &[276]);     // 276 => [276]
+        map.insert(532, &[532]);     // 532 => [532]
+        map.insert(928, &[1044]);     // 928 => [1044]
+        map.insert(1044, &[2068]);     // 1044 => [2068]
+        map.insert(831, &[4116]);     // 831 => [4116]
+        map.insert(1322, &[8212]);     // 1322 => [8212]
+        map.insert(323, &[16404]);     // 323 => [16404]
+        map.insert(1638, &[32788]);     // 1638 => [32788]
+        map.insert(1203, &[65556]);     // 1203 => [65556]
+        map.insert(1709, &[131092]);     // 1709 => [131092]
+        map.insert(505, &[262164]);     // 505 => [262164]
+        map.insert(974, &[524308]);     // 974 => [524308]
+        map.insert(2005, &[1048596]);     // 2005 => [1048596]
+        map.insert(100, &[100]);     // 100 => [100]
+        map.insert(164, &[164]);     // 164 => [164]
+        map.insert(292, &[292]);     // 292 => [292]
+        map.insert(548, &[548]);     // 548 => [548]
+        map.insert(912, &[1060]);     // 912 => [1060]
+        map.insert(1060, &[2084]);     // 1060 => [2084]
+        map.insert(783, &[4132]);     // 783 => [4132]
+        map.insert(1306, &[8228]);     // 1306 => [8228]
+        map.insert(371, &[16420]);     // 371 => [16420]
+        map.insert(1622, &[32804]);     // 1622 => [32804]
+        map.insert(1155, &[65572]);     // 1155 => [65572]
+        map.insert(1693, &[131108]);     // 1693 => [131108]
+        map.insert(457, &[262180]);     // 457 => [262180]
+        map.insert(1022, &[524324]);     // 1022 => [524324]
+        map.insert(2021, &[1048612]);     // 2021 => [1048612]
+        map.insert(196, &[196]);     // 196 => [196]
+        map.insert(324, &[324]);     // 324 => [324]
+        map.insert(580, &[580]);     // 580 => [580]
+        map.insert(1008, &[1092]);     // 1008 => [1092]
+        map.insert(1092, &[2116]);     // 1092 => [2116]
+        map.insert(879, &[4164]);     // 879 => [4164]
+        map.insert(1402, &[8260]);     // 1402 => [8260]
+        map.insert(275, &[16452]);     // 275 => [16452]
+        map.insert(1590, &[32836]);     // 1590 => [32836]
+        map.insert(1251, &[65604]);     // 1251 => [65604]
+        map.insert(1789, &[131140]);     // 1789 => [131140]
+        map.insert(425, &[262212]);     // 425 => [262212]
+        map.insert(926, &[524356]);     // 926 => [524356]
+        map.insert(1925, &[1048644]);     // 1925 => [1048644]
+        map.insert(388, &[388]);     // 388 => [388]
+        map.insert(644, &[644]);     // 644 => [644]
+        map.insert(816, &[1156]);     // 816 => [1156]
+        map.insert(1156, &[2180]);     // 1156 => [2180]
+        map.insert(943, &[4228]);     // 943 => [4228]
+        map.insert(1466, &[8324]);     // 1466 => [8324]
+        map.insert(467, &[16516]);     // 467 => [16516]
+        map.insert(1782, &[32900]);     // 1782 => [32900]
+        map.insert(1059, &[65668]);     // 1059 => [65668]
+        map.insert(1597, &[131204]);     // 1597 => [131204]
+        map.insert(361, &[262276]);     // 361 => [262276]
+        map.insert(862, &[524420]);     // 862 => [524420]
+        map.insert(1861, &[1048708]);     // 1861 => [1048708]
+        map.insert(772, &[772]);     // 772 => [772]
+        map.insert(688, &[1284]);     // 688 => [1284]
+        map.insert(1284, &[2308]);     // 1284 => [2308]
+        map.insert(559, &[4356]);     // 559 => [4356]
+        map.insert(1082, &[8452]);     // 1082 => [8452]
+        map.insert(83, &[16644]);     // 83 => [16644]
+        map.insert(1910, &[33028]);     // 1910 => [33028]
+        map.insert(1443, &[65796]);     // 1443 => [65796]
+        map.insert(1981, &[131332]);     // 1981 => [131332]
+        map.insert(233, &[262404]);     // 233 => [262404]
+        map.insert(734, &[524548]);     // 734 => [524548]
+        map.insert(1733, &[1048836]);     // 1733 => [1048836]
+        map.insert(432, &[1540]);     // 432 => [1540]
+        map.insert(1540, &[2564]);     // 1540 => [2564]
+        map.insert(303, &[4612]);     // 303 => [4612]
+        map.insert(1850, &[8708]);     // 1850 => [8708]
+        map.insert(851, &[16900]);     // 851 => [16900]
+        map.insert(1142, &[33284]);     // 1142 => [33284]
+        map.insert(1699, &[66052]);     // 1699 => [66052]
+        map.insert(1213, &[131588]);     // 1213 => [131588]
+        map.insert(1001, &[262660]);     // 1001 => [262660]
+        map.insert(478, &[524804]);     // 478 => [524804]
+        map.insert(1477, &[1049092]);     // 1477 => [1049092]
+        map.insert(1968, &[3076]);     // 1968 => [3076]
+        map.insert(155, &[5124]);     // 155 => [5124]
+        map.insert(1678, &[9220]);     // 1678 => [9220]
+        map.insert(743, &[17412]);     // 743 => [17412]
+        map.insert(1474, &[33796]);     // 1474 => [33796]
+        map.insert(1815, &[66564]);     // 1815 => [66564]
+        map.insert(1289, &[132100]);     // 1289 => [132100]
+        map.insert(605, &[263172]);     // 605 => [263172]
+        map.insert(106, &[525316]);     // 106 => [525316]
+        map.insert(1137, &[1049604]);     // 1137 => [1049604]
+        map.insert(1839, &[6148]);     // 1839 => [6148]
+        map.insert(314, &[10244]);     // 314 => [10244]
+        map.insert(1363, &[18436]);     // 1363 => [18436]
+        map.insert(630, &[34820]);     // 630 => [34820]
+        map.insert(163, &[67588]);     // 163 => [67588]
+        map.insert(701, &[133124]);     // 701 => [133124]
+        map.insert(1513, &[264196]);     // 1513 => [264196]
+        map.insert(2014, &[526340]);     // 2014 => [526340]
+        map.insert(965, &[1050628]);     // 965 => [1050628]
+        map.insert(1553, &[12292]);     // 1553 => [12292]
+        map.insert(632, &[20484]);     // 632 => [20484]
+        map.insert(1373, &[36868]);     // 1373 => [36868]
+        map.insert(1928, &[69636]);     // 1928 => [69636]
+        map.insert(1430, &[135172]);     // 1430 => [135172]
+        map.insert(706, &[266244]);     // 706 => [266244]
+        map.insert(245, &[528388]);     // 245 => [528388]
+        map.insert(1262, &[1052676]);     // 1262 => [1052676]
+        map.insert(1133, &[24580]);     // 1133 => [24580]
+        map.insert(840, &[40964]);     // 840 => [40964]
+        map.insert(413, &[73732]);     // 413 => [73732]
+        map.insert(899, &[139268]);     // 899 => [139268]
+        map.insert(1239, &[270340]);     // 1239 => [270340]
+        map.insert(1760, &[532484]);     // 1760 => [532484]
+        map.insert(763, &[1056772]);     // 763 => [1056772]
+        map.insert(1825, &[49156]);     // 1825 => [49156]
+        map.insert(1524, &[81924]);     // 1524 => [81924]
+        map.insert(2026, &[147460]);     // 2026 => [147460]
+        map.insert(190, &[278532]);     // 190 => [278532]
+        map.insert(649, &[540676]);     // 649 => [540676]
+        map.insert(1682, &[1064964]);     // 1682 => [1064964]
+        map.insert(721, &[98308]);     // 721 => [98308]
+        map.insert(207, &[163844]);     // 207 => [163844]
+        map.insert(1947, &[294916]);     // 1947 => [294916]
+        map.insert(1452, &[557060]);     // 1452 => [557060]
+        map.insert(439, &[1081348]);     // 439 => [1081348]
+        map.insert(538, &[196612]);     // 538 => [196612]
+        map.insert(1358, &[327684]);     // 1358 => [327684]
+        map.insert(1913, &[589828]);     // 1913 => [589828]
+        map.insert(866, &[1114116]);     // 866 => [1114116]
+        map.insert(1872, &[393220]);     // 1872 => [393220]
+        map.insert(1383, &[655364]);     // 1383 => [655364]
+        map.insert(380, &[1179652]);     // 380 => [1179652]
+        map.insert(563, &[786436]);     // 563 => [786436]
+        map.insert(1576, &[1310724]);     // 1576 => [1310724]
+        map.insert(1055, &[1572868]);     // 1055 => [1572868]
+        map.insert(56, &[56]);     // 56 => [56]
+        map.insert(88, &[88]);     // 88 => [88]
+        map.insert(152, &[152]);     // 152 => [152]
+        map.insert(280, &[280]);     // 280 => [280]
+        map.insert(536, &[536]);     // 536 => [536]
+        map.insert(940, &[1048]);     // 940 => [1048]
+        map.insert(1048, &[2072]);     // 1048 => [2072]
+        map.insert(819, &[4120]);     // 819 => [4120]
+        map.insert(1318, &[8216]);     // 1318 => [8216]
+        map.insert(335, &[16408]);     // 335 => [16408]
+        map.insert(1642, &[32792]);     // 1642 => [32792]
+        map.insert(1215, &[65560]);     // 1215 => [65560]
+        map.insert(1697, &[131096]);     // 1697 => [131096]
+        map.insert(501, &[262168]);     // 501 => [262168]
+        map.insert(962, &[524312]);     // 962 => [524312]
+        map.insert(2009, &[1048600]);     // 2009 => [1048600]
+        map.insert(104, &[104]);     // 104 => [104]
+        map.insert(168, &[168]);     // 168 => [168]
+        map.insert(296, &[296]);     // 296 => [296]
+        map.insert(552, &[552]);     // 552 => [552]
+        map.insert(924, &[1064]);     // 924 => [1064]
+        map.insert(1064, &[2088]);     // 1064 => [2088]
+        map.insert(771, &[4136]);     // 771 => [4136]
+        map.insert(1302, &[8232]);     // 1302 => [8232]
+        map.insert(383, &[16424]);     // 383 => [16424]
+        map.insert(1626, &[32808]);     // 1626 => [32808]
+        map.insert(1167, &[65576]);     // 1167 => [65576]
+        map.insert(1681, &[131112]);     // 1681 => [131112]
+        map.insert(453, &[262184]);     // 453 => [262184]
+        map.insert(1010, &[524328]);     // 1010 => [524328]
+        map.insert(2025, &[1048616]);     // 2025 => [1048616]
+        map.insert(200, &[200]);     // 200 => [200]
+        map.insert(328, &[328]);     // 328 => [328]
+        map.insert(584, &[584]);     // 584 => [584]
+        map.insert(1020, &[1096]);     // 1020 => [1096]
+        map.insert(1096, &[2120]);     // 1096 => [2120]
+        map.insert(867, &[4168]);     // 867 => [4168]
+        map.insert(1398, &[8264]);     // 1398 => [8264]
+        map.insert(287, &[16456]);     // 287 => [16456]
+        map.insert(1594, &[32840]);     // 1594 => [32840]
+        map.insert(1263, &[65608]);     // 1263 => [65608]
+        map.insert(1777, &[131144]);     // 1777 => [131144]
+        map.insert(421, &[262216]);     // 421 => [262216]
+        map.insert(914, &[524360]);     // 914 => [524360]
+        map.insert(1929, &[1048648]);     // 1929 => [1048648]
+        map.insert(392, &[392]);     // 392 => [392]
+        map.insert(648, &[648]);     // 648 => [648]
+        map.insert(828, &[1160]);     // 828 => [1160]
+        map.insert(1160, &[2184]);     // 1160 => [2184]
+        map.insert(931, &[4232]);     // 931 => [4232]
+        map.insert(1462, &[8328]);     // 1462 => [8328]
+        map.insert(479, &[16520]);     // 479 => [16520]
+        map.insert(1786, &[32904]);     // 1786 => [32904]
+        map.insert(1071, &[65672]);     // 1071 => [65672]
+        map.insert(1585, &[131208]);     // 1585 => [131208]
+        map.insert(357, &[262280]);     // 357 => [262280]
+        map.insert(850, &[524424]);     // 850 => [524424]
+        map.insert(1865, &[1048712]);     // 1865 => [1048712]
+        map.insert(776, &[776]);     // 776 => [776]
+        map.insert(700, &[1288]);     // 700 => [1288]
+        map.insert(1288, &[2312]);     // 1288 => [2312]
+        map.insert(547, &[4360]);     // 547 => [4360]
+        map.insert(1078, &[8456]);     // 1078 => [8456]
+        map.insert(95, &[16648]);     // 95 => [16648]
+        map.insert(1914, &[33032]);     // 1914 => [33032]
+        map.insert(1455, &[65800]);     // 1455 => [65800]
+        map.insert(1969, &[131336]);     // 1969 => [131336]
+        map.insert(229, &[262408]);     // 229 => [262408]
+        map.insert(722, &[524552]);     // 722 => [524552]
+        map.insert(1737, &[1048840]);     // 1737 => [1048840]
+        map.insert(444, &[1544]);     // 444 => [1544]
+        map.insert(1544, &[2568]);     // 1544 => [2568]
+        map.insert(291, &[4616]);     // 291 => [4616]
+        map.insert(1846, &[8712]);     // 1846 => [8712]
+        map.insert(863, &[16904]);     // 863 => [16904]
+        map.insert(1146, &[33288]);     // 1146 => [33288]
+        map.insert(1711, &[66056]);     // 1711 => [66056]
+        map.insert(1201, &[131592]);     // 1201 => [131592]
+        map.insert(997, &[262664]);     // 997 => [262664]
+        map.insert(466, &[524808]);     // 466 => [524808]
+        map.insert(1481, &[1049096]);     // 1481 => [1049096]
+        map.insert(1980, &[3080]);     // 1980 => [3080]
+        map.insert(151, &[5128]);     // 151 => [5128]
+        map.insert(1666, &[9224]);     // 1666 => [9224]
+        map.insert(747, &[17416]);     // 747 => [17416]
+        map.insert(1486, &[33800]);     // 1486 => [33800]
+        map.insert(1819, &[66568]);     // 1819 => [66568]
+        map.insert(1285, &[132104]);     // 1285 => [132104]
+        map.insert(593, &[263176]);     // 593 => [263176]
+        map.insert(102, &[525320]);     // 102 => [525320]
+        map.insert(1149, &[1049608]);     // 1149 => [1049608]
+        map.insert(1827, &[6152]);     // 1827 => [6152]
+        map.insert(310, &[10248]);     // 310 => [10248]
+        map.insert(1375, &[18440]);     // 1375 => [18440]
+        map.insert(634, &[34824]);     // 634 => [34824]
+        map.insert(175, &[67592]);     // 175 => [67592]
+        map.insert(689, &[133128]);     // 689 => [133128]
+        map.insert(1509, &[264200]);     // 1509 => [264200]
+        map.insert(2002, &[526344]);     // 2002 => [526344]
+        map.insert(969, &[1050632]);     // 969 => [1050632]
+        map.insert(1565, &[12296]);     // 1565 => [12296]
+        map.insert(628, &[20488]);     // 628 => [20488]
+        map.insert(1361, &[36872]);     // 1361 => [36872]
+        map.insert(1924, &[69640]);     // 1924 => [69640]
+        map.insert(1434, &[135176]);     // 1434 => [135176]
+        map.insert(718, &[266248]);     // 718 => [266248]
+        map.insert(249, &[528392]);     // 249 => [528392]
+        map.insert(1250, &[1052680]);     // 1250 => [1052680]
+        map.insert(1121, &[24584]);     // 1121 => [24584]
+        map.insert(836, &[40968]);     // 836 => [40968]
+        map.insert(401, &[73736]);     // 401 => [73736]
+        map.insert(911, &[139272]);     // 911 => [139272]
+        map.insert(1243, &[270344]);     // 1243 => [270344]
+        map.insert(1772, &[532488]);     // 1772 => [532488]
+        map.insert(759, &[1056776]);     // 759 => [1056776]
+        map.insert(1837, &[49160]);     // 1837 => [49160]
+        map.insert(1528, &[81928]);     // 1528 => [81928]
+        map.insert(2022, &[147464]);     // 2022 => [147464]
+        map.insert(178, &[278536]);     // 178 => [278536]
+        map.insert(645, &[540680]);     // 645 => [540680]
+        map.insert(1694, &[1064968]);     // 1694 => [1064968]
+        map.insert(733, &[98312]);     // 733 => [98312]
+        map.insert(195, &[163848]);     // 195 => [163848]
+        map.insert(1943, &[294920]);     // 1943 => [294920]
+        map.insert(1440, &[557064]);     // 1440 => [557064]
+        map.insert(443, &[1081352]);     // 443 => [1081352]
+        map.insert(534, &[196616]);     // 534 => [196616]
+        map.insert(1346, &[327688]);     // 1346 => [327688]
+        map.insert(1909, &[589832]);     // 1909 => [589832]
+        map.insert(878, &[1114120]);     // 878 => [1114120]
+        map.insert(1884, &[393224]);     // 1884 => [393224]
+        map.insert(1387, &[655368]);     // 1387 => [655368]
+        map.insert(368, &[1179656]);     // 368 => [1179656]
+        map.insert(575, &[786440]);     // 575 => [786440]
+        map.insert(1572, &[1310728]);     // 1572 => [1310728]
+        map.insert(1043, &[1572872]);     // 1043 => [1572872]
+        map.insert(112, &[112]);     // 112 => [112]
+        map.insert(176, &[176]);     // 176 => [176]
+        map.insert(304, &[304]);     // 304 => [304]
+        map.insert(560, &[560]);     // 560 => [560]
+        map.insert(900, &[1072]);     // 900 => [1072]
+        map.insert(1072, &[2096]);     // 1072 => [2096]
+        map.insert(795, &[4144]);     // 795 => [4144]
+        map.insert(1294, &[8240]);     // 1294 => [8240]
+        map.insert(359, &[16432]);     // 359 => [16432]
+        map.insert(1602, &[32816]);     // 1602 => [32816]
+        map.insert(1175, &[65584]);     // 1175 => [65584]
+        map.insert(1673, &[131120]);     // 1673 => [131120]
+        map.insert(477, &[262192]);     // 477 => [262192]
+        map.insert(1002, &[524336]);     // 1002 => [524336]
+        map.insert(2033, &[1048624]);     // 2033 => [1048624]
+        map.insert(208, &[208]);     // 208 => [208]
+        map.insert(336, &[336]);     // 336 => [336]
+        map.insert(592, &[592]);     // 592 => [592]
+        map.insert(996, &[1104]);     // 996 => [1104]
+        map.insert(1104, &[2128]);     // 1104 => [2128]
+        map.insert(891, &[4176]);     // 891 => [4176]
+        map.insert(1390, &[8272]);     // 1390 => [8272]
+        map.insert(263, &[16464]);     // 263 => [16464]
+        map.insert(1570, &[32848]);     // 1570 => [32848]
+        map.insert(1271, &[65616]);     // 1271 => [65616]
+        map.insert(1769, &[131152]);     // 1769 => [131152]
+        map.insert(445, &[262224]);     // 445 => [262224]
+        map.insert(906, &[524368]);     // 906 => [524368]
+        map.insert(1937, &[1048656]);     // 1937 => [1048656]
+        map.insert(400, &[400]);     // 400 => [400]
+        map.insert(656, &[656]);     // 656 => [656]
+        map.insert(804, &[1168]);     // 804 => [1168]
+        map.insert(1168, &[2192]);     // 1168 => [2192]
+        map.insert(955, &[4240]);     // 955 => [4240]
+        map.insert(1454, &[8336]);     // 1454 => [8336]
+        map.insert(455, &[16528]);     // 455 => [16528]
+        map.insert(1762, &[32912]);     // 1762 => [32912]
+        map.insert(1079, &[65680]);     // 1079 => [65680]
+        map.insert(1577, &[131216]);     // 1577 => [131216]
+        map.insert(381, &[262288]);     // 381 => [262288]
+        map.insert(842, &[524432]);     // 842 => [524432]
+        map.insert(1873, &[1048720]);     // 1873 => [1048720]
+        map.insert(784, &[784]);     // 784 => [784]
+        map.insert(676, &[1296]);     // 676 => [1296]
+        map.insert(1296, &[2320]);     // 1296 => [2320]
+        map.insert(571, &[4368]);     // 571 => [4368]
+        map.insert(1070, &[8464]);     // 1070 => [8464]
+        map.insert(71, &[16656]);     // 71 => [16656]
+        map.insert(1890, &[33040]);     // 1890 => [33040]
+        map.insert(1463, &[65808]);     // 1463 => [65808]
+        map.insert(1961, &[131344]);     // 1961 => [131344]
+        map.insert(253, &[262416]);     // 253 => [262416]
+        map.insert(714, &[524560]);     // 714 => [524560]
+        map.insert(1745, &[1048848]);     // 1745 => [1048848]
+        map.insert(420, &[1552]);     // 420 => [1552]
+        map.insert(1552, &[2576]);     // 1552 => [2576]
+        map.insert(315, &[4624]);     // 315 => [4624]
+        map.insert(1838, &[8720]);     // 1838 => [8720]
+        map.insert(839, &[16912]);     // 839 => [16912]
+        map.insert(1122, &[33296]);     // 1122 => [33296]
+        map.insert(1719, &[66064]);     // 1719 => [66064]
+        map.insert(1193, &[131600]);     // 1193 => [131600]
+        map.insert(1021, &[262672]);     // 1021 => [262672]
+        map.insert(458, &[524816]);     // 458 => [524816]
+        map.insert(1489, &[1049104]);     // 1489 => [1049104]
+        map.insert(1956, &[3088]);     // 1956 => [3088]
+        map.insert(143, &[5136]);     // 143 => [5136]
+        map.insert(1690, &[9232]);     // 1690 => [9232]
+        map.insert(755, &[17424]);     // 755 => [17424]
+        map.insert(1494, &[33808]);     // 1494 => [33808]
+        map.insert(1795, &[66576]);     // 1795 => [66576]
+        map.insert(1309, &[132112]);     // 1309 => [132112]
+        map.insert(585, &[263184]);     // 585 => [263184]
+        map.insert(126, &[525328]);     // 126 => [525328]
+        map.insert(1125, &[1049616]);     // 1125 => [1049616]
+        map.insert(1851, &[6160]);     // 1851 => [6160]
+        map.insert(302, &[10256]);     // 302 => [10256]
+        map.insert(1351, &[18448]);     // 1351 => [18448]
+        map.insert(610, &[34832]);     // 610 => [34832]
+        map.insert(183, &[67600]);     // 183 => [67600]
+        map.insert(681, &[133136]);     // 681 => [133136]
+        map.insert(1533, &[264208]);     // 1533 => [264208]
+        map.insert(1994, &[526352]);     // 1994 => [526352]
+        map.insert(977, &[1050640]);     // 977 => [1050640]
+        map.insert(1541, &[12304]);     // 1541 => [12304]
+        map.insert(620, &[20496]);     // 620 => [20496]
+        map.insert(1353, &[36880]);     // 1353 => [36880]
+        map.insert(1948, &[69648]);     // 1948 => [69648]
+        map.insert(1410, &[135184]);     // 1410 => [135184]
+        map.insert(726, &[266256]);     // 726 => [266256]
+        map.insert(225, &[528400]);     // 225 => [528400]
+        map.insert(1274, &[1052688]);     // 1274 => [1052688]
+        map.insert(1145, &[24592]);     // 1145 => [24592]
+        map.insert(860, &[40976]);     // 860 => [40976]
+        map.insert(393, &[73744]);     // 393 => [73744]
+        map.insert(919, &[139280]);     // 919 => [139280]
+        map.insert(1219, &[270352]);     // 1219 => [270352]
+        map.insert(1780, &[532496]);     // 1780 => [532496]
+        map.insert(751, &[1056784]);     // 751 => [1056784]
+        map.insert(1845, &[49168]);     // 1845 => [49168]
+        map.insert(1504, &[81936]);     // 1504 => [81936]
+        map.insert(2046, &[147472]);     // 2046 => [147472]
+        map.insert(170, &[278544]);     // 170 => [278544]
+        map.insert(669, &[540688]);     // 669 => [540688]
+        map.insert(1670, &[1064976]);     // 1670 => [1064976]
+        map.insert(709, &[98320]);     // 709 => [98320]
+        map.insert(219, &[163856]);     // 219 => [163856]
+        map.insert(1935, &[294928]);     // 1935 => [294928]
+        map.insert(1464, &[557072]);     // 1464 => [557072]
+        map.insert(419, &[1081360]);     // 419 => [1081360]
+        map.insert(526, &[196624]);     // 526 => [196624]
+        map.insert(1370, &[327696]);     // 1370 => [327696]
+        map.insert(1901, &[589840]);     // 1901 => [589840]
+        map.insert(886, &[1114128]);     // 886 => [1114128]
+        map.insert(1860, &[393232]);     // 1860 => [393232]
+        map.insert(1395, &[655376]);     // 1395 => [655376]
+        map.insert(360, &[1179664]);     // 360 => [1179664]
+        map.insert(551, &[786448]);     // 551 => [786448]
+        map.insert(1596, &[1310736]);     // 1596 => [1310736]
+        map.insert(1035, &[1572880]);     // 1035 => [1572880]
+        map.insert(224, &[224]);     // 224 => [224]
+        map.insert(352, &[352]);     // 352 => [352]
+        map.insert(608, &[608]);     // 608 => [608]
+        map.insert(980, &[1120]);     // 980 => [1120]
+        map.insert(1120, &[2144]);     // 1120 => [2144]
+        map.insert(843, &[4192]);     // 843 => [4192]
+        map.insert(1374, &[8288]);     // 1374 => [8288]
+        map.insert(311, &[16480]);     // 311 => [16480]
+        map.insert(1554, &[32864]);     // 1554 => [32864]
+        map.insert(1223, &[65632]);     // 1223 => [65632]
+        map.insert(1753, &[131168]);     // 1753 => [131168]
+        map.insert(397, &[262240]);     // 397 => [262240]
+        map.insert(954, &[524384]);     // 954 => [524384]
+        map.insert(1953, &[1048672]);     // 1953 => [1048672]
+        map.insert(416, &[416]);     // 416 => [416]
+        map.insert(672, &[672]);     // 672 => [672]
+        map.insert(788, &[1184]);     // 788 => [1184]
+        map.insert(1184, &[2208]);     // 1184 => [2208]
+        map.insert(907, &[4256]);     // 907 => [4256]
+        map.insert(1438, &[8352]);     // 1438 => [8352]
+        map.insert(503, &[16544]);     // 503 => [16544]
+        map.insert(1746, &[32928]);     // 1746 => [32928]
+        map.insert(1031, &[65696]);     // 1031 => [65696]
+        map.insert(1561, &[131232]);     // 1561 => [131232]
+        map.insert(333, &[262304]);     // 333 => [262304]
+        map.insert(890, &[524448]);     // 890 => [524448]
+        map.insert(1889, &[1048736]);     // 1889 => [1048736]
+        map.insert(800, &[800]);     // 800 => [800]
+        map.insert(660, &[1312]);     // 660 => [1312]
+        map.insert(1312, &[2336]);     // 1312 => [2336]
+        map.insert(523, &[4384]);     // 523 => [4384]
+        map.insert(1054, &[8480]);     // 1054 => [8480]
+        map.insert(119, &[16672]);     // 119 => [16672]
+        map.insert(1874, &[33056]);     // 1874 => [33056]
+        map.insert(1415, &[65824]);     // 1415 => [65824]
+        map.insert(1945, &[131360]);     // 1945 => [131360]
+        map.insert(205, &[262432]);     // 205 => [262432]
+        map.insert(762, &[524576]);     // 762 => [524576]
+        map.insert(1761, &[1048864]);     // 1761 => [1048864]
+        map.insert(404, &[1568]);     // 404 => [1568]
+        map.insert(1568, &[2592]);     // 1568 => [2592]
+        map.insert(267, &[4640]);     // 267 => [4640]
+        map.insert(1822, &[8736]);     // 1822 => [8736]
+        map.insert(887, &[16928]);     // 887 => [16928]
+        map.insert(1106, &[33312]);     // 1106 => [33312]
+        map.insert(1671, &[66080]);     // 1671 => [66080]
+        map.insert(1177, &[131616]);     // 1177 => [131616]
+        map.insert(973, &[262688]);     // 973 => [262688]
+        map.insert(506, &[524832]);     // 506 => [524832]
+        map.insert(1505, &[1049120]);     // 1505 => [1049120]
+        map.insert(1940, &[3104]);     // 1940 => [3104]
+        map.insert(191, &[5152]);     // 191 => [5152]
+        map.insert(1706, &[9248]);     // 1706 => [9248]
+        map.insert(707, &[17440]);     // 707 => [17440]
+        map.insert(1510, &[33824]);     // 1510 => [33824]
+        map.insert(1843, &[66592]);     // 1843 => [66592]
+        map.insert(1325, &[132128]);     // 1325 => [132128]
+        map.insert(633, &[263200]);     // 633 => [263200]
+        map.insert(78, &[525344]);     // 78 => [525344]
+        map.insert(1109, &[1049632]);     // 1109 => [1049632]
+        map.insert(1803, &[6176]);     // 1803 => [6176]
+        map.insert(286, &[10272]);     // 286 => [10272]
+        map.insert(1399, &[18464]);     // 1399 => [18464]
+        map.insert(594, &[34848]);     // 594 => [34848]
+        map.insert(135, &[67616]);     // 135 => [67616]
+        map.insert(665, &[133152]);     // 665 => [133152]
+        map.insert(1485, &[264224]);     // 1485 => [264224]
+        map.insert(2042, &[526368]);     // 2042 => [526368]
+        map.insert(993, &[1050656]);     // 993 => [1050656]
+        map.insert(1589, &[12320]);     // 1589 => [12320]
+        map.insert(604, &[20512]);     // 604 => [20512]
+        map.insert(1401, &[36896]);     // 1401 => [36896]
+        map.insert(1964, &[69664]);     // 1964 => [69664]
+        map.insert(1458, &[135200]);     // 1458 => [135200]
+        map.insert(742, &[266272]);     // 742 => [266272]
+        map.insert(209, &[528416]);     // 209 => [528416]
+        map.insert(1226, &[1052704]);     // 1226 => [1052704]
+        map.insert(1097, &[24608]);     // 1097 => [24608]
+        map.insert(876, &[40992]);     // 876 => [40992]
+        map.insert(441, &[73760]);     // 441 => [73760]
+        map.insert(935, &[139296]);     // 935 => [139296]
+        map.insert(1267, &[270368]);     // 1267 => [270368]
+        map.insert(1732, &[532512]);     // 1732 => [532512]
+        map.insert(735, &[1056800]);     // 735 => [1056800]
+        map.insert(1797, &[49184]);     // 1797 => [49184]
+        map.insert(1488, &[81952]);     // 1488 => [81952]
+        map.insert(1998, &[147488]);     // 1998 => [147488]
+        map.insert(154, &[278560]);     // 154 => [278560]
+        map.insert(685, &[540704]);     // 685 => [540704]
+        map.insert(1718, &[1064992]);     // 1718 => [1064992]
+        map.insert(757, &[98336]);     // 757 => [98336]
+        map.insert(235, &[163872]);     // 235 => [163872]
+        map.insert(1983, &[294944]);     // 1983 => [294944]
+        map.insert(1416, &[557088]);     // 1416 => [557088]
+        map.insert(403, &[1081376]);     // 403 => [1081376]
+        map.insert(574, &[196640]);     // 574 => [196640]
+        map.insert(1386, &[327712]);     // 1386 => [327712]
+        map.insert(1885, &[589856]);     // 1885 => [589856]
+        map.insert(838, &[1114144]);     // 838 => [1114144]
+        map.insert(1908, &[393248]);     // 1908 => [393248]
+        map.insert(1347, &[655392]);     // 1347 => [655392]
+        map.insert(344, &[1179680]);     // 344 => [1179680]
+        map.insert(535, &[786464]);     // 535 => [786464]
+        map.insert(1548, &[1310752]);     // 1548 => [1310752]
+        map.insert(1083, &[1572896]);     // 1083 => [1572896]
+        map.insert(448, &[448]);     // 448 => [448]
+        map.insert(704, &[704]);     // 704 => [704]
+        map.insert(884, &[1216]);     // 884 => [1216]
+        map.insert(1216, &[2240]);     // 1216 => [2240]
+        map.insert(1003, &[4288]);     // 1003 => [4288]
+        map.insert(1534, &[8384]);     // 1534 => [8384]
+        map.insert(407, &[16576]);     // 407 => [16576]
+        map.insert(1714, &[32960]);     // 1714 => [32960]
+        map.insert(1127, &[65728]);     // 1127 => [65728]
+        map.insert(1657, &[131264]);     // 1657 => [131264]
+        map.insert(301, &[262336]);     // 301 => [262336]
+        map.insert(794, &[524480]);     // 794 => [524480]
+        map.insert(1793, &[1048768]);     // 1793 => [1048768]
+        map.insert(832, &[832]);     // 832 => [832]
+        map.insert(756, &[1344]);     // 756 => [1344]
+        map.insert(1344, &[2368]);     // 1344 => [2368]
+        map.insert(619, &[4416]);     // 619 => [4416]
+        map.insert(1150, &[8512]);     // 1150 => [8512]
+        map.insert(23, &[16704]);     // 23 => [16704]
+        map.insert(1842, &[33088]);     // 1842 => [33088]
+        map.insert(1511, &[65856]);     // 1511 => [65856]
+        map.insert(2041, &[131392]);     // 2041 => [131392]
+        map.insert(173, &[262464]);     // 173 => [262464]
+        map.insert(666, &[524608]);     // 666 => [524608]
+        map.insert(1665, &[1048896]);     // 1665 => [1048896]
+        map.insert(500, &[1600]);     // 500 => [1600]
+        map.insert(1600, &[2624]);     // 1600 => [2624]
+        map.insert(363, &[4672]);     // 363 => [4672]
+        map.insert(1918, &[8768]);     // 1918 => [8768]
+        map.insert(791, &[16960]);     // 791 => [16960]
+        map.insert(1074, &[33344]);     // 1074 => [33344]
+        map.insert(1767, &[66112]);     // 1767 => [66112]
+        map.insert(1273, &[131648]);     // 1273 => [131648]
+        map.insert(941, &[262720]);     // 941 => [262720]
+        map.insert(410, &[524864]);     // 410 => [524864]
+        map.insert(1409, &[1049152]);     // 1409 => [1049152]
+        map.insert(2036, &[3136]);     // 2036 => [3136]
+        map.insert(223, &[5184]);     // 223 => [5184]
+        map.insert(1738, &[9280]);     // 1738 => [9280]
+        map.insert(675, &[17472]);     // 675 => [17472]
+        map.insert(1414, &[33856]);     // 1414 => [33856]
+        map.insert(1875, &[66624]);     // 1875 => [66624]
+        map.insert(1357, &[132160]);     // 1357 => [132160]
+        map.insert(537, &[263232]);     // 537 => [263232]
+        map.insert(46, &[525376]);     // 46 => [525376]
+        map.insert(1077, &[1049664]);     // 1077 => [1049664]
+        map.insert(1899, &[6208]);     // 1899 => [6208]
+        map.insert(382, &[10304]);     // 382 => [10304]
+        map.insert(1303, &[18496]);     // 1303 => [18496]
+        map.insert(562, &[34880]);     // 562 => [34880]
+        map.insert(231, &[67648]);     // 231 => [67648]
+        map.insert(761, &[133184]);     // 761 => [133184]
+        map.insert(1453, &[264256]);     // 1453 => [264256]
+        map.insert(1946, &[526400]);     // 1946 => [526400]
+        map.insert(897, &[1050688]);     // 897 => [1050688]
+        map.insert(1621, &[12352]);     // 1621 => [12352]
+        map.insert(572, &[20544]);     // 572 => [20544]
+        map.insert(1305, &[36928]);     // 1305 => [36928]
+        map.insert(1996, &[69696]);     // 1996 => [69696]
+        map.insert(1490, &[135232]);     // 1490 => [135232]
+        map.insert(646, &[266304]);     // 646 => [266304]
+        map.insert(177, &[528448]);     // 177 => [528448]
+        map.insert(1194, &[1052736]);     // 1194 => [1052736]
+        map.insert(1065, &[24640]);     // 1065 => [24640]
+        map.insert(780, &[41024]);     // 780 => [41024]
+        map.insert(473, &[73792]);     // 473 => [73792]
+        map.insert(967, &[139328]);     // 967 => [139328]
+        map.insert(1171, &[270400]);     // 1171 => [270400]
+        map.insert(1700, &[532544]);     // 1700 => [532544]
+        map.insert(703, &[1056832]);     // 703 => [1056832]
+        map.insert(1893, &[49216]);     // 1893 => [49216]
+        map.insert(1456, &[81984]);     // 1456 => [81984]
+        map.insert(1966, &[147520]);     // 1966 => [147520]
+        map.insert(250, &[278592]);     // 250 => [278592]
+        map.insert(717, &[540736]);     // 717 => [540736]
+        map.insert(1750, &[1065024]);     // 1750 => [1065024]
+        map.insert(661, &[98368]);     // 661 => [98368]
+        map.insert(139, &[163904]);     // 139 => [163904]
+        map.insert(2015, &[294976]);     // 2015 => [294976]
+        map.insert(1512, &[557120]);     // 1512 => [557120]
+        map.insert(499, &[1081408]);     // 499 => [1081408]
+        map.insert(606, &[196672]);     // 606 => [196672]
+        map.insert(1290, &[327744]);     // 1290 => [327744]
+        map.insert(1853, &[589888]);     // 1853 => [589888]
+        map.insert(806, &[1114176]);     // 806 => [1114176]
+        map.insert(1812, &[393280]);     // 1812 => [393280]
+        map.insert(1315, &[655424]);     // 1315 => [655424]
+        map.insert(312, &[1179712]);     // 312 => [1179712]
+        map.insert(631, &[786496]);     // 631 => [786496]
+        map.insert(1644, &[1310784]);     // 1644 => [1310784]
+        map.insert(1115, &[1572928]);     // 1115 => [1572928]
+        map.insert(896, &[896]);     // 896 => [896]
+        map.insert(564, &[1408]);     // 564 => [1408]
+        map.insert(1408, &[2432]);     // 1408 => [2432]
+        map.insert(683, &[4480]);     // 683 => [4480]
+        map.insert(1214, &[8576]);     // 1214 => [8576]
+        map.insert(215, &[16768]);     // 215 => [16768]
+        map.insert(2034, &[33152]);     // 2034 => [33152]
+        map.insert(1319, &[65920]);     // 1319 => [65920]
+        map.insert(1849, &[131456]);     // 1849 => [131456]
+        map.insert(109, &[262528]);     // 109 => [262528]
+        map.insert(602, &[524672]);     // 602 => [524672]
+        map.insert(1601, &[1048960]);     // 1601 => [1048960]
+        map.insert(308, &[1664]);     // 308 => [1664]
+        map.insert(1664, &[2688]);     // 1664 => [2688]
+        map.insert(427, &[4736]);     // 427 => [4736]
+        map.insert(1982, &[8832]);     // 1982 => [8832]
+        map.insert(983, &[17024]);     // 983 => [17024]
+        map.insert(1266, &[33408]);     // 1266 => [33408]
+        map.insert(1575, &[66176]);     // 1575 => [66176]
+        map.insert(1081, &[131712]);     // 1081 => [131712]
+        map.insert(877, &[262784]);     // 877 => [262784]
+        map.insert(346, &[524928]);     // 346 => [524928]
+        map.insert(1345, &[1049216]);     // 1345 => [1049216]
+        map.insert(1844, &[3200]);     // 1844 => [3200]
+        map.insert(31, &[5248]);     // 31 => [5248]
+        map.insert(1546, &[9344]);     // 1546 => [9344]
+        map.insert(611, &[17536]);     // 611 => [17536]
+        map.insert(1350, &[33920]);     // 1350 => [33920]
+        map.insert(1939, &[66688]);     // 1939 => [66688]
+        map.insert(1421, &[132224]);     // 1421 => [132224]
+        map.insert(729, &[263296]);     // 729 => [263296]
+        map.insert(238, &[525440]);     // 238 => [525440]
+        map.insert(1269, &[1049728]);     // 1269 => [1049728]
+        map.insert(1963, &[6272]);     // 1963 => [6272]
+        map.insert(446, &[10368]);     // 446 => [10368]
+        map.insert(1495, &[18560]);     // 1495 => [18560]
+        map.insert(754, &[34944]);     // 754 => [34944]
+        map.insert(39, &[67712]);     // 39 => [67712]
+        map.insert(569, &[133248]);     // 569 => [133248]
+        map.insert(1389, &[264320]);     // 1389 => [264320]
+        map.insert(1882, &[526464]);     // 1882 => [526464]
+        map.insert(833, &[1050752]);     // 833 => [1050752]
+        map.insert(1685, &[12416]);     // 1685 => [12416]
+        map.insert(764, &[20608]);     // 764 => [20608]
+        map.insert(1497, &[36992]);     // 1497 => [36992]
+        map.insert(1804, &[69760]);     // 1804 => [69760]
+        map.insert(1298, &[135296]);     // 1298 => [135296]
+        map.insert(582, &[266368]);     // 582 => [266368]
+        map.insert(113, &[528512]);     // 113 => [528512]
+        map.insert(1130, &[1052800]);     // 1130 => [1052800]
+        map.insert(1257, &[24704]);     // 1257 => [24704]
+        map.insert(972, &[41088]);     // 972 => [41088]
+        map.insert(281, &[73856]);     // 281 => [73856]
+        map.insert(775, &[139392]);     // 775 => [139392]
+        map.insert(1107, &[270464]);     // 1107 => [270464]
+        map.insert(1636, &[532608]);     // 1636 => [532608]
+        map.insert(639, &[1056896]);     // 639 => [1056896]
+        map.insert(1957, &[49280]);     // 1957 => [49280]
+        map.insert(1392, &[82048]);     // 1392 => [82048]
+        map.insert(1902, &[147584]);     // 1902 => [147584]
+        map.insert(58, &[278656]);     // 58 => [278656]
+        map.insert(525, &[540800]);     // 525 => [540800]
+        map.insert(1558, &[1065088]);     // 1558 => [1065088]
+        map.insert(597, &[98432]);     // 597 => [98432]
+        map.insert(75, &[163968]);     // 75 => [163968]
+        map.insert(1823, &[295040]);     // 1823 => [295040]
+        map.insert(1320, &[557184]);     // 1320 => [557184]
+        map.insert(307, &[1081472]);     // 307 => [1081472]
+        map.insert(670, &[196736]);     // 670 => [196736]
+        map.insert(1482, &[327808]);     // 1482 => [327808]
+        map.insert(2045, &[589952]);     // 2045 => [589952]
+        map.insert(998, &[1114240]);     // 998 => [1114240]
+        map.insert(2004, &[393344]);     // 2004 => [393344]
+        map.insert(1507, &[655488]);     // 1507 => [655488]
+        map.insert(504, &[1179776]);     // 504 => [1179776]
+        map.insert(695, &[786560]);     // 695 => [786560]
+        map.insert(1708, &[1310848]);     // 1708 => [1310848]
+        map.insert(1179, &[1572992]);     // 1179 => [1572992]
+        map.insert(180, &[1792]);     // 180 => [1792]
+        map.insert(1792, &[2816]);     // 1792 => [2816]
+        map.insert(43, &[4864]);     // 43 => [4864]
+        map.insert(1598, &[8960]);     // 1598 => [8960]
+        map.insert(599, &[17152]);     // 599 => [17152]
+        map.insert(1394, &[33536]);     // 1394 => [33536]
+        map.insert(1959, &[66304]);     // 1959 => [66304]
+        map.insert(1465, &[131840]);     // 1465 => [131840]
+        map.insert(749, &[262912]);     // 749 => [262912]
+        map.insert(218, &[525056]);     // 218 => [525056]
+        map.insert(1217, &[1049344]);     // 1217 => [1049344]
+        map.insert(1716, &[3328]);     // 1716 => [3328]
+        map.insert(415, &[5376]);     // 415 => [5376]
+        map.insert(1930, &[9472]);     // 1930 => [9472]
+        map.insert(995, &[17664]);     // 995 => [17664]
+        map.insert(1222, &[34048]);     // 1222 => [34048]
+        map.insert(1555, &[66816]);     // 1555 => [66816]
+        map.insert(1037, &[132352]);     // 1037 => [132352]
+        map.insert(857, &[263424]);     // 857 => [263424]
+        map.insert(366, &[525568]);     // 366 => [525568]
+        map.insert(1397, &[1049856]);     // 1397 => [1049856]
+        map.insert(1579, &[6400]);     // 1579 => [6400]
+        map.insert(62, &[10496]);     // 62 => [10496]
+        map.insert(1111, &[18688]);     // 1111 => [18688]
+        map.insert(882, &[35072]);     // 882 => [35072]
+        map.insert(423, &[67840]);     // 423 => [67840]
+        map.insert(953, &[133376]);     // 953 => [133376]
+        map.insert(1261, &[264448]);     // 1261 => [264448]
+        map.insert(1754, &[526592]);     // 1754 => [526592]
+        map.insert(705, &[1050880]);     // 705 => [1050880]
+        map.insert(1813, &[12544]);     // 1813 => [12544]
+        map.insert(892, &[20736]);     // 892 => [20736]
+        map.insert(1113, &[37120]);     // 1113 => [37120]
+        map.insert(1676, &[69888]);     // 1676 => [69888]
+        map.insert(1170, &[135424]);     // 1170 => [135424]
+        map.insert(966, &[266496]);     // 966 => [266496]
+        map.insert(497, &[528640]);     // 497 => [528640]
+        map.insert(1514, &[1052928]);     // 1514 => [1052928]
+        map.insert(1385, &[24832]);     // 1385 => [24832]
+        map.insert(588, &[41216]);     // 588 => [41216]
+        map.insert(153, &[73984]);     // 153 => [73984]
+        map.insert(647, &[139520]);     // 647 => [139520]
+        map.insert(1491, &[270592]);     // 1491 => [270592]
+        map.insert(2020, &[532736]);     // 2020 => [532736]
+        map.insert(1023, &[1057024]);     // 1023 => [1057024]
+        map.insert(1573, &[49408]);     // 1573 => [49408]
+        map.insert(1264, &[82176]);     // 1264 => [82176]
+        map.insert(1774, &[147712]);     // 1774 => [147712]
+        map.insert(442, &[278784]);     // 442 => [278784]
+        map.insert(909, &[540928]);     // 909 => [540928]
+        map.insert(1942, &[1065216]);     // 1942 => [1065216]
+        map.insert(981, &[98560]);     // 981 => [98560]
+        map.insert(459, &[164096]);     // 459 => [164096]
+        map.insert(1695, &[295168]);     // 1695 => [295168]
+        map.insert(1192, &[557312]);     // 1192 => [557312]
+        map.insert(179, &[1081600]);     // 179 => [1081600]
+        map.insert(798, &[196864]);     // 798 => [196864]
+        map.insert(1098, &[327936]);     // 1098 => [327936]
+        map.insert(1661, &[590080]);     // 1661 => [590080]
+        map.insert(614, &[1114368]);     // 614 => [1114368]
+        map.insert(1620, &[393472]);     // 1620 => [393472]
+        map.insert(1123, &[655616]);     // 1123 => [655616]
+        map.insert(120, &[1179904]);     // 120 => [1179904]
+        map.insert(823, &[786688]);     // 823 => [786688]
+        map.insert(1836, &[1310976]);     // 1836 => [1310976]
+        map.insert(1307, &[1573120]);     // 1307 => [1573120]
+        map.insert(1460, &[3584]);     // 1460 => [3584]
+        map.insert(671, &[5632]);     // 671 => [5632]
+        map.insert(1162, &[9728]);     // 1162 => [9728]
+        map.insert(227, &[17920]);     // 227 => [17920]
+        map.insert(1990, &[34304]);     // 1990 => [34304]
+        map.insert(1299, &[67072]);     // 1299 => [67072]
+        map.insert(1805, &[132608]);     // 1805 => [132608]
+        map.insert(89, &[263680]);     // 89 => [263680]
+        map.insert(622, &[525824]);     // 622 => [525824]
+        map.insert(1653, &[1050112]);     // 1653 => [1050112]
+        map.insert(1323, &[6656]);     // 1323 => [6656]
+        map.insert(830, &[10752]);     // 830 => [10752]
+        map.insert(1879, &[18944]);     // 1879 => [18944]
+        map.insert(114, &[35328]);     // 114 => [35328]
+        map.insert(679, &[68096]);     // 679 => [68096]
+        map.insert(185, &[133632]);     // 185 => [133632]
+        map.insert(2029, &[264704]);     // 2029 => [264704]
+        map.insert(1498, &[526848]);     // 1498 => [526848]
+        map.insert(449, &[1051136]);     // 449 => [1051136]
+        map.insert(1045, &[12800]);     // 1045 => [12800]
+        map.insert(124, &[20992]);     // 124 => [20992]
+        map.insert(1881, &[37376]);     // 1881 => [37376]
+        map.insert(1420, &[70144]);     // 1420 => [70144]
+        map.insert(1938, &[135680]);     // 1938 => [135680]
+        map.insert(198, &[266752]);     // 198 => [266752]
+        map.insert(753, &[528896]);     // 753 => [528896]
+        map.insert(1770, &[1053184]);     // 1770 => [1053184]
+        map.insert(1641, &[25088]);     // 1641 => [25088]
+        map.insert(332, &[41472]);     // 332 => [41472]
+        map.insert(921, &[74240]);     // 921 => [74240]
+        map.insert(391, &[139776]);     // 391 => [139776]
+        map.insert(1747, &[270848]);     // 1747 => [270848]
+        map.insert(1252, &[532992]);     // 1252 => [532992]
+        map.insert(255, &[1057280]);     // 255 => [1057280]
+        map.insert(1317, &[49664]);     // 1317 => [49664]
+        map.insert(2032, &[82432]);     // 2032 => [82432]
+        map.insert(1518, &[147968]);     // 1518 => [147968]
+        map.insert(698, &[279040]);     // 698 => [279040]
+        map.insert(141, &[541184]);     // 141 => [541184]
+        map.insert(1174, &[1065472]);     // 1174 => [1065472]
+        map.insert(213, &[98816]);     // 213 => [98816]
+        map.insert(715, &[164352]);     // 715 => [164352]
+        map.insert(1439, &[295424]);     // 1439 => [295424]
+        map.insert(1960, &[557568]);     // 1960 => [557568]
+        map.insert(947, &[1081856]);     // 947 => [1081856]
+        map.insert(30, &[197120]);     // 30 => [197120]
+        map.insert(1866, &[328192]);     // 1866 => [328192]
+        map.insert(1405, &[590336]);     // 1405 => [590336]
+        map.insert(358, &[1114624]);     // 358 => [1114624]
+        map.insert(1364, &[393728]);     // 1364 => [393728]
+        map.insert(1891, &[655872]);     // 1891 => [655872]
+        map.insert(888, &[1180160]);     // 888 => [1180160]
+        map.insert(55, &[786944]);     // 55 => [786944]
+        map.insert(1068, &[1311232]);     // 1068 => [1311232]
+        map.insert(1563, &[1573376]);     // 1563 => [1573376]
+        map.insert(1183, &[7168]);     // 1183 => [7168]
+        map.insert(650, &[11264]);     // 650 => [11264]
+        map.insert(1763, &[19456]);     // 1763 => [19456]
+        map.insert(454, &[35840]);     // 454 => [35840]
+        map.insert(787, &[68608]);     // 787 => [68608]
+        map.insert(269, &[134144]);     // 269 => [134144]
+        map.insert(1625, &[265216]);     // 1625 => [265216]
+        map.insert(1134, &[527360]);     // 1134 => [527360]
+        map.insert(117, &[1051648]);     // 117 => [1051648]
+        map.insert(1441, &[13312]);     // 1441 => [13312]
+        map.insert(456, &[21504]);     // 456 => [21504]
+        map.insert(1773, &[37888]);     // 1773 => [37888]
+        map.insert(1080, &[70656]);     // 1080 => [70656]
+        map.insert(1574, &[136192]);     // 1574 => [136192]
+        map.insert(370, &[267264]);     // 370 => [267264]
+        map.insert(837, &[529408]);     // 837 => [529408]
+        map.insert(1886, &[1053696]);     // 1886 => [1053696]
+        map.insert(2013, &[25600]);     // 2013 => [25600]
+        map.insert(248, &[41984]);     // 248 => [41984]
+        map.insert(557, &[74752]);     // 557 => [74752]
+        map.insert(51, &[140288]);     // 51 => [140288]
+        map.insert(1895, &[271360]);     // 1895 => [271360]
+        map.insert(1360, &[533504]);     // 1360 => [533504]
+        map.insert(331, &[1057792]);     // 331 => [1057792]
+        map.insert(1169, &[50176]);     // 1169 => [50176]
+        map.insert(1604, &[82944]);     // 1604 => [82944]
+        map.insert(1114, &[148480]);     // 1114 => [148480]
+        map.insert(782, &[279552]);     // 782 => [279552]
+        map.insert(313, &[541696]);     // 313 => [541696]
+        map.insert(1314, &[1065984]);     // 1314 => [1065984]
+        map.insert(353, &[99328]);     // 353 => [99328]
+        map.insert(895, &[164864]);     // 895 => [164864]
+        map.insert(1067, &[295936]);     // 1067 => [295936]
+        map.insert(1564, &[558080]);     // 1564 => [558080]
+        map.insert(519, &[1082368]);     // 519 => [1082368]
+        map.insert(426, &[197632]);     // 426 => [197632]
+        map.insert(1790, &[328704]);     // 1790 => [328704]
+        map.insert(1225, &[590848]);     // 1225 => [590848]
+        map.insert(210, &[1115136]);     // 210 => [1115136]
+        map.insert(1248, &[394240]);     // 1248 => [394240]
+        map.insert(1751, &[656384]);     // 1751 => [656384]
+        map.insert(716, &[1180672]);     // 716 => [1180672]
+        map.insert(387, &[787456]);     // 387 => [787456]
+        map.insert(1432, &[1311744]);     // 1432 => [1311744]
+        map.insert(1967, &[1573888]);     // 1967 => [1573888]
+        map.insert(533, &[14336]);     // 533 => [14336]
+        map.insert(1660, &[22528]);     // 1660 => [22528]
+        map.insert(345, &[38912]);     // 345 => [38912]
+        map.insert(908, &[71680]);     // 908 => [71680]
+        map.insert(402, &[137216]);     // 402 => [137216]
+        map.insert(1734, &[268288]);     // 1734 => [268288]
+        map.insert(1265, &[530432]);     // 1265 => [530432]
+        map.insert(234, &[1054720]);     // 234 => [1054720]
+        map.insert(105, &[26624]);     // 105 => [26624]
+        map.insert(1868, &[43008]);     // 1868 => [43008]
+        map.insert(1433, &[75776]);     // 1433 => [75776]
+        map.insert(1927, &[141312]);     // 1927 => [141312]
+        map.insert(211, &[272384]);     // 211 => [272384]
+        map.insert(740, &[534528]);     // 740 => [534528]
+        map.insert(1791, &[1058816]);     // 1791 => [1058816]
+        map.insert(805, &[51200]);     // 805 => [51200]
+        map.insert(496, &[83968]);     // 496 => [83968]
+        map.insert(1006, &[149504]);     // 1006 => [149504]
+        map.insert(1210, &[280576]);     // 1210 => [280576]
+        map.insert(1677, &[542720]);     // 1677 => [542720]
+        map.insert(662, &[1067008]);     // 662 => [1067008]
+        map.insert(1749, &[100352]);     // 1749 => [100352]
+        map.insert(1227, &[165888]);     // 1227 => [165888]
+        map.insert(927, &[296960]);     // 927 => [296960]
+        map.insert(424, &[559104]);     // 424 => [559104]
+        map.insert(1459, &[1083392]);     // 1459 => [1083392]
+        map.insert(1566, &[198656]);     // 1566 => [198656]
+        map.insert(330, &[329728]);     // 330 => [329728]
+        map.insert(893, &[591872]);     // 893 => [591872]
+        map.insert(1894, &[1116160]);     // 1894 => [1116160]
+        map.insert(852, &[395264]);     // 852 => [395264]
+        map.insert(355, &[657408]);     // 355 => [657408]
+        map.insert(1400, &[1181696]);     // 1400 => [1181696]
+        map.insert(1591, &[788480]);     // 1591 => [788480]
+        map.insert(556, &[1312768]);     // 556 => [1312768]
+        map.insert(27, &[1574912]);     // 27 => [1574912]
+        map.insert(1858, &[28672]);     // 1858 => [28672]
+        map.insert(103, &[45056]);     // 103 => [45056]
+        map.insert(690, &[77824]);     // 690 => [77824]
+        map.insert(172, &[143360]);     // 172 => [143360]
+        map.insert(2040, &[274432]);     // 2040 => [274432]
+        map.insert(1487, &[536576]);     // 1487 => [536576]
+        map.insert(468, &[1060864]);     // 468 => [1060864]
+        map.insert(1038, &[53248]);     // 1038 => [53248]
+        map.insert(1755, &[86016]);     // 1755 => [86016]
+        map.insert(1221, &[151552]);     // 1221 => [151552]
+        map.insert(913, &[282624]);     // 913 => [282624]
+        map.insert(422, &[544768]);     // 422 => [544768]
+        map.insert(1469, &[1069056]);     // 1469 => [1069056]
+        map.insert(510, &[102400]);     // 510 => [102400]
+        map.insert(992, &[167936]);     // 992 => [167936]
+        map.insert(1204, &[299008]);     // 1204 => [299008]
+        map.insert(1667, &[561152]);     // 1667 => [561152]
+        map.insert(664, &[1085440]);     // 664 => [1085440]
+        map.insert(309, &[200704]);     // 309 => [200704]
+        map.insert(1633, &[331776]);     // 1633 => [331776]
+        map.insert(1110, &[593920]);     // 1110 => [593920]
+        map.insert(77, &[1118208]);     // 77 => [1118208]
+        map.insert(1151, &[397312]);     // 1151 => [397312]
+        map.insert(1608, &[659456]);     // 1608 => [659456]
+        map.insert(595, &[1183744]);     // 595 => [1183744]
+        map.insert(284, &[790528]);     // 284 => [790528]
+        map.insert(1287, &[1314816]);     // 1287 => [1314816]
+        map.insert(1840, &[1576960]);     // 1840 => [1576960]
+        map.insert(539, &[57344]);     // 539 => [57344]
+        map.insert(206, &[90112]);     // 206 => [90112]
+        map.insert(720, &[155648]);     // 720 => [155648]
+        map.insert(1412, &[286720]);     // 1412 => [286720]
+        map.insert(1971, &[548864]);     // 1971 => [548864]
+        map.insert(936, &[1073152]);     // 936 => [1073152]
+        map.insert(2027, &[106496]);     // 2027 => [106496]
+        map.insert(1525, &[172032]);     // 1525 => [172032]
+        map.insert(673, &[303104]);     // 673 => [303104]
+        map.insert(150, &[565248]);     // 150 => [565248]
+        map.insert(1165, &[1089536]);     // 1165 => [1089536]
+        map.insert(1824, &[204800]);     // 1824 => [204800]
+        map.insert(116, &[335872]);     // 116 => [335872]
+        map.insert(579, &[598016]);     // 579 => [598016]
+        map.insert(1624, &[1122304]);     // 1624 => [1122304]
+        map.insert(618, &[401408]);     // 618 => [401408]
+        map.insert(93, &[663552]);     // 93 => [663552]
+        map.insert(1094, &[1187840]);     // 1094 => [1187840]
+        map.insert(1801, &[794624]);     // 1801 => [794624]
+        map.insert(786, &[1318912]);     // 786 => [1318912]
+        map.insert(293, &[1581056]);     // 293 => [1581056]
+        map.insert(898, &[114688]);     // 898 => [114688]
+        map.insert(412, &[180224]);     // 412 => [180224]
+        map.insert(1736, &[311296]);     // 1736 => [311296]
+        map.insert(1279, &[573440]);     // 1279 => [573440]
+        map.insert(228, &[1097728]);     // 228 => [1097728]
+        map.insert(841, &[212992]);     // 841 => [212992]
+        map.insert(1053, &[344064]);     // 1053 => [344064]
+        map.insert(1578, &[606208]);     // 1578 => [606208]
+        map.insert(561, &[1130496]);     // 561 => [1130496]
+        map.insert(1539, &[409600]);     // 1539 => [409600]
+        map.insert(1076, &[671744]);     // 1076 => [671744]
+        map.insert(47, &[1196032]);     // 47 => [1196032]
+        map.insert(864, &[802816]);     // 864 => [802816]
+        map.insert(1915, &[1327104]);     // 1915 => [1327104]
+        map.insert(1356, &[1589248]);     // 1356 => [1589248]
+        map.insert(1132, &[229376]);     // 1132 => [229376]
+        map.insert(824, &[360448]);     // 824 => [360448]
+        map.insert(271, &[622592]);     // 271 => [622592]
+        map.insert(1300, &[1146880]);     // 1300 => [1146880]
+        map.insert(294, &[425984]);     // 294 => [425984]
+        map.insert(785, &[688128]);     // 785 => [688128]
+        map.insert(1802, &[1212416]);     // 1802 => [1212416]
+        map.insert(1093, &[819200]);     // 1093 => [819200]
+        map.insert(94, &[1343488]);     // 94 => [1343488]
+        map.insert(617, &[1605632]);     // 617 => [1605632]
+        map.insert(1011, &[458752]);     // 1011 => [458752]
+        map.insert(452, &[720896]);     // 452 => [720896]
+        map.insert(1503, &[1245184]);     // 1503 => [1245184]
+        map.insert(1680, &[851968]);     // 1680 => [851968]
+        map.insert(651, &[1376256]);     // 651 => [1376256]
+        map.insert(188, &[1638400]);     // 188 => [1638400]
+        map.insert(1166, &[917504]);     // 1166 => [917504]
+        map.insert(149, &[1441792]);     // 149 => [1441792]
+        map.insert(674, &[1703936]);     // 674 => [1703936]
+        map.insert(1526, &[1835008]);     // 1526 => [1835008]
+        map.insert(15, &[15]);     // 15 => [15]
+        map.insert(812, &[4103]);     // 812 => [4103]
+        map.insert(1337, &[8199]);     // 1337 => [8199]
+        map.insert(1726, &[131079]);     // 1726 => [131079]
+        map.insert(490, &[262151]);     // 490 => [262151]
+        map.insert(989, &[524295]);     // 989 => [524295]
+        map.insert(959, &[1035]);     // 959 => [1035]
+        map.insert(1333, &[8203]);     // 1333 => [8203]
+        map.insert(348, &[16395]);     // 348 => [16395]
+        map.insert(1196, &[65547]);     // 1196 => [65547]
+        map.insert(486, &[262155]);     // 486 => [262155]
+        map.insert(147, &[147]);     // 147 => [147]
+        map.insert(531, &[531]);     // 531 => [531]
+        map.insert(99, &[99]);     // 99 => [99]
+        map.insert(372, &[16419]);     // 372 => [16419]
+        map.insert(1617, &[32803]);     // 1617 => [32803]
+        map.insert(462, &[262179]);     // 462 => [262179]
+        map.insert(1017, &[524323]);     // 1017 => [524323]
+        map.insert(2018, &[1048611]);     // 2018 => [1048611]
+        map.insert(1015, &[1091]);     // 1015 => [1091]
+        map.insert(1091, &[2115]);     // 1091 => [2115]
+        map.insert(872, &[4163]);     // 872 => [4163]
+        map.insert(430, &[262211]);     // 430 => [262211]
+        map.insert(1922, &[1048643]);     // 1922 => [1048643]
+        map.insert(643, &[643]);     // 643 => [643]
+        map.insert(1283, &[2307]);     // 1283 => [2307]
+        map.insert(1085, &[8451]);     // 1085 => [8451]
+        map.insert(1905, &[33027]);     // 1905 => [33027]
+        map.insert(1444, &[65795]);     // 1444 => [65795]
+        map.insert(1978, &[131331]);     // 1978 => [131331]
+        map.insert(1730, &[1048835]);     // 1730 => [1048835]
+        map.insert(1975, &[3075]);     // 1975 => [3075]
+        map.insert(156, &[5123]);     // 156 => [5123]
+        map.insert(736, &[17411]);     // 736 => [17411]
+        map.insert(1808, &[66563]);     // 1808 => [66563]
+        map.insert(1832, &[6147]);     // 1832 => [6147]
+        map.insert(317, &[10243]);     // 317 => [10243]
+        map.insert(625, &[34819]);     // 625 => [34819]
+        map.insert(1425, &[135171]);     // 1425 => [135171]
+        map.insert(242, &[528387]);     // 242 => [528387]
+        map.insert(847, &[40963]);     // 847 => [40963]
+        map.insert(1232, &[270339]);     // 1232 => [270339]
+        map.insert(1830, &[49155]);     // 1830 => [49155]
+        map.insert(1523, &[81923]);     // 1523 => [81923]
+        map.insert(654, &[540675]);     // 654 => [540675]
+        map.insert(1451, &[557059]);     // 1451 => [557059]
+        map.insert(541, &[196611]);     // 541 => [196611]
+        map.insert(869, &[1114115]);     // 869 => [1114115]
+        map.insert(1376, &[655363]);     // 1376 => [655363]
+        map.insert(379, &[1179651]);     // 379 => [1179651]
+        map.insert(1583, &[1310723]);     // 1583 => [1310723]
+        map.insert(29, &[29]);     // 29 => [29]
+        map.insert(45, &[45]);     // 45 => [45]
+        map.insert(1331, &[8205]);     // 1331 => [8205]
+        map.insert(1663, &[32781]);     // 1663 => [32781]
+        map.insert(480, &[262157]);     // 480 => [262157]
+        map.insert(53, &[53]);     // 53 => [53]
+        map.insert(929, &[1045]);     // 929 => [1045]
+        map.insert(1639, &[32789]);     // 1639 => [32789]
+        map.insert(1202, &[65557]);     // 1202 => [65557]
+        map.insert(975, &[524309]);     // 975 => [524309]
+        map.insert(101, &[101]);     // 101 => [101]
+        map.insert(549, &[549]);     // 549 => [549]
+        map.insert(1623, &[32805]);     // 1623 => [32805]
+        map.insert(1692, &[131109]);     // 1692 => [131109]
+        map.insert(197, &[197]);     // 197 => [197]
+        map.insert(581, &[581]);     // 581 => [581]
+        map.insert(1009, &[1093]);     // 1009 => [1093]
+        map.insert(1403, &[8261]);     // 1403 => [8261]
+        map.insert(1788, &[131141]);     // 1788 => [131141]
+        map.insert(389, &[389]);     // 389 => [389]
+        map.insert(817, &[1157]);     // 817 => [1157]
+        map.insert(942, &[4229]);     // 942 => [4229]
+        map.insert(1467, &[8325]);     // 1467 => [8325]
+        map.insert(1783, &[32901]);     // 1783 => [32901]
+        map.insert(773, &[773]);     // 773 => [773]
+        map.insert(558, &[4357]);     // 558 => [4357]
+        map.insert(1911, &[33029]);     // 1911 => [33029]
+        map.insert(1442, &[65797]);     // 1442 => [65797]
+        map.insert(232, &[262405]);     // 232 => [262405]
+        map.insert(1698, &[66053]);     // 1698 => [66053]
+        map.insert(1212, &[131589]);     // 1212 => [131589]
+        map.insert(1000, &[262661]);     // 1000 => [262661]
+        map.insert(1679, &[9221]);     // 1679 => [9221]
+        map.insert(1814, &[66565]);     // 1814 => [66565]
+        map.insert(107, &[525317]);     // 107 => [525317]
+        map.insert(1362, &[18437]);     // 1362 => [18437]
+        map.insert(964, &[1050629]);     // 964 => [1050629]
+        map.insert(1372, &[36869]);     // 1372 => [36869]
+        map.insert(1431, &[135173]);     // 1431 => [135173]
+        map.insert(244, &[528389]);     // 244 => [528389]
+        map.insert(1238, &[270341]);     // 1238 => [270341]
+        map.insert(1683, &[1064965]);     // 1683 => [1064965]
+        map.insert(1359, &[327685]);     // 1359 => [327685]
+        map.insert(1912, &[589829]);     // 1912 => [589829]
+        map.insert(1382, &[655365]);     // 1382 => [655365]
+        map.insert(57, &[57]);     // 57 => [57]
+        map.insert(818, &[4121]);     // 818 => [4121]
+        map.insert(334, &[16409]);     // 334 => [16409]
+        map.insert(1643, &[32793]);     // 1643 => [32793]
+        map.insert(1696, &[131097]);     // 1696 => [131097]
+        map.insert(169, &[169]);     // 169 => [169]
+        map.insert(925, &[1065]);     // 925 => [1065]
+        map.insert(1627, &[32809]);     // 1627 => [32809]
+        map.insert(2024, &[1048617]);     // 2024 => [1048617]
+        map.insert(329, &[329]);     // 329 => [329]
+        map.insert(915, &[524361]);     // 915 => [524361]
+        map.insert(829, &[1161]);     // 829 => [1161]
+        map.insert(1161, &[2185]);     // 1161 => [2185]
+        map.insert(930, &[4233]);     // 930 => [4233]
+        map.insert(356, &[262281]);     // 356 => [262281]
+        map.insert(1864, &[1048713]);     // 1864 => [1048713]
+        map.insert(723, &[524553]);     // 723 => [524553]
+        map.insert(1545, &[2569]);     // 1545 => [2569]
+        map.insert(1847, &[8713]);     // 1847 => [8713]
+        map.insert(1147, &[33289]);     // 1147 => [33289]
+        map.insert(1710, &[66057]);     // 1710 => [66057]
+        map.insert(1200, &[131593]);     // 1200 => [131593]
+        map.insert(1480, &[1049097]);     // 1480 => [1049097]
+        map.insert(746, &[17417]);     // 746 => [17417]
+        map.insert(1818, &[66569]);     // 1818 => [66569]
+        map.insert(1148, &[1049609]);     // 1148 => [1049609]
+        map.insert(1826, &[6153]);     // 1826 => [6153]
+        map.insert(635, &[34825]);     // 635 => [34825]
+        map.insert(174, &[67593]);     // 174 => [67593]
+        map.insert(1508, &[264201]);     // 1508 => [264201]
+        map.insert(629, &[20489]);     // 629 => [20489]
+        map.insert(1435, &[135177]);     // 1435 => [135177]
+        map.insert(719, &[266249]);     // 719 => [266249]
+        map.insert(910, &[139273]);     // 910 => [139273]
+        map.insert(1242, &[270345]);     // 1242 => [270345]
+        map.insert(758, &[1056777]);     // 758 => [1056777]
+        map.insert(1529, &[81929]);     // 1529 => [81929]
+        map.insert(2023, &[147465]);     // 2023 => [147465]
+        map.insert(732, &[98313]);     // 732 => [98313]
+        map.insert(369, &[1179657]);     // 369 => [1179657]
+        map.insert(305, &[305]);     // 305 => [305]
+        map.insert(1073, &[2097]);     // 1073 => [2097]
+        map.insert(1603, &[32817]);     // 1603 => [32817]
+        map.insert(476, &[262193]);     // 476 => [262193]
+        map.insert(1105, &[2129]);     // 1105 => [2129]
+        map.insert(1391, &[8273]);     // 1391 => [8273]
+        map.insert(1571, &[32849]);     // 1571 => [32849]
+        map.insert(1270, &[65617]);     // 1270 => [65617]
+        map.insert(1768, &[131153]);     // 1768 => [131153]
+        map.insert(1936, &[1048657]);     // 1936 => [1048657]
+        map.insert(657, &[657]);     // 657 => [657]
+        map.insert(677, &[1297]);     // 677 => [1297]
+        map.insert(1297, &[2321]);     // 1297 => [2321]
+        map.insert(570, &[4369]);     // 570 => [4369]
+        map.insert(252, &[262417]);     // 252 => [262417]
+        map.insert(1744, &[1048849]);     // 1744 => [1048849]
+        map.insert(142, &[5137]);     // 142 => [5137]
+        map.insert(1794, &[66577]);     // 1794 => [66577]
+        map.insert(127, &[525329]);     // 127 => [525329]
+        map.insert(1124, &[1049617]);     // 1124 => [1049617]
+        map.insert(182, &[67601]);     // 182 => [67601]
+        map.insert(680, &[133137]);     // 680 => [133137]
+        map.insert(1532, &[264209]);     // 1532 => [264209]
+        map.insert(621, &[20497]);     // 621 => [20497]
+        map.insert(1411, &[135185]);     // 1411 => [135185]
+        map.insert(1275, &[1052689]);     // 1275 => [1052689]
+        map.insert(1144, &[24593]);     // 1144 => [24593]
+        map.insert(861, &[40977]);     // 861 => [40977]
+        map.insert(1218, &[270353]);     // 1218 => [270353]
+        map.insert(1781, &[532497]);     // 1781 => [532497]
+        map.insert(750, &[1056785]);     // 750 => [1056785]
+        map.insert(2047, &[147473]);     // 2047 => [147473]
+        map.insert(171, &[278545]);     // 171 => [278545]
+        map.insert(668, &[540689]);     // 668 => [540689]
+        map.insert(418, &[1081361]);     // 418 => [1081361]
+        map.insert(527, &[196625]);     // 527 => [196625]
+        map.insert(1900, &[589841]);     // 1900 => [589841]
+        map.insert(550, &[786449]);     // 550 => [786449]
+        map.insert(609, &[609]);     // 609 => [609]
+        map.insert(1752, &[131169]);     // 1752 => [131169]
+        map.insert(396, &[262241]);     // 396 => [262241]
+        map.insert(1952, &[1048673]);     // 1952 => [1048673]
+        map.insert(417, &[417]);     // 417 => [417]
+        map.insert(789, &[1185]);     // 789 => [1185]
+        map.insert(502, &[16545]);     // 502 => [16545]
+        map.insert(1560, &[131233]);     // 1560 => [131233]
+        map.insert(1888, &[1048737]);     // 1888 => [1048737]
+        map.insert(1313, &[2337]);     // 1313 => [2337]
+        map.insert(118, &[16673]);     // 118 => [16673]
+        map.insert(1944, &[131361]);     // 1944 => [131361]
+        map.insert(204, &[262433]);     // 204 => [262433]
+        map.insert(405, &[1569]);     // 405 => [1569]
+        map.insert(1569, &[2593]);     // 1569 => [2593]
+        map.insert(1176, &[131617]);     // 1176 => [131617]
+        map.insert(507, &[524833]);     // 507 => [524833]
+        map.insert(1941, &[3105]);     // 1941 => [3105]
+        map.insert(79, &[525345]);     // 79 => [525345]
+        map.insert(1108, &[1049633]);     // 1108 => [1049633]
+        map.insert(1484, &[264225]);     // 1484 => [264225]
+        map.insert(2043, &[526369]);     // 2043 => [526369]
+        map.insert(1588, &[12321]);     // 1588 => [12321]
+        map.insert(1965, &[69665]);     // 1965 => [69665]
+        map.insert(440, &[73761]);     // 440 => [73761]
+        map.insert(1796, &[49185]);     // 1796 => [49185]
+        map.insert(1999, &[147489]);     // 1999 => [147489]
+        map.insert(684, &[540705]);     // 684 => [540705]
+        map.insert(1417, &[557089]);     // 1417 => [557089]
+        map.insert(1549, &[1310753]);     // 1549 => [1310753]
+        map.insert(885, &[1217]);     // 885 => [1217]
+        map.insert(1535, &[8385]);     // 1535 => [8385]
+        map.insert(406, &[16577]);     // 406 => [16577]
+        map.insert(1126, &[65729]);     // 1126 => [65729]
+        map.insert(300, &[262337]);     // 300 => [262337]
+        map.insert(667, &[524609]);     // 667 => [524609]
+        map.insert(362, &[4673]);     // 362 => [4673]
+        map.insert(790, &[16961]);     // 790 => [16961]
+        map.insert(1075, &[33345]);     // 1075 => [33345]
+        map.insert(1272, &[131649]);     // 1272 => [131649]
+        map.insert(2037, &[3137]);     // 2037 => [3137]
+        map.insert(222, &[5185]);     // 222 => [5185]
+        map.insert(1739, &[9281]);     // 1739 => [9281]
+        map.insert(1898, &[6209]);     // 1898 => [6209]
+        map.insert(230, &[67649]);     // 230 => [67649]
+        map.insert(760, &[133185]);     // 760 => [133185]
+        map.insert(573, &[20545]);     // 573 => [20545]
+        map.insert(1304, &[36929]);     // 1304 => [36929]
+        map.insert(781, &[41025]);     // 781 => [41025]
+        map.insert(702, &[1056833]);     // 702 => [1056833]
+        map.insert(1892, &[49217]);     // 1892 => [49217]
+        map.insert(1457, &[81985]);     // 1457 => [81985]
+        map.insert(251, &[278593]);     // 251 => [278593]
+        map.insert(498, &[1081409]);     // 498 => [1081409]
+        map.insert(607, &[196673]);     // 607 => [196673]
+        map.insert(1291, &[327745]);     // 1291 => [327745]
+        map.insert(1645, &[1310785]);     // 1645 => [1310785]
+        map.insert(682, &[4481]);     // 682 => [4481]
+        map.insert(214, &[16769]);     // 214 => [16769]
+        map.insert(2035, &[33153]);     // 2035 => [33153]
+        map.insert(1848, &[131457]);     // 1848 => [131457]
+        map.insert(1547, &[9345]);     // 1547 => [9345]
+        map.insert(1268, &[1049729]);     // 1268 => [1049729]
+        map.insert(1962, &[6273]);     // 1962 => [6273]
+        map.insert(447, &[10369]);     // 447 => [10369]
+        map.insert(568, &[133249]);     // 568 => [133249]
+        map.insert(1388, &[264321]);     // 1388 => [264321]
+        map.insert(1883, &[526465]);     // 1883 => [526465]
+        map.insert(1496, &[36993]);     // 1496 => [36993]
+        map.insert(583, &[266369]);     // 583 => [266369]
+        map.insert(774, &[139393]);     // 774 => [139393]
+        map.insert(1637, &[532609]);     // 1637 => [532609]
+        map.insert(1393, &[82049]);     // 1393 => [82049]
+        map.insert(1903, &[147585]);     // 1903 => [147585]
+        map.insert(59, &[278657]);     // 59 => [278657]
+        map.insert(596, &[98433]);     // 596 => [98433]
+        map.insert(1321, &[557185]);     // 1321 => [557185]
+        map.insert(306, &[1081473]);     // 306 => [1081473]
+        map.insert(1483, &[327809]);     // 1483 => [327809]
+        map.insert(2044, &[589953]);     // 2044 => [589953]
+        map.insert(999, &[1114241]);     // 999 => [1114241]
+        map.insert(1506, &[655489]);     // 1506 => [655489]
+        map.insert(1178, &[1572993]);     // 1178 => [1572993]
+        map.insert(181, &[1793]);     // 181 => [1793]
+        map.insert(1599, &[8961]);     // 1599 => [8961]
+        map.insert(598, &[17153]);     // 598 => [17153]
+        map.insert(1958, &[66305]);     // 1958 => [66305]
+        map.insert(748, &[262913]);     // 748 => [262913]
+        map.insert(414, &[5377]);     // 414 => [5377]
+        map.insert(1931, &[9473]);     // 1931 => [9473]
+        map.insert(994, &[17665]);     // 994 => [17665]
+        map.insert(1396, &[1049857]);     // 1396 => [1049857]
+        map.insert(63, &[10497]);     // 63 => [10497]
+        map.insert(883, &[35073]);     // 883 => [35073]
+        map.insert(1260, &[264449]);     // 1260 => [264449]
+        map.insert(1112, &[37121]);     // 1112 => [37121]
+        map.insert(1515, &[1052929]);     // 1515 => [1052929]
+        map.insert(1384, &[24833]);     // 1384 => [24833]
+        map.insert(589, &[41217]);     // 589 => [41217]
+        map.insert(1775, &[147713]);     // 1775 => [147713]
+        map.insert(799, &[196865]);     // 799 => [196865]
+        map.insert(1099, &[327937]);     // 1099 => [327937]
+        map.insert(615, &[1114369]);     // 615 => [1114369]
+        map.insert(121, &[1179905]);     // 121 => [1179905]
+        map.insert(1461, &[3585]);     // 1461 => [3585]
+        map.insert(1163, &[9729]);     // 1163 => [9729]
+        map.insert(226, &[17921]);     // 226 => [17921]
+        map.insert(623, &[525825]);     // 623 => [525825]
+        map.insert(115, &[35329]);     // 115 => [35329]
+        map.insert(678, &[68097]);     // 678 => [68097]
+        map.insert(1499, &[526849]);     // 1499 => [526849]
+        map.insert(125, &[20993]);     // 125 => [20993]
+        map.insert(1880, &[37377]);     // 1880 => [37377]
+        map.insert(199, &[266753]);     // 199 => [266753]
+        map.insert(752, &[528897]);     // 752 => [528897]
+        map.insert(1771, &[1053185]);     // 1771 => [1053185]
+        map.insert(1640, &[25089]);     // 1640 => [25089]
+        map.insert(390, &[139777]);     // 390 => [139777]
+        map.insert(254, &[1057281]);     // 254 => [1057281]
+        map.insert(1316, &[49665]);     // 1316 => [49665]
+        map.insert(212, &[98817]);     // 212 => [98817]
+        map.insert(1867, &[328193]);     // 1867 => [328193]
+        map.insert(889, &[1180161]);     // 889 => [1180161]
+        map.insert(54, &[786945]);     // 54 => [786945]
+        map.insert(1069, &[1311233]);     // 1069 => [1311233]
+        map.insert(1562, &[1573377]);     // 1562 => [1573377]
+        map.insert(1182, &[7169]);     // 1182 => [7169]
+        map.insert(1135, &[527361]);     // 1135 => [527361]
+        map.insert(1887, &[1053697]);     // 1887 => [1053697]
+        map.insert(2012, &[25601]);     // 2012 => [25601]
+        map.insert(1605, &[82945]);     // 1605 => [82945]
+        map.insert(894, &[164865]);     // 894 => [164865]
+        map.insert(1066, &[295937]);     // 1066 => [295937]
+        map.insert(1224, &[590849]);     // 1224 => [590849]
+        map.insert(1249, &[394241]);     // 1249 => [394241]
+        map.insert(1735, &[268289]);     // 1735 => [268289]
+        map.insert(1869, &[43009]);     // 1869 => [43009]
+        map.insert(1926, &[141313]);     // 1926 => [141313]
+        map.insert(741, &[534529]);     // 741 => [534529]
+        map.insert(663, &[1067009]);     // 663 => [1067009]
+        map.insert(1748, &[100353]);     // 1748 => [100353]
+        map.insert(1567, &[198657]);     // 1567 => [198657]
+        map.insert(354, &[657409]);     // 354 => [657409]
+        map.insert(691, &[77825]);     // 691 => [77825]
+        map.insert(1039, &[53249]);     // 1039 => [53249]
+        map.insert(1220, &[151553]);     // 1220 => [151553]
+        map.insert(1609, &[659457]);     // 1609 => [659457]
+        map.insert(285, &[790529]);     // 285 => [790529]
+        map.insert(1286, &[1314817]);     // 1286 => [1314817]
+        map.insert(1841, &[1576961]);     // 1841 => [1576961]
+        map.insert(1413, &[286721]);     // 1413 => [286721]
+        map.insert(1970, &[548865]);     // 1970 => [548865]
+        map.insert(1164, &[1089537]);     // 1164 => [1089537]
+        map.insert(92, &[663553]);     // 92 => [663553]
+        map.insert(1095, &[1187841]);     // 1095 => [1187841]
+        map.insert(1800, &[794625]);     // 1800 => [794625]
+        map.insert(1278, &[573441]);     // 1278 => [573441]
+        map.insert(1052, &[344065]);     // 1052 => [344065]
+        map.insert(865, &[802817]);     // 865 => [802817]
+        map.insert(270, &[622593]);     // 270 => [622593]
+        map.insert(1301, &[1146881]);     // 1301 => [1146881]
+        map.insert(295, &[425985]);     // 295 => [425985]
+        map.insert(616, &[1605633]);     // 616 => [1605633]
+        map.insert(1502, &[1245185]);     // 1502 => [1245185]
+        map.insert(189, &[1638401]);     // 189 => [1638401]
+        map.insert(1527, &[1835009]);     // 1527 => [1835009]
+        map.insert(1328, &[8206]);     // 1328 => [8206]
+        map.insert(483, &[262158]);     // 483 => [262158]
+        map.insert(1046, &[2070]);     // 1046 => [2070]
+        map.insert(2007, &[1048598]);     // 2007 => [1048598]
+        map.insert(465, &[16518]);     // 465 => [16518]
+        map.insert(1863, &[1048710]);     // 1863 => [1048710]
+        map.insert(1542, &[2566]);     // 1542 => [2566]
+        map.insert(849, &[16902]);     // 849 => [16902]
+        map.insert(1428, &[135174]);     // 1428 => [135174]
+        map.insert(247, &[528390]);     // 247 => [528390]
+        map.insert(1237, &[270342]);     // 1237 => [270342]
+        map.insert(1381, &[655366]);     // 1381 => [655366]
+        map.insert(90, &[90]);     // 90 => [90]
+        map.insert(282, &[282]);     // 282 => [282]
+        map.insert(586, &[586]);     // 586 => [586]
+        map.insert(394, &[394]);     // 394 => [394]
+        map.insert(848, &[524426]);     // 848 => [524426]
+        map.insert(464, &[524810]);     // 464 => [524810]
+        map.insert(745, &[17418]);     // 745 => [17418]
+        map.insert(1817, &[66570]);     // 1817 => [66570]
+        map.insert(1241, &[270346]);     // 1241 => [270346]
+        map.insert(1530, &[81930]);     // 1530 => [81930]
+        map.insert(793, &[4146]);     // 793 => [4146]
+        map.insert(1173, &[65586]);     // 1173 => [65586]
+        map.insert(904, &[524370]);     // 904 => [524370]
+        map.insert(658, &[658]);     // 658 => [658]
+        map.insert(712, &[524562]);     // 712 => [524562]
+        map.insert(1492, &[33810]);     // 1492 => [33810]
+        map.insert(587, &[263186]);     // 587 => [263186]
+        map.insert(1349, &[18450]);     // 1349 => [18450]
+        map.insert(1543, &[12306]);     // 1543 => [12306]
+        map.insert(395, &[73746]);     // 395 => [73746]
+        map.insert(1668, &[1064978]);     // 1668 => [1064978]
+        map.insert(217, &[163858]);     // 217 => [163858]
+        map.insert(1862, &[393234]);     // 1862 => [393234]
+        map.insert(399, &[262242]);     // 399 => [262242]
+        map.insert(1955, &[1048674]);     // 1955 => [1048674]
+        map.insert(905, &[4258]);     // 905 => [4258]
+        map.insert(1436, &[8354]);     // 1436 => [8354]
+        map.insert(1820, &[8738]);     // 1820 => [8738]
+        map.insert(1669, &[66082]);     // 1669 => [66082]
+        map.insert(1799, &[49186]);     // 1799 => [49186]
+        map.insert(687, &[540706]);     // 687 => [540706]
+        map.insert(1418, &[557090]);     // 1418 => [557090]
+        map.insert(1550, &[1310754]);     // 1550 => [1310754]
+        map.insert(450, &[450]);     // 450 => [450]
+        map.insert(792, &[524482]);     // 792 => [524482]
+        map.insert(834, &[834]);     // 834 => [834]
+        map.insert(2038, &[3138]);     // 2038 => [3138]
+        map.insert(221, &[5186]);     // 221 => [5186]
+        map.insert(1897, &[6210]);     // 1897 => [6210]
+        map.insert(1646, &[1310786]);     // 1646 => [1310786]
+        map.insert(1348, &[33922]);     // 1348 => [33922]
+        map.insert(1423, &[132226]);     // 1423 => [132226]
+        map.insert(1493, &[18562]);     // 1493 => [18562]
+        map.insert(835, &[1050754]);     // 835 => [1050754]
+        map.insert(1806, &[69762]);     // 1806 => [69762]
+        map.insert(283, &[73858]);     // 283 => [73858]
+        map.insert(1821, &[295042]);     // 1821 => [295042]
+        map.insert(2006, &[393346]);     // 2006 => [393346]
+        map.insert(216, &[525058]);     // 216 => [525058]
+        map.insert(60, &[10498]);     // 60 => [10498]
+        map.insert(880, &[35074]);     // 880 => [35074]
+        map.insert(590, &[41218]);     // 590 => [41218]
+        map.insert(796, &[196866]);     // 796 => [196866]
+        map.insert(612, &[1114370]);     // 612 => [1114370]
+        map.insert(122, &[1179906]);     // 122 => [1179906]
+        map.insert(1807, &[132610]);     // 1807 => [132610]
+        map.insert(91, &[263682]);     // 91 => [263682]
+        map.insert(451, &[1051138]);     // 451 => [1051138]
+        map.insert(1047, &[12802]);     // 1047 => [12802]
+        map.insert(1422, &[70146]);     // 1422 => [70146]
+        map.insert(1172, &[1065474]);     // 1172 => [1065474]
+        map.insert(713, &[164354]);     // 713 => [164354]
+        map.insert(1437, &[295426]);     // 1437 => [295426]
+        map.insert(1181, &[7170]);     // 1181 => [7170]
+        map.insert(1606, &[82946]);     // 1606 => [82946]
+        map.insert(1870, &[43010]);     // 1870 => [43010]
+        map.insert(1610, &[659458]);     // 1610 => [659458]
+        map.insert(1277, &[573442]);     // 1277 => [573442]
+        map.insert(1501, &[1245186]);     // 1501 => [1245186]
+        map.insert(1630, &[32812]);     // 1630 => [32812]
+        map.insert(1100, &[2124]);     // 1100 => [2124]
+        map.insert(1741, &[1048844]);     // 1741 => [1048844]
+        map.insert(1247, &[270348]);     // 1247 => [270348]
+        map.insert(1757, &[131172]);     // 1757 => [131172]
+        map.insert(1230, &[1052708]);     // 1230 => [1052708]
+        map.insert(1101, &[24612]);     // 1101 => [24612]
+        map.insert(1742, &[9284]);     // 1742 => [9284]
+        map.insert(1119, &[1572932]);     // 1119 => [1572932]
+        map.insert(1758, &[526596]);     // 1758 => [526596]
+        map.insert(1117, &[37124]);     // 1117 => [37124]
+        map.insert(1102, &[327940]);     // 1102 => [327940]
+        map.insert(1629, &[265220]);     // 1629 => [265220]
+        map.insert(1118, &[148484]);     // 1118 => [148484]
+        map.insert(1229, &[590852]);     // 1229 => [590852]
+        map.insert(1231, &[165892]);     // 1231 => [165892]
+        map.insert(1759, &[86020]);     // 1759 => [86020]
+        map.insert(1612, &[659460]);     // 1612 => [659460]
+        map.insert(1628, &[1122308]);     // 1628 => [1122308]
+        map.insert(1740, &[311300]);     // 1740 => [311300]
+        map.insert(1329, &[8207]);     // 1329 => [8207]
+        map.insert(482, &[262159]);     // 482 => [262159]
+        map.insert(1429, &[135175]);     // 1429 => [135175]
+        map.insert(246, &[528391]);     // 246 => [528391]
+        map.insert(1236, &[270343]);     // 1236 => [270343]
+        map.insert(1380, &[655367]);     // 1380 => [655367]
+        map.insert(744, &[17419]);     // 744 => [17419]
+        map.insert(1816, &[66571]);     // 1816 => [66571]
+        map.insert(1240, &[270347]);     // 1240 => [270347]
+        map.insert(1531, &[81931]);     // 1531 => [81931]
+        map.insert(659, &[659]);     // 659 => [659]
+        map.insert(398, &[262243]);     // 398 => [262243]
+        map.insert(1954, &[1048675]);     // 1954 => [1048675]
+        map.insert(1798, &[49187]);     // 1798 => [49187]
+        map.insert(686, &[540707]);     // 686 => [540707]
+        map.insert(1419, &[557091]);     // 1419 => [557091]
+        map.insert(1551, &[1310755]);     // 1551 => [1310755]
+        map.insert(2039, &[3139]);     // 2039 => [3139]
+        map.insert(220, &[5187]);     // 220 => [5187]
+        map.insert(1896, &[6211]);     // 1896 => [6211]
+        map.insert(1647, &[1310787]);     // 1647 => [1310787]
+        map.insert(61, &[10499]);     // 61 => [10499]
+        map.insert(881, &[35075]);     // 881 => [35075]
+        map.insert(591, &[41219]);     // 591 => [41219]
+        map.insert(797, &[196867]);     // 797 => [196867]
+        map.insert(613, &[1114371]);     // 613 => [1114371]
+        map.insert(123, &[1179907]);     // 123 => [1179907]
+        map.insert(1180, &[7171]);     // 1180 => [7171]
+        map.insert(1607, &[82947]);     // 1607 => [82947]
+        map.insert(1871, &[43011]);     // 1871 => [43011]
+        map.insert(1611, &[659459]);     // 1611 => [659459]
+        map.insert(1276, &[573443]);     // 1276 => [573443]
+        map.insert(1500, &[1245187]);     // 1500 => [1245187]
+        map.insert(1631, &[32813]);     // 1631 => [32813]
+        map.insert(1246, &[270349]);     // 1246 => [270349]
+        map.insert(1756, &[131173]);     // 1756 => [131173]
+        map.insert(1743, &[9285]);     // 1743 => [9285]
+        map.insert(1116, &[37125]);     // 1116 => [37125]
+        map.insert(1103, &[327941]);     // 1103 => [327941]
+        map.insert(1228, &[590853]);     // 1228 => [590853]
+        map.insert(1613, &[659461]);     // 1613 => [659461]
+        map.insert(1245, &[270350]);     // 1245 => [270350]
+        map.insert(1614, &[659462]);     // 1614 => [659462]
+        map.insert(1244, &[270351]);     // 1244 => [270351]
+        map.insert(1615, &[659463]);     // 1615 => [659463]
+        map
+    })
 }
 
 impl GuavaCode21_10 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -2137,24 +2137,15 @@ impl BinaryCode for GuavaCode21_10 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(21);
@@ -2179,14 +2170,26 @@ impl BinaryCode for GuavaCode21_10 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        6
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(21, 10).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[21 / 64] & !((1 << 21) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2381,8 +2384,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, true, true, false, true, false, false, true, false, true, false, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,125 +16,126 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode21_15;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1212417 ],
-                &[ 819202 ],
-                &[ 1343492 ],
-                &[ 1605640 ],
-                &[ 2031632 ],
-                &[ 458784 ],
-                &[ 720960 ],
-                &[ 1245312 ],
-                &[ 852224 ],
-                &[ 1376768 ],
-                &[ 1639424 ],
-                &[ 919552 ],
-                &[ 1445888 ],
-                &[ 1712128 ],
-                &[ 1851392 ],
-                
-            ], 21));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1918721 ],
-                &[ 1175906 ],
-                &[ 710052 ],
-                &[ 451528 ],
-                &[ 67568 ],
-                &[ 1996800 ],
-                
-            ], 21));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(22, &[32]);     // 22 => [32]
-            map.insert(26, &[64]);     // 26 => [64]
-            map.insert(28, &[128]);     // 28 => [128]
-            map.insert(31, &[256]);     // 31 => [256]
-            map.insert(25, &[512]);     // 25 => [512]
-            map.insert(21, &[1024]);     // 21 => [1024]
-            map.insert(32, &[2048]);     // 32 => [2048]
-            map.insert(38, &[4096]);     // 38 => [4096]
-            map.insert(42, &[8192]);     // 42 => [8192]
-            map.insert(47, &[16384]);     // 47 => [16384]
-            map.insert(14, &[32768]);     // 14 => [32768]
-            map.insert(19, &[65536]);     // 19 => [65536]
-            map.insert(44, &[131072]);     // 44 => [131072]
-            map.insert(41, &[262144]);     // 41 => [262144]
-            map.insert(37, &[524288]);     // 37 => [524288]
-            map.insert(35, &[1048576]);     // 35 => [1048576]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(23, &[33]);     // 23 => [33]
-            map.insert(27, &[65]);     // 27 => [65]
-            map.insert(29, &[129]);     // 29 => [129]
-            map.insert(30, &[257]);     // 30 => [257]
-            map.insert(24, &[513]);     // 24 => [513]
-            map.insert(20, &[1025]);     // 20 => [1025]
-            map.insert(33, &[2049]);     // 33 => [2049]
-            map.insert(39, &[4097]);     // 39 => [4097]
-            map.insert(43, &[8193]);     // 43 => [8193]
-            map.insert(46, &[16385]);     // 46 => [16385]
-            map.insert(15, &[32769]);     // 15 => [32769]
-            map.insert(18, &[65537]);     // 18 => [65537]
-            map.insert(45, &[131073]);     // 45 => [131073]
-            map.insert(40, &[262145]);     // 40 => [262145]
-            map.insert(36, &[524289]);     // 36 => [524289]
-            map.insert(34, &[1048577]);     // 34 => [1048577]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(12, &[32770]);     // 12 => [32770]
-            map.insert(48, &[2064]);     // 48 => [2064]
-            map.insert(54, &[4112]);     // 54 => [4112]
-            map.insert(58, &[8208]);     // 58 => [8208]
-            map.insert(63, &[16400]);     // 63 => [16400]
-            map.insert(60, &[131088]);     // 60 => [131088]
-            map.insert(57, &[262160]);     // 57 => [262160]
-            map.insert(53, &[524304]);     // 53 => [524304]
-            map.insert(51, &[1048592]);     // 51 => [1048592]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(13, &[32771]);     // 13 => [32771]
-            map.insert(49, &[2065]);     // 49 => [2065]
-            map.insert(55, &[4113]);     // 55 => [4113]
-            map.insert(59, &[8209]);     // 59 => [8209]
-            map.insert(62, &[16401]);     // 62 => [16401]
-            map.insert(61, &[131089]);     // 61 => [131089]
-            map.insert(56, &[262161]);     // 56 => [262161]
-            map.insert(52, &[524305]);     // 52 => [524305]
-            map.insert(50, &[1048593]);     // 50 => [1048593]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1212417 ],
+            &[ 819202 ],
+            &[ 1343492 ],
+            &[ 1605640 ],
+            &[ 2031632 ],
+            &[ 458784 ],
+            &[ 720960 ],
+            &[ 1245312 ],
+            &[ 852224 ],
+            &[ 1376768 ],
+            &[ 1639424 ],
+            &[ 919552 ],
+            &[ 1445888 ],
+            &[ 1712128 ],
+            &[ 1851392 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 21)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1918721 ],
+            &[ 1175906 ],
+            &[ 710052 ],
+            &[ 451528 ],
+            &[ 67568 ],
+            &[ 1996800 ],
+            
+        ], 21)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(22, &[32]);     // 22 => [32]
+        map.insert(26, &[64]);     // 26 => [64]
+        map.insert(28, &[128]);     // 28 => [128]
+        map.insert(31, &[256]);     // 31 => [256]
+        map.insert(25, &[512]);     // 25 => [512]
+        map.insert(21, &[1024]);     // 21 => [1024]
+        map.insert(32, &[2048]);     // 32 => [2048]
+        map.insert(38, &[4096]);     // 38 => [4096]
+        map.insert(42, &[8192]);     // 42 => [8192]
+        map.insert(47, &[16384]);     // 47 => [16384]
+        map.insert(14, &[32768]);     // 14 => [32768]
+        map.insert(19, &[65536]);     // 19 => [65536]
+        map.insert(44, &[131072]);     // 44 => [131072]
+        map.insert(41, &[262144]);     // 41 => [262144]
+        map.insert(37, &[524288]);     // 37 => [524288]
+        map.insert(35, &[1048576]);     // 35 => [1048576]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(23, &[33]);     // 23 => [33]
+        map.insert(27, &[65]);     // 27 => [65]
+        map.insert(29, &[129]);     // 29 => [129]
+        map.insert(30, &[257]);     // 30 => [257]
+        map.insert(24, &[513]);     // 24 => [513]
+        map.insert(20, &[1025]);     // 20 => [1025]
+        map.insert(33, &[2049]);     // 33 => [2049]
+        map.insert(39, &[4097]);     // 39 => [4097]
+        map.insert(43, &[8193]);     // 43 => [8193]
+        map.insert(46, &[16385]);     // 46 => [16385]
+        map.insert(15, &[32769]);     // 15 => [32769]
+        map.insert(18, &[65537]);     // 18 => [65537]
+        map.insert(45, &[131073]);     // 45 => [131073]
+        map.insert(40, &[262145]);     // 40 => [262145]
+        map.insert(36, &[524289]);     // 36 => [524289]
+        map.insert(34, &[1048577]);     // 34 => [1048577]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(12, &[32770]);     // 12 => [32770]
+        map.insert(48, &[2064]);     // 48 => [2064]
+        map.insert(54, &[4112]);     // 54 => [4112]
+        map.insert(58, &[8208]);     // 58 => [8208]
+        map.insert(63, &[16400]);     // 63 => [16400]
+        map.insert(60, &[131088]);     // 60 => [131088]
+        map.insert(57, &[262160]);     // 57 => [262160]
+        map.insert(53, &[524304]);     // 53 => [524304]
+        map.insert(51, &[1048592]);     // 51 => [1048592]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(13, &[32771]);     // 13 => [32771]
+        map.insert(49, &[2065]);     // 49 => [2065]
+        map.insert(55, &[4113]);     // 55 => [4113]
+        map.insert(59, &[8209]);     // 59 => [8209]
+        map.insert(62, &[16401]);     // 62 => [16401]
+        map.insert(61, &[131089]);     // 61 => [131089]
+        map.insert(56, &[262161]);     // 56 => [262161]
+        map.insert(52, &[524305]);     // 52 => [524305]
+        map.insert(50, &[1048593]);     // 50 => [1048593]
+        map
+    })
 }
 
 impl GuavaCode21_15 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -153,24 +153,15 @@ impl BinaryCode for GuavaCode21_15 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(21);
@@ -195,14 +186,26 @@ impl BinaryCode for GuavaCode21_15 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(21, 15).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[21 / 64] & !((1 << 21) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -397,8 +400,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, false, true ]);
         assert_eq!(vector, first_row.as_vector());
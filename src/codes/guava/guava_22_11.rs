@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,2110 +16,2111 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode22_11;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 325633 ],
-                &[ 2775042 ],
-                &[ 1513476 ],
-                &[ 2988040 ],
-                &[ 3878928 ],
-                &[ 3563552 ],
-                &[ 1009728 ],
-                &[ 2019456 ],
-                &[ 1941760 ],
-                &[ 3729920 ],
-                &[ 3265536 ],
-                
-            ], 22));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 2576385 ],
-                &[ 651266 ],
-                &[ 3452932 ],
-                &[ 2988040 ],
-                &[ 3878928 ],
-                &[ 1427488 ],
-                &[ 1009728 ],
-                &[ 2019456 ],
-                &[ 4065536 ],
-                &[ 3729920 ],
-                &[ 1211392 ],
-                
-            ], 22));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 325633 ],
+            &[ 2775042 ],
+            &[ 1513476 ],
+            &[ 2988040 ],
+            &[ 3878928 ],
+            &[ 3563552 ],
+            &[ 1009728 ],
+            &[ 2019456 ],
+            &[ 1941760 ],
+            &[ 3729920 ],
+            &[ 3265536 ],
+            
+        ], 22)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(2048, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(64, &[64]);     // 64 => [64]
-            map.insert(128, &[128]);     // 128 => [128]
-            map.insert(256, &[256]);     // 256 => [256]
-            map.insert(512, &[512]);     // 512 => [512]
-            map.insert(1024, &[1024]);     // 1024 => [1024]
-            map.insert(1896, &[2048]);     // 1896 => [2048]
-            map.insert(1183, &[4096]);     // 1183 => [4096]
-            map.insert(1622, &[8192]);     // 1622 => [8192]
-            map.insert(1763, &[16384]);     // 1763 => [16384]
-            map.insert(686, &[32768]);     // 686 => [32768]
-            map.insert(123, &[65536]);     // 123 => [65536]
-            map.insert(1489, &[131072]);     // 1489 => [131072]
-            map.insert(493, &[262144]);     // 493 => [262144]
-            map.insert(986, &[524288]);     // 986 => [524288]
-            map.insert(1972, &[1048576]);     // 1972 => [1048576]
-            map.insert(797, &[2097152]);     // 797 => [2097152]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(65, &[65]);     // 65 => [65]
-            map.insert(129, &[129]);     // 129 => [129]
-            map.insert(257, &[257]);     // 257 => [257]
-            map.insert(513, &[513]);     // 513 => [513]
-            map.insert(1025, &[1025]);     // 1025 => [1025]
-            map.insert(1897, &[2049]);     // 1897 => [2049]
-            map.insert(1182, &[4097]);     // 1182 => [4097]
-            map.insert(1623, &[8193]);     // 1623 => [8193]
-            map.insert(1762, &[16385]);     // 1762 => [16385]
-            map.insert(687, &[32769]);     // 687 => [32769]
-            map.insert(122, &[65537]);     // 122 => [65537]
-            map.insert(1488, &[131073]);     // 1488 => [131073]
-            map.insert(492, &[262145]);     // 492 => [262145]
-            map.insert(987, &[524289]);     // 987 => [524289]
-            map.insert(1973, &[1048577]);     // 1973 => [1048577]
-            map.insert(796, &[2097153]);     // 796 => [2097153]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(66, &[66]);     // 66 => [66]
-            map.insert(130, &[130]);     // 130 => [130]
-            map.insert(258, &[258]);     // 258 => [258]
-            map.insert(514, &[514]);     // 514 => [514]
-            map.insert(1026, &[1026]);     // 1026 => [1026]
-            map.insert(1898, &[2050]);     // 1898 => [2050]
-            map.insert(1181, &[4098]);     // 1181 => [4098]
-            map.insert(1620, &[8194]);     // 1620 => [8194]
-            map.insert(1761, &[16386]);     // 1761 => [16386]
-            map.insert(684, &[32770]);     // 684 => [32770]
-            map.insert(121, &[65538]);     // 121 => [65538]
-            map.insert(1491, &[131074]);     // 1491 => [131074]
-            map.insert(495, &[262146]);     // 495 => [262146]
-            map.insert(984, &[524290]);     // 984 => [524290]
-            map.insert(1974, &[1048578]);     // 1974 => [1048578]
-            map.insert(799, &[2097154]);     // 799 => [2097154]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(68, &[68]);     // 68 => [68]
-            map.insert(132, &[132]);     // 132 => [132]
-            map.insert(260, &[260]);     // 260 => [260]
-            map.insert(516, &[516]);     // 516 => [516]
-            map.insert(1028, &[1028]);     // 1028 => [1028]
-            map.insert(1900, &[2052]);     // 1900 => [2052]
-            map.insert(1179, &[4100]);     // 1179 => [4100]
-            map.insert(1618, &[8196]);     // 1618 => [8196]
-            map.insert(1767, &[16388]);     // 1767 => [16388]
-            map.insert(682, &[32772]);     // 682 => [32772]
-            map.insert(127, &[65540]);     // 127 => [65540]
-            map.insert(1493, &[131076]);     // 1493 => [131076]
-            map.insert(489, &[262148]);     // 489 => [262148]
-            map.insert(990, &[524292]);     // 990 => [524292]
-            map.insert(1968, &[1048580]);     // 1968 => [1048580]
-            map.insert(793, &[2097156]);     // 793 => [2097156]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(72, &[72]);     // 72 => [72]
-            map.insert(136, &[136]);     // 136 => [136]
-            map.insert(264, &[264]);     // 264 => [264]
-            map.insert(520, &[520]);     // 520 => [520]
-            map.insert(1032, &[1032]);     // 1032 => [1032]
-            map.insert(1888, &[2056]);     // 1888 => [2056]
-            map.insert(1175, &[4104]);     // 1175 => [4104]
-            map.insert(1630, &[8200]);     // 1630 => [8200]
-            map.insert(1771, &[16392]);     // 1771 => [16392]
-            map.insert(678, &[32776]);     // 678 => [32776]
-            map.insert(115, &[65544]);     // 115 => [65544]
-            map.insert(1497, &[131080]);     // 1497 => [131080]
-            map.insert(485, &[262152]);     // 485 => [262152]
-            map.insert(978, &[524296]);     // 978 => [524296]
-            map.insert(1980, &[1048584]);     // 1980 => [1048584]
-            map.insert(789, &[2097160]);     // 789 => [2097160]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(80, &[80]);     // 80 => [80]
-            map.insert(144, &[144]);     // 144 => [144]
-            map.insert(272, &[272]);     // 272 => [272]
-            map.insert(528, &[528]);     // 528 => [528]
-            map.insert(1040, &[1040]);     // 1040 => [1040]
-            map.insert(1912, &[2064]);     // 1912 => [2064]
-            map.insert(1167, &[4112]);     // 1167 => [4112]
-            map.insert(1606, &[8208]);     // 1606 => [8208]
-            map.insert(1779, &[16400]);     // 1779 => [16400]
-            map.insert(702, &[32784]);     // 702 => [32784]
-            map.insert(107, &[65552]);     // 107 => [65552]
-            map.insert(1473, &[131088]);     // 1473 => [131088]
-            map.insert(509, &[262160]);     // 509 => [262160]
-            map.insert(970, &[524304]);     // 970 => [524304]
-            map.insert(1956, &[1048592]);     // 1956 => [1048592]
-            map.insert(781, &[2097168]);     // 781 => [2097168]
-            map.insert(96, &[96]);     // 96 => [96]
-            map.insert(160, &[160]);     // 160 => [160]
-            map.insert(288, &[288]);     // 288 => [288]
-            map.insert(544, &[544]);     // 544 => [544]
-            map.insert(1056, &[1056]);     // 1056 => [1056]
-            map.insert(1864, &[2080]);     // 1864 => [2080]
-            map.insert(1215, &[4128]);     // 1215 => [4128]
-            map.insert(1654, &[8224]);     // 1654 => [8224]
-            map.insert(1731, &[16416]);     // 1731 => [16416]
-            map.insert(654, &[32800]);     // 654 => [32800]
-            map.insert(91, &[65568]);     // 91 => [65568]
-            map.insert(1521, &[131104]);     // 1521 => [131104]
-            map.insert(461, &[262176]);     // 461 => [262176]
-            map.insert(1018, &[524320]);     // 1018 => [524320]
-            map.insert(1940, &[1048608]);     // 1940 => [1048608]
-            map.insert(829, &[2097184]);     // 829 => [2097184]
-            map.insert(192, &[192]);     // 192 => [192]
-            map.insert(320, &[320]);     // 320 => [320]
-            map.insert(576, &[576]);     // 576 => [576]
-            map.insert(1088, &[1088]);     // 1088 => [1088]
-            map.insert(1832, &[2112]);     // 1832 => [2112]
-            map.insert(1247, &[4160]);     // 1247 => [4160]
-            map.insert(1558, &[8256]);     // 1558 => [8256]
-            map.insert(1699, &[16448]);     // 1699 => [16448]
-            map.insert(750, &[32832]);     // 750 => [32832]
-            map.insert(59, &[65600]);     // 59 => [65600]
-            map.insert(1425, &[131136]);     // 1425 => [131136]
-            map.insert(429, &[262208]);     // 429 => [262208]
-            map.insert(922, &[524352]);     // 922 => [524352]
-            map.insert(2036, &[1048640]);     // 2036 => [1048640]
-            map.insert(861, &[2097216]);     // 861 => [2097216]
-            map.insert(384, &[384]);     // 384 => [384]
-            map.insert(640, &[640]);     // 640 => [640]
-            map.insert(1152, &[1152]);     // 1152 => [1152]
-            map.insert(2024, &[2176]);     // 2024 => [2176]
-            map.insert(1055, &[4224]);     // 1055 => [4224]
-            map.insert(1750, &[8320]);     // 1750 => [8320]
-            map.insert(1635, &[16512]);     // 1635 => [16512]
-            map.insert(558, &[32896]);     // 558 => [32896]
-            map.insert(251, &[65664]);     // 251 => [65664]
-            map.insert(1361, &[131200]);     // 1361 => [131200]
-            map.insert(365, &[262272]);     // 365 => [262272]
-            map.insert(858, &[524416]);     // 858 => [524416]
-            map.insert(1844, &[1048704]);     // 1844 => [1048704]
-            map.insert(925, &[2097280]);     // 925 => [2097280]
-            map.insert(768, &[768]);     // 768 => [768]
-            map.insert(1280, &[1280]);     // 1280 => [1280]
-            map.insert(1640, &[2304]);     // 1640 => [2304]
-            map.insert(1439, &[4352]);     // 1439 => [4352]
-            map.insert(1878, &[8448]);     // 1878 => [8448]
-            map.insert(2019, &[16640]);     // 2019 => [16640]
-            map.insert(942, &[33024]);     // 942 => [33024]
-            map.insert(379, &[65792]);     // 379 => [65792]
-            map.insert(1233, &[131328]);     // 1233 => [131328]
-            map.insert(237, &[262400]);     // 237 => [262400]
-            map.insert(730, &[524544]);     // 730 => [524544]
-            map.insert(1716, &[1048832]);     // 1716 => [1048832]
-            map.insert(541, &[2097408]);     // 541 => [2097408]
-            map.insert(1536, &[1536]);     // 1536 => [1536]
-            map.insert(1384, &[2560]);     // 1384 => [2560]
-            map.insert(1695, &[4608]);     // 1695 => [4608]
-            map.insert(1110, &[8704]);     // 1110 => [8704]
-            map.insert(1251, &[16896]);     // 1251 => [16896]
-            map.insert(174, &[33280]);     // 174 => [33280]
-            map.insert(635, &[66048]);     // 635 => [66048]
-            map.insert(2001, &[131584]);     // 2001 => [131584]
-            map.insert(1005, &[262656]);     // 1005 => [262656]
-            map.insert(474, &[524800]);     // 474 => [524800]
-            map.insert(1460, &[1049088]);     // 1460 => [1049088]
-            map.insert(285, &[2097664]);     // 285 => [2097664]
-            map.insert(872, &[3072]);     // 872 => [3072]
-            map.insert(159, &[5120]);     // 159 => [5120]
-            map.insert(598, &[9216]);     // 598 => [9216]
-            map.insert(739, &[17408]);     // 739 => [17408]
-            map.insert(1710, &[33792]);     // 1710 => [33792]
-            map.insert(1147, &[66560]);     // 1147 => [66560]
-            map.insert(465, &[132096]);     // 465 => [132096]
-            map.insert(1517, &[263168]);     // 1517 => [263168]
-            map.insert(2010, &[525312]);     // 2010 => [525312]
-            map.insert(948, &[1049600]);     // 948 => [1049600]
-            map.insert(1821, &[2098176]);     // 1821 => [2098176]
-            map.insert(1015, &[6144]);     // 1015 => [6144]
-            map.insert(318, &[10240]);     // 318 => [10240]
-            map.insert(395, &[18432]);     // 395 => [18432]
-            map.insert(1478, &[34816]);     // 1478 => [34816]
-            map.insert(1811, &[67584]);     // 1811 => [67584]
-            map.insert(697, &[133120]);     // 697 => [133120]
-            map.insert(1669, &[264192]);     // 1669 => [264192]
-            map.insert(1202, &[526336]);     // 1202 => [526336]
-            map.insert(220, &[1050624]);     // 220 => [1050624]
-            map.insert(1141, &[2099200]);     // 1141 => [2099200]
-            map.insert(713, &[12288]);     // 713 => [12288]
-            map.insert(636, &[20480]);     // 636 => [20480]
-            map.insert(1585, &[36864]);     // 1585 => [36864]
-            map.insert(1252, &[69632]);     // 1252 => [69632]
-            map.insert(334, &[135168]);     // 334 => [135168]
-            map.insert(1394, &[266240]);     // 1394 => [266240]
-            map.insert(1861, &[528384]);     // 1861 => [528384]
-            map.insert(811, &[1052672]);     // 811 => [1052672]
-            map.insert(1922, &[2101248]);     // 1922 => [2101248]
-            map.insert(181, &[24576]);     // 181 => [24576]
-            map.insert(1272, &[40960]);     // 1272 => [40960]
-            map.insert(1581, &[73728]);     // 1581 => [73728]
-            map.insert(903, &[139264]);     // 903 => [139264]
-            map.insert(1979, &[270336]);     // 1979 => [270336]
-            map.insert(1420, &[532480]);     // 1420 => [532480]
-            map.insert(482, &[1056768]);     // 482 => [1056768]
-            map.insert(1355, &[2105344]);     // 1355 => [2105344]
-            map.insert(1101, &[49152]);     // 1101 => [49152]
-            map.insert(1688, &[81920]);     // 1688 => [81920]
-            map.insert(818, &[147456]);     // 818 => [147456]
-            map.insert(1806, &[278528]);     // 1806 => [278528]
-            map.insert(1337, &[540672]);     // 1337 => [540672]
-            map.insert(343, &[1064960]);     // 343 => [1064960]
-            map.insert(1534, &[2113536]);     // 1534 => [2113536]
-            map.insert(725, &[98304]);     // 725 => [98304]
-            map.insert(1919, &[163840]);     // 1919 => [163840]
-            map.insert(835, &[294912]);     // 835 => [294912]
-            map.insert(372, &[557056]);     // 372 => [557056]
-            map.insert(1306, &[1081344]);     // 1306 => [1081344]
-            map.insert(435, &[2129920]);     // 435 => [2129920]
-            map.insert(1450, &[196608]);     // 1450 => [196608]
-            map.insert(406, &[327680]);     // 406 => [327680]
-            map.insert(929, &[589824]);     // 929 => [589824]
-            map.insert(1999, &[1114112]);     // 1999 => [1114112]
-            map.insert(870, &[2162688]);     // 870 => [2162688]
-            map.insert(1084, &[393216]);     // 1084 => [393216]
-            map.insert(1547, &[655360]);     // 1547 => [655360]
-            map.insert(613, &[1179648]);     // 613 => [1179648]
-            map.insert(1740, &[2228224]);     // 1740 => [2228224]
-            map.insert(567, &[786432]);     // 567 => [786432]
-            map.insert(1625, &[1310720]);     // 1625 => [1310720]
-            map.insert(752, &[2359296]);     // 752 => [2359296]
-            map.insert(1134, &[1572864]);     // 1134 => [1572864]
-            map.insert(199, &[2621440]);     // 199 => [2621440]
-            map.insert(1193, &[3145728]);     // 1193 => [3145728]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(19, &[19]);     // 19 => [19]
-            map.insert(35, &[35]);     // 35 => [35]
-            map.insert(67, &[67]);     // 67 => [67]
-            map.insert(131, &[131]);     // 131 => [131]
-            map.insert(259, &[259]);     // 259 => [259]
-            map.insert(515, &[515]);     // 515 => [515]
-            map.insert(1027, &[1027]);     // 1027 => [1027]
-            map.insert(1899, &[2051]);     // 1899 => [2051]
-            map.insert(1180, &[4099]);     // 1180 => [4099]
-            map.insert(1621, &[8195]);     // 1621 => [8195]
-            map.insert(1760, &[16387]);     // 1760 => [16387]
-            map.insert(685, &[32771]);     // 685 => [32771]
-            map.insert(120, &[65539]);     // 120 => [65539]
-            map.insert(1490, &[131075]);     // 1490 => [131075]
-            map.insert(494, &[262147]);     // 494 => [262147]
-            map.insert(985, &[524291]);     // 985 => [524291]
-            map.insert(1975, &[1048579]);     // 1975 => [1048579]
-            map.insert(798, &[2097155]);     // 798 => [2097155]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(69, &[69]);     // 69 => [69]
-            map.insert(133, &[133]);     // 133 => [133]
-            map.insert(261, &[261]);     // 261 => [261]
-            map.insert(517, &[517]);     // 517 => [517]
-            map.insert(1029, &[1029]);     // 1029 => [1029]
-            map.insert(1901, &[2053]);     // 1901 => [2053]
-            map.insert(1178, &[4101]);     // 1178 => [4101]
-            map.insert(1619, &[8197]);     // 1619 => [8197]
-            map.insert(1766, &[16389]);     // 1766 => [16389]
-            map.insert(683, &[32773]);     // 683 => [32773]
-            map.insert(126, &[65541]);     // 126 => [65541]
-            map.insert(1492, &[131077]);     // 1492 => [131077]
-            map.insert(488, &[262149]);     // 488 => [262149]
-            map.insert(991, &[524293]);     // 991 => [524293]
-            map.insert(1969, &[1048581]);     // 1969 => [1048581]
-            map.insert(792, &[2097157]);     // 792 => [2097157]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(41, &[41]);     // 41 => [41]
-            map.insert(73, &[73]);     // 73 => [73]
-            map.insert(137, &[137]);     // 137 => [137]
-            map.insert(265, &[265]);     // 265 => [265]
-            map.insert(521, &[521]);     // 521 => [521]
-            map.insert(1033, &[1033]);     // 1033 => [1033]
-            map.insert(1889, &[2057]);     // 1889 => [2057]
-            map.insert(1174, &[4105]);     // 1174 => [4105]
-            map.insert(1631, &[8201]);     // 1631 => [8201]
-            map.insert(1770, &[16393]);     // 1770 => [16393]
-            map.insert(679, &[32777]);     // 679 => [32777]
-            map.insert(114, &[65545]);     // 114 => [65545]
-            map.insert(1496, &[131081]);     // 1496 => [131081]
-            map.insert(484, &[262153]);     // 484 => [262153]
-            map.insert(979, &[524297]);     // 979 => [524297]
-            map.insert(1981, &[1048585]);     // 1981 => [1048585]
-            map.insert(788, &[2097161]);     // 788 => [2097161]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(81, &[81]);     // 81 => [81]
-            map.insert(145, &[145]);     // 145 => [145]
-            map.insert(273, &[273]);     // 273 => [273]
-            map.insert(529, &[529]);     // 529 => [529]
-            map.insert(1041, &[1041]);     // 1041 => [1041]
-            map.insert(1913, &[2065]);     // 1913 => [2065]
-            map.insert(1166, &[4113]);     // 1166 => [4113]
-            map.insert(1607, &[8209]);     // 1607 => [8209]
-            map.insert(1778, &[16401]);     // 1778 => [16401]
-            map.insert(703, &[32785]);     // 703 => [32785]
-            map.insert(106, &[65553]);     // 106 => [65553]
-            map.insert(1472, &[131089]);     // 1472 => [131089]
-            map.insert(508, &[262161]);     // 508 => [262161]
-            map.insert(971, &[524305]);     // 971 => [524305]
-            map.insert(1957, &[1048593]);     // 1957 => [1048593]
-            map.insert(780, &[2097169]);     // 780 => [2097169]
-            map.insert(97, &[97]);     // 97 => [97]
-            map.insert(161, &[161]);     // 161 => [161]
-            map.insert(289, &[289]);     // 289 => [289]
-            map.insert(545, &[545]);     // 545 => [545]
-            map.insert(1057, &[1057]);     // 1057 => [1057]
-            map.insert(1865, &[2081]);     // 1865 => [2081]
-            map.insert(1214, &[4129]);     // 1214 => [4129]
-            map.insert(1655, &[8225]);     // 1655 => [8225]
-            map.insert(1730, &[16417]);     // 1730 => [16417]
-            map.insert(655, &[32801]);     // 655 => [32801]
-            map.insert(90, &[65569]);     // 90 => [65569]
-            map.insert(1520, &[131105]);     // 1520 => [131105]
-            map.insert(460, &[262177]);     // 460 => [262177]
-            map.insert(1019, &[524321]);     // 1019 => [524321]
-            map.insert(1941, &[1048609]);     // 1941 => [1048609]
-            map.insert(828, &[2097185]);     // 828 => [2097185]
-            map.insert(193, &[193]);     // 193 => [193]
-            map.insert(321, &[321]);     // 321 => [321]
-            map.insert(577, &[577]);     // 577 => [577]
-            map.insert(1089, &[1089]);     // 1089 => [1089]
-            map.insert(1833, &[2113]);     // 1833 => [2113]
-            map.insert(1246, &[4161]);     // 1246 => [4161]
-            map.insert(1559, &[8257]);     // 1559 => [8257]
-            map.insert(1698, &[16449]);     // 1698 => [16449]
-            map.insert(751, &[32833]);     // 751 => [32833]
-            map.insert(58, &[65601]);     // 58 => [65601]
-            map.insert(1424, &[131137]);     // 1424 => [131137]
-            map.insert(428, &[262209]);     // 428 => [262209]
-            map.insert(923, &[524353]);     // 923 => [524353]
-            map.insert(2037, &[1048641]);     // 2037 => [1048641]
-            map.insert(860, &[2097217]);     // 860 => [2097217]
-            map.insert(385, &[385]);     // 385 => [385]
-            map.insert(641, &[641]);     // 641 => [641]
-            map.insert(1153, &[1153]);     // 1153 => [1153]
-            map.insert(2025, &[2177]);     // 2025 => [2177]
-            map.insert(1054, &[4225]);     // 1054 => [4225]
-            map.insert(1751, &[8321]);     // 1751 => [8321]
-            map.insert(1634, &[16513]);     // 1634 => [16513]
-            map.insert(559, &[32897]);     // 559 => [32897]
-            map.insert(250, &[65665]);     // 250 => [65665]
-            map.insert(1360, &[131201]);     // 1360 => [131201]
-            map.insert(364, &[262273]);     // 364 => [262273]
-            map.insert(859, &[524417]);     // 859 => [524417]
-            map.insert(1845, &[1048705]);     // 1845 => [1048705]
-            map.insert(924, &[2097281]);     // 924 => [2097281]
-            map.insert(769, &[769]);     // 769 => [769]
-            map.insert(1281, &[1281]);     // 1281 => [1281]
-            map.insert(1641, &[2305]);     // 1641 => [2305]
-            map.insert(1438, &[4353]);     // 1438 => [4353]
-            map.insert(1879, &[8449]);     // 1879 => [8449]
-            map.insert(2018, &[16641]);     // 2018 => [16641]
-            map.insert(943, &[33025]);     // 943 => [33025]
-            map.insert(378, &[65793]);     // 378 => [65793]
-            map.insert(1232, &[131329]);     // 1232 => [131329]
-            map.insert(236, &[262401]);     // 236 => [262401]
-            map.insert(731, &[524545]);     // 731 => [524545]
-            map.insert(1717, &[1048833]);     // 1717 => [1048833]
-            map.insert(540, &[2097409]);     // 540 => [2097409]
-            map.insert(1537, &[1537]);     // 1537 => [1537]
-            map.insert(1385, &[2561]);     // 1385 => [2561]
-            map.insert(1694, &[4609]);     // 1694 => [4609]
-            map.insert(1111, &[8705]);     // 1111 => [8705]
-            map.insert(1250, &[16897]);     // 1250 => [16897]
-            map.insert(175, &[33281]);     // 175 => [33281]
-            map.insert(634, &[66049]);     // 634 => [66049]
-            map.insert(2000, &[131585]);     // 2000 => [131585]
-            map.insert(1004, &[262657]);     // 1004 => [262657]
-            map.insert(475, &[524801]);     // 475 => [524801]
-            map.insert(1461, &[1049089]);     // 1461 => [1049089]
-            map.insert(284, &[2097665]);     // 284 => [2097665]
-            map.insert(873, &[3073]);     // 873 => [3073]
-            map.insert(158, &[5121]);     // 158 => [5121]
-            map.insert(599, &[9217]);     // 599 => [9217]
-            map.insert(738, &[17409]);     // 738 => [17409]
-            map.insert(1711, &[33793]);     // 1711 => [33793]
-            map.insert(1146, &[66561]);     // 1146 => [66561]
-            map.insert(464, &[132097]);     // 464 => [132097]
-            map.insert(1516, &[263169]);     // 1516 => [263169]
-            map.insert(2011, &[525313]);     // 2011 => [525313]
-            map.insert(949, &[1049601]);     // 949 => [1049601]
-            map.insert(1820, &[2098177]);     // 1820 => [2098177]
-            map.insert(1014, &[6145]);     // 1014 => [6145]
-            map.insert(319, &[10241]);     // 319 => [10241]
-            map.insert(394, &[18433]);     // 394 => [18433]
-            map.insert(1479, &[34817]);     // 1479 => [34817]
-            map.insert(1810, &[67585]);     // 1810 => [67585]
-            map.insert(696, &[133121]);     // 696 => [133121]
-            map.insert(1668, &[264193]);     // 1668 => [264193]
-            map.insert(1203, &[526337]);     // 1203 => [526337]
-            map.insert(221, &[1050625]);     // 221 => [1050625]
-            map.insert(1140, &[2099201]);     // 1140 => [2099201]
-            map.insert(712, &[12289]);     // 712 => [12289]
-            map.insert(637, &[20481]);     // 637 => [20481]
-            map.insert(1584, &[36865]);     // 1584 => [36865]
-            map.insert(1253, &[69633]);     // 1253 => [69633]
-            map.insert(335, &[135169]);     // 335 => [135169]
-            map.insert(1395, &[266241]);     // 1395 => [266241]
-            map.insert(1860, &[528385]);     // 1860 => [528385]
-            map.insert(810, &[1052673]);     // 810 => [1052673]
-            map.insert(1923, &[2101249]);     // 1923 => [2101249]
-            map.insert(180, &[24577]);     // 180 => [24577]
-            map.insert(1273, &[40961]);     // 1273 => [40961]
-            map.insert(1580, &[73729]);     // 1580 => [73729]
-            map.insert(902, &[139265]);     // 902 => [139265]
-            map.insert(1978, &[270337]);     // 1978 => [270337]
-            map.insert(1421, &[532481]);     // 1421 => [532481]
-            map.insert(483, &[1056769]);     // 483 => [1056769]
-            map.insert(1354, &[2105345]);     // 1354 => [2105345]
-            map.insert(1100, &[49153]);     // 1100 => [49153]
-            map.insert(1689, &[81921]);     // 1689 => [81921]
-            map.insert(819, &[147457]);     // 819 => [147457]
-            map.insert(1807, &[278529]);     // 1807 => [278529]
-            map.insert(1336, &[540673]);     // 1336 => [540673]
-            map.insert(342, &[1064961]);     // 342 => [1064961]
-            map.insert(1535, &[2113537]);     // 1535 => [2113537]
-            map.insert(724, &[98305]);     // 724 => [98305]
-            map.insert(1918, &[163841]);     // 1918 => [163841]
-            map.insert(834, &[294913]);     // 834 => [294913]
-            map.insert(373, &[557057]);     // 373 => [557057]
-            map.insert(1307, &[1081345]);     // 1307 => [1081345]
-            map.insert(434, &[2129921]);     // 434 => [2129921]
-            map.insert(1451, &[196609]);     // 1451 => [196609]
-            map.insert(407, &[327681]);     // 407 => [327681]
-            map.insert(928, &[589825]);     // 928 => [589825]
-            map.insert(1998, &[1114113]);     // 1998 => [1114113]
-            map.insert(871, &[2162689]);     // 871 => [2162689]
-            map.insert(1085, &[393217]);     // 1085 => [393217]
-            map.insert(1546, &[655361]);     // 1546 => [655361]
-            map.insert(612, &[1179649]);     // 612 => [1179649]
-            map.insert(1741, &[2228225]);     // 1741 => [2228225]
-            map.insert(566, &[786433]);     // 566 => [786433]
-            map.insert(1624, &[1310721]);     // 1624 => [1310721]
-            map.insert(753, &[2359297]);     // 753 => [2359297]
-            map.insert(1135, &[1572865]);     // 1135 => [1572865]
-            map.insert(198, &[2621441]);     // 198 => [2621441]
-            map.insert(1192, &[3145729]);     // 1192 => [3145729]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(70, &[70]);     // 70 => [70]
-            map.insert(134, &[134]);     // 134 => [134]
-            map.insert(262, &[262]);     // 262 => [262]
-            map.insert(518, &[518]);     // 518 => [518]
-            map.insert(1030, &[1030]);     // 1030 => [1030]
-            map.insert(1902, &[2054]);     // 1902 => [2054]
-            map.insert(1177, &[4102]);     // 1177 => [4102]
-            map.insert(1616, &[8198]);     // 1616 => [8198]
-            map.insert(1765, &[16390]);     // 1765 => [16390]
-            map.insert(680, &[32774]);     // 680 => [32774]
-            map.insert(125, &[65542]);     // 125 => [65542]
-            map.insert(1495, &[131078]);     // 1495 => [131078]
-            map.insert(491, &[262150]);     // 491 => [262150]
-            map.insert(988, &[524294]);     // 988 => [524294]
-            map.insert(1970, &[1048582]);     // 1970 => [1048582]
-            map.insert(795, &[2097158]);     // 795 => [2097158]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(42, &[42]);     // 42 => [42]
-            map.insert(74, &[74]);     // 74 => [74]
-            map.insert(138, &[138]);     // 138 => [138]
-            map.insert(266, &[266]);     // 266 => [266]
-            map.insert(522, &[522]);     // 522 => [522]
-            map.insert(1034, &[1034]);     // 1034 => [1034]
-            map.insert(1890, &[2058]);     // 1890 => [2058]
-            map.insert(1173, &[4106]);     // 1173 => [4106]
-            map.insert(1628, &[8202]);     // 1628 => [8202]
-            map.insert(1769, &[16394]);     // 1769 => [16394]
-            map.insert(676, &[32778]);     // 676 => [32778]
-            map.insert(113, &[65546]);     // 113 => [65546]
-            map.insert(1499, &[131082]);     // 1499 => [131082]
-            map.insert(487, &[262154]);     // 487 => [262154]
-            map.insert(976, &[524298]);     // 976 => [524298]
-            map.insert(1982, &[1048586]);     // 1982 => [1048586]
-            map.insert(791, &[2097162]);     // 791 => [2097162]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(82, &[82]);     // 82 => [82]
-            map.insert(146, &[146]);     // 146 => [146]
-            map.insert(274, &[274]);     // 274 => [274]
-            map.insert(530, &[530]);     // 530 => [530]
-            map.insert(1042, &[1042]);     // 1042 => [1042]
-            map.insert(1914, &[2066]);     // 1914 => [2066]
-            map.insert(1165, &[4114]);     // 1165 => [4114]
-            map.insert(1604, &[8210]);     // 1604 => [8210]
-            map.insert(1777, &[16402]);     // 1777 => [16402]
-            map.insert(700, &[32786]);     // 700 => [32786]
-            map.insert(105, &[65554]);     // 105 => [65554]
-            map.insert(1475, &[131090]);     // 1475 => [131090]
-            map.insert(511, &[262162]);     // 511 => [262162]
-            map.insert(968, &[524306]);     // 968 => [524306]
-            map.insert(1958, &[1048594]);     // 1958 => [1048594]
-            map.insert(783, &[2097170]);     // 783 => [2097170]
-            map.insert(98, &[98]);     // 98 => [98]
-            map.insert(162, &[162]);     // 162 => [162]
-            map.insert(290, &[290]);     // 290 => [290]
-            map.insert(546, &[546]);     // 546 => [546]
-            map.insert(1058, &[1058]);     // 1058 => [1058]
-            map.insert(1866, &[2082]);     // 1866 => [2082]
-            map.insert(1213, &[4130]);     // 1213 => [4130]
-            map.insert(1652, &[8226]);     // 1652 => [8226]
-            map.insert(1729, &[16418]);     // 1729 => [16418]
-            map.insert(652, &[32802]);     // 652 => [32802]
-            map.insert(89, &[65570]);     // 89 => [65570]
-            map.insert(1523, &[131106]);     // 1523 => [131106]
-            map.insert(463, &[262178]);     // 463 => [262178]
-            map.insert(1016, &[524322]);     // 1016 => [524322]
-            map.insert(1942, &[1048610]);     // 1942 => [1048610]
-            map.insert(831, &[2097186]);     // 831 => [2097186]
-            map.insert(194, &[194]);     // 194 => [194]
-            map.insert(322, &[322]);     // 322 => [322]
-            map.insert(578, &[578]);     // 578 => [578]
-            map.insert(1090, &[1090]);     // 1090 => [1090]
-            map.insert(1834, &[2114]);     // 1834 => [2114]
-            map.insert(1245, &[4162]);     // 1245 => [4162]
-            map.insert(1556, &[8258]);     // 1556 => [8258]
-            map.insert(1697, &[16450]);     // 1697 => [16450]
-            map.insert(748, &[32834]);     // 748 => [32834]
-            map.insert(57, &[65602]);     // 57 => [65602]
-            map.insert(1427, &[131138]);     // 1427 => [131138]
-            map.insert(431, &[262210]);     // 431 => [262210]
-            map.insert(920, &[524354]);     // 920 => [524354]
-            map.insert(2038, &[1048642]);     // 2038 => [1048642]
-            map.insert(863, &[2097218]);     // 863 => [2097218]
-            map.insert(386, &[386]);     // 386 => [386]
-            map.insert(642, &[642]);     // 642 => [642]
-            map.insert(1154, &[1154]);     // 1154 => [1154]
-            map.insert(2026, &[2178]);     // 2026 => [2178]
-            map.insert(1053, &[4226]);     // 1053 => [4226]
-            map.insert(1748, &[8322]);     // 1748 => [8322]
-            map.insert(1633, &[16514]);     // 1633 => [16514]
-            map.insert(556, &[32898]);     // 556 => [32898]
-            map.insert(249, &[65666]);     // 249 => [65666]
-            map.insert(1363, &[131202]);     // 1363 => [131202]
-            map.insert(367, &[262274]);     // 367 => [262274]
-            map.insert(856, &[524418]);     // 856 => [524418]
-            map.insert(1846, &[1048706]);     // 1846 => [1048706]
-            map.insert(927, &[2097282]);     // 927 => [2097282]
-            map.insert(770, &[770]);     // 770 => [770]
-            map.insert(1282, &[1282]);     // 1282 => [1282]
-            map.insert(1642, &[2306]);     // 1642 => [2306]
-            map.insert(1437, &[4354]);     // 1437 => [4354]
-            map.insert(1876, &[8450]);     // 1876 => [8450]
-            map.insert(2017, &[16642]);     // 2017 => [16642]
-            map.insert(940, &[33026]);     // 940 => [33026]
-            map.insert(377, &[65794]);     // 377 => [65794]
-            map.insert(1235, &[131330]);     // 1235 => [131330]
-            map.insert(239, &[262402]);     // 239 => [262402]
-            map.insert(728, &[524546]);     // 728 => [524546]
-            map.insert(1718, &[1048834]);     // 1718 => [1048834]
-            map.insert(543, &[2097410]);     // 543 => [2097410]
-            map.insert(1538, &[1538]);     // 1538 => [1538]
-            map.insert(1386, &[2562]);     // 1386 => [2562]
-            map.insert(1693, &[4610]);     // 1693 => [4610]
-            map.insert(1108, &[8706]);     // 1108 => [8706]
-            map.insert(1249, &[16898]);     // 1249 => [16898]
-            map.insert(172, &[33282]);     // 172 => [33282]
-            map.insert(633, &[66050]);     // 633 => [66050]
-            map.insert(2003, &[131586]);     // 2003 => [131586]
-            map.insert(1007, &[262658]);     // 1007 => [262658]
-            map.insert(472, &[524802]);     // 472 => [524802]
-            map.insert(1462, &[1049090]);     // 1462 => [1049090]
-            map.insert(287, &[2097666]);     // 287 => [2097666]
-            map.insert(874, &[3074]);     // 874 => [3074]
-            map.insert(157, &[5122]);     // 157 => [5122]
-            map.insert(596, &[9218]);     // 596 => [9218]
-            map.insert(737, &[17410]);     // 737 => [17410]
-            map.insert(1708, &[33794]);     // 1708 => [33794]
-            map.insert(1145, &[66562]);     // 1145 => [66562]
-            map.insert(467, &[132098]);     // 467 => [132098]
-            map.insert(1519, &[263170]);     // 1519 => [263170]
-            map.insert(2008, &[525314]);     // 2008 => [525314]
-            map.insert(950, &[1049602]);     // 950 => [1049602]
-            map.insert(1823, &[2098178]);     // 1823 => [2098178]
-            map.insert(1013, &[6146]);     // 1013 => [6146]
-            map.insert(316, &[10242]);     // 316 => [10242]
-            map.insert(393, &[18434]);     // 393 => [18434]
-            map.insert(1476, &[34818]);     // 1476 => [34818]
-            map.insert(1809, &[67586]);     // 1809 => [67586]
-            map.insert(699, &[133122]);     // 699 => [133122]
-            map.insert(1671, &[264194]);     // 1671 => [264194]
-            map.insert(1200, &[526338]);     // 1200 => [526338]
-            map.insert(222, &[1050626]);     // 222 => [1050626]
-            map.insert(1143, &[2099202]);     // 1143 => [2099202]
-            map.insert(715, &[12290]);     // 715 => [12290]
-            map.insert(638, &[20482]);     // 638 => [20482]
-            map.insert(1587, &[36866]);     // 1587 => [36866]
-            map.insert(1254, &[69634]);     // 1254 => [69634]
-            map.insert(332, &[135170]);     // 332 => [135170]
-            map.insert(1392, &[266242]);     // 1392 => [266242]
-            map.insert(1863, &[528386]);     // 1863 => [528386]
-            map.insert(809, &[1052674]);     // 809 => [1052674]
-            map.insert(1920, &[2101250]);     // 1920 => [2101250]
-            map.insert(183, &[24578]);     // 183 => [24578]
-            map.insert(1274, &[40962]);     // 1274 => [40962]
-            map.insert(1583, &[73730]);     // 1583 => [73730]
-            map.insert(901, &[139266]);     // 901 => [139266]
-            map.insert(1977, &[270338]);     // 1977 => [270338]
-            map.insert(1422, &[532482]);     // 1422 => [532482]
-            map.insert(480, &[1056770]);     // 480 => [1056770]
-            map.insert(1353, &[2105346]);     // 1353 => [2105346]
-            map.insert(1103, &[49154]);     // 1103 => [49154]
-            map.insert(1690, &[81922]);     // 1690 => [81922]
-            map.insert(816, &[147458]);     // 816 => [147458]
-            map.insert(1804, &[278530]);     // 1804 => [278530]
-            map.insert(1339, &[540674]);     // 1339 => [540674]
-            map.insert(341, &[1064962]);     // 341 => [1064962]
-            map.insert(1532, &[2113538]);     // 1532 => [2113538]
-            map.insert(727, &[98306]);     // 727 => [98306]
-            map.insert(1917, &[163842]);     // 1917 => [163842]
-            map.insert(833, &[294914]);     // 833 => [294914]
-            map.insert(374, &[557058]);     // 374 => [557058]
-            map.insert(1304, &[1081346]);     // 1304 => [1081346]
-            map.insert(433, &[2129922]);     // 433 => [2129922]
-            map.insert(1448, &[196610]);     // 1448 => [196610]
-            map.insert(404, &[327682]);     // 404 => [327682]
-            map.insert(931, &[589826]);     // 931 => [589826]
-            map.insert(1997, &[1114114]);     // 1997 => [1114114]
-            map.insert(868, &[2162690]);     // 868 => [2162690]
-            map.insert(1086, &[393218]);     // 1086 => [393218]
-            map.insert(1545, &[655362]);     // 1545 => [655362]
-            map.insert(615, &[1179650]);     // 615 => [1179650]
-            map.insert(1742, &[2228226]);     // 1742 => [2228226]
-            map.insert(565, &[786434]);     // 565 => [786434]
-            map.insert(1627, &[1310722]);     // 1627 => [1310722]
-            map.insert(754, &[2359298]);     // 754 => [2359298]
-            map.insert(1132, &[1572866]);     // 1132 => [1572866]
-            map.insert(197, &[2621442]);     // 197 => [2621442]
-            map.insert(1195, &[3145730]);     // 1195 => [3145730]
-            map.insert(28, &[28]);     // 28 => [28]
-            map.insert(44, &[44]);     // 44 => [44]
-            map.insert(76, &[76]);     // 76 => [76]
-            map.insert(140, &[140]);     // 140 => [140]
-            map.insert(268, &[268]);     // 268 => [268]
-            map.insert(524, &[524]);     // 524 => [524]
-            map.insert(1036, &[1036]);     // 1036 => [1036]
-            map.insert(1892, &[2060]);     // 1892 => [2060]
-            map.insert(1171, &[4108]);     // 1171 => [4108]
-            map.insert(1626, &[8204]);     // 1626 => [8204]
-            map.insert(1775, &[16396]);     // 1775 => [16396]
-            map.insert(674, &[32780]);     // 674 => [32780]
-            map.insert(119, &[65548]);     // 119 => [65548]
-            map.insert(1501, &[131084]);     // 1501 => [131084]
-            map.insert(481, &[262156]);     // 481 => [262156]
-            map.insert(982, &[524300]);     // 982 => [524300]
-            map.insert(1976, &[1048588]);     // 1976 => [1048588]
-            map.insert(785, &[2097164]);     // 785 => [2097164]
-            map.insert(52, &[52]);     // 52 => [52]
-            map.insert(84, &[84]);     // 84 => [84]
-            map.insert(148, &[148]);     // 148 => [148]
-            map.insert(276, &[276]);     // 276 => [276]
-            map.insert(532, &[532]);     // 532 => [532]
-            map.insert(1044, &[1044]);     // 1044 => [1044]
-            map.insert(1916, &[2068]);     // 1916 => [2068]
-            map.insert(1163, &[4116]);     // 1163 => [4116]
-            map.insert(1602, &[8212]);     // 1602 => [8212]
-            map.insert(1783, &[16404]);     // 1783 => [16404]
-            map.insert(698, &[32788]);     // 698 => [32788]
-            map.insert(111, &[65556]);     // 111 => [65556]
-            map.insert(1477, &[131092]);     // 1477 => [131092]
-            map.insert(505, &[262164]);     // 505 => [262164]
-            map.insert(974, &[524308]);     // 974 => [524308]
-            map.insert(1952, &[1048596]);     // 1952 => [1048596]
-            map.insert(777, &[2097172]);     // 777 => [2097172]
-            map.insert(100, &[100]);     // 100 => [100]
-            map.insert(164, &[164]);     // 164 => [164]
-            map.insert(292, &[292]);     // 292 => [292]
-            map.insert(548, &[548]);     // 548 => [548]
-            map.insert(1060, &[1060]);     // 1060 => [1060]
-            map.insert(1868, &[2084]);     // 1868 => [2084]
-            map.insert(1211, &[4132]);     // 1211 => [4132]
-            map.insert(1650, &[8228]);     // 1650 => [8228]
-            map.insert(1735, &[16420]);     // 1735 => [16420]
-            map.insert(650, &[32804]);     // 650 => [32804]
-            map.insert(95, &[65572]);     // 95 => [65572]
-            map.insert(1525, &[131108]);     // 1525 => [131108]
-            map.insert(457, &[262180]);     // 457 => [262180]
-            map.insert(1022, &[524324]);     // 1022 => [524324]
-            map.insert(1936, &[1048612]);     // 1936 => [1048612]
-            map.insert(825, &[2097188]);     // 825 => [2097188]
-            map.insert(196, &[196]);     // 196 => [196]
-            map.insert(324, &[324]);     // 324 => [324]
-            map.insert(580, &[580]);     // 580 => [580]
-            map.insert(1092, &[1092]);     // 1092 => [1092]
-            map.insert(1836, &[2116]);     // 1836 => [2116]
-            map.insert(1243, &[4164]);     // 1243 => [4164]
-            map.insert(1554, &[8260]);     // 1554 => [8260]
-            map.insert(1703, &[16452]);     // 1703 => [16452]
-            map.insert(746, &[32836]);     // 746 => [32836]
-            map.insert(63, &[65604]);     // 63 => [65604]
-            map.insert(1429, &[131140]);     // 1429 => [131140]
-            map.insert(425, &[262212]);     // 425 => [262212]
-            map.insert(926, &[524356]);     // 926 => [524356]
-            map.insert(2032, &[1048644]);     // 2032 => [1048644]
-            map.insert(857, &[2097220]);     // 857 => [2097220]
-            map.insert(388, &[388]);     // 388 => [388]
-            map.insert(644, &[644]);     // 644 => [644]
-            map.insert(1156, &[1156]);     // 1156 => [1156]
-            map.insert(2028, &[2180]);     // 2028 => [2180]
-            map.insert(1051, &[4228]);     // 1051 => [4228]
-            map.insert(1746, &[8324]);     // 1746 => [8324]
-            map.insert(1639, &[16516]);     // 1639 => [16516]
-            map.insert(554, &[32900]);     // 554 => [32900]
-            map.insert(255, &[65668]);     // 255 => [65668]
-            map.insert(1365, &[131204]);     // 1365 => [131204]
-            map.insert(361, &[262276]);     // 361 => [262276]
-            map.insert(862, &[524420]);     // 862 => [524420]
-            map.insert(1840, &[1048708]);     // 1840 => [1048708]
-            map.insert(921, &[2097284]);     // 921 => [2097284]
-            map.insert(772, &[772]);     // 772 => [772]
-            map.insert(1284, &[1284]);     // 1284 => [1284]
-            map.insert(1644, &[2308]);     // 1644 => [2308]
-            map.insert(1435, &[4356]);     // 1435 => [4356]
-            map.insert(1874, &[8452]);     // 1874 => [8452]
-            map.insert(2023, &[16644]);     // 2023 => [16644]
-            map.insert(938, &[33028]);     // 938 => [33028]
-            map.insert(383, &[65796]);     // 383 => [65796]
-            map.insert(1237, &[131332]);     // 1237 => [131332]
-            map.insert(233, &[262404]);     // 233 => [262404]
-            map.insert(734, &[524548]);     // 734 => [524548]
-            map.insert(1712, &[1048836]);     // 1712 => [1048836]
-            map.insert(537, &[2097412]);     // 537 => [2097412]
-            map.insert(1540, &[1540]);     // 1540 => [1540]
-            map.insert(1388, &[2564]);     // 1388 => [2564]
-            map.insert(1691, &[4612]);     // 1691 => [4612]
-            map.insert(1106, &[8708]);     // 1106 => [8708]
-            map.insert(1255, &[16900]);     // 1255 => [16900]
-            map.insert(170, &[33284]);     // 170 => [33284]
-            map.insert(639, &[66052]);     // 639 => [66052]
-            map.insert(2005, &[131588]);     // 2005 => [131588]
-            map.insert(1001, &[262660]);     // 1001 => [262660]
-            map.insert(478, &[524804]);     // 478 => [524804]
-            map.insert(1456, &[1049092]);     // 1456 => [1049092]
-            map.insert(281, &[2097668]);     // 281 => [2097668]
-            map.insert(876, &[3076]);     // 876 => [3076]
-            map.insert(155, &[5124]);     // 155 => [5124]
-            map.insert(594, &[9220]);     // 594 => [9220]
-            map.insert(743, &[17412]);     // 743 => [17412]
-            map.insert(1706, &[33796]);     // 1706 => [33796]
-            map.insert(1151, &[66564]);     // 1151 => [66564]
-            map.insert(469, &[132100]);     // 469 => [132100]
-            map.insert(1513, &[263172]);     // 1513 => [263172]
-            map.insert(2014, &[525316]);     // 2014 => [525316]
-            map.insert(944, &[1049604]);     // 944 => [1049604]
-            map.insert(1817, &[2098180]);     // 1817 => [2098180]
-            map.insert(1011, &[6148]);     // 1011 => [6148]
-            map.insert(314, &[10244]);     // 314 => [10244]
-            map.insert(399, &[18436]);     // 399 => [18436]
-            map.insert(1474, &[34820]);     // 1474 => [34820]
-            map.insert(1815, &[67588]);     // 1815 => [67588]
-            map.insert(701, &[133124]);     // 701 => [133124]
-            map.insert(1665, &[264196]);     // 1665 => [264196]
-            map.insert(1206, &[526340]);     // 1206 => [526340]
-            map.insert(216, &[1050628]);     // 216 => [1050628]
-            map.insert(1137, &[2099204]);     // 1137 => [2099204]
-            map.insert(717, &[12292]);     // 717 => [12292]
-            map.insert(632, &[20484]);     // 632 => [20484]
-            map.insert(1589, &[36868]);     // 1589 => [36868]
-            map.insert(1248, &[69636]);     // 1248 => [69636]
-            map.insert(330, &[135172]);     // 330 => [135172]
-            map.insert(1398, &[266244]);     // 1398 => [266244]
-            map.insert(1857, &[528388]);     // 1857 => [528388]
-            map.insert(815, &[1052676]);     // 815 => [1052676]
-            map.insert(1926, &[2101252]);     // 1926 => [2101252]
-            map.insert(177, &[24580]);     // 177 => [24580]
-            map.insert(1276, &[40964]);     // 1276 => [40964]
-            map.insert(1577, &[73732]);     // 1577 => [73732]
-            map.insert(899, &[139268]);     // 899 => [139268]
-            map.insert(1983, &[270340]);     // 1983 => [270340]
-            map.insert(1416, &[532484]);     // 1416 => [532484]
-            map.insert(486, &[1056772]);     // 486 => [1056772]
-            map.insert(1359, &[2105348]);     // 1359 => [2105348]
-            map.insert(1097, &[49156]);     // 1097 => [49156]
-            map.insert(1692, &[81924]);     // 1692 => [81924]
-            map.insert(822, &[147460]);     // 822 => [147460]
-            map.insert(1802, &[278532]);     // 1802 => [278532]
-            map.insert(1341, &[540676]);     // 1341 => [540676]
-            map.insert(339, &[1064964]);     // 339 => [1064964]
-            map.insert(1530, &[2113540]);     // 1530 => [2113540]
-            map.insert(721, &[98308]);     // 721 => [98308]
-            map.insert(1915, &[163844]);     // 1915 => [163844]
-            map.insert(839, &[294916]);     // 839 => [294916]
-            map.insert(368, &[557060]);     // 368 => [557060]
-            map.insert(1310, &[1081348]);     // 1310 => [1081348]
-            map.insert(439, &[2129924]);     // 439 => [2129924]
-            map.insert(1454, &[196612]);     // 1454 => [196612]
-            map.insert(402, &[327684]);     // 402 => [327684]
-            map.insert(933, &[589828]);     // 933 => [589828]
-            map.insert(1995, &[1114116]);     // 1995 => [1114116]
-            map.insert(866, &[2162692]);     // 866 => [2162692]
-            map.insert(1080, &[393220]);     // 1080 => [393220]
-            map.insert(1551, &[655364]);     // 1551 => [655364]
-            map.insert(609, &[1179652]);     // 609 => [1179652]
-            map.insert(1736, &[2228228]);     // 1736 => [2228228]
-            map.insert(563, &[786436]);     // 563 => [786436]
-            map.insert(1629, &[1310724]);     // 1629 => [1310724]
-            map.insert(756, &[2359300]);     // 756 => [2359300]
-            map.insert(1130, &[1572868]);     // 1130 => [1572868]
-            map.insert(195, &[2621444]);     // 195 => [2621444]
-            map.insert(1197, &[3145732]);     // 1197 => [3145732]
-            map.insert(56, &[56]);     // 56 => [56]
-            map.insert(88, &[88]);     // 88 => [88]
-            map.insert(152, &[152]);     // 152 => [152]
-            map.insert(280, &[280]);     // 280 => [280]
-            map.insert(536, &[536]);     // 536 => [536]
-            map.insert(1048, &[1048]);     // 1048 => [1048]
-            map.insert(1904, &[2072]);     // 1904 => [2072]
-            map.insert(1159, &[4120]);     // 1159 => [4120]
-            map.insert(1614, &[8216]);     // 1614 => [8216]
-            map.insert(1787, &[16408]);     // 1787 => [16408]
-            map.insert(694, &[32792]);     // 694 => [32792]
-            map.insert(99, &[65560]);     // 99 => [65560]
-            map.insert(1481, &[131096]);     // 1481 => [131096]
-            map.insert(501, &[262168]);     // 501 => [262168]
-            map.insert(962, &[524312]);     // 962 => [524312]
-            map.insert(1964, &[1048600]);     // 1964 => [1048600]
-            map.insert(773, &[2097176]);     // 773 => [2097176]
-            map.insert(104, &[104]);     // 104 => [104]
-            map.insert(168, &[168]);     // 168 => [168]
-            map.insert(296, &[296]);     // 296 => [296]
-            map.insert(552, &[552]);     // 552 => [552]
-            map.insert(1064, &[1064]);     // 1064 => [1064]
-            map.insert(1856, &[2088]);     // 1856 => [2088]
-            map.insert(1207, &[4136]);     // 1207 => [4136]
-            map.insert(1662, &[8232]);     // 1662 => [8232]
-            map.insert(1739, &[16424]);     // 1739 => [16424]
-            map.insert(646, &[32808]);     // 646 => [32808]
-            map.insert(83, &[65576]);     // 83 => [65576]
-            map.insert(1529, &[131112]);     // 1529 => [131112]
-            map.insert(453, &[262184]);     // 453 => [262184]
-            map.insert(1010, &[524328]);     // 1010 => [524328]
-            map.insert(1948, &[1048616]);     // 1948 => [1048616]
-            map.insert(821, &[2097192]);     // 821 => [2097192]
-            map.insert(200, &[200]);     // 200 => [200]
-            map.insert(328, &[328]);     // 328 => [328]
-            map.insert(584, &[584]);     // 584 => [584]
-            map.insert(1096, &[1096]);     // 1096 => [1096]
-            map.insert(1824, &[2120]);     // 1824 => [2120]
-            map.insert(1239, &[4168]);     // 1239 => [4168]
-            map.insert(1566, &[8264]);     // 1566 => [8264]
-            map.insert(1707, &[16456]);     // 1707 => [16456]
-            map.insert(742, &[32840]);     // 742 => [32840]
-            map.insert(51, &[65608]);     // 51 => [65608]
-            map.insert(1433, &[131144]);     // 1433 => [131144]
-            map.insert(421, &[262216]);     // 421 => [262216]
-            map.insert(914, &[524360]);     // 914 => [524360]
-            map.insert(2044, &[1048648]);     // 2044 => [1048648]
-            map.insert(853, &[2097224]);     // 853 => [2097224]
-            map.insert(392, &[392]);     // 392 => [392]
-            map.insert(648, &[648]);     // 648 => [648]
-            map.insert(1160, &[1160]);     // 1160 => [1160]
-            map.insert(2016, &[2184]);     // 2016 => [2184]
-            map.insert(1047, &[4232]);     // 1047 => [4232]
-            map.insert(1758, &[8328]);     // 1758 => [8328]
-            map.insert(1643, &[16520]);     // 1643 => [16520]
-            map.insert(550, &[32904]);     // 550 => [32904]
-            map.insert(243, &[65672]);     // 243 => [65672]
-            map.insert(1369, &[131208]);     // 1369 => [131208]
-            map.insert(357, &[262280]);     // 357 => [262280]
-            map.insert(850, &[524424]);     // 850 => [524424]
-            map.insert(1852, &[1048712]);     // 1852 => [1048712]
-            map.insert(917, &[2097288]);     // 917 => [2097288]
-            map.insert(776, &[776]);     // 776 => [776]
-            map.insert(1288, &[1288]);     // 1288 => [1288]
-            map.insert(1632, &[2312]);     // 1632 => [2312]
-            map.insert(1431, &[4360]);     // 1431 => [4360]
-            map.insert(1886, &[8456]);     // 1886 => [8456]
-            map.insert(2027, &[16648]);     // 2027 => [16648]
-            map.insert(934, &[33032]);     // 934 => [33032]
-            map.insert(371, &[65800]);     // 371 => [65800]
-            map.insert(1241, &[131336]);     // 1241 => [131336]
-            map.insert(229, &[262408]);     // 229 => [262408]
-            map.insert(722, &[524552]);     // 722 => [524552]
-            map.insert(1724, &[1048840]);     // 1724 => [1048840]
-            map.insert(533, &[2097416]);     // 533 => [2097416]
-            map.insert(1544, &[1544]);     // 1544 => [1544]
-            map.insert(1376, &[2568]);     // 1376 => [2568]
-            map.insert(1687, &[4616]);     // 1687 => [4616]
-            map.insert(1118, &[8712]);     // 1118 => [8712]
-            map.insert(1259, &[16904]);     // 1259 => [16904]
-            map.insert(166, &[33288]);     // 166 => [33288]
-            map.insert(627, &[66056]);     // 627 => [66056]
-            map.insert(2009, &[131592]);     // 2009 => [131592]
-            map.insert(997, &[262664]);     // 997 => [262664]
-            map.insert(466, &[524808]);     // 466 => [524808]
-            map.insert(1468, &[1049096]);     // 1468 => [1049096]
-            map.insert(277, &[2097672]);     // 277 => [2097672]
-            map.insert(864, &[3080]);     // 864 => [3080]
-            map.insert(151, &[5128]);     // 151 => [5128]
-            map.insert(606, &[9224]);     // 606 => [9224]
-            map.insert(747, &[17416]);     // 747 => [17416]
-            map.insert(1702, &[33800]);     // 1702 => [33800]
-            map.insert(1139, &[66568]);     // 1139 => [66568]
-            map.insert(473, &[132104]);     // 473 => [132104]
-            map.insert(1509, &[263176]);     // 1509 => [263176]
-            map.insert(2002, &[525320]);     // 2002 => [525320]
-            map.insert(956, &[1049608]);     // 956 => [1049608]
-            map.insert(1813, &[2098184]);     // 1813 => [2098184]
-            map.insert(1023, &[6152]);     // 1023 => [6152]
-            map.insert(310, &[10248]);     // 310 => [10248]
-            map.insert(387, &[18440]);     // 387 => [18440]
-            map.insert(1486, &[34824]);     // 1486 => [34824]
-            map.insert(1819, &[67592]);     // 1819 => [67592]
-            map.insert(689, &[133128]);     // 689 => [133128]
-            map.insert(1677, &[264200]);     // 1677 => [264200]
-            map.insert(1210, &[526344]);     // 1210 => [526344]
-            map.insert(212, &[1050632]);     // 212 => [1050632]
-            map.insert(1149, &[2099208]);     // 1149 => [2099208]
-            map.insert(705, &[12296]);     // 705 => [12296]
-            map.insert(628, &[20488]);     // 628 => [20488]
-            map.insert(1593, &[36872]);     // 1593 => [36872]
-            map.insert(1260, &[69640]);     // 1260 => [69640]
-            map.insert(326, &[135176]);     // 326 => [135176]
-            map.insert(1402, &[266248]);     // 1402 => [266248]
-            map.insert(1869, &[528392]);     // 1869 => [528392]
-            map.insert(803, &[1052680]);     // 803 => [1052680]
-            map.insert(1930, &[2101256]);     // 1930 => [2101256]
-            map.insert(189, &[24584]);     // 189 => [24584]
-            map.insert(1264, &[40968]);     // 1264 => [40968]
-            map.insert(1573, &[73736]);     // 1573 => [73736]
-            map.insert(911, &[139272]);     // 911 => [139272]
-            map.insert(1971, &[270344]);     // 1971 => [270344]
-            map.insert(1412, &[532488]);     // 1412 => [532488]
-            map.insert(490, &[1056776]);     // 490 => [1056776]
-            map.insert(1347, &[2105352]);     // 1347 => [2105352]
-            map.insert(1093, &[49160]);     // 1093 => [49160]
-            map.insert(1680, &[81928]);     // 1680 => [81928]
-            map.insert(826, &[147464]);     // 826 => [147464]
-            map.insert(1798, &[278536]);     // 1798 => [278536]
-            map.insert(1329, &[540680]);     // 1329 => [540680]
-            map.insert(351, &[1064968]);     // 351 => [1064968]
-            map.insert(1526, &[2113544]);     // 1526 => [2113544]
-            map.insert(733, &[98312]);     // 733 => [98312]
-            map.insert(1911, &[163848]);     // 1911 => [163848]
-            map.insert(843, &[294920]);     // 843 => [294920]
-            map.insert(380, &[557064]);     // 380 => [557064]
-            map.insert(1298, &[1081352]);     // 1298 => [1081352]
-            map.insert(443, &[2129928]);     // 443 => [2129928]
-            map.insert(1442, &[196616]);     // 1442 => [196616]
-            map.insert(414, &[327688]);     // 414 => [327688]
-            map.insert(937, &[589832]);     // 937 => [589832]
-            map.insert(1991, &[1114120]);     // 1991 => [1114120]
-            map.insert(878, &[2162696]);     // 878 => [2162696]
-            map.insert(1076, &[393224]);     // 1076 => [393224]
-            map.insert(1539, &[655368]);     // 1539 => [655368]
-            map.insert(621, &[1179656]);     // 621 => [1179656]
-            map.insert(1732, &[2228232]);     // 1732 => [2228232]
-            map.insert(575, &[786440]);     // 575 => [786440]
-            map.insert(1617, &[1310728]);     // 1617 => [1310728]
-            map.insert(760, &[2359304]);     // 760 => [2359304]
-            map.insert(1126, &[1572872]);     // 1126 => [1572872]
-            map.insert(207, &[2621448]);     // 207 => [2621448]
-            map.insert(1185, &[3145736]);     // 1185 => [3145736]
-            map.insert(112, &[112]);     // 112 => [112]
-            map.insert(176, &[176]);     // 176 => [176]
-            map.insert(304, &[304]);     // 304 => [304]
-            map.insert(560, &[560]);     // 560 => [560]
-            map.insert(1072, &[1072]);     // 1072 => [1072]
-            map.insert(1880, &[2096]);     // 1880 => [2096]
-            map.insert(1199, &[4144]);     // 1199 => [4144]
-            map.insert(1638, &[8240]);     // 1638 => [8240]
-            map.insert(1747, &[16432]);     // 1747 => [16432]
-            map.insert(670, &[32816]);     // 670 => [32816]
-            map.insert(75, &[65584]);     // 75 => [65584]
-            map.insert(1505, &[131120]);     // 1505 => [131120]
-            map.insert(477, &[262192]);     // 477 => [262192]
-            map.insert(1002, &[524336]);     // 1002 => [524336]
-            map.insert(1924, &[1048624]);     // 1924 => [1048624]
-            map.insert(813, &[2097200]);     // 813 => [2097200]
-            map.insert(208, &[208]);     // 208 => [208]
-            map.insert(336, &[336]);     // 336 => [336]
-            map.insert(592, &[592]);     // 592 => [592]
-            map.insert(1104, &[1104]);     // 1104 => [1104]
-            map.insert(1848, &[2128]);     // 1848 => [2128]
-            map.insert(1231, &[4176]);     // 1231 => [4176]
-            map.insert(1542, &[8272]);     // 1542 => [8272]
-            map.insert(1715, &[16464]);     // 1715 => [16464]
-            map.insert(766, &[32848]);     // 766 => [32848]
-            map.insert(43, &[65616]);     // 43 => [65616]
-            map.insert(1409, &[131152]);     // 1409 => [131152]
-            map.insert(445, &[262224]);     // 445 => [262224]
-            map.insert(906, &[524368]);     // 906 => [524368]
-            map.insert(2020, &[1048656]);     // 2020 => [1048656]
-            map.insert(845, &[2097232]);     // 845 => [2097232]
-            map.insert(400, &[400]);     // 400 => [400]
-            map.insert(656, &[656]);     // 656 => [656]
-            map.insert(1168, &[1168]);     // 1168 => [1168]
-            map.insert(2040, &[2192]);     // 2040 => [2192]
-            map.insert(1039, &[4240]);     // 1039 => [4240]
-            map.insert(1734, &[8336]);     // 1734 => [8336]
-            map.insert(1651, &[16528]);     // 1651 => [16528]
-            map.insert(574, &[32912]);     // 574 => [32912]
-            map.insert(235, &[65680]);     // 235 => [65680]
-            map.insert(1345, &[131216]);     // 1345 => [131216]
-            map.insert(381, &[262288]);     // 381 => [262288]
-            map.insert(842, &[524432]);     // 842 => [524432]
-            map.insert(1828, &[1048720]);     // 1828 => [1048720]
-            map.insert(909, &[2097296]);     // 909 => [2097296]
-            map.insert(784, &[784]);     // 784 => [784]
-            map.insert(1296, &[1296]);     // 1296 => [1296]
-            map.insert(1656, &[2320]);     // 1656 => [2320]
-            map.insert(1423, &[4368]);     // 1423 => [4368]
-            map.insert(1862, &[8464]);     // 1862 => [8464]
-            map.insert(2035, &[16656]);     // 2035 => [16656]
-            map.insert(958, &[33040]);     // 958 => [33040]
-            map.insert(363, &[65808]);     // 363 => [65808]
-            map.insert(1217, &[131344]);     // 1217 => [131344]
-            map.insert(253, &[262416]);     // 253 => [262416]
-            map.insert(714, &[524560]);     // 714 => [524560]
-            map.insert(1700, &[1048848]);     // 1700 => [1048848]
-            map.insert(525, &[2097424]);     // 525 => [2097424]
-            map.insert(1552, &[1552]);     // 1552 => [1552]
-            map.insert(1400, &[2576]);     // 1400 => [2576]
-            map.insert(1679, &[4624]);     // 1679 => [4624]
-            map.insert(1094, &[8720]);     // 1094 => [8720]
-            map.insert(1267, &[16912]);     // 1267 => [16912]
-            map.insert(190, &[33296]);     // 190 => [33296]
-            map.insert(619, &[66064]);     // 619 => [66064]
-            map.insert(1985, &[131600]);     // 1985 => [131600]
-            map.insert(1021, &[262672]);     // 1021 => [262672]
-            map.insert(458, &[524816]);     // 458 => [524816]
-            map.insert(1444, &[1049104]);     // 1444 => [1049104]
-            map.insert(269, &[2097680]);     // 269 => [2097680]
-            map.insert(888, &[3088]);     // 888 => [3088]
-            map.insert(143, &[5136]);     // 143 => [5136]
-            map.insert(582, &[9232]);     // 582 => [9232]
-            map.insert(755, &[17424]);     // 755 => [17424]
-            map.insert(1726, &[33808]);     // 1726 => [33808]
-            map.insert(1131, &[66576]);     // 1131 => [66576]
-            map.insert(449, &[132112]);     // 449 => [132112]
-            map.insert(1533, &[263184]);     // 1533 => [263184]
-            map.insert(1994, &[525328]);     // 1994 => [525328]
-            map.insert(932, &[1049616]);     // 932 => [1049616]
-            map.insert(1805, &[2098192]);     // 1805 => [2098192]
-            map.insert(999, &[6160]);     // 999 => [6160]
-            map.insert(302, &[10256]);     // 302 => [10256]
-            map.insert(411, &[18448]);     // 411 => [18448]
-            map.insert(1494, &[34832]);     // 1494 => [34832]
-            map.insert(1795, &[67600]);     // 1795 => [67600]
-            map.insert(681, &[133136]);     // 681 => [133136]
-            map.insert(1685, &[264208]);     // 1685 => [264208]
-            map.insert(1186, &[526352]);     // 1186 => [526352]
-            map.insert(204, &[1050640]);     // 204 => [1050640]
-            map.insert(1125, &[2099216]);     // 1125 => [2099216]
-            map.insert(729, &[12304]);     // 729 => [12304]
-            map.insert(620, &[20496]);     // 620 => [20496]
-            map.insert(1569, &[36880]);     // 1569 => [36880]
-            map.insert(1268, &[69648]);     // 1268 => [69648]
-            map.insert(350, &[135184]);     // 350 => [135184]
-            map.insert(1378, &[266256]);     // 1378 => [266256]
-            map.insert(1877, &[528400]);     // 1877 => [528400]
-            map.insert(827, &[1052688]);     // 827 => [1052688]
-            map.insert(1938, &[2101264]);     // 1938 => [2101264]
-            map.insert(165, &[24592]);     // 165 => [24592]
-            map.insert(1256, &[40976]);     // 1256 => [40976]
-            map.insert(1597, &[73744]);     // 1597 => [73744]
-            map.insert(919, &[139280]);     // 919 => [139280]
-            map.insert(1963, &[270352]);     // 1963 => [270352]
-            map.insert(1436, &[532496]);     // 1436 => [532496]
-            map.insert(498, &[1056784]);     // 498 => [1056784]
-            map.insert(1371, &[2105360]);     // 1371 => [2105360]
-            map.insert(1117, &[49168]);     // 1117 => [49168]
-            map.insert(1672, &[81936]);     // 1672 => [81936]
-            map.insert(802, &[147472]);     // 802 => [147472]
-            map.insert(1822, &[278544]);     // 1822 => [278544]
-            map.insert(1321, &[540688]);     // 1321 => [540688]
-            map.insert(327, &[1064976]);     // 327 => [1064976]
-            map.insert(1518, &[2113552]);     // 1518 => [2113552]
-            map.insert(709, &[98320]);     // 709 => [98320]
-            map.insert(1903, &[163856]);     // 1903 => [163856]
-            map.insert(851, &[294928]);     // 851 => [294928]
-            map.insert(356, &[557072]);     // 356 => [557072]
-            map.insert(1290, &[1081360]);     // 1290 => [1081360]
-            map.insert(419, &[2129936]);     // 419 => [2129936]
-            map.insert(1466, &[196624]);     // 1466 => [196624]
-            map.insert(390, &[327696]);     // 390 => [327696]
-            map.insert(945, &[589840]);     // 945 => [589840]
-            map.insert(2015, &[1114128]);     // 2015 => [1114128]
-            map.insert(886, &[2162704]);     // 886 => [2162704]
-            map.insert(1068, &[393232]);     // 1068 => [393232]
-            map.insert(1563, &[655376]);     // 1563 => [655376]
-            map.insert(629, &[1179664]);     // 629 => [1179664]
-            map.insert(1756, &[2228240]);     // 1756 => [2228240]
-            map.insert(551, &[786448]);     // 551 => [786448]
-            map.insert(1609, &[1310736]);     // 1609 => [1310736]
-            map.insert(736, &[2359312]);     // 736 => [2359312]
-            map.insert(1150, &[1572880]);     // 1150 => [1572880]
-            map.insert(215, &[2621456]);     // 215 => [2621456]
-            map.insert(1209, &[3145744]);     // 1209 => [3145744]
-            map.insert(224, &[224]);     // 224 => [224]
-            map.insert(352, &[352]);     // 352 => [352]
-            map.insert(608, &[608]);     // 608 => [608]
-            map.insert(1120, &[1120]);     // 1120 => [1120]
-            map.insert(1800, &[2144]);     // 1800 => [2144]
-            map.insert(1279, &[4192]);     // 1279 => [4192]
-            map.insert(1590, &[8288]);     // 1590 => [8288]
-            map.insert(1667, &[16480]);     // 1667 => [16480]
-            map.insert(718, &[32864]);     // 718 => [32864]
-            map.insert(27, &[65632]);     // 27 => [65632]
-            map.insert(1457, &[131168]);     // 1457 => [131168]
-            map.insert(397, &[262240]);     // 397 => [262240]
-            map.insert(954, &[524384]);     // 954 => [524384]
-            map.insert(2004, &[1048672]);     // 2004 => [1048672]
-            map.insert(893, &[2097248]);     // 893 => [2097248]
-            map.insert(416, &[416]);     // 416 => [416]
-            map.insert(672, &[672]);     // 672 => [672]
-            map.insert(1184, &[1184]);     // 1184 => [1184]
-            map.insert(1992, &[2208]);     // 1992 => [2208]
-            map.insert(1087, &[4256]);     // 1087 => [4256]
-            map.insert(1782, &[8352]);     // 1782 => [8352]
-            map.insert(1603, &[16544]);     // 1603 => [16544]
-            map.insert(526, &[32928]);     // 526 => [32928]
-            map.insert(219, &[65696]);     // 219 => [65696]
-            map.insert(1393, &[131232]);     // 1393 => [131232]
-            map.insert(333, &[262304]);     // 333 => [262304]
-            map.insert(890, &[524448]);     // 890 => [524448]
-            map.insert(1812, &[1048736]);     // 1812 => [1048736]
-            map.insert(957, &[2097312]);     // 957 => [2097312]
-            map.insert(800, &[800]);     // 800 => [800]
-            map.insert(1312, &[1312]);     // 1312 => [1312]
-            map.insert(1608, &[2336]);     // 1608 => [2336]
-            map.insert(1471, &[4384]);     // 1471 => [4384]
-            map.insert(1910, &[8480]);     // 1910 => [8480]
-            map.insert(1987, &[16672]);     // 1987 => [16672]
-            map.insert(910, &[33056]);     // 910 => [33056]
-            map.insert(347, &[65824]);     // 347 => [65824]
-            map.insert(1265, &[131360]);     // 1265 => [131360]
-            map.insert(205, &[262432]);     // 205 => [262432]
-            map.insert(762, &[524576]);     // 762 => [524576]
-            map.insert(1684, &[1048864]);     // 1684 => [1048864]
-            map.insert(573, &[2097440]);     // 573 => [2097440]
-            map.insert(1568, &[1568]);     // 1568 => [1568]
-            map.insert(1352, &[2592]);     // 1352 => [2592]
-            map.insert(1727, &[4640]);     // 1727 => [4640]
-            map.insert(1142, &[8736]);     // 1142 => [8736]
-            map.insert(1219, &[16928]);     // 1219 => [16928]
-            map.insert(142, &[33312]);     // 142 => [33312]
-            map.insert(603, &[66080]);     // 603 => [66080]
-            map.insert(2033, &[131616]);     // 2033 => [131616]
-            map.insert(973, &[262688]);     // 973 => [262688]
-            map.insert(506, &[524832]);     // 506 => [524832]
-            map.insert(1428, &[1049120]);     // 1428 => [1049120]
-            map.insert(317, &[2097696]);     // 317 => [2097696]
-            map.insert(840, &[3104]);     // 840 => [3104]
-            map.insert(191, &[5152]);     // 191 => [5152]
-            map.insert(630, &[9248]);     // 630 => [9248]
-            map.insert(707, &[17440]);     // 707 => [17440]
-            map.insert(1678, &[33824]);     // 1678 => [33824]
-            map.insert(1115, &[66592]);     // 1115 => [66592]
-            map.insert(497, &[132128]);     // 497 => [132128]
-            map.insert(1485, &[263200]);     // 1485 => [263200]
-            map.insert(2042, &[525344]);     // 2042 => [525344]
-            map.insert(916, &[1049632]);     // 916 => [1049632]
-            map.insert(1853, &[2098208]);     // 1853 => [2098208]
-            map.insert(983, &[6176]);     // 983 => [6176]
-            map.insert(286, &[10272]);     // 286 => [10272]
-            map.insert(427, &[18464]);     // 427 => [18464]
-            map.insert(1510, &[34848]);     // 1510 => [34848]
-            map.insert(1843, &[67616]);     // 1843 => [67616]
-            map.insert(665, &[133152]);     // 665 => [133152]
-            map.insert(1701, &[264224]);     // 1701 => [264224]
-            map.insert(1170, &[526368]);     // 1170 => [526368]
-            map.insert(252, &[1050656]);     // 252 => [1050656]
-            map.insert(1109, &[2099232]);     // 1109 => [2099232]
-            map.insert(745, &[12320]);     // 745 => [12320]
-            map.insert(604, &[20512]);     // 604 => [20512]
-            map.insert(1553, &[36896]);     // 1553 => [36896]
-            map.insert(1220, &[69664]);     // 1220 => [69664]
-            map.insert(366, &[135200]);     // 366 => [135200]
-            map.insert(1362, &[266272]);     // 1362 => [266272]
-            map.insert(1893, &[528416]);     // 1893 => [528416]
-            map.insert(779, &[1052704]);     // 779 => [1052704]
-            map.insert(1954, &[2101280]);     // 1954 => [2101280]
-            map.insert(149, &[24608]);     // 149 => [24608]
-            map.insert(1240, &[40992]);     // 1240 => [40992]
-            map.insert(1549, &[73760]);     // 1549 => [73760]
-            map.insert(935, &[139296]);     // 935 => [139296]
-            map.insert(1947, &[270368]);     // 1947 => [270368]
-            map.insert(1452, &[532512]);     // 1452 => [532512]
-            map.insert(450, &[1056800]);     // 450 => [1056800]
-            map.insert(1387, &[2105376]);     // 1387 => [2105376]
-            map.insert(1133, &[49184]);     // 1133 => [49184]
-            map.insert(1720, &[81952]);     // 1720 => [81952]
-            map.insert(786, &[147488]);     // 786 => [147488]
-            map.insert(1838, &[278560]);     // 1838 => [278560]
-            map.insert(1305, &[540704]);     // 1305 => [540704]
-            map.insert(375, &[1064992]);     // 375 => [1064992]
-            map.insert(1502, &[2113568]);     // 1502 => [2113568]
-            map.insert(757, &[98336]);     // 757 => [98336]
-            map.insert(1887, &[163872]);     // 1887 => [163872]
-            map.insert(867, &[294944]);     // 867 => [294944]
-            map.insert(340, &[557088]);     // 340 => [557088]
-            map.insert(1338, &[1081376]);     // 1338 => [1081376]
-            map.insert(403, &[2129952]);     // 403 => [2129952]
-            map.insert(1418, &[196640]);     // 1418 => [196640]
-            map.insert(438, &[327712]);     // 438 => [327712]
-            map.insert(897, &[589856]);     // 897 => [589856]
-            map.insert(2031, &[1114144]);     // 2031 => [1114144]
-            map.insert(838, &[2162720]);     // 838 => [2162720]
-            map.insert(1052, &[393248]);     // 1052 => [393248]
-            map.insert(1579, &[655392]);     // 1579 => [655392]
-            map.insert(581, &[1179680]);     // 581 => [1179680]
-            map.insert(1772, &[2228256]);     // 1772 => [2228256]
-            map.insert(535, &[786464]);     // 535 => [786464]
-            map.insert(1657, &[1310752]);     // 1657 => [1310752]
-            map.insert(720, &[2359328]);     // 720 => [2359328]
-            map.insert(1102, &[1572896]);     // 1102 => [1572896]
-            map.insert(231, &[2621472]);     // 231 => [2621472]
-            map.insert(1161, &[3145760]);     // 1161 => [3145760]
-            map.insert(448, &[448]);     // 448 => [448]
-            map.insert(704, &[704]);     // 704 => [704]
-            map.insert(1216, &[1216]);     // 1216 => [1216]
-            map.insert(1960, &[2240]);     // 1960 => [2240]
-            map.insert(1119, &[4288]);     // 1119 => [4288]
-            map.insert(1686, &[8384]);     // 1686 => [8384]
-            map.insert(1571, &[16576]);     // 1571 => [16576]
-            map.insert(622, &[32960]);     // 622 => [32960]
-            map.insert(187, &[65728]);     // 187 => [65728]
-            map.insert(1297, &[131264]);     // 1297 => [131264]
-            map.insert(301, &[262336]);     // 301 => [262336]
-            map.insert(794, &[524480]);     // 794 => [524480]
-            map.insert(1908, &[1048768]);     // 1908 => [1048768]
-            map.insert(989, &[2097344]);     // 989 => [2097344]
-            map.insert(832, &[832]);     // 832 => [832]
-            map.insert(1344, &[1344]);     // 1344 => [1344]
-            map.insert(1576, &[2368]);     // 1576 => [2368]
-            map.insert(1503, &[4416]);     // 1503 => [4416]
-            map.insert(1814, &[8512]);     // 1814 => [8512]
-            map.insert(1955, &[16704]);     // 1955 => [16704]
-            map.insert(1006, &[33088]);     // 1006 => [33088]
-            map.insert(315, &[65856]);     // 315 => [65856]
-            map.insert(1169, &[131392]);     // 1169 => [131392]
-            map.insert(173, &[262464]);     // 173 => [262464]
-            map.insert(666, &[524608]);     // 666 => [524608]
-            map.insert(1780, &[1048896]);     // 1780 => [1048896]
-            map.insert(605, &[2097472]);     // 605 => [2097472]
-            map.insert(1600, &[1600]);     // 1600 => [1600]
-            map.insert(1320, &[2624]);     // 1320 => [2624]
-            map.insert(1759, &[4672]);     // 1759 => [4672]
-            map.insert(1046, &[8768]);     // 1046 => [8768]
-            map.insert(1187, &[16960]);     // 1187 => [16960]
-            map.insert(238, &[33344]);     // 238 => [33344]
-            map.insert(571, &[66112]);     // 571 => [66112]
-            map.insert(1937, &[131648]);     // 1937 => [131648]
-            map.insert(941, &[262720]);     // 941 => [262720]
-            map.insert(410, &[524864]);     // 410 => [524864]
-            map.insert(1524, &[1049152]);     // 1524 => [1049152]
-            map.insert(349, &[2097728]);     // 349 => [2097728]
-            map.insert(808, &[3136]);     // 808 => [3136]
-            map.insert(223, &[5184]);     // 223 => [5184]
-            map.insert(534, &[9280]);     // 534 => [9280]
-            map.insert(675, &[17472]);     // 675 => [17472]
-            map.insert(1774, &[33856]);     // 1774 => [33856]
-            map.insert(1083, &[66624]);     // 1083 => [66624]
-            map.insert(401, &[132160]);     // 401 => [132160]
-            map.insert(1453, &[263232]);     // 1453 => [263232]
-            map.insert(1946, &[525376]);     // 1946 => [525376]
-            map.insert(1012, &[1049664]);     // 1012 => [1049664]
-            map.insert(1885, &[2098240]);     // 1885 => [2098240]
-            map.insert(951, &[6208]);     // 951 => [6208]
-            map.insert(382, &[10304]);     // 382 => [10304]
-            map.insert(459, &[18496]);     // 459 => [18496]
-            map.insert(1414, &[34880]);     // 1414 => [34880]
-            map.insert(1875, &[67648]);     // 1875 => [67648]
-            map.insert(761, &[133184]);     // 761 => [133184]
-            map.insert(1733, &[264256]);     // 1733 => [264256]
-            map.insert(1266, &[526400]);     // 1266 => [526400]
-            map.insert(156, &[1050688]);     // 156 => [1050688]
-            map.insert(1077, &[2099264]);     // 1077 => [2099264]
-            map.insert(649, &[12352]);     // 649 => [12352]
-            map.insert(572, &[20544]);     // 572 => [20544]
-            map.insert(1649, &[36928]);     // 1649 => [36928]
-            map.insert(1188, &[69696]);     // 1188 => [69696]
-            map.insert(270, &[135232]);     // 270 => [135232]
-            map.insert(1330, &[266304]);     // 1330 => [266304]
-            map.insert(1797, &[528448]);     // 1797 => [528448]
-            map.insert(875, &[1052736]);     // 875 => [1052736]
-            map.insert(1986, &[2101312]);     // 1986 => [2101312]
-            map.insert(245, &[24640]);     // 245 => [24640]
-            map.insert(1208, &[41024]);     // 1208 => [41024]
-            map.insert(1645, &[73792]);     // 1645 => [73792]
-            map.insert(967, &[139328]);     // 967 => [139328]
-            map.insert(2043, &[270400]);     // 2043 => [270400]
-            map.insert(1484, &[532544]);     // 1484 => [532544]
-            map.insert(418, &[1056832]);     // 418 => [1056832]
-            map.insert(1291, &[2105408]);     // 1291 => [2105408]
-            map.insert(1037, &[49216]);     // 1037 => [49216]
-            map.insert(1752, &[81984]);     // 1752 => [81984]
-            map.insert(882, &[147520]);     // 882 => [147520]
-            map.insert(1870, &[278592]);     // 1870 => [278592]
-            map.insert(1401, &[540736]);     // 1401 => [540736]
-            map.insert(279, &[1065024]);     // 279 => [1065024]
-            map.insert(1470, &[2113600]);     // 1470 => [2113600]
-            map.insert(661, &[98368]);     // 661 => [98368]
-            map.insert(1855, &[163904]);     // 1855 => [163904]
-            map.insert(771, &[294976]);     // 771 => [294976]
-            map.insert(308, &[557120]);     // 308 => [557120]
-            map.insert(1370, &[1081408]);     // 1370 => [1081408]
-            map.insert(499, &[2129984]);     // 499 => [2129984]
-            map.insert(1514, &[196672]);     // 1514 => [196672]
-            map.insert(470, &[327744]);     // 470 => [327744]
-            map.insert(993, &[589888]);     // 993 => [589888]
-            map.insert(1935, &[1114176]);     // 1935 => [1114176]
-            map.insert(806, &[2162752]);     // 806 => [2162752]
-            map.insert(1148, &[393280]);     // 1148 => [393280]
-            map.insert(1611, &[655424]);     // 1611 => [655424]
-            map.insert(549, &[1179712]);     // 549 => [1179712]
-            map.insert(1676, &[2228288]);     // 1676 => [2228288]
-            map.insert(631, &[786496]);     // 631 => [786496]
-            map.insert(1561, &[1310784]);     // 1561 => [1310784]
-            map.insert(688, &[2359360]);     // 688 => [2359360]
-            map.insert(1070, &[1572928]);     // 1070 => [1572928]
-            map.insert(135, &[2621504]);     // 135 => [2621504]
-            map.insert(1257, &[3145792]);     // 1257 => [3145792]
-            map.insert(896, &[896]);     // 896 => [896]
-            map.insert(1408, &[1408]);     // 1408 => [1408]
-            map.insert(1768, &[2432]);     // 1768 => [2432]
-            map.insert(1311, &[4480]);     // 1311 => [4480]
-            map.insert(2006, &[8576]);     // 2006 => [8576]
-            map.insert(1891, &[16768]);     // 1891 => [16768]
-            map.insert(814, &[33152]);     // 814 => [33152]
-            map.insert(507, &[65920]);     // 507 => [65920]
-            map.insert(1105, &[131456]);     // 1105 => [131456]
-            map.insert(109, &[262528]);     // 109 => [262528]
-            map.insert(602, &[524672]);     // 602 => [524672]
-            map.insert(1588, &[1048960]);     // 1588 => [1048960]
-            map.insert(669, &[2097536]);     // 669 => [2097536]
-            map.insert(1664, &[1664]);     // 1664 => [1664]
-            map.insert(1512, &[2688]);     // 1512 => [2688]
-            map.insert(1567, &[4736]);     // 1567 => [4736]
-            map.insert(1238, &[8832]);     // 1238 => [8832]
-            map.insert(1123, &[17024]);     // 1123 => [17024]
-            map.insert(46, &[33408]);     // 46 => [33408]
-            map.insert(763, &[66176]);     // 763 => [66176]
-            map.insert(1873, &[131712]);     // 1873 => [131712]
-            map.insert(877, &[262784]);     // 877 => [262784]
-            map.insert(346, &[524928]);     // 346 => [524928]
-            map.insert(1332, &[1049216]);     // 1332 => [1049216]
-            map.insert(413, &[2097792]);     // 413 => [2097792]
-            map.insert(1000, &[3200]);     // 1000 => [3200]
-            map.insert(31, &[5248]);     // 31 => [5248]
-            map.insert(726, &[9344]);     // 726 => [9344]
-            map.insert(611, &[17536]);     // 611 => [17536]
-            map.insert(1582, &[33920]);     // 1582 => [33920]
-            map.insert(1275, &[66688]);     // 1275 => [66688]
-            map.insert(337, &[132224]);     // 337 => [132224]
-            map.insert(1389, &[263296]);     // 1389 => [263296]
-            map.insert(1882, &[525440]);     // 1882 => [525440]
-            map.insert(820, &[1049728]);     // 820 => [1049728]
-            map.insert(1949, &[2098304]);     // 1949 => [2098304]
-            map.insert(887, &[6272]);     // 887 => [6272]
-            map.insert(446, &[10368]);     // 446 => [10368]
-            map.insert(267, &[18560]);     // 267 => [18560]
-            map.insert(1350, &[34944]);     // 1350 => [34944]
-            map.insert(1939, &[67712]);     // 1939 => [67712]
-            map.insert(569, &[133248]);     // 569 => [133248]
-            map.insert(1541, &[264320]);     // 1541 => [264320]
-            map.insert(1074, &[526464]);     // 1074 => [526464]
-            map.insert(92, &[1050752]);     // 92 => [1050752]
-            map.insert(1269, &[2099328]);     // 1269 => [2099328]
-            map.insert(585, &[12416]);     // 585 => [12416]
-            map.insert(764, &[20608]);     // 764 => [20608]
-            map.insert(1713, &[36992]);     // 1713 => [36992]
-            map.insert(1124, &[69760]);     // 1124 => [69760]
-            map.insert(462, &[135296]);     // 462 => [135296]
-            map.insert(1522, &[266368]);     // 1522 => [266368]
-            map.insert(1989, &[528512]);     // 1989 => [528512]
-            map.insert(939, &[1052800]);     // 939 => [1052800]
-            map.insert(1794, &[2101376]);     // 1794 => [2101376]
-            map.insert(53, &[24704]);     // 53 => [24704]
-            map.insert(1144, &[41088]);     // 1144 => [41088]
-            map.insert(1709, &[73856]);     // 1709 => [73856]
-            map.insert(775, &[139392]);     // 775 => [139392]
-            map.insert(1851, &[270464]);     // 1851 => [270464]
-            map.insert(1292, &[532608]);     // 1292 => [532608]
-            map.insert(354, &[1056896]);     // 354 => [1056896]
-            map.insert(1483, &[2105472]);     // 1483 => [2105472]
-            map.insert(1229, &[49280]);     // 1229 => [49280]
-            map.insert(1560, &[82048]);     // 1560 => [82048]
-            map.insert(946, &[147584]);     // 946 => [147584]
-            map.insert(1934, &[278656]);     // 1934 => [278656]
-            map.insert(1465, &[540800]);     // 1465 => [540800]
-            map.insert(471, &[1065088]);     // 471 => [1065088]
-            map.insert(1406, &[2113664]);     // 1406 => [2113664]
-            map.insert(597, &[98432]);     // 597 => [98432]
-            map.insert(2047, &[163968]);     // 2047 => [163968]
-            map.insert(963, &[295040]);     // 963 => [295040]
-            map.insert(500, &[557184]);     // 500 => [557184]
-            map.insert(1434, &[1081472]);     // 1434 => [1081472]
-            map.insert(307, &[2130048]);     // 307 => [2130048]
-            map.insert(1322, &[196736]);     // 1322 => [196736]
-            map.insert(278, &[327808]);     // 278 => [327808]
-            map.insert(801, &[589952]);     // 801 => [589952]
-            map.insert(1871, &[1114240]);     // 1871 => [1114240]
-            map.insert(998, &[2162816]);     // 998 => [2162816]
-            map.insert(1212, &[393344]);     // 1212 => [393344]
-            map.insert(1675, &[655488]);     // 1675 => [655488]
-            map.insert(741, &[1179776]);     // 741 => [1179776]
-            map.insert(1612, &[2228352]);     // 1612 => [2228352]
-            map.insert(695, &[786560]);     // 695 => [786560]
-            map.insert(1753, &[1310848]);     // 1753 => [1310848]
-            map.insert(624, &[2359424]);     // 624 => [2359424]
-            map.insert(1262, &[1572992]);     // 1262 => [1572992]
-            map.insert(71, &[2621568]);     // 71 => [2621568]
-            map.insert(1065, &[3145856]);     // 1065 => [3145856]
-            map.insert(1792, &[1792]);     // 1792 => [1792]
-            map.insert(1128, &[2816]);     // 1128 => [2816]
-            map.insert(1951, &[4864]);     // 1951 => [4864]
-            map.insert(1366, &[8960]);     // 1366 => [8960]
-            map.insert(1507, &[17152]);     // 1507 => [17152]
-            map.insert(430, &[33536]);     // 430 => [33536]
-            map.insert(891, &[66304]);     // 891 => [66304]
-            map.insert(1745, &[131840]);     // 1745 => [131840]
-            map.insert(749, &[262912]);     // 749 => [262912]
-            map.insert(218, &[525056]);     // 218 => [525056]
-            map.insert(1204, &[1049344]);     // 1204 => [1049344]
-            map.insert(29, &[2097920]);     // 29 => [2097920]
-            map.insert(616, &[3328]);     // 616 => [3328]
-            map.insert(415, &[5376]);     // 415 => [5376]
-            map.insert(854, &[9472]);     // 854 => [9472]
-            map.insert(995, &[17664]);     // 995 => [17664]
-            map.insert(1966, &[34048]);     // 1966 => [34048]
-            map.insert(1403, &[66816]);     // 1403 => [66816]
-            map.insert(209, &[132352]);     // 209 => [132352]
-            map.insert(1261, &[263424]);     // 1261 => [263424]
-            map.insert(1754, &[525568]);     // 1754 => [525568]
-            map.insert(692, &[1049856]);     // 692 => [1049856]
-            map.insert(1565, &[2098432]);     // 1565 => [2098432]
-            map.insert(759, &[6400]);     // 759 => [6400]
-            map.insert(62, &[10496]);     // 62 => [10496]
-            map.insert(139, &[18688]);     // 139 => [18688]
-            map.insert(1222, &[35072]);     // 1222 => [35072]
-            map.insert(1555, &[67840]);     // 1555 => [67840]
-            map.insert(953, &[133376]);     // 953 => [133376]
-            map.insert(1925, &[264448]);     // 1925 => [264448]
-            map.insert(1458, &[526592]);     // 1458 => [526592]
-            map.insert(476, &[1050880]);     // 476 => [1050880]
-            map.insert(1397, &[2099456]);     // 1397 => [2099456]
-            map.insert(969, &[12544]);     // 969 => [12544]
-            map.insert(892, &[20736]);     // 892 => [20736]
-            map.insert(1841, &[37120]);     // 1841 => [37120]
-            map.insert(1508, &[69888]);     // 1508 => [69888]
-            map.insert(78, &[135424]);     // 78 => [135424]
-            map.insert(1138, &[266496]);     // 1138 => [266496]
-            map.insert(1605, &[528640]);     // 1605 => [528640]
-            map.insert(555, &[1052928]);     // 555 => [1052928]
-            map.insert(1666, &[2101504]);     // 1666 => [2101504]
-            map.insert(437, &[24832]);     // 437 => [24832]
-            map.insert(1528, &[41216]);     // 1528 => [41216]
-            map.insert(1837, &[73984]);     // 1837 => [73984]
-            map.insert(647, &[139520]);     // 647 => [139520]
-            map.insert(1723, &[270592]);     // 1723 => [270592]
-            map.insert(1164, &[532736]);     // 1164 => [532736]
-            map.insert(226, &[1057024]);     // 226 => [1057024]
-            map.insert(1099, &[2105600]);     // 1099 => [2105600]
-            map.insert(1357, &[49408]);     // 1357 => [49408]
-            map.insert(1944, &[82176]);     // 1944 => [82176]
-            map.insert(562, &[147712]);     // 562 => [147712]
-            map.insert(1550, &[278784]);     // 1550 => [278784]
-            map.insert(1081, &[540928]);     // 1081 => [540928]
-            map.insert(87, &[1065216]);     // 87 => [1065216]
-            map.insert(1278, &[2113792]);     // 1278 => [2113792]
-            map.insert(981, &[98560]);     // 981 => [98560]
-            map.insert(1663, &[164096]);     // 1663 => [164096]
-            map.insert(579, &[295168]);     // 579 => [295168]
-            map.insert(116, &[557312]);     // 116 => [557312]
-            map.insert(1050, &[1081600]);     // 1050 => [1081600]
-            map.insert(179, &[2130176]);     // 179 => [2130176]
-            map.insert(1194, &[196864]);     // 1194 => [196864]
-            map.insert(150, &[327936]);     // 150 => [327936]
-            map.insert(673, &[590080]);     // 673 => [590080]
-            map.insert(1743, &[1114368]);     // 1743 => [1114368]
-            map.insert(614, &[2162944]);     // 614 => [2162944]
-            map.insert(1340, &[393472]);     // 1340 => [393472]
-            map.insert(1803, &[655616]);     // 1803 => [655616]
-            map.insert(869, &[1179904]);     // 869 => [1179904]
-            map.insert(1996, &[2228480]);     // 1996 => [2228480]
-            map.insert(823, &[786688]);     // 823 => [786688]
-            map.insert(1881, &[1310976]);     // 1881 => [1310976]
-            map.insert(1008, &[2359552]);     // 1008 => [2359552]
-            map.insert(1390, &[1573120]);     // 1390 => [1573120]
-            map.insert(455, &[2621696]);     // 455 => [2621696]
-            map.insert(1449, &[3145984]);     // 1449 => [3145984]
-            map.insert(360, &[3584]);     // 360 => [3584]
-            map.insert(671, &[5632]);     // 671 => [5632]
-            map.insert(86, &[9728]);     // 86 => [9728]
-            map.insert(227, &[17920]);     // 227 => [17920]
-            map.insert(1198, &[34304]);     // 1198 => [34304]
-            map.insert(1659, &[67072]);     // 1659 => [67072]
-            map.insert(977, &[132608]);     // 977 => [132608]
-            map.insert(2029, &[263680]);     // 2029 => [263680]
-            map.insert(1498, &[525824]);     // 1498 => [525824]
-            map.insert(436, &[1050112]);     // 436 => [1050112]
-            map.insert(1309, &[2098688]);     // 1309 => [2098688]
-            map.insert(503, &[6656]);     // 503 => [6656]
-            map.insert(830, &[10752]);     // 830 => [10752]
-            map.insert(907, &[18944]);     // 907 => [18944]
-            map.insert(1990, &[35328]);     // 1990 => [35328]
-            map.insert(1299, &[68096]);     // 1299 => [68096]
-            map.insert(185, &[133632]);     // 185 => [133632]
-            map.insert(1157, &[264704]);     // 1157 => [264704]
-            map.insert(1714, &[526848]);     // 1714 => [526848]
-            map.insert(732, &[1051136]);     // 732 => [1051136]
-            map.insert(1653, &[2099712]);     // 1653 => [2099712]
-            map.insert(201, &[12800]);     // 201 => [12800]
-            map.insert(124, &[20992]);     // 124 => [20992]
-            map.insert(1073, &[37376]);     // 1073 => [37376]
-            map.insert(1764, &[70144]);     // 1764 => [70144]
-            map.insert(846, &[135680]);     // 846 => [135680]
-            map.insert(1906, &[266752]);     // 1906 => [266752]
-            map.insert(1349, &[528896]);     // 1349 => [528896]
-            map.insert(299, &[1053184]);     // 299 => [1053184]
-            map.insert(1410, &[2101760]);     // 1410 => [2101760]
-            map.insert(693, &[25088]);     // 693 => [25088]
-            map.insert(1784, &[41472]);     // 1784 => [41472]
-            map.insert(1069, &[74240]);     // 1069 => [74240]
-            map.insert(391, &[139776]);     // 391 => [139776]
-            map.insert(1467, &[270848]);     // 1467 => [270848]
-            map.insert(1932, &[532992]);     // 1932 => [532992]
-            map.insert(994, &[1057280]);     // 994 => [1057280]
-            map.insert(1867, &[2105856]);     // 1867 => [2105856]
-            map.insert(1613, &[49664]);     // 1613 => [49664]
-            map.insert(1176, &[82432]);     // 1176 => [82432]
-            map.insert(306, &[147968]);     // 306 => [147968]
-            map.insert(1294, &[279040]);     // 1294 => [279040]
-            map.insert(1849, &[541184]);     // 1849 => [541184]
-            map.insert(855, &[1065472]);     // 855 => [1065472]
-            map.insert(2046, &[2114048]);     // 2046 => [2114048]
-            map.insert(213, &[98816]);     // 213 => [98816]
-            map.insert(1407, &[164352]);     // 1407 => [164352]
-            map.insert(323, &[295424]);     // 323 => [295424]
-            map.insert(884, &[557568]);     // 884 => [557568]
-            map.insert(1818, &[1081856]);     // 1818 => [1081856]
-            map.insert(947, &[2130432]);     // 947 => [2130432]
-            map.insert(1962, &[197120]);     // 1962 => [197120]
-            map.insert(918, &[328192]);     // 918 => [328192]
-            map.insert(417, &[590336]);     // 417 => [590336]
-            map.insert(1487, &[1114624]);     // 1487 => [1114624]
-            map.insert(358, &[2163200]);     // 358 => [2163200]
-            map.insert(1596, &[393728]);     // 1596 => [393728]
-            map.insert(1035, &[655872]);     // 1035 => [655872]
-            map.insert(101, &[1180160]);     // 101 => [1180160]
-            map.insert(1228, &[2228736]);     // 1228 => [2228736]
-            map.insert(55, &[786944]);     // 55 => [786944]
-            map.insert(1113, &[1311232]);     // 1113 => [1311232]
-            map.insert(240, &[2359808]);     // 240 => [2359808]
-            map.insert(1646, &[1573376]);     // 1646 => [1573376]
-            map.insert(711, &[2621952]);     // 711 => [2621952]
-            map.insert(1705, &[3146240]);     // 1705 => [3146240]
-            map.insert(2039, &[7168]);     // 2039 => [7168]
-            map.insert(1342, &[11264]);     // 1342 => [11264]
-            map.insert(1419, &[19456]);     // 1419 => [19456]
-            map.insert(454, &[35840]);     // 454 => [35840]
-            map.insert(787, &[68608]);     // 787 => [68608]
-            map.insert(1721, &[134144]);     // 1721 => [134144]
-            map.insert(645, &[265216]);     // 645 => [265216]
-            map.insert(178, &[527360]);     // 178 => [527360]
-            map.insert(1244, &[1051648]);     // 1244 => [1051648]
-            map.insert(117, &[2100224]);     // 117 => [2100224]
-            map.insert(1737, &[13312]);     // 1737 => [13312]
-            map.insert(1660, &[21504]);     // 1660 => [21504]
-            map.insert(561, &[37888]);     // 561 => [37888]
-            map.insert(228, &[70656]);     // 228 => [70656]
-            map.insert(1358, &[136192]);     // 1358 => [136192]
-            map.insert(370, &[267264]);     // 370 => [267264]
-            map.insert(837, &[529408]);     // 837 => [529408]
-            map.insert(1835, &[1053696]);     // 1835 => [1053696]
-            map.insert(898, &[2102272]);     // 898 => [2102272]
-            map.insert(1205, &[25600]);     // 1205 => [25600]
-            map.insert(248, &[41984]);     // 248 => [41984]
-            map.insert(557, &[74752]);     // 557 => [74752]
-            map.insert(1927, &[140288]);     // 1927 => [140288]
-            map.insert(955, &[271360]);     // 955 => [271360]
-            map.insert(396, &[533504]);     // 396 => [533504]
-            map.insert(1506, &[1057792]);     // 1506 => [1057792]
-            map.insert(331, &[2106368]);     // 331 => [2106368]
-            map.insert(77, &[50176]);     // 77 => [50176]
-            map.insert(664, &[82944]);     // 664 => [82944]
-            map.insert(1842, &[148480]);     // 1842 => [148480]
-            map.insert(782, &[279552]);     // 782 => [279552]
-            map.insert(313, &[541696]);     // 313 => [541696]
-            map.insert(1367, &[1065984]);     // 1367 => [1065984]
-            map.insert(510, &[2114560]);     // 510 => [2114560]
-            map.insert(1749, &[99328]);     // 1749 => [99328]
-            map.insert(895, &[164864]);     // 895 => [164864]
-            map.insert(1859, &[295936]);     // 1859 => [295936]
-            map.insert(1396, &[558080]);     // 1396 => [558080]
-            map.insert(282, &[1082368]);     // 282 => [1082368]
-            map.insert(1459, &[2130944]);     // 1459 => [2130944]
-            map.insert(426, &[197632]);     // 426 => [197632]
-            map.insert(1430, &[328704]);     // 1430 => [328704]
-            map.insert(1953, &[590848]);     // 1953 => [590848]
-            map.insert(975, &[1115136]);     // 975 => [1115136]
-            map.insert(1894, &[2163712]);     // 1894 => [2163712]
-            map.insert(60, &[394240]);     // 60 => [394240]
-            map.insert(523, &[656384]);     // 523 => [656384]
-            map.insert(1637, &[1180672]);     // 1637 => [1180672]
-            map.insert(716, &[2229248]);     // 716 => [2229248]
-            map.insert(1591, &[787456]);     // 1591 => [787456]
-            map.insert(601, &[1311744]);     // 601 => [1311744]
-            map.insert(1776, &[2360320]);     // 1776 => [2360320]
-            map.insert(110, &[1573888]);     // 110 => [1573888]
-            map.insert(1223, &[2622464]);     // 1223 => [2622464]
-            map.insert(169, &[3146752]);     // 169 => [3146752]
-            map.insert(1441, &[14336]);     // 1441 => [14336]
-            map.insert(1300, &[22528]);     // 1300 => [22528]
-            map.insert(345, &[38912]);     // 345 => [38912]
-            map.insert(908, &[71680]);     // 908 => [71680]
-            map.insert(1574, &[137216]);     // 1574 => [137216]
-            map.insert(538, &[268288]);     // 538 => [268288]
-            map.insert(45, &[530432]);     // 45 => [530432]
-            map.insert(1091, &[1054720]);     // 1091 => [1054720]
-            map.insert(234, &[2103296]);     // 234 => [2103296]
-            map.insert(2013, &[26624]);     // 2013 => [26624]
-            map.insert(912, &[43008]);     // 912 => [43008]
-            map.insert(325, &[75776]);     // 325 => [75776]
-            map.insert(1263, &[141312]);     // 1263 => [141312]
-            map.insert(211, &[272384]);     // 211 => [272384]
-            map.insert(740, &[534528]);     // 740 => [534528]
-            map.insert(1674, &[1058816]);     // 1674 => [1058816]
-            map.insert(547, &[2107392]);     // 547 => [2107392]
-            map.insert(805, &[51200]);     // 805 => [51200]
-            map.insert(496, &[83968]);     // 496 => [83968]
-            map.insert(1114, &[149504]);     // 1114 => [149504]
-            map.insert(102, &[280576]);     // 102 => [280576]
-            map.insert(593, &[542720]);     // 593 => [542720]
-            map.insert(1599, &[1067008]);     // 1599 => [1067008]
-            map.insert(662, &[2115584]);     // 662 => [2115584]
-            map.insert(1469, &[100352]);     // 1469 => [100352]
-            map.insert(23, &[165888]);     // 23 => [165888]
-            map.insert(1067, &[296960]);     // 1067 => [296960]
-            map.insert(1564, &[559104]);     // 1564 => [559104]
-            map.insert(626, &[1083392]);     // 626 => [1083392]
-            map.insert(1755, &[2131968]);     // 1755 => [2131968]
-            map.insert(706, &[198656]);     // 706 => [198656]
-            map.insert(1790, &[329728]);     // 1790 => [329728]
-            map.insert(1225, &[591872]);     // 1225 => [591872]
-            map.insert(167, &[1116160]);     // 167 => [1116160]
-            map.insert(1038, &[2164736]);     // 1038 => [2164736]
-            map.insert(852, &[395264]);     // 852 => [395264]
-            map.insert(355, &[657408]);     // 355 => [657408]
-            map.insert(1293, &[1181696]);     // 1293 => [1181696]
-            map.insert(420, &[2230272]);     // 420 => [2230272]
-            map.insert(1375, &[788480]);     // 1375 => [788480]
-            map.insert(305, &[1312768]);     // 305 => [1312768]
-            map.insert(1432, &[2361344]);     // 1432 => [2361344]
-            map.insert(774, &[1574912]);     // 774 => [1574912]
-            map.insert(1967, &[2623488]);     // 1967 => [2623488]
-            map.insert(961, &[3147776]);     // 961 => [3147776]
-            map.insert(1066, &[28672]);     // 1066 => [28672]
-            map.insert(103, &[45056]);     // 103 => [45056]
-            map.insert(690, &[77824]);     // 690 => [77824]
-            map.insert(1816, &[143360]);     // 1816 => [143360]
-            map.insert(804, &[274432]);     // 804 => [274432]
-            map.insert(275, &[536576]);     // 275 => [536576]
-            map.insert(1405, &[1060864]);     // 1405 => [1060864]
-            map.insert(468, &[2109440]);     // 468 => [2109440]
-            map.insert(210, &[53248]);     // 210 => [53248]
-            map.insert(519, &[86016]);     // 519 => [86016]
-            map.insert(1965, &[151552]);     // 1965 => [151552]
-            map.insert(913, &[282624]);     // 913 => [282624]
-            map.insert(422, &[544768]);     // 422 => [544768]
-            map.insert(1480, &[1069056]);     // 1480 => [1069056]
-            map.insert(353, &[2117632]);     // 353 => [2117632]
-            map.insert(1610, &[102400]);     // 1610 => [102400]
-            map.insert(992, &[167936]);     // 992 => [167936]
-            map.insert(2012, &[299008]);     // 2012 => [299008]
-            map.insert(1515, &[561152]);     // 1515 => [561152]
-            map.insert(389, &[1085440]);     // 389 => [1085440]
-            map.insert(1324, &[2134016]);     // 1324 => [2134016]
-            map.insert(309, &[200704]);     // 309 => [200704]
-            map.insert(1289, &[331776]);     // 1289 => [331776]
-            map.insert(1854, &[593920]);     // 1854 => [593920]
-            map.insert(848, &[1118208]);     // 848 => [1118208]
-            map.insert(2041, &[2166784]);     // 2041 => [2166784]
-            map.insert(163, &[397312]);     // 163 => [397312]
-            map.insert(660, &[659456]);     // 660 => [659456]
-            map.insert(1786, &[1183744]);     // 1786 => [1183744]
-            map.insert(595, &[2232320]);     // 595 => [2232320]
-            map.insert(1704, &[790528]);     // 1704 => [790528]
-            map.insert(710, &[1314816]);     // 710 => [1314816]
-            map.insert(1647, &[2363392]);     // 1647 => [2363392]
-            map.insert(241, &[1576960]);     // 241 => [1576960]
-            map.insert(1112, &[2625536]);     // 1112 => [2625536]
-            map.insert(54, &[3149824]);     // 54 => [3149824]
-            map.insert(539, &[57344]);     // 539 => [57344]
-            map.insert(206, &[90112]);     // 206 => [90112]
-            map.insert(1380, &[155648]);     // 1380 => [155648]
-            map.insert(344, &[286720]);     // 344 => [286720]
-            map.insert(879, &[548864]);     // 879 => [548864]
-            map.insert(1793, &[1073152]);     // 1793 => [1073152]
-            map.insert(936, &[2121728]);     // 936 => [2121728]
-            map.insert(1155, &[106496]);     // 1155 => [106496]
-            map.insert(297, &[172032]);     // 297 => [172032]
-            map.insert(1301, &[303104]);     // 1301 => [303104]
-            map.insert(1826, &[565248]);     // 1826 => [565248]
-            map.insert(844, &[1089536]);     // 844 => [1089536]
-            map.insert(2021, &[2138112]);     // 2021 => [2138112]
-            map.insert(1020, &[204800]);     // 1020 => [204800]
-            map.insert(1984, &[335872]);     // 1984 => [335872]
-            map.insert(1527, &[598016]);     // 1527 => [598016]
-            map.insert(409, &[1122304]);     // 409 => [1122304]
-            map.insert(1328, &[2170880]);     // 1328 => [2170880]
-            map.insert(618, &[401408]);     // 618 => [401408]
-            map.insert(93, &[663552]);     // 93 => [663552]
-            map.insert(1075, &[1187840]);     // 1075 => [1187840]
-            map.insert(154, &[2236416]);     // 154 => [2236416]
-            map.insert(1121, &[794624]);     // 1121 => [794624]
-            map.insert(15, &[1318912]);     // 15 => [1318912]
-            map.insert(1190, &[2367488]);     // 1190 => [2367488]
-            map.insert(568, &[1581056]);     // 568 => [1581056]
-            map.insert(1681, &[2629632]);     // 1681 => [2629632]
-            map.insert(767, &[3153920]);     // 767 => [3153920]
-            map.insert(1078, &[114688]);     // 1078 => [114688]
-            map.insert(412, &[180224]);     // 412 => [180224]
-            map.insert(1440, &[311296]);     // 1440 => [311296]
-            map.insert(1943, &[573440]);     // 1943 => [573440]
-            map.insert(1017, &[1097728]);     // 1017 => [1097728]
-            map.insert(1872, &[2146304]);     // 1872 => [2146304]
-            map.insert(841, &[212992]);     // 841 => [212992]
-            map.insert(1909, &[344064]);     // 1909 => [344064]
-            map.insert(1346, &[606208]);     // 1346 => [606208]
-            map.insert(300, &[1130496]);     // 300 => [1130496]
-            map.insert(1413, &[2179072]);     // 1413 => [2179072]
-            map.insert(735, &[409600]);     // 735 => [409600]
-            map.insert(232, &[671744]);     // 232 => [671744]
-            map.insert(1158, &[1196032]);     // 1158 => [1196032]
-            map.insert(47, &[2244608]);     // 47 => [2244608]
-            map.insert(1236, &[802816]);     // 1236 => [802816]
-            map.insert(186, &[1327104]);     // 186 => [1327104]
-            map.insert(1043, &[2375680]);     // 1043 => [2375680]
-            map.insert(653, &[1589248]);     // 653 => [1589248]
-            map.insert(1572, &[2637824]);     // 1572 => [2637824]
-            map.insert(586, &[3162112]);     // 586 => [3162112]
-            map.insert(1796, &[229376]);     // 1796 => [229376]
-            map.insert(824, &[360448]);     // 824 => [360448]
-            map.insert(271, &[622592]);     // 271 => [622592]
-            map.insert(1377, &[1146880]);     // 1377 => [1146880]
-            map.insert(456, &[2195456]);     // 456 => [2195456]
-            map.insert(1682, &[425984]);     // 1682 => [425984]
-            map.insert(1189, &[688128]);     // 1189 => [688128]
-            map.insert(203, &[1212416]);     // 203 => [1212416]
-            map.insert(1122, &[2260992]);     // 1122 => [2260992]
-            map.insert(153, &[819200]);     // 153 => [819200]
-            map.insert(1271, &[1343488]);     // 1271 => [1343488]
-            map.insert(94, &[2392064]);     // 94 => [2392064]
-            map.insert(1728, &[1605632]);     // 1728 => [1605632]
-            map.insert(617, &[2654208]);     // 617 => [2654208]
-            map.insert(1543, &[3178496]);     // 1543 => [3178496]
-            map.insert(1095, &[458752]);     // 1095 => [458752]
-            map.insert(1648, &[720896]);     // 1648 => [720896]
-            map.insert(542, &[1245184]);     // 542 => [1245184]
-            map.insert(1719, &[2293760]);     // 1719 => [2293760]
-            map.insert(588, &[851968]);     // 588 => [851968]
-            map.insert(1570, &[1376256]);     // 1570 => [1376256]
-            map.insert(651, &[2424832]);     // 651 => [2424832]
-            map.insert(1045, &[1638400]);     // 1045 => [1638400]
-            map.insert(188, &[2686976]);     // 188 => [2686976]
-            map.insert(1234, &[3211264]);     // 1234 => [3211264]
-            map.insert(2022, &[917504]);     // 2022 => [917504]
-            map.insert(904, &[1441792]);     // 904 => [1441792]
-            map.insert(1825, &[2490368]);     // 1825 => [2490368]
-            map.insert(447, &[1703936]);     // 447 => [1703936]
-            map.insert(1302, &[2752512]);     // 1302 => [2752512]
-            map.insert(376, &[3276800]);     // 376 => [3276800]
-            map.insert(1411, &[1835008]);     // 1411 => [1835008]
-            map.insert(298, &[2883584]);     // 298 => [2883584]
-            map.insert(1348, &[3407872]);     // 1348 => [3407872]
-            map.insert(1907, &[3670016]);     // 1907 => [3670016]
-            map.insert(39, &[39]);     // 39 => [39]
-            map.insert(263, &[263]);     // 263 => [263]
-            map.insert(1031, &[1031]);     // 1031 => [1031]
-            map.insert(1172, &[4107]);     // 1172 => [4107]
-            map.insert(677, &[32779]);     // 677 => [32779]
-            map.insert(790, &[2097163]);     // 790 => [2097163]
-            map.insert(147, &[147]);     // 147 => [147]
-            map.insert(531, &[531]);     // 531 => [531]
-            map.insert(1959, &[1048595]);     // 1959 => [1048595]
-            map.insert(291, &[291]);     // 291 => [291]
-            map.insert(1059, &[1059]);     // 1059 => [1059]
-            map.insert(1557, &[8259]);     // 1557 => [8259]
-            map.insert(1696, &[16451]);     // 1696 => [16451]
-            map.insert(1426, &[131139]);     // 1426 => [131139]
-            map.insert(643, &[643]);     // 643 => [643]
-            map.insert(1847, &[1048707]);     // 1847 => [1048707]
-            map.insert(1283, &[1283]);     // 1283 => [1283]
-            map.insert(1463, &[1049091]);     // 1463 => [1049091]
-            map.insert(1808, &[67587]);     // 1808 => [67587]
-            map.insert(1670, &[264195]);     // 1670 => [264195]
-            map.insert(1201, &[526339]);     // 1201 => [526339]
-            map.insert(1586, &[36867]);     // 1586 => [36867]
-            map.insert(1921, &[2101251]);     // 1921 => [2101251]
-            map.insert(182, &[24579]);     // 182 => [24579]
-            map.insert(900, &[139267]);     // 900 => [139267]
-            map.insert(817, &[147459]);     // 817 => [147459]
-            map.insert(432, &[2129923]);     // 432 => [2129923]
-            map.insert(405, &[327683]);     // 405 => [327683]
-            map.insert(930, &[589827]);     // 930 => [589827]
-            map.insert(564, &[786435]);     // 564 => [786435]
-            map.insert(141, &[141]);     // 141 => [141]
-            map.insert(118, &[65549]);     // 118 => [65549]
-            map.insert(1500, &[131085]);     // 1500 => [131085]
-            map.insert(85, &[85]);     // 85 => [85]
-            map.insert(1162, &[4117]);     // 1162 => [4117]
-            map.insert(504, &[262165]);     // 504 => [262165]
-            map.insert(293, &[293]);     // 293 => [293]
-            map.insert(1061, &[1061]);     // 1061 => [1061]
-            map.insert(1242, &[4165]);     // 1242 => [4165]
-            map.insert(424, &[262213]);     // 424 => [262213]
-            map.insert(254, &[65669]);     // 254 => [65669]
-            map.insert(1364, &[131205]);     // 1364 => [131205]
-            map.insert(1285, &[1285]);     // 1285 => [1285]
-            map.insert(1107, &[8709]);     // 1107 => [8709]
-            map.insert(171, &[33285]);     // 171 => [33285]
-            map.insert(479, &[524805]);     // 479 => [524805]
-            map.insert(398, &[18437]);     // 398 => [18437]
-            map.insert(217, &[1050629]);     // 217 => [1050629]
-            map.insert(1136, &[2099205]);     // 1136 => [2099205]
-            map.insert(1399, &[266245]);     // 1399 => [266245]
-            map.insert(1277, &[40965]);     // 1277 => [40965]
-            map.insert(1417, &[532485]);     // 1417 => [532485]
-            map.insert(338, &[1064965]);     // 338 => [1064965]
-            map.insert(1531, &[2113541]);     // 1531 => [2113541]
-            map.insert(369, &[557061]);     // 369 => [557061]
-            map.insert(1455, &[196613]);     // 1455 => [196613]
-            map.insert(1196, &[3145733]);     // 1196 => [3145733]
-            map.insert(1049, &[1049]);     // 1049 => [1049]
-            map.insert(1905, &[2073]);     // 1905 => [2073]
-            map.insert(1615, &[8217]);     // 1615 => [8217]
-            map.insert(553, &[553]);     // 553 => [553]
-            map.insert(1738, &[16425]);     // 1738 => [16425]
-            map.insert(452, &[262185]);     // 452 => [262185]
-            map.insert(329, &[329]);     // 329 => [329]
-            map.insert(915, &[524361]);     // 915 => [524361]
-            map.insert(2045, &[1048649]);     // 2045 => [1048649]
-            map.insert(242, &[65673]);     // 242 => [65673]
-            map.insert(1368, &[131209]);     // 1368 => [131209]
-            map.insert(723, &[524553]);     // 723 => [524553]
-            map.insert(1725, &[1048841]);     // 1725 => [1048841]
-            map.insert(1258, &[16905]);     // 1258 => [16905]
-            map.insert(996, &[262665]);     // 996 => [262665]
-            map.insert(865, &[3081]);     // 865 => [3081]
-            map.insert(607, &[9225]);     // 607 => [9225]
-            map.insert(311, &[10249]);     // 311 => [10249]
-            map.insert(1592, &[36873]);     // 1592 => [36873]
-            map.insert(1931, &[2101257]);     // 1931 => [2101257]
-            map.insert(1799, &[278537]);     // 1799 => [278537]
-            map.insert(442, &[2129929]);     // 442 => [2129929]
-            map.insert(1443, &[196617]);     // 1443 => [196617]
-            map.insert(1127, &[1572873]);     // 1127 => [1572873]
-            map.insert(1504, &[131121]);     // 1504 => [131121]
-            map.insert(1003, &[524337]);     // 1003 => [524337]
-            map.insert(812, &[2097201]);     // 812 => [2097201]
-            map.insert(1230, &[4177]);     // 1230 => [4177]
-            map.insert(444, &[262225]);     // 444 => [262225]
-            map.insert(657, &[657]);     // 657 => [657]
-            map.insert(1829, &[1048721]);     // 1829 => [1048721]
-            map.insert(2034, &[16657]);     // 2034 => [16657]
-            map.insert(959, &[33041]);     // 959 => [33041]
-            map.insert(362, &[65809]);     // 362 => [65809]
-            map.insert(1445, &[1049105]);     // 1445 => [1049105]
-            map.insert(889, &[3089]);     // 889 => [3089]
-            map.insert(583, &[9233]);     // 583 => [9233]
-            map.insert(303, &[10257]);     // 303 => [10257]
-            map.insert(1379, &[266257]);     // 1379 => [266257]
-            map.insert(1116, &[49169]);     // 1116 => [49169]
-            map.insert(1673, &[81937]);     // 1673 => [81937]
-            map.insert(708, &[98321]);     // 708 => [98321]
-            map.insert(1562, &[655377]);     // 1562 => [655377]
-            map.insert(1757, &[2228241]);     // 1757 => [2228241]
-            map.insert(214, &[2621457]);     // 214 => [2621457]
-            map.insert(225, &[225]);     // 225 => [225]
-            map.insert(1801, &[2145]);     // 1801 => [2145]
-            map.insert(719, &[32865]);     // 719 => [32865]
-            map.insert(1993, &[2209]);     // 1993 => [2209]
-            map.insert(527, &[32929]);     // 527 => [32929]
-            map.insert(1313, &[1313]);     // 1313 => [1313]
-            map.insert(1218, &[16929]);     // 1218 => [16929]
-            map.insert(972, &[262689]);     // 972 => [262689]
-            map.insert(1511, &[34849]);     // 1511 => [34849]
-            map.insert(744, &[12321]);     // 744 => [12321]
-            map.insert(1221, &[69665]);     // 1221 => [69665]
-            map.insert(778, &[1052705]);     // 778 => [1052705]
-            map.insert(1548, &[73761]);     // 1548 => [73761]
-            map.insert(451, &[1056801]);     // 451 => [1056801]
-            map.insert(1839, &[278561]);     // 1839 => [278561]
-            map.insert(2030, &[1114145]);     // 2030 => [1114145]
-            map.insert(1578, &[655393]);     // 1578 => [655393]
-            map.insert(1773, &[2228257]);     // 1773 => [2228257]
-            map.insert(230, &[2621473]);     // 230 => [2621473]
-            map.insert(1961, &[2241]);     // 1961 => [2241]
-            map.insert(623, &[32961]);     // 623 => [32961]
-            map.insert(667, &[524609]);     // 667 => [524609]
-            map.insert(1781, &[1048897]);     // 1781 => [1048897]
-            map.insert(1601, &[1601]);     // 1601 => [1601]
-            map.insert(570, &[66113]);     // 570 => [66113]
-            map.insert(348, &[2097729]);     // 348 => [2097729]
-            map.insert(1082, &[66625]);     // 1082 => [66625]
-            map.insert(1884, &[2098241]);     // 1884 => [2098241]
-            map.insert(1415, &[34881]);     // 1415 => [34881]
-            map.insert(1331, &[266305]);     // 1331 => [266305]
-            map.insert(244, &[24641]);     // 244 => [24641]
-            map.insert(966, &[139329]);     // 966 => [139329]
-            map.insert(883, &[147521]);     // 883 => [147521]
-            map.insert(807, &[2162753]);     // 807 => [2162753]
-            map.insert(1071, &[1572929]);     // 1071 => [1572929]
-            map.insert(2007, &[8577]);     // 2007 => [8577]
-            map.insert(108, &[262529]);     // 108 => [262529]
-            map.insert(668, &[2097537]);     // 668 => [2097537]
-            map.insert(1333, &[1049217]);     // 1333 => [1049217]
-            map.insert(30, &[5249]);     // 30 => [5249]
-            map.insert(610, &[17537]);     // 610 => [17537]
-            map.insert(1883, &[525441]);     // 1883 => [525441]
-            map.insert(1351, &[34945]);     // 1351 => [34945]
-            map.insert(765, &[20609]);     // 765 => [20609]
-            map.insert(1988, &[528513]);     // 1988 => [528513]
-            map.insert(1850, &[270465]);     // 1850 => [270465]
-            map.insert(1482, &[2105473]);     // 1482 => [2105473]
-            map.insert(1464, &[540801]);     // 1464 => [540801]
-            map.insert(1323, &[196737]);     // 1323 => [196737]
-            map.insert(625, &[2359425]);     // 625 => [2359425]
-            map.insert(1129, &[2817]);     // 1129 => [2817]
-            map.insert(1950, &[4865]);     // 1950 => [4865]
-            map.insert(1744, &[131841]);     // 1744 => [131841]
-            map.insert(758, &[6401]);     // 758 => [6401]
-            map.insert(952, &[133377]);     // 952 => [133377]
-            map.insert(79, &[135425]);     // 79 => [135425]
-            map.insert(1722, &[270593]);     // 1722 => [270593]
-            map.insert(1098, &[2105601]);     // 1098 => [2105601]
-            map.insert(1356, &[49409]);     // 1356 => [49409]
-            map.insert(1945, &[82177]);     // 1945 => [82177]
-            map.insert(980, &[98561]);     // 980 => [98561]
-            map.insert(1009, &[2359553]);     // 1009 => [2359553]
-            map.insert(1391, &[1573121]);     // 1391 => [1573121]
-            map.insert(1658, &[67073]);     // 1658 => [67073]
-            map.insert(1308, &[2098689]);     // 1308 => [2098689]
-            map.insert(502, &[6657]);     // 502 => [6657]
-            map.insert(184, &[133633]);     // 184 => [133633]
-            map.insert(847, &[135681]);     // 847 => [135681]
-            map.insert(1785, &[41473]);     // 1785 => [41473]
-            map.insert(1933, &[532993]);     // 1933 => [532993]
-            map.insert(1295, &[279041]);     // 1295 => [279041]
-            map.insert(885, &[557569]);     // 885 => [557569]
-            map.insert(359, &[2163201]);     // 359 => [2163201]
-            map.insert(1343, &[11265]);     // 1343 => [11265]
-            map.insert(1661, &[21505]);     // 1661 => [21505]
-            map.insert(836, &[529409]);     // 836 => [529409]
-            map.insert(312, &[541697]);     // 312 => [541697]
-            map.insert(894, &[164865]);     // 894 => [164865]
-            map.insert(1858, &[295937]);     // 1858 => [295937]
-            map.insert(283, &[1082369]);     // 283 => [1082369]
-            map.insert(1895, &[2163713]);     // 1895 => [2163713]
-            map.insert(61, &[394241]);     // 61 => [394241]
-            map.insert(1636, &[1180673]);     // 1636 => [1180673]
-            map.insert(600, &[1311745]);     // 600 => [1311745]
-            map.insert(1575, &[137217]);     // 1575 => [137217]
-            map.insert(1598, &[1067009]);     // 1598 => [1067009]
-            map.insert(663, &[2115585]);     // 663 => [2115585]
-            map.insert(1791, &[329729]);     // 1791 => [329729]
-            map.insert(1224, &[591873]);     // 1224 => [591873]
-            map.insert(1374, &[788481]);     // 1374 => [788481]
-            map.insert(960, &[3147777]);     // 960 => [3147777]
-            map.insert(691, &[77825]);     // 691 => [77825]
-            map.insert(1404, &[1060865]);     // 1404 => [1060865]
-            map.insert(423, &[544769]);     // 423 => [544769]
-            map.insert(1325, &[2134017]);     // 1325 => [2134017]
-            map.insert(849, &[1118209]);     // 849 => [1118209]
-            map.insert(1381, &[155649]);     // 1381 => [155649]
-            map.insert(1827, &[565249]);     // 1827 => [565249]
-            map.insert(408, &[1122305]);     // 408 => [1122305]
-            map.insert(1191, &[2367489]);     // 1191 => [2367489]
-            map.insert(1079, &[114689]);     // 1079 => [114689]
-            map.insert(587, &[3162113]);     // 587 => [3162113]
-            map.insert(1683, &[425985]);     // 1683 => [425985]
-            map.insert(202, &[1212417]);     // 202 => [1212417]
-            map.insert(1270, &[1343489]);     // 1270 => [1343489]
-            map.insert(589, &[851969]);     // 589 => [851969]
-            map.insert(905, &[1441793]);     // 905 => [1441793]
-            map.insert(1303, &[2752513]);     // 1303 => [2752513]
-            map.insert(294, &[294]);     // 294 => [294]
-            map.insert(1062, &[1062]);     // 1062 => [1062]
-            map.insert(1286, &[1286]);     // 1286 => [1286]
-            map.insert(1595, &[36874]);     // 1595 => [36874]
-            map.insert(1928, &[2101258]);     // 1928 => [2101258]
-            map.insert(441, &[2129930]);     // 441 => [2129930]
-            map.insert(658, &[658]);     // 658 => [658]
-            map.insert(1830, &[1048722]);     // 1830 => [1048722]
-            map.insert(1446, &[1049106]);     // 1446 => [1049106]
-            map.insert(1314, &[1314]);     // 1314 => [1314]
-            map.insert(247, &[24642]);     // 247 => [24642]
-            map.insert(965, &[139330]);     // 965 => [139330]
-            map.insert(880, &[147522]);     // 880 => [147522]
-            map.insert(1334, &[1049218]);     // 1334 => [1049218]
-            map.insert(1788, &[329730]);     // 1788 => [329730]
-            map.insert(1227, &[591874]);     // 1227 => [591874]
-            map.insert(1373, &[788482]);     // 1373 => [788482]
-            map.insert(1326, &[2134018]);     // 1326 => [2134018]
-            map.insert(1382, &[155650]);     // 1382 => [155650]
-            map.insert(590, &[851970]);     // 590 => [851970]
-            map.insert(1316, &[1316]);     // 1316 => [1316]
-            map.insert(295, &[295]);     // 295 => [295]
-            map.insert(1063, &[1063]);     // 1063 => [1063]
-            map.insert(1287, &[1287]);     // 1287 => [1287]
-            map.insert(1594, &[36875]);     // 1594 => [36875]
-            map.insert(1929, &[2101259]);     // 1929 => [2101259]
-            map.insert(440, &[2129931]);     // 440 => [2129931]
-            map.insert(659, &[659]);     // 659 => [659]
-            map.insert(1831, &[1048723]);     // 1831 => [1048723]
-            map.insert(1447, &[1049107]);     // 1447 => [1049107]
-            map.insert(1315, &[1315]);     // 1315 => [1315]
-            map.insert(246, &[24643]);     // 246 => [24643]
-            map.insert(964, &[139331]);     // 964 => [139331]
-            map.insert(881, &[147523]);     // 881 => [147523]
-            map.insert(1335, &[1049219]);     // 1335 => [1049219]
-            map.insert(1789, &[329731]);     // 1789 => [329731]
-            map.insert(1226, &[591875]);     // 1226 => [591875]
-            map.insert(1372, &[788483]);     // 1372 => [788483]
-            map.insert(1327, &[2134019]);     // 1327 => [2134019]
-            map.insert(1383, &[155651]);     // 1383 => [155651]
-            map.insert(591, &[851971]);     // 591 => [851971]
-            map.insert(1317, &[1317]);     // 1317 => [1317]
-            map.insert(1318, &[1318]);     // 1318 => [1318]
-            map.insert(1319, &[1319]);     // 1319 => [1319]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 2576385 ],
+            &[ 651266 ],
+            &[ 3452932 ],
+            &[ 2988040 ],
+            &[ 3878928 ],
+            &[ 1427488 ],
+            &[ 1009728 ],
+            &[ 2019456 ],
+            &[ 4065536 ],
+            &[ 3729920 ],
+            &[ 1211392 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 22)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(2048, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(64, &[64]);     // 64 => [64]
+        map.insert(128, &[128]);     // 128 => [128]
+        map.insert(256, &[256]);     // 256 => [256]
+        map.insert(512, &[512]);     // 512 => [512]
+        map.insert(1024, &[1024]);     // 1024 => [1024]
+        map.insert(1896, &[2048]);     // 1896 => [2048]
+        map.insert(1183, &[4096]);     // 1183 => [4096]
+        map.insert(1622, &[8192]);     // 1622 => [8192]
+        map.insert(1763, &[16384]);     // 1763 => [16384]
+        map.insert(686, &[32768]);     // 686 => [32768]
+        map.insert(123, &[65536]);     // 123 => [65536]
+        map.insert(1489, &[131072]);     // 1489 => [131072]
+        map.insert(493, &[262144]);     // 493 => [262144]
+        map.insert(986, &[524288]);     // 986 => [524288]
+        map.insert(1972, &[1048576]);     // 1972 => [1048576]
+        map.insert(797, &[2097152]);     // 797 => [2097152]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(65, &[65]);     // 65 => [65]
+        map.insert(129, &[129]);     // 129 => [129]
+        map.insert(257, &[257]);     // 257 => [257]
+        map.insert(513, &[513]);     // 513 => [513]
+        map.insert(1025, &[1025]);     // 1025 => [1025]
+        map.insert(1897, &[2049]);     // 1897 => [2049]
+        map.insert(1182, &[4097]);     // 1182 => [4097]
+        map.insert(1623, &[8193]);     // 1623 => [8193]
+        map.insert(1762, &[16385]);     // 1762 => [16385]
+        map.insert(687, &[32769]);     // 687 => [32769]
+        map.insert(122, &[65537]);     // 122 => [65537]
+        map.insert(1488, &[131073]);     // 1488 => [131073]
+        map.insert(492, &[262145]);     // 492 => [262145]
+        map.insert(987, &[524289]);     // 987 => [524289]
+        map.insert(1973, &[1048577]);     // 1973 => [1048577]
+        map.insert(796, &[2097153]);     // 796 => [2097153]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(66, &[66]);     // 66 => [66]
+        map.insert(130, &[130]);     // 130 => [130]
+        map.insert(258, &[258]);     // 258 => [258]
+        map.insert(514, &[514]);     // 514 => [514]
+        map.insert(1026, &[1026]);     // 1026 => [1026]
+        map.insert(1898, &[2050]);     // 1898 => [2050]
+        map.insert(1181, &[4098]);     // 1181 => [4098]
+        map.insert(1620, &[8194]);     // 1620 => [8194]
+        map.insert(1761, &[16386]);     // 1761 => [16386]
+        map.insert(684, &[32770]);     // 684 => [32770]
+        map.insert(121, &[65538]);     // 121 => [65538]
+        map.insert(1491, &[131074]);     // 1491 => [131074]
+        map.insert(495, &[262146]);     // 495 => [262146]
+        map.insert(984, &[524290]);     // 984 => [524290]
+        map.insert(1974, &[1048578]);     // 1974 => [1048578]
+        map.insert(799, &[2097154]);     // 799 => [2097154]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(68, &[68]);     // 68 => [68]
+        map.insert(132, &[132]);     // 132 => [132]
+        map.insert(260, &[260]);     // 260 => [260]
+        map.insert(516, &[516]);     // 516 => [516]
+        map.insert(1028, &[1028]);     // 1028 => [1028]
+        map.insert(1900, &[2052]);     // 1900 => [2052]
+        map.insert(1179, &[4100]);     // 1179 => [4100]
+        map.insert(1618, &[8196]);     // 1618 => [8196]
+        map.insert(1767, &[16388]);     // 1767 => [16388]
+        map.insert(682, &[32772]);     // 682 => [32772]
+        map.insert(127, &[65540]);     // 127 => [65540]
+        map.insert(1493, &[131076]);     // 1493 => [131076]
+        map.insert(489, &[262148]);     // 489 => [262148]
+        map.insert(990, &[524292]);     // 990 => [524292]
+        map.insert(1968, &[1048580]);     // 1968 => [1048580]
+        map.insert(793, &[2097156]);     // 793 => [2097156]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(72, &[72]);     // 72 => [72]
+        map.insert(136, &[136]);     // 136 => [136]
+        map.insert(264, &[264]);     // 264 => [264]
+        map.insert(520, &[520]);     // 520 => [520]
+        map.insert(1032, &[1032]);     // 1032 => [1032]
+        map.insert(1888, &[2056]);     // 1888 => [2056]
+        map.insert(1175, &[4104]);     // 1175 => [4104]
+        map.insert(1630, &[8200]);     // 1630 => [8200]
+        map.insert(1771, &[16392]);     // 1771 => [16392]
+        map.insert(678, &[32776]);     // 678 => [32776]
+        map.insert(115, &[65544]);     // 115 => [65544]
+        map.insert(1497, &[131080]);     // 1497 => [131080]
+        map.insert(485, &[262152]);     // 485 => [262152]
+        map.insert(978, &[524296]);     // 978 => [524296]
+        map.insert(1980, &[1048584]);     // 1980 => [1048584]
+        map.insert(789, &[2097160]);     // 789 => [2097160]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(80, &[80]);     // 80 => [80]
+        map.insert(144, &[144]);     // 144 => [144]
+        map.insert(272, &[272]);     // 272 => [272]
+        map.insert(528, &[528]);     // 528 => [528]
+        map.insert(1040, &[1040]);     // 1040 => [1040]
+        map.insert(1912, &[2064]);     // 1912 => [2064]
+        map.insert(1167, &[4112]);     // 1167 => [4112]
+        map.insert(1606, &[8208]);     // 1606 => [8208]
+        map.insert(1779, &[16400]);     // 1779 => [16400]
+        map.insert(702, &[32784]);     // 702 => [32784]
+        map.insert(107, &[65552]);     // 107 => [65552]
+        map.insert(1473, &[131088]);     // 1473 => [131088]
+        map.insert(509, &[262160]);     // 509 => [262160]
+        map.insert(970, &[524304]);     // 970 => [524304]
+        map.insert(1956, &[1048592]);     // 1956 => [1048592]
+        map.insert(781, &[2097168]);     // 781 => [2097168]
+        map.insert(96, &[96]);     // 96 => [96]
+        map.insert(160, &[160]);     // 160 => [160]
+        map.insert(288, &[288]);     // 288 => [288]
+        map.insert(544, &[544]);     // 544 => [544]
+        map.insert(1056, &[1056]);     // 1056 => [1056]
+        map.insert(1864, &[2080]);     // 1864 => [2080]
+        map.insert(1215, &[4128]);     // 1215 => [4128]
+        map.insert(1654, &[8224]);     // 1654 => [8224]
+        map.insert(1731, &[16416]);     // 1731 => [16416]
+        map.insert(654, &[32800]);     // 654 => [32800]
+        map.insert(91, &[65568]);     // 91 => [65568]
+        map.insert(1521, &[131104]);     // 1521 => [131104]
+        map.insert(461, &[262176]);     // 461 => [262176]
+        map.insert(1018, &[524320]);     // 1018 => [524320]
+        map.insert(1940, &[1048608]);     // 1940 => [1048608]
+        map.insert(829, &[2097184]);     // 829 => [2097184]
+        map.insert(192, &[192]);     // 192 => [192]
+        map.insert(320, &[320]);     // 320 => [320]
+        map.insert(576, &[576]);     // 576 => [576]
+        map.insert(1088, &[1088]);     // 1088 => [1088]
+        map.insert(1832, &[2112]);     // 1832 => [2112]
+        map.insert(1247, &[4160]);     // 1247 => [4160]
+        map.insert(1558, &[8256]);     // 1558 => [8256]
+        map.insert(1699, &[16448]);     // 1699 => [16448]
+        map.insert(750, &[32832]);     // 750 => [32832]
+        map.insert(59, &[65600]);     // 59 => [65600]
+        map.insert(1425, &[131136]);     // 1425 => [131136]
+        map.insert(429, &[262208]);     // 429 => [262208]
+        map.insert(922, &[524352]);     // 922 => [524352]
+        map.insert(2036, &[1048640]);     // 2036 => [1048640]
+        map.insert(861, &[2097216]);     // 861 => [2097216]
+        map.insert(384, &[384]);     // 384 => [384]
+        map.insert(640, &[640]);     // 640 => [640]
+        map.insert(1152, &[1152]);     // 1152 => [1152]
+        map.insert(2024, &[2176]);     // 2024 => [2176]
+        map.insert(1055, &[4224]);     // 1055 => [4224]
+        map.insert(1750, &[8320]);     // 1750 => [8320]
+        map.insert(1635, &[16512]);     // 1635 => [16512]
+        map.insert(558, &[32896]);     // 558 => [32896]
+        map.insert(251, &[65664]);     // 251 => [65664]
+        map.insert(1361, &[131200]);     // 1361 => [131200]
+        map.insert(365, &[262272]);     // 365 => [262272]
+        map.insert(858, &[524416]);     // 858 => [524416]
+        map.insert(1844, &[1048704]);     // 1844 => [1048704]
+        map.insert(925, &[2097280]);     // 925 => [2097280]
+        map.insert(768, &[768]);     // 768 => [768]
+        map.insert(1280, &[1280]);     // 1280 => [1280]
+        map.insert(1640, &[2304]);     // 1640 => [2304]
+        map.insert(1439, &[4352]);     // 1439 => [4352]
+        map.insert(1878, &[8448]);     // 1878 => [8448]
+        map.insert(2019, &[16640]);     // 2019 => [16640]
+        map.insert(942, &[33024]);     // 942 => [33024]
+        map.insert(379, &[65792]);     // 379 => [65792]
+        map.insert(1233, &[131328]);     // 1233 => [131328]
+        map.insert(237, &[262400]);     // 237 => [262400]
+        map.insert(730, &[524544]);     // 730 => [524544]
+        map.insert(1716, &[1048832]);     // 1716 => [1048832]
+        map.insert(541, &[2097408]);     // 541 => [2097408]
+        map.insert(1536, &[1536]);     // 1536 => [1536]
+        map.insert(1384, &[2560]);     // 1384 => [2560]
+        map.insert(1695, &[4608]);     // 1695 => [4608]
+        map.insert(1110, &[8704]);     // 1110 => [8704]
+        map.insert(1251, &[16896]);     // 1251 => [16896]
+        map.insert(174, &[33280]);     // 174 => [33280]
+        map.insert(635, &[66048]);     // 635 => [66048]
+        map.insert(2001, &[131584]);     // 2001 => [131584]
+        map.insert(1005, &[262656]);     // 1005 => [262656]
+        map.insert(474, &[524800]);     // 474 => [524800]
+        map.insert(1460, &[1049088]);     // 1460 => [1049088]
+        map.insert(285, &[2097664]);     // 285 => [2097664]
+        map.insert(872, &[3072]);     // 872 => [3072]
+        map.insert(159, &[5120]);     // 159 => [5120]
+        map.insert(598, &[9216]);     // 598 => [9216]
+        map.insert(739, &[17408]);     // 739 => [17408]
+        map.insert(1710, &[33792]);     // 1710 => [33792]
+        map.insert(1147, &[66560]);     // 1147 => [66560]
+        map.insert(465, &[132096]);     // 465 => [132096]
+        map.insert(1517, &[263168]);     // 1517 => [263168]
+        map.insert(2010, &[525312]);     // 2010 => [525312]
+        map.insert(948, &[1049600]);     // 948 => [1049600]
+        map.insert(1821, &[2098176]);     // 1821 => [2098176]
+        map.insert(1015, &[6144]);     // 1015 => [6144]
+        map.insert(318, &[10240]);     // 318 => [10240]
+        map.insert(395, &[18432]);     // 395 => [18432]
+        map.insert(1478, &[34816]);     // 1478 => [34816]
+        map.insert(1811, &[67584]);     // 1811 => [67584]
+        map.insert(697, &[133120]);     // 697 => [133120]
+        map.insert(1669, &[264192]);     // 1669 => [264192]
+        map.insert(1202, &[526336]);     // 1202 => [526336]
+        map.insert(220, &[1050624]);     // 220 => [1050624]
+        map.insert(1141, &[2099200]);     // 1141 => [2099200]
+        map.insert(713, &[12288]);     // 713 => [12288]
+        map.insert(636, &[20480]);     // 636 => [20480]
+        map.insert(1585, &[36864]);     // 1585 => [36864]
+        map.insert(1252, &[69632]);     // 1252 => [69632]
+        map.insert(334, &[135168]);     // 334 => [135168]
+        map.insert(1394, &[266240]);     // 1394 => [266240]
+        map.insert(1861, &[528384]);     // 1861 => [528384]
+        map.insert(811, &[1052672]);     // 811 => [1052672]
+        map.insert(1922, &[2101248]);     // 1922 => [2101248]
+        map.insert(181, &[24576]);     // 181 => [24576]
+        map.insert(1272, &[40960]);     // 1272 => [40960]
+        map.insert(1581, &[73728]);     // 1581 => [73728]
+        map.insert(903, &[139264]);     // 903 => [139264]
+        map.insert(1979, &[270336]);     // 1979 => [270336]
+        map.insert(1420, &[532480]);     // 1420 => [532480]
+        map.insert(482, &[1056768]);     // 482 => [1056768]
+        map.insert(1355, &[2105344]);     // 1355 => [2105344]
+        map.insert(1101, &[49152]);     // 1101 => [49152]
+        map.insert(1688, &[81920]);     // 1688 => [81920]
+        map.insert(818, &[147456]);     // 818 => [147456]
+        map.insert(1806, &[278528]);     // 1806 => [278528]
+        map.insert(1337, &[540672]);     // 1337 => [540672]
+        map.insert(343, &[1064960]);     // 343 => [1064960]
+        map.insert(1534, &[2113536]);     // 1534 => [2113536]
+        map.insert(725, &[98304]);     // 725 => [98304]
+        map.insert(1919, &[163840]);     // 1919 => [163840]
+        map.insert(835, &[294912]);     // 835 => [294912]
+        map.insert(372, &[557056]);     // 372 => [557056]
+        map.insert(1306, &[1081344]);     // 1306 => [1081344]
+        map.insert(435, &[2129920]);     // 435 => [2129920]
+        map.insert(1450, &[196608]);     // 1450 => [196608]
+        map.insert(406, &[327680]);     // 406 => [327680]
+        map.insert(929, &[589824]);     // 929 => [589824]
+        map.insert(1999, &[1114112]);     // 1999 => [1114112]
+        map.insert(870, &[2162688]);     // 870 => [2162688]
+        map.insert(1084, &[393216]);     // 1084 => [393216]
+        map.insert(1547, &[655360]);     // 1547 => [655360]
+        map.insert(613, &[1179648]);     // 613 => [1179648]
+        map.insert(1740, &[2228224]);     // 1740 => [2228224]
+        map.insert(567, &[786432]);     // 567 => [786432]
+        map.insert(1625, &[1310720]);     // 1625 => [1310720]
+        map.insert(752, &[2359296]);     // 752 => [2359296]
+        map.insert(1134, &[1572864]);     // 1134 => [1572864]
+        map.insert(199, &[2621440]);     // 199 => [2621440]
+        map.insert(1193, &[3145728]);     // 1193 => [3145728]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(19, &[19]);     // 19 => [19]
+        map.insert(35, &[35]);     // 35 => [35]
+        map.insert(67, &[67]);     // 67 => [67]
+        map.insert(131, &[131]);     // 131 => [131]
+        map.insert(259, &[259]);     // 259 => [259]
+        map.insert(515, &[515]);     // 515 => [515]
+        map.insert(1027, &[1027]);     // 1027 => [1027]
+        map.insert(1899, &[2051]);     // 1899 => [2051]
+        map.insert(1180, &[4099]);     // 1180 => [4099]
+        map.insert(1621, &[8195]);     // 1621 => [8195]
+        map.insert(1760, &[16387]);     // 1760 => [16387]
+        map.insert(685, &[32771]);     // 685 => [32771]
+        map.insert(120, &[65539]);     // 120 => [65539]
+        map.insert(1490, &[131075]);     // 1490 => [131075]
+        map.insert(494, &[262147]);     // 494 => [262147]
+        map.insert(985, &[524291]);     // 985 => [524291]
+        map.insert(1975, &[1048579]);     // 1975 => [1048579]
+        map.insert(798, &[2097155]);     // 798 => [2097155]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(69, &[69]);     // 69 => [69]
+        map.insert(133, &[133]);     // 133 => [133]
+        map.insert(261, &[261]);     // 261 => [261]
+        map.insert(517, &[517]);     // 517 => [517]
+        map.insert(1029, &[1029]);     // 1029 => [1029]
+        map.insert(1901, &[2053]);     // 1901 => [2053]
+        map.insert(1178, &[4101]);     // 1178 => [4101]
+        map.insert(1619, &[8197]);     // 1619 => [8197]
+        map.insert(1766, &[16389]);     // 1766 => [16389]
+        map.insert(683, &[32773]);     // 683 => [32773]
+        map.insert(126, &[65541]);     // 126 => [65541]
+        map.insert(1492, &[131077]);     // 1492 => [131077]
+        map.insert(488, &[262149]);     // 488 => [262149]
+        map.insert(991, &[524293]);     // 991 => [524293]
+        map.insert(1969, &[1048581]);     // 1969 => [1048581]
+        map.insert(792, &[2097157]);     // 792 => [2097157]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(41, &[41]);     // 41 => [41]
+        map.insert(73, &[73]);     // 73 => [73]
+        map.insert(137, &[137]);     // 137 => [137]
+        map.insert(265, &[265]);     // 265 => [265]
+        map.insert(521, &[521]);     // 521 => [521]
+        map.insert(1033, &[1033]);     // 1033 => [1033]
+        map.insert(1889, &[2057]);     // 1889 => [2057]
+        map.insert(1174, &[4105]);     // 1174 => [4105]
+        map.insert(1631, &[8201]);     // 1631 => [8201]
+        map.insert(1770, &[16393]);     // 1770 => [16393]
+        map.insert(679, &[32777]);     // 679 => [32777]
+        map.insert(114, &[65545]);     // 114 => [65545]
+        map.insert(1496, &[131081]);     // 1496 => [131081]
+        map.insert(484, &[262153]);     // 484 => [262153]
+        map.insert(979, &[524297]);     // 979 => [524297]
+        map.insert(1981, &[1048585]);     // 1981 => [1048585]
+        map.insert(788, &[2097161]);     // 788 => [2097161]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(81, &[81]);     // 81 => [81]
+        map.insert(145, &[145]);     // 145 => [145]
+        map.insert(273, &[273]);     // 273 => [273]
+        map.insert(529, &[529]);     // 529 => [529]
+        map.insert(1041, &[1041]);     // 1041 => [1041]
+        map.insert(1913, &[2065]);     // 1913 => [2065]
+        map.insert(1166, &[4113]);     // 1166 => [4113]
+        map.insert(1607, &[8209]);     // 1607 => [8209]
+        map.insert(1778, &[16401]);     // 1778 => [16401]
+        map.insert(703, &[32785]);     // 703 => [32785]
+        map.insert(106, &[65553]);     // 106 => [65553]
+        map.insert(1472, &[131089]);     // 1472 => [131089]
+        map.insert(508, &[262161]);     // 508 => [262161]
+        map.insert(971, &[524305]);     // 971 => [524305]
+        map.insert(1957, &[1048593]);     // 1957 => [1048593]
+        map.insert(780, &[2097169]);     // 780 => [2097169]
+        map.insert(97, &[97]);     // 97 => [97]
+        map.insert(161, &[161]);     // 161 => [161]
+        map.insert(289, &[289]);     // 289 => [289]
+        map.insert(545, &[545]);     // 545 => [545]
+        map.insert(1057, &[1057]);     // 1057 => [1057]
+        map.insert(1865, &[2081]);     // 1865 => [2081]
+        map.insert(1214, &[4129]);     // 1214 => [4129]
+        map.insert(1655, &[8225]);     // 1655 => [8225]
+        map.insert(1730, &[16417]);     // 1730 => [16417]
+        map.insert(655, &[32801]);     // 655 => [32801]
+        map.insert(90, &[65569]);     // 90 => [65569]
+        map.insert(1520, &[131105]);     // 1520 => [131105]
+        map.insert(460, &[262177]);     // 460 => [262177]
+        map.insert(1019, &[524321]);     // 1019 => [524321]
+        map.insert(1941, &[1048609]);     // 1941 => [1048609]
+        map.insert(828, &[2097185]);     // 828 => [2097185]
+        map.insert(193, &[193]);     // 193 => [193]
+        map.insert(321, &[321]);     // 321 => [321]
+        map.insert(577, &[577]);     // 577 => [577]
+        map.insert(1089, &[1089]);     // 1089 => [1089]
+        map.insert(1833, &[2113]);     // 1833 => [2113]
+        map.insert(1246, &[4161]);     // 1246 => [4161]
+        map.insert(1559, &[8257]);     // 1559 => [8257]
+        map.insert(1698, &[16449]);     // 1698 => [16449]
+        map.insert(751, &[32833]);     // 751 => [32833]
+        map.insert(58, &[65601]);     // 58 => [65601]
+        map.insert(1424, &[131137]);     // 1424 => [131137]
+        map.insert(428, &[262209]);     // 428 => [262209]
+        map.insert(923, &[524353]);     // 923 => [524353]
+        map.insert(2037, &[1048641]);     // 2037 => [1048641]
+        map.insert(860, &[2097217]);     // 860 => [2097217]
+        map.insert(385, &[385]);     // 385 => [385]
+        map.insert(641, &[641]);     // 641 => [641]
+        map.insert(1153, &[1153]);     // 1153 => [1153]
+        map.insert(2025, &[2177]);     // 2025 => [2177]
+        map.insert(1054, &[4225]);     // 1054 => [4225]
+        map.insert(1751, &[8321]);     // 1751 => [8321]
+        map.insert(1634, &[16513]);     // 1634 => [16513]
+        map.insert(559, &[32897]);     // 559 => [32897]
+        map.insert(250, &[65665]);     // 250 => [65665]
+        map.insert(1360, &[131201]);     // 1360 => [131201]
+        map.insert(364, &[262273]);     // 364 => [262273]
+        map.insert(859, &[524417]);     // 859 => [524417]
+        map.insert(1845, &[1048705]);     // 1845 => [1048705]
+        map.insert(924, &[2097281]);     // 924 => [2097281]
+        map.insert(769, &[769]);     // 769 => [769]
+        map.insert(1281, &[1281]);     // 1281 => [1281]
+        map.insert(1641, &[2305]);     // 1641 => [2305]
+        map.insert(1438, &[4353]);     // 1438 => [4353]
+        map.insert(1879, &[8449]);     // 1879 => [8449]
+        map.insert(2018, &[16641]);     // 2018 => [16641]
+        map.insert(943, &[33025]);     // 943 => [33025]
+        map.insert(378, &[65793]);     // 378 => [65793]
+        map.insert(1232, &[131329]);     // 1232 => [131329]
+        map.insert(236, &[262401]);     // 236 => [262401]
+        map.insert(731, &[524545]);     // 731 => [524545]
+        map.insert(1717, &[1048833]);     // 1717 => [1048833]
+        map.insert(540, &[2097409]);     // 540 => [2097409]
+        map.insert(1537, &[1537]);     // 1537 => [1537]
+        map.insert(1385, &[2561]);     // 1385 => [2561]
+        map.insert(1694, &[4609]);     // 1694 => [4609]
+        map.insert(1111, &[8705]);     // 1111 => [8705]
+        map.insert(1250, &[16897]);     // 1250 => [16897]
+        map.insert(175, &[33281]);     // 175 => [33281]
+        map.insert(634, &[66049]);     // 634 => [66049]
+        map.insert(2000, &[131585]);     // 2000 => [131585]
+        map.insert(1004, &[262657]);     // 1004 => [262657]
+        map.insert(475, &[524801]);     // 475 => [524801]
+        map.insert(1461, &[1049089]);     // 1461 => [1049089]
+        map.insert(284, &[2097665]);     // 284 => [2097665]
+        map.insert(873, &[3073]);     // 873 => [3073]
+        map.insert(158, &[5121]);     // 158 => [5121]
+        map.insert(599, &[9217]);     // 599 => [9217]
+        map.insert(738, &[17409]);     // 738 => [17409]
+        map.insert(1711, &[33793]);     // 1711 => [33793]
+        map.insert(1146, &[66561]);     // 1146 => [66561]
+        map.insert(464, &[132097]);     // 464 => [132097]
+        map.insert(1516, &[263169]);     // 1516 => [263169]
+        map.insert(2011, &[525313]);     // 2011 => [525313]
+        map.insert(949, &[1049601]);     // 949 => [1049601]
+        map.insert(1820, &[2098177]);     // 1820 => [2098177]
+        map.insert(1014, &[6145]);     // 1014 => [6145]
+        map.insert(319, &[10241]);     // 319 => [10241]
+        map.insert(394, &[18433]);     // 394 => [18433]
+        map.insert(1479, &[34817]);     // 1479 => [34817]
+        map.insert(1810, &[67585]);     // 1810 => [67585]
+        map.insert(696, &[133121]);     // 696 => [133121]
+        map.insert(1668, &[264193]);     // 1668 => [264193]
+        map.insert(1203, &[526337]);     // 1203 => [526337]
+        map.insert(221, &[1050625]);     // 221 => [1050625]
+        map.insert(1140, &[2099201]);     // 1140 => [2099201]
+        map.insert(712, &[12289]);     // 712 => [12289]
+        map.insert(637, &[20481]);     // 637 => [20481]
+        map.insert(1584, &[36865]);     // 1584 => [36865]
+        map.insert(1253, &[69633]);     // 1253 => [69633]
+        map.insert(335, &[135169]);     // 335 => [135169]
+        map.insert(1395, &[266241]);     // 1395 => [266241]
+        map.insert(1860, &[528385]);     // 1860 => [528385]
+        map.insert(810, &[1052673]);     // 810 => [1052673]
+        map.insert(1923, &[2101249]);     // 1923 => [2101249]
+        map.insert(180, &[24577]);     // 180 => [24577]
+        map.insert(1273, &[40961]);     // 1273 => [40961]
+        map.insert(1580, &[73729]);     // 1580 => [73729]
+        map.insert(902, &[139265]);     // 902 => [139265]
+        map.insert(1978, &[270337]);     // 1978 => [270337]
+        map.insert(1421, &[532481]);     // 1421 => [532481]
+        map.insert(483, &[1056769]);     // 483 => [1056769]
+        map.insert(1354, &[2105345]);     // 1354 => [2105345]
+        map.insert(1100, &[49153]);     // 1100 => [49153]
+        map.insert(1689, &[81921]);     // 1689 => [81921]
+        map.insert(819, &[147457]);     // 819 => [147457]
+        map.insert(1807, &[278529]);     // 1807 => [278529]
+        map.insert(1336, &[540673]);     // 1336 => [540673]
+        map.insert(342, &[1064961]);     // 342 => [1064961]
+        map.insert(1535, &[2113537]);     // 1535 => [2113537]
+        map.insert(724, &[98305]);     // 724 => [98305]
+        map.insert(1918, &[163841]);     // 1918 => [163841]
+        map.insert(834, &[294913]);     // 834 => [294913]
+        map.insert(373, &[557057]);     // 373 => [557057]
+        map.insert(1307, &[1081345]);     // 1307 => [1081345]
+        map.insert(434, &[2129921]);     // 434 => [2129921]
+        map.insert(1451, &[196609]);     // 1451 => [196609]
+        map.insert(407, &[327681]);     // 407 => [327681]
+        map.insert(928, &[589825]);     // 928 => [589825]
+        map.insert(1998, &[1114113]);     // 1998 => [1114113]
+        map.insert(871, &[2162689]);     // 871 => [2162689]
+        map.insert(1085, &[393217]);     // 1085 => [393217]
+        map.insert(1546, &[655361]);     // 1546 => [655361]
+        map.insert(612, &[1179649]);     // 612 => [1179649]
+        map.insert(1741, &[2228225]);     // 1741 => [2228225]
+        map.insert(566, &[786433]);     // 566 => [786433]
+        map.insert(1624, &[1310721]);     // 1624 => [1310721]
+        map.insert(753, &[2359297]);     // 753 => [2359297]
+        map.insert(1135, &[1572865]);     // 1135 => [1572865]
+        map.insert(198, &[2621441]);     // 198 => [2621441]
+        map.insert(1192, &[3145729]);     // 1192 => [3145729]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(70, &[70]);     // 70 => [70]
+        map.insert(134, &[134]);     // 134 => [134]
+        map.insert(262, &[262]);     // 262 => [262]
+        map.insert(518, &[518]);     // 518 => [518]
+        map.insert(1030, &[1030]);     // 1030 => [1030]
+        map.insert(1902, &[2054]);     // 1902 => [2054]
+        map.insert(1177, &[4102]);     // 1177 => [4102]
+        map.insert(1616, &[8198]);     // 1616 => [8198]
+        map.insert(1765, &[16390]);     // 1765 => [16390]
+        map.insert(680, &[32774]);     // 680 => [32774]
+        map.insert(125, &[65542]);     // 125 => [65542]
+        map.insert(1495, &[131078]);     // 1495 => [131078]
+        map.insert(491, &[262150]);     // 491 => [262150]
+        map.insert(988, &[524294]);     // 988 => [524294]
+        map.insert(1970, &[1048582]);     // 1970 => [1048582]
+        map.insert(795, &[2097158]);     // 795 => [2097158]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(42, &[42]);     // 42 => [42]
+        map.insert(74, &[74]);     // 74 => [74]
+        map.insert(138, &[138]);     // 138 => [138]
+        map.insert(266, &[266]);     // 266 => [266]
+        map.insert(522, &[522]);     // 522 => [522]
+        map.insert(1034, &[1034]);     // 1034 => [1034]
+        map.insert(1890, &[2058]);     // 1890 => [2058]
+        map.insert(1173, &[4106]);     // 1173 => [4106]
+        map.insert(1628, &[8202]);     // 1628 => [8202]
+        map.insert(1769, &[16394]);     // 1769 => [16394]
+        map.insert(676, &[32778]);     // 676 => [32778]
+        map.insert(113, &[65546]);     // 113 => [65546]
+        map.insert(1499, &[131082]);     // 1499 => [131082]
+        map.insert(487, &[262154]);     // 487 => [262154]
+        map.insert(976, &[524298]);     // 976 => [524298]
+        map.insert(1982, &[1048586]);     // 1982 => [1048586]
+        map.insert(791, &[2097162]);     // 791 => [2097162]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(82, &[82]);     // 82 => [82]
+        map.insert(146, &[146]);     // 146 => [146]
+        map.insert(274, &[274]);     // 274 => [274]
+        map.insert(530, &[530]);     // 530 => [530]
+        map.insert(1042, &[1042]);     // 1042 => [1042]
+        map.insert(1914, &[2066]);     // 1914 => [2066]
+        map.insert(1165, &[4114]);     // 1165 => [4114]
+        map.insert(1604, &[8210]);     // 1604 => [8210]
+        map.insert(1777, &[16402]);     // 1777 => [16402]
+        map.insert(700, &[32786]);     // 700 => [32786]
+        map.insert(105, &[65554]);     // 105 => [65554]
+        map.insert(1475, &[131090]);     // 1475 => [131090]
+        map.insert(511, &[262162]);     // 511 => [262162]
+        map.insert(968, &[524306]);     // 968 => [524306]
+        map.insert(1958, &[1048594]);     // 1958 => [1048594]
+        map.insert(783, &[2097170]);     // 783 => [2097170]
+        map.insert(98, &[98]);     // 98 => [98]
+        map.insert(162, &[162]);     // 162 => [162]
+        map.insert(290, &[290]);     // 290 => [290]
+        map.insert(546, &[546]);     // 546 => [546]
+        map.insert(1058, &[1058]);     // 1058 => [1058]
+        map.insert(1866, &[2082]);     // 1866 => [2082]
+        map.insert(1213, &[4130]);     // 1213 => [4130]
+        map.insert(1652, &[8226]);     // 1652 => [8226]
+        map.insert(1729, &[16418]);     // 1729 => [16418]
+        map.insert(652, &[32802]);     // 652 => [32802]
+        map.insert(89, &[65570]);     // 89 => [65570]
+        map.insert(1523, &[131106]);     // 1523 => [131106]
+        map.insert(463, &[262178]);     // 463 => [262178]
+        map.insert(1016, &[524322]);     // 1016 => [524322]
+        map.insert(1942, &[1048610]);     // 1942 => [1048610]
+        map.insert(831, &[2097186]);     // 831 => [2097186]
+        map.insert(194, &[194]);     // 194 => [194]
+        map.insert(322, &[322]);     // 322 => [322]
+        map.insert(578, &[578]);     // 578 => [578]
+        map.insert(1090, &[1090]);     // 1090 => [1090]
+        map.insert(1834, &[2114]);     // 1834 => [2114]
+        map.insert(1245, &[4162]);     // 1245 => [4162]
+        map.insert(1556, &[8258]);     // 1556 => [8258]
+        map.insert(1697, &[16450]);     // 1697 => [16450]
+        map.insert(748, &[32834]);     // 748 => [32834]
+        map.insert(57, &[65602]);     // 57 => [65602]
+        map.insert(1427, &[131138]);     // 1427 => [131138]
+        map.insert(431, &[262210]);     // 431 => [262210]
+        map.insert(920, &[524354]);     // 920 => [524354]
+        map.insert(2038, &[1048642]);     // 2038 => [1048642]
+        map.insert(863, &[2097218]);     // 863 => [2097218]
+        map.insert(386, &[386]);     // 386 => [386]
+        map.insert(642, &[642]);     // 642 => [642]
+        map.insert(1154, &[1154]);     // 1154 => [1154]
+        map.insert(2026, &[2178]);     // 2026 => [2178]
+        map.insert(1053, &[4226]);     // 1053 => [4226]
+        map.insert(1748, &[8322]);     // 1748 => [8322]
+        map.insert(1633, &[16514]);     // 1633 => [16514]
+        map.insert(556, &[32898]);     // 556 => [32898]
+        map.insert(249, &[65666]);     // 249 => [65666]
+        map.insert(1363, &[131202]);     // 1363 => [131202]
+        map.insert(367, &[262274]);     // 367 => [262274]
+        map.insert(856, &[524418]);     // 856 => [524418]
+        map.insert(1846, &[1048706]);     // 1846 => [1048706]
+        map.insert(927, &[2097282]);     // 927 => [2097282]
+        map.insert(770, &[770]);     // 770 => [770]
+        map.insert(1282, &[1282]);     // 1282 => [1282]
+        map.insert(1642, &[2306]);     // 1642 => [2306]
+        map.insert(1437, &[4354]);     // 1437 => [4354]
+        map.insert(1876, &[8450]);     // 1876 => [8450]
+        map.insert(2017, &[16642]);     // 2017 => [16642]
+        map.insert(940, &[33026]);     // 940 => [33026]
+        map.insert(377, &[65794]);     // 377 => [65794]
+        map.insert(1235, &[131330]);     // 1235 => [131330]
+        map.insert(239, &[262402]);     // 239 => [262402]
+        map.insert(728, &[524546]);     // 728 => [524546]
+        map.insert(1718, &[1048834]);     // 1718 => [1048834]
+        map.insert(543, &[2097410]);     // 543 => [2097410]
+        map.insert(1538, &[1538]);     // 1538 => [1538]
+        map.insert(1386, &[2562]);     // 1386 => [2562]
+        map.insert(1693, &[4610]);     // 1693 => [4610]
+        map.insert(1108, &[8706]);     // 1108 => [8706]
+        map.insert(1249, &[16898]);     // 1249 => [16898]
+        map.insert(172, &[33282]);     // 172 => [33282]
+        map.insert(633, &[66050]);     // 633 => [66050]
+        map.insert(2003, &[131586]);     // 2003 => [131586]
+        map.insert(1007, &[262658]);     // 1007 => [262658]
+        map.insert(472, &[524802]);     // 472 => [524802]
+        map.insert(1462, &[1049090]);     // 1462 => [1049090]
+        map.insert(287, &[2097666]);     // 287 => [2097666]
+        map.insert(874, &[3074]);     // 874 => [3074]
+        map.insert(157, &[5122]);     // 157 => [5122]
+        map.insert(596, &[9218]);     // 596 => [9218]
+        map.insert(737, &[17410]);     // 737 => [17410]
+        map.insert(1708, &[33794]);     // 1708 => [33794]
+        map.insert(1145, &[66562]);     // 1145 => [66562]
+        map.insert(467, &[132098]);     // 467 => [132098]
+        map.insert(1519, &[263170]);     // 1519 => [263170]
+        map.insert(2008, &[525314]);     // 2008 => [525314]
+        map.insert(950, &[1049602]);     // 950 => [1049602]
+        map.insert(1823, &[2098178]);     // 1823 => [2098178]
+        map.insert(1013, &[6146]);     // 1013 => [6146]
+        map.insert(316, &[10242]);     // 316 => [10242]
+        map.insert(393, &[18434]);     // 393 => [18434]
+        map.insert(1476, &[34818]);     // 1476 => [34818]
+        map.insert(1809, &[67586]);     // 1809 => [67586]
+        map.insert(699, &[133122]);     // 699 => [133122]
+        map.insert(1671, &[264194]);     // 1671 => [264194]
+        map.insert(1200, &[526338]);     // 1200 => [526338]
+        map.insert(222, &[1050626]);     // 222 => [1050626]
+        map.insert(1143, &[2099202]);     // 1143 => [2099202]
+        map.insert(715, &[12290]);     // 715 => [12290]
+        map.insert(638, &[20482]);     // 638 => [20482]
+        map.insert(1587, &[36866]);     // 1587 => [36866]
+        map.insert(1254, &[69634]);     // 1254 => [69634]
+        map.insert(332, &[135170]);     // 332 => [135170]
+        map.insert(1392, &[266242]);     // 1392 => [266242]
+        map.insert(1863, &[528386]);     // 1863 => [528386]
+        map.insert(809, &[1052674]);     // 809 => [1052674]
+        map.insert(1920, &[2101250]);     // 1920 => [2101250]
+        map.insert(183, &[24578]);     // 183 => [24578]
+        map.insert(1274, &[40962]);     // 1274 => [40962]
+        map.insert(1583, &[73730]);     // 1583 => [73730]
+        map.insert(901, &[139266]);     // 901 => [139266]
+        map.insert(1977, &[270338]);     // 1977 => [270338]
+        map.insert(1422, &[532482]);     // 1422 => [532482]
+        map.insert(480, &[1056770]);     // 480 => [1056770]
+        map.insert(1353, &[2105346]);     // 1353 => [2105346]
+        map.insert(1103, &[49154]);     // 1103 => [49154]
+        map.insert(1690, &[81922]);     // 1690 => [81922]
+        map.insert(816, &[147458]);     // 816 => [147458]
+        map.insert(1804, &[278530]);     // 1804 => [278530]
+        map.insert(1339, &[540674]);     // 1339 => [540674]
+        map.insert(341, &[1064962]);     // 341 => [1064962]
+        map.insert(1532, &[2113538]);     // 1532 => [2113538]
+        map.insert(727, &[98306]);     // 727 => [98306]
+        map.insert(1917, &[163842]);     // 1917 => [163842]
+        map.insert(833, &[294914]);     // 833 => [294914]
+        map.insert(374, &[557058]);     // 374 => [557058]
+        map.insert(1304, &[1081346]);     // 1304 => [1081346]
+        map.insert(433, &[2129922]);     // 433 => [2129922]
+        map.insert(1448, &[196610]);     // 1448 => [196610]
+        map.insert(404, &[327682]);     // 404 => [327682]
+        map.insert(931, &[589826]);     // 931 => [589826]
+        map.insert(1997, &[1114114]);     // 1997 => [1114114]
+        map.insert(868, &[2162690]);     // 868 => [2162690]
+        map.insert(1086, &[393218]);     // 1086 => [393218]
+        map.insert(1545, &[655362]);     // 1545 => [655362]
+        map.insert(615, &[1179650]);     // 615 => [1179650]
+        map.insert(1742, &[2228226]);     // 1742 => [2228226]
+        map.insert(565, &[786434]);     // 565 => [786434]
+        map.insert(1627, &[1310722]);     // 1627 => [1310722]
+        map.insert(754, &[2359298]);     // 754 => [2359298]
+        map.insert(1132, &[1572866]);     // 1132 => [1572866]
+        map.insert(197, &[2621442]);     // 197 => [2621442]
+        map.insert(1195, &[3145730]);     // 1195 => [3145730]
+        map.insert(28, &[28]);     // 28 => [28]
+        map.insert(44, &[44]);     // 44 => [44]
+        map.insert(76, &[76]);     // 76 => [76]
+        map.insert(140, &[140]);     // 140 => [140]
+        map.insert(268, &[268]);     // 268 => [268]
+        map.insert(524, &[524]);     // 524 => [524]
+        map.insert(1036, &[1036]);     // 1036 => [1036]
+        map.insert(1892, &[2060]);     // 1892 => [2060]
+        map.insert(1171, &[4108]);     // 1171 => [4108]
+        map.insert(1626, &[8204]);     // 1626 => [8204]
+        map.insert(1775, &[16396]);     // 1775 => [16396]
+        map.insert(674, &[32780]);     // 674 => [32780]
+        map.insert(119, &[65548]);     // 119 => [65548]
+        map.insert(1501, &[131084]);     // 1501 => [131084]
+        map.insert(481, &[262156]);     // 481 => [262156]
+        map.insert(982, &[524300]);     // 982 => [524300]
+        map.insert(1976, &[1048588]);     // 1976 => [1048588]
+        map.insert(785, &[2097164]);     // 785 => [2097164]
+        map.insert(52, &[52]);     // 52 => [52]
+        map.insert(84, &[84]);     // 84 => [84]
+        map.insert(148, &[148]);     // 148 => [148]
+        map.insert(276, &[276]);     // 276 => [276]
+        map.insert(532, &[532]);     // 532 => [532]
+        map.insert(1044, &[1044]);     // 1044 => [1044]
+        map.insert(1916, &[2068]);     // 1916 => [2068]
+        map.insert(1163, &[4116]);     // 1163 => [4116]
+        map.insert(1602, &[8212]);     // 1602 => [8212]
+        map.insert(1783, &[16404]);     // 1783 => [16404]
+        map.insert(698, &[32788]);     // 698 => [32788]
+        map.insert(111, &[65556]);     // 111 => [65556]
+        map.insert(1477, &[131092]);     // 1477 => [131092]
+        map.insert(505, &[262164]);     // 505 => [262164]
+        map.insert(974, &[524308]);     // 974 => [524308]
+        map.insert(1952, &[1048596]);     // 1952 => [1048596]
+        map.insert(777, &[2097172]);     // 777 => [2097172]
+        map.insert(100, &[100]);     // 100 => [100]
+        map.insert(164, &[164]);     // 164 => [164]
+        map.insert(292, &[292]);     // 292 => [292]
+        map.insert(548, &[548]);     // 548 => [548]
+        map.insert(1060, &[1060]);     // 1060 => [1060]
+        map.insert(1868, &[2084]);     // 1868 => [2084]
+        map.insert(1211, &[4132]);     // 1211 => [4132]
+        map.insert(1650, &[8228]);     // 1650 => [8228]
+        map.insert(1735, &[16420]);     // 1735 => [16420]
+        map.insert(650, &[32804]);     // 650 => [32804]
+        map.insert(95, &[65572]);     // 95 => [65572]
+        map.insert(1525, &[131108]);     // 1525 => [131108]
+        map.insert(457, &[262180]);     // 457 => [262180]
+        map.insert(1022, &[524324]);     // 1022 => [524324]
+        map.insert(1936, &[1048612]);     // 1936 => [1048612]
+        map.insert(825, &[2097188]);     // 825 => [2097188]
+        map.insert(196, &[196]);     // 196 => [196]
+        map.insert(324, &[324]);     // 324 => [324]
+        map.insert(580, &[580]);     // 580 => [580]
+        map.insert(1092, &[1092]);     // 1092 => [1092]
+        map.insert(1836, &[2116]);     // 1836 => [2116]
+        map.insert(1243, &[4164]);     // 1243 => [4164]
+        map.insert(1554, &[8260]);     // 1554 => [8260]
+        map.insert(1703, &[16452]);     // 1703 => [16452]
+        map.insert(746, &[32836]);     // 746 => [32836]
+        map.insert(63, &[65604]);     // 63 => [65604]
+        map.insert(1429, &[131140]);     // 1429 => [131140]
+        map.insert(425, &[262212]);     // 425 => [262212]
+        map.insert(926, &[524356]);     // 926 => [524356]
+        map.insert(2032, &[1048644]);     // 2032 => [1048644]
+        map.insert(857, &[2097220]);     // 857 => [2097220]
+        map.insert(388, &[388]);     // 388 => [388]
+        map.insert(644, &[644]);     // 644 => [644]
+        map.insert(1156, &[1156]);     // 1156 => [1156]
+        map.insert(2028, &[2180]);     // 2028 => [2180]
+        map.insert(1051, &[4228]);     // 1051 => [4228]
+        map.insert(1746, &[8324]);     // 1746 => [8324]
+        map.insert(1639, &[16516]);     // 1639 => [16516]
+        map.insert(554, &[32900]);     // 554 => [32900]
+        map.insert(255, &[65668]);     // 255 => [65668]
+        map.insert(1365, &[131204]);     // 1365 => [131204]
+        map.insert(361, &[262276]);     // 361 => [262276]
+        map.insert(862, &[524420]);     // 862 => [524420]
+        map.insert(1840, &[1048708]);     // 1840 => [1048708]
+        map.insert(921, &[2097284]);     // 921 => [2097284]
+        map.insert(772, &[772]);     // 772 => [772]
+        map.insert(1284, &[1284]);     // 1284 => [1284]
+        map.insert(1644, &[2308]);     // 1644 => [2308]
+        map.insert(1435, &[4356]);     // 1435 => [4356]
+        map.insert(1874, &[8452]);     // 1874 => [8452]
+        map.insert(2023, &[16644]);     // 2023 => [16644]
+        map.insert(938, &[33028]);     // 938 => [33028]
+        map.insert(383, &[65796]);     // 383 => [65796]
+        map.insert(1237, &[131332]);     // 1237 => [131332]
+        map.insert(233, &[262404]);     // 233 => [262404]
+        map.insert(734, &[524548]);     // 734 => [524548]
+        map.insert(1712, &[1048836]);     // 1712 => [1048836]
+        map.insert(537, &[2097412]);     // 537 => [2097412]
+        map.insert(1540, &[1540]);     // 1540 => [1540]
+        map.insert(1388, &[2564]);     // 1388 => [2564]
+        map.insert(1691, &[4612]);     // 1691 => [4612]
+        map.insert(1106, &[8708]);     // 1106 => [8708]
+        map.insert(1255, &[16900]);     // 1255 => [16900]
+        map.insert(170, &[33284]);     // 170 => [33284]
+        map.insert(639, &[66052]);     // 639 => [66052]
+        map.insert(2005, &[131588]);     // 2005 => [131588]
+        map.insert(1001, &[262660]);     // 1001 => [262660]
+        map.insert(478, &[524804]);     // 478 => [524804]
+        map.insert(1456, &[1049092]);     // 1456 => [1049092]
+        map.insert(281, &[2097668]);     // 281 => [2097668]
+        map.insert(876, &[3076]);     // 876 => [3076]
+        map.insert(155, &[5124]);     // 155 => [5124]
+        map.insert(594, &[9220]);     // 594 => [9220]
+        map.insert(743, &[17412]);     // 743 => [17412]
+        map.insert(1706, &[33796]);     // 1706 => [33796]
+        map.insert(1151, &[66564]);     // 1151 => [66564]
+        map.insert(469, &[132100]);     // 469 => [132100]
+        map.insert(1513, &[263172]);     // 1513 => [263172]
+        map.insert(2014, &[525316]);     // 2014 => [525316]
+        map.insert(944, &[1049604]);     // 944 => [1049604]
+        map.insert(1817, &[2098180]);     // 1817 => [2098180]
+        map.insert(1011, &[6148]);     // 1011 => [6148]
+        map.insert(314, &[10244]);     // 314 => [10244]
+        map.insert(399, &[18436]);     // 399 => [18436]
+        map.insert(1474, &[34820]);     // 1474 => [34820]
+        map.insert(1815, &[67588]);     // 1815 => [67588]
+        map.insert(701, &[133124]);     // 701 => [133124]
+        map.insert(1665, &[264196]);     // 1665 => [264196]
+        map.insert(1206, &[526340]);     // 1206 => [526340]
+        map.insert(216, &[1050628]);     // 216 => [1050628]
+        map.insert(1137, &[2099204]);     // 1137 => [2099204]
+        map.insert(717, &[12292]);     // 717 => [12292]
+        map.insert(632, &[20484]);     // 632 => [20484]
+        map.insert(1589, &[36868]);     // 1589 => [36868]
+        map.insert(1248, &[69636]);     // 1248 => [69636]
+        map.insert(330, &[135172]);     // 330 => [135172]
+        map.insert(1398, &[266244]);     // 1398 => [266244]
+        map.insert(1857, &[528388]);     // 1857 => [528388]
+        map.insert(815, &[1052676]);     // 815 => [1052676]
+        map.insert(1926, &[2101252]);     // 1926 => [2101252]
+        map.insert(177, &[24580]);     // 177 => [24580]
+        map.insert(1276, &[40964]);     // 1276 => [40964]
+        map.insert(1577, &[73732]);     // 1577 => [73732]
+        map.insert(899, &[139268]);     // 899 => [139268]
+        map.insert(1983, &[270340]);     // 1983 => [270340]
+        map.insert(1416, &[532484]);     // 1416 => [532484]
+        map.insert(486, &[1056772]);     // 486 => [1056772]
+        map.insert(1359, &[2105348]);     // 1359 => [2105348]
+        map.insert(1097, &[49156]);     // 1097 => [49156]
+        map.insert(1692, &[81924]);     // 1692 => [81924]
+        map.insert(822, &[147460]);     // 822 => [147460]
+        map.insert(1802, &[278532]);     // 1802 => [278532]
+        map.insert(1341, &[540676]);     // 1341 => [540676]
+        map.insert(339, &[1064964]);     // 339 => [1064964]
+        map.insert(1530, &[2113540]);     // 1530 => [2113540]
+        map.insert(721, &[98308]);     // 721 => [98308]
+        map.insert(1915, &[163844]);     // 1915 => [163844]
+        map.insert(839, &[294916]);     // 839 => [294916]
+        map.insert(368, &[557060]);     // 368 => [557060]
+        map.insert(1310, &[1081348]);     // 1310 => [1081348]
+        map.insert(439, &[2129924]);     // 439 => [2129924]
+        map.insert(1454, &[196612]);     // 1454 => [196612]
+        map.insert(402, &[327684]);     // 402 => [327684]
+        map.insert(933, &[589828]);     // 933 => [589828]
+        map.insert(1995, &[1114116]);     // 1995 => [1114116]
+        map.insert(866, &[2162692]);     // 866 => [2162692]
+        map.insert(1080, &[393220]);     // 1080 => [393220]
+        map.insert(1551, &[655364]);     // 1551 => [655364]
+        map.insert(609, &[1179652]);     // 609 => [1179652]
+        map.insert(1736, &[2228228]);     // 1736 => [2228228]
+        map.insert(563, &[786436]);     // 563 => [786436]
+        map.insert(1629, &[1310724]);     // 1629 => [1310724]
+        map.insert(756, &[2359300]);     // 756 => [2359300]
+        map.insert(1130, &[1572868]);     // 1130 => [1572868]
+        map.insert(195, &[2621444]);     // 195 => [2621444]
+        map.insert(1197, &[3145732]);     // 1197 => [3145732]
+        map.insert(56, &[56]);     // 56 => [56]
+        map.insert(88, &[88]);     // 88 => [88]
+        map.insert(152, &[152]);     // 152 => [152]
+        map.insert(280, &[280]);     // 280 => [280]
+        map.insert(536, &[536]);     // 536 => [536]
+        map.insert(1048, &[1048]);     // 1048 => [1048]
+        map.insert(1904, &[2072]);     // 1904 => [2072]
+        map.insert(1159, &[4120]);     // 1159 => [4120]
+        map.insert(1614, &[8216]);     // 1614 => [8216]
+        map.insert(1787, &[16408]);     // 1787 => [16408]
+        map.insert(694, &[32792]);     // 694 => [32792]
+        map.insert(99, &[65560]);     // 99 => [65560]
+        map.insert(1481, &[131096]);     // 1481 => [131096]
+        map.insert(501, &[262168]);     // 501 => [262168]
+        map.insert(962, &[524312]);     // 962 => [524312]
+        map.insert(1964, &[1048600]);     // 1964 => [1048600]
+        map.insert(773, &[2097176]);     // 773 => [2097176]
+        map.insert(104, &[104]);     // 104 => [104]
+        map.insert(168, &[168]);     // 168 => [168]
+        map.insert(296, &[296]);     // 296 => [296]
+        map.insert(552, &[552]);     // 552 => [552]
+        map.insert(1064, &[1064]);     // 1064 => [1064]
+        map.insert(1856, &[2088]);     // 1856 => [2088]
+        map.insert(1207, &[4136]);     // 1207 => [4136]
+        map.insert(1662, &[8232]);     // 1662 => [8232]
+        map.insert(1739, &[16424]);     // 1739 => [16424]
+        map.insert(646, &[32808]);     // 646 => [32808]
+        map.insert(83, &[65576]);     // 83 => [65576]
+        map.insert(1529, &[131112]);     // 1529 => [131112]
+        map.insert(453, &[262184]);     // 453 => [262184]
+        map.insert(1010, &[524328]);     // 1010 => [524328]
+        map.insert(1948, &[1048616]);     // 1948 => [1048616]
+        map.insert(821, &[2097192]);     // 821 => [2097192]
+        map.insert(200, &[200]);     // 200 => [200]
+        map.insert(328, &[328]);     // 328 => [328]
+        map.insert(584, &[584]);     // 584 => [584]
+        map.insert(1096, &[1096]);     // 1096 => [1096]
+        map.insert(1824, &[2120]);     // 1824 => [2120]
+        map.insert(1239, &[4168]);     // 1239 => [4168]
+        map.insert(1566, &[8264]);     // 1566 => [8264]
+        map.insert(1707, &[16456]);     // 1707 => [16456]
+        map.insert(742, &[32840]);     // 742 => [32840]
+        map.insert(51, &[65608]);     // 51 => [65608]
+        map.insert(1433, &[131144]);     // 1433 => [131144]
+        map.insert(421, &[262216]);     // 421 => [262216]
+        map.insert(914, &[524360]);     // 914 => [524360]
+        map.insert(2044, &[1048648]);     // 2044 => [1048648]
+        map.insert(853, &[2097224]);     // 853 => [2097224]
+        map.insert(392, &[392]);     // 392 => [392]
+        map.insert(648, &[648]);     // 648 => [648]
+        map.insert(1160, &[1160]);     // 1160 => [1160]
+        map.insert(2016, &[2184]);     // 2016 => [2184]
+        map.insert(1047, &[4232]);     // 1047 => [4232]
+        map.insert(1758, &[8328]);     // 1758 => [8328]
+        map.insert(1643, &[16520]);     // 1643 => [16520]
+        map.insert(550, &[32904]);     // 550 => [32904]
+        map.insert(243, &[65672]);     // 243 => [65672]
+        map.insert(1369, &[131208]);     // 1369 => [131208]
+        map.insert(357, &[262280]);     // 357 => [262280]
+        map.insert(850, &[524424]);     // 850 => [524424]
+        map.insert(1852, &[1048712]);     // 1852 => [1048712]
+        map.insert(917, &[2097288]);     // 917 => [2097288]
+        map.insert(776, &[776]);     // 776 => [776]
+        map.insert(1288, &[1288]);     // 1288 => [1288]
+        map.insert(1632, &[2312]);     // 1632 => [2312]
+        map.insert(1431, &[4360]);     // 1431 => [4360]
+        map.insert(1886, &[8456]);     // 1886 => [8456]
+        map.insert(2027, &[16648]);     // 2027 => [16648]
+        map.insert(934, &[33032]);     // 934 => [33032]
+        map.insert(371, &[65800]);     // 371 => [65800]
+        map.insert(1241, &[131336]);     // 1241 => [131336]
+        map.insert(229, &[262408]);     // 229 => [262408]
+        map.insert(722, &[524552]);     // 722 => [524552]
+        map.insert(1724, &[1048840]);     // 1724 => [1048840]
+        map.insert(533, &[2097416]);     // 533 => [2097416]
+        map.insert(1544, &[1544]);     // 1544 => [1544]
+        map.insert(1376, &[2568]);     // 1376 => [2568]
+        map.insert(1687, &[4616]);     // 1687 => [4616]
+        map.insert(1118, &[8712]);     // 1118 => [8712]
+        map.insert(1259, &[16904]);     // 1259 => [16904]
+        map.insert(166, &[33288]);     // 166 => [33288]
+        map.insert(627, &[66056]);     // 627 => [66056]
+        map.insert(2009, &[131592]);     // 2009 => [131592]
+        map.insert(997, &[262664]);     // 997 => [262664]
+        map.insert(466, &[524808]);     // 466 => [524808]
+        map.insert(1468, &[1049096]);     // 1468 => [1049096]
+        map.insert(277, &[2097672]);     // 277 => [2097672]
+        map.insert(864, &[3080]);     // 864 => [3080]
+        map.insert(151, &[5128]);     // 151 => [5128]
+        map.insert(606, &[9224]);     // 606 => [9224]
+        map.insert(747, &[17416]);     // 747 => [17416]
+        map.insert(1702, &[33800]);     // 1702 => [33800]
+        map.insert(1139, &[66568]);     // 1139 => [66568]
+        map.insert(473, &[132104]);     // 473 => [132104]
+        map.insert(1509, &[263176]);     // 1509 => [263176]
+        map.insert(2002, &[525320]);     // 2002 => [525320]
+        map.insert(956, &[1049608]);     // 956 => [1049608]
+        map.insert(1813, &[2098184]);     // 1813 => [2098184]
+        map.insert(1023, &[6152]);     // 1023 => [6152]
+        map.insert(310, &[10248]);     // 310 => [10248]
+        map.insert(387, &[18440]);     // 387 => [18440]
+        map.insert(1486, &[34824]);     // 1486 => [34824]
+        map.insert(1819, &[67592]);     // 1819 => [67592]
+        map.insert(689, &[133128]);     // 689 => [133128]
+        map.insert(1677, &[264200]);     // 1677 => [264200]
+        map.insert(1210, &[526344]);     // 1210 => [526344]
+        map.insert(212, &[1050632]);     // 212 => [1050632]
+        map.insert(1149, &[2099208]);     // 1149 => [2099208]
+        map.insert(705, &[12296]);     // 705 => [12296]
+        map.insert(628, &[20488]);     // 628 => [20488]
+        map.insert(1593, &[36872]);     // 1593 => [36872]
+        map.insert(1260, &[69640]);     // 1260 => [69640]
+        map.insert(326, &[135176]);     // 326 => [135176]
+        map.insert(1402, &[266248]);     // 1402 => [266248]
+        map.insert(1869, &[528392]);     // 1869 => [528392]
+        map.insert(803, &[1052680]);     // 803 => [1052680]
+        map.insert(1930, &[2101256]);     // 1930 => [2101256]
+        map.insert(189, &[24584]);     // 189 => [24584]
+        map.insert(1264, &[40968]);     // 1264 => [40968]
+        map.insert(1573, &[73736]);     // 1573 => [73736]
+        map.insert(911, &[139272]);     // 911 => [139272]
+        map.insert(1971, &[270344]);     // 1971 => [270344]
+        map.insert(1412, &[532488]);     // 1412 => [532488]
+        map.insert(490, &[1056776]);     // 490 => [1056776]
+        map.insert(1347, &[2105352]);     // 1347 => [2105352]
+        map.insert(1093, &[49160]);     // 1093 => [49160]
+        map.insert(1680, &[81928]);     // 1680 => [81928]
+        map.insert(826, &[147464]);     // 826 => [147464]
+        map.insert(1798, &[278536]);     // 1798 => [278536]
+        map.insert(1329, &[540680]);     // 1329 => [540680]
+        map.insert(351, &[1064968]);     // 351 => [1064968]
+        map.insert(1526, &[2113544]);     // 1526 => [2113544]
+        map.insert(733, &[98312]);     // 733 => [98312]
+        map.insert(1911, &[163848]);     // 1911 => [163848]
+        map.insert(843, &[294920]);     // 843 => [294920]
+        map.insert(380, &[557064]);     // 380 => [557064]
+        map.insert(1298, &[1081352]);     // 1298 => [1081352]
+        map.insert(443, &[2129928]);     // 443 => [2129928]
+        map.insert(1442, &[196616]);     // 1442 => [196616]
+        map.insert(414, &[327688]);     // 414 => [327688]
+        map.insert(937, &[589832]);     // 937 => [589832]
+        map.insert(1991, &[1114120]);     // 1991 => [1114120]
+        map.insert(878, &[2162696]);     // 878 => [2162696]
+        map.insert(1076, &[393224]);     // 1076 => [393224]
+        map.insert(1539, &[655368]);     // 1539 => [655368]
+        map.insert(621, &[1179656]);     // 621 => [1179656]
+        map.insert(1732, &[2228232]);     // 1732 => [2228232]
+        map.insert(575, &[786440]);     // 575 => [786440]
+        map.insert(1617, &[1310728]);     // 1617 => [1310728]
+        map.insert(760, &[2359304]);     // 760 => [2359304]
+        map.insert(1126, &[1572872]);     // 1126 => [1572872]
+        map.insert(207, &[2621448]);     // 207 => [2621448]
+        map.insert(1185, &[3145736]);     // 1185 => [3145736]
+        map.insert(112, &[112]);     // 112 => [112]
+        map.insert(176, &[176]);     // 176 => [176]
+        map.insert(304, &[304]);     // 304 => [304]
+        map.insert(560, &[560]);     // 560 => [560]
+        map.insert(1072, &[1072]);     // 1072 => [1072]
+        map.insert(1880, &[2096]);     // 1880 => [2096]
+        map.insert(1199, &[4144]);     // 1199 => [4144]
+        map.insert(1638, &[8240]);     // 1638 => [8240]
+        map.insert(1747, &[16432]);     // 1747 => [16432]
+        map.insert(670, &[32816]);     // 670 => [32816]
+        map.insert(75, &[65584]);     // 75 => [65584]
+        map.insert(1505, &[131120]);     // 1505 => [131120]
+        map.insert(477, &[262192]);     // 477 => [262192]
+        map.insert(1002, &[524336]);     // 1002 => [524336]
+        map.insert(1924, &[1048624]);     // 1924 => [1048624]
+        map.insert(813, &[2097200]);     // 813 => [2097200]
+        map.insert(208, &[208]);     // 208 => [208]
+        map.insert(336, &[336]);     // 336 => [336]
+        map.insert(592, &[592]);     // 592 => [592]
+        map.insert(1104, &[1104]);     // 1104 => [1104]
+        map.insert(1848, &[2128]);     // 1848 => [2128]
+        map.insert(1231, &[4176]);     // 1231 => [4176]
+        map.insert(1542, &[8272]);     // 1542 => [8272]
+        map.insert(1715, &[16464]);     // 1715 => [16464]
+        map.insert(766, &[32848]);     // 766 => [32848]
+        map.insert(43, &[65616]);     // 43 => [65616]
+        map.insert(1409, &[131152]);     // 1409 => [131152]
+        map.insert(445, &[262224]);     // 445 => [262224]
+        map.insert(906, &[524368]);     // 906 => [524368]
+        map.insert(2020, &[1048656]);     // 2020 => [1048656]
+        map.insert(845, &[2097232]);     // 845 => [2097232]
+        map.insert(400, &[400]);     // 400 => [400]
+        map.insert(656, &[656]);     // 656 => [656]
+        map.insert(1168, &[1168]);     // 1168 => [1168]
+        map.insert(2040, &[2192]);     // 2040 => [2192]
+        map.insert(1039, &[4240]);     // 1039 => [4240]
+        map.insert(1734, &[8336]);     // 1734 => [8336]
+        map.insert(1651, &[16528]);     // 1651 => [16528]
+        map.insert(574, &[32912]);     // 574 => [32912]
+        map.insert(235, &[65680]);     // 235 => [65680]
+        map.insert(1345, &[131216]);     // 1345 => [131216]
+        map.insert(381, &[262288]);     // 381 => [262288]
+        map.insert(842, &[524432]);     // 842 => [524432]
+        map.insert(1828, &[1048720]);     // 1828 => [1048720]
+        map.insert(909, &[2097296]);     // 909 => [2097296]
+        map.insert(784, &[784]);     // 784 => [784]
+        map.insert(1296, &[1296]);     // 1296 => [1296]
+        map.insert(1656, &[2320]);     // 1656 => [2320]
+        map.insert(1423, &[4368]);     // 1423 => [4368]
+        map.insert(1862, &[8464]);     // 1862 => [8464]
+        map.insert(2035, &[16656]);     // 2035 => [16656]
+        map.insert(958, &[33040]);     // 958 => [33040]
+        map.insert(363, &[65808]);     // 363 => [65808]
+        map.insert(1217, &[131344]);     // 1217 => [131344]
+        map.insert(253, &[262416]);     // 253 => [262416]
+        map.insert(714, &[524560]);     // 714 => [524560]
+        map.insert(1700, &[1048848]);     // 1700 => [1048848]
+        map.insert(525, &[2097424]);     // 525 => [2097424]
+        map.insert(1552, &[1552]);     // 1552 => [1552]
+        map.insert(1400, &[2576]);     // 1400 => [2576]
+        map.insert(1679, &[4624]);     // 1679 => [4624]
+        map.insert(1094, &[8720]);     // 1094 => [8720]
+        map.insert(1267, &[16912]);     // 1267 => [16912]
+        map.insert(190, &[33296]);     // 190 => [33296]
+        map.insert(619, &[66064]);     // 619 => [66064]
+        map.insert(1985, &[131600]);     // 1985 => [131600]
+        map.insert(1021, &[262672]);     // 1021 => [262672]
+        map.insert(458, &[524816]);     // 458 => [524816]
+        map.insert(1444, &[1049104]);     // 1444 => [1049104]
+        map.insert(269, &[2097680]);     // 269 => [2097680]
+        map.insert(888, &[3088]);     // 888 => [3088]
+        map.insert(143, &[5136]);     // 143 => [5136]
+        map.insert(582, &[9232]);     // 582 => [9232]
+        map.insert(755, &[17424]);     // 755 => [17424]
+        map.insert(1726, &[33808]);     // 1726 => [33808]
+        map.insert(1131, &[66576]);     // 1131 => [66576]
+        map.insert(449, &[132112]);     // 449 => [132112]
+        map.insert(1533, &[263184]);     // 1533 => [263184]
+        map.insert(1994, &[525328]);     // 1994 => [525328]
+        map.insert(932, &[1049616]);     // 932 => [1049616]
+        map.insert(1805, &[2098192]);     // 1805 => [2098192]
+        map.insert(999, &[6160]);     // 999 => [6160]
+        map.insert(302, &[10256]);     // 302 => [10256]
+        map.insert(411, &[18448]);     // 411 => [18448]
+        map.insert(1494, &[34832]);     // 1494 => [34832]
+        map.insert(1795, &[67600]);     // 1795 => [67600]
+        map.insert(681, &[133136]);     // 681 => [133136]
+        map.insert(1685, &[264208]);     // 1685 => [264208]
+        map.insert(1186, &[526352]);     // 1186 => [526352]
+        map.insert(204, &[1050640]);     // 204 => [1050640]
+        map.insert(1125, &[2099216]);     // 1125 => [2099216]
+        map.insert(729, &[12304]);     // 729 => [12304]
+        map.insert(620, &[20496]);     // 620 => [20496]
+        map.insert(1569, &[36880]);     // 1569 => [36880]
+        map.insert(1268, &[69648]);     // 1268 => [69648]
+        map.insert(350, &[135184]);     // 350 => [135184]
+        map.insert(1378, &[266256]);     // 1378 => [266256]
+        map.insert(1877, &[528400]);     // 1877 => [528400]
+        map.insert(827, &[1052688]);     // 827 => [1052688]
+        map.insert(1938, &[2101264]);     // 1938 => [2101264]
+        map.insert(165, &[24592]);     // 165 => [24592]
+        map.insert(1256, &[40976]);     // 1256 => [40976]
+        map.insert(1597, &[73744]);     // 1597 => [73744]
+        map.insert(919, &[139280]);     // 919 => [139280]
+        map.insert(1963, &[270352]);     // 1963 => [270352]
+        map.insert(1436, &[532496]);     // 1436 => [532496]
+        map.insert(498, &[1056784]);     // 498 => [1056784]
+        map.insert(1371, &[2105360]);     // 1371 => [2105360]
+        map.insert(1117, &[49168]);     // 1117 => [49168]
+        map.insert(1672, &[81936]);     // 1672 => [81936]
+        map.insert(802, &[147472]);     // 802 => [147472]
+        map.insert(1822, &[278544]);     // 1822 => [278544]
+        map.insert(1321, &[540688]);     // 1321 => [540688]
+        map.insert(327, &[1064976]);     // 327 => [1064976]
+        map.insert(1518, &[2113552]);     // 1518 => [2113552]
+        map.insert(709, &[98320]);     // 709 => [98320]
+        map.insert(1903, &[163856]);     // 1903 => [163856]
+        map.insert(851, &[294928]);     // 851 => [294928]
+        map.insert(356, &[557072]);     // 356 => [557072]
+        map.insert(1290, &[1081360]);     // 1290 => [1081360]
+        map.insert(419, &[2129936]);     // 419 => [2129936]
+        map.insert(1466, &[196624]);     // 1466 => [196624]
+        map.insert(390, &[327696]);     // 390 => [327696]
+        map.insert(945, &[589840]);     // 945 => [589840]
+        map.insert(2015, &[1114128]);     // 2015 => [1114128]
+        map.insert(886, &[2162704]);     // 886 => [2162704]
+        map.insert(1068, &[393232]);     // 1068 => [393232]
+        map.insert(1563, &[655376]);     // 1563 => [655376]
+        map.insert(629, &[1179664]);     // 629 => [1179664]
+        map.insert(1756, &[2228240]);     // 1756 => [2228240]
+        map.insert(551, &[786448]);     // 551 => [786448]
+        map.insert(1609, &[1310736]);     // 1609 => [1310736]
+        map.insert(736, &[2359312]);     // 736 => [2359312]
+        map.insert(1150, &[1572880]);     // 1150 => [1572880]
+        map.insert(215, &[2621456]);     // 215 => [2621456]
+        map.insert(1209, &[3145744]);     // 1209 => [3145744]
+        map.insert(224, &[224]);     // 224 => [224]
+        map.insert(352, &[352]);     // 352 => [352]
+        map.insert(608, &[608]);     // 608 => [608]
+        map.insert(1120, &[1120]);     // 1120 => [1120]
+        map.insert(1800, &[2144]);     // 1800 => [2144]
+        map.insert(1279, &[4192]);     // 1279 => [4192]
+        map.insert(1590, &[8288]);     // 1590 => [8288]
+        map.insert(1667, &[16480]);     // 1667 => [16480]
+        map.insert(718, &[32864]);     // 718 => [32864]
+        map.insert(27, &[65632]);     // 27 => [65632]
+        map.insert(1457, &[131168]);     // 1457 => [131168]
+        map.insert(397, &[262240]);     // 397 => [262240]
+        map.insert(954, &[524384]);     // 954 => [524384]
+        map.insert(2004, &[1048672]);     // 2004 => [1048672]
+        map.insert(893, &[2097248]);     // 893 => [2097248]
+        map.insert(416, &[416]);     // 416 => [416]
+        map.insert(672, &[672]);     // 672 => [672]
+        map.insert(1184, &[1184]);     // 1184 => [1184]
+        map.insert(1992, &[2208]);     // 1992 => [2208]
+        map.insert(1087, &[4256]);     // 1087 => [4256]
+        map.insert(1782, &[8352]);     // 1782 => [8352]
+        map.insert(1603, &[16544]);     // 1603 => [16544]
+        map.insert(526, &[32928]);     // 526 => [32928]
+        map.insert(219, &[65696]);     // 219 => [65696]
+        map.insert(1393, &[131232]);     // 1393 => [131232]
+        map.insert(333, &[262304]);     // 333 => [262304]
+        map.insert(890, &[524448]);     // 890 => [524448]
+        map.insert(1812, &[1048736]);     // 1812 => [1048736]
+        map.insert(957, &[2097312]);     // 957 => [2097312]
+        map.insert(800, &[800]);     // 800 => [800]
+        map.insert(1312, &[1312]);     // 1312 => [1312]
+        map.insert(1608, &[2336]);     // 1608 => [2336]
+        map.insert(1471, &[4384]);     // 1471 => [4384]
+        map.insert(1910, &[8480]);     // 1910 => [8480]
+        map.insert(1987, &[16672]);     // 1987 => [16672]
+        map.insert(910, &[33056]);     // 910 => [33056]
+        map.insert(347, &[65824]);     // 347 => [65824]
+        map.insert(1265, &[131360]);     // 1265 => [131360]
+        map.insert(205, &[262432]);     // 205 => [262432]
+        map.insert(762, &[524576]);     // 762 => [524576]
+        map.insert(1684, &[1048864]);     // 1684 => [1048864]
+        map.insert(573, &[2097440]);     // 573 => [2097440]
+        map.insert(1568, &[1568]);     // 1568 => [1568]
+        map.insert(1352, &[2592]);     // 1352 => [2592]
+        map.insert(1727, &[4640]);     // 1727 => [4640]
+        map.insert(1142, &[8736]);     // 1142 => [8736]
+        map.insert(1219, &[16928]);     // 1219 => [16928]
+        map.insert(142, &[33312]);     // 142 => [33312]
+        map.insert(603, &[66080]);     // 603 => [66080]
+        map.insert(2033, &[131616]);     // 2033 => [131616]
+        map.insert(973, &[262688]);     // 973 => [262688]
+        map.insert(506, &[524832]);     // 506 => [524832]
+        map.insert(1428, &[1049120]);     // 1428 => [1049120]
+        map.insert(317, &[2097696]);     // 317 => [2097696]
+        map.insert(840, &[3104]);     // 840 => [3104]
+        map.insert(191, &[5152]);     // 191 => [5152]
+        map.insert(630, &[9248]);     // 630 => [9248]
+        map.insert(707, &[17440]);     // 707 => [17440]
+        map.insert(1678, &[33824]);     // 1678 => [33824]
+        map.insert(1115, &[66592]);     // 1115 => [66592]
+        map.insert(497, &[132128]);     // 497 => [132128]
+        map.insert(1485, &[263200]);     // 1485 => [263200]
+        map.insert(2042, &[525344]);     // 2042 => [525344]
+        map.insert(916, &[1049632]);     // 916 => [1049632]
+        map.insert(1853, &[2098208]);     // 1853 => [2098208]
+        map.insert(983, &[6176]);     // 983 => [6176]
+        map.insert(286, &[10272]);     // 286 => [10272]
+        map.insert(427, &[18464]);     // 427 => [18464]
+        map.insert(1510, &[34848]);     // 1510 => [34848]
+        map.insert(1843, &[67616]);     // 1843 => [67616]
+        map.insert(665, &[133152]);     // 665 => [133152]
+        map.insert(1701, &[264224]);     // 1701 => [264224]
+        map.insert(1170, &[526368]);     // 1170 => [526368]
+        map.insert(252, &[1050656]);     // 252 => [1050656]
+        map.insert(1109, &[2099232]);     // 1109 => [2099232]
+        map.insert(745, &[12320]);     // 745 => [12320]
+        map.insert(604, &[20512]);     // 604 => [20512]
+        map.insert(1553, &[36896]);     // 1553 => [36896]
+        map.insert(1220, &[69664]);     // 1220 => [69664]
+        map.insert(366, &[135200]);     // 366 => [135200]
+        map.insert(1362, &[266272]);     // 1362 => [266272]
+        map.insert(1893, &[528416]);     // 1893 => [528416]
+        map.insert(779, &[1052704]);     // 779 => [1052704]
+        map.insert(1954, &[2101280]);     // 1954 => [2101280]
+        map.insert(149, &[24608]);     // 149 => [24608]
+        map.insert(1240, &[40992]);     // 1240 => [40992]
+        map.insert(1549, &[73760]);     // 1549 => [73760]
+        map.insert(935, &[139296]);     // 935 => [139296]
+        map.insert(1947, &[270368]);     // 1947 => [270368]
+        map.insert(1452, &[532512]);     // 1452 => [532512]
+        map.insert(450, &[1056800]);     // 450 => [1056800]
+        map.insert(1387, &[2105376]);     // 1387 => [2105376]
+        map.insert(1133, &[49184]);     // 1133 => [49184]
+        map.insert(1720, &[81952]);     // 1720 => [81952]
+        map.insert(786, &[147488]);     // 786 => [147488]
+        map.insert(1838, &[278560]);     // 1838 => [278560]
+        map.insert(1305, &[540704]);     // 1305 => [540704]
+        map.insert(375, &[1064992]);     // 375 => [1064992]
+        map.insert(1502, &[2113568]);     // 1502 => [2113568]
+        map.insert(757, &[98336]);     // 757 => [98336]
+        map.insert(1887, &[163872]);     // 1887 => [163872]
+        map.insert(867, &[294944]);     // 867 => [294944]
+        map.insert(340, &[557088]);     // 340 => [557088]
+        map.insert(1338, &[1081376]);     // 1338 => [1081376]
+        map.insert(403, &[2129952]);     // 403 => [2129952]
+        map.insert(1418, &[196640]);     // 1418 => [196640]
+        map.insert(438, &[327712]);     // 438 => [327712]
+        map.insert(897, &[589856]);     // 897 => [589856]
+        map.insert(2031, &[1114144]);     // 2031 => [1114144]
+        map.insert(838, &[2162720]);     // 838 => [2162720]
+        map.insert(1052, &[393248]);     // 1052 => [393248]
+        map.insert(1579, &[655392]);     // 1579 => [655392]
+        map.insert(581, &[1179680]);     // 581 => [1179680]
+        map.insert(1772, &[2228256]);     // 1772 => [2228256]
+        map.insert(535, &[786464]);     // 535 => [786464]
+        map.insert(1657, &[1310752]);     // 1657 => [1310752]
+        map.insert(720, &[2359328]);     // 720 => [2359328]
+        map.insert(1102, &[1572896]);     // 1102 => [1572896]
+        map.insert(231, &[2621472]);     // 231 => [2621472]
+        map.insert(1161, &[3145760]);     // 1161 => [3145760]
+        map.insert(448, &[448]);     // 448 => [448]
+        map.insert(704, &[704]);     // 704 => [704]
+        map.insert(1216, &[1216]);     // 1216 => [1216]
+        map.insert(1960, &[2240]);     // 1960 => [2240]
+        map.insert(1119, &[4288]);     // 1119 => [4288]
+        map.insert(1686, &[8384]);     // 1686 => [8384]
+        map.insert(1571, &[16576]);     // 1571 => [16576]
+        map.insert(622, &[32960]);     // 622 => [32960]
+        map.insert(187, &[65728]);     // 187 => [65728]
+        map.insert(1297, &[131264]);     // 1297 => [131264]
+        map.insert(301, &[262336]);     // 301 => [262336]
+        map.insert(794, &[524480]);     // 794 => [524480]
+        map.insert(1908, &[1048768]);     // 1908 => [1048768]
+        map.insert(989, &[2097344]);     // 989 => [2097344]
+        map.insert(832, &[832]);     // 832 => [832]
+        map.insert(1344, &[1344]);     // 1344 => [1344]
+        map.insert(1576, &[2368]);     // 1576 => [2368]
+        map.insert(1503, &[4416]);     // 1503 => [4416]
+        map.insert(1814, &[8512]);     // 1814 => [8512]
+        map.insert(1955, &[16704]);     // 1955 => [16704]
+        map.insert(1006, &[33088]);     // 1006 => [33088]
+        map.insert(315, &[65856]);     // 315 => [65856]
+        map.insert(1169, &[131392]);     // 1169 => [131392]
+        map.insert(173, &[262464]);     // 173 => [262464]
+        map.insert(666, &[524608]);     // 666 => [524608]
+        map.insert(1780, &[1048896]);     // 1780 => [1048896]
+        map.insert(605, &[2097472]);     // 605 => [2097472]
+        map.insert(1600, &[1600]);     // 1600 => [1600]
+        map.insert(1320, &[2624]);     // 1320 => [2624]
+        map.insert(1759, &[4672]);     // 1759 => [4672]
+        map.insert(1046, &[8768]);     // 1046 => [8768]
+        map.insert(1187, &[16960]);     // 1187 => [16960]
+        map.insert(238, &[33344]);     // 238 => [33344]
+        map.insert(571, &[66112]);     // 571 => [66112]
+        map.insert(1937, &[131648]);     // 1937 => [131648]
+        map.insert(941, &[262720]);     // 941 => [262720]
+        map.insert(410, &[524864]);     // 410 => [524864]
+        map.insert(1524, &[1049152]);     // 1524 => [1049152]
+        map.insert(349, &[2097728]);     // 349 => [2097728]
+        map.insert(808, &[3136]);     // 808 => [3136]
+        map.insert(223, &[5184]);     // 223 => [5184]
+        map.insert(534, &[9280]);     // 534 => [9280]
+        map.insert(675, &[17472]);     // 675 => [17472]
+        map.insert(1774, &[33856]);     // 1774 => [33856]
+        map.insert(1083, &[66624]);     // 1083 => [66624]
+        map.insert(401, &[132160]);     // 401 => [132160]
+        map.insert(1453, &[263232]);     // 1453 => [263232]
+        map.insert(1946, &[525376]);     // 1946 => [525376]
+        map.insert(1012, &[1049664]);     // 1012 => [1049664]
+        map.insert(1885, &[2098240]);     // 1885 => [2098240]
+        map.insert(951, &[6208]);     // 951 => [6208]
+        map.insert(382, &[10304]);     // 382 => [10304]
+        map.insert(459, &[18496]);     // 459 => [18496]
+        map.insert(1414, &[34880]);     // 1414 => [34880]
+        map.insert(1875, &[67648]);     // 1875 => [67648]
+        map.insert(761, &[133184]);     // 761 => [133184]
+        map.insert(1733, &[264256]);     // 1733 => [264256]
+        map.insert(1266, &[526400]);     // 1266 => [526400]
+        map.insert(156, &[1050688]);     // 156 => [1050688]
+        map.insert(1077, &[2099264]);     // 1077 => [2099264]
+        map.insert(649, &[12352]);     // 649 => [12352]
+        map.insert(572, &[20544]);     // 572 => [20544]
+        map.insert(1649, &[36928]);     // 1649 => [36928]
+        map.insert(1188, &[69696]);     // 1188 => [69696]
+        map.insert(270, &[135232]);     // 270 => [135232]
+        map.insert(1330, &[266304]);     // 1330 => [266304]
+        map.insert(1797, &[528448]);     // 1797 => [528448]
+        map.insert(875, &[1052736]);     // 875 => [1052736]
+        map.insert(1986, &[2101312]);     // 1986 => [2101312]
+        map.insert(245, &[24640]);     // 245 => [24640]
+        map.insert(1208, &[41024]);     // 1208 => [41024]
+        map.insert(1645, &[73792]);     // 1645 => [73792]
+        map.insert(967, &[139328]);     // 967 => [139328]
+        map.insert(2043, &[270400]);     // 2043 => [270400]
+        map.insert(1484, &[532544]);     // 1484 => [532544]
+        map.insert(418, &[1056832]);     // 418 => [1056832]
+        map.insert(1291, &[2105408]);     // 1291 => [2105408]
+        map.insert(1037, &[49216]);     // 1037 => [49216]
+        map.insert(1752, &[81984]);     // 1752 => [81984]
+        map.insert(882, &[147520]);     // 882 => [147520]
+        map.insert(1870, &[278592]);     // 1870 => [278592]
+        map.insert(1401, &[540736]);     // 1401 => [540736]
+        map.insert(279, &[1065024]);     // 279 => [1065024]
+        map.insert(1470, &[2113600]);     // 1470 => [2113600]
+        map.insert(661, &[98368]);     // 661 => [98368]
+        map.insert(1855, &[163904]);     // 1855 => [163904]
+        map.insert(771, &[294976]);     // 771 => [294976]
+        map.insert(308, &[557120]);     // 308 => [557120]
+        map.insert(1370, &[1081408]);     // 1370 => [1081408]
+        map.insert(499, &[2129984]);     // 499 => [2129984]
+        map.insert(1514, &[196672]);     // 1514 => [196672]
+        map.insert(470, &[327744]);     // 470 => [327744]
+        map.insert(993, &[589888]);     // 993 => [589888]
+        map.insert(1935, &[1114176]);     // 1935 => [1114176]
+        map.insert(806, &[2162752]);     // 806 => [2162752]
+        map.insert(1148, &[393280]);     // 1148 => [393280]
+        map.insert(1611, &[655424]);     // 1611 => [655424]
+        map.insert(549, &[1179712]);     // 549 => [1179712]
+        map.insert(1676, &[2228288]);     // 1676 => [2228288]
+        map.insert(631, &[786496]);     // 631 => [786496]
+        map.insert(1561, &[1310784]);     // 1561 => [1310784]
+        map.insert(688, &[2359360]);     // 688 => [2359360]
+        map.insert(1070, &[1572928]);     // 1070 => [1572928]
+        map.insert(135, &[2621504]);     // 135 => [2621504]
+        map.insert(1257, &[3145792]);     // 1257 => [3145792]
+        map.insert(896, &[896]);     // 896 => [896]
+        map.insert(1408, &[1408]);     // 1408 => [1408]
+        map.insert(1768, &[2432]);     // 1768 => [2432]
+        map.insert(1311, &[4480]);     // 1311 => [4480]
+        map.insert(2006, &[8576]);     // 2006 => [8576]
+        map.insert(1891, &[16768]);     // 1891 => [16768]
+        map.insert(814, &[33152]);     // 814 => [33152]
+        map.insert(507, &[65920]);     // 507 => [65920]
+        map.insert(1105, &[131456]);     // 1105 => [131456]
+        map.insert(109, &[262528]);     // 109 => [262528]
+        map.insert(602, &[524672]);     // 602 => [524672]
+        map.insert(1588, &[1048960]);     // 1588 => [1048960]
+        map.insert(669, &[2097536]);     // 669 => [2097536]
+        map.insert(1664, &[1664]);     // 1664 => [1664]
+        map.insert(1512, &[2688]);     // 1512 => [2688]
+        map.insert(1567, &[4736]);     // 1567 => [4736]
+        map.insert(1238, &[8832]);     // 1238 => [8832]
+        map.insert(1123, &[17024]);     // 1123 => [17024]
+        map.insert(46, &[33408]);     // 46 => [33408]
+        map.insert(763, &[66176]);     // 763 => [66176]
+        map.insert(1873, &[131712]);     // 1873 => [131712]
+        map.insert(877, &[262784]);     // 877 => [262784]
+        map.insert(346, &[524928]);     // 346 => [524928]
+        map.insert(1332, &[1049216]);     // 1332 => [1049216]
+        map.insert(413, &[2097792]);     // 413 => [2097792]
+        map.insert(1000, &[3200]);     // 1000 => [3200]
+        map.insert(31, &[5248]);     // 31 => [5248]
+        map.insert(726, &[9344]);     // 726 => [9344]
+        map.insert(611, &[17536]);     // 611 => [17536]
+        map.insert(1582, &[33920]);     // 1582 => [33920]
+        map.insert(1275, &[66688]);     // 1275 => [66688]
+        map.insert(337, &[132224]);     // 337 => [132224]
+        map.insert(1389, &[263296]);     // 1389 => [263296]
+        map.insert(1882, &[525440]);     // 1882 => [525440]
+        map.insert(820, &[1049728]);     // 820 => [1049728]
+        map.insert(1949, &[2098304]);     // 1949 => [2098304]
+        map.insert(887, &[6272]);     // 887 => [6272]
+        map.insert(446, &[10368]);     // 446 => [10368]
+        map.insert(267, &[18560]);     // 267 => [18560]
+        map.insert(1350, &[34944]);     // 1350 => [34944]
+        map.insert(1939, &[67712]);     // 1939 => [67712]
+        map.insert(569, &[133248]);     // 569 => [133248]
+        map.insert(1541, &[264320]);     // 1541 => [264320]
+        map.insert(1074, &[526464]);     // 1074 => [526464]
+        map.insert(92, &[1050752]);     // 92 => [1050752]
+        map.insert(1269, &[2099328]);     // 1269 => [2099328]
+        map.insert(585, &[12416]);     // 585 => [12416]
+        map.insert(764, &[20608]);     // 764 => [20608]
+        map.insert(1713, &[36992]);     // 1713 => [36992]
+        map.insert(1124, &[69760]);     // 1124 => [69760]
+        map.insert(462, &[135296]);     // 462 => [135296]
+        map.insert(1522, &[266368]);     // 1522 => [266368]
+        map.insert(1989, &[528512]);     // 1989 => [528512]
+        map.insert(939, &[1052800]);     // 939 => [1052800]
+        map.insert(1794, &[2101376]);     // 1794 => [2101376]
+        map.insert(53, &[24704]);     // 53 => [24704]
+        map.insert(1144, &[41088]);     // 1144 => [41088]
+        map.insert(1709, &[73856]);     // 1709 => [73856]
+        map.insert(775, &[139392]);     // 775 => [139392]
+        map.insert(1851, &[270464]);     // 1851 => [270464]
+        map.insert(1292, &[532608]);     // 1292 => [532608]
+        map.insert(354, &[1056896]);     // 354 => [1056896]
+        map.insert(1483, &[2105472]);     // 1483 => [2105472]
+        map.insert(1229, &[49280]);     // 1229 => [49280]
+        map.insert(1560, &[82048]);     // 1560 => [82048]
+        map.insert(946, &[147584]);     // 946 => [147584]
+        map.insert(1934, &[278656]);     // 1934 => [278656]
+        map.insert(1465, &[540800]);     // 1465 => [540800]
+        map.insert(471, &[1065088]);     // 471 => [1065088]
+        map.insert(1406, &[2113664]);     // 1406 => [2113664]
+        map.insert(597, &[98432]);     // 597 => [98432]
+        map.insert(2047, &[163968]);     // 2047 => [163968]
+        map.insert(963, &[295040]);     // 963 => [295040]
+        map.insert(500, &[557184]);     // 500 => [557184]
+        map.insert(1434, &[1081472]);     // 1434 => [1081472]
+        map.insert(307, &[2130048]);     // 307 => [2130048]
+        map.insert(1322, &[196736]);     // 1322 => [196736]
+        map.insert(278, &[327808]);     // 278 => [327808]
+        map.insert(801, &[589952]);     // 801 => [589952]
+        map.insert(1871, &[1114240]);     // 1871 => [1114240]
+        map.insert(998, &[2162816]);     // 998 => [2162816]
+        map.insert(1212, &[393344]);     // 1212 => [393344]
+        map.insert(1675, &[655488]);     // 1675 => [655488]
+        map.insert(741, &[1179776]);     // 741 => [1179776]
+        map.insert(1612, &[2228352]);     // 1612 => [2228352]
+        map.insert(695, &[786560]);     // 695 => [786560]
+        map.insert(1753, &[1310848]);     // 1753 => [1310848]
+        map.insert(624, &[2359424]);     // 624 => [2359424]
+        map.insert(1262, &[1572992]);     // 1262 => [1572992]
+        map.insert(71, &[2621568]);     // 71 => [2621568]
+        map.insert(1065, &[3145856]);     // 1065 => [3145856]
+        map.insert(1792, &[1792]);     // 1792 => [1792]
+        map.insert(1128, &[2816]);     // 1128 => [2816]
+        map.insert(1951, &[4864]);     // 1951 => [4864]
+        map.insert(1366, &[8960]);     // 1366 => [8960]
+        map.insert(1507, &[17152]);     // 1507 => [17152]
+        map.insert(430, &[33536]);     // 430 => [33536]
+        map.insert(891, &[66304]);     // 891 => [66304]
+        map.insert(1745, &[131840]);     // 1745 => [131840]
+        map.insert(749, &[262912]);     // 749 => [262912]
+        map.insert(218, &[525056]);     // 218 => [525056]
+        map.insert(1204, &[1049344]);     // 1204 => [1049344]
+        map.insert(29, &[2097920]);     // 29 => [2097920]
+        map.insert(616, &[3328]);     // 616 => [3328]
+        map.insert(415, &[5376]);     // 415 => [5376]
+        map.insert(854, &[9472]);     // 854 => [9472]
+        map.insert(995, &[17664]);     // 995 => [17664]
+        map.insert(1966, &[34048]);     // 1966 => [34048]
+        map.insert(1403, &[66816]);     // 1403 => [66816]
+        map.insert(209, &[132352]);     // 209 => [132352]
+        map.insert(1261, &[263424]);     // 1261 => [263424]
+        map.insert(1754, &[525568]);     // 1754 => [525568]
+        map.insert(692, &[1049856]);     // 692 => [1049856]
+        map.insert(1565, &[2098432]);     // 1565 => [2098432]
+        map.insert(759, &[6400]);     // 759 => [6400]
+        map.insert(62, &[10496]);     // 62 => [10496]
+        map.insert(139, &[18688]);     // 139 => [18688]
+        map.insert(1222, &[35072]);     // 1222 => [35072]
+        map.insert(1555, &[67840]);     // 1555 => [67840]
+        map.insert(953, &[133376]);     // 953 => [133376]
+        map.insert(1925, &[264448]);     // 1925 => [264448]
+        map.insert(1458, &[526592]);     // 1458 => [526592]
+        map.insert(476, &[1050880]);     // 476 => [1050880]
+        map.insert(1397, &[2099456]);     // 1397 => [2099456]
+        map.insert(969, &[12544]);     // 969 => [12544]
+        map.insert(892, &[20736]);     // 892 => [20736]
+        map.insert(1841, &[37120]);     // 1841 => [37120]
+        map.insert(1508, &[69888]);     // 1508 => [69888]
+        map.insert(78, &[135424]);     // 78 => [135424]
+        map.insert(1138, &[266496]);     // 1138 => [266496]
+        map.insert(1605, &[528640]);     // 1605 => [528640]
+        map.insert(555, &[1052928]);     // 555 => [1052928]
+        map.insert(1666, &[2101504]);     // 1666 => [2101504]
+        map.insert(437, &[24832]);     // 437 => [24832]
+        map.insert(1528, &[41216]);     // 1528 => [41216]
+        map.insert(1837, &[73984]);     // 1837 => [73984]
+        map.insert(647, &[139520]);     // 647 => [139520]
+        map.insert(1723, &[270592]);     // 1723 => [270592]
+        map.insert(1164, &[532736]);     // 1164 => [532736]
+        map.insert(226, &[1057024]);     // 226 => [1057024]
+        map.insert(1099, &[2105600]);     // 1099 => [2105600]
+        map.insert(1357, &[49408]);     // 1357 => [49408]
+        map.insert(1944, &[82176]);     // 1944 => [82176]
+        map.insert(562, &[147712]);     // 562 => [147712]
+        map.insert(1550, &[278784]);     // 1550 => [278784]
+        map.insert(1081, &[540928]);     // 1081 => [540928]
+        map.insert(87, &[1065216]);     // 87 => [1065216]
+        map.insert(1278, &[2113792]);     // 1278 => [2113792]
+        map.insert(981, &[98560]);     // 981 => [98560]
+        map.insert(1663, &[164096]);     // 1663 => [164096]
+        map.insert(579, &[295168]);     // 579 => [295168]
+        map.insert(116, &[557312]);     // 116 => [557312]
+        map.insert(1050, &[1081600]);     // 1050 => [1081600]
+        map.insert(179, &[2130176]);     // 179 => [2130176]
+        map.insert(1194, &[196864]);     // 1194 => [196864]
+        map.insert(150, &[327936]);     // 150 => [327936]
+        map.insert(673, &[590080]);     // 673 => [590080]
+        map.insert(1743, &[1114368]);     // 1743 => [1114368]
+        map.insert(614, &[2162944]);     // 614 => [2162944]
+        map.insert(1340, &[393472]);     // 1340 => [393472]
+        map.insert(1803, &[655616]);     // 1803 => [655616]
+        map.insert(869, &[1179904]);     // 869 => [1179904]
+        map.insert(1996, &[2228480]);     // 1996 => [2228480]
+        map.insert(823, &[786688]);     // 823 => [786688]
+        map.insert(1881, &[1310976]);     // 1881 => [1310976]
+        map.insert(1008, &[2359552]);     // 1008 => [2359552]
+        map.insert(1390, &[1573120]);     // 1390 => [1573120]
+        map.insert(455, &[2621696]);     // 455 => [2621696]
+        map.insert(1449, &[3145984]);     // 1449 => [3145984]
+        map.insert(360, &[3584]);     // 360 => [3584]
+        map.insert(671, &[5632]);     // 671 => [5632]
+        map.insert(86, &[9728]);     // 86 => [9728]
+        map.insert(227, &[17920]);     // 227 => [17920]
+        map.insert(1198, &[34304]);     // 1198 => [34304]
+        map.insert(1659, &[67072]);     // 1659 => [67072]
+        map.insert(977, &[132608]);     // 977 => [132608]
+        map.insert(2029, &[263680]);     // 2029 => [263680]
+        map.insert(1498, &[525824]);     // 1498 => [525824]
+        map.insert(436, &[1050112]);     // 436 => [1050112]
+        map.insert(1309, &[2098688]);     // 1309 => [2098688]
+        map.insert(503, &[6656]);     // 503 => [6656]
+        map.insert(830, &[10752]);     // 830 => [10752]
+        map.insert(907, &[18944]);     // 907 => [18944]
+        map.insert(1990, &[35328]);     // 1990 => [35328]
+        map.insert(1299, &[68096]);     // 1299 => [68096]
+        map.insert(185, &[133632]);     // 185 => [133632]
+        map.insert(1157, &[264704]);     // 1157 => [264704]
+        map.insert(1714, &[526848]);     // 1714 => [526848]
+        map.insert(732, &[1051136]);     // 732 => [1051136]
+        map.insert(1653, &[2099712]);     // 1653 => [2099712]
+        map.insert(201, &[12800]);     // 201 => [12800]
+        map.insert(124, &[20992]);     // 124 => [20992]
+        map.insert(1073, &[37376]);     // 1073 => [37376]
+        map.insert(1764, &[70144]);     // 1764 => [70144]
+        map.insert(846, &[135680]);     // 846 => [135680]
+        map.insert(1906, &[266752]);     // 1906 => [266752]
+        map.insert(1349, &[528896]);     // 1349 => [528896]
+        map.insert(299, &[1053184]);     // 299 => [1053184]
+        map.insert(1410, &[2101760]);     // 1410 => [2101760]
+        map.insert(693, &[25088]);     // 693 => [25088]
+        map.insert(1784, &[41472]);     // 1784 => [41472]
+        map.insert(1069, &[74240]);     // 1069 => [74240]
+        map.insert(391, &[139776]);     // 391 => [139776]
+        map.insert(1467, &[270848]);     // 1467 => [270848]
+        map.insert(1932, &[532992]);     // 1932 => [532992]
+        map.insert(994, &[1057280]);     // 994 => [1057280]
+        map.insert(1867, &[2105856]);     // 1867 => [2105856]
+        map.insert(1613, &[49664]);     // 1613 => [49664]
+        map.insert(1176, &[82432]);     // 1176 => [82432]
+        map.insert(306, &[147968]);     // 306 => [147968]
+        map.insert(1294, &[279040]);     // 1294 => [279040]
+        map.insert(1849, &[541184]);     // 1849 => [541184]
+        map.insert(855, &[1065472]);     // 855 => [1065472]
+        map.insert(2046, &[2114048]);     // 2046 => [2114048]
+        map.insert(213, &[98816]);     // 213 => [98816]
+        map.insert(1407, &[164352]);     // 1407 => [164352]
+        map.insert(323, &[295424]);     // 323 => [295424]
+        map.insert(884, &[557568]);     // 884 => [557568]
+        map.insert(1818, &[1081856]);     // 1818 => [1081856]
+        map.insert(947, &[2130432]);     // 947 => [2130432]
+        map.insert(1962, &[197120]);     // 1962 => [197120]
+        map.insert(918, &[328192]);     // 918 => [328192]
+        map.insert(417, &[590336]);     // 417 => [590336]
+        map.insert(1487, &[1114624]);     // 1487 => [1114624]
+        map.insert(358, &[2163200]);     // 358 => [2163200]
+        map.insert(1596, &[393728]);     // 1596 => [393728]
+        map.insert(1035, &[655872]);     // 1035 => [655872]
+        map.insert(101, &[1180160]);     // 101 => [1180160]
+        map.insert(1228, &[2228736]);     // 1228 => [2228736]
+        map.insert(55, &[786944]);     // 55 => [786944]
+        map.insert(1113, &[1311232]);     // 1113 => [1311232]
+        map.insert(240, &[2359808]);     // 240 => [2359808]
+        map.insert(1646, &[1573376]);     // 1646 => [1573376]
+        map.insert(711, &[2621952]);     // 711 => [2621952]
+        map.insert(1705, &[3146240]);     // 1705 => [3146240]
+        map.insert(2039, &[7168]);     // 2039 => [7168]
+        map.insert(1342, &[11264]);     // 1342 => [11264]
+        map.insert(1419, &[19456]);     // 1419 => [19456]
+        map.insert(454, &[35840]);     // 454 => [35840]
+        map.insert(787, &[68608]);     // 787 => [68608]
+        map.insert(1721, &[134144]);     // 1721 => [134144]
+        map.insert(645, &[265216]);     // 645 => [265216]
+        map.insert(178, &[527360]);     // 178 => [527360]
+        map.insert(1244, &[1051648]);     // 1244 => [1051648]
+        map.insert(117, &[2100224]);     // 117 => [2100224]
+        map.insert(1737, &[13312]);     // 1737 => [13312]
+        map.insert(1660, &[21504]);     // 1660 => [21504]
+        map.insert(561, &[37888]);     // 561 => [37888]
+        map.insert(228, &[70656]);     // 228 => [70656]
+        map.insert(1358, &[136192]);     // 1358 => [136192]
+        map.insert(370, &[267264]);     // 370 => [267264]
+        map.insert(837, &[529408]);     // 837 => [529408]
+        map.insert(1835, &[1053696]);     // 1835 => [1053696]
+        map.insert(898, &[2102272]);     // 898 => [2102272]
+        map.insert(1205, &[25600]);     // 1205 => [25600]
+        map.insert(248, &[41984]);     // 248 => [41984]
+        map.insert(557, &[74752]);     // 557 => [74752]
+        map.insert(1927, &[140288]);     // 1927 => [140288]
+        map.insert(955, &[271360]);     // 955 => [271360]
+        map.insert(396, &[533504]);     // 396 => [533504]
+        map.insert(1506, &[1057792]);     // 1506 => [1057792]
+        map.insert(331, &[2106368]);     // 331 => [2106368]
+        map.insert(77, &[50176]);     // 77 => [50176]
+        map.insert(664, &[82944]);     // 664 => [82944]
+        map.insert(1842, &[148480]);     // 1842 => [148480]
+        map.insert(782, &[279552]);     // 782 => [279552]
+        map.insert(313, &[541696]);     // 313 => [541696]
+        map.insert(1367, &[1065984]);     // 1367 => [1065984]
+        map.insert(510, &[2114560]);     // 510 => [2114560]
+        map.insert(1749, &[99328]);     // 1749 => [99328]
+        map.insert(895, &[164864]);     // 895 => [164864]
+        map.insert(1859, &[295936]);     // 1859 => [295936]
+        map.insert(1396, &[558080]);     // 1396 => [558080]
+        map.insert(282, &[1082368]);     // 282 => [1082368]
+        map.insert(1459, &[2130944]);     // 1459 => [2130944]
+        map.insert(426, &[197632]);     // 426 => [197632]
+        map.insert(1430, &[328704]);     // 1430 => [328704]
+        map.insert(1953, &[590848]);     // 1953 => [590848]
+        map.insert(975, &[1115136]);     // 975 => [1115136]
+        map.insert(1894, &[2163712]);     // 1894 => [2163712]
+        map.insert(60, &[394240]);     // 60 => [394240]
+        map.insert(523, &[656384]);     // 523 => [656384]
+        map.insert(1637, &[1180672]);     // 1637 => [1180672]
+        map.insert(716, &[2229248]);     // 716 => [2229248]
+        map.insert(1591, &[787456]);     // 1591 => [787456]
+        map.insert(601, &[1311744]);     // 601 => [1311744]
+        map.insert(1776, &[2360320]);     // 1776 => [2360320]
+        map.insert(110, &[1573888]);     // 110 => [1573888]
+        map.insert(1223, &[2622464]);     // 1223 => [2622464]
+        map.insert(169, &[3146752]);     // 169 => [3146752]
+        map.insert(1441, &[14336]);     // 1441 => [14336]
+        map.insert(1300, &[22528]);     // 1300 => [22528]
+        map.insert(345, &[38912]);     // 345 => [38912]
+        map.insert(908, &[71680]);     // 908 => [71680]
+        map.insert(1574, &[137216]);     // 1574 => [137216]
+        map.insert(538, &[268288]);     // 538 => [268288]
+        map.insert(45, &[530432]);     // 45 => [530432]
+        map.insert(1091, &[1054720]);     // 1091 => [1054720]
+        map.insert(234, &[2103296]);     // 234 => [2103296]
+        map.insert(2013, &[26624]);     // 2013 => [26624]
+        map.insert(912, &[43008]);     // 912 => [43008]
+        map.insert(325, &[75776]);     // 325 => [75776]
+        map.insert(1263, &[141312]);     // 1263 => [141312]
+        map.insert(211, &[272384]);     // 211 => [272384]
+        map.insert(740, &[534528]);     // 740 => [534528]
+        map.insert(1674, &[1058816]);     // 1674 => [1058816]
+        map.insert(547, &[2107392]);     // 547 => [2107392]
+        map.insert(805, &[51200]);     // 805 => [51200]
+        map.insert(496, &[83968]);     // 496 => [83968]
+        map.insert(1114, &[149504]);     // 1114 => [149504]
+        map.insert(102, &[280576]);     // 102 => [280576]
+        map.insert(593, &[542720]);     // 593 => [542720]
+        map.insert(1599, &[1067008]);     // 1599 => [1067008]
+        map.insert(662, &[2115584]);     // 662 => [2115584]
+        map.insert(1469, &[100352]);     // 1469 => [100352]
+        map.insert(23, &[165888]);     // 23 => [165888]
+        map.insert(1067, &[296960]);     // 1067 => [296960]
+        map.insert(1564, &[559104]);     // 1564 => [559104]
+        map.insert(626, &[1083392]);     // 626 => [1083392]
+        map.insert(1755, &[2131968]);     // 1755 => [2131968]
+        map.insert(706, &[198656]);     // 706 => [198656]
+        map.insert(1790, &[329728]);     // 1790 => [329728]
+        map.insert(1225, &[591872]);     // 1225 => [591872]
+        map.insert(167, &[1116160]);     // 167 => [1116160]
+        map.insert(1038, &[2164736]);     // 1038 => [2164736]
+        map.insert(852, &[395264]);     // 852 => [395264]
+        map.insert(355, &[657408]);     // 355 => [657408]
+        map.insert(1293, &[1181696]);     // 1293 => [1181696]
+        map.insert(420, &[2230272]);     // 420 => [2230272]
+        map.insert(1375, &[788480]);     // 1375 => [788480]
+        map.insert(305, &[1312768]);     // 305 => [1312768]
+        map.insert(1432, &[2361344]);     // 1432 => [2361344]
+        map.insert(774, &[1574912]);     // 774 => [1574912]
+        map.insert(1967, &[2623488]);     // 1967 => [2623488]
+        map.insert(961, &[3147776]);     // 961 => [3147776]
+        map.insert(1066, &[28672]);     // 1066 => [28672]
+        map.insert(103, &[45056]);     // 103 => [45056]
+        map.insert(690, &[77824]);     // 690 => [77824]
+        map.insert(1816, &[143360]);     // 1816 => [143360]
+        map.insert(804, &[274432]);     // 804 => [274432]
+        map.insert(275, &[536576]);     // 275 => [536576]
+        map.insert(1405, &[1060864]);     // 1405 => [1060864]
+        map.insert(468, &[2109440]);     // 468 => [2109440]
+        map.insert(210, &[53248]);     // 210 => [53248]
+        map.insert(519, &[86016]);     // 519 => [86016]
+        map.insert(1965, &[151552]);     // 1965 => [151552]
+        map.insert(913, &[282624]);     // 913 => [282624]
+        map.insert(422, &[544768]);     // 422 => [544768]
+        map.insert(1480, &[1069056]);     // 1480 => [1069056]
+        map.insert(353, &[2117632]);     // 353 => [2117632]
+        map.insert(1610, &[102400]);     // 1610 => [102400]
+        map.insert(992, &[167936]);     // 992 => [167936]
+        map.insert(2012, &[299008]);     // 2012 => [299008]
+        map.insert(1515, &[561152]);     // 1515 => [561152]
+        map.insert(389, &[1085440]);     // 389 => [1085440]
+        map.insert(1324, &[2134016]);     // 1324 => [2134016]
+        map.insert(309, &[200704]);     // 309 => [200704]
+        map.insert(1289, &[331776]);     // 1289 => [331776]
+        map.insert(1854, &[593920]);     // 1854 => [593920]
+        map.insert(848, &[1118208]);     // 848 => [1118208]
+        map.insert(2041, &[2166784]);     // 2041 => [2166784]
+        map.insert(163, &[397312]);     // 163 => [397312]
+        map.insert(660, &[659456]);     // 660 => [659456]
+        map.insert(1786, &[1183744]);     // 1786 => [1183744]
+        map.insert(595, &[2232320]);     // 595 => [2232320]
+        map.insert(1704, &[790528]);     // 1704 => [790528]
+        map.insert(710, &[1314816]);     // 710 => [1314816]
+        map.insert(1647, &[2363392]);     // 1647 => [2363392]
+        map.insert(241, &[1576960]);     // 241 => [1576960]
+        map.insert(1112, &[2625536]);     // 1112 => [2625536]
+        map.insert(54, &[3149824]);     // 54 => [3149824]
+        map.insert(539, &[57344]);     // 539 => [57344]
+        map.insert(206, &[90112]);     // 206 => [90112]
+        map.insert(1380, &[155648]);     // 1380 => [155648]
+        map.insert(344, &[286720]);     // 344 => [286720]
+        map.insert(879, &[548864]);     // 879 => [548864]
+        map.insert(1793, &[1073152]);     // 1793 => [1073152]
+        map.insert(936, &[2121728]);     // 936 => [2121728]
+        map.insert(1155, &[106496]);     // 1155 => [106496]
+        map.insert(297, &[172032]);     // 297 => [172032]
+        map.insert(1301, &[303104]);     // 1301 => [303104]
+        map.insert(1826, &[565248]);     // 1826 => [565248]
+        map.insert(844, &[1089536]);     // 844 => [1089536]
+        map.insert(2021, &[2138112]);     // 2021 => [2138112]
+        map.insert(1020, &[204800]);     // 1020 => [204800]
+        map.insert(1984, &[335872]);     // 1984 => [335872]
+        map.insert(1527, &[598016]);     // 1527 => [598016]
+        map.insert(409, &[1122304]);     // 409 => [1122304]
+        map.insert(1328, &[2170880]);     // 1328 => [2170880]
+        map.insert(618, &[401408]);     // 618 => [401408]
+        map.insert(93, &[663552]);     // 93 => [663552]
+        map.insert(1075, &[1187840]);     // 1075 => [1187840]
+        map.insert(154, &[2236416]);     // 154 => [2236416]
+        map.insert(1121, &[794624]);     // 1121 => [794624]
+        map.insert(15, &[1318912]);     // 15 => [1318912]
+        map.insert(1190, &[2367488]);     // 1190 => [2367488]
+        map.insert(568, &[1581056]);     // 568 => [1581056]
+        map.insert(1681, &[2629632]);     // 1681 => [2629632]
+        map.insert(767, &[3153920]);     // 767 => [3153920]
+        map.insert(1078, &[114688]);     // 1078 => [114688]
+        map.insert(412, &[180224]);     // 412 => [180224]
+        map.insert(1440, &[311296]);     // 1440 => [311296]
+        map.insert(1943, &[573440]);     // 1943 => [573440]
+        map.insert(1017, &[1097728]);     // 1017 => [1097728]
+        map.insert(1872, &[2146304]);     // 1872 => [2146304]
+        map.insert(841, &[212992]);     // 841 => [212992]
+        map.insert(1909, &[344064]);     // 1909 => [344064]
+        map.insert(1346, &[606208]);     // 1346 => [606208]
+        map.insert(300, &[1130496]);     // 300 => [1130496]
+        map.insert(1413, &[2179072]);     // 1413 => [2179072]
+        map.insert(735, &[409600]);     // 735 => [409600]
+        map.insert(232, &[671744]);     // 232 => [671744]
+        map.insert(1158, &[1196032]);     // 1158 => [1196032]
+        map.insert(47, &[2244608]);     // 47 => [2244608]
+        map.insert(1236, &[802816]);     // 1236 => [802816]
+        map.insert(186, &[1327104]);     // 186 => [1327104]
+        map.insert(1043, &[2375680]);     // 1043 => [2375680]
+        map.insert(653, &[1589248]);     // 653 => [1589248]
+        map.insert(1572, &[2637824]);     // 1572 => [2637824]
+        map.insert(586, &[3162112]);     // 586 => [3162112]
+        map.insert(1796, &[229376]);     // 1796 => [229376]
+        map.insert(824, &[360448]);     // 824 => [360448]
+        map.insert(271, &[622592]);     // 271 => [622592]
+        map.insert(1377, &[1146880]);     // 1377 => [1146880]
+        map.insert(456, &[2195456]);     // 456 => [2195456]
+        map.insert(1682, &[425984]);     // 1682 => [425984]
+        map.insert(1189, &[688128]);     // 1189 => [688128]
+        map.insert(203, &[1212416]);     // 203 => [1212416]
+        map.insert(1122, &[2260992]);     // 1122 => [2260992]
+        map.insert(153, &[819200]);     // 153 => [819200]
+        map.insert(1271, &[1343488]);     // 1271 => [1343488]
+        map.insert(94, &[2392064]);     // 94 => [2392064]
+        map.insert(1728, &[1605632]);     // 1728 => [1605632]
+        map.insert(617, &[2654208]);     // 617 => [2654208]
+        map.insert(1543, &[3178496]);     // 1543 => [3178496]
+        map.insert(1095, &[458752]);     // 1095 => [458752]
+        map.insert(1648, &[720896]);     // 1648 => [720896]
+        map.insert(542, &[1245184]);     // 542 => [1245184]
+        map.insert(1719, &[2293760]);     // 1719 => [2293760]
+        map.insert(588, &[851968]);     // 588 => [851968]
+        map.insert(1570, &[1376256]);     // 1570 => [1376256]
+        map.insert(651, &[2424832]);     // 651 => [2424832]
+        map.insert(1045, &[1638400]);     // 1045 => [1638400]
+        map.insert(188, &[2686976]);     // 188 => [2686976]
+        map.insert(1234, &[3211264]);     // 1234 => [3211264]
+        map.insert(2022, &[917504]);     // 2022 => [917504]
+        map.insert(904, &[1441792]);     // 904 => [1441792]
+        map.insert(1825, &[2490368]);     // 1825 => [2490368]
+        map.insert(447, &[1703936]);     // 447 => [1703936]
+        map.insert(1302, &[2752512]);     // 1302 => [2752512]
+        map.insert(376, &[3276800]);     // 376 => [3276800]
+        map.insert(1411, &[1835008]);     // 1411 => [1835008]
+        map.insert(298, &[2883584]);     // 298 => [2883584]
+        map.insert(1348, &[3407872]);     // 1348 => [3407872]
+        map.insert(1907, &[3670016]);     // 1907 => [3670016]
+        map.insert(39, &[39]);     // 39 => [39]
+        map.insert(263, &[263]);     // 263 => [263]
+        map.insert(1031, &[1031]);     // 1031 => [1031]
+        map.insert(1172, &[4107]);     // 1172 => [4107]
+        map.insert(677, &[32779]);     // 677 => [32779]
+        map.insert(790, &[2097163]);     // 790 => [2097163]
+        map.insert(147, &[147]);     // 147 => [147]
+        map.insert(531, &[531]);     // 531 => [531]
+        map.insert(1959, &[1048595]);     // 1959 => [1048595]
+        map.insert(291, &[291]);     // 291 => [291]
+        map.insert(1059, &[1059]);     // 1059 => [1059]
+        map.insert(1557, &[8259]);     // 1557 => [8259]
+        map.insert(1696, &[16451]);     // 1696 => [16451]
+        map.insert(1426, &[131139]);     // 1426 => [131139]
+        map.insert(643, &[643]);     // 643 => [643]
+        map.insert(1847, &[1048707]);     // 1847 => [1048707]
+        map.insert(1283, &[1283]);     // 1283 => [1283]
+        map.insert(1463, &[1049091]);     // 1463 => [1049091]
+        map.insert(1808, &[67587]);     // 1808 => [67587]
+        map.insert(1670, &[264195]);     // 1670 => [264195]
+        map.insert(1201, &[526339]);     // 1201 => [526339]
+        map.insert(1586, &[36867]);     // 1586 => [36867]
+        map.insert(1921, &[2101251]);     // 1921 => [2101251]
+        map.insert(182, &[24579]);     // 182 => [24579]
+        map.insert(900, &[139267]);     // 900 => [139267]
+        map.insert(817, &[147459]);     // 817 => [147459]
+        map.insert(432, &[2129923]);     // 432 => [2129923]
+        map.insert(405, &[327683]);     // 405 => [327683]
+        map.insert(930, &[589827]);     // 930 => [589827]
+        map.insert(564, &[786435]);     // 564 => [786435]
+        map.insert(141, &[141]);     // 141 => [141]
+        map.insert(118, &[65549]);     // 118 => [65549]
+        map.insert(1500, &[131085]);     // 1500 => [131085]
+        map.insert(85, &[85]);     // 85 => [85]
+        map.insert(1162, &[4117]);     // 1162 => [4117]
+        map.insert(504, &[262165]);     // 504 => [262165]
+        map.insert(293, &[293]);     // 293 => [293]
+        map.insert(1061, &[1061]);     // 1061 => [1061]
+        map.insert(1242, &[4165]);     // 1242 => [4165]
+        map.insert(424, &[262213]);     // 424 => [262213]
+        map.insert(254, &[65669]);     // 254 => [65669]
+        map.insert(1364, &[131205]);     // 1364 => [131205]
+        map.insert(1285, &[1285]);     // 1285 => [1285]
+        map.insert(1107, &[8709]);     // 1107 => [8709]
+        map.insert(171, &[33285]);     // 171 => [33285]
+        map.insert(479, &[524805]);     // 479 => [524805]
+        map.insert(398, &[18437]);     // 398 => [18437]
+        map.insert(217, &[1050629]);     // 217 => [1050629]
+        map.insert(1136, &[2099205]);     // 1136 => [2099205]
+        map.insert(1399, &[266245]);     // 1399 => [266245]
+        map.insert(1277, &[40965]);     // 1277 => [40965]
+        map.insert(1417, &[532485]);     // 1417 => [532485]
+        map.insert(338, &[1064965]);     // 338 => [1064965]
+        map.insert(1531, &[2113541]);     // 1531 => [2113541]
+        map.insert(369, &[557061]);     // 369 => [557061]
+        map.insert(1455, &[196613]);     // 1455 => [196613]
+        map.insert(1196, &[3145733]);     // 1196 => [3145733]
+        map.insert(1049, &[1049]);     // 1049 => [1049]
+        map.insert(1905, &[2073]);     // 1905 => [2073]
+        map.insert(1615, &[8217]);     // 1615 => [8217]
+        map.insert(553, &[553]);     // 553 => [553]
+        map.insert(1738, &[16425]);     // 1738 => [16425]
+        map.insert(452, &[262185]);     // 452 => [262185]
+        map.insert(329, &[329]);     // 329 => [329]
+        map.insert(915, &[524361]);     // 915 => [524361]
+        map.insert(2045, &[1048649]);     // 2045 => [1048649]
+        map.insert(242, &[65673]);     // 242 => [65673]
+        map.insert(1368, &[131209]);     // 1368 => [131209]
+        map.insert(723, &[524553]);     // 723 => [524553]
+        map.insert(1725, &[1048841]);     // 1725 => [1048841]
+        map.insert(1258, &[16905]);     // 1258 => [16905]
+        map.insert(996, &[262665]);     // 996 => [262665]
+        map.insert(865, &[3081]);     // 865 => [3081]
+        map.insert(607, &[9225]);     // 607 => [9225]
+        map.insert(311, &[10249]);     // 311 => [10249]
+        map.insert(1592, &[36873]);     // 1592 => [36873]
+        map.insert(1931, &[2101257]);     // 1931 => [2101257]
+        map.insert(1799, &[278537]);     // 1799 => [278537]
+        map.insert(442, &[2129929]);     // 442 => [2129929]
+        map.insert(1443, &[196617]);     // 1443 => [196617]
+        map.insert(1127, &[1572873]);     // 1127 => [1572873]
+        map.insert(1504, &[131121]);     // 1504 => [131121]
+        map.insert(1003, &[524337]);     // 1003 => [524337]
+        map.insert(812, &[2097201]);     // 812 => [2097201]
+        map.insert(1230, &[4177]);     // 1230 => [4177]
+        map.insert(444, &[262225]);     // 444 => [262225]
+        map.insert(657, &[657]);     // 657 => [657]
+        map.insert(1829, &[1048721]);     // 1829 => [1048721]
+        map.insert(2034, &[16657]);     // 2034 => [16657]
+        map.insert(959, &[33041]);     // 959 => [33041]
+        map.insert(362, &[65809]);     // 362 => [65809]
+        map.insert(1445, &[1049105]);     // 1445 => [1049105]
+        map.insert(889, &[3089]);     // 889 => [3089]
+        map.insert(583, &[9233]);     // 583 => [9233]
+        map.insert(303, &[10257]);     // 303 => [10257]
+        map.insert(1379, &[266257]);     // 1379 => [266257]
+        map.insert(1116, &[49169]);     // 1116 => [49169]
+        map.insert(1673, &[81937]);     // 1673 => [81937]
+        map.insert(708, &[98321]);     // 708 => [98321]
+        map.insert(1562, &[655377]);     // 1562 => [655377]
+        map.insert(1757, &[2228241]);     // 1757 => [2228241]
+        map.insert(214, &[2621457]);     // 214 => [2621457]
+        map.insert(225, &[225]);     // 225 => [225]
+        map.insert(1801, &[2145]);     // 1801 => [2145]
+        map.insert(719, &[32865]);     // 719 => [32865]
+        map.insert(1993, &[2209]);     // 1993 => [2209]
+        map.insert(527, &[32929]);     // 527 => [32929]
+        map.insert(1313, &[1313]);     // 1313 => [1313]
+        map.insert(1218, &[16929]);     // 1218 => [16929]
+        map.insert(972, &[262689]);     // 972 => [262689]
+        map.insert(1511, &[34849]);     // 1511 => [34849]
+        map.insert(744, &[12321]);     // 744 => [12321]
+        map.insert(1221, &[69665]);     // 1221 => [69665]
+        map.insert(778, &[1052705]);     // 778 => [1052705]
+        map.insert(1548, &[73761]);     // 1548 => [73761]
+        map.insert(451, &[1056801]);     // 451 => [1056801]
+        map.insert(1839, &[278561]);     // 1839 => [278561]
+        map.insert(2030, &[1114145]);     // 2030 => [1114145]
+        map.insert(1578, &[655393]);     // 1578 => [655393]
+        map.insert(1773, &[2228257]);     // 1773 => [2228257]
+        map.insert(230, &[2621473]);     // 230 => [2621473]
+        map.insert(1961, &[2241]);     // 1961 => [2241]
+        map.insert(623, &[32961]);     // 623 => [32961]
+        map.insert(667, &[524609]);     // 667 => [524609]
+        map.insert(1781, &[1048897]);     // 1781 => [1048897]
+        map.insert(1601, &[1601]);     // 1601 => [1601]
+        map.insert(570, &[66113]);     // 570 => [66113]
+        map.insert(348, &[2097729]);     // 348 => [2097729]
+        map.insert(1082, &[66625]);     // 1082 => [66625]
+        map.insert(1884, &[2098241]);     // 1884 => [2098241]
+        map.insert(1415, &[34881]);     // 1415 => [34881]
+        map.insert(1331, &[266305]);     // 1331 => [266305]
+        map.insert(244, &[24641]);     // 244 => [24641]
+        map.insert(966, &[139329]);     // 966 => [139329]
+        map.insert(883, &[147521]);     // 883 => [147521]
+        map.insert(807, &[2162753]);     // 807 => [2162753]
+        map.insert(1071, &[1572929]);     // 1071 => [1572929]
+        map.insert(2007, &[8577]);     // 2007 => [8577]
+        map.insert(108, &[262529]);     // 108 => [262529]
+        map.insert(668, &[2097537]);     // 668 => [2097537]
+        map.insert(1333, &[1049217]);     // 1333 => [1049217]
+        map.insert(30, &[5249]);     // 30 => [5249]
+        map.insert(610, &[17537]);     // 610 => [17537]
+        map.insert(1883, &[525441]);     // 1883 => [525441]
+        map.insert(1351, &[34945]);     // 1351 => [34945]
+        map.insert(765, &[20609]);     // 765 => [20609]
+        map.insert(1988, &[528513]);     // 1988 => [528513]
+        map.insert(1850, &[270465]);     // 1850 => [270465]
+        map.insert(1482, &[2105473]);     // 1482 => [2105473]
+        map.insert(1464, &[540801]);     // 1464 => [540801]
+        map.insert(1323, &[196737]);     // 1323 => [196737]
+        map.insert(625, &[2359425]);     // 625 => [2359425]
+        map.insert(1129, &[2817]);     // 1129 => [2817]
+        map.insert(1950, &[4865]);     // 1950 => [4865]
+        map.insert(1744, &[131841]);     // 1744 => [131841]
+        map.insert(758, &[6401]);     // 758 => [6401]
+        map.insert(952, &[133377]);     // 952 => [133377]
+        map.insert(79, &[135425]);     // 79 => [135425]
+        map.insert(1722, &[270593]);     // 1722 => [270593]
+        map.insert(1098, &[2105601]);     // 1098 => [2105601]
+        map.insert(1356, &[49409]);     // 1356 => [49409]
+        map.insert(1945, &[82177]);     // 1945 => [82177]
+        map.insert(980, &[98561]);     // 980 => [98561]
+        map.insert(1009, &[2359553]);     // 1009 => [2359553]
+        map.insert(1391, &[1573121]);     // 1391 => [1573121]
+        map.insert(1658, &[67073]);     // 1658 => [67073]
+        map.insert(1308, &[2098689]);     // 1308 => [2098689]
+        map.insert(502, &[6657]);     // 502 => [6657]
+        map.insert(184, &[133633]);     // 184 => [133633]
+        map.insert(847, &[135681]);     // 847 => [135681]
+        map.insert(1785, &[41473]);     // 1785 => [41473]
+        map.insert(1933, &[532993]);     // 1933 => [532993]
+        map.insert(1295, &[279041]);     // 1295 => [279041]
+        map.insert(885, &[557569]);     // 885 => [557569]
+        map.insert(359, &[2163201]);     // 359 => [2163201]
+        map.insert(1343, &[11265]);     // 1343 => [11265]
+        map.insert(1661, &[21505]);     // 1661 => [21505]
+        map.insert(836, &[529409]);     // 836 => [529409]
+        map.insert(312, &[541697]);     // 312 => [541697]
+        map.insert(894, &[164865]);     // 894 => [164865]
+        map.insert(1858, &[295937]);     // 1858 => [295937]
+        map.insert(283, &[1082369]);     // 283 => [1082369]
+        map.insert(1895, &[2163713]);     // 1895 => [2163713]
+        map.insert(61, &[394241]);     // 61 => [394241]
+        map.insert(1636, &[1180673]);     // 1636 => [1180673]
+        map.insert(600, &[1311745]);     // 600 => [1311745]
+        map.insert(1575, &[137217]);     // 1575 => [137217]
+        map.insert(1598, &[1067009]);     // 1598 => [1067009]
+        map.insert(663, &[2115585]);     // 663 => [2115585]
+        map.insert(1791, &[329729]);     // 1791 => [329729]
+        map.insert(1224, &[591873]);     // 1224 => [591873]
+        map.insert(1374, &[788481]);     // 1374 => [788481]
+        map.insert(960, &[3147777]);     // 960 => [3147777]
+        map.insert(691, &[77825]);     // 691 => [77825]
+        map.insert(1404, &[1060865]);     // 1404 => [1060865]
+        map.insert(423, &[544769]);     // 423 => [544769]
+        map.insert(1325, &[2134017]);     // 1325 => [2134017]
+        map.insert(849, &[1118209]);     // 849 => [1118209]
+        map.insert(1381, &[155649]);     // 1381 => [155649]
+        map.insert(1827, &[565249]);     // 1827 => [565249]
+        map.insert(408, &[1122305]);     // 408 => [1122305]
+        map.insert(1191, &[2367489]);     // 1191 => [2367489]
+        map.insert(1079, &[114689]);     // 1079 => [114689]
+        map.insert(587, &[3162113]);     // 587 => [3162113]
+        map.insert(1683, &[425985]);     // 1683 => [425985]
+        map.insert(202, &[1212417]);     // 202 => [1212417]
+        map.insert(1270, &[1343489]);     // 1270 => [1343489]
+        map.insert(589, &[851969]);     // 589 => [851969]
+        map.insert(905, &[1441793]);     // 905 => [1441793]
+        map.insert(1303, &[2752513]);     // 1303 => [2752513]
+        map.insert(294, &[294]);     // 294 => [294]
+        map.insert(1062, &[1062]);     // 1062 => [1062]
+        map.insert(1286, &[1286]);     // 1286 => [1286]
+        map.insert(1595, &[36874]);     // 1595 => [36874]
+        map.insert(1928, &[2101258]);     // 1928 => [2101258]
+        map.insert(441, &[2129930]);     // 441 => [2129930]
+        map.insert(658, &[658]);     // 658 => [658]
+        map.insert(1830, &[1048722]);     // 1830 => [1048722]
+        map.insert(1446, &[1049106]);     // 1446 => [1049106]
+        map.insert(1314, &[1314]);     // 1314 => [1314]
+        map.insert(247, &[24642]);     // 247 => [24642]
+        map.insert(965, &[139330]);     // 965 => [139330]
+        map.insert(880, &[147522]);     // 880 => [147522]
+        map.insert(1334, &[1049218]);     // 1334 => [1049218]
+        map.insert(1788, &[329730]);     // 1788 => [329730]
+        map.insert(1227, &[591874]);     // 1227 => [591874]
+        map.insert(1373, &[788482]);     // 1373 => [788482]
+        map.insert(1326, &[2134018]);     // 1326 => [2134018]
+        map.insert(1382, &[155650]);     // 1382 => [155650]
+        map.insert(590, &[851970]);     // 590 => [851970]
+        map.insert(1316, &[1316]);     // 1316 => [1316]
+        map.insert(295, &[295]);     // 295 => [295]
+        map.insert(1063, &[1063]);     // 1063 => [1063]
+        map.insert(1287, &[1287]);     // 1287 => [1287]
+        map.insert(1594, &[36875]);     // 1594 => [36875]
+        map.insert(1929, &[2101259]);     // 1929 => [2101259]
+        map.insert(440, &[2129931]);     // 440 => [2129931]
+        map.insert(659, &[659]);     // 659 => [659]
+        map.insert(1831, &[1048723]);     // 1831 => [1048723]
+        map.insert(1447, &[1049107]);     // 1447 => [1049107]
+        map.insert(1315, &[1315]);     // 1315 => [1315]
+        map.insert(246, &[24643]);     // 246 => [24643]
+        map.insert(964, &[139331]);     // 964 => [139331]
+        map.insert(881, &[147523]);     // 881 => [147523]
+        map.insert(1335, &[1049219]);     // 1335 => [1049219]
+        map.insert(1789, &[329731]);     // 1789 => [329731]
+        map.insert(1226, &[591875]);     // 1226 => [591875]
+        map.insert(1372, &[788483]);     // 1372 => [788483]
+        map.insert(1327, &[2134019]);     // 1327 => [2134019]
+        map.insert(1383, &[155651]);     // 1383 => [155651]
+        map.insert(591, &[851971]);     // 591 => [851971]
+        map.insert(1317, &[1317]);     // 1317 => [1317]
+        map.insert(1318, &[1318]);     // 1318 => [1318]
+        map.insert(1319, &[1319]);     // 1319 => [1319]
+        map
+    })
 }
 
 impl GuavaCode22_11 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -2138,24 +2138,15 @@ impl BinaryCode for GuavaCode22_11 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(22);
@@ -2180,14 +2171,26 @@ impl BinaryCode for GuavaCode22_11 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        6
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(22, 11).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[22 / 64] & !((1 << 22) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2382,8 +2385,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, true, true, true, true, true, false, false, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
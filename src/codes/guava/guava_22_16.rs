@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,126 +16,127 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode22_16;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1376257 ],
-                &[ 2424834 ],
-                &[ 1638404 ],
-                &[ 2686984 ],
-                &[ 3211280 ],
-                &[ 4063264 ],
-                &[ 917568 ],
-                &[ 1441920 ],
-                &[ 2490624 ],
-                &[ 1704448 ],
-                &[ 2753536 ],
-                &[ 3278848 ],
-                &[ 1839104 ],
-                &[ 2891776 ],
-                &[ 3424256 ],
-                &[ 3702784 ],
-                
-            ], 22));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1420105 ],
-                &[ 3089738 ],
-                &[ 3557772 ],
-                &[ 903056 ],
-                &[ 135136 ],
-                &[ 3993600 ],
-                
-            ], 22));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(7, &[8]);     // 7 => [8]
-            map.insert(8, &[16]);     // 8 => [16]
-            map.insert(16, &[32]);     // 16 => [32]
-            map.insert(19, &[64]);     // 19 => [64]
-            map.insert(28, &[128]);     // 28 => [128]
-            map.insert(31, &[256]);     // 31 => [256]
-            map.insert(25, &[512]);     // 25 => [512]
-            map.insert(26, &[1024]);     // 26 => [1024]
-            map.insert(21, &[2048]);     // 21 => [2048]
-            map.insert(32, &[4096]);     // 32 => [4096]
-            map.insert(35, &[8192]);     // 35 => [8192]
-            map.insert(44, &[16384]);     // 44 => [16384]
-            map.insert(41, &[32768]);     // 41 => [32768]
-            map.insert(11, &[65536]);     // 11 => [65536]
-            map.insert(22, &[131072]);     // 22 => [131072]
-            map.insert(47, &[262144]);     // 47 => [262144]
-            map.insert(42, &[524288]);     // 42 => [524288]
-            map.insert(37, &[1048576]);     // 37 => [1048576]
-            map.insert(38, &[2097152]);     // 38 => [2097152]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[9]);     // 6 => [9]
-            map.insert(9, &[17]);     // 9 => [17]
-            map.insert(17, &[33]);     // 17 => [33]
-            map.insert(18, &[65]);     // 18 => [65]
-            map.insert(29, &[129]);     // 29 => [129]
-            map.insert(30, &[257]);     // 30 => [257]
-            map.insert(24, &[513]);     // 24 => [513]
-            map.insert(27, &[1025]);     // 27 => [1025]
-            map.insert(20, &[2049]);     // 20 => [2049]
-            map.insert(33, &[4097]);     // 33 => [4097]
-            map.insert(34, &[8193]);     // 34 => [8193]
-            map.insert(45, &[16385]);     // 45 => [16385]
-            map.insert(40, &[32769]);     // 40 => [32769]
-            map.insert(10, &[65537]);     // 10 => [65537]
-            map.insert(23, &[131073]);     // 23 => [131073]
-            map.insert(46, &[262145]);     // 46 => [262145]
-            map.insert(43, &[524289]);     // 43 => [524289]
-            map.insert(36, &[1048577]);     // 36 => [1048577]
-            map.insert(39, &[2097153]);     // 39 => [2097153]
-            map.insert(12, &[20]);     // 12 => [20]
-            map.insert(15, &[65540]);     // 15 => [65540]
-            map.insert(48, &[4128]);     // 48 => [4128]
-            map.insert(51, &[8224]);     // 51 => [8224]
-            map.insert(60, &[16416]);     // 60 => [16416]
-            map.insert(57, &[32800]);     // 57 => [32800]
-            map.insert(63, &[262176]);     // 63 => [262176]
-            map.insert(58, &[524320]);     // 58 => [524320]
-            map.insert(53, &[1048608]);     // 53 => [1048608]
-            map.insert(54, &[2097184]);     // 54 => [2097184]
-            map.insert(13, &[21]);     // 13 => [21]
-            map.insert(14, &[65541]);     // 14 => [65541]
-            map.insert(49, &[4129]);     // 49 => [4129]
-            map.insert(50, &[8225]);     // 50 => [8225]
-            map.insert(61, &[16417]);     // 61 => [16417]
-            map.insert(56, &[32801]);     // 56 => [32801]
-            map.insert(62, &[262177]);     // 62 => [262177]
-            map.insert(59, &[524321]);     // 59 => [524321]
-            map.insert(52, &[1048609]);     // 52 => [1048609]
-            map.insert(55, &[2097185]);     // 55 => [2097185]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1376257 ],
+            &[ 2424834 ],
+            &[ 1638404 ],
+            &[ 2686984 ],
+            &[ 3211280 ],
+            &[ 4063264 ],
+            &[ 917568 ],
+            &[ 1441920 ],
+            &[ 2490624 ],
+            &[ 1704448 ],
+            &[ 2753536 ],
+            &[ 3278848 ],
+            &[ 1839104 ],
+            &[ 2891776 ],
+            &[ 3424256 ],
+            &[ 3702784 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 22)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1420105 ],
+            &[ 3089738 ],
+            &[ 3557772 ],
+            &[ 903056 ],
+            &[ 135136 ],
+            &[ 3993600 ],
+            
+        ], 22)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(7, &[8]);     // 7 => [8]
+        map.insert(8, &[16]);     // 8 => [16]
+        map.insert(16, &[32]);     // 16 => [32]
+        map.insert(19, &[64]);     // 19 => [64]
+        map.insert(28, &[128]);     // 28 => [128]
+        map.insert(31, &[256]);     // 31 => [256]
+        map.insert(25, &[512]);     // 25 => [512]
+        map.insert(26, &[1024]);     // 26 => [1024]
+        map.insert(21, &[2048]);     // 21 => [2048]
+        map.insert(32, &[4096]);     // 32 => [4096]
+        map.insert(35, &[8192]);     // 35 => [8192]
+        map.insert(44, &[16384]);     // 44 => [16384]
+        map.insert(41, &[32768]);     // 41 => [32768]
+        map.insert(11, &[65536]);     // 11 => [65536]
+        map.insert(22, &[131072]);     // 22 => [131072]
+        map.insert(47, &[262144]);     // 47 => [262144]
+        map.insert(42, &[524288]);     // 42 => [524288]
+        map.insert(37, &[1048576]);     // 37 => [1048576]
+        map.insert(38, &[2097152]);     // 38 => [2097152]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[9]);     // 6 => [9]
+        map.insert(9, &[17]);     // 9 => [17]
+        map.insert(17, &[33]);     // 17 => [33]
+        map.insert(18, &[65]);     // 18 => [65]
+        map.insert(29, &[129]);     // 29 => [129]
+        map.insert(30, &[257]);     // 30 => [257]
+        map.insert(24, &[513]);     // 24 => [513]
+        map.insert(27, &[1025]);     // 27 => [1025]
+        map.insert(20, &[2049]);     // 20 => [2049]
+        map.insert(33, &[4097]);     // 33 => [4097]
+        map.insert(34, &[8193]);     // 34 => [8193]
+        map.insert(45, &[16385]);     // 45 => [16385]
+        map.insert(40, &[32769]);     // 40 => [32769]
+        map.insert(10, &[65537]);     // 10 => [65537]
+        map.insert(23, &[131073]);     // 23 => [131073]
+        map.insert(46, &[262145]);     // 46 => [262145]
+        map.insert(43, &[524289]);     // 43 => [524289]
+        map.insert(36, &[1048577]);     // 36 => [1048577]
+        map.insert(39, &[2097153]);     // 39 => [2097153]
+        map.insert(12, &[20]);     // 12 => [20]
+        map.insert(15, &[65540]);     // 15 => [65540]
+        map.insert(48, &[4128]);     // 48 => [4128]
+        map.insert(51, &[8224]);     // 51 => [8224]
+        map.insert(60, &[16416]);     // 60 => [16416]
+        map.insert(57, &[32800]);     // 57 => [32800]
+        map.insert(63, &[262176]);     // 63 => [262176]
+        map.insert(58, &[524320]);     // 58 => [524320]
+        map.insert(53, &[1048608]);     // 53 => [1048608]
+        map.insert(54, &[2097184]);     // 54 => [2097184]
+        map.insert(13, &[21]);     // 13 => [21]
+        map.insert(14, &[65541]);     // 14 => [65541]
+        map.insert(49, &[4129]);     // 49 => [4129]
+        map.insert(50, &[8225]);     // 50 => [8225]
+        map.insert(61, &[16417]);     // 61 => [16417]
+        map.insert(56, &[32801]);     // 56 => [32801]
+        map.insert(62, &[262177]);     // 62 => [262177]
+        map.insert(59, &[524321]);     // 59 => [524321]
+        map.insert(52, &[1048609]);     // 52 => [1048609]
+        map.insert(55, &[2097185]);     // 55 => [2097185]
+        map
+    })
 }
 
 impl GuavaCode22_16 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -154,24 +154,15 @@ impl BinaryCode for GuavaCode22_16 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(22);
@@ -196,14 +187,26 @@ impl BinaryCode for GuavaCode22_16 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(22, 16).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[22 / 64] & !((1 << 22) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -398,8 +401,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, true, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,78 +16,79 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode22_18;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 262145 ],
-                &[ 262146 ],
-                &[ 262148 ],
-                &[ 262152 ],
-                &[ 262160 ],
-                &[ 262176 ],
-                &[ 262208 ],
-                &[ 262272 ],
-                &[ 262400 ],
-                &[ 262656 ],
-                &[ 263168 ],
-                &[ 264192 ],
-                &[ 266240 ],
-                &[ 270336 ],
-                &[ 278528 ],
-                &[ 294912 ],
-                &[ 327680 ],
-                &[ 393216 ],
-                
-            ], 22));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 524287 ],
-                &[ 524288 ],
-                &[ 1048576 ],
-                &[ 2097152 ],
-                
-            ], 22));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(16, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[524288]);     // 2 => [524288]
-            map.insert(4, &[1048576]);     // 4 => [1048576]
-            map.insert(8, &[2097152]);     // 8 => [2097152]
-            map.insert(3, &[524289]);     // 3 => [524289]
-            map.insert(5, &[1048577]);     // 5 => [1048577]
-            map.insert(9, &[2097153]);     // 9 => [2097153]
-            map.insert(6, &[1572864]);     // 6 => [1572864]
-            map.insert(10, &[2621440]);     // 10 => [2621440]
-            map.insert(12, &[3145728]);     // 12 => [3145728]
-            map.insert(7, &[1572865]);     // 7 => [1572865]
-            map.insert(11, &[2621441]);     // 11 => [2621441]
-            map.insert(13, &[3145729]);     // 13 => [3145729]
-            map.insert(14, &[3670016]);     // 14 => [3670016]
-            map.insert(15, &[3670017]);     // 15 => [3670017]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 262145 ],
+            &[ 262146 ],
+            &[ 262148 ],
+            &[ 262152 ],
+            &[ 262160 ],
+            &[ 262176 ],
+            &[ 262208 ],
+            &[ 262272 ],
+            &[ 262400 ],
+            &[ 262656 ],
+            &[ 263168 ],
+            &[ 264192 ],
+            &[ 266240 ],
+            &[ 270336 ],
+            &[ 278528 ],
+            &[ 294912 ],
+            &[ 327680 ],
+            &[ 393216 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 22)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 524287 ],
+            &[ 524288 ],
+            &[ 1048576 ],
+            &[ 2097152 ],
+            
+        ], 22)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(16, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[524288]);     // 2 => [524288]
+        map.insert(4, &[1048576]);     // 4 => [1048576]
+        map.insert(8, &[2097152]);     // 8 => [2097152]
+        map.insert(3, &[524289]);     // 3 => [524289]
+        map.insert(5, &[1048577]);     // 5 => [1048577]
+        map.insert(9, &[2097153]);     // 9 => [2097153]
+        map.insert(6, &[1572864]);     // 6 => [1572864]
+        map.insert(10, &[2621440]);     // 10 => [2621440]
+        map.insert(12, &[3145728]);     // 12 => [3145728]
+        map.insert(7, &[1572865]);     // 7 => [1572865]
+        map.insert(11, &[2621441]);     // 11 => [2621441]
+        map.insert(13, &[3145729]);     // 13 => [3145729]
+        map.insert(14, &[3670016]);     // 14 => [3670016]
+        map.insert(15, &[3670017]);     // 15 => [3670017]
+        map
+    })
 }
 
 impl GuavaCode22_18 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -106,24 +106,15 @@ impl BinaryCode for GuavaCode22_18 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(22);
@@ -148,14 +139,26 @@ impl BinaryCode for GuavaCode22_18 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        4
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(22, 18).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[22 / 64] & !((1 << 22) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -350,8 +353,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,2111 +16,2112 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode23_12;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 4673537 ],
-                &[ 651266 ],
-                &[ 5550084 ],
-                &[ 3026952 ],
-                &[ 5976080 ],
-                &[ 7757856 ],
-                &[ 7127104 ],
-                &[ 2019456 ],
-                &[ 4038912 ],
-                &[ 3883520 ],
-                &[ 7459840 ],
-                &[ 6531072 ],
-                
-            ], 23));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 2422785 ],
-                &[ 6969346 ],
-                &[ 3610628 ],
-                &[ 5085192 ],
-                &[ 5976080 ],
-                &[ 7757856 ],
-                &[ 1009728 ],
-                &[ 2019456 ],
-                &[ 4038912 ],
-                &[ 5827072 ],
-                &[ 7459840 ],
-                
-            ], 23));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 4673537 ],
+            &[ 651266 ],
+            &[ 5550084 ],
+            &[ 3026952 ],
+            &[ 5976080 ],
+            &[ 7757856 ],
+            &[ 7127104 ],
+            &[ 2019456 ],
+            &[ 4038912 ],
+            &[ 3883520 ],
+            &[ 7459840 ],
+            &[ 6531072 ],
+            
+        ], 23)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(2048, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(64, &[64]);     // 64 => [64]
-            map.insert(128, &[128]);     // 128 => [128]
-            map.insert(256, &[256]);     // 256 => [256]
-            map.insert(512, &[512]);     // 512 => [512]
-            map.insert(1024, &[1024]);     // 1024 => [1024]
-            map.insert(591, &[2048]);     // 591 => [2048]
-            map.insert(1183, &[4096]);     // 1183 => [4096]
-            map.insert(881, &[8192]);     // 881 => [8192]
-            map.insert(1763, &[16384]);     // 1763 => [16384]
-            map.insert(1929, &[32768]);     // 1929 => [32768]
-            map.insert(1372, &[65536]);     // 1372 => [65536]
-            map.insert(246, &[131072]);     // 246 => [131072]
-            map.insert(493, &[262144]);     // 493 => [262144]
-            map.insert(986, &[524288]);     // 986 => [524288]
-            map.insert(1972, &[1048576]);     // 1972 => [1048576]
-            map.insert(1319, &[2097152]);     // 1319 => [2097152]
-            map.insert(1594, &[4194304]);     // 1594 => [4194304]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(65, &[65]);     // 65 => [65]
-            map.insert(129, &[129]);     // 129 => [129]
-            map.insert(257, &[257]);     // 257 => [257]
-            map.insert(513, &[513]);     // 513 => [513]
-            map.insert(1025, &[1025]);     // 1025 => [1025]
-            map.insert(590, &[2049]);     // 590 => [2049]
-            map.insert(1182, &[4097]);     // 1182 => [4097]
-            map.insert(880, &[8193]);     // 880 => [8193]
-            map.insert(1762, &[16385]);     // 1762 => [16385]
-            map.insert(1928, &[32769]);     // 1928 => [32769]
-            map.insert(1373, &[65537]);     // 1373 => [65537]
-            map.insert(247, &[131073]);     // 247 => [131073]
-            map.insert(492, &[262145]);     // 492 => [262145]
-            map.insert(987, &[524289]);     // 987 => [524289]
-            map.insert(1973, &[1048577]);     // 1973 => [1048577]
-            map.insert(1318, &[2097153]);     // 1318 => [2097153]
-            map.insert(1595, &[4194305]);     // 1595 => [4194305]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(66, &[66]);     // 66 => [66]
-            map.insert(130, &[130]);     // 130 => [130]
-            map.insert(258, &[258]);     // 258 => [258]
-            map.insert(514, &[514]);     // 514 => [514]
-            map.insert(1026, &[1026]);     // 1026 => [1026]
-            map.insert(589, &[2050]);     // 589 => [2050]
-            map.insert(1181, &[4098]);     // 1181 => [4098]
-            map.insert(883, &[8194]);     // 883 => [8194]
-            map.insert(1761, &[16386]);     // 1761 => [16386]
-            map.insert(1931, &[32770]);     // 1931 => [32770]
-            map.insert(1374, &[65538]);     // 1374 => [65538]
-            map.insert(244, &[131074]);     // 244 => [131074]
-            map.insert(495, &[262146]);     // 495 => [262146]
-            map.insert(984, &[524290]);     // 984 => [524290]
-            map.insert(1974, &[1048578]);     // 1974 => [1048578]
-            map.insert(1317, &[2097154]);     // 1317 => [2097154]
-            map.insert(1592, &[4194306]);     // 1592 => [4194306]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(68, &[68]);     // 68 => [68]
-            map.insert(132, &[132]);     // 132 => [132]
-            map.insert(260, &[260]);     // 260 => [260]
-            map.insert(516, &[516]);     // 516 => [516]
-            map.insert(1028, &[1028]);     // 1028 => [1028]
-            map.insert(587, &[2052]);     // 587 => [2052]
-            map.insert(1179, &[4100]);     // 1179 => [4100]
-            map.insert(885, &[8196]);     // 885 => [8196]
-            map.insert(1767, &[16388]);     // 1767 => [16388]
-            map.insert(1933, &[32772]);     // 1933 => [32772]
-            map.insert(1368, &[65540]);     // 1368 => [65540]
-            map.insert(242, &[131076]);     // 242 => [131076]
-            map.insert(489, &[262148]);     // 489 => [262148]
-            map.insert(990, &[524292]);     // 990 => [524292]
-            map.insert(1968, &[1048580]);     // 1968 => [1048580]
-            map.insert(1315, &[2097156]);     // 1315 => [2097156]
-            map.insert(1598, &[4194308]);     // 1598 => [4194308]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(72, &[72]);     // 72 => [72]
-            map.insert(136, &[136]);     // 136 => [136]
-            map.insert(264, &[264]);     // 264 => [264]
-            map.insert(520, &[520]);     // 520 => [520]
-            map.insert(1032, &[1032]);     // 1032 => [1032]
-            map.insert(583, &[2056]);     // 583 => [2056]
-            map.insert(1175, &[4104]);     // 1175 => [4104]
-            map.insert(889, &[8200]);     // 889 => [8200]
-            map.insert(1771, &[16392]);     // 1771 => [16392]
-            map.insert(1921, &[32776]);     // 1921 => [32776]
-            map.insert(1364, &[65544]);     // 1364 => [65544]
-            map.insert(254, &[131080]);     // 254 => [131080]
-            map.insert(485, &[262152]);     // 485 => [262152]
-            map.insert(978, &[524296]);     // 978 => [524296]
-            map.insert(1980, &[1048584]);     // 1980 => [1048584]
-            map.insert(1327, &[2097160]);     // 1327 => [2097160]
-            map.insert(1586, &[4194312]);     // 1586 => [4194312]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(80, &[80]);     // 80 => [80]
-            map.insert(144, &[144]);     // 144 => [144]
-            map.insert(272, &[272]);     // 272 => [272]
-            map.insert(528, &[528]);     // 528 => [528]
-            map.insert(1040, &[1040]);     // 1040 => [1040]
-            map.insert(607, &[2064]);     // 607 => [2064]
-            map.insert(1167, &[4112]);     // 1167 => [4112]
-            map.insert(865, &[8208]);     // 865 => [8208]
-            map.insert(1779, &[16400]);     // 1779 => [16400]
-            map.insert(1945, &[32784]);     // 1945 => [32784]
-            map.insert(1356, &[65552]);     // 1356 => [65552]
-            map.insert(230, &[131088]);     // 230 => [131088]
-            map.insert(509, &[262160]);     // 509 => [262160]
-            map.insert(970, &[524304]);     // 970 => [524304]
-            map.insert(1956, &[1048592]);     // 1956 => [1048592]
-            map.insert(1335, &[2097168]);     // 1335 => [2097168]
-            map.insert(1578, &[4194320]);     // 1578 => [4194320]
-            map.insert(96, &[96]);     // 96 => [96]
-            map.insert(160, &[160]);     // 160 => [160]
-            map.insert(288, &[288]);     // 288 => [288]
-            map.insert(544, &[544]);     // 544 => [544]
-            map.insert(1056, &[1056]);     // 1056 => [1056]
-            map.insert(623, &[2080]);     // 623 => [2080]
-            map.insert(1215, &[4128]);     // 1215 => [4128]
-            map.insert(849, &[8224]);     // 849 => [8224]
-            map.insert(1731, &[16416]);     // 1731 => [16416]
-            map.insert(1961, &[32800]);     // 1961 => [32800]
-            map.insert(1404, &[65568]);     // 1404 => [65568]
-            map.insert(214, &[131104]);     // 214 => [131104]
-            map.insert(461, &[262176]);     // 461 => [262176]
-            map.insert(1018, &[524320]);     // 1018 => [524320]
-            map.insert(1940, &[1048608]);     // 1940 => [1048608]
-            map.insert(1287, &[2097184]);     // 1287 => [2097184]
-            map.insert(1562, &[4194336]);     // 1562 => [4194336]
-            map.insert(192, &[192]);     // 192 => [192]
-            map.insert(320, &[320]);     // 320 => [320]
-            map.insert(576, &[576]);     // 576 => [576]
-            map.insert(1088, &[1088]);     // 1088 => [1088]
-            map.insert(527, &[2112]);     // 527 => [2112]
-            map.insert(1247, &[4160]);     // 1247 => [4160]
-            map.insert(817, &[8256]);     // 817 => [8256]
-            map.insert(1699, &[16448]);     // 1699 => [16448]
-            map.insert(1993, &[32832]);     // 1993 => [32832]
-            map.insert(1308, &[65600]);     // 1308 => [65600]
-            map.insert(182, &[131136]);     // 182 => [131136]
-            map.insert(429, &[262208]);     // 429 => [262208]
-            map.insert(922, &[524352]);     // 922 => [524352]
-            map.insert(2036, &[1048640]);     // 2036 => [1048640]
-            map.insert(1383, &[2097216]);     // 1383 => [2097216]
-            map.insert(1658, &[4194368]);     // 1658 => [4194368]
-            map.insert(384, &[384]);     // 384 => [384]
-            map.insert(640, &[640]);     // 640 => [640]
-            map.insert(1152, &[1152]);     // 1152 => [1152]
-            map.insert(719, &[2176]);     // 719 => [2176]
-            map.insert(1055, &[4224]);     // 1055 => [4224]
-            map.insert(1009, &[8320]);     // 1009 => [8320]
-            map.insert(1635, &[16512]);     // 1635 => [16512]
-            map.insert(1801, &[32896]);     // 1801 => [32896]
-            map.insert(1500, &[65664]);     // 1500 => [65664]
-            map.insert(118, &[131200]);     // 118 => [131200]
-            map.insert(365, &[262272]);     // 365 => [262272]
-            map.insert(858, &[524416]);     // 858 => [524416]
-            map.insert(1844, &[1048704]);     // 1844 => [1048704]
-            map.insert(1447, &[2097280]);     // 1447 => [2097280]
-            map.insert(1722, &[4194432]);     // 1722 => [4194432]
-            map.insert(768, &[768]);     // 768 => [768]
-            map.insert(1280, &[1280]);     // 1280 => [1280]
-            map.insert(847, &[2304]);     // 847 => [2304]
-            map.insert(1439, &[4352]);     // 1439 => [4352]
-            map.insert(625, &[8448]);     // 625 => [8448]
-            map.insert(2019, &[16640]);     // 2019 => [16640]
-            map.insert(1673, &[33024]);     // 1673 => [33024]
-            map.insert(1116, &[65792]);     // 1116 => [65792]
-            map.insert(502, &[131328]);     // 502 => [131328]
-            map.insert(237, &[262400]);     // 237 => [262400]
-            map.insert(730, &[524544]);     // 730 => [524544]
-            map.insert(1716, &[1048832]);     // 1716 => [1048832]
-            map.insert(1063, &[2097408]);     // 1063 => [2097408]
-            map.insert(1850, &[4194560]);     // 1850 => [4194560]
-            map.insert(1536, &[1536]);     // 1536 => [1536]
-            map.insert(79, &[2560]);     // 79 => [2560]
-            map.insert(1695, &[4608]);     // 1695 => [4608]
-            map.insert(369, &[8704]);     // 369 => [8704]
-            map.insert(1251, &[16896]);     // 1251 => [16896]
-            map.insert(1417, &[33280]);     // 1417 => [33280]
-            map.insert(1884, &[66048]);     // 1884 => [66048]
-            map.insert(758, &[131584]);     // 758 => [131584]
-            map.insert(1005, &[262656]);     // 1005 => [262656]
-            map.insert(474, &[524800]);     // 474 => [524800]
-            map.insert(1460, &[1049088]);     // 1460 => [1049088]
-            map.insert(1831, &[2097664]);     // 1831 => [2097664]
-            map.insert(1082, &[4194816]);     // 1082 => [4194816]
-            map.insert(1615, &[3072]);     // 1615 => [3072]
-            map.insert(159, &[5120]);     // 159 => [5120]
-            map.insert(1905, &[9216]);     // 1905 => [9216]
-            map.insert(739, &[17408]);     // 739 => [17408]
-            map.insert(905, &[33792]);     // 905 => [33792]
-            map.insert(348, &[66560]);     // 348 => [66560]
-            map.insert(1270, &[132096]);     // 1270 => [132096]
-            map.insert(1517, &[263168]);     // 1517 => [263168]
-            map.insert(2010, &[525312]);     // 2010 => [525312]
-            map.insert(948, &[1049600]);     // 948 => [1049600]
-            map.insert(295, &[2098176]);     // 295 => [2098176]
-            map.insert(570, &[4195328]);     // 570 => [4195328]
-            map.insert(1744, &[6144]);     // 1744 => [6144]
-            map.insert(318, &[10240]);     // 318 => [10240]
-            map.insert(1196, &[18432]);     // 1196 => [18432]
-            map.insert(1478, &[34816]);     // 1478 => [34816]
-            map.insert(1811, &[67584]);     // 1811 => [67584]
-            map.insert(697, &[133120]);     // 697 => [133120]
-            map.insert(930, &[264192]);     // 930 => [264192]
-            map.insert(405, &[526336]);     // 405 => [526336]
-            map.insert(1531, &[1050624]);     // 1531 => [1050624]
-            map.insert(1896, &[2099200]);     // 1896 => [2099200]
-            map.insert(1141, &[4196352]);     // 1141 => [4196352]
-            map.insert(2030, &[12288]);     // 2030 => [12288]
-            map.insert(636, &[20480]);     // 636 => [20480]
-            map.insert(790, &[36864]);     // 790 => [36864]
-            map.insert(451, &[69632]);     // 451 => [69632]
-            map.insert(1129, &[135168]);     // 1129 => [135168]
-            map.insert(1394, &[266240]);     // 1394 => [266240]
-            map.insert(1861, &[528384]);     // 1861 => [528384]
-            map.insert(811, &[1052672]);     // 811 => [1052672]
-            map.insert(440, &[2101248]);     // 440 => [2101248]
-            map.insert(677, &[4198400]);     // 677 => [4198400]
-            map.insert(1426, &[24576]);     // 1426 => [24576]
-            map.insert(1272, &[40960]);     // 1272 => [40960]
-            map.insert(1581, &[73728]);     // 1581 => [73728]
-            map.insert(903, &[139264]);     // 903 => [139264]
-            map.insert(668, &[270336]);     // 668 => [270336]
-            map.insert(171, &[532480]);     // 171 => [532480]
-            map.insert(1221, &[1056768]);     // 1221 => [1056768]
-            map.insert(1622, &[2105344]);     // 1622 => [2105344]
-            map.insert(1355, &[4202496]);     // 1355 => [4202496]
-            map.insert(362, &[49152]);     // 362 => [49152]
-            map.insert(959, &[81920]);     // 959 => [81920]
-            map.insert(1557, &[147456]);     // 1557 => [147456]
-            map.insert(1806, &[278528]);     // 1806 => [278528]
-            map.insert(1337, &[540672]);     // 1337 => [540672]
-            map.insert(343, &[1064960]);     // 343 => [1064960]
-            map.insert(964, &[2113536]);     // 964 => [2113536]
-            map.insert(217, &[4210688]);     // 217 => [4210688]
-            map.insert(725, &[98304]);     // 725 => [98304]
-            map.insert(1919, &[163840]);     // 1919 => [163840]
-            map.insert(1636, &[294912]);     // 1636 => [294912]
-            map.insert(1107, &[557056]);     // 1107 => [557056]
-            map.insert(61, &[1081344]);     // 61 => [1081344]
-            map.insert(686, &[2129920]);     // 686 => [2129920]
-            map.insert(435, &[4227072]);     // 435 => [4227072]
-            map.insert(1450, &[196608]);     // 1450 => [196608]
-            map.insert(1201, &[327680]);     // 1201 => [327680]
-            map.insert(1670, &[589824]);     // 1670 => [589824]
-            map.insert(744, &[1114112]);     // 744 => [1114112]
-            map.insert(123, &[2162688]);     // 123 => [2162688]
-            map.insert(870, &[4259840]);     // 870 => [4259840]
-            map.insert(283, &[393216]);     // 283 => [393216]
-            map.insert(812, &[655360]);     // 812 => [655360]
-            map.insert(1858, &[1179648]);     // 1858 => [1179648]
-            map.insert(1489, &[2228224]);     // 1489 => [2228224]
-            map.insert(1740, &[4325376]);     // 1740 => [4325376]
-            map.insert(567, &[786432]);     // 567 => [786432]
-            map.insert(1625, &[1310720]);     // 1625 => [1310720]
-            map.insert(1226, &[2359296]);     // 1226 => [2359296]
-            map.insert(2007, &[4456448]);     // 2007 => [4456448]
-            map.insert(1134, &[1572864]);     // 1134 => [1572864]
-            map.insert(1789, &[2621440]);     // 1789 => [2621440]
-            map.insert(1504, &[4718592]);     // 1504 => [4718592]
-            map.insert(659, &[3145728]);     // 659 => [3145728]
-            map.insert(398, &[5242880]);     // 398 => [5242880]
-            map.insert(797, &[6291456]);     // 797 => [6291456]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(19, &[19]);     // 19 => [19]
-            map.insert(35, &[35]);     // 35 => [35]
-            map.insert(67, &[67]);     // 67 => [67]
-            map.insert(131, &[131]);     // 131 => [131]
-            map.insert(259, &[259]);     // 259 => [259]
-            map.insert(515, &[515]);     // 515 => [515]
-            map.insert(1027, &[1027]);     // 1027 => [1027]
-            map.insert(588, &[2051]);     // 588 => [2051]
-            map.insert(1180, &[4099]);     // 1180 => [4099]
-            map.insert(882, &[8195]);     // 882 => [8195]
-            map.insert(1760, &[16387]);     // 1760 => [16387]
-            map.insert(1930, &[32771]);     // 1930 => [32771]
-            map.insert(1375, &[65539]);     // 1375 => [65539]
-            map.insert(245, &[131075]);     // 245 => [131075]
-            map.insert(494, &[262147]);     // 494 => [262147]
-            map.insert(985, &[524291]);     // 985 => [524291]
-            map.insert(1975, &[1048579]);     // 1975 => [1048579]
-            map.insert(1316, &[2097155]);     // 1316 => [2097155]
-            map.insert(1593, &[4194307]);     // 1593 => [4194307]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(69, &[69]);     // 69 => [69]
-            map.insert(133, &[133]);     // 133 => [133]
-            map.insert(261, &[261]);     // 261 => [261]
-            map.insert(517, &[517]);     // 517 => [517]
-            map.insert(1029, &[1029]);     // 1029 => [1029]
-            map.insert(586, &[2053]);     // 586 => [2053]
-            map.insert(1178, &[4101]);     // 1178 => [4101]
-            map.insert(884, &[8197]);     // 884 => [8197]
-            map.insert(1766, &[16389]);     // 1766 => [16389]
-            map.insert(1932, &[32773]);     // 1932 => [32773]
-            map.insert(1369, &[65541]);     // 1369 => [65541]
-            map.insert(243, &[131077]);     // 243 => [131077]
-            map.insert(488, &[262149]);     // 488 => [262149]
-            map.insert(991, &[524293]);     // 991 => [524293]
-            map.insert(1969, &[1048581]);     // 1969 => [1048581]
-            map.insert(1314, &[2097157]);     // 1314 => [2097157]
-            map.insert(1599, &[4194309]);     // 1599 => [4194309]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(41, &[41]);     // 41 => [41]
-            map.insert(73, &[73]);     // 73 => [73]
-            map.insert(137, &[137]);     // 137 => [137]
-            map.insert(265, &[265]);     // 265 => [265]
-            map.insert(521, &[521]);     // 521 => [521]
-            map.insert(1033, &[1033]);     // 1033 => [1033]
-            map.insert(582, &[2057]);     // 582 => [2057]
-            map.insert(1174, &[4105]);     // 1174 => [4105]
-            map.insert(888, &[8201]);     // 888 => [8201]
-            map.insert(1770, &[16393]);     // 1770 => [16393]
-            map.insert(1920, &[32777]);     // 1920 => [32777]
-            map.insert(1365, &[65545]);     // 1365 => [65545]
-            map.insert(255, &[131081]);     // 255 => [131081]
-            map.insert(484, &[262153]);     // 484 => [262153]
-            map.insert(979, &[524297]);     // 979 => [524297]
-            map.insert(1981, &[1048585]);     // 1981 => [1048585]
-            map.insert(1326, &[2097161]);     // 1326 => [2097161]
-            map.insert(1587, &[4194313]);     // 1587 => [4194313]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(81, &[81]);     // 81 => [81]
-            map.insert(145, &[145]);     // 145 => [145]
-            map.insert(273, &[273]);     // 273 => [273]
-            map.insert(529, &[529]);     // 529 => [529]
-            map.insert(1041, &[1041]);     // 1041 => [1041]
-            map.insert(606, &[2065]);     // 606 => [2065]
-            map.insert(1166, &[4113]);     // 1166 => [4113]
-            map.insert(864, &[8209]);     // 864 => [8209]
-            map.insert(1778, &[16401]);     // 1778 => [16401]
-            map.insert(1944, &[32785]);     // 1944 => [32785]
-            map.insert(1357, &[65553]);     // 1357 => [65553]
-            map.insert(231, &[131089]);     // 231 => [131089]
-            map.insert(508, &[262161]);     // 508 => [262161]
-            map.insert(971, &[524305]);     // 971 => [524305]
-            map.insert(1957, &[1048593]);     // 1957 => [1048593]
-            map.insert(1334, &[2097169]);     // 1334 => [2097169]
-            map.insert(1579, &[4194321]);     // 1579 => [4194321]
-            map.insert(97, &[97]);     // 97 => [97]
-            map.insert(161, &[161]);     // 161 => [161]
-            map.insert(289, &[289]);     // 289 => [289]
-            map.insert(545, &[545]);     // 545 => [545]
-            map.insert(1057, &[1057]);     // 1057 => [1057]
-            map.insert(622, &[2081]);     // 622 => [2081]
-            map.insert(1214, &[4129]);     // 1214 => [4129]
-            map.insert(848, &[8225]);     // 848 => [8225]
-            map.insert(1730, &[16417]);     // 1730 => [16417]
-            map.insert(1960, &[32801]);     // 1960 => [32801]
-            map.insert(1405, &[65569]);     // 1405 => [65569]
-            map.insert(215, &[131105]);     // 215 => [131105]
-            map.insert(460, &[262177]);     // 460 => [262177]
-            map.insert(1019, &[524321]);     // 1019 => [524321]
-            map.insert(1941, &[1048609]);     // 1941 => [1048609]
-            map.insert(1286, &[2097185]);     // 1286 => [2097185]
-            map.insert(1563, &[4194337]);     // 1563 => [4194337]
-            map.insert(193, &[193]);     // 193 => [193]
-            map.insert(321, &[321]);     // 321 => [321]
-            map.insert(577, &[577]);     // 577 => [577]
-            map.insert(1089, &[1089]);     // 1089 => [1089]
-            map.insert(526, &[2113]);     // 526 => [2113]
-            map.insert(1246, &[4161]);     // 1246 => [4161]
-            map.insert(816, &[8257]);     // 816 => [8257]
-            map.insert(1698, &[16449]);     // 1698 => [16449]
-            map.insert(1992, &[32833]);     // 1992 => [32833]
-            map.insert(1309, &[65601]);     // 1309 => [65601]
-            map.insert(183, &[131137]);     // 183 => [131137]
-            map.insert(428, &[262209]);     // 428 => [262209]
-            map.insert(923, &[524353]);     // 923 => [524353]
-            map.insert(2037, &[1048641]);     // 2037 => [1048641]
-            map.insert(1382, &[2097217]);     // 1382 => [2097217]
-            map.insert(1659, &[4194369]);     // 1659 => [4194369]
-            map.insert(385, &[385]);     // 385 => [385]
-            map.insert(641, &[641]);     // 641 => [641]
-            map.insert(1153, &[1153]);     // 1153 => [1153]
-            map.insert(718, &[2177]);     // 718 => [2177]
-            map.insert(1054, &[4225]);     // 1054 => [4225]
-            map.insert(1008, &[8321]);     // 1008 => [8321]
-            map.insert(1634, &[16513]);     // 1634 => [16513]
-            map.insert(1800, &[32897]);     // 1800 => [32897]
-            map.insert(1501, &[65665]);     // 1501 => [65665]
-            map.insert(119, &[131201]);     // 119 => [131201]
-            map.insert(364, &[262273]);     // 364 => [262273]
-            map.insert(859, &[524417]);     // 859 => [524417]
-            map.insert(1845, &[1048705]);     // 1845 => [1048705]
-            map.insert(1446, &[2097281]);     // 1446 => [2097281]
-            map.insert(1723, &[4194433]);     // 1723 => [4194433]
-            map.insert(769, &[769]);     // 769 => [769]
-            map.insert(1281, &[1281]);     // 1281 => [1281]
-            map.insert(846, &[2305]);     // 846 => [2305]
-            map.insert(1438, &[4353]);     // 1438 => [4353]
-            map.insert(624, &[8449]);     // 624 => [8449]
-            map.insert(2018, &[16641]);     // 2018 => [16641]
-            map.insert(1672, &[33025]);     // 1672 => [33025]
-            map.insert(1117, &[65793]);     // 1117 => [65793]
-            map.insert(503, &[131329]);     // 503 => [131329]
-            map.insert(236, &[262401]);     // 236 => [262401]
-            map.insert(731, &[524545]);     // 731 => [524545]
-            map.insert(1717, &[1048833]);     // 1717 => [1048833]
-            map.insert(1062, &[2097409]);     // 1062 => [2097409]
-            map.insert(1851, &[4194561]);     // 1851 => [4194561]
-            map.insert(1537, &[1537]);     // 1537 => [1537]
-            map.insert(78, &[2561]);     // 78 => [2561]
-            map.insert(1694, &[4609]);     // 1694 => [4609]
-            map.insert(368, &[8705]);     // 368 => [8705]
-            map.insert(1250, &[16897]);     // 1250 => [16897]
-            map.insert(1416, &[33281]);     // 1416 => [33281]
-            map.insert(1885, &[66049]);     // 1885 => [66049]
-            map.insert(759, &[131585]);     // 759 => [131585]
-            map.insert(1004, &[262657]);     // 1004 => [262657]
-            map.insert(475, &[524801]);     // 475 => [524801]
-            map.insert(1461, &[1049089]);     // 1461 => [1049089]
-            map.insert(1830, &[2097665]);     // 1830 => [2097665]
-            map.insert(1083, &[4194817]);     // 1083 => [4194817]
-            map.insert(1614, &[3073]);     // 1614 => [3073]
-            map.insert(158, &[5121]);     // 158 => [5121]
-            map.insert(1904, &[9217]);     // 1904 => [9217]
-            map.insert(738, &[17409]);     // 738 => [17409]
-            map.insert(904, &[33793]);     // 904 => [33793]
-            map.insert(349, &[66561]);     // 349 => [66561]
-            map.insert(1271, &[132097]);     // 1271 => [132097]
-            map.insert(1516, &[263169]);     // 1516 => [263169]
-            map.insert(2011, &[525313]);     // 2011 => [525313]
-            map.insert(949, &[1049601]);     // 949 => [1049601]
-            map.insert(294, &[2098177]);     // 294 => [2098177]
-            map.insert(571, &[4195329]);     // 571 => [4195329]
-            map.insert(1745, &[6145]);     // 1745 => [6145]
-            map.insert(319, &[10241]);     // 319 => [10241]
-            map.insert(1197, &[18433]);     // 1197 => [18433]
-            map.insert(1479, &[34817]);     // 1479 => [34817]
-            map.insert(1810, &[67585]);     // 1810 => [67585]
-            map.insert(696, &[133121]);     // 696 => [133121]
-            map.insert(931, &[264193]);     // 931 => [264193]
-            map.insert(404, &[526337]);     // 404 => [526337]
-            map.insert(1530, &[1050625]);     // 1530 => [1050625]
-            map.insert(1897, &[2099201]);     // 1897 => [2099201]
-            map.insert(1140, &[4196353]);     // 1140 => [4196353]
-            map.insert(2031, &[12289]);     // 2031 => [12289]
-            map.insert(637, &[20481]);     // 637 => [20481]
-            map.insert(791, &[36865]);     // 791 => [36865]
-            map.insert(450, &[69633]);     // 450 => [69633]
-            map.insert(1128, &[135169]);     // 1128 => [135169]
-            map.insert(1395, &[266241]);     // 1395 => [266241]
-            map.insert(1860, &[528385]);     // 1860 => [528385]
-            map.insert(810, &[1052673]);     // 810 => [1052673]
-            map.insert(441, &[2101249]);     // 441 => [2101249]
-            map.insert(676, &[4198401]);     // 676 => [4198401]
-            map.insert(1427, &[24577]);     // 1427 => [24577]
-            map.insert(1273, &[40961]);     // 1273 => [40961]
-            map.insert(1580, &[73729]);     // 1580 => [73729]
-            map.insert(902, &[139265]);     // 902 => [139265]
-            map.insert(669, &[270337]);     // 669 => [270337]
-            map.insert(170, &[532481]);     // 170 => [532481]
-            map.insert(1220, &[1056769]);     // 1220 => [1056769]
-            map.insert(1623, &[2105345]);     // 1623 => [2105345]
-            map.insert(1354, &[4202497]);     // 1354 => [4202497]
-            map.insert(363, &[49153]);     // 363 => [49153]
-            map.insert(958, &[81921]);     // 958 => [81921]
-            map.insert(1556, &[147457]);     // 1556 => [147457]
-            map.insert(1807, &[278529]);     // 1807 => [278529]
-            map.insert(1336, &[540673]);     // 1336 => [540673]
-            map.insert(342, &[1064961]);     // 342 => [1064961]
-            map.insert(965, &[2113537]);     // 965 => [2113537]
-            map.insert(216, &[4210689]);     // 216 => [4210689]
-            map.insert(724, &[98305]);     // 724 => [98305]
-            map.insert(1918, &[163841]);     // 1918 => [163841]
-            map.insert(1637, &[294913]);     // 1637 => [294913]
-            map.insert(1106, &[557057]);     // 1106 => [557057]
-            map.insert(60, &[1081345]);     // 60 => [1081345]
-            map.insert(687, &[2129921]);     // 687 => [2129921]
-            map.insert(434, &[4227073]);     // 434 => [4227073]
-            map.insert(1451, &[196609]);     // 1451 => [196609]
-            map.insert(1200, &[327681]);     // 1200 => [327681]
-            map.insert(1671, &[589825]);     // 1671 => [589825]
-            map.insert(745, &[1114113]);     // 745 => [1114113]
-            map.insert(122, &[2162689]);     // 122 => [2162689]
-            map.insert(871, &[4259841]);     // 871 => [4259841]
-            map.insert(282, &[393217]);     // 282 => [393217]
-            map.insert(813, &[655361]);     // 813 => [655361]
-            map.insert(1859, &[1179649]);     // 1859 => [1179649]
-            map.insert(1488, &[2228225]);     // 1488 => [2228225]
-            map.insert(1741, &[4325377]);     // 1741 => [4325377]
-            map.insert(566, &[786433]);     // 566 => [786433]
-            map.insert(1624, &[1310721]);     // 1624 => [1310721]
-            map.insert(1227, &[2359297]);     // 1227 => [2359297]
-            map.insert(2006, &[4456449]);     // 2006 => [4456449]
-            map.insert(1135, &[1572865]);     // 1135 => [1572865]
-            map.insert(1788, &[2621441]);     // 1788 => [2621441]
-            map.insert(1505, &[4718593]);     // 1505 => [4718593]
-            map.insert(658, &[3145729]);     // 658 => [3145729]
-            map.insert(399, &[5242881]);     // 399 => [5242881]
-            map.insert(796, &[6291457]);     // 796 => [6291457]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(70, &[70]);     // 70 => [70]
-            map.insert(134, &[134]);     // 134 => [134]
-            map.insert(262, &[262]);     // 262 => [262]
-            map.insert(518, &[518]);     // 518 => [518]
-            map.insert(1030, &[1030]);     // 1030 => [1030]
-            map.insert(585, &[2054]);     // 585 => [2054]
-            map.insert(1177, &[4102]);     // 1177 => [4102]
-            map.insert(887, &[8198]);     // 887 => [8198]
-            map.insert(1765, &[16390]);     // 1765 => [16390]
-            map.insert(1935, &[32774]);     // 1935 => [32774]
-            map.insert(1370, &[65542]);     // 1370 => [65542]
-            map.insert(240, &[131078]);     // 240 => [131078]
-            map.insert(491, &[262150]);     // 491 => [262150]
-            map.insert(988, &[524294]);     // 988 => [524294]
-            map.insert(1970, &[1048582]);     // 1970 => [1048582]
-            map.insert(1313, &[2097158]);     // 1313 => [2097158]
-            map.insert(1596, &[4194310]);     // 1596 => [4194310]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(42, &[42]);     // 42 => [42]
-            map.insert(74, &[74]);     // 74 => [74]
-            map.insert(138, &[138]);     // 138 => [138]
-            map.insert(266, &[266]);     // 266 => [266]
-            map.insert(522, &[522]);     // 522 => [522]
-            map.insert(1034, &[1034]);     // 1034 => [1034]
-            map.insert(581, &[2058]);     // 581 => [2058]
-            map.insert(1173, &[4106]);     // 1173 => [4106]
-            map.insert(891, &[8202]);     // 891 => [8202]
-            map.insert(1769, &[16394]);     // 1769 => [16394]
-            map.insert(1923, &[32778]);     // 1923 => [32778]
-            map.insert(1366, &[65546]);     // 1366 => [65546]
-            map.insert(252, &[131082]);     // 252 => [131082]
-            map.insert(487, &[262154]);     // 487 => [262154]
-            map.insert(976, &[524298]);     // 976 => [524298]
-            map.insert(1982, &[1048586]);     // 1982 => [1048586]
-            map.insert(1325, &[2097162]);     // 1325 => [2097162]
-            map.insert(1584, &[4194314]);     // 1584 => [4194314]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(82, &[82]);     // 82 => [82]
-            map.insert(146, &[146]);     // 146 => [146]
-            map.insert(274, &[274]);     // 274 => [274]
-            map.insert(530, &[530]);     // 530 => [530]
-            map.insert(1042, &[1042]);     // 1042 => [1042]
-            map.insert(605, &[2066]);     // 605 => [2066]
-            map.insert(1165, &[4114]);     // 1165 => [4114]
-            map.insert(867, &[8210]);     // 867 => [8210]
-            map.insert(1777, &[16402]);     // 1777 => [16402]
-            map.insert(1947, &[32786]);     // 1947 => [32786]
-            map.insert(1358, &[65554]);     // 1358 => [65554]
-            map.insert(228, &[131090]);     // 228 => [131090]
-            map.insert(511, &[262162]);     // 511 => [262162]
-            map.insert(968, &[524306]);     // 968 => [524306]
-            map.insert(1958, &[1048594]);     // 1958 => [1048594]
-            map.insert(1333, &[2097170]);     // 1333 => [2097170]
-            map.insert(1576, &[4194322]);     // 1576 => [4194322]
-            map.insert(98, &[98]);     // 98 => [98]
-            map.insert(162, &[162]);     // 162 => [162]
-            map.insert(290, &[290]);     // 290 => [290]
-            map.insert(546, &[546]);     // 546 => [546]
-            map.insert(1058, &[1058]);     // 1058 => [1058]
-            map.insert(621, &[2082]);     // 621 => [2082]
-            map.insert(1213, &[4130]);     // 1213 => [4130]
-            map.insert(851, &[8226]);     // 851 => [8226]
-            map.insert(1729, &[16418]);     // 1729 => [16418]
-            map.insert(1963, &[32802]);     // 1963 => [32802]
-            map.insert(1406, &[65570]);     // 1406 => [65570]
-            map.insert(212, &[131106]);     // 212 => [131106]
-            map.insert(463, &[262178]);     // 463 => [262178]
-            map.insert(1016, &[524322]);     // 1016 => [524322]
-            map.insert(1942, &[1048610]);     // 1942 => [1048610]
-            map.insert(1285, &[2097186]);     // 1285 => [2097186]
-            map.insert(1560, &[4194338]);     // 1560 => [4194338]
-            map.insert(194, &[194]);     // 194 => [194]
-            map.insert(322, &[322]);     // 322 => [322]
-            map.insert(578, &[578]);     // 578 => [578]
-            map.insert(1090, &[1090]);     // 1090 => [1090]
-            map.insert(525, &[2114]);     // 525 => [2114]
-            map.insert(1245, &[4162]);     // 1245 => [4162]
-            map.insert(819, &[8258]);     // 819 => [8258]
-            map.insert(1697, &[16450]);     // 1697 => [16450]
-            map.insert(1995, &[32834]);     // 1995 => [32834]
-            map.insert(1310, &[65602]);     // 1310 => [65602]
-            map.insert(180, &[131138]);     // 180 => [131138]
-            map.insert(431, &[262210]);     // 431 => [262210]
-            map.insert(920, &[524354]);     // 920 => [524354]
-            map.insert(2038, &[1048642]);     // 2038 => [1048642]
-            map.insert(1381, &[2097218]);     // 1381 => [2097218]
-            map.insert(1656, &[4194370]);     // 1656 => [4194370]
-            map.insert(386, &[386]);     // 386 => [386]
-            map.insert(642, &[642]);     // 642 => [642]
-            map.insert(1154, &[1154]);     // 1154 => [1154]
-            map.insert(717, &[2178]);     // 717 => [2178]
-            map.insert(1053, &[4226]);     // 1053 => [4226]
-            map.insert(1011, &[8322]);     // 1011 => [8322]
-            map.insert(1633, &[16514]);     // 1633 => [16514]
-            map.insert(1803, &[32898]);     // 1803 => [32898]
-            map.insert(1502, &[65666]);     // 1502 => [65666]
-            map.insert(116, &[131202]);     // 116 => [131202]
-            map.insert(367, &[262274]);     // 367 => [262274]
-            map.insert(856, &[524418]);     // 856 => [524418]
-            map.insert(1846, &[1048706]);     // 1846 => [1048706]
-            map.insert(1445, &[2097282]);     // 1445 => [2097282]
-            map.insert(1720, &[4194434]);     // 1720 => [4194434]
-            map.insert(770, &[770]);     // 770 => [770]
-            map.insert(1282, &[1282]);     // 1282 => [1282]
-            map.insert(845, &[2306]);     // 845 => [2306]
-            map.insert(1437, &[4354]);     // 1437 => [4354]
-            map.insert(627, &[8450]);     // 627 => [8450]
-            map.insert(2017, &[16642]);     // 2017 => [16642]
-            map.insert(1675, &[33026]);     // 1675 => [33026]
-            map.insert(1118, &[65794]);     // 1118 => [65794]
-            map.insert(500, &[131330]);     // 500 => [131330]
-            map.insert(239, &[262402]);     // 239 => [262402]
-            map.insert(728, &[524546]);     // 728 => [524546]
-            map.insert(1718, &[1048834]);     // 1718 => [1048834]
-            map.insert(1061, &[2097410]);     // 1061 => [2097410]
-            map.insert(1848, &[4194562]);     // 1848 => [4194562]
-            map.insert(1538, &[1538]);     // 1538 => [1538]
-            map.insert(77, &[2562]);     // 77 => [2562]
-            map.insert(1693, &[4610]);     // 1693 => [4610]
-            map.insert(371, &[8706]);     // 371 => [8706]
-            map.insert(1249, &[16898]);     // 1249 => [16898]
-            map.insert(1419, &[33282]);     // 1419 => [33282]
-            map.insert(1886, &[66050]);     // 1886 => [66050]
-            map.insert(756, &[131586]);     // 756 => [131586]
-            map.insert(1007, &[262658]);     // 1007 => [262658]
-            map.insert(472, &[524802]);     // 472 => [524802]
-            map.insert(1462, &[1049090]);     // 1462 => [1049090]
-            map.insert(1829, &[2097666]);     // 1829 => [2097666]
-            map.insert(1080, &[4194818]);     // 1080 => [4194818]
-            map.insert(1613, &[3074]);     // 1613 => [3074]
-            map.insert(157, &[5122]);     // 157 => [5122]
-            map.insert(1907, &[9218]);     // 1907 => [9218]
-            map.insert(737, &[17410]);     // 737 => [17410]
-            map.insert(907, &[33794]);     // 907 => [33794]
-            map.insert(350, &[66562]);     // 350 => [66562]
-            map.insert(1268, &[132098]);     // 1268 => [132098]
-            map.insert(1519, &[263170]);     // 1519 => [263170]
-            map.insert(2008, &[525314]);     // 2008 => [525314]
-            map.insert(950, &[1049602]);     // 950 => [1049602]
-            map.insert(293, &[2098178]);     // 293 => [2098178]
-            map.insert(568, &[4195330]);     // 568 => [4195330]
-            map.insert(1746, &[6146]);     // 1746 => [6146]
-            map.insert(316, &[10242]);     // 316 => [10242]
-            map.insert(1198, &[18434]);     // 1198 => [18434]
-            map.insert(1476, &[34818]);     // 1476 => [34818]
-            map.insert(1809, &[67586]);     // 1809 => [67586]
-            map.insert(699, &[133122]);     // 699 => [133122]
-            map.insert(928, &[264194]);     // 928 => [264194]
-            map.insert(407, &[526338]);     // 407 => [526338]
-            map.insert(1529, &[1050626]);     // 1529 => [1050626]
-            map.insert(1898, &[2099202]);     // 1898 => [2099202]
-            map.insert(1143, &[4196354]);     // 1143 => [4196354]
-            map.insert(2028, &[12290]);     // 2028 => [12290]
-            map.insert(638, &[20482]);     // 638 => [20482]
-            map.insert(788, &[36866]);     // 788 => [36866]
-            map.insert(449, &[69634]);     // 449 => [69634]
-            map.insert(1131, &[135170]);     // 1131 => [135170]
-            map.insert(1392, &[266242]);     // 1392 => [266242]
-            map.insert(1863, &[528386]);     // 1863 => [528386]
-            map.insert(809, &[1052674]);     // 809 => [1052674]
-            map.insert(442, &[2101250]);     // 442 => [2101250]
-            map.insert(679, &[4198402]);     // 679 => [4198402]
-            map.insert(1424, &[24578]);     // 1424 => [24578]
-            map.insert(1274, &[40962]);     // 1274 => [40962]
-            map.insert(1583, &[73730]);     // 1583 => [73730]
-            map.insert(901, &[139266]);     // 901 => [139266]
-            map.insert(670, &[270338]);     // 670 => [270338]
-            map.insert(169, &[532482]);     // 169 => [532482]
-            map.insert(1223, &[1056770]);     // 1223 => [1056770]
-            map.insert(1620, &[2105346]);     // 1620 => [2105346]
-            map.insert(1353, &[4202498]);     // 1353 => [4202498]
-            map.insert(360, &[49154]);     // 360 => [49154]
-            map.insert(957, &[81922]);     // 957 => [81922]
-            map.insert(1559, &[147458]);     // 1559 => [147458]
-            map.insert(1804, &[278530]);     // 1804 => [278530]
-            map.insert(1339, &[540674]);     // 1339 => [540674]
-            map.insert(341, &[1064962]);     // 341 => [1064962]
-            map.insert(966, &[2113538]);     // 966 => [2113538]
-            map.insert(219, &[4210690]);     // 219 => [4210690]
-            map.insert(727, &[98306]);     // 727 => [98306]
-            map.insert(1917, &[163842]);     // 1917 => [163842]
-            map.insert(1638, &[294914]);     // 1638 => [294914]
-            map.insert(1105, &[557058]);     // 1105 => [557058]
-            map.insert(63, &[1081346]);     // 63 => [1081346]
-            map.insert(684, &[2129922]);     // 684 => [2129922]
-            map.insert(433, &[4227074]);     // 433 => [4227074]
-            map.insert(1448, &[196610]);     // 1448 => [196610]
-            map.insert(1203, &[327682]);     // 1203 => [327682]
-            map.insert(1668, &[589826]);     // 1668 => [589826]
-            map.insert(746, &[1114114]);     // 746 => [1114114]
-            map.insert(121, &[2162690]);     // 121 => [2162690]
-            map.insert(868, &[4259842]);     // 868 => [4259842]
-            map.insert(281, &[393218]);     // 281 => [393218]
-            map.insert(814, &[655362]);     // 814 => [655362]
-            map.insert(1856, &[1179650]);     // 1856 => [1179650]
-            map.insert(1491, &[2228226]);     // 1491 => [2228226]
-            map.insert(1742, &[4325378]);     // 1742 => [4325378]
-            map.insert(565, &[786434]);     // 565 => [786434]
-            map.insert(1627, &[1310722]);     // 1627 => [1310722]
-            map.insert(1224, &[2359298]);     // 1224 => [2359298]
-            map.insert(2005, &[4456450]);     // 2005 => [4456450]
-            map.insert(1132, &[1572866]);     // 1132 => [1572866]
-            map.insert(1791, &[2621442]);     // 1791 => [2621442]
-            map.insert(1506, &[4718594]);     // 1506 => [4718594]
-            map.insert(657, &[3145730]);     // 657 => [3145730]
-            map.insert(396, &[5242882]);     // 396 => [5242882]
-            map.insert(799, &[6291458]);     // 799 => [6291458]
-            map.insert(28, &[28]);     // 28 => [28]
-            map.insert(44, &[44]);     // 44 => [44]
-            map.insert(76, &[76]);     // 76 => [76]
-            map.insert(140, &[140]);     // 140 => [140]
-            map.insert(268, &[268]);     // 268 => [268]
-            map.insert(524, &[524]);     // 524 => [524]
-            map.insert(1036, &[1036]);     // 1036 => [1036]
-            map.insert(579, &[2060]);     // 579 => [2060]
-            map.insert(1171, &[4108]);     // 1171 => [4108]
-            map.insert(893, &[8204]);     // 893 => [8204]
-            map.insert(1775, &[16396]);     // 1775 => [16396]
-            map.insert(1925, &[32780]);     // 1925 => [32780]
-            map.insert(1360, &[65548]);     // 1360 => [65548]
-            map.insert(250, &[131084]);     // 250 => [131084]
-            map.insert(481, &[262156]);     // 481 => [262156]
-            map.insert(982, &[524300]);     // 982 => [524300]
-            map.insert(1976, &[1048588]);     // 1976 => [1048588]
-            map.insert(1323, &[2097164]);     // 1323 => [2097164]
-            map.insert(1590, &[4194316]);     // 1590 => [4194316]
-            map.insert(52, &[52]);     // 52 => [52]
-            map.insert(84, &[84]);     // 84 => [84]
-            map.insert(148, &[148]);     // 148 => [148]
-            map.insert(276, &[276]);     // 276 => [276]
-            map.insert(532, &[532]);     // 532 => [532]
-            map.insert(1044, &[1044]);     // 1044 => [1044]
-            map.insert(603, &[2068]);     // 603 => [2068]
-            map.insert(1163, &[4116]);     // 1163 => [4116]
-            map.insert(869, &[8212]);     // 869 => [8212]
-            map.insert(1783, &[16404]);     // 1783 => [16404]
-            map.insert(1949, &[32788]);     // 1949 => [32788]
-            map.insert(1352, &[65556]);     // 1352 => [65556]
-            map.insert(226, &[131092]);     // 226 => [131092]
-            map.insert(505, &[262164]);     // 505 => [262164]
-            map.insert(974, &[524308]);     // 974 => [524308]
-            map.insert(1952, &[1048596]);     // 1952 => [1048596]
-            map.insert(1331, &[2097172]);     // 1331 => [2097172]
-            map.insert(1582, &[4194324]);     // 1582 => [4194324]
-            map.insert(100, &[100]);     // 100 => [100]
-            map.insert(164, &[164]);     // 164 => [164]
-            map.insert(292, &[292]);     // 292 => [292]
-            map.insert(548, &[548]);     // 548 => [548]
-            map.insert(1060, &[1060]);     // 1060 => [1060]
-            map.insert(619, &[2084]);     // 619 => [2084]
-            map.insert(1211, &[4132]);     // 1211 => [4132]
-            map.insert(853, &[8228]);     // 853 => [8228]
-            map.insert(1735, &[16420]);     // 1735 => [16420]
-            map.insert(1965, &[32804]);     // 1965 => [32804]
-            map.insert(1400, &[65572]);     // 1400 => [65572]
-            map.insert(210, &[131108]);     // 210 => [131108]
-            map.insert(457, &[262180]);     // 457 => [262180]
-            map.insert(1022, &[524324]);     // 1022 => [524324]
-            map.insert(1936, &[1048612]);     // 1936 => [1048612]
-            map.insert(1283, &[2097188]);     // 1283 => [2097188]
-            map.insert(1566, &[4194340]);     // 1566 => [4194340]
-            map.insert(196, &[196]);     // 196 => [196]
-            map.insert(324, &[324]);     // 324 => [324]
-            map.insert(580, &[580]);     // 580 => [580]
-            map.insert(1092, &[1092]);     // 1092 => [1092]
-            map.insert(523, &[2116]);     // 523 => [2116]
-            map.insert(1243, &[4164]);     // 1243 => [4164]
-            map.insert(821, &[8260]);     // 821 => [8260]
-            map.insert(1703, &[16452]);     // 1703 => [16452]
-            map.insert(1997, &[32836]);     // 1997 => [32836]
-            map.insert(1304, &[65604]);     // 1304 => [65604]
-            map.insert(178, &[131140]);     // 178 => [131140]
-            map.insert(425, &[262212]);     // 425 => [262212]
-            map.insert(926, &[524356]);     // 926 => [524356]
-            map.insert(2032, &[1048644]);     // 2032 => [1048644]
-            map.insert(1379, &[2097220]);     // 1379 => [2097220]
-            map.insert(1662, &[4194372]);     // 1662 => [4194372]
-            map.insert(388, &[388]);     // 388 => [388]
-            map.insert(644, &[644]);     // 644 => [644]
-            map.insert(1156, &[1156]);     // 1156 => [1156]
-            map.insert(715, &[2180]);     // 715 => [2180]
-            map.insert(1051, &[4228]);     // 1051 => [4228]
-            map.insert(1013, &[8324]);     // 1013 => [8324]
-            map.insert(1639, &[16516]);     // 1639 => [16516]
-            map.insert(1805, &[32900]);     // 1805 => [32900]
-            map.insert(1496, &[65668]);     // 1496 => [65668]
-            map.insert(114, &[131204]);     // 114 => [131204]
-            map.insert(361, &[262276]);     // 361 => [262276]
-            map.insert(862, &[524420]);     // 862 => [524420]
-            map.insert(1840, &[1048708]);     // 1840 => [1048708]
-            map.insert(1443, &[2097284]);     // 1443 => [2097284]
-            map.insert(1726, &[4194436]);     // 1726 => [4194436]
-            map.insert(772, &[772]);     // 772 => [772]
-            map.insert(1284, &[1284]);     // 1284 => [1284]
-            map.insert(843, &[2308]);     // 843 => [2308]
-            map.insert(1435, &[4356]);     // 1435 => [4356]
-            map.insert(629, &[8452]);     // 629 => [8452]
-            map.insert(2023, &[16644]);     // 2023 => [16644]
-            map.insert(1677, &[33028]);     // 1677 => [33028]
-            map.insert(1112, &[65796]);     // 1112 => [65796]
-            map.insert(498, &[131332]);     // 498 => [131332]
-            map.insert(233, &[262404]);     // 233 => [262404]
-            map.insert(734, &[524548]);     // 734 => [524548]
-            map.insert(1712, &[1048836]);     // 1712 => [1048836]
-            map.insert(1059, &[2097412]);     // 1059 => [2097412]
-            map.insert(1854, &[4194564]);     // 1854 => [4194564]
-            map.insert(1540, &[1540]);     // 1540 => [1540]
-            map.insert(75, &[2564]);     // 75 => [2564]
-            map.insert(1691, &[4612]);     // 1691 => [4612]
-            map.insert(373, &[8708]);     // 373 => [8708]
-            map.insert(1255, &[16900]);     // 1255 => [16900]
-            map.insert(1421, &[33284]);     // 1421 => [33284]
-            map.insert(1880, &[66052]);     // 1880 => [66052]
-            map.insert(754, &[131588]);     // 754 => [131588]
-            map.insert(1001, &[262660]);     // 1001 => [262660]
-            map.insert(478, &[524804]);     // 478 => [524804]
-            map.insert(1456, &[1049092]);     // 1456 => [1049092]
-            map.insert(1827, &[2097668]);     // 1827 => [2097668]
-            map.insert(1086, &[4194820]);     // 1086 => [4194820]
-            map.insert(1611, &[3076]);     // 1611 => [3076]
-            map.insert(155, &[5124]);     // 155 => [5124]
-            map.insert(1909, &[9220]);     // 1909 => [9220]
-            map.insert(743, &[17412]);     // 743 => [17412]
-            map.insert(909, &[33796]);     // 909 => [33796]
-            map.insert(344, &[66564]);     // 344 => [66564]
-            map.insert(1266, &[132100]);     // 1266 => [132100]
-            map.insert(1513, &[263172]);     // 1513 => [263172]
-            map.insert(2014, &[525316]);     // 2014 => [525316]
-            map.insert(944, &[1049604]);     // 944 => [1049604]
-            map.insert(291, &[2098180]);     // 291 => [2098180]
-            map.insert(574, &[4195332]);     // 574 => [4195332]
-            map.insert(1748, &[6148]);     // 1748 => [6148]
-            map.insert(314, &[10244]);     // 314 => [10244]
-            map.insert(1192, &[18436]);     // 1192 => [18436]
-            map.insert(1474, &[34820]);     // 1474 => [34820]
-            map.insert(1815, &[67588]);     // 1815 => [67588]
-            map.insert(701, &[133124]);     // 701 => [133124]
-            map.insert(934, &[264196]);     // 934 => [264196]
-            map.insert(401, &[526340]);     // 401 => [526340]
-            map.insert(1535, &[1050628]);     // 1535 => [1050628]
-            map.insert(1900, &[2099204]);     // 1900 => [2099204]
-            map.insert(1137, &[4196356]);     // 1137 => [4196356]
-            map.insert(2026, &[12292]);     // 2026 => [12292]
-            map.insert(632, &[20484]);     // 632 => [20484]
-            map.insert(786, &[36868]);     // 786 => [36868]
-            map.insert(455, &[69636]);     // 455 => [69636]
-            map.insert(1133, &[135172]);     // 1133 => [135172]
-            map.insert(1398, &[266244]);     // 1398 => [266244]
-            map.insert(1857, &[528388]);     // 1857 => [528388]
-            map.insert(815, &[1052676]);     // 815 => [1052676]
-            map.insert(444, &[2101252]);     // 444 => [2101252]
-            map.insert(673, &[4198404]);     // 673 => [4198404]
-            map.insert(1430, &[24580]);     // 1430 => [24580]
-            map.insert(1276, &[40964]);     // 1276 => [40964]
-            map.insert(1577, &[73732]);     // 1577 => [73732]
-            map.insert(899, &[139268]);     // 899 => [139268]
-            map.insert(664, &[270340]);     // 664 => [270340]
-            map.insert(175, &[532484]);     // 175 => [532484]
-            map.insert(1217, &[1056772]);     // 1217 => [1056772]
-            map.insert(1618, &[2105348]);     // 1618 => [2105348]
-            map.insert(1359, &[4202500]);     // 1359 => [4202500]
-            map.insert(366, &[49156]);     // 366 => [49156]
-            map.insert(955, &[81924]);     // 955 => [81924]
-            map.insert(1553, &[147460]);     // 1553 => [147460]
-            map.insert(1802, &[278532]);     // 1802 => [278532]
-            map.insert(1341, &[540676]);     // 1341 => [540676]
-            map.insert(339, &[1064964]);     // 339 => [1064964]
-            map.insert(960, &[2113540]);     // 960 => [2113540]
-            map.insert(221, &[4210692]);     // 221 => [4210692]
-            map.insert(721, &[98308]);     // 721 => [98308]
-            map.insert(1915, &[163844]);     // 1915 => [163844]
-            map.insert(1632, &[294916]);     // 1632 => [294916]
-            map.insert(1111, &[557060]);     // 1111 => [557060]
-            map.insert(57, &[1081348]);     // 57 => [1081348]
-            map.insert(682, &[2129924]);     // 682 => [2129924]
-            map.insert(439, &[4227076]);     // 439 => [4227076]
-            map.insert(1454, &[196612]);     // 1454 => [196612]
-            map.insert(1205, &[327684]);     // 1205 => [327684]
-            map.insert(1666, &[589828]);     // 1666 => [589828]
-            map.insert(748, &[1114116]);     // 748 => [1114116]
-            map.insert(127, &[2162692]);     // 127 => [2162692]
-            map.insert(866, &[4259844]);     // 866 => [4259844]
-            map.insert(287, &[393220]);     // 287 => [393220]
-            map.insert(808, &[655364]);     // 808 => [655364]
-            map.insert(1862, &[1179652]);     // 1862 => [1179652]
-            map.insert(1493, &[2228228]);     // 1493 => [2228228]
-            map.insert(1736, &[4325380]);     // 1736 => [4325380]
-            map.insert(563, &[786436]);     // 563 => [786436]
-            map.insert(1629, &[1310724]);     // 1629 => [1310724]
-            map.insert(1230, &[2359300]);     // 1230 => [2359300]
-            map.insert(2003, &[4456452]);     // 2003 => [4456452]
-            map.insert(1130, &[1572868]);     // 1130 => [1572868]
-            map.insert(1785, &[2621444]);     // 1785 => [2621444]
-            map.insert(1508, &[4718596]);     // 1508 => [4718596]
-            map.insert(663, &[3145732]);     // 663 => [3145732]
-            map.insert(394, &[5242884]);     // 394 => [5242884]
-            map.insert(793, &[6291460]);     // 793 => [6291460]
-            map.insert(56, &[56]);     // 56 => [56]
-            map.insert(88, &[88]);     // 88 => [88]
-            map.insert(152, &[152]);     // 152 => [152]
-            map.insert(280, &[280]);     // 280 => [280]
-            map.insert(536, &[536]);     // 536 => [536]
-            map.insert(1048, &[1048]);     // 1048 => [1048]
-            map.insert(599, &[2072]);     // 599 => [2072]
-            map.insert(1159, &[4120]);     // 1159 => [4120]
-            map.insert(873, &[8216]);     // 873 => [8216]
-            map.insert(1787, &[16408]);     // 1787 => [16408]
-            map.insert(1937, &[32792]);     // 1937 => [32792]
-            map.insert(1348, &[65560]);     // 1348 => [65560]
-            map.insert(238, &[131096]);     // 238 => [131096]
-            map.insert(501, &[262168]);     // 501 => [262168]
-            map.insert(962, &[524312]);     // 962 => [524312]
-            map.insert(1964, &[1048600]);     // 1964 => [1048600]
-            map.insert(1343, &[2097176]);     // 1343 => [2097176]
-            map.insert(1570, &[4194328]);     // 1570 => [4194328]
-            map.insert(104, &[104]);     // 104 => [104]
-            map.insert(168, &[168]);     // 168 => [168]
-            map.insert(296, &[296]);     // 296 => [296]
-            map.insert(552, &[552]);     // 552 => [552]
-            map.insert(1064, &[1064]);     // 1064 => [1064]
-            map.insert(615, &[2088]);     // 615 => [2088]
-            map.insert(1207, &[4136]);     // 1207 => [4136]
-            map.insert(857, &[8232]);     // 857 => [8232]
-            map.insert(1739, &[16424]);     // 1739 => [16424]
-            map.insert(1953, &[32808]);     // 1953 => [32808]
-            map.insert(1396, &[65576]);     // 1396 => [65576]
-            map.insert(222, &[131112]);     // 222 => [131112]
-            map.insert(453, &[262184]);     // 453 => [262184]
-            map.insert(1010, &[524328]);     // 1010 => [524328]
-            map.insert(1948, &[1048616]);     // 1948 => [1048616]
-            map.insert(1295, &[2097192]);     // 1295 => [2097192]
-            map.insert(1554, &[4194344]);     // 1554 => [4194344]
-            map.insert(200, &[200]);     // 200 => [200]
-            map.insert(328, &[328]);     // 328 => [328]
-            map.insert(584, &[584]);     // 584 => [584]
-            map.insert(1096, &[1096]);     // 1096 => [1096]
-            map.insert(519, &[2120]);     // 519 => [2120]
-            map.insert(1239, &[4168]);     // 1239 => [4168]
-            map.insert(825, &[8264]);     // 825 => [8264]
-            map.insert(1707, &[16456]);     // 1707 => [16456]
-            map.insert(1985, &[32840]);     // 1985 => [32840]
-            map.insert(1300, &[65608]);     // 1300 => [65608]
-            map.insert(190, &[131144]);     // 190 => [131144]
-            map.insert(421, &[262216]);     // 421 => [262216]
-            map.insert(914, &[524360]);     // 914 => [524360]
-            map.insert(2044, &[1048648]);     // 2044 => [1048648]
-            map.insert(1391, &[2097224]);     // 1391 => [2097224]
-            map.insert(1650, &[4194376]);     // 1650 => [4194376]
-            map.insert(392, &[392]);     // 392 => [392]
-            map.insert(648, &[648]);     // 648 => [648]
-            map.insert(1160, &[1160]);     // 1160 => [1160]
-            map.insert(711, &[2184]);     // 711 => [2184]
-            map.insert(1047, &[4232]);     // 1047 => [4232]
-            map.insert(1017, &[8328]);     // 1017 => [8328]
-            map.insert(1643, &[16520]);     // 1643 => [16520]
-            map.insert(1793, &[32904]);     // 1793 => [32904]
-            map.insert(1492, &[65672]);     // 1492 => [65672]
-            map.insert(126, &[131208]);     // 126 => [131208]
-            map.insert(357, &[262280]);     // 357 => [262280]
-            map.insert(850, &[524424]);     // 850 => [524424]
-            map.insert(1852, &[1048712]);     // 1852 => [1048712]
-            map.insert(1455, &[2097288]);     // 1455 => [2097288]
-            map.insert(1714, &[4194440]);     // 1714 => [4194440]
-            map.insert(776, &[776]);     // 776 => [776]
-            map.insert(1288, &[1288]);     // 1288 => [1288]
-            map.insert(839, &[2312]);     // 839 => [2312]
-            map.insert(1431, &[4360]);     // 1431 => [4360]
-            map.insert(633, &[8456]);     // 633 => [8456]
-            map.insert(2027, &[16648]);     // 2027 => [16648]
-            map.insert(1665, &[33032]);     // 1665 => [33032]
-            map.insert(1108, &[65800]);     // 1108 => [65800]
-            map.insert(510, &[131336]);     // 510 => [131336]
-            map.insert(229, &[262408]);     // 229 => [262408]
-            map.insert(722, &[524552]);     // 722 => [524552]
-            map.insert(1724, &[1048840]);     // 1724 => [1048840]
-            map.insert(1071, &[2097416]);     // 1071 => [2097416]
-            map.insert(1842, &[4194568]);     // 1842 => [4194568]
-            map.insert(1544, &[1544]);     // 1544 => [1544]
-            map.insert(71, &[2568]);     // 71 => [2568]
-            map.insert(1687, &[4616]);     // 1687 => [4616]
-            map.insert(377, &[8712]);     // 377 => [8712]
-            map.insert(1259, &[16904]);     // 1259 => [16904]
-            map.insert(1409, &[33288]);     // 1409 => [33288]
-            map.insert(1876, &[66056]);     // 1876 => [66056]
-            map.insert(766, &[131592]);     // 766 => [131592]
-            map.insert(997, &[262664]);     // 997 => [262664]
-            map.insert(466, &[524808]);     // 466 => [524808]
-            map.insert(1468, &[1049096]);     // 1468 => [1049096]
-            map.insert(1839, &[2097672]);     // 1839 => [2097672]
-            map.insert(1074, &[4194824]);     // 1074 => [4194824]
-            map.insert(1607, &[3080]);     // 1607 => [3080]
-            map.insert(151, &[5128]);     // 151 => [5128]
-            map.insert(1913, &[9224]);     // 1913 => [9224]
-            map.insert(747, &[17416]);     // 747 => [17416]
-            map.insert(897, &[33800]);     // 897 => [33800]
-            map.insert(340, &[66568]);     // 340 => [66568]
-            map.insert(1278, &[132104]);     // 1278 => [132104]
-            map.insert(1509, &[263176]);     // 1509 => [263176]
-            map.insert(2002, &[525320]);     // 2002 => [525320]
-            map.insert(956, &[1049608]);     // 956 => [1049608]
-            map.insert(303, &[2098184]);     // 303 => [2098184]
-            map.insert(562, &[4195336]);     // 562 => [4195336]
-            map.insert(1752, &[6152]);     // 1752 => [6152]
-            map.insert(310, &[10248]);     // 310 => [10248]
-            map.insert(1188, &[18440]);     // 1188 => [18440]
-            map.insert(1486, &[34824]);     // 1486 => [34824]
-            map.insert(1819, &[67592]);     // 1819 => [67592]
-            map.insert(689, &[133128]);     // 689 => [133128]
-            map.insert(938, &[264200]);     // 938 => [264200]
-            map.insert(413, &[526344]);     // 413 => [526344]
-            map.insert(1523, &[1050632]);     // 1523 => [1050632]
-            map.insert(1888, &[2099208]);     // 1888 => [2099208]
-            map.insert(1149, &[4196360]);     // 1149 => [4196360]
-            map.insert(2022, &[12296]);     // 2022 => [12296]
-            map.insert(628, &[20488]);     // 628 => [20488]
-            map.insert(798, &[36872]);     // 798 => [36872]
-            map.insert(459, &[69640]);     // 459 => [69640]
-            map.insert(1121, &[135176]);     // 1121 => [135176]
-            map.insert(1402, &[266248]);     // 1402 => [266248]
-            map.insert(1869, &[528392]);     // 1869 => [528392]
-            map.insert(803, &[1052680]);     // 803 => [1052680]
-            map.insert(432, &[2101256]);     // 432 => [2101256]
-            map.insert(685, &[4198408]);     // 685 => [4198408]
-            map.insert(1434, &[24584]);     // 1434 => [24584]
-            map.insert(1264, &[40968]);     // 1264 => [40968]
-            map.insert(1573, &[73736]);     // 1573 => [73736]
-            map.insert(911, &[139272]);     // 911 => [139272]
-            map.insert(660, &[270344]);     // 660 => [270344]
-            map.insert(163, &[532488]);     // 163 => [532488]
-            map.insert(1229, &[1056776]);     // 1229 => [1056776]
-            map.insert(1630, &[2105352]);     // 1630 => [2105352]
-            map.insert(1347, &[4202504]);     // 1347 => [4202504]
-            map.insert(354, &[49160]);     // 354 => [49160]
-            map.insert(951, &[81928]);     // 951 => [81928]
-            map.insert(1565, &[147464]);     // 1565 => [147464]
-            map.insert(1798, &[278536]);     // 1798 => [278536]
-            map.insert(1329, &[540680]);     // 1329 => [540680]
-            map.insert(351, &[1064968]);     // 351 => [1064968]
-            map.insert(972, &[2113544]);     // 972 => [2113544]
-            map.insert(209, &[4210696]);     // 209 => [4210696]
-            map.insert(733, &[98312]);     // 733 => [98312]
-            map.insert(1911, &[163848]);     // 1911 => [163848]
-            map.insert(1644, &[294920]);     // 1644 => [294920]
-            map.insert(1115, &[557064]);     // 1115 => [557064]
-            map.insert(53, &[1081352]);     // 53 => [1081352]
-            map.insert(678, &[2129928]);     // 678 => [2129928]
-            map.insert(443, &[4227080]);     // 443 => [4227080]
-            map.insert(1442, &[196616]);     // 1442 => [196616]
-            map.insert(1209, &[327688]);     // 1209 => [327688]
-            map.insert(1678, &[589832]);     // 1678 => [589832]
-            map.insert(736, &[1114120]);     // 736 => [1114120]
-            map.insert(115, &[2162696]);     // 115 => [2162696]
-            map.insert(878, &[4259848]);     // 878 => [4259848]
-            map.insert(275, &[393224]);     // 275 => [393224]
-            map.insert(804, &[655368]);     // 804 => [655368]
-            map.insert(1866, &[1179656]);     // 1866 => [1179656]
-            map.insert(1497, &[2228232]);     // 1497 => [2228232]
-            map.insert(1732, &[4325384]);     // 1732 => [4325384]
-            map.insert(575, &[786440]);     // 575 => [786440]
-            map.insert(1617, &[1310728]);     // 1617 => [1310728]
-            map.insert(1218, &[2359304]);     // 1218 => [2359304]
-            map.insert(2015, &[4456456]);     // 2015 => [4456456]
-            map.insert(1126, &[1572872]);     // 1126 => [1572872]
-            map.insert(1781, &[2621448]);     // 1781 => [2621448]
-            map.insert(1512, &[4718600]);     // 1512 => [4718600]
-            map.insert(667, &[3145736]);     // 667 => [3145736]
-            map.insert(390, &[5242888]);     // 390 => [5242888]
-            map.insert(789, &[6291464]);     // 789 => [6291464]
-            map.insert(112, &[112]);     // 112 => [112]
-            map.insert(176, &[176]);     // 176 => [176]
-            map.insert(304, &[304]);     // 304 => [304]
-            map.insert(560, &[560]);     // 560 => [560]
-            map.insert(1072, &[1072]);     // 1072 => [1072]
-            map.insert(639, &[2096]);     // 639 => [2096]
-            map.insert(1199, &[4144]);     // 1199 => [4144]
-            map.insert(833, &[8240]);     // 833 => [8240]
-            map.insert(1747, &[16432]);     // 1747 => [16432]
-            map.insert(1977, &[32816]);     // 1977 => [32816]
-            map.insert(1388, &[65584]);     // 1388 => [65584]
-            map.insert(198, &[131120]);     // 198 => [131120]
-            map.insert(477, &[262192]);     // 477 => [262192]
-            map.insert(1002, &[524336]);     // 1002 => [524336]
-            map.insert(1924, &[1048624]);     // 1924 => [1048624]
-            map.insert(1303, &[2097200]);     // 1303 => [2097200]
-            map.insert(1546, &[4194352]);     // 1546 => [4194352]
-            map.insert(208, &[208]);     // 208 => [208]
-            map.insert(336, &[336]);     // 336 => [336]
-            map.insert(592, &[592]);     // 592 => [592]
-            map.insert(1104, &[1104]);     // 1104 => [1104]
-            map.insert(543, &[2128]);     // 543 => [2128]
-            map.insert(1231, &[4176]);     // 1231 => [4176]
-            map.insert(801, &[8272]);     // 801 => [8272]
-            map.insert(1715, &[16464]);     // 1715 => [16464]
-            map.insert(2009, &[32848]);     // 2009 => [32848]
-            map.insert(1292, &[65616]);     // 1292 => [65616]
-            map.insert(166, &[131152]);     // 166 => [131152]
-            map.insert(445, &[262224]);     // 445 => [262224]
-            map.insert(906, &[524368]);     // 906 => [524368]
-            map.insert(2020, &[1048656]);     // 2020 => [1048656]
-            map.insert(1399, &[2097232]);     // 1399 => [2097232]
-            map.insert(1642, &[4194384]);     // 1642 => [4194384]
-            map.insert(400, &[400]);     // 400 => [400]
-            map.insert(656, &[656]);     // 656 => [656]
-            map.insert(1168, &[1168]);     // 1168 => [1168]
-            map.insert(735, &[2192]);     // 735 => [2192]
-            map.insert(1039, &[4240]);     // 1039 => [4240]
-            map.insert(993, &[8336]);     // 993 => [8336]
-            map.insert(1651, &[16528]);     // 1651 => [16528]
-            map.insert(1817, &[32912]);     // 1817 => [32912]
-            map.insert(1484, &[65680]);     // 1484 => [65680]
-            map.insert(102, &[131216]);     // 102 => [131216]
-            map.insert(381, &[262288]);     // 381 => [262288]
-            map.insert(842, &[524432]);     // 842 => [524432]
-            map.insert(1828, &[1048720]);     // 1828 => [1048720]
-            map.insert(1463, &[2097296]);     // 1463 => [2097296]
-            map.insert(1706, &[4194448]);     // 1706 => [4194448]
-            map.insert(784, &[784]);     // 784 => [784]
-            map.insert(1296, &[1296]);     // 1296 => [1296]
-            map.insert(863, &[2320]);     // 863 => [2320]
-            map.insert(1423, &[4368]);     // 1423 => [4368]
-            map.insert(609, &[8464]);     // 609 => [8464]
-            map.insert(2035, &[16656]);     // 2035 => [16656]
-            map.insert(1689, &[33040]);     // 1689 => [33040]
-            map.insert(1100, &[65808]);     // 1100 => [65808]
-            map.insert(486, &[131344]);     // 486 => [131344]
-            map.insert(253, &[262416]);     // 253 => [262416]
-            map.insert(714, &[524560]);     // 714 => [524560]
-            map.insert(1700, &[1048848]);     // 1700 => [1048848]
-            map.insert(1079, &[2097424]);     // 1079 => [2097424]
-            map.insert(1834, &[4194576]);     // 1834 => [4194576]
-            map.insert(1552, &[1552]);     // 1552 => [1552]
-            map.insert(95, &[2576]);     // 95 => [2576]
-            map.insert(1679, &[4624]);     // 1679 => [4624]
-            map.insert(353, &[8720]);     // 353 => [8720]
-            map.insert(1267, &[16912]);     // 1267 => [16912]
-            map.insert(1433, &[33296]);     // 1433 => [33296]
-            map.insert(1868, &[66064]);     // 1868 => [66064]
-            map.insert(742, &[131600]);     // 742 => [131600]
-            map.insert(1021, &[262672]);     // 1021 => [262672]
-            map.insert(458, &[524816]);     // 458 => [524816]
-            map.insert(1444, &[1049104]);     // 1444 => [1049104]
-            map.insert(1847, &[2097680]);     // 1847 => [2097680]
-            map.insert(1066, &[4194832]);     // 1066 => [4194832]
-            map.insert(1631, &[3088]);     // 1631 => [3088]
-            map.insert(143, &[5136]);     // 143 => [5136]
-            map.insert(1889, &[9232]);     // 1889 => [9232]
-            map.insert(755, &[17424]);     // 755 => [17424]
-            map.insert(921, &[33808]);     // 921 => [33808]
-            map.insert(332, &[66576]);     // 332 => [66576]
-            map.insert(1254, &[132112]);     // 1254 => [132112]
-            map.insert(1533, &[263184]);     // 1533 => [263184]
-            map.insert(1994, &[525328]);     // 1994 => [525328]
-            map.insert(932, &[1049616]);     // 932 => [1049616]
-            map.insert(311, &[2098192]);     // 311 => [2098192]
-            map.insert(554, &[4195344]);     // 554 => [4195344]
-            map.insert(1728, &[6160]);     // 1728 => [6160]
-            map.insert(302, &[10256]);     // 302 => [10256]
-            map.insert(1212, &[18448]);     // 1212 => [18448]
-            map.insert(1494, &[34832]);     // 1494 => [34832]
-            map.insert(1795, &[67600]);     // 1795 => [67600]
-            map.insert(681, &[133136]);     // 681 => [133136]
-            map.insert(946, &[264208]);     // 946 => [264208]
-            map.insert(389, &[526352]);     // 389 => [526352]
-            map.insert(1515, &[1050640]);     // 1515 => [1050640]
-            map.insert(1912, &[2099216]);     // 1912 => [2099216]
-            map.insert(1125, &[4196368]);     // 1125 => [4196368]
-            map.insert(2046, &[12304]);     // 2046 => [12304]
-            map.insert(620, &[20496]);     // 620 => [20496]
-            map.insert(774, &[36880]);     // 774 => [36880]
-            map.insert(467, &[69648]);     // 467 => [69648]
-            map.insert(1145, &[135184]);     // 1145 => [135184]
-            map.insert(1378, &[266256]);     // 1378 => [266256]
-            map.insert(1877, &[528400]);     // 1877 => [528400]
-            map.insert(827, &[1052688]);     // 827 => [1052688]
-            map.insert(424, &[2101264]);     // 424 => [2101264]
-            map.insert(693, &[4198416]);     // 693 => [4198416]
-            map.insert(1410, &[24592]);     // 1410 => [24592]
-            map.insert(1256, &[40976]);     // 1256 => [40976]
-            map.insert(1597, &[73744]);     // 1597 => [73744]
-            map.insert(919, &[139280]);     // 919 => [139280]
-            map.insert(652, &[270352]);     // 652 => [270352]
-            map.insert(187, &[532496]);     // 187 => [532496]
-            map.insert(1237, &[1056784]);     // 1237 => [1056784]
-            map.insert(1606, &[2105360]);     // 1606 => [2105360]
-            map.insert(1371, &[4202512]);     // 1371 => [4202512]
-            map.insert(378, &[49168]);     // 378 => [49168]
-            map.insert(943, &[81936]);     // 943 => [81936]
-            map.insert(1541, &[147472]);     // 1541 => [147472]
-            map.insert(1822, &[278544]);     // 1822 => [278544]
-            map.insert(1321, &[540688]);     // 1321 => [540688]
-            map.insert(327, &[1064976]);     // 327 => [1064976]
-            map.insert(980, &[2113552]);     // 980 => [2113552]
-            map.insert(201, &[4210704]);     // 201 => [4210704]
-            map.insert(709, &[98320]);     // 709 => [98320]
-            map.insert(1903, &[163856]);     // 1903 => [163856]
-            map.insert(1652, &[294928]);     // 1652 => [294928]
-            map.insert(1091, &[557072]);     // 1091 => [557072]
-            map.insert(45, &[1081360]);     // 45 => [1081360]
-            map.insert(702, &[2129936]);     // 702 => [2129936]
-            map.insert(419, &[4227088]);     // 419 => [4227088]
-            map.insert(1466, &[196624]);     // 1466 => [196624]
-            map.insert(1185, &[327696]);     // 1185 => [327696]
-            map.insert(1686, &[589840]);     // 1686 => [589840]
-            map.insert(760, &[1114128]);     // 760 => [1114128]
-            map.insert(107, &[2162704]);     // 107 => [2162704]
-            map.insert(886, &[4259856]);     // 886 => [4259856]
-            map.insert(267, &[393232]);     // 267 => [393232]
-            map.insert(828, &[655376]);     // 828 => [655376]
-            map.insert(1874, &[1179664]);     // 1874 => [1179664]
-            map.insert(1473, &[2228240]);     // 1473 => [2228240]
-            map.insert(1756, &[4325392]);     // 1756 => [4325392]
-            map.insert(551, &[786448]);     // 551 => [786448]
-            map.insert(1609, &[1310736]);     // 1609 => [1310736]
-            map.insert(1242, &[2359312]);     // 1242 => [2359312]
-            map.insert(1991, &[4456464]);     // 1991 => [4456464]
-            map.insert(1150, &[1572880]);     // 1150 => [1572880]
-            map.insert(1773, &[2621456]);     // 1773 => [2621456]
-            map.insert(1520, &[4718608]);     // 1520 => [4718608]
-            map.insert(643, &[3145744]);     // 643 => [3145744]
-            map.insert(414, &[5242896]);     // 414 => [5242896]
-            map.insert(781, &[6291472]);     // 781 => [6291472]
-            map.insert(224, &[224]);     // 224 => [224]
-            map.insert(352, &[352]);     // 352 => [352]
-            map.insert(608, &[608]);     // 608 => [608]
-            map.insert(1120, &[1120]);     // 1120 => [1120]
-            map.insert(559, &[2144]);     // 559 => [2144]
-            map.insert(1279, &[4192]);     // 1279 => [4192]
-            map.insert(785, &[8288]);     // 785 => [8288]
-            map.insert(1667, &[16480]);     // 1667 => [16480]
-            map.insert(2025, &[32864]);     // 2025 => [32864]
-            map.insert(1340, &[65632]);     // 1340 => [65632]
-            map.insert(150, &[131168]);     // 150 => [131168]
-            map.insert(397, &[262240]);     // 397 => [262240]
-            map.insert(954, &[524384]);     // 954 => [524384]
-            map.insert(2004, &[1048672]);     // 2004 => [1048672]
-            map.insert(1351, &[2097248]);     // 1351 => [2097248]
-            map.insert(1626, &[4194400]);     // 1626 => [4194400]
-            map.insert(416, &[416]);     // 416 => [416]
-            map.insert(672, &[672]);     // 672 => [672]
-            map.insert(1184, &[1184]);     // 1184 => [1184]
-            map.insert(751, &[2208]);     // 751 => [2208]
-            map.insert(1087, &[4256]);     // 1087 => [4256]
-            map.insert(977, &[8352]);     // 977 => [8352]
-            map.insert(1603, &[16544]);     // 1603 => [16544]
-            map.insert(1833, &[32928]);     // 1833 => [32928]
-            map.insert(1532, &[65696]);     // 1532 => [65696]
-            map.insert(86, &[131232]);     // 86 => [131232]
-            map.insert(333, &[262304]);     // 333 => [262304]
-            map.insert(890, &[524448]);     // 890 => [524448]
-            map.insert(1812, &[1048736]);     // 1812 => [1048736]
-            map.insert(1415, &[2097312]);     // 1415 => [2097312]
-            map.insert(1690, &[4194464]);     // 1690 => [4194464]
-            map.insert(800, &[800]);     // 800 => [800]
-            map.insert(1312, &[1312]);     // 1312 => [1312]
-            map.insert(879, &[2336]);     // 879 => [2336]
-            map.insert(1471, &[4384]);     // 1471 => [4384]
-            map.insert(593, &[8480]);     // 593 => [8480]
-            map.insert(1987, &[16672]);     // 1987 => [16672]
-            map.insert(1705, &[33056]);     // 1705 => [33056]
-            map.insert(1148, &[65824]);     // 1148 => [65824]
-            map.insert(470, &[131360]);     // 470 => [131360]
-            map.insert(205, &[262432]);     // 205 => [262432]
-            map.insert(762, &[524576]);     // 762 => [524576]
-            map.insert(1684, &[1048864]);     // 1684 => [1048864]
-            map.insert(1031, &[2097440]);     // 1031 => [2097440]
-            map.insert(1818, &[4194592]);     // 1818 => [4194592]
-            map.insert(1568, &[1568]);     // 1568 => [1568]
-            map.insert(111, &[2592]);     // 111 => [2592]
-            map.insert(1727, &[4640]);     // 1727 => [4640]
-            map.insert(337, &[8736]);     // 337 => [8736]
-            map.insert(1219, &[16928]);     // 1219 => [16928]
-            map.insert(1449, &[33312]);     // 1449 => [33312]
-            map.insert(1916, &[66080]);     // 1916 => [66080]
-            map.insert(726, &[131616]);     // 726 => [131616]
-            map.insert(973, &[262688]);     // 973 => [262688]
-            map.insert(506, &[524832]);     // 506 => [524832]
-            map.insert(1428, &[1049120]);     // 1428 => [1049120]
-            map.insert(1799, &[2097696]);     // 1799 => [2097696]
-            map.insert(1050, &[4194848]);     // 1050 => [4194848]
-            map.insert(1647, &[3104]);     // 1647 => [3104]
-            map.insert(191, &[5152]);     // 191 => [5152]
-            map.insert(1873, &[9248]);     // 1873 => [9248]
-            map.insert(707, &[17440]);     // 707 => [17440]
-            map.insert(937, &[33824]);     // 937 => [33824]
-            map.insert(380, &[66592]);     // 380 => [66592]
-            map.insert(1238, &[132128]);     // 1238 => [132128]
-            map.insert(1485, &[263200]);     // 1485 => [263200]
-            map.insert(2042, &[525344]);     // 2042 => [525344]
-            map.insert(916, &[1049632]);     // 916 => [1049632]
-            map.insert(263, &[2098208]);     // 263 => [2098208]
-            map.insert(538, &[4195360]);     // 538 => [4195360]
-            map.insert(1776, &[6176]);     // 1776 => [6176]
-            map.insert(286, &[10272]);     // 286 => [10272]
-            map.insert(1164, &[18464]);     // 1164 => [18464]
-            map.insert(1510, &[34848]);     // 1510 => [34848]
-            map.insert(1843, &[67616]);     // 1843 => [67616]
-            map.insert(665, &[133152]);     // 665 => [133152]
-            map.insert(898, &[264224]);     // 898 => [264224]
-            map.insert(437, &[526368]);     // 437 => [526368]
-            map.insert(1499, &[1050656]);     // 1499 => [1050656]
-            map.insert(1864, &[2099232]);     // 1864 => [2099232]
-            map.insert(1109, &[4196384]);     // 1109 => [4196384]
-            map.insert(1998, &[12320]);     // 1998 => [12320]
-            map.insert(604, &[20512]);     // 604 => [20512]
-            map.insert(822, &[36896]);     // 822 => [36896]
-            map.insert(483, &[69664]);     // 483 => [69664]
-            map.insert(1097, &[135200]);     // 1097 => [135200]
-            map.insert(1362, &[266272]);     // 1362 => [266272]
-            map.insert(1893, &[528416]);     // 1893 => [528416]
-            map.insert(779, &[1052704]);     // 779 => [1052704]
-            map.insert(408, &[2101280]);     // 408 => [2101280]
-            map.insert(645, &[4198432]);     // 645 => [4198432]
-            map.insert(1458, &[24608]);     // 1458 => [24608]
-            map.insert(1240, &[40992]);     // 1240 => [40992]
-            map.insert(1549, &[73760]);     // 1549 => [73760]
-            map.insert(935, &[139296]);     // 935 => [139296]
-            map.insert(700, &[270368]);     // 700 => [270368]
-            map.insert(139, &[532512]);     // 139 => [532512]
-            map.insert(1253, &[1056800]);     // 1253 => [1056800]
-            map.insert(1654, &[2105376]);     // 1654 => [2105376]
-            map.insert(1387, &[4202528]);     // 1387 => [4202528]
-            map.insert(330, &[49184]);     // 330 => [49184]
-            map.insert(927, &[81952]);     // 927 => [81952]
-            map.insert(1589, &[147488]);     // 1589 => [147488]
-            map.insert(1838, &[278560]);     // 1838 => [278560]
-            map.insert(1305, &[540704]);     // 1305 => [540704]
-            map.insert(375, &[1064992]);     // 375 => [1064992]
-            map.insert(996, &[2113568]);     // 996 => [2113568]
-            map.insert(249, &[4210720]);     // 249 => [4210720]
-            map.insert(757, &[98336]);     // 757 => [98336]
-            map.insert(1887, &[163872]);     // 1887 => [163872]
-            map.insert(1604, &[294944]);     // 1604 => [294944]
-            map.insert(1139, &[557088]);     // 1139 => [557088]
-            map.insert(29, &[1081376]);     // 29 => [1081376]
-            map.insert(654, &[2129952]);     // 654 => [2129952]
-            map.insert(403, &[4227104]);     // 403 => [4227104]
-            map.insert(1418, &[196640]);     // 1418 => [196640]
-            map.insert(1169, &[327712]);     // 1169 => [327712]
-            map.insert(1702, &[589856]);     // 1702 => [589856]
-            map.insert(712, &[1114144]);     // 712 => [1114144]
-            map.insert(91, &[2162720]);     // 91 => [2162720]
-            map.insert(838, &[4259872]);     // 838 => [4259872]
-            map.insert(315, &[393248]);     // 315 => [393248]
-            map.insert(780, &[655392]);     // 780 => [655392]
-            map.insert(1890, &[1179680]);     // 1890 => [1179680]
-            map.insert(1521, &[2228256]);     // 1521 => [2228256]
-            map.insert(1772, &[4325408]);     // 1772 => [4325408]
-            map.insert(535, &[786464]);     // 535 => [786464]
-            map.insert(1657, &[1310752]);     // 1657 => [1310752]
-            map.insert(1258, &[2359328]);     // 1258 => [2359328]
-            map.insert(2039, &[4456480]);     // 2039 => [4456480]
-            map.insert(1102, &[1572896]);     // 1102 => [1572896]
-            map.insert(1757, &[2621472]);     // 1757 => [2621472]
-            map.insert(1472, &[4718624]);     // 1472 => [4718624]
-            map.insert(691, &[3145760]);     // 691 => [3145760]
-            map.insert(430, &[5242912]);     // 430 => [5242912]
-            map.insert(829, &[6291488]);     // 829 => [6291488]
-            map.insert(448, &[448]);     // 448 => [448]
-            map.insert(704, &[704]);     // 704 => [704]
-            map.insert(1216, &[1216]);     // 1216 => [1216]
-            map.insert(655, &[2240]);     // 655 => [2240]
-            map.insert(1119, &[4288]);     // 1119 => [4288]
-            map.insert(945, &[8384]);     // 945 => [8384]
-            map.insert(1571, &[16576]);     // 1571 => [16576]
-            map.insert(1865, &[32960]);     // 1865 => [32960]
-            map.insert(1436, &[65728]);     // 1436 => [65728]
-            map.insert(54, &[131264]);     // 54 => [131264]
-            map.insert(301, &[262336]);     // 301 => [262336]
-            map.insert(794, &[524480]);     // 794 => [524480]
-            map.insert(1908, &[1048768]);     // 1908 => [1048768]
-            map.insert(1511, &[2097344]);     // 1511 => [2097344]
-            map.insert(1786, &[4194496]);     // 1786 => [4194496]
-            map.insert(832, &[832]);     // 832 => [832]
-            map.insert(1344, &[1344]);     // 1344 => [1344]
-            map.insert(783, &[2368]);     // 783 => [2368]
-            map.insert(1503, &[4416]);     // 1503 => [4416]
-            map.insert(561, &[8512]);     // 561 => [8512]
-            map.insert(1955, &[16704]);     // 1955 => [16704]
-            map.insert(1737, &[33088]);     // 1737 => [33088]
-            map.insert(1052, &[65856]);     // 1052 => [65856]
-            map.insert(438, &[131392]);     // 438 => [131392]
-            map.insert(173, &[262464]);     // 173 => [262464]
-            map.insert(666, &[524608]);     // 666 => [524608]
-            map.insert(1780, &[1048896]);     // 1780 => [1048896]
-            map.insert(1127, &[2097472]);     // 1127 => [2097472]
-            map.insert(1914, &[4194624]);     // 1914 => [4194624]
-            map.insert(1600, &[1600]);     // 1600 => [1600]
-            map.insert(15, &[2624]);     // 15 => [2624]
-            map.insert(1759, &[4672]);     // 1759 => [4672]
-            map.insert(305, &[8768]);     // 305 => [8768]
-            map.insert(1187, &[16960]);     // 1187 => [16960]
-            map.insert(1481, &[33344]);     // 1481 => [33344]
-            map.insert(1820, &[66112]);     // 1820 => [66112]
-            map.insert(694, &[131648]);     // 694 => [131648]
-            map.insert(941, &[262720]);     // 941 => [262720]
-            map.insert(410, &[524864]);     // 410 => [524864]
-            map.insert(1524, &[1049152]);     // 1524 => [1049152]
-            map.insert(1895, &[2097728]);     // 1895 => [2097728]
-            map.insert(1146, &[4194880]);     // 1146 => [4194880]
-            map.insert(1551, &[3136]);     // 1551 => [3136]
-            map.insert(223, &[5184]);     // 223 => [5184]
-            map.insert(1841, &[9280]);     // 1841 => [9280]
-            map.insert(675, &[17472]);     // 675 => [17472]
-            map.insert(969, &[33856]);     // 969 => [33856]
-            map.insert(284, &[66624]);     // 284 => [66624]
-            map.insert(1206, &[132160]);     // 1206 => [132160]
-            map.insert(1453, &[263232]);     // 1453 => [263232]
-            map.insert(1946, &[525376]);     // 1946 => [525376]
-            map.insert(1012, &[1049664]);     // 1012 => [1049664]
-            map.insert(359, &[2098240]);     // 359 => [2098240]
-            map.insert(634, &[4195392]);     // 634 => [4195392]
-            map.insert(1680, &[6208]);     // 1680 => [6208]
-            map.insert(382, &[10304]);     // 382 => [10304]
-            map.insert(1260, &[18496]);     // 1260 => [18496]
-            map.insert(1414, &[34880]);     // 1414 => [34880]
-            map.insert(1875, &[67648]);     // 1875 => [67648]
-            map.insert(761, &[133184]);     // 761 => [133184]
-            map.insert(994, &[264256]);     // 994 => [264256]
-            map.insert(469, &[526400]);     // 469 => [526400]
-            map.insert(1467, &[1050688]);     // 1467 => [1050688]
-            map.insert(1832, &[2099264]);     // 1832 => [2099264]
-            map.insert(1077, &[4196416]);     // 1077 => [4196416]
-            map.insert(1966, &[12352]);     // 1966 => [12352]
-            map.insert(572, &[20544]);     // 572 => [20544]
-            map.insert(854, &[36928]);     // 854 => [36928]
-            map.insert(387, &[69696]);     // 387 => [69696]
-            map.insert(1065, &[135232]);     // 1065 => [135232]
-            map.insert(1330, &[266304]);     // 1330 => [266304]
-            map.insert(1797, &[528448]);     // 1797 => [528448]
-            map.insert(875, &[1052736]);     // 875 => [1052736]
-            map.insert(504, &[2101312]);     // 504 => [2101312]
-            map.insert(741, &[4198464]);     // 741 => [4198464]
-            map.insert(1490, &[24640]);     // 1490 => [24640]
-            map.insert(1208, &[41024]);     // 1208 => [41024]
-            map.insert(1645, &[73792]);     // 1645 => [73792]
-            map.insert(967, &[139328]);     // 967 => [139328]
-            map.insert(732, &[270400]);     // 732 => [270400]
-            map.insert(235, &[532544]);     // 235 => [532544]
-            map.insert(1157, &[1056832]);     // 1157 => [1056832]
-            map.insert(1558, &[2105408]);     // 1558 => [2105408]
-            map.insert(1291, &[4202560]);     // 1291 => [4202560]
-            map.insert(298, &[49216]);     // 298 => [49216]
-            map.insert(1023, &[81984]);     // 1023 => [81984]
-            map.insert(1621, &[147520]);     // 1621 => [147520]
-            map.insert(1870, &[278592]);     // 1870 => [278592]
-            map.insert(1401, &[540736]);     // 1401 => [540736]
-            map.insert(279, &[1065024]);     // 279 => [1065024]
-            map.insert(900, &[2113600]);     // 900 => [2113600]
-            map.insert(153, &[4210752]);     // 153 => [4210752]
-            map.insert(661, &[98368]);     // 661 => [98368]
-            map.insert(1855, &[163904]);     // 1855 => [163904]
-            map.insert(1572, &[294976]);     // 1572 => [294976]
-            map.insert(1043, &[557120]);     // 1043 => [557120]
-            map.insert(125, &[1081408]);     // 125 => [1081408]
-            map.insert(750, &[2129984]);     // 750 => [2129984]
-            map.insert(499, &[4227136]);     // 499 => [4227136]
-            map.insert(1514, &[196672]);     // 1514 => [196672]
-            map.insert(1265, &[327744]);     // 1265 => [327744]
-            map.insert(1734, &[589888]);     // 1734 => [589888]
-            map.insert(680, &[1114176]);     // 680 => [1114176]
-            map.insert(59, &[2162752]);     // 59 => [2162752]
-            map.insert(806, &[4259904]);     // 806 => [4259904]
-            map.insert(347, &[393280]);     // 347 => [393280]
-            map.insert(876, &[655424]);     // 876 => [655424]
-            map.insert(1794, &[1179712]);     // 1794 => [1179712]
-            map.insert(1425, &[2228288]);     // 1425 => [2228288]
-            map.insert(1676, &[4325440]);     // 1676 => [4325440]
-            map.insert(631, &[786496]);     // 631 => [786496]
-            map.insert(1561, &[1310784]);     // 1561 => [1310784]
-            map.insert(1162, &[2359360]);     // 1162 => [2359360]
-            map.insert(1943, &[4456512]);     // 1943 => [4456512]
-            map.insert(1070, &[1572928]);     // 1070 => [1572928]
-            map.insert(1725, &[2621504]);     // 1725 => [2621504]
-            map.insert(1440, &[4718656]);     // 1440 => [4718656]
-            map.insert(723, &[3145792]);     // 723 => [3145792]
-            map.insert(462, &[5242944]);     // 462 => [5242944]
-            map.insert(861, &[6291520]);     // 861 => [6291520]
-            map.insert(896, &[896]);     // 896 => [896]
-            map.insert(1408, &[1408]);     // 1408 => [1408]
-            map.insert(975, &[2432]);     // 975 => [2432]
-            map.insert(1311, &[4480]);     // 1311 => [4480]
-            map.insert(753, &[8576]);     // 753 => [8576]
-            map.insert(1891, &[16768]);     // 1891 => [16768]
-            map.insert(1545, &[33152]);     // 1545 => [33152]
-            map.insert(1244, &[65920]);     // 1244 => [65920]
-            map.insert(374, &[131456]);     // 374 => [131456]
-            map.insert(109, &[262528]);     // 109 => [262528]
-            map.insert(602, &[524672]);     // 602 => [524672]
-            map.insert(1588, &[1048960]);     // 1588 => [1048960]
-            map.insert(1191, &[2097536]);     // 1191 => [2097536]
-            map.insert(1978, &[4194688]);     // 1978 => [4194688]
-            map.insert(1664, &[1664]);     // 1664 => [1664]
-            map.insert(207, &[2688]);     // 207 => [2688]
-            map.insert(1567, &[4736]);     // 1567 => [4736]
-            map.insert(497, &[8832]);     // 497 => [8832]
-            map.insert(1123, &[17024]);     // 1123 => [17024]
-            map.insert(1289, &[33408]);     // 1289 => [33408]
-            map.insert(2012, &[66176]);     // 2012 => [66176]
-            map.insert(630, &[131712]);     // 630 => [131712]
-            map.insert(877, &[262784]);     // 877 => [262784]
-            map.insert(346, &[524928]);     // 346 => [524928]
-            map.insert(1332, &[1049216]);     // 1332 => [1049216]
-            map.insert(1959, &[2097792]);     // 1959 => [2097792]
-            map.insert(1210, &[4194944]);     // 1210 => [4194944]
-            map.insert(1743, &[3200]);     // 1743 => [3200]
-            map.insert(31, &[5248]);     // 31 => [5248]
-            map.insert(2033, &[9344]);     // 2033 => [9344]
-            map.insert(611, &[17536]);     // 611 => [17536]
-            map.insert(777, &[33920]);     // 777 => [33920]
-            map.insert(476, &[66688]);     // 476 => [66688]
-            map.insert(1142, &[132224]);     // 1142 => [132224]
-            map.insert(1389, &[263296]);     // 1389 => [263296]
-            map.insert(1882, &[525440]);     // 1882 => [525440]
-            map.insert(820, &[1049728]);     // 820 => [1049728]
-            map.insert(423, &[2098304]);     // 423 => [2098304]
-            map.insert(698, &[4195456]);     // 698 => [4195456]
-            map.insert(1616, &[6272]);     // 1616 => [6272]
-            map.insert(446, &[10368]);     // 446 => [10368]
-            map.insert(1068, &[18560]);     // 1068 => [18560]
-            map.insert(1350, &[34944]);     // 1350 => [34944]
-            map.insert(1939, &[67712]);     // 1939 => [67712]
-            map.insert(569, &[133248]);     // 569 => [133248]
-            map.insert(802, &[264320]);     // 802 => [264320]
-            map.insert(277, &[526464]);     // 277 => [526464]
-            map.insert(1403, &[1050752]);     // 1403 => [1050752]
-            map.insert(2024, &[2099328]);     // 2024 => [2099328]
-            map.insert(1269, &[4196480]);     // 1269 => [4196480]
-            map.insert(1902, &[12416]);     // 1902 => [12416]
-            map.insert(764, &[20608]);     // 764 => [20608]
-            map.insert(918, &[36992]);     // 918 => [36992]
-            map.insert(323, &[69760]);     // 323 => [69760]
-            map.insert(1257, &[135296]);     // 1257 => [135296]
-            map.insert(1522, &[266368]);     // 1522 => [266368]
-            map.insert(1989, &[528512]);     // 1989 => [528512]
-            map.insert(939, &[1052800]);     // 939 => [1052800]
-            map.insert(312, &[2101376]);     // 312 => [2101376]
-            map.insert(549, &[4198528]);     // 549 => [4198528]
-            map.insert(1298, &[24704]);     // 1298 => [24704]
-            map.insert(1144, &[41088]);     // 1144 => [41088]
-            map.insert(1709, &[73856]);     // 1709 => [73856]
-            map.insert(775, &[139392]);     // 775 => [139392]
-            map.insert(540, &[270464]);     // 540 => [270464]
-            map.insert(43, &[532608]);     // 43 => [532608]
-            map.insert(1093, &[1056896]);     // 1093 => [1056896]
-            map.insert(1750, &[2105472]);     // 1750 => [2105472]
-            map.insert(1483, &[4202624]);     // 1483 => [4202624]
-            map.insert(490, &[49280]);     // 490 => [49280]
-            map.insert(831, &[82048]);     // 831 => [82048]
-            map.insert(1685, &[147584]);     // 1685 => [147584]
-            map.insert(1934, &[278656]);     // 1934 => [278656]
-            map.insert(1465, &[540800]);     // 1465 => [540800]
-            map.insert(471, &[1065088]);     // 471 => [1065088]
-            map.insert(836, &[2113664]);     // 836 => [2113664]
-            map.insert(89, &[4210816]);     // 89 => [4210816]
-            map.insert(597, &[98432]);     // 597 => [98432]
-            map.insert(2047, &[163968]);     // 2047 => [163968]
-            map.insert(1764, &[295040]);     // 1764 => [295040]
-            map.insert(1235, &[557184]);     // 1235 => [557184]
-            map.insert(189, &[1081472]);     // 189 => [1081472]
-            map.insert(558, &[2130048]);     // 558 => [2130048]
-            map.insert(307, &[4227200]);     // 307 => [4227200]
-            map.insert(1322, &[196736]);     // 1322 => [196736]
-            map.insert(1073, &[327808]);     // 1073 => [327808]
-            map.insert(1542, &[589952]);     // 1542 => [589952]
-            map.insert(616, &[1114240]);     // 616 => [1114240]
-            map.insert(251, &[2162816]);     // 251 => [2162816]
-            map.insert(998, &[4259968]);     // 998 => [4259968]
-            map.insert(411, &[393344]);     // 411 => [393344]
-            map.insert(940, &[655488]);     // 940 => [655488]
-            map.insert(1986, &[1179776]);     // 1986 => [1179776]
-            map.insert(1361, &[2228352]);     // 1361 => [2228352]
-            map.insert(1612, &[4325504]);     // 1612 => [4325504]
-            map.insert(695, &[786560]);     // 695 => [786560]
-            map.insert(1753, &[1310848]);     // 1753 => [1310848]
-            map.insert(1098, &[2359424]);     // 1098 => [2359424]
-            map.insert(1879, &[4456576]);     // 1879 => [4456576]
-            map.insert(1262, &[1572992]);     // 1262 => [1572992]
-            map.insert(1661, &[2621568]);     // 1661 => [2621568]
-            map.insert(1376, &[4718720]);     // 1376 => [4718720]
-            map.insert(531, &[3145856]);     // 531 => [3145856]
-            map.insert(270, &[5243008]);     // 270 => [5243008]
-            map.insert(925, &[6291584]);     // 925 => [6291584]
-            map.insert(1792, &[1792]);     // 1792 => [1792]
-            map.insert(335, &[2816]);     // 335 => [2816]
-            map.insert(1951, &[4864]);     // 1951 => [4864]
-            map.insert(113, &[8960]);     // 113 => [8960]
-            map.insert(1507, &[17152]);     // 1507 => [17152]
-            map.insert(1161, &[33536]);     // 1161 => [33536]
-            map.insert(1628, &[66304]);     // 1628 => [66304]
-            map.insert(1014, &[131840]);     // 1014 => [131840]
-            map.insert(749, &[262912]);     // 749 => [262912]
-            map.insert(218, &[525056]);     // 218 => [525056]
-            map.insert(1204, &[1049344]);     // 1204 => [1049344]
-            map.insert(1575, &[2097920]);     // 1575 => [2097920]
-            map.insert(1338, &[4195072]);     // 1338 => [4195072]
-            map.insert(1871, &[3328]);     // 1871 => [3328]
-            map.insert(415, &[5376]);     // 415 => [5376]
-            map.insert(1649, &[9472]);     // 1649 => [9472]
-            map.insert(995, &[17664]);     // 995 => [17664]
-            map.insert(649, &[34048]);     // 649 => [34048]
-            map.insert(92, &[66816]);     // 92 => [66816]
-            map.insert(1526, &[132352]);     // 1526 => [132352]
-            map.insert(1261, &[263424]);     // 1261 => [263424]
-            map.insert(1754, &[525568]);     // 1754 => [525568]
-            map.insert(692, &[1049856]);     // 692 => [1049856]
-            map.insert(39, &[2098432]);     // 39 => [2098432]
-            map.insert(826, &[4195584]);     // 826 => [4195584]
-            map.insert(2000, &[6400]);     // 2000 => [6400]
-            map.insert(62, &[10496]);     // 62 => [10496]
-            map.insert(1452, &[18688]);     // 1452 => [18688]
-            map.insert(1222, &[35072]);     // 1222 => [35072]
-            map.insert(1555, &[67840]);     // 1555 => [67840]
-            map.insert(953, &[133376]);     // 953 => [133376]
-            map.insert(674, &[264448]);     // 674 => [264448]
-            map.insert(149, &[526592]);     // 149 => [526592]
-            map.insert(1275, &[1050880]);     // 1275 => [1050880]
-            map.insert(1640, &[2099456]);     // 1640 => [2099456]
-            map.insert(1397, &[4196608]);     // 1397 => [4196608]
-            map.insert(1774, &[12544]);     // 1774 => [12544]
-            map.insert(892, &[20736]);     // 892 => [20736]
-            map.insert(534, &[37120]);     // 534 => [37120]
-            map.insert(195, &[69888]);     // 195 => [69888]
-            map.insert(1385, &[135424]);     // 1385 => [135424]
-            map.insert(1138, &[266496]);     // 1138 => [266496]
-            map.insert(1605, &[528640]);     // 1605 => [528640]
-            map.insert(555, &[1052928]);     // 555 => [1052928]
-            map.insert(184, &[2101504]);     // 184 => [2101504]
-            map.insert(933, &[4198656]);     // 933 => [4198656]
-            map.insert(1170, &[24832]);     // 1170 => [24832]
-            map.insert(1528, &[41216]);     // 1528 => [41216]
-            map.insert(1837, &[73984]);     // 1837 => [73984]
-            map.insert(647, &[139520]);     // 647 => [139520]
-            map.insert(924, &[270592]);     // 924 => [270592]
-            map.insert(427, &[532736]);     // 427 => [532736]
-            map.insert(1477, &[1057024]);     // 1477 => [1057024]
-            map.insert(1878, &[2105600]);     // 1878 => [2105600]
-            map.insert(1099, &[4202752]);     // 1099 => [4202752]
-            map.insert(106, &[49408]);     // 106 => [49408]
-            map.insert(703, &[82176]);     // 703 => [82176]
-            map.insert(1813, &[147712]);     // 1813 => [147712]
-            map.insert(1550, &[278784]);     // 1550 => [278784]
-            map.insert(1081, &[540928]);     // 1081 => [540928]
-            map.insert(87, &[1065216]);     // 87 => [1065216]
-            map.insert(708, &[2113792]);     // 708 => [2113792]
-            map.insert(473, &[4210944]);     // 473 => [4210944]
-            map.insert(981, &[98560]);     // 981 => [98560]
-            map.insert(1663, &[164096]);     // 1663 => [164096]
-            map.insert(1892, &[295168]);     // 1892 => [295168]
-            map.insert(1363, &[557312]);     // 1363 => [557312]
-            map.insert(317, &[1081600]);     // 317 => [1081600]
-            map.insert(942, &[2130176]);     // 942 => [2130176]
-            map.insert(179, &[4227328]);     // 179 => [4227328]
-            map.insert(1194, &[196864]);     // 1194 => [196864]
-            map.insert(1457, &[327936]);     // 1457 => [327936]
-            map.insert(1926, &[590080]);     // 1926 => [590080]
-            map.insert(1000, &[1114368]);     // 1000 => [1114368]
-            map.insert(379, &[2162944]);     // 379 => [2162944]
-            map.insert(614, &[4260096]);     // 614 => [4260096]
-            map.insert(27, &[393472]);     // 27 => [393472]
-            map.insert(556, &[655616]);     // 556 => [655616]
-            map.insert(1602, &[1179904]);     // 1602 => [1179904]
-            map.insert(1233, &[2228480]);     // 1233 => [2228480]
-            map.insert(1996, &[4325632]);     // 1996 => [4325632]
-            map.insert(823, &[786688]);     // 823 => [786688]
-            map.insert(1881, &[1310976]);     // 1881 => [1310976]
-            map.insert(1482, &[2359552]);     // 1482 => [2359552]
-            map.insert(1751, &[4456704]);     // 1751 => [4456704]
-            map.insert(1390, &[1573120]);     // 1390 => [1573120]
-            map.insert(2045, &[2621696]);     // 2045 => [2621696]
-            map.insert(1248, &[4718848]);     // 1248 => [4718848]
-            map.insert(915, &[3145984]);     // 915 => [3145984]
-            map.insert(142, &[5243136]);     // 142 => [5243136]
-            map.insert(541, &[6291712]);     // 541 => [6291712]
-            map.insert(1103, &[3584]);     // 1103 => [3584]
-            map.insert(671, &[5632]);     // 671 => [5632]
-            map.insert(1393, &[9728]);     // 1393 => [9728]
-            map.insert(227, &[17920]);     // 227 => [17920]
-            map.insert(393, &[34304]);     // 393 => [34304]
-            map.insert(860, &[67072]);     // 860 => [67072]
-            map.insert(1782, &[132608]);     // 1782 => [132608]
-            map.insert(2029, &[263680]);     // 2029 => [263680]
-            map.insert(1498, &[525824]);     // 1498 => [525824]
-            map.insert(436, &[1050112]);     // 436 => [1050112]
-            map.insert(807, &[2098688]);     // 807 => [2098688]
-            map.insert(58, &[4195840]);     // 58 => [4195840]
-            map.insert(1232, &[6656]);     // 1232 => [6656]
-            map.insert(830, &[10752]);     // 830 => [10752]
-            map.insert(1708, &[18944]);     // 1708 => [18944]
-            map.insert(1990, &[35328]);     // 1990 => [35328]
-            map.insert(1299, &[68096]);     // 1299 => [68096]
-            map.insert(185, &[133632]);     // 185 => [133632]
-            map.insert(418, &[264704]);     // 418 => [264704]
-            map.insert(917, &[526848]);     // 917 => [526848]
-            map.insert(2043, &[1051136]);     // 2043 => [1051136]
-            map.insert(1384, &[2099712]);     // 1384 => [2099712]
-            map.insert(1653, &[4196864]);     // 1653 => [4196864]
-            map.insert(1518, &[12800]);     // 1518 => [12800]
-            map.insert(124, &[20992]);     // 124 => [20992]
-            map.insert(278, &[37376]);     // 278 => [37376]
-            map.insert(963, &[70144]);     // 963 => [70144]
-            map.insert(1641, &[135680]);     // 1641 => [135680]
-            map.insert(1906, &[266752]);     // 1906 => [266752]
-            map.insert(1349, &[528896]);     // 1349 => [528896]
-            map.insert(299, &[1053184]);     // 299 => [1053184]
-            map.insert(952, &[2101760]);     // 952 => [2101760]
-            map.insert(165, &[4198912]);     // 165 => [4198912]
-            map.insert(1938, &[25088]);     // 1938 => [25088]
-            map.insert(1784, &[41472]);     // 1784 => [41472]
-            map.insert(1069, &[74240]);     // 1069 => [74240]
-            map.insert(391, &[139776]);     // 391 => [139776]
-            map.insert(156, &[270848]);     // 156 => [270848]
-            map.insert(683, &[532992]);     // 683 => [532992]
-            map.insert(1733, &[1057280]);     // 1733 => [1057280]
-            map.insert(1110, &[2105856]);     // 1110 => [2105856]
-            map.insert(1867, &[4203008]);     // 1867 => [4203008]
-            map.insert(874, &[49664]);     // 874 => [49664]
-            map.insert(447, &[82432]);     // 447 => [82432]
-            map.insert(1045, &[147968]);     // 1045 => [147968]
-            map.insert(1294, &[279040]);     // 1294 => [279040]
-            map.insert(1849, &[541184]);     // 1849 => [541184]
-            map.insert(855, &[1065472]);     // 855 => [1065472]
-            map.insert(452, &[2114048]);     // 452 => [2114048]
-            map.insert(729, &[4211200]);     // 729 => [4211200]
-            map.insert(213, &[98816]);     // 213 => [98816]
-            map.insert(1407, &[164352]);     // 1407 => [164352]
-            map.insert(1124, &[295424]);     // 1124 => [295424]
-            map.insert(1619, &[557568]);     // 1619 => [557568]
-            map.insert(573, &[1081856]);     // 573 => [1081856]
-            map.insert(174, &[2130432]);     // 174 => [2130432]
-            map.insert(947, &[4227584]);     // 947 => [4227584]
-            map.insert(1962, &[197120]);     // 1962 => [197120]
-            map.insert(1713, &[328192]);     // 1713 => [328192]
-            map.insert(1158, &[590336]);     // 1158 => [590336]
-            map.insert(232, &[1114624]);     // 232 => [1114624]
-            map.insert(635, &[2163200]);     // 635 => [2163200]
-            map.insert(358, &[4260352]);     // 358 => [4260352]
-            map.insert(795, &[393728]);     // 795 => [393728]
-            map.insert(300, &[655872]);     // 300 => [655872]
-            map.insert(1346, &[1180160]);     // 1346 => [1180160]
-            map.insert(2001, &[2228736]);     // 2001 => [2228736]
-            map.insert(1228, &[4325888]);     // 1228 => [4325888]
-            map.insert(55, &[786944]);     // 55 => [786944]
-            map.insert(1113, &[1311232]);     // 1113 => [1311232]
-            map.insert(1738, &[2359808]);     // 1738 => [2359808]
-            map.insert(1495, &[4456960]);     // 1495 => [4456960]
-            map.insert(1646, &[1573376]);     // 1646 => [1573376]
-            map.insert(1277, &[2621952]);     // 1277 => [2621952]
-            map.insert(2016, &[4719104]);     // 2016 => [4719104]
-            map.insert(147, &[3146240]);     // 147 => [3146240]
-            map.insert(910, &[5243392]);     // 910 => [5243392]
-            map.insert(285, &[6291968]);     // 285 => [6291968]
-            map.insert(720, &[7168]);     // 720 => [7168]
-            map.insert(1342, &[11264]);     // 1342 => [11264]
-            map.insert(172, &[19456]);     // 172 => [19456]
-            map.insert(454, &[35840]);     // 454 => [35840]
-            map.insert(787, &[68608]);     // 787 => [68608]
-            map.insert(1721, &[134144]);     // 1721 => [134144]
-            map.insert(1954, &[265216]);     // 1954 => [265216]
-            map.insert(1429, &[527360]);     // 1429 => [527360]
-            map.insert(507, &[1051648]);     // 507 => [1051648]
-            map.insert(872, &[2100224]);     // 872 => [2100224]
-            map.insert(117, &[4197376]);     // 117 => [4197376]
-            map.insert(1006, &[13312]);     // 1006 => [13312]
-            map.insert(1660, &[21504]);     // 1660 => [21504]
-            map.insert(1814, &[37888]);     // 1814 => [37888]
-            map.insert(1475, &[70656]);     // 1475 => [70656]
-            map.insert(105, &[136192]);     // 105 => [136192]
-            map.insert(370, &[267264]);     // 370 => [267264]
-            map.insert(837, &[529408]);     // 837 => [529408]
-            map.insert(1835, &[1053696]);     // 1835 => [1053696]
-            map.insert(1464, &[2102272]);     // 1464 => [2102272]
-            map.insert(1701, &[4199424]);     // 1701 => [4199424]
-            map.insert(402, &[25600]);     // 402 => [25600]
-            map.insert(248, &[41984]);     // 248 => [41984]
-            map.insert(557, &[74752]);     // 557 => [74752]
-            map.insert(1927, &[140288]);     // 1927 => [140288]
-            map.insert(1692, &[271360]);     // 1692 => [271360]
-            map.insert(1195, &[533504]);     // 1195 => [533504]
-            map.insert(197, &[1057792]);     // 197 => [1057792]
-            map.insert(598, &[2106368]);     // 598 => [2106368]
-            map.insert(331, &[4203520]);     // 331 => [4203520]
-            map.insert(1386, &[50176]);     // 1386 => [50176]
-            map.insert(1983, &[82944]);     // 1983 => [82944]
-            map.insert(533, &[148480]);     // 533 => [148480]
-            map.insert(782, &[279552]);     // 782 => [279552]
-            map.insert(313, &[541696]);     // 313 => [541696]
-            map.insert(1367, &[1065984]);     // 1367 => [1065984]
-            map.insert(1988, &[2114560]);     // 1988 => [2114560]
-            map.insert(1241, &[4211712]);     // 1241 => [4211712]
-            map.insert(1749, &[99328]);     // 1749 => [99328]
-            map.insert(895, &[164864]);     // 895 => [164864]
-            map.insert(612, &[295936]);     // 612 => [295936]
-            map.insert(83, &[558080]);     // 83 => [558080]
-            map.insert(1085, &[1082368]);     // 1085 => [1082368]
-            map.insert(1710, &[2130944]);     // 1710 => [2130944]
-            map.insert(1459, &[4228096]);     // 1459 => [4228096]
-            map.insert(426, &[197632]);     // 426 => [197632]
-            map.insert(177, &[328704]);     // 177 => [328704]
-            map.insert(646, &[590848]);     // 646 => [590848]
-            map.insert(1768, &[1115136]);     // 1768 => [1115136]
-            map.insert(1147, &[2163712]);     // 1147 => [2163712]
-            map.insert(1894, &[4260864]);     // 1894 => [4260864]
-            map.insert(1307, &[394240]);     // 1307 => [394240]
-            map.insert(1836, &[656384]);     // 1836 => [656384]
-            map.insert(834, &[1180672]);     // 834 => [1180672]
-            map.insert(465, &[2229248]);     // 465 => [2229248]
-            map.insert(716, &[4326400]);     // 716 => [4326400]
-            map.insert(1591, &[787456]);     // 1591 => [787456]
-            map.insert(601, &[1311744]);     // 601 => [1311744]
-            map.insert(202, &[2360320]);     // 202 => [2360320]
-            map.insert(983, &[4457472]);     // 983 => [4457472]
-            map.insert(110, &[1573888]);     // 110 => [1573888]
-            map.insert(765, &[2622464]);     // 765 => [2622464]
-            map.insert(480, &[4719616]);     // 480 => [4719616]
-            map.insert(1683, &[3146752]);     // 1683 => [3146752]
-            map.insert(1422, &[5243904]);     // 1422 => [5243904]
-            map.insert(1821, &[6292480]);     // 1821 => [6292480]
-            map.insert(1441, &[14336]);     // 1441 => [14336]
-            map.insert(51, &[22528]);     // 51 => [22528]
-            map.insert(345, &[38912]);     // 345 => [38912]
-            map.insert(908, &[71680]);     // 908 => [71680]
-            map.insert(1574, &[137216]);     // 1574 => [137216]
-            map.insert(1853, &[268288]);     // 1853 => [268288]
-            map.insert(1290, &[530432]);     // 1290 => [530432]
-            map.insert(356, &[1054720]);     // 356 => [1054720]
-            map.insert(1015, &[2103296]);     // 1015 => [2103296]
-            map.insert(234, &[4200448]);     // 234 => [4200448]
-            map.insert(2013, &[26624]);     // 2013 => [26624]
-            map.insert(1719, &[43008]);     // 1719 => [43008]
-            map.insert(1122, &[75776]);     // 1122 => [75776]
-            map.insert(456, &[141312]);     // 456 => [141312]
-            map.insert(211, &[272384]);     // 211 => [272384]
-            map.insert(740, &[534528]);     // 740 => [534528]
-            map.insert(1674, &[1058816]);     // 1674 => [1058816]
-            map.insert(1049, &[2107392]);     // 1049 => [2107392]
-            map.insert(1796, &[4204544]);     // 1796 => [4204544]
-            map.insert(805, &[51200]);     // 805 => [51200]
-            map.insert(496, &[83968]);     // 496 => [83968]
-            map.insert(1114, &[149504]);     // 1114 => [149504]
-            map.insert(1345, &[280576]);     // 1345 => [280576]
-            map.insert(1910, &[542720]);     // 1910 => [542720]
-            map.insert(792, &[1067008]);     // 792 => [1067008]
-            map.insert(395, &[2115584]);     // 395 => [2115584]
-            map.insert(662, &[4212736]);     // 662 => [4212736]
-            map.insert(154, &[100352]);     // 154 => [100352]
-            map.insert(1328, &[165888]);     // 1328 => [165888]
-            map.insert(1067, &[296960]);     // 1067 => [296960]
-            map.insert(1564, &[559104]);     // 1564 => [559104]
-            map.insert(626, &[1083392]);     // 626 => [1083392]
-            map.insert(225, &[2131968]);     // 225 => [2131968]
-            map.insert(1020, &[4229120]);     // 1020 => [4229120]
-            map.insert(2021, &[198656]);     // 2021 => [198656]
-            map.insert(1790, &[329728]);     // 1790 => [329728]
-            map.insert(1225, &[591872]);     // 1225 => [591872]
-            map.insert(167, &[1116160]);     // 167 => [1116160]
-            map.insert(564, &[2164736]);     // 564 => [2164736]
-            map.insert(297, &[4261888]);     // 297 => [4261888]
-            map.insert(852, &[395264]);     // 852 => [395264]
-            map.insert(355, &[657408]);     // 355 => [657408]
-            map.insert(1293, &[1181696]);     // 1293 => [1181696]
-            map.insert(1950, &[2230272]);     // 1950 => [2230272]
-            map.insert(1155, &[4327424]);     // 1155 => [4327424]
-            map.insert(120, &[788480]);     // 120 => [788480]
-            map.insert(1046, &[1312768]);     // 1046 => [1312768]
-            map.insert(1669, &[2361344]);     // 1669 => [2361344]
-            map.insert(1432, &[4458496]);     // 1432 => [4458496]
-            map.insert(1569, &[1574912]);     // 1569 => [1574912]
-            map.insert(1202, &[2623488]);     // 1202 => [2623488]
-            map.insert(1967, &[4720640]);     // 1967 => [4720640]
-            map.insert(220, &[3147776]);     // 220 => [3147776]
-            map.insert(961, &[5244928]);     // 961 => [5244928]
-            map.insert(338, &[6293504]);     // 338 => [6293504]
-            map.insert(269, &[28672]);     // 269 => [28672]
-            map.insert(103, &[45056]);     // 103 => [45056]
-            map.insert(690, &[77824]);     // 690 => [77824]
-            map.insert(1816, &[143360]);     // 1816 => [143360]
-            map.insert(1539, &[274432]);     // 1539 => [274432]
-            map.insert(1076, &[536576]);     // 1076 => [536576]
-            map.insert(90, &[1060864]);     // 90 => [1060864]
-            map.insert(713, &[2109440]);     // 713 => [2109440]
-            map.insert(468, &[4206592]);     // 468 => [4206592]
-            map.insert(1525, &[53248]);     // 1525 => [53248]
-            map.insert(1824, &[86016]);     // 1824 => [86016]
-            map.insert(650, &[151552]);     // 650 => [151552]
-            map.insert(913, &[282624]);     // 913 => [282624]
-            map.insert(422, &[544768]);     // 422 => [544768]
-            map.insert(1480, &[1069056]);     // 1480 => [1069056]
-            map.insert(1883, &[2117632]);     // 1883 => [2117632]
-            map.insert(1094, &[4214784]);     // 1094 => [4214784]
-            map.insert(1610, &[102400]);     // 1610 => [102400]
-            map.insert(992, &[167936]);     // 992 => [167936]
-            map.insert(763, &[299008]);     // 763 => [299008]
-            map.insert(204, &[561152]);     // 204 => [561152]
-            map.insert(1186, &[1085440]);     // 1186 => [1085440]
-            map.insert(1585, &[2134016]);     // 1585 => [2134016]
-            map.insert(1324, &[4231168]);     // 1324 => [4231168]
-            map.insert(309, &[200704]);     // 309 => [200704]
-            map.insert(46, &[331776]);     // 46 => [331776]
-            map.insert(537, &[593920]);     // 537 => [593920]
-            map.insert(1655, &[1118208]);     // 1655 => [1118208]
-            map.insert(1252, &[2166784]);     // 1252 => [2166784]
-            map.insert(2041, &[4263936]);     // 2041 => [4263936]
-            map.insert(1412, &[397312]);     // 1412 => [397312]
-            map.insert(1971, &[659456]);     // 1971 => [659456]
-            map.insert(989, &[1183744]);     // 989 => [1183744]
-            map.insert(334, &[2232320]);     // 334 => [2232320]
-            map.insert(595, &[4329472]);     // 595 => [4329472]
-            map.insert(1704, &[790528]);     // 1704 => [790528]
-            map.insert(710, &[1314816]);     // 710 => [1314816]
-            map.insert(85, &[2363392]);     // 85 => [2363392]
-            map.insert(840, &[4460544]);     // 840 => [4460544]
-            map.insert(241, &[1576960]);     // 241 => [1576960]
-            map.insert(610, &[2625536]);     // 610 => [2625536]
-            map.insert(383, &[4722688]);     // 383 => [4722688]
-            map.insert(1548, &[3149824]);     // 1548 => [3149824]
-            map.insert(1297, &[5246976]);     // 1297 => [5246976]
-            map.insert(1922, &[6295552]);     // 1922 => [6295552]
-            map.insert(539, &[57344]);     // 539 => [57344]
-            map.insert(206, &[90112]);     // 206 => [90112]
-            map.insert(1380, &[155648]);     // 1380 => [155648]
-            map.insert(1151, &[286720]);     // 1151 => [286720]
-            map.insert(1608, &[548864]);     // 1608 => [548864]
-            map.insert(550, &[1073152]);     // 550 => [1073152]
-            map.insert(181, &[2121728]);     // 181 => [2121728]
-            map.insert(936, &[4218880]);     // 936 => [4218880]
-            map.insert(420, &[106496]);     // 420 => [106496]
-            map.insert(1038, &[172032]);     // 1038 => [172032]
-            map.insert(1301, &[303104]);     // 1301 => [303104]
-            map.insert(1826, &[565248]);     // 1826 => [565248]
-            map.insert(844, &[1089536]);     // 844 => [1089536]
-            map.insert(479, &[2138112]);     // 479 => [2138112]
-            map.insert(706, &[4235264]);     // 706 => [4235264]
-            map.insert(1755, &[204800]);     // 1755 => [204800]
-            map.insert(1984, &[335872]);     // 1984 => [335872]
-            map.insert(1527, &[598016]);     // 1527 => [598016]
-            map.insert(409, &[1122304]);     // 409 => [1122304]
-            map.insert(778, &[2170880]);     // 778 => [2170880]
-            map.insert(23, &[4268032]);     // 23 => [4268032]
-            map.insert(618, &[401408]);     // 618 => [401408]
-            map.insert(93, &[663552]);     // 93 => [663552]
-            map.insert(1075, &[1187840]);     // 1075 => [1187840]
-            map.insert(1696, &[2236416]);     // 1696 => [2236416]
-            map.insert(1469, &[4333568]);     // 1469 => [4333568]
-            map.insert(326, &[794624]);     // 326 => [794624]
-            map.insert(1320, &[1318912]);     // 1320 => [1318912]
-            map.insert(1979, &[2367488]);     // 1979 => [2367488]
-            map.insert(1190, &[4464640]);     // 1190 => [4464640]
-            map.insert(1823, &[1581056]);     // 1823 => [1581056]
-            map.insert(1420, &[2629632]);     // 1420 => [2629632]
-            map.insert(1681, &[4726784]);     // 1681 => [4726784]
-            map.insert(482, &[3153920]);     // 482 => [3153920]
-            map.insert(767, &[5251072]);     // 767 => [5251072]
-            map.insert(108, &[6299648]);     // 108 => [6299648]
-            map.insert(1078, &[114688]);     // 1078 => [114688]
-            map.insert(412, &[180224]);     // 412 => [180224]
-            map.insert(135, &[311296]);     // 135 => [311296]
-            map.insert(688, &[573440]);     // 688 => [573440]
-            map.insert(1758, &[1097728]);     // 1758 => [1097728]
-            map.insert(1101, &[2146304]);     // 1101 => [2146304]
-            map.insert(1872, &[4243456]);     // 1872 => [4243456]
-            map.insert(841, &[212992]);     // 841 => [212992]
-            map.insert(594, &[344064]);     // 594 => [344064]
-            map.insert(101, &[606208]);     // 101 => [606208]
-            map.insert(1035, &[1130496]);     // 1035 => [1130496]
-            map.insert(1688, &[2179072]);     // 1688 => [2179072]
-            map.insert(1413, &[4276224]);     // 1413 => [4276224]
-            map.insert(2040, &[409600]);     // 2040 => [409600]
-            map.insert(1487, &[671744]);     // 1487 => [671744]
-            map.insert(417, &[1196032]);     // 417 => [1196032]
-            map.insert(818, &[2244608]);     // 818 => [2244608]
-            map.insert(47, &[4341760]);     // 47 => [4341760]
-            map.insert(1236, &[802816]);     // 1236 => [802816]
-            map.insert(186, &[1327104]);     // 186 => [1327104]
-            map.insert(553, &[2375680]);     // 553 => [2375680]
-            map.insert(308, &[4472832]);     // 308 => [4472832]
-            map.insert(653, &[1589248]);     // 653 => [1589248]
-            map.insert(30, &[2637824]);     // 30 => [2637824]
-            map.insert(771, &[4734976]);     // 771 => [4734976]
-            map.insert(1136, &[3162112]);     // 1136 => [3162112]
-            map.insert(1901, &[5259264]);     // 1901 => [5259264]
-            map.insert(1534, &[6307840]);     // 1534 => [6307840]
-            map.insert(547, &[229376]);     // 547 => [229376]
-            map.insert(824, &[360448]);     // 824 => [360448]
-            map.insert(271, &[622592]);     // 271 => [622592]
-            map.insert(1377, &[1146880]);     // 1377 => [1146880]
-            map.insert(2034, &[2195456]);     // 2034 => [2195456]
-            map.insert(1263, &[4292608]);     // 1263 => [4292608]
-            map.insert(1682, &[425984]);     // 1682 => [425984]
-            map.insert(1189, &[688128]);     // 1189 => [688128]
-            map.insert(203, &[1212416]);     // 203 => [1212416]
-            map.insert(600, &[2260992]);     // 600 => [2260992]
-            map.insert(325, &[4358144]);     // 325 => [4358144]
-            map.insert(1470, &[819200]);     // 1470 => [819200]
-            map.insert(464, &[1343488]);     // 464 => [1343488]
-            map.insert(835, &[2392064]);     // 835 => [2392064]
-            map.insert(94, &[4489216]);     // 94 => [4489216]
-            map.insert(999, &[1605632]);     // 999 => [1605632]
-            map.insert(372, &[2654208]);     // 372 => [2654208]
-            map.insert(617, &[4751360]);     // 617 => [4751360]
-            map.insert(1306, &[3178496]);     // 1306 => [3178496]
-            map.insert(1543, &[5275648]);     // 1543 => [5275648]
-            map.insert(1172, &[6324224]);     // 1172 => [6324224]
-            map.insert(1095, &[458752]);     // 1095 => [458752]
-            map.insert(1648, &[720896]);     // 1648 => [720896]
-            map.insert(542, &[1245184]);     // 542 => [1245184]
-            map.insert(141, &[2293760]);     // 141 => [2293760]
-            map.insert(912, &[4390912]);     // 912 => [4390912]
-            map.insert(1899, &[851968]);     // 1899 => [851968]
-            map.insert(773, &[1376256]);     // 773 => [1376256]
-            map.insert(406, &[2424832]);     // 406 => [2424832]
-            map.insert(651, &[4521984]);     // 651 => [4521984]
-            map.insert(306, &[1638400]);     // 306 => [1638400]
-            map.insert(929, &[2686976]);     // 929 => [2686976]
-            map.insert(188, &[4784128]);     // 188 => [4784128]
-            map.insert(1999, &[3211264]);     // 1999 => [3211264]
-            map.insert(1234, &[5308416]);     // 1234 => [5308416]
-            map.insert(1601, &[6356992]);     // 1601 => [6356992]
-            map.insert(705, &[917504]);     // 705 => [917504]
-            map.insert(1711, &[1441792]);     // 1711 => [1441792]
-            map.insert(1084, &[2490368]);     // 1084 => [2490368]
-            map.insert(1825, &[4587520]);     // 1825 => [4587520]
-            map.insert(1176, &[1703936]);     // 1176 => [1703936]
-            map.insert(1547, &[2752512]);     // 1547 => [2752512]
-            map.insert(1302, &[4849664]);     // 1302 => [4849664]
-            map.insert(613, &[3276800]);     // 613 => [3276800]
-            map.insert(376, &[5373952]);     // 376 => [5373952]
-            map.insert(1003, &[6422528]);     // 1003 => [6422528]
-            map.insert(1411, &[1835008]);     // 1411 => [1835008]
-            map.insert(1808, &[2883584]);     // 1808 => [2883584]
-            map.insert(1037, &[4980736]);     // 1037 => [4980736]
-            map.insert(894, &[3407872]);     // 894 => [3407872]
-            map.insert(99, &[5505024]);     // 99 => [5505024]
-            map.insert(752, &[6553600]);     // 752 => [6553600]
-            map.insert(329, &[3670016]);     // 329 => [3670016]
-            map.insert(596, &[5767168]);     // 596 => [5767168]
-            map.insert(199, &[6815744]);     // 199 => [6815744]
-            map.insert(1193, &[7340032]);     // 1193 => [7340032]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 2422785 ],
+            &[ 6969346 ],
+            &[ 3610628 ],
+            &[ 5085192 ],
+            &[ 5976080 ],
+            &[ 7757856 ],
+            &[ 1009728 ],
+            &[ 2019456 ],
+            &[ 4038912 ],
+            &[ 5827072 ],
+            &[ 7459840 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 23)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(2048, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(64, &[64]);     // 64 => [64]
+        map.insert(128, &[128]);     // 128 => [128]
+        map.insert(256, &[256]);     // 256 => [256]
+        map.insert(512, &[512]);     // 512 => [512]
+        map.insert(1024, &[1024]);     // 1024 => [1024]
+        map.insert(591, &[2048]);     // 591 => [2048]
+        map.insert(1183, &[4096]);     // 1183 => [4096]
+        map.insert(881, &[8192]);     // 881 => [8192]
+        map.insert(1763, &[16384]);     // 1763 => [16384]
+        map.insert(1929, &[32768]);     // 1929 => [32768]
+        map.insert(1372, &[65536]);     // 1372 => [65536]
+        map.insert(246, &[131072]);     // 246 => [131072]
+        map.insert(493, &[262144]);     // 493 => [262144]
+        map.insert(986, &[524288]);     // 986 => [524288]
+        map.insert(1972, &[1048576]);     // 1972 => [1048576]
+        map.insert(1319, &[2097152]);     // 1319 => [2097152]
+        map.insert(1594, &[4194304]);     // 1594 => [4194304]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(65, &[65]);     // 65 => [65]
+        map.insert(129, &[129]);     // 129 => [129]
+        map.insert(257, &[257]);     // 257 => [257]
+        map.insert(513, &[513]);     // 513 => [513]
+        map.insert(1025, &[1025]);     // 1025 => [1025]
+        map.insert(590, &[2049]);     // 590 => [2049]
+        map.insert(1182, &[4097]);     // 1182 => [4097]
+        map.insert(880, &[8193]);     // 880 => [8193]
+        map.insert(1762, &[16385]);     // 1762 => [16385]
+        map.insert(1928, &[32769]);     // 1928 => [32769]
+        map.insert(1373, &[65537]);     // 1373 => [65537]
+        map.insert(247, &[131073]);     // 247 => [131073]
+        map.insert(492, &[262145]);     // 492 => [262145]
+        map.insert(987, &[524289]);     // 987 => [524289]
+        map.insert(1973, &[1048577]);     // 1973 => [1048577]
+        map.insert(1318, &[2097153]);     // 1318 => [2097153]
+        map.insert(1595, &[4194305]);     // 1595 => [4194305]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(66, &[66]);     // 66 => [66]
+        map.insert(130, &[130]);     // 130 => [130]
+        map.insert(258, &[258]);     // 258 => [258]
+        map.insert(514, &[514]);     // 514 => [514]
+        map.insert(1026, &[1026]);     // 1026 => [1026]
+        map.insert(589, &[2050]);     // 589 => [2050]
+        map.insert(1181, &[4098]);     // 1181 => [4098]
+        map.insert(883, &[8194]);     // 883 => [8194]
+        map.insert(1761, &[16386]);     // 1761 => [16386]
+        map.insert(1931, &[32770]);     // 1931 => [32770]
+        map.insert(1374, &[65538]);     // 1374 => [65538]
+        map.insert(244, &[131074]);     // 244 => [131074]
+        map.insert(495, &[262146]);     // 495 => [262146]
+        map.insert(984, &[524290]);     // 984 => [524290]
+        map.insert(1974, &[1048578]);     // 1974 => [1048578]
+        map.insert(1317, &[2097154]);     // 1317 => [2097154]
+        map.insert(1592, &[4194306]);     // 1592 => [4194306]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(68, &[68]);     // 68 => [68]
+        map.insert(132, &[132]);     // 132 => [132]
+        map.insert(260, &[260]);     // 260 => [260]
+        map.insert(516, &[516]);     // 516 => [516]
+        map.insert(1028, &[1028]);     // 1028 => [1028]
+        map.insert(587, &[2052]);     // 587 => [2052]
+        map.insert(1179, &[4100]);     // 1179 => [4100]
+        map.insert(885, &[8196]);     // 885 => [8196]
+        map.insert(1767, &[16388]);     // 1767 => [16388]
+        map.insert(1933, &[32772]);     // 1933 => [32772]
+        map.insert(1368, &[65540]);     // 1368 => [65540]
+        map.insert(242, &[131076]);     // 242 => [131076]
+        map.insert(489, &[262148]);     // 489 => [262148]
+        map.insert(990, &[524292]);     // 990 => [524292]
+        map.insert(1968, &[1048580]);     // 1968 => [1048580]
+        map.insert(1315, &[2097156]);     // 1315 => [2097156]
+        map.insert(1598, &[4194308]);     // 1598 => [4194308]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(72, &[72]);     // 72 => [72]
+        map.insert(136, &[136]);     // 136 => [136]
+        map.insert(264, &[264]);     // 264 => [264]
+        map.insert(520, &[520]);     // 520 => [520]
+        map.insert(1032, &[1032]);     // 1032 => [1032]
+        map.insert(583, &[2056]);     // 583 => [2056]
+        map.insert(1175, &[4104]);     // 1175 => [4104]
+        map.insert(889, &[8200]);     // 889 => [8200]
+        map.insert(1771, &[16392]);     // 1771 => [16392]
+        map.insert(1921, &[32776]);     // 1921 => [32776]
+        map.insert(1364, &[65544]);     // 1364 => [65544]
+        map.insert(254, &[131080]);     // 254 => [131080]
+        map.insert(485, &[262152]);     // 485 => [262152]
+        map.insert(978, &[524296]);     // 978 => [524296]
+        map.insert(1980, &[1048584]);     // 1980 => [1048584]
+        map.insert(1327, &[2097160]);     // 1327 => [2097160]
+        map.insert(1586, &[4194312]);     // 1586 => [4194312]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(80, &[80]);     // 80 => [80]
+        map.insert(144, &[144]);     // 144 => [144]
+        map.insert(272, &[272]);     // 272 => [272]
+        map.insert(528, &[528]);     // 528 => [528]
+        map.insert(1040, &[1040]);     // 1040 => [1040]
+        map.insert(607, &[2064]);     // 607 => [2064]
+        map.insert(1167, &[4112]);     // 1167 => [4112]
+        map.insert(865, &[8208]);     // 865 => [8208]
+        map.insert(1779, &[16400]);     // 1779 => [16400]
+        map.insert(1945, &[32784]);     // 1945 => [32784]
+        map.insert(1356, &[65552]);     // 1356 => [65552]
+        map.insert(230, &[131088]);     // 230 => [131088]
+        map.insert(509, &[262160]);     // 509 => [262160]
+        map.insert(970, &[524304]);     // 970 => [524304]
+        map.insert(1956, &[1048592]);     // 1956 => [1048592]
+        map.insert(1335, &[2097168]);     // 1335 => [2097168]
+        map.insert(1578, &[4194320]);     // 1578 => [4194320]
+        map.insert(96, &[96]);     // 96 => [96]
+        map.insert(160, &[160]);     // 160 => [160]
+        map.insert(288, &[288]);     // 288 => [288]
+        map.insert(544, &[544]);     // 544 => [544]
+        map.insert(1056, &[1056]);     // 1056 => [1056]
+        map.insert(623, &[2080]);     // 623 => [2080]
+        map.insert(1215, &[4128]);     // 1215 => [4128]
+        map.insert(849, &[8224]);     // 849 => [8224]
+        map.insert(1731, &[16416]);     // 1731 => [16416]
+        map.insert(1961, &[32800]);     // 1961 => [32800]
+        map.insert(1404, &[65568]);     // 1404 => [65568]
+        map.insert(214, &[131104]);     // 214 => [131104]
+        map.insert(461, &[262176]);     // 461 => [262176]
+        map.insert(1018, &[524320]);     // 1018 => [524320]
+        map.insert(1940, &[1048608]);     // 1940 => [1048608]
+        map.insert(1287, &[2097184]);     // 1287 => [2097184]
+        map.insert(1562, &[4194336]);     // 1562 => [4194336]
+        map.insert(192, &[192]);     // 192 => [192]
+        map.insert(320, &[320]);     // 320 => [320]
+        map.insert(576, &[576]);     // 576 => [576]
+        map.insert(1088, &[1088]);     // 1088 => [1088]
+        map.insert(527, &[2112]);     // 527 => [2112]
+        map.insert(1247, &[4160]);     // 1247 => [4160]
+        map.insert(817, &[8256]);     // 817 => [8256]
+        map.insert(1699, &[16448]);     // 1699 => [16448]
+        map.insert(1993, &[32832]);     // 1993 => [32832]
+        map.insert(1308, &[65600]);     // 1308 => [65600]
+        map.insert(182, &[131136]);     // 182 => [131136]
+        map.insert(429, &[262208]);     // 429 => [262208]
+        map.insert(922, &[524352]);     // 922 => [524352]
+        map.insert(2036, &[1048640]);     // 2036 => [1048640]
+        map.insert(1383, &[2097216]);     // 1383 => [2097216]
+        map.insert(1658, &[4194368]);     // 1658 => [4194368]
+        map.insert(384, &[384]);     // 384 => [384]
+        map.insert(640, &[640]);     // 640 => [640]
+        map.insert(1152, &[1152]);     // 1152 => [1152]
+        map.insert(719, &[2176]);     // 719 => [2176]
+        map.insert(1055, &[4224]);     // 1055 => [4224]
+        map.insert(1009, &[8320]);     // 1009 => [8320]
+        map.insert(1635, &[16512]);     // 1635 => [16512]
+        map.insert(1801, &[32896]);     // 1801 => [32896]
+        map.insert(1500, &[65664]);     // 1500 => [65664]
+        map.insert(118, &[131200]);     // 118 => [131200]
+        map.insert(365, &[262272]);     // 365 => [262272]
+        map.insert(858, &[524416]);     // 858 => [524416]
+        map.insert(1844, &[1048704]);     // 1844 => [1048704]
+        map.insert(1447, &[2097280]);     // 1447 => [2097280]
+        map.insert(1722, &[4194432]);     // 1722 => [4194432]
+        map.insert(768, &[768]);     // 768 => [768]
+        map.insert(1280, &[1280]);     // 1280 => [1280]
+        map.insert(847, &[2304]);     // 847 => [2304]
+        map.insert(1439, &[4352]);     // 1439 => [4352]
+        map.insert(625, &[8448]);     // 625 => [8448]
+        map.insert(2019, &[16640]);     // 2019 => [16640]
+        map.insert(1673, &[33024]);     // 1673 => [33024]
+        map.insert(1116, &[65792]);     // 1116 => [65792]
+        map.insert(502, &[131328]);     // 502 => [131328]
+        map.insert(237, &[262400]);     // 237 => [262400]
+        map.insert(730, &[524544]);     // 730 => [524544]
+        map.insert(1716, &[1048832]);     // 1716 => [1048832]
+        map.insert(1063, &[2097408]);     // 1063 => [2097408]
+        map.insert(1850, &[4194560]);     // 1850 => [4194560]
+        map.insert(1536, &[1536]);     // 1536 => [1536]
+        map.insert(79, &[2560]);     // 79 => [2560]
+        map.insert(1695, &[4608]);     // 1695 => [4608]
+        map.insert(369, &[8704]);     // 369 => [8704]
+        map.insert(1251, &[16896]);     // 1251 => [16896]
+        map.insert(1417, &[33280]);     // 1417 => [33280]
+        map.insert(1884, &[66048]);     // 1884 => [66048]
+        map.insert(758, &[131584]);     // 758 => [131584]
+        map.insert(1005, &[262656]);     // 1005 => [262656]
+        map.insert(474, &[524800]);     // 474 => [524800]
+        map.insert(1460, &[1049088]);     // 1460 => [1049088]
+        map.insert(1831, &[2097664]);     // 1831 => [2097664]
+        map.insert(1082, &[4194816]);     // 1082 => [4194816]
+        map.insert(1615, &[3072]);     // 1615 => [3072]
+        map.insert(159, &[5120]);     // 159 => [5120]
+        map.insert(1905, &[9216]);     // 1905 => [9216]
+        map.insert(739, &[17408]);     // 739 => [17408]
+        map.insert(905, &[33792]);     // 905 => [33792]
+        map.insert(348, &[66560]);     // 348 => [66560]
+        map.insert(1270, &[132096]);     // 1270 => [132096]
+        map.insert(1517, &[263168]);     // 1517 => [263168]
+        map.insert(2010, &[525312]);     // 2010 => [525312]
+        map.insert(948, &[1049600]);     // 948 => [1049600]
+        map.insert(295, &[2098176]);     // 295 => [2098176]
+        map.insert(570, &[4195328]);     // 570 => [4195328]
+        map.insert(1744, &[6144]);     // 1744 => [6144]
+        map.insert(318, &[10240]);     // 318 => [10240]
+        map.insert(1196, &[18432]);     // 1196 => [18432]
+        map.insert(1478, &[34816]);     // 1478 => [34816]
+        map.insert(1811, &[67584]);     // 1811 => [67584]
+        map.insert(697, &[133120]);     // 697 => [133120]
+        map.insert(930, &[264192]);     // 930 => [264192]
+        map.insert(405, &[526336]);     // 405 => [526336]
+        map.insert(1531, &[1050624]);     // 1531 => [1050624]
+        map.insert(1896, &[2099200]);     // 1896 => [2099200]
+        map.insert(1141, &[4196352]);     // 1141 => [4196352]
+        map.insert(2030, &[12288]);     // 2030 => [12288]
+        map.insert(636, &[20480]);     // 636 => [20480]
+        map.insert(790, &[36864]);     // 790 => [36864]
+        map.insert(451, &[69632]);     // 451 => [69632]
+        map.insert(1129, &[135168]);     // 1129 => [135168]
+        map.insert(1394, &[266240]);     // 1394 => [266240]
+        map.insert(1861, &[528384]);     // 1861 => [528384]
+        map.insert(811, &[1052672]);     // 811 => [1052672]
+        map.insert(440, &[2101248]);     // 440 => [2101248]
+        map.insert(677, &[4198400]);     // 677 => [4198400]
+        map.insert(1426, &[24576]);     // 1426 => [24576]
+        map.insert(1272, &[40960]);     // 1272 => [40960]
+        map.insert(1581, &[73728]);     // 1581 => [73728]
+        map.insert(903, &[139264]);     // 903 => [139264]
+        map.insert(668, &[270336]);     // 668 => [270336]
+        map.insert(171, &[532480]);     // 171 => [532480]
+        map.insert(1221, &[1056768]);     // 1221 => [1056768]
+        map.insert(1622, &[2105344]);     // 1622 => [2105344]
+        map.insert(1355, &[4202496]);     // 1355 => [4202496]
+        map.insert(362, &[49152]);     // 362 => [49152]
+        map.insert(959, &[81920]);     // 959 => [81920]
+        map.insert(1557, &[147456]);     // 1557 => [147456]
+        map.insert(1806, &[278528]);     // 1806 => [278528]
+        map.insert(1337, &[540672]);     // 1337 => [540672]
+        map.insert(343, &[1064960]);     // 343 => [1064960]
+        map.insert(964, &[2113536]);     // 964 => [2113536]
+        map.insert(217, &[4210688]);     // 217 => [4210688]
+        map.insert(725, &[98304]);     // 725 => [98304]
+        map.insert(1919, &[163840]);     // 1919 => [163840]
+        map.insert(1636, &[294912]);     // 1636 => [294912]
+        map.insert(1107, &[557056]);     // 1107 => [557056]
+        map.insert(61, &[1081344]);     // 61 => [1081344]
+        map.insert(686, &[2129920]);     // 686 => [2129920]
+        map.insert(435, &[4227072]);     // 435 => [4227072]
+        map.insert(1450, &[196608]);     // 1450 => [196608]
+        map.insert(1201, &[327680]);     // 1201 => [327680]
+        map.insert(1670, &[589824]);     // 1670 => [589824]
+        map.insert(744, &[1114112]);     // 744 => [1114112]
+        map.insert(123, &[2162688]);     // 123 => [2162688]
+        map.insert(870, &[4259840]);     // 870 => [4259840]
+        map.insert(283, &[393216]);     // 283 => [393216]
+        map.insert(812, &[655360]);     // 812 => [655360]
+        map.insert(1858, &[1179648]);     // 1858 => [1179648]
+        map.insert(1489, &[2228224]);     // 1489 => [2228224]
+        map.insert(1740, &[4325376]);     // 1740 => [4325376]
+        map.insert(567, &[786432]);     // 567 => [786432]
+        map.insert(1625, &[1310720]);     // 1625 => [1310720]
+        map.insert(1226, &[2359296]);     // 1226 => [2359296]
+        map.insert(2007, &[4456448]);     // 2007 => [4456448]
+        map.insert(1134, &[1572864]);     // 1134 => [1572864]
+        map.insert(1789, &[2621440]);     // 1789 => [2621440]
+        map.insert(1504, &[4718592]);     // 1504 => [4718592]
+        map.insert(659, &[3145728]);     // 659 => [3145728]
+        map.insert(398, &[5242880]);     // 398 => [5242880]
+        map.insert(797, &[6291456]);     // 797 => [6291456]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(19, &[19]);     // 19 => [19]
+        map.insert(35, &[35]);     // 35 => [35]
+        map.insert(67, &[67]);     // 67 => [67]
+        map.insert(131, &[131]);     // 131 => [131]
+        map.insert(259, &[259]);     // 259 => [259]
+        map.insert(515, &[515]);     // 515 => [515]
+        map.insert(1027, &[1027]);     // 1027 => [1027]
+        map.insert(588, &[2051]);     // 588 => [2051]
+        map.insert(1180, &[4099]);     // 1180 => [4099]
+        map.insert(882, &[8195]);     // 882 => [8195]
+        map.insert(1760, &[16387]);     // 1760 => [16387]
+        map.insert(1930, &[32771]);     // 1930 => [32771]
+        map.insert(1375, &[65539]);     // 1375 => [65539]
+        map.insert(245, &[131075]);     // 245 => [131075]
+        map.insert(494, &[262147]);     // 494 => [262147]
+        map.insert(985, &[524291]);     // 985 => [524291]
+        map.insert(1975, &[1048579]);     // 1975 => [1048579]
+        map.insert(1316, &[2097155]);     // 1316 => [2097155]
+        map.insert(1593, &[4194307]);     // 1593 => [4194307]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(69, &[69]);     // 69 => [69]
+        map.insert(133, &[133]);     // 133 => [133]
+        map.insert(261, &[261]);     // 261 => [261]
+        map.insert(517, &[517]);     // 517 => [517]
+        map.insert(1029, &[1029]);     // 1029 => [1029]
+        map.insert(586, &[2053]);     // 586 => [2053]
+        map.insert(1178, &[4101]);     // 1178 => [4101]
+        map.insert(884, &[8197]);     // 884 => [8197]
+        map.insert(1766, &[16389]);     // 1766 => [16389]
+        map.insert(1932, &[32773]);     // 1932 => [32773]
+        map.insert(1369, &[65541]);     // 1369 => [65541]
+        map.insert(243, &[131077]);     // 243 => [131077]
+        map.insert(488, &[262149]);     // 488 => [262149]
+        map.insert(991, &[524293]);     // 991 => [524293]
+        map.insert(1969, &[1048581]);     // 1969 => [1048581]
+        map.insert(1314, &[2097157]);     // 1314 => [2097157]
+        map.insert(1599, &[4194309]);     // 1599 => [4194309]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(41, &[41]);     // 41 => [41]
+        map.insert(73, &[73]);     // 73 => [73]
+        map.insert(137, &[137]);     // 137 => [137]
+        map.insert(265, &[265]);     // 265 => [265]
+        map.insert(521, &[521]);     // 521 => [521]
+        map.insert(1033, &[1033]);     // 1033 => [1033]
+        map.insert(582, &[2057]);     // 582 => [2057]
+        map.insert(1174, &[4105]);     // 1174 => [4105]
+        map.insert(888, &[8201]);     // 888 => [8201]
+        map.insert(1770, &[16393]);     // 1770 => [16393]
+        map.insert(1920, &[32777]);     // 1920 => [32777]
+        map.insert(1365, &[65545]);     // 1365 => [65545]
+        map.insert(255, &[131081]);     // 255 => [131081]
+        map.insert(484, &[262153]);     // 484 => [262153]
+        map.insert(979, &[524297]);     // 979 => [524297]
+        map.insert(1981, &[1048585]);     // 1981 => [1048585]
+        map.insert(1326, &[2097161]);     // 1326 => [2097161]
+        map.insert(1587, &[4194313]);     // 1587 => [4194313]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(81, &[81]);     // 81 => [81]
+        map.insert(145, &[145]);     // 145 => [145]
+        map.insert(273, &[273]);     // 273 => [273]
+        map.insert(529, &[529]);     // 529 => [529]
+        map.insert(1041, &[1041]);     // 1041 => [1041]
+        map.insert(606, &[2065]);     // 606 => [2065]
+        map.insert(1166, &[4113]);     // 1166 => [4113]
+        map.insert(864, &[8209]);     // 864 => [8209]
+        map.insert(1778, &[16401]);     // 1778 => [16401]
+        map.insert(1944, &[32785]);     // 1944 => [32785]
+        map.insert(1357, &[65553]);     // 1357 => [65553]
+        map.insert(231, &[131089]);     // 231 => [131089]
+        map.insert(508, &[262161]);     // 508 => [262161]
+        map.insert(971, &[524305]);     // 971 => [524305]
+        map.insert(1957, &[1048593]);     // 1957 => [1048593]
+        map.insert(1334, &[2097169]);     // 1334 => [2097169]
+        map.insert(1579, &[4194321]);     // 1579 => [4194321]
+        map.insert(97, &[97]);     // 97 => [97]
+        map.insert(161, &[161]);     // 161 => [161]
+        map.insert(289, &[289]);     // 289 => [289]
+        map.insert(545, &[545]);     // 545 => [545]
+        map.insert(1057, &[1057]);     // 1057 => [1057]
+        map.insert(622, &[2081]);     // 622 => [2081]
+        map.insert(1214, &[4129]);     // 1214 => [4129]
+        map.insert(848, &[8225]);     // 848 => [8225]
+        map.insert(1730, &[16417]);     // 1730 => [16417]
+        map.insert(1960, &[32801]);     // 1960 => [32801]
+        map.insert(1405, &[65569]);     // 1405 => [65569]
+        map.insert(215, &[131105]);     // 215 => [131105]
+        map.insert(460, &[262177]);     // 460 => [262177]
+        map.insert(1019, &[524321]);     // 1019 => [524321]
+        map.insert(1941, &[1048609]);     // 1941 => [1048609]
+        map.insert(1286, &[2097185]);     // 1286 => [2097185]
+        map.insert(1563, &[4194337]);     // 1563 => [4194337]
+        map.insert(193, &[193]);     // 193 => [193]
+        map.insert(321, &[321]);     // 321 => [321]
+        map.insert(577, &[577]);     // 577 => [577]
+        map.insert(1089, &[1089]);     // 1089 => [1089]
+        map.insert(526, &[2113]);     // 526 => [2113]
+        map.insert(1246, &[4161]);     // 1246 => [4161]
+        map.insert(816, &[8257]);     // 816 => [8257]
+        map.insert(1698, &[16449]);     // 1698 => [16449]
+        map.insert(1992, &[32833]);     // 1992 => [32833]
+        map.insert(1309, &[65601]);     // 1309 => [65601]
+        map.insert(183, &[131137]);     // 183 => [131137]
+        map.insert(428, &[262209]);     // 428 => [262209]
+        map.insert(923, &[524353]);     // 923 => [524353]
+        map.insert(2037, &[1048641]);     // 2037 => [1048641]
+        map.insert(1382, &[2097217]);     // 1382 => [2097217]
+        map.insert(1659, &[4194369]);     // 1659 => [4194369]
+        map.insert(385, &[385]);     // 385 => [385]
+        map.insert(641, &[641]);     // 641 => [641]
+        map.insert(1153, &[1153]);     // 1153 => [1153]
+        map.insert(718, &[2177]);     // 718 => [2177]
+        map.insert(1054, &[4225]);     // 1054 => [4225]
+        map.insert(1008, &[8321]);     // 1008 => [8321]
+        map.insert(1634, &[16513]);     // 1634 => [16513]
+        map.insert(1800, &[32897]);     // 1800 => [32897]
+        map.insert(1501, &[65665]);     // 1501 => [65665]
+        map.insert(119, &[131201]);     // 119 => [131201]
+        map.insert(364, &[262273]);     // 364 => [262273]
+        map.insert(859, &[524417]);     // 859 => [524417]
+        map.insert(1845, &[1048705]);     // 1845 => [1048705]
+        map.insert(1446, &[2097281]);     // 1446 => [2097281]
+        map.insert(1723, &[4194433]);     // 1723 => [4194433]
+        map.insert(769, &[769]);     // 769 => [769]
+        map.insert(1281, &[1281]);     // 1281 => [1281]
+        map.insert(846, &[2305]);     // 846 => [2305]
+        map.insert(1438, &[4353]);     // 1438 => [4353]
+        map.insert(624, &[8449]);     // 624 => [8449]
+        map.insert(2018, &[16641]);     // 2018 => [16641]
+        map.insert(1672, &[33025]);     // 1672 => [33025]
+        map.insert(1117, &[65793]);     // 1117 => [65793]
+        map.insert(503, &[131329]);     // 503 => [131329]
+        map.insert(236, &[262401]);     // 236 => [262401]
+        map.insert(731, &[524545]);     // 731 => [524545]
+        map.insert(1717, &[1048833]);     // 1717 => [1048833]
+        map.insert(1062, &[2097409]);     // 1062 => [2097409]
+        map.insert(1851, &[4194561]);     // 1851 => [4194561]
+        map.insert(1537, &[1537]);     // 1537 => [1537]
+        map.insert(78, &[2561]);     // 78 => [2561]
+        map.insert(1694, &[4609]);     // 1694 => [4609]
+        map.insert(368, &[8705]);     // 368 => [8705]
+        map.insert(1250, &[16897]);     // 1250 => [16897]
+        map.insert(1416, &[33281]);     // 1416 => [33281]
+        map.insert(1885, &[66049]);     // 1885 => [66049]
+        map.insert(759, &[131585]);     // 759 => [131585]
+        map.insert(1004, &[262657]);     // 1004 => [262657]
+        map.insert(475, &[524801]);     // 475 => [524801]
+        map.insert(1461, &[1049089]);     // 1461 => [1049089]
+        map.insert(1830, &[2097665]);     // 1830 => [2097665]
+        map.insert(1083, &[4194817]);     // 1083 => [4194817]
+        map.insert(1614, &[3073]);     // 1614 => [3073]
+        map.insert(158, &[5121]);     // 158 => [5121]
+        map.insert(1904, &[9217]);     // 1904 => [9217]
+        map.insert(738, &[17409]);     // 738 => [17409]
+        map.insert(904, &[33793]);     // 904 => [33793]
+        map.insert(349, &[66561]);     // 349 => [66561]
+        map.insert(1271, &[132097]);     // 1271 => [132097]
+        map.insert(1516, &[263169]);     // 1516 => [263169]
+        map.insert(2011, &[525313]);     // 2011 => [525313]
+        map.insert(949, &[1049601]);     // 949 => [1049601]
+        map.insert(294, &[2098177]);     // 294 => [2098177]
+        map.insert(571, &[4195329]);     // 571 => [4195329]
+        map.insert(1745, &[6145]);     // 1745 => [6145]
+        map.insert(319, &[10241]);     // 319 => [10241]
+        map.insert(1197, &[18433]);     // 1197 => [18433]
+        map.insert(1479, &[34817]);     // 1479 => [34817]
+        map.insert(1810, &[67585]);     // 1810 => [67585]
+        map.insert(696, &[133121]);     // 696 => [133121]
+        map.insert(931, &[264193]);     // 931 => [264193]
+        map.insert(404, &[526337]);     // 404 => [526337]
+        map.insert(1530, &[1050625]);     // 1530 => [1050625]
+        map.insert(1897, &[2099201]);     // 1897 => [2099201]
+        map.insert(1140, &[4196353]);     // 1140 => [4196353]
+        map.insert(2031, &[12289]);     // 2031 => [12289]
+        map.insert(637, &[20481]);     // 637 => [20481]
+        map.insert(791, &[36865]);     // 791 => [36865]
+        map.insert(450, &[69633]);     // 450 => [69633]
+        map.insert(1128, &[135169]);     // 1128 => [135169]
+        map.insert(1395, &[266241]);     // 1395 => [266241]
+        map.insert(1860, &[528385]);     // 1860 => [528385]
+        map.insert(810, &[1052673]);     // 810 => [1052673]
+        map.insert(441, &[2101249]);     // 441 => [2101249]
+        map.insert(676, &[4198401]);     // 676 => [4198401]
+        map.insert(1427, &[24577]);     // 1427 => [24577]
+        map.insert(1273, &[40961]);     // 1273 => [40961]
+        map.insert(1580, &[73729]);     // 1580 => [73729]
+        map.insert(902, &[139265]);     // 902 => [139265]
+        map.insert(669, &[270337]);     // 669 => [270337]
+        map.insert(170, &[532481]);     // 170 => [532481]
+        map.insert(1220, &[1056769]);     // 1220 => [1056769]
+        map.insert(1623, &[2105345]);     // 1623 => [2105345]
+        map.insert(1354, &[4202497]);     // 1354 => [4202497]
+        map.insert(363, &[49153]);     // 363 => [49153]
+        map.insert(958, &[81921]);     // 958 => [81921]
+        map.insert(1556, &[147457]);     // 1556 => [147457]
+        map.insert(1807, &[278529]);     // 1807 => [278529]
+        map.insert(1336, &[540673]);     // 1336 => [540673]
+        map.insert(342, &[1064961]);     // 342 => [1064961]
+        map.insert(965, &[2113537]);     // 965 => [2113537]
+        map.insert(216, &[4210689]);     // 216 => [4210689]
+        map.insert(724, &[98305]);     // 724 => [98305]
+        map.insert(1918, &[163841]);     // 1918 => [163841]
+        map.insert(1637, &[294913]);     // 1637 => [294913]
+        map.insert(1106, &[557057]);     // 1106 => [557057]
+        map.insert(60, &[1081345]);     // 60 => [1081345]
+        map.insert(687, &[2129921]);     // 687 => [2129921]
+        map.insert(434, &[4227073]);     // 434 => [4227073]
+        map.insert(1451, &[196609]);     // 1451 => [196609]
+        map.insert(1200, &[327681]);     // 1200 => [327681]
+        map.insert(1671, &[589825]);     // 1671 => [589825]
+        map.insert(745, &[1114113]);     // 745 => [1114113]
+        map.insert(122, &[2162689]);     // 122 => [2162689]
+        map.insert(871, &[4259841]);     // 871 => [4259841]
+        map.insert(282, &[393217]);     // 282 => [393217]
+        map.insert(813, &[655361]);     // 813 => [655361]
+        map.insert(1859, &[1179649]);     // 1859 => [1179649]
+        map.insert(1488, &[2228225]);     // 1488 => [2228225]
+        map.insert(1741, &[4325377]);     // 1741 => [4325377]
+        map.insert(566, &[786433]);     // 566 => [786433]
+        map.insert(1624, &[1310721]);     // 1624 => [1310721]
+        map.insert(1227, &[2359297]);     // 1227 => [2359297]
+        map.insert(2006, &[4456449]);     // 2006 => [4456449]
+        map.insert(1135, &[1572865]);     // 1135 => [1572865]
+        map.insert(1788, &[2621441]);     // 1788 => [2621441]
+        map.insert(1505, &[4718593]);     // 1505 => [4718593]
+        map.insert(658, &[3145729]);     // 658 => [3145729]
+        map.insert(399, &[5242881]);     // 399 => [5242881]
+        map.insert(796, &[6291457]);     // 796 => [6291457]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(70, &[70]);     // 70 => [70]
+        map.insert(134, &[134]);     // 134 => [134]
+        map.insert(262, &[262]);     // 262 => [262]
+        map.insert(518, &[518]);     // 518 => [518]
+        map.insert(1030, &[1030]);     // 1030 => [1030]
+        map.insert(585, &[2054]);     // 585 => [2054]
+        map.insert(1177, &[4102]);     // 1177 => [4102]
+        map.insert(887, &[8198]);     // 887 => [8198]
+        map.insert(1765, &[16390]);     // 1765 => [16390]
+        map.insert(1935, &[32774]);     // 1935 => [32774]
+        map.insert(1370, &[65542]);     // 1370 => [65542]
+        map.insert(240, &[131078]);     // 240 => [131078]
+        map.insert(491, &[262150]);     // 491 => [262150]
+        map.insert(988, &[524294]);     // 988 => [524294]
+        map.insert(1970, &[1048582]);     // 1970 => [1048582]
+        map.insert(1313, &[2097158]);     // 1313 => [2097158]
+        map.insert(1596, &[4194310]);     // 1596 => [4194310]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(42, &[42]);     // 42 => [42]
+        map.insert(74, &[74]);     // 74 => [74]
+        map.insert(138, &[138]);     // 138 => [138]
+        map.insert(266, &[266]);     // 266 => [266]
+        map.insert(522, &[522]);     // 522 => [522]
+        map.insert(1034, &[1034]);     // 1034 => [1034]
+        map.insert(581, &[2058]);     // 581 => [2058]
+        map.insert(1173, &[4106]);     // 1173 => [4106]
+        map.insert(891, &[8202]);     // 891 => [8202]
+        map.insert(1769, &[16394]);     // 1769 => [16394]
+        map.insert(1923, &[32778]);     // 1923 => [32778]
+        map.insert(1366, &[65546]);     // 1366 => [65546]
+        map.insert(252, &[131082]);     // 252 => [131082]
+        map.insert(487, &[262154]);     // 487 => [262154]
+        map.insert(976, &[524298]);     // 976 => [524298]
+        map.insert(1982, &[1048586]);     // 1982 => [1048586]
+        map.insert(1325, &[2097162]);     // 1325 => [2097162]
+        map.insert(1584, &[4194314]);     // 1584 => [4194314]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(82, &[82]);     // 82 => [82]
+        map.insert(146, &[146]);     // 146 => [146]
+        map.insert(274, &[274]);     // 274 => [274]
+        map.insert(530, &[530]);     // 530 => [530]
+        map.insert(1042, &[1042]);     // 1042 => [1042]
+        map.insert(605, &[2066]);     // 605 => [2066]
+        map.insert(1165, &[4114]);     // 1165 => [4114]
+        map.insert(867, &[8210]);     // 867 => [8210]
+        map.insert(1777, &[16402]);     // 1777 => [16402]
+        map.insert(1947, &[32786]);     // 1947 => [32786]
+        map.insert(1358, &[65554]);     // 1358 => [65554]
+        map.insert(228, &[131090]);     // 228 => [131090]
+        map.insert(511, &[262162]);     // 511 => [262162]
+        map.insert(968, &[524306]);     // 968 => [524306]
+        map.insert(1958, &[1048594]);     // 1958 => [1048594]
+        map.insert(1333, &[2097170]);     // 1333 => [2097170]
+        map.insert(1576, &[4194322]);     // 1576 => [4194322]
+        map.insert(98, &[98]);     // 98 => [98]
+        map.insert(162, &[162]);     // 162 => [162]
+        map.insert(290, &[290]);     // 290 => [290]
+        map.insert(546, &[546]);     // 546 => [546]
+        map.insert(1058, &[1058]);     // 1058 => [1058]
+        map.insert(621, &[2082]);     // 621 => [2082]
+        map.insert(1213, &[4130]);     // 1213 => [4130]
+        map.insert(851, &[8226]);     // 851 => [8226]
+        map.insert(1729, &[16418]);     // 1729 => [16418]
+        map.insert(1963, &[32802]);     // 1963 => [32802]
+        map.insert(1406, &[65570]);     // 1406 => [65570]
+        map.insert(212, &[131106]);     // 212 => [131106]
+        map.insert(463, &[262178]);     // 463 => [262178]
+        map.insert(1016, &[524322]);     // 1016 => [524322]
+        map.insert(1942, &[1048610]);     // 1942 => [1048610]
+        map.insert(1285, &[2097186]);     // 1285 => [2097186]
+        map.insert(1560, &[4194338]);     // 1560 => [4194338]
+        map.insert(194, &[194]);     // 194 => [194]
+        map.insert(322, &[322]);     // 322 => [322]
+        map.insert(578, &[578]);     // 578 => [578]
+        map.insert(1090, &[1090]);     // 1090 => [1090]
+        map.insert(525, &[2114]);     // 525 => [2114]
+        map.insert(1245, &[4162]);     // 1245 => [4162]
+        map.insert(819, &[8258]);     // 819 => [8258]
+        map.insert(1697, &[16450]);     // 1697 => [16450]
+        map.insert(1995, &[32834]);     // 1995 => [32834]
+        map.insert(1310, &[65602]);     // 1310 => [65602]
+        map.insert(180, &[131138]);     // 180 => [131138]
+        map.insert(431, &[262210]);     // 431 => [262210]
+        map.insert(920, &[524354]);     // 920 => [524354]
+        map.insert(2038, &[1048642]);     // 2038 => [1048642]
+        map.insert(1381, &[2097218]);     // 1381 => [2097218]
+        map.insert(1656, &[4194370]);     // 1656 => [4194370]
+        map.insert(386, &[386]);     // 386 => [386]
+        map.insert(642, &[642]);     // 642 => [642]
+        map.insert(1154, &[1154]);     // 1154 => [1154]
+        map.insert(717, &[2178]);     // 717 => [2178]
+        map.insert(1053, &[4226]);     // 1053 => [4226]
+        map.insert(1011, &[8322]);     // 1011 => [8322]
+        map.insert(1633, &[16514]);     // 1633 => [16514]
+        map.insert(1803, &[32898]);     // 1803 => [32898]
+        map.insert(1502, &[65666]);     // 1502 => [65666]
+        map.insert(116, &[131202]);     // 116 => [131202]
+        map.insert(367, &[262274]);     // 367 => [262274]
+        map.insert(856, &[524418]);     // 856 => [524418]
+        map.insert(1846, &[1048706]);     // 1846 => [1048706]
+        map.insert(1445, &[2097282]);     // 1445 => [2097282]
+        map.insert(1720, &[4194434]);     // 1720 => [4194434]
+        map.insert(770, &[770]);     // 770 => [770]
+        map.insert(1282, &[1282]);     // 1282 => [1282]
+        map.insert(845, &[2306]);     // 845 => [2306]
+        map.insert(1437, &[4354]);     // 1437 => [4354]
+        map.insert(627, &[8450]);     // 627 => [8450]
+        map.insert(2017, &[16642]);     // 2017 => [16642]
+        map.insert(1675, &[33026]);     // 1675 => [33026]
+        map.insert(1118, &[65794]);     // 1118 => [65794]
+        map.insert(500, &[131330]);     // 500 => [131330]
+        map.insert(239, &[262402]);     // 239 => [262402]
+        map.insert(728, &[524546]);     // 728 => [524546]
+        map.insert(1718, &[1048834]);     // 1718 => [1048834]
+        map.insert(1061, &[2097410]);     // 1061 => [2097410]
+        map.insert(1848, &[4194562]);     // 1848 => [4194562]
+        map.insert(1538, &[1538]);     // 1538 => [1538]
+        map.insert(77, &[2562]);     // 77 => [2562]
+        map.insert(1693, &[4610]);     // 1693 => [4610]
+        map.insert(371, &[8706]);     // 371 => [8706]
+        map.insert(1249, &[16898]);     // 1249 => [16898]
+        map.insert(1419, &[33282]);     // 1419 => [33282]
+        map.insert(1886, &[66050]);     // 1886 => [66050]
+        map.insert(756, &[131586]);     // 756 => [131586]
+        map.insert(1007, &[262658]);     // 1007 => [262658]
+        map.insert(472, &[524802]);     // 472 => [524802]
+        map.insert(1462, &[1049090]);     // 1462 => [1049090]
+        map.insert(1829, &[2097666]);     // 1829 => [2097666]
+        map.insert(1080, &[4194818]);     // 1080 => [4194818]
+        map.insert(1613, &[3074]);     // 1613 => [3074]
+        map.insert(157, &[5122]);     // 157 => [5122]
+        map.insert(1907, &[9218]);     // 1907 => [9218]
+        map.insert(737, &[17410]);     // 737 => [17410]
+        map.insert(907, &[33794]);     // 907 => [33794]
+        map.insert(350, &[66562]);     // 350 => [66562]
+        map.insert(1268, &[132098]);     // 1268 => [132098]
+        map.insert(1519, &[263170]);     // 1519 => [263170]
+        map.insert(2008, &[525314]);     // 2008 => [525314]
+        map.insert(950, &[1049602]);     // 950 => [1049602]
+        map.insert(293, &[2098178]);     // 293 => [2098178]
+        map.insert(568, &[4195330]);     // 568 => [4195330]
+        map.insert(1746, &[6146]);     // 1746 => [6146]
+        map.insert(316, &[10242]);     // 316 => [10242]
+        map.insert(1198, &[18434]);     // 1198 => [18434]
+        map.insert(1476, &[34818]);     // 1476 => [34818]
+        map.insert(1809, &[67586]);     // 1809 => [67586]
+        map.insert(699, &[133122]);     // 699 => [133122]
+        map.insert(928, &[264194]);     // 928 => [264194]
+        map.insert(407, &[526338]);     // 407 => [526338]
+        map.insert(1529, &[1050626]);     // 1529 => [1050626]
+        map.insert(1898, &[2099202]);     // 1898 => [2099202]
+        map.insert(1143, &[4196354]);     // 1143 => [4196354]
+        map.insert(2028, &[12290]);     // 2028 => [12290]
+        map.insert(638, &[20482]);     // 638 => [20482]
+        map.insert(788, &[36866]);     // 788 => [36866]
+        map.insert(449, &[69634]);     // 449 => [69634]
+        map.insert(1131, &[135170]);     // 1131 => [135170]
+        map.insert(1392, &[266242]);     // 1392 => [266242]
+        map.insert(1863, &[528386]);     // 1863 => [528386]
+        map.insert(809, &[1052674]);     // 809 => [1052674]
+        map.insert(442, &[2101250]);     // 442 => [2101250]
+        map.insert(679, &[4198402]);     // 679 => [4198402]
+        map.insert(1424, &[24578]);     // 1424 => [24578]
+        map.insert(1274, &[40962]);     // 1274 => [40962]
+        map.insert(1583, &[73730]);     // 1583 => [73730]
+        map.insert(901, &[139266]);     // 901 => [139266]
+        map.insert(670, &[270338]);     // 670 => [270338]
+        map.insert(169, &[532482]);     // 169 => [532482]
+        map.insert(1223, &[1056770]);     // 1223 => [1056770]
+        map.insert(1620, &[2105346]);     // 1620 => [2105346]
+        map.insert(1353, &[4202498]);     // 1353 => [4202498]
+        map.insert(360, &[49154]);     // 360 => [49154]
+        map.insert(957, &[81922]);     // 957 => [81922]
+        map.insert(1559, &[147458]);     // 1559 => [147458]
+        map.insert(1804, &[278530]);     // 1804 => [278530]
+        map.insert(1339, &[540674]);     // 1339 => [540674]
+        map.insert(341, &[1064962]);     // 341 => [1064962]
+        map.insert(966, &[2113538]);     // 966 => [2113538]
+        map.insert(219, &[4210690]);     // 219 => [4210690]
+        map.insert(727, &[98306]);     // 727 => [98306]
+        map.insert(1917, &[163842]);     // 1917 => [163842]
+        map.insert(1638, &[294914]);     // 1638 => [294914]
+        map.insert(1105, &[557058]);     // 1105 => [557058]
+        map.insert(63, &[1081346]);     // 63 => [1081346]
+        map.insert(684, &[2129922]);     // 684 => [2129922]
+        map.insert(433, &[4227074]);     // 433 => [4227074]
+        map.insert(1448, &[196610]);     // 1448 => [196610]
+        map.insert(1203, &[327682]);     // 1203 => [327682]
+        map.insert(1668, &[589826]);     // 1668 => [589826]
+        map.insert(746, &[1114114]);     // 746 => [1114114]
+        map.insert(121, &[2162690]);     // 121 => [2162690]
+        map.insert(868, &[4259842]);     // 868 => [4259842]
+        map.insert(281, &[393218]);     // 281 => [393218]
+        map.insert(814, &[655362]);     // 814 => [655362]
+        map.insert(1856, &[1179650]);     // 1856 => [1179650]
+        map.insert(1491, &[2228226]);     // 1491 => [2228226]
+        map.insert(1742, &[4325378]);     // 1742 => [4325378]
+        map.insert(565, &[786434]);     // 565 => [786434]
+        map.insert(1627, &[1310722]);     // 1627 => [1310722]
+        map.insert(1224, &[2359298]);     // 1224 => [2359298]
+        map.insert(2005, &[4456450]);     // 2005 => [4456450]
+        map.insert(1132, &[1572866]);     // 1132 => [1572866]
+        map.insert(1791, &[2621442]);     // 1791 => [2621442]
+        map.insert(1506, &[4718594]);     // 1506 => [4718594]
+        map.insert(657, &[3145730]);     // 657 => [3145730]
+        map.insert(396, &[5242882]);     // 396 => [5242882]
+        map.insert(799, &[6291458]);     // 799 => [6291458]
+        map.insert(28, &[28]);     // 28 => [28]
+        map.insert(44, &[44]);     // 44 => [44]
+        map.insert(76, &[76]);     // 76 => [76]
+        map.insert(140, &[140]);     // 140 => [140]
+        map.insert(268, &[268]);     // 268 => [268]
+        map.insert(524, &[524]);     // 524 => [524]
+        map.insert(1036, &[1036]);     // 1036 => [1036]
+        map.insert(579, &[2060]);     // 579 => [2060]
+        map.insert(1171, &[4108]);     // 1171 => [4108]
+        map.insert(893, &[8204]);     // 893 => [8204]
+        map.insert(1775, &[16396]);     // 1775 => [16396]
+        map.insert(1925, &[32780]);     // 1925 => [32780]
+        map.insert(1360, &[65548]);     // 1360 => [65548]
+        map.insert(250, &[131084]);     // 250 => [131084]
+        map.insert(481, &[262156]);     // 481 => [262156]
+        map.insert(982, &[524300]);     // 982 => [524300]
+        map.insert(1976, &[1048588]);     // 1976 => [1048588]
+        map.insert(1323, &[2097164]);     // 1323 => [2097164]
+        map.insert(1590, &[4194316]);     // 1590 => [4194316]
+        map.insert(52, &[52]);     // 52 => [52]
+        map.insert(84, &[84]);     // 84 => [84]
+        map.insert(148, &[148]);     // 148 => [148]
+        map.insert(276, &[276]);     // 276 => [276]
+        map.insert(532, &[532]);     // 532 => [532]
+        map.insert(1044, &[1044]);     // 1044 => [1044]
+        map.insert(603, &[2068]);     // 603 => [2068]
+        map.insert(1163, &[4116]);     // 1163 => [4116]
+        map.insert(869, &[8212]);     // 869 => [8212]
+        map.insert(1783, &[16404]);     // 1783 => [16404]
+        map.insert(1949, &[32788]);     // 1949 => [32788]
+        map.insert(1352, &[65556]);     // 1352 => [65556]
+        map.insert(226, &[131092]);     // 226 => [131092]
+        map.insert(505, &[262164]);     // 505 => [262164]
+        map.insert(974, &[524308]);     // 974 => [524308]
+        map.insert(1952, &[1048596]);     // 1952 => [1048596]
+        map.insert(1331, &[2097172]);     // 1331 => [2097172]
+        map.insert(1582, &[4194324]);     // 1582 => [4194324]
+        map.insert(100, &[100]);     // 100 => [100]
+        map.insert(164, &[164]);     // 164 => [164]
+        map.insert(292, &[292]);     // 292 => [292]
+        map.insert(548, &[548]);     // 548 => [548]
+        map.insert(1060, &[1060]);     // 1060 => [1060]
+        map.insert(619, &[2084]);     // 619 => [2084]
+        map.insert(1211, &[4132]);     // 1211 => [4132]
+        map.insert(853, &[8228]);     // 853 => [8228]
+        map.insert(1735, &[16420]);     // 1735 => [16420]
+        map.insert(1965, &[32804]);     // 1965 => [32804]
+        map.insert(1400, &[65572]);     // 1400 => [65572]
+        map.insert(210, &[131108]);     // 210 => [131108]
+        map.insert(457, &[262180]);     // 457 => [262180]
+        map.insert(1022, &[524324]);     // 1022 => [524324]
+        map.insert(1936, &[1048612]);     // 1936 => [1048612]
+        map.insert(1283, &[2097188]);     // 1283 => [2097188]
+        map.insert(1566, &[4194340]);     // 1566 => [4194340]
+        map.insert(196, &[196]);     // 196 => [196]
+        map.insert(324, &[324]);     // 324 => [324]
+        map.insert(580, &[580]);     // 580 => [580]
+        map.insert(1092, &[1092]);     // 1092 => [1092]
+        map.insert(523, &[2116]);     // 523 => [2116]
+        map.insert(1243, &[4164]);     // 1243 => [4164]
+        map.insert(821, &[8260]);     // 821 => [8260]
+        map.insert(1703, &[16452]);     // 1703 => [16452]
+        map.insert(1997, &[32836]);     // 1997 => [32836]
+        map.insert(1304, &[65604]);     // 1304 => [65604]
+        map.insert(178, &[131140]);     // 178 => [131140]
+        map.insert(425, &[262212]);     // 425 => [262212]
+        map.insert(926, &[524356]);     // 926 => [524356]
+        map.insert(2032, &[1048644]);     // 2032 => [1048644]
+        map.insert(1379, &[2097220]);     // 1379 => [2097220]
+        map.insert(1662, &[4194372]);     // 1662 => [4194372]
+        map.insert(388, &[388]);     // 388 => [388]
+        map.insert(644, &[644]);     // 644 => [644]
+        map.insert(1156, &[1156]);     // 1156 => [1156]
+        map.insert(715, &[2180]);     // 715 => [2180]
+        map.insert(1051, &[4228]);     // 1051 => [4228]
+        map.insert(1013, &[8324]);     // 1013 => [8324]
+        map.insert(1639, &[16516]);     // 1639 => [16516]
+        map.insert(1805, &[32900]);     // 1805 => [32900]
+        map.insert(1496, &[65668]);     // 1496 => [65668]
+        map.insert(114, &[131204]);     // 114 => [131204]
+        map.insert(361, &[262276]);     // 361 => [262276]
+        map.insert(862, &[524420]);     // 862 => [524420]
+        map.insert(1840, &[1048708]);     // 1840 => [1048708]
+        map.insert(1443, &[2097284]);     // 1443 => [2097284]
+        map.insert(1726, &[4194436]);     // 1726 => [4194436]
+        map.insert(772, &[772]);     // 772 => [772]
+        map.insert(1284, &[1284]);     // 1284 => [1284]
+        map.insert(843, &[2308]);     // 843 => [2308]
+        map.insert(1435, &[4356]);     // 1435 => [4356]
+        map.insert(629, &[8452]);     // 629 => [8452]
+        map.insert(2023, &[16644]);     // 2023 => [16644]
+        map.insert(1677, &[33028]);     // 1677 => [33028]
+        map.insert(1112, &[65796]);     // 1112 => [65796]
+        map.insert(498, &[131332]);     // 498 => [131332]
+        map.insert(233, &[262404]);     // 233 => [262404]
+        map.insert(734, &[524548]);     // 734 => [524548]
+        map.insert(1712, &[1048836]);     // 1712 => [1048836]
+        map.insert(1059, &[2097412]);     // 1059 => [2097412]
+        map.insert(1854, &[4194564]);     // 1854 => [4194564]
+        map.insert(1540, &[1540]);     // 1540 => [1540]
+        map.insert(75, &[2564]);     // 75 => [2564]
+        map.insert(1691, &[4612]);     // 1691 => [4612]
+        map.insert(373, &[8708]);     // 373 => [8708]
+        map.insert(1255, &[16900]);     // 1255 => [16900]
+        map.insert(1421, &[33284]);     // 1421 => [33284]
+        map.insert(1880, &[66052]);     // 1880 => [66052]
+        map.insert(754, &[131588]);     // 754 => [131588]
+        map.insert(1001, &[262660]);     // 1001 => [262660]
+        map.insert(478, &[524804]);     // 478 => [524804]
+        map.insert(1456, &[1049092]);     // 1456 => [1049092]
+        map.insert(1827, &[2097668]);     // 1827 => [2097668]
+        map.insert(1086, &[4194820]);     // 1086 => [4194820]
+        map.insert(1611, &[3076]);     // 1611 => [3076]
+        map.insert(155, &[5124]);     // 155 => [5124]
+        map.insert(1909, &[9220]);     // 1909 => [9220]
+        map.insert(743, &[17412]);     // 743 => [17412]
+        map.insert(909, &[33796]);     // 909 => [33796]
+        map.insert(344, &[66564]);     // 344 => [66564]
+        map.insert(1266, &[132100]);     // 1266 => [132100]
+        map.insert(1513, &[263172]);     // 1513 => [263172]
+        map.insert(2014, &[525316]);     // 2014 => [525316]
+        map.insert(944, &[1049604]);     // 944 => [1049604]
+        map.insert(291, &[2098180]);     // 291 => [2098180]
+        map.insert(574, &[4195332]);     // 574 => [4195332]
+        map.insert(1748, &[6148]);     // 1748 => [6148]
+        map.insert(314, &[10244]);     // 314 => [10244]
+        map.insert(1192, &[18436]);     // 1192 => [18436]
+        map.insert(1474, &[34820]);     // 1474 => [34820]
+        map.insert(1815, &[67588]);     // 1815 => [67588]
+        map.insert(701, &[133124]);     // 701 => [133124]
+        map.insert(934, &[264196]);     // 934 => [264196]
+        map.insert(401, &[526340]);     // 401 => [526340]
+        map.insert(1535, &[1050628]);     // 1535 => [1050628]
+        map.insert(1900, &[2099204]);     // 1900 => [2099204]
+        map.insert(1137, &[4196356]);     // 1137 => [4196356]
+        map.insert(2026, &[12292]);     // 2026 => [12292]
+        map.insert(632, &[20484]);     // 632 => [20484]
+        map.insert(786, &[36868]);     // 786 => [36868]
+        map.insert(455, &[69636]);     // 455 => [69636]
+        map.insert(1133, &[135172]);     // 1133 => [135172]
+        map.insert(1398, &[266244]);     // 1398 => [266244]
+        map.insert(1857, &[528388]);     // 1857 => [528388]
+        map.insert(815, &[1052676]);     // 815 => [1052676]
+        map.insert(444, &[2101252]);     // 444 => [2101252]
+        map.insert(673, &[4198404]);     // 673 => [4198404]
+        map.insert(1430, &[24580]);     // 1430 => [24580]
+        map.insert(1276, &[40964]);     // 1276 => [40964]
+        map.insert(1577, &[73732]);     // 1577 => [73732]
+        map.insert(899, &[139268]);     // 899 => [139268]
+        map.insert(664, &[270340]);     // 664 => [270340]
+        map.insert(175, &[532484]);     // 175 => [532484]
+        map.insert(1217, &[1056772]);     // 1217 => [1056772]
+        map.insert(1618, &[2105348]);     // 1618 => [2105348]
+        map.insert(1359, &[4202500]);     // 1359 => [4202500]
+        map.insert(366, &[49156]);     // 366 => [49156]
+        map.insert(955, &[81924]);     // 955 => [81924]
+        map.insert(1553, &[147460]);     // 1553 => [147460]
+        map.insert(1802, &[278532]);     // 1802 => [278532]
+        map.insert(1341, &[540676]);     // 1341 => [540676]
+        map.insert(339, &[1064964]);     // 339 => [1064964]
+        map.insert(960, &[2113540]);     // 960 => [2113540]
+        map.insert(221, &[4210692]);     // 221 => [4210692]
+        map.insert(721, &[98308]);     // 721 => [98308]
+        map.insert(1915, &[163844]);     // 1915 => [163844]
+        map.insert(1632, &[294916]);     // 1632 => [294916]
+        map.insert(1111, &[557060]);     // 1111 => [557060]
+        map.insert(57, &[1081348]);     // 57 => [1081348]
+        map.insert(682, &[2129924]);     // 682 => [2129924]
+        map.insert(439, &[4227076]);     // 439 => [4227076]
+        map.insert(1454, &[196612]);     // 1454 => [196612]
+        map.insert(1205, &[327684]);     // 1205 => [327684]
+        map.insert(1666, &[589828]);     // 1666 => [589828]
+        map.insert(748, &[1114116]);     // 748 => [1114116]
+        map.insert(127, &[2162692]);     // 127 => [2162692]
+        map.insert(866, &[4259844]);     // 866 => [4259844]
+        map.insert(287, &[393220]);     // 287 => [393220]
+        map.insert(808, &[655364]);     // 808 => [655364]
+        map.insert(1862, &[1179652]);     // 1862 => [1179652]
+        map.insert(1493, &[2228228]);     // 1493 => [2228228]
+        map.insert(1736, &[4325380]);     // 1736 => [4325380]
+        map.insert(563, &[786436]);     // 563 => [786436]
+        map.insert(1629, &[1310724]);     // 1629 => [1310724]
+        map.insert(1230, &[2359300]);     // 1230 => [2359300]
+        map.insert(2003, &[4456452]);     // 2003 => [4456452]
+        map.insert(1130, &[1572868]);     // 1130 => [1572868]
+        map.insert(1785, &[2621444]);     // 1785 => [2621444]
+        map.insert(1508, &[4718596]);     // 1508 => [4718596]
+        map.insert(663, &[3145732]);     // 663 => [3145732]
+        map.insert(394, &[5242884]);     // 394 => [5242884]
+        map.insert(793, &[6291460]);     // 793 => [6291460]
+        map.insert(56, &[56]);     // 56 => [56]
+        map.insert(88, &[88]);     // 88 => [88]
+        map.insert(152, &[152]);     // 152 => [152]
+        map.insert(280, &[280]);     // 280 => [280]
+        map.insert(536, &[536]);     // 536 => [536]
+        map.insert(1048, &[1048]);     // 1048 => [1048]
+        map.insert(599, &[2072]);     // 599 => [2072]
+        map.insert(1159, &[4120]);     // 1159 => [4120]
+        map.insert(873, &[8216]);     // 873 => [8216]
+        map.insert(1787, &[16408]);     // 1787 => [16408]
+        map.insert(1937, &[32792]);     // 1937 => [32792]
+        map.insert(1348, &[65560]);     // 1348 => [65560]
+        map.insert(238, &[131096]);     // 238 => [131096]
+        map.insert(501, &[262168]);     // 501 => [262168]
+        map.insert(962, &[524312]);     // 962 => [524312]
+        map.insert(1964, &[1048600]);     // 1964 => [1048600]
+        map.insert(1343, &[2097176]);     // 1343 => [2097176]
+        map.insert(1570, &[4194328]);     // 1570 => [4194328]
+        map.insert(104, &[104]);     // 104 => [104]
+        map.insert(168, &[168]);     // 168 => [168]
+        map.insert(296, &[296]);     // 296 => [296]
+        map.insert(552, &[552]);     // 552 => [552]
+        map.insert(1064, &[1064]);     // 1064 => [1064]
+        map.insert(615, &[2088]);     // 615 => [2088]
+        map.insert(1207, &[4136]);     // 1207 => [4136]
+        map.insert(857, &[8232]);     // 857 => [8232]
+        map.insert(1739, &[16424]);     // 1739 => [16424]
+        map.insert(1953, &[32808]);     // 1953 => [32808]
+        map.insert(1396, &[65576]);     // 1396 => [65576]
+        map.insert(222, &[131112]);     // 222 => [131112]
+        map.insert(453, &[262184]);     // 453 => [262184]
+        map.insert(1010, &[524328]);     // 1010 => [524328]
+        map.insert(1948, &[1048616]);     // 1948 => [1048616]
+        map.insert(1295, &[2097192]);     // 1295 => [2097192]
+        map.insert(1554, &[4194344]);     // 1554 => [4194344]
+        map.insert(200, &[200]);     // 200 => [200]
+        map.insert(328, &[328]);     // 328 => [328]
+        map.insert(584, &[584]);     // 584 => [584]
+        map.insert(1096, &[1096]);     // 1096 => [1096]
+        map.insert(519, &[2120]);     // 519 => [2120]
+        map.insert(1239, &[4168]);     // 1239 => [4168]
+        map.insert(825, &[8264]);     // 825 => [8264]
+        map.insert(1707, &[16456]);     // 1707 => [16456]
+        map.insert(1985, &[32840]);     // 1985 => [32840]
+        map.insert(1300, &[65608]);     // 1300 => [65608]
+        map.insert(190, &[131144]);     // 190 => [131144]
+        map.insert(421, &[262216]);     // 421 => [262216]
+        map.insert(914, &[524360]);     // 914 => [524360]
+        map.insert(2044, &[1048648]);     // 2044 => [1048648]
+        map.insert(1391, &[2097224]);     // 1391 => [2097224]
+        map.insert(1650, &[4194376]);     // 1650 => [4194376]
+        map.insert(392, &[392]);     // 392 => [392]
+        map.insert(648, &[648]);     // 648 => [648]
+        map.insert(1160, &[1160]);     // 1160 => [1160]
+        map.insert(711, &[2184]);     // 711 => [2184]
+        map.insert(1047, &[4232]);     // 1047 => [4232]
+        map.insert(1017, &[8328]);     // 1017 => [8328]
+        map.insert(1643, &[16520]);     // 1643 => [16520]
+        map.insert(1793, &[32904]);     // 1793 => [32904]
+        map.insert(1492, &[65672]);     // 1492 => [65672]
+        map.insert(126, &[131208]);     // 126 => [131208]
+        map.insert(357, &[262280]);     // 357 => [262280]
+        map.insert(850, &[524424]);     // 850 => [524424]
+        map.insert(1852, &[1048712]);     // 1852 => [1048712]
+        map.insert(1455, &[2097288]);     // 1455 => [2097288]
+        map.insert(1714, &[4194440]);     // 1714 => [4194440]
+        map.insert(776, &[776]);     // 776 => [776]
+        map.insert(1288, &[1288]);     // 1288 => [1288]
+        map.insert(839, &[2312]);     // 839 => [2312]
+        map.insert(1431, &[4360]);     // 1431 => [4360]
+        map.insert(633, &[8456]);     // 633 => [8456]
+        map.insert(2027, &[16648]);     // 2027 => [16648]
+        map.insert(1665, &[33032]);     // 1665 => [33032]
+        map.insert(1108, &[65800]);     // 1108 => [65800]
+        map.insert(510, &[131336]);     // 510 => [131336]
+        map.insert(229, &[262408]);     // 229 => [262408]
+        map.insert(722, &[524552]);     // 722 => [524552]
+        map.insert(1724, &[1048840]);     // 1724 => [1048840]
+        map.insert(1071, &[2097416]);     // 1071 => [2097416]
+        map.insert(1842, &[4194568]);     // 1842 => [4194568]
+        map.insert(1544, &[1544]);     // 1544 => [1544]
+        map.insert(71, &[2568]);     // 71 => [2568]
+        map.insert(1687, &[4616]);     // 1687 => [4616]
+        map.insert(377, &[8712]);     // 377 => [8712]
+        map.insert(1259, &[16904]);     // 1259 => [16904]
+        map.insert(1409, &[33288]);     // 1409 => [33288]
+        map.insert(1876, &[66056]);     // 1876 => [66056]
+        map.insert(766, &[131592]);     // 766 => [131592]
+        map.insert(997, &[262664]);     // 997 => [262664]
+        map.insert(466, &[524808]);     // 466 => [524808]
+        map.insert(1468, &[1049096]);     // 1468 => [1049096]
+        map.insert(1839, &[2097672]);     // 1839 => [2097672]
+        map.insert(1074, &[4194824]);     // 1074 => [4194824]
+        map.insert(1607, &[3080]);     // 1607 => [3080]
+        map.insert(151, &[5128]);     // 151 => [5128]
+        map.insert(1913, &[9224]);     // 1913 => [9224]
+        map.insert(747, &[17416]);     // 747 => [17416]
+        map.insert(897, &[33800]);     // 897 => [33800]
+        map.insert(340, &[66568]);     // 340 => [66568]
+        map.insert(1278, &[132104]);     // 1278 => [132104]
+        map.insert(1509, &[263176]);     // 1509 => [263176]
+        map.insert(2002, &[525320]);     // 2002 => [525320]
+        map.insert(956, &[1049608]);     // 956 => [1049608]
+        map.insert(303, &[2098184]);     // 303 => [2098184]
+        map.insert(562, &[4195336]);     // 562 => [4195336]
+        map.insert(1752, &[6152]);     // 1752 => [6152]
+        map.insert(310, &[10248]);     // 310 => [10248]
+        map.insert(1188, &[18440]);     // 1188 => [18440]
+        map.insert(1486, &[34824]);     // 1486 => [34824]
+        map.insert(1819, &[67592]);     // 1819 => [67592]
+        map.insert(689, &[133128]);     // 689 => [133128]
+        map.insert(938, &[264200]);     // 938 => [264200]
+        map.insert(413, &[526344]);     // 413 => [526344]
+        map.insert(1523, &[1050632]);     // 1523 => [1050632]
+        map.insert(1888, &[2099208]);     // 1888 => [2099208]
+        map.insert(1149, &[4196360]);     // 1149 => [4196360]
+        map.insert(2022, &[12296]);     // 2022 => [12296]
+        map.insert(628, &[20488]);     // 628 => [20488]
+        map.insert(798, &[36872]);     // 798 => [36872]
+        map.insert(459, &[69640]);     // 459 => [69640]
+        map.insert(1121, &[135176]);     // 1121 => [135176]
+        map.insert(1402, &[266248]);     // 1402 => [266248]
+        map.insert(1869, &[528392]);     // 1869 => [528392]
+        map.insert(803, &[1052680]);     // 803 => [1052680]
+        map.insert(432, &[2101256]);     // 432 => [2101256]
+        map.insert(685, &[4198408]);     // 685 => [4198408]
+        map.insert(1434, &[24584]);     // 1434 => [24584]
+        map.insert(1264, &[40968]);     // 1264 => [40968]
+        map.insert(1573, &[73736]);     // 1573 => [73736]
+        map.insert(911, &[139272]);     // 911 => [139272]
+        map.insert(660, &[270344]);     // 660 => [270344]
+        map.insert(163, &[532488]);     // 163 => [532488]
+        map.insert(1229, &[1056776]);     // 1229 => [1056776]
+        map.insert(1630, &[2105352]);     // 1630 => [2105352]
+        map.insert(1347, &[4202504]);     // 1347 => [4202504]
+        map.insert(354, &[49160]);     // 354 => [49160]
+        map.insert(951, &[81928]);     // 951 => [81928]
+        map.insert(1565, &[147464]);     // 1565 => [147464]
+        map.insert(1798, &[278536]);     // 1798 => [278536]
+        map.insert(1329, &[540680]);     // 1329 => [540680]
+        map.insert(351, &[1064968]);     // 351 => [1064968]
+        map.insert(972, &[2113544]);     // 972 => [2113544]
+        map.insert(209, &[4210696]);     // 209 => [4210696]
+        map.insert(733, &[98312]);     // 733 => [98312]
+        map.insert(1911, &[163848]);     // 1911 => [163848]
+        map.insert(1644, &[294920]);     // 1644 => [294920]
+        map.insert(1115, &[557064]);     // 1115 => [557064]
+        map.insert(53, &[1081352]);     // 53 => [1081352]
+        map.insert(678, &[2129928]);     // 678 => [2129928]
+        map.insert(443, &[4227080]);     // 443 => [4227080]
+        map.insert(1442, &[196616]);     // 1442 => [196616]
+        map.insert(1209, &[327688]);     // 1209 => [327688]
+        map.insert(1678, &[589832]);     // 1678 => [589832]
+        map.insert(736, &[1114120]);     // 736 => [1114120]
+        map.insert(115, &[2162696]);     // 115 => [2162696]
+        map.insert(878, &[4259848]);     // 878 => [4259848]
+        map.insert(275, &[393224]);     // 275 => [393224]
+        map.insert(804, &[655368]);     // 804 => [655368]
+        map.insert(1866, &[1179656]);     // 1866 => [1179656]
+        map.insert(1497, &[2228232]);     // 1497 => [2228232]
+        map.insert(1732, &[4325384]);     // 1732 => [4325384]
+        map.insert(575, &[786440]);     // 575 => [786440]
+        map.insert(1617, &[1310728]);     // 1617 => [1310728]
+        map.insert(1218, &[2359304]);     // 1218 => [2359304]
+        map.insert(2015, &[4456456]);     // 2015 => [4456456]
+        map.insert(1126, &[1572872]);     // 1126 => [1572872]
+        map.insert(1781, &[2621448]);     // 1781 => [2621448]
+        map.insert(1512, &[4718600]);     // 1512 => [4718600]
+        map.insert(667, &[3145736]);     // 667 => [3145736]
+        map.insert(390, &[5242888]);     // 390 => [5242888]
+        map.insert(789, &[6291464]);     // 789 => [6291464]
+        map.insert(112, &[112]);     // 112 => [112]
+        map.insert(176, &[176]);     // 176 => [176]
+        map.insert(304, &[304]);     // 304 => [304]
+        map.insert(560, &[560]);     // 560 => [560]
+        map.insert(1072, &[1072]);     // 1072 => [1072]
+        map.insert(639, &[2096]);     // 639 => [2096]
+        map.insert(1199, &[4144]);     // 1199 => [4144]
+        map.insert(833, &[8240]);     // 833 => [8240]
+        map.insert(1747, &[16432]);     // 1747 => [16432]
+        map.insert(1977, &[32816]);     // 1977 => [32816]
+        map.insert(1388, &[65584]);     // 1388 => [65584]
+        map.insert(198, &[131120]);     // 198 => [131120]
+        map.insert(477, &[262192]);     // 477 => [262192]
+        map.insert(1002, &[524336]);     // 1002 => [524336]
+        map.insert(1924, &[1048624]);     // 1924 => [1048624]
+        map.insert(1303, &[2097200]);     // 1303 => [2097200]
+        map.insert(1546, &[4194352]);     // 1546 => [4194352]
+        map.insert(208, &[208]);     // 208 => [208]
+        map.insert(336, &[336]);     // 336 => [336]
+        map.insert(592, &[592]);     // 592 => [592]
+        map.insert(1104, &[1104]);     // 1104 => [1104]
+        map.insert(543, &[2128]);     // 543 => [2128]
+        map.insert(1231, &[4176]);     // 1231 => [4176]
+        map.insert(801, &[8272]);     // 801 => [8272]
+        map.insert(1715, &[16464]);     // 1715 => [16464]
+        map.insert(2009, &[32848]);     // 2009 => [32848]
+        map.insert(1292, &[65616]);     // 1292 => [65616]
+        map.insert(166, &[131152]);     // 166 => [131152]
+        map.insert(445, &[262224]);     // 445 => [262224]
+        map.insert(906, &[524368]);     // 906 => [524368]
+        map.insert(2020, &[1048656]);     // 2020 => [1048656]
+        map.insert(1399, &[2097232]);     // 1399 => [2097232]
+        map.insert(1642, &[4194384]);     // 1642 => [4194384]
+        map.insert(400, &[400]);     // 400 => [400]
+        map.insert(656, &[656]);     // 656 => [656]
+        map.insert(1168, &[1168]);     // 1168 => [1168]
+        map.insert(735, &[2192]);     // 735 => [2192]
+        map.insert(1039, &[4240]);     // 1039 => [4240]
+        map.insert(993, &[8336]);     // 993 => [8336]
+        map.insert(1651, &[16528]);     // 1651 => [16528]
+        map.insert(1817, &[32912]);     // 1817 => [32912]
+        map.insert(1484, &[65680]);     // 1484 => [65680]
+        map.insert(102, &[131216]);     // 102 => [131216]
+        map.insert(381, &[262288]);     // 381 => [262288]
+        map.insert(842, &[524432]);     // 842 => [524432]
+        map.insert(1828, &[1048720]);     // 1828 => [1048720]
+        map.insert(1463, &[2097296]);     // 1463 => [2097296]
+        map.insert(1706, &[4194448]);     // 1706 => [4194448]
+        map.insert(784, &[784]);     // 784 => [784]
+        map.insert(1296, &[1296]);     // 1296 => [1296]
+        map.insert(863, &[2320]);     // 863 => [2320]
+        map.insert(1423, &[4368]);     // 1423 => [4368]
+        map.insert(609, &[8464]);     // 609 => [8464]
+        map.insert(2035, &[16656]);     // 2035 => [16656]
+        map.insert(1689, &[33040]);     // 1689 => [33040]
+        map.insert(1100, &[65808]);     // 1100 => [65808]
+        map.insert(486, &[131344]);     // 486 => [131344]
+        map.insert(253, &[262416]);     // 253 => [262416]
+        map.insert(714, &[524560]);     // 714 => [524560]
+        map.insert(1700, &[1048848]);     // 1700 => [1048848]
+        map.insert(1079, &[2097424]);     // 1079 => [2097424]
+        map.insert(1834, &[4194576]);     // 1834 => [4194576]
+        map.insert(1552, &[1552]);     // 1552 => [1552]
+        map.insert(95, &[2576]);     // 95 => [2576]
+        map.insert(1679, &[4624]);     // 1679 => [4624]
+        map.insert(353, &[8720]);     // 353 => [8720]
+        map.insert(1267, &[16912]);     // 1267 => [16912]
+        map.insert(1433, &[33296]);     // 1433 => [33296]
+        map.insert(1868, &[66064]);     // 1868 => [66064]
+        map.insert(742, &[131600]);     // 742 => [131600]
+        map.insert(1021, &[262672]);     // 1021 => [262672]
+        map.insert(458, &[524816]);     // 458 => [524816]
+        map.insert(1444, &[1049104]);     // 1444 => [1049104]
+        map.insert(1847, &[2097680]);     // 1847 => [2097680]
+        map.insert(1066, &[4194832]);     // 1066 => [4194832]
+        map.insert(1631, &[3088]);     // 1631 => [3088]
+        map.insert(143, &[5136]);     // 143 => [5136]
+        map.insert(1889, &[9232]);     // 1889 => [9232]
+        map.insert(755, &[17424]);     // 755 => [17424]
+        map.insert(921, &[33808]);     // 921 => [33808]
+        map.insert(332, &[66576]);     // 332 => [66576]
+        map.insert(1254, &[132112]);     // 1254 => [132112]
+        map.insert(1533, &[263184]);     // 1533 => [263184]
+        map.insert(1994, &[525328]);     // 1994 => [525328]
+        map.insert(932, &[1049616]);     // 932 => [1049616]
+        map.insert(311, &[2098192]);     // 311 => [2098192]
+        map.insert(554, &[4195344]);     // 554 => [4195344]
+        map.insert(1728, &[6160]);     // 1728 => [6160]
+        map.insert(302, &[10256]);     // 302 => [10256]
+        map.insert(1212, &[18448]);     // 1212 => [18448]
+        map.insert(1494, &[34832]);     // 1494 => [34832]
+        map.insert(1795, &[67600]);     // 1795 => [67600]
+        map.insert(681, &[133136]);     // 681 => [133136]
+        map.insert(946, &[264208]);     // 946 => [264208]
+        map.insert(389, &[526352]);     // 389 => [526352]
+        map.insert(1515, &[1050640]);     // 1515 => [1050640]
+        map.insert(1912, &[2099216]);     // 1912 => [2099216]
+        map.insert(1125, &[4196368]);     // 1125 => [4196368]
+        map.insert(2046, &[12304]);     // 2046 => [12304]
+        map.insert(620, &[20496]);     // 620 => [20496]
+        map.insert(774, &[36880]);     // 774 => [36880]
+        map.insert(467, &[69648]);     // 467 => [69648]
+        map.insert(1145, &[135184]);     // 1145 => [135184]
+        map.insert(1378, &[266256]);     // 1378 => [266256]
+        map.insert(1877, &[528400]);     // 1877 => [528400]
+        map.insert(827, &[1052688]);     // 827 => [1052688]
+        map.insert(424, &[2101264]);     // 424 => [2101264]
+        map.insert(693, &[4198416]);     // 693 => [4198416]
+        map.insert(1410, &[24592]);     // 1410 => [24592]
+        map.insert(1256, &[40976]);     // 1256 => [40976]
+        map.insert(1597, &[73744]);     // 1597 => [73744]
+        map.insert(919, &[139280]);     // 919 => [139280]
+        map.insert(652, &[270352]);     // 652 => [270352]
+        map.insert(187, &[532496]);     // 187 => [532496]
+        map.insert(1237, &[1056784]);     // 1237 => [1056784]
+        map.insert(1606, &[2105360]);     // 1606 => [2105360]
+        map.insert(1371, &[4202512]);     // 1371 => [4202512]
+        map.insert(378, &[49168]);     // 378 => [49168]
+        map.insert(943, &[81936]);     // 943 => [81936]
+        map.insert(1541, &[147472]);     // 1541 => [147472]
+        map.insert(1822, &[278544]);     // 1822 => [278544]
+        map.insert(1321, &[540688]);     // 1321 => [540688]
+        map.insert(327, &[1064976]);     // 327 => [1064976]
+        map.insert(980, &[2113552]);     // 980 => [2113552]
+        map.insert(201, &[4210704]);     // 201 => [4210704]
+        map.insert(709, &[98320]);     // 709 => [98320]
+        map.insert(1903, &[163856]);     // 1903 => [163856]
+        map.insert(1652, &[294928]);     // 1652 => [294928]
+        map.insert(1091, &[557072]);     // 1091 => [557072]
+        map.insert(45, &[1081360]);     // 45 => [1081360]
+        map.insert(702, &[2129936]);     // 702 => [2129936]
+        map.insert(419, &[4227088]);     // 419 => [4227088]
+        map.insert(1466, &[196624]);     // 1466 => [196624]
+        map.insert(1185, &[327696]);     // 1185 => [327696]
+        map.insert(1686, &[589840]);     // 1686 => [589840]
+        map.insert(760, &[1114128]);     // 760 => [1114128]
+        map.insert(107, &[2162704]);     // 107 => [2162704]
+        map.insert(886, &[4259856]);     // 886 => [4259856]
+        map.insert(267, &[393232]);     // 267 => [393232]
+        map.insert(828, &[655376]);     // 828 => [655376]
+        map.insert(1874, &[1179664]);     // 1874 => [1179664]
+        map.insert(1473, &[2228240]);     // 1473 => [2228240]
+        map.insert(1756, &[4325392]);     // 1756 => [4325392]
+        map.insert(551, &[786448]);     // 551 => [786448]
+        map.insert(1609, &[1310736]);     // 1609 => [1310736]
+        map.insert(1242, &[2359312]);     // 1242 => [2359312]
+        map.insert(1991, &[4456464]);     // 1991 => [4456464]
+        map.insert(1150, &[1572880]);     // 1150 => [1572880]
+        map.insert(1773, &[2621456]);     // 1773 => [2621456]
+        map.insert(1520, &[4718608]);     // 1520 => [4718608]
+        map.insert(643, &[3145744]);     // 643 => [3145744]
+        map.insert(414, &[5242896]);     // 414 => [5242896]
+        map.insert(781, &[6291472]);     // 781 => [6291472]
+        map.insert(224, &[224]);     // 224 => [224]
+        map.insert(352, &[352]);     // 352 => [352]
+        map.insert(608, &[608]);     // 608 => [608]
+        map.insert(1120, &[1120]);     // 1120 => [1120]
+        map.insert(559, &[2144]);     // 559 => [2144]
+        map.insert(1279, &[4192]);     // 1279 => [4192]
+        map.insert(785, &[8288]);     // 785 => [8288]
+        map.insert(1667, &[16480]);     // 1667 => [16480]
+        map.insert(2025, &[32864]);     // 2025 => [32864]
+        map.insert(1340, &[65632]);     // 1340 => [65632]
+        map.insert(150, &[131168]);     // 150 => [131168]
+        map.insert(397, &[262240]);     // 397 => [262240]
+        map.insert(954, &[524384]);     // 954 => [524384]
+        map.insert(2004, &[1048672]);     // 2004 => [1048672]
+        map.insert(1351, &[2097248]);     // 1351 => [2097248]
+        map.insert(1626, &[4194400]);     // 1626 => [4194400]
+        map.insert(416, &[416]);     // 416 => [416]
+        map.insert(672, &[672]);     // 672 => [672]
+        map.insert(1184, &[1184]);     // 1184 => [1184]
+        map.insert(751, &[2208]);     // 751 => [2208]
+        map.insert(1087, &[4256]);     // 1087 => [4256]
+        map.insert(977, &[8352]);     // 977 => [8352]
+        map.insert(1603, &[16544]);     // 1603 => [16544]
+        map.insert(1833, &[32928]);     // 1833 => [32928]
+        map.insert(1532, &[65696]);     // 1532 => [65696]
+        map.insert(86, &[131232]);     // 86 => [131232]
+        map.insert(333, &[262304]);     // 333 => [262304]
+        map.insert(890, &[524448]);     // 890 => [524448]
+        map.insert(1812, &[1048736]);     // 1812 => [1048736]
+        map.insert(1415, &[2097312]);     // 1415 => [2097312]
+        map.insert(1690, &[4194464]);     // 1690 => [4194464]
+        map.insert(800, &[800]);     // 800 => [800]
+        map.insert(1312, &[1312]);     // 1312 => [1312]
+        map.insert(879, &[2336]);     // 879 => [2336]
+        map.insert(1471, &[4384]);     // 1471 => [4384]
+        map.insert(593, &[8480]);     // 593 => [8480]
+        map.insert(1987, &[16672]);     // 1987 => [16672]
+        map.insert(1705, &[33056]);     // 1705 => [33056]
+        map.insert(1148, &[65824]);     // 1148 => [65824]
+        map.insert(470, &[131360]);     // 470 => [131360]
+        map.insert(205, &[262432]);     // 205 => [262432]
+        map.insert(762, &[524576]);     // 762 => [524576]
+        map.insert(1684, &[1048864]);     // 1684 => [1048864]
+        map.insert(1031, &[2097440]);     // 1031 => [2097440]
+        map.insert(1818, &[4194592]);     // 1818 => [4194592]
+        map.insert(1568, &[1568]);     // 1568 => [1568]
+        map.insert(111, &[2592]);     // 111 => [2592]
+        map.insert(1727, &[4640]);     // 1727 => [4640]
+        map.insert(337, &[8736]);     // 337 => [8736]
+        map.insert(1219, &[16928]);     // 1219 => [16928]
+        map.insert(1449, &[33312]);     // 1449 => [33312]
+        map.insert(1916, &[66080]);     // 1916 => [66080]
+        map.insert(726, &[131616]);     // 726 => [131616]
+        map.insert(973, &[262688]);     // 973 => [262688]
+        map.insert(506, &[524832]);     // 506 => [524832]
+        map.insert(1428, &[1049120]);     // 1428 => [1049120]
+        map.insert(1799, &[2097696]);     // 1799 => [2097696]
+        map.insert(1050, &[4194848]);     // 1050 => [4194848]
+        map.insert(1647, &[3104]);     // 1647 => [3104]
+        map.insert(191, &[5152]);     // 191 => [5152]
+        map.insert(1873, &[9248]);     // 1873 => [9248]
+        map.insert(707, &[17440]);     // 707 => [17440]
+        map.insert(937, &[33824]);     // 937 => [33824]
+        map.insert(380, &[66592]);     // 380 => [66592]
+        map.insert(1238, &[132128]);     // 1238 => [132128]
+        map.insert(1485, &[263200]);     // 1485 => [263200]
+        map.insert(2042, &[525344]);     // 2042 => [525344]
+        map.insert(916, &[1049632]);     // 916 => [1049632]
+        map.insert(263, &[2098208]);     // 263 => [2098208]
+        map.insert(538, &[4195360]);     // 538 => [4195360]
+        map.insert(1776, &[6176]);     // 1776 => [6176]
+        map.insert(286, &[10272]);     // 286 => [10272]
+        map.insert(1164, &[18464]);     // 1164 => [18464]
+        map.insert(1510, &[34848]);     // 1510 => [34848]
+        map.insert(1843, &[67616]);     // 1843 => [67616]
+        map.insert(665, &[133152]);     // 665 => [133152]
+        map.insert(898, &[264224]);     // 898 => [264224]
+        map.insert(437, &[526368]);     // 437 => [526368]
+        map.insert(1499, &[1050656]);     // 1499 => [1050656]
+        map.insert(1864, &[2099232]);     // 1864 => [2099232]
+        map.insert(1109, &[4196384]);     // 1109 => [4196384]
+        map.insert(1998, &[12320]);     // 1998 => [12320]
+        map.insert(604, &[20512]);     // 604 => [20512]
+        map.insert(822, &[36896]);     // 822 => [36896]
+        map.insert(483, &[69664]);     // 483 => [69664]
+        map.insert(1097, &[135200]);     // 1097 => [135200]
+        map.insert(1362, &[266272]);     // 1362 => [266272]
+        map.insert(1893, &[528416]);     // 1893 => [528416]
+        map.insert(779, &[1052704]);     // 779 => [1052704]
+        map.insert(408, &[2101280]);     // 408 => [2101280]
+        map.insert(645, &[4198432]);     // 645 => [4198432]
+        map.insert(1458, &[24608]);     // 1458 => [24608]
+        map.insert(1240, &[40992]);     // 1240 => [40992]
+        map.insert(1549, &[73760]);     // 1549 => [73760]
+        map.insert(935, &[139296]);     // 935 => [139296]
+        map.insert(700, &[270368]);     // 700 => [270368]
+        map.insert(139, &[532512]);     // 139 => [532512]
+        map.insert(1253, &[1056800]);     // 1253 => [1056800]
+        map.insert(1654, &[2105376]);     // 1654 => [2105376]
+        map.insert(1387, &[4202528]);     // 1387 => [4202528]
+        map.insert(330, &[49184]);     // 330 => [49184]
+        map.insert(927, &[81952]);     // 927 => [81952]
+        map.insert(1589, &[147488]);     // 1589 => [147488]
+        map.insert(1838, &[278560]);     // 1838 => [278560]
+        map.insert(1305, &[540704]);     // 1305 => [540704]
+        map.insert(375, &[1064992]);     // 375 => [1064992]
+        map.insert(996, &[2113568]);     // 996 => [2113568]
+        map.insert(249, &[4210720]);     // 249 => [4210720]
+        map.insert(757, &[98336]);     // 757 => [98336]
+        map.insert(1887, &[163872]);     // 1887 => [163872]
+        map.insert(1604, &[294944]);     // 1604 => [294944]
+        map.insert(1139, &[557088]);     // 1139 => [557088]
+        map.insert(29, &[1081376]);     // 29 => [1081376]
+        map.insert(654, &[2129952]);     // 654 => [2129952]
+        map.insert(403, &[4227104]);     // 403 => [4227104]
+        map.insert(1418, &[196640]);     // 1418 => [196640]
+        map.insert(1169, &[327712]);     // 1169 => [327712]
+        map.insert(1702, &[589856]);     // 1702 => [589856]
+        map.insert(712, &[1114144]);     // 712 => [1114144]
+        map.insert(91, &[2162720]);     // 91 => [2162720]
+        map.insert(838, &[4259872]);     // 838 => [4259872]
+        map.insert(315, &[393248]);     // 315 => [393248]
+        map.insert(780, &[655392]);     // 780 => [655392]
+        map.insert(1890, &[1179680]);     // 1890 => [1179680]
+        map.insert(1521, &[2228256]);     // 1521 => [2228256]
+        map.insert(1772, &[4325408]);     // 1772 => [4325408]
+        map.insert(535, &[786464]);     // 535 => [786464]
+        map.insert(1657, &[1310752]);     // 1657 => [1310752]
+        map.insert(1258, &[2359328]);     // 1258 => [2359328]
+        map.insert(2039, &[4456480]);     // 2039 => [4456480]
+        map.insert(1102, &[1572896]);     // 1102 => [1572896]
+        map.insert(1757, &[2621472]);     // 1757 => [2621472]
+        map.insert(1472, &[4718624]);     // 1472 => [4718624]
+        map.insert(691, &[3145760]);     // 691 => [3145760]
+        map.insert(430, &[5242912]);     // 430 => [5242912]
+        map.insert(829, &[6291488]);     // 829 => [6291488]
+        map.insert(448, &[448]);     // 448 => [448]
+        map.insert(704, &[704]);     // 704 => [704]
+        map.insert(1216, &[1216]);     // 1216 => [1216]
+        map.insert(655, &[2240]);     // 655 => [2240]
+        map.insert(1119, &[4288]);     // 1119 => [4288]
+        map.insert(945, &[8384]);     // 945 => [8384]
+        map.insert(1571, &[16576]);     // 1571 => [16576]
+        map.insert(1865, &[32960]);     // 1865 => [32960]
+        map.insert(1436, &[65728]);     // 1436 => [65728]
+        map.insert(54, &[131264]);     // 54 => [131264]
+        map.insert(301, &[262336]);     // 301 => [262336]
+        map.insert(794, &[524480]);     // 794 => [524480]
+        map.insert(1908, &[1048768]);     // 1908 => [1048768]
+        map.insert(1511, &[2097344]);     // 1511 => [2097344]
+        map.insert(1786, &[4194496]);     // 1786 => [4194496]
+        map.insert(832, &[832]);     // 832 => [832]
+        map.insert(1344, &[1344]);     // 1344 => [1344]
+        map.insert(783, &[2368]);     // 783 => [2368]
+        map.insert(1503, &[4416]);     // 1503 => [4416]
+        map.insert(561, &[8512]);     // 561 => [8512]
+        map.insert(1955, &[16704]);     // 1955 => [16704]
+        map.insert(1737, &[33088]);     // 1737 => [33088]
+        map.insert(1052, &[65856]);     // 1052 => [65856]
+        map.insert(438, &[131392]);     // 438 => [131392]
+        map.insert(173, &[262464]);     // 173 => [262464]
+        map.insert(666, &[524608]);     // 666 => [524608]
+        map.insert(1780, &[1048896]);     // 1780 => [1048896]
+        map.insert(1127, &[2097472]);     // 1127 => [2097472]
+        map.insert(1914, &[4194624]);     // 1914 => [4194624]
+        map.insert(1600, &[1600]);     // 1600 => [1600]
+        map.insert(15, &[2624]);     // 15 => [2624]
+        map.insert(1759, &[4672]);     // 1759 => [4672]
+        map.insert(305, &[8768]);     // 305 => [8768]
+        map.insert(1187, &[16960]);     // 1187 => [16960]
+        map.insert(1481, &[33344]);     // 1481 => [33344]
+        map.insert(1820, &[66112]);     // 1820 => [66112]
+        map.insert(694, &[131648]);     // 694 => [131648]
+        map.insert(941, &[262720]);     // 941 => [262720]
+        map.insert(410, &[524864]);     // 410 => [524864]
+        map.insert(1524, &[1049152]);     // 1524 => [1049152]
+        map.insert(1895, &[2097728]);     // 1895 => [2097728]
+        map.insert(1146, &[4194880]);     // 1146 => [4194880]
+        map.insert(1551, &[3136]);     // 1551 => [3136]
+        map.insert(223, &[5184]);     // 223 => [5184]
+        map.insert(1841, &[9280]);     // 1841 => [9280]
+        map.insert(675, &[17472]);     // 675 => [17472]
+        map.insert(969, &[33856]);     // 969 => [33856]
+        map.insert(284, &[66624]);     // 284 => [66624]
+        map.insert(1206, &[132160]);     // 1206 => [132160]
+        map.insert(1453, &[263232]);     // 1453 => [263232]
+        map.insert(1946, &[525376]);     // 1946 => [525376]
+        map.insert(1012, &[1049664]);     // 1012 => [1049664]
+        map.insert(359, &[2098240]);     // 359 => [2098240]
+        map.insert(634, &[4195392]);     // 634 => [4195392]
+        map.insert(1680, &[6208]);     // 1680 => [6208]
+        map.insert(382, &[10304]);     // 382 => [10304]
+        map.insert(1260, &[18496]);     // 1260 => [18496]
+        map.insert(1414, &[34880]);     // 1414 => [34880]
+        map.insert(1875, &[67648]);     // 1875 => [67648]
+        map.insert(761, &[133184]);     // 761 => [133184]
+        map.insert(994, &[264256]);     // 994 => [264256]
+        map.insert(469, &[526400]);     // 469 => [526400]
+        map.insert(1467, &[1050688]);     // 1467 => [1050688]
+        map.insert(1832, &[2099264]);     // 1832 => [2099264]
+        map.insert(1077, &[4196416]);     // 1077 => [4196416]
+        map.insert(1966, &[12352]);     // 1966 => [12352]
+        map.insert(572, &[20544]);     // 572 => [20544]
+        map.insert(854, &[36928]);     // 854 => [36928]
+        map.insert(387, &[69696]);     // 387 => [69696]
+        map.insert(1065, &[135232]);     // 1065 => [135232]
+        map.insert(1330, &[266304]);     // 1330 => [266304]
+        map.insert(1797, &[528448]);     // 1797 => [528448]
+        map.insert(875, &[1052736]);     // 875 => [1052736]
+        map.insert(504, &[2101312]);     // 504 => [2101312]
+        map.insert(741, &[4198464]);     // 741 => [4198464]
+        map.insert(1490, &[24640]);     // 1490 => [24640]
+        map.insert(1208, &[41024]);     // 1208 => [41024]
+        map.insert(1645, &[73792]);     // 1645 => [73792]
+        map.insert(967, &[139328]);     // 967 => [139328]
+        map.insert(732, &[270400]);     // 732 => [270400]
+        map.insert(235, &[532544]);     // 235 => [532544]
+        map.insert(1157, &[1056832]);     // 1157 => [1056832]
+        map.insert(1558, &[2105408]);     // 1558 => [2105408]
+        map.insert(1291, &[4202560]);     // 1291 => [4202560]
+        map.insert(298, &[49216]);     // 298 => [49216]
+        map.insert(1023, &[81984]);     // 1023 => [81984]
+        map.insert(1621, &[147520]);     // 1621 => [147520]
+        map.insert(1870, &[278592]);     // 1870 => [278592]
+        map.insert(1401, &[540736]);     // 1401 => [540736]
+        map.insert(279, &[1065024]);     // 279 => [1065024]
+        map.insert(900, &[2113600]);     // 900 => [2113600]
+        map.insert(153, &[4210752]);     // 153 => [4210752]
+        map.insert(661, &[98368]);     // 661 => [98368]
+        map.insert(1855, &[163904]);     // 1855 => [163904]
+        map.insert(1572, &[294976]);     // 1572 => [294976]
+        map.insert(1043, &[557120]);     // 1043 => [557120]
+        map.insert(125, &[1081408]);     // 125 => [1081408]
+        map.insert(750, &[2129984]);     // 750 => [2129984]
+        map.insert(499, &[4227136]);     // 499 => [4227136]
+        map.insert(1514, &[196672]);     // 1514 => [196672]
+        map.insert(1265, &[327744]);     // 1265 => [327744]
+        map.insert(1734, &[589888]);     // 1734 => [589888]
+        map.insert(680, &[1114176]);     // 680 => [1114176]
+        map.insert(59, &[2162752]);     // 59 => [2162752]
+        map.insert(806, &[4259904]);     // 806 => [4259904]
+        map.insert(347, &[393280]);     // 347 => [393280]
+        map.insert(876, &[655424]);     // 876 => [655424]
+        map.insert(1794, &[1179712]);     // 1794 => [1179712]
+        map.insert(1425, &[2228288]);     // 1425 => [2228288]
+        map.insert(1676, &[4325440]);     // 1676 => [4325440]
+        map.insert(631, &[786496]);     // 631 => [786496]
+        map.insert(1561, &[1310784]);     // 1561 => [1310784]
+        map.insert(1162, &[2359360]);     // 1162 => [2359360]
+        map.insert(1943, &[4456512]);     // 1943 => [4456512]
+        map.insert(1070, &[1572928]);     // 1070 => [1572928]
+        map.insert(1725, &[2621504]);     // 1725 => [2621504]
+        map.insert(1440, &[4718656]);     // 1440 => [4718656]
+        map.insert(723, &[3145792]);     // 723 => [3145792]
+        map.insert(462, &[5242944]);     // 462 => [5242944]
+        map.insert(861, &[6291520]);     // 861 => [6291520]
+        map.insert(896, &[896]);     // 896 => [896]
+        map.insert(1408, &[1408]);     // 1408 => [1408]
+        map.insert(975, &[2432]);     // 975 => [2432]
+        map.insert(1311, &[4480]);     // 1311 => [4480]
+        map.insert(753, &[8576]);     // 753 => [8576]
+        map.insert(1891, &[16768]);     // 1891 => [16768]
+        map.insert(1545, &[33152]);     // 1545 => [33152]
+        map.insert(1244, &[65920]);     // 1244 => [65920]
+        map.insert(374, &[131456]);     // 374 => [131456]
+        map.insert(109, &[262528]);     // 109 => [262528]
+        map.insert(602, &[524672]);     // 602 => [524672]
+        map.insert(1588, &[1048960]);     // 1588 => [1048960]
+        map.insert(1191, &[2097536]);     // 1191 => [2097536]
+        map.insert(1978, &[4194688]);     // 1978 => [4194688]
+        map.insert(1664, &[1664]);     // 1664 => [1664]
+        map.insert(207, &[2688]);     // 207 => [2688]
+        map.insert(1567, &[4736]);     // 1567 => [4736]
+        map.insert(497, &[8832]);     // 497 => [8832]
+        map.insert(1123, &[17024]);     // 1123 => [17024]
+        map.insert(1289, &[33408]);     // 1289 => [33408]
+        map.insert(2012, &[66176]);     // 2012 => [66176]
+        map.insert(630, &[131712]);     // 630 => [131712]
+        map.insert(877, &[262784]);     // 877 => [262784]
+        map.insert(346, &[524928]);     // 346 => [524928]
+        map.insert(1332, &[1049216]);     // 1332 => [1049216]
+        map.insert(1959, &[2097792]);     // 1959 => [2097792]
+        map.insert(1210, &[4194944]);     // 1210 => [4194944]
+        map.insert(1743, &[3200]);     // 1743 => [3200]
+        map.insert(31, &[5248]);     // 31 => [5248]
+        map.insert(2033, &[9344]);     // 2033 => [9344]
+        map.insert(611, &[17536]);     // 611 => [17536]
+        map.insert(777, &[33920]);     // 777 => [33920]
+        map.insert(476, &[66688]);     // 476 => [66688]
+        map.insert(1142, &[132224]);     // 1142 => [132224]
+        map.insert(1389, &[263296]);     // 1389 => [263296]
+        map.insert(1882, &[525440]);     // 1882 => [525440]
+        map.insert(820, &[1049728]);     // 820 => [1049728]
+        map.insert(423, &[2098304]);     // 423 => [2098304]
+        map.insert(698, &[4195456]);     // 698 => [4195456]
+        map.insert(1616, &[6272]);     // 1616 => [6272]
+        map.insert(446, &[10368]);     // 446 => [10368]
+        map.insert(1068, &[18560]);     // 1068 => [18560]
+        map.insert(1350, &[34944]);     // 1350 => [34944]
+        map.insert(1939, &[67712]);     // 1939 => [67712]
+        map.insert(569, &[133248]);     // 569 => [133248]
+        map.insert(802, &[264320]);     // 802 => [264320]
+        map.insert(277, &[526464]);     // 277 => [526464]
+        map.insert(1403, &[1050752]);     // 1403 => [1050752]
+        map.insert(2024, &[2099328]);     // 2024 => [2099328]
+        map.insert(1269, &[4196480]);     // 1269 => [4196480]
+        map.insert(1902, &[12416]);     // 1902 => [12416]
+        map.insert(764, &[20608]);     // 764 => [20608]
+        map.insert(918, &[36992]);     // 918 => [36992]
+        map.insert(323, &[69760]);     // 323 => [69760]
+        map.insert(1257, &[135296]);     // 1257 => [135296]
+        map.insert(1522, &[266368]);     // 1522 => [266368]
+        map.insert(1989, &[528512]);     // 1989 => [528512]
+        map.insert(939, &[1052800]);     // 939 => [1052800]
+        map.insert(312, &[2101376]);     // 312 => [2101376]
+        map.insert(549, &[4198528]);     // 549 => [4198528]
+        map.insert(1298, &[24704]);     // 1298 => [24704]
+        map.insert(1144, &[41088]);     // 1144 => [41088]
+        map.insert(1709, &[73856]);     // 1709 => [73856]
+        map.insert(775, &[139392]);     // 775 => [139392]
+        map.insert(540, &[270464]);     // 540 => [270464]
+        map.insert(43, &[532608]);     // 43 => [532608]
+        map.insert(1093, &[1056896]);     // 1093 => [1056896]
+        map.insert(1750, &[2105472]);     // 1750 => [2105472]
+        map.insert(1483, &[4202624]);     // 1483 => [4202624]
+        map.insert(490, &[49280]);     // 490 => [49280]
+        map.insert(831, &[82048]);     // 831 => [82048]
+        map.insert(1685, &[147584]);     // 1685 => [147584]
+        map.insert(1934, &[278656]);     // 1934 => [278656]
+        map.insert(1465, &[540800]);     // 1465 => [540800]
+        map.insert(471, &[1065088]);     // 471 => [1065088]
+        map.insert(836, &[2113664]);     // 836 => [2113664]
+        map.insert(89, &[4210816]);     // 89 => [4210816]
+        map.insert(597, &[98432]);     // 597 => [98432]
+        map.insert(2047, &[163968]);     // 2047 => [163968]
+        map.insert(1764, &[295040]);     // 1764 => [295040]
+        map.insert(1235, &[557184]);     // 1235 => [557184]
+        map.insert(189, &[1081472]);     // 189 => [1081472]
+        map.insert(558, &[2130048]);     // 558 => [2130048]
+        map.insert(307, &[4227200]);     // 307 => [4227200]
+        map.insert(1322, &[196736]);     // 1322 => [196736]
+        map.insert(1073, &[327808]);     // 1073 => [327808]
+        map.insert(1542, &[589952]);     // 1542 => [589952]
+        map.insert(616, &[1114240]);     // 616 => [1114240]
+        map.insert(251, &[2162816]);     // 251 => [2162816]
+        map.insert(998, &[4259968]);     // 998 => [4259968]
+        map.insert(411, &[393344]);     // 411 => [393344]
+        map.insert(940, &[655488]);     // 940 => [655488]
+        map.insert(1986, &[1179776]);     // 1986 => [1179776]
+        map.insert(1361, &[2228352]);     // 1361 => [2228352]
+        map.insert(1612, &[4325504]);     // 1612 => [4325504]
+        map.insert(695, &[786560]);     // 695 => [786560]
+        map.insert(1753, &[1310848]);     // 1753 => [1310848]
+        map.insert(1098, &[2359424]);     // 1098 => [2359424]
+        map.insert(1879, &[4456576]);     // 1879 => [4456576]
+        map.insert(1262, &[1572992]);     // 1262 => [1572992]
+        map.insert(1661, &[2621568]);     // 1661 => [2621568]
+        map.insert(1376, &[4718720]);     // 1376 => [4718720]
+        map.insert(531, &[3145856]);     // 531 => [3145856]
+        map.insert(270, &[5243008]);     // 270 => [5243008]
+        map.insert(925, &[6291584]);     // 925 => [6291584]
+        map.insert(1792, &[1792]);     // 1792 => [1792]
+        map.insert(335, &[2816]);     // 335 => [2816]
+        map.insert(1951, &[4864]);     // 1951 => [4864]
+        map.insert(113, &[8960]);     // 113 => [8960]
+        map.insert(1507, &[17152]);     // 1507 => [17152]
+        map.insert(1161, &[33536]);     // 1161 => [33536]
+        map.insert(1628, &[66304]);     // 1628 => [66304]
+        map.insert(1014, &[131840]);     // 1014 => [131840]
+        map.insert(749, &[262912]);     // 749 => [262912]
+        map.insert(218, &[525056]);     // 218 => [525056]
+        map.insert(1204, &[1049344]);     // 1204 => [1049344]
+        map.insert(1575, &[2097920]);     // 1575 => [2097920]
+        map.insert(1338, &[4195072]);     // 1338 => [4195072]
+        map.insert(1871, &[3328]);     // 1871 => [3328]
+        map.insert(415, &[5376]);     // 415 => [5376]
+        map.insert(1649, &[9472]);     // 1649 => [9472]
+        map.insert(995, &[17664]);     // 995 => [17664]
+        map.insert(649, &[34048]);     // 649 => [34048]
+        map.insert(92, &[66816]);     // 92 => [66816]
+        map.insert(1526, &[132352]);     // 1526 => [132352]
+        map.insert(1261, &[263424]);     // 1261 => [263424]
+        map.insert(1754, &[525568]);     // 1754 => [525568]
+        map.insert(692, &[1049856]);     // 692 => [1049856]
+        map.insert(39, &[2098432]);     // 39 => [2098432]
+        map.insert(826, &[4195584]);     // 826 => [4195584]
+        map.insert(2000, &[6400]);     // 2000 => [6400]
+        map.insert(62, &[10496]);     // 62 => [10496]
+        map.insert(1452, &[18688]);     // 1452 => [18688]
+        map.insert(1222, &[35072]);     // 1222 => [35072]
+        map.insert(1555, &[67840]);     // 1555 => [67840]
+        map.insert(953, &[133376]);     // 953 => [133376]
+        map.insert(674, &[264448]);     // 674 => [264448]
+        map.insert(149, &[526592]);     // 149 => [526592]
+        map.insert(1275, &[1050880]);     // 1275 => [1050880]
+        map.insert(1640, &[2099456]);     // 1640 => [2099456]
+        map.insert(1397, &[4196608]);     // 1397 => [4196608]
+        map.insert(1774, &[12544]);     // 1774 => [12544]
+        map.insert(892, &[20736]);     // 892 => [20736]
+        map.insert(534, &[37120]);     // 534 => [37120]
+        map.insert(195, &[69888]);     // 195 => [69888]
+        map.insert(1385, &[135424]);     // 1385 => [135424]
+        map.insert(1138, &[266496]);     // 1138 => [266496]
+        map.insert(1605, &[528640]);     // 1605 => [528640]
+        map.insert(555, &[1052928]);     // 555 => [1052928]
+        map.insert(184, &[2101504]);     // 184 => [2101504]
+        map.insert(933, &[4198656]);     // 933 => [4198656]
+        map.insert(1170, &[24832]);     // 1170 => [24832]
+        map.insert(1528, &[41216]);     // 1528 => [41216]
+        map.insert(1837, &[73984]);     // 1837 => [73984]
+        map.insert(647, &[139520]);     // 647 => [139520]
+        map.insert(924, &[270592]);     // 924 => [270592]
+        map.insert(427, &[532736]);     // 427 => [532736]
+        map.insert(1477, &[1057024]);     // 1477 => [1057024]
+        map.insert(1878, &[2105600]);     // 1878 => [2105600]
+        map.insert(1099, &[4202752]);     // 1099 => [4202752]
+        map.insert(106, &[49408]);     // 106 => [49408]
+        map.insert(703, &[82176]);     // 703 => [82176]
+        map.insert(1813, &[147712]);     // 1813 => [147712]
+        map.insert(1550, &[278784]);     // 1550 => [278784]
+        map.insert(1081, &[540928]);     // 1081 => [540928]
+        map.insert(87, &[1065216]);     // 87 => [1065216]
+        map.insert(708, &[2113792]);     // 708 => [2113792]
+        map.insert(473, &[4210944]);     // 473 => [4210944]
+        map.insert(981, &[98560]);     // 981 => [98560]
+        map.insert(1663, &[164096]);     // 1663 => [164096]
+        map.insert(1892, &[295168]);     // 1892 => [295168]
+        map.insert(1363, &[557312]);     // 1363 => [557312]
+        map.insert(317, &[1081600]);     // 317 => [1081600]
+        map.insert(942, &[2130176]);     // 942 => [2130176]
+        map.insert(179, &[4227328]);     // 179 => [4227328]
+        map.insert(1194, &[196864]);     // 1194 => [196864]
+        map.insert(1457, &[327936]);     // 1457 => [327936]
+        map.insert(1926, &[590080]);     // 1926 => [590080]
+        map.insert(1000, &[1114368]);     // 1000 => [1114368]
+        map.insert(379, &[2162944]);     // 379 => [2162944]
+        map.insert(614, &[4260096]);     // 614 => [4260096]
+        map.insert(27, &[393472]);     // 27 => [393472]
+        map.insert(556, &[655616]);     // 556 => [655616]
+        map.insert(1602, &[1179904]);     // 1602 => [1179904]
+        map.insert(1233, &[2228480]);     // 1233 => [2228480]
+        map.insert(1996, &[4325632]);     // 1996 => [4325632]
+        map.insert(823, &[786688]);     // 823 => [786688]
+        map.insert(1881, &[1310976]);     // 1881 => [1310976]
+        map.insert(1482, &[2359552]);     // 1482 => [2359552]
+        map.insert(1751, &[4456704]);     // 1751 => [4456704]
+        map.insert(1390, &[1573120]);     // 1390 => [1573120]
+        map.insert(2045, &[2621696]);     // 2045 => [2621696]
+        map.insert(1248, &[4718848]);     // 1248 => [4718848]
+        map.insert(915, &[3145984]);     // 915 => [3145984]
+        map.insert(142, &[5243136]);     // 142 => [5243136]
+        map.insert(541, &[6291712]);     // 541 => [6291712]
+        map.insert(1103, &[3584]);     // 1103 => [3584]
+        map.insert(671, &[5632]);     // 671 => [5632]
+        map.insert(1393, &[9728]);     // 1393 => [9728]
+        map.insert(227, &[17920]);     // 227 => [17920]
+        map.insert(393, &[34304]);     // 393 => [34304]
+        map.insert(860, &[67072]);     // 860 => [67072]
+        map.insert(1782, &[132608]);     // 1782 => [132608]
+        map.insert(2029, &[263680]);     // 2029 => [263680]
+        map.insert(1498, &[525824]);     // 1498 => [525824]
+        map.insert(436, &[1050112]);     // 436 => [1050112]
+        map.insert(807, &[2098688]);     // 807 => [2098688]
+        map.insert(58, &[4195840]);     // 58 => [4195840]
+        map.insert(1232, &[6656]);     // 1232 => [6656]
+        map.insert(830, &[10752]);     // 830 => [10752]
+        map.insert(1708, &[18944]);     // 1708 => [18944]
+        map.insert(1990, &[35328]);     // 1990 => [35328]
+        map.insert(1299, &[68096]);     // 1299 => [68096]
+        map.insert(185, &[133632]);     // 185 => [133632]
+        map.insert(418, &[264704]);     // 418 => [264704]
+        map.insert(917, &[526848]);     // 917 => [526848]
+        map.insert(2043, &[1051136]);     // 2043 => [1051136]
+        map.insert(1384, &[2099712]);     // 1384 => [2099712]
+        map.insert(1653, &[4196864]);     // 1653 => [4196864]
+        map.insert(1518, &[12800]);     // 1518 => [12800]
+        map.insert(124, &[20992]);     // 124 => [20992]
+        map.insert(278, &[37376]);     // 278 => [37376]
+        map.insert(963, &[70144]);     // 963 => [70144]
+        map.insert(1641, &[135680]);     // 1641 => [135680]
+        map.insert(1906, &[266752]);     // 1906 => [266752]
+        map.insert(1349, &[528896]);     // 1349 => [528896]
+        map.insert(299, &[1053184]);     // 299 => [1053184]
+        map.insert(952, &[2101760]);     // 952 => [2101760]
+        map.insert(165, &[4198912]);     // 165 => [4198912]
+        map.insert(1938, &[25088]);     // 1938 => [25088]
+        map.insert(1784, &[41472]);     // 1784 => [41472]
+        map.insert(1069, &[74240]);     // 1069 => [74240]
+        map.insert(391, &[139776]);     // 391 => [139776]
+        map.insert(156, &[270848]);     // 156 => [270848]
+        map.insert(683, &[532992]);     // 683 => [532992]
+        map.insert(1733, &[1057280]);     // 1733 => [1057280]
+        map.insert(1110, &[2105856]);     // 1110 => [2105856]
+        map.insert(1867, &[4203008]);     // 1867 => [4203008]
+        map.insert(874, &[49664]);     // 874 => [49664]
+        map.insert(447, &[82432]);     // 447 => [82432]
+        map.insert(1045, &[147968]);     // 1045 => [147968]
+        map.insert(1294, &[279040]);     // 1294 => [279040]
+        map.insert(1849, &[541184]);     // 1849 => [541184]
+        map.insert(855, &[1065472]);     // 855 => [1065472]
+        map.insert(452, &[2114048]);     // 452 => [2114048]
+        map.insert(729, &[4211200]);     // 729 => [4211200]
+        map.insert(213, &[98816]);     // 213 => [98816]
+        map.insert(1407, &[164352]);     // 1407 => [164352]
+        map.insert(1124, &[295424]);     // 1124 => [295424]
+        map.insert(1619, &[557568]);     // 1619 => [557568]
+        map.insert(573, &[1081856]);     // 573 => [1081856]
+        map.insert(174, &[2130432]);     // 174 => [2130432]
+        map.insert(947, &[4227584]);     // 947 => [4227584]
+        map.insert(1962, &[197120]);     // 1962 => [197120]
+        map.insert(1713, &[328192]);     // 1713 => [328192]
+        map.insert(1158, &[590336]);     // 1158 => [590336]
+        map.insert(232, &[1114624]);     // 232 => [1114624]
+        map.insert(635, &[2163200]);     // 635 => [2163200]
+        map.insert(358, &[4260352]);     // 358 => [4260352]
+        map.insert(795, &[393728]);     // 795 => [393728]
+        map.insert(300, &[655872]);     // 300 => [655872]
+        map.insert(1346, &[1180160]);     // 1346 => [1180160]
+        map.insert(2001, &[2228736]);     // 2001 => [2228736]
+        map.insert(1228, &[4325888]);     // 1228 => [4325888]
+        map.insert(55, &[786944]);     // 55 => [786944]
+        map.insert(1113, &[1311232]);     // 1113 => [1311232]
+        map.insert(1738, &[2359808]);     // 1738 => [2359808]
+        map.insert(1495, &[4456960]);     // 1495 => [4456960]
+        map.insert(1646, &[1573376]);     // 1646 => [1573376]
+        map.insert(1277, &[2621952]);     // 1277 => [2621952]
+        map.insert(2016, &[4719104]);     // 2016 => [4719104]
+        map.insert(147, &[3146240]);     // 147 => [3146240]
+        map.insert(910, &[5243392]);     // 910 => [5243392]
+        map.insert(285, &[6291968]);     // 285 => [6291968]
+        map.insert(720, &[7168]);     // 720 => [7168]
+        map.insert(1342, &[11264]);     // 1342 => [11264]
+        map.insert(172, &[19456]);     // 172 => [19456]
+        map.insert(454, &[35840]);     // 454 => [35840]
+        map.insert(787, &[68608]);     // 787 => [68608]
+        map.insert(1721, &[134144]);     // 1721 => [134144]
+        map.insert(1954, &[265216]);     // 1954 => [265216]
+        map.insert(1429, &[527360]);     // 1429 => [527360]
+        map.insert(507, &[1051648]);     // 507 => [1051648]
+        map.insert(872, &[2100224]);     // 872 => [2100224]
+        map.insert(117, &[4197376]);     // 117 => [4197376]
+        map.insert(1006, &[13312]);     // 1006 => [13312]
+        map.insert(1660, &[21504]);     // 1660 => [21504]
+        map.insert(1814, &[37888]);     // 1814 => [37888]
+        map.insert(1475, &[70656]);     // 1475 => [70656]
+        map.insert(105, &[136192]);     // 105 => [136192]
+        map.insert(370, &[267264]);     // 370 => [267264]
+        map.insert(837, &[529408]);     // 837 => [529408]
+        map.insert(1835, &[1053696]);     // 1835 => [1053696]
+        map.insert(1464, &[2102272]);     // 1464 => [2102272]
+        map.insert(1701, &[4199424]);     // 1701 => [4199424]
+        map.insert(402, &[25600]);     // 402 => [25600]
+        map.insert(248, &[41984]);     // 248 => [41984]
+        map.insert(557, &[74752]);     // 557 => [74752]
+        map.insert(1927, &[140288]);     // 1927 => [140288]
+        map.insert(1692, &[271360]);     // 1692 => [271360]
+        map.insert(1195, &[533504]);     // 1195 => [533504]
+        map.insert(197, &[1057792]);     // 197 => [1057792]
+        map.insert(598, &[2106368]);     // 598 => [2106368]
+        map.insert(331, &[4203520]);     // 331 => [4203520]
+        map.insert(1386, &[50176]);     // 1386 => [50176]
+        map.insert(1983, &[82944]);     // 1983 => [82944]
+        map.insert(533, &[148480]);     // 533 => [148480]
+        map.insert(782, &[279552]);     // 782 => [279552]
+        map.insert(313, &[541696]);     // 313 => [541696]
+        map.insert(1367, &[1065984]);     // 1367 => [1065984]
+        map.insert(1988, &[2114560]);     // 1988 => [2114560]
+        map.insert(1241, &[4211712]);     // 1241 => [4211712]
+        map.insert(1749, &[99328]);     // 1749 => [99328]
+        map.insert(895, &[164864]);     // 895 => [164864]
+        map.insert(612, &[295936]);     // 612 => [295936]
+        map.insert(83, &[558080]);     // 83 => [558080]
+        map.insert(1085, &[1082368]);     // 1085 => [1082368]
+        map.insert(1710, &[2130944]);     // 1710 => [2130944]
+        map.insert(1459, &[4228096]);     // 1459 => [4228096]
+        map.insert(426, &[197632]);     // 426 => [197632]
+        map.insert(177, &[328704]);     // 177 => [328704]
+        map.insert(646, &[590848]);     // 646 => [590848]
+        map.insert(1768, &[1115136]);     // 1768 => [1115136]
+        map.insert(1147, &[2163712]);     // 1147 => [2163712]
+        map.insert(1894, &[4260864]);     // 1894 => [4260864]
+        map.insert(1307, &[394240]);     // 1307 => [394240]
+        map.insert(1836, &[656384]);     // 1836 => [656384]
+        map.insert(834, &[1180672]);     // 834 => [1180672]
+        map.insert(465, &[2229248]);     // 465 => [2229248]
+        map.insert(716, &[4326400]);     // 716 => [4326400]
+        map.insert(1591, &[787456]);     // 1591 => [787456]
+        map.insert(601, &[1311744]);     // 601 => [1311744]
+        map.insert(202, &[2360320]);     // 202 => [2360320]
+        map.insert(983, &[4457472]);     // 983 => [4457472]
+        map.insert(110, &[1573888]);     // 110 => [1573888]
+        map.insert(765, &[2622464]);     // 765 => [2622464]
+        map.insert(480, &[4719616]);     // 480 => [4719616]
+        map.insert(1683, &[3146752]);     // 1683 => [3146752]
+        map.insert(1422, &[5243904]);     // 1422 => [5243904]
+        map.insert(1821, &[6292480]);     // 1821 => [6292480]
+        map.insert(1441, &[14336]);     // 1441 => [14336]
+        map.insert(51, &[22528]);     // 51 => [22528]
+        map.insert(345, &[38912]);     // 345 => [38912]
+        map.insert(908, &[71680]);     // 908 => [71680]
+        map.insert(1574, &[137216]);     // 1574 => [137216]
+        map.insert(1853, &[268288]);     // 1853 => [268288]
+        map.insert(1290, &[530432]);     // 1290 => [530432]
+        map.insert(356, &[1054720]);     // 356 => [1054720]
+        map.insert(1015, &[2103296]);     // 1015 => [2103296]
+        map.insert(234, &[4200448]);     // 234 => [4200448]
+        map.insert(2013, &[26624]);     // 2013 => [26624]
+        map.insert(1719, &[43008]);     // 1719 => [43008]
+        map.insert(1122, &[75776]);     // 1122 => [75776]
+        map.insert(456, &[141312]);     // 456 => [141312]
+        map.insert(211, &[272384]);     // 211 => [272384]
+        map.insert(740, &[534528]);     // 740 => [534528]
+        map.insert(1674, &[1058816]);     // 1674 => [1058816]
+        map.insert(1049, &[2107392]);     // 1049 => [2107392]
+        map.insert(1796, &[4204544]);     // 1796 => [4204544]
+        map.insert(805, &[51200]);     // 805 => [51200]
+        map.insert(496, &[83968]);     // 496 => [83968]
+        map.insert(1114, &[149504]);     // 1114 => [149504]
+        map.insert(1345, &[280576]);     // 1345 => [280576]
+        map.insert(1910, &[542720]);     // 1910 => [542720]
+        map.insert(792, &[1067008]);     // 792 => [1067008]
+        map.insert(395, &[2115584]);     // 395 => [2115584]
+        map.insert(662, &[4212736]);     // 662 => [4212736]
+        map.insert(154, &[100352]);     // 154 => [100352]
+        map.insert(1328, &[165888]);     // 1328 => [165888]
+        map.insert(1067, &[296960]);     // 1067 => [296960]
+        map.insert(1564, &[559104]);     // 1564 => [559104]
+        map.insert(626, &[1083392]);     // 626 => [1083392]
+        map.insert(225, &[2131968]);     // 225 => [2131968]
+        map.insert(1020, &[4229120]);     // 1020 => [4229120]
+        map.insert(2021, &[198656]);     // 2021 => [198656]
+        map.insert(1790, &[329728]);     // 1790 => [329728]
+        map.insert(1225, &[591872]);     // 1225 => [591872]
+        map.insert(167, &[1116160]);     // 167 => [1116160]
+        map.insert(564, &[2164736]);     // 564 => [2164736]
+        map.insert(297, &[4261888]);     // 297 => [4261888]
+        map.insert(852, &[395264]);     // 852 => [395264]
+        map.insert(355, &[657408]);     // 355 => [657408]
+        map.insert(1293, &[1181696]);     // 1293 => [1181696]
+        map.insert(1950, &[2230272]);     // 1950 => [2230272]
+        map.insert(1155, &[4327424]);     // 1155 => [4327424]
+        map.insert(120, &[788480]);     // 120 => [788480]
+        map.insert(1046, &[1312768]);     // 1046 => [1312768]
+        map.insert(1669, &[2361344]);     // 1669 => [2361344]
+        map.insert(1432, &[4458496]);     // 1432 => [4458496]
+        map.insert(1569, &[1574912]);     // 1569 => [1574912]
+        map.insert(1202, &[2623488]);     // 1202 => [2623488]
+        map.insert(1967, &[4720640]);     // 1967 => [4720640]
+        map.insert(220, &[3147776]);     // 220 => [3147776]
+        map.insert(961, &[5244928]);     // 961 => [5244928]
+        map.insert(338, &[6293504]);     // 338 => [6293504]
+        map.insert(269, &[28672]);     // 269 => [28672]
+        map.insert(103, &[45056]);     // 103 => [45056]
+        map.insert(690, &[77824]);     // 690 => [77824]
+        map.insert(1816, &[143360]);     // 1816 => [143360]
+        map.insert(1539, &[274432]);     // 1539 => [274432]
+        map.insert(1076, &[536576]);     // 1076 => [536576]
+        map.insert(90, &[1060864]);     // 90 => [1060864]
+        map.insert(713, &[2109440]);     // 713 => [2109440]
+        map.insert(468, &[4206592]);     // 468 => [4206592]
+        map.insert(1525, &[53248]);     // 1525 => [53248]
+        map.insert(1824, &[86016]);     // 1824 => [86016]
+        map.insert(650, &[151552]);     // 650 => [151552]
+        map.insert(913, &[282624]);     // 913 => [282624]
+        map.insert(422, &[544768]);     // 422 => [544768]
+        map.insert(1480, &[1069056]);     // 1480 => [1069056]
+        map.insert(1883, &[2117632]);     // 1883 => [2117632]
+        map.insert(1094, &[4214784]);     // 1094 => [4214784]
+        map.insert(1610, &[102400]);     // 1610 => [102400]
+        map.insert(992, &[167936]);     // 992 => [167936]
+        map.insert(763, &[299008]);     // 763 => [299008]
+        map.insert(204, &[561152]);     // 204 => [561152]
+        map.insert(1186, &[1085440]);     // 1186 => [1085440]
+        map.insert(1585, &[2134016]);     // 1585 => [2134016]
+        map.insert(1324, &[4231168]);     // 1324 => [4231168]
+        map.insert(309, &[200704]);     // 309 => [200704]
+        map.insert(46, &[331776]);     // 46 => [331776]
+        map.insert(537, &[593920]);     // 537 => [593920]
+        map.insert(1655, &[1118208]);     // 1655 => [1118208]
+        map.insert(1252, &[2166784]);     // 1252 => [2166784]
+        map.insert(2041, &[4263936]);     // 2041 => [4263936]
+        map.insert(1412, &[397312]);     // 1412 => [397312]
+        map.insert(1971, &[659456]);     // 1971 => [659456]
+        map.insert(989, &[1183744]);     // 989 => [1183744]
+        map.insert(334, &[2232320]);     // 334 => [2232320]
+        map.insert(595, &[4329472]);     // 595 => [4329472]
+        map.insert(1704, &[790528]);     // 1704 => [790528]
+        map.insert(710, &[1314816]);     // 710 => [1314816]
+        map.insert(85, &[2363392]);     // 85 => [2363392]
+        map.insert(840, &[4460544]);     // 840 => [4460544]
+        map.insert(241, &[1576960]);     // 241 => [1576960]
+        map.insert(610, &[2625536]);     // 610 => [2625536]
+        map.insert(383, &[4722688]);     // 383 => [4722688]
+        map.insert(1548, &[3149824]);     // 1548 => [3149824]
+        map.insert(1297, &[5246976]);     // 1297 => [5246976]
+        map.insert(1922, &[6295552]);     // 1922 => [6295552]
+        map.insert(539, &[57344]);     // 539 => [57344]
+        map.insert(206, &[90112]);     // 206 => [90112]
+        map.insert(1380, &[155648]);     // 1380 => [155648]
+        map.insert(1151, &[286720]);     // 1151 => [286720]
+        map.insert(1608, &[548864]);     // 1608 => [548864]
+        map.insert(550, &[1073152]);     // 550 => [1073152]
+        map.insert(181, &[2121728]);     // 181 => [2121728]
+        map.insert(936, &[4218880]);     // 936 => [4218880]
+        map.insert(420, &[106496]);     // 420 => [106496]
+        map.insert(1038, &[172032]);     // 1038 => [172032]
+        map.insert(1301, &[303104]);     // 1301 => [303104]
+        map.insert(1826, &[565248]);     // 1826 => [565248]
+        map.insert(844, &[1089536]);     // 844 => [1089536]
+        map.insert(479, &[2138112]);     // 479 => [2138112]
+        map.insert(706, &[4235264]);     // 706 => [4235264]
+        map.insert(1755, &[204800]);     // 1755 => [204800]
+        map.insert(1984, &[335872]);     // 1984 => [335872]
+        map.insert(1527, &[598016]);     // 1527 => [598016]
+        map.insert(409, &[1122304]);     // 409 => [1122304]
+        map.insert(778, &[2170880]);     // 778 => [2170880]
+        map.insert(23, &[4268032]);     // 23 => [4268032]
+        map.insert(618, &[401408]);     // 618 => [401408]
+        map.insert(93, &[663552]);     // 93 => [663552]
+        map.insert(1075, &[1187840]);     // 1075 => [1187840]
+        map.insert(1696, &[2236416]);     // 1696 => [2236416]
+        map.insert(1469, &[4333568]);     // 1469 => [4333568]
+        map.insert(326, &[794624]);     // 326 => [794624]
+        map.insert(1320, &[1318912]);     // 1320 => [1318912]
+        map.insert(1979, &[2367488]);     // 1979 => [2367488]
+        map.insert(1190, &[4464640]);     // 1190 => [4464640]
+        map.insert(1823, &[1581056]);     // 1823 => [1581056]
+        map.insert(1420, &[2629632]);     // 1420 => [2629632]
+        map.insert(1681, &[4726784]);     // 1681 => [4726784]
+        map.insert(482, &[3153920]);     // 482 => [3153920]
+        map.insert(767, &[5251072]);     // 767 => [5251072]
+        map.insert(108, &[6299648]);     // 108 => [6299648]
+        map.insert(1078, &[114688]);     // 1078 => [114688]
+        map.insert(412, &[180224]);     // 412 => [180224]
+        map.insert(135, &[311296]);     // 135 => [311296]
+        map.insert(688, &[573440]);     // 688 => [573440]
+        map.insert(1758, &[1097728]);     // 1758 => [1097728]
+        map.insert(1101, &[2146304]);     // 1101 => [2146304]
+        map.insert(1872, &[4243456]);     // 1872 => [4243456]
+        map.insert(841, &[212992]);     // 841 => [212992]
+        map.insert(594, &[344064]);     // 594 => [344064]
+        map.insert(101, &[606208]);     // 101 => [606208]
+        map.insert(1035, &[1130496]);     // 1035 => [1130496]
+        map.insert(1688, &[2179072]);     // 1688 => [2179072]
+        map.insert(1413, &[4276224]);     // 1413 => [4276224]
+        map.insert(2040, &[409600]);     // 2040 => [409600]
+        map.insert(1487, &[671744]);     // 1487 => [671744]
+        map.insert(417, &[1196032]);     // 417 => [1196032]
+        map.insert(818, &[2244608]);     // 818 => [2244608]
+        map.insert(47, &[4341760]);     // 47 => [4341760]
+        map.insert(1236, &[802816]);     // 1236 => [802816]
+        map.insert(186, &[1327104]);     // 186 => [1327104]
+        map.insert(553, &[2375680]);     // 553 => [2375680]
+        map.insert(308, &[4472832]);     // 308 => [4472832]
+        map.insert(653, &[1589248]);     // 653 => [1589248]
+        map.insert(30, &[2637824]);     // 30 => [2637824]
+        map.insert(771, &[4734976]);     // 771 => [4734976]
+        map.insert(1136, &[3162112]);     // 1136 => [3162112]
+        map.insert(1901, &[5259264]);     // 1901 => [5259264]
+        map.insert(1534, &[6307840]);     // 1534 => [6307840]
+        map.insert(547, &[229376]);     // 547 => [229376]
+        map.insert(824, &[360448]);     // 824 => [360448]
+        map.insert(271, &[622592]);     // 271 => [622592]
+        map.insert(1377, &[1146880]);     // 1377 => [1146880]
+        map.insert(2034, &[2195456]);     // 2034 => [2195456]
+        map.insert(1263, &[4292608]);     // 1263 => [4292608]
+        map.insert(1682, &[425984]);     // 1682 => [425984]
+        map.insert(1189, &[688128]);     // 1189 => [688128]
+        map.insert(203, &[1212416]);     // 203 => [1212416]
+        map.insert(600, &[2260992]);     // 600 => [2260992]
+        map.insert(325, &[4358144]);     // 325 => [4358144]
+        map.insert(1470, &[819200]);     // 1470 => [819200]
+        map.insert(464, &[1343488]);     // 464 => [1343488]
+        map.insert(835, &[2392064]);     // 835 => [2392064]
+        map.insert(94, &[4489216]);     // 94 => [4489216]
+        map.insert(999, &[1605632]);     // 999 => [1605632]
+        map.insert(372, &[2654208]);     // 372 => [2654208]
+        map.insert(617, &[4751360]);     // 617 => [4751360]
+        map.insert(1306, &[3178496]);     // 1306 => [3178496]
+        map.insert(1543, &[5275648]);     // 1543 => [5275648]
+        map.insert(1172, &[6324224]);     // 1172 => [6324224]
+        map.insert(1095, &[458752]);     // 1095 => [458752]
+        map.insert(1648, &[720896]);     // 1648 => [720896]
+        map.insert(542, &[1245184]);     // 542 => [1245184]
+        map.insert(141, &[2293760]);     // 141 => [2293760]
+        map.insert(912, &[4390912]);     // 912 => [4390912]
+        map.insert(1899, &[851968]);     // 1899 => [851968]
+        map.insert(773, &[1376256]);     // 773 => [1376256]
+        map.insert(406, &[2424832]);     // 406 => [2424832]
+        map.insert(651, &[4521984]);     // 651 => [4521984]
+        map.insert(306, &[1638400]);     // 306 => [1638400]
+        map.insert(929, &[2686976]);     // 929 => [2686976]
+        map.insert(188, &[4784128]);     // 188 => [4784128]
+        map.insert(1999, &[3211264]);     // 1999 => [3211264]
+        map.insert(1234, &[5308416]);     // 1234 => [5308416]
+        map.insert(1601, &[6356992]);     // 1601 => [6356992]
+        map.insert(705, &[917504]);     // 705 => [917504]
+        map.insert(1711, &[1441792]);     // 1711 => [1441792]
+        map.insert(1084, &[2490368]);     // 1084 => [2490368]
+        map.insert(1825, &[4587520]);     // 1825 => [4587520]
+        map.insert(1176, &[1703936]);     // 1176 => [1703936]
+        map.insert(1547, &[2752512]);     // 1547 => [2752512]
+        map.insert(1302, &[4849664]);     // 1302 => [4849664]
+        map.insert(613, &[3276800]);     // 613 => [3276800]
+        map.insert(376, &[5373952]);     // 376 => [5373952]
+        map.insert(1003, &[6422528]);     // 1003 => [6422528]
+        map.insert(1411, &[1835008]);     // 1411 => [1835008]
+        map.insert(1808, &[2883584]);     // 1808 => [2883584]
+        map.insert(1037, &[4980736]);     // 1037 => [4980736]
+        map.insert(894, &[3407872]);     // 894 => [3407872]
+        map.insert(99, &[5505024]);     // 99 => [5505024]
+        map.insert(752, &[6553600]);     // 752 => [6553600]
+        map.insert(329, &[3670016]);     // 329 => [3670016]
+        map.insert(596, &[5767168]);     // 596 => [5767168]
+        map.insert(199, &[6815744]);     // 199 => [6815744]
+        map.insert(1193, &[7340032]);     // 1193 => [7340032]
+        map
+    })
 }
 
 impl GuavaCode23_12 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -2139,24 +2139,15 @@ impl BinaryCode for GuavaCode23_12 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(23);
@@ -2181,14 +2172,26 @@ impl BinaryCode for GuavaCode23_12 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(23, 12).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[23 / 64] & !((1 << 23) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2383,8 +2386,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, true, true, true, false, false, false, true ]);
         assert_eq!(vector, first_row.as_vector());
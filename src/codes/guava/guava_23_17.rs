@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,127 +16,128 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode23_17;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1703937 ],
-                &[ 2752514 ],
-                &[ 4849668 ],
-                &[ 3276808 ],
-                &[ 5373968 ],
-                &[ 6422560 ],
-                &[ 8126528 ],
-                &[ 1835136 ],
-                &[ 2883840 ],
-                &[ 4981248 ],
-                &[ 3408896 ],
-                &[ 5507072 ],
-                &[ 6557696 ],
-                &[ 3678208 ],
-                &[ 5783552 ],
-                &[ 6848512 ],
-                &[ 7405568 ],
-                
-            ], 23));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1806113 ],
-                &[ 2840210 ],
-                &[ 4572596 ],
-                &[ 7806008 ],
-                &[ 270272 ],
-                &[ 7987200 ],
-                
-            ], 23));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(64, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(14, &[16]);     // 14 => [16]
-            map.insert(13, &[32]);     // 13 => [32]
-            map.insert(16, &[64]);     // 16 => [64]
-            map.insert(22, &[128]);     // 22 => [128]
-            map.insert(21, &[256]);     // 21 => [256]
-            map.insert(19, &[512]);     // 19 => [512]
-            map.insert(31, &[1024]);     // 31 => [1024]
-            map.insert(25, &[2048]);     // 25 => [2048]
-            map.insert(26, &[4096]);     // 26 => [4096]
-            map.insert(32, &[8192]);     // 32 => [8192]
-            map.insert(38, &[16384]);     // 38 => [16384]
-            map.insert(37, &[32768]);     // 37 => [32768]
-            map.insert(47, &[65536]);     // 47 => [65536]
-            map.insert(11, &[131072]);     // 11 => [131072]
-            map.insert(28, &[262144]);     // 28 => [262144]
-            map.insert(35, &[524288]);     // 35 => [524288]
-            map.insert(41, &[1048576]);     // 41 => [1048576]
-            map.insert(42, &[2097152]);     // 42 => [2097152]
-            map.insert(44, &[4194304]);     // 44 => [4194304]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(15, &[17]);     // 15 => [17]
-            map.insert(12, &[33]);     // 12 => [33]
-            map.insert(17, &[65]);     // 17 => [65]
-            map.insert(23, &[129]);     // 23 => [129]
-            map.insert(20, &[257]);     // 20 => [257]
-            map.insert(18, &[513]);     // 18 => [513]
-            map.insert(30, &[1025]);     // 30 => [1025]
-            map.insert(24, &[2049]);     // 24 => [2049]
-            map.insert(27, &[4097]);     // 27 => [4097]
-            map.insert(33, &[8193]);     // 33 => [8193]
-            map.insert(39, &[16385]);     // 39 => [16385]
-            map.insert(36, &[32769]);     // 36 => [32769]
-            map.insert(46, &[65537]);     // 46 => [65537]
-            map.insert(10, &[131073]);     // 10 => [131073]
-            map.insert(29, &[262145]);     // 29 => [262145]
-            map.insert(34, &[524289]);     // 34 => [524289]
-            map.insert(40, &[1048577]);     // 40 => [1048577]
-            map.insert(43, &[2097153]);     // 43 => [2097153]
-            map.insert(45, &[4194305]);     // 45 => [4194305]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(48, &[8256]);     // 48 => [8256]
-            map.insert(54, &[16448]);     // 54 => [16448]
-            map.insert(53, &[32832]);     // 53 => [32832]
-            map.insert(63, &[65600]);     // 63 => [65600]
-            map.insert(51, &[524352]);     // 51 => [524352]
-            map.insert(57, &[1048640]);     // 57 => [1048640]
-            map.insert(58, &[2097216]);     // 58 => [2097216]
-            map.insert(60, &[4194368]);     // 60 => [4194368]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(49, &[8257]);     // 49 => [8257]
-            map.insert(55, &[16449]);     // 55 => [16449]
-            map.insert(52, &[32833]);     // 52 => [32833]
-            map.insert(62, &[65601]);     // 62 => [65601]
-            map.insert(50, &[524353]);     // 50 => [524353]
-            map.insert(56, &[1048641]);     // 56 => [1048641]
-            map.insert(59, &[2097217]);     // 59 => [2097217]
-            map.insert(61, &[4194369]);     // 61 => [4194369]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1703937 ],
+            &[ 2752514 ],
+            &[ 4849668 ],
+            &[ 3276808 ],
+            &[ 5373968 ],
+            &[ 6422560 ],
+            &[ 8126528 ],
+            &[ 1835136 ],
+            &[ 2883840 ],
+            &[ 4981248 ],
+            &[ 3408896 ],
+            &[ 5507072 ],
+            &[ 6557696 ],
+            &[ 3678208 ],
+            &[ 5783552 ],
+            &[ 6848512 ],
+            &[ 7405568 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 23)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1806113 ],
+            &[ 2840210 ],
+            &[ 4572596 ],
+            &[ 7806008 ],
+            &[ 270272 ],
+            &[ 7987200 ],
+            
+        ], 23)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(64, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(14, &[16]);     // 14 => [16]
+        map.insert(13, &[32]);     // 13 => [32]
+        map.insert(16, &[64]);     // 16 => [64]
+        map.insert(22, &[128]);     // 22 => [128]
+        map.insert(21, &[256]);     // 21 => [256]
+        map.insert(19, &[512]);     // 19 => [512]
+        map.insert(31, &[1024]);     // 31 => [1024]
+        map.insert(25, &[2048]);     // 25 => [2048]
+        map.insert(26, &[4096]);     // 26 => [4096]
+        map.insert(32, &[8192]);     // 32 => [8192]
+        map.insert(38, &[16384]);     // 38 => [16384]
+        map.insert(37, &[32768]);     // 37 => [32768]
+        map.insert(47, &[65536]);     // 47 => [65536]
+        map.insert(11, &[131072]);     // 11 => [131072]
+        map.insert(28, &[262144]);     // 28 => [262144]
+        map.insert(35, &[524288]);     // 35 => [524288]
+        map.insert(41, &[1048576]);     // 41 => [1048576]
+        map.insert(42, &[2097152]);     // 42 => [2097152]
+        map.insert(44, &[4194304]);     // 44 => [4194304]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(15, &[17]);     // 15 => [17]
+        map.insert(12, &[33]);     // 12 => [33]
+        map.insert(17, &[65]);     // 17 => [65]
+        map.insert(23, &[129]);     // 23 => [129]
+        map.insert(20, &[257]);     // 20 => [257]
+        map.insert(18, &[513]);     // 18 => [513]
+        map.insert(30, &[1025]);     // 30 => [1025]
+        map.insert(24, &[2049]);     // 24 => [2049]
+        map.insert(27, &[4097]);     // 27 => [4097]
+        map.insert(33, &[8193]);     // 33 => [8193]
+        map.insert(39, &[16385]);     // 39 => [16385]
+        map.insert(36, &[32769]);     // 36 => [32769]
+        map.insert(46, &[65537]);     // 46 => [65537]
+        map.insert(10, &[131073]);     // 10 => [131073]
+        map.insert(29, &[262145]);     // 29 => [262145]
+        map.insert(34, &[524289]);     // 34 => [524289]
+        map.insert(40, &[1048577]);     // 40 => [1048577]
+        map.insert(43, &[2097153]);     // 43 => [2097153]
+        map.insert(45, &[4194305]);     // 45 => [4194305]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(48, &[8256]);     // 48 => [8256]
+        map.insert(54, &[16448]);     // 54 => [16448]
+        map.insert(53, &[32832]);     // 53 => [32832]
+        map.insert(63, &[65600]);     // 63 => [65600]
+        map.insert(51, &[524352]);     // 51 => [524352]
+        map.insert(57, &[1048640]);     // 57 => [1048640]
+        map.insert(58, &[2097216]);     // 58 => [2097216]
+        map.insert(60, &[4194368]);     // 60 => [4194368]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(49, &[8257]);     // 49 => [8257]
+        map.insert(55, &[16449]);     // 55 => [16449]
+        map.insert(52, &[32833]);     // 52 => [32833]
+        map.insert(62, &[65601]);     // 62 => [65601]
+        map.insert(50, &[524353]);     // 50 => [524353]
+        map.insert(56, &[1048641]);     // 56 => [1048641]
+        map.insert(59, &[2097217]);     // 59 => [2097217]
+        map.insert(61, &[4194369]);     // 61 => [4194369]
+        map
+    })
 }
 
 impl GuavaCode23_17 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -155,24 +155,15 @@ impl BinaryCode for GuavaCode23_17 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(23);
@@ -197,14 +188,26 @@ impl BinaryCode for GuavaCode23_17 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(23, 17).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[23 / 64] & !((1 << 23) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -399,8 +402,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, true, false, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,95 +16,96 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode23_18;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 2359297 ],
-                &[ 1310722 ],
-                &[ 7602180 ],
-                &[ 786440 ],
-                &[ 7077904 ],
-                &[ 6029344 ],
-                &[ 3932224 ],
-                &[ 8126592 ],
-                &[ 1835264 ],
-                &[ 2884096 ],
-                &[ 4981760 ],
-                &[ 3409920 ],
-                &[ 5509120 ],
-                &[ 6561792 ],
-                &[ 3686400 ],
-                &[ 5799936 ],
-                &[ 6881280 ],
-                &[ 7471104 ],
-                
-            ], 23));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 6348385 ],
-                &[ 5336402 ],
-                &[ 3951412 ],
-                &[ 7747704 ],
-                &[ 8388480 ],
-                
-            ], 23));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(14, &[16]);     // 14 => [16]
-            map.insert(13, &[32]);     // 13 => [32]
-            map.insert(11, &[64]);     // 11 => [64]
-            map.insert(16, &[128]);     // 16 => [128]
-            map.insert(22, &[256]);     // 22 => [256]
-            map.insert(21, &[512]);     // 21 => [512]
-            map.insert(19, &[1024]);     // 19 => [1024]
-            map.insert(31, &[2048]);     // 31 => [2048]
-            map.insert(25, &[4096]);     // 25 => [4096]
-            map.insert(26, &[8192]);     // 26 => [8192]
-            map.insert(23, &[16384]);     // 23 => [16384]
-            map.insert(17, &[32768]);     // 17 => [32768]
-            map.insert(18, &[65536]);     // 18 => [65536]
-            map.insert(24, &[131072]);     // 24 => [131072]
-            map.insert(28, &[262144]);     // 28 => [262144]
-            map.insert(20, &[524288]);     // 20 => [524288]
-            map.insert(30, &[1048576]);     // 30 => [1048576]
-            map.insert(29, &[2097152]);     // 29 => [2097152]
-            map.insert(27, &[4194304]);     // 27 => [4194304]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(15, &[17]);     // 15 => [17]
-            map.insert(12, &[33]);     // 12 => [33]
-            map.insert(10, &[65]);     // 10 => [65]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(7, &[16512]);     // 7 => [16512]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 2359297 ],
+            &[ 1310722 ],
+            &[ 7602180 ],
+            &[ 786440 ],
+            &[ 7077904 ],
+            &[ 6029344 ],
+            &[ 3932224 ],
+            &[ 8126592 ],
+            &[ 1835264 ],
+            &[ 2884096 ],
+            &[ 4981760 ],
+            &[ 3409920 ],
+            &[ 5509120 ],
+            &[ 6561792 ],
+            &[ 3686400 ],
+            &[ 5799936 ],
+            &[ 6881280 ],
+            &[ 7471104 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 23)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 6348385 ],
+            &[ 5336402 ],
+            &[ 3951412 ],
+            &[ 7747704 ],
+            &[ 8388480 ],
+            
+        ], 23)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(14, &[16]);     // 14 => [16]
+        map.insert(13, &[32]);     // 13 => [32]
+        map.insert(11, &[64]);     // 11 => [64]
+        map.insert(16, &[128]);     // 16 => [128]
+        map.insert(22, &[256]);     // 22 => [256]
+        map.insert(21, &[512]);     // 21 => [512]
+        map.insert(19, &[1024]);     // 19 => [1024]
+        map.insert(31, &[2048]);     // 31 => [2048]
+        map.insert(25, &[4096]);     // 25 => [4096]
+        map.insert(26, &[8192]);     // 26 => [8192]
+        map.insert(23, &[16384]);     // 23 => [16384]
+        map.insert(17, &[32768]);     // 17 => [32768]
+        map.insert(18, &[65536]);     // 18 => [65536]
+        map.insert(24, &[131072]);     // 24 => [131072]
+        map.insert(28, &[262144]);     // 28 => [262144]
+        map.insert(20, &[524288]);     // 20 => [524288]
+        map.insert(30, &[1048576]);     // 30 => [1048576]
+        map.insert(29, &[2097152]);     // 29 => [2097152]
+        map.insert(27, &[4194304]);     // 27 => [4194304]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(15, &[17]);     // 15 => [17]
+        map.insert(12, &[33]);     // 12 => [33]
+        map.insert(10, &[65]);     // 10 => [65]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(7, &[16512]);     // 7 => [16512]
+        map
+    })
 }
 
 impl GuavaCode23_18 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -123,24 +123,15 @@ impl BinaryCode for GuavaCode23_18 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(23);
@@ -165,14 +156,26 @@ impl BinaryCode for GuavaCode23_18 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        2
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(23, 18).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[23 / 64] & !((1 << 23) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -367,8 +370,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, true, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,79 +16,80 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode23_19;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 524289 ],
-                &[ 524290 ],
-                &[ 524292 ],
-                &[ 524296 ],
-                &[ 524304 ],
-                &[ 524320 ],
-                &[ 524352 ],
-                &[ 524416 ],
-                &[ 524544 ],
-                &[ 524800 ],
-                &[ 525312 ],
-                &[ 526336 ],
-                &[ 528384 ],
-                &[ 532480 ],
-                &[ 540672 ],
-                &[ 557056 ],
-                &[ 589824 ],
-                &[ 655360 ],
-                &[ 786432 ],
-                
-            ], 23));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1048575 ],
-                &[ 1048576 ],
-                &[ 2097152 ],
-                &[ 4194304 ],
-                
-            ], 23));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(16, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[1048576]);     // 2 => [1048576]
-            map.insert(4, &[2097152]);     // 4 => [2097152]
-            map.insert(8, &[4194304]);     // 8 => [4194304]
-            map.insert(3, &[1048577]);     // 3 => [1048577]
-            map.insert(5, &[2097153]);     // 5 => [2097153]
-            map.insert(9, &[4194305]);     // 9 => [4194305]
-            map.insert(6, &[3145728]);     // 6 => [3145728]
-            map.insert(10, &[5242880]);     // 10 => [5242880]
-            map.insert(12, &[6291456]);     // 12 => [6291456]
-            map.insert(7, &[3145729]);     // 7 => [3145729]
-            map.insert(11, &[5242881]);     // 11 => [5242881]
-            map.insert(13, &[6291457]);     // 13 => [6291457]
-            map.insert(14, &[7340032]);     // 14 => [7340032]
-            map.insert(15, &[7340033]);     // 15 => [7340033]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 524289 ],
+            &[ 524290 ],
+            &[ 524292 ],
+            &[ 524296 ],
+            &[ 524304 ],
+            &[ 524320 ],
+            &[ 524352 ],
+            &[ 524416 ],
+            &[ 524544 ],
+            &[ 524800 ],
+            &[ 525312 ],
+            &[ 526336 ],
+            &[ 528384 ],
+            &[ 532480 ],
+            &[ 540672 ],
+            &[ 557056 ],
+            &[ 589824 ],
+            &[ 655360 ],
+            &[ 786432 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 23)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1048575 ],
+            &[ 1048576 ],
+            &[ 2097152 ],
+            &[ 4194304 ],
+            
+        ], 23)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(16, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[1048576]);     // 2 => [1048576]
+        map.insert(4, &[2097152]);     // 4 => [2097152]
+        map.insert(8, &[4194304]);     // 8 => [4194304]
+        map.insert(3, &[1048577]);     // 3 => [1048577]
+        map.insert(5, &[2097153]);     // 5 => [2097153]
+        map.insert(9, &[4194305]);     // 9 => [4194305]
+        map.insert(6, &[3145728]);     // 6 => [3145728]
+        map.insert(10, &[5242880]);     // 10 => [5242880]
+        map.insert(12, &[6291456]);     // 12 => [6291456]
+        map.insert(7, &[3145729]);     // 7 => [3145729]
+        map.insert(11, &[5242881]);     // 11 => [5242881]
+        map.insert(13, &[6291457]);     // 13 => [6291457]
+        map.insert(14, &[7340032]);     // 14 => [7340032]
+        map.insert(15, &[7340033]);     // 15 => [7340033]
+        map
+    })
 }
 
 impl GuavaCode23_19 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -107,24 +107,15 @@ impl BinaryCode for GuavaCode23_19 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(23);
@@ -149,14 +140,26 @@ impl BinaryCode for GuavaCode23_19 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        4
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(23, 19).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[23 / 64] & !((1 << 23) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -351,8 +354,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,96 +16,97 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode24_19;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 8912897 ],
-                &[ 4718594 ],
-                &[ 2621444 ],
-                &[ 15204360 ],
-                &[ 1572880 ],
-                &[ 14155808 ],
-                &[ 12058688 ],
-                &[ 7864448 ],
-                &[ 16253184 ],
-                &[ 3670528 ],
-                &[ 5768192 ],
-                &[ 9963520 ],
-                &[ 6819840 ],
-                &[ 11018240 ],
-                &[ 13123584 ],
-                &[ 7372800 ],
-                &[ 11599872 ],
-                &[ 13762560 ],
-                &[ 14942208 ],
-                
-            ], 24));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 7902825 ],
-                &[ 12135082 ],
-                &[ 14306508 ],
-                &[ 15495408 ],
-                &[ 16776960 ],
-                
-            ], 24));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(7, &[8]);     // 7 => [8]
-            map.insert(8, &[16]);     // 8 => [16]
-            map.insert(11, &[32]);     // 11 => [32]
-            map.insert(13, &[64]);     // 13 => [64]
-            map.insert(14, &[128]);     // 14 => [128]
-            map.insert(16, &[256]);     // 16 => [256]
-            map.insert(19, &[512]);     // 19 => [512]
-            map.insert(21, &[1024]);     // 21 => [1024]
-            map.insert(22, &[2048]);     // 22 => [2048]
-            map.insert(25, &[4096]);     // 25 => [4096]
-            map.insert(26, &[8192]);     // 26 => [8192]
-            map.insert(28, &[16384]);     // 28 => [16384]
-            map.insert(17, &[32768]);     // 17 => [32768]
-            map.insert(18, &[65536]);     // 18 => [65536]
-            map.insert(20, &[131072]);     // 20 => [131072]
-            map.insert(24, &[262144]);     // 24 => [262144]
-            map.insert(31, &[524288]);     // 31 => [524288]
-            map.insert(23, &[1048576]);     // 23 => [1048576]
-            map.insert(27, &[2097152]);     // 27 => [2097152]
-            map.insert(29, &[4194304]);     // 29 => [4194304]
-            map.insert(30, &[8388608]);     // 30 => [8388608]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[9]);     // 6 => [9]
-            map.insert(9, &[17]);     // 9 => [17]
-            map.insert(10, &[33]);     // 10 => [33]
-            map.insert(12, &[65]);     // 12 => [65]
-            map.insert(15, &[129]);     // 15 => [129]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 8912897 ],
+            &[ 4718594 ],
+            &[ 2621444 ],
+            &[ 15204360 ],
+            &[ 1572880 ],
+            &[ 14155808 ],
+            &[ 12058688 ],
+            &[ 7864448 ],
+            &[ 16253184 ],
+            &[ 3670528 ],
+            &[ 5768192 ],
+            &[ 9963520 ],
+            &[ 6819840 ],
+            &[ 11018240 ],
+            &[ 13123584 ],
+            &[ 7372800 ],
+            &[ 11599872 ],
+            &[ 13762560 ],
+            &[ 14942208 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 24)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 7902825 ],
+            &[ 12135082 ],
+            &[ 14306508 ],
+            &[ 15495408 ],
+            &[ 16776960 ],
+            
+        ], 24)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(7, &[8]);     // 7 => [8]
+        map.insert(8, &[16]);     // 8 => [16]
+        map.insert(11, &[32]);     // 11 => [32]
+        map.insert(13, &[64]);     // 13 => [64]
+        map.insert(14, &[128]);     // 14 => [128]
+        map.insert(16, &[256]);     // 16 => [256]
+        map.insert(19, &[512]);     // 19 => [512]
+        map.insert(21, &[1024]);     // 21 => [1024]
+        map.insert(22, &[2048]);     // 22 => [2048]
+        map.insert(25, &[4096]);     // 25 => [4096]
+        map.insert(26, &[8192]);     // 26 => [8192]
+        map.insert(28, &[16384]);     // 28 => [16384]
+        map.insert(17, &[32768]);     // 17 => [32768]
+        map.insert(18, &[65536]);     // 18 => [65536]
+        map.insert(20, &[131072]);     // 20 => [131072]
+        map.insert(24, &[262144]);     // 24 => [262144]
+        map.insert(31, &[524288]);     // 31 => [524288]
+        map.insert(23, &[1048576]);     // 23 => [1048576]
+        map.insert(27, &[2097152]);     // 27 => [2097152]
+        map.insert(29, &[4194304]);     // 29 => [4194304]
+        map.insert(30, &[8388608]);     // 30 => [8388608]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[9]);     // 6 => [9]
+        map.insert(9, &[17]);     // 9 => [17]
+        map.insert(10, &[33]);     // 10 => [33]
+        map.insert(12, &[65]);     // 12 => [65]
+        map.insert(15, &[129]);     // 15 => [129]
+        map
+    })
 }
 
 impl GuavaCode24_19 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -124,24 +124,15 @@ impl BinaryCode for GuavaCode24_19 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(24);
@@ -166,14 +157,26 @@ impl BinaryCode for GuavaCode24_19 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        2
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(24, 19).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[24 / 64] & !((1 << 24) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -368,8 +371,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -17,80 +16,81 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct GuavaCode24_20;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
-
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1048577 ],
-                &[ 1048578 ],
-                &[ 1048580 ],
-                &[ 1048584 ],
-                &[ 1048592 ],
-                &[ 1048608 ],
-                &[ 1048640 ],
-                &[ 1048704 ],
-                &[ 1048832 ],
-                &[ 1049088 ],
-                &[ 1049600 ],
-                &[ 1050624 ],
-                &[ 1052672 ],
-                &[ 1056768 ],
-                &[ 1064960 ],
-                &[ 1081344 ],
-                &[ 1114112 ],
-                &[ 1179648 ],
-                &[ 1310720 ],
-                &[ 1572864 ],
-                
-            ], 24));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 2097151 ],
-                &[ 2097152 ],
-                &[ 4194304 ],
-                &[ 8388608 ],
-                
-            ], 24));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
-
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(16, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2097152]);     // 2 => [2097152]
-            map.insert(4, &[4194304]);     // 4 => [4194304]
-            map.insert(8, &[8388608]);     // 8 => [8388608]
-            map.insert(3, &[2097153]);     // 3 => [2097153]
-            map.insert(5, &[4194305]);     // 5 => [4194305]
-            map.insert(9, &[8388609]);     // 9 => [8388609]
-            map.insert(6, &[6291456]);     // 6 => [6291456]
-            map.insert(10, &[10485760]);     // 10 => [10485760]
-            map.insert(12, &[12582912]);     // 12 => [12582912]
-            map.insert(7, &[6291457]);     // 7 => [6291457]
-            map.insert(11, &[10485761]);     // 11 => [10485761]
-            map.insert(13, &[12582913]);     // 13 => [12582913]
-            map.insert(14, &[14680064]);     // 14 => [14680064]
-            map.insert(15, &[14680065]);     // 15 => [14680065]
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
+
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1048577 ],
+            &[ 1048578 ],
+            &[ 1048580 ],
+            &[ 1048584 ],
+            &[ 1048592 ],
+            &[ 1048608 ],
+            &[ 1048640 ],
+            &[ 1048704 ],
+            &[ 1048832 ],
+            &[ 1049088 ],
+            &[ 1049600 ],
+            &[ 1050624 ],
+            &[ 1052672 ],
+            &[ 1056768 ],
+            &[ 1064960 ],
+            &[ 1081344 ],
+            &[ 1114112 ],
+            &[ 1179648 ],
+            &[ 1310720 ],
+            &[ 1572864 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 24)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 2097151 ],
+            &[ 2097152 ],
+            &[ 4194304 ],
+            &[ 8388608 ],
+            
+        ], 24)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(16, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2097152]);     // 2 => [2097152]
+        map.insert(4, &[4194304]);     // 4 => [4194304]
+        map.insert(8, &[8388608]);     // 8 => [8388608]
+        map.insert(3, &[2097153]);     // 3 => [2097153]
+        map.insert(5, &[4194305]);     // 5 => [4194305]
+        map.insert(9, &[8388609]);     // 9 => [8388609]
+        map.insert(6, &[6291456]);     // 6 => [6291456]
+        map.insert(10, &[10485760]);     // 10 => [10485760]
+        map.insert(12, &[12582912]);     // 12 => [12582912]
+        map.insert(7, &[6291457]);     // 7 => [6291457]
+        map.insert(11, &[10485761]);     // 11 => [10485761]
+        map.insert(13, &[12582913]);     // 13 => [12582913]
+        map.insert(14, &[14680064]);     // 14 => [14680064]
+        map.insert(15, &[14680065]);     // 15 => [14680065]
+        map
+    })
 }
 
 impl GuavaCode24_20 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -108,24 +108,15 @@ impl BinaryCode for GuavaCode24_20 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(24);
@@ -150,14 +141,26 @@ impl BinaryCode for GuavaCode24_20 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        4
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
+    
+    /// The weight distribution from the GUAVA database
+    fn weight_distribution(&self) -> Vec<u64> {
+        crate::codes::guava_distance_spectrum(24, 20).unwrap().to_vec()
+    }
+    
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[24 / 64] & !((1 << 24) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -352,8 +355,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, false, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -6,183 +6,134 @@ macro_rules! useit {
     };
 }
 
-#[cfg(feature = "guava_12")]
-useit!(guava_12_10);
-#[cfg(feature = "guava_13")]
-useit!(guava_13_10);
-#[cfg(feature = "guava_13")]
-useit!(guava_13_11);
-#[cfg(feature = "guava_14")]
-useit!(guava_14_10);
-#[cfg(feature = "guava_14")]
-useit!(guava_14_11);
-#[cfg(feature = "guava_14")]
-useit!(guava_14_12);
-#[cfg(feature = "guava_15")]
-useit!(guava_15_10);
-#[cfg(feature = "guava_15")]
-useit!(guava_15_11);
-#[cfg(feature = "guava_15")]
-useit!(guava_15_12);
-#[cfg(feature = "guava_15")]
-useit!(guava_15_13);
-#[cfg(feature = "guava_16")]
-useit!(guava_16_10);
-#[cfg(feature = "guava_16")]
-useit!(guava_16_11);
-#[cfg(feature = "guava_16")]
-useit!(guava_16_12);
-#[cfg(feature = "guava_16")]
-useit!(guava_16_13);
-#[cfg(feature = "guava_16")]
-useit!(guava_16_14);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_10);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_11);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_12);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_13);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_14);
-#[cfg(feature = "guava_17")]
-useit!(guava_17_15);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_10);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_11);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_12);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_13);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_14);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_15);
-#[cfg(feature = "guava_18")]
-useit!(guava_18_16);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_10);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_11);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_12);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_13);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_14);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_15);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_16);
-#[cfg(feature = "guava_19")]
-useit!(guava_19_17);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_10);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_11);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_12);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_13);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_14);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_15);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_16);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_17);
-#[cfg(feature = "guava_20")]
-useit!(guava_20_18);
-#[cfg(feature = "guava_20")]
-useit!(guava_21_10);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_11);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_12);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_13);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_14);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_15);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_16);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_17);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_18);
-#[cfg(feature = "guava_21")]
-useit!(guava_21_19);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_10);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_11);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_12);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_13);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_14);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_15);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_16);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_17);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_18);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_19);
-#[cfg(feature = "guava_22")]
-useit!(guava_22_20);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_10);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_11);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_12);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_13);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_14);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_15);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_16);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_17);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_18);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_19);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_20);
-#[cfg(feature = "guava_23")]
-useit!(guava_23_21);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_11);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_12);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_13);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_14);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_15);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_16);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_17);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_18);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_19);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_20);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_21);
-#[cfg(feature = "guava_24")]
-useit!(guava_24_22);
+use crate::codes::BinaryCode;
+
+mod spectrum;
+pub use self::spectrum::*;
+
+/// Declare the modules of the Guava codes and list the codes enabled in this build
+///
+/// Every entry is the feature that enables a code, followed by its module and type.
+macro_rules! declare_guava_codes {
+    ($($feature:literal => $module:ident::$code:ident,)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            useit!($module);
+        )*
+
+        /// All Guava codes enabled in this build, ordered by length and dimension
+        pub fn guava_codes() -> Vec<&'static dyn BinaryCode> {
+            #[allow(unused_mut)]
+            let mut codes: Vec<&'static dyn BinaryCode> = Vec::new();
+            $(
+                #[cfg(feature = $feature)]
+                codes.push(&$code);
+            )*
+            codes
+        }
+
+        /// Constructors of the codes in [`guava_codes`], in the same order
+        pub(crate) fn guava_code_constructors() -> Vec<fn() -> Box<dyn BinaryCode>> {
+            #[allow(unused_mut)]
+            let mut constructors: Vec<fn() -> Box<dyn BinaryCode>> = Vec::new();
+            $(
+                #[cfg(feature = $feature)]
+                constructors.push(|| Box::new($code) as Box<dyn BinaryCode>);
+            )*
+            constructors
+        }
+    };
+}
+
+declare_guava_codes! {
+    "guava_12" => guava_12_10::GuavaCode12_10,
+    "guava_13" => guava_13_10::GuavaCode13_10,
+    "guava_13" => guava_13_11::GuavaCode13_11,
+    "guava_14" => guava_14_10::GuavaCode14_10,
+    "guava_14" => guava_14_11::GuavaCode14_11,
+    "guava_14" => guava_14_12::GuavaCode14_12,
+    "guava_15" => guava_15_10::GuavaCode15_10,
+    "guava_15" => guava_15_11::GuavaCode15_11,
+    "guava_15" => guava_15_12::GuavaCode15_12,
+    "guava_15" => guava_15_13::GuavaCode15_13,
+    "guava_16" => guava_16_10::GuavaCode16_10,
+    "guava_16" => guava_16_11::GuavaCode16_11,
+    "guava_16" => guava_16_12::GuavaCode16_12,
+    "guava_16" => guava_16_13::GuavaCode16_13,
+    "guava_16" => guava_16_14::GuavaCode16_14,
+    "guava_17" => guava_17_10::GuavaCode17_10,
+    "guava_17" => guava_17_11::GuavaCode17_11,
+    "guava_17" => guava_17_12::GuavaCode17_12,
+    "guava_17" => guava_17_13::GuavaCode17_13,
+    "guava_17" => guava_17_14::GuavaCode17_14,
+    "guava_17" => guava_17_15::GuavaCode17_15,
+    "guava_18" => guava_18_10::GuavaCode18_10,
+    "guava_18" => guava_18_11::GuavaCode18_11,
+    "guava_18" => guava_18_12::GuavaCode18_12,
+    "guava_18" => guava_18_13::GuavaCode18_13,
+    "guava_18" => guava_18_14::GuavaCode18_14,
+    "guava_18" => guava_18_15::GuavaCode18_15,
+    "guava_18" => guava_18_16::GuavaCode18_16,
+    "guava_19" => guava_19_10::GuavaCode19_10,
+    "guava_19" => guava_19_11::GuavaCode19_11,
+    "guava_19" => guava_19_12::GuavaCode19_12,
+    "guava_19" => guava_19_13::GuavaCode19_13,
+    "guava_19" => guava_19_14::GuavaCode19_14,
+    "guava_19" => guava_19_15::GuavaCode19_15,
+    "guava_19" => guava_19_16::GuavaCode19_16,
+    "guava_19" => guava_19_17::GuavaCode19_17,
+    "guava_20" => guava_20_10::GuavaCode20_10,
+    "guava_20" => guava_20_11::GuavaCode20_11,
+    "guava_20" => guava_20_12::GuavaCode20_12,
+    "guava_20" => guava_20_13::GuavaCode20_13,
+    "guava_20" => guava_20_14::GuavaCode20_14,
+    "guava_20" => guava_20_15::GuavaCode20_15,
+    "guava_20" => guava_20_16::GuavaCode20_16,
+    "guava_20" => guava_20_17::GuavaCode20_17,
+    "guava_20" => guava_20_18::GuavaCode20_18,
+    "guava_20" => guava_21_10::GuavaCode21_10,
+    "guava_21" => guava_21_11::GuavaCode21_11,
+    "guava_21" => guava_21_12::GuavaCode21_12,
+    "guava_21" => guava_21_13::GuavaCode21_13,
+    "guava_21" => guava_21_14::GuavaCode21_14,
+    "guava_21" => guava_21_15::GuavaCode21_15,
+    "guava_21" => guava_21_16::GuavaCode21_16,
+    "guava_21" => guava_21_17::GuavaCode21_17,
+    "guava_21" => guava_21_18::GuavaCode21_18,
+    "guava_21" => guava_21_19::GuavaCode21_19,
+    "guava_22" => guava_22_10::GuavaCode22_10,
+    "guava_22" => guava_22_11::GuavaCode22_11,
+    "guava_22" => guava_22_12::GuavaCode22_12,
+    "guava_22" => guava_22_13::GuavaCode22_13,
+    "guava_22" => guava_22_14::GuavaCode22_14,
+    "guava_22" => guava_22_15::GuavaCode22_15,
+    "guava_22" => guava_22_16::GuavaCode22_16,
+    "guava_22" => guava_22_17::GuavaCode22_17,
+    "guava_22" => guava_22_18::GuavaCode22_18,
+    "guava_22" => guava_22_19::GuavaCode22_19,
+    "guava_22" => guava_22_20::GuavaCode22_20,
+    "guava_23" => guava_23_10::GuavaCode23_10,
+    "guava_23" => guava_23_11::GuavaCode23_11,
+    "guava_23" => guava_23_12::GuavaCode23_12,
+    "guava_23" => guava_23_13::GuavaCode23_13,
+    "guava_23" => guava_23_14::GuavaCode23_14,
+    "guava_23" => guava_23_15::GuavaCode23_15,
+    "guava_23" => guava_23_16::GuavaCode23_16,
+    "guava_23" => guava_23_17::GuavaCode23_17,
+    "guava_23" => guava_23_18::GuavaCode23_18,
+    "guava_23" => guava_23_19::GuavaCode23_19,
+    "guava_23" => guava_23_20::GuavaCode23_20,
+    "guava_23" => guava_23_21::GuavaCode23_21,
+    "guava_24" => guava_24_11::GuavaCode24_11,
+    "guava_24" => guava_24_12::GuavaCode24_12,
+    "guava_24" => guava_24_13::GuavaCode24_13,
+    "guava_24" => guava_24_14::GuavaCode24_14,
+    "guava_24" => guava_24_15::GuavaCode24_15,
+    "guava_24" => guava_24_16::GuavaCode24_16,
+    "guava_24" => guava_24_17::GuavaCode24_17,
+    "guava_24" => guava_24_18::GuavaCode24_18,
+    "guava_24" => guava_24_19::GuavaCode24_19,
+    "guava_24" => guava_24_20::GuavaCode24_20,
+    "guava_24" => guava_24_21::GuavaCode24_21,
+    "guava_24" => guava_24_22::GuavaCode24_22,
+}
@@ -0,0 +1,345 @@
+//! Weight distributions of the Guava codes
+//!
+//! These are the weight distributions of the best known codes from the
+//! GUAVA database version 3.15 that are implemented in this module.
+
+/// Look up the weight distribution (distance spectrum) of the $[n, k]$ Guava code
+///
+/// Entry `i` of the result is the number of codewords of Hamming weight `i`.
+/// Returns `None` if we don't know the code.
+pub fn guava_distance_spectrum(n: usize, k: usize) -> Option<&'static [u64]> {
+    match (n, k) {
+        (12, 10) => Some(&[1, 0, 55, 0, 330, 0, 462, 0, 165, 0, 11, 0, 0]),
+        (13, 10) => Some(&[1, 0, 55, 0, 330, 0, 462, 0, 165, 0, 11, 0, 0, 0]),
+        (13, 11) => Some(&[1, 0, 66, 0, 495, 0, 924, 0, 495, 0, 66, 0, 1, 0]),
+        (14, 10) => Some(&[1, 0, 0, 28, 77, 112, 168, 232, 203, 112, 56, 28, 7, 0, 0]),
+        (14, 11) => Some(&[1, 0, 66, 0, 495, 0, 924, 0, 495, 0, 66, 0, 1, 0, 0]),
+        (14, 12) => Some(&[1, 0, 78, 0, 715, 0, 1716, 0, 1287, 0, 286, 0, 13, 0, 0]),
+        (15, 10) => Some(&[1, 0, 0, 0, 105, 0, 280, 0, 435, 0, 168, 0, 35, 0, 0, 0]),
+        (15, 11) => Some(&[
+            1, 0, 0, 35, 105, 168, 280, 435, 435, 280, 168, 105, 35, 0, 0, 1,
+        ]),
+        (15, 12) => Some(&[1, 0, 78, 0, 715, 0, 1716, 0, 1287, 0, 286, 0, 13, 0, 0, 0]),
+        (15, 13) => Some(&[1, 0, 91, 0, 1001, 0, 3003, 0, 3003, 0, 1001, 0, 91, 0, 1, 0]),
+        (16, 10) => Some(&[1, 0, 0, 0, 76, 0, 192, 0, 486, 0, 192, 0, 76, 0, 0, 0, 1]),
+        (16, 11) => Some(&[1, 0, 0, 0, 140, 0, 448, 0, 870, 0, 448, 0, 140, 0, 0, 0, 1]),
+        (16, 12) => Some(&[
+            1, 0, 78, 0, 715, 0, 1716, 0, 1287, 0, 286, 0, 13, 0, 0, 0, 0,
+        ]),
+        (16, 13) => Some(&[
+            1, 0, 91, 0, 1001, 0, 3003, 0, 3003, 0, 1001, 0, 91, 0, 1, 0, 0,
+        ]),
+        (16, 14) => Some(&[
+            1, 0, 105, 0, 1365, 0, 5005, 0, 6435, 0, 3003, 0, 455, 0, 15, 0, 0,
+        ]),
+        (17, 10) => Some(&[1, 0, 0, 0, 76, 0, 192, 0, 486, 0, 192, 0, 76, 0, 0, 0, 1, 0]),
+        (17, 11) => Some(&[
+            1, 0, 0, 0, 140, 0, 448, 0, 870, 0, 448, 0, 140, 0, 0, 0, 1, 0,
+        ]),
+        (17, 12) => Some(&[
+            1, 0, 0, 8, 140, 112, 448, 504, 870, 800, 448, 504, 140, 112, 0, 8, 1, 0,
+        ]),
+        (17, 13) => Some(&[
+            1, 0, 91, 0, 1001, 0, 3003, 0, 3003, 0, 1001, 0, 91, 0, 1, 0, 0, 0,
+        ]),
+        (17, 14) => Some(&[
+            1, 0, 105, 0, 1365, 0, 5005, 0, 6435, 0, 3003, 0, 455, 0, 15, 0, 0, 0,
+        ]),
+        (17, 15) => Some(&[
+            1, 0, 120, 0, 1820, 0, 8008, 0, 12870, 0, 8008, 0, 1820, 0, 120, 0, 1, 0,
+        ]),
+        (18, 10) => Some(&[
+            1, 0, 0, 0, 54, 0, 124, 0, 340, 0, 296, 0, 178, 0, 28, 0, 3, 0, 0,
+        ]),
+        (18, 11) => Some(&[
+            1, 0, 0, 0, 80, 0, 248, 0, 738, 0, 592, 0, 328, 0, 56, 0, 5, 0, 0,
+        ]),
+        (18, 12) => Some(&[
+            1, 0, 0, 0, 148, 0, 560, 0, 1374, 0, 1248, 0, 644, 0, 112, 0, 9, 0, 0,
+        ]),
+        (18, 13) => Some(&[
+            1, 0, 0, 16, 148, 224, 560, 1008, 1374, 1600, 1248, 1008, 644, 224, 112, 16, 9, 0, 0,
+        ]),
+        (18, 14) => Some(&[
+            1, 0, 105, 0, 1365, 0, 5005, 0, 6435, 0, 3003, 0, 455, 0, 15, 0, 0, 0, 0,
+        ]),
+        (18, 15) => Some(&[
+            1, 0, 120, 0, 1820, 0, 8008, 0, 12870, 0, 8008, 0, 1820, 0, 120, 0, 1, 0, 0,
+        ]),
+        (18, 16) => Some(&[
+            1, 0, 136, 0, 2380, 0, 12376, 0, 24310, 0, 19448, 0, 6188, 0, 680, 0, 17, 0, 0,
+        ]),
+        (19, 10) => Some(&[
+            1, 0, 0, 0, 0, 28, 67, 96, 139, 176, 182, 152, 98, 52, 23, 8, 2, 0, 0, 0,
+        ]),
+        (19, 11) => Some(&[
+            1, 0, 0, 0, 60, 0, 192, 0, 590, 0, 672, 0, 428, 0, 96, 0, 9, 0, 0, 0,
+        ]),
+        (19, 12) => Some(&[
+            1, 0, 0, 0, 88, 0, 360, 0, 1242, 0, 1392, 0, 832, 0, 168, 0, 13, 0, 0, 0,
+        ]),
+        (19, 13) => Some(&[
+            1, 0, 0, 0, 164, 0, 784, 0, 2382, 0, 2848, 0, 1652, 0, 336, 0, 25, 0, 0, 0,
+        ]),
+        (19, 14) => Some(&[
+            1, 0, 0, 24, 164, 344, 784, 1624, 2382, 2904, 2848, 2312, 1652, 840, 336, 136, 25, 8,
+            0, 0,
+        ]),
+        (19, 15) => Some(&[
+            1, 0, 120, 0, 1820, 0, 8008, 0, 12870, 0, 8008, 0, 1820, 0, 120, 0, 1, 0, 0, 0,
+        ]),
+        (19, 16) => Some(&[
+            1, 0, 136, 0, 2380, 0, 12376, 0, 24310, 0, 19448, 0, 6188, 0, 680, 0, 17, 0, 0, 0,
+        ]),
+        (19, 17) => Some(&[
+            1, 0, 153, 0, 3060, 0, 18564, 0, 43758, 0, 43758, 0, 18564, 0, 3060, 0, 153, 0, 1, 0,
+        ]),
+        (20, 10) => Some(&[
+            1, 0, 0, 0, 0, 0, 95, 0, 235, 0, 358, 0, 250, 0, 75, 0, 10, 0, 0, 0, 0,
+        ]),
+        (20, 11) => Some(&[
+            1, 0, 0, 0, 0, 39, 95, 141, 235, 330, 358, 330, 250, 155, 75, 25, 10, 4, 0, 0, 0,
+        ]),
+        (20, 12) => Some(&[
+            1, 0, 0, 0, 70, 0, 300, 0, 996, 0, 1352, 0, 994, 0, 332, 0, 51, 0, 0, 0, 0,
+        ]),
+        (20, 13) => Some(&[
+            1, 0, 0, 0, 100, 0, 528, 0, 2062, 0, 2848, 0, 1972, 0, 592, 0, 89, 0, 0, 0, 0,
+        ]),
+        (20, 14) => Some(&[
+            1, 0, 0, 0, 189, 0, 1120, 0, 4034, 0, 5696, 0, 4034, 0, 1120, 0, 189, 0, 0, 0, 1,
+        ]),
+        (20, 15) => Some(&[
+            1, 0, 0, 32, 189, 480, 1120, 2464, 4034, 5216, 5696, 5216, 4034, 2464, 1120, 480, 189,
+            32, 0, 0, 1,
+        ]),
+        (20, 16) => Some(&[
+            1, 0, 136, 0, 2380, 0, 12376, 0, 24310, 0, 19448, 0, 6188, 0, 680, 0, 17, 0, 0, 0, 0,
+        ]),
+        (20, 17) => Some(&[
+            1, 0, 153, 0, 3060, 0, 18564, 0, 43758, 0, 43758, 0, 18564, 0, 3060, 0, 153, 0, 1, 0, 0,
+        ]),
+        (20, 18) => Some(&[
+            1, 0, 171, 0, 3876, 0, 27132, 0, 75582, 0, 92378, 0, 50388, 0, 11628, 0, 969, 0, 19, 0,
+            0,
+        ]),
+        (21, 10) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 120, 210, 0, 0, 336, 280, 0, 0, 56, 21, 0, 0, 0, 0, 0,
+        ]),
+        (21, 11) => Some(&[
+            1, 0, 0, 0, 0, 0, 134, 0, 376, 0, 688, 0, 580, 0, 230, 0, 35, 0, 4, 0, 0, 0,
+        ]),
+        (21, 12) => Some(&[
+            1, 0, 0, 0, 0, 51, 135, 210, 372, 585, 693, 684, 580, 405, 225, 98, 39, 15, 3, 0, 0, 0,
+        ]),
+        (21, 13) => Some(&[
+            1, 0, 0, 0, 78, 0, 396, 0, 1580, 0, 2696, 0, 2362, 0, 940, 0, 139, 0, 0, 0, 0, 0,
+        ]),
+        (21, 14) => Some(&[
+            1, 0, 0, 0, 120, 0, 756, 0, 3298, 0, 5476, 0, 4560, 0, 1820, 0, 341, 0, 12, 0, 0, 0,
+        ]),
+        (21, 15) => Some(&[
+            1, 0, 0, 0, 221, 0, 1600, 0, 6498, 0, 10912, 0, 9250, 0, 3584, 0, 669, 0, 32, 0, 1, 0,
+        ]),
+        (21, 16) => Some(&[
+            1, 0, 0, 40, 221, 640, 1600, 3648, 6498, 9152, 10912, 11024, 9250, 6400, 3584, 1664,
+            669, 192, 32, 8, 1, 0,
+        ]),
+        (21, 17) => Some(&[
+            1, 0, 153, 0, 3060, 0, 18564, 0, 43758, 0, 43758, 0, 18564, 0, 3060, 0, 153, 0, 1, 0,
+            0, 0,
+        ]),
+        (21, 18) => Some(&[
+            1, 0, 171, 0, 3876, 0, 27132, 0, 75582, 0, 92378, 0, 50388, 0, 11628, 0, 969, 0, 19, 0,
+            0, 0,
+        ]),
+        (21, 19) => Some(&[
+            1, 0, 190, 0, 4845, 0, 38760, 0, 125970, 0, 184756, 0, 125970, 0, 38760, 0, 4845, 0,
+            190, 0, 1, 0,
+        ]),
+        (22, 10) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 0, 330, 0, 0, 0, 616, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0,
+        ]),
+        (22, 11) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 176, 330, 0, 0, 672, 616, 0, 0, 176, 77, 0, 0, 0, 0, 0, 0,
+        ]),
+        (22, 12) => Some(&[
+            1, 0, 0, 0, 0, 0, 186, 0, 582, 0, 1278, 0, 1264, 0, 630, 0, 137, 0, 18, 0, 0, 0, 0,
+        ]),
+        (22, 13) => Some(&[
+            1, 0, 0, 0, 0, 66, 186, 308, 582, 990, 1278, 1368, 1264, 990, 630, 308, 137, 66, 18, 0,
+            0, 0, 0,
+        ]),
+        (22, 14) => Some(&[
+            1, 0, 0, 0, 96, 0, 580, 0, 2474, 0, 4884, 0, 5192, 0, 2572, 0, 557, 0, 28, 0, 0, 0, 0,
+        ]),
+        (22, 15) => Some(&[
+            1, 0, 0, 0, 145, 0, 1056, 0, 5154, 0, 10016, 0, 10122, 0, 4960, 0, 1213, 0, 96, 0, 5,
+            0, 0,
+        ]),
+        (22, 16) => Some(&[
+            1, 0, 0, 0, 263, 0, 2224, 0, 10202, 0, 19952, 0, 20414, 0, 9872, 0, 2389, 0, 208, 0,
+            11, 0, 0,
+        ]),
+        (22, 17) => Some(&[
+            1, 0, 0, 48, 263, 832, 2224, 5312, 10202, 15552, 19952, 22048, 20414, 15552, 9872,
+            5312, 2389, 832, 208, 48, 11, 0, 0,
+        ]),
+        (22, 18) => Some(&[
+            1, 0, 171, 0, 3876, 0, 27132, 0, 75582, 0, 92378, 0, 50388, 0, 11628, 0, 969, 0, 19, 0,
+            0, 0, 0,
+        ]),
+        (22, 19) => Some(&[
+            1, 0, 190, 0, 4845, 0, 38760, 0, 125970, 0, 184756, 0, 125970, 0, 38760, 0, 4845, 0,
+            190, 0, 1, 0, 0,
+        ]),
+        (22, 20) => Some(&[
+            1, 0, 210, 0, 5985, 0, 54264, 0, 203490, 0, 352716, 0, 293930, 0, 116280, 0, 20349, 0,
+            1330, 0, 21, 0, 0,
+        ]),
+        (23, 10) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 0, 330, 0, 0, 0, 616, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0,
+        ]),
+        (23, 11) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 0, 506, 0, 0, 0, 1288, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0,
+        ]),
+        (23, 12) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 253, 506, 0, 0, 1288, 1288, 0, 0, 506, 253, 0, 0, 0, 0, 0, 0, 1,
+        ]),
+        (23, 13) => Some(&[
+            1, 0, 0, 0, 0, 0, 252, 0, 890, 0, 2268, 0, 2632, 0, 1620, 0, 445, 0, 84, 0, 0, 0, 0, 0,
+        ]),
+        (23, 14) => Some(&[
+            1, 0, 0, 0, 0, 84, 252, 445, 890, 1620, 2268, 2632, 2632, 2268, 1620, 890, 445, 252,
+            84, 0, 0, 0, 0, 1,
+        ]),
+        (23, 15) => Some(&[
+            1, 0, 0, 0, 119, 0, 820, 0, 3822, 0, 8580, 0, 10754, 0, 6556, 0, 1937, 0, 172, 0, 7, 0,
+            0, 0,
+        ]),
+        (23, 16) => Some(&[
+            1, 0, 0, 0, 175, 0, 1440, 0, 7882, 0, 17696, 0, 21246, 0, 12640, 0, 3941, 0, 480, 0,
+            35, 0, 0, 0,
+        ]),
+        (23, 17) => Some(&[
+            1, 0, 0, 0, 315, 0, 3024, 0, 15626, 0, 35280, 0, 42742, 0, 25200, 0, 7813, 0, 1008, 0,
+            63, 0, 0, 0,
+        ]),
+        (23, 18) => Some(&[
+            1, 0, 0, 56, 315, 1064, 3024, 7616, 15626, 25600, 35280, 42224, 42742, 35728, 25200,
+            15360, 7813, 3136, 1008, 280, 63, 8, 0, 0,
+        ]),
+        (23, 19) => Some(&[
+            1, 0, 190, 0, 4845, 0, 38760, 0, 125970, 0, 184756, 0, 125970, 0, 38760, 0, 4845, 0,
+            190, 0, 1, 0, 0, 0,
+        ]),
+        (23, 20) => Some(&[
+            1, 0, 210, 0, 5985, 0, 54264, 0, 203490, 0, 352716, 0, 293930, 0, 116280, 0, 20349, 0,
+            1330, 0, 21, 0, 0, 0,
+        ]),
+        (23, 21) => Some(&[
+            1, 0, 231, 0, 7315, 0, 74613, 0, 319770, 0, 646646, 0, 646646, 0, 319770, 0, 74613, 0,
+            7315, 0, 231, 0, 1, 0,
+        ]),
+        (24, 11) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 0, 506, 0, 0, 0, 1288, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]),
+        (24, 12) => Some(&[
+            1, 0, 0, 0, 0, 0, 0, 0, 759, 0, 0, 0, 2576, 0, 0, 0, 759, 0, 0, 0, 0, 0, 0, 0, 1,
+        ]),
+        (24, 13) => Some(&[
+            1, 0, 0, 0, 0, 0, 252, 0, 890, 0, 2268, 0, 2632, 0, 1620, 0, 445, 0, 84, 0, 0, 0, 0, 0,
+            0,
+        ]),
+        (24, 14) => Some(&[
+            1, 0, 0, 0, 0, 0, 336, 0, 1335, 0, 3888, 0, 5264, 0, 3888, 0, 1335, 0, 336, 0, 0, 0, 0,
+            0, 1,
+        ]),
+        (24, 15) => Some(&[
+            1, 0, 0, 0, 105, 0, 600, 0, 2922, 0, 7128, 0, 10498, 0, 8136, 0, 3093, 0, 264, 0, 21,
+            0, 0, 0, 0,
+        ]),
+        (24, 16) => Some(&[
+            1, 0, 0, 0, 147, 0, 1128, 0, 5826, 0, 14664, 0, 21246, 0, 15672, 0, 5997, 0, 792, 0,
+            63, 0, 0, 0, 0,
+        ]),
+        (24, 17) => Some(&[
+            1, 0, 0, 0, 210, 0, 1920, 0, 11823, 0, 30336, 0, 42492, 0, 30336, 0, 11823, 0, 1920, 0,
+            210, 0, 0, 0, 1,
+        ]),
+        (24, 18) => Some(&[
+            1, 0, 0, 0, 378, 0, 4032, 0, 23439, 0, 60480, 0, 85484, 0, 60480, 0, 23439, 0, 4032, 0,
+            378, 0, 0, 0, 1,
+        ]),
+        (24, 19) => Some(&[
+            1, 0, 0, 64, 378, 1344, 4032, 10752, 23439, 40960, 60480, 77952, 85484, 77952, 60480,
+            40960, 23439, 10752, 4032, 1344, 378, 64, 0, 0, 1,
+        ]),
+        (24, 20) => Some(&[
+            1, 0, 210, 0, 5985, 0, 54264, 0, 203490, 0, 352716, 0, 293930, 0, 116280, 0, 20349, 0,
+            1330, 0, 21, 0, 0, 0, 0,
+        ]),
+        (24, 21) => Some(&[
+            1, 0, 231, 0, 7315, 0, 74613, 0, 319770, 0, 646646, 0, 646646, 0, 319770, 0, 74613, 0,
+            7315, 0, 231, 0, 1, 0, 0,
+        ]),
+        (24, 22) => Some(&[
+            1, 0, 253, 0, 8855, 0, 100947, 0, 490314, 0, 1144066, 0, 1352078, 0, 817190, 0, 245157,
+            0, 33649, 0, 1771, 0, 23, 0, 0,
+        ]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::{
+        bounds::weight_distribution_of_span, catalog::fixed_code_constructors, BinaryCode,
+    };
+
+    /// Compute the weight distribution by enumerating all codewords
+    fn brute_force_spectrum(code: &dyn BinaryCode) -> Vec<u64> {
+        weight_distribution_of_span(code.generator_matrix())
+    }
+
+    #[test]
+    fn spectra_are_sane() {
+        for n in 0..30 {
+            for k in 0..n {
+                if let Some(spectrum) = guava_distance_spectrum(n, k) {
+                    assert_eq!(spectrum.len(), n + 1);
+                    assert_eq!(spectrum[0], 1);
+                    assert_eq!(spectrum.iter().sum::<u64>(), 1 << k);
+                }
+            }
+        }
+        assert_eq!(guava_distance_spectrum(8, 4), None);
+    }
+
+    #[test]
+    fn brute_force_hamming_spectrum() {
+        let code = crate::codes::HammingCode::new(3);
+        assert_eq!(brute_force_spectrum(&code), vec![1, 0, 0, 7, 7, 0, 0, 1]);
+    }
+
+    #[test]
+    fn fixed_code_spectra() {
+        for constructor in fixed_code_constructors() {
+            let code = constructor();
+            // the table holds the spectra of the Guava codes, other codes with the same
+            // parameters may have a different one
+            if !code.name().ends_with("Guava code") {
+                continue;
+            }
+            if let Some(spectrum) = guava_distance_spectrum(code.length(), code.dimension()) {
+                assert_eq!(
+                    spectrum,
+                    &brute_force_spectrum(&*code)[..],
+                    "{}",
+                    code.name()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::codes::{kernel_basis, BinaryCode};
+use crate::fwht::fwht;
+use m4ri_rust::friendly::*;
+
+/// First-order Reed-Muller code $R(1, m)$ from the Sylvester Hadamard matrix
+///
+/// The $2^m \times 2^m$ Hadamard matrix is built as $H_{2^m} = H_2 \otimes H_{2^{m-1}}$.
+/// Mapping $1 \mapsto 0$ and $-1 \mapsto 1$, its rows and their complements are the
+/// codewords, which gives a $[2^m, m + 1, 2^{m-1}]$ code.
+/// Decoding correlates with all rows at once with a fast Walsh-Hadamard transform.
+#[derive(Clone, Serialize)]
+pub struct HadamardCode {
+    m: usize,
+    generator: BinMatrix,
+    parity_check: BinMatrix,
+}
+
+/// The binary Sylvester Hadamard matrix of size $2^m$
+fn binary_hadamard_matrix(m: usize) -> Vec<Vec<bool>> {
+    let mut matrix = vec![vec![false]];
+    for _ in 0..m {
+        // [[H, H], [H, -H]]
+        let top = matrix
+            .iter()
+            .map(|row| row.iter().chain(row.iter()).copied().collect());
+        let bottom = matrix
+            .iter()
+            .map(|row| row.iter().copied().chain(row.iter().map(|&x| !x)).collect());
+        matrix = top.chain(bottom).collect();
+    }
+    matrix
+}
+
+impl HadamardCode {
+    /// Construct $R(1, m)$ of length $2^m$
+    pub fn new(m: usize) -> HadamardCode {
+        assert!((1..=12).contains(&m), "1 <= m <= 12");
+        let n = 1 << m;
+        let hadamard = binary_hadamard_matrix(m);
+        // the all-ones word and the rows 2^i span all rows and their complements
+        let generator_rows: Vec<Vec<bool>> = std::iter::once(vec![true; n])
+            .chain((0..m).map(|i| hadamard[1 << i].clone()))
+            .collect();
+        let generator = BinMatrix::new(
+            generator_rows
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        let (_, parity_checks) = kernel_basis(generator_rows, n);
+        let parity_check = BinMatrix::new(
+            parity_checks
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        HadamardCode {
+            m,
+            generator,
+            parity_check,
+        }
+    }
+}
+
+impl BinaryCode for HadamardCode {
+    fn name(&self) -> String {
+        format!("[{}, {}] Hadamard code", self.length(), self.dimension())
+    }
+
+    fn length(&self) -> usize {
+        1 << self.m
+    }
+
+    fn dimension(&self) -> usize {
+        self.m + 1
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    /// Maximum likelihood decoding
+    ///
+    /// Row $u$ of the Hadamard matrix is the codeword $\langle u, j \rangle$, so the
+    /// transform of $(-1)^{c_j}$ has its largest absolute value at the closest row.
+    /// A negative correlation means the complement is closer.
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let mut correlations: Vec<i64> = c.iter().map(|bit| if bit { -1 } else { 1 }).collect();
+        fwht(&mut correlations, self.m as u32);
+        let u = (0..correlations.len())
+            .max_by_key(|&u| correlations[u].abs())
+            .unwrap();
+        let complement = correlations[u] < 0;
+        let mut message = vec![complement];
+        message.extend((0..self.m).map(|i| (u >> i) & 1 == 1));
+        Ok(BinVector::from_bools(&message))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn codespace(code: &dyn BinaryCode) -> HashSet<BinVector> {
+        (0..(1 << code.dimension()))
+            .map(|x: usize| {
+                code.encode(&BinVector::from_bools(
+                    &(0..code.dimension())
+                        .map(|i| (x >> i) & 1 == 1)
+                        .collect::<Vec<bool>>(),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parameters() {
+        for m in 1..6 {
+            let code = HadamardCode::new(m);
+            assert_eq!(code.length(), 1 << m);
+            assert_eq!(code.dimension(), m + 1);
+            let codewords = codespace(&code);
+            assert_eq!(codewords.len(), 1 << (m + 1));
+            let min_distance = codewords
+                .iter()
+                .map(|c| c.count_ones())
+                .filter(|&w| w > 0)
+                .min();
+            assert_eq!(min_distance, Some(1 << (m - 1)));
+            let h_transposed = code.parity_check_matrix().transposed();
+            for c in codewords.iter() {
+                assert_eq!((c * &h_transposed).count_ones(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn same_as_reed_muller() {
+        // R(1, 4) as evaluations of the affine functions on GF(2)^4
+        let reed_muller = BinMatrix::new(
+            std::iter::once(BinVector::from_elem(16, true))
+                .chain((0..4).map(|i| {
+                    BinVector::from_bools(
+                        &(0..16).map(|j| (j >> i) & 1 == 1).collect::<Vec<bool>>(),
+                    )
+                }))
+                .collect(),
+        );
+        let reed_muller: HashSet<BinVector> = (0..32usize)
+            .map(|x| {
+                &BinVector::from_bools(&(0..5).map(|i| (x >> i) & 1 == 1).collect::<Vec<bool>>())
+                    * &reed_muller
+            })
+            .collect();
+        assert_eq!(codespace(&HadamardCode::new(4)), reed_muller);
+    }
+
+    #[test]
+    fn decoding() {
+        let code = HadamardCode::new(5);
+        for _ in 0..100 {
+            let m = BinVector::random(code.dimension());
+            let codeword = code.encode(&m);
+            let mut received = codeword.clone();
+            // up to (d - 1) / 2 = 7 errors
+            for i in 0..7 {
+                let bit = received[4 * i + 1];
+                received.set(4 * i + 1, !bit);
+            }
+            assert_eq!(code.decode_to_message(&received), Ok(m));
+            assert_eq!(code.decode_to_code(&received), Ok(codeword));
+        }
+    }
+}
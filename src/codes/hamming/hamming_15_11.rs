@@ -1,5 +1,4 @@
-use std::sync::Once;
-use std::boxed::Box;
+use std::sync::OnceLock;
 
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
@@ -13,39 +12,38 @@ use crate::oracle::{Sample, SAMPLE_LEN};
 #[derive(Debug,Serialize)]
 pub struct HammingCode15_11;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[24577],
-                &[20482],
-                &[12292],
-                &[17416],
-                &[9232],
-                &[5152],
-                &[29760],
-                &[13440],
-                &[21760],
-                &[26112],
-                &[30720],
-                
-            ], 15));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[24577],
+            &[20482],
+            &[12292],
+            &[17416],
+            &[9232],
+            &[5152],
+            &[29760],
+            &[13440],
+            &[21760],
+            &[26112],
+            &[30720],
+            
+        ], 15)
+    })
+}
 
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                    &[21845],
-                    &[26214],
-                    &[30840],
-                    &[32640],
-                    
-            ], 15));
-            PARITY_MATRIX = Box::into_raw(matrix);
-        }
-    });
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+                &[21845],
+                &[26214],
+                &[30840],
+                &[32640],
+                
+        ], 15)
+    })
 }
 
 // only supports 1-block syndromes
@@ -34886,17 +34884,11 @@ impl BinaryCode for HammingCode15_11 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
@@ -34938,6 +34930,10 @@ impl BinaryCode for HammingCode15_11 {
         c[0] = SYNDROME[c[0] as usize] as u64
     }
 
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
     // for hamming codes
     fn bias(&self, delta: f64) -> f64 {
         (1f64 + f64::from(15) * delta) / f64::from(15 + 1)
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -16,103 +15,104 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct HammingCode31_26;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1610612737 ],
-                &[ 1342177282 ],
-                &[ 805306372 ],
-                &[ 1207959560 ],
-                &[ 671088656 ],
-                &[ 402653216 ],
-                &[ 2013265984 ],
-                &[ 1140850816 ],
-                &[ 603980032 ],
-                &[ 335544832 ],
-                &[ 1946158080 ],
-                &[ 201328640 ],
-                &[ 1811943424 ],
-                &[ 1543512064 ],
-                &[ 1006649344 ],
-                &[ 2080407552 ],
-                &[ 469827584 ],
-                &[ 738328576 ],
-                &[ 1275330560 ],
-                &[ 872939520 ],
-                &[ 1410334720 ],
-                &[ 1679818752 ],
-                &[ 943718400 ],
-                &[ 1484783616 ],
-                &[ 1761607680 ],
-                &[ 1912602624 ],
-                
-            ], 31));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 1553290581 ],
-                &[ 1831233126 ],
-                &[ 1983412344 ],
-                &[ 2076213120 ],
-                &[ 2147450880 ],
-                
-            ], 31));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1610612737 ],
+            &[ 1342177282 ],
+            &[ 805306372 ],
+            &[ 1207959560 ],
+            &[ 671088656 ],
+            &[ 402653216 ],
+            &[ 2013265984 ],
+            &[ 1140850816 ],
+            &[ 603980032 ],
+            &[ 335544832 ],
+            &[ 1946158080 ],
+            &[ 201328640 ],
+            &[ 1811943424 ],
+            &[ 1543512064 ],
+            &[ 1006649344 ],
+            &[ 2080407552 ],
+            &[ 469827584 ],
+            &[ 738328576 ],
+            &[ 1275330560 ],
+            &[ 872939520 ],
+            &[ 1410334720 ],
+            &[ 1679818752 ],
+            &[ 943718400 ],
+            &[ 1484783616 ],
+            &[ 1761607680 ],
+            &[ 1912602624 ],
+            
+        ], 31)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(32, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(3, &[4]);     // 3 => [4]
-            map.insert(4, &[8]);     // 4 => [8]
-            map.insert(5, &[16]);     // 5 => [16]
-            map.insert(6, &[32]);     // 6 => [32]
-            map.insert(7, &[64]);     // 7 => [64]
-            map.insert(8, &[128]);     // 8 => [128]
-            map.insert(9, &[256]);     // 9 => [256]
-            map.insert(10, &[512]);     // 10 => [512]
-            map.insert(11, &[1024]);     // 11 => [1024]
-            map.insert(12, &[2048]);     // 12 => [2048]
-            map.insert(13, &[4096]);     // 13 => [4096]
-            map.insert(14, &[8192]);     // 14 => [8192]
-            map.insert(15, &[16384]);     // 15 => [16384]
-            map.insert(16, &[32768]);     // 16 => [32768]
-            map.insert(17, &[65536]);     // 17 => [65536]
-            map.insert(18, &[131072]);     // 18 => [131072]
-            map.insert(19, &[262144]);     // 19 => [262144]
-            map.insert(20, &[524288]);     // 20 => [524288]
-            map.insert(21, &[1048576]);     // 21 => [1048576]
-            map.insert(22, &[2097152]);     // 22 => [2097152]
-            map.insert(24, &[4194304]);     // 24 => [4194304]
-            map.insert(25, &[8388608]);     // 25 => [8388608]
-            map.insert(26, &[16777216]);     // 26 => [16777216]
-            map.insert(28, &[33554432]);     // 28 => [33554432]
-            map.insert(23, &[67108864]);     // 23 => [67108864]
-            map.insert(27, &[134217728]);     // 27 => [134217728]
-            map.insert(29, &[268435456]);     // 29 => [268435456]
-            map.insert(30, &[536870912]);     // 30 => [536870912]
-            map.insert(31, &[1073741824]);     // 31 => [1073741824]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 1553290581 ],
+            &[ 1831233126 ],
+            &[ 1983412344 ],
+            &[ 2076213120 ],
+            &[ 2147450880 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 31)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(32, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(3, &[4]);     // 3 => [4]
+        map.insert(4, &[8]);     // 4 => [8]
+        map.insert(5, &[16]);     // 5 => [16]
+        map.insert(6, &[32]);     // 6 => [32]
+        map.insert(7, &[64]);     // 7 => [64]
+        map.insert(8, &[128]);     // 8 => [128]
+        map.insert(9, &[256]);     // 9 => [256]
+        map.insert(10, &[512]);     // 10 => [512]
+        map.insert(11, &[1024]);     // 11 => [1024]
+        map.insert(12, &[2048]);     // 12 => [2048]
+        map.insert(13, &[4096]);     // 13 => [4096]
+        map.insert(14, &[8192]);     // 14 => [8192]
+        map.insert(15, &[16384]);     // 15 => [16384]
+        map.insert(16, &[32768]);     // 16 => [32768]
+        map.insert(17, &[65536]);     // 17 => [65536]
+        map.insert(18, &[131072]);     // 18 => [131072]
+        map.insert(19, &[262144]);     // 19 => [262144]
+        map.insert(20, &[524288]);     // 20 => [524288]
+        map.insert(21, &[1048576]);     // 21 => [1048576]
+        map.insert(22, &[2097152]);     // 22 => [2097152]
+        map.insert(24, &[4194304]);     // 24 => [4194304]
+        map.insert(25, &[8388608]);     // 25 => [8388608]
+        map.insert(26, &[16777216]);     // 26 => [16777216]
+        map.insert(28, &[33554432]);     // 28 => [33554432]
+        map.insert(23, &[67108864]);     // 23 => [67108864]
+        map.insert(27, &[134217728]);     // 27 => [134217728]
+        map.insert(29, &[268435456]);     // 29 => [268435456]
+        map.insert(30, &[536870912]);     // 30 => [536870912]
+        map.insert(31, &[1073741824]);     // 31 => [1073741824]
+        map
+    })
 }
 
 impl HammingCode31_26 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -130,24 +130,15 @@ impl BinaryCode for HammingCode31_26 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(31);
@@ -172,14 +163,19 @@ impl BinaryCode for HammingCode31_26 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[31 / 64] & !((1 << 31) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -381,8 +377,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,5 +1,4 @@
-use std::sync::Once;
-use std::boxed::Box;
+use std::sync::OnceLock;
 
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
@@ -13,31 +12,30 @@ use crate::oracle::{Sample, SAMPLE_LEN};
 #[derive(Debug,Serialize)]
 pub struct HammingCode7_4;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[97],
-                &[82],
-                &[52],
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[97],
+            &[82],
+            &[52],
+            &[120],
+            
+        ], 7)
+    })
+}
+
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+                &[85],
+                &[102],
                 &[120],
                 
-            ], 7));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                    &[85],
-                    &[102],
-                    &[120],
-                    
-            ], 7));
-            PARITY_MATRIX = Box::into_raw(matrix);
-        }
-    });
+        ], 7)
+    })
 }
 
 // only supports 1-block syndromes
@@ -206,17 +204,11 @@ impl BinaryCode for HammingCode7_4 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
@@ -258,6 +250,10 @@ impl BinaryCode for HammingCode7_4 {
         c[0] = SYNDROME[c[0] as usize] as u64
     }
 
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
     // for hamming codes
     fn bias(&self, delta: f64) -> f64 {
         (1f64 + f64::from(7) * delta) / f64::from(7 + 1)
@@ -0,0 +1,192 @@
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::BinMatrix;
+use m4ri_rust::friendly::BinVector;
+
+/// $[2^r - 1, 2^r - 1 - r]$ Hamming code, constructed at runtime
+///
+/// Column $j$ of the parity check matrix is the binary representation of $j + 1$,
+/// so the syndrome of a single error directly gives its position. The positions
+/// $2^i - 1$ hold the parity bits, the other positions hold the message.
+///
+/// The generated `HammingCodeN_K` codes are the same codes with lookup tables.
+#[derive(Debug, Clone, Serialize)]
+pub struct HammingCode {
+    r: usize,
+    generator: BinMatrix,
+    parity: BinMatrix,
+    #[serde(skip)]
+    parity_t: BinMatrix,
+}
+
+impl HammingCode {
+    /// Construct the $[2^r - 1, 2^r - 1 - r]$ Hamming code
+    ///
+    /// Panics unless $2 \le r \le 10$.
+    pub fn new(r: u32) -> HammingCode {
+        assert!((2..=10).contains(&r), "Need 2 <= r <= 10");
+        let r = r as usize;
+        let n = (1 << r) - 1;
+        let parity = BinMatrix::new(
+            (0..r)
+                .map(|i| {
+                    BinVector::from_bools(
+                        &(0..n)
+                            .map(|j| ((j + 1) >> i) & 1 == 1)
+                            .collect::<Vec<bool>>(),
+                    )
+                })
+                .collect(),
+        );
+        let generator = BinMatrix::new(
+            Self::message_positions(n)
+                .map(|p| {
+                    BinVector::from_bools(
+                        &(0..n)
+                            .map(|j| {
+                                j == p || ((j + 1).is_power_of_two() && (p + 1) & (j + 1) != 0)
+                            })
+                            .collect::<Vec<bool>>(),
+                    )
+                })
+                .collect(),
+        );
+        let parity_t = parity.transposed();
+        HammingCode {
+            r,
+            generator,
+            parity,
+            parity_t,
+        }
+    }
+
+    /// The positions that are not parity bits
+    fn message_positions(n: usize) -> impl Iterator<Item = usize> {
+        (0..n).filter(|j| !(j + 1).is_power_of_two())
+    }
+}
+
+impl BinaryCode for HammingCode {
+    fn name(&self) -> String {
+        format!("[{}, {}] Hamming code", self.length(), self.dimension())
+    }
+
+    fn length(&self) -> usize {
+        (1 << self.r) - 1
+    }
+
+    fn dimension(&self) -> usize {
+        self.length() - self.r
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length(),
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let syndrome = (c * &self.parity_t).as_u64() as usize;
+        let mut result = c.clone();
+        if syndrome != 0 {
+            result.set(syndrome - 1, !c[syndrome - 1]);
+        }
+        Ok(result)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let codeword = self.decode_to_code(c)?;
+        Ok(BinVector::from_bools(
+            &Self::message_positions(self.length())
+                .map(|p| codeword[p])
+                .collect::<Vec<bool>>(),
+        ))
+    }
+
+    /// Hamming codes are perfect codes with minimum distance 3
+    fn covering_radius(&self) -> usize {
+        1
+    }
+
+    fn error_correction_capability(&self) -> usize {
+        1
+    }
+
+    /// Every vector is within distance one of exactly one codeword
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameters() {
+        for r in 2..=6 {
+            let code = HammingCode::new(r);
+            let n = (1 << r) - 1;
+            assert_eq!(code.length(), n);
+            assert_eq!(code.dimension(), n - r as usize);
+            let product = code.generator_matrix() * &code.parity_t;
+            assert_eq!(product, BinMatrix::zero(code.dimension(), r as usize));
+            assert_eq!(code.generator_matrix().rank(), code.dimension());
+        }
+    }
+
+    #[test]
+    fn corrects_single_errors() {
+        for r in 2..=6 {
+            let code = HammingCode::new(r);
+            for _ in 0..20 {
+                let message = BinVector::random(code.dimension());
+                let codeword = code.encode(&message);
+                assert_eq!(code.decode_to_message(&codeword).unwrap(), message);
+                for pos in 0..code.length() {
+                    let mut received = codeword.clone();
+                    received.set(pos, !received[pos]);
+                    assert_eq!(code.decode_to_code(&received).unwrap(), codeword);
+                    assert_eq!(code.decode_to_message(&received).unwrap(), message);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bias_from_coset_leaders() {
+        // every coset of a perfect code with distance 3 has a leader of weight at most one
+        let code = HammingCode::new(5);
+        assert_eq!(code.coset_leader_weight_distribution(), vec![1, 31]);
+        for &delta in [0.0, 0.25, 0.8].iter() {
+            let bias = code.bias(delta);
+            assert!((bias - (1.0 + 31.0 * delta) / 32.0).abs() < 1e-12);
+            assert_eq!(bias, code.bias(delta), "the bias should be deterministic");
+        }
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn same_as_generated() {
+        use crate::codes::{HammingCode15_11, HammingCode7_4};
+        let generated: [&dyn BinaryCode; 2] = [&HammingCode7_4, &HammingCode15_11];
+        for (r, other) in (3..=4).zip(generated.iter()) {
+            let code = HammingCode::new(r);
+            assert_eq!(code.length(), other.length());
+            assert_eq!(code.dimension(), other.dimension());
+            for _ in 0..100 {
+                let received = BinVector::random(code.length());
+                let ours = code.decode_to_code(&received).unwrap();
+                let theirs = other.decode_to_code(&received).unwrap();
+                // both are perfect codes, so the nearest codeword is unique
+                assert_eq!(ours, theirs);
+            }
+        }
+    }
+}
@@ -50,6 +50,11 @@ impl BinaryCode for IdentityCode {
         panic!("Doesn't have one");
     }
 
+    /// Every vector of length $k$ is a codeword
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        v.len() == self.k
+    }
+
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
         Ok(c.clone())
     }
@@ -66,6 +71,14 @@ impl BinaryCode for IdentityCode {
         // identity
     }
 
+    fn covering_radius(&self) -> usize {
+        0
+    }
+
+    fn error_correction_capability(&self) -> usize {
+        0
+    }
+
     fn bias(&self, _delta: f64) -> f64 {
         1f64
     }
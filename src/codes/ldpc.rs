@@ -0,0 +1,522 @@
+use crate::codes::{kernel_basis, BinaryCode};
+use fnv::FnvHashSet;
+use itertools::Itertools;
+use m4ri_rust::friendly::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Reverse;
+
+/// Degree of the variable nodes in the random ensemble
+const ENSEMBLE_COLUMN_WEIGHT: usize = 3;
+
+/// Number of graphs to try before giving up on the random ensemble
+const ENSEMBLE_ATTEMPTS: usize = 1000;
+
+/// Low-density parity-check code constructed from its Tanner graph
+///
+/// Decoding uses sum-product (or min-sum) belief propagation, treating the input
+/// vector as received over a binary symmetric channel.
+#[derive(Clone, Serialize)]
+pub struct LdpcCode {
+    n: usize,
+    k: usize,
+    /// The variable nodes connected to each check node
+    check_neighbours: Vec<Vec<usize>>,
+    /// The positions of the message bits in a codeword
+    information_set: Vec<usize>,
+    parity_check: BinMatrix,
+    generator: BinMatrix,
+    crossover_probability: f64,
+    max_iterations: usize,
+    min_sum: bool,
+}
+
+impl LdpcCode {
+    /// Construct the code from a Tanner graph
+    ///
+    /// `edges` are `(variable_node_index, check_node_index)` pairs.
+    /// Every check node gives a row of the parity check matrix.
+    pub fn from_tanner_graph(
+        variable_nodes: usize,
+        check_nodes: usize,
+        edges: &[(usize, usize)],
+    ) -> LdpcCode {
+        let mut rows = vec![vec![false; variable_nodes]; check_nodes];
+        let mut check_neighbours = vec![Vec::new(); check_nodes];
+        for &(variable, check) in edges {
+            assert!(
+                variable < variable_nodes,
+                "Invalid variable node {}",
+                variable
+            );
+            assert!(check < check_nodes, "Invalid check node {}", check);
+            assert!(
+                !rows[check][variable],
+                "Duplicate edge {:?}",
+                (variable, check)
+            );
+            rows[check][variable] = true;
+            check_neighbours[check].push(variable);
+        }
+        let parity_check =
+            BinMatrix::new(rows.iter().map(|row| BinVector::from_bools(row)).collect());
+
+        let (information_set, kernel) = kernel_basis(rows, variable_nodes);
+        assert!(!information_set.is_empty(), "This code has dimension 0");
+        let generator = BinMatrix::new(
+            kernel
+                .iter()
+                .map(|codeword| BinVector::from_bools(codeword))
+                .collect(),
+        );
+
+        LdpcCode {
+            n: variable_nodes,
+            k: information_set.len(),
+            check_neighbours,
+            information_set,
+            parity_check,
+            generator,
+            crossover_probability: 0.1,
+            max_iterations: 50,
+            min_sum: false,
+        }
+    }
+
+    /// Sample a code with $n - k$ check nodes from the regular LDPC ensemble
+    ///
+    /// Every column of the parity check matrix has weight `col_weight` and every row
+    /// has weight `row_weight`, so we need $n \cdot$ `col_weight` $= (n - k) \cdot$
+    /// `row_weight`. Unlike [`ldpc_random_ensemble`], columns may repeat and $H$ may
+    /// not have full rank, in which case the dimension is larger than $k$. This
+    /// always happens for even `col_weight`, as the rows of $H$ then add up to zero.
+    ///
+    /// The code decodes with sum-product belief propagation.
+    pub fn random(
+        n: usize,
+        k: usize,
+        row_weight: usize,
+        col_weight: usize,
+        rng: &mut impl Rng,
+    ) -> LdpcCode {
+        assert!(k > 0 && k < n, "0 < k < n");
+        let checks = n - k;
+        assert!(
+            0 < col_weight && col_weight <= checks,
+            "Need 0 < col_weight <= n - k"
+        );
+        assert_eq!(
+            n * col_weight,
+            checks * row_weight,
+            "The number of edges should match: n * col_weight = (n - k) * row_weight"
+        );
+        let edges = sample_biregular_graph(n, checks, col_weight, rng);
+        let code = LdpcCode::from_tanner_graph(n, checks, &edges);
+        if code.dimension() > k {
+            log::debug!(
+                "Parity check matrix of the [{}, {}] LDPC code has rank {}",
+                n,
+                k,
+                n - code.dimension()
+            );
+        }
+        code
+    }
+
+    /// Set the crossover probability of the channel assumed by the decoder
+    pub fn with_crossover_probability(mut self, probability: f64) -> LdpcCode {
+        assert!(
+            0.0 < probability && probability < 0.5,
+            "0 < probability < 0.5"
+        );
+        self.crossover_probability = probability;
+        self
+    }
+
+    /// Set the maximum number of belief propagation iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> LdpcCode {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Use the min-sum approximation instead of the sum-product rule in the check nodes
+    pub fn with_min_sum(mut self, min_sum: bool) -> LdpcCode {
+        self.min_sum = min_sum;
+        self
+    }
+
+    /// Run belief propagation decoding, returns `None` if it does not converge to a codeword
+    fn belief_propagation(&self, c: &BinVector) -> Option<Vec<bool>> {
+        debug_assert_eq!(c.len(), self.n);
+        let llr = ((1.0 - self.crossover_probability) / self.crossover_probability).ln();
+        let channel: Vec<f64> = (0..self.n).map(|j| if c[j] { -llr } else { llr }).collect();
+
+        let mut to_check: Vec<Vec<f64>> = self
+            .check_neighbours
+            .iter()
+            .map(|vars| vars.iter().map(|&j| channel[j]).collect())
+            .collect();
+        let mut to_variable: Vec<Vec<f64>> = to_check.iter().map(|m| vec![0.0; m.len()]).collect();
+
+        for _ in 0..self.max_iterations {
+            // check node update
+            for (incoming, outgoing) in to_check.iter().zip(to_variable.iter_mut()) {
+                for (t, message) in outgoing.iter_mut().enumerate() {
+                    let others = incoming
+                        .iter()
+                        .enumerate()
+                        .filter(|&(u, _)| u != t)
+                        .map(|(_, m)| m);
+                    *message = if self.min_sum {
+                        let (sign, magnitude) = others
+                            .fold((1.0, f64::INFINITY), |(sign, magnitude), m| {
+                                (sign * m.signum(), magnitude.min(m.abs()))
+                            });
+                        sign * magnitude
+                    } else {
+                        let product = others.fold(1.0, |acc, m| acc * (m / 2.0).tanh());
+                        let product = product.clamp(-1.0 + 1e-12, 1.0 - 1e-12);
+                        2.0 * product.atanh()
+                    };
+                }
+            }
+
+            // variable node update and tentative decision
+            let mut total = channel.clone();
+            for (vars, messages) in self.check_neighbours.iter().zip(to_variable.iter()) {
+                for (&j, m) in vars.iter().zip(messages.iter()) {
+                    total[j] += m;
+                }
+            }
+            // on ties, keep the received bit
+            let decision: Vec<bool> = total
+                .iter()
+                .zip(c.iter())
+                .map(|(&l, received)| l < 0.0 || (l == 0.0 && received))
+                .collect();
+            if self
+                .check_neighbours
+                .iter()
+                .all(|vars| vars.iter().filter(|&&j| decision[j]).count() % 2 == 0)
+            {
+                return Some(decision);
+            }
+
+            for ((vars, incoming), outgoing) in self
+                .check_neighbours
+                .iter()
+                .zip(to_variable.iter())
+                .zip(to_check.iter_mut())
+            {
+                for ((&j, m_in), m_out) in vars.iter().zip(incoming.iter()).zip(outgoing.iter_mut())
+                {
+                    *m_out = total[j] - m_in;
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Sample a code from the $(c, d)$-regular LDPC ensemble with $c = 3$ and $d = 3n/(n-k)$
+///
+/// The Tanner graph is built one variable node at a time, with constraints on the
+/// remaining degree of the check nodes and on the columns of $H$: every column is
+/// nonzero and all columns are distinct, so the minimum distance is at least 3.
+/// If $3n$ is not a multiple of $n-k$, some check nodes get degree $d+1$.
+/// Graphs for which $H$ does not have full rank are rejected, so the dimension is exactly $k$.
+///
+/// The code decodes with min-sum belief propagation.
+pub fn ldpc_random_ensemble(n: usize, k: usize, seed: u64) -> LdpcCode {
+    assert!(k > 0 && k < n, "0 < k < n");
+    let checks = n - k;
+    assert!(
+        checks >= ENSEMBLE_COLUMN_WEIGHT,
+        "Need at least {} check nodes",
+        ENSEMBLE_COLUMN_WEIGHT
+    );
+    assert!(
+        checks * (checks - 1) * (checks - 2) / 6 >= n,
+        "Not enough distinct columns for a code of length {}",
+        n
+    );
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    for attempt in 0..ENSEMBLE_ATTEMPTS {
+        if let Some(edges) = sample_regular_graph(n, checks, &mut rng) {
+            let code = LdpcCode::from_tanner_graph(n, checks, &edges);
+            if code.dimension() == k {
+                log::debug!(
+                    "Found [{}, {}] LDPC code after {} attempts",
+                    n,
+                    k,
+                    attempt + 1
+                );
+                return code.with_min_sum(true);
+            }
+        }
+    }
+    panic!(
+        "Could not construct a [{}, {}] LDPC code in {} attempts",
+        n, k, ENSEMBLE_ATTEMPTS
+    );
+}
+
+/// Try to sample the edges of a Tanner graph with distinct columns of weight 3
+///
+/// Every variable node is connected to the first triple of check nodes with the most
+/// remaining degree that does not repeat an earlier column. Ties are broken randomly.
+fn sample_regular_graph(
+    variable_nodes: usize,
+    check_nodes: usize,
+    rng: &mut impl Rng,
+) -> Option<Vec<(usize, usize)>> {
+    let total = ENSEMBLE_COLUMN_WEIGHT * variable_nodes;
+    let mut remaining: Vec<usize> = (0..check_nodes)
+        .map(|check| total / check_nodes + usize::from(check < total % check_nodes))
+        .collect();
+    let mut columns = FnvHashSet::default();
+    let mut edges = Vec::with_capacity(total);
+    for variable in 0..variable_nodes {
+        let mut candidates: Vec<usize> = (0..check_nodes)
+            .filter(|&check| remaining[check] > 0)
+            .collect();
+        candidates.shuffle(rng);
+        candidates.sort_by_key(|&check| Reverse(remaining[check]));
+        let column = candidates
+            .into_iter()
+            .tuple_combinations()
+            .map(|(a, b, c)| {
+                let mut column = [a, b, c];
+                column.sort_unstable();
+                column
+            })
+            .find(|column| !columns.contains(column))?;
+        for &check in column.iter() {
+            remaining[check] -= 1;
+            edges.push((variable, check));
+        }
+        columns.insert(column);
+    }
+    Some(edges)
+}
+
+/// Sample the edges of a Tanner graph in which every variable node has degree `col_weight`
+///
+/// Every variable node is connected to the `col_weight` check nodes with the most
+/// remaining degree, with ties broken randomly. This keeps the remaining degrees within
+/// one of each other, so the last variable nodes always find enough check nodes.
+fn sample_biregular_graph(
+    variable_nodes: usize,
+    check_nodes: usize,
+    col_weight: usize,
+    rng: &mut impl Rng,
+) -> Vec<(usize, usize)> {
+    let total = col_weight * variable_nodes;
+    let mut remaining: Vec<usize> = (0..check_nodes)
+        .map(|check| total / check_nodes + usize::from(check < total % check_nodes))
+        .collect();
+    let mut edges = Vec::with_capacity(total);
+    for variable in 0..variable_nodes {
+        let mut candidates: Vec<usize> = (0..check_nodes).collect();
+        candidates.shuffle(rng);
+        candidates.sort_by_key(|&check| Reverse(remaining[check]));
+        for &check in &candidates[..col_weight] {
+            debug_assert!(remaining[check] > 0);
+            remaining[check] -= 1;
+            edges.push((variable, check));
+        }
+    }
+    edges
+}
+
+impl BinaryCode for LdpcCode {
+    fn name(&self) -> String {
+        format!("[{}, {}] LDPC code", self.n, self.k)
+    }
+
+    fn length(&self) -> usize {
+        self.n
+    }
+
+    fn dimension(&self) -> usize {
+        self.k
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let codeword = self
+            .belief_propagation(c)
+            .ok_or("Belief propagation did not converge")?;
+        Ok(BinVector::from_bools(&codeword))
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let codeword = self
+            .belief_propagation(c)
+            .ok_or("Belief propagation did not converge")?;
+        let message: Vec<bool> = self.information_set.iter().map(|&i| codeword[i]).collect();
+        Ok(BinVector::from_bools(&message))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tanner graph of a [6, 3, 3] code: every check node has degree 3
+    fn tanner_code() -> LdpcCode {
+        let edges = [
+            (0, 0),
+            (1, 0),
+            (3, 0),
+            (1, 1),
+            (2, 1),
+            (4, 1),
+            (0, 2),
+            (2, 2),
+            (5, 2),
+        ];
+        LdpcCode::from_tanner_graph(6, 3, &edges)
+    }
+
+    fn codewords(code: &LdpcCode) -> Vec<BinVector> {
+        (0..(1 << code.dimension()))
+            .map(|m: usize| {
+                let message: Vec<bool> = (0..code.dimension()).map(|i| (m >> i) & 1 == 1).collect();
+                code.encode(&BinVector::from_bools(&message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parameters() {
+        let code = tanner_code();
+        assert_eq!(code.length(), 6);
+        assert_eq!(code.dimension(), 3);
+
+        let h_transposed = code.parity_check_matrix().transposed();
+        let codewords = codewords(&code);
+        for codeword in codewords.iter() {
+            assert_eq!((codeword * &h_transposed).count_ones(), 0);
+        }
+        let min_distance = codewords
+            .iter()
+            .map(BinVector::count_ones)
+            .filter(|&w| w > 0)
+            .min();
+        assert_eq!(min_distance, Some(3));
+    }
+
+    #[test]
+    fn corrects_single_errors() {
+        let code = tanner_code();
+        for (m, codeword) in codewords(&code).into_iter().enumerate() {
+            assert_eq!(code.decode_to_code(&codeword), Ok(codeword.clone()));
+            for error in 0..code.length() {
+                let mut received = codeword.clone();
+                let bit = received[error];
+                received.set(error, !bit);
+                assert_eq!(code.decode_to_code(&received), Ok(codeword.clone()));
+                let message = code.decode_to_message(&received).unwrap();
+                assert_eq!(code.encode(&message), codeword, "message {}", m);
+            }
+        }
+    }
+
+    fn ensemble_codewords(code: &LdpcCode, count: usize) -> Vec<BinVector> {
+        std::iter::once(BinVector::from_elem(code.dimension(), false))
+            .chain((0..count).map(|_| BinVector::random(code.dimension())))
+            .map(|message| code.encode(&message))
+            .collect()
+    }
+
+    #[test]
+    fn random_ensemble_parameters() {
+        for &(n, k) in &[(20, 10), (30, 20), (48, 24), (100, 50)] {
+            let code = ldpc_random_ensemble(n, k, 42);
+            let target = k as f64 / n as f64;
+            let rate = code.dimension() as f64 / code.length() as f64;
+            assert!((rate - target).abs() <= 0.01 * target, "rate {}", rate);
+
+            // distinct columns of weight 3 give minimum distance at least 3
+            let h_transposed = code.parity_check_matrix().transposed();
+            let mut seen = FnvHashSet::default();
+            for i in 0..n {
+                let mut unit = BinVector::from_elem(n, false);
+                unit.set(i, true);
+                let column = &unit * &h_transposed;
+                assert_eq!(column.count_ones(), ENSEMBLE_COLUMN_WEIGHT as u32);
+                assert!(seen.insert(column), "repeated column {}", i);
+            }
+
+            let same = ldpc_random_ensemble(n, k, 42);
+            assert_eq!(code.parity_check_matrix(), same.parity_check_matrix());
+        }
+
+        let code = ldpc_random_ensemble(20, 10, 1);
+        let min_distance = codewords(&code)
+            .iter()
+            .map(BinVector::count_ones)
+            .filter(|&w| w > 0)
+            .min()
+            .unwrap();
+        assert!(min_distance >= 3, "minimum distance {}", min_distance);
+    }
+
+    #[test]
+    fn random_regular() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for &(n, k, row_weight, col_weight) in &[(24, 12, 6, 3), (40, 20, 8, 4), (60, 40, 9, 3)] {
+            let code = LdpcCode::random(n, k, row_weight, col_weight, &mut rng);
+            let h = code.parity_check_matrix();
+            assert_eq!((h.nrows(), h.ncols()), (n - k, n));
+            for row in 0..n - k {
+                let weight = (0..n).filter(|&col| h.bit(row, col)).count();
+                assert_eq!(weight, row_weight);
+            }
+            for col in 0..n {
+                let weight = (0..n - k).filter(|&row| h.bit(row, col)).count();
+                assert_eq!(weight, col_weight);
+            }
+            assert!(code.dimension() >= k);
+            if col_weight % 2 == 0 {
+                assert!(code.dimension() > k);
+            }
+            for codeword in ensemble_codewords(&code, 20) {
+                assert!(code.is_codeword(&codeword));
+                assert_eq!(code.decode_to_code(&codeword), Ok(codeword.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn random_ensemble_corrects_single_errors() {
+        for &(n, k) in &[(20, 10), (30, 20), (48, 24), (50, 40)] {
+            let code = ldpc_random_ensemble(n, k, 7);
+            for codeword in ensemble_codewords(&code, 20) {
+                assert_eq!(code.decode_to_code(&codeword), Ok(codeword.clone()));
+                for error in 0..n {
+                    let mut received = codeword.clone();
+                    let bit = received[error];
+                    received.set(error, !bit);
+                    assert_eq!(
+                        code.decode_to_code(&received),
+                        Ok(codeword.clone()),
+                        "[{}, {}] code, error at {}",
+                        n,
+                        k,
+                        error
+                    );
+                }
+            }
+        }
+    }
+}
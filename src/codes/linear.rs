@@ -0,0 +1,448 @@
+use crate::codes::{kernel_basis, utils::build_syndrome_map, BinaryCode};
+use fnv::FnvHashMap;
+use m4ri_rust::friendly::BinMatrix;
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+
+/// Linear code given by a generator matrix at runtime
+///
+/// Decodes using Syndrome decoding, with a table of coset leaders
+/// for all $2^{n-k}$ syndromes.
+#[derive(Clone)]
+pub struct LinearCode {
+    generator: BinMatrix,
+    parity: BinMatrix,
+    parity_t: BinMatrix,
+    syndrome_map: FnvHashMap<u64, Vec<u64>>,
+    length: usize,
+    dimension: usize,
+    /// Columns of the generator matrix that form an invertible submatrix
+    information_set: Vec<usize>,
+    /// Inverse of the generator matrix restricted to the information set,
+    /// `None` if that restriction is the identity
+    information_inverse: Option<BinMatrix>,
+}
+
+impl LinearCode {
+    /// Construct the code spanned by the rows of `generator`
+    ///
+    /// Computes the parity check matrix by Gaussian elimination and a coset leader
+    /// for every syndrome. Panics if `generator` does not have full rank or if the
+    /// syndrome table would get too large.
+    ///
+    /// If the generator matrix is in systematic form, with the identity in any $k$
+    /// columns, decoding to a message reads the message bits off those columns.
+    pub fn from_generator(generator: BinMatrix) -> Self {
+        let length = generator.ncols();
+        let dimension = generator.nrows();
+        assert!(
+            0 < dimension && dimension < length,
+            "Need 0 < k < n for a code with redundancy"
+        );
+        assert!(
+            length - dimension <= 24,
+            "The syndrome table would have 2^{} entries",
+            length - dimension
+        );
+
+        let rows: Vec<Vec<bool>> = (0..dimension)
+            .map(|i| (0..length).map(|j| generator.bit(i, j)).collect())
+            .collect();
+        let (free, checks) = kernel_basis(rows.clone(), length);
+        assert_eq!(
+            free.len(),
+            length - dimension,
+            "The generator matrix should have full rank"
+        );
+        let parity = BinMatrix::new(
+            checks
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        let parity_t = parity.transposed();
+
+        let (information_set, information_inverse) = match Self::unit_columns(&rows, length) {
+            Some(columns) => (columns, None),
+            None => {
+                let information_set: Vec<usize> =
+                    (0..length).filter(|col| !free.contains(col)).collect();
+                let restricted = BinMatrix::new(
+                    rows.iter()
+                        .map(|row| {
+                            BinVector::from_bools(
+                                &information_set
+                                    .iter()
+                                    .map(|&col| row[col])
+                                    .collect::<Vec<bool>>(),
+                            )
+                        })
+                        .collect(),
+                );
+                (information_set, Some(restricted.inverted()))
+            }
+        };
+
+        let syndrome_map = build_syndrome_map(&parity_t, length);
+
+        LinearCode {
+            generator,
+            parity,
+            parity_t,
+            syndrome_map,
+            length,
+            dimension,
+            information_set,
+            information_inverse,
+        }
+    }
+
+    /// Find the columns $e_1, \ldots, e_k$ of a generator matrix in systematic form
+    ///
+    /// The identity columns may be anywhere, so this also finds $[P \mid I]$ or
+    /// permutations of it. Returns `None` if some unit column is missing.
+    fn unit_columns(rows: &[Vec<bool>], length: usize) -> Option<Vec<usize>> {
+        (0..rows.len())
+            .map(|i| {
+                (0..length).find(|&col| {
+                    rows.iter()
+                        .enumerate()
+                        .all(|(row_idx, row)| row[col] == (row_idx == i))
+                })
+            })
+            .collect()
+    }
+
+    /// The positions of the message bits in a codeword
+    ///
+    /// If the generator matrix is in systematic form, these are the positions of the
+    /// unit columns and the message can be read off the codeword directly.
+    pub fn information_set(&self) -> &[usize] {
+        &self.information_set
+    }
+
+    /// Whether the generator matrix is in systematic form, with the identity in any columns
+    pub fn is_systematic(&self) -> bool {
+        self.information_inverse.is_none()
+    }
+
+    /// Decode every row of `queries` to the nearest codeword
+    ///
+    /// The syndromes of all rows come from a single product with the transposed parity
+    /// check matrix, after which the coset leaders are looked up in parallel.
+    pub fn decode_batch_matrix(&self, queries: &BinMatrix) -> Vec<Result<BinVector, &str>> {
+        debug_assert_eq!(
+            queries.ncols(),
+            self.length,
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let syndromes = queries * &self.parity_t;
+        let mask = (1u64 << (self.length - self.dimension)) - 1;
+        (0..queries.nrows())
+            .into_par_iter()
+            .map(|i| {
+                let error = &self.syndrome_map[&(syndromes.get_word(i, 0) & mask)];
+                let mut codeword = BinVector::with_capacity(self.length);
+                let stor = unsafe { codeword.get_storage_mut() };
+                stor.extend(
+                    error
+                        .iter()
+                        .enumerate()
+                        .map(|(w, &block)| (queries.get_word(i, w) ^ block) as usize),
+                );
+                unsafe { codeword.set_len(self.length) };
+                Ok(codeword)
+            })
+            .collect()
+    }
+}
+
+impl BinaryCode for LinearCode {
+    fn name(&self) -> String {
+        format!("[{}, {}] Linear code", self.length, self.dimension)
+    }
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length,
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let syndrome = (c * &self.parity_t).as_u64();
+        let mut error = BinVector::with_capacity(self.length);
+        let stor = unsafe { error.get_storage_mut() };
+        stor.extend(
+            self.syndrome_map[&syndrome]
+                .iter()
+                .map(|&block| block as usize),
+        );
+        unsafe { error.set_len(self.length) };
+        let result = c + &error;
+        debug_assert_eq!((&result * &self.parity_t).count_ones(), 0);
+        Ok(result)
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        let codeword = self.decode_to_code(c)?;
+        let information = BinVector::from_bools(
+            &self
+                .information_set
+                .iter()
+                .map(|&col| codeword[col])
+                .collect::<Vec<bool>>(),
+        );
+        match &self.information_inverse {
+            Some(inverse) => Ok(&information * inverse),
+            None => Ok(information),
+        }
+    }
+
+    /// Computes the syndromes of all vectors with a single matrix product
+    fn decode_batch(&self, vectors: &[BinVector]) -> Vec<Result<BinVector, &str>>
+    where
+        Self: Sync,
+    {
+        if vectors.is_empty() {
+            return Vec::new();
+        }
+        self.decode_batch_matrix(&BinMatrix::new(vectors.to_vec()))
+    }
+
+    /// The largest weight in the table of coset leaders
+    fn covering_radius(&self) -> usize {
+        self.syndrome_map
+            .values()
+            .map(|error| {
+                error
+                    .iter()
+                    .map(|block| block.count_ones() as usize)
+                    .sum::<usize>()
+            })
+            .max()
+            .unwrap()
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The [7, 4] Hamming code with a non-systematic generator matrix
+    fn hamming_code() -> LinearCode {
+        LinearCode::from_generator(BinMatrix::from_slices(
+            &[
+                &[0b111_0000u64],
+                &[0b100_1100],
+                &[0b010_1010],
+                &[0b110_1001],
+            ],
+            7,
+        ))
+    }
+
+    #[test]
+    fn parity_check() {
+        let code = hamming_code();
+        assert_eq!(code.redundancy(), 3);
+        assert_eq!(code.rate(), 4.0 / 7.0);
+        assert_eq!(code.parity_check_matrix().nrows(), 3);
+        let product = code.generator_matrix() * &code.parity.transposed();
+        assert_eq!(product, BinMatrix::zero(4, 3));
+        assert_eq!(code.syndrome_map.len(), 8);
+    }
+
+    #[test]
+    fn corrects_single_errors() {
+        let code = hamming_code();
+        for _ in 0..100 {
+            let message = BinVector::random(4);
+            let codeword = code.encode(&message);
+            for pos in 0..7 {
+                let mut received = codeword.clone();
+                received.set(pos, !received[pos]);
+                assert_eq!(code.decode_to_code(&received).unwrap(), codeword);
+                assert_eq!(code.decode_to_message(&received).unwrap(), message);
+            }
+        }
+    }
+
+    #[test]
+    fn encoded_messages_are_codewords() {
+        let code = hamming_code();
+        for _ in 0..100 {
+            let codeword = code.encode(&BinVector::random(4));
+            assert!(code.is_codeword(&codeword));
+            let mut received = codeword.clone();
+            let pos = rand::random::<usize>() % 7;
+            received.set(pos, !received[pos]);
+            assert!(!code.is_codeword(&received));
+        }
+    }
+
+    #[test]
+    fn puncture_and_shorten() {
+        use crate::codes::minimum_distance;
+        let code = hamming_code();
+        for pos in 0..7 {
+            let punctured = code.puncture(pos);
+            assert_eq!((punctured.length(), punctured.dimension()), (6, 4));
+            assert_eq!(minimum_distance(&punctured), 2);
+
+            let shortened = code.shorten(pos);
+            assert_eq!((shortened.length(), shortened.dimension()), (6, 3));
+            assert_eq!(minimum_distance(&shortened), 3);
+            for _ in 0..20 {
+                let codeword = code.encode(&BinVector::random(4));
+                assert!(punctured.is_codeword(&BinVector::from_bools(
+                    &(0..7)
+                        .filter(|&i| i != pos)
+                        .map(|i| codeword[i])
+                        .collect::<Vec<bool>>()
+                )));
+
+                let shortened_codeword = shortened.encode(&BinVector::random(3));
+                // inserting a zero at `pos` gives a codeword of the original code
+                let extended = BinVector::from_bools(
+                    &(0..7)
+                        .map(|i| match i.cmp(&pos) {
+                            std::cmp::Ordering::Less => shortened_codeword[i],
+                            std::cmp::Ordering::Equal => false,
+                            std::cmp::Ordering::Greater => shortened_codeword[i - 1],
+                        })
+                        .collect::<Vec<bool>>(),
+                );
+                assert!(code.is_codeword(&extended));
+            }
+        }
+    }
+
+    #[test]
+    fn extend() {
+        use crate::codes::minimum_distance;
+        let code = hamming_code().extend();
+        assert_eq!((code.length(), code.dimension()), (8, 4));
+        assert_eq!(minimum_distance(&code), 4);
+        assert_eq!(code.weight_distribution(), vec![1, 0, 0, 0, 14, 0, 0, 0, 1]);
+        assert!(code.is_codeword(&BinVector::from_elem(8, true)));
+        for _ in 0..100 {
+            let message = BinVector::random(4);
+            let codeword = code.encode(&message);
+            assert_eq!(codeword.count_ones() % 2, 0);
+            let mut received = codeword.clone();
+            let pos = rand::random::<usize>() % 8;
+            received.set(pos, !received[pos]);
+            assert_eq!(code.decode_to_message(&received).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn decode_batch() {
+        let code = hamming_code();
+        let vectors: Vec<BinVector> = (0..100).map(|_| BinVector::random(7)).collect();
+        let batch = code.decode_batch(&vectors);
+        assert_eq!(batch.len(), vectors.len());
+        for (v, decoded) in vectors.iter().zip(batch.into_iter()) {
+            assert_eq!(decoded.unwrap(), code.decode_to_code(v).unwrap());
+        }
+        assert!(code.decode_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn decode_batch_matrix() {
+        let code = hamming_code();
+        let vectors: Vec<BinVector> = (0..100).map(|_| BinVector::random(7)).collect();
+        let decoded = code.decode_batch_matrix(&BinMatrix::new(vectors.clone()));
+        assert_eq!(decoded.len(), vectors.len());
+        for (v, c) in vectors.iter().zip(decoded) {
+            assert_eq!(c, code.decode_to_code(v));
+        }
+    }
+
+    #[test]
+    fn trailing_information_set() {
+        // [P | I]: the message is in the last four positions
+        let rows: [[bool; 7]; 4] = [
+            [true, true, false, true, false, false, false],
+            [true, false, true, false, true, false, false],
+            [false, true, true, false, false, true, false],
+            [true, true, true, false, false, false, true],
+        ];
+        let code = LinearCode::from_generator(BinMatrix::new(
+            rows.iter().map(|row| BinVector::from_bools(row)).collect(),
+        ));
+        assert!(code.is_systematic());
+        assert_eq!(code.information_set(), &[3, 4, 5, 6]);
+        for _ in 0..100 {
+            let message = BinVector::random(4);
+            let codeword = code.encode(&message);
+            for (i, &col) in code.information_set().iter().enumerate() {
+                assert_eq!(codeword[col], message[i]);
+            }
+            let mut received = codeword.clone();
+            received.set(1, !received[1]);
+            assert_eq!(code.decode_to_message(&received).unwrap(), message);
+            assert!(code.is_codeword(&codeword));
+        }
+    }
+
+    #[test]
+    fn random_generator() {
+        let generator = loop {
+            let generator = BinMatrix::random(10, 18);
+            if generator.rank() == 10 {
+                break generator;
+            }
+        };
+        let code = LinearCode::from_generator(generator);
+        assert_eq!(code.syndrome_map.len(), 1 << 8);
+        for _ in 0..100 {
+            let message = BinVector::random(10);
+            let codeword = code.encode(&message);
+            assert_eq!(code.decode_to_message(&codeword).unwrap(), message);
+
+            let received = BinVector::random(18);
+            let decoded = code.decode_to_code(&received).unwrap();
+            assert_eq!((&decoded * &code.parity_t).count_ones(), 0);
+        }
+    }
+
+    #[cfg(feature = "guava_15")]
+    #[test]
+    fn same_as_guava() {
+        use crate::codes::GuavaCode15_10;
+        let guava = GuavaCode15_10;
+        let code = LinearCode::from_generator(guava.generator_matrix().clone());
+        for _ in 0..1000 {
+            let received = BinVector::random(15);
+            let ours = code.decode_to_code(&received).unwrap();
+            let theirs = guava.decode_to_code(&received).unwrap();
+            // coset leaders need not be unique, but have the same weight
+            assert_eq!(
+                (&ours + &received).count_ones(),
+                (&theirs + &received).count_ones()
+            );
+        }
+        assert_eq!(code.covering_radius(), guava.covering_radius());
+    }
+}
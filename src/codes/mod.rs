@@ -1,15 +1,24 @@
 //! This module defines Linear codes for the covering-codes reduction.
+use itertools::Itertools;
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
+use std::cmp;
 use std::collections::HashSet;
 use std::fmt;
 use std::mem;
 
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
 use crate::oracle::Sample;
 
 /// Sample size to estimate the covering radius
 pub(crate) static N: usize = 10000;
 
+/// Codes with at most this many redundant bits get their bias from the coset leaders
+const MAX_COSET_LEADER_REDUNDANCY: usize = 20;
+
 fn usize_to_binvec(c: usize, size: usize) -> BinVector {
     let bytes = unsafe { mem::transmute::<usize, [u8; mem::size_of::<usize>()]>(c.to_be()) };
     let skip = (64 - size) / 8;
@@ -19,6 +28,116 @@ fn usize_to_binvec(c: usize, size: usize) -> BinVector {
     result
 }
 
+/// Basis of the kernel of the matrix with the given rows
+///
+/// Returns the free columns of the reduced row echelon form, and for every free
+/// column the kernel vector that is one in that column and zero in the other free columns.
+pub(crate) fn kernel_basis(
+    mut rows: Vec<Vec<bool>>,
+    columns: usize,
+) -> (Vec<usize>, Vec<Vec<bool>>) {
+    let mut pivots = Vec::with_capacity(rows.len());
+    for col in 0..columns {
+        let rank = pivots.len();
+        if let Some(row) = (rank..rows.len()).find(|&row| rows[row][col]) {
+            rows.swap(rank, row);
+            let pivot_row = rows[rank].clone();
+            for (other, row) in rows.iter_mut().enumerate() {
+                if other != rank && row[col] {
+                    row.iter_mut()
+                        .zip(pivot_row.iter())
+                        .for_each(|(bit, pivot_bit)| *bit ^= pivot_bit);
+                }
+            }
+            pivots.push(col);
+        }
+    }
+    let free: Vec<usize> = (0..columns).filter(|col| !pivots.contains(col)).collect();
+
+    // x_p = sum_f R[p][f] x_f for the pivot columns p and the free columns f
+    let basis = free
+        .iter()
+        .map(|&f| {
+            let mut v = vec![false; columns];
+            v[f] = true;
+            for (row, &pivot) in pivots.iter().enumerate() {
+                v[pivot] = rows[row][f];
+            }
+            v
+        })
+        .collect();
+    (free, basis)
+}
+
+/// The rows of a matrix as bits, for manipulating small matrices
+fn matrix_rows(matrix: &BinMatrix) -> Vec<Vec<bool>> {
+    (0..matrix.nrows())
+        .map(|row| {
+            (0..matrix.ncols())
+                .map(|col| matrix.bit(row, col))
+                .collect()
+        })
+        .collect()
+}
+
+/// The matrix with the given rows, without column `position`
+fn without_column(rows: &[Vec<bool>], position: usize) -> BinMatrix {
+    BinMatrix::new(
+        rows.iter()
+            .map(|row| {
+                let mut row = row.clone();
+                row.remove(position);
+                BinVector::from_bools(&row)
+            })
+            .collect(),
+    )
+}
+
+/// Row reduce `matrix` to the form $[A \mid I_r]$ for its rank $r$
+///
+/// Pivots are searched from the last column backwards and zero rows are removed.
+/// If the pivot columns are not the last $r$ columns, they are moved there and the
+/// permutation is returned: column `j` of the result is column `perm[j]` of `matrix`.
+pub(crate) fn systematic_form(matrix: &BinMatrix) -> (BinMatrix, Option<Vec<usize>>) {
+    let columns = matrix.ncols();
+    let mut rows = matrix_rows(matrix);
+    let mut pivots = Vec::with_capacity(rows.len());
+    for col in (0..columns).rev() {
+        let rank = pivots.len();
+        if let Some(row) = (rank..rows.len()).find(|&row| rows[row][col]) {
+            rows.swap(rank, row);
+            let pivot_row = rows[rank].clone();
+            for (other, row) in rows.iter_mut().enumerate() {
+                if other != rank && row[col] {
+                    row.iter_mut()
+                        .zip(pivot_row.iter())
+                        .for_each(|(bit, pivot_bit)| *bit ^= pivot_bit);
+                }
+            }
+            pivots.push(col);
+        }
+    }
+    rows.truncate(pivots.len());
+
+    // the pivots were found from right to left, sort the rows to get the identity
+    let mut order: Vec<usize> = (0..pivots.len()).collect();
+    order.sort_unstable_by_key(|&row| pivots[row]);
+    let perm: Vec<usize> = (0..columns)
+        .filter(|col| !pivots.contains(col))
+        .chain(order.iter().map(|&row| pivots[row]))
+        .collect();
+    let result = BinMatrix::new(
+        order
+            .iter()
+            .map(|&row| {
+                BinVector::from_bools(&perm.iter().map(|&col| rows[row][col]).collect::<Vec<_>>())
+            })
+            .collect(),
+    );
+    let is_identity = perm.iter().enumerate().all(|(j, &col)| j == col);
+    (result, if is_identity { None } else { Some(perm) })
+}
+
 /// Generic binary linear code API
 pub trait BinaryCode {
     /// Name of the code
@@ -30,12 +149,94 @@ pub trait BinaryCode {
     /// Dimension of the code
     fn dimension(&self) -> usize;
 
+    /// The rate $k / n$ of the code
+    fn rate(&self) -> f64 {
+        self.dimension() as f64 / self.length() as f64
+    }
+
+    /// The redundancy $n - k$, the length of the syndromes
+    fn redundancy(&self) -> usize {
+        self.length() - self.dimension()
+    }
+
     /// Generator Matrix
     fn generator_matrix(&self) -> &BinMatrix;
 
     /// Parity check matrix
     fn parity_check_matrix(&self) -> &BinMatrix;
 
+    /// The parity check matrix in systematic form $[P^T \mid I_{n-k}]$
+    ///
+    /// Row reduces [`BinaryCode::parity_check_matrix`], which also drops dependent rows.
+    /// If the last $n - k$ columns are not independent, other columns are moved to the
+    /// end and the permutation is returned as well: column `j` of the result is column
+    /// `perm[j]` of the parity check matrix, so a vector $c$ for the original matrix
+    /// corresponds to $c'_j = c_{perm[j]}$.
+    fn parity_check_systematic(&self) -> (BinMatrix, Option<Vec<usize>>) {
+        let (matrix, perm) = systematic_form(self.parity_check_matrix());
+        debug_assert_eq!(
+            matrix.nrows(),
+            self.redundancy(),
+            "the parity check matrix should have rank n - k"
+        );
+        (matrix, perm)
+    }
+
+    /// Puncture the code by deleting coordinate `position`
+    ///
+    /// Deleting the column of the generator matrix gives an $[n - 1, k, d']$ code with
+    /// $d - 1 \le d' \le d$. Panics if the dimension would drop, which happens if a
+    /// codeword is zero outside of `position`.
+    fn puncture(&self, position: usize) -> LinearCode {
+        assert!(position < self.length(), "Invalid position {}", position);
+        let rows = matrix_rows(self.generator_matrix());
+        LinearCode::from_generator(without_column(&rows, position))
+    }
+
+    /// Shorten the code to the codewords that are zero in coordinate `position`
+    ///
+    /// Deleting that coordinate gives an $[n - 1, k - 1, d']$ code with $d' \ge d$. One
+    /// generator row with a one in `position` is added to the others with a one there
+    /// and removed. If all codewords are zero in `position` the dimension stays $k$.
+    fn shorten(&self, position: usize) -> LinearCode {
+        assert!(position < self.length(), "Invalid position {}", position);
+        let mut rows = matrix_rows(self.generator_matrix());
+        if let Some(pivot) = rows.iter().position(|row| row[position]) {
+            let pivot_row = rows.remove(pivot);
+            for row in rows.iter_mut().filter(|row| row[position]) {
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(bit, pivot_bit)| *bit ^= pivot_bit);
+            }
+        }
+        LinearCode::from_generator(without_column(&rows, position))
+    }
+
+    /// Extend the code with an overall parity bit
+    ///
+    /// Every generator row gets its parity appended, so all codewords of the
+    /// $[n + 1, k, d']$ code have even weight and $d' = d + 1$ if $d$ is odd. The parity
+    /// check matrix of the result contains the all-ones vector in its span.
+    fn extend(&self) -> LinearCode {
+        let mut rows = matrix_rows(self.generator_matrix());
+        for row in rows.iter_mut() {
+            let parity = row.iter().filter(|&&bit| bit).count() % 2 == 1;
+            row.push(parity);
+        }
+        LinearCode::from_generator(BinMatrix::new(
+            rows.iter().map(|row| BinVector::from_bools(row)).collect(),
+        ))
+    }
+
+    /// Check if `v` is a codeword
+    ///
+    /// Computes the syndrome $H v$, which costs about $n(n-k)$ additions and does not
+    /// need a decoder.
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        debug_assert_eq!(v.len(), self.length(), "the length doesn't match the code");
+        (self.parity_check_matrix() * v).count_ones() == 0
+    }
+
     /// Decode a codeword to the codeword space
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
         Ok(self.encode(&self.decode_to_message(c)?))
@@ -44,9 +245,24 @@ pub trait BinaryCode {
     /// Decode a codeword to the message space
     fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str>;
 
-    /// Encode a codeword
+    /// Decode many vectors to the codeword space
+    ///
+    /// Calls [`BinaryCode::decode_to_code`] for every vector in parallel. Syndrome
+    /// decoders may override this to compute all syndromes with one matrix product.
+    fn decode_batch(&self, vectors: &[BinVector]) -> Vec<Result<BinVector, &str>>
+    where
+        Self: Sync,
+    {
+        vectors.par_iter().map(|v| self.decode_to_code(v)).collect()
+    }
+
+    /// Encode a message of length $k$ to a codeword
+    ///
+    /// Multiplies the message with the generator matrix. Codes with a systematic
+    /// generator matrix may override this to only compute the parity bits.
+    /// Panics if the message does not have length $k$.
     fn encode(&self, c: &BinVector) -> BinVector {
-        debug_assert_eq!(
+        assert_eq!(
             c.len(),
             self.dimension(),
             "Vector to encode should be of length {}",
@@ -91,41 +307,191 @@ pub trait BinaryCode {
             });
     }
 
-    /// Get or compute the bc of a code
-    fn bias(&self, delta: f64) -> f64 {
-        let mut distances = Vec::with_capacity(N);
-        if 2f64.powi(self.length() as i32) > 1.5 * N as f64 {
-            let mut seen = HashSet::with_capacity(N);
-            while seen.len() < N {
-                let v = BinVector::random(self.length());
-                if seen.contains(&v) {
-                    continue;
-                }
-                let decoded = self.decode_to_code(&v);
-                if let Ok(decoded) = decoded {
-                    distances.push((&v + &decoded).count_ones() as i32);
-                    seen.insert(v);
-                } else {
-                    println!("Decoding something failed");
-                    return 0.0;
+    /// Estimate of the number of GF(2) additions needed to encode a message
+    ///
+    /// Multiplying by a systematic generator matrix $[I_k \mid P]$ takes about $k(n-k)$ additions.
+    fn encoding_complexity(&self) -> usize {
+        self.dimension() * (self.length() - self.dimension())
+    }
+
+    /// Estimate of the number of GF(2) additions needed to decode a vector
+    ///
+    /// For syndrome decoding: about $(n-k)^2$ for the syndrome, plus a table lookup.
+    fn decoding_complexity(&self) -> usize {
+        self.redundancy() * self.redundancy() + 1
+    }
+
+    /// Ratio of the decoding complexity to the encoding complexity
+    fn complexity_ratio(&self) -> f64 {
+        self.decoding_complexity() as f64 / cmp::max(self.encoding_complexity(), 1) as f64
+    }
+
+    /// The covering radius: the largest distance of any vector to the code
+    ///
+    /// Computed as the largest weight of a coset leader.
+    fn covering_radius(&self) -> usize {
+        self.coset_leader_weight_distribution().len() - 1
+    }
+
+    /// The weights of the coset leaders: entry `i` is the number of cosets whose
+    /// lightest vector has weight `i`
+    ///
+    /// Computed by enumerating error patterns by weight until every syndrome has been
+    /// seen, so the entries sum to $2^{n-k}$.
+    fn coset_leader_weight_distribution(&self) -> Vec<u64> {
+        let n = self.length();
+        let redundancy = self.redundancy();
+        assert!(redundancy < 32, "Too many syndromes to enumerate");
+        let h_transposed = self.parity_check_matrix().transposed();
+        let mut syndromes = HashSet::with_capacity(1 << redundancy);
+        let mut distribution = Vec::new();
+        for weight in 0..=n {
+            let seen = syndromes.len();
+            for positions in (0..n).combinations(weight) {
+                let mut error = BinVector::from_elem(n, false);
+                for &pos in positions.iter() {
+                    error.set(pos, true);
                 }
+                syndromes.insert((&error * &h_transposed).as_u64());
+            }
+            distribution.push((syndromes.len() - seen) as u64);
+            if syndromes.len() == 1 << redundancy {
+                return distribution;
             }
+        }
+        unreachable!("The syndromes of all vectors cover the whole space")
+    }
+
+    /// The number of errors $t = \lfloor (d - 1) / 2 \rfloor$ that are always corrected
+    ///
+    /// Computes the minimum distance $d$ by enumerating all codewords, codes for which
+    /// it is known override this.
+    fn error_correction_capability(&self) -> usize {
+        (minimum_distance(self) - 1) / 2
+    }
+
+    /// A uniformly random error vector of weight exactly [`error_correction_capability`]
+    ///
+    /// Adding it to a codeword gives a vector that decodes back to that codeword.
+    /// Picks the positions with a partial Fisher-Yates shuffle.
+    ///
+    /// [`error_correction_capability`]: BinaryCode::error_correction_capability
+    fn random_error_pattern(&self, rng: &mut dyn RngCore) -> BinVector {
+        let n = self.length();
+        let t = self.error_correction_capability();
+        let mut positions: Vec<usize> = (0..n).collect();
+        let mut error = BinVector::from_elem(n, false);
+        for i in 0..t {
+            let j = i + (rng.next_u64() % (n - i) as u64) as usize;
+            positions.swap(i, j);
+            error.set(positions[i], true);
+        }
+        error
+    }
+
+    /// The dual distance: the minimum distance of the dual code
+    ///
+    /// The dual code is spanned by the rows of the parity check matrix, so this is the
+    /// smallest weight of a nonzero combination of them, which can be smaller than the
+    /// weight of every single row. Enumerates all $2^{n-k}$ combinations.
+    fn dual_distance(&self) -> usize {
+        self::bounds::minimum_weight_of_span(self.parity_check_matrix())
+    }
+
+    /// The weight distribution: entry `i` is the number of codewords of weight `i`
+    ///
+    /// This is the list of coefficients of the weight enumerator
+    /// $W(x, y) = \sum_c x^{n - wt(c)} y^{wt(c)}$. Enumerates all $2^k$ codewords.
+    fn weight_distribution(&self) -> Vec<u64> {
+        self::bounds::weight_distribution_of_span(self.generator_matrix())
+    }
+
+    /// Iterate over all $2^k$ codewords, starting with the zero codeword
+    ///
+    /// The codewords are visited in Gray code order, so every step adds one row of the
+    /// generator matrix instead of encoding a message. Panics if $k > 24$.
+    fn all_codewords(&self) -> Codewords {
+        assert!(
+            self.dimension() <= 24,
+            "Too many codewords to enumerate, need k <= 24"
+        );
+        Codewords::new(self.generator_matrix())
+    }
+
+    /// Check if the minimum distance is at least the Gilbert-Varshamov bound
+    ///
+    /// Enumerates all codewords, so only feasible for small dimensions.
+    fn meets_gv_bound(&self) -> bool {
+        minimum_distance(self) >= gv_lower_bound(self.length(), self.dimension())
+    }
+
+    /// Whether the decoder always finds a closest codeword
+    ///
+    /// True for syndrome table decoders. Bounded distance and iterative decoders may
+    /// fail or pick a farther codeword, so the default is false.
+    fn decodes_to_closest_codeword(&self) -> bool {
+        false
+    }
+
+    /// Get or compute the bc of a code
+    ///
+    /// The bc is $E[\delta^{wt(e)}]$ for the error $e$ of decoding a uniform vector.
+    /// If the decoder [finds a closest codeword] and the code has few redundant bits,
+    /// it follows exactly from the [coset leader weights]. Otherwise short codes
+    /// decode all vectors, and longer codes decode `N` vectors drawn with a fixed seed.
+    /// A vector the decoder fails on tells nothing about the secret, so it counts
+    /// with bias zero.
+    ///
+    /// [finds a closest codeword]: BinaryCode::decodes_to_closest_codeword
+    /// [coset leader weights]: BinaryCode::coset_leader_weight_distribution
+    fn bias(&self, delta: f64) -> f64 {
+        let n = self.length();
+        if self.decodes_to_closest_codeword() && self.redundancy() <= MAX_COSET_LEADER_REDUNDANCY {
+            let leaders = self.coset_leader_weight_distribution();
+            let cosets = leaders.iter().sum::<u64>() as f64;
+            return leaders
+                .into_iter()
+                .enumerate()
+                .fold(0f64, |acc, (weight, count)| {
+                    acc + count as f64 * delta.powi(weight as i32)
+                })
+                / cosets;
+        }
+
+        let distance = |v: &BinVector| {
+            self.decode_to_code(v)
+                .ok()
+                .map(|decoded| (v + &decoded).count_ones() as i32)
+        };
+        let distances: Vec<Option<i32>> = if 2f64.powi(n as i32) <= 1.5 * N as f64 {
+            (0..2usize.pow(n as u32))
+                .map(|i| distance(&usize_to_binvec(i, n)))
+                .collect()
         } else {
-            for i in 0..2usize.pow(self.length() as u32) {
-                let v = usize_to_binvec(i, self.length());
-                let decoded = self.decode_to_code(&v);
-                if let Ok(decoded) = decoded {
-                    distances.push((&v + &decoded).count_ones() as i32);
-                } else {
-                    println!("Decoding something failed");
-                    return 0.0;
+            let mut rng = ChaCha8Rng::seed_from_u64(0);
+            let mut seen = HashSet::with_capacity(N);
+            let mut distances = Vec::with_capacity(N);
+            while distances.len() < N {
+                let v = BinVector::from_bools(&(0..n).map(|_| rng.gen()).collect::<Vec<bool>>());
+                if seen.insert(v.clone()) {
+                    distances.push(distance(&v));
                 }
             }
-        }
+            distances
+        };
 
+        let failures = distances.iter().filter(|dist| dist.is_none()).count();
+        if failures > 0 {
+            log::debug!(
+                "Decoding failed for {} of {} vectors",
+                failures,
+                distances.len()
+            );
+        }
         let count = distances.len();
         let sum = distances
             .into_iter()
+            .flatten()
             .fold(0f64, |acc, dist| acc + delta.powi(dist));
 
         sum / (count as f64)
@@ -160,6 +526,56 @@ pub use self::golay::*;
 mod concatenated;
 pub use self::concatenated::*;
 
+mod affine;
+pub use self::affine::*;
+
+mod bec;
+pub use self::bec::*;
+
+mod ldpc;
+pub use self::ldpc::*;
+
+mod evaluation;
+pub use self::evaluation::*;
+
+mod hadamard;
+pub use self::hadamard::*;
+
+mod reed_muller;
+pub use self::reed_muller::*;
+
+mod bounds;
+pub use self::bounds::*;
+
+mod linear;
+pub use self::linear::*;
+
+mod bch;
+pub use self::bch::*;
+
+mod goppa;
+pub use self::goppa::*;
+
+mod hamming_family;
+pub use self::hamming_family::*;
+
+mod dual;
+pub use self::dual::*;
+
+mod xor;
+pub use self::xor::*;
+
+mod repeated;
+pub use self::repeated::*;
+
+mod catalog;
+pub use self::catalog::*;
+
+mod trellis;
+pub use self::trellis::*;
+
+pub mod utils;
+
 #[cfg(feature = "stgen")]
 mod stgen;
 #[cfg(feature = "stgen")]
@@ -189,3 +605,56 @@ pub use self::wagner::*;
 
 mod guava;
 pub use self::guava::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complexity_estimates() {
+        let code = RepetitionCode::new(5);
+        assert_eq!(code.encoding_complexity(), 4);
+        assert_eq!(code.decoding_complexity(), 17);
+        assert!((code.complexity_ratio() - 17.0 / 4.0).abs() < 1e-12);
+
+        let code = IdentityCode::new(8);
+        assert_eq!(code.encoding_complexity(), 0);
+        assert_eq!(code.decoding_complexity(), 1);
+        assert!((code.complexity_ratio() - 1.0).abs() < 1e-12);
+    }
+
+    /// Number of GF(2) additions of the product $x M$ for a vector $x$ of all ones
+    fn additions_per_column(matrix: &BinMatrix) -> usize {
+        (0..matrix.ncols())
+            .map(|col| {
+                (0..matrix.nrows())
+                    .filter(|&row| matrix.bit(row, col))
+                    .count()
+            })
+            .map(|weight| weight.saturating_sub(1))
+            .sum()
+    }
+
+    #[test]
+    fn complexity_estimates_match_operation_counts() {
+        for r in 3..=6 {
+            let code = HammingCode::new(r);
+            let (n, k) = (code.length(), code.dimension());
+            let half = 1 << (r - 1);
+            // The parity bit at position 2^i - 1 sums the half - 1 message bits of which
+            // the position has bit i set. This is at most the k(n - k) of the estimate,
+            // and at least half of it as 2 (half - 2) = 2^r - 4 >= k = 2^r - 1 - r.
+            let encoding = additions_per_column(code.generator_matrix());
+            assert_eq!(encoding, (n - k) * (half - 2));
+            assert!(encoding <= code.encoding_complexity());
+            assert!(2 * encoding >= code.encoding_complexity());
+            // Syndrome bit i sums the half positions that have bit i set. This is
+            // (n - k)(n - 1) / 2 additions, the (n - k)^2 of the estimate only covers
+            // them for n - k >= (n - 1) / 2, so up to r = 3.
+            let decoding = additions_per_column(&code.parity_check_matrix().transposed());
+            assert_eq!(decoding, (n - k) * (half - 1));
+            assert_eq!(code.decoding_complexity(), (n - k) * (n - k) + 1);
+            assert_eq!(decoding <= code.decoding_complexity(), r <= 3);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::codes::{kernel_basis, BinaryCode};
+use m4ri_rust::friendly::*;
+
+/// Reed-Muller code $R(r, m)$
+///
+/// The rows of $\begin{pmatrix} 1 & 0 \\ 1 & 1 \end{pmatrix}^{\otimes m}$ are indexed by
+/// $x \in \{0, 1\}^m$, row $x$ has a one in column $j$ if $j \subseteq x$. This row is the
+/// evaluation of the monomial $\prod_{i \notin x} (1 + v_i)$ of degree $m - |x|$, so the
+/// rows of weight at least $2^{m-r}$ generate the
+/// $[2^m, \sum_{i \le r} \binom{m}{i}, 2^{m-r}]$ code.
+///
+/// $R(1, m)$ is the same code as [`crate::codes::HadamardCode`] and $R(m - 2, m)$ is the
+/// extended Hamming code, e.g. $R(1, 3)$ is the $[8, 4, 4]$ code. Decoding uses Reed's
+/// majority-logic decoder, which corrects up to $2^{m-r-1} - 1$ errors.
+#[derive(Clone, Serialize)]
+pub struct ReedMullerCode {
+    r: usize,
+    m: usize,
+    /// The rows $x$ of the Kronecker product that are in the generator matrix
+    rows: Vec<usize>,
+    generator: BinMatrix,
+    parity_check: BinMatrix,
+}
+
+impl ReedMullerCode {
+    /// Construct $R(r, m)$ of length $2^m$
+    pub fn new(r: usize, m: usize) -> ReedMullerCode {
+        assert!((1..=12).contains(&m), "1 <= m <= 12");
+        assert!(r < m, "r < m, R(m, m) is the full space");
+        let n = 1 << m;
+        let rows: Vec<usize> = (0..n)
+            .filter(|x: &usize| x.count_ones() as usize >= m - r)
+            .collect();
+        let generator_rows: Vec<Vec<bool>> = rows
+            .iter()
+            .map(|&x| (0..n).map(|j| j & !x == 0).collect())
+            .collect();
+        let generator = BinMatrix::new(
+            generator_rows
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        let (_, parity_checks) = kernel_basis(generator_rows, n);
+        let parity_check = BinMatrix::new(
+            parity_checks
+                .iter()
+                .map(|row| BinVector::from_bools(row))
+                .collect(),
+        );
+        ReedMullerCode {
+            r,
+            m,
+            rows,
+            generator,
+            parity_check,
+        }
+    }
+
+    /// The order $r$, the largest degree of the monomials
+    pub fn order(&self) -> usize {
+        self.r
+    }
+}
+
+/// All subsets of the bits of `mask`
+fn subsets(mask: usize) -> impl Iterator<Item = usize> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let subset = next?;
+        next = if subset == 0 {
+            None
+        } else {
+            Some((subset - 1) & mask)
+        };
+        Some(subset)
+    })
+}
+
+impl BinaryCode for ReedMullerCode {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] Reed-Muller code R({}, {})",
+            self.length(),
+            self.dimension(),
+            self.r,
+            self.m
+        )
+    }
+
+    fn length(&self) -> usize {
+        1 << self.m
+    }
+
+    fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        &self.generator
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        &self.parity_check
+    }
+
+    /// Reed's majority-logic decoding
+    ///
+    /// The monomial of row $x$ sums to one over every subcube in which the variables
+    /// outside of $x$ vary, and all other monomials of at most the same degree sum to
+    /// zero over it. So every one of the $2^{|x|}$ subcubes gives a vote for the
+    /// coefficient of row $x$. Starting with the highest degree, every coefficient is
+    /// set to the majority of its votes and its row is subtracted from the received word.
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length(),
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let n = self.length();
+        let mut received: Vec<bool> = c.iter().collect();
+        let mut message = vec![false; self.dimension()];
+        // the highest degrees have the fewest bits in x
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by_key(|&i| self.rows[i].count_ones());
+        for i in order {
+            let x = self.rows[i];
+            let varying = !x & (n - 1);
+            let votes = subsets(x)
+                .filter(|&fixed| subsets(varying).fold(false, |acc, j| acc ^ received[fixed | j]))
+                .count();
+            if 2 * votes > 1 << x.count_ones() {
+                message[i] = true;
+                for fixed in subsets(x) {
+                    received[fixed] ^= true;
+                }
+            }
+        }
+        Ok(BinVector::from_bools(&message))
+    }
+
+    /// The minimum distance is $2^{m - r}$
+    fn error_correction_capability(&self) -> usize {
+        (1 << (self.m - self.r - 1)) - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::codes::HadamardCode;
+    use std::collections::HashSet;
+
+    fn codespace(code: &dyn BinaryCode) -> HashSet<BinVector> {
+        (0..(1 << code.dimension()))
+            .map(|x: usize| {
+                code.encode(&BinVector::from_bools(
+                    &(0..code.dimension())
+                        .map(|i| (x >> i) & 1 == 1)
+                        .collect::<Vec<bool>>(),
+                ))
+            })
+            .collect()
+    }
+
+    fn binomial(n: usize, k: usize) -> usize {
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn parameters() {
+        for m in 2..=5 {
+            for r in 0..m {
+                let code = ReedMullerCode::new(r, m);
+                assert_eq!(code.length(), 1 << m);
+                let k = (0..=r).map(|i| binomial(m, i)).sum::<usize>();
+                assert_eq!(code.dimension(), k);
+                assert_eq!(code.generator_matrix().rank(), k);
+                if k <= 16 {
+                    assert_eq!(crate::codes::minimum_distance(&code), 1 << (m - r));
+                }
+                let product = code.generator_matrix() * &code.parity_check.transposed();
+                assert_eq!(product, BinMatrix::zero(k, (1 << m) - k));
+            }
+        }
+    }
+
+    #[test]
+    fn same_as_hadamard() {
+        for m in 2..=5 {
+            assert_eq!(
+                codespace(&ReedMullerCode::new(1, m)),
+                codespace(&HadamardCode::new(m))
+            );
+        }
+    }
+
+    #[test]
+    fn extended_hamming() {
+        let code = ReedMullerCode::new(1, 3);
+        assert_eq!((code.length(), code.dimension()), (8, 4));
+        assert_eq!(crate::codes::minimum_distance(&code), 4);
+    }
+
+    #[test]
+    fn decoding() {
+        for &(r, m) in &[(1, 4), (2, 5), (2, 6), (3, 6)] {
+            let code = ReedMullerCode::new(r, m);
+            assert_eq!(code.error_correction_capability(), (1 << (m - r - 1)) - 1);
+            for _ in 0..50 {
+                let message = BinVector::random(code.dimension());
+                let codeword = code.encode(&message);
+                let error = code.random_error_pattern(&mut rand::thread_rng());
+                assert_eq!(
+                    error.count_ones() as usize,
+                    code.error_correction_capability()
+                );
+                let received = &codeword + &error;
+                assert_eq!(code.decode_to_message(&received), Ok(message));
+                assert_eq!(code.decode_to_code(&received), Ok(codeword));
+            }
+        }
+    }
+}
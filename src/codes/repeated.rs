@@ -0,0 +1,193 @@
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::BinMatrix;
+use m4ri_rust::friendly::BinVector;
+use std::sync::OnceLock;
+
+/// A code repeated a number of times
+///
+/// The codewords are $(c, c, \ldots, c)$ for all codewords $c$ of `inner`, which gives
+/// an $[rn, k, rd]$ code. Unlike [`crate::codes::RepetitionCode`], which repeats single
+/// bits, every block is decoded independently by `inner`, after which every message bit
+/// is decided by a majority vote over the blocks.
+///
+/// The generator and parity check matrices are built on first use.
+pub struct RepeatedCode<C: BinaryCode> {
+    /// The code that is repeated
+    pub inner: C,
+    /// The number of copies $r$
+    pub repetitions: usize,
+    generator: OnceLock<BinMatrix>,
+    parity_check: OnceLock<BinMatrix>,
+}
+
+impl<C: BinaryCode> RepeatedCode<C> {
+    /// The code with codewords of `inner` repeated `repetitions` times
+    pub fn new(inner: C, repetitions: usize) -> RepeatedCode<C> {
+        assert!(repetitions > 0, "Need at least one repetition");
+        RepeatedCode {
+            inner,
+            repetitions,
+            generator: OnceLock::new(),
+            parity_check: OnceLock::new(),
+        }
+    }
+
+    /// The minimum distance $rd$, computed from the inner code
+    pub fn minimum_distance(&self) -> usize {
+        self.repetitions * crate::codes::minimum_distance(&self.inner)
+    }
+
+    /// Split `v` into the blocks for the inner code
+    fn blocks(&self, v: &BinVector) -> Vec<BinVector> {
+        let n = self.inner.length();
+        let mut blocks = Vec::with_capacity(self.repetitions);
+        let mut rest = v.clone();
+        for _ in 1..self.repetitions {
+            let tail = BinVector::from(rest.split_off(n));
+            blocks.push(rest);
+            rest = tail;
+        }
+        blocks.push(rest);
+        blocks
+    }
+}
+
+impl<C: BinaryCode> BinaryCode for RepeatedCode<C> {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] {} times repeated {}",
+            self.length(),
+            self.dimension(),
+            self.repetitions,
+            self.inner.name()
+        )
+    }
+
+    fn length(&self) -> usize {
+        self.repetitions * self.inner.length()
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    /// The generator matrix $[G \mid G \mid \cdots \mid G]$
+    fn generator_matrix(&self) -> &BinMatrix {
+        self.generator.get_or_init(|| {
+            let generator = self.inner.generator_matrix();
+            (1..self.repetitions).fold(generator.clone(), |result, _| result.augmented(generator))
+        })
+    }
+
+    /// The parity check matrix checks the first block with $H$ and the others against it
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        self.parity_check.get_or_init(|| {
+            let n = self.inner.length();
+            let parity_check = self.inner.parity_check_matrix();
+            let identity = BinMatrix::identity(n);
+            let mut result = BinMatrix::zero(
+                parity_check.nrows() + (self.repetitions - 1) * n,
+                self.length(),
+            );
+            result.set_window(0, 0, parity_check);
+            for block in 1..self.repetitions {
+                let row = parity_check.nrows() + (block - 1) * n;
+                result.set_window(row, 0, &identity);
+                result.set_window(row, block * n, &identity);
+            }
+            result
+        })
+    }
+
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        debug_assert_eq!(v.len(), self.length(), "the length doesn't match the code");
+        let blocks = self.blocks(v);
+        blocks.iter().all(|block| block == &blocks[0]) && self.inner.is_codeword(&blocks[0])
+    }
+
+    fn encode(&self, c: &BinVector) -> BinVector {
+        debug_assert_eq!(
+            c.len(),
+            self.dimension(),
+            "the message has the wrong length"
+        );
+        let codeword = self.inner.encode(c);
+        let mut result = codeword.clone();
+        for _ in 1..self.repetitions {
+            result.extend_from_binvec(&codeword);
+        }
+        result
+    }
+
+    /// Decode every block and take the majority of every message bit
+    ///
+    /// Blocks that fail to decode do not vote, ties are broken by the first block that
+    /// decoded.
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length(),
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let messages: Vec<BinVector> = self
+            .blocks(c)
+            .iter()
+            .filter_map(|block| self.inner.decode_to_message(block).ok())
+            .collect();
+        if messages.is_empty() {
+            return Err("None of the blocks could be decoded");
+        }
+        let mut result = BinVector::with_capacity(self.dimension());
+        for bit in 0..self.dimension() {
+            let ones = messages.iter().filter(|message| message[bit]).count();
+            result.push(match (2 * ones).cmp(&messages.len()) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => messages[0][bit],
+                std::cmp::Ordering::Greater => true,
+            });
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::HammingCode;
+
+    #[test]
+    fn repeated_hamming() {
+        let code = RepeatedCode::new(HammingCode::new(3), 3);
+        assert_eq!((code.length(), code.dimension()), (21, 4));
+        assert_eq!(code.minimum_distance(), 9);
+        assert_eq!(
+            code.minimum_distance(),
+            crate::codes::minimum_distance(&code)
+        );
+        let product = code.generator_matrix() * &code.parity_check_matrix().transposed();
+        assert_eq!(product, BinMatrix::zero(4, 17));
+        for _ in 0..100 {
+            let message = BinVector::random(4);
+            let codeword = code.encode(&message);
+            assert_eq!(codeword, &message * code.generator_matrix());
+            assert!(code.is_codeword(&codeword));
+            assert_eq!(code.decode_to_message(&codeword), Ok(message.clone()));
+
+            // one error in every block
+            let mut received = codeword.clone();
+            for block in 0..3 {
+                let pos = block * 7 + rand::random::<usize>() % 7;
+                received.set(pos, !received[pos]);
+            }
+            assert!(!code.is_codeword(&received));
+            assert_eq!(code.decode_to_code(&received), Ok(codeword.clone()));
+
+            // the other blocks outvote a garbled one
+            let mut received = codeword.clone();
+            for pos in 7..14 {
+                received.set(pos, rand::random());
+            }
+            assert_eq!(code.decode_to_message(&received), Ok(message));
+        }
+    }
+}
@@ -58,6 +58,19 @@ impl BinaryCode for RepetitionCode {
         panic!("not yet implemented");
     }
 
+    fn covering_radius(&self) -> usize {
+        self.k / 2
+    }
+
+    fn error_correction_capability(&self) -> usize {
+        (self.k - 1) / 2
+    }
+
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        let ones = v.count_ones() as usize;
+        ones == 0 || ones == self.k
+    }
+
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
         let bit = c.count_ones() > ((self.k / 2) as u32);
         Ok(BinVector::from_elem(self.k, bit))
@@ -0,0 +1,210 @@
+use crate::codes::BinaryCode;
+use fnv::{FnvHashMap, FnvHashSet};
+use m4ri_rust::friendly::*;
+
+/// The syndrome trellis of a code of length at most 64, for Viterbi decoding
+///
+/// The states at depth $i$ are the partial syndromes $\sum_{j < i} c_j h_j$ of the
+/// columns $h_j$ of the parity check matrix, an edge from depth $i$ to $i + 1$ chooses
+/// the bit $c_i$. Every path from the zero state at depth 0 to the zero state at depth
+/// $n$ is a codeword. States that are not on such a path are pruned, which leaves the
+/// minimal trellis with at most $2^{\min(k, n - k)}$ states at every depth.
+///
+/// Building the trellis needs memory for up to $2^{n - k}$ partial syndromes.
+#[derive(Debug, Clone)]
+pub struct SyndromeTrellis {
+    /// The partial syndromes at every depth
+    states: Vec<Vec<u64>>,
+    /// For every state at depth $i$, the next state at depth $i + 1$ for the bits 0 and 1
+    edges: Vec<Vec<[Option<u32>; 2]>>,
+}
+
+impl SyndromeTrellis {
+    /// Construct the trellis from the parity check matrix of `code`
+    pub fn new<C: BinaryCode + ?Sized>(code: &C) -> SyndromeTrellis {
+        let n = code.length();
+        assert!(n <= 64, "The trellis only supports codes up to length 64");
+        let parity_check = code.parity_check_matrix();
+
+        // reduce the checks to a basis, every basis vector has a highest bit that is
+        // not set in the ones before it
+        let mut checks: Vec<u64> = Vec::new();
+        for row in 0..parity_check.nrows() {
+            let check = (0..n)
+                .filter(|&col| parity_check.bit(row, col))
+                .fold(0u64, |check, col| check | 1 << col);
+            let check = checks
+                .iter()
+                .fold(check, |check, basis| check.min(check ^ basis));
+            if check != 0 {
+                checks.push(check);
+            }
+        }
+        let columns: Vec<u64> = (0..n)
+            .map(|col| {
+                checks
+                    .iter()
+                    .enumerate()
+                    .fold(0u64, |column, (row, check)| {
+                        column | ((check >> col) & 1) << row
+                    })
+            })
+            .collect();
+
+        // the partial syndromes from which the zero syndrome can be reached
+        let mut backward = vec![FnvHashSet::default(); n + 1];
+        backward[n].insert(0u64);
+        for i in (0..n).rev() {
+            let reachable: FnvHashSet<u64> = backward[i + 1]
+                .iter()
+                .flat_map(|&state| [state, state ^ columns[i]])
+                .collect();
+            backward[i] = reachable;
+        }
+
+        let mut states = vec![vec![0u64]];
+        let mut edges = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut next_states: FnvHashMap<u64, u32> = FnvHashMap::default();
+            let section = states[i]
+                .iter()
+                .map(|&state| {
+                    let mut next = [None, None];
+                    for (bit, next_state) in [state, state ^ columns[i]].iter().enumerate() {
+                        if backward[i + 1].contains(next_state) {
+                            let index = next_states.len() as u32;
+                            next[bit] = Some(*next_states.entry(*next_state).or_insert(index));
+                        }
+                    }
+                    next
+                })
+                .collect();
+            let mut next_states: Vec<(u64, u32)> = next_states.into_iter().collect();
+            next_states.sort_unstable_by_key(|&(_, index)| index);
+            states.push(next_states.into_iter().map(|(state, _)| state).collect());
+            edges.push(section);
+        }
+
+        SyndromeTrellis { states, edges }
+    }
+
+    /// The length of the code
+    pub fn length(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// The largest number of states at any depth
+    pub fn state_complexity(&self) -> usize {
+        self.states.iter().map(Vec::len).max().unwrap()
+    }
+
+    /// Decode `received` to a closest codeword in Hamming distance
+    pub fn decode(&self, received: &BinVector) -> BinVector {
+        self.decode_soft(received, &vec![1.0; received.len()])
+    }
+
+    /// Decode the hard decisions `received` using the reliability of every bit
+    ///
+    /// Finds the codeword $c$ that minimises $\sum_{i : c_i \ne r_i} w_i$ for the
+    /// reliabilities $w_i \ge 0$, e.g. the absolute values of the log-likelihood ratios.
+    /// Ties are broken towards zero bits.
+    pub fn decode_soft(&self, received: &BinVector, reliabilities: &[f64]) -> BinVector {
+        let n = self.length();
+        assert_eq!(received.len(), n, "the length doesn't match the code");
+        assert_eq!(
+            reliabilities.len(),
+            n,
+            "Need a reliability for every received bit"
+        );
+
+        let mut costs = vec![0f64];
+        // for every state the previous state and the bit of the best path into it
+        let mut survivors: Vec<Vec<(u32, bool)>> = Vec::with_capacity(n);
+        for (i, section) in self.edges.iter().enumerate() {
+            let mut next_costs = vec![f64::INFINITY; self.states[i + 1].len()];
+            let mut next_survivors = vec![(0, false); next_costs.len()];
+            for (state, (cost, next)) in costs.iter().zip(section.iter()).enumerate() {
+                for (bit, next) in [false, true].iter().zip(next.iter()) {
+                    if let Some(next) = *next {
+                        let mut cost = *cost;
+                        if *bit != received[i] {
+                            cost += reliabilities[i];
+                        }
+                        if cost < next_costs[next as usize] {
+                            next_costs[next as usize] = cost;
+                            next_survivors[next as usize] = (state as u32, *bit);
+                        }
+                    }
+                }
+            }
+            costs = next_costs;
+            survivors.push(next_survivors);
+        }
+
+        // trace back from the zero syndrome, the only state at depth n
+        let mut state = 0;
+        let mut codeword = vec![false; n];
+        for (i, section) in survivors.iter().enumerate().rev() {
+            let (previous, bit) = section[state];
+            codeword[i] = bit;
+            state = previous as usize;
+        }
+        BinVector::from_bools(&codeword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::{HammingCode, RepetitionCode};
+
+    #[test]
+    fn hard_decoding() {
+        let code = HammingCode::new(3);
+        let trellis = SyndromeTrellis::new(&code);
+        assert_eq!(trellis.length(), 7);
+        assert_eq!(trellis.state_complexity(), 8);
+        for codeword in code.all_codewords() {
+            assert_eq!(trellis.decode(&codeword), codeword);
+            for i in 0..7 {
+                let mut received = codeword.clone();
+                received.set(i, !received[i]);
+                assert_eq!(trellis.decode(&received), codeword);
+            }
+        }
+    }
+
+    #[test]
+    fn soft_decoding() {
+        let code = RepetitionCode::new(3);
+        let trellis = SyndromeTrellis::new(&code);
+        assert_eq!(trellis.state_complexity(), 2);
+        let received = BinVector::from_bools(&[true, true, false]);
+        assert_eq!(trellis.decode(&received), BinVector::from_elem(3, true));
+        assert_eq!(
+            trellis.decode_soft(&received, &[0.2, 0.2, 1.0]),
+            BinVector::from_elem(3, false)
+        );
+
+        // compare with the best codeword found by trying all of them
+        let code = HammingCode::new(4);
+        let trellis = SyndromeTrellis::new(&code);
+        let cost = |codeword: &BinVector, received: &BinVector, reliabilities: &[f64]| {
+            (0..15)
+                .filter(|&i| codeword[i] != received[i])
+                .map(|i| reliabilities[i])
+                .sum::<f64>()
+        };
+        for _ in 0..50 {
+            let received = BinVector::random(15);
+            let reliabilities: Vec<f64> = (0..15).map(|_| rand::random()).collect();
+            let decoded = trellis.decode_soft(&received, &reliabilities);
+            assert!(code.is_codeword(&decoded));
+            let best = code
+                .all_codewords()
+                .map(|codeword| cost(&codeword, &received, &reliabilities))
+                .fold(f64::INFINITY, f64::min);
+            assert!((cost(&decoded, &received, &reliabilities) - best).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Helpers to construct codes at runtime
+use fnv::FnvHashMap;
+use m4ri_rust::friendly::{BinMatrix, BinVector};
+use rayon::prelude::*;
+
+/// Build the table of minimum-weight coset leaders for every syndrome
+///
+/// The syndrome of $e$ is $e H^T$ read as a `u64`, the coset leaders are stored as
+/// `u64` blocks. Starting from the zero syndrome, every round adds all unit vectors
+/// to the leaders found in the previous round in parallel, so the syndromes found in
+/// round $w$ are exactly those with leaders of weight $w$. Of several leaders of the
+/// same weight, the smallest one is kept, so the table does not depend on scheduling.
+///
+/// Takes $n 2^{n-k}$ steps, which is feasible for up to about $n - k = 24$.
+pub fn build_syndrome_map(
+    parity_check_matrix_t: &BinMatrix,
+    n: usize,
+) -> FnvHashMap<u64, Vec<u64>> {
+    assert_eq!(parity_check_matrix_t.nrows(), n, "H^T should have n rows");
+    let redundancy = parity_check_matrix_t.ncols();
+    assert!(redundancy <= 32, "Too many syndromes for a table");
+    let syndromes = 1usize << redundancy;
+    let blocks = n.div_ceil(64);
+
+    let columns: Vec<u64> = (0..n)
+        .map(|i| {
+            let mut unit = BinVector::from_elem(n, false);
+            unit.set(i, true);
+            (&unit * parity_check_matrix_t).as_u64()
+        })
+        .collect();
+
+    let mut map: FnvHashMap<u64, Vec<u64>> =
+        FnvHashMap::with_capacity_and_hasher(syndromes, Default::default());
+    map.insert(0, vec![0; blocks]);
+    let mut frontier = vec![0u64];
+    while map.len() < syndromes && !frontier.is_empty() {
+        let known = &map;
+        let mut found: Vec<(u64, Vec<u64>)> = frontier
+            .par_iter()
+            .flat_map_iter(|syndrome| {
+                let leader = &known[syndrome];
+                columns
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(i, _)| (leader[i / 64] >> (i % 64)) & 1 == 0)
+                    .filter_map(move |(i, column)| {
+                        let next = syndrome ^ column;
+                        if known.contains_key(&next) {
+                            return None;
+                        }
+                        let mut next_leader = leader.clone();
+                        next_leader[i / 64] |= 1 << (i % 64);
+                        Some((next, next_leader))
+                    })
+            })
+            .collect();
+        // keep the smallest leader for every new syndrome
+        found.par_sort_unstable_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then_with(|| a.1.iter().rev().cmp(b.1.iter().rev()))
+        });
+        found.dedup_by_key(|(syndrome, _)| *syndrome);
+        frontier = found.iter().map(|(syndrome, _)| *syndrome).collect();
+        map.extend(found);
+    }
+    assert_eq!(
+        map.len(),
+        syndromes,
+        "The parity check matrix should have full rank"
+    );
+    map
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn syndrome(v: &BinVector, h_t: &BinMatrix) -> u64 {
+        (v * h_t).as_u64()
+    }
+
+    fn from_blocks(blocks: &[u64], n: usize) -> BinVector {
+        BinVector::from_bools(
+            &(0..n)
+                .map(|i| (blocks[i / 64] >> (i % 64)) & 1 == 1)
+                .collect::<Vec<bool>>(),
+        )
+    }
+
+    #[test]
+    fn hamming_code() {
+        // the columns of H are 1, ..., 7
+        let h = BinMatrix::from_slices(&[&[0b1010101u64], &[0b1100110], &[0b1111000]], 7);
+        let h_t = h.transposed();
+        let map = build_syndrome_map(&h_t, 7);
+        assert_eq!(map.len(), 8);
+        for (s, leader) in map.iter() {
+            let leader = from_blocks(leader, 7);
+            assert!(leader.count_ones() <= 1);
+            assert_eq!(syndrome(&leader, &h_t), *s);
+        }
+    }
+
+    #[test]
+    fn minimum_weight_leaders() {
+        let n = 14;
+        let h = loop {
+            let h = BinMatrix::random(6, n);
+            if h.rank() == 6 {
+                break h;
+            }
+        };
+        let h_t = h.transposed();
+        let map = build_syndrome_map(&h_t, n);
+        assert_eq!(map.len(), 1 << 6);
+
+        let mut min_weight: FnvHashMap<u64, u32> = FnvHashMap::default();
+        for x in 0..(1u64 << n) {
+            let v = from_blocks(&[x], n);
+            let weight = min_weight.entry(syndrome(&v, &h_t)).or_insert(u32::MAX);
+            *weight = (*weight).min(v.count_ones());
+        }
+        for (s, leader) in map.iter() {
+            let leader = from_blocks(leader, n);
+            assert_eq!(syndrome(&leader, &h_t), *s);
+            assert_eq!(leader.count_ones(), min_weight[s]);
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let h = loop {
+            let h = BinMatrix::random(8, 20);
+            if h.rank() == 8 {
+                break h;
+            }
+        };
+        let h_t = h.transposed();
+        assert_eq!(build_syndrome_map(&h_t, 20), build_syndrome_map(&h_t, 20));
+    }
+}
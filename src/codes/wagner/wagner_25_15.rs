@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -15,1089 +14,1090 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct WagnerCode25_15;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 14843905 ],
-                &[ 28835842 ],
-                &[ 5963780 ],
-                &[ 2981896 ],
-                &[ 33521680 ],
-                &[ 18251808 ],
-                &[ 29687872 ],
-                &[ 25919616 ],
-                &[ 24215808 ],
-                &[ 14451200 ],
-                &[ 5604352 ],
-                &[ 9537536 ],
-                &[ 10686464 ],
-                &[ 3481600 ],
-                &[ 31014912 ],
-                
-            ], 25));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 16693313 ],
-                &[ 8192066 ],
-                &[ 27131972 ],
-                &[ 32022600 ],
-                &[ 22331472 ],
-                &[ 13865056 ],
-                &[ 15071360 ],
-                &[ 2091264 ],
-                &[ 28498432 ],
-                &[ 31001600 ],
-                
-            ], 25));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 14843905 ],
+            &[ 28835842 ],
+            &[ 5963780 ],
+            &[ 2981896 ],
+            &[ 33521680 ],
+            &[ 18251808 ],
+            &[ 29687872 ],
+            &[ 25919616 ],
+            &[ 24215808 ],
+            &[ 14451200 ],
+            &[ 5604352 ],
+            &[ 9537536 ],
+            &[ 10686464 ],
+            &[ 3481600 ],
+            &[ 31014912 ],
+            
+        ], 25)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(1024, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(7, &[7]);     // 7 => [7]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(11, &[11]);     // 11 => [11]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(15, &[112]);     // 15 => [112]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(19, &[19]);     // 19 => [19]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(23, &[104]);     // 23 => [104]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(27, &[100]);     // 27 => [100]
-            map.insert(28, &[270338]);     // 28 => [270338]
-            map.insert(29, &[98]);     // 29 => [98]
-            map.insert(30, &[270336]);     // 30 => [270336]
-            map.insert(31, &[96]);     // 31 => [96]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(35, &[35]);     // 35 => [35]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(39, &[88]);     // 39 => [88]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(41, &[49152]);     // 41 => [49152]
-            map.insert(42, &[2101248]);     // 42 => [2101248]
-            map.insert(43, &[2101249]);     // 43 => [2101249]
-            map.insert(44, &[44]);     // 44 => [44]
-            map.insert(45, &[82]);     // 45 => [82]
-            map.insert(46, &[81]);     // 46 => [81]
-            map.insert(47, &[80]);     // 47 => [80]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(51, &[76]);     // 51 => [76]
-            map.insert(52, &[52]);     // 52 => [52]
-            map.insert(53, &[74]);     // 53 => [74]
-            map.insert(54, &[73]);     // 54 => [73]
-            map.insert(55, &[72]);     // 55 => [72]
-            map.insert(56, &[56]);     // 56 => [56]
-            map.insert(57, &[70]);     // 57 => [70]
-            map.insert(58, &[69]);     // 58 => [69]
-            map.insert(59, &[68]);     // 59 => [68]
-            map.insert(60, &[67]);     // 60 => [67]
-            map.insert(61, &[66]);     // 61 => [66]
-            map.insert(62, &[65]);     // 62 => [65]
-            map.insert(63, &[64]);     // 63 => [64]
-            map.insert(64, &[128]);     // 64 => [128]
-            map.insert(65, &[129]);     // 65 => [129]
-            map.insert(66, &[130]);     // 66 => [130]
-            map.insert(67, &[131]);     // 67 => [131]
-            map.insert(68, &[132]);     // 68 => [132]
-            map.insert(69, &[133]);     // 69 => [133]
-            map.insert(70, &[134]);     // 70 => [134]
-            map.insert(71, &[262416]);     // 71 => [262416]
-            map.insert(72, &[136]);     // 72 => [136]
-            map.insert(73, &[8448]);     // 73 => [8448]
-            map.insert(74, &[2097665]);     // 74 => [2097665]
-            map.insert(75, &[2097664]);     // 75 => [2097664]
-            map.insert(76, &[140]);     // 76 => [140]
-            map.insert(77, &[8452]);     // 77 => [8452]
-            map.insert(78, &[25165888]);     // 78 => [25165888]
-            map.insert(79, &[2097668]);     // 79 => [2097668]
-            map.insert(80, &[144]);     // 80 => [144]
-            map.insert(81, &[145]);     // 81 => [145]
-            map.insert(82, &[146]);     // 82 => [146]
-            map.insert(83, &[262404]);     // 83 => [262404]
-            map.insert(84, &[148]);     // 84 => [148]
-            map.insert(85, &[262402]);     // 85 => [262402]
-            map.insert(86, &[262401]);     // 86 => [262401]
-            map.insert(87, &[262400]);     // 87 => [262400]
-            map.insert(88, &[163844]);     // 88 => [163844]
-            map.insert(89, &[8464]);     // 89 => [8464]
-            map.insert(90, &[9437440]);     // 90 => [9437440]
-            map.insert(91, &[2097680]);     // 91 => [2097680]
-            map.insert(92, &[163840]);     // 92 => [163840]
-            map.insert(93, &[163841]);     // 93 => [163841]
-            map.insert(94, &[163842]);     // 94 => [163842]
-            map.insert(95, &[262408]);     // 95 => [262408]
-            map.insert(96, &[160]);     // 96 => [160]
-            map.insert(97, &[4608]);     // 97 => [4608]
-            map.insert(98, &[162]);     // 98 => [162]
-            map.insert(99, &[4610]);     // 99 => [4610]
-            map.insert(100, &[164]);     // 100 => [164]
-            map.insert(101, &[4612]);     // 101 => [4612]
-            map.insert(102, &[1050640]);     // 102 => [1050640]
-            map.insert(103, &[20972032]);     // 103 => [20972032]
-            map.insert(104, &[168]);     // 104 => [168]
-            map.insert(105, &[589828]);     // 105 => [589828]
-            map.insert(106, &[2101376]);     // 106 => [2101376]
-            map.insert(107, &[4195344]);     // 107 => [4195344]
-            map.insert(108, &[589825]);     // 108 => [589825]
-            map.insert(109, &[589824]);     // 109 => [589824]
-            map.insert(110, &[2228480]);     // 110 => [2228480]
-            map.insert(111, &[589826]);     // 111 => [589826]
-            map.insert(112, &[25165825]);     // 112 => [25165825]
-            map.insert(113, &[25165824]);     // 113 => [25165824]
-            map.insert(114, &[1050628]);     // 114 => [1050628]
-            map.insert(115, &[25165826]);     // 115 => [25165826]
-            map.insert(116, &[147457]);     // 116 => [147457]
-            map.insert(117, &[147456]);     // 117 => [147456]
-            map.insert(118, &[1050624]);     // 118 => [1050624]
-            map.insert(119, &[1050625]);     // 119 => [1050625]
-            map.insert(120, &[28672]);     // 120 => [28672]
-            map.insert(121, &[4195330]);     // 121 => [4195330]
-            map.insert(122, &[4195329]);     // 122 => [4195329]
-            map.insert(123, &[4195328]);     // 123 => [4195328]
-            map.insert(124, &[163872]);     // 124 => [163872]
-            map.insert(125, &[194]);     // 125 => [194]
-            map.insert(126, &[193]);     // 126 => [193]
-            map.insert(127, &[192]);     // 127 => [192]
-            map.insert(128, &[256]);     // 128 => [256]
-            map.insert(129, &[257]);     // 129 => [257]
-            map.insert(130, &[258]);     // 130 => [258]
-            map.insert(131, &[259]);     // 131 => [259]
-            map.insert(132, &[260]);     // 132 => [260]
-            map.insert(133, &[261]);     // 133 => [261]
-            map.insert(134, &[262]);     // 134 => [262]
-            map.insert(135, &[525320]);     // 135 => [525320]
-            map.insert(136, &[264]);     // 136 => [264]
-            map.insert(137, &[8320]);     // 137 => [8320]
-            map.insert(138, &[266]);     // 138 => [266]
-            map.insert(139, &[8322]);     // 139 => [8322]
-            map.insert(140, &[268]);     // 140 => [268]
-            map.insert(141, &[525314]);     // 141 => [525314]
-            map.insert(142, &[525313]);     // 142 => [525313]
-            map.insert(143, &[525312]);     // 143 => [525312]
-            map.insert(144, &[272]);     // 144 => [272]
-            map.insert(145, &[273]);     // 145 => [273]
-            map.insert(146, &[274]);     // 146 => [274]
-            map.insert(147, &[262276]);     // 147 => [262276]
-            map.insert(148, &[276]);     // 148 => [276]
-            map.insert(149, &[262274]);     // 149 => [262274]
-            map.insert(150, &[262273]);     // 150 => [262273]
-            map.insert(151, &[262272]);     // 151 => [262272]
-            map.insert(152, &[36864]);     // 152 => [36864]
-            map.insert(153, &[4259840]);     // 153 => [4259840]
-            map.insert(154, &[2113537]);     // 154 => [2113537]
-            map.insert(155, &[2113536]);     // 155 => [2113536]
-            map.insert(156, &[36868]);     // 156 => [36868]
-            map.insert(157, &[4259844]);     // 157 => [4259844]
-            map.insert(158, &[270592]);     // 158 => [270592]
-            map.insert(159, &[2113540]);     // 159 => [2113540]
-            map.insert(160, &[288]);     // 160 => [288]
-            map.insert(161, &[289]);     // 161 => [289]
-            map.insert(162, &[290]);     // 162 => [290]
-            map.insert(163, &[17825800]);     // 163 => [17825800]
-            map.insert(164, &[131585]);     // 164 => [131585]
-            map.insert(165, &[131584]);     // 165 => [131584]
-            map.insert(166, &[4259904]);     // 166 => [4259904]
-            map.insert(167, &[131586]);     // 167 => [131586]
-            map.insert(168, &[8390660]);     // 168 => [8390660]
-            map.insert(169, &[17825794]);     // 169 => [17825794]
-            map.insert(170, &[17825793]);     // 170 => [17825793]
-            map.insert(171, &[17825792]);     // 171 => [17825792]
-            map.insert(172, &[8390656]);     // 172 => [8390656]
-            map.insert(173, &[8390657]);     // 173 => [8390657]
-            map.insert(174, &[8390658]);     // 174 => [8390658]
-            map.insert(175, &[17825796]);     // 175 => [17825796]
-            map.insert(176, &[20481]);     // 176 => [20481]
-            map.insert(177, &[20480]);     // 177 => [20480]
-            map.insert(178, &[2129920]);     // 178 => [2129920]
-            map.insert(179, &[2129921]);     // 179 => [2129921]
-            map.insert(180, &[4718720]);     // 180 => [4718720]
-            map.insert(181, &[20484]);     // 181 => [20484]
-            map.insert(182, &[2129924]);     // 182 => [2129924]
-            map.insert(183, &[328]);     // 183 => [328]
-            map.insert(184, &[36896]);     // 184 => [36896]
-            map.insert(185, &[20488]);     // 185 => [20488]
-            map.insert(186, &[2129928]);     // 186 => [2129928]
-            map.insert(187, &[324]);     // 187 => [324]
-            map.insert(188, &[8390672]);     // 188 => [8390672]
-            map.insert(189, &[322]);     // 189 => [322]
-            map.insert(190, &[321]);     // 190 => [321]
-            map.insert(191, &[320]);     // 191 => [320]
-            map.insert(192, &[384]);     // 192 => [384]
-            map.insert(193, &[8200]);     // 193 => [8200]
-            map.insert(194, &[386]);     // 194 => [386]
-            map.insert(195, &[8202]);     // 195 => [8202]
-            map.insert(196, &[135168]);     // 196 => [135168]
-            map.insert(197, &[135169]);     // 197 => [135169]
-            map.insert(198, &[262161]);     // 198 => [262161]
-            map.insert(199, &[262160]);     // 199 => [262160]
-            map.insert(200, &[8193]);     // 200 => [8193]
-            map.insert(201, &[8192]);     // 201 => [8192]
-            map.insert(202, &[8195]);     // 202 => [8195]
-            map.insert(203, &[8194]);     // 203 => [8194]
-            map.insert(204, &[8197]);     // 204 => [8197]
-            map.insert(205, &[8196]);     // 205 => [8196]
-            map.insert(206, &[2228256]);     // 206 => [2228256]
-            map.insert(207, &[8198]);     // 207 => [8198]
-            map.insert(208, &[16896]);     // 208 => [16896]
-            map.insert(209, &[16897]);     // 209 => [16897]
-            map.insert(210, &[262149]);     // 210 => [262149]
-            map.insert(211, &[262148]);     // 211 => [262148]
-            map.insert(212, &[262147]);     // 212 => [262147]
-            map.insert(213, &[262146]);     // 213 => [262146]
-            map.insert(214, &[262145]);     // 214 => [262145]
-            map.insert(215, &[262144]);     // 215 => [262144]
-            map.insert(216, &[8209]);     // 216 => [8209]
-            map.insert(217, &[8208]);     // 217 => [8208]
-            map.insert(218, &[9437184]);     // 218 => [9437184]
-            map.insert(219, &[9437185]);     // 219 => [9437185]
-            map.insert(220, &[16779265]);     // 220 => [16779265]
-            map.insert(221, &[16779264]);     // 221 => [16779264]
-            map.insert(222, &[262153]);     // 222 => [262153]
-            map.insert(223, &[262152]);     // 223 => [262152]
-            map.insert(224, &[66562]);     // 224 => [66562]
-            map.insert(225, &[8232]);     // 225 => [8232]
-            map.insert(226, &[66560]);     // 226 => [66560]
-            map.insert(227, &[66561]);     // 227 => [66561]
-            map.insert(228, &[4718608]);     // 228 => [4718608]
-            map.insert(229, &[9437248]);     // 229 => [9437248]
-            map.insert(230, &[66564]);     // 230 => [66564]
-            map.insert(231, &[262192]);     // 231 => [262192]
-            map.insert(232, &[262208]);     // 232 => [262208]
-            map.insert(233, &[8224]);     // 233 => [8224]
-            map.insert(234, &[262210]);     // 234 => [262210]
-            map.insert(235, &[8226]);     // 235 => [8226]
-            map.insert(236, &[2228226]);     // 236 => [2228226]
-            map.insert(237, &[8228]);     // 237 => [8228]
-            map.insert(238, &[2228224]);     // 238 => [2228224]
-            map.insert(239, &[2228225]);     // 239 => [2228225]
-            map.insert(240, &[4718596]);     // 240 => [4718596]
-            map.insert(241, &[33288]);     // 241 => [33288]
-            map.insert(242, &[8260]);     // 242 => [8260]
-            map.insert(243, &[262180]);     // 243 => [262180]
-            map.insert(244, &[4718592]);     // 244 => [4718592]
-            map.insert(245, &[4718593]);     // 245 => [4718593]
-            map.insert(246, &[8256]);     // 246 => [8256]
-            map.insert(247, &[262176]);     // 247 => [262176]
-            map.insert(248, &[33281]);     // 248 => [33281]
-            map.insert(249, &[33280]);     // 249 => [33280]
-            map.insert(250, &[9437216]);     // 250 => [9437216]
-            map.insert(251, &[33282]);     // 251 => [33282]
-            map.insert(252, &[4718600]);     // 252 => [4718600]
-            map.insert(253, &[33284]);     // 253 => [33284]
-            map.insert(254, &[8264]);     // 254 => [8264]
-            map.insert(255, &[262184]);     // 255 => [262184]
-            map.insert(256, &[512]);     // 256 => [512]
-            map.insert(257, &[513]);     // 257 => [513]
-            map.insert(258, &[514]);     // 258 => [514]
-            map.insert(259, &[515]);     // 259 => [515]
-            map.insert(260, &[516]);     // 260 => [516]
-            map.insert(261, &[517]);     // 261 => [517]
-            map.insert(262, &[278529]);     // 262 => [278529]
-            map.insert(263, &[278528]);     // 263 => [278528]
-            map.insert(264, &[520]);     // 264 => [520]
-            map.insert(265, &[521]);     // 265 => [521]
-            map.insert(266, &[2097281]);     // 266 => [2097281]
-            map.insert(267, &[2097280]);     // 267 => [2097280]
-            map.insert(268, &[524]);     // 268 => [524]
-            map.insert(269, &[16795648]);     // 269 => [16795648]
-            map.insert(270, &[294944]);     // 270 => [294944]
-            map.insert(271, &[2097284]);     // 271 => [2097284]
-            map.insert(272, &[528]);     // 272 => [528]
-            map.insert(273, &[529]);     // 273 => [529]
-            map.insert(274, &[530]);     // 274 => [530]
-            map.insert(275, &[17301760]);     // 275 => [17301760]
-            map.insert(276, &[12582914]);     // 276 => [12582914]
-            map.insert(277, &[1114240]);     // 277 => [1114240]
-            map.insert(278, &[12582912]);     // 278 => [12582912]
-            map.insert(279, &[12582913]);     // 279 => [12582913]
-            map.insert(280, &[24577]);     // 280 => [24577]
-            map.insert(281, &[24576]);     // 281 => [24576]
-            map.insert(282, &[131392]);     // 282 => [131392]
-            map.insert(283, &[24578]);     // 283 => [24578]
-            map.insert(284, &[16778240]);     // 284 => [16778240]
-            map.insert(285, &[16778241]);     // 285 => [16778241]
-            map.insert(286, &[16778242]);     // 286 => [16778242]
-            map.insert(287, &[608]);     // 287 => [608]
-            map.insert(288, &[544]);     // 288 => [544]
-            map.insert(289, &[4224]);     // 289 => [4224]
-            map.insert(290, &[67585]);     // 290 => [67585]
-            map.insert(291, &[67584]);     // 291 => [67584]
-            map.insert(292, &[131329]);     // 292 => [131329]
-            map.insert(293, &[131328]);     // 293 => [131328]
-            map.insert(294, &[294920]);     // 294 => [294920]
-            map.insert(295, &[131330]);     // 295 => [131330]
-            map.insert(296, &[552]);     // 296 => [552]
-            map.insert(297, &[4232]);     // 297 => [4232]
-            map.insert(298, &[294916]);     // 298 => [294916]
-            map.insert(299, &[67592]);     // 299 => [67592]
-            map.insert(300, &[294914]);     // 300 => [294914]
-            map.insert(301, &[131336]);     // 301 => [131336]
-            map.insert(302, &[294912]);     // 302 => [294912]
-            map.insert(303, &[294913]);     // 303 => [294913]
-            map.insert(304, &[40960]);     // 304 => [40960]
-            map.insert(305, &[40961]);     // 305 => [40961]
-            map.insert(306, &[40962]);     // 306 => [40962]
-            map.insert(307, &[67600]);     // 307 => [67600]
-            map.insert(308, &[40964]);     // 308 => [40964]
-            map.insert(309, &[131344]);     // 309 => [131344]
-            map.insert(310, &[12582944]);     // 310 => [12582944]
-            map.insert(311, &[584]);     // 311 => [584]
-            map.insert(312, &[1572864]);     // 312 => [1572864]
-            map.insert(313, &[1572865]);     // 313 => [1572865]
-            map.insert(314, &[1572866]);     // 314 => [1572866]
-            map.insert(315, &[580]);     // 315 => [580]
-            map.insert(316, &[1572868]);     // 316 => [1572868]
-            map.insert(317, &[578]);     // 317 => [578]
-            map.insert(318, &[577]);     // 318 => [577]
-            map.insert(319, &[576]);     // 319 => [576]
-            map.insert(320, &[640]);     // 320 => [640]
-            map.insert(321, &[4128]);     // 321 => [4128]
-            map.insert(322, &[2097161]);     // 322 => [2097161]
-            map.insert(323, &[2097160]);     // 323 => [2097160]
-            map.insert(324, &[644]);     // 324 => [644]
-            map.insert(325, &[4132]);     // 325 => [4132]
-            map.insert(326, &[526344]);     // 326 => [526344]
-            map.insert(327, &[2097164]);     // 327 => [2097164]
-            map.insert(328, &[2097155]);     // 328 => [2097155]
-            map.insert(329, &[2097154]);     // 329 => [2097154]
-            map.insert(330, &[2097153]);     // 330 => [2097153]
-            map.insert(331, &[2097152]);     // 331 => [2097152]
-            map.insert(332, &[526338]);     // 332 => [526338]
-            map.insert(333, &[2097158]);     // 333 => [2097158]
-            map.insert(334, &[526336]);     // 334 => [526336]
-            map.insert(335, &[2097156]);     // 335 => [2097156]
-            map.insert(336, &[16640]);     // 336 => [16640]
-            map.insert(337, &[16641]);     // 337 => [16641]
-            map.insert(338, &[16642]);     // 338 => [16642]
-            map.insert(339, &[2097176]);     // 339 => [2097176]
-            map.insert(340, &[1114113]);     // 340 => [1114113]
-            map.insert(341, &[1114112]);     // 341 => [1114112]
-            map.insert(342, &[4168]);     // 342 => [4168]
-            map.insert(343, &[1114114]);     // 343 => [1114114]
-            map.insert(344, &[16648]);     // 344 => [16648]
-            map.insert(345, &[2097170]);     // 345 => [2097170]
-            map.insert(346, &[2097169]);     // 346 => [2097169]
-            map.insert(347, &[2097168]);     // 347 => [2097168]
-            map.insert(348, &[4162]);     // 348 => [4162]
-            map.insert(349, &[1114120]);     // 349 => [1114120]
-            map.insert(350, &[4160]);     // 350 => [4160]
-            map.insert(351, &[4161]);     // 351 => [4161]
-            map.insert(352, &[4097]);     // 352 => [4097]
-            map.insert(353, &[4096]);     // 353 => [4096]
-            map.insert(354, &[4099]);     // 354 => [4099]
-            map.insert(355, &[4098]);     // 355 => [4098]
-            map.insert(356, &[4101]);     // 356 => [4101]
-            map.insert(357, &[4100]);     // 357 => [4100]
-            map.insert(358, &[20971521]);     // 358 => [20971521]
-            map.insert(359, &[20971520]);     // 359 => [20971520]
-            map.insert(360, &[4105]);     // 360 => [4105]
-            map.insert(361, &[4104]);     // 361 => [4104]
-            map.insert(362, &[2097185]);     // 362 => [2097185]
-            map.insert(363, &[2097184]);     // 363 => [2097184]
-            map.insert(364, &[139264]);     // 364 => [139264]
-            map.insert(365, &[8389632]);     // 365 => [8389632]
-            map.insert(366, &[139266]);     // 366 => [139266]
-            map.insert(367, &[8389634]);     // 367 => [8389634]
-            map.insert(368, &[4113]);     // 368 => [4113]
-            map.insert(369, &[4112]);     // 369 => [4112]
-            map.insert(370, &[393216]);     // 370 => [393216]
-            map.insert(371, &[393217]);     // 371 => [393217]
-            map.insert(372, &[2097216]);     // 372 => [2097216]
-            map.insert(373, &[2097217]);     // 373 => [2097217]
-            map.insert(374, &[2097218]);     // 374 => [2097218]
-            map.insert(375, &[20971536]);     // 375 => [20971536]
-            map.insert(376, &[33025]);     // 376 => [33025]
-            map.insert(377, &[33024]);     // 377 => [33024]
-            map.insert(378, &[393224]);     // 378 => [393224]
-            map.insert(379, &[33026]);     // 379 => [33026]
-            map.insert(380, &[2097224]);     // 380 => [2097224]
-            map.insert(381, &[33028]);     // 381 => [33028]
-            map.insert(382, &[4192]);     // 382 => [4192]
-            map.insert(383, &[704]);     // 383 => [704]
-            map.insert(384, &[768]);     // 384 => [768]
-            map.insert(385, &[769]);     // 385 => [769]
-            map.insert(386, &[2105344]);     // 386 => [2105344]
-            map.insert(387, &[2105345]);     // 387 => [2105345]
-            map.insert(388, &[131105]);     // 388 => [131105]
-            map.insert(389, &[131104]);     // 389 => [131104]
-            map.insert(390, &[2105348]);     // 390 => [2105348]
-            map.insert(391, &[131106]);     // 391 => [131106]
-            map.insert(392, &[776]);     // 392 => [776]
-            map.insert(393, &[266304]);     // 393 => [266304]
-            map.insert(394, &[2105352]);     // 394 => [2105352]
-            map.insert(395, &[8454148]);     // 395 => [8454148]
-            map.insert(396, &[2359312]);     // 396 => [2359312]
-            map.insert(397, &[8454146]);     // 397 => [8454146]
-            map.insert(398, &[8454145]);     // 398 => [8454145]
-            map.insert(399, &[8454144]);     // 399 => [8454144]
-            map.insert(400, &[16512]);     // 400 => [16512]
-            map.insert(401, &[16513]);     // 401 => [16513]
-            map.insert(402, &[17301505]);     // 402 => [17301505]
-            map.insert(403, &[17301504]);     // 403 => [17301504]
-            map.insert(404, &[16516]);     // 404 => [16516]
-            map.insert(405, &[131120]);     // 405 => [131120]
-            map.insert(406, &[266272]);     // 406 => [266272]
-            map.insert(407, &[17301508]);     // 407 => [17301508]
-            map.insert(408, &[131138]);     // 408 => [131138]
-            map.insert(409, &[32928]);     // 409 => [32928]
-            map.insert(410, &[131136]);     // 410 => [131136]
-            map.insert(411, &[131137]);     // 411 => [131137]
-            map.insert(412, &[2359296]);     // 412 => [2359296]
-            map.insert(413, &[2359297]);     // 413 => [2359297]
-            map.insert(414, &[2359298]);     // 414 => [2359298]
-            map.insert(415, &[8454160]);     // 415 => [8454160]
-            map.insert(416, &[131077]);     // 416 => [131077]
-            map.insert(417, &[131076]);     // 417 => [131076]
-            map.insert(418, &[2105376]);     // 418 => [2105376]
-            map.insert(419, &[131078]);     // 419 => [131078]
-            map.insert(420, &[131073]);     // 420 => [131073]
-            map.insert(421, &[131072]);     // 421 => [131072]
-            map.insert(422, &[131075]);     // 422 => [131075]
-            map.insert(423, &[131074]);     // 423 => [131074]
-            map.insert(424, &[12288]);     // 424 => [12288]
-            map.insert(425, &[12289]);     // 425 => [12289]
-            map.insert(426, &[12290]);     // 426 => [12290]
-            map.insert(427, &[17826304]);     // 427 => [17826304]
-            map.insert(428, &[131081]);     // 428 => [131081]
-            map.insert(429, &[131080]);     // 429 => [131080]
-            map.insert(430, &[295168]);     // 430 => [295168]
-            map.insert(431, &[131082]);     // 431 => [131082]
-            map.insert(432, &[16544]);     // 432 => [16544]
-            map.insert(433, &[131092]);     // 433 => [131092]
-            map.insert(434, &[266244]);     // 434 => [266244]
-            map.insert(435, &[1049604]);     // 435 => [1049604]
-            map.insert(436, &[131089]);     // 436 => [131089]
-            map.insert(437, &[131088]);     // 437 => [131088]
-            map.insert(438, &[266240]);     // 438 => [266240]
-            map.insert(439, &[1049600]);     // 439 => [1049600]
-            map.insert(440, &[32897]);     // 440 => [32897]
-            map.insert(441, &[32896]);     // 441 => [32896]
-            map.insert(442, &[4196352]);     // 442 => [4196352]
-            map.insert(443, &[4196353]);     // 443 => [4196353]
-            map.insert(444, &[2359328]);     // 444 => [2359328]
-            map.insert(445, &[32900]);     // 445 => [32900]
-            map.insert(446, &[4196356]);     // 446 => [4196356]
-            map.insert(447, &[1049608]);     // 447 => [1049608]
-            map.insert(448, &[16400]);     // 448 => [16400]
-            map.insert(449, &[3072]);     // 449 => [3072]
-            map.insert(450, &[16402]);     // 450 => [16402]
-            map.insert(451, &[3074]);     // 451 => [3074]
-            map.insert(452, &[32834]);     // 452 => [32834]
-            map.insert(453, &[3076]);     // 453 => [3076]
-            map.insert(454, &[32832]);     // 454 => [32832]
-            map.insert(455, &[32833]);     // 455 => [32833]
-            map.insert(456, &[8705]);     // 456 => [8705]
-            map.insert(457, &[8704]);     // 457 => [8704]
-            map.insert(458, &[2097409]);     // 458 => [2097409]
-            map.insert(459, &[2097408]);     // 459 => [2097408]
-            map.insert(460, &[5242880]);     // 460 => [5242880]
-            map.insert(461, &[5242881]);     // 461 => [5242881]
-            map.insert(462, &[5242882]);     // 462 => [5242882]
-            map.insert(463, &[2097412]);     // 463 => [2097412]
-            map.insert(464, &[16384]);     // 464 => [16384]
-            map.insert(465, &[16385]);     // 465 => [16385]
-            map.insert(466, &[16386]);     // 466 => [16386]
-            map.insert(467, &[16387]);     // 467 => [16387]
-            map.insert(468, &[16388]);     // 468 => [16388]
-            map.insert(469, &[16389]);     // 469 => [16389]
-            map.insert(470, &[262657]);     // 470 => [262657]
-            map.insert(471, &[262656]);     // 471 => [262656]
-            map.insert(472, &[16392]);     // 472 => [16392]
-            map.insert(473, &[32800]);     // 473 => [32800]
-            map.insert(474, &[16394]);     // 474 => [16394]
-            map.insert(475, &[32802]);     // 475 => [32802]
-            map.insert(476, &[16396]);     // 476 => [16396]
-            map.insert(477, &[32804]);     // 477 => [32804]
-            map.insert(478, &[16842784]);     // 478 => [16842784]
-            map.insert(479, &[262664]);     // 479 => [262664]
-            map.insert(480, &[4353]);     // 480 => [4353]
-            map.insert(481, &[4352]);     // 481 => [4352]
-            map.insert(482, &[8912896]);     // 482 => [8912896]
-            map.insert(483, &[8912897]);     // 483 => [8912897]
-            map.insert(484, &[131201]);     // 484 => [131201]
-            map.insert(485, &[131200]);     // 485 => [131200]
-            map.insert(486, &[8912900]);     // 486 => [8912900]
-            map.insert(487, &[131202]);     // 487 => [131202]
-            map.insert(488, &[32785]);     // 488 => [32785]
-            map.insert(489, &[32784]);     // 489 => [32784]
-            map.insert(490, &[8912904]);     // 490 => [8912904]
-            map.insert(491, &[32786]);     // 491 => [32786]
-            map.insert(492, &[5242912]);     // 492 => [5242912]
-            map.insert(493, &[16450]);     // 493 => [16450]
-            map.insert(494, &[16449]);     // 494 => [16449]
-            map.insert(495, &[16448]);     // 495 => [16448]
-            map.insert(496, &[16416]);     // 496 => [16416]
-            map.insert(497, &[32776]);     // 497 => [32776]
-            map.insert(498, &[16418]);     // 498 => [16418]
-            map.insert(499, &[32778]);     // 499 => [32778]
-            map.insert(500, &[16420]);     // 500 => [16420]
-            map.insert(501, &[32780]);     // 501 => [32780]
-            map.insert(502, &[16842760]);     // 502 => [16842760]
-            map.insert(503, &[262688]);     // 503 => [262688]
-            map.insert(504, &[32769]);     // 504 => [32769]
-            map.insert(505, &[32768]);     // 505 => [32768]
-            map.insert(506, &[32771]);     // 506 => [32771]
-            map.insert(507, &[32770]);     // 507 => [32770]
-            map.insert(508, &[32773]);     // 508 => [32773]
-            map.insert(509, &[32772]);     // 509 => [32772]
-            map.insert(510, &[16842752]);     // 510 => [16842752]
-            map.insert(511, &[16842753]);     // 511 => [16842753]
-            map.insert(512, &[1024]);     // 512 => [1024]
-            map.insert(513, &[1025]);     // 513 => [1025]
-            map.insert(514, &[1026]);     // 514 => [1026]
-            map.insert(515, &[1027]);     // 515 => [1027]
-            map.insert(516, &[1028]);     // 516 => [1028]
-            map.insert(517, &[1029]);     // 517 => [1029]
-            map.insert(518, &[1030]);     // 518 => [1030]
-            map.insert(519, &[524552]);     // 519 => [524552]
-            map.insert(520, &[1032]);     // 520 => [1032]
-            map.insert(521, &[1033]);     // 521 => [1033]
-            map.insert(522, &[1034]);     // 522 => [1034]
-            map.insert(523, &[524548]);     // 523 => [524548]
-            map.insert(524, &[8392704]);     // 524 => [8392704]
-            map.insert(525, &[8392705]);     // 525 => [8392705]
-            map.insert(526, &[524545]);     // 526 => [524545]
-            map.insert(527, &[524544]);     // 527 => [524544]
-            map.insert(528, &[1040]);     // 528 => [1040]
-            map.insert(529, &[18432]);     // 529 => [18432]
-            map.insert(530, &[1179648]);     // 530 => [1179648]
-            map.insert(531, &[1179649]);     // 531 => [1179649]
-            map.insert(532, &[1044]);     // 532 => [1044]
-            map.insert(533, &[18436]);     // 533 => [18436]
-            map.insert(534, &[1179652]);     // 534 => [1179652]
-            map.insert(535, &[18874496]);     // 535 => [18874496]
-            map.insert(536, &[16777732]);     // 536 => [16777732]
-            map.insert(537, &[18440]);     // 537 => [18440]
-            map.insert(538, &[1179656]);     // 538 => [1179656]
-            map.insert(539, &[4194464]);     // 539 => [4194464]
-            map.insert(540, &[16777728]);     // 540 => [16777728]
-            map.insert(541, &[16777729]);     // 541 => [16777729]
-            map.insert(542, &[16777730]);     // 542 => [16777730]
-            map.insert(543, &[524560]);     // 543 => [524560]
-            map.insert(544, &[1056]);     // 544 => [1056]
-            map.insert(545, &[1057]);     // 545 => [1057]
-            map.insert(546, &[1058]);     // 546 => [1058]
-            map.insert(547, &[73736]);     // 547 => [73736]
-            map.insert(548, &[10485762]);     // 548 => [10485762]
-            map.insert(549, &[327696]);     // 549 => [327696]
-            map.insert(550, &[10485760]);     // 550 => [10485760]
-            map.insert(551, &[10485761]);     // 551 => [10485761]
-            map.insert(552, &[1064]);     // 552 => [1064]
-            map.insert(553, &[73730]);     // 553 => [73730]
-            map.insert(554, &[73729]);     // 554 => [73729]
-            map.insert(555, &[73728]);     // 555 => [73728]
-            map.insert(556, &[8392736]);     // 556 => [8392736]
-            map.insert(557, &[1179712]);     // 557 => [1179712]
-            map.insert(558, &[10485768]);     // 558 => [10485768]
-            map.insert(559, &[73732]);     // 559 => [73732]
-            map.insert(560, &[34824]);     // 560 => [34824]
-            map.insert(561, &[327684]);     // 561 => [327684]
-            map.insert(562, &[1179680]);     // 562 => [1179680]
-            map.insert(563, &[4194440]);     // 563 => [4194440]
-            map.insert(564, &[327681]);     // 564 => [327681]
-            map.insert(565, &[327680]);     // 565 => [327680]
-            map.insert(566, &[10485776]);     // 566 => [10485776]
-            map.insert(567, &[327682]);     // 567 => [327682]
-            map.insert(568, &[34816]);     // 568 => [34816]
-            map.insert(569, &[34817]);     // 569 => [34817]
-            map.insert(570, &[4194433]);     // 570 => [4194433]
-            map.insert(571, &[4194432]);     // 571 => [4194432]
-            map.insert(572, &[34820]);     // 572 => [34820]
-            map.insert(573, &[1090]);     // 573 => [1090]
-            map.insert(574, &[1089]);     // 574 => [1089]
-            map.insert(575, &[1088]);     // 575 => [1088]
-            map.insert(576, &[1152]);     // 576 => [1152]
-            map.insert(577, &[1153]);     // 577 => [1153]
-            map.insert(578, &[1154]);     // 578 => [1154]
-            map.insert(579, &[2107392]);     // 579 => [2107392]
-            map.insert(580, &[4194368]);     // 580 => [4194368]
-            map.insert(581, &[4194369]);     // 581 => [4194369]
-            map.insert(582, &[532480]);     // 582 => [532480]
-            map.insert(583, &[532481]);     // 583 => [532481]
-            map.insert(584, &[1160]);     // 584 => [1160]
-            map.insert(585, &[9472]);     // 585 => [9472]
-            map.insert(586, &[1081348]);     // 586 => [1081348]
-            map.insert(587, &[4194352]);     // 587 => [4194352]
-            map.insert(588, &[1081346]);     // 588 => [1081346]
-            map.insert(589, &[8389152]);     // 589 => [8389152]
-            map.insert(590, &[1081344]);     // 590 => [1081344]
-            map.insert(591, &[1081345]);     // 591 => [1081345]
-            map.insert(592, &[786440]);     // 592 => [786440]
-            map.insert(593, &[18560]);     // 593 => [18560]
-            map.insert(594, &[8389184]);     // 594 => [8389184]
-            map.insert(595, &[18874372]);     // 595 => [18874372]
-            map.insert(596, &[4194384]);     // 596 => [4194384]
-            map.insert(597, &[18874370]);     // 597 => [18874370]
-            map.insert(598, &[18874369]);     // 598 => [18874369]
-            map.insert(599, &[18874368]);     // 599 => [18874368]
-            map.insert(600, &[786432]);     // 600 => [786432]
-            map.insert(601, &[786433]);     // 601 => [786433]
-            map.insert(602, &[4194337]);     // 602 => [4194337]
-            map.insert(603, &[4194336]);     // 603 => [4194336]
-            map.insert(604, &[786436]);     // 604 => [786436]
-            map.insert(605, &[16781344]);     // 605 => [16781344]
-            map.insert(606, &[1081360]);     // 606 => [1081360]
-            map.insert(607, &[4194340]);     // 607 => [4194340]
-            map.insert(608, &[65794]);     // 608 => [65794]
-            map.insert(609, &[5632]);     // 609 => [5632]
-            map.insert(610, &[65792]);     // 610 => [65792]
-            map.insert(611, &[65793]);     // 611 => [65793]
-            map.insert(612, &[133120]);     // 612 => [133120]
-            map.insert(613, &[133121]);     // 613 => [133121]
-            map.insert(614, &[1064961]);     // 614 => [1064961]
-            map.insert(615, &[1064960]);     // 615 => [1064960]
-            map.insert(616, &[18874432]);     // 616 => [18874432]
-            map.insert(617, &[4194322]);     // 617 => [4194322]
-            map.insert(618, &[4194321]);     // 618 => [4194321]
-            map.insert(619, &[4194320]);     // 619 => [4194320]
-            map.insert(620, &[8389121]);     // 620 => [8389121]
-            map.insert(621, &[8389120]);     // 621 => [8389120]
-            map.insert(622, &[1081376]);     // 622 => [1081376]
-            map.insert(623, &[8389122]);     // 623 => [8389122]
-            map.insert(624, &[16916480]);     // 624 => [16916480]
-            map.insert(625, &[4194314]);     // 625 => [4194314]
-            map.insert(626, &[4194313]);     // 626 => [4194313]
-            map.insert(627, &[4194312]);     // 627 => [4194312]
-            map.insert(628, &[133136]);     // 628 => [133136]
-            map.insert(629, &[16781320]);     // 629 => [16781320]
-            map.insert(630, &[557568]);     // 630 => [557568]
-            map.insert(631, &[4194316]);     // 631 => [4194316]
-            map.insert(632, &[4194307]);     // 632 => [4194307]
-            map.insert(633, &[4194306]);     // 633 => [4194306]
-            map.insert(634, &[4194305]);     // 634 => [4194305]
-            map.insert(635, &[4194304]);     // 635 => [4194304]
-            map.insert(636, &[16781313]);     // 636 => [16781313]
-            map.insert(637, &[16781312]);     // 637 => [16781312]
-            map.insert(638, &[4194309]);     // 638 => [4194309]
-            map.insert(639, &[4194308]);     // 639 => [4194308]
-            map.insert(640, &[1280]);     // 640 => [1280]
-            map.insert(641, &[1281]);     // 641 => [1281]
-            map.insert(642, &[1282]);     // 642 => [1282]
-            map.insert(643, &[524300]);     // 643 => [524300]
-            map.insert(644, &[1284]);     // 644 => [1284]
-            map.insert(645, &[524298]);     // 645 => [524298]
-            map.insert(646, &[524297]);     // 646 => [524297]
-            map.insert(647, &[524296]);     // 647 => [524296]
-            map.insert(648, &[2099202]);     // 648 => [2099202]
-            map.insert(649, &[524294]);     // 649 => [524294]
-            map.insert(650, &[2099200]);     // 650 => [2099200]
-            map.insert(651, &[524292]);     // 651 => [524292]
-            map.insert(652, &[524291]);     // 652 => [524291]
-            map.insert(653, &[524290]);     // 653 => [524290]
-            map.insert(654, &[524289]);     // 654 => [524289]
-            map.insert(655, &[524288]);     // 655 => [524288]
-            map.insert(656, &[8421380]);     // 656 => [8421380]
-            map.insert(657, &[18688]);     // 657 => [18688]
-            map.insert(658, &[4202528]);     // 658 => [4202528]
-            map.insert(659, &[4456512]);     // 659 => [4456512]
-            map.insert(660, &[8421376]);     // 660 => [8421376]
-            map.insert(661, &[8421377]);     // 661 => [8421377]
-            map.insert(662, &[8421378]);     // 662 => [8421378]
-            map.insert(663, &[524312]);     // 663 => [524312]
-            map.insert(664, &[37888]);     // 664 => [37888]
-            map.insert(665, &[16908320]);     // 665 => [16908320]
-            map.insert(666, &[2099216]);     // 666 => [2099216]
-            map.insert(667, &[524308]);     // 667 => [524308]
-            map.insert(668, &[8421384]);     // 668 => [8421384]
-            map.insert(669, &[524306]);     // 669 => [524306]
-            map.insert(670, &[524305]);     // 670 => [524305]
-            map.insert(671, &[524304]);     // 671 => [524304]
-            map.insert(672, &[6144]);     // 672 => [6144]
-            map.insert(673, &[6145]);     // 673 => [6145]
-            map.insert(674, &[65664]);     // 674 => [65664]
-            map.insert(675, &[65665]);     // 675 => [65665]
-            map.insert(676, &[6148]);     // 676 => [6148]
-            map.insert(677, &[16810112]);     // 677 => [16810112]
-            map.insert(678, &[65668]);     // 678 => [65668]
-            map.insert(679, &[524328]);     // 679 => [524328]
-            map.insert(680, &[4456452]);     // 680 => [4456452]
-            map.insert(681, &[16908304]);     // 681 => [16908304]
-            map.insert(682, &[65672]);     // 682 => [65672]
-            map.insert(683, &[524324]);     // 683 => [524324]
-            map.insert(684, &[4456448]);     // 684 => [4456448]
-            map.insert(685, &[4456449]);     // 685 => [4456449]
-            map.insert(686, &[524321]);     // 686 => [524321]
-            map.insert(687, &[524320]);     // 687 => [524320]
-            map.insert(688, &[524352]);     // 688 => [524352]
-            map.insert(689, &[524353]);     // 689 => [524353]
-            map.insert(690, &[4202496]);     // 690 => [4202496]
-            map.insert(691, &[4202497]);     // 691 => [4202497]
-            map.insert(692, &[524356]);     // 692 => [524356]
-            map.insert(693, &[1049090]);     // 693 => [1049090]
-            map.insert(694, &[1049089]);     // 694 => [1049089]
-            map.insert(695, &[1049088]);     // 695 => [1049088]
-            map.insert(696, &[16908289]);     // 696 => [16908289]
-            map.insert(697, &[16908288]);     // 697 => [16908288]
-            map.insert(698, &[4202504]);     // 698 => [4202504]
-            map.insert(699, &[16908290]);     // 699 => [16908290]
-            map.insert(700, &[8404993]);     // 700 => [8404993]
-            map.insert(701, &[8404992]);     // 701 => [8404992]
-            map.insert(702, &[229376]);     // 702 => [229376]
-            map.insert(703, &[8404994]);     // 703 => [8404994]
-            map.insert(704, &[2561]);     // 704 => [2561]
-            map.insert(705, &[2560]);     // 705 => [2560]
-            map.insert(706, &[65568]);     // 706 => [65568]
-            map.insert(707, &[65569]);     // 707 => [65569]
-            map.insert(708, &[16793608]);     // 708 => [16793608]
-            map.insert(709, &[2564]);     // 709 => [2564]
-            map.insert(710, &[65572]);     // 710 => [65572]
-            map.insert(711, &[524424]);     // 711 => [524424]
-            map.insert(712, &[8519680]);     // 712 => [8519680]
-            map.insert(713, &[9216]);     // 713 => [9216]
-            map.insert(714, &[8519682]);     // 714 => [8519682]
-            map.insert(715, &[9218]);     // 715 => [9218]
-            map.insert(716, &[16793600]);     // 716 => [16793600]
-            map.insert(717, &[16793601]);     // 717 => [16793601]
-            map.insert(718, &[524417]);     // 718 => [524417]
-            map.insert(719, &[524416]);     // 719 => [524416]
-            map.insert(720, &[17920]);     // 720 => [17920]
-            map.insert(721, &[2576]);     // 721 => [2576]
-            map.insert(722, &[1052676]);     // 722 => [1052676]
-            map.insert(723, &[263172]);     // 723 => [263172]
-            map.insert(724, &[1052674]);     // 724 => [1052674]
-            map.insert(725, &[263170]);     // 725 => [263170]
-            map.insert(726, &[1052672]);     // 726 => [1052672]
-            map.insert(727, &[263168]);     // 727 => [263168]
-            map.insert(728, &[8519696]);     // 728 => [8519696]
-            map.insert(729, &[65604]);     // 729 => [65604]
-            map.insert(730, &[16810048]);     // 730 => [16810048]
-            map.insert(731, &[4194592]);     // 731 => [4194592]
-            map.insert(732, &[65601]);     // 732 => [65601]
-            map.insert(733, &[65600]);     // 733 => [65600]
-            map.insert(734, &[1052680]);     // 734 => [1052680]
-            map.insert(735, &[65602]);     // 735 => [65602]
-            map.insert(736, &[65538]);     // 736 => [65538]
-            map.insert(737, &[65539]);     // 737 => [65539]
-            map.insert(738, &[65536]);     // 738 => [65536]
-            map.insert(739, &[65537]);     // 739 => [65537]
-            map.insert(740, &[16809985]);     // 740 => [16809985]
-            map.insert(741, &[16809984]);     // 741 => [16809984]
-            map.insert(742, &[65540]);     // 742 => [65540]
-            map.insert(743, &[65541]);     // 743 => [65541]
-            map.insert(744, &[65546]);     // 744 => [65546]
-            map.insert(745, &[9248]);     // 745 => [9248]
-            map.insert(746, &[65544]);     // 746 => [65544]
-            map.insert(747, &[65545]);     // 747 => [65545]
-            map.insert(748, &[3145744]);     // 748 => [3145744]
-            map.insert(749, &[16809992]);     // 749 => [16809992]
-            map.insert(750, &[65548]);     // 750 => [65548]
-            map.insert(751, &[524448]);     // 751 => [524448]
-            map.insert(752, &[65554]);     // 752 => [65554]
-            map.insert(753, &[43008]);     // 753 => [43008]
-            map.insert(754, &[65552]);     // 754 => [65552]
-            map.insert(755, &[65553]);     // 755 => [65553]
-            map.insert(756, &[3145736]);     // 756 => [3145736]
-            map.insert(757, &[16810000]);     // 757 => [16810000]
-            map.insert(758, &[65556]);     // 758 => [65556]
-            map.insert(759, &[263200]);     // 759 => [263200]
-            map.insert(760, &[3145732]);     // 760 => [3145732]
-            map.insert(761, &[4194562]);     // 761 => [4194562]
-            map.insert(762, &[4194561]);     // 762 => [4194561]
-            map.insert(763, &[4194560]);     // 763 => [4194560]
-            map.insert(764, &[3145728]);     // 764 => [3145728]
-            map.insert(765, &[3145729]);     // 765 => [3145729]
-            map.insert(766, &[3145730]);     // 766 => [3145730]
-            map.insert(767, &[4194564]);     // 767 => [4194564]
-            map.insert(768, &[1536]);     // 768 => [1536]
-            map.insert(769, &[1537]);     // 769 => [1537]
-            map.insert(770, &[1538]);     // 770 => [1538]
-            map.insert(771, &[16777312]);     // 771 => [16777312]
-            map.insert(772, &[1540]);     // 772 => [1540]
-            map.insert(773, &[137216]);     // 773 => [137216]
-            map.insert(774, &[264208]);     // 774 => [264208]
-            map.insert(775, &[196736]);     // 775 => [196736]
-            map.insert(776, &[10240]);     // 776 => [10240]
-            map.insert(777, &[10241]);     // 777 => [10241]
-            map.insert(778, &[10242]);     // 778 => [10242]
-            map.insert(779, &[98320]);     // 779 => [98320]
-            map.insert(780, &[16777232]);     // 780 => [16777232]
-            map.insert(781, &[16777233]);     // 781 => [16777233]
-            map.insert(782, &[16777234]);     // 782 => [16777234]
-            map.insert(783, &[6291520]);     // 783 => [6291520]
-            map.insert(784, &[16777228]);     // 784 => [16777228]
-            map.insert(785, &[18944]);     // 785 => [18944]
-            map.insert(786, &[264196]);     // 786 => [264196]
-            map.insert(787, &[98312]);     // 787 => [98312]
-            map.insert(788, &[16777224]);     // 788 => [16777224]
-            map.insert(789, &[16777225]);     // 789 => [16777225]
-            map.insert(790, &[264192]);     // 790 => [264192]
-            map.insert(791, &[264193]);     // 791 => [264193]
-            map.insert(792, &[16777220]);     // 792 => [16777220]
-            map.insert(793, &[16777221]);     // 793 => [16777221]
-            map.insert(794, &[4198400]);     // 794 => [4198400]
-            map.insert(795, &[98304]);     // 795 => [98304]
-            map.insert(796, &[16777216]);     // 796 => [16777216]
-            map.insert(797, &[16777217]);     // 797 => [16777217]
-            map.insert(798, &[16777218]);     // 798 => [16777218]
-            map.insert(799, &[16777219]);     // 799 => [16777219]
-            map.insert(800, &[6291472]);     // 800 => [6291472]
-            map.insert(801, &[16777282]);     // 801 => [16777282]
-            map.insert(802, &[16777281]);     // 802 => [16777281]
-            map.insert(803, &[16777280]);     // 803 => [16777280]
-            map.insert(804, &[98368]);     // 804 => [98368]
-            map.insert(805, &[8388744]);     // 805 => [8388744]
-            map.insert(806, &[10486272]);     // 806 => [10486272]
-            map.insert(807, &[16777284]);     // 807 => [16777284]
-            map.insert(808, &[655362]);     // 808 => [655362]
-            map.insert(809, &[8388740]);     // 809 => [8388740]
-            map.insert(810, &[655360]);     // 810 => [655360]
-            map.insert(811, &[655361]);     // 811 => [655361]
-            map.insert(812, &[8388737]);     // 812 => [8388737]
-            map.insert(813, &[8388736]);     // 813 => [8388736]
-            map.insert(814, &[655364]);     // 814 => [655364]
-            map.insert(815, &[8388738]);     // 815 => [8388738]
-            map.insert(816, &[6291456]);     // 816 => [6291456]
-            map.insert(817, &[6291457]);     // 817 => [6291457]
-            map.insert(818, &[81920]);     // 818 => [81920]
-            map.insert(819, &[81921]);     // 819 => [81921]
-            map.insert(820, &[6291460]);     // 820 => [6291460]
-            map.insert(821, &[1048834]);     // 821 => [1048834]
-            map.insert(822, &[1048833]);     // 822 => [1048833]
-            map.insert(823, &[1048832]);     // 823 => [1048832]
-            map.insert(824, &[16777252]);     // 824 => [16777252]
-            map.insert(825, &[790528]);     // 825 => [790528]
-            map.insert(826, &[81928]);     // 826 => [81928]
-            map.insert(827, &[98336]);     // 827 => [98336]
-            map.insert(828, &[16777248]);     // 828 => [16777248]
-            map.insert(829, &[16777249]);     // 829 => [16777249]
-            map.insert(830, &[16777250]);     // 830 => [16777250]
-            map.insert(831, &[1048840]);     // 831 => [1048840]
-            map.insert(832, &[2305]);     // 832 => [2305]
-            map.insert(833, &[2304]);     // 833 => [2304]
-            map.insert(834, &[8388688]);     // 834 => [8388688]
-            map.insert(835, &[2306]);     // 835 => [2306]
-            map.insert(836, &[4194880]);     // 836 => [4194880]
-            map.insert(837, &[196610]);     // 837 => [196610]
-            map.insert(838, &[196609]);     // 838 => [196609]
-            map.insert(839, &[196608]);     // 839 => [196608]
-            map.insert(840, &[10368]);     // 840 => [10368]
-            map.insert(841, &[2098178]);     // 841 => [2098178]
-            map.insert(842, &[2098177]);     // 842 => [2098177]
-            map.insert(843, &[2098176]);     // 843 => [2098176]
-            map.insert(844, &[8388641]);     // 844 => [8388641]
-            map.insert(845, &[8388640]);     // 845 => [8388640]
-            map.insert(846, &[1081856]);     // 846 => [1081856]
-            map.insert(847, &[8388642]);     // 847 => [8388642]
-            map.insert(848, &[8388674]);     // 848 => [8388674]
-            map.insert(849, &[2320]);     // 849 => [2320]
-            map.insert(850, &[8388672]);     // 850 => [8388672]
-            map.insert(851, &[8388673]);     // 851 => [8388673]
-            map.insert(852, &[16777352]);     // 852 => [16777352]
-            map.insert(853, &[16785664]);     // 853 => [16785664]
-            map.insert(854, &[8388676]);     // 854 => [8388676]
-            map.insert(855, &[540680]);     // 855 => [540680]
-            map.insert(856, &[16777348]);     // 856 => [16777348]
-            map.insert(857, &[38912]);     // 857 => [38912]
-            map.insert(858, &[8388680]);     // 858 => [8388680]
-            map.insert(859, &[540676]);     // 859 => [540676]
-            map.insert(860, &[16777344]);     // 860 => [16777344]
-            map.insert(861, &[16777345]);     // 861 => [16777345]
-            map.insert(862, &[540673]);     // 862 => [540673]
-            map.insert(863, &[540672]);     // 863 => [540672]
-            map.insert(864, &[1310720]);     // 864 => [1310720]
-            map.insert(865, &[5120]);     // 865 => [5120]
-            map.insert(866, &[1310722]);     // 866 => [1310722]
-            map.insert(867, &[5122]);     // 867 => [5122]
-            map.insert(868, &[8388617]);     // 868 => [8388617]
-            map.insert(869, &[8388616]);     // 869 => [8388616]
-            map.insert(870, &[557072]);     // 870 => [557072]
-            map.insert(871, &[8388618]);     // 871 => [8388618]
-            map.insert(872, &[8388613]);     // 872 => [8388613]
-            map.insert(873, &[8388612]);     // 873 => [8388612]
-            map.insert(874, &[655488]);     // 874 => [655488]
-            map.insert(875, &[8388614]);     // 875 => [8388614]
-            map.insert(876, &[8388609]);     // 876 => [8388609]
-            map.insert(877, &[8388608]);     // 877 => [8388608]
-            map.insert(878, &[8388611]);     // 878 => [8388611]
-            map.insert(879, &[8388610]);     // 879 => [8388610]
-            map.insert(880, &[1310736]);     // 880 => [1310736]
-            map.insert(881, &[5136]);     // 881 => [5136]
-            map.insert(882, &[557060]);     // 882 => [557060]
-            map.insert(883, &[4194824]);     // 883 => [4194824]
-            map.insert(884, &[557058]);     // 884 => [557058]
-            map.insert(885, &[8388632]);     // 885 => [8388632]
-            map.insert(886, &[557056]);     // 886 => [557056]
-            map.insert(887, &[557057]);     // 887 => [557057]
-            map.insert(888, &[196672]);     // 888 => [196672]
-            map.insert(889, &[4194818]);     // 889 => [4194818]
-            map.insert(890, &[4194817]);     // 890 => [4194817]
-            map.insert(891, &[4194816]);     // 891 => [4194816]
-            map.insert(892, &[8388625]);     // 892 => [8388625]
-            map.insert(893, &[8388624]);     // 893 => [8388624]
-            map.insert(894, &[1056768]);     // 894 => [1056768]
-            map.insert(895, &[1056769]);     // 895 => [1056769]
-            map.insert(896, &[2177]);     // 896 => [2177]
-            map.insert(897, &[2176]);     // 897 => [2176]
-            map.insert(898, &[4227072]);     // 898 => [4227072]
-            map.insert(899, &[69632]);     // 899 => [69632]
-            map.insert(900, &[8396832]);     // 900 => [8396832]
-            map.insert(901, &[2180]);     // 901 => [2180]
-            map.insert(902, &[4227076]);     // 902 => [4227076]
-            map.insert(903, &[69636]);     // 903 => [69636]
-            map.insert(904, &[1048640]);     // 904 => [1048640]
-            map.insert(905, &[1048641]);     // 905 => [1048641]
-            map.insert(906, &[1048642]);     // 906 => [1048642]
-            map.insert(907, &[524804]);     // 907 => [524804]
-            map.insert(908, &[1048644]);     // 908 => [1048644]
-            map.insert(909, &[524802]);     // 909 => [524802]
-            map.insert(910, &[524801]);     // 910 => [524801]
-            map.insert(911, &[524800]);     // 911 => [524800]
-            map.insert(912, &[17536]);     // 912 => [17536]
-            map.insert(913, &[2192]);     // 913 => [2192]
-            map.insert(914, &[4227088]);     // 914 => [4227088]
-            map.insert(915, &[1048612]);     // 915 => [1048612]
-            map.insert(916, &[16777480]);     // 916 => [16777480]
-            map.insert(917, &[1048610]);     // 917 => [1048610]
-            map.insert(918, &[1048609]);     // 918 => [1048609]
-            map.insert(919, &[1048608]);     // 919 => [1048608]
-            map.insert(920, &[16777476]);     // 920 => [16777476]
-            map.insert(921, &[13107200]);     // 921 => [13107200]
-            map.insert(922, &[8650784]);     // 922 => [8650784]
-            map.insert(923, &[8396864]);     // 923 => [8396864]
-            map.insert(924, &[16777472]);     // 924 => [16777472]
-            map.insert(925, &[16777473]);     // 925 => [16777473]
-            map.insert(926, &[16777474]);     // 926 => [16777474]
-            map.insert(927, &[1048616]);     // 927 => [1048616]
-            map.insert(928, &[8396804]);     // 928 => [8396804]
-            map.insert(929, &[132100]);     // 929 => [132100]
-            map.insert(930, &[4227104]);     // 930 => [4227104]
-            map.insert(931, &[1048596]);     // 931 => [1048596]
-            map.insert(932, &[8396800]);     // 932 => [8396800]
-            map.insert(933, &[132096]);     // 933 => [132096]
-            map.insert(934, &[1048593]);     // 934 => [1048593]
-            map.insert(935, &[1048592]);     // 935 => [1048592]
-            map.insert(936, &[2162689]);     // 936 => [2162689]
-            map.insert(937, &[2162688]);     // 937 => [2162688]
-            map.insert(938, &[4210689]);     // 938 => [4210689]
-            map.insert(939, &[4210688]);     // 939 => [4210688]
-            map.insert(940, &[8396808]);     // 940 => [8396808]
-            map.insert(941, &[2162692]);     // 941 => [2162692]
-            map.insert(942, &[528512]);     // 942 => [528512]
-            map.insert(943, &[4210692]);     // 943 => [4210692]
-            map.insert(944, &[524864]);     // 944 => [524864]
-            map.insert(945, &[1048582]);     // 945 => [1048582]
-            map.insert(946, &[1048581]);     // 946 => [1048581]
-            map.insert(947, &[1048580]);     // 947 => [1048580]
-            map.insert(948, &[1048579]);     // 948 => [1048579]
-            map.insert(949, &[1048578]);     // 949 => [1048578]
-            map.insert(950, &[1048577]);     // 950 => [1048577]
-            map.insert(951, &[1048576]);     // 951 => [1048576]
-            map.insert(952, &[8650754]);     // 952 => [8650754]
-            map.insert(953, &[2162704]);     // 953 => [2162704]
-            map.insert(954, &[8650752]);     // 954 => [8650752]
-            map.insert(955, &[8650753]);     // 955 => [8650753]
-            map.insert(956, &[16777504]);     // 956 => [16777504]
-            map.insert(957, &[1048586]);     // 957 => [1048586]
-            map.insert(958, &[1048585]);     // 958 => [1048585]
-            map.insert(959, &[1048584]);     // 959 => [1048584]
-            map.insert(960, &[2049]);     // 960 => [2049]
-            map.insert(961, &[2048]);     // 961 => [2048]
-            map.insert(962, &[2051]);     // 962 => [2051]
-            map.insert(963, &[2050]);     // 963 => [2050]
-            map.insert(964, &[2621440]);     // 964 => [2621440]
-            map.insert(965, &[2052]);     // 965 => [2052]
-            map.insert(966, &[2621442]);     // 966 => [2621442]
-            map.insert(967, &[2054]);     // 967 => [2054]
-            map.insert(968, &[2057]);     // 968 => [2057]
-            map.insert(969, &[2056]);     // 969 => [2056]
-            map.insert(970, &[17039361]);     // 970 => [17039361]
-            map.insert(971, &[17039360]);     // 971 => [17039360]
-            map.insert(972, &[2621448]);     // 972 => [2621448]
-            map.insert(973, &[2060]);     // 973 => [2060]
-            map.insert(974, &[4325392]);     // 974 => [4325392]
-            map.insert(975, &[17039364]);     // 975 => [17039364]
-            map.insert(976, &[17408]);     // 976 => [17408]
-            map.insert(977, &[2064]);     // 977 => [2064]
-            map.insert(978, &[17410]);     // 978 => [17410]
-            map.insert(979, &[2066]);     // 979 => [2066]
-            map.insert(980, &[16785409]);     // 980 => [16785409]
-            map.insert(981, &[16785408]);     // 981 => [16785408]
-            map.insert(982, &[4325384]);     // 982 => [4325384]
-            map.insert(983, &[16785410]);     // 983 => [16785410]
-            map.insert(984, &[17416]);     // 984 => [17416]
-            map.insert(985, &[2072]);     // 985 => [2072]
-            map.insert(986, &[4325380]);     // 986 => [4325380]
-            map.insert(987, &[17039376]);     // 987 => [17039376]
-            map.insert(988, &[4325378]);     // 988 => [4325378]
-            map.insert(989, &[16785416]);     // 989 => [16785416]
-            map.insert(990, &[4325376]);     // 990 => [4325376]
-            map.insert(991, &[4325377]);     // 991 => [4325377]
-            map.insert(992, &[2081]);     // 992 => [2081]
-            map.insert(993, &[2080]);     // 993 => [2080]
-            map.insert(994, &[66048]);     // 994 => [66048]
-            map.insert(995, &[66049]);     // 995 => [66049]
-            map.insert(996, &[2621472]);     // 996 => [2621472]
-            map.insert(997, &[2084]);     // 997 => [2084]
-            map.insert(998, &[66052]);     // 998 => [66052]
-            map.insert(999, &[1048720]);     // 999 => [1048720]
-            map.insert(1000, &[51200]);     // 1000 => [51200]
-            map.insert(1001, &[8388868]);     // 1001 => [8388868]
-            map.insert(1002, &[528388]);     // 1002 => [528388]
-            map.insert(1003, &[17039392]);     // 1003 => [17039392]
-            map.insert(1004, &[8388865]);     // 1004 => [8388865]
-            map.insert(1005, &[8388864]);     // 1005 => [8388864]
-            map.insert(1006, &[528384]);     // 1006 => [528384]
-            map.insert(1007, &[528385]);     // 1007 => [528385]
-            map.insert(1008, &[17440]);     // 1008 => [17440]
-            map.insert(1009, &[33800]);     // 1009 => [33800]
-            map.insert(1010, &[66064]);     // 1010 => [66064]
-            map.insert(1011, &[1048708]);     // 1011 => [1048708]
-            map.insert(1012, &[17039424]);     // 1012 => [17039424]
-            map.insert(1013, &[1048706]);     // 1013 => [1048706]
-            map.insert(1014, &[1048705]);     // 1014 => [1048705]
-            map.insert(1015, &[1048704]);     // 1015 => [1048704]
-            map.insert(1016, &[33793]);     // 1016 => [33793]
-            map.insert(1017, &[33792]);     // 1017 => [33792]
-            map.insert(1018, &[2116]);     // 1018 => [2116]
-            map.insert(1019, &[33794]);     // 1019 => [33794]
-            map.insert(1020, &[2114]);     // 1020 => [2114]
-            map.insert(1021, &[33796]);     // 1021 => [33796]
-            map.insert(1022, &[2112]);     // 1022 => [2112]
-            map.insert(1023, &[2113]);     // 1023 => [2113]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 16693313 ],
+            &[ 8192066 ],
+            &[ 27131972 ],
+            &[ 32022600 ],
+            &[ 22331472 ],
+            &[ 13865056 ],
+            &[ 15071360 ],
+            &[ 2091264 ],
+            &[ 28498432 ],
+            &[ 31001600 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 25)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(1024, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(7, &[7]);     // 7 => [7]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(11, &[11]);     // 11 => [11]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(15, &[112]);     // 15 => [112]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(19, &[19]);     // 19 => [19]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(23, &[104]);     // 23 => [104]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(27, &[100]);     // 27 => [100]
+        map.insert(28, &[270338]);     // 28 => [270338]
+        map.insert(29, &[98]);     // 29 => [98]
+        map.insert(30, &[270336]);     // 30 => [270336]
+        map.insert(31, &[96]);     // 31 => [96]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(35, &[35]);     // 35 => [35]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(39, &[88]);     // 39 => [88]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(41, &[49152]);     // 41 => [49152]
+        map.insert(42, &[2101248]);     // 42 => [2101248]
+        map.insert(43, &[2101249]);     // 43 => [2101249]
+        map.insert(44, &[44]);     // 44 => [44]
+        map.insert(45, &[82]);     // 45 => [82]
+        map.insert(46, &[81]);     // 46 => [81]
+        map.insert(47, &[80]);     // 47 => [80]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(51, &[76]);     // 51 => [76]
+        map.insert(52, &[52]);     // 52 => [52]
+        map.insert(53, &[74]);     // 53 => [74]
+        map.insert(54, &[73]);     // 54 => [73]
+        map.insert(55, &[72]);     // 55 => [72]
+        map.insert(56, &[56]);     // 56 => [56]
+        map.insert(57, &[70]);     // 57 => [70]
+        map.insert(58, &[69]);     // 58 => [69]
+        map.insert(59, &[68]);     // 59 => [68]
+        map.insert(60, &[67]);     // 60 => [67]
+        map.insert(61, &[66]);     // 61 => [66]
+        map.insert(62, &[65]);     // 62 => [65]
+        map.insert(63, &[64]);     // 63 => [64]
+        map.insert(64, &[128]);     // 64 => [128]
+        map.insert(65, &[129]);     // 65 => [129]
+        map.insert(66, &[130]);     // 66 => [130]
+        map.insert(67, &[131]);     // 67 => [131]
+        map.insert(68, &[132]);     // 68 => [132]
+        map.insert(69, &[133]);     // 69 => [133]
+        map.insert(70, &[134]);     // 70 => [134]
+        map.insert(71, &[262416]);     // 71 => [262416]
+        map.insert(72, &[136]);     // 72 => [136]
+        map.insert(73, &[8448]);     // 73 => [8448]
+        map.insert(74, &[2097665]);     // 74 => [2097665]
+        map.insert(75, &[2097664]);     // 75 => [2097664]
+        map.insert(76, &[140]);     // 76 => [140]
+        map.insert(77, &[8452]);     // 77 => [8452]
+        map.insert(78, &[25165888]);     // 78 => [25165888]
+        map.insert(79, &[2097668]);     // 79 => [2097668]
+        map.insert(80, &[144]);     // 80 => [144]
+        map.insert(81, &[145]);     // 81 => [145]
+        map.insert(82, &[146]);     // 82 => [146]
+        map.insert(83, &[262404]);     // 83 => [262404]
+        map.insert(84, &[148]);     // 84 => [148]
+        map.insert(85, &[262402]);     // 85 => [262402]
+        map.insert(86, &[262401]);     // 86 => [262401]
+        map.insert(87, &[262400]);     // 87 => [262400]
+        map.insert(88, &[163844]);     // 88 => [163844]
+        map.insert(89, &[8464]);     // 89 => [8464]
+        map.insert(90, &[9437440]);     // 90 => [9437440]
+        map.insert(91, &[2097680]);     // 91 => [2097680]
+        map.insert(92, &[163840]);     // 92 => [163840]
+        map.insert(93, &[163841]);     // 93 => [163841]
+        map.insert(94, &[163842]);     // 94 => [163842]
+        map.insert(95, &[262408]);     // 95 => [262408]
+        map.insert(96, &[160]);     // 96 => [160]
+        map.insert(97, &[4608]);     // 97 => [4608]
+        map.insert(98, &[162]);     // 98 => [162]
+        map.insert(99, &[4610]);     // 99 => [4610]
+        map.insert(100, &[164]);     // 100 => [164]
+        map.insert(101, &[4612]);     // 101 => [4612]
+        map.insert(102, &[1050640]);     // 102 => [1050640]
+        map.insert(103, &[20972032]);     // 103 => [20972032]
+        map.insert(104, &[168]);     // 104 => [168]
+        map.insert(105, &[589828]);     // 105 => [589828]
+        map.insert(106, &[2101376]);     // 106 => [2101376]
+        map.insert(107, &[4195344]);     // 107 => [4195344]
+        map.insert(108, &[589825]);     // 108 => [589825]
+        map.insert(109, &[589824]);     // 109 => [589824]
+        map.insert(110, &[2228480]);     // 110 => [2228480]
+        map.insert(111, &[589826]);     // 111 => [589826]
+        map.insert(112, &[25165825]);     // 112 => [25165825]
+        map.insert(113, &[25165824]);     // 113 => [25165824]
+        map.insert(114, &[1050628]);     // 114 => [1050628]
+        map.insert(115, &[25165826]);     // 115 => [25165826]
+        map.insert(116, &[147457]);     // 116 => [147457]
+        map.insert(117, &[147456]);     // 117 => [147456]
+        map.insert(118, &[1050624]);     // 118 => [1050624]
+        map.insert(119, &[1050625]);     // 119 => [1050625]
+        map.insert(120, &[28672]);     // 120 => [28672]
+        map.insert(121, &[4195330]);     // 121 => [4195330]
+        map.insert(122, &[4195329]);     // 122 => [4195329]
+        map.insert(123, &[4195328]);     // 123 => [4195328]
+        map.insert(124, &[163872]);     // 124 => [163872]
+        map.insert(125, &[194]);     // 125 => [194]
+        map.insert(126, &[193]);     // 126 => [193]
+        map.insert(127, &[192]);     // 127 => [192]
+        map.insert(128, &[256]);     // 128 => [256]
+        map.insert(129, &[257]);     // 129 => [257]
+        map.insert(130, &[258]);     // 130 => [258]
+        map.insert(131, &[259]);     // 131 => [259]
+        map.insert(132, &[260]);     // 132 => [260]
+        map.insert(133, &[261]);     // 133 => [261]
+        map.insert(134, &[262]);     // 134 => [262]
+        map.insert(135, &[525320]);     // 135 => [525320]
+        map.insert(136, &[264]);     // 136 => [264]
+        map.insert(137, &[8320]);     // 137 => [8320]
+        map.insert(138, &[266]);     // 138 => [266]
+        map.insert(139, &[8322]);     // 139 => [8322]
+        map.insert(140, &[268]);     // 140 => [268]
+        map.insert(141, &[525314]);     // 141 => [525314]
+        map.insert(142, &[525313]);     // 142 => [525313]
+        map.insert(143, &[525312]);     // 143 => [525312]
+        map.insert(144, &[272]);     // 144 => [272]
+        map.insert(145, &[273]);     // 145 => [273]
+        map.insert(146, &[274]);     // 146 => [274]
+        map.insert(147, &[262276]);     // 147 => [262276]
+        map.insert(148, &[276]);     // 148 => [276]
+        map.insert(149, &[262274]);     // 149 => [262274]
+        map.insert(150, &[262273]);     // 150 => [262273]
+        map.insert(151, &[262272]);     // 151 => [262272]
+        map.insert(152, &[36864]);     // 152 => [36864]
+        map.insert(153, &[4259840]);     // 153 => [4259840]
+        map.insert(154, &[2113537]);     // 154 => [2113537]
+        map.insert(155, &[2113536]);     // 155 => [2113536]
+        map.insert(156, &[36868]);     // 156 => [36868]
+        map.insert(157, &[4259844]);     // 157 => [4259844]
+        map.insert(158, &[270592]);     // 158 => [270592]
+        map.insert(159, &[2113540]);     // 159 => [2113540]
+        map.insert(160, &[288]);     // 160 => [288]
+        map.insert(161, &[289]);     // 161 => [289]
+        map.insert(162, &[290]);     // 162 => [290]
+        map.insert(163, &[17825800]);     // 163 => [17825800]
+        map.insert(164, &[131585]);     // 164 => [131585]
+        map.insert(165, &[131584]);     // 165 => [131584]
+        map.insert(166, &[4259904]);     // 166 => [4259904]
+        map.insert(167, &[131586]);     // 167 => [131586]
+        map.insert(168, &[8390660]);     // 168 => [8390660]
+        map.insert(169, &[17825794]);     // 169 => [17825794]
+        map.insert(170, &[17825793]);     // 170 => [17825793]
+        map.insert(171, &[17825792]);     // 171 => [17825792]
+        map.insert(172, &[8390656]);     // 172 => [8390656]
+        map.insert(173, &[8390657]);     // 173 => [8390657]
+        map.insert(174, &[8390658]);     // 174 => [8390658]
+        map.insert(175, &[17825796]);     // 175 => [17825796]
+        map.insert(176, &[20481]);     // 176 => [20481]
+        map.insert(177, &[20480]);     // 177 => [20480]
+        map.insert(178, &[2129920]);     // 178 => [2129920]
+        map.insert(179, &[2129921]);     // 179 => [2129921]
+        map.insert(180, &[4718720]);     // 180 => [4718720]
+        map.insert(181, &[20484]);     // 181 => [20484]
+        map.insert(182, &[2129924]);     // 182 => [2129924]
+        map.insert(183, &[328]);     // 183 => [328]
+        map.insert(184, &[36896]);     // 184 => [36896]
+        map.insert(185, &[20488]);     // 185 => [20488]
+        map.insert(186, &[2129928]);     // 186 => [2129928]
+        map.insert(187, &[324]);     // 187 => [324]
+        map.insert(188, &[8390672]);     // 188 => [8390672]
+        map.insert(189, &[322]);     // 189 => [322]
+        map.insert(190, &[321]);     // 190 => [321]
+        map.insert(191, &[320]);     // 191 => [320]
+        map.insert(192, &[384]);     // 192 => [384]
+        map.insert(193, &[8200]);     // 193 => [8200]
+        map.insert(194, &[386]);     // 194 => [386]
+        map.insert(195, &[8202]);     // 195 => [8202]
+        map.insert(196, &[135168]);     // 196 => [135168]
+        map.insert(197, &[135169]);     // 197 => [135169]
+        map.insert(198, &[262161]);     // 198 => [262161]
+        map.insert(199, &[262160]);     // 199 => [262160]
+        map.insert(200, &[8193]);     // 200 => [8193]
+        map.insert(201, &[8192]);     // 201 => [8192]
+        map.insert(202, &[8195]);     // 202 => [8195]
+        map.insert(203, &[8194]);     // 203 => [8194]
+        map.insert(204, &[8197]);     // 204 => [8197]
+        map.insert(205, &[8196]);     // 205 => [8196]
+        map.insert(206, &[2228256]);     // 206 => [2228256]
+        map.insert(207, &[8198]);     // 207 => [8198]
+        map.insert(208, &[16896]);     // 208 => [16896]
+        map.insert(209, &[16897]);     // 209 => [16897]
+        map.insert(210, &[262149]);     // 210 => [262149]
+        map.insert(211, &[262148]);     // 211 => [262148]
+        map.insert(212, &[262147]);     // 212 => [262147]
+        map.insert(213, &[262146]);     // 213 => [262146]
+        map.insert(214, &[262145]);     // 214 => [262145]
+        map.insert(215, &[262144]);     // 215 => [262144]
+        map.insert(216, &[8209]);     // 216 => [8209]
+        map.insert(217, &[8208]);     // 217 => [8208]
+        map.insert(218, &[9437184]);     // 218 => [9437184]
+        map.insert(219, &[9437185]);     // 219 => [9437185]
+        map.insert(220, &[16779265]);     // 220 => [16779265]
+        map.insert(221, &[16779264]);     // 221 => [16779264]
+        map.insert(222, &[262153]);     // 222 => [262153]
+        map.insert(223, &[262152]);     // 223 => [262152]
+        map.insert(224, &[66562]);     // 224 => [66562]
+        map.insert(225, &[8232]);     // 225 => [8232]
+        map.insert(226, &[66560]);     // 226 => [66560]
+        map.insert(227, &[66561]);     // 227 => [66561]
+        map.insert(228, &[4718608]);     // 228 => [4718608]
+        map.insert(229, &[9437248]);     // 229 => [9437248]
+        map.insert(230, &[66564]);     // 230 => [66564]
+        map.insert(231, &[262192]);     // 231 => [262192]
+        map.insert(232, &[262208]);     // 232 => [262208]
+        map.insert(233, &[8224]);     // 233 => [8224]
+        map.insert(234, &[262210]);     // 234 => [262210]
+        map.insert(235, &[8226]);     // 235 => [8226]
+        map.insert(236, &[2228226]);     // 236 => [2228226]
+        map.insert(237, &[8228]);     // 237 => [8228]
+        map.insert(238, &[2228224]);     // 238 => [2228224]
+        map.insert(239, &[2228225]);     // 239 => [2228225]
+        map.insert(240, &[4718596]);     // 240 => [4718596]
+        map.insert(241, &[33288]);     // 241 => [33288]
+        map.insert(242, &[8260]);     // 242 => [8260]
+        map.insert(243, &[262180]);     // 243 => [262180]
+        map.insert(244, &[4718592]);     // 244 => [4718592]
+        map.insert(245, &[4718593]);     // 245 => [4718593]
+        map.insert(246, &[8256]);     // 246 => [8256]
+        map.insert(247, &[262176]);     // 247 => [262176]
+        map.insert(248, &[33281]);     // 248 => [33281]
+        map.insert(249, &[33280]);     // 249 => [33280]
+        map.insert(250, &[9437216]);     // 250 => [9437216]
+        map.insert(251, &[33282]);     // 251 => [33282]
+        map.insert(252, &[4718600]);     // 252 => [4718600]
+        map.insert(253, &[33284]);     // 253 => [33284]
+        map.insert(254, &[8264]);     // 254 => [8264]
+        map.insert(255, &[262184]);     // 255 => [262184]
+        map.insert(256, &[512]);     // 256 => [512]
+        map.insert(257, &[513]);     // 257 => [513]
+        map.insert(258, &[514]);     // 258 => [514]
+        map.insert(259, &[515]);     // 259 => [515]
+        map.insert(260, &[516]);     // 260 => [516]
+        map.insert(261, &[517]);     // 261 => [517]
+        map.insert(262, &[278529]);     // 262 => [278529]
+        map.insert(263, &[278528]);     // 263 => [278528]
+        map.insert(264, &[520]);     // 264 => [520]
+        map.insert(265, &[521]);     // 265 => [521]
+        map.insert(266, &[2097281]);     // 266 => [2097281]
+        map.insert(267, &[2097280]);     // 267 => [2097280]
+        map.insert(268, &[524]);     // 268 => [524]
+        map.insert(269, &[16795648]);     // 269 => [16795648]
+        map.insert(270, &[294944]);     // 270 => [294944]
+        map.insert(271, &[2097284]);     // 271 => [2097284]
+        map.insert(272, &[528]);     // 272 => [528]
+        map.insert(273, &[529]);     // 273 => [529]
+        map.insert(274, &[530]);     // 274 => [530]
+        map.insert(275, &[17301760]);     // 275 => [17301760]
+        map.insert(276, &[12582914]);     // 276 => [12582914]
+        map.insert(277, &[1114240]);     // 277 => [1114240]
+        map.insert(278, &[12582912]);     // 278 => [12582912]
+        map.insert(279, &[12582913]);     // 279 => [12582913]
+        map.insert(280, &[24577]);     // 280 => [24577]
+        map.insert(281, &[24576]);     // 281 => [24576]
+        map.insert(282, &[131392]);     // 282 => [131392]
+        map.insert(283, &[24578]);     // 283 => [24578]
+        map.insert(284, &[16778240]);     // 284 => [16778240]
+        map.insert(285, &[16778241]);     // 285 => [16778241]
+        map.insert(286, &[16778242]);     // 286 => [16778242]
+        map.insert(287, &[608]);     // 287 => [608]
+        map.insert(288, &[544]);     // 288 => [544]
+        map.insert(289, &[4224]);     // 289 => [4224]
+        map.insert(290, &[67585]);     // 290 => [67585]
+        map.insert(291, &[67584]);     // 291 => [67584]
+        map.insert(292, &[131329]);     // 292 => [131329]
+        map.insert(293, &[131328]);     // 293 => [131328]
+        map.insert(294, &[294920]);     // 294 => [294920]
+        map.insert(295, &[131330]);     // 295 => [131330]
+        map.insert(296, &[552]);     // 296 => [552]
+        map.insert(297, &[4232]);     // 297 => [4232]
+        map.insert(298, &[294916]);     // 298 => [294916]
+        map.insert(299, &[67592]);     // 299 => [67592]
+        map.insert(300, &[294914]);     // 300 => [294914]
+        map.insert(301, &[131336]);     // 301 => [131336]
+        map.insert(302, &[294912]);     // 302 => [294912]
+        map.insert(303, &[294913]);     // 303 => [294913]
+        map.insert(304, &[40960]);     // 304 => [40960]
+        map.insert(305, &[40961]);     // 305 => [40961]
+        map.insert(306, &[40962]);     // 306 => [40962]
+        map.insert(307, &[67600]);     // 307 => [67600]
+        map.insert(308, &[40964]);     // 308 => [40964]
+        map.insert(309, &[131344]);     // 309 => [131344]
+        map.insert(310, &[12582944]);     // 310 => [12582944]
+        map.insert(311, &[584]);     // 311 => [584]
+        map.insert(312, &[1572864]);     // 312 => [1572864]
+        map.insert(313, &[1572865]);     // 313 => [1572865]
+        map.insert(314, &[1572866]);     // 314 => [1572866]
+        map.insert(315, &[580]);     // 315 => [580]
+        map.insert(316, &[1572868]);     // 316 => [1572868]
+        map.insert(317, &[578]);     // 317 => [578]
+        map.insert(318, &[577]);     // 318 => [577]
+        map.insert(319, &[576]);     // 319 => [576]
+        map.insert(320, &[640]);     // 320 => [640]
+        map.insert(321, &[4128]);     // 321 => [4128]
+        map.insert(322, &[2097161]);     // 322 => [2097161]
+        map.insert(323, &[2097160]);     // 323 => [2097160]
+        map.insert(324, &[644]);     // 324 => [644]
+        map.insert(325, &[4132]);     // 325 => [4132]
+        map.insert(326, &[526344]);     // 326 => [526344]
+        map.insert(327, &[2097164]);     // 327 => [2097164]
+        map.insert(328, &[2097155]);     // 328 => [2097155]
+        map.insert(329, &[2097154]);     // 329 => [2097154]
+        map.insert(330, &[2097153]);     // 330 => [2097153]
+        map.insert(331, &[2097152]);     // 331 => [2097152]
+        map.insert(332, &[526338]);     // 332 => [526338]
+        map.insert(333, &[2097158]);     // 333 => [2097158]
+        map.insert(334, &[526336]);     // 334 => [526336]
+        map.insert(335, &[2097156]);     // 335 => [2097156]
+        map.insert(336, &[16640]);     // 336 => [16640]
+        map.insert(337, &[16641]);     // 337 => [16641]
+        map.insert(338, &[16642]);     // 338 => [16642]
+        map.insert(339, &[2097176]);     // 339 => [2097176]
+        map.insert(340, &[1114113]);     // 340 => [1114113]
+        map.insert(341, &[1114112]);     // 341 => [1114112]
+        map.insert(342, &[4168]);     // 342 => [4168]
+        map.insert(343, &[1114114]);     // 343 => [1114114]
+        map.insert(344, &[16648]);     // 344 => [16648]
+        map.insert(345, &[2097170]);     // 345 => [2097170]
+        map.insert(346, &[2097169]);     // 346 => [2097169]
+        map.insert(347, &[2097168]);     // 347 => [2097168]
+        map.insert(348, &[4162]);     // 348 => [4162]
+        map.insert(349, &[1114120]);     // 349 => [1114120]
+        map.insert(350, &[4160]);     // 350 => [4160]
+        map.insert(351, &[4161]);     // 351 => [4161]
+        map.insert(352, &[4097]);     // 352 => [4097]
+        map.insert(353, &[4096]);     // 353 => [4096]
+        map.insert(354, &[4099]);     // 354 => [4099]
+        map.insert(355, &[4098]);     // 355 => [4098]
+        map.insert(356, &[4101]);     // 356 => [4101]
+        map.insert(357, &[4100]);     // 357 => [4100]
+        map.insert(358, &[20971521]);     // 358 => [20971521]
+        map.insert(359, &[20971520]);     // 359 => [20971520]
+        map.insert(360, &[4105]);     // 360 => [4105]
+        map.insert(361, &[4104]);     // 361 => [4104]
+        map.insert(362, &[2097185]);     // 362 => [2097185]
+        map.insert(363, &[2097184]);     // 363 => [2097184]
+        map.insert(364, &[139264]);     // 364 => [139264]
+        map.insert(365, &[8389632]);     // 365 => [8389632]
+        map.insert(366, &[139266]);     // 366 => [139266]
+        map.insert(367, &[8389634]);     // 367 => [8389634]
+        map.insert(368, &[4113]);     // 368 => [4113]
+        map.insert(369, &[4112]);     // 369 => [4112]
+        map.insert(370, &[393216]);     // 370 => [393216]
+        map.insert(371, &[393217]);     // 371 => [393217]
+        map.insert(372, &[2097216]);     // 372 => [2097216]
+        map.insert(373, &[2097217]);     // 373 => [2097217]
+        map.insert(374, &[2097218]);     // 374 => [2097218]
+        map.insert(375, &[20971536]);     // 375 => [20971536]
+        map.insert(376, &[33025]);     // 376 => [33025]
+        map.insert(377, &[33024]);     // 377 => [33024]
+        map.insert(378, &[393224]);     // 378 => [393224]
+        map.insert(379, &[33026]);     // 379 => [33026]
+        map.insert(380, &[2097224]);     // 380 => [2097224]
+        map.insert(381, &[33028]);     // 381 => [33028]
+        map.insert(382, &[4192]);     // 382 => [4192]
+        map.insert(383, &[704]);     // 383 => [704]
+        map.insert(384, &[768]);     // 384 => [768]
+        map.insert(385, &[769]);     // 385 => [769]
+        map.insert(386, &[2105344]);     // 386 => [2105344]
+        map.insert(387, &[2105345]);     // 387 => [2105345]
+        map.insert(388, &[131105]);     // 388 => [131105]
+        map.insert(389, &[131104]);     // 389 => [131104]
+        map.insert(390, &[2105348]);     // 390 => [2105348]
+        map.insert(391, &[131106]);     // 391 => [131106]
+        map.insert(392, &[776]);     // 392 => [776]
+        map.insert(393, &[266304]);     // 393 => [266304]
+        map.insert(394, &[2105352]);     // 394 => [2105352]
+        map.insert(395, &[8454148]);     // 395 => [8454148]
+        map.insert(396, &[2359312]);     // 396 => [2359312]
+        map.insert(397, &[8454146]);     // 397 => [8454146]
+        map.insert(398, &[8454145]);     // 398 => [8454145]
+        map.insert(399, &[8454144]);     // 399 => [8454144]
+        map.insert(400, &[16512]);     // 400 => [16512]
+        map.insert(401, &[16513]);     // 401 => [16513]
+        map.insert(402, &[17301505]);     // 402 => [17301505]
+        map.insert(403, &[17301504]);     // 403 => [17301504]
+        map.insert(404, &[16516]);     // 404 => [16516]
+        map.insert(405, &[131120]);     // 405 => [131120]
+        map.insert(406, &[266272]);     // 406 => [266272]
+        map.insert(407, &[17301508]);     // 407 => [17301508]
+        map.insert(408, &[131138]);     // 408 => [131138]
+        map.insert(409, &[32928]);     // 409 => [32928]
+        map.insert(410, &[131136]);     // 410 => [131136]
+        map.insert(411, &[131137]);     // 411 => [131137]
+        map.insert(412, &[2359296]);     // 412 => [2359296]
+        map.insert(413, &[2359297]);     // 413 => [2359297]
+        map.insert(414, &[2359298]);     // 414 => [2359298]
+        map.insert(415, &[8454160]);     // 415 => [8454160]
+        map.insert(416, &[131077]);     // 416 => [131077]
+        map.insert(417, &[131076]);     // 417 => [131076]
+        map.insert(418, &[2105376]);     // 418 => [2105376]
+        map.insert(419, &[131078]);     // 419 => [131078]
+        map.insert(420, &[131073]);     // 420 => [131073]
+        map.insert(421, &[131072]);     // 421 => [131072]
+        map.insert(422, &[131075]);     // 422 => [131075]
+        map.insert(423, &[131074]);     // 423 => [131074]
+        map.insert(424, &[12288]);     // 424 => [12288]
+        map.insert(425, &[12289]);     // 425 => [12289]
+        map.insert(426, &[12290]);     // 426 => [12290]
+        map.insert(427, &[17826304]);     // 427 => [17826304]
+        map.insert(428, &[131081]);     // 428 => [131081]
+        map.insert(429, &[131080]);     // 429 => [131080]
+        map.insert(430, &[295168]);     // 430 => [295168]
+        map.insert(431, &[131082]);     // 431 => [131082]
+        map.insert(432, &[16544]);     // 432 => [16544]
+        map.insert(433, &[131092]);     // 433 => [131092]
+        map.insert(434, &[266244]);     // 434 => [266244]
+        map.insert(435, &[1049604]);     // 435 => [1049604]
+        map.insert(436, &[131089]);     // 436 => [131089]
+        map.insert(437, &[131088]);     // 437 => [131088]
+        map.insert(438, &[266240]);     // 438 => [266240]
+        map.insert(439, &[1049600]);     // 439 => [1049600]
+        map.insert(440, &[32897]);     // 440 => [32897]
+        map.insert(441, &[32896]);     // 441 => [32896]
+        map.insert(442, &[4196352]);     // 442 => [4196352]
+        map.insert(443, &[4196353]);     // 443 => [4196353]
+        map.insert(444, &[2359328]);     // 444 => [2359328]
+        map.insert(445, &[32900]);     // 445 => [32900]
+        map.insert(446, &[4196356]);     // 446 => [4196356]
+        map.insert(447, &[1049608]);     // 447 => [1049608]
+        map.insert(448, &[16400]);     // 448 => [16400]
+        map.insert(449, &[3072]);     // 449 => [3072]
+        map.insert(450, &[16402]);     // 450 => [16402]
+        map.insert(451, &[3074]);     // 451 => [3074]
+        map.insert(452, &[32834]);     // 452 => [32834]
+        map.insert(453, &[3076]);     // 453 => [3076]
+        map.insert(454, &[32832]);     // 454 => [32832]
+        map.insert(455, &[32833]);     // 455 => [32833]
+        map.insert(456, &[8705]);     // 456 => [8705]
+        map.insert(457, &[8704]);     // 457 => [8704]
+        map.insert(458, &[2097409]);     // 458 => [2097409]
+        map.insert(459, &[2097408]);     // 459 => [2097408]
+        map.insert(460, &[5242880]);     // 460 => [5242880]
+        map.insert(461, &[5242881]);     // 461 => [5242881]
+        map.insert(462, &[5242882]);     // 462 => [5242882]
+        map.insert(463, &[2097412]);     // 463 => [2097412]
+        map.insert(464, &[16384]);     // 464 => [16384]
+        map.insert(465, &[16385]);     // 465 => [16385]
+        map.insert(466, &[16386]);     // 466 => [16386]
+        map.insert(467, &[16387]);     // 467 => [16387]
+        map.insert(468, &[16388]);     // 468 => [16388]
+        map.insert(469, &[16389]);     // 469 => [16389]
+        map.insert(470, &[262657]);     // 470 => [262657]
+        map.insert(471, &[262656]);     // 471 => [262656]
+        map.insert(472, &[16392]);     // 472 => [16392]
+        map.insert(473, &[32800]);     // 473 => [32800]
+        map.insert(474, &[16394]);     // 474 => [16394]
+        map.insert(475, &[32802]);     // 475 => [32802]
+        map.insert(476, &[16396]);     // 476 => [16396]
+        map.insert(477, &[32804]);     // 477 => [32804]
+        map.insert(478, &[16842784]);     // 478 => [16842784]
+        map.insert(479, &[262664]);     // 479 => [262664]
+        map.insert(480, &[4353]);     // 480 => [4353]
+        map.insert(481, &[4352]);     // 481 => [4352]
+        map.insert(482, &[8912896]);     // 482 => [8912896]
+        map.insert(483, &[8912897]);     // 483 => [8912897]
+        map.insert(484, &[131201]);     // 484 => [131201]
+        map.insert(485, &[131200]);     // 485 => [131200]
+        map.insert(486, &[8912900]);     // 486 => [8912900]
+        map.insert(487, &[131202]);     // 487 => [131202]
+        map.insert(488, &[32785]);     // 488 => [32785]
+        map.insert(489, &[32784]);     // 489 => [32784]
+        map.insert(490, &[8912904]);     // 490 => [8912904]
+        map.insert(491, &[32786]);     // 491 => [32786]
+        map.insert(492, &[5242912]);     // 492 => [5242912]
+        map.insert(493, &[16450]);     // 493 => [16450]
+        map.insert(494, &[16449]);     // 494 => [16449]
+        map.insert(495, &[16448]);     // 495 => [16448]
+        map.insert(496, &[16416]);     // 496 => [16416]
+        map.insert(497, &[32776]);     // 497 => [32776]
+        map.insert(498, &[16418]);     // 498 => [16418]
+        map.insert(499, &[32778]);     // 499 => [32778]
+        map.insert(500, &[16420]);     // 500 => [16420]
+        map.insert(501, &[32780]);     // 501 => [32780]
+        map.insert(502, &[16842760]);     // 502 => [16842760]
+        map.insert(503, &[262688]);     // 503 => [262688]
+        map.insert(504, &[32769]);     // 504 => [32769]
+        map.insert(505, &[32768]);     // 505 => [32768]
+        map.insert(506, &[32771]);     // 506 => [32771]
+        map.insert(507, &[32770]);     // 507 => [32770]
+        map.insert(508, &[32773]);     // 508 => [32773]
+        map.insert(509, &[32772]);     // 509 => [32772]
+        map.insert(510, &[16842752]);     // 510 => [16842752]
+        map.insert(511, &[16842753]);     // 511 => [16842753]
+        map.insert(512, &[1024]);     // 512 => [1024]
+        map.insert(513, &[1025]);     // 513 => [1025]
+        map.insert(514, &[1026]);     // 514 => [1026]
+        map.insert(515, &[1027]);     // 515 => [1027]
+        map.insert(516, &[1028]);     // 516 => [1028]
+        map.insert(517, &[1029]);     // 517 => [1029]
+        map.insert(518, &[1030]);     // 518 => [1030]
+        map.insert(519, &[524552]);     // 519 => [524552]
+        map.insert(520, &[1032]);     // 520 => [1032]
+        map.insert(521, &[1033]);     // 521 => [1033]
+        map.insert(522, &[1034]);     // 522 => [1034]
+        map.insert(523, &[524548]);     // 523 => [524548]
+        map.insert(524, &[8392704]);     // 524 => [8392704]
+        map.insert(525, &[8392705]);     // 525 => [8392705]
+        map.insert(526, &[524545]);     // 526 => [524545]
+        map.insert(527, &[524544]);     // 527 => [524544]
+        map.insert(528, &[1040]);     // 528 => [1040]
+        map.insert(529, &[18432]);     // 529 => [18432]
+        map.insert(530, &[1179648]);     // 530 => [1179648]
+        map.insert(531, &[1179649]);     // 531 => [1179649]
+        map.insert(532, &[1044]);     // 532 => [1044]
+        map.insert(533, &[18436]);     // 533 => [18436]
+        map.insert(534, &[1179652]);     // 534 => [1179652]
+        map.insert(535, &[18874496]);     // 535 => [18874496]
+        map.insert(536, &[16777732]);     // 536 => [16777732]
+        map.insert(537, &[18440]);     // 537 => [18440]
+        map.insert(538, &[1179656]);     // 538 => [1179656]
+        map.insert(539, &[4194464]);     // 539 => [4194464]
+        map.insert(540, &[16777728]);     // 540 => [16777728]
+        map.insert(541, &[16777729]);     // 541 => [16777729]
+        map.insert(542, &[16777730]);     // 542 => [16777730]
+        map.insert(543, &[524560]);     // 543 => [524560]
+        map.insert(544, &[1056]);     // 544 => [1056]
+        map.insert(545, &[1057]);     // 545 => [1057]
+        map.insert(546, &[1058]);     // 546 => [1058]
+        map.insert(547, &[73736]);     // 547 => [73736]
+        map.insert(548, &[10485762]);     // 548 => [10485762]
+        map.insert(549, &[327696]);     // 549 => [327696]
+        map.insert(550, &[10485760]);     // 550 => [10485760]
+        map.insert(551, &[10485761]);     // 551 => [10485761]
+        map.insert(552, &[1064]);     // 552 => [1064]
+        map.insert(553, &[73730]);     // 553 => [73730]
+        map.insert(554, &[73729]);     // 554 => [73729]
+        map.insert(555, &[73728]);     // 555 => [73728]
+        map.insert(556, &[8392736]);     // 556 => [8392736]
+        map.insert(557, &[1179712]);     // 557 => [1179712]
+        map.insert(558, &[10485768]);     // 558 => [10485768]
+        map.insert(559, &[73732]);     // 559 => [73732]
+        map.insert(560, &[34824]);     // 560 => [34824]
+        map.insert(561, &[327684]);     // 561 => [327684]
+        map.insert(562, &[1179680]);     // 562 => [1179680]
+        map.insert(563, &[4194440]);     // 563 => [4194440]
+        map.insert(564, &[327681]);     // 564 => [327681]
+        map.insert(565, &[327680]);     // 565 => [327680]
+        map.insert(566, &[10485776]);     // 566 => [10485776]
+        map.insert(567, &[327682]);     // 567 => [327682]
+        map.insert(568, &[34816]);     // 568 => [34816]
+        map.insert(569, &[34817]);     // 569 => [34817]
+        map.insert(570, &[4194433]);     // 570 => [4194433]
+        map.insert(571, &[4194432]);     // 571 => [4194432]
+        map.insert(572, &[34820]);     // 572 => [34820]
+        map.insert(573, &[1090]);     // 573 => [1090]
+        map.insert(574, &[1089]);     // 574 => [1089]
+        map.insert(575, &[1088]);     // 575 => [1088]
+        map.insert(576, &[1152]);     // 576 => [1152]
+        map.insert(577, &[1153]);     // 577 => [1153]
+        map.insert(578, &[1154]);     // 578 => [1154]
+        map.insert(579, &[2107392]);     // 579 => [2107392]
+        map.insert(580, &[4194368]);     // 580 => [4194368]
+        map.insert(581, &[4194369]);     // 581 => [4194369]
+        map.insert(582, &[532480]);     // 582 => [532480]
+        map.insert(583, &[532481]);     // 583 => [532481]
+        map.insert(584, &[1160]);     // 584 => [1160]
+        map.insert(585, &[9472]);     // 585 => [9472]
+        map.insert(586, &[1081348]);     // 586 => [1081348]
+        map.insert(587, &[4194352]);     // 587 => [4194352]
+        map.insert(588, &[1081346]);     // 588 => [1081346]
+        map.insert(589, &[8389152]);     // 589 => [8389152]
+        map.insert(590, &[1081344]);     // 590 => [1081344]
+        map.insert(591, &[1081345]);     // 591 => [1081345]
+        map.insert(592, &[786440]);     // 592 => [786440]
+        map.insert(593, &[18560]);     // 593 => [18560]
+        map.insert(594, &[8389184]);     // 594 => [8389184]
+        map.insert(595, &[18874372]);     // 595 => [18874372]
+        map.insert(596, &[4194384]);     // 596 => [4194384]
+        map.insert(597, &[18874370]);     // 597 => [18874370]
+        map.insert(598, &[18874369]);     // 598 => [18874369]
+        map.insert(599, &[18874368]);     // 599 => [18874368]
+        map.insert(600, &[786432]);     // 600 => [786432]
+        map.insert(601, &[786433]);     // 601 => [786433]
+        map.insert(602, &[4194337]);     // 602 => [4194337]
+        map.insert(603, &[4194336]);     // 603 => [4194336]
+        map.insert(604, &[786436]);     // 604 => [786436]
+        map.insert(605, &[16781344]);     // 605 => [16781344]
+        map.insert(606, &[1081360]);     // 606 => [1081360]
+        map.insert(607, &[4194340]);     // 607 => [4194340]
+        map.insert(608, &[65794]);     // 608 => [65794]
+        map.insert(609, &[5632]);     // 609 => [5632]
+        map.insert(610, &[65792]);     // 610 => [65792]
+        map.insert(611, &[65793]);     // 611 => [65793]
+        map.insert(612, &[133120]);     // 612 => [133120]
+        map.insert(613, &[133121]);     // 613 => [133121]
+        map.insert(614, &[1064961]);     // 614 => [1064961]
+        map.insert(615, &[1064960]);     // 615 => [1064960]
+        map.insert(616, &[18874432]);     // 616 => [18874432]
+        map.insert(617, &[4194322]);     // 617 => [4194322]
+        map.insert(618, &[4194321]);     // 618 => [4194321]
+        map.insert(619, &[4194320]);     // 619 => [4194320]
+        map.insert(620, &[8389121]);     // 620 => [8389121]
+        map.insert(621, &[8389120]);     // 621 => [8389120]
+        map.insert(622, &[1081376]);     // 622 => [1081376]
+        map.insert(623, &[8389122]);     // 623 => [8389122]
+        map.insert(624, &[16916480]);     // 624 => [16916480]
+        map.insert(625, &[4194314]);     // 625 => [4194314]
+        map.insert(626, &[4194313]);     // 626 => [4194313]
+        map.insert(627, &[4194312]);     // 627 => [4194312]
+        map.insert(628, &[133136]);     // 628 => [133136]
+        map.insert(629, &[16781320]);     // 629 => [16781320]
+        map.insert(630, &[557568]);     // 630 => [557568]
+        map.insert(631, &[4194316]);     // 631 => [4194316]
+        map.insert(632, &[4194307]);     // 632 => [4194307]
+        map.insert(633, &[4194306]);     // 633 => [4194306]
+        map.insert(634, &[4194305]);     // 634 => [4194305]
+        map.insert(635, &[4194304]);     // 635 => [4194304]
+        map.insert(636, &[16781313]);     // 636 => [16781313]
+        map.insert(637, &[16781312]);     // 637 => [16781312]
+        map.insert(638, &[4194309]);     // 638 => [4194309]
+        map.insert(639, &[4194308]);     // 639 => [4194308]
+        map.insert(640, &[1280]);     // 640 => [1280]
+        map.insert(641, &[1281]);     // 641 => [1281]
+        map.insert(642, &[1282]);     // 642 => [1282]
+        map.insert(643, &[524300]);     // 643 => [524300]
+        map.insert(644, &[1284]);     // 644 => [1284]
+        map.insert(645, &[524298]);     // 645 => [524298]
+        map.insert(646, &[524297]);     // 646 => [524297]
+        map.insert(647, &[524296]);     // 647 => [524296]
+        map.insert(648, &[2099202]);     // 648 => [2099202]
+        map.insert(649, &[524294]);     // 649 => [524294]
+        map.insert(650, &[2099200]);     // 650 => [2099200]
+        map.insert(651, &[524292]);     // 651 => [524292]
+        map.insert(652, &[524291]);     // 652 => [524291]
+        map.insert(653, &[524290]);     // 653 => [524290]
+        map.insert(654, &[524289]);     // 654 => [524289]
+        map.insert(655, &[524288]);     // 655 => [524288]
+        map.insert(656, &[8421380]);     // 656 => [8421380]
+        map.insert(657, &[18688]);     // 657 => [18688]
+        map.insert(658, &[4202528]);     // 658 => [4202528]
+        map.insert(659, &[4456512]);     // 659 => [4456512]
+        map.insert(660, &[8421376]);     // 660 => [8421376]
+        map.insert(661, &[8421377]);     // 661 => [8421377]
+        map.insert(662, &[8421378]);     // 662 => [8421378]
+        map.insert(663, &[524312]);     // 663 => [524312]
+        map.insert(664, &[37888]);     // 664 => [37888]
+        map.insert(665, &[16908320]);     // 665 => [16908320]
+        map.insert(666, &[2099216]);     // 666 => [2099216]
+        map.insert(667, &[524308]);     // 667 => [524308]
+        map.insert(668, &[8421384]);     // 668 => [8421384]
+        map.insert(669, &[524306]);     // 669 => [524306]
+        map.insert(670, &[524305]);     // 670 => [524305]
+        map.insert(671, &[524304]);     // 671 => [524304]
+        map.insert(672, &[6144]);     // 672 => [6144]
+        map.insert(673, &[6145]);     // 673 => [6145]
+        map.insert(674, &[65664]);     // 674 => [65664]
+        map.insert(675, &[65665]);     // 675 => [65665]
+        map.insert(676, &[6148]);     // 676 => [6148]
+        map.insert(677, &[16810112]);     // 677 => [16810112]
+        map.insert(678, &[65668]);     // 678 => [65668]
+        map.insert(679, &[524328]);     // 679 => [524328]
+        map.insert(680, &[4456452]);     // 680 => [4456452]
+        map.insert(681, &[16908304]);     // 681 => [16908304]
+        map.insert(682, &[65672]);     // 682 => [65672]
+        map.insert(683, &[524324]);     // 683 => [524324]
+        map.insert(684, &[4456448]);     // 684 => [4456448]
+        map.insert(685, &[4456449]);     // 685 => [4456449]
+        map.insert(686, &[524321]);     // 686 => [524321]
+        map.insert(687, &[524320]);     // 687 => [524320]
+        map.insert(688, &[524352]);     // 688 => [524352]
+        map.insert(689, &[524353]);     // 689 => [524353]
+        map.insert(690, &[4202496]);     // 690 => [4202496]
+        map.insert(691, &[4202497]);     // 691 => [4202497]
+        map.insert(692, &[524356]);     // 692 => [524356]
+        map.insert(693, &[1049090]);     // 693 => [1049090]
+        map.insert(694, &[1049089]);     // 694 => [1049089]
+        map.insert(695, &[1049088]);     // 695 => [1049088]
+        map.insert(696, &[16908289]);     // 696 => [16908289]
+        map.insert(697, &[16908288]);     // 697 => [16908288]
+        map.insert(698, &[4202504]);     // 698 => [4202504]
+        map.insert(699, &[16908290]);     // 699 => [16908290]
+        map.insert(700, &[8404993]);     // 700 => [8404993]
+        map.insert(701, &[8404992]);     // 701 => [8404992]
+        map.insert(702, &[229376]);     // 702 => [229376]
+        map.insert(703, &[8404994]);     // 703 => [8404994]
+        map.insert(704, &[2561]);     // 704 => [2561]
+        map.insert(705, &[2560]);     // 705 => [2560]
+        map.insert(706, &[65568]);     // 706 => [65568]
+        map.insert(707, &[65569]);     // 707 => [65569]
+        map.insert(708, &[16793608]);     // 708 => [16793608]
+        map.insert(709, &[2564]);     // 709 => [2564]
+        map.insert(710, &[65572]);     // 710 => [65572]
+        map.insert(711, &[524424]);     // 711 => [524424]
+        map.insert(712, &[8519680]);     // 712 => [8519680]
+        map.insert(713, &[9216]);     // 713 => [9216]
+        map.insert(714, &[8519682]);     // 714 => [8519682]
+        map.insert(715, &[9218]);     // 715 => [9218]
+        map.insert(716, &[16793600]);     // 716 => [16793600]
+        map.insert(717, &[16793601]);     // 717 => [16793601]
+        map.insert(718, &[524417]);     // 718 => [524417]
+        map.insert(719, &[524416]);     // 719 => [524416]
+        map.insert(720, &[17920]);     // 720 => [17920]
+        map.insert(721, &[2576]);     // 721 => [2576]
+        map.insert(722, &[1052676]);     // 722 => [1052676]
+        map.insert(723, &[263172]);     // 723 => [263172]
+        map.insert(724, &[1052674]);     // 724 => [1052674]
+        map.insert(725, &[263170]);     // 725 => [263170]
+        map.insert(726, &[1052672]);     // 726 => [1052672]
+        map.insert(727, &[263168]);     // 727 => [263168]
+        map.insert(728, &[8519696]);     // 728 => [8519696]
+        map.insert(729, &[65604]);     // 729 => [65604]
+        map.insert(730, &[16810048]);     // 730 => [16810048]
+        map.insert(731, &[4194592]);     // 731 => [4194592]
+        map.insert(732, &[65601]);     // 732 => [65601]
+        map.insert(733, &[65600]);     // 733 => [65600]
+        map.insert(734, &[1052680]);     // 734 => [1052680]
+        map.insert(735, &[65602]);     // 735 => [65602]
+        map.insert(736, &[65538]);     // 736 => [65538]
+        map.insert(737, &[65539]);     // 737 => [65539]
+        map.insert(738, &[65536]);     // 738 => [65536]
+        map.insert(739, &[65537]);     // 739 => [65537]
+        map.insert(740, &[16809985]);     // 740 => [16809985]
+        map.insert(741, &[16809984]);     // 741 => [16809984]
+        map.insert(742, &[65540]);     // 742 => [65540]
+        map.insert(743, &[65541]);     // 743 => [65541]
+        map.insert(744, &[65546]);     // 744 => [65546]
+        map.insert(745, &[9248]);     // 745 => [9248]
+        map.insert(746, &[65544]);     // 746 => [65544]
+        map.insert(747, &[65545]);     // 747 => [65545]
+        map.insert(748, &[3145744]);     // 748 => [3145744]
+        map.insert(749, &[16809992]);     // 749 => [16809992]
+        map.insert(750, &[65548]);     // 750 => [65548]
+        map.insert(751, &[524448]);     // 751 => [524448]
+        map.insert(752, &[65554]);     // 752 => [65554]
+        map.insert(753, &[43008]);     // 753 => [43008]
+        map.insert(754, &[65552]);     // 754 => [65552]
+        map.insert(755, &[65553]);     // 755 => [65553]
+        map.insert(756, &[3145736]);     // 756 => [3145736]
+        map.insert(757, &[16810000]);     // 757 => [16810000]
+        map.insert(758, &[65556]);     // 758 => [65556]
+        map.insert(759, &[263200]);     // 759 => [263200]
+        map.insert(760, &[3145732]);     // 760 => [3145732]
+        map.insert(761, &[4194562]);     // 761 => [4194562]
+        map.insert(762, &[4194561]);     // 762 => [4194561]
+        map.insert(763, &[4194560]);     // 763 => [4194560]
+        map.insert(764, &[3145728]);     // 764 => [3145728]
+        map.insert(765, &[3145729]);     // 765 => [3145729]
+        map.insert(766, &[3145730]);     // 766 => [3145730]
+        map.insert(767, &[4194564]);     // 767 => [4194564]
+        map.insert(768, &[1536]);     // 768 => [1536]
+        map.insert(769, &[1537]);     // 769 => [1537]
+        map.insert(770, &[1538]);     // 770 => [1538]
+        map.insert(771, &[16777312]);     // 771 => [16777312]
+        map.insert(772, &[1540]);     // 772 => [1540]
+        map.insert(773, &[137216]);     // 773 => [137216]
+        map.insert(774, &[264208]);     // 774 => [264208]
+        map.insert(775, &[196736]);     // 775 => [196736]
+        map.insert(776, &[10240]);     // 776 => [10240]
+        map.insert(777, &[10241]);     // 777 => [10241]
+        map.insert(778, &[10242]);     // 778 => [10242]
+        map.insert(779, &[98320]);     // 779 => [98320]
+        map.insert(780, &[16777232]);     // 780 => [16777232]
+        map.insert(781, &[16777233]);     // 781 => [16777233]
+        map.insert(782, &[16777234]);     // 782 => [16777234]
+        map.insert(783, &[6291520]);     // 783 => [6291520]
+        map.insert(784, &[16777228]);     // 784 => [16777228]
+        map.insert(785, &[18944]);     // 785 => [18944]
+        map.insert(786, &[264196]);     // 786 => [264196]
+        map.insert(787, &[98312]);     // 787 => [98312]
+        map.insert(788, &[16777224]);     // 788 => [16777224]
+        map.insert(789, &[16777225]);     // 789 => [16777225]
+        map.insert(790, &[264192]);     // 790 => [264192]
+        map.insert(791, &[264193]);     // 791 => [264193]
+        map.insert(792, &[16777220]);     // 792 => [16777220]
+        map.insert(793, &[16777221]);     // 793 => [16777221]
+        map.insert(794, &[4198400]);     // 794 => [4198400]
+        map.insert(795, &[98304]);     // 795 => [98304]
+        map.insert(796, &[16777216]);     // 796 => [16777216]
+        map.insert(797, &[16777217]);     // 797 => [16777217]
+        map.insert(798, &[16777218]);     // 798 => [16777218]
+        map.insert(799, &[16777219]);     // 799 => [16777219]
+        map.insert(800, &[6291472]);     // 800 => [6291472]
+        map.insert(801, &[16777282]);     // 801 => [16777282]
+        map.insert(802, &[16777281]);     // 802 => [16777281]
+        map.insert(803, &[16777280]);     // 803 => [16777280]
+        map.insert(804, &[98368]);     // 804 => [98368]
+        map.insert(805, &[8388744]);     // 805 => [8388744]
+        map.insert(806, &[10486272]);     // 806 => [10486272]
+        map.insert(807, &[16777284]);     // 807 => [16777284]
+        map.insert(808, &[655362]);     // 808 => [655362]
+        map.insert(809, &[8388740]);     // 809 => [8388740]
+        map.insert(810, &[655360]);     // 810 => [655360]
+        map.insert(811, &[655361]);     // 811 => [655361]
+        map.insert(812, &[8388737]);     // 812 => [8388737]
+        map.insert(813, &[8388736]);     // 813 => [8388736]
+        map.insert(814, &[655364]);     // 814 => [655364]
+        map.insert(815, &[8388738]);     // 815 => [8388738]
+        map.insert(816, &[6291456]);     // 816 => [6291456]
+        map.insert(817, &[6291457]);     // 817 => [6291457]
+        map.insert(818, &[81920]);     // 818 => [81920]
+        map.insert(819, &[81921]);     // 819 => [81921]
+        map.insert(820, &[6291460]);     // 820 => [6291460]
+        map.insert(821, &[1048834]);     // 821 => [1048834]
+        map.insert(822, &[1048833]);     // 822 => [1048833]
+        map.insert(823, &[1048832]);     // 823 => [1048832]
+        map.insert(824, &[16777252]);     // 824 => [16777252]
+        map.insert(825, &[790528]);     // 825 => [790528]
+        map.insert(826, &[81928]);     // 826 => [81928]
+        map.insert(827, &[98336]);     // 827 => [98336]
+        map.insert(828, &[16777248]);     // 828 => [16777248]
+        map.insert(829, &[16777249]);     // 829 => [16777249]
+        map.insert(830, &[16777250]);     // 830 => [16777250]
+        map.insert(831, &[1048840]);     // 831 => [1048840]
+        map.insert(832, &[2305]);     // 832 => [2305]
+        map.insert(833, &[2304]);     // 833 => [2304]
+        map.insert(834, &[8388688]);     // 834 => [8388688]
+        map.insert(835, &[2306]);     // 835 => [2306]
+        map.insert(836, &[4194880]);     // 836 => [4194880]
+        map.insert(837, &[196610]);     // 837 => [196610]
+        map.insert(838, &[196609]);     // 838 => [196609]
+        map.insert(839, &[196608]);     // 839 => [196608]
+        map.insert(840, &[10368]);     // 840 => [10368]
+        map.insert(841, &[2098178]);     // 841 => [2098178]
+        map.insert(842, &[2098177]);     // 842 => [2098177]
+        map.insert(843, &[2098176]);     // 843 => [2098176]
+        map.insert(844, &[8388641]);     // 844 => [8388641]
+        map.insert(845, &[8388640]);     // 845 => [8388640]
+        map.insert(846, &[1081856]);     // 846 => [1081856]
+        map.insert(847, &[8388642]);     // 847 => [8388642]
+        map.insert(848, &[8388674]);     // 848 => [8388674]
+        map.insert(849, &[2320]);     // 849 => [2320]
+        map.insert(850, &[8388672]);     // 850 => [8388672]
+        map.insert(851, &[8388673]);     // 851 => [8388673]
+        map.insert(852, &[16777352]);     // 852 => [16777352]
+        map.insert(853, &[16785664]);     // 853 => [16785664]
+        map.insert(854, &[8388676]);     // 854 => [8388676]
+        map.insert(855, &[540680]);     // 855 => [540680]
+        map.insert(856, &[16777348]);     // 856 => [16777348]
+        map.insert(857, &[38912]);     // 857 => [38912]
+        map.insert(858, &[8388680]);     // 858 => [8388680]
+        map.insert(859, &[540676]);     // 859 => [540676]
+        map.insert(860, &[16777344]);     // 860 => [16777344]
+        map.insert(861, &[16777345]);     // 861 => [16777345]
+        map.insert(862, &[540673]);     // 862 => [540673]
+        map.insert(863, &[540672]);     // 863 => [540672]
+        map.insert(864, &[1310720]);     // 864 => [1310720]
+        map.insert(865, &[5120]);     // 865 => [5120]
+        map.insert(866, &[1310722]);     // 866 => [1310722]
+        map.insert(867, &[5122]);     // 867 => [5122]
+        map.insert(868, &[8388617]);     // 868 => [8388617]
+        map.insert(869, &[8388616]);     // 869 => [8388616]
+        map.insert(870, &[557072]);     // 870 => [557072]
+        map.insert(871, &[8388618]);     // 871 => [8388618]
+        map.insert(872, &[8388613]);     // 872 => [8388613]
+        map.insert(873, &[8388612]);     // 873 => [8388612]
+        map.insert(874, &[655488]);     // 874 => [655488]
+        map.insert(875, &[8388614]);     // 875 => [8388614]
+        map.insert(876, &[8388609]);     // 876 => [8388609]
+        map.insert(877, &[8388608]);     // 877 => [8388608]
+        map.insert(878, &[8388611]);     // 878 => [8388611]
+        map.insert(879, &[8388610]);     // 879 => [8388610]
+        map.insert(880, &[1310736]);     // 880 => [1310736]
+        map.insert(881, &[5136]);     // 881 => [5136]
+        map.insert(882, &[557060]);     // 882 => [557060]
+        map.insert(883, &[4194824]);     // 883 => [4194824]
+        map.insert(884, &[557058]);     // 884 => [557058]
+        map.insert(885, &[8388632]);     // 885 => [8388632]
+        map.insert(886, &[557056]);     // 886 => [557056]
+        map.insert(887, &[557057]);     // 887 => [557057]
+        map.insert(888, &[196672]);     // 888 => [196672]
+        map.insert(889, &[4194818]);     // 889 => [4194818]
+        map.insert(890, &[4194817]);     // 890 => [4194817]
+        map.insert(891, &[4194816]);     // 891 => [4194816]
+        map.insert(892, &[8388625]);     // 892 => [8388625]
+        map.insert(893, &[8388624]);     // 893 => [8388624]
+        map.insert(894, &[1056768]);     // 894 => [1056768]
+        map.insert(895, &[1056769]);     // 895 => [1056769]
+        map.insert(896, &[2177]);     // 896 => [2177]
+        map.insert(897, &[2176]);     // 897 => [2176]
+        map.insert(898, &[4227072]);     // 898 => [4227072]
+        map.insert(899, &[69632]);     // 899 => [69632]
+        map.insert(900, &[8396832]);     // 900 => [8396832]
+        map.insert(901, &[2180]);     // 901 => [2180]
+        map.insert(902, &[4227076]);     // 902 => [4227076]
+        map.insert(903, &[69636]);     // 903 => [69636]
+        map.insert(904, &[1048640]);     // 904 => [1048640]
+        map.insert(905, &[1048641]);     // 905 => [1048641]
+        map.insert(906, &[1048642]);     // 906 => [1048642]
+        map.insert(907, &[524804]);     // 907 => [524804]
+        map.insert(908, &[1048644]);     // 908 => [1048644]
+        map.insert(909, &[524802]);     // 909 => [524802]
+        map.insert(910, &[524801]);     // 910 => [524801]
+        map.insert(911, &[524800]);     // 911 => [524800]
+        map.insert(912, &[17536]);     // 912 => [17536]
+        map.insert(913, &[2192]);     // 913 => [2192]
+        map.insert(914, &[4227088]);     // 914 => [4227088]
+        map.insert(915, &[1048612]);     // 915 => [1048612]
+        map.insert(916, &[16777480]);     // 916 => [16777480]
+        map.insert(917, &[1048610]);     // 917 => [1048610]
+        map.insert(918, &[1048609]);     // 918 => [1048609]
+        map.insert(919, &[1048608]);     // 919 => [1048608]
+        map.insert(920, &[16777476]);     // 920 => [16777476]
+        map.insert(921, &[13107200]);     // 921 => [13107200]
+        map.insert(922, &[8650784]);     // 922 => [8650784]
+        map.insert(923, &[8396864]);     // 923 => [8396864]
+        map.insert(924, &[16777472]);     // 924 => [16777472]
+        map.insert(925, &[16777473]);     // 925 => [16777473]
+        map.insert(926, &[16777474]);     // 926 => [16777474]
+        map.insert(927, &[1048616]);     // 927 => [1048616]
+        map.insert(928, &[8396804]);     // 928 => [8396804]
+        map.insert(929, &[132100]);     // 929 => [132100]
+        map.insert(930, &[4227104]);     // 930 => [4227104]
+        map.insert(931, &[1048596]);     // 931 => [1048596]
+        map.insert(932, &[8396800]);     // 932 => [8396800]
+        map.insert(933, &[132096]);     // 933 => [132096]
+        map.insert(934, &[1048593]);     // 934 => [1048593]
+        map.insert(935, &[1048592]);     // 935 => [1048592]
+        map.insert(936, &[2162689]);     // 936 => [2162689]
+        map.insert(937, &[2162688]);     // 937 => [2162688]
+        map.insert(938, &[4210689]);     // 938 => [4210689]
+        map.insert(939, &[4210688]);     // 939 => [4210688]
+        map.insert(940, &[8396808]);     // 940 => [8396808]
+        map.insert(941, &[2162692]);     // 941 => [2162692]
+        map.insert(942, &[528512]);     // 942 => [528512]
+        map.insert(943, &[4210692]);     // 943 => [4210692]
+        map.insert(944, &[524864]);     // 944 => [524864]
+        map.insert(945, &[1048582]);     // 945 => [1048582]
+        map.insert(946, &[1048581]);     // 946 => [1048581]
+        map.insert(947, &[1048580]);     // 947 => [1048580]
+        map.insert(948, &[1048579]);     // 948 => [1048579]
+        map.insert(949, &[1048578]);     // 949 => [1048578]
+        map.insert(950, &[1048577]);     // 950 => [1048577]
+        map.insert(951, &[1048576]);     // 951 => [1048576]
+        map.insert(952, &[8650754]);     // 952 => [8650754]
+        map.insert(953, &[2162704]);     // 953 => [2162704]
+        map.insert(954, &[8650752]);     // 954 => [8650752]
+        map.insert(955, &[8650753]);     // 955 => [8650753]
+        map.insert(956, &[16777504]);     // 956 => [16777504]
+        map.insert(957, &[1048586]);     // 957 => [1048586]
+        map.insert(958, &[1048585]);     // 958 => [1048585]
+        map.insert(959, &[1048584]);     // 959 => [1048584]
+        map.insert(960, &[2049]);     // 960 => [2049]
+        map.insert(961, &[2048]);     // 961 => [2048]
+        map.insert(962, &[2051]);     // 962 => [2051]
+        map.insert(963, &[2050]);     // 963 => [2050]
+        map.insert(964, &[2621440]);     // 964 => [2621440]
+        map.insert(965, &[2052]);     // 965 => [2052]
+        map.insert(966, &[2621442]);     // 966 => [2621442]
+        map.insert(967, &[2054]);     // 967 => [2054]
+        map.insert(968, &[2057]);     // 968 => [2057]
+        map.insert(969, &[2056]);     // 969 => [2056]
+        map.insert(970, &[17039361]);     // 970 => [17039361]
+        map.insert(971, &[17039360]);     // 971 => [17039360]
+        map.insert(972, &[2621448]);     // 972 => [2621448]
+        map.insert(973, &[2060]);     // 973 => [2060]
+        map.insert(974, &[4325392]);     // 974 => [4325392]
+        map.insert(975, &[17039364]);     // 975 => [17039364]
+        map.insert(976, &[17408]);     // 976 => [17408]
+        map.insert(977, &[2064]);     // 977 => [2064]
+        map.insert(978, &[17410]);     // 978 => [17410]
+        map.insert(979, &[2066]);     // 979 => [2066]
+        map.insert(980, &[16785409]);     // 980 => [16785409]
+        map.insert(981, &[16785408]);     // 981 => [16785408]
+        map.insert(982, &[4325384]);     // 982 => [4325384]
+        map.insert(983, &[16785410]);     // 983 => [16785410]
+        map.insert(984, &[17416]);     // 984 => [17416]
+        map.insert(985, &[2072]);     // 985 => [2072]
+        map.insert(986, &[4325380]);     // 986 => [4325380]
+        map.insert(987, &[17039376]);     // 987 => [17039376]
+        map.insert(988, &[4325378]);     // 988 => [4325378]
+        map.insert(989, &[16785416]);     // 989 => [16785416]
+        map.insert(990, &[4325376]);     // 990 => [4325376]
+        map.insert(991, &[4325377]);     // 991 => [4325377]
+        map.insert(992, &[2081]);     // 992 => [2081]
+        map.insert(993, &[2080]);     // 993 => [2080]
+        map.insert(994, &[66048]);     // 994 => [66048]
+        map.insert(995, &[66049]);     // 995 => [66049]
+        map.insert(996, &[2621472]);     // 996 => [2621472]
+        map.insert(997, &[2084]);     // 997 => [2084]
+        map.insert(998, &[66052]);     // 998 => [66052]
+        map.insert(999, &[1048720]);     // 999 => [1048720]
+        map.insert(1000, &[51200]);     // 1000 => [51200]
+        map.insert(1001, &[8388868]);     // 1001 => [8388868]
+        map.insert(1002, &[528388]);     // 1002 => [528388]
+        map.insert(1003, &[17039392]);     // 1003 => [17039392]
+        map.insert(1004, &[8388865]);     // 1004 => [8388865]
+        map.insert(1005, &[8388864]);     // 1005 => [8388864]
+        map.insert(1006, &[528384]);     // 1006 => [528384]
+        map.insert(1007, &[528385]);     // 1007 => [528385]
+        map.insert(1008, &[17440]);     // 1008 => [17440]
+        map.insert(1009, &[33800]);     // 1009 => [33800]
+        map.insert(1010, &[66064]);     // 1010 => [66064]
+        map.insert(1011, &[1048708]);     // 1011 => [1048708]
+        map.insert(1012, &[17039424]);     // 1012 => [17039424]
+        map.insert(1013, &[1048706]);     // 1013 => [1048706]
+        map.insert(1014, &[1048705]);     // 1014 => [1048705]
+        map.insert(1015, &[1048704]);     // 1015 => [1048704]
+        map.insert(1016, &[33793]);     // 1016 => [33793]
+        map.insert(1017, &[33792]);     // 1017 => [33792]
+        map.insert(1018, &[2116]);     // 1018 => [2116]
+        map.insert(1019, &[33794]);     // 1019 => [33794]
+        map.insert(1020, &[2114]);     // 1020 => [2114]
+        map.insert(1021, &[33796]);     // 1021 => [33796]
+        map.insert(1022, &[2112]);     // 1022 => [2112]
+        map.insert(1023, &[2113]);     // 1023 => [2113]
+        map
+    })
 }
 
 impl WagnerCode25_15 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -1115,24 +1115,15 @@ impl BinaryCode for WagnerCode25_15 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(25);
@@ -1157,14 +1148,19 @@ impl BinaryCode for WagnerCode25_15 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[25 / 64] & !((1 << 25) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2619,8 +2615,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, false, false, true, true, true, false ]);
         assert_eq!(vector, first_row.as_vector());
@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -15,1091 +14,1092 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct WagnerCode27_17;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 89391105 ],
-                &[ 44695554 ],
-                &[ 133693444 ],
-                &[ 132513800 ],
-                &[ 127795216 ],
-                &[ 108920864 ],
-                &[ 33423424 ],
-                &[ 3014784 ],
-                &[ 9830656 ],
-                &[ 38142464 ],
-                &[ 84280320 ],
-                &[ 18483200 ],
-                &[ 101322752 ],
-                &[ 6037504 ],
-                &[ 76300288 ],
-                &[ 55083008 ],
-                &[ 24182784 ],
-                
-            ], 27));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 53825537 ],
-                &[ 80433154 ],
-                &[ 35464260 ],
-                &[ 40808520 ],
-                &[ 96415824 ],
-                &[ 49913952 ],
-                &[ 106980480 ],
-                &[ 25670912 ],
-                &[ 33474048 ],
-                &[ 133724160 ],
-                
-            ], 27));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 89391105 ],
+            &[ 44695554 ],
+            &[ 133693444 ],
+            &[ 132513800 ],
+            &[ 127795216 ],
+            &[ 108920864 ],
+            &[ 33423424 ],
+            &[ 3014784 ],
+            &[ 9830656 ],
+            &[ 38142464 ],
+            &[ 84280320 ],
+            &[ 18483200 ],
+            &[ 101322752 ],
+            &[ 6037504 ],
+            &[ 76300288 ],
+            &[ 55083008 ],
+            &[ 24182784 ],
+            
+        ], 27)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(1024, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(7, &[393216]);     // 7 => [393216]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(11, &[1572864]);     // 11 => [1572864]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(15, &[1572868]);     // 15 => [1572868]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(19, &[6291456]);     // 19 => [6291456]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(23, &[6291460]);     // 23 => [6291460]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(27, &[6291464]);     // 27 => [6291464]
-            map.insert(28, &[96]);     // 28 => [96]
-            map.insert(29, &[97]);     // 29 => [97]
-            map.insert(30, &[98]);     // 30 => [98]
-            map.insert(31, &[100663328]);     // 31 => [100663328]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(35, &[25165824]);     // 35 => [25165824]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(37, &[37]);     // 37 => [37]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(39, &[25165828]);     // 39 => [25165828]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(41, &[4210688]);     // 41 => [4210688]
-            map.insert(42, &[42]);     // 42 => [42]
-            map.insert(43, &[4210690]);     // 43 => [4210690]
-            map.insert(44, &[80]);     // 44 => [80]
-            map.insert(45, &[81]);     // 45 => [81]
-            map.insert(46, &[82]);     // 46 => [82]
-            map.insert(47, &[100663312]);     // 47 => [100663312]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(50, &[163840]);     // 50 => [163840]
-            map.insert(51, &[163841]);     // 51 => [163841]
-            map.insert(52, &[72]);     // 52 => [72]
-            map.insert(53, &[294912]);     // 53 => [294912]
-            map.insert(54, &[65793]);     // 54 => [65793]
-            map.insert(55, &[65792]);     // 55 => [65792]
-            map.insert(56, &[68]);     // 56 => [68]
-            map.insert(57, &[69]);     // 57 => [69]
-            map.insert(58, &[2113536]);     // 58 => [2113536]
-            map.insert(59, &[2113537]);     // 59 => [2113537]
-            map.insert(60, &[64]);     // 60 => [64]
-            map.insert(61, &[65]);     // 61 => [65]
-            map.insert(62, &[66]);     // 62 => [66]
-            map.insert(63, &[100663296]);     // 63 => [100663296]
-            map.insert(64, &[128]);     // 64 => [128]
-            map.insert(65, &[129]);     // 65 => [129]
-            map.insert(66, &[130]);     // 66 => [130]
-            map.insert(67, &[131]);     // 67 => [131]
-            map.insert(68, &[132]);     // 68 => [132]
-            map.insert(69, &[133]);     // 69 => [133]
-            map.insert(70, &[2621441]);     // 70 => [2621441]
-            map.insert(71, &[2621440]);     // 71 => [2621440]
-            map.insert(72, &[136]);     // 72 => [136]
-            map.insert(73, &[137]);     // 73 => [137]
-            map.insert(74, &[138]);     // 74 => [138]
-            map.insert(75, &[1572992]);     // 75 => [1572992]
-            map.insert(76, &[3145728]);     // 76 => [3145728]
-            map.insert(77, &[3145729]);     // 77 => [3145729]
-            map.insert(78, &[3145730]);     // 78 => [3145730]
-            map.insert(79, &[2621448]);     // 79 => [2621448]
-            map.insert(80, &[144]);     // 80 => [144]
-            map.insert(81, &[145]);     // 81 => [145]
-            map.insert(82, &[67110912]);     // 82 => [67110912]
-            map.insert(83, &[67110913]);     // 83 => [67110913]
-            map.insert(84, &[4718592]);     // 84 => [4718592]
-            map.insert(85, &[4718593]);     // 85 => [4718593]
-            map.insert(86, &[4718594]);     // 86 => [4718594]
-            map.insert(87, &[5242888]);     // 87 => [5242888]
-            map.insert(88, &[263169]);     // 88 => [263169]
-            map.insert(89, &[263168]);     // 89 => [263168]
-            map.insert(90, &[132100]);     // 90 => [132100]
-            map.insert(91, &[263170]);     // 91 => [263170]
-            map.insert(92, &[132098]);     // 92 => [132098]
-            map.insert(93, &[5242882]);     // 93 => [5242882]
-            map.insert(94, &[132096]);     // 94 => [132096]
-            map.insert(95, &[5242880]);     // 95 => [5242880]
-            map.insert(96, &[160]);     // 96 => [160]
-            map.insert(97, &[161]);     // 97 => [161]
-            map.insert(98, &[162]);     // 98 => [162]
-            map.insert(99, &[12292]);     // 99 => [12292]
-            map.insert(100, &[164]);     // 100 => [164]
-            map.insert(101, &[12290]);     // 101 => [12290]
-            map.insert(102, &[12289]);     // 102 => [12289]
-            map.insert(103, &[12288]);     // 103 => [12288]
-            map.insert(104, &[33796]);     // 104 => [33796]
-            map.insert(105, &[33556484]);     // 105 => [33556484]
-            map.insert(106, &[4196864]);     // 106 => [4196864]
-            map.insert(107, &[4329472]);     // 107 => [4329472]
-            map.insert(108, &[33792]);     // 108 => [33792]
-            map.insert(109, &[33556480]);     // 109 => [33556480]
-            map.insert(110, &[33794]);     // 110 => [33794]
-            map.insert(111, &[33556482]);     // 111 => [33556482]
-            map.insert(112, &[176]);     // 112 => [176]
-            map.insert(113, &[8405248]);     // 113 => [8405248]
-            map.insert(114, &[1064964]);     // 114 => [1064964]
-            map.insert(115, &[67072]);     // 115 => [67072]
-            map.insert(116, &[1064962]);     // 116 => [1064962]
-            map.insert(117, &[540680]);     // 117 => [540680]
-            map.insert(118, &[1064960]);     // 118 => [1064960]
-            map.insert(119, &[1064961]);     // 119 => [1064961]
-            map.insert(120, &[196]);     // 120 => [196]
-            map.insert(121, &[540676]);     // 121 => [540676]
-            map.insert(122, &[2113664]);     // 122 => [2113664]
-            map.insert(123, &[2621504]);     // 123 => [2621504]
-            map.insert(124, &[192]);     // 124 => [192]
-            map.insert(125, &[540672]);     // 125 => [540672]
-            map.insert(126, &[194]);     // 126 => [194]
-            map.insert(127, &[540674]);     // 127 => [540674]
-            map.insert(128, &[256]);     // 128 => [256]
-            map.insert(129, &[257]);     // 129 => [257]
-            map.insert(130, &[258]);     // 130 => [258]
-            map.insert(131, &[259]);     // 131 => [259]
-            map.insert(132, &[260]);     // 132 => [260]
-            map.insert(133, &[261]);     // 133 => [261]
-            map.insert(134, &[9437185]);     // 134 => [9437185]
-            map.insert(135, &[9437184]);     // 135 => [9437184]
-            map.insert(136, &[264]);     // 136 => [264]
-            map.insert(137, &[265]);     // 137 => [265]
-            map.insert(138, &[65601]);     // 138 => [65601]
-            map.insert(139, &[65600]);     // 139 => [65600]
-            map.insert(140, &[8912896]);     // 140 => [8912896]
-            map.insert(141, &[8912897]);     // 141 => [8912897]
-            map.insert(142, &[8912898]);     // 142 => [8912898]
-            map.insert(143, &[65604]);     // 143 => [65604]
-            map.insert(144, &[272]);     // 144 => [272]
-            map.insert(145, &[33562624]);     // 145 => [33562624]
-            map.insert(146, &[274]);     // 146 => [274]
-            map.insert(147, &[33562626]);     // 147 => [33562626]
-            map.insert(148, &[276]);     // 148 => [276]
-            map.insert(149, &[65570]);     // 149 => [65570]
-            map.insert(150, &[65569]);     // 150 => [65569]
-            map.insert(151, &[65568]);     // 151 => [65568]
-            map.insert(152, &[131586]);     // 152 => [131586]
-            map.insert(153, &[6146]);     // 153 => [6146]
-            map.insert(154, &[131584]);     // 154 => [131584]
-            map.insert(155, &[6144]);     // 155 => [6144]
-            map.insert(156, &[262657]);     // 156 => [262657]
-            map.insert(157, &[262656]);     // 157 => [262656]
-            map.insert(158, &[131588]);     // 158 => [131588]
-            map.insert(159, &[262658]);     // 159 => [262658]
-            map.insert(160, &[288]);     // 160 => [288]
-            map.insert(161, &[289]);     // 161 => [289]
-            map.insert(162, &[290]);     // 162 => [290]
-            map.insert(163, &[65556]);     // 163 => [65556]
-            map.insert(164, &[17825792]);     // 164 => [17825792]
-            map.insert(165, &[17825793]);     // 165 => [17825793]
-            map.insert(166, &[65553]);     // 166 => [65553]
-            map.insert(167, &[65552]);     // 167 => [65552]
-            map.insert(168, &[33280]);     // 168 => [33280]
-            map.insert(169, &[33281]);     // 169 => [33281]
-            map.insert(170, &[33282]);     // 170 => [33282]
-            map.insert(171, &[17301508]);     // 171 => [17301508]
-            map.insert(172, &[67117058]);     // 172 => [67117058]
-            map.insert(173, &[17301506]);     // 173 => [17301506]
-            map.insert(174, &[67117056]);     // 174 => [67117056]
-            map.insert(175, &[17301504]);     // 175 => [17301504]
-            map.insert(176, &[304]);     // 176 => [304]
-            map.insert(177, &[65542]);     // 177 => [65542]
-            map.insert(178, &[65541]);     // 178 => [65541]
-            map.insert(179, &[65540]);     // 179 => [65540]
-            map.insert(180, &[65539]);     // 180 => [65539]
-            map.insert(181, &[65538]);     // 181 => [65538]
-            map.insert(182, &[65537]);     // 182 => [65537]
-            map.insert(183, &[65536]);     // 183 => [65536]
-            map.insert(184, &[324]);     // 184 => [324]
-            map.insert(185, &[67158016]);     // 185 => [67158016]
-            map.insert(186, &[131616]);     // 186 => [131616]
-            map.insert(187, &[65548]);     // 187 => [65548]
-            map.insert(188, &[320]);     // 188 => [320]
-            map.insert(189, &[321]);     // 189 => [321]
-            map.insert(190, &[65545]);     // 190 => [65545]
-            map.insert(191, &[65544]);     // 191 => [65544]
-            map.insert(192, &[384]);     // 192 => [384]
-            map.insert(193, &[385]);     // 193 => [385]
-            map.insert(194, &[386]);     // 194 => [386]
-            map.insert(195, &[10485768]);     // 195 => [10485768]
-            map.insert(196, &[1536]);     // 196 => [1536]
-            map.insert(197, &[1537]);     // 197 => [1537]
-            map.insert(198, &[1538]);     // 198 => [1538]
-            map.insert(199, &[20971584]);     // 199 => [20971584]
-            map.insert(200, &[67112961]);     // 200 => [67112961]
-            map.insert(201, &[67112960]);     // 201 => [67112960]
-            map.insert(202, &[10485761]);     // 202 => [10485761]
-            map.insert(203, &[10485760]);     // 203 => [10485760]
-            map.insert(204, &[1544]);     // 204 => [1544]
-            map.insert(205, &[67112964]);     // 205 => [67112964]
-            map.insert(206, &[16782336]);     // 206 => [16782336]
-            map.insert(207, &[10485764]);     // 207 => [10485764]
-            map.insert(208, &[16793602]);     // 208 => [16793602]
-            map.insert(209, &[8405024]);     // 209 => [8405024]
-            map.insert(210, &[16793600]);     // 210 => [16793600]
-            map.insert(211, &[16793601]);     // 211 => [16793601]
-            map.insert(212, &[1552]);     // 212 => [1552]
-            map.insert(213, &[67126272]);     // 213 => [67126272]
-            map.insert(214, &[16793604]);     // 214 => [16793604]
-            map.insert(215, &[65696]);     // 215 => [65696]
-            map.insert(216, &[12582912]);     // 216 => [12582912]
-            map.insert(217, &[12582913]);     // 217 => [12582913]
-            map.insert(218, &[12582914]);     // 218 => [12582914]
-            map.insert(219, &[10485776]);     // 219 => [10485776]
-            map.insert(220, &[12582916]);     // 220 => [12582916]
-            map.insert(221, &[262784]);     // 221 => [262784]
-            map.insert(222, &[132352]);     // 222 => [132352]
-            map.insert(223, &[5243136]);     // 223 => [5243136]
-            map.insert(224, &[18874376]);     // 224 => [18874376]
-            map.insert(225, &[8405008]);     // 225 => [8405008]
-            map.insert(226, &[2101760]);     // 226 => [2101760]
-            map.insert(227, &[2230272]);     // 227 => [2230272]
-            map.insert(228, &[1568]);     // 228 => [1568]
-            map.insert(229, &[67176448]);     // 229 => [67176448]
-            map.insert(230, &[33558544]);     // 230 => [33558544]
-            map.insert(231, &[65680]);     // 231 => [65680]
-            map.insert(232, &[18874368]);     // 232 => [18874368]
-            map.insert(233, &[18874369]);     // 233 => [18874369]
-            map.insert(234, &[18874370]);     // 234 => [18874370]
-            map.insert(235, &[20971536]);     // 235 => [20971536]
-            map.insert(236, &[18874372]);     // 236 => [18874372]
-            map.insert(237, &[33556736]);     // 237 => [33556736]
-            map.insert(238, &[16793664]);     // 238 => [16793664]
-            map.insert(239, &[17301632]);     // 239 => [17301632]
-            map.insert(240, &[8404993]);     // 240 => [8404993]
-            map.insert(241, &[8404992]);     // 241 => [8404992]
-            map.insert(242, &[33558532]);     // 242 => [33558532]
-            map.insert(243, &[8404994]);     // 243 => [8404994]
-            map.insert(244, &[33558530]);     // 244 => [33558530]
-            map.insert(245, &[65666]);     // 245 => [65666]
-            map.insert(246, &[33558528]);     // 246 => [33558528]
-            map.insert(247, &[65664]);     // 247 => [65664]
-            map.insert(248, &[10244]);     // 248 => [10244]
-            map.insert(249, &[20971522]);     // 249 => [20971522]
-            map.insert(250, &[20971521]);     // 250 => [20971521]
-            map.insert(251, &[20971520]);     // 251 => [20971520]
-            map.insert(252, &[10240]);     // 252 => [10240]
-            map.insert(253, &[10241]);     // 253 => [10241]
-            map.insert(254, &[10242]);     // 254 => [10242]
-            map.insert(255, &[20971524]);     // 255 => [20971524]
-            map.insert(256, &[512]);     // 256 => [512]
-            map.insert(257, &[513]);     // 257 => [513]
-            map.insert(258, &[514]);     // 258 => [514]
-            map.insert(259, &[515]);     // 259 => [515]
-            map.insert(260, &[516]);     // 260 => [516]
-            map.insert(261, &[517]);     // 261 => [517]
-            map.insert(262, &[37748737]);     // 262 => [37748737]
-            map.insert(263, &[37748736]);     // 263 => [37748736]
-            map.insert(264, &[520]);     // 264 => [520]
-            map.insert(265, &[521]);     // 265 => [521]
-            map.insert(266, &[16781312]);     // 266 => [16781312]
-            map.insert(267, &[16781313]);     // 267 => [16781313]
-            map.insert(268, &[524]);     // 268 => [524]
-            map.insert(269, &[262416]);     // 269 => [262416]
-            map.insert(270, &[16781316]);     // 270 => [16781316]
-            map.insert(271, &[37748744]);     // 271 => [37748744]
-            map.insert(272, &[528]);     // 272 => [528]
-            map.insert(273, &[67125248]);     // 273 => [67125248]
-            map.insert(274, &[530]);     // 274 => [530]
-            map.insert(275, &[67125250]);     // 275 => [67125250]
-            map.insert(276, &[35651584]);     // 276 => [35651584]
-            map.insert(277, &[35651585]);     // 277 => [35651585]
-            map.insert(278, &[35651586]);     // 278 => [35651586]
-            map.insert(279, &[98312]);     // 279 => [98312]
-            map.insert(280, &[131330]);     // 280 => [131330]
-            map.insert(281, &[262404]);     // 281 => [262404]
-            map.insert(282, &[131328]);     // 282 => [131328]
-            map.insert(283, &[131329]);     // 283 => [131329]
-            map.insert(284, &[262401]);     // 284 => [262401]
-            map.insert(285, &[262400]);     // 285 => [262400]
-            map.insert(286, &[98305]);     // 286 => [98305]
-            map.insert(287, &[98304]);     // 287 => [98304]
-            map.insert(288, &[544]);     // 288 => [544]
-            map.insert(289, &[545]);     // 289 => [545]
-            map.insert(290, &[546]);     // 290 => [546]
-            map.insert(291, &[69206024]);     // 291 => [69206024]
-            map.insert(292, &[548]);     // 292 => [548]
-            map.insert(293, &[196616]);     // 293 => [196616]
-            map.insert(294, &[33570824]);     // 294 => [33570824]
-            map.insert(295, &[37748768]);     // 295 => [37748768]
-            map.insert(296, &[33024]);     // 296 => [33024]
-            map.insert(297, &[8392704]);     // 297 => [8392704]
-            map.insert(298, &[327680]);     // 298 => [327680]
-            map.insert(299, &[69206016]);     // 299 => [69206016]
-            map.insert(300, &[196609]);     // 300 => [196609]
-            map.insert(301, &[196608]);     // 301 => [196608]
-            map.insert(302, &[33570816]);     // 302 => [33570816]
-            map.insert(303, &[33570817]);     // 303 => [33570817]
-            map.insert(304, &[71303176]);     // 304 => [71303176]
-            map.insert(305, &[1050628]);     // 305 => [1050628]
-            map.insert(306, &[8390912]);     // 306 => [8390912]
-            map.insert(307, &[66688]);     // 307 => [66688]
-            map.insert(308, &[1050625]);     // 308 => [1050625]
-            map.insert(309, &[1050624]);     // 309 => [1050624]
-            map.insert(310, &[526344]);     // 310 => [526344]
-            map.insert(311, &[1050626]);     // 311 => [1050626]
-            map.insert(312, &[71303168]);     // 312 => [71303168]
-            map.insert(313, &[71303169]);     // 313 => [71303169]
-            map.insert(314, &[71303170]);     // 314 => [71303170]
-            map.insert(315, &[69206032]);     // 315 => [69206032]
-            map.insert(316, &[576]);     // 316 => [576]
-            map.insert(317, &[577]);     // 317 => [577]
-            map.insert(318, &[526336]);     // 318 => [526336]
-            map.insert(319, &[526337]);     // 319 => [526337]
-            map.insert(320, &[640]);     // 320 => [640]
-            map.insert(321, &[641]);     // 321 => [641]
-            map.insert(322, &[18433]);     // 322 => [18433]
-            map.insert(323, &[18432]);     // 323 => [18432]
-            map.insert(324, &[1280]);     // 324 => [1280]
-            map.insert(325, &[1281]);     // 325 => [1281]
-            map.insert(326, &[1282]);     // 326 => [1282]
-            map.insert(327, &[18436]);     // 327 => [18436]
-            map.insert(328, &[648]);     // 328 => [648]
-            map.insert(329, &[1057024]);     // 329 => [1057024]
-            map.insert(330, &[8396804]);     // 330 => [8396804]
-            map.insert(331, &[18440]);     // 331 => [18440]
-            map.insert(332, &[8396802]);     // 332 => [8396802]
-            map.insert(333, &[16785440]);     // 333 => [16785440]
-            map.insert(334, &[8396800]);     // 334 => [8396800]
-            map.insert(335, &[8396801]);     // 335 => [8396801]
-            map.insert(336, &[34603016]);     // 336 => [34603016]
-            map.insert(337, &[34078722]);     // 337 => [34078722]
-            map.insert(338, &[34078721]);     // 338 => [34078721]
-            map.insert(339, &[34078720]);     // 339 => [34078720]
-            map.insert(340, &[1296]);     // 340 => [1296]
-            map.insert(341, &[33563648]);     // 341 => [33563648]
-            map.insert(342, &[4196416]);     // 342 => [4196416]
-            map.insert(343, &[34078724]);     // 343 => [34078724]
-            map.insert(344, &[34603008]);     // 344 => [34603008]
-            map.insert(345, &[34603009]);     // 345 => [34603009]
-            map.insert(346, &[34603010]);     // 346 => [34603010]
-            map.insert(347, &[34078728]);     // 347 => [34078728]
-            map.insert(348, &[34603012]);     // 348 => [34603012]
-            map.insert(349, &[262528]);     // 349 => [262528]
-            map.insert(350, &[8396816]);     // 350 => [8396816]
-            map.insert(351, &[98432]);     // 351 => [98432]
-            map.insert(352, &[672]);     // 352 => [672]
-            map.insert(353, &[272384]);     // 353 => [272384]
-            map.insert(354, &[4196360]);     // 354 => [4196360]
-            map.insert(355, &[68157444]);     // 355 => [68157444]
-            map.insert(356, &[67633160]);     // 356 => [67633160]
-            map.insert(357, &[68157442]);     // 357 => [68157442]
-            map.insert(358, &[68157441]);     // 358 => [68157441]
-            map.insert(359, &[68157440]);     // 359 => [68157440]
-            map.insert(360, &[4196354]);     // 360 => [4196354]
-            map.insert(361, &[16785412]);     // 361 => [16785412]
-            map.insert(362, &[4196352]);     // 362 => [4196352]
-            map.insert(363, &[4196353]);     // 363 => [4196353]
-            map.insert(364, &[67633152]);     // 364 => [67633152]
-            map.insert(365, &[16785408]);     // 365 => [16785408]
-            map.insert(366, &[67633154]);     // 366 => [67633154]
-            map.insert(367, &[16785410]);     // 367 => [16785410]
-            map.insert(368, &[12615680]);     // 368 => [12615680]
-            map.insert(369, &[66562]);     // 369 => [66562]
-            map.insert(370, &[66561]);     // 370 => [66561]
-            map.insert(371, &[66560]);     // 371 => [66560]
-            map.insert(372, &[83951616]);     // 372 => [83951616]
-            map.insert(373, &[1050752]);     // 373 => [1050752]
-            map.insert(374, &[1065472]);     // 374 => [1065472]
-            map.insert(375, &[66564]);     // 375 => [66564]
-            map.insert(376, &[2099201]);     // 376 => [2099201]
-            map.insert(377, &[2099200]);     // 377 => [2099200]
-            map.insert(378, &[4196368]);     // 378 => [4196368]
-            map.insert(379, &[2099202]);     // 379 => [2099202]
-            map.insert(380, &[67633168]);     // 380 => [67633168]
-            map.insert(381, &[2099204]);     // 381 => [2099204]
-            map.insert(382, &[526464]);     // 382 => [526464]
-            map.insert(383, &[18496]);     // 383 => [18496]
-            map.insert(384, &[768]);     // 384 => [768]
-            map.insert(385, &[769]);     // 385 => [769]
-            map.insert(386, &[770]);     // 386 => [770]
-            map.insert(387, &[24640]);     // 387 => [24640]
-            map.insert(388, &[1152]);     // 388 => [1152]
-            map.insert(389, &[2105344]);     // 389 => [2105344]
-            map.insert(390, &[1154]);     // 390 => [1154]
-            map.insert(391, &[2105346]);     // 391 => [2105346]
-            map.insert(392, &[32800]);     // 392 => [32800]
-            map.insert(393, &[32801]);     // 393 => [32801]
-            map.insert(394, &[131088]);     // 394 => [131088]
-            map.insert(395, &[131089]);     // 395 => [131089]
-            map.insert(396, &[262161]);     // 396 => [262161]
-            map.insert(397, &[262160]);     // 397 => [262160]
-            map.insert(398, &[131092]);     // 398 => [131092]
-            map.insert(399, &[262162]);     // 399 => [262162]
-            map.insert(400, &[16779265]);     // 400 => [16779265]
-            map.insert(401, &[16779264]);     // 401 => [16779264]
-            map.insert(402, &[131080]);     // 402 => [131080]
-            map.insert(403, &[131081]);     // 403 => [131081]
-            map.insert(404, &[32832]);     // 404 => [32832]
-            map.insert(405, &[262152]);     // 405 => [262152]
-            map.insert(406, &[4202496]);     // 406 => [4202496]
-            map.insert(407, &[4202497]);     // 407 => [4202497]
-            map.insert(408, &[131074]);     // 408 => [131074]
-            map.insert(409, &[262148]);     // 409 => [262148]
-            map.insert(410, &[131072]);     // 410 => [131072]
-            map.insert(411, &[131073]);     // 411 => [131073]
-            map.insert(412, &[262145]);     // 412 => [262145]
-            map.insert(413, &[262144]);     // 413 => [262144]
-            map.insert(414, &[131076]);     // 414 => [131076]
-            map.insert(415, &[262146]);     // 415 => [262146]
-            map.insert(416, &[32776]);     // 416 => [32776]
-            map.insert(417, &[262208]);     // 417 => [262208]
-            map.insert(418, &[32778]);     // 418 => [32778]
-            map.insert(419, &[262210]);     // 419 => [262210]
-            map.insert(420, &[528385]);     // 420 => [528385]
-            map.insert(421, &[528384]);     // 421 => [528384]
-            map.insert(422, &[131136]);     // 422 => [131136]
-            map.insert(423, &[131137]);     // 423 => [131137]
-            map.insert(424, &[32768]);     // 424 => [32768]
-            map.insert(425, &[32769]);     // 425 => [32769]
-            map.insert(426, &[32770]);     // 426 => [32770]
-            map.insert(427, &[32771]);     // 427 => [32771]
-            map.insert(428, &[32772]);     // 428 => [32772]
-            map.insert(429, &[32773]);     // 429 => [32773]
-            map.insert(430, &[1052672]);     // 430 => [1052672]
-            map.insert(431, &[1052673]);     // 431 => [1052673]
-            map.insert(432, &[8390658]);     // 432 => [8390658]
-            map.insert(433, &[262224]);     // 433 => [262224]
-            map.insert(434, &[8390656]);     // 434 => [8390656]
-            map.insert(435, &[8390657]);     // 435 => [8390657]
-            map.insert(436, &[32864]);     // 436 => [32864]
-            map.insert(437, &[66050]);     // 437 => [66050]
-            map.insert(438, &[66049]);     // 438 => [66049]
-            map.insert(439, &[66048]);     // 439 => [66048]
-            map.insert(440, &[32784]);     // 440 => [32784]
-            map.insert(441, &[32785]);     // 441 => [32785]
-            map.insert(442, &[131104]);     // 442 => [131104]
-            map.insert(443, &[131105]);     // 443 => [131105]
-            map.insert(444, &[262177]);     // 444 => [262177]
-            map.insert(445, &[262176]);     // 445 => [262176]
-            map.insert(446, &[24577]);     // 446 => [24577]
-            map.insert(447, &[24576]);     // 447 => [24576]
-            map.insert(448, &[1028]);     // 448 => [1028]
-            map.insert(449, &[1029]);     // 449 => [1029]
-            map.insert(450, &[532480]);     // 450 => [532480]
-            map.insert(451, &[83886080]);     // 451 => [83886080]
-            map.insert(452, &[1024]);     // 452 => [1024]
-            map.insert(453, &[1025]);     // 453 => [1025]
-            map.insert(454, &[1026]);     // 454 => [1026]
-            map.insert(455, &[1027]);     // 455 => [1027]
-            map.insert(456, &[1056769]);     // 456 => [1056769]
-            map.insert(457, &[1056768]);     // 457 => [1056768]
-            map.insert(458, &[532488]);     // 458 => [532488]
-            map.insert(459, &[1056770]);     // 459 => [1056770]
-            map.insert(460, &[1032]);     // 460 => [1032]
-            map.insert(461, &[1033]);     // 461 => [1033]
-            map.insert(462, &[1034]);     // 462 => [1034]
-            map.insert(463, &[41943056]);     // 463 => [41943056]
-            map.insert(464, &[1044]);     // 464 => [1044]
-            map.insert(465, &[4198432]);     // 465 => [4198432]
-            map.insert(466, &[131208]);     // 466 => [131208]
-            map.insert(467, &[83886096]);     // 467 => [83886096]
-            map.insert(468, &[1040]);     // 468 => [1040]
-            map.insert(469, &[1041]);     // 469 => [1041]
-            map.insert(470, &[1042]);     // 470 => [1042]
-            map.insert(471, &[41943048]);     // 471 => [41943048]
-            map.insert(472, &[20480]);     // 472 => [20480]
-            map.insert(473, &[20481]);     // 473 => [20481]
-            map.insert(474, &[131200]);     // 474 => [131200]
-            map.insert(475, &[131201]);     // 475 => [131201]
-            map.insert(476, &[262273]);     // 476 => [262273]
-            map.insert(477, &[262272]);     // 477 => [262272]
-            map.insert(478, &[41943041]);     // 478 => [41943041]
-            map.insert(479, &[41943040]);     // 479 => [41943040]
-            map.insert(480, &[75497472]);     // 480 => [75497472]
-            map.insert(481, &[75497473]);     // 481 => [75497473]
-            map.insert(482, &[2101248]);     // 482 => [2101248]
-            map.insert(483, &[2101249]);     // 483 => [2101249]
-            map.insert(484, &[1056]);     // 484 => [1056]
-            map.insert(485, &[1057]);     // 485 => [1057]
-            map.insert(486, &[1058]);     // 486 => [1058]
-            map.insert(487, &[68157696]);     // 487 => [68157696]
-            map.insert(488, &[32896]);     // 488 => [32896]
-            map.insert(489, &[32897]);     // 489 => [32897]
-            map.insert(490, &[32898]);     // 490 => [32898]
-            map.insert(491, &[1327104]);     // 491 => [1327104]
-            map.insert(492, &[32900]);     // 492 => [32900]
-            map.insert(493, &[16785664]);     // 493 => [16785664]
-            map.insert(494, &[1052800]);     // 494 => [1052800]
-            map.insert(495, &[2654208]);     // 495 => [2654208]
-            map.insert(496, &[4198401]);     // 496 => [4198401]
-            map.insert(497, &[4198400]);     // 497 => [4198400]
-            map.insert(498, &[2101264]);     // 498 => [2101264]
-            map.insert(499, &[4198402]);     // 499 => [4198402]
-            map.insert(500, &[50331656]);     // 500 => [50331656]
-            map.insert(501, &[4198404]);     // 501 => [4198404]
-            map.insert(502, &[33559040]);     // 502 => [33559040]
-            map.insert(503, &[66176]);     // 503 => [66176]
-            map.insert(504, &[1088]);     // 504 => [1088]
-            map.insert(505, &[1089]);     // 505 => [1089]
-            map.insert(506, &[1090]);     // 506 => [1090]
-            map.insert(507, &[20972032]);     // 507 => [20972032]
-            map.insert(508, &[50331648]);     // 508 => [50331648]
-            map.insert(509, &[50331649]);     // 509 => [50331649]
-            map.insert(510, &[50331650]);     // 510 => [50331650]
-            map.insert(511, &[41943072]);     // 511 => [41943072]
-            map.insert(512, &[2048]);     // 512 => [2048]
-            map.insert(513, &[2049]);     // 513 => [2049]
-            map.insert(514, &[2050]);     // 514 => [2050]
-            map.insert(515, &[2051]);     // 515 => [2051]
-            map.insert(516, &[2052]);     // 516 => [2052]
-            map.insert(517, &[2053]);     // 517 => [2053]
-            map.insert(518, &[2054]);     // 518 => [2054]
-            map.insert(519, &[17664]);     // 519 => [17664]
-            map.insert(520, &[2056]);     // 520 => [2056]
-            map.insert(521, &[2057]);     // 521 => [2057]
-            map.insert(522, &[16908289]);     // 522 => [16908289]
-            map.insert(523, &[16908288]);     // 523 => [16908288]
-            map.insert(524, &[17039360]);     // 524 => [17039360]
-            map.insert(525, &[17039361]);     // 525 => [17039361]
-            map.insert(526, &[17039362]);     // 526 => [17039362]
-            map.insert(527, &[16908292]);     // 527 => [16908292]
-            map.insert(528, &[2064]);     // 528 => [2064]
-            map.insert(529, &[2065]);     // 529 => [2065]
-            map.insert(530, &[67108992]);     // 530 => [67108992]
-            map.insert(531, &[67108993]);     // 531 => [67108993]
-            map.insert(532, &[2068]);     // 532 => [2068]
-            map.insert(533, &[1049120]);     // 533 => [1049120]
-            map.insert(534, &[67108996]);     // 534 => [67108996]
-            map.insert(535, &[8916992]);     // 535 => [8916992]
-            map.insert(536, &[8421378]);     // 536 => [8421378]
-            map.insert(537, &[4354]);     // 537 => [4354]
-            map.insert(538, &[8421376]);     // 538 => [8421376]
-            map.insert(539, &[4352]);     // 539 => [4352]
-            map.insert(540, &[17039376]);     // 540 => [17039376]
-            map.insert(541, &[1081600]);     // 541 => [1081600]
-            map.insert(542, &[8421380]);     // 542 => [8421380]
-            map.insert(543, &[4356]);     // 543 => [4356]
-            map.insert(544, &[2080]);     // 544 => [2080]
-            map.insert(545, &[2081]);     // 545 => [2081]
-            map.insert(546, &[2082]);     // 546 => [2082]
-            map.insert(547, &[786688]);     // 547 => [786688]
-            map.insert(548, &[2084]);     // 548 => [2084]
-            map.insert(549, &[33554568]);     // 549 => [33554568]
-            map.insert(550, &[8421440]);     // 550 => [8421440]
-            map.insert(551, &[8650760]);     // 551 => [8650760]
-            map.insert(552, &[8519680]);     // 552 => [8519680]
-            map.insert(553, &[8519681]);     // 553 => [8519681]
-            map.insert(554, &[8519682]);     // 554 => [8519682]
-            map.insert(555, &[8650756]);     // 555 => [8650756]
-            map.insert(556, &[69632]);     // 556 => [69632]
-            map.insert(557, &[33554560]);     // 557 => [33554560]
-            map.insert(558, &[8650753]);     // 558 => [8650753]
-            map.insert(559, &[8650752]);     // 559 => [8650752]
-            map.insert(560, &[2096]);     // 560 => [2096]
-            map.insert(561, &[1049092]);     // 561 => [1049092]
-            map.insert(562, &[67109024]);     // 562 => [67109024]
-            map.insert(563, &[37376]);     // 563 => [37376]
-            map.insert(564, &[1049089]);     // 564 => [1049089]
-            map.insert(565, &[1049088]);     // 565 => [1049088]
-            map.insert(566, &[524808]);     // 566 => [524808]
-            map.insert(567, &[1049090]);     // 567 => [1049090]
-            map.insert(568, &[16809985]);     // 568 => [16809985]
-            map.insert(569, &[16809984]);     // 569 => [16809984]
-            map.insert(570, &[524804]);     // 570 => [524804]
-            map.insert(571, &[16809986]);     // 571 => [16809986]
-            map.insert(572, &[2112]);     // 572 => [2112]
-            map.insert(573, &[2113]);     // 573 => [2113]
-            map.insert(574, &[524800]);     // 574 => [524800]
-            map.insert(575, &[524801]);     // 575 => [524801]
-            map.insert(576, &[2176]);     // 576 => [2176]
-            map.insert(577, &[2177]);     // 577 => [2177]
-            map.insert(578, &[67108880]);     // 578 => [67108880]
-            map.insert(579, &[16896]);     // 579 => [16896]
-            map.insert(580, &[2180]);     // 580 => [2180]
-            map.insert(581, &[33554472]);     // 581 => [33554472]
-            map.insert(582, &[67108884]);     // 582 => [67108884]
-            map.insert(583, &[16900]);     // 583 => [16900]
-            map.insert(584, &[2184]);     // 584 => [2184]
-            map.insert(585, &[73730]);     // 585 => [73730]
-            map.insert(586, &[73729]);     // 586 => [73729]
-            map.insert(587, &[73728]);     // 587 => [73728]
-            map.insert(588, &[33554465]);     // 588 => [33554465]
-            map.insert(589, &[33554464]);     // 589 => [33554464]
-            map.insert(590, &[67108960]);     // 590 => [67108960]
-            map.insert(591, &[33554466]);     // 591 => [33554466]
-            map.insert(592, &[67108866]);     // 592 => [67108866]
-            map.insert(593, &[33554496]);     // 593 => [33554496]
-            map.insert(594, &[67108864]);     // 594 => [67108864]
-            map.insert(595, &[67108865]);     // 595 => [67108865]
-            map.insert(596, &[16778241]);     // 596 => [16778241]
-            map.insert(597, &[16778240]);     // 597 => [16778240]
-            map.insert(598, &[67108868]);     // 598 => [67108868]
-            map.insert(599, &[67108869]);     // 599 => [67108869]
-            map.insert(600, &[67108874]);     // 600 => [67108874]
-            map.insert(601, &[33554504]);     // 601 => [33554504]
-            map.insert(602, &[67108872]);     // 602 => [67108872]
-            map.insert(603, &[67108873]);     // 603 => [67108873]
-            map.insert(604, &[8480]);     // 604 => [8480]
-            map.insert(605, &[16778248]);     // 605 => [16778248]
-            map.insert(606, &[67108876]);     // 606 => [67108876]
-            map.insert(607, &[5632]);     // 607 => [5632]
-            map.insert(608, &[2208]);     // 608 => [2208]
-            map.insert(609, &[33554444]);     // 609 => [33554444]
-            map.insert(610, &[4194824]);     // 610 => [4194824]
-            map.insert(611, &[16928]);     // 611 => [16928]
-            map.insert(612, &[33554441]);     // 612 => [33554441]
-            map.insert(613, &[33554440]);     // 613 => [33554440]
-            map.insert(614, &[67108936]);     // 614 => [67108936]
-            map.insert(615, &[33554442]);     // 615 => [33554442]
-            map.insert(616, &[33554437]);     // 616 => [33554437]
-            map.insert(617, &[33554436]);     // 617 => [33554436]
-            map.insert(618, &[4194816]);     // 618 => [4194816]
-            map.insert(619, &[4194817]);     // 619 => [4194817]
-            map.insert(620, &[33554433]);     // 620 => [33554433]
-            map.insert(621, &[33554432]);     // 621 => [33554432]
-            map.insert(622, &[67108928]);     // 622 => [67108928]
-            map.insert(623, &[33554434]);     // 623 => [33554434]
-            map.insert(624, &[67108898]);     // 624 => [67108898]
-            map.insert(625, &[2097672]);     // 625 => [2097672]
-            map.insert(626, &[67108896]);     // 626 => [67108896]
-            map.insert(627, &[67108897]);     // 627 => [67108897]
-            map.insert(628, &[8389634]);     // 628 => [8389634]
-            map.insert(629, &[33554456]);     // 629 => [33554456]
-            map.insert(630, &[8389632]);     // 630 => [8389632]
-            map.insert(631, &[8389633]);     // 631 => [8389633]
-            map.insert(632, &[2097665]);     // 632 => [2097665]
-            map.insert(633, &[2097664]);     // 633 => [2097664]
-            map.insert(634, &[67108904]);     // 634 => [67108904]
-            map.insert(635, &[2097666]);     // 635 => [2097666]
-            map.insert(636, &[8448]);     // 636 => [8448]
-            map.insert(637, &[33554448]);     // 637 => [33554448]
-            map.insert(638, &[8450]);     // 638 => [8450]
-            map.insert(639, &[33554450]);     // 639 => [33554450]
-            map.insert(640, &[2304]);     // 640 => [2304]
-            map.insert(641, &[2305]);     // 641 => [2305]
-            map.insert(642, &[2306]);     // 642 => [2306]
-            map.insert(643, &[17412]);     // 643 => [17412]
-            map.insert(644, &[2308]);     // 644 => [2308]
-            map.insert(645, &[17410]);     // 645 => [17410]
-            map.insert(646, &[17409]);     // 646 => [17409]
-            map.insert(647, &[17408]);     // 647 => [17408]
-            map.insert(648, &[2312]);     // 648 => [2312]
-            map.insert(649, &[4114]);     // 649 => [4114]
-            map.insert(650, &[4113]);     // 650 => [4113]
-            map.insert(651, &[4112]);     // 651 => [4112]
-            map.insert(652, &[17039616]);     // 652 => [17039616]
-            map.insert(653, &[1081360]);     // 653 => [1081360]
-            map.insert(654, &[4195360]);     // 654 => [4195360]
-            map.insert(655, &[4116]);     // 655 => [4116]
-            map.insert(656, &[16777729]);     // 656 => [16777729]
-            map.insert(657, &[16777728]);     // 657 => [16777728]
-            map.insert(658, &[4105]);     // 658 => [4105]
-            map.insert(659, &[4104]);     // 659 => [4104]
-            map.insert(660, &[557058]);     // 660 => [557058]
-            map.insert(661, &[16777732]);     // 661 => [16777732]
-            map.insert(662, &[557056]);     // 662 => [557056]
-            map.insert(663, &[557057]);     // 663 => [557057]
-            map.insert(664, &[4099]);     // 664 => [4099]
-            map.insert(665, &[4098]);     // 665 => [4098]
-            map.insert(666, &[4097]);     // 666 => [4097]
-            map.insert(667, &[4096]);     // 667 => [4096]
-            map.insert(668, &[1081345]);     // 668 => [1081345]
-            map.insert(669, &[1081344]);     // 669 => [1081344]
-            map.insert(670, &[4101]);     // 670 => [4101]
-            map.insert(671, &[4100]);     // 671 => [4100]
-            map.insert(672, &[655364]);     // 672 => [655364]
-            map.insert(673, &[786434]);     // 673 => [786434]
-            map.insert(674, &[786433]);     // 674 => [786433]
-            map.insert(675, &[786432]);     // 675 => [786432]
-            map.insert(676, &[655360]);     // 676 => [655360]
-            map.insert(677, &[655361]);     // 677 => [655361]
-            map.insert(678, &[4161]);     // 678 => [4161]
-            map.insert(679, &[4160]);     // 679 => [4160]
-            map.insert(680, &[1310720]);     // 680 => [1310720]
-            map.insert(681, &[1310721]);     // 681 => [1310721]
-            map.insert(682, &[1310722]);     // 682 => [1310722]
-            map.insert(683, &[1179652]);     // 683 => [1179652]
-            map.insert(684, &[4195330]);     // 684 => [4195330]
-            map.insert(685, &[1179650]);     // 685 => [1179650]
-            map.insert(686, &[4195328]);     // 686 => [4195328]
-            map.insert(687, &[1179648]);     // 687 => [1179648]
-            map.insert(688, &[8389122]);     // 688 => [8389122]
-            map.insert(689, &[16777760]);     // 689 => [16777760]
-            map.insert(690, &[8389120]);     // 690 => [8389120]
-            map.insert(691, &[8389121]);     // 691 => [8389121]
-            map.insert(692, &[8328]);     // 692 => [8328]
-            map.insert(693, &[67586]);     // 693 => [67586]
-            map.insert(694, &[67585]);     // 694 => [67585]
-            map.insert(695, &[67584]);     // 695 => [67584]
-            map.insert(696, &[8324]);     // 696 => [8324]
-            map.insert(697, &[4130]);     // 697 => [4130]
-            map.insert(698, &[4129]);     // 698 => [4129]
-            map.insert(699, &[4128]);     // 699 => [4128]
-            map.insert(700, &[8320]);     // 700 => [8320]
-            map.insert(701, &[2098176]);     // 701 => [2098176]
-            map.insert(702, &[8322]);     // 702 => [8322]
-            map.insert(703, &[2098178]);     // 703 => [2098178]
-            map.insert(704, &[8256]);     // 704 => [8256]
-            map.insert(705, &[8257]);     // 705 => [8257]
-            map.insert(706, &[4227072]);     // 706 => [4227072]
-            map.insert(707, &[4227073]);     // 707 => [4227073]
-            map.insert(708, &[8260]);     // 708 => [8260]
-            map.insert(709, &[67174432]);     // 709 => [67174432]
-            map.insert(710, &[4227076]);     // 710 => [4227076]
-            map.insert(711, &[17536]);     // 711 => [17536]
-            map.insert(712, &[8264]);     // 712 => [8264]
-            map.insert(713, &[147472]);     // 713 => [147472]
-            map.insert(714, &[4227080]);     // 714 => [4227080]
-            map.insert(715, &[4240]);     // 715 => [4240]
-            map.insert(716, &[8240]);     // 716 => [8240]
-            map.insert(717, &[33554720]);     // 717 => [33554720]
-            map.insert(718, &[278544]);     // 718 => [278544]
-            map.insert(719, &[67371520]);     // 719 => [67371520]
-            map.insert(720, &[2129921]);     // 720 => [2129921]
-            map.insert(721, &[2129920]);     // 721 => [2129920]
-            map.insert(722, &[67109120]);     // 722 => [67109120]
-            map.insert(723, &[67109121]);     // 723 => [67109121]
-            map.insert(724, &[8232]);     // 724 => [8232]
-            map.insert(725, &[2129924]);     // 725 => [2129924]
-            map.insert(726, &[67109124]);     // 726 => [67109124]
-            map.insert(727, &[4456480]);     // 727 => [4456480]
-            map.insert(728, &[147457]);     // 728 => [147457]
-            map.insert(729, &[147456]);     // 729 => [147456]
-            map.insert(730, &[33619968]);     // 730 => [33619968]
-            map.insert(731, &[4224]);     // 731 => [4224]
-            map.insert(732, &[8224]);     // 732 => [8224]
-            map.insert(733, &[8225]);     // 733 => [8225]
-            map.insert(734, &[278528]);     // 734 => [278528]
-            map.insert(735, &[278529]);     // 735 => [278529]
-            map.insert(736, &[2359300]);     // 736 => [2359300]
-            map.insert(737, &[2228226]);     // 737 => [2228226]
-            map.insert(738, &[2228225]);     // 738 => [2228225]
-            map.insert(739, &[2228224]);     // 739 => [2228224]
-            map.insert(740, &[2359296]);     // 740 => [2359296]
-            map.insert(741, &[67174400]);     // 741 => [67174400]
-            map.insert(742, &[2359298]);     // 742 => [2359298]
-            map.insert(743, &[67174402]);     // 743 => [67174402]
-            map.insert(744, &[8212]);     // 744 => [8212]
-            map.insert(745, &[49154]);     // 745 => [49154]
-            map.insert(746, &[49153]);     // 746 => [49153]
-            map.insert(747, &[49152]);     // 747 => [49152]
-            map.insert(748, &[8208]);     // 748 => [8208]
-            map.insert(749, &[33554688]);     // 749 => [33554688]
-            map.insert(750, &[8210]);     // 750 => [8210]
-            map.insert(751, &[33554690]);     // 751 => [33554690]
-            map.insert(752, &[4325376]);     // 752 => [4325376]
-            map.insert(753, &[1049600]);     // 753 => [1049600]
-            map.insert(754, &[4325378]);     // 754 => [4325378]
-            map.insert(755, &[1049602]);     // 755 => [1049602]
-            map.insert(756, &[8200]);     // 756 => [8200]
-            map.insert(757, &[8201]);     // 757 => [8201]
-            map.insert(758, &[4456449]);     // 758 => [4456449]
-            map.insert(759, &[4456448]);     // 759 => [4456448]
-            map.insert(760, &[8196]);     // 760 => [8196]
-            map.insert(761, &[8197]);     // 761 => [8197]
-            map.insert(762, &[525312]);     // 762 => [525312]
-            map.insert(763, &[525313]);     // 763 => [525313]
-            map.insert(764, &[8192]);     // 764 => [8192]
-            map.insert(765, &[8193]);     // 765 => [8193]
-            map.insert(766, &[8194]);     // 766 => [8194]
-            map.insert(767, &[8195]);     // 767 => [8195]
-            map.insert(768, &[2560]);     // 768 => [2560]
-            map.insert(769, &[135168]);     // 769 => [135168]
-            map.insert(770, &[524352]);     // 770 => [524352]
-            map.insert(771, &[16512]);     // 771 => [16512]
-            map.insert(772, &[8454145]);     // 772 => [8454145]
-            map.insert(773, &[8454144]);     // 773 => [8454144]
-            map.insert(774, &[266240]);     // 774 => [266240]
-            map.insert(775, &[266241]);     // 775 => [266241]
-            map.insert(776, &[1048641]);     // 776 => [1048641]
-            map.insert(777, &[1048640]);     // 777 => [1048640]
-            map.insert(778, &[524360]);     // 778 => [524360]
-            map.insert(779, &[1048642]);     // 779 => [1048642]
-            map.insert(780, &[17039872]);     // 780 => [17039872]
-            map.insert(781, &[1048644]);     // 781 => [1048644]
-            map.insert(782, &[266248]);     // 782 => [266248]
-            map.insert(783, &[36928]);     // 783 => [36928]
-            map.insert(784, &[16777473]);     // 784 => [16777473]
-            map.insert(785, &[16777472]);     // 785 => [16777472]
-            map.insert(786, &[524368]);     // 786 => [524368]
-            map.insert(787, &[16777474]);     // 787 => [16777474]
-            map.insert(788, &[1048609]);     // 788 => [1048609]
-            map.insert(789, &[1048608]);     // 789 => [1048608]
-            map.insert(790, &[524328]);     // 790 => [524328]
-            map.insert(791, &[1048610]);     // 791 => [1048610]
-            map.insert(792, &[9248]);     // 792 => [9248]
-            map.insert(793, &[16777480]);     // 793 => [16777480]
-            map.insert(794, &[524324]);     // 794 => [524324]
-            map.insert(795, &[4864]);     // 795 => [4864]
-            map.insert(796, &[524322]);     // 796 => [524322]
-            map.insert(797, &[1048616]);     // 797 => [1048616]
-            map.insert(798, &[524320]);     // 798 => [524320]
-            map.insert(799, &[524321]);     // 799 => [524321]
-            map.insert(800, &[2592]);     // 800 => [2592]
-            map.insert(801, &[1048596]);     // 801 => [1048596]
-            map.insert(802, &[16842756]);     // 802 => [16842756]
-            map.insert(803, &[36880]);     // 803 => [36880]
-            map.insert(804, &[1048593]);     // 804 => [1048593]
-            map.insert(805, &[1048592]);     // 805 => [1048592]
-            map.insert(806, &[16842752]);     // 806 => [16842752]
-            map.insert(807, &[16842753]);     // 807 => [16842753]
-            map.insert(808, &[4194434]);     // 808 => [4194434]
-            map.insert(809, &[2097296]);     // 809 => [2097296]
-            map.insert(810, &[4194432]);     // 810 => [4194432]
-            map.insert(811, &[4194433]);     // 811 => [4194433]
-            map.insert(812, &[524306]);     // 812 => [524306]
-            map.insert(813, &[1048600]);     // 813 => [1048600]
-            map.insert(814, &[524304]);     // 814 => [524304]
-            map.insert(815, &[524305]);     // 815 => [524305]
-            map.insert(816, &[1048581]);     // 816 => [1048581]
-            map.insert(817, &[1048580]);     // 817 => [1048580]
-            map.insert(818, &[8388864]);     // 818 => [8388864]
-            map.insert(819, &[36864]);     // 819 => [36864]
-            map.insert(820, &[1048577]);     // 820 => [1048577]
-            map.insert(821, &[1048576]);     // 821 => [1048576]
-            map.insert(822, &[524296]);     // 822 => [524296]
-            map.insert(823, &[1048578]);     // 823 => [1048578]
-            map.insert(824, &[9216]);     // 824 => [9216]
-            map.insert(825, &[2097280]);     // 825 => [2097280]
-            map.insert(826, &[524292]);     // 826 => [524292]
-            map.insert(827, &[524293]);     // 827 => [524293]
-            map.insert(828, &[524290]);     // 828 => [524290]
-            map.insert(829, &[1048584]);     // 829 => [1048584]
-            map.insert(830, &[524288]);     // 830 => [524288]
-            map.insert(831, &[524289]);     // 831 => [524289]
-            map.insert(832, &[16387]);     // 832 => [16387]
-            map.insert(833, &[16386]);     // 833 => [16386]
-            map.insert(834, &[16385]);     // 834 => [16385]
-            map.insert(835, &[16384]);     // 835 => [16384]
-            map.insert(836, &[2097217]);     // 836 => [2097217]
-            map.insert(837, &[2097216]);     // 837 => [2097216]
-            map.insert(838, &[16389]);     // 838 => [16389]
-            map.insert(839, &[16388]);     // 839 => [16388]
-            map.insert(840, &[4194338]);     // 840 => [4194338]
-            map.insert(841, &[16394]);     // 841 => [16394]
-            map.insert(842, &[4194336]);     // 842 => [4194336]
-            map.insert(843, &[16392]);     // 843 => [16392]
-            map.insert(844, &[2392064]);     // 844 => [2392064]
-            map.insert(845, &[2097224]);     // 845 => [2097224]
-            map.insert(846, &[4194340]);     // 846 => [4194340]
-            map.insert(847, &[16396]);     // 847 => [16396]
-            map.insert(848, &[67109378]);     // 848 => [67109378]
-            map.insert(849, &[16402]);     // 849 => [16402]
-            map.insert(850, &[67109376]);     // 850 => [67109376]
-            map.insert(851, &[16400]);     // 851 => [16400]
-            map.insert(852, &[40960]);     // 852 => [40960]
-            map.insert(853, &[40961]);     // 853 => [40961]
-            map.insert(854, &[4194368]);     // 854 => [4194368]
-            map.insert(855, &[4194369]);     // 855 => [4194369]
-            map.insert(856, &[2097185]);     // 856 => [2097185]
-            map.insert(857, &[2097184]);     // 857 => [2097184]
-            map.insert(858, &[67109384]);     // 858 => [67109384]
-            map.insert(859, &[2097186]);     // 859 => [2097186]
-            map.insert(860, &[40968]);     // 860 => [40968]
-            map.insert(861, &[5122]);     // 861 => [5122]
-            map.insert(862, &[5121]);     // 862 => [5121]
-            map.insert(863, &[5120]);     // 863 => [5120]
-            map.insert(864, &[270337]);     // 864 => [270337]
-            map.insert(865, &[270336]);     // 865 => [270336]
-            map.insert(866, &[4194312]);     // 866 => [4194312]
-            map.insert(867, &[16416]);     // 867 => [16416]
-            map.insert(868, &[139266]);     // 868 => [139266]
-            map.insert(869, &[270340]);     // 869 => [270340]
-            map.insert(870, &[139264]);     // 870 => [139264]
-            map.insert(871, &[139265]);     // 871 => [139265]
-            map.insert(872, &[4194306]);     // 872 => [4194306]
-            map.insert(873, &[2097168]);     // 873 => [2097168]
-            map.insert(874, &[4194304]);     // 874 => [4194304]
-            map.insert(875, &[4194305]);     // 875 => [4194305]
-            map.insert(876, &[33554945]);     // 876 => [33554945]
-            map.insert(877, &[33554944]);     // 877 => [33554944]
-            map.insert(878, &[4194308]);     // 878 => [4194308]
-            map.insert(879, &[4194309]);     // 879 => [4194309]
-            map.insert(880, &[2097161]);     // 880 => [2097161]
-            map.insert(881, &[2097160]);     // 881 => [2097160]
-            map.insert(882, &[4194328]);     // 882 => [4194328]
-            map.insert(883, &[2097162]);     // 883 => [2097162]
-            map.insert(884, &[1048705]);     // 884 => [1048705]
-            map.insert(885, &[1048704]);     // 885 => [1048704]
-            map.insert(886, &[524424]);     // 886 => [524424]
-            map.insert(887, &[1048706]);     // 887 => [1048706]
-            map.insert(888, &[2097153]);     // 888 => [2097153]
-            map.insert(889, &[2097152]);     // 889 => [2097152]
-            map.insert(890, &[4194320]);     // 890 => [4194320]
-            map.insert(891, &[2097154]);     // 891 => [2097154]
-            map.insert(892, &[2097157]);     // 892 => [2097157]
-            map.insert(893, &[2097156]);     // 893 => [2097156]
-            map.insert(894, &[524416]);     // 894 => [524416]
-            map.insert(895, &[16448]);     // 895 => [16448]
-            map.insert(896, &[16777233]);     // 896 => [16777233]
-            map.insert(897, &[16777232]);     // 897 => [16777232]
-            map.insert(898, &[1114112]);     // 898 => [1114112]
-            map.insert(899, &[1114113]);     // 899 => [1114113]
-            map.insert(900, &[3200]);     // 900 => [3200]
-            map.insert(901, &[16777236]);     // 901 => [16777236]
-            map.insert(902, &[1114116]);     // 902 => [1114116]
-            map.insert(903, &[17920]);     // 903 => [17920]
-            map.insert(904, &[589825]);     // 904 => [589825]
-            map.insert(905, &[589824]);     // 905 => [589824]
-            map.insert(906, &[8388676]);     // 906 => [8388676]
-            map.insert(907, &[589826]);     // 907 => [589826]
-            map.insert(908, &[8388674]);     // 908 => [8388674]
-            map.insert(909, &[589828]);     // 909 => [589828]
-            map.insert(910, &[8388672]);     // 910 => [8388672]
-            map.insert(911, &[8388673]);     // 911 => [8388673]
-            map.insert(912, &[16777217]);     // 912 => [16777217]
-            map.insert(913, &[16777216]);     // 913 => [16777216]
-            map.insert(914, &[8388640]);     // 914 => [8388640]
-            map.insert(915, &[16777218]);     // 915 => [16777218]
-            map.insert(916, &[16777221]);     // 916 => [16777221]
-            map.insert(917, &[16777220]);     // 917 => [16777220]
-            map.insert(918, &[67109888]);     // 918 => [67109888]
-            map.insert(919, &[67109889]);     // 919 => [67109889]
-            map.insert(920, &[16777225]);     // 920 => [16777225]
-            map.insert(921, &[16777224]);     // 921 => [16777224]
-            map.insert(922, &[133120]);     // 922 => [133120]
-            map.insert(923, &[4608]);     // 923 => [4608]
-            map.insert(924, &[264193]);     // 924 => [264193]
-            map.insert(925, &[264192]);     // 925 => [264192]
-            map.insert(926, &[133124]);     // 926 => [133124]
-            map.insert(927, &[264194]);     // 927 => [264194]
-            map.insert(928, &[8388626]);     // 928 => [8388626]
-            map.insert(929, &[33555464]);     // 929 => [33555464]
-            map.insert(930, &[8388624]);     // 930 => [8388624]
-            map.insert(931, &[8388625]);     // 931 => [8388625]
-            map.insert(932, &[655872]);     // 932 => [655872]
-            map.insert(933, &[16777288]);     // 933 => [16777288]
-            map.insert(934, &[8388628]);     // 934 => [8388628]
-            map.insert(935, &[4672]);     // 935 => [4672]
-            map.insert(936, &[34816]);     // 936 => [34816]
-            map.insert(937, &[33555456]);     // 937 => [33555456]
-            map.insert(938, &[34818]);     // 938 => [34818]
-            map.insert(939, &[33555458]);     // 939 => [33555458]
-            map.insert(940, &[16777281]);     // 940 => [16777281]
-            map.insert(941, &[16777280]);     // 941 => [16777280]
-            map.insert(942, &[524560]);     // 942 => [524560]
-            map.insert(943, &[16777282]);     // 943 => [16777282]
-            map.insert(944, &[8388610]);     // 944 => [8388610]
-            map.insert(945, &[16777248]);     // 945 => [16777248]
-            map.insert(946, &[8388608]);     // 946 => [8388608]
-            map.insert(947, &[8388609]);     // 947 => [8388609]
-            map.insert(948, &[1048833]);     // 948 => [1048833]
-            map.insert(949, &[1048832]);     // 949 => [1048832]
-            map.insert(950, &[8388612]);     // 950 => [8388612]
-            map.insert(951, &[8388613]);     // 951 => [8388613]
-            map.insert(952, &[8388618]);     // 952 => [8388618]
-            map.insert(953, &[16777256]);     // 953 => [16777256]
-            map.insert(954, &[8388616]);     // 954 => [8388616]
-            map.insert(955, &[8388617]);     // 955 => [8388617]
-            map.insert(956, &[524546]);     // 956 => [524546]
-            map.insert(957, &[1048840]);     // 957 => [1048840]
-            map.insert(958, &[524544]);     // 958 => [524544]
-            map.insert(959, &[524545]);     // 959 => [524545]
-            map.insert(960, &[3076]);     // 960 => [3076]
-            map.insert(961, &[16642]);     // 961 => [16642]
-            map.insert(962, &[16641]);     // 962 => [16641]
-            map.insert(963, &[16640]);     // 963 => [16640]
-            map.insert(964, &[3072]);     // 964 => [3072]
-            map.insert(965, &[33587200]);     // 965 => [33587200]
-            map.insert(966, &[3074]);     // 966 => [3074]
-            map.insert(967, &[33587202]);     // 967 => [33587202]
-            map.insert(968, &[67239936]);     // 968 => [67239936]
-            map.insert(969, &[67239937]);     // 969 => [67239937]
-            map.insert(970, &[67239938]);     // 970 => [67239938]
-            map.insert(971, &[67371012]);     // 971 => [67371012]
-            map.insert(972, &[2162690]);     // 972 => [2162690]
-            map.insert(973, &[67371010]);     // 973 => [67371010]
-            map.insert(974, &[2162688]);     // 974 => [2162688]
-            map.insert(975, &[67371008]);     // 975 => [67371008]
-            map.insert(976, &[16777345]);     // 976 => [16777345]
-            map.insert(977, &[16777344]);     // 977 => [16777344]
-            map.insert(978, &[8388768]);     // 978 => [8388768]
-            map.insert(979, &[16777346]);     // 979 => [16777346]
-            map.insert(980, &[3088]);     // 980 => [3088]
-            map.insert(981, &[16777348]);     // 981 => [16777348]
-            map.insert(982, &[4194624]);     // 982 => [4194624]
-            map.insert(983, &[33685536]);     // 983 => [33685536]
-            map.insert(984, &[67239952]);     // 984 => [67239952]
-            map.insert(985, &[4259844]);     // 985 => [4259844]
-            map.insert(986, &[67141664]);     // 986 => [67141664]
-            map.insert(987, &[4736]);     // 987 => [4736]
-            map.insert(988, &[4259841]);     // 988 => [4259841]
-            map.insert(989, &[4259840]);     // 989 => [4259840]
-            map.insert(990, &[2162704]);     // 990 => [2162704]
-            map.insert(991, &[4259842]);     // 991 => [4259842]
-            map.insert(992, &[33816592]);     // 992 => [33816592]
-            map.insert(993, &[4259904]);     // 993 => [4259904]
-            map.insert(994, &[4194568]);     // 994 => [4194568]
-            map.insert(995, &[16672]);     // 995 => [16672]
-            map.insert(996, &[81936]);     // 996 => [81936]
-            map.insert(997, &[33587232]);     // 997 => [33587232]
-            map.insert(998, &[139520]);     // 998 => [139520]
-            map.insert(999, &[33685520]);     // 999 => [33685520]
-            map.insert(1000, &[4194562]);     // 1000 => [4194562]
-            map.insert(1001, &[2097424]);     // 1001 => [2097424]
-            map.insert(1002, &[4194560]);     // 1002 => [4194560]
-            map.insert(1003, &[4194561]);     // 1003 => [4194561]
-            map.insert(1004, &[8720]);     // 1004 => [8720]
-            map.insert(1005, &[16777408]);     // 1005 => [16777408]
-            map.insert(1006, &[4194564]);     // 1006 => [4194564]
-            map.insert(1007, &[67371040]);     // 1007 => [67371040]
-            map.insert(1008, &[33816576]);     // 1008 => [33816576]
-            map.insert(1009, &[33816577]);     // 1009 => [33816577]
-            map.insert(1010, &[8388736]);     // 1010 => [8388736]
-            map.insert(1011, &[8388737]);     // 1011 => [8388737]
-            map.insert(1012, &[81920]);     // 1012 => [81920]
-            map.insert(1013, &[81921]);     // 1013 => [81921]
-            map.insert(1014, &[33685505]);     // 1014 => [33685505]
-            map.insert(1015, &[33685504]);     // 1015 => [33685504]
-            map.insert(1016, &[2097409]);     // 1016 => [2097409]
-            map.insert(1017, &[2097408]);     // 1017 => [2097408]
-            map.insert(1018, &[67141632]);     // 1018 => [67141632]
-            map.insert(1019, &[67141633]);     // 1019 => [67141633]
-            map.insert(1020, &[8704]);     // 1020 => [8704]
-            map.insert(1021, &[8705]);     // 1021 => [8705]
-            map.insert(1022, &[8706]);     // 1022 => [8706]
-            map.insert(1023, &[33685512]);     // 1023 => [33685512]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 53825537 ],
+            &[ 80433154 ],
+            &[ 35464260 ],
+            &[ 40808520 ],
+            &[ 96415824 ],
+            &[ 49913952 ],
+            &[ 106980480 ],
+            &[ 25670912 ],
+            &[ 33474048 ],
+            &[ 133724160 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 27)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(1024, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(7, &[393216]);     // 7 => [393216]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(11, &[1572864]);     // 11 => [1572864]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(15, &[1572868]);     // 15 => [1572868]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(19, &[6291456]);     // 19 => [6291456]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(23, &[6291460]);     // 23 => [6291460]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(27, &[6291464]);     // 27 => [6291464]
+        map.insert(28, &[96]);     // 28 => [96]
+        map.insert(29, &[97]);     // 29 => [97]
+        map.insert(30, &[98]);     // 30 => [98]
+        map.insert(31, &[100663328]);     // 31 => [100663328]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(35, &[25165824]);     // 35 => [25165824]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(37, &[37]);     // 37 => [37]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(39, &[25165828]);     // 39 => [25165828]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(41, &[4210688]);     // 41 => [4210688]
+        map.insert(42, &[42]);     // 42 => [42]
+        map.insert(43, &[4210690]);     // 43 => [4210690]
+        map.insert(44, &[80]);     // 44 => [80]
+        map.insert(45, &[81]);     // 45 => [81]
+        map.insert(46, &[82]);     // 46 => [82]
+        map.insert(47, &[100663312]);     // 47 => [100663312]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(50, &[163840]);     // 50 => [163840]
+        map.insert(51, &[163841]);     // 51 => [163841]
+        map.insert(52, &[72]);     // 52 => [72]
+        map.insert(53, &[294912]);     // 53 => [294912]
+        map.insert(54, &[65793]);     // 54 => [65793]
+        map.insert(55, &[65792]);     // 55 => [65792]
+        map.insert(56, &[68]);     // 56 => [68]
+        map.insert(57, &[69]);     // 57 => [69]
+        map.insert(58, &[2113536]);     // 58 => [2113536]
+        map.insert(59, &[2113537]);     // 59 => [2113537]
+        map.insert(60, &[64]);     // 60 => [64]
+        map.insert(61, &[65]);     // 61 => [65]
+        map.insert(62, &[66]);     // 62 => [66]
+        map.insert(63, &[100663296]);     // 63 => [100663296]
+        map.insert(64, &[128]);     // 64 => [128]
+        map.insert(65, &[129]);     // 65 => [129]
+        map.insert(66, &[130]);     // 66 => [130]
+        map.insert(67, &[131]);     // 67 => [131]
+        map.insert(68, &[132]);     // 68 => [132]
+        map.insert(69, &[133]);     // 69 => [133]
+        map.insert(70, &[2621441]);     // 70 => [2621441]
+        map.insert(71, &[2621440]);     // 71 => [2621440]
+        map.insert(72, &[136]);     // 72 => [136]
+        map.insert(73, &[137]);     // 73 => [137]
+        map.insert(74, &[138]);     // 74 => [138]
+        map.insert(75, &[1572992]);     // 75 => [1572992]
+        map.insert(76, &[3145728]);     // 76 => [3145728]
+        map.insert(77, &[3145729]);     // 77 => [3145729]
+        map.insert(78, &[3145730]);     // 78 => [3145730]
+        map.insert(79, &[2621448]);     // 79 => [2621448]
+        map.insert(80, &[144]);     // 80 => [144]
+        map.insert(81, &[145]);     // 81 => [145]
+        map.insert(82, &[67110912]);     // 82 => [67110912]
+        map.insert(83, &[67110913]);     // 83 => [67110913]
+        map.insert(84, &[4718592]);     // 84 => [4718592]
+        map.insert(85, &[4718593]);     // 85 => [4718593]
+        map.insert(86, &[4718594]);     // 86 => [4718594]
+        map.insert(87, &[5242888]);     // 87 => [5242888]
+        map.insert(88, &[263169]);     // 88 => [263169]
+        map.insert(89, &[263168]);     // 89 => [263168]
+        map.insert(90, &[132100]);     // 90 => [132100]
+        map.insert(91, &[263170]);     // 91 => [263170]
+        map.insert(92, &[132098]);     // 92 => [132098]
+        map.insert(93, &[5242882]);     // 93 => [5242882]
+        map.insert(94, &[132096]);     // 94 => [132096]
+        map.insert(95, &[5242880]);     // 95 => [5242880]
+        map.insert(96, &[160]);     // 96 => [160]
+        map.insert(97, &[161]);     // 97 => [161]
+        map.insert(98, &[162]);     // 98 => [162]
+        map.insert(99, &[12292]);     // 99 => [12292]
+        map.insert(100, &[164]);     // 100 => [164]
+        map.insert(101, &[12290]);     // 101 => [12290]
+        map.insert(102, &[12289]);     // 102 => [12289]
+        map.insert(103, &[12288]);     // 103 => [12288]
+        map.insert(104, &[33796]);     // 104 => [33796]
+        map.insert(105, &[33556484]);     // 105 => [33556484]
+        map.insert(106, &[4196864]);     // 106 => [4196864]
+        map.insert(107, &[4329472]);     // 107 => [4329472]
+        map.insert(108, &[33792]);     // 108 => [33792]
+        map.insert(109, &[33556480]);     // 109 => [33556480]
+        map.insert(110, &[33794]);     // 110 => [33794]
+        map.insert(111, &[33556482]);     // 111 => [33556482]
+        map.insert(112, &[176]);     // 112 => [176]
+        map.insert(113, &[8405248]);     // 113 => [8405248]
+        map.insert(114, &[1064964]);     // 114 => [1064964]
+        map.insert(115, &[67072]);     // 115 => [67072]
+        map.insert(116, &[1064962]);     // 116 => [1064962]
+        map.insert(117, &[540680]);     // 117 => [540680]
+        map.insert(118, &[1064960]);     // 118 => [1064960]
+        map.insert(119, &[1064961]);     // 119 => [1064961]
+        map.insert(120, &[196]);     // 120 => [196]
+        map.insert(121, &[540676]);     // 121 => [540676]
+        map.insert(122, &[2113664]);     // 122 => [2113664]
+        map.insert(123, &[2621504]);     // 123 => [2621504]
+        map.insert(124, &[192]);     // 124 => [192]
+        map.insert(125, &[540672]);     // 125 => [540672]
+        map.insert(126, &[194]);     // 126 => [194]
+        map.insert(127, &[540674]);     // 127 => [540674]
+        map.insert(128, &[256]);     // 128 => [256]
+        map.insert(129, &[257]);     // 129 => [257]
+        map.insert(130, &[258]);     // 130 => [258]
+        map.insert(131, &[259]);     // 131 => [259]
+        map.insert(132, &[260]);     // 132 => [260]
+        map.insert(133, &[261]);     // 133 => [261]
+        map.insert(134, &[9437185]);     // 134 => [9437185]
+        map.insert(135, &[9437184]);     // 135 => [9437184]
+        map.insert(136, &[264]);     // 136 => [264]
+        map.insert(137, &[265]);     // 137 => [265]
+        map.insert(138, &[65601]);     // 138 => [65601]
+        map.insert(139, &[65600]);     // 139 => [65600]
+        map.insert(140, &[8912896]);     // 140 => [8912896]
+        map.insert(141, &[8912897]);     // 141 => [8912897]
+        map.insert(142, &[8912898]);     // 142 => [8912898]
+        map.insert(143, &[65604]);     // 143 => [65604]
+        map.insert(144, &[272]);     // 144 => [272]
+        map.insert(145, &[33562624]);     // 145 => [33562624]
+        map.insert(146, &[274]);     // 146 => [274]
+        map.insert(147, &[33562626]);     // 147 => [33562626]
+        map.insert(148, &[276]);     // 148 => [276]
+        map.insert(149, &[65570]);     // 149 => [65570]
+        map.insert(150, &[65569]);     // 150 => [65569]
+        map.insert(151, &[65568]);     // 151 => [65568]
+        map.insert(152, &[131586]);     // 152 => [131586]
+        map.insert(153, &[6146]);     // 153 => [6146]
+        map.insert(154, &[131584]);     // 154 => [131584]
+        map.insert(155, &[6144]);     // 155 => [6144]
+        map.insert(156, &[262657]);     // 156 => [262657]
+        map.insert(157, &[262656]);     // 157 => [262656]
+        map.insert(158, &[131588]);     // 158 => [131588]
+        map.insert(159, &[262658]);     // 159 => [262658]
+        map.insert(160, &[288]);     // 160 => [288]
+        map.insert(161, &[289]);     // 161 => [289]
+        map.insert(162, &[290]);     // 162 => [290]
+        map.insert(163, &[65556]);     // 163 => [65556]
+        map.insert(164, &[17825792]);     // 164 => [17825792]
+        map.insert(165, &[17825793]);     // 165 => [17825793]
+        map.insert(166, &[65553]);     // 166 => [65553]
+        map.insert(167, &[65552]);     // 167 => [65552]
+        map.insert(168, &[33280]);     // 168 => [33280]
+        map.insert(169, &[33281]);     // 169 => [33281]
+        map.insert(170, &[33282]);     // 170 => [33282]
+        map.insert(171, &[17301508]);     // 171 => [17301508]
+        map.insert(172, &[67117058]);     // 172 => [67117058]
+        map.insert(173, &[17301506]);     // 173 => [17301506]
+        map.insert(174, &[67117056]);     // 174 => [67117056]
+        map.insert(175, &[17301504]);     // 175 => [17301504]
+        map.insert(176, &[304]);     // 176 => [304]
+        map.insert(177, &[65542]);     // 177 => [65542]
+        map.insert(178, &[65541]);     // 178 => [65541]
+        map.insert(179, &[65540]);     // 179 => [65540]
+        map.insert(180, &[65539]);     // 180 => [65539]
+        map.insert(181, &[65538]);     // 181 => [65538]
+        map.insert(182, &[65537]);     // 182 => [65537]
+        map.insert(183, &[65536]);     // 183 => [65536]
+        map.insert(184, &[324]);     // 184 => [324]
+        map.insert(185, &[67158016]);     // 185 => [67158016]
+        map.insert(186, &[131616]);     // 186 => [131616]
+        map.insert(187, &[65548]);     // 187 => [65548]
+        map.insert(188, &[320]);     // 188 => [320]
+        map.insert(189, &[321]);     // 189 => [321]
+        map.insert(190, &[65545]);     // 190 => [65545]
+        map.insert(191, &[65544]);     // 191 => [65544]
+        map.insert(192, &[384]);     // 192 => [384]
+        map.insert(193, &[385]);     // 193 => [385]
+        map.insert(194, &[386]);     // 194 => [386]
+        map.insert(195, &[10485768]);     // 195 => [10485768]
+        map.insert(196, &[1536]);     // 196 => [1536]
+        map.insert(197, &[1537]);     // 197 => [1537]
+        map.insert(198, &[1538]);     // 198 => [1538]
+        map.insert(199, &[20971584]);     // 199 => [20971584]
+        map.insert(200, &[67112961]);     // 200 => [67112961]
+        map.insert(201, &[67112960]);     // 201 => [67112960]
+        map.insert(202, &[10485761]);     // 202 => [10485761]
+        map.insert(203, &[10485760]);     // 203 => [10485760]
+        map.insert(204, &[1544]);     // 204 => [1544]
+        map.insert(205, &[67112964]);     // 205 => [67112964]
+        map.insert(206, &[16782336]);     // 206 => [16782336]
+        map.insert(207, &[10485764]);     // 207 => [10485764]
+        map.insert(208, &[16793602]);     // 208 => [16793602]
+        map.insert(209, &[8405024]);     // 209 => [8405024]
+        map.insert(210, &[16793600]);     // 210 => [16793600]
+        map.insert(211, &[16793601]);     // 211 => [16793601]
+        map.insert(212, &[1552]);     // 212 => [1552]
+        map.insert(213, &[67126272]);     // 213 => [67126272]
+        map.insert(214, &[16793604]);     // 214 => [16793604]
+        map.insert(215, &[65696]);     // 215 => [65696]
+        map.insert(216, &[12582912]);     // 216 => [12582912]
+        map.insert(217, &[12582913]);     // 217 => [12582913]
+        map.insert(218, &[12582914]);     // 218 => [12582914]
+        map.insert(219, &[10485776]);     // 219 => [10485776]
+        map.insert(220, &[12582916]);     // 220 => [12582916]
+        map.insert(221, &[262784]);     // 221 => [262784]
+        map.insert(222, &[132352]);     // 222 => [132352]
+        map.insert(223, &[5243136]);     // 223 => [5243136]
+        map.insert(224, &[18874376]);     // 224 => [18874376]
+        map.insert(225, &[8405008]);     // 225 => [8405008]
+        map.insert(226, &[2101760]);     // 226 => [2101760]
+        map.insert(227, &[2230272]);     // 227 => [2230272]
+        map.insert(228, &[1568]);     // 228 => [1568]
+        map.insert(229, &[67176448]);     // 229 => [67176448]
+        map.insert(230, &[33558544]);     // 230 => [33558544]
+        map.insert(231, &[65680]);     // 231 => [65680]
+        map.insert(232, &[18874368]);     // 232 => [18874368]
+        map.insert(233, &[18874369]);     // 233 => [18874369]
+        map.insert(234, &[18874370]);     // 234 => [18874370]
+        map.insert(235, &[20971536]);     // 235 => [20971536]
+        map.insert(236, &[18874372]);     // 236 => [18874372]
+        map.insert(237, &[33556736]);     // 237 => [33556736]
+        map.insert(238, &[16793664]);     // 238 => [16793664]
+        map.insert(239, &[17301632]);     // 239 => [17301632]
+        map.insert(240, &[8404993]);     // 240 => [8404993]
+        map.insert(241, &[8404992]);     // 241 => [8404992]
+        map.insert(242, &[33558532]);     // 242 => [33558532]
+        map.insert(243, &[8404994]);     // 243 => [8404994]
+        map.insert(244, &[33558530]);     // 244 => [33558530]
+        map.insert(245, &[65666]);     // 245 => [65666]
+        map.insert(246, &[33558528]);     // 246 => [33558528]
+        map.insert(247, &[65664]);     // 247 => [65664]
+        map.insert(248, &[10244]);     // 248 => [10244]
+        map.insert(249, &[20971522]);     // 249 => [20971522]
+        map.insert(250, &[20971521]);     // 250 => [20971521]
+        map.insert(251, &[20971520]);     // 251 => [20971520]
+        map.insert(252, &[10240]);     // 252 => [10240]
+        map.insert(253, &[10241]);     // 253 => [10241]
+        map.insert(254, &[10242]);     // 254 => [10242]
+        map.insert(255, &[20971524]);     // 255 => [20971524]
+        map.insert(256, &[512]);     // 256 => [512]
+        map.insert(257, &[513]);     // 257 => [513]
+        map.insert(258, &[514]);     // 258 => [514]
+        map.insert(259, &[515]);     // 259 => [515]
+        map.insert(260, &[516]);     // 260 => [516]
+        map.insert(261, &[517]);     // 261 => [517]
+        map.insert(262, &[37748737]);     // 262 => [37748737]
+        map.insert(263, &[37748736]);     // 263 => [37748736]
+        map.insert(264, &[520]);     // 264 => [520]
+        map.insert(265, &[521]);     // 265 => [521]
+        map.insert(266, &[16781312]);     // 266 => [16781312]
+        map.insert(267, &[16781313]);     // 267 => [16781313]
+        map.insert(268, &[524]);     // 268 => [524]
+        map.insert(269, &[262416]);     // 269 => [262416]
+        map.insert(270, &[16781316]);     // 270 => [16781316]
+        map.insert(271, &[37748744]);     // 271 => [37748744]
+        map.insert(272, &[528]);     // 272 => [528]
+        map.insert(273, &[67125248]);     // 273 => [67125248]
+        map.insert(274, &[530]);     // 274 => [530]
+        map.insert(275, &[67125250]);     // 275 => [67125250]
+        map.insert(276, &[35651584]);     // 276 => [35651584]
+        map.insert(277, &[35651585]);     // 277 => [35651585]
+        map.insert(278, &[35651586]);     // 278 => [35651586]
+        map.insert(279, &[98312]);     // 279 => [98312]
+        map.insert(280, &[131330]);     // 280 => [131330]
+        map.insert(281, &[262404]);     // 281 => [262404]
+        map.insert(282, &[131328]);     // 282 => [131328]
+        map.insert(283, &[131329]);     // 283 => [131329]
+        map.insert(284, &[262401]);     // 284 => [262401]
+        map.insert(285, &[262400]);     // 285 => [262400]
+        map.insert(286, &[98305]);     // 286 => [98305]
+        map.insert(287, &[98304]);     // 287 => [98304]
+        map.insert(288, &[544]);     // 288 => [544]
+        map.insert(289, &[545]);     // 289 => [545]
+        map.insert(290, &[546]);     // 290 => [546]
+        map.insert(291, &[69206024]);     // 291 => [69206024]
+        map.insert(292, &[548]);     // 292 => [548]
+        map.insert(293, &[196616]);     // 293 => [196616]
+        map.insert(294, &[33570824]);     // 294 => [33570824]
+        map.insert(295, &[37748768]);     // 295 => [37748768]
+        map.insert(296, &[33024]);     // 296 => [33024]
+        map.insert(297, &[8392704]);     // 297 => [8392704]
+        map.insert(298, &[327680]);     // 298 => [327680]
+        map.insert(299, &[69206016]);     // 299 => [69206016]
+        map.insert(300, &[196609]);     // 300 => [196609]
+        map.insert(301, &[196608]);     // 301 => [196608]
+        map.insert(302, &[33570816]);     // 302 => [33570816]
+        map.insert(303, &[33570817]);     // 303 => [33570817]
+        map.insert(304, &[71303176]);     // 304 => [71303176]
+        map.insert(305, &[1050628]);     // 305 => [1050628]
+        map.insert(306, &[8390912]);     // 306 => [8390912]
+        map.insert(307, &[66688]);     // 307 => [66688]
+        map.insert(308, &[1050625]);     // 308 => [1050625]
+        map.insert(309, &[1050624]);     // 309 => [1050624]
+        map.insert(310, &[526344]);     // 310 => [526344]
+        map.insert(311, &[1050626]);     // 311 => [1050626]
+        map.insert(312, &[71303168]);     // 312 => [71303168]
+        map.insert(313, &[71303169]);     // 313 => [71303169]
+        map.insert(314, &[71303170]);     // 314 => [71303170]
+        map.insert(315, &[69206032]);     // 315 => [69206032]
+        map.insert(316, &[576]);     // 316 => [576]
+        map.insert(317, &[577]);     // 317 => [577]
+        map.insert(318, &[526336]);     // 318 => [526336]
+        map.insert(319, &[526337]);     // 319 => [526337]
+        map.insert(320, &[640]);     // 320 => [640]
+        map.insert(321, &[641]);     // 321 => [641]
+        map.insert(322, &[18433]);     // 322 => [18433]
+        map.insert(323, &[18432]);     // 323 => [18432]
+        map.insert(324, &[1280]);     // 324 => [1280]
+        map.insert(325, &[1281]);     // 325 => [1281]
+        map.insert(326, &[1282]);     // 326 => [1282]
+        map.insert(327, &[18436]);     // 327 => [18436]
+        map.insert(328, &[648]);     // 328 => [648]
+        map.insert(329, &[1057024]);     // 329 => [1057024]
+        map.insert(330, &[8396804]);     // 330 => [8396804]
+        map.insert(331, &[18440]);     // 331 => [18440]
+        map.insert(332, &[8396802]);     // 332 => [8396802]
+        map.insert(333, &[16785440]);     // 333 => [16785440]
+        map.insert(334, &[8396800]);     // 334 => [8396800]
+        map.insert(335, &[8396801]);     // 335 => [8396801]
+        map.insert(336, &[34603016]);     // 336 => [34603016]
+        map.insert(337, &[34078722]);     // 337 => [34078722]
+        map.insert(338, &[34078721]);     // 338 => [34078721]
+        map.insert(339, &[34078720]);     // 339 => [34078720]
+        map.insert(340, &[1296]);     // 340 => [1296]
+        map.insert(341, &[33563648]);     // 341 => [33563648]
+        map.insert(342, &[4196416]);     // 342 => [4196416]
+        map.insert(343, &[34078724]);     // 343 => [34078724]
+        map.insert(344, &[34603008]);     // 344 => [34603008]
+        map.insert(345, &[34603009]);     // 345 => [34603009]
+        map.insert(346, &[34603010]);     // 346 => [34603010]
+        map.insert(347, &[34078728]);     // 347 => [34078728]
+        map.insert(348, &[34603012]);     // 348 => [34603012]
+        map.insert(349, &[262528]);     // 349 => [262528]
+        map.insert(350, &[8396816]);     // 350 => [8396816]
+        map.insert(351, &[98432]);     // 351 => [98432]
+        map.insert(352, &[672]);     // 352 => [672]
+        map.insert(353, &[272384]);     // 353 => [272384]
+        map.insert(354, &[4196360]);     // 354 => [4196360]
+        map.insert(355, &[68157444]);     // 355 => [68157444]
+        map.insert(356, &[67633160]);     // 356 => [67633160]
+        map.insert(357, &[68157442]);     // 357 => [68157442]
+        map.insert(358, &[68157441]);     // 358 => [68157441]
+        map.insert(359, &[68157440]);     // 359 => [68157440]
+        map.insert(360, &[4196354]);     // 360 => [4196354]
+        map.insert(361, &[16785412]);     // 361 => [16785412]
+        map.insert(362, &[4196352]);     // 362 => [4196352]
+        map.insert(363, &[4196353]);     // 363 => [4196353]
+        map.insert(364, &[67633152]);     // 364 => [67633152]
+        map.insert(365, &[16785408]);     // 365 => [16785408]
+        map.insert(366, &[67633154]);     // 366 => [67633154]
+        map.insert(367, &[16785410]);     // 367 => [16785410]
+        map.insert(368, &[12615680]);     // 368 => [12615680]
+        map.insert(369, &[66562]);     // 369 => [66562]
+        map.insert(370, &[66561]);     // 370 => [66561]
+        map.insert(371, &[66560]);     // 371 => [66560]
+        map.insert(372, &[83951616]);     // 372 => [83951616]
+        map.insert(373, &[1050752]);     // 373 => [1050752]
+        map.insert(374, &[1065472]);     // 374 => [1065472]
+        map.insert(375, &[66564]);     // 375 => [66564]
+        map.insert(376, &[2099201]);     // 376 => [2099201]
+        map.insert(377, &[2099200]);     // 377 => [2099200]
+        map.insert(378, &[4196368]);     // 378 => [4196368]
+        map.insert(379, &[2099202]);     // 379 => [2099202]
+        map.insert(380, &[67633168]);     // 380 => [67633168]
+        map.insert(381, &[2099204]);     // 381 => [2099204]
+        map.insert(382, &[526464]);     // 382 => [526464]
+        map.insert(383, &[18496]);     // 383 => [18496]
+        map.insert(384, &[768]);     // 384 => [768]
+        map.insert(385, &[769]);     // 385 => [769]
+        map.insert(386, &[770]);     // 386 => [770]
+        map.insert(387, &[24640]);     // 387 => [24640]
+        map.insert(388, &[1152]);     // 388 => [1152]
+        map.insert(389, &[2105344]);     // 389 => [2105344]
+        map.insert(390, &[1154]);     // 390 => [1154]
+        map.insert(391, &[2105346]);     // 391 => [2105346]
+        map.insert(392, &[32800]);     // 392 => [32800]
+        map.insert(393, &[32801]);     // 393 => [32801]
+        map.insert(394, &[131088]);     // 394 => [131088]
+        map.insert(395, &[131089]);     // 395 => [131089]
+        map.insert(396, &[262161]);     // 396 => [262161]
+        map.insert(397, &[262160]);     // 397 => [262160]
+        map.insert(398, &[131092]);     // 398 => [131092]
+        map.insert(399, &[262162]);     // 399 => [262162]
+        map.insert(400, &[16779265]);     // 400 => [16779265]
+        map.insert(401, &[16779264]);     // 401 => [16779264]
+        map.insert(402, &[131080]);     // 402 => [131080]
+        map.insert(403, &[131081]);     // 403 => [131081]
+        map.insert(404, &[32832]);     // 404 => [32832]
+        map.insert(405, &[262152]);     // 405 => [262152]
+        map.insert(406, &[4202496]);     // 406 => [4202496]
+        map.insert(407, &[4202497]);     // 407 => [4202497]
+        map.insert(408, &[131074]);     // 408 => [131074]
+        map.insert(409, &[262148]);     // 409 => [262148]
+        map.insert(410, &[131072]);     // 410 => [131072]
+        map.insert(411, &[131073]);     // 411 => [131073]
+        map.insert(412, &[262145]);     // 412 => [262145]
+        map.insert(413, &[262144]);     // 413 => [262144]
+        map.insert(414, &[131076]);     // 414 => [131076]
+        map.insert(415, &[262146]);     // 415 => [262146]
+        map.insert(416, &[32776]);     // 416 => [32776]
+        map.insert(417, &[262208]);     // 417 => [262208]
+        map.insert(418, &[32778]);     // 418 => [32778]
+        map.insert(419, &[262210]);     // 419 => [262210]
+        map.insert(420, &[528385]);     // 420 => [528385]
+        map.insert(421, &[528384]);     // 421 => [528384]
+        map.insert(422, &[131136]);     // 422 => [131136]
+        map.insert(423, &[131137]);     // 423 => [131137]
+        map.insert(424, &[32768]);     // 424 => [32768]
+        map.insert(425, &[32769]);     // 425 => [32769]
+        map.insert(426, &[32770]);     // 426 => [32770]
+        map.insert(427, &[32771]);     // 427 => [32771]
+        map.insert(428, &[32772]);     // 428 => [32772]
+        map.insert(429, &[32773]);     // 429 => [32773]
+        map.insert(430, &[1052672]);     // 430 => [1052672]
+        map.insert(431, &[1052673]);     // 431 => [1052673]
+        map.insert(432, &[8390658]);     // 432 => [8390658]
+        map.insert(433, &[262224]);     // 433 => [262224]
+        map.insert(434, &[8390656]);     // 434 => [8390656]
+        map.insert(435, &[8390657]);     // 435 => [8390657]
+        map.insert(436, &[32864]);     // 436 => [32864]
+        map.insert(437, &[66050]);     // 437 => [66050]
+        map.insert(438, &[66049]);     // 438 => [66049]
+        map.insert(439, &[66048]);     // 439 => [66048]
+        map.insert(440, &[32784]);     // 440 => [32784]
+        map.insert(441, &[32785]);     // 441 => [32785]
+        map.insert(442, &[131104]);     // 442 => [131104]
+        map.insert(443, &[131105]);     // 443 => [131105]
+        map.insert(444, &[262177]);     // 444 => [262177]
+        map.insert(445, &[262176]);     // 445 => [262176]
+        map.insert(446, &[24577]);     // 446 => [24577]
+        map.insert(447, &[24576]);     // 447 => [24576]
+        map.insert(448, &[1028]);     // 448 => [1028]
+        map.insert(449, &[1029]);     // 449 => [1029]
+        map.insert(450, &[532480]);     // 450 => [532480]
+        map.insert(451, &[83886080]);     // 451 => [83886080]
+        map.insert(452, &[1024]);     // 452 => [1024]
+        map.insert(453, &[1025]);     // 453 => [1025]
+        map.insert(454, &[1026]);     // 454 => [1026]
+        map.insert(455, &[1027]);     // 455 => [1027]
+        map.insert(456, &[1056769]);     // 456 => [1056769]
+        map.insert(457, &[1056768]);     // 457 => [1056768]
+        map.insert(458, &[532488]);     // 458 => [532488]
+        map.insert(459, &[1056770]);     // 459 => [1056770]
+        map.insert(460, &[1032]);     // 460 => [1032]
+        map.insert(461, &[1033]);     // 461 => [1033]
+        map.insert(462, &[1034]);     // 462 => [1034]
+        map.insert(463, &[41943056]);     // 463 => [41943056]
+        map.insert(464, &[1044]);     // 464 => [1044]
+        map.insert(465, &[4198432]);     // 465 => [4198432]
+        map.insert(466, &[131208]);     // 466 => [131208]
+        map.insert(467, &[83886096]);     // 467 => [83886096]
+        map.insert(468, &[1040]);     // 468 => [1040]
+        map.insert(469, &[1041]);     // 469 => [1041]
+        map.insert(470, &[1042]);     // 470 => [1042]
+        map.insert(471, &[41943048]);     // 471 => [41943048]
+        map.insert(472, &[20480]);     // 472 => [20480]
+        map.insert(473, &[20481]);     // 473 => [20481]
+        map.insert(474, &[131200]);     // 474 => [131200]
+        map.insert(475, &[131201]);     // 475 => [131201]
+        map.insert(476, &[262273]);     // 476 => [262273]
+        map.insert(477, &[262272]);     // 477 => [262272]
+        map.insert(478, &[41943041]);     // 478 => [41943041]
+        map.insert(479, &[41943040]);     // 479 => [41943040]
+        map.insert(480, &[75497472]);     // 480 => [75497472]
+        map.insert(481, &[75497473]);     // 481 => [75497473]
+        map.insert(482, &[2101248]);     // 482 => [2101248]
+        map.insert(483, &[2101249]);     // 483 => [2101249]
+        map.insert(484, &[1056]);     // 484 => [1056]
+        map.insert(485, &[1057]);     // 485 => [1057]
+        map.insert(486, &[1058]);     // 486 => [1058]
+        map.insert(487, &[68157696]);     // 487 => [68157696]
+        map.insert(488, &[32896]);     // 488 => [32896]
+        map.insert(489, &[32897]);     // 489 => [32897]
+        map.insert(490, &[32898]);     // 490 => [32898]
+        map.insert(491, &[1327104]);     // 491 => [1327104]
+        map.insert(492, &[32900]);     // 492 => [32900]
+        map.insert(493, &[16785664]);     // 493 => [16785664]
+        map.insert(494, &[1052800]);     // 494 => [1052800]
+        map.insert(495, &[2654208]);     // 495 => [2654208]
+        map.insert(496, &[4198401]);     // 496 => [4198401]
+        map.insert(497, &[4198400]);     // 497 => [4198400]
+        map.insert(498, &[2101264]);     // 498 => [2101264]
+        map.insert(499, &[4198402]);     // 499 => [4198402]
+        map.insert(500, &[50331656]);     // 500 => [50331656]
+        map.insert(501, &[4198404]);     // 501 => [4198404]
+        map.insert(502, &[33559040]);     // 502 => [33559040]
+        map.insert(503, &[66176]);     // 503 => [66176]
+        map.insert(504, &[1088]);     // 504 => [1088]
+        map.insert(505, &[1089]);     // 505 => [1089]
+        map.insert(506, &[1090]);     // 506 => [1090]
+        map.insert(507, &[20972032]);     // 507 => [20972032]
+        map.insert(508, &[50331648]);     // 508 => [50331648]
+        map.insert(509, &[50331649]);     // 509 => [50331649]
+        map.insert(510, &[50331650]);     // 510 => [50331650]
+        map.insert(511, &[41943072]);     // 511 => [41943072]
+        map.insert(512, &[2048]);     // 512 => [2048]
+        map.insert(513, &[2049]);     // 513 => [2049]
+        map.insert(514, &[2050]);     // 514 => [2050]
+        map.insert(515, &[2051]);     // 515 => [2051]
+        map.insert(516, &[2052]);     // 516 => [2052]
+        map.insert(517, &[2053]);     // 517 => [2053]
+        map.insert(518, &[2054]);     // 518 => [2054]
+        map.insert(519, &[17664]);     // 519 => [17664]
+        map.insert(520, &[2056]);     // 520 => [2056]
+        map.insert(521, &[2057]);     // 521 => [2057]
+        map.insert(522, &[16908289]);     // 522 => [16908289]
+        map.insert(523, &[16908288]);     // 523 => [16908288]
+        map.insert(524, &[17039360]);     // 524 => [17039360]
+        map.insert(525, &[17039361]);     // 525 => [17039361]
+        map.insert(526, &[17039362]);     // 526 => [17039362]
+        map.insert(527, &[16908292]);     // 527 => [16908292]
+        map.insert(528, &[2064]);     // 528 => [2064]
+        map.insert(529, &[2065]);     // 529 => [2065]
+        map.insert(530, &[67108992]);     // 530 => [67108992]
+        map.insert(531, &[67108993]);     // 531 => [67108993]
+        map.insert(532, &[2068]);     // 532 => [2068]
+        map.insert(533, &[1049120]);     // 533 => [1049120]
+        map.insert(534, &[67108996]);     // 534 => [67108996]
+        map.insert(535, &[8916992]);     // 535 => [8916992]
+        map.insert(536, &[8421378]);     // 536 => [8421378]
+        map.insert(537, &[4354]);     // 537 => [4354]
+        map.insert(538, &[8421376]);     // 538 => [8421376]
+        map.insert(539, &[4352]);     // 539 => [4352]
+        map.insert(540, &[17039376]);     // 540 => [17039376]
+        map.insert(541, &[1081600]);     // 541 => [1081600]
+        map.insert(542, &[8421380]);     // 542 => [8421380]
+        map.insert(543, &[4356]);     // 543 => [4356]
+        map.insert(544, &[2080]);     // 544 => [2080]
+        map.insert(545, &[2081]);     // 545 => [2081]
+        map.insert(546, &[2082]);     // 546 => [2082]
+        map.insert(547, &[786688]);     // 547 => [786688]
+        map.insert(548, &[2084]);     // 548 => [2084]
+        map.insert(549, &[33554568]);     // 549 => [33554568]
+        map.insert(550, &[8421440]);     // 550 => [8421440]
+        map.insert(551, &[8650760]);     // 551 => [8650760]
+        map.insert(552, &[8519680]);     // 552 => [8519680]
+        map.insert(553, &[8519681]);     // 553 => [8519681]
+        map.insert(554, &[8519682]);     // 554 => [8519682]
+        map.insert(555, &[8650756]);     // 555 => [8650756]
+        map.insert(556, &[69632]);     // 556 => [69632]
+        map.insert(557, &[33554560]);     // 557 => [33554560]
+        map.insert(558, &[8650753]);     // 558 => [8650753]
+        map.insert(559, &[8650752]);     // 559 => [8650752]
+        map.insert(560, &[2096]);     // 560 => [2096]
+        map.insert(561, &[1049092]);     // 561 => [1049092]
+        map.insert(562, &[67109024]);     // 562 => [67109024]
+        map.insert(563, &[37376]);     // 563 => [37376]
+        map.insert(564, &[1049089]);     // 564 => [1049089]
+        map.insert(565, &[1049088]);     // 565 => [1049088]
+        map.insert(566, &[524808]);     // 566 => [524808]
+        map.insert(567, &[1049090]);     // 567 => [1049090]
+        map.insert(568, &[16809985]);     // 568 => [16809985]
+        map.insert(569, &[16809984]);     // 569 => [16809984]
+        map.insert(570, &[524804]);     // 570 => [524804]
+        map.insert(571, &[16809986]);     // 571 => [16809986]
+        map.insert(572, &[2112]);     // 572 => [2112]
+        map.insert(573, &[2113]);     // 573 => [2113]
+        map.insert(574, &[524800]);     // 574 => [524800]
+        map.insert(575, &[524801]);     // 575 => [524801]
+        map.insert(576, &[2176]);     // 576 => [2176]
+        map.insert(577, &[2177]);     // 577 => [2177]
+        map.insert(578, &[67108880]);     // 578 => [67108880]
+        map.insert(579, &[16896]);     // 579 => [16896]
+        map.insert(580, &[2180]);     // 580 => [2180]
+        map.insert(581, &[33554472]);     // 581 => [33554472]
+        map.insert(582, &[67108884]);     // 582 => [67108884]
+        map.insert(583, &[16900]);     // 583 => [16900]
+        map.insert(584, &[2184]);     // 584 => [2184]
+        map.insert(585, &[73730]);     // 585 => [73730]
+        map.insert(586, &[73729]);     // 586 => [73729]
+        map.insert(587, &[73728]);     // 587 => [73728]
+        map.insert(588, &[33554465]);     // 588 => [33554465]
+        map.insert(589, &[33554464]);     // 589 => [33554464]
+        map.insert(590, &[67108960]);     // 590 => [67108960]
+        map.insert(591, &[33554466]);     // 591 => [33554466]
+        map.insert(592, &[67108866]);     // 592 => [67108866]
+        map.insert(593, &[33554496]);     // 593 => [33554496]
+        map.insert(594, &[67108864]);     // 594 => [67108864]
+        map.insert(595, &[67108865]);     // 595 => [67108865]
+        map.insert(596, &[16778241]);     // 596 => [16778241]
+        map.insert(597, &[16778240]);     // 597 => [16778240]
+        map.insert(598, &[67108868]);     // 598 => [67108868]
+        map.insert(599, &[67108869]);     // 599 => [67108869]
+        map.insert(600, &[67108874]);     // 600 => [67108874]
+        map.insert(601, &[33554504]);     // 601 => [33554504]
+        map.insert(602, &[67108872]);     // 602 => [67108872]
+        map.insert(603, &[67108873]);     // 603 => [67108873]
+        map.insert(604, &[8480]);     // 604 => [8480]
+        map.insert(605, &[16778248]);     // 605 => [16778248]
+        map.insert(606, &[67108876]);     // 606 => [67108876]
+        map.insert(607, &[5632]);     // 607 => [5632]
+        map.insert(608, &[2208]);     // 608 => [2208]
+        map.insert(609, &[33554444]);     // 609 => [33554444]
+        map.insert(610, &[4194824]);     // 610 => [4194824]
+        map.insert(611, &[16928]);     // 611 => [16928]
+        map.insert(612, &[33554441]);     // 612 => [33554441]
+        map.insert(613, &[33554440]);     // 613 => [33554440]
+        map.insert(614, &[67108936]);     // 614 => [67108936]
+        map.insert(615, &[33554442]);     // 615 => [33554442]
+        map.insert(616, &[33554437]);     // 616 => [33554437]
+        map.insert(617, &[33554436]);     // 617 => [33554436]
+        map.insert(618, &[4194816]);     // 618 => [4194816]
+        map.insert(619, &[4194817]);     // 619 => [4194817]
+        map.insert(620, &[33554433]);     // 620 => [33554433]
+        map.insert(621, &[33554432]);     // 621 => [33554432]
+        map.insert(622, &[67108928]);     // 622 => [67108928]
+        map.insert(623, &[33554434]);     // 623 => [33554434]
+        map.insert(624, &[67108898]);     // 624 => [67108898]
+        map.insert(625, &[2097672]);     // 625 => [2097672]
+        map.insert(626, &[67108896]);     // 626 => [67108896]
+        map.insert(627, &[67108897]);     // 627 => [67108897]
+        map.insert(628, &[8389634]);     // 628 => [8389634]
+        map.insert(629, &[33554456]);     // 629 => [33554456]
+        map.insert(630, &[8389632]);     // 630 => [8389632]
+        map.insert(631, &[8389633]);     // 631 => [8389633]
+        map.insert(632, &[2097665]);     // 632 => [2097665]
+        map.insert(633, &[2097664]);     // 633 => [2097664]
+        map.insert(634, &[67108904]);     // 634 => [67108904]
+        map.insert(635, &[2097666]);     // 635 => [2097666]
+        map.insert(636, &[8448]);     // 636 => [8448]
+        map.insert(637, &[33554448]);     // 637 => [33554448]
+        map.insert(638, &[8450]);     // 638 => [8450]
+        map.insert(639, &[33554450]);     // 639 => [33554450]
+        map.insert(640, &[2304]);     // 640 => [2304]
+        map.insert(641, &[2305]);     // 641 => [2305]
+        map.insert(642, &[2306]);     // 642 => [2306]
+        map.insert(643, &[17412]);     // 643 => [17412]
+        map.insert(644, &[2308]);     // 644 => [2308]
+        map.insert(645, &[17410]);     // 645 => [17410]
+        map.insert(646, &[17409]);     // 646 => [17409]
+        map.insert(647, &[17408]);     // 647 => [17408]
+        map.insert(648, &[2312]);     // 648 => [2312]
+        map.insert(649, &[4114]);     // 649 => [4114]
+        map.insert(650, &[4113]);     // 650 => [4113]
+        map.insert(651, &[4112]);     // 651 => [4112]
+        map.insert(652, &[17039616]);     // 652 => [17039616]
+        map.insert(653, &[1081360]);     // 653 => [1081360]
+        map.insert(654, &[4195360]);     // 654 => [4195360]
+        map.insert(655, &[4116]);     // 655 => [4116]
+        map.insert(656, &[16777729]);     // 656 => [16777729]
+        map.insert(657, &[16777728]);     // 657 => [16777728]
+        map.insert(658, &[4105]);     // 658 => [4105]
+        map.insert(659, &[4104]);     // 659 => [4104]
+        map.insert(660, &[557058]);     // 660 => [557058]
+        map.insert(661, &[16777732]);     // 661 => [16777732]
+        map.insert(662, &[557056]);     // 662 => [557056]
+        map.insert(663, &[557057]);     // 663 => [557057]
+        map.insert(664, &[4099]);     // 664 => [4099]
+        map.insert(665, &[4098]);     // 665 => [4098]
+        map.insert(666, &[4097]);     // 666 => [4097]
+        map.insert(667, &[4096]);     // 667 => [4096]
+        map.insert(668, &[1081345]);     // 668 => [1081345]
+        map.insert(669, &[1081344]);     // 669 => [1081344]
+        map.insert(670, &[4101]);     // 670 => [4101]
+        map.insert(671, &[4100]);     // 671 => [4100]
+        map.insert(672, &[655364]);     // 672 => [655364]
+        map.insert(673, &[786434]);     // 673 => [786434]
+        map.insert(674, &[786433]);     // 674 => [786433]
+        map.insert(675, &[786432]);     // 675 => [786432]
+        map.insert(676, &[655360]);     // 676 => [655360]
+        map.insert(677, &[655361]);     // 677 => [655361]
+        map.insert(678, &[4161]);     // 678 => [4161]
+        map.insert(679, &[4160]);     // 679 => [4160]
+        map.insert(680, &[1310720]);     // 680 => [1310720]
+        map.insert(681, &[1310721]);     // 681 => [1310721]
+        map.insert(682, &[1310722]);     // 682 => [1310722]
+        map.insert(683, &[1179652]);     // 683 => [1179652]
+        map.insert(684, &[4195330]);     // 684 => [4195330]
+        map.insert(685, &[1179650]);     // 685 => [1179650]
+        map.insert(686, &[4195328]);     // 686 => [4195328]
+        map.insert(687, &[1179648]);     // 687 => [1179648]
+        map.insert(688, &[8389122]);     // 688 => [8389122]
+        map.insert(689, &[16777760]);     // 689 => [16777760]
+        map.insert(690, &[8389120]);     // 690 => [8389120]
+        map.insert(691, &[8389121]);     // 691 => [8389121]
+        map.insert(692, &[8328]);     // 692 => [8328]
+        map.insert(693, &[67586]);     // 693 => [67586]
+        map.insert(694, &[67585]);     // 694 => [67585]
+        map.insert(695, &[67584]);     // 695 => [67584]
+        map.insert(696, &[8324]);     // 696 => [8324]
+        map.insert(697, &[4130]);     // 697 => [4130]
+        map.insert(698, &[4129]);     // 698 => [4129]
+        map.insert(699, &[4128]);     // 699 => [4128]
+        map.insert(700, &[8320]);     // 700 => [8320]
+        map.insert(701, &[2098176]);     // 701 => [2098176]
+        map.insert(702, &[8322]);     // 702 => [8322]
+        map.insert(703, &[2098178]);     // 703 => [2098178]
+        map.insert(704, &[8256]);     // 704 => [8256]
+        map.insert(705, &[8257]);     // 705 => [8257]
+        map.insert(706, &[4227072]);     // 706 => [4227072]
+        map.insert(707, &[4227073]);     // 707 => [4227073]
+        map.insert(708, &[8260]);     // 708 => [8260]
+        map.insert(709, &[67174432]);     // 709 => [67174432]
+        map.insert(710, &[4227076]);     // 710 => [4227076]
+        map.insert(711, &[17536]);     // 711 => [17536]
+        map.insert(712, &[8264]);     // 712 => [8264]
+        map.insert(713, &[147472]);     // 713 => [147472]
+        map.insert(714, &[4227080]);     // 714 => [4227080]
+        map.insert(715, &[4240]);     // 715 => [4240]
+        map.insert(716, &[8240]);     // 716 => [8240]
+        map.insert(717, &[33554720]);     // 717 => [33554720]
+        map.insert(718, &[278544]);     // 718 => [278544]
+        map.insert(719, &[67371520]);     // 719 => [67371520]
+        map.insert(720, &[2129921]);     // 720 => [2129921]
+        map.insert(721, &[2129920]);     // 721 => [2129920]
+        map.insert(722, &[67109120]);     // 722 => [67109120]
+        map.insert(723, &[67109121]);     // 723 => [67109121]
+        map.insert(724, &[8232]);     // 724 => [8232]
+        map.insert(725, &[2129924]);     // 725 => [2129924]
+        map.insert(726, &[67109124]);     // 726 => [67109124]
+        map.insert(727, &[4456480]);     // 727 => [4456480]
+        map.insert(728, &[147457]);     // 728 => [147457]
+        map.insert(729, &[147456]);     // 729 => [147456]
+        map.insert(730, &[33619968]);     // 730 => [33619968]
+        map.insert(731, &[4224]);     // 731 => [4224]
+        map.insert(732, &[8224]);     // 732 => [8224]
+        map.insert(733, &[8225]);     // 733 => [8225]
+        map.insert(734, &[278528]);     // 734 => [278528]
+        map.insert(735, &[278529]);     // 735 => [278529]
+        map.insert(736, &[2359300]);     // 736 => [2359300]
+        map.insert(737, &[2228226]);     // 737 => [2228226]
+        map.insert(738, &[2228225]);     // 738 => [2228225]
+        map.insert(739, &[2228224]);     // 739 => [2228224]
+        map.insert(740, &[2359296]);     // 740 => [2359296]
+        map.insert(741, &[67174400]);     // 741 => [67174400]
+        map.insert(742, &[2359298]);     // 742 => [2359298]
+        map.insert(743, &[67174402]);     // 743 => [67174402]
+        map.insert(744, &[8212]);     // 744 => [8212]
+        map.insert(745, &[49154]);     // 745 => [49154]
+        map.insert(746, &[49153]);     // 746 => [49153]
+        map.insert(747, &[49152]);     // 747 => [49152]
+        map.insert(748, &[8208]);     // 748 => [8208]
+        map.insert(749, &[33554688]);     // 749 => [33554688]
+        map.insert(750, &[8210]);     // 750 => [8210]
+        map.insert(751, &[33554690]);     // 751 => [33554690]
+        map.insert(752, &[4325376]);     // 752 => [4325376]
+        map.insert(753, &[1049600]);     // 753 => [1049600]
+        map.insert(754, &[4325378]);     // 754 => [4325378]
+        map.insert(755, &[1049602]);     // 755 => [1049602]
+        map.insert(756, &[8200]);     // 756 => [8200]
+        map.insert(757, &[8201]);     // 757 => [8201]
+        map.insert(758, &[4456449]);     // 758 => [4456449]
+        map.insert(759, &[4456448]);     // 759 => [4456448]
+        map.insert(760, &[8196]);     // 760 => [8196]
+        map.insert(761, &[8197]);     // 761 => [8197]
+        map.insert(762, &[525312]);     // 762 => [525312]
+        map.insert(763, &[525313]);     // 763 => [525313]
+        map.insert(764, &[8192]);     // 764 => [8192]
+        map.insert(765, &[8193]);     // 765 => [8193]
+        map.insert(766, &[8194]);     // 766 => [8194]
+        map.insert(767, &[8195]);     // 767 => [8195]
+        map.insert(768, &[2560]);     // 768 => [2560]
+        map.insert(769, &[135168]);     // 769 => [135168]
+        map.insert(770, &[524352]);     // 770 => [524352]
+        map.insert(771, &[16512]);     // 771 => [16512]
+        map.insert(772, &[8454145]);     // 772 => [8454145]
+        map.insert(773, &[8454144]);     // 773 => [8454144]
+        map.insert(774, &[266240]);     // 774 => [266240]
+        map.insert(775, &[266241]);     // 775 => [266241]
+        map.insert(776, &[1048641]);     // 776 => [1048641]
+        map.insert(777, &[1048640]);     // 777 => [1048640]
+        map.insert(778, &[524360]);     // 778 => [524360]
+        map.insert(779, &[1048642]);     // 779 => [1048642]
+        map.insert(780, &[17039872]);     // 780 => [17039872]
+        map.insert(781, &[1048644]);     // 781 => [1048644]
+        map.insert(782, &[266248]);     // 782 => [266248]
+        map.insert(783, &[36928]);     // 783 => [36928]
+        map.insert(784, &[16777473]);     // 784 => [16777473]
+        map.insert(785, &[16777472]);     // 785 => [16777472]
+        map.insert(786, &[524368]);     // 786 => [524368]
+        map.insert(787, &[16777474]);     // 787 => [16777474]
+        map.insert(788, &[1048609]);     // 788 => [1048609]
+        map.insert(789, &[1048608]);     // 789 => [1048608]
+        map.insert(790, &[524328]);     // 790 => [524328]
+        map.insert(791, &[1048610]);     // 791 => [1048610]
+        map.insert(792, &[9248]);     // 792 => [9248]
+        map.insert(793, &[16777480]);     // 793 => [16777480]
+        map.insert(794, &[524324]);     // 794 => [524324]
+        map.insert(795, &[4864]);     // 795 => [4864]
+        map.insert(796, &[524322]);     // 796 => [524322]
+        map.insert(797, &[1048616]);     // 797 => [1048616]
+        map.insert(798, &[524320]);     // 798 => [524320]
+        map.insert(799, &[524321]);     // 799 => [524321]
+        map.insert(800, &[2592]);     // 800 => [2592]
+        map.insert(801, &[1048596]);     // 801 => [1048596]
+        map.insert(802, &[16842756]);     // 802 => [16842756]
+        map.insert(803, &[36880]);     // 803 => [36880]
+        map.insert(804, &[1048593]);     // 804 => [1048593]
+        map.insert(805, &[1048592]);     // 805 => [1048592]
+        map.insert(806, &[16842752]);     // 806 => [16842752]
+        map.insert(807, &[16842753]);     // 807 => [16842753]
+        map.insert(808, &[4194434]);     // 808 => [4194434]
+        map.insert(809, &[2097296]);     // 809 => [2097296]
+        map.insert(810, &[4194432]);     // 810 => [4194432]
+        map.insert(811, &[4194433]);     // 811 => [4194433]
+        map.insert(812, &[524306]);     // 812 => [524306]
+        map.insert(813, &[1048600]);     // 813 => [1048600]
+        map.insert(814, &[524304]);     // 814 => [524304]
+        map.insert(815, &[524305]);     // 815 => [524305]
+        map.insert(816, &[1048581]);     // 816 => [1048581]
+        map.insert(817, &[1048580]);     // 817 => [1048580]
+        map.insert(818, &[8388864]);     // 818 => [8388864]
+        map.insert(819, &[36864]);     // 819 => [36864]
+        map.insert(820, &[1048577]);     // 820 => [1048577]
+        map.insert(821, &[1048576]);     // 821 => [1048576]
+        map.insert(822, &[524296]);     // 822 => [524296]
+        map.insert(823, &[1048578]);     // 823 => [1048578]
+        map.insert(824, &[9216]);     // 824 => [9216]
+        map.insert(825, &[2097280]);     // 825 => [2097280]
+        map.insert(826, &[524292]);     // 826 => [524292]
+        map.insert(827, &[524293]);     // 827 => [524293]
+        map.insert(828, &[524290]);     // 828 => [524290]
+        map.insert(829, &[1048584]);     // 829 => [1048584]
+        map.insert(830, &[524288]);     // 830 => [524288]
+        map.insert(831, &[524289]);     // 831 => [524289]
+        map.insert(832, &[16387]);     // 832 => [16387]
+        map.insert(833, &[16386]);     // 833 => [16386]
+        map.insert(834, &[16385]);     // 834 => [16385]
+        map.insert(835, &[16384]);     // 835 => [16384]
+        map.insert(836, &[2097217]);     // 836 => [2097217]
+        map.insert(837, &[2097216]);     // 837 => [2097216]
+        map.insert(838, &[16389]);     // 838 => [16389]
+        map.insert(839, &[16388]);     // 839 => [16388]
+        map.insert(840, &[4194338]);     // 840 => [4194338]
+        map.insert(841, &[16394]);     // 841 => [16394]
+        map.insert(842, &[4194336]);     // 842 => [4194336]
+        map.insert(843, &[16392]);     // 843 => [16392]
+        map.insert(844, &[2392064]);     // 844 => [2392064]
+        map.insert(845, &[2097224]);     // 845 => [2097224]
+        map.insert(846, &[4194340]);     // 846 => [4194340]
+        map.insert(847, &[16396]);     // 847 => [16396]
+        map.insert(848, &[67109378]);     // 848 => [67109378]
+        map.insert(849, &[16402]);     // 849 => [16402]
+        map.insert(850, &[67109376]);     // 850 => [67109376]
+        map.insert(851, &[16400]);     // 851 => [16400]
+        map.insert(852, &[40960]);     // 852 => [40960]
+        map.insert(853, &[40961]);     // 853 => [40961]
+        map.insert(854, &[4194368]);     // 854 => [4194368]
+        map.insert(855, &[4194369]);     // 855 => [4194369]
+        map.insert(856, &[2097185]);     // 856 => [2097185]
+        map.insert(857, &[2097184]);     // 857 => [2097184]
+        map.insert(858, &[67109384]);     // 858 => [67109384]
+        map.insert(859, &[2097186]);     // 859 => [2097186]
+        map.insert(860, &[40968]);     // 860 => [40968]
+        map.insert(861, &[5122]);     // 861 => [5122]
+        map.insert(862, &[5121]);     // 862 => [5121]
+        map.insert(863, &[5120]);     // 863 => [5120]
+        map.insert(864, &[270337]);     // 864 => [270337]
+        map.insert(865, &[270336]);     // 865 => [270336]
+        map.insert(866, &[4194312]);     // 866 => [4194312]
+        map.insert(867, &[16416]);     // 867 => [16416]
+        map.insert(868, &[139266]);     // 868 => [139266]
+        map.insert(869, &[270340]);     // 869 => [270340]
+        map.insert(870, &[139264]);     // 870 => [139264]
+        map.insert(871, &[139265]);     // 871 => [139265]
+        map.insert(872, &[4194306]);     // 872 => [4194306]
+        map.insert(873, &[2097168]);     // 873 => [2097168]
+        map.insert(874, &[4194304]);     // 874 => [4194304]
+        map.insert(875, &[4194305]);     // 875 => [4194305]
+        map.insert(876, &[33554945]);     // 876 => [33554945]
+        map.insert(877, &[33554944]);     // 877 => [33554944]
+        map.insert(878, &[4194308]);     // 878 => [4194308]
+        map.insert(879, &[4194309]);     // 879 => [4194309]
+        map.insert(880, &[2097161]);     // 880 => [2097161]
+        map.insert(881, &[2097160]);     // 881 => [2097160]
+        map.insert(882, &[4194328]);     // 882 => [4194328]
+        map.insert(883, &[2097162]);     // 883 => [2097162]
+        map.insert(884, &[1048705]);     // 884 => [1048705]
+        map.insert(885, &[1048704]);     // 885 => [1048704]
+        map.insert(886, &[524424]);     // 886 => [524424]
+        map.insert(887, &[1048706]);     // 887 => [1048706]
+        map.insert(888, &[2097153]);     // 888 => [2097153]
+        map.insert(889, &[2097152]);     // 889 => [2097152]
+        map.insert(890, &[4194320]);     // 890 => [4194320]
+        map.insert(891, &[2097154]);     // 891 => [2097154]
+        map.insert(892, &[2097157]);     // 892 => [2097157]
+        map.insert(893, &[2097156]);     // 893 => [2097156]
+        map.insert(894, &[524416]);     // 894 => [524416]
+        map.insert(895, &[16448]);     // 895 => [16448]
+        map.insert(896, &[16777233]);     // 896 => [16777233]
+        map.insert(897, &[16777232]);     // 897 => [16777232]
+        map.insert(898, &[1114112]);     // 898 => [1114112]
+        map.insert(899, &[1114113]);     // 899 => [1114113]
+        map.insert(900, &[3200]);     // 900 => [3200]
+        map.insert(901, &[16777236]);     // 901 => [16777236]
+        map.insert(902, &[1114116]);     // 902 => [1114116]
+        map.insert(903, &[17920]);     // 903 => [17920]
+        map.insert(904, &[589825]);     // 904 => [589825]
+        map.insert(905, &[589824]);     // 905 => [589824]
+        map.insert(906, &[8388676]);     // 906 => [8388676]
+        map.insert(907, &[589826]);     // 907 => [589826]
+        map.insert(908, &[8388674]);     // 908 => [8388674]
+        map.insert(909, &[589828]);     // 909 => [589828]
+        map.insert(910, &[8388672]);     // 910 => [8388672]
+        map.insert(911, &[8388673]);     // 911 => [8388673]
+        map.insert(912, &[16777217]);     // 912 => [16777217]
+        map.insert(913, &[16777216]);     // 913 => [16777216]
+        map.insert(914, &[8388640]);     // 914 => [8388640]
+        map.insert(915, &[16777218]);     // 915 => [16777218]
+        map.insert(916, &[16777221]);     // 916 => [16777221]
+        map.insert(917, &[16777220]);     // 917 => [16777220]
+        map.insert(918, &[67109888]);     // 918 => [67109888]
+        map.insert(919, &[67109889]);     // 919 => [67109889]
+        map.insert(920, &[16777225]);     // 920 => [16777225]
+        map.insert(921, &[16777224]);     // 921 => [16777224]
+        map.insert(922, &[133120]);     // 922 => [133120]
+        map.insert(923, &[4608]);     // 923 => [4608]
+        map.insert(924, &[264193]);     // 924 => [264193]
+        map.insert(925, &[264192]);     // 925 => [264192]
+        map.insert(926, &[133124]);     // 926 => [133124]
+        map.insert(927, &[264194]);     // 927 => [264194]
+        map.insert(928, &[8388626]);     // 928 => [8388626]
+        map.insert(929, &[33555464]);     // 929 => [33555464]
+        map.insert(930, &[8388624]);     // 930 => [8388624]
+        map.insert(931, &[8388625]);     // 931 => [8388625]
+        map.insert(932, &[655872]);     // 932 => [655872]
+        map.insert(933, &[16777288]);     // 933 => [16777288]
+        map.insert(934, &[8388628]);     // 934 => [8388628]
+        map.insert(935, &[4672]);     // 935 => [4672]
+        map.insert(936, &[34816]);     // 936 => [34816]
+        map.insert(937, &[33555456]);     // 937 => [33555456]
+        map.insert(938, &[34818]);     // 938 => [34818]
+        map.insert(939, &[33555458]);     // 939 => [33555458]
+        map.insert(940, &[16777281]);     // 940 => [16777281]
+        map.insert(941, &[16777280]);     // 941 => [16777280]
+        map.insert(942, &[524560]);     // 942 => [524560]
+        map.insert(943, &[16777282]);     // 943 => [16777282]
+        map.insert(944, &[8388610]);     // 944 => [8388610]
+        map.insert(945, &[16777248]);     // 945 => [16777248]
+        map.insert(946, &[8388608]);     // 946 => [8388608]
+        map.insert(947, &[8388609]);     // 947 => [8388609]
+        map.insert(948, &[1048833]);     // 948 => [1048833]
+        map.insert(949, &[1048832]);     // 949 => [1048832]
+        map.insert(950, &[8388612]);     // 950 => [8388612]
+        map.insert(951, &[8388613]);     // 951 => [8388613]
+        map.insert(952, &[8388618]);     // 952 => [8388618]
+        map.insert(953, &[16777256]);     // 953 => [16777256]
+        map.insert(954, &[8388616]);     // 954 => [8388616]
+        map.insert(955, &[8388617]);     // 955 => [8388617]
+        map.insert(956, &[524546]);     // 956 => [524546]
+        map.insert(957, &[1048840]);     // 957 => [1048840]
+        map.insert(958, &[524544]);     // 958 => [524544]
+        map.insert(959, &[524545]);     // 959 => [524545]
+        map.insert(960, &[3076]);     // 960 => [3076]
+        map.insert(961, &[16642]);     // 961 => [16642]
+        map.insert(962, &[16641]);     // 962 => [16641]
+        map.insert(963, &[16640]);     // 963 => [16640]
+        map.insert(964, &[3072]);     // 964 => [3072]
+        map.insert(965, &[33587200]);     // 965 => [33587200]
+        map.insert(966, &[3074]);     // 966 => [3074]
+        map.insert(967, &[33587202]);     // 967 => [33587202]
+        map.insert(968, &[67239936]);     // 968 => [67239936]
+        map.insert(969, &[67239937]);     // 969 => [67239937]
+        map.insert(970, &[67239938]);     // 970 => [67239938]
+        map.insert(971, &[67371012]);     // 971 => [67371012]
+        map.insert(972, &[2162690]);     // 972 => [2162690]
+        map.insert(973, &[67371010]);     // 973 => [67371010]
+        map.insert(974, &[2162688]);     // 974 => [2162688]
+        map.insert(975, &[67371008]);     // 975 => [67371008]
+        map.insert(976, &[16777345]);     // 976 => [16777345]
+        map.insert(977, &[16777344]);     // 977 => [16777344]
+        map.insert(978, &[8388768]);     // 978 => [8388768]
+        map.insert(979, &[16777346]);     // 979 => [16777346]
+        map.insert(980, &[3088]);     // 980 => [3088]
+        map.insert(981, &[16777348]);     // 981 => [16777348]
+        map.insert(982, &[4194624]);     // 982 => [4194624]
+        map.insert(983, &[33685536]);     // 983 => [33685536]
+        map.insert(984, &[67239952]);     // 984 => [67239952]
+        map.insert(985, &[4259844]);     // 985 => [4259844]
+        map.insert(986, &[67141664]);     // 986 => [67141664]
+        map.insert(987, &[4736]);     // 987 => [4736]
+        map.insert(988, &[4259841]);     // 988 => [4259841]
+        map.insert(989, &[4259840]);     // 989 => [4259840]
+        map.insert(990, &[2162704]);     // 990 => [2162704]
+        map.insert(991, &[4259842]);     // 991 => [4259842]
+        map.insert(992, &[33816592]);     // 992 => [33816592]
+        map.insert(993, &[4259904]);     // 993 => [4259904]
+        map.insert(994, &[4194568]);     // 994 => [4194568]
+        map.insert(995, &[16672]);     // 995 => [16672]
+        map.insert(996, &[81936]);     // 996 => [81936]
+        map.insert(997, &[33587232]);     // 997 => [33587232]
+        map.insert(998, &[139520]);     // 998 => [139520]
+        map.insert(999, &[33685520]);     // 999 => [33685520]
+        map.insert(1000, &[4194562]);     // 1000 => [4194562]
+        map.insert(1001, &[2097424]);     // 1001 => [2097424]
+        map.insert(1002, &[4194560]);     // 1002 => [4194560]
+        map.insert(1003, &[4194561]);     // 1003 => [4194561]
+        map.insert(1004, &[8720]);     // 1004 => [8720]
+        map.insert(1005, &[16777408]);     // 1005 => [16777408]
+        map.insert(1006, &[4194564]);     // 1006 => [4194564]
+        map.insert(1007, &[67371040]);     // 1007 => [67371040]
+        map.insert(1008, &[33816576]);     // 1008 => [33816576]
+        map.insert(1009, &[33816577]);     // 1009 => [33816577]
+        map.insert(1010, &[8388736]);     // 1010 => [8388736]
+        map.insert(1011, &[8388737]);     // 1011 => [8388737]
+        map.insert(1012, &[81920]);     // 1012 => [81920]
+        map.insert(1013, &[81921]);     // 1013 => [81921]
+        map.insert(1014, &[33685505]);     // 1014 => [33685505]
+        map.insert(1015, &[33685504]);     // 1015 => [33685504]
+        map.insert(1016, &[2097409]);     // 1016 => [2097409]
+        map.insert(1017, &[2097408]);     // 1017 => [2097408]
+        map.insert(1018, &[67141632]);     // 1018 => [67141632]
+        map.insert(1019, &[67141633]);     // 1019 => [67141633]
+        map.insert(1020, &[8704]);     // 1020 => [8704]
+        map.insert(1021, &[8705]);     // 1021 => [8705]
+        map.insert(1022, &[8706]);     // 1022 => [8706]
+        map.insert(1023, &[33685512]);     // 1023 => [33685512]
+        map
+    })
 }
 
 impl WagnerCode27_17 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -1117,24 +1117,15 @@ impl BinaryCode for WagnerCode27_17 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(27);
@@ -1159,14 +1150,19 @@ impl BinaryCode for WagnerCode27_17 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[27 / 64] & !((1 << 27) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2621,8 +2617,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, true, false, true, false, true ]);
         assert_eq!(vector, first_row.as_vector());
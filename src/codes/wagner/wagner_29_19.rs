@@ -1,6 +1,5 @@
-use std::boxed::Box;
 use std::default::Default;
-use std::sync::Once;
+use std::sync::OnceLock;
 
 use fnv::FnvHashMap;
 
@@ -15,1093 +14,1094 @@ use crate::codes::BinaryCode;
 #[derive(Clone, Serialize)]
 pub struct WagnerCode29_19;
 
-static INIT: Once = Once::new();
-static mut GENERATOR_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX: *const BinMatrix = 0 as *const BinMatrix;
-static mut PARITY_MATRIX_T: *const BinMatrix = 0 as *const BinMatrix;
-static mut SYNDROME_MAP: *const FnvHashMap<u64, &'static [usize; 1]> = 0 as *const FnvHashMap<u64, &'static [usize; 1]>;
+static GENERATOR_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX: OnceLock<BinMatrix> = OnceLock::new();
+static PARITY_MATRIX_T: OnceLock<BinMatrix> = OnceLock::new();
+static SYNDROME_MAP: OnceLock<FnvHashMap<u64, &'static [usize; 1]>> = OnceLock::new();
 
-fn init() {
-    INIT.call_once(|| {
-        unsafe {
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 357564417 ],
-                &[ 178782210 ],
-                &[ 534773764 ],
-                &[ 530055176 ],
-                &[ 511180816 ],
-                &[ 435683360 ],
-                &[ 133693504 ],
-                &[ 12058752 ],
-                &[ 39321856 ],
-                &[ 152568320 ],
-                &[ 337118208 ],
-                &[ 73926656 ],
-                &[ 405278720 ],
-                &[ 24125440 ],
-                &[ 305152000 ],
-                &[ 237010944 ],
-                &[ 226557952 ],
-                &[ 332005376 ],
-                &[ 484179968 ],
-                
-            ], 29));
-            GENERATOR_MATRIX = Box::into_raw(matrix);
-
-            let matrix = Box::new(BinMatrix::from_slices(&[
-                &[ 215306241 ],
-                &[ 321474562 ],
-                &[ 142025796 ],
-                &[ 163262536 ],
-                &[ 385527888 ],
-                &[ 199598176 ],
-                &[ 428336256 ],
-                &[ 102577408 ],
-                &[ 134202880 ],
-                &[ 535197696 ],
-                
-            ], 29));
-            let matrix_t = Box::new(matrix.transposed());
-            PARITY_MATRIX = Box::into_raw(matrix);
-            PARITY_MATRIX_T = Box::into_raw(matrix_t);
+fn generator_matrix() -> &'static BinMatrix {
+    GENERATOR_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 357564417 ],
+            &[ 178782210 ],
+            &[ 534773764 ],
+            &[ 530055176 ],
+            &[ 511180816 ],
+            &[ 435683360 ],
+            &[ 133693504 ],
+            &[ 12058752 ],
+            &[ 39321856 ],
+            &[ 152568320 ],
+            &[ 337118208 ],
+            &[ 73926656 ],
+            &[ 405278720 ],
+            &[ 24125440 ],
+            &[ 305152000 ],
+            &[ 237010944 ],
+            &[ 226557952 ],
+            &[ 332005376 ],
+            &[ 484179968 ],
+            
+        ], 29)
+    })
+}
 
-            let mut map = Box::new(FnvHashMap::with_capacity_and_hasher(1024, Default::default()));
-            map.insert(0, &[0]);     // 0 => [0]
-            map.insert(1, &[1]);     // 1 => [1]
-            map.insert(2, &[2]);     // 2 => [2]
-            map.insert(3, &[3]);     // 3 => [3]
-            map.insert(4, &[4]);     // 4 => [4]
-            map.insert(5, &[5]);     // 5 => [5]
-            map.insert(6, &[6]);     // 6 => [6]
-            map.insert(7, &[1572864]);     // 7 => [1572864]
-            map.insert(8, &[8]);     // 8 => [8]
-            map.insert(9, &[9]);     // 9 => [9]
-            map.insert(10, &[10]);     // 10 => [10]
-            map.insert(11, &[6291456]);     // 11 => [6291456]
-            map.insert(12, &[12]);     // 12 => [12]
-            map.insert(13, &[13]);     // 13 => [13]
-            map.insert(14, &[14]);     // 14 => [14]
-            map.insert(15, &[6291460]);     // 15 => [6291460]
-            map.insert(16, &[16]);     // 16 => [16]
-            map.insert(17, &[17]);     // 17 => [17]
-            map.insert(18, &[18]);     // 18 => [18]
-            map.insert(19, &[25165824]);     // 19 => [25165824]
-            map.insert(20, &[20]);     // 20 => [20]
-            map.insert(21, &[21]);     // 21 => [21]
-            map.insert(22, &[22]);     // 22 => [22]
-            map.insert(23, &[25165828]);     // 23 => [25165828]
-            map.insert(24, &[24]);     // 24 => [24]
-            map.insert(25, &[25]);     // 25 => [25]
-            map.insert(26, &[26]);     // 26 => [26]
-            map.insert(27, &[147460]);     // 27 => [147460]
-            map.insert(28, &[96]);     // 28 => [96]
-            map.insert(29, &[97]);     // 29 => [97]
-            map.insert(30, &[147457]);     // 30 => [147457]
-            map.insert(31, &[147456]);     // 31 => [147456]
-            map.insert(32, &[32]);     // 32 => [32]
-            map.insert(33, &[33]);     // 33 => [33]
-            map.insert(34, &[34]);     // 34 => [34]
-            map.insert(35, &[100663296]);     // 35 => [100663296]
-            map.insert(36, &[36]);     // 36 => [36]
-            map.insert(37, &[8519680]);     // 37 => [8519680]
-            map.insert(38, &[38]);     // 38 => [38]
-            map.insert(39, &[8519682]);     // 39 => [8519682]
-            map.insert(40, &[40]);     // 40 => [40]
-            map.insert(41, &[16793600]);     // 41 => [16793600]
-            map.insert(42, &[66561]);     // 42 => [66561]
-            map.insert(43, &[66560]);     // 43 => [66560]
-            map.insert(44, &[80]);     // 44 => [80]
-            map.insert(45, &[81]);     // 45 => [81]
-            map.insert(46, &[82]);     // 46 => [82]
-            map.insert(47, &[66564]);     // 47 => [66564]
-            map.insert(48, &[48]);     // 48 => [48]
-            map.insert(49, &[49]);     // 49 => [49]
-            map.insert(50, &[50]);     // 50 => [50]
-            map.insert(51, &[100663312]);     // 51 => [100663312]
-            map.insert(52, &[72]);     // 52 => [72]
-            map.insert(53, &[73]);     // 53 => [73]
-            map.insert(54, &[16908288]);     // 54 => [16908288]
-            map.insert(55, &[16908289]);     // 55 => [16908289]
-            map.insert(56, &[68]);     // 56 => [68]
-            map.insert(57, &[69]);     // 57 => [69]
-            map.insert(58, &[8404992]);     // 58 => [8404992]
-            map.insert(59, &[8404993]);     // 59 => [8404993]
-            map.insert(60, &[64]);     // 60 => [64]
-            map.insert(61, &[65]);     // 61 => [65]
-            map.insert(62, &[66]);     // 62 => [66]
-            map.insert(63, &[402653184]);     // 63 => [402653184]
-            map.insert(64, &[128]);     // 64 => [128]
-            map.insert(65, &[129]);     // 65 => [129]
-            map.insert(66, &[130]);     // 66 => [130]
-            map.insert(67, &[131]);     // 67 => [131]
-            map.insert(68, &[132]);     // 68 => [132]
-            map.insert(69, &[133]);     // 69 => [133]
-            map.insert(70, &[10485761]);     // 70 => [10485761]
-            map.insert(71, &[10485760]);     // 71 => [10485760]
-            map.insert(72, &[136]);     // 72 => [136]
-            map.insert(73, &[137]);     // 73 => [137]
-            map.insert(74, &[138]);     // 74 => [138]
-            map.insert(75, &[6291584]);     // 75 => [6291584]
-            map.insert(76, &[12582912]);     // 76 => [12582912]
-            map.insert(77, &[12582913]);     // 77 => [12582913]
-            map.insert(78, &[12582914]);     // 78 => [12582914]
-            map.insert(79, &[10485768]);     // 79 => [10485768]
-            map.insert(80, &[144]);     // 80 => [144]
-            map.insert(81, &[145]);     // 81 => [145]
-            map.insert(82, &[268437504]);     // 82 => [268437504]
-            map.insert(83, &[268437505]);     // 83 => [268437505]
-            map.insert(84, &[18874368]);     // 84 => [18874368]
-            map.insert(85, &[18874369]);     // 85 => [18874369]
-            map.insert(86, &[33816577]);     // 86 => [33816577]
-            map.insert(87, &[33816576]);     // 87 => [33816576]
-            map.insert(88, &[1049601]);     // 88 => [1049601]
-            map.insert(89, &[1049600]);     // 89 => [1049600]
-            map.insert(90, &[525316]);     // 90 => [525316]
-            map.insert(91, &[1049602]);     // 91 => [1049602]
-            map.insert(92, &[525314]);     // 92 => [525314]
-            map.insert(93, &[20971522]);     // 93 => [20971522]
-            map.insert(94, &[525312]);     // 94 => [525312]
-            map.insert(95, &[20971520]);     // 95 => [20971520]
-            map.insert(96, &[160]);     // 96 => [160]
-            map.insert(97, &[161]);     // 97 => [161]
-            map.insert(98, &[2228224]);     // 98 => [2228224]
-            map.insert(99, &[2228225]);     // 99 => [2228225]
-            map.insert(100, &[164]);     // 100 => [164]
-            map.insert(101, &[12290]);     // 101 => [12290]
-            map.insert(102, &[12289]);     // 102 => [12289]
-            map.insert(103, &[12288]);     // 103 => [12288]
-            map.insert(104, &[4325377]);     // 104 => [4325377]
-            map.insert(105, &[4325376]);     // 105 => [4325376]
-            map.insert(106, &[2228232]);     // 106 => [2228232]
-            map.insert(107, &[4325378]);     // 107 => [4325378]
-            map.insert(108, &[134219777]);     // 108 => [134219777]
-            map.insert(109, &[134219776]);     // 109 => [134219776]
-            map.insert(110, &[268437568]);     // 110 => [268437568]
-            map.insert(111, &[134219778]);     // 111 => [134219778]
-            map.insert(112, &[33280]);     // 112 => [33280]
-            map.insert(113, &[33281]);     // 113 => [33281]
-            map.insert(114, &[1114112]);     // 114 => [1114112]
-            map.insert(115, &[1114113]);     // 115 => [1114113]
-            map.insert(116, &[67371008]);     // 116 => [67371008]
-            map.insert(117, &[589824]);     // 117 => [589824]
-            map.insert(118, &[4210688]);     // 118 => [4210688]
-            map.insert(119, &[4210689]);     // 119 => [4210689]
-            map.insert(120, &[196]);     // 120 => [196]
-            map.insert(121, &[2113540]);     // 121 => [2113540]
-            map.insert(122, &[1114120]);     // 122 => [1114120]
-            map.insert(123, &[10485824]);     // 123 => [10485824]
-            map.insert(124, &[192]);     // 124 => [192]
-            map.insert(125, &[2113536]);     // 125 => [2113536]
-            map.insert(126, &[194]);     // 126 => [194]
-            map.insert(127, &[2113538]);     // 127 => [2113538]
-            map.insert(128, &[256]);     // 128 => [256]
-            map.insert(129, &[257]);     // 129 => [257]
-            map.insert(130, &[258]);     // 130 => [258]
-            map.insert(131, &[259]);     // 131 => [259]
-            map.insert(132, &[260]);     // 132 => [260]
-            map.insert(133, &[261]);     // 133 => [261]
-            map.insert(134, &[37748737]);     // 134 => [37748737]
-            map.insert(135, &[37748736]);     // 135 => [37748736]
-            map.insert(136, &[264]);     // 136 => [264]
-            map.insert(137, &[265]);     // 137 => [265]
-            map.insert(138, &[266]);     // 138 => [266]
-            map.insert(139, &[17039364]);     // 139 => [17039364]
-            map.insert(140, &[35651584]);     // 140 => [35651584]
-            map.insert(141, &[35651585]);     // 141 => [35651585]
-            map.insert(142, &[17039361]);     // 142 => [17039361]
-            map.insert(143, &[17039360]);     // 143 => [17039360]
-            map.insert(144, &[272]);     // 144 => [272]
-            map.insert(145, &[134225920]);     // 145 => [134225920]
-            map.insert(146, &[274]);     // 146 => [274]
-            map.insert(147, &[134225922]);     // 147 => [134225922]
-            map.insert(148, &[276]);     // 148 => [276]
-            map.insert(149, &[134225924]);     // 149 => [134225924]
-            map.insert(150, &[16785920]);     // 150 => [16785920]
-            map.insert(151, &[98312]);     // 151 => [98312]
-            map.insert(152, &[524802]);     // 152 => [524802]
-            map.insert(153, &[6146]);     // 153 => [6146]
-            map.insert(154, &[524800]);     // 154 => [524800]
-            map.insert(155, &[6144]);     // 155 => [6144]
-            map.insert(156, &[8650752]);     // 156 => [8650752]
-            map.insert(157, &[1049088]);     // 157 => [1049088]
-            map.insert(158, &[98305]);     // 158 => [98305]
-            map.insert(159, &[98304]);     // 159 => [98304]
-            map.insert(160, &[288]);     // 160 => [288]
-            map.insert(161, &[289]);     // 161 => [289]
-            map.insert(162, &[290]);     // 162 => [290]
-            map.insert(163, &[98368]);     // 163 => [98368]
-            map.insert(164, &[71303168]);     // 164 => [71303168]
-            map.insert(165, &[71303169]);     // 165 => [71303169]
-            map.insert(166, &[278528]);     // 166 => [278528]
-            map.insert(167, &[278529]);     // 167 => [278529]
-            map.insert(168, &[296]);     // 168 => [296]
-            map.insert(169, &[393232]);     // 169 => [393232]
-            map.insert(170, &[268443652]);     // 170 => [268443652]
-            map.insert(171, &[69206020]);     // 171 => [69206020]
-            map.insert(172, &[268443650]);     // 172 => [268443650]
-            map.insert(173, &[69206018]);     // 173 => [69206018]
-            map.insert(174, &[268443648]);     // 174 => [268443648]
-            map.insert(175, &[69206016]);     // 175 => [69206016]
-            map.insert(176, &[33796]);     // 176 => [33796]
-            map.insert(177, &[393224]);     // 177 => [393224]
-            map.insert(178, &[33556992]);     // 178 => [33556992]
-            map.insert(179, &[17039424]);     // 179 => [17039424]
-            map.insert(180, &[33792]);     // 180 => [33792]
-            map.insert(181, &[33793]);     // 181 => [33793]
-            map.insert(182, &[33794]);     // 182 => [33794]
-            map.insert(183, &[268698112]);     // 183 => [268698112]
-            map.insert(184, &[393217]);     // 184 => [393217]
-            map.insert(185, &[393216]);     // 185 => [393216]
-            map.insert(186, &[524832]);     // 186 => [524832]
-            map.insert(187, &[393218]);     // 187 => [393218]
-            map.insert(188, &[320]);     // 188 => [320]
-            map.insert(189, &[321]);     // 189 => [321]
-            map.insert(190, &[322]);     // 190 => [322]
-            map.insert(191, &[69206032]);     // 191 => [69206032]
-            map.insert(192, &[384]);     // 192 => [384]
-            map.insert(193, &[385]);     // 193 => [385]
-            map.insert(194, &[386]);     // 194 => [386]
-            map.insert(195, &[41943048]);     // 195 => [41943048]
-            map.insert(196, &[1536]);     // 196 => [1536]
-            map.insert(197, &[1537]);     // 197 => [1537]
-            map.insert(198, &[1538]);     // 198 => [1538]
-            map.insert(199, &[83886144]);     // 199 => [83886144]
-            map.insert(200, &[268439553]);     // 200 => [268439553]
-            map.insert(201, &[268439552]);     // 201 => [268439552]
-            map.insert(202, &[41943041]);     // 202 => [41943041]
-            map.insert(203, &[41943040]);     // 203 => [41943040]
-            map.insert(204, &[67239937]);     // 204 => [67239937]
-            map.insert(205, &[67239936]);     // 205 => [67239936]
-            map.insert(206, &[33685536]);     // 206 => [33685536]
-            map.insert(207, &[67239938]);     // 207 => [67239938]
-            map.insert(208, &[4456448]);     // 208 => [4456448]
-            map.insert(209, &[4456449]);     // 209 => [4456449]
-            map.insert(210, &[67125248]);     // 210 => [67125248]
-            map.insert(211, &[67125249]);     // 211 => [67125249]
-            map.insert(212, &[4456452]);     // 212 => [4456452]
-            map.insert(213, &[268452864]);     // 213 => [268452864]
-            map.insert(214, &[67125252]);     // 214 => [67125252]
-            map.insert(215, &[33816832]);     // 215 => [33816832]
-            map.insert(216, &[50331648]);     // 216 => [50331648]
-            map.insert(217, &[50331649]);     // 217 => [50331649]
-            map.insert(218, &[2359297]);     // 218 => [2359297]
-            map.insert(219, &[2359296]);     // 219 => [2359296]
-            map.insert(220, &[50331652]);     // 220 => [50331652]
-            map.insert(221, &[67239952]);     // 221 => [67239952]
-            map.insert(222, &[525568]);     // 222 => [525568]
-            map.insert(223, &[2359300]);     // 223 => [2359300]
-            map.insert(224, &[75497480]);     // 224 => [75497480]
-            map.insert(225, &[33570832]);     // 225 => [33570832]
-            map.insert(226, &[557064]);     // 226 => [557064]
-            map.insert(227, &[8914944]);     // 227 => [8914944]
-            map.insert(228, &[1568]);     // 228 => [1568]
-            map.insert(229, &[1081352]);     // 229 => [1081352]
-            map.insert(230, &[33685512]);     // 230 => [33685512]
-            map.insert(231, &[66056]);     // 231 => [66056]
-            map.insert(232, &[75497472]);     // 232 => [75497472]
-            map.insert(233, &[75497473]);     // 233 => [75497473]
-            map.insert(234, &[557056]);     // 234 => [557056]
-            map.insert(235, &[557057]);     // 235 => [557057]
-            map.insert(236, &[1081345]);     // 236 => [1081345]
-            map.insert(237, &[1081344]);     // 237 => [1081344]
-            map.insert(238, &[33685504]);     // 238 => [33685504]
-            map.insert(239, &[66048]);     // 239 => [66048]
-            map.insert(240, &[33570817]);     // 240 => [33570817]
-            map.insert(241, &[33570816]);     // 241 => [33570816]
-            map.insert(242, &[134221828]);     // 242 => [134221828]
-            map.insert(243, &[33570818]);     // 243 => [33570818]
-            map.insert(244, &[134221826]);     // 244 => [134221826]
-            map.insert(245, &[33570820]);     // 245 => [33570820]
-            map.insert(246, &[134221824]);     // 246 => [134221824]
-            map.insert(247, &[134221825]);     // 247 => [134221825]
-            map.insert(248, &[10244]);     // 248 => [10244]
-            map.insert(249, &[83886082]);     // 249 => [83886082]
-            map.insert(250, &[83886081]);     // 250 => [83886081]
-            map.insert(251, &[83886080]);     // 251 => [83886080]
-            map.insert(252, &[10240]);     // 252 => [10240]
-            map.insert(253, &[10241]);     // 253 => [10241]
-            map.insert(254, &[10242]);     // 254 => [10242]
-            map.insert(255, &[83886084]);     // 255 => [83886084]
-            map.insert(256, &[512]);     // 256 => [512]
-            map.insert(257, &[513]);     // 257 => [513]
-            map.insert(258, &[514]);     // 258 => [514]
-            map.insert(259, &[515]);     // 259 => [515]
-            map.insert(260, &[516]);     // 260 => [516]
-            map.insert(261, &[517]);     // 261 => [517]
-            map.insert(262, &[150994945]);     // 262 => [150994945]
-            map.insert(263, &[150994944]);     // 263 => [150994944]
-            map.insert(264, &[520]);     // 264 => [520]
-            map.insert(265, &[521]);     // 265 => [521]
-            map.insert(266, &[67112960]);     // 266 => [67112960]
-            map.insert(267, &[67112961]);     // 267 => [67112961]
-            map.insert(268, &[268566530]);     // 268 => [268566530]
-            map.insert(269, &[1048848]);     // 269 => [1048848]
-            map.insert(270, &[268566528]);     // 270 => [268566528]
-            map.insert(271, &[268566529]);     // 271 => [268566529]
-            map.insert(272, &[528]);     // 272 => [528]
-            map.insert(273, &[268451840]);     // 273 => [268451840]
-            map.insert(274, &[530]);     // 274 => [530]
-            map.insert(275, &[268451842]);     // 275 => [268451842]
-            map.insert(276, &[142606336]);     // 276 => [142606336]
-            map.insert(277, &[142606337]);     // 277 => [142606337]
-            map.insert(278, &[142606338]);     // 278 => [142606338]
-            map.insert(279, &[150994960]);     // 279 => [150994960]
-            map.insert(280, &[270337]);     // 280 => [270337]
-            map.insert(281, &[270336]);     // 281 => [270336]
-            map.insert(282, &[524544]);     // 282 => [524544]
-            map.insert(283, &[524545]);     // 283 => [524545]
-            map.insert(284, &[1048833]);     // 284 => [1048833]
-            map.insert(285, &[1048832]);     // 285 => [1048832]
-            map.insert(286, &[524548]);     // 286 => [524548]
-            map.insert(287, &[1048834]);     // 287 => [1048834]
-            map.insert(288, &[544]);     // 288 => [544]
-            map.insert(289, &[545]);     // 289 => [545]
-            map.insert(290, &[546]);     // 290 => [546]
-            map.insert(291, &[276824072]);     // 291 => [276824072]
-            map.insert(292, &[548]);     // 292 => [548]
-            map.insert(293, &[4196368]);     // 293 => [4196368]
-            map.insert(294, &[134234120]);     // 294 => [134234120]
-            map.insert(295, &[150994976]);     // 295 => [150994976]
-            map.insert(296, &[33558529]);     // 296 => [33558529]
-            map.insert(297, &[33558528]);     // 297 => [33558528]
-            map.insert(298, &[276824065]);     // 298 => [276824065]
-            map.insert(299, &[276824064]);     // 299 => [276824064]
-            map.insert(300, &[134234114]);     // 300 => [134234114]
-            map.insert(301, &[33558532]);     // 301 => [33558532]
-            map.insert(302, &[134234112]);     // 302 => [134234112]
-            map.insert(303, &[134234113]);     // 303 => [134234113]
-            map.insert(304, &[32896]);     // 304 => [32896]
-            map.insert(305, &[134348800]);     // 305 => [134348800]
-            map.insert(306, &[32898]);     // 306 => [32898]
-            map.insert(307, &[134348802]);     // 307 => [134348802]
-            map.insert(308, &[4196353]);     // 308 => [4196353]
-            map.insert(309, &[4196352]);     // 309 => [4196352]
-            map.insert(310, &[2099208]);     // 310 => [2099208]
-            map.insert(311, &[4196354]);     // 311 => [4196354]
-            map.insert(312, &[285212672]);     // 312 => [285212672]
-            map.insert(313, &[285212673]);     // 313 => [285212673]
-            map.insert(314, &[285212674]);     // 314 => [285212674]
-            map.insert(315, &[276824080]);     // 315 => [276824080]
-            map.insert(316, &[576]);     // 316 => [576]
-            map.insert(317, &[577]);     // 317 => [577]
-            map.insert(318, &[2099200]);     // 318 => [2099200]
-            map.insert(319, &[2099201]);     // 319 => [2099201]
-            map.insert(320, &[640]);     // 320 => [640]
-            map.insert(321, &[641]);     // 321 => [641]
-            map.insert(322, &[18433]);     // 322 => [18433]
-            map.insert(323, &[18432]);     // 323 => [18432]
-            map.insert(324, &[1280]);     // 324 => [1280]
-            map.insert(325, &[1281]);     // 325 => [1281]
-            map.insert(326, &[1282]);     // 326 => [1282]
-            map.insert(327, &[18436]);     // 327 => [18436]
-            map.insert(328, &[32836]);     // 328 => [32836]
-            map.insert(329, &[4202752]);     // 329 => [4202752]
-            map.insert(330, &[33562628]);     // 330 => [33562628]
-            map.insert(331, &[18440]);     // 331 => [18440]
-            map.insert(332, &[32832]);     // 332 => [32832]
-            map.insert(333, &[32833]);     // 333 => [32833]
-            map.insert(334, &[33562624]);     // 334 => [33562624]
-            map.insert(335, &[33562625]);     // 335 => [33562625]
-            map.insert(336, &[32800]);     // 336 => [32800]
-            map.insert(337, &[32801]);     // 337 => [32801]
-            map.insert(338, &[136314881]);     // 338 => [136314881]
-            map.insert(339, &[136314880]);     // 339 => [136314880]
-            map.insert(340, &[32804]);     // 340 => [32804]
-            map.insert(341, &[134226944]);     // 341 => [134226944]
-            map.insert(342, &[16779328]);     // 342 => [16779328]
-            map.insert(343, &[136314884]);     // 343 => [136314884]
-            map.insert(344, &[138412032]);     // 344 => [138412032]
-            map.insert(345, &[138412033]);     // 345 => [138412033]
-            map.insert(346, &[138412034]);     // 346 => [138412034]
-            map.insert(347, &[136314888]);     // 347 => [136314888]
-            map.insert(348, &[133120]);     // 348 => [133120]
-            map.insert(349, &[133121]);     // 349 => [133121]
-            map.insert(350, &[133122]);     // 350 => [133122]
-            map.insert(351, &[167772416]);     // 351 => [167772416]
-            map.insert(352, &[32784]);     // 352 => [32784]
-            map.insert(353, &[32785]);     // 353 => [32785]
-            map.insert(354, &[32786]);     // 354 => [32786]
-            map.insert(355, &[272629764]);     // 355 => [272629764]
-            map.insert(356, &[32788]);     // 356 => [32788]
-            map.insert(357, &[272629762]);     // 357 => [272629762]
-            map.insert(358, &[272629761]);     // 358 => [272629761]
-            map.insert(359, &[272629760]);     // 359 => [272629760]
-            map.insert(360, &[16779266]);     // 360 => [16779266]
-            map.insert(361, &[67117060]);     // 361 => [67117060]
-            map.insert(362, &[16779264]);     // 362 => [16779264]
-            map.insert(363, &[16779265]);     // 363 => [16779265]
-            map.insert(364, &[270532608]);     // 364 => [270532608]
-            map.insert(365, &[67117056]);     // 365 => [67117056]
-            map.insert(366, &[65793]);     // 366 => [65793]
-            map.insert(367, &[65792]);     // 367 => [65792]
-            map.insert(368, &[32768]);     // 368 => [32768]
-            map.insert(369, &[32769]);     // 369 => [32769]
-            map.insert(370, &[32770]);     // 370 => [32770]
-            map.insert(371, &[32771]);     // 371 => [32771]
-            map.insert(372, &[32772]);     // 372 => [32772]
-            map.insert(373, &[32773]);     // 373 => [32773]
-            map.insert(374, &[32774]);     // 374 => [32774]
-            map.insert(375, &[272629776]);     // 375 => [272629776]
-            map.insert(376, &[32776]);     // 376 => [32776]
-            map.insert(377, &[8390656]);     // 377 => [8390656]
-            map.insert(378, &[32778]);     // 378 => [32778]
-            map.insert(379, &[8390658]);     // 379 => [8390658]
-            map.insert(380, &[266242]);     // 380 => [266242]
-            map.insert(381, &[8390660]);     // 381 => [8390660]
-            map.insert(382, &[266240]);     // 382 => [266240]
-            map.insert(383, &[266241]);     // 383 => [266241]
-            map.insert(384, &[768]);     // 384 => [768]
-            map.insert(385, &[769]);     // 385 => [769]
-            map.insert(386, &[770]);     // 386 => [770]
-            map.insert(387, &[24640]);     // 387 => [24640]
-            map.insert(388, &[1152]);     // 388 => [1152]
-            map.insert(389, &[8396800]);     // 389 => [8396800]
-            map.insert(390, &[1154]);     // 390 => [1154]
-            map.insert(391, &[8396802]);     // 391 => [8396802]
-            map.insert(392, &[134479872]);     // 392 => [134479872]
-            map.insert(393, &[134479873]);     // 393 => [134479873]
-            map.insert(394, &[524304]);     // 394 => [524304]
-            map.insert(395, &[524305]);     // 395 => [524305]
-            map.insert(396, &[1048593]);     // 396 => [1048593]
-            map.insert(397, &[1048592]);     // 397 => [1048592]
-            map.insert(398, &[524308]);     // 398 => [524308]
-            map.insert(399, &[1048594]);     // 399 => [1048594]
-            map.insert(400, &[67110913]);     // 400 => [67110913]
-            map.insert(401, &[67110912]);     // 401 => [67110912]
-            map.insert(402, &[524296]);     // 402 => [524296]
-            map.insert(403, &[524297]);     // 403 => [524297]
-            map.insert(404, &[1048585]);     // 404 => [1048585]
-            map.insert(405, &[1048584]);     // 405 => [1048584]
-            map.insert(406, &[16785408]);     // 406 => [16785408]
-            map.insert(407, &[16785409]);     // 407 => [16785409]
-            map.insert(408, &[524290]);     // 408 => [524290]
-            map.insert(409, &[1048580]);     // 409 => [1048580]
-            map.insert(410, &[524288]);     // 410 => [524288]
-            map.insert(411, &[524289]);     // 411 => [524289]
-            map.insert(412, &[1048577]);     // 412 => [1048577]
-            map.insert(413, &[1048576]);     // 413 => [1048576]
-            map.insert(414, &[524292]);     // 414 => [524292]
-            map.insert(415, &[1048578]);     // 415 => [1048578]
-            map.insert(416, &[139264]);     // 416 => [139264]
-            map.insert(417, &[1048640]);     // 417 => [1048640]
-            map.insert(418, &[139266]);     // 418 => [139266]
-            map.insert(419, &[1048642]);     // 419 => [1048642]
-            map.insert(420, &[2101249]);     // 420 => [2101249]
-            map.insert(421, &[2101248]);     // 421 => [2101248]
-            map.insert(422, &[524352]);     // 422 => [524352]
-            map.insert(423, &[524353]);     // 423 => [524353]
-            map.insert(424, &[139272]);     // 424 => [139272]
-            map.insert(425, &[1048648]);     // 425 => [1048648]
-            map.insert(426, &[4198404]);     // 426 => [4198404]
-            map.insert(427, &[65668]);     // 427 => [65668]
-            map.insert(428, &[4198402]);     // 428 => [4198402]
-            map.insert(429, &[65666]);     // 429 => [65666]
-            map.insert(430, &[4198400]);     // 430 => [4198400]
-            map.insert(431, &[65664]);     // 431 => [65664]
-            map.insert(432, &[33556482]);     // 432 => [33556482]
-            map.insert(433, &[1048656]);     // 433 => [1048656]
-            map.insert(434, &[33556480]);     // 434 => [33556480]
-            map.insert(435, &[33556481]);     // 435 => [33556481]
-            map.insert(436, &[134479936]);     // 436 => [134479936]
-            map.insert(437, &[268697602]);     // 437 => [268697602]
-            map.insert(438, &[268697601]);     // 438 => [268697601]
-            map.insert(439, &[268697600]);     // 439 => [268697600]
-            map.insert(440, &[524322]);     // 440 => [524322]
-            map.insert(441, &[1048612]);     // 441 => [1048612]
-            map.insert(442, &[524320]);     // 442 => [524320]
-            map.insert(443, &[524321]);     // 443 => [524321]
-            map.insert(444, &[1048609]);     // 444 => [1048609]
-            map.insert(445, &[1048608]);     // 445 => [1048608]
-            map.insert(446, &[24577]);     // 446 => [24577]
-            map.insert(447, &[24576]);     // 447 => [24576]
-            map.insert(448, &[1028]);     // 448 => [1028]
-            map.insert(449, &[1029]);     // 449 => [1029]
-            map.insert(450, &[2105344]);     // 450 => [2105344]
-            map.insert(451, &[335544320]);     // 451 => [335544320]
-            map.insert(452, &[1024]);     // 452 => [1024]
-            map.insert(453, &[1025]);     // 453 => [1025]
-            map.insert(454, &[1026]);     // 454 => [1026]
-            map.insert(455, &[135168]);     // 455 => [135168]
-            map.insert(456, &[4202497]);     // 456 => [4202497]
-            map.insert(457, &[4202496]);     // 457 => [4202496]
-            map.insert(458, &[2105352]);     // 458 => [2105352]
-            map.insert(459, &[4202498]);     // 459 => [4202498]
-            map.insert(460, &[1032]);     // 460 => [1032]
-            map.insert(461, &[1033]);     // 461 => [1033]
-            map.insert(462, &[65569]);     // 462 => [65569]
-            map.insert(463, &[65568]);     // 463 => [65568]
-            map.insert(464, &[1044]);     // 464 => [1044]
-            map.insert(465, &[65602]);     // 465 => [65602]
-            map.insert(466, &[65601]);     // 466 => [65601]
-            map.insert(467, &[65600]);     // 467 => [65600]
-            map.insert(468, &[1040]);     // 468 => [1040]
-            map.insert(469, &[1041]);     // 469 => [1041]
-            map.insert(470, &[1042]);     // 470 => [1042]
-            map.insert(471, &[65604]);     // 471 => [65604]
-            map.insert(472, &[20480]);     // 472 => [20480]
-            map.insert(473, &[20481]);     // 473 => [20481]
-            map.insert(474, &[524416]);     // 474 => [524416]
-            map.insert(475, &[524417]);     // 475 => [524417]
-            map.insert(476, &[1048705]);     // 476 => [1048705]
-            map.insert(477, &[1048704]);     // 477 => [1048704]
-            map.insert(478, &[167772161]);     // 478 => [167772161]
-            map.insert(479, &[167772160]);     // 479 => [167772160]
-            map.insert(480, &[301989888]);     // 480 => [301989888]
-            map.insert(481, &[301989889]);     // 481 => [301989889]
-            map.insert(482, &[8392704]);     // 482 => [8392704]
-            map.insert(483, &[8392705]);     // 483 => [8392705]
-            map.insert(484, &[1056]);     // 484 => [1056]
-            map.insert(485, &[264192]);     // 485 => [264192]
-            map.insert(486, &[65545]);     // 486 => [65545]
-            map.insert(487, &[65544]);     // 487 => [65544]
-            map.insert(488, &[301989896]);     // 488 => [301989896]
-            map.insert(489, &[65542]);     // 489 => [65542]
-            map.insert(490, &[65541]);     // 490 => [65541]
-            map.insert(491, &[65540]);     // 491 => [65540]
-            map.insert(492, &[65539]);     // 492 => [65539]
-            map.insert(493, &[65538]);     // 493 => [65538]
-            map.insert(494, &[65537]);     // 494 => [65537]
-            map.insert(495, &[65536]);     // 495 => [65536]
-            map.insert(496, &[33024]);     // 496 => [33024]
-            map.insert(497, &[16781312]);     // 497 => [16781312]
-            map.insert(498, &[33026]);     // 498 => [33026]
-            map.insert(499, &[16781314]);     // 499 => [16781314]
-            map.insert(500, &[33028]);     // 500 => [33028]
-            map.insert(501, &[16781316]);     // 501 => [16781316]
-            map.insert(502, &[134222336]);     // 502 => [134222336]
-            map.insert(503, &[65560]);     // 503 => [65560]
-            map.insert(504, &[1088]);     // 504 => [1088]
-            map.insert(505, &[1089]);     // 505 => [1089]
-            map.insert(506, &[1090]);     // 506 => [1090]
-            map.insert(507, &[65556]);     // 507 => [65556]
-            map.insert(508, &[201326592]);     // 508 => [201326592]
-            map.insert(509, &[201326593]);     // 509 => [201326593]
-            map.insert(510, &[65553]);     // 510 => [65553]
-            map.insert(511, &[65552]);     // 511 => [65552]
-            map.insert(512, &[2048]);     // 512 => [2048]
-            map.insert(513, &[2049]);     // 513 => [2049]
-            map.insert(514, &[2050]);     // 514 => [2050]
-            map.insert(515, &[2051]);     // 515 => [2051]
-            map.insert(516, &[2052]);     // 516 => [2052]
-            map.insert(517, &[2053]);     // 517 => [2053]
-            map.insert(518, &[2054]);     // 518 => [2054]
-            map.insert(519, &[17664]);     // 519 => [17664]
-            map.insert(520, &[2056]);     // 520 => [2056]
-            map.insert(521, &[8421376]);     // 521 => [8421376]
-            map.insert(522, &[327680]);     // 522 => [327680]
-            map.insert(523, &[67633152]);     // 523 => [67633152]
-            map.insert(524, &[68157440]);     // 524 => [68157440]
-            map.insert(525, &[68157441]);     // 525 => [68157441]
-            map.insert(526, &[68157442]);     // 526 => [68157442]
-            map.insert(527, &[67633156]);     // 527 => [67633156]
-            map.insert(528, &[2064]);     // 528 => [2064]
-            map.insert(529, &[2065]);     // 529 => [2065]
-            map.insert(530, &[268435584]);     // 530 => [268435584]
-            map.insert(531, &[268435585]);     // 531 => [268435585]
-            map.insert(532, &[2068]);     // 532 => [2068]
-            map.insert(533, &[4194848]);     // 533 => [4194848]
-            map.insert(534, &[268435588]);     // 534 => [268435588]
-            map.insert(535, &[35655680]);     // 535 => [35655680]
-            map.insert(536, &[16809986]);     // 536 => [16809986]
-            map.insert(537, &[4354]);     // 537 => [4354]
-            map.insert(538, &[16809984]);     // 538 => [16809984]
-            map.insert(539, &[4352]);     // 539 => [4352]
-            map.insert(540, &[68157456]);     // 540 => [68157456]
-            map.insert(541, &[263232]);     // 541 => [263232]
-            map.insert(542, &[16809988]);     // 542 => [16809988]
-            map.insert(543, &[4356]);     // 543 => [4356]
-            map.insert(544, &[2080]);     // 544 => [2080]
-            map.insert(545, &[263168]);     // 545 => [263168]
-            map.insert(546, &[2082]);     // 546 => [2082]
-            map.insert(547, &[263170]);     // 547 => [263170]
-            map.insert(548, &[2084]);     // 548 => [2084]
-            map.insert(549, &[263172]);     // 549 => [263172]
-            map.insert(550, &[16810048]);     // 550 => [16810048]
-            map.insert(551, &[34603016]);     // 551 => [34603016]
-            map.insert(552, &[34078720]);     // 552 => [34078720]
-            map.insert(553, &[34078721]);     // 553 => [34078721]
-            map.insert(554, &[34078722]);     // 554 => [34078722]
-            map.insert(555, &[34603012]);     // 555 => [34603012]
-            map.insert(556, &[163840]);     // 556 => [163840]
-            map.insert(557, &[134217856]);     // 557 => [134217856]
-            map.insert(558, &[34603009]);     // 558 => [34603009]
-            map.insert(559, &[34603008]);     // 559 => [34603008]
-            map.insert(560, &[2096]);     // 560 => [2096]
-            map.insert(561, &[49154]);     // 561 => [49154]
-            map.insert(562, &[49153]);     // 562 => [49153]
-            map.insert(563, &[49152]);     // 563 => [49152]
-            map.insert(564, &[4194817]);     // 564 => [4194817]
-            map.insert(565, &[4194816]);     // 565 => [4194816]
-            map.insert(566, &[2097672]);     // 566 => [2097672]
-            map.insert(567, &[4194818]);     // 567 => [4194818]
-            map.insert(568, &[2116]);     // 568 => [2116]
-            map.insert(569, &[8389248]);     // 569 => [8389248]
-            map.insert(570, &[2097668]);     // 570 => [2097668]
-            map.insert(571, &[49160]);     // 571 => [49160]
-            map.insert(572, &[2112]);     // 572 => [2112]
-            map.insert(573, &[2113]);     // 573 => [2113]
-            map.insert(574, &[2097664]);     // 574 => [2097664]
-            map.insert(575, &[2097665]);     // 575 => [2097665]
-            map.insert(576, &[2176]);     // 576 => [2176]
-            map.insert(577, &[2177]);     // 577 => [2177]
-            map.insert(578, &[268435472]);     // 578 => [268435472]
-            map.insert(579, &[16896]);     // 579 => [16896]
-            map.insert(580, &[4227073]);     // 580 => [4227073]
-            map.insert(581, &[4227072]);     // 581 => [4227072]
-            map.insert(582, &[268435476]);     // 582 => [268435476]
-            map.insert(583, &[4227074]);     // 583 => [4227074]
-            map.insert(584, &[2184]);     // 584 => [2184]
-            map.insert(585, &[134217764]);     // 585 => [134217764]
-            map.insert(586, &[2129924]);     // 586 => [2129924]
-            map.insert(587, &[16904]);     // 587 => [16904]
-            map.insert(588, &[134217761]);     // 588 => [134217761]
-            map.insert(589, &[134217760]);     // 589 => [134217760]
-            map.insert(590, &[2129920]);     // 590 => [2129920]
-            map.insert(591, &[2129921]);     // 591 => [2129921]
-            map.insert(592, &[268435458]);     // 592 => [268435458]
-            map.insert(593, &[134217792]);     // 593 => [134217792]
-            map.insert(594, &[268435456]);     // 594 => [268435456]
-            map.insert(595, &[268435457]);     // 595 => [268435457]
-            map.insert(596, &[67109889]);     // 596 => [67109889]
-            map.insert(597, &[67109888]);     // 597 => [67109888]
-            map.insert(598, &[268435460]);     // 598 => [268435460]
-            map.insert(599, &[268435461]);     // 599 => [268435461]
-            map.insert(600, &[268435466]);     // 600 => [268435466]
-            map.insert(601, &[33619972]);     // 601 => [33619972]
-            map.insert(602, &[268435464]);     // 602 => [268435464]
-            map.insert(603, &[268435465]);     // 603 => [268435465]
-            map.insert(604, &[131584]);     // 604 => [131584]
-            map.insert(605, &[33619968]);     // 605 => [33619968]
-            map.insert(606, &[131586]);     // 606 => [131586]
-            map.insert(607, &[33619970]);     // 607 => [33619970]
-            map.insert(608, &[2208]);     // 608 => [2208]
-            map.insert(609, &[134217740]);     // 609 => [134217740]
-            map.insert(610, &[16777736]);     // 610 => [16777736]
-            map.insert(611, &[16928]);     // 611 => [16928]
-            map.insert(612, &[134217737]);     // 612 => [134217737]
-            map.insert(613, &[134217736]);     // 613 => [134217736]
-            map.insert(614, &[268435528]);     // 614 => [268435528]
-            map.insert(615, &[134217738]);     // 615 => [134217738]
-            map.insert(616, &[134217733]);     // 616 => [134217733]
-            map.insert(617, &[134217732]);     // 617 => [134217732]
-            map.insert(618, &[16777728]);     // 618 => [16777728]
-            map.insert(619, &[16777729]);     // 619 => [16777729]
-            map.insert(620, &[134217729]);     // 620 => [134217729]
-            map.insert(621, &[134217728]);     // 621 => [134217728]
-            map.insert(622, &[268435520]);     // 622 => [268435520]
-            map.insert(623, &[134217730]);     // 623 => [134217730]
-            map.insert(624, &[268435490]);     // 624 => [268435490]
-            map.insert(625, &[8389128]);     // 625 => [8389128]
-            map.insert(626, &[268435488]);     // 626 => [268435488]
-            map.insert(627, &[268435489]);     // 627 => [268435489]
-            map.insert(628, &[33555458]);     // 628 => [33555458]
-            map.insert(629, &[134217752]);     // 629 => [134217752]
-            map.insert(630, &[33555456]);     // 630 => [33555456]
-            map.insert(631, &[33555457]);     // 631 => [33555457]
-            map.insert(632, &[1310720]);     // 632 => [1310720]
-            map.insert(633, &[8389120]);     // 633 => [8389120]
-            map.insert(634, &[1310722]);     // 634 => [1310722]
-            map.insert(635, &[8389122]);     // 635 => [8389122]
-            map.insert(636, &[8448]);     // 636 => [8448]
-            map.insert(637, &[134217744]);     // 637 => [134217744]
-            map.insert(638, &[67174400]);     // 638 => [67174400]
-            map.insert(639, &[786432]);     // 639 => [786432]
-            map.insert(640, &[2304]);     // 640 => [2304]
-            map.insert(641, &[2305]);     // 641 => [2305]
-            map.insert(642, &[2306]);     // 642 => [2306]
-            map.insert(643, &[17412]);     // 643 => [17412]
-            map.insert(644, &[16842753]);     // 644 => [16842753]
-            map.insert(645, &[16842752]);     // 645 => [16842752]
-            map.insert(646, &[17409]);     // 646 => [17409]
-            map.insert(647, &[17408]);     // 647 => [17408]
-            map.insert(648, &[2312]);     // 648 => [2312]
-            map.insert(649, &[4114]);     // 649 => [4114]
-            map.insert(650, &[4113]);     // 650 => [4113]
-            map.insert(651, &[4112]);     // 651 => [4112]
-            map.insert(652, &[81952]);     // 652 => [81952]
-            map.insert(653, &[16842760]);     // 653 => [16842760]
-            map.insert(654, &[16778272]);     // 654 => [16778272]
-            map.insert(655, &[4116]);     // 655 => [4116]
-            map.insert(656, &[67109377]);     // 656 => [67109377]
-            map.insert(657, &[67109376]);     // 657 => [67109376]
-            map.insert(658, &[4105]);     // 658 => [4105]
-            map.insert(659, &[4104]);     // 659 => [4104]
-            map.insert(660, &[294913]);     // 660 => [294913]
-            map.insert(661, &[294912]);     // 661 => [294912]
-            map.insert(662, &[8454144]);     // 662 => [8454144]
-            map.insert(663, &[8454145]);     // 663 => [8454145]
-            map.insert(664, &[132096]);     // 664 => [132096]
-            map.insert(665, &[4098]);     // 665 => [4098]
-            map.insert(666, &[4097]);     // 666 => [4097]
-            map.insert(667, &[4096]);     // 667 => [4096]
-            map.insert(668, &[132100]);     // 668 => [132100]
-            map.insert(669, &[4102]);     // 669 => [4102]
-            map.insert(670, &[4101]);     // 670 => [4101]
-            map.insert(671, &[4100]);     // 671 => [4100]
-            map.insert(672, &[2621444]);     // 672 => [2621444]
-            map.insert(673, &[3145730]);     // 673 => [3145730]
-            map.insert(674, &[3145729]);     // 674 => [3145729]
-            map.insert(675, &[3145728]);     // 675 => [3145728]
-            map.insert(676, &[2621440]);     // 676 => [2621440]
-            map.insert(677, &[2621441]);     // 677 => [2621441]
-            map.insert(678, &[4161]);     // 678 => [4161]
-            map.insert(679, &[4160]);     // 679 => [4160]
-            map.insert(680, &[5242880]);     // 680 => [5242880]
-            map.insert(681, &[5242881]);     // 681 => [5242881]
-            map.insert(682, &[5242882]);     // 682 => [5242882]
-            map.insert(683, &[4718596]);     // 683 => [4718596]
-            map.insert(684, &[81920]);     // 684 => [81920]
-            map.insert(685, &[81921]);     // 685 => [81921]
-            map.insert(686, &[16778240]);     // 686 => [16778240]
-            map.insert(687, &[4718592]);     // 687 => [4718592]
-            map.insert(688, &[33554946]);     // 688 => [33554946]
-            map.insert(689, &[196610]);     // 689 => [196610]
-            map.insert(690, &[33554944]);     // 690 => [33554944]
-            map.insert(691, &[196608]);     // 691 => [196608]
-            map.insert(692, &[8328]);     // 692 => [8328]
-            map.insert(693, &[8389640]);     // 693 => [8389640]
-            map.insert(694, &[33554948]);     // 694 => [33554948]
-            map.insert(695, &[196612]);     // 695 => [196612]
-            map.insert(696, &[8324]);     // 696 => [8324]
-            map.insert(697, &[4130]);     // 697 => [4130]
-            map.insert(698, &[4129]);     // 698 => [4129]
-            map.insert(699, &[4128]);     // 699 => [4128]
-            map.insert(700, &[8320]);     // 700 => [8320]
-            map.insert(701, &[8389632]);     // 701 => [8389632]
-            map.insert(702, &[8322]);     // 702 => [8322]
-            map.insert(703, &[8389634]);     // 703 => [8389634]
-            map.insert(704, &[8256]);     // 704 => [8256]
-            map.insert(705, &[1179648]);     // 705 => [1179648]
-            map.insert(706, &[33587200]);     // 706 => [33587200]
-            map.insert(707, &[33587201]);     // 707 => [33587201]
-            map.insert(708, &[655362]);     // 708 => [655362]
-            map.insert(709, &[1179652]);     // 709 => [1179652]
-            map.insert(710, &[655360]);     // 710 => [655360]
-            map.insert(711, &[655361]);     // 711 => [655361]
-            map.insert(712, &[8264]);     // 712 => [8264]
-            map.insert(713, &[1179656]);     // 713 => [1179656]
-            map.insert(714, &[33587208]);     // 714 => [33587208]
-            map.insert(715, &[4240]);     // 715 => [4240]
-            map.insert(716, &[8240]);     // 716 => [8240]
-            map.insert(717, &[134218016]);     // 717 => [134218016]
-            map.insert(718, &[655368]);     // 718 => [655368]
-            map.insert(719, &[269484544]);     // 719 => [269484544]
-            map.insert(720, &[2162689]);     // 720 => [2162689]
-            map.insert(721, &[2162688]);     // 721 => [2162688]
-            map.insert(722, &[268435712]);     // 722 => [268435712]
-            map.insert(723, &[268435713]);     // 723 => [268435713]
-            map.insert(724, &[8232]);     // 724 => [8232]
-            map.insert(725, &[2162692]);     // 725 => [2162692]
-            map.insert(726, &[268435716]);     // 726 => [268435716]
-            map.insert(727, &[17825824]);     // 727 => [17825824]
-            map.insert(728, &[540673]);     // 728 => [540673]
-            map.insert(729, &[540672]);     // 729 => [540672]
-            map.insert(730, &[4259840]);     // 730 => [4259840]
-            map.insert(731, &[4224]);     // 731 => [4224]
-            map.insert(732, &[8224]);     // 732 => [8224]
-            map.insert(733, &[8225]);     // 733 => [8225]
-            map.insert(734, &[1064960]);     // 734 => [1064960]
-            map.insert(735, &[1064961]);     // 735 => [1064961]
-            map.insert(736, &[67141633]);     // 736 => [67141633]
-            map.insert(737, &[67141632]);     // 737 => [67141632]
-            map.insert(738, &[8912897]);     // 738 => [8912897]
-            map.insert(739, &[8912896]);     // 739 => [8912896]
-            map.insert(740, &[9437184]);     // 740 => [9437184]
-            map.insert(741, &[262656]);     // 741 => [262656]
-            map.insert(742, &[9437186]);     // 742 => [9437186]
-            map.insert(743, &[262658]);     // 743 => [262658]
-            map.insert(744, &[8212]);     // 744 => [8212]
-            map.insert(745, &[134217988]);     // 745 => [134217988]
-            map.insert(746, &[2098192]);     // 746 => [2098192]
-            map.insert(747, &[8912904]);     // 747 => [8912904]
-            map.insert(748, &[8208]);     // 748 => [8208]
-            map.insert(749, &[134217984]);     // 749 => [134217984]
-            map.insert(750, &[8210]);     // 750 => [8210]
-            map.insert(751, &[134217986]);     // 751 => [134217986]
-            map.insert(752, &[17301504]);     // 752 => [17301504]
-            map.insert(753, &[4195328]);     // 753 => [4195328]
-            map.insert(754, &[17301506]);     // 754 => [17301506]
-            map.insert(755, &[4195330]);     // 755 => [4195330]
-            map.insert(756, &[8200]);     // 756 => [8200]
-            map.insert(757, &[8201]);     // 757 => [8201]
-            map.insert(758, &[17825793]);     // 758 => [17825793]
-            map.insert(759, &[17825792]);     // 759 => [17825792]
-            map.insert(760, &[8196]);     // 760 => [8196]
-            map.insert(761, &[8197]);     // 761 => [8197]
-            map.insert(762, &[2098176]);     // 762 => [2098176]
-            map.insert(763, &[2098177]);     // 763 => [2098177]
-            map.insert(764, &[8192]);     // 764 => [8192]
-            map.insert(765, &[8193]);     // 765 => [8193]
-            map.insert(766, &[8194]);     // 766 => [8194]
-            map.insert(767, &[8195]);     // 767 => [8195]
-            map.insert(768, &[2560]);     // 768 => [2560]
-            map.insert(769, &[528384]);     // 769 => [528384]
-            map.insert(770, &[2097216]);     // 770 => [2097216]
-            map.insert(771, &[16512]);     // 771 => [16512]
-            map.insert(772, &[1052674]);     // 772 => [1052674]
-            map.insert(773, &[528388]);     // 773 => [528388]
-            map.insert(774, &[1052672]);     // 774 => [1052672]
-            map.insert(775, &[1052673]);     // 775 => [1052673]
-            map.insert(776, &[4194369]);     // 776 => [4194369]
-            map.insert(777, &[4194368]);     // 777 => [4194368]
-            map.insert(778, &[2097224]);     // 778 => [2097224]
-            map.insert(779, &[4194370]);     // 779 => [4194370]
-            map.insert(780, &[131216]);     // 780 => [131216]
-            map.insert(781, &[4194372]);     // 781 => [4194372]
-            map.insert(782, &[1052680]);     // 782 => [1052680]
-            map.insert(783, &[12599296]);     // 783 => [12599296]
-            map.insert(784, &[67109121]);     // 784 => [67109121]
-            map.insert(785, &[67109120]);     // 785 => [67109120]
-            map.insert(786, &[73729]);     // 786 => [73729]
-            map.insert(787, &[73728]);     // 787 => [73728]
-            map.insert(788, &[4194337]);     // 788 => [4194337]
-            map.insert(789, &[4194336]);     // 789 => [4194336]
-            map.insert(790, &[2097192]);     // 790 => [2097192]
-            map.insert(791, &[4194338]);     // 791 => [4194338]
-            map.insert(792, &[131204]);     // 792 => [131204]
-            map.insert(793, &[134250500]);     // 793 => [134250500]
-            map.insert(794, &[2097188]);     // 794 => [2097188]
-            map.insert(795, &[73736]);     // 795 => [73736]
-            map.insert(796, &[131200]);     // 796 => [131200]
-            map.insert(797, &[134250496]);     // 797 => [134250496]
-            map.insert(798, &[2097184]);     // 798 => [2097184]
-            map.insert(799, &[2097185]);     // 799 => [2097185]
-            map.insert(800, &[268468226]);     // 800 => [268468226]
-            map.insert(801, &[4194324]);     // 801 => [4194324]
-            map.insert(802, &[268468224]);     // 802 => [268468224]
-            map.insert(803, &[268468225]);     // 803 => [268468225]
-            map.insert(804, &[4194321]);     // 804 => [4194321]
-            map.insert(805, &[4194320]);     // 805 => [4194320]
-            map.insert(806, &[268468228]);     // 806 => [268468228]
-            map.insert(807, &[4194322]);     // 807 => [4194322]
-            map.insert(808, &[16777346]);     // 808 => [16777346]
-            map.insert(809, &[8388752]);     // 809 => [8388752]
-            map.insert(810, &[16777344]);     // 810 => [16777344]
-            map.insert(811, &[16777345]);     // 811 => [16777345]
-            map.insert(812, &[2097170]);     // 812 => [2097170]
-            map.insert(813, &[4194328]);     // 813 => [4194328]
-            map.insert(814, &[2097168]);     // 814 => [2097168]
-            map.insert(815, &[2097169]);     // 815 => [2097169]
-            map.insert(816, &[4194309]);     // 816 => [4194309]
-            map.insert(817, &[4194308]);     // 817 => [4194308]
-            map.insert(818, &[33554688]);     // 818 => [33554688]
-            map.insert(819, &[33554689]);     // 819 => [33554689]
-            map.insert(820, &[4194305]);     // 820 => [4194305]
-            map.insert(821, &[4194304]);     // 821 => [4194304]
-            map.insert(822, &[2097160]);     // 822 => [2097160]
-            map.insert(823, &[4194306]);     // 823 => [4194306]
-            map.insert(824, &[9216]);     // 824 => [9216]
-            map.insert(825, &[8388736]);     // 825 => [8388736]
-            map.insert(826, &[2097156]);     // 826 => [2097156]
-            map.insert(827, &[2097157]);     // 827 => [2097157]
-            map.insert(828, &[2097154]);     // 828 => [2097154]
-            map.insert(829, &[4194312]);     // 829 => [4194312]
-            map.insert(830, &[2097152]);     // 830 => [2097152]
-            map.insert(831, &[2097153]);     // 831 => [2097153]
-            map.insert(832, &[16387]);     // 832 => [16387]
-            map.insert(833, &[16386]);     // 833 => [16386]
-            map.insert(834, &[16385]);     // 834 => [16385]
-            map.insert(835, &[16384]);     // 835 => [16384]
-            map.insert(836, &[8388673]);     // 836 => [8388673]
-            map.insert(837, &[8388672]);     // 837 => [8388672]
-            map.insert(838, &[16389]);     // 838 => [16389]
-            map.insert(839, &[16388]);     // 839 => [16388]
-            map.insert(840, &[16777250]);     // 840 => [16777250]
-            map.insert(841, &[16394]);     // 841 => [16394]
-            map.insert(842, &[16777248]);     // 842 => [16777248]
-            map.insert(843, &[16392]);     // 843 => [16392]
-            map.insert(844, &[131088]);     // 844 => [131088]
-            map.insert(845, &[131089]);     // 845 => [131089]
-            map.insert(846, &[131090]);     // 846 => [131090]
-            map.insert(847, &[16396]);     // 847 => [16396]
-            map.insert(848, &[268435970]);     // 848 => [268435970]
-            map.insert(849, &[16402]);     // 849 => [16402]
-            map.insert(850, &[268435968]);     // 850 => [268435968]
-            map.insert(851, &[16400]);     // 851 => [16400]
-            map.insert(852, &[131080]);     // 852 => [131080]
-            map.insert(853, &[131081]);     // 853 => [131081]
-            map.insert(854, &[16777280]);     // 854 => [16777280]
-            map.insert(855, &[16777281]);     // 855 => [16777281]
-            map.insert(856, &[131076]);     // 856 => [131076]
-            map.insert(857, &[8388640]);     // 857 => [8388640]
-            map.insert(858, &[131078]);     // 858 => [131078]
-            map.insert(859, &[8388642]);     // 859 => [8388642]
-            map.insert(860, &[131072]);     // 860 => [131072]
-            map.insert(861, &[131073]);     // 861 => [131073]
-            map.insert(862, &[131074]);     // 862 => [131074]
-            map.insert(863, &[5120]);     // 863 => [5120]
-            map.insert(864, &[131136]);     // 864 => [131136]
-            map.insert(865, &[1056768]);     // 865 => [1056768]
-            map.insert(866, &[16777224]);     // 866 => [16777224]
-            map.insert(867, &[16416]);     // 867 => [16416]
-            map.insert(868, &[262401]);     // 868 => [262401]
-            map.insert(869, &[262400]);     // 869 => [262400]
-            map.insert(870, &[532480]);     // 870 => [532480]
-            map.insert(871, &[532481]);     // 871 => [532481]
-            map.insert(872, &[16777218]);     // 872 => [16777218]
-            map.insert(873, &[8388624]);     // 873 => [8388624]
-            map.insert(874, &[16777216]);     // 874 => [16777216]
-            map.insert(875, &[16777217]);     // 875 => [16777217]
-            map.insert(876, &[134218241]);     // 876 => [134218241]
-            map.insert(877, &[134218240]);     // 877 => [134218240]
-            map.insert(878, &[16777220]);     // 878 => [16777220]
-            map.insert(879, &[16777221]);     // 879 => [16777221]
-            map.insert(880, &[34816]);     // 880 => [34816]
-            map.insert(881, &[8388616]);     // 881 => [8388616]
-            map.insert(882, &[34818]);     // 882 => [34818]
-            map.insert(883, &[8388618]);     // 883 => [8388618]
-            map.insert(884, &[69632]);     // 884 => [69632]
-            map.insert(885, &[4194432]);     // 885 => [4194432]
-            map.insert(886, &[69634]);     // 886 => [69634]
-            map.insert(887, &[4194434]);     // 887 => [4194434]
-            map.insert(888, &[8388609]);     // 888 => [8388609]
-            map.insert(889, &[8388608]);     // 889 => [8388608]
-            map.insert(890, &[16777232]);     // 890 => [16777232]
-            map.insert(891, &[8388610]);     // 891 => [8388610]
-            map.insert(892, &[131104]);     // 892 => [131104]
-            map.insert(893, &[8388612]);     // 893 => [8388612]
-            map.insert(894, &[2097280]);     // 894 => [2097280]
-            map.insert(895, &[16448]);     // 895 => [16448]
-            map.insert(896, &[67108881]);     // 896 => [67108881]
-            map.insert(897, &[67108880]);     // 897 => [67108880]
-            map.insert(898, &[134283264]);     // 898 => [134283264]
-            map.insert(899, &[134283265]);     // 899 => [134283265]
-            map.insert(900, &[40968]);     // 900 => [40968]
-            map.insert(901, &[67108884]);     // 901 => [67108884]
-            map.insert(902, &[134283268]);     // 902 => [134283268]
-            map.insert(903, &[17920]);     // 903 => [17920]
-            map.insert(904, &[40964]);     // 904 => [40964]
-            map.insert(905, &[67108888]);     // 905 => [67108888]
-            map.insert(906, &[33554500]);     // 906 => [33554500]
-            map.insert(907, &[4624]);     // 907 => [4624]
-            map.insert(908, &[40960]);     // 908 => [40960]
-            map.insert(909, &[40961]);     // 909 => [40961]
-            map.insert(910, &[33554496]);     // 910 => [33554496]
-            map.insert(911, &[33554497]);     // 911 => [33554497]
-            map.insert(912, &[67108865]);     // 912 => [67108865]
-            map.insert(913, &[67108864]);     // 913 => [67108864]
-            map.insert(914, &[33554464]);     // 914 => [33554464]
-            map.insert(915, &[67108866]);     // 915 => [67108866]
-            map.insert(916, &[67108869]);     // 916 => [67108869]
-            map.insert(917, &[67108868]);     // 917 => [67108868]
-            map.insert(918, &[268436480]);     // 918 => [268436480]
-            map.insert(919, &[268436481]);     // 919 => [268436481]
-            map.insert(920, &[67108873]);     // 920 => [67108873]
-            map.insert(921, &[67108872]);     // 921 => [67108872]
-            map.insert(922, &[526336]);     // 922 => [526336]
-            map.insert(923, &[4608]);     // 923 => [4608]
-            map.insert(924, &[1050625]);     // 924 => [1050625]
-            map.insert(925, &[1050624]);     // 925 => [1050624]
-            map.insert(926, &[526340]);     // 926 => [526340]
-            map.insert(927, &[1050626]);     // 927 => [1050626]
-            map.insert(928, &[33554450]);     // 928 => [33554450]
-            map.insert(929, &[262276]);     // 929 => [262276]
-            map.insert(930, &[33554448]);     // 930 => [33554448]
-            map.insert(931, &[33554449]);     // 931 => [33554449]
-            map.insert(932, &[262273]);     // 932 => [262273]
-            map.insert(933, &[262272]);     // 933 => [262272]
-            map.insert(934, &[33554452]);     // 934 => [33554452]
-            map.insert(935, &[262274]);     // 935 => [262274]
-            map.insert(936, &[134218753]);     // 936 => [134218753]
-            map.insert(937, &[134218752]);     // 937 => [134218752]
-            map.insert(938, &[33554456]);     // 938 => [33554456]
-            map.insert(939, &[134218754]);     // 939 => [134218754]
-            map.insert(940, &[67108929]);     // 940 => [67108929]
-            map.insert(941, &[67108928]);     // 941 => [67108928]
-            map.insert(942, &[2097424]);     // 942 => [2097424]
-            map.insert(943, &[67108930]);     // 943 => [67108930]
-            map.insert(944, &[33554434]);     // 944 => [33554434]
-            map.insert(945, &[67108896]);     // 945 => [67108896]
-            map.insert(946, &[33554432]);     // 946 => [33554432]
-            map.insert(947, &[33554433]);     // 947 => [33554433]
-            map.insert(948, &[4194561]);     // 948 => [4194561]
-            map.insert(949, &[4194560]);     // 949 => [4194560]
-            map.insert(950, &[33554436]);     // 950 => [33554436]
-            map.insert(951, &[33554437]);     // 951 => [33554437]
-            map.insert(952, &[33554442]);     // 952 => [33554442]
-            map.insert(953, &[268500996]);     // 953 => [268500996]
-            map.insert(954, &[33554440]);     // 954 => [33554440]
-            map.insert(955, &[33554441]);     // 955 => [33554441]
-            map.insert(956, &[268500993]);     // 956 => [268500993]
-            map.insert(957, &[268500992]);     // 957 => [268500992]
-            map.insert(958, &[2097408]);     // 958 => [2097408]
-            map.insert(959, &[2097409]);     // 959 => [2097409]
-            map.insert(960, &[3076]);     // 960 => [3076]
-            map.insert(961, &[16642]);     // 961 => [16642]
-            map.insert(962, &[16641]);     // 962 => [16641]
-            map.insert(963, &[16640]);     // 963 => [16640]
-            map.insert(964, &[3072]);     // 964 => [3072]
-            map.insert(965, &[262176]);     // 965 => [262176]
-            map.insert(966, &[3074]);     // 966 => [3074]
-            map.insert(967, &[262178]);     // 967 => [262178]
-            map.insert(968, &[268959744]);     // 968 => [268959744]
-            map.insert(969, &[268959745]);     // 969 => [268959745]
-            map.insert(970, &[268959746]);     // 970 => [268959746]
-            map.insert(971, &[269484036]);     // 971 => [269484036]
-            map.insert(972, &[268959748]);     // 972 => [268959748]
-            map.insert(973, &[269484034]);     // 973 => [269484034]
-            map.insert(974, &[269484033]);     // 974 => [269484033]
-            map.insert(975, &[269484032]);     // 975 => [269484032]
-            map.insert(976, &[67108993]);     // 976 => [67108993]
-            map.insert(977, &[67108992]);     // 977 => [67108992]
-            map.insert(978, &[33554592]);     // 978 => [33554592]
-            map.insert(979, &[67108994]);     // 979 => [67108994]
-            map.insert(980, &[131336]);     // 980 => [131336]
-            map.insert(981, &[67108996]);     // 981 => [67108996]
-            map.insert(982, &[16777536]);     // 982 => [16777536]
-            map.insert(983, &[134742048]);     // 983 => [134742048]
-            map.insert(984, &[262209]);     // 984 => [262209]
-            map.insert(985, &[262208]);     // 985 => [262208]
-            map.insert(986, &[526464]);     // 986 => [526464]
-            map.insert(987, &[262210]);     // 987 => [262210]
-            map.insert(988, &[131328]);     // 988 => [131328]
-            map.insert(989, &[131329]);     // 989 => [131329]
-            map.insert(990, &[131330]);     // 990 => [131330]
-            map.insert(991, &[269484048]);     // 991 => [269484048]
-            map.insert(992, &[262149]);     // 992 => [262149]
-            map.insert(993, &[262148]);     // 993 => [262148]
-            map.insert(994, &[16777480]);     // 994 => [16777480]
-            map.insert(995, &[262150]);     // 995 => [262150]
-            map.insert(996, &[262145]);     // 996 => [262145]
-            map.insert(997, &[262144]);     // 997 => [262144]
-            map.insert(998, &[262147]);     // 998 => [262147]
-            map.insert(999, &[262146]);     // 999 => [262146]
-            map.insert(1000, &[16777474]);     // 1000 => [16777474]
-            map.insert(1001, &[36866]);     // 1001 => [36866]
-            map.insert(1002, &[16777472]);     // 1002 => [16777472]
-            map.insert(1003, &[36864]);     // 1003 => [36864]
-            map.insert(1004, &[262153]);     // 1004 => [262153]
-            map.insert(1005, &[262152]);     // 1005 => [262152]
-            map.insert(1006, &[67585]);     // 1006 => [67585]
-            map.insert(1007, &[67584]);     // 1007 => [67584]
-            map.insert(1008, &[135266304]);     // 1008 => [135266304]
-            map.insert(1009, &[135266305]);     // 1009 => [135266305]
-            map.insert(1010, &[33554560]);     // 1010 => [33554560]
-            map.insert(1011, &[33554561]);     // 1011 => [33554561]
-            map.insert(1012, &[262161]);     // 1012 => [262161]
-            map.insert(1013, &[262160]);     // 1013 => [262160]
-            map.insert(1014, &[134742017]);     // 1014 => [134742017]
-            map.insert(1015, &[134742016]);     // 1015 => [134742016]
-            map.insert(1016, &[8388865]);     // 1016 => [8388865]
-            map.insert(1017, &[8388864]);     // 1017 => [8388864]
-            map.insert(1018, &[33554568]);     // 1018 => [33554568]
-            map.insert(1019, &[8388866]);     // 1019 => [8388866]
-            map.insert(1020, &[8704]);     // 1020 => [8704]
-            map.insert(1021, &[8705]);     // 1021 => [8705]
-            map.insert(1022, &[8706]);     // 1022 => [8706]
-            map.insert(1023, &[134742024]);     // 1023 => [134742024]
+fn parity_matrix() -> &'static BinMatrix {
+    PARITY_MATRIX.get_or_init(|| {
+        BinMatrix::from_slices(&[
+            &[ 215306241 ],
+            &[ 321474562 ],
+            &[ 142025796 ],
+            &[ 163262536 ],
+            &[ 385527888 ],
+            &[ 199598176 ],
+            &[ 428336256 ],
+            &[ 102577408 ],
+            &[ 134202880 ],
+            &[ 535197696 ],
             
-            SYNDROME_MAP = Box::into_raw(map);
-        }
-    });
+        ], 29)
+    })
+}
+
+fn parity_matrix_transposed() -> &'static BinMatrix {
+    PARITY_MATRIX_T.get_or_init(|| parity_matrix().transposed())
+}
+
+fn syndrome_map() -> &'static FnvHashMap<u64, &'static [usize; 1]> {
+    SYNDROME_MAP.get_or_init(|| {
+        let mut map = FnvHashMap::with_capacity_and_hasher(1024, Default::default());
+        map.insert(0, &[0]);     // 0 => [0]
+        map.insert(1, &[1]);     // 1 => [1]
+        map.insert(2, &[2]);     // 2 => [2]
+        map.insert(3, &[3]);     // 3 => [3]
+        map.insert(4, &[4]);     // 4 => [4]
+        map.insert(5, &[5]);     // 5 => [5]
+        map.insert(6, &[6]);     // 6 => [6]
+        map.insert(7, &[1572864]);     // 7 => [1572864]
+        map.insert(8, &[8]);     // 8 => [8]
+        map.insert(9, &[9]);     // 9 => [9]
+        map.insert(10, &[10]);     // 10 => [10]
+        map.insert(11, &[6291456]);     // 11 => [6291456]
+        map.insert(12, &[12]);     // 12 => [12]
+        map.insert(13, &[13]);     // 13 => [13]
+        map.insert(14, &[14]);     // 14 => [14]
+        map.insert(15, &[6291460]);     // 15 => [6291460]
+        map.insert(16, &[16]);     // 16 => [16]
+        map.insert(17, &[17]);     // 17 => [17]
+        map.insert(18, &[18]);     // 18 => [18]
+        map.insert(19, &[25165824]);     // 19 => [25165824]
+        map.insert(20, &[20]);     // 20 => [20]
+        map.insert(21, &[21]);     // 21 => [21]
+        map.insert(22, &[22]);     // 22 => [22]
+        map.insert(23, &[25165828]);     // 23 => [25165828]
+        map.insert(24, &[24]);     // 24 => [24]
+        map.insert(25, &[25]);     // 25 => [25]
+        map.insert(26, &[26]);     // 26 => [26]
+        map.insert(27, &[147460]);     // 27 => [147460]
+        map.insert(28, &[96]);     // 28 => [96]
+        map.insert(29, &[97]);     // 29 => [97]
+        map.insert(30, &[147457]);     // 30 => [147457]
+        map.insert(31, &[147456]);     // 31 => [147456]
+        map.insert(32, &[32]);     // 32 => [32]
+        map.insert(33, &[33]);     // 33 => [33]
+        map.insert(34, &[34]);     // 34 => [34]
+        map.insert(35, &[100663296]);     // 35 => [100663296]
+        map.insert(36, &[36]);     // 36 => [36]
+        map.insert(37, &[8519680]);     // 37 => [8519680]
+        map.insert(38, &[38]);     // 38 => [38]
+        map.insert(39, &[8519682]);     // 39 => [8519682]
+        map.insert(40, &[40]);     // 40 => [40]
+        map.insert(41, &[16793600]);     // 41 => [16793600]
+        map.insert(42, &[66561]);     // 42 => [66561]
+        map.insert(43, &[66560]);     // 43 => [66560]
+        map.insert(44, &[80]);     // 44 => [80]
+        map.insert(45, &[81]);     // 45 => [81]
+        map.insert(46, &[82]);     // 46 => [82]
+        map.insert(47, &[66564]);     // 47 => [66564]
+        map.insert(48, &[48]);     // 48 => [48]
+        map.insert(49, &[49]);     // 49 => [49]
+        map.insert(50, &[50]);     // 50 => [50]
+        map.insert(51, &[100663312]);     // 51 => [100663312]
+        map.insert(52, &[72]);     // 52 => [72]
+        map.insert(53, &[73]);     // 53 => [73]
+        map.insert(54, &[16908288]);     // 54 => [16908288]
+        map.insert(55, &[16908289]);     // 55 => [16908289]
+        map.insert(56, &[68]);     // 56 => [68]
+        map.insert(57, &[69]);     // 57 => [69]
+        map.insert(58, &[8404992]);     // 58 => [8404992]
+        map.insert(59, &[8404993]);     // 59 => [8404993]
+        map.insert(60, &[64]);     // 60 => [64]
+        map.insert(61, &[65]);     // 61 => [65]
+        map.insert(62, &[66]);     // 62 => [66]
+        map.insert(63, &[402653184]);     // 63 => [402653184]
+        map.insert(64, &[128]);     // 64 => [128]
+        map.insert(65, &[129]);     // 65 => [129]
+        map.insert(66, &[130]);     // 66 => [130]
+        map.insert(67, &[131]);     // 67 => [131]
+        map.insert(68, &[132]);     // 68 => [132]
+        map.insert(69, &[133]);     // 69 => [133]
+        map.insert(70, &[10485761]);     // 70 => [10485761]
+        map.insert(71, &[10485760]);     // 71 => [10485760]
+        map.insert(72, &[136]);     // 72 => [136]
+        map.insert(73, &[137]);     // 73 => [137]
+        map.insert(74, &[138]);     // 74 => [138]
+        map.insert(75, &[6291584]);     // 75 => [6291584]
+        map.insert(76, &[12582912]);     // 76 => [12582912]
+        map.insert(77, &[12582913]);     // 77 => [12582913]
+        map.insert(78, &[12582914]);     // 78 => [12582914]
+        map.insert(79, &[10485768]);     // 79 => [10485768]
+        map.insert(80, &[144]);     // 80 => [144]
+        map.insert(81, &[145]);     // 81 => [145]
+        map.insert(82, &[268437504]);     // 82 => [268437504]
+        map.insert(83, &[268437505]);     // 83 => [268437505]
+        map.insert(84, &[18874368]);     // 84 => [18874368]
+        map.insert(85, &[18874369]);     // 85 => [18874369]
+        map.insert(86, &[33816577]);     // 86 => [33816577]
+        map.insert(87, &[33816576]);     // 87 => [33816576]
+        map.insert(88, &[1049601]);     // 88 => [1049601]
+        map.insert(89, &[1049600]);     // 89 => [1049600]
+        map.insert(90, &[525316]);     // 90 => [525316]
+        map.insert(91, &[1049602]);     // 91 => [1049602]
+        map.insert(92, &[525314]);     // 92 => [525314]
+        map.insert(93, &[20971522]);     // 93 => [20971522]
+        map.insert(94, &[525312]);     // 94 => [525312]
+        map.insert(95, &[20971520]);     // 95 => [20971520]
+        map.insert(96, &[160]);     // 96 => [160]
+        map.insert(97, &[161]);     // 97 => [161]
+        map.insert(98, &[2228224]);     // 98 => [2228224]
+        map.insert(99, &[2228225]);     // 99 => [2228225]
+        map.insert(100, &[164]);     // 100 => [164]
+        map.insert(101, &[12290]);     // 101 => [12290]
+        map.insert(102, &[12289]);     // 102 => [12289]
+        map.insert(103, &[12288]);     // 103 => [12288]
+        map.insert(104, &[4325377]);     // 104 => [4325377]
+        map.insert(105, &[4325376]);     // 105 => [4325376]
+        map.insert(106, &[2228232]);     // 106 => [2228232]
+        map.insert(107, &[4325378]);     // 107 => [4325378]
+        map.insert(108, &[134219777]);     // 108 => [134219777]
+        map.insert(109, &[134219776]);     // 109 => [134219776]
+        map.insert(110, &[268437568]);     // 110 => [268437568]
+        map.insert(111, &[134219778]);     // 111 => [134219778]
+        map.insert(112, &[33280]);     // 112 => [33280]
+        map.insert(113, &[33281]);     // 113 => [33281]
+        map.insert(114, &[1114112]);     // 114 => [1114112]
+        map.insert(115, &[1114113]);     // 115 => [1114113]
+        map.insert(116, &[67371008]);     // 116 => [67371008]
+        map.insert(117, &[589824]);     // 117 => [589824]
+        map.insert(118, &[4210688]);     // 118 => [4210688]
+        map.insert(119, &[4210689]);     // 119 => [4210689]
+        map.insert(120, &[196]);     // 120 => [196]
+        map.insert(121, &[2113540]);     // 121 => [2113540]
+        map.insert(122, &[1114120]);     // 122 => [1114120]
+        map.insert(123, &[10485824]);     // 123 => [10485824]
+        map.insert(124, &[192]);     // 124 => [192]
+        map.insert(125, &[2113536]);     // 125 => [2113536]
+        map.insert(126, &[194]);     // 126 => [194]
+        map.insert(127, &[2113538]);     // 127 => [2113538]
+        map.insert(128, &[256]);     // 128 => [256]
+        map.insert(129, &[257]);     // 129 => [257]
+        map.insert(130, &[258]);     // 130 => [258]
+        map.insert(131, &[259]);     // 131 => [259]
+        map.insert(132, &[260]);     // 132 => [260]
+        map.insert(133, &[261]);     // 133 => [261]
+        map.insert(134, &[37748737]);     // 134 => [37748737]
+        map.insert(135, &[37748736]);     // 135 => [37748736]
+        map.insert(136, &[264]);     // 136 => [264]
+        map.insert(137, &[265]);     // 137 => [265]
+        map.insert(138, &[266]);     // 138 => [266]
+        map.insert(139, &[17039364]);     // 139 => [17039364]
+        map.insert(140, &[35651584]);     // 140 => [35651584]
+        map.insert(141, &[35651585]);     // 141 => [35651585]
+        map.insert(142, &[17039361]);     // 142 => [17039361]
+        map.insert(143, &[17039360]);     // 143 => [17039360]
+        map.insert(144, &[272]);     // 144 => [272]
+        map.insert(145, &[134225920]);     // 145 => [134225920]
+        map.insert(146, &[274]);     // 146 => [274]
+        map.insert(147, &[134225922]);     // 147 => [134225922]
+        map.insert(148, &[276]);     // 148 => [276]
+        map.insert(149, &[134225924]);     // 149 => [134225924]
+        map.insert(150, &[16785920]);     // 150 => [16785920]
+        map.insert(151, &[98312]);     // 151 => [98312]
+        map.insert(152, &[524802]);     // 152 => [524802]
+        map.insert(153, &[6146]);     // 153 => [6146]
+        map.insert(154, &[524800]);     // 154 => [524800]
+        map.insert(155, &[6144]);     // 155 => [6144]
+        map.insert(156, &[8650752]);     // 156 => [8650752]
+        map.insert(157, &[1049088]);     // 157 => [1049088]
+        map.insert(158, &[98305]);     // 158 => [98305]
+        map.insert(159, &[98304]);     // 159 => [98304]
+        map.insert(160, &[288]);     // 160 => [288]
+        map.insert(161, &[289]);     // 161 => [289]
+        map.insert(162, &[290]);     // 162 => [290]
+        map.insert(163, &[98368]);     // 163 => [98368]
+        map.insert(164, &[71303168]);     // 164 => [71303168]
+        map.insert(165, &[71303169]);     // 165 => [71303169]
+        map.insert(166, &[278528]);     // 166 => [278528]
+        map.insert(167, &[278529]);     // 167 => [278529]
+        map.insert(168, &[296]);     // 168 => [296]
+        map.insert(169, &[393232]);     // 169 => [393232]
+        map.insert(170, &[268443652]);     // 170 => [268443652]
+        map.insert(171, &[69206020]);     // 171 => [69206020]
+        map.insert(172, &[268443650]);     // 172 => [268443650]
+        map.insert(173, &[69206018]);     // 173 => [69206018]
+        map.insert(174, &[268443648]);     // 174 => [268443648]
+        map.insert(175, &[69206016]);     // 175 => [69206016]
+        map.insert(176, &[33796]);     // 176 => [33796]
+        map.insert(177, &[393224]);     // 177 => [393224]
+        map.insert(178, &[33556992]);     // 178 => [33556992]
+        map.insert(179, &[17039424]);     // 179 => [17039424]
+        map.insert(180, &[33792]);     // 180 => [33792]
+        map.insert(181, &[33793]);     // 181 => [33793]
+        map.insert(182, &[33794]);     // 182 => [33794]
+        map.insert(183, &[268698112]);     // 183 => [268698112]
+        map.insert(184, &[393217]);     // 184 => [393217]
+        map.insert(185, &[393216]);     // 185 => [393216]
+        map.insert(186, &[524832]);     // 186 => [524832]
+        map.insert(187, &[393218]);     // 187 => [393218]
+        map.insert(188, &[320]);     // 188 => [320]
+        map.insert(189, &[321]);     // 189 => [321]
+        map.insert(190, &[322]);     // 190 => [322]
+        map.insert(191, &[69206032]);     // 191 => [69206032]
+        map.insert(192, &[384]);     // 192 => [384]
+        map.insert(193, &[385]);     // 193 => [385]
+        map.insert(194, &[386]);     // 194 => [386]
+        map.insert(195, &[41943048]);     // 195 => [41943048]
+        map.insert(196, &[1536]);     // 196 => [1536]
+        map.insert(197, &[1537]);     // 197 => [1537]
+        map.insert(198, &[1538]);     // 198 => [1538]
+        map.insert(199, &[83886144]);     // 199 => [83886144]
+        map.insert(200, &[268439553]);     // 200 => [268439553]
+        map.insert(201, &[268439552]);     // 201 => [268439552]
+        map.insert(202, &[41943041]);     // 202 => [41943041]
+        map.insert(203, &[41943040]);     // 203 => [41943040]
+        map.insert(204, &[67239937]);     // 204 => [67239937]
+        map.insert(205, &[67239936]);     // 205 => [67239936]
+        map.insert(206, &[33685536]);     // 206 => [33685536]
+        map.insert(207, &[67239938]);     // 207 => [67239938]
+        map.insert(208, &[4456448]);     // 208 => [4456448]
+        map.insert(209, &[4456449]);     // 209 => [4456449]
+        map.insert(210, &[67125248]);     // 210 => [67125248]
+        map.insert(211, &[67125249]);     // 211 => [67125249]
+        map.insert(212, &[4456452]);     // 212 => [4456452]
+        map.insert(213, &[268452864]);     // 213 => [268452864]
+        map.insert(214, &[67125252]);     // 214 => [67125252]
+        map.insert(215, &[33816832]);     // 215 => [33816832]
+        map.insert(216, &[50331648]);     // 216 => [50331648]
+        map.insert(217, &[50331649]);     // 217 => [50331649]
+        map.insert(218, &[2359297]);     // 218 => [2359297]
+        map.insert(219, &[2359296]);     // 219 => [2359296]
+        map.insert(220, &[50331652]);     // 220 => [50331652]
+        map.insert(221, &[67239952]);     // 221 => [67239952]
+        map.insert(222, &[525568]);     // 222 => [525568]
+        map.insert(223, &[2359300]);     // 223 => [2359300]
+        map.insert(224, &[75497480]);     // 224 => [75497480]
+        map.insert(225, &[33570832]);     // 225 => [33570832]
+        map.insert(226, &[557064]);     // 226 => [557064]
+        map.insert(227, &[8914944]);     // 227 => [8914944]
+        map.insert(228, &[1568]);     // 228 => [1568]
+        map.insert(229, &[1081352]);     // 229 => [1081352]
+        map.insert(230, &[33685512]);     // 230 => [33685512]
+        map.insert(231, &[66056]);     // 231 => [66056]
+        map.insert(232, &[75497472]);     // 232 => [75497472]
+        map.insert(233, &[75497473]);     // 233 => [75497473]
+        map.insert(234, &[557056]);     // 234 => [557056]
+        map.insert(235, &[557057]);     // 235 => [557057]
+        map.insert(236, &[1081345]);     // 236 => [1081345]
+        map.insert(237, &[1081344]);     // 237 => [1081344]
+        map.insert(238, &[33685504]);     // 238 => [33685504]
+        map.insert(239, &[66048]);     // 239 => [66048]
+        map.insert(240, &[33570817]);     // 240 => [33570817]
+        map.insert(241, &[33570816]);     // 241 => [33570816]
+        map.insert(242, &[134221828]);     // 242 => [134221828]
+        map.insert(243, &[33570818]);     // 243 => [33570818]
+        map.insert(244, &[134221826]);     // 244 => [134221826]
+        map.insert(245, &[33570820]);     // 245 => [33570820]
+        map.insert(246, &[134221824]);     // 246 => [134221824]
+        map.insert(247, &[134221825]);     // 247 => [134221825]
+        map.insert(248, &[10244]);     // 248 => [10244]
+        map.insert(249, &[83886082]);     // 249 => [83886082]
+        map.insert(250, &[83886081]);     // 250 => [83886081]
+        map.insert(251, &[83886080]);     // 251 => [83886080]
+        map.insert(252, &[10240]);     // 252 => [10240]
+        map.insert(253, &[10241]);     // 253 => [10241]
+        map.insert(254, &[10242]);     // 254 => [10242]
+        map.insert(255, &[83886084]);     // 255 => [83886084]
+        map.insert(256, &[512]);     // 256 => [512]
+        map.insert(257, &[513]);     // 257 => [513]
+        map.insert(258, &[514]);     // 258 => [514]
+        map.insert(259, &[515]);     // 259 => [515]
+        map.insert(260, &[516]);     // 260 => [516]
+        map.insert(261, &[517]);     // 261 => [517]
+        map.insert(262, &[150994945]);     // 262 => [150994945]
+        map.insert(263, &[150994944]);     // 263 => [150994944]
+        map.insert(264, &[520]);     // 264 => [520]
+        map.insert(265, &[521]);     // 265 => [521]
+        map.insert(266, &[67112960]);     // 266 => [67112960]
+        map.insert(267, &[67112961]);     // 267 => [67112961]
+        map.insert(268, &[268566530]);     // 268 => [268566530]
+        map.insert(269, &[1048848]);     // 269 => [1048848]
+        map.insert(270, &[268566528]);     // 270 => [268566528]
+        map.insert(271, &[268566529]);     // 271 => [268566529]
+        map.insert(272, &[528]);     // 272 => [528]
+        map.insert(273, &[268451840]);     // 273 => [268451840]
+        map.insert(274, &[530]);     // 274 => [530]
+        map.insert(275, &[268451842]);     // 275 => [268451842]
+        map.insert(276, &[142606336]);     // 276 => [142606336]
+        map.insert(277, &[142606337]);     // 277 => [142606337]
+        map.insert(278, &[142606338]);     // 278 => [142606338]
+        map.insert(279, &[150994960]);     // 279 => [150994960]
+        map.insert(280, &[270337]);     // 280 => [270337]
+        map.insert(281, &[270336]);     // 281 => [270336]
+        map.insert(282, &[524544]);     // 282 => [524544]
+        map.insert(283, &[524545]);     // 283 => [524545]
+        map.insert(284, &[1048833]);     // 284 => [1048833]
+        map.insert(285, &[1048832]);     // 285 => [1048832]
+        map.insert(286, &[524548]);     // 286 => [524548]
+        map.insert(287, &[1048834]);     // 287 => [1048834]
+        map.insert(288, &[544]);     // 288 => [544]
+        map.insert(289, &[545]);     // 289 => [545]
+        map.insert(290, &[546]);     // 290 => [546]
+        map.insert(291, &[276824072]);     // 291 => [276824072]
+        map.insert(292, &[548]);     // 292 => [548]
+        map.insert(293, &[4196368]);     // 293 => [4196368]
+        map.insert(294, &[134234120]);     // 294 => [134234120]
+        map.insert(295, &[150994976]);     // 295 => [150994976]
+        map.insert(296, &[33558529]);     // 296 => [33558529]
+        map.insert(297, &[33558528]);     // 297 => [33558528]
+        map.insert(298, &[276824065]);     // 298 => [276824065]
+        map.insert(299, &[276824064]);     // 299 => [276824064]
+        map.insert(300, &[134234114]);     // 300 => [134234114]
+        map.insert(301, &[33558532]);     // 301 => [33558532]
+        map.insert(302, &[134234112]);     // 302 => [134234112]
+        map.insert(303, &[134234113]);     // 303 => [134234113]
+        map.insert(304, &[32896]);     // 304 => [32896]
+        map.insert(305, &[134348800]);     // 305 => [134348800]
+        map.insert(306, &[32898]);     // 306 => [32898]
+        map.insert(307, &[134348802]);     // 307 => [134348802]
+        map.insert(308, &[4196353]);     // 308 => [4196353]
+        map.insert(309, &[4196352]);     // 309 => [4196352]
+        map.insert(310, &[2099208]);     // 310 => [2099208]
+        map.insert(311, &[4196354]);     // 311 => [4196354]
+        map.insert(312, &[285212672]);     // 312 => [285212672]
+        map.insert(313, &[285212673]);     // 313 => [285212673]
+        map.insert(314, &[285212674]);     // 314 => [285212674]
+        map.insert(315, &[276824080]);     // 315 => [276824080]
+        map.insert(316, &[576]);     // 316 => [576]
+        map.insert(317, &[577]);     // 317 => [577]
+        map.insert(318, &[2099200]);     // 318 => [2099200]
+        map.insert(319, &[2099201]);     // 319 => [2099201]
+        map.insert(320, &[640]);     // 320 => [640]
+        map.insert(321, &[641]);     // 321 => [641]
+        map.insert(322, &[18433]);     // 322 => [18433]
+        map.insert(323, &[18432]);     // 323 => [18432]
+        map.insert(324, &[1280]);     // 324 => [1280]
+        map.insert(325, &[1281]);     // 325 => [1281]
+        map.insert(326, &[1282]);     // 326 => [1282]
+        map.insert(327, &[18436]);     // 327 => [18436]
+        map.insert(328, &[32836]);     // 328 => [32836]
+        map.insert(329, &[4202752]);     // 329 => [4202752]
+        map.insert(330, &[33562628]);     // 330 => [33562628]
+        map.insert(331, &[18440]);     // 331 => [18440]
+        map.insert(332, &[32832]);     // 332 => [32832]
+        map.insert(333, &[32833]);     // 333 => [32833]
+        map.insert(334, &[33562624]);     // 334 => [33562624]
+        map.insert(335, &[33562625]);     // 335 => [33562625]
+        map.insert(336, &[32800]);     // 336 => [32800]
+        map.insert(337, &[32801]);     // 337 => [32801]
+        map.insert(338, &[136314881]);     // 338 => [136314881]
+        map.insert(339, &[136314880]);     // 339 => [136314880]
+        map.insert(340, &[32804]);     // 340 => [32804]
+        map.insert(341, &[134226944]);     // 341 => [134226944]
+        map.insert(342, &[16779328]);     // 342 => [16779328]
+        map.insert(343, &[136314884]);     // 343 => [136314884]
+        map.insert(344, &[138412032]);     // 344 => [138412032]
+        map.insert(345, &[138412033]);     // 345 => [138412033]
+        map.insert(346, &[138412034]);     // 346 => [138412034]
+        map.insert(347, &[136314888]);     // 347 => [136314888]
+        map.insert(348, &[133120]);     // 348 => [133120]
+        map.insert(349, &[133121]);     // 349 => [133121]
+        map.insert(350, &[133122]);     // 350 => [133122]
+        map.insert(351, &[167772416]);     // 351 => [167772416]
+        map.insert(352, &[32784]);     // 352 => [32784]
+        map.insert(353, &[32785]);     // 353 => [32785]
+        map.insert(354, &[32786]);     // 354 => [32786]
+        map.insert(355, &[272629764]);     // 355 => [272629764]
+        map.insert(356, &[32788]);     // 356 => [32788]
+        map.insert(357, &[272629762]);     // 357 => [272629762]
+        map.insert(358, &[272629761]);     // 358 => [272629761]
+        map.insert(359, &[272629760]);     // 359 => [272629760]
+        map.insert(360, &[16779266]);     // 360 => [16779266]
+        map.insert(361, &[67117060]);     // 361 => [67117060]
+        map.insert(362, &[16779264]);     // 362 => [16779264]
+        map.insert(363, &[16779265]);     // 363 => [16779265]
+        map.insert(364, &[270532608]);     // 364 => [270532608]
+        map.insert(365, &[67117056]);     // 365 => [67117056]
+        map.insert(366, &[65793]);     // 366 => [65793]
+        map.insert(367, &[65792]);     // 367 => [65792]
+        map.insert(368, &[32768]);     // 368 => [32768]
+        map.insert(369, &[32769]);     // 369 => [32769]
+        map.insert(370, &[32770]);     // 370 => [32770]
+        map.insert(371, &[32771]);     // 371 => [32771]
+        map.insert(372, &[32772]);     // 372 => [32772]
+        map.insert(373, &[32773]);     // 373 => [32773]
+        map.insert(374, &[32774]);     // 374 => [32774]
+        map.insert(375, &[272629776]);     // 375 => [272629776]
+        map.insert(376, &[32776]);     // 376 => [32776]
+        map.insert(377, &[8390656]);     // 377 => [8390656]
+        map.insert(378, &[32778]);     // 378 => [32778]
+        map.insert(379, &[8390658]);     // 379 => [8390658]
+        map.insert(380, &[266242]);     // 380 => [266242]
+        map.insert(381, &[8390660]);     // 381 => [8390660]
+        map.insert(382, &[266240]);     // 382 => [266240]
+        map.insert(383, &[266241]);     // 383 => [266241]
+        map.insert(384, &[768]);     // 384 => [768]
+        map.insert(385, &[769]);     // 385 => [769]
+        map.insert(386, &[770]);     // 386 => [770]
+        map.insert(387, &[24640]);     // 387 => [24640]
+        map.insert(388, &[1152]);     // 388 => [1152]
+        map.insert(389, &[8396800]);     // 389 => [8396800]
+        map.insert(390, &[1154]);     // 390 => [1154]
+        map.insert(391, &[8396802]);     // 391 => [8396802]
+        map.insert(392, &[134479872]);     // 392 => [134479872]
+        map.insert(393, &[134479873]);     // 393 => [134479873]
+        map.insert(394, &[524304]);     // 394 => [524304]
+        map.insert(395, &[524305]);     // 395 => [524305]
+        map.insert(396, &[1048593]);     // 396 => [1048593]
+        map.insert(397, &[1048592]);     // 397 => [1048592]
+        map.insert(398, &[524308]);     // 398 => [524308]
+        map.insert(399, &[1048594]);     // 399 => [1048594]
+        map.insert(400, &[67110913]);     // 400 => [67110913]
+        map.insert(401, &[67110912]);     // 401 => [67110912]
+        map.insert(402, &[524296]);     // 402 => [524296]
+        map.insert(403, &[524297]);     // 403 => [524297]
+        map.insert(404, &[1048585]);     // 404 => [1048585]
+        map.insert(405, &[1048584]);     // 405 => [1048584]
+        map.insert(406, &[16785408]);     // 406 => [16785408]
+        map.insert(407, &[16785409]);     // 407 => [16785409]
+        map.insert(408, &[524290]);     // 408 => [524290]
+        map.insert(409, &[1048580]);     // 409 => [1048580]
+        map.insert(410, &[524288]);     // 410 => [524288]
+        map.insert(411, &[524289]);     // 411 => [524289]
+        map.insert(412, &[1048577]);     // 412 => [1048577]
+        map.insert(413, &[1048576]);     // 413 => [1048576]
+        map.insert(414, &[524292]);     // 414 => [524292]
+        map.insert(415, &[1048578]);     // 415 => [1048578]
+        map.insert(416, &[139264]);     // 416 => [139264]
+        map.insert(417, &[1048640]);     // 417 => [1048640]
+        map.insert(418, &[139266]);     // 418 => [139266]
+        map.insert(419, &[1048642]);     // 419 => [1048642]
+        map.insert(420, &[2101249]);     // 420 => [2101249]
+        map.insert(421, &[2101248]);     // 421 => [2101248]
+        map.insert(422, &[524352]);     // 422 => [524352]
+        map.insert(423, &[524353]);     // 423 => [524353]
+        map.insert(424, &[139272]);     // 424 => [139272]
+        map.insert(425, &[1048648]);     // 425 => [1048648]
+        map.insert(426, &[4198404]);     // 426 => [4198404]
+        map.insert(427, &[65668]);     // 427 => [65668]
+        map.insert(428, &[4198402]);     // 428 => [4198402]
+        map.insert(429, &[65666]);     // 429 => [65666]
+        map.insert(430, &[4198400]);     // 430 => [4198400]
+        map.insert(431, &[65664]);     // 431 => [65664]
+        map.insert(432, &[33556482]);     // 432 => [33556482]
+        map.insert(433, &[1048656]);     // 433 => [1048656]
+        map.insert(434, &[33556480]);     // 434 => [33556480]
+        map.insert(435, &[33556481]);     // 435 => [33556481]
+        map.insert(436, &[134479936]);     // 436 => [134479936]
+        map.insert(437, &[268697602]);     // 437 => [268697602]
+        map.insert(438, &[268697601]);     // 438 => [268697601]
+        map.insert(439, &[268697600]);     // 439 => [268697600]
+        map.insert(440, &[524322]);     // 440 => [524322]
+        map.insert(441, &[1048612]);     // 441 => [1048612]
+        map.insert(442, &[524320]);     // 442 => [524320]
+        map.insert(443, &[524321]);     // 443 => [524321]
+        map.insert(444, &[1048609]);     // 444 => [1048609]
+        map.insert(445, &[1048608]);     // 445 => [1048608]
+        map.insert(446, &[24577]);     // 446 => [24577]
+        map.insert(447, &[24576]);     // 447 => [24576]
+        map.insert(448, &[1028]);     // 448 => [1028]
+        map.insert(449, &[1029]);     // 449 => [1029]
+        map.insert(450, &[2105344]);     // 450 => [2105344]
+        map.insert(451, &[335544320]);     // 451 => [335544320]
+        map.insert(452, &[1024]);     // 452 => [1024]
+        map.insert(453, &[1025]);     // 453 => [1025]
+        map.insert(454, &[1026]);     // 454 => [1026]
+        map.insert(455, &[135168]);     // 455 => [135168]
+        map.insert(456, &[4202497]);     // 456 => [4202497]
+        map.insert(457, &[4202496]);     // 457 => [4202496]
+        map.insert(458, &[2105352]);     // 458 => [2105352]
+        map.insert(459, &[4202498]);     // 459 => [4202498]
+        map.insert(460, &[1032]);     // 460 => [1032]
+        map.insert(461, &[1033]);     // 461 => [1033]
+        map.insert(462, &[65569]);     // 462 => [65569]
+        map.insert(463, &[65568]);     // 463 => [65568]
+        map.insert(464, &[1044]);     // 464 => [1044]
+        map.insert(465, &[65602]);     // 465 => [65602]
+        map.insert(466, &[65601]);     // 466 => [65601]
+        map.insert(467, &[65600]);     // 467 => [65600]
+        map.insert(468, &[1040]);     // 468 => [1040]
+        map.insert(469, &[1041]);     // 469 => [1041]
+        map.insert(470, &[1042]);     // 470 => [1042]
+        map.insert(471, &[65604]);     // 471 => [65604]
+        map.insert(472, &[20480]);     // 472 => [20480]
+        map.insert(473, &[20481]);     // 473 => [20481]
+        map.insert(474, &[524416]);     // 474 => [524416]
+        map.insert(475, &[524417]);     // 475 => [524417]
+        map.insert(476, &[1048705]);     // 476 => [1048705]
+        map.insert(477, &[1048704]);     // 477 => [1048704]
+        map.insert(478, &[167772161]);     // 478 => [167772161]
+        map.insert(479, &[167772160]);     // 479 => [167772160]
+        map.insert(480, &[301989888]);     // 480 => [301989888]
+        map.insert(481, &[301989889]);     // 481 => [301989889]
+        map.insert(482, &[8392704]);     // 482 => [8392704]
+        map.insert(483, &[8392705]);     // 483 => [8392705]
+        map.insert(484, &[1056]);     // 484 => [1056]
+        map.insert(485, &[264192]);     // 485 => [264192]
+        map.insert(486, &[65545]);     // 486 => [65545]
+        map.insert(487, &[65544]);     // 487 => [65544]
+        map.insert(488, &[301989896]);     // 488 => [301989896]
+        map.insert(489, &[65542]);     // 489 => [65542]
+        map.insert(490, &[65541]);     // 490 => [65541]
+        map.insert(491, &[65540]);     // 491 => [65540]
+        map.insert(492, &[65539]);     // 492 => [65539]
+        map.insert(493, &[65538]);     // 493 => [65538]
+        map.insert(494, &[65537]);     // 494 => [65537]
+        map.insert(495, &[65536]);     // 495 => [65536]
+        map.insert(496, &[33024]);     // 496 => [33024]
+        map.insert(497, &[16781312]);     // 497 => [16781312]
+        map.insert(498, &[33026]);     // 498 => [33026]
+        map.insert(499, &[16781314]);     // 499 => [16781314]
+        map.insert(500, &[33028]);     // 500 => [33028]
+        map.insert(501, &[16781316]);     // 501 => [16781316]
+        map.insert(502, &[134222336]);     // 502 => [134222336]
+        map.insert(503, &[65560]);     // 503 => [65560]
+        map.insert(504, &[1088]);     // 504 => [1088]
+        map.insert(505, &[1089]);     // 505 => [1089]
+        map.insert(506, &[1090]);     // 506 => [1090]
+        map.insert(507, &[65556]);     // 507 => [65556]
+        map.insert(508, &[201326592]);     // 508 => [201326592]
+        map.insert(509, &[201326593]);     // 509 => [201326593]
+        map.insert(510, &[65553]);     // 510 => [65553]
+        map.insert(511, &[65552]);     // 511 => [65552]
+        map.insert(512, &[2048]);     // 512 => [2048]
+        map.insert(513, &[2049]);     // 513 => [2049]
+        map.insert(514, &[2050]);     // 514 => [2050]
+        map.insert(515, &[2051]);     // 515 => [2051]
+        map.insert(516, &[2052]);     // 516 => [2052]
+        map.insert(517, &[2053]);     // 517 => [2053]
+        map.insert(518, &[2054]);     // 518 => [2054]
+        map.insert(519, &[17664]);     // 519 => [17664]
+        map.insert(520, &[2056]);     // 520 => [2056]
+        map.insert(521, &[8421376]);     // 521 => [8421376]
+        map.insert(522, &[327680]);     // 522 => [327680]
+        map.insert(523, &[67633152]);     // 523 => [67633152]
+        map.insert(524, &[68157440]);     // 524 => [68157440]
+        map.insert(525, &[68157441]);     // 525 => [68157441]
+        map.insert(526, &[68157442]);     // 526 => [68157442]
+        map.insert(527, &[67633156]);     // 527 => [67633156]
+        map.insert(528, &[2064]);     // 528 => [2064]
+        map.insert(529, &[2065]);     // 529 => [2065]
+        map.insert(530, &[268435584]);     // 530 => [268435584]
+        map.insert(531, &[268435585]);     // 531 => [268435585]
+        map.insert(532, &[2068]);     // 532 => [2068]
+        map.insert(533, &[4194848]);     // 533 => [4194848]
+        map.insert(534, &[268435588]);     // 534 => [268435588]
+        map.insert(535, &[35655680]);     // 535 => [35655680]
+        map.insert(536, &[16809986]);     // 536 => [16809986]
+        map.insert(537, &[4354]);     // 537 => [4354]
+        map.insert(538, &[16809984]);     // 538 => [16809984]
+        map.insert(539, &[4352]);     // 539 => [4352]
+        map.insert(540, &[68157456]);     // 540 => [68157456]
+        map.insert(541, &[263232]);     // 541 => [263232]
+        map.insert(542, &[16809988]);     // 542 => [16809988]
+        map.insert(543, &[4356]);     // 543 => [4356]
+        map.insert(544, &[2080]);     // 544 => [2080]
+        map.insert(545, &[263168]);     // 545 => [263168]
+        map.insert(546, &[2082]);     // 546 => [2082]
+        map.insert(547, &[263170]);     // 547 => [263170]
+        map.insert(548, &[2084]);     // 548 => [2084]
+        map.insert(549, &[263172]);     // 549 => [263172]
+        map.insert(550, &[16810048]);     // 550 => [16810048]
+        map.insert(551, &[34603016]);     // 551 => [34603016]
+        map.insert(552, &[34078720]);     // 552 => [34078720]
+        map.insert(553, &[34078721]);     // 553 => [34078721]
+        map.insert(554, &[34078722]);     // 554 => [34078722]
+        map.insert(555, &[34603012]);     // 555 => [34603012]
+        map.insert(556, &[163840]);     // 556 => [163840]
+        map.insert(557, &[134217856]);     // 557 => [134217856]
+        map.insert(558, &[34603009]);     // 558 => [34603009]
+        map.insert(559, &[34603008]);     // 559 => [34603008]
+        map.insert(560, &[2096]);     // 560 => [2096]
+        map.insert(561, &[49154]);     // 561 => [49154]
+        map.insert(562, &[49153]);     // 562 => [49153]
+        map.insert(563, &[49152]);     // 563 => [49152]
+        map.insert(564, &[4194817]);     // 564 => [4194817]
+        map.insert(565, &[4194816]);     // 565 => [4194816]
+        map.insert(566, &[2097672]);     // 566 => [2097672]
+        map.insert(567, &[4194818]);     // 567 => [4194818]
+        map.insert(568, &[2116]);     // 568 => [2116]
+        map.insert(569, &[8389248]);     // 569 => [8389248]
+        map.insert(570, &[2097668]);     // 570 => [2097668]
+        map.insert(571, &[49160]);     // 571 => [49160]
+        map.insert(572, &[2112]);     // 572 => [2112]
+        map.insert(573, &[2113]);     // 573 => [2113]
+        map.insert(574, &[2097664]);     // 574 => [2097664]
+        map.insert(575, &[2097665]);     // 575 => [2097665]
+        map.insert(576, &[2176]);     // 576 => [2176]
+        map.insert(577, &[2177]);     // 577 => [2177]
+        map.insert(578, &[268435472]);     // 578 => [268435472]
+        map.insert(579, &[16896]);     // 579 => [16896]
+        map.insert(580, &[4227073]);     // 580 => [4227073]
+        map.insert(581, &[4227072]);     // 581 => [4227072]
+        map.insert(582, &[268435476]);     // 582 => [268435476]
+        map.insert(583, &[4227074]);     // 583 => [4227074]
+        map.insert(584, &[2184]);     // 584 => [2184]
+        map.insert(585, &[134217764]);     // 585 => [134217764]
+        map.insert(586, &[2129924]);     // 586 => [2129924]
+        map.insert(587, &[16904]);     // 587 => [16904]
+        map.insert(588, &[134217761]);     // 588 => [134217761]
+        map.insert(589, &[134217760]);     // 589 => [134217760]
+        map.insert(590, &[2129920]);     // 590 => [2129920]
+        map.insert(591, &[2129921]);     // 591 => [2129921]
+        map.insert(592, &[268435458]);     // 592 => [268435458]
+        map.insert(593, &[134217792]);     // 593 => [134217792]
+        map.insert(594, &[268435456]);     // 594 => [268435456]
+        map.insert(595, &[268435457]);     // 595 => [268435457]
+        map.insert(596, &[67109889]);     // 596 => [67109889]
+        map.insert(597, &[67109888]);     // 597 => [67109888]
+        map.insert(598, &[268435460]);     // 598 => [268435460]
+        map.insert(599, &[268435461]);     // 599 => [268435461]
+        map.insert(600, &[268435466]);     // 600 => [268435466]
+        map.insert(601, &[33619972]);     // 601 => [33619972]
+        map.insert(602, &[268435464]);     // 602 => [268435464]
+        map.insert(603, &[268435465]);     // 603 => [268435465]
+        map.insert(604, &[131584]);     // 604 => [131584]
+        map.insert(605, &[33619968]);     // 605 => [33619968]
+        map.insert(606, &[131586]);     // 606 => [131586]
+        map.insert(607, &[33619970]);     // 607 => [33619970]
+        map.insert(608, &[2208]);     // 608 => [2208]
+        map.insert(609, &[134217740]);     // 609 => [134217740]
+        map.insert(610, &[16777736]);     // 610 => [16777736]
+        map.insert(611, &[16928]);     // 611 => [16928]
+        map.insert(612, &[134217737]);     // 612 => [134217737]
+        map.insert(613, &[134217736]);     // 613 => [134217736]
+        map.insert(614, &[268435528]);     // 614 => [268435528]
+        map.insert(615, &[134217738]);     // 615 => [134217738]
+        map.insert(616, &[134217733]);     // 616 => [134217733]
+        map.insert(617, &[134217732]);     // 617 => [134217732]
+        map.insert(618, &[16777728]);     // 618 => [16777728]
+        map.insert(619, &[16777729]);     // 619 => [16777729]
+        map.insert(620, &[134217729]);     // 620 => [134217729]
+        map.insert(621, &[134217728]);     // 621 => [134217728]
+        map.insert(622, &[268435520]);     // 622 => [268435520]
+        map.insert(623, &[134217730]);     // 623 => [134217730]
+        map.insert(624, &[268435490]);     // 624 => [268435490]
+        map.insert(625, &[8389128]);     // 625 => [8389128]
+        map.insert(626, &[268435488]);     // 626 => [268435488]
+        map.insert(627, &[268435489]);     // 627 => [268435489]
+        map.insert(628, &[33555458]);     // 628 => [33555458]
+        map.insert(629, &[134217752]);     // 629 => [134217752]
+        map.insert(630, &[33555456]);     // 630 => [33555456]
+        map.insert(631, &[33555457]);     // 631 => [33555457]
+        map.insert(632, &[1310720]);     // 632 => [1310720]
+        map.insert(633, &[8389120]);     // 633 => [8389120]
+        map.insert(634, &[1310722]);     // 634 => [1310722]
+        map.insert(635, &[8389122]);     // 635 => [8389122]
+        map.insert(636, &[8448]);     // 636 => [8448]
+        map.insert(637, &[134217744]);     // 637 => [134217744]
+        map.insert(638, &[67174400]);     // 638 => [67174400]
+        map.insert(639, &[786432]);     // 639 => [786432]
+        map.insert(640, &[2304]);     // 640 => [2304]
+        map.insert(641, &[2305]);     // 641 => [2305]
+        map.insert(642, &[2306]);     // 642 => [2306]
+        map.insert(643, &[17412]);     // 643 => [17412]
+        map.insert(644, &[16842753]);     // 644 => [16842753]
+        map.insert(645, &[16842752]);     // 645 => [16842752]
+        map.insert(646, &[17409]);     // 646 => [17409]
+        map.insert(647, &[17408]);     // 647 => [17408]
+        map.insert(648, &[2312]);     // 648 => [2312]
+        map.insert(649, &[4114]);     // 649 => [4114]
+        map.insert(650, &[4113]);     // 650 => [4113]
+        map.insert(651, &[4112]);     // 651 => [4112]
+        map.insert(652, &[81952]);     // 652 => [81952]
+        map.insert(653, &[16842760]);     // 653 => [16842760]
+        map.insert(654, &[16778272]);     // 654 => [16778272]
+        map.insert(655, &[4116]);     // 655 => [4116]
+        map.insert(656, &[67109377]);     // 656 => [67109377]
+        map.insert(657, &[67109376]);     // 657 => [67109376]
+        map.insert(658, &[4105]);     // 658 => [4105]
+        map.insert(659, &[4104]);     // 659 => [4104]
+        map.insert(660, &[294913]);     // 660 => [294913]
+        map.insert(661, &[294912]);     // 661 => [294912]
+        map.insert(662, &[8454144]);     // 662 => [8454144]
+        map.insert(663, &[8454145]);     // 663 => [8454145]
+        map.insert(664, &[132096]);     // 664 => [132096]
+        map.insert(665, &[4098]);     // 665 => [4098]
+        map.insert(666, &[4097]);     // 666 => [4097]
+        map.insert(667, &[4096]);     // 667 => [4096]
+        map.insert(668, &[132100]);     // 668 => [132100]
+        map.insert(669, &[4102]);     // 669 => [4102]
+        map.insert(670, &[4101]);     // 670 => [4101]
+        map.insert(671, &[4100]);     // 671 => [4100]
+        map.insert(672, &[2621444]);     // 672 => [2621444]
+        map.insert(673, &[3145730]);     // 673 => [3145730]
+        map.insert(674, &[3145729]);     // 674 => [3145729]
+        map.insert(675, &[3145728]);     // 675 => [3145728]
+        map.insert(676, &[2621440]);     // 676 => [2621440]
+        map.insert(677, &[2621441]);     // 677 => [2621441]
+        map.insert(678, &[4161]);     // 678 => [4161]
+        map.insert(679, &[4160]);     // 679 => [4160]
+        map.insert(680, &[5242880]);     // 680 => [5242880]
+        map.insert(681, &[5242881]);     // 681 => [5242881]
+        map.insert(682, &[5242882]);     // 682 => [5242882]
+        map.insert(683, &[4718596]);     // 683 => [4718596]
+        map.insert(684, &[81920]);     // 684 => [81920]
+        map.insert(685, &[81921]);     // 685 => [81921]
+        map.insert(686, &[16778240]);     // 686 => [16778240]
+        map.insert(687, &[4718592]);     // 687 => [4718592]
+        map.insert(688, &[33554946]);     // 688 => [33554946]
+        map.insert(689, &[196610]);     // 689 => [196610]
+        map.insert(690, &[33554944]);     // 690 => [33554944]
+        map.insert(691, &[196608]);     // 691 => [196608]
+        map.insert(692, &[8328]);     // 692 => [8328]
+        map.insert(693, &[8389640]);     // 693 => [8389640]
+        map.insert(694, &[33554948]);     // 694 => [33554948]
+        map.insert(695, &[196612]);     // 695 => [196612]
+        map.insert(696, &[8324]);     // 696 => [8324]
+        map.insert(697, &[4130]);     // 697 => [4130]
+        map.insert(698, &[4129]);     // 698 => [4129]
+        map.insert(699, &[4128]);     // 699 => [4128]
+        map.insert(700, &[8320]);     // 700 => [8320]
+        map.insert(701, &[8389632]);     // 701 => [8389632]
+        map.insert(702, &[8322]);     // 702 => [8322]
+        map.insert(703, &[8389634]);     // 703 => [8389634]
+        map.insert(704, &[8256]);     // 704 => [8256]
+        map.insert(705, &[1179648]);     // 705 => [1179648]
+        map.insert(706, &[33587200]);     // 706 => [33587200]
+        map.insert(707, &[33587201]);     // 707 => [33587201]
+        map.insert(708, &[655362]);     // 708 => [655362]
+        map.insert(709, &[1179652]);     // 709 => [1179652]
+        map.insert(710, &[655360]);     // 710 => [655360]
+        map.insert(711, &[655361]);     // 711 => [655361]
+        map.insert(712, &[8264]);     // 712 => [8264]
+        map.insert(713, &[1179656]);     // 713 => [1179656]
+        map.insert(714, &[33587208]);     // 714 => [33587208]
+        map.insert(715, &[4240]);     // 715 => [4240]
+        map.insert(716, &[8240]);     // 716 => [8240]
+        map.insert(717, &[134218016]);     // 717 => [134218016]
+        map.insert(718, &[655368]);     // 718 => [655368]
+        map.insert(719, &[269484544]);     // 719 => [269484544]
+        map.insert(720, &[2162689]);     // 720 => [2162689]
+        map.insert(721, &[2162688]);     // 721 => [2162688]
+        map.insert(722, &[268435712]);     // 722 => [268435712]
+        map.insert(723, &[268435713]);     // 723 => [268435713]
+        map.insert(724, &[8232]);     // 724 => [8232]
+        map.insert(725, &[2162692]);     // 725 => [2162692]
+        map.insert(726, &[268435716]);     // 726 => [268435716]
+        map.insert(727, &[17825824]);     // 727 => [17825824]
+        map.insert(728, &[540673]);     // 728 => [540673]
+        map.insert(729, &[540672]);     // 729 => [540672]
+        map.insert(730, &[4259840]);     // 730 => [4259840]
+        map.insert(731, &[4224]);     // 731 => [4224]
+        map.insert(732, &[8224]);     // 732 => [8224]
+        map.insert(733, &[8225]);     // 733 => [8225]
+        map.insert(734, &[1064960]);     // 734 => [1064960]
+        map.insert(735, &[1064961]);     // 735 => [1064961]
+        map.insert(736, &[67141633]);     // 736 => [67141633]
+        map.insert(737, &[67141632]);     // 737 => [67141632]
+        map.insert(738, &[8912897]);     // 738 => [8912897]
+        map.insert(739, &[8912896]);     // 739 => [8912896]
+        map.insert(740, &[9437184]);     // 740 => [9437184]
+        map.insert(741, &[262656]);     // 741 => [262656]
+        map.insert(742, &[9437186]);     // 742 => [9437186]
+        map.insert(743, &[262658]);     // 743 => [262658]
+        map.insert(744, &[8212]);     // 744 => [8212]
+        map.insert(745, &[134217988]);     // 745 => [134217988]
+        map.insert(746, &[2098192]);     // 746 => [2098192]
+        map.insert(747, &[8912904]);     // 747 => [8912904]
+        map.insert(748, &[8208]);     // 748 => [8208]
+        map.insert(749, &[134217984]);     // 749 => [134217984]
+        map.insert(750, &[8210]);     // 750 => [8210]
+        map.insert(751, &[134217986]);     // 751 => [134217986]
+        map.insert(752, &[17301504]);     // 752 => [17301504]
+        map.insert(753, &[4195328]);     // 753 => [4195328]
+        map.insert(754, &[17301506]);     // 754 => [17301506]
+        map.insert(755, &[4195330]);     // 755 => [4195330]
+        map.insert(756, &[8200]);     // 756 => [8200]
+        map.insert(757, &[8201]);     // 757 => [8201]
+        map.insert(758, &[17825793]);     // 758 => [17825793]
+        map.insert(759, &[17825792]);     // 759 => [17825792]
+        map.insert(760, &[8196]);     // 760 => [8196]
+        map.insert(761, &[8197]);     // 761 => [8197]
+        map.insert(762, &[2098176]);     // 762 => [2098176]
+        map.insert(763, &[2098177]);     // 763 => [2098177]
+        map.insert(764, &[8192]);     // 764 => [8192]
+        map.insert(765, &[8193]);     // 765 => [8193]
+        map.insert(766, &[8194]);     // 766 => [8194]
+        map.insert(767, &[8195]);     // 767 => [8195]
+        map.insert(768, &[2560]);     // 768 => [2560]
+        map.insert(769, &[528384]);     // 769 => [528384]
+        map.insert(770, &[2097216]);     // 770 => [2097216]
+        map.insert(771, &[16512]);     // 771 => [16512]
+        map.insert(772, &[1052674]);     // 772 => [1052674]
+        map.insert(773, &[528388]);     // 773 => [528388]
+        map.insert(774, &[1052672]);     // 774 => [1052672]
+        map.insert(775, &[1052673]);     // 775 => [1052673]
+        map.insert(776, &[4194369]);     // 776 => [4194369]
+        map.insert(777, &[4194368]);     // 777 => [4194368]
+        map.insert(778, &[2097224]);     // 778 => [2097224]
+        map.insert(779, &[4194370]);     // 779 => [4194370]
+        map.insert(780, &[131216]);     // 780 => [131216]
+        map.insert(781, &[4194372]);     // 781 => [4194372]
+        map.insert(782, &[1052680]);     // 782 => [1052680]
+        map.insert(783, &[12599296]);     // 783 => [12599296]
+        map.insert(784, &[67109121]);     // 784 => [67109121]
+        map.insert(785, &[67109120]);     // 785 => [67109120]
+        map.insert(786, &[73729]);     // 786 => [73729]
+        map.insert(787, &[73728]);     // 787 => [73728]
+        map.insert(788, &[4194337]);     // 788 => [4194337]
+        map.insert(789, &[4194336]);     // 789 => [4194336]
+        map.insert(790, &[2097192]);     // 790 => [2097192]
+        map.insert(791, &[4194338]);     // 791 => [4194338]
+        map.insert(792, &[131204]);     // 792 => [131204]
+        map.insert(793, &[134250500]);     // 793 => [134250500]
+        map.insert(794, &[2097188]);     // 794 => [2097188]
+        map.insert(795, &[73736]);     // 795 => [73736]
+        map.insert(796, &[131200]);     // 796 => [131200]
+        map.insert(797, &[134250496]);     // 797 => [134250496]
+        map.insert(798, &[2097184]);     // 798 => [2097184]
+        map.insert(799, &[2097185]);     // 799 => [2097185]
+        map.insert(800, &[268468226]);     // 800 => [268468226]
+        map.insert(801, &[4194324]);     // 801 => [4194324]
+        map.insert(802, &[268468224]);     // 802 => [268468224]
+        map.insert(803, &[268468225]);     // 803 => [268468225]
+        map.insert(804, &[4194321]);     // 804 => [4194321]
+        map.insert(805, &[4194320]);     // 805 => [4194320]
+        map.insert(806, &[268468228]);     // 806 => [268468228]
+        map.insert(807, &[4194322]);     // 807 => [4194322]
+        map.insert(808, &[16777346]);     // 808 => [16777346]
+        map.insert(809, &[8388752]);     // 809 => [8388752]
+        map.insert(810, &[16777344]);     // 810 => [16777344]
+        map.insert(811, &[16777345]);     // 811 => [16777345]
+        map.insert(812, &[2097170]);     // 812 => [2097170]
+        map.insert(813, &[4194328]);     // 813 => [4194328]
+        map.insert(814, &[2097168]);     // 814 => [2097168]
+        map.insert(815, &[2097169]);     // 815 => [2097169]
+        map.insert(816, &[4194309]);     // 816 => [4194309]
+        map.insert(817, &[4194308]);     // 817 => [4194308]
+        map.insert(818, &[33554688]);     // 818 => [33554688]
+        map.insert(819, &[33554689]);     // 819 => [33554689]
+        map.insert(820, &[4194305]);     // 820 => [4194305]
+        map.insert(821, &[4194304]);     // 821 => [4194304]
+        map.insert(822, &[2097160]);     // 822 => [2097160]
+        map.insert(823, &[4194306]);     // 823 => [4194306]
+        map.insert(824, &[9216]);     // 824 => [9216]
+        map.insert(825, &[8388736]);     // 825 => [8388736]
+        map.insert(826, &[2097156]);     // 826 => [2097156]
+        map.insert(827, &[2097157]);     // 827 => [2097157]
+        map.insert(828, &[2097154]);     // 828 => [2097154]
+        map.insert(829, &[4194312]);     // 829 => [4194312]
+        map.insert(830, &[2097152]);     // 830 => [2097152]
+        map.insert(831, &[2097153]);     // 831 => [2097153]
+        map.insert(832, &[16387]);     // 832 => [16387]
+        map.insert(833, &[16386]);     // 833 => [16386]
+        map.insert(834, &[16385]);     // 834 => [16385]
+        map.insert(835, &[16384]);     // 835 => [16384]
+        map.insert(836, &[8388673]);     // 836 => [8388673]
+        map.insert(837, &[8388672]);     // 837 => [8388672]
+        map.insert(838, &[16389]);     // 838 => [16389]
+        map.insert(839, &[16388]);     // 839 => [16388]
+        map.insert(840, &[16777250]);     // 840 => [16777250]
+        map.insert(841, &[16394]);     // 841 => [16394]
+        map.insert(842, &[16777248]);     // 842 => [16777248]
+        map.insert(843, &[16392]);     // 843 => [16392]
+        map.insert(844, &[131088]);     // 844 => [131088]
+        map.insert(845, &[131089]);     // 845 => [131089]
+        map.insert(846, &[131090]);     // 846 => [131090]
+        map.insert(847, &[16396]);     // 847 => [16396]
+        map.insert(848, &[268435970]);     // 848 => [268435970]
+        map.insert(849, &[16402]);     // 849 => [16402]
+        map.insert(850, &[268435968]);     // 850 => [268435968]
+        map.insert(851, &[16400]);     // 851 => [16400]
+        map.insert(852, &[131080]);     // 852 => [131080]
+        map.insert(853, &[131081]);     // 853 => [131081]
+        map.insert(854, &[16777280]);     // 854 => [16777280]
+        map.insert(855, &[16777281]);     // 855 => [16777281]
+        map.insert(856, &[131076]);     // 856 => [131076]
+        map.insert(857, &[8388640]);     // 857 => [8388640]
+        map.insert(858, &[131078]);     // 858 => [131078]
+        map.insert(859, &[8388642]);     // 859 => [8388642]
+        map.insert(860, &[131072]);     // 860 => [131072]
+        map.insert(861, &[131073]);     // 861 => [131073]
+        map.insert(862, &[131074]);     // 862 => [131074]
+        map.insert(863, &[5120]);     // 863 => [5120]
+        map.insert(864, &[131136]);     // 864 => [131136]
+        map.insert(865, &[1056768]);     // 865 => [1056768]
+        map.insert(866, &[16777224]);     // 866 => [16777224]
+        map.insert(867, &[16416]);     // 867 => [16416]
+        map.insert(868, &[262401]);     // 868 => [262401]
+        map.insert(869, &[262400]);     // 869 => [262400]
+        map.insert(870, &[532480]);     // 870 => [532480]
+        map.insert(871, &[532481]);     // 871 => [532481]
+        map.insert(872, &[16777218]);     // 872 => [16777218]
+        map.insert(873, &[8388624]);     // 873 => [8388624]
+        map.insert(874, &[16777216]);     // 874 => [16777216]
+        map.insert(875, &[16777217]);     // 875 => [16777217]
+        map.insert(876, &[134218241]);     // 876 => [134218241]
+        map.insert(877, &[134218240]);     // 877 => [134218240]
+        map.insert(878, &[16777220]);     // 878 => [16777220]
+        map.insert(879, &[16777221]);     // 879 => [16777221]
+        map.insert(880, &[34816]);     // 880 => [34816]
+        map.insert(881, &[8388616]);     // 881 => [8388616]
+        map.insert(882, &[34818]);     // 882 => [34818]
+        map.insert(883, &[8388618]);     // 883 => [8388618]
+        map.insert(884, &[69632]);     // 884 => [69632]
+        map.insert(885, &[4194432]);     // 885 => [4194432]
+        map.insert(886, &[69634]);     // 886 => [69634]
+        map.insert(887, &[4194434]);     // 887 => [4194434]
+        map.insert(888, &[8388609]);     // 888 => [8388609]
+        map.insert(889, &[8388608]);     // 889 => [8388608]
+        map.insert(890, &[16777232]);     // 890 => [16777232]
+        map.insert(891, &[8388610]);     // 891 => [8388610]
+        map.insert(892, &[131104]);     // 892 => [131104]
+        map.insert(893, &[8388612]);     // 893 => [8388612]
+        map.insert(894, &[2097280]);     // 894 => [2097280]
+        map.insert(895, &[16448]);     // 895 => [16448]
+        map.insert(896, &[67108881]);     // 896 => [67108881]
+        map.insert(897, &[67108880]);     // 897 => [67108880]
+        map.insert(898, &[134283264]);     // 898 => [134283264]
+        map.insert(899, &[134283265]);     // 899 => [134283265]
+        map.insert(900, &[40968]);     // 900 => [40968]
+        map.insert(901, &[67108884]);     // 901 => [67108884]
+        map.insert(902, &[134283268]);     // 902 => [134283268]
+        map.insert(903, &[17920]);     // 903 => [17920]
+        map.insert(904, &[40964]);     // 904 => [40964]
+        map.insert(905, &[67108888]);     // 905 => [67108888]
+        map.insert(906, &[33554500]);     // 906 => [33554500]
+        map.insert(907, &[4624]);     // 907 => [4624]
+        map.insert(908, &[40960]);     // 908 => [40960]
+        map.insert(909, &[40961]);     // 909 => [40961]
+        map.insert(910, &[33554496]);     // 910 => [33554496]
+        map.insert(911, &[33554497]);     // 911 => [33554497]
+        map.insert(912, &[67108865]);     // 912 => [67108865]
+        map.insert(913, &[67108864]);     // 913 => [67108864]
+        map.insert(914, &[33554464]);     // 914 => [33554464]
+        map.insert(915, &[67108866]);     // 915 => [67108866]
+        map.insert(916, &[67108869]);     // 916 => [67108869]
+        map.insert(917, &[67108868]);     // 917 => [67108868]
+        map.insert(918, &[268436480]);     // 918 => [268436480]
+        map.insert(919, &[268436481]);     // 919 => [268436481]
+        map.insert(920, &[67108873]);     // 920 => [67108873]
+        map.insert(921, &[67108872]);     // 921 => [67108872]
+        map.insert(922, &[526336]);     // 922 => [526336]
+        map.insert(923, &[4608]);     // 923 => [4608]
+        map.insert(924, &[1050625]);     // 924 => [1050625]
+        map.insert(925, &[1050624]);     // 925 => [1050624]
+        map.insert(926, &[526340]);     // 926 => [526340]
+        map.insert(927, &[1050626]);     // 927 => [1050626]
+        map.insert(928, &[33554450]);     // 928 => [33554450]
+        map.insert(929, &[262276]);     // 929 => [262276]
+        map.insert(930, &[33554448]);     // 930 => [33554448]
+        map.insert(931, &[33554449]);     // 931 => [33554449]
+        map.insert(932, &[262273]);     // 932 => [262273]
+        map.insert(933, &[262272]);     // 933 => [262272]
+        map.insert(934, &[33554452]);     // 934 => [33554452]
+        map.insert(935, &[262274]);     // 935 => [262274]
+        map.insert(936, &[134218753]);     // 936 => [134218753]
+        map.insert(937, &[134218752]);     // 937 => [134218752]
+        map.insert(938, &[33554456]);     // 938 => [33554456]
+        map.insert(939, &[134218754]);     // 939 => [134218754]
+        map.insert(940, &[67108929]);     // 940 => [67108929]
+        map.insert(941, &[67108928]);     // 941 => [67108928]
+        map.insert(942, &[2097424]);     // 942 => [2097424]
+        map.insert(943, &[67108930]);     // 943 => [67108930]
+        map.insert(944, &[33554434]);     // 944 => [33554434]
+        map.insert(945, &[67108896]);     // 945 => [67108896]
+        map.insert(946, &[33554432]);     // 946 => [33554432]
+        map.insert(947, &[33554433]);     // 947 => [33554433]
+        map.insert(948, &[4194561]);     // 948 => [4194561]
+        map.insert(949, &[4194560]);     // 949 => [4194560]
+        map.insert(950, &[33554436]);     // 950 => [33554436]
+        map.insert(951, &[33554437]);     // 951 => [33554437]
+        map.insert(952, &[33554442]);     // 952 => [33554442]
+        map.insert(953, &[268500996]);     // 953 => [268500996]
+        map.insert(954, &[33554440]);     // 954 => [33554440]
+        map.insert(955, &[33554441]);     // 955 => [33554441]
+        map.insert(956, &[268500993]);     // 956 => [268500993]
+        map.insert(957, &[268500992]);     // 957 => [268500992]
+        map.insert(958, &[2097408]);     // 958 => [2097408]
+        map.insert(959, &[2097409]);     // 959 => [2097409]
+        map.insert(960, &[3076]);     // 960 => [3076]
+        map.insert(961, &[16642]);     // 961 => [16642]
+        map.insert(962, &[16641]);     // 962 => [16641]
+        map.insert(963, &[16640]);     // 963 => [16640]
+        map.insert(964, &[3072]);     // 964 => [3072]
+        map.insert(965, &[262176]);     // 965 => [262176]
+        map.insert(966, &[3074]);     // 966 => [3074]
+        map.insert(967, &[262178]);     // 967 => [262178]
+        map.insert(968, &[268959744]);     // 968 => [268959744]
+        map.insert(969, &[268959745]);     // 969 => [268959745]
+        map.insert(970, &[268959746]);     // 970 => [268959746]
+        map.insert(971, &[269484036]);     // 971 => [269484036]
+        map.insert(972, &[268959748]);     // 972 => [268959748]
+        map.insert(973, &[269484034]);     // 973 => [269484034]
+        map.insert(974, &[269484033]);     // 974 => [269484033]
+        map.insert(975, &[269484032]);     // 975 => [269484032]
+        map.insert(976, &[67108993]);     // 976 => [67108993]
+        map.insert(977, &[67108992]);     // 977 => [67108992]
+        map.insert(978, &[33554592]);     // 978 => [33554592]
+        map.insert(979, &[67108994]);     // 979 => [67108994]
+        map.insert(980, &[131336]);     // 980 => [131336]
+        map.insert(981, &[67108996]);     // 981 => [67108996]
+        map.insert(982, &[16777536]);     // 982 => [16777536]
+        map.insert(983, &[134742048]);     // 983 => [134742048]
+        map.insert(984, &[262209]);     // 984 => [262209]
+        map.insert(985, &[262208]);     // 985 => [262208]
+        map.insert(986, &[526464]);     // 986 => [526464]
+        map.insert(987, &[262210]);     // 987 => [262210]
+        map.insert(988, &[131328]);     // 988 => [131328]
+        map.insert(989, &[131329]);     // 989 => [131329]
+        map.insert(990, &[131330]);     // 990 => [131330]
+        map.insert(991, &[269484048]);     // 991 => [269484048]
+        map.insert(992, &[262149]);     // 992 => [262149]
+        map.insert(993, &[262148]);     // 993 => [262148]
+        map.insert(994, &[16777480]);     // 994 => [16777480]
+        map.insert(995, &[262150]);     // 995 => [262150]
+        map.insert(996, &[262145]);     // 996 => [262145]
+        map.insert(997, &[262144]);     // 997 => [262144]
+        map.insert(998, &[262147]);     // 998 => [262147]
+        map.insert(999, &[262146]);     // 999 => [262146]
+        map.insert(1000, &[16777474]);     // 1000 => [16777474]
+        map.insert(1001, &[36866]);     // 1001 => [36866]
+        map.insert(1002, &[16777472]);     // 1002 => [16777472]
+        map.insert(1003, &[36864]);     // 1003 => [36864]
+        map.insert(1004, &[262153]);     // 1004 => [262153]
+        map.insert(1005, &[262152]);     // 1005 => [262152]
+        map.insert(1006, &[67585]);     // 1006 => [67585]
+        map.insert(1007, &[67584]);     // 1007 => [67584]
+        map.insert(1008, &[135266304]);     // 1008 => [135266304]
+        map.insert(1009, &[135266305]);     // 1009 => [135266305]
+        map.insert(1010, &[33554560]);     // 1010 => [33554560]
+        map.insert(1011, &[33554561]);     // 1011 => [33554561]
+        map.insert(1012, &[262161]);     // 1012 => [262161]
+        map.insert(1013, &[262160]);     // 1013 => [262160]
+        map.insert(1014, &[134742017]);     // 1014 => [134742017]
+        map.insert(1015, &[134742016]);     // 1015 => [134742016]
+        map.insert(1016, &[8388865]);     // 1016 => [8388865]
+        map.insert(1017, &[8388864]);     // 1017 => [8388864]
+        map.insert(1018, &[33554568]);     // 1018 => [33554568]
+        map.insert(1019, &[8388866]);     // 1019 => [8388866]
+        map.insert(1020, &[8704]);     // 1020 => [8704]
+        map.insert(1021, &[8705]);     // 1021 => [8705]
+        map.insert(1022, &[8706]);     // 1022 => [8706]
+        map.insert(1023, &[134742024]);     // 1023 => [134742024]
+        map
+    })
 }
 
 impl WagnerCode29_19 {
     fn parity_check_matrix_transposed(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX_T.as_ref().unwrap()
-        }
+        parity_matrix_transposed()
     }
 }
 
@@ -1119,24 +1119,15 @@ impl BinaryCode for WagnerCode29_19 {
     }
 
     fn generator_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            GENERATOR_MATRIX.as_ref().unwrap()
-        }
+        generator_matrix()
     }
 
     fn parity_check_matrix(&self) -> &BinMatrix {
-        init();
-        unsafe {
-            PARITY_MATRIX.as_ref().unwrap()
-        }
+        parity_matrix()
     }
 
     fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
-        init();
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         debug_assert_eq!(c.len(), self.length(), "the length doesn't match the expected length (length of the code)");
         let he = c * self.parity_check_matrix_transposed();
         let mut error = BinVector::with_capacity(29);
@@ -1161,14 +1152,19 @@ impl BinaryCode for WagnerCode29_19 {
         
     }
 
+    fn covering_radius(&self) -> usize {
+        3
+    }
+
+    /// The syndrome table holds a coset leader for every syndrome
+    fn decodes_to_closest_codeword(&self) -> bool {
+        true
+    }
+
     fn decode_slice(&self, c: &mut [u64]) {
-        init();
-        
         debug_assert_eq!(c[29 / 64] & !((1 << 29) - 1), 0, "this message has excess bits");
 
-        let map = unsafe {
-            SYNDROME_MAP.as_ref().unwrap()
-        };
+        let map = syndrome_map();
         let he = &BinMatrix::from_slices(&[&c[..]], self.length()) * self.parity_check_matrix_transposed();
         let error = map[unsafe { &he.get_word_unchecked(0, 0) }];
         c.iter_mut().zip(error.iter().copied()).for_each(|(sample, error)| *sample ^= error as u64);
@@ -2623,8 +2619,7 @@ mod tests {
 
     #[test]
     fn test_generator_representation() {
-        init();
-        let generator_matrix = unsafe { GENERATOR_MATRIX.as_ref().unwrap() };
+        let generator_matrix = generator_matrix();
         let first_row = generator_matrix.get_window(0, 0, 1, generator_matrix.ncols());
         let vector = BinVector::from_bools(&[ true, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, true, false, true, false, true, false, true, false, true ]);
         assert_eq!(vector, first_row.as_vector());
@@ -0,0 +1,187 @@
+use crate::codes::BinaryCode;
+use m4ri_rust::friendly::BinMatrix;
+use m4ri_rust::friendly::BinVector;
+use std::sync::OnceLock;
+
+/// The direct sum of two codes
+///
+/// The codewords are $(c_a, c_b)$ for all codewords $c_a$ of `inner_a` and $c_b$ of
+/// `inner_b`, which gives an $[n_a + n_b, k_a + k_b, \min(d_a, d_b)]$ code. Both halves
+/// are encoded and decoded independently. Unlike [`crate::codes::ConcatenatedCode`],
+/// this owns its parts, so it can be nested and combined with any other code.
+///
+/// The block diagonal generator and parity check matrices are built on first use.
+pub struct XorCode<A: BinaryCode, B: BinaryCode> {
+    /// The code for the first $n_a$ positions
+    pub inner_a: A,
+    /// The code for the last $n_b$ positions
+    pub inner_b: B,
+    generator: OnceLock<BinMatrix>,
+    parity_check: OnceLock<BinMatrix>,
+}
+
+impl<A: BinaryCode, B: BinaryCode> XorCode<A, B> {
+    /// The direct sum of `inner_a` and `inner_b`
+    pub fn new(inner_a: A, inner_b: B) -> XorCode<A, B> {
+        XorCode {
+            inner_a,
+            inner_b,
+            generator: OnceLock::new(),
+            parity_check: OnceLock::new(),
+        }
+    }
+
+    /// The minimum distance $\min(d_a, d_b)$, computed from the parts
+    pub fn minimum_distance(&self) -> usize {
+        crate::codes::minimum_distance(&self.inner_a)
+            .min(crate::codes::minimum_distance(&self.inner_b))
+    }
+
+    /// Split `v` into the parts for `inner_a` and `inner_b`
+    fn split(v: &BinVector, at: usize) -> (BinVector, BinVector) {
+        let mut first = v.clone();
+        let second = BinVector::from(first.split_off(at));
+        (first, second)
+    }
+}
+
+/// The matrix with `a` in the top left and `b` in the bottom right corner
+fn block_diagonal(a: &BinMatrix, b: &BinMatrix) -> BinMatrix {
+    let mut result = a
+        .augmented(&BinMatrix::zero(a.nrows(), b.ncols()))
+        .stacked(&BinMatrix::zero(b.nrows(), a.ncols() + b.ncols()));
+    result.set_window(a.nrows(), a.ncols(), b);
+    result
+}
+
+fn concat(mut first: BinVector, second: &BinVector) -> BinVector {
+    first.extend_from_binvec(second);
+    first
+}
+
+impl<A: BinaryCode, B: BinaryCode> BinaryCode for XorCode<A, B> {
+    fn name(&self) -> String {
+        format!(
+            "[{}, {}] Direct sum of {} and {}",
+            self.length(),
+            self.dimension(),
+            self.inner_a.name(),
+            self.inner_b.name()
+        )
+    }
+
+    fn length(&self) -> usize {
+        self.inner_a.length() + self.inner_b.length()
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner_a.dimension() + self.inner_b.dimension()
+    }
+
+    fn generator_matrix(&self) -> &BinMatrix {
+        self.generator.get_or_init(|| {
+            block_diagonal(
+                self.inner_a.generator_matrix(),
+                self.inner_b.generator_matrix(),
+            )
+        })
+    }
+
+    fn parity_check_matrix(&self) -> &BinMatrix {
+        self.parity_check.get_or_init(|| {
+            block_diagonal(
+                self.inner_a.parity_check_matrix(),
+                self.inner_b.parity_check_matrix(),
+            )
+        })
+    }
+
+    fn is_codeword(&self, v: &BinVector) -> bool {
+        debug_assert_eq!(v.len(), self.length(), "the length doesn't match the code");
+        let (a, b) = Self::split(v, self.inner_a.length());
+        self.inner_a.is_codeword(&a) && self.inner_b.is_codeword(&b)
+    }
+
+    fn encode(&self, c: &BinVector) -> BinVector {
+        debug_assert_eq!(
+            c.len(),
+            self.dimension(),
+            "the message has the wrong length"
+        );
+        let (a, b) = Self::split(c, self.inner_a.dimension());
+        concat(self.inner_a.encode(&a), &self.inner_b.encode(&b))
+    }
+
+    fn decode_to_code(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length(),
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let (a, b) = Self::split(c, self.inner_a.length());
+        Ok(concat(
+            self.inner_a.decode_to_code(&a)?,
+            &self.inner_b.decode_to_code(&b)?,
+        ))
+    }
+
+    fn decode_to_message(&self, c: &BinVector) -> Result<BinVector, &str> {
+        debug_assert_eq!(
+            c.len(),
+            self.length(),
+            "the length doesn't match the expected length (length of the code)"
+        );
+        let (a, b) = Self::split(c, self.inner_a.length());
+        Ok(concat(
+            self.inner_a.decode_to_message(&a)?,
+            &self.inner_b.decode_to_message(&b)?,
+        ))
+    }
+
+    /// The errors in both halves add up
+    fn covering_radius(&self) -> usize {
+        self.inner_a.covering_radius() + self.inner_b.covering_radius()
+    }
+
+    /// The errors in both halves are independent, so their biases multiply
+    fn bias(&self, delta: f64) -> f64 {
+        self.inner_a.bias(delta) * self.inner_b.bias(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codes::HammingCode;
+
+    #[test]
+    fn direct_sum() {
+        let code = XorCode::new(HammingCode::new(3), HammingCode::new(4));
+        assert_eq!((code.length(), code.dimension()), (22, 15));
+        assert_eq!(code.minimum_distance(), 3);
+        assert_eq!(
+            code.minimum_distance(),
+            crate::codes::minimum_distance(&code)
+        );
+        assert_eq!(code.covering_radius(), 2);
+        let product = code.generator_matrix() * &code.parity_check_matrix().transposed();
+        assert_eq!(product, BinMatrix::zero(15, 7));
+        for _ in 0..100 {
+            let message = BinVector::random(15);
+            let codeword = code.encode(&message);
+            assert_eq!(codeword, &message * code.generator_matrix());
+            assert!(code.is_codeword(&codeword));
+            // one error in each part
+            let mut received = codeword.clone();
+            for &i in &[
+                rand::random::<usize>() % 7,
+                7 + rand::random::<usize>() % 15,
+            ] {
+                received.set(i, !received[i]);
+            }
+            assert!(!code.is_codeword(&received));
+            assert_eq!(code.decode_to_code(&received), Ok(codeword));
+            assert_eq!(code.decode_to_message(&received), Ok(message));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Sets of oracles for the same LPN problem
+//!
+//! Samples for one secret may come from several sources. A `CoherentOracleSet`
+//! makes sure they agree on the problem before they are attacked together.
+use crate::oracle::LpnOracle;
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+use std::{error, fmt};
+
+/// Reasons why a set of oracles can not describe the same problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncoherenceError {
+    /// The set has no oracles
+    Empty,
+    /// Oracle `index` has size `k` while the first oracle has size `expected`
+    DifferentK {
+        index: usize,
+        k: usize,
+        expected: usize,
+    },
+    /// Oracle `index` has a different secret than the first oracle
+    DifferentSecret { index: usize },
+}
+
+impl fmt::Display for IncoherenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IncoherenceError::Empty => write!(f, "no oracles given"),
+            IncoherenceError::DifferentK { index, k, expected } => write!(
+                f,
+                "oracle {} has k = {}, expected k = {}",
+                index, k, expected
+            ),
+            IncoherenceError::DifferentSecret { index } => {
+                write!(f, "oracle {} has a different secret", index)
+            }
+        }
+    }
+}
+
+impl error::Error for IncoherenceError {}
+
+/// Oracles that are known to have the same size and secret
+pub struct CoherentOracleSet {
+    oracles: Vec<LpnOracle>,
+}
+
+impl CoherentOracleSet {
+    /// Check that all oracles have the same $k$ and secret
+    pub fn new(oracles: Vec<LpnOracle>) -> Result<CoherentOracleSet, IncoherenceError> {
+        let first = oracles.first().ok_or(IncoherenceError::Empty)?;
+        let k = first.get_k();
+        let secret = first.secret.as_binvector(k);
+        for (index, oracle) in oracles.iter().enumerate().skip(1) {
+            if oracle.get_k() != k {
+                return Err(IncoherenceError::DifferentK {
+                    index,
+                    k: oracle.get_k(),
+                    expected: k,
+                });
+            }
+            if oracle.secret.as_binvector(k) != secret {
+                return Err(IncoherenceError::DifferentSecret { index });
+            }
+        }
+        Ok(CoherentOracleSet { oracles })
+    }
+
+    /// The size of the problem
+    pub fn get_k(&self) -> usize {
+        self.oracles[0].get_k()
+    }
+
+    /// The oracles in this set
+    pub fn oracles(&self) -> &[LpnOracle] {
+        &self.oracles
+    }
+
+    /// For every oracle, the fraction of samples consistent with `candidate` as secret
+    pub fn coherence_score(&self, candidate: &BinVector) -> Vec<f64> {
+        assert_eq!(
+            candidate.len(),
+            self.get_k(),
+            "Candidate should have length k"
+        );
+        let k = self.get_k();
+        self.oracles
+            .iter()
+            .map(|oracle| {
+                let consistent = oracle
+                    .samples
+                    .par_iter()
+                    .filter(|q| &q.as_binvector(k) * candidate == q.get_product())
+                    .count();
+                consistent as f64 / oracle.samples.len() as f64
+            })
+            .collect()
+    }
+
+    /// Get back the oracles
+    pub fn into_oracles(self) -> Vec<LpnOracle> {
+        self.oracles
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shared_secret() {
+        let tau = 1.0 / 8.0;
+        let first = LpnOracle::new(32, tau);
+        let oracles = (0..4)
+            .map(|_| {
+                let mut oracle = LpnOracle::new_with_secret(first.secret.clone(), 32, tau);
+                oracle.get_samples(10_000);
+                oracle
+            })
+            .collect();
+        let set = CoherentOracleSet::new(oracles).unwrap();
+
+        let secret = first.secret.as_binvector(32);
+        for score in set.coherence_score(&secret) {
+            assert!((score - (1.0 - tau)).abs() < 0.02, "score {}", score);
+        }
+        let wrong = &secret + &BinVector::from_function(32, |i| i == 0);
+        for score in set.coherence_score(&wrong) {
+            assert!((score - 0.5).abs() < 0.02, "score {}", score);
+        }
+    }
+
+    #[test]
+    fn incoherent() {
+        let first = LpnOracle::new(32, 0.1);
+        let other = LpnOracle::new(32, 0.1);
+        assert_eq!(
+            CoherentOracleSet::new(vec![first.clone(), first.clone(), other])
+                .err()
+                .unwrap(),
+            IncoherenceError::DifferentSecret { index: 2 }
+        );
+        assert_eq!(
+            CoherentOracleSet::new(vec![first, LpnOracle::new(16, 0.1)])
+                .err()
+                .unwrap(),
+            IncoherenceError::DifferentK {
+                index: 1,
+                k: 16,
+                expected: 32
+            }
+        );
+        assert_eq!(
+            CoherentOracleSet::new(vec![]).err().unwrap(),
+            IncoherenceError::Empty
+        );
+    }
+}
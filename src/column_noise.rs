@@ -0,0 +1,226 @@
+//! LPN with noise that depends on the columns of the queries
+//!
+//! In this variant every term $a_i s_i$ of the inner product is flipped independently
+//! with its own noise rate $\tau_i$. The total noise of a sample thus depends on
+//! which columns are set in its query vector: a sample with query $a$ has bias
+//! $\prod_{i : a_i = 1} (1 - 2\tau_i)$, or $\prod_i (1 - \tau_i)$ averaged over the queries.
+//!
+//! The bias shrinks quickly with the number of columns. With $k = 32$ and rates between
+//! 0.05 and 0.3 a sample has an expected bias of about $2^{-9}$, which is far beyond
+//! what BKW can handle. Recovering the secret needs rates of about $1/k$ or less.
+use crate::{
+    bkw::bkw,
+    oracle::{LpnOracle, Sample},
+    random::lpn_thread_rng,
+};
+use m4ri_rust::friendly::BinVector;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::prelude::*;
+use rayon::prelude::*;
+
+/// LPN oracle with a separate noise rate for every column
+#[derive(Clone)]
+pub struct PerColumnNoiseLpnOracle {
+    /// The samples held by this oracle.
+    pub samples: Vec<Sample>,
+    /// The secret of this problem
+    pub secret: Sample,
+    /// The noise rate of every column
+    pub rates: Vec<f64>,
+    /// The size of this problem
+    k: usize,
+}
+
+impl PerColumnNoiseLpnOracle {
+    /// Create a new problem with a random secret and the noise rates `rates[i]` for column `i`
+    pub fn new(k: u32, rates: Vec<f64>) -> PerColumnNoiseLpnOracle {
+        let k = k as usize;
+        assert_eq!(rates.len(), k, "Need a noise rate for every column");
+        assert!(
+            rates.iter().all(|tau| (0.0..1.0).contains(tau)),
+            "0 <= tau < 1"
+        );
+        let secret = LpnOracle::new(k as u32, 0.0).secret;
+        PerColumnNoiseLpnOracle {
+            samples: vec![],
+            secret,
+            rates,
+            k,
+        }
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// Get new samples from the oracle
+    ///
+    /// These samples are stored in ``oracle.samples``
+    pub fn get_samples(&mut self, n: usize) {
+        let k = self.k;
+        let secret = self.secret.as_binvector(k);
+        let dists = self
+            .rates
+            .iter()
+            .map(|&tau| Bernoulli::new(tau).unwrap())
+            .collect::<Vec<_>>();
+
+        self.samples.reserve_exact(n);
+        self.samples
+            .par_extend((0..n).into_par_iter().map_init(lpn_thread_rng, |rng, _| {
+                let query =
+                    BinVector::from_bools(&(0..k).map(|_| rng.gen()).collect::<Vec<bool>>());
+                let noise = query
+                    .iter_set_bits(..)
+                    .fold(false, |e, i| e ^ dists[i].sample(rng));
+                let product = (&query * &secret) ^ noise;
+                Sample::from_binvector(&query, product)
+            }));
+    }
+}
+
+/// BKW that takes the noise rates of the columns into account
+///
+/// The columns are reordered such that the noisiest columns are eliminated by the
+/// reduction steps, while the `k - (a-1) * b` least noisy columns remain to be solved
+/// through majority.
+///
+/// Returns the secret bits of these least noisy columns, in the order of the columns.
+pub fn bkw_column_noise_adapted(oracle: PerColumnNoiseLpnOracle, a: u32, b: u32) -> BinVector {
+    let k = oracle.get_k();
+
+    // order[j] is the column we store at position j, least noisy first
+    let mut order = (0..k).collect::<Vec<usize>>();
+    order.sort_by(|&i, &j| oracle.rates[i].partial_cmp(&oracle.rates[j]).unwrap());
+    let permute = |v: &BinVector| {
+        BinVector::from_bools(
+            &order
+                .iter()
+                .map(|&i| v.get(i).unwrap())
+                .collect::<Vec<bool>>(),
+        )
+    };
+
+    let mean_rate = oracle.rates.iter().sum::<f64>() / k as f64;
+    let secret = Sample::from_binvector(&permute(&oracle.secret.as_binvector(k)), false);
+    let mut lpn = LpnOracle::new_with_secret(secret, k as u32, mean_rate);
+    lpn.samples = oracle
+        .samples
+        .into_par_iter()
+        .map(|q| Sample::from_binvector(&permute(&q.as_binvector(k)), q.get_product()))
+        .collect();
+
+    let solution = bkw(lpn, a, b);
+
+    let mut recovered = order[..solution.len()]
+        .iter()
+        .copied()
+        .zip(solution.iter())
+        .collect::<Vec<(usize, bool)>>();
+    recovered.sort_unstable();
+    BinVector::from_bools(
+        &recovered
+            .into_iter()
+            .map(|(_, bit)| bit)
+            .collect::<Vec<bool>>(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_column_noise_rates() {
+        // the noise rates vary from 0.05 to 0.3 across the columns
+        let rates = (0..32)
+            .map(|i| 0.05 + 0.25 * i as f64 / 31.0)
+            .collect::<Vec<f64>>();
+        let mut oracle = PerColumnNoiseLpnOracle::new(32, rates.clone());
+        oracle.get_samples(100_000);
+        let secret = oracle.secret.as_binvector(32);
+
+        // compare the noise of the samples with the rate predicted from their queries
+        let mut flips = 0;
+        let mut expected = 0.0;
+        for q in oracle.samples.iter() {
+            let query = q.as_binvector(32);
+            if &query * &secret != q.get_product() {
+                flips += 1;
+            }
+            let bias = query
+                .iter_set_bits(..)
+                .map(|i| 1.0 - 2.0 * rates[i])
+                .product::<f64>();
+            expected += (1.0 - bias) / 2.0;
+        }
+        let difference = (flips as f64 - expected).abs();
+        assert!(
+            difference < 1000.0,
+            "expected {} flipped samples, got {}",
+            expected,
+            flips
+        );
+
+        // a single noisy column at the highest rate
+        let mut rates = vec![0.0; 32];
+        rates[5] = 0.3;
+        let mut oracle = PerColumnNoiseLpnOracle::new(32, rates);
+        oracle.get_samples(10_000);
+        let secret = oracle.secret.as_binvector(32);
+        let (mut set, mut flips) = (0, 0);
+        for q in oracle.samples.iter() {
+            let query = q.as_binvector(32);
+            if query.get(5).unwrap() {
+                set += 1;
+            }
+            if &query * &secret != q.get_product() {
+                assert!(query.get(5).unwrap(), "noise without the noisy column");
+                flips += 1;
+            }
+        }
+        let rate = flips as f64 / set as f64;
+        assert!((rate - 0.3).abs() < 0.03, "expected rate 0.3, got {}", rate);
+    }
+
+    #[test]
+    fn test_column_noise_samples() {
+        // only the first column is noisy
+        let mut rates = vec![0.0; 16];
+        rates[0] = 0.5;
+        let mut oracle = PerColumnNoiseLpnOracle::new(16, rates);
+        oracle.get_samples(1000);
+        let secret = oracle.secret.as_binvector(16);
+        let mut flips = 0;
+        for q in oracle.samples.iter() {
+            let query = q.as_binvector(16);
+            if &query * &secret != q.get_product() {
+                assert!(query.get(0).unwrap(), "noise without the noisy column");
+                flips += 1;
+            }
+        }
+        assert!(
+            flips > 100,
+            "expected about 250 flipped samples, got {}",
+            flips
+        );
+    }
+
+    #[test]
+    fn test_bkw_column_noise_adapted() {
+        // the noise decreases with the column index, it has to stay below 1/k
+        // for BKW to work, see the module documentation
+        let rates = (0..32)
+            .map(|i| 0.01 * (31 - i) as f64 / 31.0)
+            .collect::<Vec<f64>>();
+        let mut oracle = PerColumnNoiseLpnOracle::new(32, rates);
+        oracle.get_samples(1_000_000);
+        let mut secret = oracle.secret.as_binvector(32);
+
+        let solution = bkw_column_noise_adapted(oracle, 4, 8);
+
+        // the least noisy columns are the last ones
+        let secret = BinVector::from(secret.split_off(24));
+        assert_eq!(solution, secret);
+    }
+}
@@ -0,0 +1,385 @@
+//! Keeps the samples of an oracle compressed in memory
+//!
+//! The samples are stored in blocks, which are compressed using a simple
+//! LZ4-style byte-oriented compression scheme. Most of the bytes of a sample are zero
+//! if $k$ is much smaller than `MAX_K`, which makes them compress very well.
+//! Blocks are decompressed when they are accessed.
+use crate::{
+    bkw::majority,
+    oracle::{query_bits_range, LpnOracle, Sample, StorageBlock, SAMPLE_LEN},
+};
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+use std::{cmp, collections::HashSet, convert::TryInto, mem, ops};
+
+/// Number of samples that are compressed together
+const SAMPLES_PER_BLOCK: usize = 4096;
+/// Number of bytes in a sample
+const SAMPLE_BYTES: usize = SAMPLE_LEN * mem::size_of::<StorageBlock>();
+/// Minimal length of a match
+const MIN_MATCH: usize = 4;
+
+/// LPN oracle of which the samples are stored in compressed form
+pub struct CompressedBkwOracle {
+    blocks: Vec<CompressedBlock>,
+    /// The oracle without its samples
+    oracle: LpnOracle,
+    compression_level: u8,
+}
+
+/// A compressed block of samples
+struct CompressedBlock {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl CompressedBkwOracle {
+    /// Compress the samples of `oracle`
+    ///
+    /// The `compression_level` (0 to 8) determines how hard we look for repetitions.
+    pub fn new(mut oracle: LpnOracle, compression_level: u8) -> Self {
+        let samples = mem::take(&mut oracle.samples);
+        let blocks = samples
+            .par_chunks(SAMPLES_PER_BLOCK)
+            .map(|chunk| CompressedBlock::compress(chunk, compression_level))
+            .collect();
+        CompressedBkwOracle {
+            blocks,
+            oracle,
+            compression_level,
+        }
+    }
+
+    /// The number of samples
+    pub fn len(&self) -> usize {
+        self.blocks.iter().map(|block| block.len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.oracle.get_k()
+    }
+
+    /// Get the secret of this problem
+    pub fn secret(&self) -> &Sample {
+        &self.oracle.secret
+    }
+
+    /// Number of bytes used to store the compressed samples
+    pub fn compressed_size(&self) -> usize {
+        self.blocks.iter().map(|block| block.data.len()).sum()
+    }
+
+    /// Number of bytes the samples would use without compression
+    pub fn uncompressed_size(&self) -> usize {
+        self.len() * mem::size_of::<Sample>()
+    }
+
+    /// Iterate over (decompressed copies of) the samples
+    pub fn iter(&self) -> impl Iterator<Item = Sample> + '_ {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.decompress().into_iter())
+    }
+
+    /// Apply `f` to all samples in parallel
+    ///
+    /// Every block is decompressed, modified and compressed again.
+    pub fn par_iter_mut<F>(&mut self, f: F)
+    where
+        F: Fn(&mut Sample) + Sync,
+    {
+        let level = self.compression_level;
+        self.blocks.par_iter_mut().for_each(|block| {
+            let mut samples = block.decompress();
+            samples.iter_mut().for_each(&f);
+            *block = CompressedBlock::compress(&samples, level);
+        });
+    }
+
+    /// Decompress all samples and obtain the regular oracle
+    pub fn into_oracle(self) -> LpnOracle {
+        let mut oracle = self.oracle;
+        oracle.samples = self
+            .blocks
+            .into_par_iter()
+            .flat_map_iter(|block| block.decompress().into_iter())
+            .collect();
+        oracle
+    }
+
+    /// Performs a single BKW reduction on the bits `(k - b * i)..(k - b * (i-1))`
+    fn bkw_reduce_step(&mut self, i: usize, b: usize) {
+        let k = self.get_k();
+        let bitrange: ops::Range<usize> = (k - (b * i))..(k - (b * (i - 1)));
+        let maxj = 2usize.pow(b as u32);
+        println!(
+            "BKW iteration, {} compressed samples left, expecting to remove {}",
+            self.len(),
+            maxj
+        );
+
+        // find the first sample of every partition
+        let mut firsts: Vec<Option<Sample>> = vec![None; maxj];
+        let mut pivots = HashSet::with_capacity(maxj);
+        for (idx, q) in self.iter().enumerate() {
+            let bucket = query_bits_range(&q, bitrange.clone()) as usize;
+            if firsts[bucket].is_none() {
+                firsts[bucket] = Some(q);
+                pivots.insert(idx);
+                if pivots.len() == maxj {
+                    break;
+                }
+            }
+        }
+
+        let offsets = self
+            .blocks
+            .iter()
+            .scan(0, |offset, block| {
+                *offset += block.len;
+                Some(*offset - block.len)
+            })
+            .collect::<Vec<usize>>();
+        let level = self.compression_level;
+        self.blocks
+            .par_iter_mut()
+            .zip(offsets)
+            .for_each(|(block, offset)| {
+                let samples = block
+                    .decompress()
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(idx, _)| !pivots.contains(&(offset + idx)))
+                    .map(|(_, mut q)| {
+                        let bucket = query_bits_range(&q, bitrange.clone()) as usize;
+                        if let Some(first) = &firsts[bucket] {
+                            q.xor_into(first);
+                        }
+                        q
+                    })
+                    .collect::<Vec<Sample>>();
+                *block = CompressedBlock::compress(&samples, level);
+            });
+    }
+}
+
+/// The full BKW algorithm on a compressed oracle
+///
+/// The reduction steps are done on the compressed samples, the samples are only
+/// fully decompressed for the majority step.
+pub fn bkw_compressed(mut oracle: CompressedBkwOracle, a: u32, b: u32) -> BinVector {
+    let k = oracle.get_k();
+    let a = a as usize;
+    let b = b as usize;
+    assert!(a * b <= k, "a*b <= k");
+
+    for i in 1..a {
+        oracle.bkw_reduce_step(i, b);
+    }
+
+    let mut oracle = oracle.into_oracle();
+    oracle.truncate(k - (a - 1) * b);
+    majority(oracle)
+}
+
+impl CompressedBlock {
+    fn compress(samples: &[Sample], level: u8) -> CompressedBlock {
+        let mut bytes = Vec::with_capacity(samples.len() * SAMPLE_BYTES);
+        for q in samples {
+            for block in q.get_sample() {
+                bytes.extend_from_slice(&block.to_le_bytes());
+            }
+        }
+        CompressedBlock {
+            data: compress(&bytes, level),
+            len: samples.len(),
+        }
+    }
+
+    fn decompress(&self) -> Vec<Sample> {
+        let bytes = decompress(&self.data, self.len * SAMPLE_BYTES);
+        debug_assert_eq!(bytes.len(), self.len * SAMPLE_BYTES);
+        bytes
+            .chunks_exact(SAMPLE_BYTES)
+            .map(|chunk| {
+                let mut q = Sample::new();
+                q.get_sample_mut()
+                    .iter_mut()
+                    .zip(chunk.chunks_exact(mem::size_of::<StorageBlock>()))
+                    .for_each(|(block, bytes)| {
+                        *block = StorageBlock::from_le_bytes(bytes.try_into().unwrap())
+                    });
+                q
+            })
+            .collect()
+    }
+}
+
+/// Write the remainder of a length that didn't fit in a token
+fn write_length(output: &mut Vec<u8>, mut len: usize) {
+    while len >= 255 {
+        output.push(255);
+        len -= 255;
+    }
+    output.push(len as u8);
+}
+
+/// Read the remainder of a length that didn't fit in a token
+fn read_length(input: &[u8], pos: &mut usize) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = input[*pos];
+        *pos += 1;
+        len += byte as usize;
+        if byte != 255 {
+            return len;
+        }
+    }
+}
+
+/// Write a sequence of literals, optionally followed by a match `(offset, length)`
+fn write_sequence(output: &mut Vec<u8>, literals: &[u8], repeat: Option<(usize, usize)>) {
+    let match_len = repeat.map_or(0, |(_, len)| len - MIN_MATCH);
+    let token = (cmp::min(literals.len(), 15) << 4) | cmp::min(match_len, 15);
+    output.push(token as u8);
+    if literals.len() >= 15 {
+        write_length(output, literals.len() - 15);
+    }
+    output.extend_from_slice(literals);
+    if let Some((offset, _)) = repeat {
+        output.extend_from_slice(&(offset as u16).to_le_bytes());
+        if match_len >= 15 {
+            write_length(output, match_len - 15);
+        }
+    }
+}
+
+/// Compress `input` into a sequence of literals and back-references
+fn compress(input: &[u8], level: u8) -> Vec<u8> {
+    let hash_bits = 8 + cmp::min(level, 8) as u32;
+    let mut table = vec![None; 1 << hash_bits];
+    let hash = |pos: usize| {
+        let word = u32::from_le_bytes(input[pos..pos + MIN_MATCH].try_into().unwrap());
+        (word.wrapping_mul(2_654_435_761) >> (32 - hash_bits)) as usize
+    };
+
+    let mut output = Vec::with_capacity(input.len() / 2);
+    let mut anchor = 0;
+    let mut pos = 0;
+    while pos + MIN_MATCH <= input.len() {
+        let h = hash(pos);
+        let candidate = table[h].replace(pos);
+        if let Some(candidate) = candidate {
+            if pos - candidate <= u16::MAX as usize
+                && input[candidate..candidate + MIN_MATCH] == input[pos..pos + MIN_MATCH]
+            {
+                let mut len = MIN_MATCH;
+                while pos + len < input.len() && input[candidate + len] == input[pos + len] {
+                    len += 1;
+                }
+                write_sequence(
+                    &mut output,
+                    &input[anchor..pos],
+                    Some((pos - candidate, len)),
+                );
+                pos += len;
+                anchor = pos;
+                continue;
+            }
+        }
+        pos += 1;
+    }
+    write_sequence(&mut output, &input[anchor..], None);
+    output
+}
+
+/// Decompress the output of [`compress`]
+fn decompress(input: &[u8], expected_len: usize) -> Vec<u8> {
+    let mut output = Vec::with_capacity(expected_len);
+    let mut pos = 0;
+    loop {
+        let token = input[pos];
+        pos += 1;
+        let mut literals = (token >> 4) as usize;
+        if literals == 15 {
+            literals += read_length(input, &mut pos);
+        }
+        output.extend_from_slice(&input[pos..pos + literals]);
+        pos += literals;
+        if pos == input.len() {
+            return output;
+        }
+
+        let offset = u16::from_le_bytes([input[pos], input[pos + 1]]) as usize;
+        pos += 2;
+        let mut match_len = (token & 15) as usize;
+        if match_len == 15 {
+            match_len += read_length(input, &mut pos);
+        }
+        // matches may overlap with the bytes they produce, so copy byte-by-byte
+        let start = output.len() - offset;
+        for idx in start..(start + match_len + MIN_MATCH) {
+            let byte = output[idx];
+            output.push(byte);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compress_round_trip() {
+        let inputs: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![1, 2, 3],
+            vec![0; 100_000],
+            (0..100_000).map(|i| (i % 7) as u8).collect(),
+            (0..100_000).map(|_| rand::random()).collect(),
+        ];
+        for input in inputs {
+            for level in 0..=8 {
+                let compressed = compress(&input, level);
+                assert_eq!(decompress(&compressed, input.len()), input);
+            }
+        }
+    }
+
+    #[test]
+    fn lossless() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(10_000);
+        let samples = oracle.samples.clone();
+        let compressed = CompressedBkwOracle::new(oracle, 4);
+        assert_eq!(compressed.len(), samples.len());
+        assert_eq!(compressed.iter().collect::<Vec<_>>(), samples);
+        assert_eq!(compressed.into_oracle().samples, samples);
+    }
+
+    #[test]
+    fn memory_savings() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(100_000);
+        let compressed = CompressedBkwOracle::new(oracle, 4);
+        let ratio = compressed.compressed_size() as f64 / compressed.uncompressed_size() as f64;
+        assert!(ratio < 0.7, "only compressed to {:.2}", ratio);
+    }
+
+    #[test]
+    fn test_bkw_compressed() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 32.0);
+        oracle.get_samples(400_000);
+        let mut secret = oracle.secret.as_binvector(32);
+
+        let solution = crate::bkw::bkw(oracle.clone(), 4, 8);
+        let compressed_solution = bkw_compressed(CompressedBkwOracle::new(oracle, 4), 4, 8);
+        secret.truncate(solution.len());
+        assert_eq!(solution, secret);
+        assert_eq!(compressed_solution, solution);
+    }
+}
@@ -2,15 +2,17 @@
 use std::sync::{Arc, Mutex};
 
 use crate::{
-    oracle::{LpnOracle, Sample},
+    oracle::{query_bits_range, LpnOracle, Sample},
     random::lpn_thread_rng,
 };
+use fnv::FnvHashMap;
 use indicatif::ProgressBar;
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
 use rayon::prelude::*;
 
 use crate::codes::BinaryCode;
+use itertools::Itertools;
 use rand::prelude::*;
 
 /// Sparse secret reduction
@@ -136,6 +138,94 @@ pub fn unsparse_secret(oracle: &LpnOracle, secret: &BinVector) -> BinVector {
     (secret + c_prime) * m.transposed().inverted()
 }
 
+/// Largest secret weight for which [`low_weight_solve`] enumerates the candidates
+const MAX_SECRET_WEIGHT: usize = 10;
+
+/// Recover a secret of weight at most `weight_bound` by trying all candidates
+///
+/// Every secret of weight at most $w$ is scored by the number of samples it is
+/// inconsistent with, and the one with the fewest is returned. This takes
+/// $\sum_{i \le w} \binom{k}{i}$ candidates, which after [`sparse_secret_reduce`]
+/// is much less than BKW if $k\tau$ is small. The samples are stored by column, so
+/// scoring a candidate only adds $w$ bit vectors of length $n$.
+///
+/// Panics if `weight_bound` is larger than `MAX_SECRET_WEIGHT`.
+pub fn low_weight_solve(oracle: LpnOracle, weight_bound: usize) -> BinVector {
+    assert!(
+        weight_bound <= MAX_SECRET_WEIGHT,
+        "Enumerating the secrets of weight {} is impractical, at most {} is supported",
+        weight_bound,
+        MAX_SECRET_WEIGHT
+    );
+    let k = oracle.get_k();
+    let weight_bound = weight_bound.min(k);
+    let queries: Vec<BinVector> = oracle
+        .samples
+        .par_iter()
+        .map(|q| q.as_binvector(k))
+        .collect();
+    let columns: Vec<BinVector> = (0..k)
+        .into_par_iter()
+        .map(|i| BinVector::from_bools(&queries.iter().map(|q| q[i]).collect::<Vec<bool>>()))
+        .collect();
+    let products = BinVector::from_bools(
+        &oracle
+            .samples
+            .iter()
+            .map(|q| q.get_product())
+            .collect::<Vec<bool>>(),
+    );
+
+    let (errors, support) = (0..=weight_bound)
+        .flat_map(|weight| (0..k).combinations(weight))
+        .par_bridge()
+        .map(|support| {
+            let mut residual = products.clone();
+            for &i in support.iter() {
+                residual += &columns[i];
+            }
+            (residual.count_ones(), support)
+        })
+        .min_by_key(|(errors, support)| (*errors, support.clone()))
+        .unwrap();
+    log::debug!(
+        "Best secret of weight {} is inconsistent with {} of {} samples",
+        support.len(),
+        errors,
+        oracle.samples.len()
+    );
+    BinVector::from_bools(&(0..k).map(|i| support.contains(&i)).collect::<Vec<bool>>())
+}
+
+/// Codes with more redundancy take too long to compute the covering radius of
+const MAX_CHECKED_REDUNDANCY: usize = 24;
+
+/// Warn if the samples are unlikely to suffice after reducing with `code`
+///
+/// Decoding adds $\langle e, s \rangle$ to the noise, with $e$ of weight at most the
+/// covering radius $\rho$, so the bias after the reduction is at least
+/// $\delta \cdot \delta_s^\rho$. Distinguishing this bias needs about $\delta^{-2}$ samples.
+/// Returns the worst-case bias, or `None` if the check was skipped for a large code.
+pub fn check_covering_radius(oracle: &LpnOracle, code: &dyn BinaryCode) -> Option<f64> {
+    if code.length() - code.dimension() > MAX_CHECKED_REDUNDANCY {
+        log::debug!("Not checking the covering radius of {}", code.name());
+        return None;
+    }
+    let radius = code.covering_radius();
+    let bias = oracle.delta * oracle.delta_s.powi(radius as i32);
+    if (oracle.samples.len() as f64) < bias.powi(-2) {
+        log::warn!(
+            "The reduction with {} (covering radius {}) leaves a bias of at least {}, \
+             {} samples are likely not enough",
+            code.name(),
+            radius,
+            bias,
+            oracle.samples.len()
+        );
+    }
+    Some(bias)
+}
+
 /// Reduce using the covering codes attack (Guo, Johansson, Lohndal; 2014)
 ///
 /// $k' = dim(G)$
@@ -152,6 +242,7 @@ pub fn code_reduce<T: BinaryCode + Sync>(oracle: &mut LpnOracle, code: &T) {
         code.length(),
         "The length of the code does not match the problem size!"
     );
+    check_covering_radius(oracle, code);
 
     log::info!("Decoding samples");
     let progress = ProgressBar::new(oracle.samples.len() as u64);
@@ -177,9 +268,74 @@ pub fn code_reduce<T: BinaryCode + Sync>(oracle: &mut LpnOracle, code: &T) {
 
     unsafe { oracle.set_k(code.dimension()) };
 
-    //log::trace!("Computing new delta");
-    //oracle.delta *= code.bias(oracle.delta_s);
-    //log::debug!("New delta = {}", oracle.delta);
+    log::trace!("Computing new delta");
+    oracle.delta *= code.bias(oracle.delta_s);
+    log::debug!("New delta = {}", oracle.delta);
+}
+
+/// Partition the samples by the codeword nearest to their top `code.length()` bits
+pub(crate) fn partition_by_coset(
+    oracle: &mut LpnOracle,
+    code: &dyn BinaryCode,
+) -> Vec<Vec<Sample>> {
+    let k = oracle.get_k();
+    let n_c = code.length();
+    assert!(n_c < k, "The code should be shorter than the problem");
+    assert!(n_c <= 64, "The code should have length at most 64");
+    let block = (k - n_c)..k;
+
+    let mut classes: FnvHashMap<u64, Vec<Sample>> = FnvHashMap::default();
+    for query in oracle.samples.drain(..) {
+        let bits = query_bits_range(&query, block.clone());
+        let v = BinVector::from_bools(
+            &(0..n_c)
+                .map(|i| (bits >> i) & 1 == 1)
+                .collect::<Vec<bool>>(),
+        );
+        let codeword = code
+            .decode_to_code(&v)
+            .expect("Decoding the block should succeed");
+        classes
+            .entry(codeword.get_storage()[0] as u64)
+            .or_default()
+            .push(query);
+    }
+    classes.into_values().collect()
+}
+
+/// Coded-BKW step: reduce the top `code.length()` bits by coset
+///
+/// The top block of every query is decoded to its nearest codeword $c$, so it
+/// becomes $c + e$ with $e$ of weight at most the covering radius. Disjoint pairs of
+/// samples with the same codeword are added, which leaves $e_1 + e_2$ in the block.
+/// The block is then dropped, which adds $\langle e_1 + e_2, s_B \rangle$ to the noise.
+///
+/// $k' = k - n_c$
+/// $n' \approx n/2$
+/// $d' = d^2 \cdot bc^2$, with $bc$ the bias of the code for $d_s$
+pub fn bkw_coset_reduce(oracle: &mut LpnOracle, code: &dyn BinaryCode) {
+    let k = oracle.get_k();
+    let n_c = code.length();
+
+    log::info!("Pairing samples by coset of {}", code.name());
+    let mut reduced = Vec::with_capacity(oracle.samples.len() / 2);
+    for class in partition_by_coset(oracle, code) {
+        for pair in class.chunks_exact(2) {
+            let mut sum = pair[0].clone();
+            sum.xor_into(&pair[1]);
+            reduced.push(sum);
+        }
+    }
+    oracle.samples = reduced;
+    oracle.truncate(k - n_c);
+
+    oracle.delta = oracle.delta.powi(2) * code.bias(oracle.delta_s).powi(2);
+    log::debug!(
+        "Coset reduction left {} samples with k = {}, new delta = {}",
+        oracle.samples.len(),
+        oracle.get_k(),
+        oracle.delta
+    );
 }
 
 #[cfg(test)]
@@ -205,7 +361,7 @@ mod test {
     #[test]
     fn test_reduction() {
         use crate::codes::HammingCode15_11;
-        use crate::lf1::fwht_solve;
+        use crate::fwht::fwht_solve;
 
         // setup
         let mut oracle: LpnOracle = LpnOracle::new(15, 0.0 / 8.0);
@@ -225,4 +381,77 @@ mod test {
         let fwht_solution = fwht_solve(oracle.clone());
         assert_eq!(secret, fwht_solution, "Found wrong solution");
     }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn test_check_covering_radius() {
+        use crate::codes::HammingCode15_11;
+
+        let mut oracle: LpnOracle = LpnOracle::new(15, 1.0 / 8.0);
+        oracle.get_samples(100);
+        sparse_secret_reduce(&mut oracle);
+        // the Hamming code has covering radius 1
+        let bias = check_covering_radius(&oracle, &HammingCode15_11).unwrap();
+        assert!((bias - 0.75 * 0.75).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn test_coset_reduction() {
+        use crate::codes::HammingCode7_4;
+
+        let k = 32;
+        let mut oracle: LpnOracle = LpnOracle::new(k, 0.0);
+        // the block of the secret that gets reduced has weight one
+        let secret = BinVector::from_function(k as usize, |i| (i < 25 && i % 3 == 0) || i == 28);
+        oracle.secret = Sample::from_binvector(&secret, false);
+        oracle.get_samples(200_000);
+
+        bkw_coset_reduce(&mut oracle, &HammingCode7_4);
+        assert_eq!(oracle.get_k(), 25);
+        assert!(oracle.samples.len() > 99_000);
+        let new_secret = oracle.secret.as_binvector(25);
+        assert_eq!(new_secret, BinVector::from_function(25, |i| i % 3 == 0));
+
+        // The Hamming code is perfect with covering radius 1: the error in the block is
+        // zero or one of the 7 unit vectors, each with probability 1/8. The error hits the
+        // single secret bit with probability 1/8, so it has bias 3/4. The pair adds two errors.
+        let predicted_bias = (3.0f64 / 4.0).powi(2);
+        let wrong = oracle
+            .samples
+            .iter()
+            .filter(|q| &q.as_binvector(25) * &new_secret != q.get_product())
+            .count();
+        let bias = 1.0 - 2.0 * wrong as f64 / oracle.samples.len() as f64;
+        assert!(
+            (bias - predicted_bias).abs() < 0.02,
+            "bias {} should be {}",
+            bias,
+            predicted_bias
+        );
+        // the noise introduced by the block is less than for a uniform secret block
+        assert!(bias > oracle.delta);
+    }
+
+    #[test]
+    fn test_low_weight_solve() {
+        let secret = BinVector::from_bools(
+            &(0..32)
+                .map(|i| i == 3 || i == 17 || i == 30)
+                .collect::<Vec<bool>>(),
+        );
+        let mut oracle: LpnOracle = LpnOracle::from_secret(32, 1.0 / 8.0, secret.clone());
+        oracle.get_samples(2000);
+        assert_eq!(low_weight_solve(oracle.clone(), 4), secret);
+        // a bound below the weight of the secret can't find it
+        assert_ne!(low_weight_solve(oracle, 2), secret);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_low_weight_solve_bound() {
+        let mut oracle: LpnOracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(10);
+        low_weight_solve(oracle, MAX_SECRET_WEIGHT + 1);
+    }
 }
@@ -0,0 +1,92 @@
+//! Test whether samples follow the LPN distribution
+//!
+//! Without the secret the products of LPN samples look uniform, so the test looks for
+//! a secret that correlates with the samples. It tries all $2^k$ secrets at once with
+//! a Walsh-Hadamard transform, which limits it to small $k$.
+use crate::{
+    fwht::fwht,
+    oracle::{query_bits_range, Sample},
+};
+
+/// Largest `k` for which the transform is computed
+const MAX_K: usize = 25;
+
+/// Check if `samples` of size `k` have a secret, i.e. if they are not uniform
+///
+/// For every secret $s$, the correlation $\sum_i (-1)^{b_i + \langle a_i, s \rangle}$ is
+/// a sum of $n$ independent signs under the null hypothesis that the products are
+/// uniform. By the Hoeffding bound and a union bound over all $2^k$ secrets, the largest
+/// correlation $M$ has probability at most $2^{k+1} e^{-M^2 / 2n}$. The null hypothesis
+/// is rejected, and `true` returned, if this is below `significance`.
+///
+/// For LPN samples the correlation of the secret is about $n\delta$, which the test
+/// can only tell apart from noise if $n \delta^2 \gg 2k \ln 2$. A warning is logged if
+/// there are too few samples for the expected bias `delta`.
+pub fn is_lpn_distribution(samples: &[Sample], k: usize, delta: f64, significance: f64) -> bool {
+    assert!(
+        0 < k && k <= MAX_K,
+        "The test takes 2^k memory, need 0 < k <= {}",
+        MAX_K
+    );
+    assert!(
+        0.0 < significance && significance < 1.0,
+        "0 < significance < 1"
+    );
+    let n = samples.len() as f64;
+    let needed = 2.0 * (k as f64 + 1.0 - significance.log2()) * 2f64.ln() / delta.powi(2);
+    if n < needed {
+        log::warn!(
+            "Only {} samples, about {:.0} are needed to detect bias {}",
+            samples.len(),
+            needed,
+            delta
+        );
+    }
+
+    let mut correlations = vec![0i64; 1 << k];
+    for q in samples {
+        let idx = query_bits_range(q, 0..k) as usize;
+        correlations[idx] += if q.get_product() { -1 } else { 1 };
+    }
+    fwht(&mut correlations, k as u32);
+    let max = correlations.iter().map(|c| c.abs()).max().unwrap() as f64;
+
+    let log_p_value = (k + 1) as f64 * 2f64.ln() - max.powi(2) / (2.0 * n);
+    log::debug!(
+        "Largest correlation {} of {} samples, p <= {:e}",
+        max,
+        samples.len(),
+        log_p_value.exp()
+    );
+    log_p_value < significance.ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::oracle::LpnOracle;
+    use m4ri_rust::friendly::BinVector;
+
+    #[test]
+    fn lpn_samples() {
+        let mut oracle = LpnOracle::new(12, 1.0 / 8.0);
+        oracle.get_samples(2000);
+        assert!(is_lpn_distribution(&oracle.samples, 12, 0.75, 0.01));
+    }
+
+    #[test]
+    fn uniform_samples() {
+        let samples: Vec<Sample> = (0..2000)
+            .map(|_| Sample::from_binvector(&BinVector::random(12), rand::random()))
+            .collect();
+        assert!(!is_lpn_distribution(&samples, 12, 0.75, 0.01));
+    }
+
+    #[test]
+    fn too_noisy() {
+        // with bias 1/32 the secret doesn't stand out from 2000 samples
+        let mut oracle = LpnOracle::new(12, 0.5 - 1.0 / 64.0);
+        oracle.get_samples(2000);
+        assert!(!is_lpn_distribution(&oracle.samples, 12, 1.0 / 32.0, 0.01));
+    }
+}
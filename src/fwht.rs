@@ -0,0 +1,185 @@
+//! Solving LPN through the Fast Walsh-Hadamard Transform
+use crate::oracle::LpnOracle;
+use m4ri_rust::friendly::BinVector;
+use packed_simd_2::i64x4;
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Solving using the Fast Walsh-Hamadard Transform
+///
+/// Every sample adds $(-1)^c$ for its product $c$ at the index of its query, the
+/// transform of this vector at $s$ is $\sum_i (-1)^{c_i + \langle a_i, s \rangle}$.
+/// The guess is the index with the largest value. Unlike [`crate::bkw::majority`]
+/// this uses all samples, at the cost of $O(k 2^k)$ time and $2^k$ counters, which
+/// is fine up to about $k = 20$.
+///
+/// This section of code is based on the implementation of
+/// LPN by Tramer (Bogos, Tramer, Vaudenay 2015)
+pub fn fwht_solve(oracle: LpnOracle) -> BinVector {
+    let k = oracle.get_k();
+    let majority_counter = transformed_counters(oracle);
+    let guess = (0..majority_counter.len())
+        .max_by_key(|x| majority_counter[*x])
+        .unwrap();
+    index_to_binvector(guess, k)
+}
+
+/// Solving using the Fast Walsh-Hamadard Transform, for samples of unknown sign of bias
+///
+/// Like [`fwht_solve`], but the guess is the index with the largest absolute value,
+/// so it also finds the secret if the bias of the samples is negative.
+pub fn fwht_solve_abs(oracle: LpnOracle) -> BinVector {
+    let k = oracle.get_k();
+    let majority_counter = transformed_counters(oracle);
+    let guess = (0..majority_counter.len())
+        .max_by_key(|x| majority_counter[*x].abs())
+        .unwrap();
+    index_to_binvector(guess, k)
+}
+
+/// Count the samples per query and transform the counters
+fn transformed_counters(oracle: LpnOracle) -> Vec<i64> {
+    log::info!("FWHT solving for k' = {}", oracle.get_k());
+    assert!(oracle.get_k() < crate::util::num_bits::<usize>());
+
+    let k = oracle.get_k() as u32;
+    let mut majority_counter = count_samples(oracle);
+
+    log::debug!("FWHT");
+    parfwht(&mut majority_counter[..], k);
+    majority_counter
+}
+
+fn index_to_binvector(index: usize, k: usize) -> BinVector {
+    let mut result = BinVector::with_capacity(k);
+    for i in 0..k {
+        result.push(index >> i & 1 == 1);
+    }
+    result
+}
+
+#[cfg(target_arch = "x86_64")]
+fn count_samples(oracle: LpnOracle) -> Vec<i64> {
+    let k = oracle.get_k() as u32;
+
+    let mut sum_vector = Vec::new();
+    sum_vector.resize_with(2usize.pow(k), || AtomicI64::new(0));
+
+    oracle
+        .samples
+        .into_par_iter()
+        .for_each_with(&sum_vector[..], |counters, sample| {
+            let idx = sample.get_block(0) as usize;
+            counters[idx].fetch_add(if sample.get_product() { -1 } else { 1 }, Ordering::Relaxed);
+        });
+    sum_vector
+        .into_iter()
+        .map(|i| i.into_inner())
+        .collect::<Vec<_>>()
+}
+
+/// Fast Walsh Hamadard Transform
+///
+/// Adapted from Bogos, Tramer, Vaudenay,
+/// who used http://www.musicdsp.org/showone.php?id=18
+///
+/// Data: data to transform the transform over (whoooah)
+/// bits: log2(size), the length of the data
+#[inline]
+#[allow(clippy::many_single_char_names)]
+pub fn fwht(data: &mut [i64], bits: u32) {
+    let n = bits;
+    for i in 0..n {
+        let mut j = 0;
+        while j < (1 << n) {
+            let mut k = 0;
+            while k < (1 << i) {
+                let a = j + k;
+                let b = j + k + (1 << i);
+
+                let tmp = data[a];
+                data[a] += data[b];
+                data[b] = tmp - data[b];
+
+                k += 1;
+            }
+            j += 1 << (i + 1);
+        }
+    }
+}
+
+#[inline]
+pub fn parfwht(data: &mut [i64], bits: u32) {
+    let n = 1 << bits;
+    assert!(data.len() == n);
+
+    let mut stride = n / 2;
+    // cycle through stages with different butterfly strides
+    while stride >= 1 {
+        // cycle through subvectors for (2 * stride) elements
+        if stride >= 4 {
+            data.par_chunks_mut(2 * stride).for_each(|data| {
+                let (left, right) = data.split_at_mut(stride);
+                (0..stride).step_by(4).into_iter().for_each(|j| unsafe {
+                    let l = i64x4::from_slice_unaligned_unchecked(&left[j..j + 4]);
+                    let r = i64x4::from_slice_unaligned_unchecked(&right[j..j + 4]);
+                    (l + r).write_to_slice_unaligned_unchecked(&mut left[j..j + 4]);
+                    (l - r).write_to_slice_unaligned_unchecked(&mut right[j..j + 4]);
+                });
+            });
+        } else {
+            data.par_chunks_mut(2 * stride).for_each(|data| {
+                let (left, right) = data.split_at_mut(stride);
+                (0..stride).into_iter().for_each(|j| {
+                    let l = left[j];
+                    let r = right[j];
+                    left[j] = l + r;
+                    right[j] = l - r;
+                });
+            });
+        }
+        stride >>= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_fwht() {
+        let bits = 16;
+        let mut majority_counter = vec![0; 2usize.pow(bits)];
+        let rng = &mut rand::thread_rng();
+        majority_counter.iter_mut().for_each(|el| {
+            *el = (rng).gen::<i64>() % 2i64.pow(16);
+        });
+
+        let mut majority_1 = majority_counter.clone();
+        let mut majority_2 = majority_counter;
+        fwht(&mut majority_1, bits);
+        parfwht(&mut majority_2, bits);
+
+        assert_eq!(majority_1, majority_2, "Should be the same");
+    }
+
+    #[test]
+    fn solve_with_all_samples() {
+        let k = 12;
+        let mut oracle = LpnOracle::new(k, 1.0 / 8.0);
+        // too few samples of weight one for the majority strategy
+        oracle.get_samples(5000);
+        let secret = oracle.secret.as_binvector(k as usize);
+        assert_eq!(fwht_solve(oracle.clone()), secret);
+        assert_eq!(fwht_solve_abs(oracle.clone()), secret);
+
+        // a negative bias gives the largest absolute value at the secret as well
+        oracle
+            .samples
+            .iter_mut()
+            .for_each(|q| q.set_product(!q.get_product()));
+        assert_ne!(fwht_solve(oracle.clone()), secret);
+        assert_eq!(fwht_solve_abs(oracle), secret);
+    }
+}
@@ -1,25 +1,80 @@
 //! Defines the Pooled Gauss solving algorithms by Esser, Kübler and May
 use crate::{
-    oracle::{LpnOracle, StorageBlock},
+    oracle::{LpnOracle, Sample, StorageBlock},
     random::{lpn_thread_rng, ThreadRng},
 };
 use m4ri_rust::friendly::solve_left;
 use m4ri_rust::friendly::BinMatrix;
 use m4ri_rust::friendly::BinVector;
-use rand::prelude::SliceRandom;
+use rand::prelude::{Rng, SliceRandom};
 use rayon::prelude::*;
 
 use std::{
     cell::RefCell,
+    cmp::Reverse,
+    error, fmt,
     sync::{Arc, Mutex},
 };
 
+/// Number of candidates Pooled Gauss tests per parallel round
+const GAUSS_ROUND_SIZE: usize = 100;
+
+/// Pooled Gauss gives up after this many times the expected number of candidates
+///
+/// A batch is error-free with probability $\beta / \alpha$, so this misses an
+/// error-free batch with probability about $e^{-20}$.
+const GAUSS_CANDIDATE_FACTOR: f64 = 20.0;
+
+/// Reasons why [`pooled_gauss_solve`] did not find a secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GaussError {
+    /// The queries of all samples only span a space of dimension `rank_achieved`
+    InsufficientSamples {
+        rank_achieved: usize,
+        rank_needed: usize,
+    },
+    /// None of the candidate secrets passed the test against the pool
+    NoSolution,
+}
+
+impl fmt::Display for GaussError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GaussError::InsufficientSamples {
+                rank_achieved,
+                rank_needed,
+            } => write!(
+                f,
+                "the samples have rank {}, need rank {}",
+                rank_achieved, rank_needed
+            ),
+            GaussError::NoSolution => write!(f, "no candidate secret passed the test"),
+        }
+    }
+}
+
+impl error::Error for GaussError {}
+
 /// Solves an LPN problem using Pooled Gauss
+///
+/// Repeatedly solves $As = b$ for $k$ random samples with independent queries, and
+/// tests every candidate against a pool of other samples. If a batch of samples is
+/// rank deficient, the dependent samples are replaced by other random samples.
+/// Returns [`GaussError::InsufficientSamples`] if the queries of all samples do not
+/// have rank $k$, in which case no batch is independent, and
+/// [`GaussError::NoSolution`] if none of $20 \alpha / \beta$ candidates passes the test.
 #[allow(clippy::many_single_char_names, clippy::needless_pass_by_value)]
-pub fn pooled_gauss_solve(oracle: LpnOracle) -> BinVector {
+pub fn pooled_gauss_solve(oracle: LpnOracle) -> Result<BinVector, GaussError> {
     let mut rng = lpn_thread_rng();
 
     let k = oracle.get_k();
+    let rank = query_rank(&oracle.samples, k);
+    if rank < k {
+        return Err(GaussError::InsufficientSamples {
+            rank_achieved: rank,
+            rank_needed: k,
+        });
+    }
     let alpha = 0.5f64.powi(k as i32);
     let tau = (1.0 - oracle.delta) / 2.0;
     let beta = ((1f64 - tau) / 2f64).powi(k as i32);
@@ -28,6 +83,8 @@ pub fn pooled_gauss_solve(oracle: LpnOracle) -> BinVector {
         .floor();
     let c = (tau * m + (3.0 * (0.5 - tau) * (1.0 / alpha).ln() * m).sqrt().floor()) as u32;
     let m = m as usize;
+    let max_rounds =
+        (GAUSS_CANDIDATE_FACTOR * alpha / beta / GAUSS_ROUND_SIZE as f64).ceil() as usize;
 
     log::info!(
         "Attempting Pooled Gauss solving method, k={}, tau={}",
@@ -35,6 +92,10 @@ pub fn pooled_gauss_solve(oracle: LpnOracle) -> BinVector {
         tau
     );
     log::trace!("Target secret weight <= {}", c);
+    log::trace!(
+        "Testing at most {} candidates",
+        max_rounds * GAUSS_ROUND_SIZE
+    );
     log::trace!("Building (Am, b) with length {}", m);
     let (am, bm) = sample_matrix(m, &oracle, &mut rng);
     debug_assert_eq!(am.ncols(), k);
@@ -64,14 +125,11 @@ pub fn pooled_gauss_solve(oracle: LpnOracle) -> BinVector {
     log::debug!("Starting random sampling of invertible (A, b)");
 
     let s_prime_finder = move |(sender, rng): &mut (Arc<Mutex<Option<BinMatrix>>>, _), _| {
-        for _ in 0..10000 {
-            // find k-rank matrix
+        for _ in 0..GAUSS_ROUND_SIZE {
+            // find k-rank matrix, the samples have rank k so this terminates
             let (a, mut b) = loop {
-                let (a_try, b_try) = sample_matrix(k as usize, &oracle, rng);
-                // TODO is this check necessary?
-                // TODO avoid allocate?
-                if a_try.clone().echelonize() == k as usize {
-                    break (a_try, b_try);
+                if let Ok(samples) = independent_samples(&oracle, 4 * k, rng) {
+                    break samples;
                 }
             };
             // A*s = b
@@ -98,13 +156,169 @@ pub fn pooled_gauss_solve(oracle: LpnOracle) -> BinVector {
     let sender_parent = Arc::new(Mutex::new(None));
     let sender = sender_parent.clone();
 
-    rayon::iter::repeat(())
+    rayon::iter::repeatn((), max_rounds)
         .try_for_each_init(|| (sender.clone(), lpn_thread_rng()), s_prime_finder);
 
     let sender = sender_parent.lock().unwrap();
-    let s_prime = sender.as_ref().unwrap();
+    let s_prime = sender.as_ref().ok_or(GaussError::NoSolution)?;
+
+    Ok(s_prime.as_vector())
+}
+
+/// Add `v` to the `basis` in echelon form if it is independent of it
+///
+/// Every entry holds a vector and the position of its first one, which is zero in the
+/// vectors added after it.
+fn insert_independent(basis: &mut Vec<(usize, BinVector)>, mut v: BinVector) -> bool {
+    for (pivot, row) in basis.iter() {
+        if v[*pivot] {
+            v = &v + row;
+        }
+    }
+    match v.iter().position(|bit| bit) {
+        Some(pivot) => {
+            basis.push((pivot, v));
+            true
+        }
+        None => false,
+    }
+}
+
+/// The rank of the queries of `samples`, scanning them until rank `k` is found
+fn query_rank(samples: &[Sample], k: usize) -> usize {
+    let mut basis = Vec::with_capacity(k);
+    for q in samples {
+        insert_independent(&mut basis, q.as_binvector(k));
+        if basis.len() == k {
+            break;
+        }
+    }
+    basis.len()
+}
+
+/// Draw random samples until $k$ of them have independent queries, as ``(A, b)``
+///
+/// Dependent samples are skipped. Returns the rank reached if `max_draws` samples
+/// were not enough.
+fn independent_samples(
+    oracle: &LpnOracle,
+    max_draws: usize,
+    rng: &mut ThreadRng,
+) -> Result<(BinMatrix, BinMatrix), usize> {
+    let k = oracle.get_k();
+    let mut basis = Vec::with_capacity(k);
+    let mut rows = Vec::with_capacity(k);
+    let mut products = BinVector::with_capacity(k);
+    for _ in 0..max_draws {
+        let q = &oracle.samples[rng.gen_range(0..oracle.samples.len())];
+        let query = q.as_binvector(k);
+        if insert_independent(&mut basis, query.clone()) {
+            rows.push(query);
+            products.push(q.get_product());
+            if rows.len() == k {
+                return Ok((BinMatrix::new(rows), products.as_column_matrix()));
+            }
+        }
+    }
+    Err(rows.len())
+}
+
+/// Transform the problem such that $k$ of its samples are the unit vectors
+///
+/// Picks $k$ random samples with an invertible query matrix $A$ and replaces every
+/// query $a$ by $a A^{-1}$. As $\langle a A^{-1}, As \rangle = \langle a, s \rangle$,
+/// the products are unchanged and the secret becomes $s' = As$. The picked samples are
+/// now the unit vectors, so their products are noisy copies of the bits of $s'$.
+/// The reduced oracle can be passed to any solver, the returned $A^{-1}$ maps its
+/// solution back with $s = A^{-1} s'$.
+/// Returns [`GaussError::InsufficientSamples`] if the queries of the samples do not
+/// have rank $k$, in which case no such $A$ exists.
+///
+/// $k' = k$
+/// $n' = n$
+/// $d' = d$
+pub fn gauss_reduce(mut oracle: LpnOracle) -> Result<(LpnOracle, BinMatrix), GaussError> {
+    let k = oracle.get_k();
+    let rank = query_rank(&oracle.samples, k);
+    if rank < k {
+        return Err(GaussError::InsufficientSamples {
+            rank_achieved: rank,
+            rank_needed: k,
+        });
+    }
+    let mut rng = lpn_thread_rng();
+    // the samples have rank k so this terminates
+    let a = loop {
+        if let Ok((a, _)) = independent_samples(&oracle, 4 * k, &mut rng) {
+            break a;
+        }
+    };
+    let a_inv = a.inverted();
+
+    log::info!(
+        "Transforming {} samples to systematic form",
+        oracle.samples.len()
+    );
+    oracle.samples.par_iter_mut().for_each(|query| {
+        let new_query = &query.as_binvector(k) * &a_inv;
+        *query = Sample::from_binvector(&new_query, query.get_product());
+    });
+    let secret = &a * &oracle.secret.as_binvector(k);
+    oracle.secret = Sample::from_binvector(&secret, false);
+
+    Ok((oracle, a_inv))
+}
+
+/// Guess the secret bits the samples are most certain of and remove them
+///
+/// The products of the samples of weight one $e_i$ are noisy copies of $s_i$, so their
+/// sum as $\pm 1$ estimates the bias of $s_i$. The $k - k'$ bits with the largest
+/// absolute bias are set to their majority value $g_i$ and removed: every query $a$
+/// loses these positions and $\sum_i a_i g_i$ is added to its product. The other
+/// positions keep their order. Positions without samples of weight one have bias zero,
+/// so they are removed last. Samples of which the query becomes zero are dropped.
+///
+/// A wrongly guessed bit adds an error to every sample that has a one in its position,
+/// so this needs enough samples of weight one, e.g. after [`gauss_reduce`] or BKW steps.
+///
+/// $k' = $ `target_k`
+/// $n' \le n$
+/// $d' = d$ if all guesses are correct
+pub fn reduce_secret_bits(mut oracle: LpnOracle, target_k: usize) -> LpnOracle {
+    let k = oracle.get_k();
+    assert!(0 < target_k && target_k <= k, "Need 0 < target_k <= k");
+
+    let mut bias = vec![0i64; k];
+    for query in oracle.samples.iter().filter(|q| q.is_unit_vector()) {
+        let i = query.as_binvector(k).iter().position(|bit| bit).unwrap();
+        bias[i] += if query.get_product() { -1 } else { 1 };
+    }
+    let mut by_bias: Vec<usize> = (0..k).collect();
+    by_bias.sort_by_key(|&i| Reverse(bias[i].abs()));
+    let mut guess = BinVector::from_elem(k, false);
+    for &i in &by_bias[..k - target_k] {
+        guess.set(i, bias[i] < 0);
+    }
+    let mut kept = by_bias[k - target_k..].to_vec();
+    kept.sort_unstable();
+    log::debug!(
+        "Guessed {} secret bits, keeping positions {:?}",
+        k - target_k,
+        kept
+    );
 
-    s_prime.as_vector()
+    let select =
+        |v: &BinVector| BinVector::from_bools(&kept.iter().map(|&i| v[i]).collect::<Vec<bool>>());
+    oracle.samples.par_iter_mut().for_each(|query| {
+        let a = query.as_binvector(k);
+        let product = query.get_product() ^ (&a * &guess);
+        *query = Sample::from_binvector(&select(&a), product);
+    });
+    oracle.samples.retain(|q| q.hamming_weight() > 0);
+    let secret = select(&oracle.secret.as_binvector(k));
+    oracle.secret = Sample::from_binvector(&secret, false);
+    oracle.truncate(target_k);
+    oracle
 }
 
 /// Randomly sample ``k`` queries from the oracle as a ``(A, s)``.
@@ -141,6 +355,112 @@ mod test {
         oracle.get_samples(4000555);
         let secret = oracle.secret.clone();
         let solution = pooled_gauss_solve(oracle);
-        assert_eq!(solution, secret.as_binvector(32));
+        assert_eq!(solution, Ok(secret.as_binvector(32)));
+    }
+
+    #[test]
+    fn gauss_rank_deficient() {
+        let mut oracle: LpnOracle = LpnOracle::new(16, 1.0 / 8.0);
+        oracle.get_samples(1000);
+        // the last bit of every query is zero
+        for q in oracle.samples.iter_mut() {
+            let mut query = q.as_binvector(16);
+            query.set(15, false);
+            *q = Sample::from_binvector(&query, q.get_product());
+        }
+        assert_eq!(
+            pooled_gauss_solve(oracle),
+            Err(GaussError::InsufficientSamples {
+                rank_achieved: 15,
+                rank_needed: 16
+            })
+        );
+    }
+
+    #[test]
+    fn gauss_no_solution() {
+        let mut oracle: LpnOracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(2000);
+        // the products are independent of the secret, so no candidate passes
+        for q in oracle.samples.iter_mut() {
+            q.set_product(rand::random());
+        }
+        assert_eq!(pooled_gauss_solve(oracle), Err(GaussError::NoSolution));
+    }
+
+    #[test]
+    fn test_gauss_reduce() {
+        let mut oracle: LpnOracle = LpnOracle::new(16, 0.0);
+        oracle.get_samples(1000);
+        let secret = oracle.secret.as_binvector(16);
+        let (reduced, a_inv) = gauss_reduce(oracle.clone()).unwrap();
+        assert_eq!(reduced.samples.len(), oracle.samples.len());
+        let new_secret = reduced.secret.as_binvector(16);
+        for q in reduced.samples.iter() {
+            assert_eq!(&q.as_binvector(16) * &new_secret, q.get_product());
+        }
+        // the unit vectors are among the samples
+        let weight_one = reduced
+            .samples
+            .iter()
+            .filter(|q| q.hamming_weight() == 1)
+            .count();
+        assert!(weight_one >= 16);
+
+        let solution = crate::bkw::majority(reduced);
+        assert_eq!(solution, new_secret);
+        assert_eq!(&a_inv * &solution, secret);
+    }
+
+    #[test]
+    fn gauss_reduce_rank_deficient() {
+        let mut oracle: LpnOracle = LpnOracle::new(16, 0.0);
+        oracle.get_samples(1000);
+        // the first bit of every query is zero
+        for q in oracle.samples.iter_mut() {
+            let mut query = q.as_binvector(16);
+            query.set(0, false);
+            *q = Sample::from_binvector(&query, q.get_product());
+        }
+        assert_eq!(
+            gauss_reduce(oracle).err(),
+            Some(GaussError::InsufficientSamples {
+                rank_achieved: 15,
+                rank_needed: 16
+            })
+        );
+
+        let mut oracle: LpnOracle = LpnOracle::new(16, 0.0);
+        oracle.get_samples(10);
+        match gauss_reduce(oracle) {
+            Err(GaussError::InsufficientSamples {
+                rank_achieved,
+                rank_needed: 16,
+            }) => assert!(rank_achieved <= 10),
+            _ => panic!("10 samples can not have rank 16"),
+        }
+    }
+
+    #[test]
+    fn test_reduce_secret_bits() {
+        let mut oracle: LpnOracle = LpnOracle::new(10, 0.0);
+        oracle.get_samples(20_000);
+        let secret = oracle.secret.as_binvector(10);
+        let reduced = reduce_secret_bits(oracle, 6);
+        assert_eq!(reduced.get_k(), 6);
+        let new_secret = reduced.secret.as_binvector(6);
+        // the kept bits of the secret in their original order
+        let mut bits = secret.iter();
+        assert!(new_secret.iter().all(|bit| bits.any(|b| b == bit)));
+        for q in reduced.samples.iter() {
+            assert!(q.hamming_weight() > 0);
+            assert_eq!(&q.as_binvector(6) * &new_secret, q.get_product());
+        }
+
+        let mut oracle: LpnOracle = LpnOracle::new(10, 1.0 / 8.0);
+        oracle.get_samples(100_000);
+        let reduced = reduce_secret_bits(oracle, 6);
+        let new_secret = reduced.secret.as_binvector(6);
+        assert_eq!(crate::bkw::majority(reduced), new_secret);
     }
 }
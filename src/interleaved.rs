@@ -0,0 +1,154 @@
+//! LPN with queries interleaved from several independent problems
+//!
+//! A sample concatenates the queries of one sample of every component oracle,
+//! and its product is the XOR of the component products.
+use crate::oracle::{LpnOracle, Sample, MAX_K};
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Oracle that combines the samples of several component oracles
+pub struct InterleavedLpnOracle {
+    /// The samples held by this oracle.
+    pub samples: Vec<Sample>,
+    components: Vec<LpnOracle>,
+    /// The bits of the joint query that belong to every component
+    ranges: Vec<Range<usize>>,
+    k: usize,
+}
+
+impl InterleavedLpnOracle {
+    /// Combine the component oracles, which should not have any samples yet
+    pub fn new(component_oracles: Vec<LpnOracle>) -> InterleavedLpnOracle {
+        assert!(!component_oracles.is_empty(), "Need at least one component");
+        let mut ranges = Vec::with_capacity(component_oracles.len());
+        let mut k = 0;
+        for oracle in component_oracles.iter() {
+            ranges.push(k..k + oracle.get_k());
+            k += oracle.get_k();
+        }
+        assert!(
+            k < MAX_K,
+            "The joint problem is too large for this build, max k: {}",
+            MAX_K
+        );
+        InterleavedLpnOracle {
+            samples: vec![],
+            components: component_oracles,
+            ranges,
+            k,
+        }
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// The bits of the joint query that belong to every component
+    pub fn component_ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    /// The secret of the joint problem: the concatenated component secrets
+    pub fn secret(&self) -> BinVector {
+        let mut secret = BinVector::with_capacity(self.k);
+        for oracle in self.components.iter() {
+            secret.extend_from_binvec(&oracle.secret.as_binvector(oracle.get_k()));
+        }
+        secret
+    }
+
+    /// The bias of the joint problem, the product of the component biases
+    pub fn delta(&self) -> f64 {
+        self.components.iter().map(|oracle| oracle.delta).product()
+    }
+
+    /// Get new samples from the oracle
+    ///
+    /// Takes `n` samples from every component.
+    /// These samples are stored in ``oracle.samples``
+    pub fn get_samples(&mut self, n: usize) {
+        let parts: Vec<Vec<Sample>> = self
+            .components
+            .iter_mut()
+            .map(|oracle| {
+                oracle.get_samples(n);
+                std::mem::take(&mut oracle.samples)
+            })
+            .collect();
+        let components = &self.components;
+        let k = self.k;
+        self.samples.reserve_exact(n);
+        self.samples.par_extend((0..n).into_par_iter().map(|i| {
+            let mut query = BinVector::with_capacity(k);
+            let mut product = false;
+            for (oracle, part) in components.iter().zip(parts.iter()) {
+                query.extend_from_binvec(&part[i].as_binvector(oracle.get_k()));
+                product ^= part[i].get_product();
+            }
+            Sample::from_binvector(&query, product)
+        }));
+    }
+
+    /// The joint problem with the bits of component `index` moved to the front
+    ///
+    /// As [`bkw`] recovers the first bits of the secret, reducing this problem down to
+    /// the size of the component recovers the secret of that component.
+    ///
+    /// [`bkw`]: crate::bkw::bkw
+    pub fn component_problem(&self, index: usize) -> LpnOracle {
+        let k = self.k;
+        let range = self.ranges[index].clone();
+        let order: Vec<usize> = range
+            .clone()
+            .chain((0..k).filter(|i| !range.contains(i)))
+            .collect();
+        let permute = |v: &BinVector| {
+            BinVector::from_bools(&order.iter().map(|&i| v[i]).collect::<Vec<bool>>())
+        };
+
+        let secret = Sample::from_binvector(&permute(&self.secret()), false);
+        let mut oracle = LpnOracle::new_with_secret(secret, k as u32, 0.0);
+        oracle.delta = self.delta();
+        oracle.samples = self
+            .samples
+            .par_iter()
+            .map(|q| Sample::from_binvector(&permute(&q.as_binvector(k)), q.get_product()))
+            .collect();
+        oracle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bkw::bkw;
+
+    #[test]
+    fn recover_components() {
+        let components = (0..3).map(|_| LpnOracle::new(8, 1.0 / 32.0)).collect();
+        let mut oracle = InterleavedLpnOracle::new(components);
+        assert_eq!(oracle.get_k(), 24);
+        oracle.get_samples(200_000);
+
+        let secret = oracle.secret();
+        let mut recovered = BinVector::with_capacity(24);
+        for (i, range) in oracle.component_ranges().iter().enumerate() {
+            let solution = bkw(oracle.component_problem(i), 3, 8);
+            assert_eq!(solution.len(), range.len());
+            recovered.extend_from_binvec(&solution);
+        }
+        assert_eq!(recovered, secret);
+    }
+
+    #[test]
+    fn product_is_xor_of_components() {
+        let components = vec![LpnOracle::new(10, 0.0), LpnOracle::new(20, 0.0)];
+        let mut oracle = InterleavedLpnOracle::new(components);
+        oracle.get_samples(1000);
+        let secret = oracle.secret();
+        for q in oracle.samples.iter() {
+            assert_eq!(&q.as_binvector(30) * &secret, q.get_product());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Key encapsulation from LPN
+//!
+//! Alekhnovich-style: the public key is $(A, b = As + e)$. A key bit $m$ is sent as
+//! $(u, v) = (rA, \langle r, b \rangle + m)$ for a sparse $r$, from which the holder of
+//! $s$ computes $v + \langle u, s \rangle = m + \langle r, e \rangle$.
+//! Every bit is sent `REPETITIONS` times and decoded by majority.
+//!
+//! The weight of $r$ is chosen such that enumerating its support takes at least
+//! $2^{80}$ steps, see [`sender_weight`].
+//!
+//! **Insecure, do not use.** The size of the secret is limited by `MAX_K`, so the
+//! public key can be broken with the LPN solvers of this crate, and $r$ can be found
+//! faster than by enumeration with information set decoding. The random number
+//! generator of this crate is not meant for cryptography, and no attempt is made at
+//! constant time.
+use crate::oracle::LpnOracle;
+use crate::random::lpn_thread_rng;
+use m4ri_rust::friendly::BinVector;
+use rand::seq::index;
+
+/// Number of bits of the shared key
+pub const KEY_LEN: usize = 128;
+/// Number of encryptions of every key bit
+const REPETITIONS: usize = 15;
+/// Number of rows of $A$ per bit of the secret
+const ROWS_PER_BIT: usize = 4;
+/// Security in bits against finding $r$ by enumerating its support
+pub const SECURITY_BITS: f64 = 80.0;
+
+/// $\log_2 \binom{n}{w}$
+fn log2_binomial(n: usize, w: usize) -> f64 {
+    (0..w)
+        .map(|i| ((n - i) as f64).log2() - ((i + 1) as f64).log2())
+        .sum()
+}
+
+/// The weight of $r$ for a public key with `rows` rows, if there is a secure one
+///
+/// A meet-in-the-middle search finds $r$ in about $\sqrt{\binom{rows}{w}}$ steps, so
+/// this is the smallest $w$ with $\binom{rows}{w} \ge 2^{2 \cdot SECURITY\_BITS}$.
+pub fn sender_weight(rows: usize) -> Option<usize> {
+    (0..=rows / 2).find(|&w| log2_binomial(rows, w) >= 2.0 * SECURITY_BITS)
+}
+
+/// The shared key
+pub type SharedKey = BinVector;
+
+/// Public key $(A, b = As + e)$
+#[derive(Clone)]
+pub struct PublicKey {
+    /// Rows of the matrix $A$
+    a: Vec<BinVector>,
+    b: BinVector,
+    /// Weight of the randomness $r$ of the encapsulation
+    sender_weight: usize,
+}
+
+impl PublicKey {
+    /// The size of the secret
+    pub fn get_k(&self) -> usize {
+        self.a[0].len()
+    }
+
+    /// The weight of the randomness $r$ of the encapsulation
+    pub fn sender_weight(&self) -> usize {
+        self.sender_weight
+    }
+}
+
+/// The secret $s$
+#[derive(Clone)]
+pub struct SecretKey {
+    s: BinVector,
+}
+
+/// Encapsulated key
+#[derive(Clone)]
+pub struct Ciphertext {
+    u: Vec<BinVector>,
+    v: BinVector,
+}
+
+/// LPN key encapsulation mechanism
+///
+/// **Insecure, do not use**, only meant for experiments, see the module documentation.
+pub struct LpnKem;
+
+impl LpnKem {
+    /// Generate a key pair for secrets of size `k`
+    ///
+    /// The probability that decapsulation fails grows with `noise_rate`: every repetition
+    /// is flipped with probability $(1 - (1 - 2\tau)^w) / 2$ with $w$ the
+    /// [sender weight](PublicKey::sender_weight), e.g. $w = 42$ for $k = 64$.
+    /// Panics if $k$ is too small for a weight with `SECURITY_BITS` bits of security.
+    pub fn new(k: usize, noise_rate: f64) -> (PublicKey, SecretKey) {
+        let sender_weight = sender_weight(ROWS_PER_BIT * k)
+            .unwrap_or_else(|| panic!("k = {} is too small for {} bits", k, SECURITY_BITS));
+        let mut oracle = LpnOracle::new(k as u32, noise_rate);
+        oracle.get_samples(ROWS_PER_BIT * k);
+        let a = oracle.samples.iter().map(|q| q.as_binvector(k)).collect();
+        let b = BinVector::from_bools(
+            &oracle
+                .samples
+                .iter()
+                .map(|q| q.get_product())
+                .collect::<Vec<bool>>(),
+        );
+        (
+            PublicKey {
+                a,
+                b,
+                sender_weight,
+            },
+            SecretKey {
+                s: oracle.secret.as_binvector(k),
+            },
+        )
+    }
+
+    /// Generate a random key and encapsulate it under `pk`
+    pub fn encapsulate(pk: &PublicKey) -> (Ciphertext, SharedKey) {
+        let mut rng = lpn_thread_rng();
+        let key = BinVector::random(KEY_LEN);
+        let mut u = Vec::with_capacity(KEY_LEN * REPETITIONS);
+        let mut v = BinVector::with_capacity(KEY_LEN * REPETITIONS);
+        for bit in key.iter() {
+            for _ in 0..REPETITIONS {
+                let mut ui = BinVector::from_elem(pk.get_k(), false);
+                let mut vi = bit;
+                for j in index::sample(&mut rng, pk.a.len(), pk.sender_weight).into_iter() {
+                    ui += &pk.a[j];
+                    vi ^= pk.b[j];
+                }
+                u.push(ui);
+                v.push(vi);
+            }
+        }
+        (Ciphertext { u, v }, key)
+    }
+
+    /// Recover the key from `ct`
+    ///
+    /// With the wrong secret key, the result is a uniformly random key.
+    pub fn decapsulate(sk: &SecretKey, ct: &Ciphertext) -> SharedKey {
+        let bits: Vec<bool> = (0..KEY_LEN)
+            .map(|i| {
+                let votes = (i * REPETITIONS..(i + 1) * REPETITIONS)
+                    .filter(|&j| ct.v[j] ^ (&ct.u[j] * &sk.s))
+                    .count();
+                votes > REPETITIONS / 2
+            })
+            .collect();
+        BinVector::from_bools(&bits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shared_keys_match() {
+        for _ in 0..50 {
+            let (pk, sk) = LpnKem::new(64, 1.0 / 1024.0);
+            let (ct, key) = LpnKem::encapsulate(&pk);
+            assert_eq!(key.len(), KEY_LEN);
+            assert_eq!(LpnKem::decapsulate(&sk, &ct), key);
+        }
+    }
+
+    #[test]
+    fn enumeration_is_infeasible() {
+        let (pk, _) = LpnKem::new(64, 1.0 / 1024.0);
+        let rows = ROWS_PER_BIT * 64;
+        let weight = pk.sender_weight();
+        assert!(log2_binomial(rows, weight) >= 2.0 * SECURITY_BITS);
+        assert!(log2_binomial(rows, weight - 1) < 2.0 * SECURITY_BITS);
+        // a weight of 4 would be found by enumerating 2^28 supports
+        assert!(log2_binomial(rows, 4) < 28.0);
+        // there are fewer than 2^160 supports for small k
+        assert_eq!(sender_weight(ROWS_PER_BIT * 32), None);
+    }
+
+    #[test]
+    fn wrong_secret_key() {
+        let (pk, _) = LpnKem::new(64, 1.0 / 1024.0);
+        for _ in 0..50 {
+            let (_, wrong_sk) = LpnKem::new(64, 1.0 / 1024.0);
+            let (ct, key) = LpnKem::encapsulate(&pk);
+            let wrong_key = LpnKem::decapsulate(&wrong_sk, &ct);
+            assert_ne!(wrong_key, key);
+            // about half of the bits are wrong
+            let errors = (&wrong_key + &key).count_ones();
+            assert!((32..=96).contains(&errors), "{} errors", errors);
+        }
+    }
+}
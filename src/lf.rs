@@ -0,0 +1,155 @@
+//! Defines the coset-based reductions LF1 and LF2 from the Levieil and Fouque paper
+use crate::{
+    codes::BinaryCode,
+    covering_codes::partition_by_coset,
+    oracle::{query_bits_range, LpnOracle, Sample},
+};
+use itertools::Itertools;
+
+/// Drop the top `b` bits of the `reduced` samples and update the bias
+///
+/// `bias_sum` is the sum over the samples of $\delta_s^{w}$, with $w$ the weight
+/// of the residual in the dropped block.
+fn finish_coset_reduce(oracle: &mut LpnOracle, reduced: Vec<Sample>, bias_sum: f64, b: usize) {
+    let k = oracle.get_k();
+    if !reduced.is_empty() {
+        oracle.delta = oracle.delta.powi(2) * bias_sum / reduced.len() as f64;
+    }
+    oracle.samples = reduced;
+    oracle.truncate(k - b);
+    log::debug!(
+        "Coset reduction left {} samples with k = {}, new delta = {}",
+        oracle.samples.len(),
+        oracle.get_k(),
+        oracle.delta
+    );
+}
+
+/// LF1 reduction of the top `b` bits by coset of `code`
+///
+/// Samples are partitioned by the codeword nearest to their top block, which is
+/// $c + e$ with $e$ of weight at most the covering radius. Like LF1, the first sample
+/// of every class is added to the other samples of that class and then dropped.
+/// That leaves the residual $e_1 + e_2$ in the block, which is dropped as well, so
+/// $\langle e_1 + e_2, s_B \rangle$ is added to the noise. The bias of this term is
+/// estimated as $\delta_s^{w}$ for a residual of weight $w$, averaged over the samples.
+///
+/// $k' = k - b$
+/// $n' = n - \#classes$
+/// $\delta' = \delta^2 \cdot \mathrm{avg}(\delta_s^{w})$
+pub fn lf1_reduce(oracle: &mut LpnOracle, b: u32, code: &dyn BinaryCode) {
+    let b = b as usize;
+    assert_eq!(code.length(), b, "The code should have length b");
+    let k = oracle.get_k();
+    let block = (k - b)..k;
+    let delta_s = oracle.delta_s;
+
+    let mut reduced = Vec::with_capacity(oracle.samples.len());
+    let mut bias_sum = 0f64;
+    for class in partition_by_coset(oracle, code) {
+        let (pivot, rest) = class.split_first().unwrap();
+        for query in rest {
+            let mut sum = query.clone();
+            sum.xor_into(pivot);
+            bias_sum += delta_s.powi(query_bits_range(&sum, block.clone()).count_ones() as i32);
+            reduced.push(sum);
+        }
+    }
+    finish_coset_reduce(oracle, reduced, bias_sum, b);
+}
+
+/// LF2 reduction of the top `b` bits by coset of `code`
+///
+/// Like [`lf1_reduce`], but like LF2 all pairs of samples in a class are added.
+/// As a second noise reduction, only the sums of which the residual $e_1 + e_2$ has
+/// weight at most the covering radius of `code` are kept, as they add the least noise.
+///
+/// $k' = k - b$
+/// $n' \le \#classes \binom{n / \#classes}{2}$
+/// $\delta' = \delta^2 \cdot \mathrm{avg}(\delta_s^{w})$
+pub fn lf2_reduce(oracle: &mut LpnOracle, b: u32, code: &dyn BinaryCode) {
+    let b = b as usize;
+    assert_eq!(code.length(), b, "The code should have length b");
+    let k = oracle.get_k();
+    let block = (k - b)..k;
+    let delta_s = oracle.delta_s;
+    let radius = code.covering_radius() as u32;
+
+    let mut reduced = Vec::new();
+    let mut bias_sum = 0f64;
+    for class in partition_by_coset(oracle, code) {
+        for (first, second) in class.iter().tuple_combinations() {
+            let mut sum = first.clone();
+            sum.xor_into(second);
+            let weight = query_bits_range(&sum, block.clone()).count_ones();
+            if weight <= radius {
+                bias_sum += delta_s.powi(weight as i32);
+                reduced.push(sum);
+            }
+        }
+    }
+    finish_coset_reduce(oracle, reduced, bias_sum, b);
+}
+
+#[cfg(all(test, feature = "hamming"))]
+mod tests {
+    use super::*;
+    use crate::codes::HammingCode7_4;
+    use m4ri_rust::friendly::BinVector;
+    use rand::prelude::*;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Average the measured and predicted bias over oracles with sparse secrets
+    fn coset_reduction_bias(
+        reduce: fn(&mut LpnOracle, u32, &dyn BinaryCode),
+        n: usize,
+    ) -> (f64, f64) {
+        let trials = 100;
+        let (mut measured, mut predicted) = (0f64, 0f64);
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for trial in 0..trials {
+            // the secret bits of the block are one with probability 1/8
+            let bits: Vec<bool> = (0..16).map(|i| i < 9 || rng.gen_bool(1.0 / 8.0)).collect();
+            let mut oracle = LpnOracle::from_secret(16, 0.0, BinVector::from_bools(&bits));
+            oracle.delta_s = 0.75;
+            oracle.get_samples_seeded(n, trial);
+            reduce(&mut oracle, 7, &HammingCode7_4);
+            let secret = oracle.secret.as_binvector(9);
+            let correct = oracle
+                .samples
+                .iter()
+                .filter(|q| &q.as_binvector(9) * &secret == q.get_product())
+                .count();
+            measured += 2.0 * correct as f64 / oracle.samples.len() as f64 - 1.0;
+            predicted += oracle.delta;
+        }
+        (measured / trials as f64, predicted / trials as f64)
+    }
+
+    #[test]
+    fn test_lf1_reduce() {
+        let (measured, predicted) = coset_reduction_bias(lf1_reduce, 1000);
+        // the residual has weight at most two, (8 + 14 * 3/4 + 42 * (3/4)^2) / 64 = 0.66
+        assert!((predicted - 0.66).abs() < 0.05, "predicted {}", predicted);
+        assert!(
+            (measured - predicted).abs() < 0.08,
+            "{} vs {}",
+            measured,
+            predicted
+        );
+    }
+
+    #[test]
+    fn test_lf2_reduce() {
+        let (lf1, _) = coset_reduction_bias(lf1_reduce, 1000);
+        let (measured, predicted) = coset_reduction_bias(lf2_reduce, 300);
+        assert!(
+            (measured - predicted).abs() < 0.08,
+            "{} vs {}",
+            measured,
+            predicted
+        );
+        // dropping the residuals of weight two reduces the noise
+        assert!(measured > lf1, "{} vs {}", measured, lf1);
+    }
+}
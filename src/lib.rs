@@ -20,12 +20,31 @@ extern crate rayon;
 #[cfg_attr(feature = "codes", macro_use)]
 extern crate serde;
 
+#[cfg(feature = "codes")]
+pub mod benchmark;
 pub mod bkw;
+pub mod coherent;
+pub mod column_noise;
+pub mod compressed;
+pub mod distinguisher;
 #[cfg(feature = "codes")]
 pub mod covering_codes;
+pub mod fwht;
 pub mod gauss;
+pub mod interleaved;
+pub mod kem;
+#[cfg(feature = "codes")]
+pub mod lf;
 pub mod lf1;
+#[cfg(feature = "codes")]
+pub mod mitm;
+pub mod modular;
+pub mod network;
 pub mod oracle;
+pub mod polynomial_secret;
+pub mod side_channel;
+pub mod sparse_query;
+pub mod split_key;
 
 #[cfg(feature = "codes")]
 pub mod codes;
@@ -0,0 +1,99 @@
+//! Meet-in-the-middle solving for secrets with code structure
+use crate::codes::BinaryCode;
+use crate::fwht::fwht;
+use crate::oracle::LpnOracle;
+use fnv::FnvHashMap;
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+use std::collections::hash_map::Entry;
+use std::ops::Range;
+
+/// Solve LPN for a secret in the dual of `code` by meeting in the middle.
+///
+/// If $s = yH$ for the $r \times n$ parity check matrix $H$ of `code`, then
+/// $\langle a, s \rangle = \langle aH^T, y \rangle$, so only the $r$ bits of $y$ are unknown.
+/// The syndromes $aH^T$ are split after `half_dim` bits. Pairs of samples that agree on the
+/// high part give a sample for only the low part of $y$, which is then found with a
+/// Walsh-Hadamard transform, and vice versa.
+/// The tables have `2^half_dim` and `2^(r - half_dim)` entries, instead of the
+/// $2^{b}$ entries per step that BKW needs for the full $n$ bits.
+pub fn structured_mitm(oracle: LpnOracle, code: &dyn BinaryCode, half_dim: usize) -> BinVector {
+    let n = oracle.get_k();
+    assert_eq!(code.length(), n, "The code should have length k");
+    let h = code.parity_check_matrix();
+    let r = h.nrows();
+    assert!(r < 64, "Syndromes need to fit in a u64");
+    assert!(0 < half_dim && half_dim < r, "0 < half_dim < n - k");
+
+    let h_transposed = h.transposed();
+    let syndromes: Vec<(u64, bool)> = oracle
+        .samples
+        .par_iter()
+        .map(|q| {
+            let syndrome = &q.as_binvector(n) * &h_transposed;
+            (syndrome.get_storage()[0] as u64, q.get_product())
+        })
+        .collect();
+
+    let low = solve_part(&syndromes, r, 0..half_dim);
+    let high = solve_part(&syndromes, r, half_dim..r);
+    let y = low | high;
+    let y = (0..r).map(|i| (y >> i) & 1 == 1).collect::<Vec<bool>>();
+    &BinVector::from_bools(&y) * h
+}
+
+/// Recover the bits in `range` of $y$ from pairs of samples that agree on all other bits
+fn solve_part(syndromes: &[(u64, bool)], r: usize, range: Range<usize>) -> u64 {
+    let width = range.len();
+    let part_mask = ((1u64 << width) - 1) << range.start;
+    let rest_mask = ((1u64 << r) - 1) & !part_mask;
+
+    // pair up samples in disjoint pairs, so the noise of the pairs is independent
+    let mut unpaired: FnvHashMap<u64, (u64, bool)> = FnvHashMap::default();
+    let mut counts = vec![0i64; 1 << width];
+    for &(syndrome, product) in syndromes {
+        match unpaired.entry(syndrome & rest_mask) {
+            Entry::Vacant(entry) => {
+                entry.insert((syndrome, product));
+            }
+            Entry::Occupied(entry) => {
+                let (other, other_product) = entry.remove();
+                let query = ((syndrome ^ other) & part_mask) >> range.start;
+                counts[query as usize] += if product ^ other_product { -1 } else { 1 };
+            }
+        }
+    }
+    log::debug!(
+        "Solving bits {:?} of y from {} pairs",
+        range,
+        counts.iter().map(|c| c.abs()).sum::<i64>()
+    );
+
+    fwht(&mut counts, width as u32);
+    let guess = (0..counts.len()).max_by_key(|&x| counts[x]).unwrap() as u64;
+    guess << range.start
+}
+
+#[cfg(all(test, feature = "hamming"))]
+mod test {
+    use super::*;
+    use crate::bkw::bkw_optimal_params;
+    use crate::codes::HammingCode63_57;
+    use crate::oracle::Sample;
+
+    #[test]
+    fn secret_in_dual_code() {
+        let code = HammingCode63_57;
+        let tau = 1.0 / 8.0;
+        let n = 4000;
+        for _ in 0..10 {
+            let secret = &BinVector::random(6) * code.parity_check_matrix();
+            let mut oracle =
+                LpnOracle::new_with_secret(Sample::from_binvector(&secret, false), 63, tau);
+            oracle.get_samples(n);
+            assert_eq!(structured_mitm(oracle, &code, 3), secret);
+        }
+        // generic BKW can't do anything with this few samples
+        assert_eq!(bkw_optimal_params(63, n, 1.0 - 2.0 * tau), None);
+    }
+}
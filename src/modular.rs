@@ -0,0 +1,209 @@
+//! LPN over $\mathbb{Z}_q$
+//!
+//! Queries and secret are vectors over $\mathbb{Z}_q$ and the product is
+//! $\langle a, s \rangle + e \bmod q$, with the noise $e$ drawn from a given distribution.
+use crate::random::lpn_thread_rng;
+use fnv::FnvHashMap;
+use rand::prelude::*;
+use std::collections::hash_map::Entry;
+use std::mem;
+
+/// Distribution of the noise added to the products
+pub type NoiseDistribution = Box<dyn Fn(&mut dyn RngCore) -> i32 + Send + Sync>;
+
+/// A sample $(a, \langle a, s \rangle + e)$ over $\mathbb{Z}_q$
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModularSample {
+    /// The query vector $a$
+    pub query: Vec<u32>,
+    /// The noisy product
+    pub product: u32,
+}
+
+/// Oracle for LPN over $\mathbb{Z}_q$
+pub struct ModularLpnOracle {
+    /// The samples held by this oracle.
+    pub samples: Vec<ModularSample>,
+    /// The secret of this problem
+    pub secret: Vec<u32>,
+    k: usize,
+    q: u32,
+    noise_distribution: NoiseDistribution,
+}
+
+/// $\langle a, s \rangle \bmod q$
+fn inner_product(a: &[u32], s: &[u32], q: u32) -> u32 {
+    (a.iter()
+        .zip(s.iter())
+        .fold(0u64, |acc, (&a, &s)| acc + u64::from(a) * u64::from(s))
+        % u64::from(q)) as u32
+}
+
+/// $x - y \bmod q$ for $x, y < q$, computed without overflowing for any $q$
+fn sub_mod(x: u32, y: u32, q: u32) -> u32 {
+    ((u64::from(x) + u64::from(q) - u64::from(y)) % u64::from(q)) as u32
+}
+
+impl ModularLpnOracle {
+    /// Create a new problem of size `k` over $\mathbb{Z}_q$ with a random secret
+    pub fn new(
+        k: usize,
+        q: u32,
+        noise_distribution: impl Fn(&mut dyn RngCore) -> i32 + Send + Sync + 'static,
+    ) -> ModularLpnOracle {
+        assert!(q >= 2, "q should be at least 2");
+        let mut rng = lpn_thread_rng();
+        let secret = (0..k).map(|_| rng.gen_range(0..q)).collect();
+        log::info!("Constructed modular oracle with k={}, q={}", k, q);
+        ModularLpnOracle {
+            samples: vec![],
+            secret,
+            k,
+            q,
+            noise_distribution: Box::new(noise_distribution),
+        }
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    pub fn get_q(&self) -> u32 {
+        self.q
+    }
+
+    /// Get new samples from the oracle
+    ///
+    /// These samples are stored in ``oracle.samples``
+    pub fn get_samples(&mut self, n: usize) {
+        let mut rng = lpn_thread_rng();
+        let q = self.q;
+        self.samples.reserve(n);
+        for _ in 0..n {
+            let query: Vec<u32> = (0..self.k).map(|_| rng.gen_range(0..q)).collect();
+            let noise = (self.noise_distribution)(&mut rng);
+            let product = (i64::from(inner_product(&query, &self.secret, q)) + i64::from(noise))
+                .rem_euclid(i64::from(q)) as u32;
+            self.samples.push(ModularSample { query, product });
+        }
+    }
+}
+
+/// BKW over $\mathbb{Z}_q$.
+///
+/// Does `a-1` reduction steps that each zero out a block of `b` coordinates, by subtracting
+/// the first sample with the same values in that block. The remaining $k - (a-1)b$
+/// coordinates of the secret are recovered by picking the candidate that agrees with the
+/// most samples.
+pub fn bkw_modular(mut oracle: ModularLpnOracle, a: u32, b: u32) -> Vec<u32> {
+    let (k, q) = (oracle.get_k(), oracle.get_q());
+    let (a, b) = (a as usize, b as usize);
+    assert!(a >= 1, "a >= 1");
+    assert!((a - 1) * b < k, "(a-1)*b < k");
+
+    for i in 1..a {
+        let range = (k - b * i)..(k - b * (i - 1));
+        let mut firsts: FnvHashMap<Vec<u32>, ModularSample> = FnvHashMap::default();
+        let samples = mem::take(&mut oracle.samples);
+        for sample in samples {
+            match firsts.entry(sample.query[range.clone()].to_vec()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(sample);
+                }
+                Entry::Occupied(entry) => {
+                    let first = entry.get();
+                    let query = sample
+                        .query
+                        .iter()
+                        .zip(first.query.iter())
+                        .map(|(&x, &y)| sub_mod(x, y, q))
+                        .collect();
+                    let product = sub_mod(sample.product, first.product, q);
+                    oracle.samples.push(ModularSample { query, product });
+                }
+            }
+        }
+        log::info!(
+            "Modular BKW iteration {}, {} samples left",
+            i,
+            oracle.samples.len()
+        );
+    }
+
+    let k_final = k - (a - 1) * b;
+    let num_candidates = (q as usize)
+        .checked_pow(k_final as u32)
+        .filter(|&n| n <= 1 << 24)
+        .expect("Too many candidates left for exhaustive search");
+    let mut candidate = vec![0u32; k_final];
+    let mut best = (0, candidate.clone());
+    for _ in 0..num_candidates {
+        let agreeing = oracle
+            .samples
+            .iter()
+            .filter(|sample| {
+                inner_product(&sample.query[..k_final], &candidate, q) == sample.product
+            })
+            .count();
+        if agreeing > best.0 {
+            best = (agreeing, candidate.clone());
+        }
+        // next candidate, counting in base q
+        for digit in candidate.iter_mut() {
+            *digit += 1;
+            if *digit < q {
+                break;
+            }
+            *digit = 0;
+        }
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// $e = 0$ with probability 0.9, otherwise $\pm 1$
+    fn small_noise(rng: &mut dyn RngCore) -> i32 {
+        match rng.gen_range(0..20) {
+            0 => -1,
+            1 => 1,
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn bkw_modular_recovers_secret() {
+        for &q in &[3, 4] {
+            let mut oracle = ModularLpnOracle::new(8, q, small_noise);
+            oracle.get_samples(10_000);
+            let secret = oracle.secret.clone();
+            assert_eq!(bkw_modular(oracle, 3, 2), &secret[..4], "q = {}", q);
+        }
+    }
+
+    #[test]
+    fn sub_mod_large_modulus() {
+        let q = u32::MAX;
+        assert_eq!(sub_mod(q - 1, q - 2, q), 1);
+        assert_eq!(sub_mod(1, q - 1, q), 2);
+        assert_eq!(sub_mod(0, 0, q), 0);
+        assert_eq!(sub_mod(2, 3, 5), 4);
+    }
+
+    #[test]
+    fn noise_free_solution_is_consistent() {
+        for &q in &[3, 4] {
+            let mut oracle = ModularLpnOracle::new(6, q, |_| 0);
+            oracle.get_samples(200);
+            let samples = oracle.samples.clone();
+            let secret = oracle.secret.clone();
+            let solution = bkw_modular(oracle, 1, 0);
+            assert_eq!(solution, secret);
+            for sample in samples.iter() {
+                assert_eq!(inner_product(&sample.query, &solution, q), sample.product);
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Oracles that serve samples over the network
+//!
+//! The client asks for samples by sending their number as a 4-byte little-endian
+//! integer. The server answers with that many samples, each sent as its storage
+//! blocks in little-endian order. Both sides should be built with the same `MAX_K`.
+//! A request may ask for at most [`MAX_SAMPLES_PER_REQUEST`] samples, the server
+//! closes the connection on larger requests.
+use crate::oracle::{LpnOracle, Sample};
+use m4ri_rust::friendly::BinVector;
+use std::convert::TryFrom;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+/// The largest number of samples a client may ask for at once
+pub const MAX_SAMPLES_PER_REQUEST: u32 = 1 << 20;
+
+/// Client for an oracle served by [`run_oracle_server`]
+pub struct NetworkLpnOracle {
+    /// The samples held by this oracle.
+    pub samples: Vec<Sample>,
+    stream: TcpStream,
+    k: usize,
+    noise_rate: f64,
+}
+
+impl NetworkLpnOracle {
+    /// Connect to the server for a problem of size `k` with noise rate `noise_rate`
+    pub fn connect(addr: SocketAddr, k: usize, noise_rate: f64) -> io::Result<NetworkLpnOracle> {
+        let stream = TcpStream::connect(addr)?;
+        log::info!("Connected to oracle at {}", addr);
+        Ok(NetworkLpnOracle {
+            samples: vec![],
+            stream,
+            k,
+            noise_rate,
+        })
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// Get new samples from the server
+    ///
+    /// These samples are stored in ``oracle.samples``. More than
+    /// [`MAX_SAMPLES_PER_REQUEST`] samples are requested in several parts.
+    pub fn get_samples(&mut self, n: usize) -> io::Result<()> {
+        self.samples.reserve_exact(n);
+        let mut remaining = n;
+        while remaining > 0 {
+            let count = remaining.min(MAX_SAMPLES_PER_REQUEST as usize);
+            self.request_samples(count)?;
+            remaining -= count;
+        }
+        Ok(())
+    }
+
+    fn request_samples(&mut self, n: usize) -> io::Result<()> {
+        let count = u32::try_from(n)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Too many samples"))?;
+        self.stream.write_all(&count.to_le_bytes())?;
+
+        let mut reader = BufReader::new(&self.stream);
+        let mut block = [0u8; 8];
+        for _ in 0..n {
+            let mut sample = Sample::new();
+            for word in sample.get_sample_mut() {
+                reader.read_exact(&mut block)?;
+                *word = u64::from_le_bytes(block);
+            }
+            self.samples.push(sample);
+        }
+        Ok(())
+    }
+
+    /// Turn the received samples into a local oracle to run attacks on
+    ///
+    /// The secret is not known to the client, so it is set to zero.
+    pub fn into_oracle(self) -> LpnOracle {
+        let secret = Sample::from_binvector(&BinVector::from_elem(self.k, false), false);
+        let mut oracle = LpnOracle::new_with_secret(secret, self.k as u32, self.noise_rate);
+        oracle.samples = self.samples;
+        oracle
+    }
+}
+
+/// Serve samples of `oracle` on `addr`
+///
+/// Connections are handled one at a time, until the client disconnects. Errors on a
+/// connection, like asking for more than [`MAX_SAMPLES_PER_REQUEST`] samples, close
+/// it. Only returns on errors of the listener.
+pub fn run_oracle_server(mut oracle: LpnOracle, addr: SocketAddr) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    log::info!(
+        "Serving oracle with k={} on {}",
+        oracle.get_k(),
+        listener.local_addr()?
+    );
+    for stream in listener.incoming() {
+        if let Err(e) = serve_samples(&mut oracle, stream?) {
+            log::warn!("Closed connection: {}", e);
+        }
+    }
+    Ok(())
+}
+
+fn serve_samples(oracle: &mut LpnOracle, stream: TcpStream) -> io::Result<()> {
+    let mut reader = &stream;
+    let mut writer = BufWriter::new(&stream);
+    let mut count = [0u8; 4];
+    loop {
+        match reader.read_exact(&mut count) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(e) => return Err(e),
+        }
+        let n = u32::from_le_bytes(count);
+        if n > MAX_SAMPLES_PER_REQUEST {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Asked for {} samples, at most {} are allowed",
+                    n, MAX_SAMPLES_PER_REQUEST
+                ),
+            ));
+        }
+        let n = n as usize;
+        log::debug!("Sending {} samples", n);
+        let start = oracle.samples.len();
+        oracle.get_samples(n);
+        for sample in oracle.samples.drain(start..) {
+            for word in sample.get_sample() {
+                writer.write_all(&word.to_le_bytes())?;
+            }
+        }
+        writer.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bkw::bkw;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn bkw_over_loopback() {
+        let oracle = LpnOracle::new(32, 1.0 / 32.0);
+        let mut secret = oracle.secret.as_binvector(32);
+
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let server_oracle = oracle.clone();
+        thread::spawn(move || run_oracle_server(server_oracle, addr));
+        let mut network = (0..100)
+            .find_map(|_| {
+                NetworkLpnOracle::connect(addr, 32, 1.0 / 32.0)
+                    .map_err(|_| thread::sleep(Duration::from_millis(10)))
+                    .ok()
+            })
+            .expect("Could not connect to the server");
+        network.get_samples(100_000).unwrap();
+        network.get_samples(300_000).unwrap();
+        assert_eq!(network.samples.len(), 400_000);
+
+        let mut local = oracle;
+        local.get_samples(400_000);
+        let local_solution = bkw(local, 4, 8);
+        let network_solution = bkw(network.into_oracle(), 4, 8);
+        secret.truncate(8);
+        assert_eq!(local_solution, secret);
+        assert_eq!(network_solution, local_solution);
+    }
+
+    #[test]
+    fn request_too_large() {
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        thread::spawn(move || run_oracle_server(LpnOracle::new(32, 1.0 / 32.0), addr));
+        let connect = || {
+            (0..100)
+                .find_map(|_| {
+                    TcpStream::connect(addr)
+                        .map_err(|_| thread::sleep(Duration::from_millis(10)))
+                        .ok()
+                })
+                .expect("Could not connect to the server")
+        };
+
+        // the server closes the connection without sending samples
+        let mut stream = connect();
+        stream
+            .write_all(&(MAX_SAMPLES_PER_REQUEST + 1).to_le_bytes())
+            .unwrap();
+        let mut answer = Vec::new();
+        stream.read_to_end(&mut answer).unwrap();
+        assert!(answer.is_empty());
+
+        // and keeps serving other clients
+        let mut network = NetworkLpnOracle::connect(addr, 32, 1.0 / 32.0).unwrap();
+        network.get_samples(10).unwrap();
+        assert_eq!(network.samples.len(), 10);
+    }
+}
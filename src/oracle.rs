@@ -1,26 +1,33 @@
 //! Describes the LPN problem oracle on which we apply reductions and solving algorithms
 //!
 //! This project currently makes strong assumptions that u64 == usize
+use fnv::FnvHashMap;
 use indicatif::ProgressBar;
 use m4ri_rust::friendly::*;
-use rand::distributions::{Bernoulli, Distribution};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     cmp, fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter},
     mem::{self, MaybeUninit},
     ops::Range,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use rand::prelude::*;
 use rayon::prelude::*;
 
-use crate::random::lpn_thread_rng;
+use crate::random::{lpn_thread_rng, ThreadRng};
 use crate::util::log_2;
+use rand_chacha::ChaCha8Rng;
 
 pub(crate) type StorageBlock = u64;
 pub(crate) const ONE: StorageBlock = 1;
 
 /// How many bits are stored in each underlying storage block?
-const fn bits_per_block() -> usize {
+pub(crate) const fn bits_per_block() -> usize {
     bytes_per_block() * 8
 }
 
@@ -36,7 +43,7 @@ const fn block_offset(off: usize) -> usize {
 
 /// Takes as input a number of bits requiring storage; returns an aligned number of blocks needed
 /// to store those bits.
-const fn blocks_required(num_bits: usize) -> usize {
+pub(crate) const fn blocks_required(num_bits: usize) -> usize {
     num_bits / bits_per_block()
         + if num_bits % bits_per_block() == 0 {
             0
@@ -71,7 +78,8 @@ pub(crate) type SampleStorage = [StorageBlock; SAMPLE_LEN];
 /// Represents a sample in the oracle
 ///
 /// `<a, s> + e = c`
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
 #[repr(transparent)]
 pub struct Sample {
     sample: [StorageBlock; SAMPLE_LEN],
@@ -90,7 +98,7 @@ impl fmt::Debug for Sample {
 }
 
 impl Sample {
-    const fn new() -> Sample {
+    pub(crate) const fn new() -> Sample {
         Sample {
             sample: [0; SAMPLE_LEN],
         }
@@ -105,8 +113,12 @@ impl Sample {
             == 1
     }
 
-    /// Get the Hamming weight of the sample
-    pub fn count_ones(&self) -> u32 {
+    /// Get the Hamming weight of the query vector, without the product bit
+    ///
+    /// This only masks the block holding the product bit, which is cheap enough that
+    /// the weight is not cached: samples are changed in place by the reductions.
+    #[inline]
+    pub fn hamming_weight(&self) -> u32 {
         let mut acc = 0;
         for block in &self.sample[..SAMPLE_LEN - 1] {
             acc += block.count_ones();
@@ -116,6 +128,31 @@ impl Sample {
         acc
     }
 
+    /// Check if the query vector has weight one
+    ///
+    /// Unlike comparing [`Sample::hamming_weight`] to one, this stops at the first
+    /// block with more than one bit set or at the second nonzero block.
+    #[inline]
+    pub fn is_unit_vector(&self) -> bool {
+        let mut found = false;
+        for i in 0..SAMPLE_LEN {
+            let block = self.get_block(i);
+            if block != 0 {
+                if found || block & (block - 1) != 0 {
+                    return false;
+                }
+                found = true;
+            }
+        }
+        found
+    }
+
+    /// Get the Hamming weight of the sample, see [`Sample::hamming_weight`]
+    #[inline]
+    pub fn count_ones(&self) -> u32 {
+        self.hamming_weight()
+    }
+
     /// get the noisy inner product
     pub fn get_product(&self) -> bool {
         (self.sample[NOISE_BIT_BLOCK] >> NOISE_BIT_IDX) == 1
@@ -224,6 +261,80 @@ impl Sample {
     }
 }
 
+/// Statistics of sums of random pairs of samples, see [`LpnOracle::pairwise_xor_analysis`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairwiseStats {
+    /// Mean Hamming weight of the summed query vectors
+    pub mean_query_weight: f64,
+    /// Fraction of summed samples of which the product is wrong, i.e. the noise rate
+    pub product_ones_fraction: f64,
+    /// The noise rate $2\tau(1 - \tau)$ expected for the sum of two samples
+    pub expected_noise_rate: f64,
+}
+
+/// The distribution of the noise bits of the samples of an [`LpnOracle`]
+#[derive(Clone)]
+pub enum NoiseModel {
+    /// Every noise bit is one with probability $\tau$, the usual LPN noise
+    Bernoulli(f64),
+    /// The parity of a discrete Gaussian over $\mathbb{Z}$ with parameter `sigma`,
+    /// i.e. LWE noise reduced modulo 2
+    Gaussian { sigma: f64 },
+    /// Any other distribution of the noise bits
+    ///
+    /// The oracle can't know the bias of such noise, so `delta` has to be set by the caller.
+    Custom(Arc<dyn Fn(&mut dyn RngCore) -> bool + Send + Sync>),
+}
+
+impl NoiseModel {
+    /// Largest $|x|$ sampled for [`NoiseModel::Gaussian`], the mass beyond is negligible
+    fn gaussian_tail(sigma: f64) -> i64 {
+        (10.0 * sigma).ceil() as i64 + 1
+    }
+
+    /// Draw a noise bit
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> bool {
+        match self {
+            NoiseModel::Bernoulli(tau) => rng.gen_bool(*tau),
+            NoiseModel::Gaussian { sigma } => {
+                // rejection sampling from the uniform distribution on [-t, t]
+                let tail = Self::gaussian_tail(*sigma);
+                loop {
+                    let x = rng.gen_range(-tail..=tail);
+                    let rho = (-((x * x) as f64) / (2.0 * sigma * sigma)).exp();
+                    if rng.gen_bool(rho) {
+                        return x % 2 != 0;
+                    }
+                }
+            }
+            NoiseModel::Custom(noise) => noise(&mut *rng as &mut dyn RngCore),
+        }
+    }
+
+    /// The bias $\delta = 1 - 2 \Pr[e = 1]$ of the noise, if it is known
+    pub fn bias(&self) -> Option<f64> {
+        match self {
+            NoiseModel::Bernoulli(tau) => Some(1.0 - 2.0 * tau),
+            NoiseModel::Gaussian { sigma } => {
+                let tail = Self::gaussian_tail(*sigma);
+                let (even, odd) = (-tail..=tail).fold((0.0, 0.0), |(even, odd), x| {
+                    let rho = (-((x * x) as f64) / (2.0 * sigma * sigma)).exp();
+                    if x % 2 == 0 {
+                        (even + rho, odd)
+                    } else {
+                        (even, odd + rho)
+                    }
+                });
+                Some((even - odd) / (even + odd))
+            }
+            NoiseModel::Custom(_) => None,
+        }
+    }
+}
+
+/// Number of samples drawn from one stream in [`LpnOracle::get_samples_seeded`]
+const SEEDED_CHUNK_SIZE: usize = 10_000;
+
 /// This struct represents the oracle of the LPN problem.
 ///
 /// We need to obtain the queries needed before applying reductions or transformations.
@@ -241,10 +352,16 @@ pub struct LpnOracle {
     pub delta: f64,
     /// The bias of the secret
     pub delta_s: f64,
+    /// The Hamming weight of the secret, if it was chosen to be sparse
+    ///
+    /// Set by [`LpnOracle::with_sparse_secret`], reductions don't update it.
+    pub sparse_weight: Option<usize>,
     /// The transformation matrix used by the sparse secret reduction
     pub(crate) sparse_transform_matrix: Option<BinMatrix>,
     /// The vector used by the sparse secret reduction
     pub(crate) sparse_transform_vector: Option<BinVector>,
+    /// The distribution of the noise of new samples
+    noise_model: NoiseModel,
 }
 
 impl LpnOracle {
@@ -273,11 +390,37 @@ impl LpnOracle {
             k,
             delta: 1f64 - 2f64 * tau,
             delta_s: 0f64, // uniformly random
+            sparse_weight: None,
             sparse_transform_matrix: None,
             sparse_transform_vector: None,
+            noise_model: NoiseModel::Bernoulli(tau),
         }
     }
 
+    /// Create a new LPN problem with secret `secret` of length `k`
+    ///
+    /// Apart from the secret, this is the same as [`LpnOracle::new`].
+    pub fn from_secret(k: usize, tau: f64, secret: BinVector) -> LpnOracle {
+        assert_eq!(secret.len(), k, "The secret should have length k");
+        Self::new_with_secret(Sample::from_binvector(&secret, false), k as u32, tau)
+    }
+
+    /// Create a new LPN problem with a random secret of Hamming weight `weight`
+    ///
+    /// The `weight` positions of the ones are chosen uniformly. The bias of the secret
+    /// `delta_s` is set to $1 - 2w/k$ and the weight is recorded in `sparse_weight`.
+    pub fn with_sparse_secret(k: usize, tau: f64, weight: usize, rng: &mut impl Rng) -> LpnOracle {
+        assert!(weight <= k, "The weight can be at most k");
+        let mut secret = BinVector::from_elem(k, false);
+        for position in rand::seq::index::sample(rng, k, weight) {
+            secret.set(position, true);
+        }
+        let mut oracle = Self::from_secret(k, tau, secret);
+        oracle.delta_s = 1.0 - 2.0 * weight as f64 / k as f64;
+        oracle.sparse_weight = Some(weight);
+        oracle
+    }
+
     /// Create a new LPN problem with a set secret
     pub fn new_with_secret(secret: Sample, k: u32, tau: f64) -> LpnOracle {
         let mut lpn = Self::new(k, tau);
@@ -285,6 +428,27 @@ impl LpnOracle {
         lpn
     }
 
+    /// The distribution of the noise of new samples
+    pub fn noise_model(&self) -> &NoiseModel {
+        &self.noise_model
+    }
+
+    /// Draw the noise of new samples from `noise_model`
+    ///
+    /// Samples already held by the oracle are kept. If the bias of the model is known,
+    /// `delta` is set to it, otherwise it is left to the caller.
+    pub fn set_noise_model(&mut self, noise_model: NoiseModel) {
+        match noise_model {
+            NoiseModel::Bernoulli(tau) => assert!((0.0..=1.0).contains(&tau), "0 <= tau <= 1"),
+            NoiseModel::Gaussian { sigma } => assert!(sigma > 0.0, "sigma should be positive"),
+            NoiseModel::Custom(_) => {}
+        }
+        if let Some(delta) = noise_model.bias() {
+            self.delta = delta;
+        }
+        self.noise_model = noise_model;
+    }
+
     /// Get new samples from the oracle
     ///
     /// These samples are stored in ``oracle.samples``
@@ -297,11 +461,57 @@ impl LpnOracle {
         self.samples.extend(input_samples);
     }
 
+    /// Get `n` new samples that are determined by `seed`
+    ///
+    /// Like [`LpnOracle::get_samples`] this uses parallelism, but every chunk of
+    /// `SEEDED_CHUNK_SIZE` samples is drawn from its own stream of a `ChaCha8Rng`
+    /// seeded with `seed`. For the same secret and noise rate the samples are the same
+    /// in every run, independent of the number of threads.
+    pub fn get_samples_seeded(&mut self, n: usize, seed: u64) {
+        let k = self.k;
+        let noise = &self.noise_model;
+        let secret = &self.secret;
+        let start = self.samples.len();
+        self.samples.resize(start + n, Sample::new());
+        self.samples[start..]
+            .par_chunks_mut(SEEDED_CHUNK_SIZE)
+            .enumerate()
+            .for_each(|(stream, chunk)| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(stream as u64);
+                for sample in chunk {
+                    *sample = Sample { sample: rng.gen() };
+                    sample.truncate(k, true);
+                    let product = sample.vector_product(secret, k) ^ noise.sample(&mut rng);
+                    sample.set_product(product);
+                }
+            });
+    }
+
+    /// Get new samples that expire `ttl` from now
+    ///
+    /// Unlike `get_samples`, the samples are not stored in the oracle,
+    /// but returned together with their expiry time.
+    pub fn get_samples_with_expiry(&self, n: usize, ttl: Duration) -> Vec<(Sample, Instant)> {
+        let mut samples = Vec::with_capacity(n);
+        self.get_some_samples(&mut samples, n);
+        let expiry = Instant::now() + ttl;
+        samples.into_iter().map(|q| (q, expiry)).collect()
+    }
+
+    /// Keep only the samples that have not yet expired
+    pub fn filter_fresh_samples(&self, samples: Vec<(Sample, Instant)>) -> Vec<Sample> {
+        let now = Instant::now();
+        samples
+            .into_iter()
+            .filter_map(|(q, expiry)| if expiry > now { Some(q) } else { None })
+            .collect()
+    }
+
     fn get_some_samples(&self, result: &mut Vec<Sample>, n: usize) {
         let k = self.k as usize;
 
-        let tau = (1.0 - self.delta) / 2.0;
-        let dist = Bernoulli::new(tau).unwrap();
+        let noise = &self.noise_model;
         let secret = &self.secret;
 
         // allocate the space.
@@ -337,7 +547,7 @@ impl LpnOracle {
             samples
                 .par_iter_mut()
                 .for_each_init(lpn_thread_rng, |rng, sample| {
-                    let noise_bit = dist.sample(rng);
+                    let noise_bit = noise.sample(rng);
                     sample.sample[(block_offset(k) + 1)..SAMPLE_LEN]
                         .iter_mut()
                         .for_each(|block| *block = 0);
@@ -352,7 +562,7 @@ impl LpnOracle {
                 .par_iter_mut()
                 .for_each_init(lpn_thread_rng, |rng, sample| {
                     sample.sample[NOISE_BIT_BLOCK] &= (ONE << (k % bits_per_block())) - 1;
-                    let noise_bit = dist.sample(rng);
+                    let noise_bit = noise.sample(rng);
                     let product = sample.vector_product(&secret, k) ^ noise_bit;
                     if product {
                         sample.sample[NOISE_BIT_BLOCK] |= ONE << NOISE_BIT_IDX;
@@ -367,6 +577,317 @@ impl LpnOracle {
         }
     }
 
+    /// Construct the query matrix $A$, which has the query vectors of the samples as rows
+    ///
+    /// The resulting matrix has dimensions `(samples.len(), k)`.
+    /// Panics if the oracle holds no samples.
+    pub fn as_query_matrix(&self) -> BinMatrix {
+        BinMatrix::from_slices(
+            &self
+                .samples
+                .iter()
+                .map(|q| q.get_sample())
+                .collect::<Vec<&[StorageBlock]>>(),
+            self.k,
+        )
+    }
+
+    /// Construct the vector $b$ of the noisy products of the samples
+    pub fn as_product_vector(&self) -> BinVector {
+        BinVector::from_bools(
+            &self
+                .samples
+                .iter()
+                .map(|q| q.get_product())
+                .collect::<Vec<bool>>(),
+        )
+    }
+
+    /// Construct an LPN problem from the matrix form $A s + e = b$
+    ///
+    /// The rows of `a` are taken as query vectors and `b` holds the corresponding products.
+    pub fn from_matrix(
+        k: usize,
+        noise_rate: f64,
+        a: BinMatrix,
+        b: BinVector,
+        secret: BinVector,
+    ) -> LpnOracle {
+        assert_eq!(a.ncols(), k, "A should have k columns");
+        assert_eq!(
+            a.nrows(),
+            b.len(),
+            "A and b should have the same number of rows"
+        );
+        assert_eq!(secret.len(), k, "The secret should have length k");
+        let mut oracle =
+            Self::new_with_secret(Sample::from_binvector(&secret, false), k as u32, noise_rate);
+        oracle.samples = (0..a.nrows())
+            .map(|row| {
+                let mut sample = Sample::new();
+                for block in 0..blocks_required(k) {
+                    sample.sample[block] = unsafe { a.get_word_unchecked(row, block) };
+                }
+                sample.truncate(k, true);
+                sample.set_product(b.get(row).unwrap());
+                sample
+            })
+            .collect();
+        oracle
+    }
+
+    /// Decompose the query matrix $A$ of rank $r$ into $A = C B$
+    ///
+    /// Returns the $r \times k$ matrix $B$ whose rows are a basis of the row space of $A$,
+    /// and the $n \times r$ factor $C$ holding the coordinates of every query vector in
+    /// that basis. The basis is built from the query vectors in order of the samples, every
+    /// query vector that is not in the span of the earlier ones is reduced and added.
+    /// Storing $C$ and $B$ takes $(n + k) r$ instead of $n k$ bits, which pays off for
+    /// $k \gg \mathrm{rank}(A)$.
+    ///
+    /// Panics if the oracle holds no samples or all query vectors are zero.
+    pub fn compress_query_matrix(&self) -> (BinMatrix, BinMatrix) {
+        assert!(!self.samples.is_empty(), "Need samples to compress");
+        // the basis vectors with their pivots, every vector is zero in the earlier pivots
+        let mut basis: Vec<(usize, BinVector)> = Vec::new();
+        let mut coordinates = self
+            .samples
+            .iter()
+            .map(|q| {
+                let mut query = q.as_binvector(self.k);
+                let mut coordinate = BinVector::from_elem(basis.len(), false);
+                for (i, (pivot, vector)) in basis.iter().enumerate() {
+                    if query[*pivot] {
+                        query += vector;
+                        coordinate.set(i, true);
+                    }
+                }
+                if let Some(pivot) = query.iter_set_bits(..).next() {
+                    basis.push((pivot, query));
+                    coordinate.push(true);
+                }
+                coordinate
+            })
+            .collect::<Vec<BinVector>>();
+        assert!(!basis.is_empty(), "All query vectors are zero");
+        let rank = basis.len();
+        coordinates
+            .iter_mut()
+            .for_each(|coordinate| coordinate.resize(rank, false));
+        (
+            BinMatrix::new(basis.into_iter().map(|(_, vector)| vector).collect()),
+            BinMatrix::new(coordinates),
+        )
+    }
+
+    /// Reconstruct the query matrix from the output of [`compress_query_matrix`]
+    ///
+    /// [`compress_query_matrix`]: LpnOracle::compress_query_matrix
+    pub fn decompress_query_matrix(basis: &BinMatrix, factor: &BinMatrix) -> BinMatrix {
+        factor * basis
+    }
+
+    /// Check the `index`-th sample against the codeword nearest to its query vector
+    ///
+    /// The query vector $a$ is decoded to the codeword $c$, after which we check if the
+    /// product of the sample equals $\langle c, s \rangle$. This holds for all noiseless
+    /// samples of which the query vector is a codeword.
+    #[cfg(feature = "codes")]
+    pub fn validate_sample_syndrome(
+        &self,
+        index: usize,
+        code: &dyn crate::codes::BinaryCode,
+    ) -> bool {
+        assert_eq!(
+            code.length(),
+            self.k,
+            "The length of the code does not match the problem size!"
+        );
+        let sample = &self.samples[index];
+        let codeword = code
+            .decode_to_code(&sample.as_binvector(self.k))
+            .expect("Decoding failed");
+        &codeword * &self.secret.as_binvector(self.k) == sample.get_product()
+    }
+
+    /// Sum `num_pairs` random pairs of distinct samples and collect statistics
+    ///
+    /// The sum of two samples is again a sample for the same secret,
+    /// of which the noise is the sum of the two noise bits.
+    pub fn pairwise_xor_analysis(&self, num_pairs: usize, rng: &mut impl Rng) -> PairwiseStats {
+        assert!(self.samples.len() >= 2, "Need at least two samples");
+        let n = self.samples.len();
+        let (mut weight, mut noisy) = (0u64, 0usize);
+        for _ in 0..num_pairs {
+            let i = rng.gen_range(0..n);
+            let j = (i + rng.gen_range(1..n)) % n;
+            let mut sum = self.samples[i].clone();
+            sum.xor_into(&self.samples[j]);
+            weight += u64::from(sum.count_ones());
+            if sum.vector_product(&self.secret, self.k) != sum.get_product() {
+                noisy += 1;
+            }
+        }
+        let tau = (1.0 - self.delta) / 2.0;
+        PairwiseStats {
+            mean_query_weight: weight as f64 / num_pairs as f64,
+            product_ones_fraction: noisy as f64 / num_pairs as f64,
+            expected_noise_rate: 2.0 * tau * (1.0 - tau),
+        }
+    }
+
+    /// Detect correlations between query vectors of samples at distance `lag`
+    ///
+    /// Row `lag - 1` of the result, for `lag` in `1..=max_lag`, has a one in column `shift`
+    /// if bit $j$ of $a_i$ and bit $j + \mathrm{shift}$ of $a_{i + \mathrm{lag}}$ are
+    /// correlated, i.e. if the XOR of these bits over all $i$ and $j$ has a bias of more
+    /// than $5 / \sqrt{n - \mathrm{lag}}$. The bits within a query need not be independent,
+    /// so this only counts the pairs of queries as independent trials.
+    /// Independent random queries give the zero matrix. If every query is the previous
+    /// one shifted by one position, as for a Toeplitz matrix, column `lag` is set in
+    /// every row.
+    pub fn row_correlation_matrix(&self, max_lag: usize) -> BinMatrix {
+        assert!(max_lag > 0, "Need at least lag one");
+        assert!(
+            self.samples.len() > max_lag,
+            "Need more than max_lag samples"
+        );
+        let queries: Vec<Vec<bool>> = self
+            .samples
+            .iter()
+            .map(|q| q.as_binvector(self.k).iter().collect())
+            .collect();
+        let rows = (1..=max_lag)
+            .map(|lag| {
+                let pairs = queries.len() - lag;
+                let correlated = (0..self.k)
+                    .map(|shift| {
+                        let overlap = self.k - shift;
+                        let agreeing: usize = queries
+                            .iter()
+                            .zip(queries.iter().skip(lag))
+                            .map(|(a, b)| {
+                                a[..overlap]
+                                    .iter()
+                                    .zip(b[shift..].iter())
+                                    .filter(|(x, y)| x == y)
+                                    .count()
+                            })
+                            .sum();
+                        let total = (pairs * overlap) as f64;
+                        let bias = (2.0 * agreeing as f64 / total - 1.0).abs();
+                        bias > 5.0 / (pairs as f64).sqrt()
+                    })
+                    .collect::<Vec<bool>>();
+                BinVector::from_bools(&correlated)
+            })
+            .collect();
+        BinMatrix::new(rows)
+    }
+
+    /// Amplify the samples of which the query vector is close to `target_pattern`
+    ///
+    /// Every sample whose query vector is `target_pattern`, or within Hamming distance 1
+    /// of it, is copied `amplification - 1` times, so it counts `amplification` times in
+    /// majority voting. Returns the number of samples added.
+    pub fn amplify_queries(&mut self, target_pattern: &BinVector, amplification: usize) -> usize {
+        assert_eq!(
+            target_pattern.len(),
+            self.k,
+            "The target pattern should have length k"
+        );
+        assert!(amplification > 0, "amplification should be at least 1");
+        let target = Sample::from_binvector(target_pattern, false);
+        let copies = self
+            .samples
+            .par_iter()
+            .filter(|q| {
+                let mut difference = (*q).clone();
+                difference.xor_into(&target);
+                difference.count_ones() <= 1
+            })
+            .flat_map_iter(|q| std::iter::repeat(q.clone()).take(amplification - 1))
+            .collect::<Vec<Sample>>();
+        let added = copies.len();
+        self.samples.extend(copies);
+        added
+    }
+
+    /// Compress the query vectors into `target_k` bits with column operations
+    ///
+    /// Eliminates the columns of the query matrix $A$ in random order: every column that
+    /// depends on the columns before it is cleared, the independent ones are moved to
+    /// the front. This gives an invertible $T$ such that $AT$ is zero beyond its first
+    /// $\mathrm{rank}(A)$ columns. The samples become $(aT, b)$ and the secret $T^{-1}s$,
+    /// as $\langle aT, T^{-1}s \rangle = \langle a, s \rangle$, after which the problem is
+    /// truncated to `target_k` bits. If the rank is larger than `target_k`, the samples
+    /// that still have bits set beyond `target_k` are dropped.
+    ///
+    /// Returns $T$. A secret $s'$ recovered from the compressed problem, padded with zeros
+    /// to length $k$, gives $Ts'$, which agrees with the secret on all query vectors.
+    pub fn compress_queries(&mut self, target_k: usize, rng: &mut impl Rng) -> BinMatrix {
+        let k = self.k;
+        assert!(0 < target_k && target_k <= k, "0 < target_k <= k");
+        let samples = mem::take(&mut self.samples);
+        let n = samples.len();
+        let queries: Vec<BinVector> = samples.par_iter().map(|q| q.as_binvector(k)).collect();
+
+        let mut order: Vec<usize> = (0..k).collect();
+        order.shuffle(rng);
+        // (pivot row, reduced column, the original columns it is the sum of)
+        let mut basis: Vec<(usize, BinVector, BinVector)> = Vec::with_capacity(k);
+        let mut dependent: Vec<BinVector> = Vec::new();
+        for &j in order.iter() {
+            let mut column =
+                BinVector::from_bools(&queries.iter().map(|q| q[j]).collect::<Vec<bool>>());
+            let mut combination = BinVector::from_elem(k, false);
+            combination.set(j, true);
+            for (pivot, reduced, reduced_combination) in basis.iter() {
+                if column[*pivot] {
+                    column += reduced;
+                    combination += reduced_combination;
+                }
+            }
+            match column.iter().position(|bit| bit) {
+                Some(pivot) => basis.push((pivot, column, combination)),
+                None => dependent.push(combination),
+            }
+        }
+        let rank = basis.len();
+        let transform = BinMatrix::new(
+            basis
+                .into_iter()
+                .map(|(_, _, combination)| combination)
+                .chain(dependent)
+                .collect(),
+        )
+        .transposed();
+
+        let secret = &self.secret.as_binvector(k) * &transform.inverted().transposed();
+        self.secret = Sample::from_binvector(&secret, false);
+        self.samples = samples
+            .into_par_iter()
+            .zip(queries.into_par_iter())
+            .filter_map(|(q, a)| {
+                let query = &a * &transform;
+                if (target_k..k).any(|i| query[i]) {
+                    None
+                } else {
+                    Some(Sample::from_binvector(&query, q.get_product()))
+                }
+            })
+            .collect();
+        log::info!(
+            "Compressed queries of rank {} into {} bits, {} of {} samples left",
+            rank,
+            target_k,
+            self.samples.len(),
+            n
+        );
+        self.truncate(target_k);
+        transform
+    }
+
     /// Get samples from the oracle with a trailing number of zero bits
     ///
     /// Uses parallelism
@@ -425,6 +946,75 @@ impl LpnOracle {
         self.k
     }
 
+    /// The noise rate $\tau = (1 - \delta) / 2$ that corresponds to the current bias
+    ///
+    /// The reductions update `delta` with their theoretical effect on the bias, so
+    /// after a few steps this shows how noisy the reduced problem is expected to be.
+    pub fn effective_noise(&self) -> f64 {
+        (1.0 - self.delta) / 2.0
+    }
+
+    /// Estimate the noise rate $\tau$ from the samples, without using the secret
+    ///
+    /// Two samples with the same query vector have products that differ with
+    /// probability $2\tau(1 - \tau)$, the noise rate of their sum, so $\delta^2$ is
+    /// estimated by $1 - 2m$ for the fraction $m$ of such pairs with different products.
+    /// At most `10_000` disjoint pairs are used. This needs repeated query vectors,
+    /// so it only works if the number of samples is at least about $2^{k/2}$. Returns
+    /// `None` if no two samples have the same query vector.
+    pub fn noise_estimate(&self) -> Option<f64> {
+        const MAX_PAIRS: usize = 10_000;
+        let mut products: FnvHashMap<Vec<StorageBlock>, bool> = FnvHashMap::default();
+        let (mut pairs, mut mismatches) = (0usize, 0usize);
+        for sample in &self.samples {
+            let mut query = sample.get_sample().to_vec();
+            query[NOISE_BIT_BLOCK] &= !NOISE_BIT_MASK;
+            // a query that was already seen is paired with it, after which it is free again
+            match products.remove(&query) {
+                Some(product) => {
+                    pairs += 1;
+                    if product != sample.get_product() {
+                        mismatches += 1;
+                    }
+                    if pairs == MAX_PAIRS {
+                        break;
+                    }
+                }
+                None => {
+                    products.insert(query, sample.get_product());
+                }
+            }
+        }
+        if pairs == 0 {
+            return None;
+        }
+        let mismatch_rate = mismatches as f64 / pairs as f64;
+        log::debug!(
+            "{} of {} pairs of equal queries have different products",
+            mismatches,
+            pairs
+        );
+        let delta = (1.0 - 2.0 * mismatch_rate).max(0.0).sqrt();
+        Some((1.0 - delta) / 2.0)
+    }
+
+    /// The fraction of the samples of which the product matches `candidate`
+    ///
+    /// For the secret this is about $1 - \tau = (1 + \delta) / 2$, for any other
+    /// candidate about $1/2$. This does not use `self.secret`, so it also checks
+    /// solutions for oracles of which the secret is not known.
+    pub fn verify_solution(&self, candidate: &BinVector) -> f64 {
+        assert_eq!(candidate.len(), self.k, "The candidate needs k bits");
+        assert!(!self.samples.is_empty(), "Need samples to verify against");
+        let candidate = Sample::from_binvector(candidate, false);
+        let matches = self
+            .samples
+            .par_iter()
+            .filter(|q| q.vector_product(&candidate, self.k) == q.get_product())
+            .count();
+        matches as f64 / self.samples.len() as f64
+    }
+
     /// Override what the value of k is, without running truncate.
     pub unsafe fn set_k(&mut self, new_k: usize) {
         self.k = new_k
@@ -473,6 +1063,291 @@ impl LpnOracle {
 
         self.secret.truncate(new_k, true);
     }
+
+    /// Keep only the first `n` samples
+    ///
+    /// Unlike [`LpnOracle::truncate`], this changes the number of samples rather than
+    /// their length. The secret and the biases are not affected.
+    pub fn truncate_samples(&mut self, n: usize) {
+        self.samples.truncate(n);
+    }
+
+    /// Split the samples into two oracles for the same problem
+    ///
+    /// The first oracle gets the first `(fraction * n) as usize` of the `n` samples and
+    /// the second one gets the rest, so the split only depends on the order of the
+    /// samples. This allows holding out samples to validate a solution, e.g. with
+    /// [`LpnOracle::validate_sample_syndrome`].
+    pub fn split(mut self, fraction: f64) -> (LpnOracle, LpnOracle) {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "The fraction should be in [0, 1]"
+        );
+        let at = (fraction * self.samples.len() as f64) as usize;
+        let rest = self.samples.split_off(at);
+        let second = LpnOracle {
+            samples: rest,
+            secret: self.secret.clone(),
+            k: self.k,
+            delta: self.delta,
+            delta_s: self.delta_s,
+            sparse_weight: self.sparse_weight,
+            sparse_transform_matrix: self.sparse_transform_matrix.clone(),
+            sparse_transform_vector: self.sparse_transform_vector.clone(),
+            noise_model: self.noise_model.clone(),
+        };
+        (self, second)
+    }
+
+    /// Write this oracle, including its samples and secret, to `path` as JSON
+    ///
+    /// A [`NoiseModel::Custom`] can't be written, so oracles with one are rejected with
+    /// [`io::ErrorKind::InvalidInput`] before `path` is created.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let NoiseModel::Custom(_) = self.noise_model {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Can't save an oracle with a custom noise model",
+            ));
+        }
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Read an oracle written by [`LpnOracle::save`]
+    ///
+    /// New samples get noise from the saved noise model.
+    /// Files written in another format version or by a build with a different
+    /// `MAX_K` are rejected with [`io::ErrorKind::InvalidData`].
+    pub fn load(path: &Path) -> io::Result<LpnOracle> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Read the samples of a problem of size `k` and bias `delta` from a CSV file
+    ///
+    /// Every line holds `k` comma-separated query bits followed by the product bit,
+    /// e.g. `0,1,1,0,1`. Empty lines are skipped. The secret is not known, so it is set
+    /// to zero. Lines with a different number of fields or with fields other than `0`
+    /// and `1` are rejected with [`io::ErrorKind::InvalidData`], as is a `k` outside of
+    /// `0 < k < MAX_K`. New samples get Bernoulli noise with bias `delta`.
+    pub fn from_csv(path: &Path, k: usize, delta: f64) -> io::Result<LpnOracle> {
+        if k == 0 || k >= MAX_K {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Need 0 < k < MAX_K, max k for this build: {}", MAX_K),
+            ));
+        }
+        let invalid = |line: usize, message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", line, message),
+            )
+        };
+
+        let mut samples = Vec::new();
+        for (idx, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let bits = line
+                .split(',')
+                .map(|field| match field.trim() {
+                    "0" => Ok(false),
+                    "1" => Ok(true),
+                    other => Err(invalid(idx + 1, format!("invalid bit {:?}", other))),
+                })
+                .collect::<io::Result<Vec<bool>>>()?;
+            if bits.len() != k + 1 {
+                return Err(invalid(
+                    idx + 1,
+                    format!("expected {} fields, found {}", k + 1, bits.len()),
+                ));
+            }
+            samples.push(Sample::from_binvector(
+                &BinVector::from_bools(&bits[..k]),
+                bits[k],
+            ));
+        }
+
+        Ok(LpnOracle {
+            samples,
+            secret: Sample::new(),
+            k,
+            delta,
+            delta_s: 0f64,
+            sparse_weight: None,
+            sparse_transform_matrix: None,
+            sparse_transform_vector: None,
+            noise_model: NoiseModel::Bernoulli((1.0 - delta) / 2.0),
+        })
+    }
+
+    /// Turn this oracle into a stream of samples
+    ///
+    /// The stream first yields the samples held by this oracle and then draws fresh
+    /// samples one at a time, so it never holds more samples than the oracle did.
+    pub fn into_streaming(self) -> StreamingLpnOracle {
+        StreamingLpnOracle {
+            k: self.k,
+            delta: self.delta,
+            secret: self.secret,
+            buffered: self.samples.into_iter(),
+            noise: self.noise_model,
+            rng: lpn_thread_rng(),
+        }
+    }
+}
+
+/// An LPN oracle that produces samples on demand, see [`LpnOracle::into_streaming`]
+///
+/// This is an endless iterator of samples, to be consumed by algorithms that look
+/// at every sample only once, like [`crate::bkw::bkw_lazy`].
+pub struct StreamingLpnOracle {
+    /// The size of this problem
+    k: usize,
+    /// The bias of the samples
+    pub delta: f64,
+    /// The secret of this problem
+    pub secret: Sample,
+    /// Samples taken over from the [`LpnOracle`], yielded before any new ones
+    buffered: std::vec::IntoIter<Sample>,
+    noise: NoiseModel,
+    rng: ThreadRng,
+}
+
+impl StreamingLpnOracle {
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// Draw a fresh sample
+    fn new_sample(&mut self) -> Sample {
+        let mut sample = Sample {
+            sample: self.rng.gen(),
+        };
+        sample.truncate(self.k, true);
+        let product =
+            sample.vector_product(&self.secret, self.k) ^ self.noise.sample(&mut self.rng);
+        sample.set_product(product);
+        sample
+    }
+}
+
+impl Iterator for StreamingLpnOracle {
+    type Item = Sample;
+
+    fn next(&mut self) -> Option<Sample> {
+        Some(match self.buffered.next() {
+            Some(sample) => sample,
+            None => self.new_sample(),
+        })
+    }
+}
+
+/// Version of the layout written by [`LpnOracle::save`]
+///
+/// Increase this whenever the fields of [`OracleFile`] change.
+const ORACLE_FILE_VERSION: u32 = 2;
+
+/// The on-disk form of the [`NoiseModel`] of an [`LpnOracle`]
+#[derive(Serialize, Deserialize)]
+enum NoiseModelFile {
+    Bernoulli(f64),
+    Gaussian { sigma: f64 },
+}
+
+/// The on-disk layout of an [`LpnOracle`]
+///
+/// `BinMatrix` can't be deserialized, so the sparse transformation matrix is stored by rows.
+#[derive(Serialize, Deserialize)]
+struct OracleFile<S> {
+    version: u32,
+    max_k: usize,
+    k: usize,
+    delta: f64,
+    delta_s: f64,
+    sparse_weight: Option<usize>,
+    secret: Sample,
+    samples: S,
+    sparse_transform_matrix: Option<Vec<BinVector>>,
+    sparse_transform_vector: Option<BinVector>,
+    noise_model: NoiseModelFile,
+}
+
+impl Serialize for LpnOracle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let sparse_transform_matrix = self.sparse_transform_matrix.as_ref().map(|m| {
+            (0..m.nrows())
+                .map(|i| {
+                    BinVector::from_bools(
+                        &(0..m.ncols()).map(|j| m.bit(i, j)).collect::<Vec<bool>>(),
+                    )
+                })
+                .collect()
+        });
+        let noise_model = match self.noise_model {
+            NoiseModel::Bernoulli(tau) => NoiseModelFile::Bernoulli(tau),
+            NoiseModel::Gaussian { sigma } => NoiseModelFile::Gaussian { sigma },
+            NoiseModel::Custom(_) => {
+                return Err(ser::Error::custom(
+                    "Can't save an oracle with a custom noise model",
+                ))
+            }
+        };
+        OracleFile {
+            version: ORACLE_FILE_VERSION,
+            max_k: MAX_K,
+            k: self.k,
+            delta: self.delta,
+            delta_s: self.delta_s,
+            sparse_weight: self.sparse_weight,
+            secret: self.secret.clone(),
+            samples: &self.samples,
+            sparse_transform_matrix,
+            sparse_transform_vector: self.sparse_transform_vector.clone(),
+            noise_model,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LpnOracle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let file = OracleFile::<Vec<Sample>>::deserialize(deserializer)?;
+        if file.version != ORACLE_FILE_VERSION {
+            return Err(de::Error::custom(format!(
+                "Unsupported oracle file version {}, this build reads version {}",
+                file.version, ORACLE_FILE_VERSION
+            )));
+        }
+        if file.max_k != MAX_K {
+            return Err(de::Error::custom(format!(
+                "Oracle was saved with MAX_K={}, but this build has MAX_K={}",
+                file.max_k, MAX_K
+            )));
+        }
+        if file.k == 0 || file.k >= MAX_K {
+            return Err(de::Error::custom(format!("Invalid k={}", file.k)));
+        }
+        Ok(LpnOracle {
+            samples: file.samples,
+            secret: file.secret,
+            k: file.k,
+            delta: file.delta,
+            delta_s: file.delta_s,
+            sparse_weight: file.sparse_weight,
+            sparse_transform_matrix: file.sparse_transform_matrix.map(BinMatrix::new),
+            sparse_transform_vector: file.sparse_transform_vector,
+            noise_model: match file.noise_model {
+                NoiseModelFile::Bernoulli(tau) => NoiseModel::Bernoulli(tau),
+                NoiseModelFile::Gaussian { sigma } => NoiseModel::Gaussian { sigma },
+            },
+        })
+    }
 }
 
 #[inline]
@@ -588,10 +1463,590 @@ mod test {
         }
     }
 
+    #[test]
+    fn matrix_round_trip() {
+        let mut oracle = LpnOracle::new(100, 1.0 / 8.0);
+        oracle.get_samples(1000);
+        let a = oracle.as_query_matrix();
+        let b = oracle.as_product_vector();
+        assert_eq!((a.nrows(), a.ncols()), (1000, 100));
+        assert_eq!(b.len(), 1000);
+
+        let secret = oracle.secret.as_binvector(100);
+        let copy = LpnOracle::from_matrix(100, 1.0 / 8.0, a, b, secret);
+        assert_eq!(copy.get_k(), oracle.get_k());
+        assert_eq!(copy.secret, oracle.secret);
+        assert_eq!(copy.samples, oracle.samples);
+    }
+
+    #[test]
+    fn compress_query_matrix() {
+        // a full-rank problem of small k
+        let mut oracle = LpnOracle::new(12, 1.0 / 16.0);
+        oracle.get_samples(50_000);
+        let (basis, factor) = oracle.compress_query_matrix();
+        assert_eq!((basis.nrows(), basis.ncols()), (12, 12));
+        assert_eq!((factor.nrows(), factor.ncols()), (50_000, 12));
+        let a = LpnOracle::decompress_query_matrix(&basis, &factor);
+        assert_eq!(a, oracle.as_query_matrix());
+
+        let secret = oracle.secret.as_binvector(12);
+        let decompressed =
+            LpnOracle::from_matrix(12, 1.0 / 16.0, a, oracle.as_product_vector(), secret);
+        assert_eq!(
+            crate::bkw::bkw(decompressed, 2, 6),
+            crate::bkw::bkw(oracle, 2, 6)
+        );
+
+        // queries from a subspace of rank 8 in a problem with k = 100
+        let generators = BinMatrix::random(8, 100);
+        let mut oracle = LpnOracle::new(100, 1.0 / 16.0);
+        oracle.samples = (0..10_000)
+            .map(|_| {
+                let query = &BinVector::random(8) * &generators;
+                let product = &query * &oracle.secret.as_binvector(100);
+                Sample::from_binvector(&query, product)
+            })
+            .collect();
+        let (basis, factor) = oracle.compress_query_matrix();
+        let rank = generators.rank();
+        assert_eq!(basis.nrows(), rank);
+        assert_eq!(factor.ncols(), rank);
+        assert_eq!(
+            LpnOracle::decompress_query_matrix(&basis, &factor),
+            oracle.as_query_matrix()
+        );
+        let compressed_bits = (factor.nrows() + basis.ncols()) * rank;
+        let uncompressed_bits = oracle.samples.len() * oracle.get_k();
+        assert!(compressed_bits * 10 < uncompressed_bits);
+    }
+
+    #[cfg(feature = "hamming")]
+    #[test]
+    fn validate_sample_syndrome() {
+        use crate::codes::{BinaryCode, HammingCode7_4};
+        let code = HammingCode7_4;
+        let secret = BinVector::random(7);
+        let codewords = (0..999)
+            .map(|_| code.encode(&BinVector::random(4)))
+            .collect::<Vec<_>>();
+        let products = codewords.iter().map(|c| c * &secret).collect::<Vec<bool>>();
+        let mut oracle = LpnOracle::from_matrix(
+            7,
+            0.0,
+            BinMatrix::new(codewords),
+            BinVector::from_bools(&products),
+            secret,
+        );
+        assert!((0..999).all(|i| oracle.validate_sample_syndrome(i, &code)));
+
+        // flip every third sample
+        for sample in oracle.samples.iter_mut().step_by(3) {
+            let product = sample.get_product();
+            sample.set_product(!product);
+        }
+        let failed = (0..999)
+            .filter(|&i| !oracle.validate_sample_syndrome(i, &code))
+            .collect::<Vec<usize>>();
+        assert_eq!(failed, (0..999).step_by(3).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn pairwise_xor_analysis() {
+        let mut rng = rand::thread_rng();
+        for &tau in &[0.05, 1.0 / 8.0, 0.25] {
+            let mut oracle = LpnOracle::new(64, tau);
+            oracle.get_samples(50_000);
+            let stats = oracle.pairwise_xor_analysis(100_000, &mut rng);
+            assert!((stats.expected_noise_rate - 2.0 * tau * (1.0 - tau)).abs() < 1e-9);
+            assert!(
+                (stats.product_ones_fraction - stats.expected_noise_rate).abs() < 0.02,
+                "{:?}",
+                stats
+            );
+            // the sum of two uniform queries is uniform
+            assert!((stats.mean_query_weight - 32.0).abs() < 0.5, "{:?}", stats);
+        }
+    }
+
+    #[test]
+    fn samples_with_expiry() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 32.0);
+        let short = oracle.get_samples_with_expiry(10_000, Duration::from_secs(1));
+        assert_eq!(oracle.filter_fresh_samples(short.clone()).len(), 10_000);
+        let long = oracle.get_samples_with_expiry(400_000, Duration::from_secs(3600));
+
+        std::thread::sleep(Duration::from_millis(1100));
+        assert!(oracle.filter_fresh_samples(short.clone()).is_empty());
+
+        let mixed = short.into_iter().chain(long).collect();
+        oracle.samples = oracle.filter_fresh_samples(mixed);
+        assert_eq!(oracle.samples.len(), 400_000);
+        let mut secret = oracle.secret.as_binvector(32);
+        let solution = crate::bkw::bkw(oracle, 4, 8);
+        secret.truncate(solution.len());
+        assert_eq!(solution, secret);
+    }
+
+    #[test]
+    fn amplify_queries() {
+        let k = 8;
+        let mut oracle = LpnOracle::new(k as u32, 1.0 / 4.0);
+        oracle.get_samples(20_000);
+        let target = Sample::from_binvector(&BinVector::from_function(8, |i| i == 0), false);
+        let close = |q: &Sample| {
+            let mut difference = q.clone();
+            difference.xor_into(&target);
+            difference.count_ones() <= 1
+        };
+        let matching = oracle.samples.iter().filter(|q| close(q)).count();
+        let num_samples = oracle.samples.len();
+
+        // the majority vote for the first secret bit, as confidence |count - 2 * sum|
+        let confidence = |oracle: &LpnOracle| {
+            let (count, sum) = oracle
+                .samples
+                .iter()
+                .filter(|q| q.get_block(0) == 1)
+                .fold((0i64, 0i64), |(count, sum), q| {
+                    (count + 1, sum + q.get_product() as i64)
+                });
+            (
+                (count < 2 * sum) == oracle.secret.as_binvector(8)[0],
+                (count - 2 * sum).abs(),
+            )
+        };
+        let (correct_before, confidence_before) = confidence(&oracle);
+
+        let added = oracle.amplify_queries(&target.as_binvector(k), 5);
+        assert_eq!(added, 4 * matching);
+        assert_eq!(oracle.samples.len(), num_samples + added);
+        assert_eq!(
+            oracle.samples.iter().filter(|q| close(q)).count(),
+            5 * matching
+        );
+
+        let (correct_after, confidence_after) = confidence(&oracle);
+        assert!(correct_before && correct_after);
+        assert_eq!(confidence_after, 5 * confidence_before);
+    }
+
     #[test]
     fn test_from_binvec() {
         let binvec = BinVector::from_bytes(&[0b001000]);
         let sample = Sample::from_binvector(&binvec, false);
         assert_eq!(sample.get_block(0) as usize, binvec.get_storage()[0]);
     }
+
+    #[test]
+    fn compress_queries() {
+        use crate::fwht::fwht_solve;
+        let (k, rank, n) = (96, 20, 100_000);
+        let mut rng = rand::thread_rng();
+
+        // queries from a subspace of dimension 20
+        let subspace = BinMatrix::random(rank, k);
+        let a = &BinMatrix::random(n, rank) * &subspace;
+        let secret = BinVector::random(k);
+        let noise = rand::distributions::Bernoulli::new(1.0 / 16.0).unwrap();
+        let b = &(&a * &secret)
+            + &BinVector::from_bools(
+                &(0..n)
+                    .map(|_| noise.sample(&mut rng))
+                    .collect::<Vec<bool>>(),
+            );
+        let mut oracle = LpnOracle::from_matrix(k, 1.0 / 16.0, a, b, secret.clone());
+
+        let transform = oracle.compress_queries(rank, &mut rng);
+        assert_eq!((transform.nrows(), transform.ncols()), (k, k));
+        assert_eq!(transform.rank(), k);
+        assert_eq!(oracle.get_k(), rank);
+        assert_eq!(oracle.samples.len(), n);
+        let compressed_secret = oracle.secret.as_binvector(rank);
+        let noisy = oracle
+            .samples
+            .iter()
+            .filter(|q| &q.as_binvector(rank) * &compressed_secret != q.get_product())
+            .count();
+        assert!((noisy as f64 / n as f64 - 1.0 / 16.0).abs() < 0.01);
+
+        // the compressed problem is small enough to solve all bits with the FWHT,
+        // the secret is only determined on the subspace
+        let mut recovered = fwht_solve(oracle);
+        assert_eq!(recovered, compressed_secret);
+        recovered.extend_from_binvec(&BinVector::from_elem(k - rank, false));
+        let recovered = &transform * &recovered;
+        assert_eq!(&subspace * &recovered, &subspace * &secret);
+    }
+
+    #[test]
+    fn row_correlation_matrix() {
+        let k = 64;
+        let max_lag = 4;
+        let mut oracle = LpnOracle::new(k as u32, 1.0 / 8.0);
+        oracle.get_samples(2000);
+        let correlations = oracle.row_correlation_matrix(max_lag);
+        assert_eq!((correlations.nrows(), correlations.ncols()), (max_lag, k));
+        assert_eq!(correlations, BinMatrix::zero(max_lag, k));
+
+        // Toeplitz: a_i[j] = t[n + j - i], so a_{i + lag}[j + lag] = a_i[j]
+        let n = 2000;
+        let t = BinVector::random(n + k);
+        let a = BinMatrix::new(
+            (0..n)
+                .map(|i| {
+                    BinVector::from_bools(&(0..k).map(|j| t[n + j - i]).collect::<Vec<bool>>())
+                })
+                .collect(),
+        );
+        let secret = oracle.secret.as_binvector(k);
+        let b = &a * &secret;
+        let toeplitz = LpnOracle::from_matrix(k, 0.0, a, b, secret);
+        let correlations = toeplitz.row_correlation_matrix(max_lag);
+        for lag in 1..=max_lag {
+            for shift in 0..k {
+                assert_eq!(correlations.bit(lag - 1, shift), shift == lag);
+            }
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut oracle = LpnOracle::new(100, 1.0 / 8.0);
+        oracle.get_samples(1000);
+        oracle.delta_s = 0.5;
+        oracle.sparse_transform_matrix = Some(BinMatrix::random(100, 100));
+        oracle.sparse_transform_vector = Some(BinVector::random(100));
+        let path = std::env::temp_dir().join(format!("lpn-oracle-{}.json", std::process::id()));
+        oracle.save(&path).unwrap();
+        let loaded = LpnOracle::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_eq!(loaded.get_k(), 100);
+        assert_eq!(loaded.samples, oracle.samples);
+        assert_eq!(loaded.secret, oracle.secret);
+        assert_eq!(loaded.delta, oracle.delta);
+        assert_eq!(loaded.delta_s, oracle.delta_s);
+        assert_eq!(loaded.sparse_weight, None);
+        assert_eq!(
+            loaded.sparse_transform_matrix,
+            oracle.sparse_transform_matrix
+        );
+        assert_eq!(
+            loaded.sparse_transform_vector,
+            oracle.sparse_transform_vector
+        );
+    }
+
+    #[test]
+    fn save_and_load_sparse_weight() {
+        let mut oracle = LpnOracle::with_sparse_secret(64, 1.0 / 8.0, 5, &mut rand::thread_rng());
+        oracle.get_samples(100);
+        let path =
+            std::env::temp_dir().join(format!("lpn-oracle-sparse-{}.json", std::process::id()));
+        oracle.save(&path).unwrap();
+        let loaded = LpnOracle::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_eq!(loaded.sparse_weight, Some(5));
+        assert_eq!(loaded.secret, oracle.secret);
+        assert_eq!(loaded.delta_s, oracle.delta_s);
+    }
+
+    #[test]
+    fn save_and_load_noise_model() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.set_noise_model(NoiseModel::Gaussian { sigma: 0.5 });
+        oracle.get_samples(1000);
+        crate::bkw::partition_reduce(&mut oracle, 8);
+        let path =
+            std::env::temp_dir().join(format!("lpn-oracle-noise-{}.json", std::process::id()));
+        oracle.save(&path).unwrap();
+        let loaded = LpnOracle::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        // the bias was reduced, but new samples still get the original noise
+        assert_eq!(loaded.delta, oracle.delta);
+        match loaded.noise_model() {
+            NoiseModel::Gaussian { sigma } => assert_eq!(*sigma, 0.5),
+            _ => panic!("The noise model should still be Gaussian"),
+        }
+
+        oracle.set_noise_model(NoiseModel::Custom(Arc::new(|_: &mut dyn RngCore| false)));
+        let error = oracle
+            .save(&path)
+            .err()
+            .expect("should reject custom noise");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn load_rejects_other_versions() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(10);
+        let mut json = serde_json::to_value(&oracle).unwrap();
+        json["version"] = serde_json::json!(ORACLE_FILE_VERSION + 1);
+        let path =
+            std::env::temp_dir().join(format!("lpn-oracle-version-{}.json", std::process::id()));
+        std::fs::write(&path, json.to_string()).unwrap();
+        let loaded = LpnOracle::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let error = loaded.err().expect("should reject a newer version");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("version"));
+    }
+
+    #[test]
+    fn truncate_samples() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(100);
+        let samples = oracle.samples.clone();
+        let delta = oracle.delta;
+        oracle.truncate_samples(200);
+        assert_eq!(oracle.samples, samples);
+        oracle.truncate_samples(10);
+        assert_eq!(oracle.samples, &samples[..10]);
+        assert_eq!(oracle.get_k(), 32);
+        assert_eq!(oracle.delta, delta);
+    }
+
+    #[test]
+    fn split() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(100);
+        let samples = oracle.samples.clone();
+        let secret = oracle.secret.clone();
+        let (train, validation) = oracle.split(0.75);
+        assert_eq!(train.samples, &samples[..75]);
+        assert_eq!(validation.samples, &samples[75..]);
+        for part in &[train, validation] {
+            assert_eq!(part.get_k(), 32);
+            assert_eq!(part.delta, 0.75);
+            assert_eq!(part.secret, secret);
+        }
+
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(10);
+        let (all, none) = oracle.split(1.0);
+        assert_eq!((all.samples.len(), none.samples.len()), (10, 0));
+    }
+
+    #[test]
+    fn samples_seeded() {
+        let mut oracle = LpnOracle::new(100, 1.0 / 8.0);
+        let mut other = oracle.clone();
+        oracle.get_samples_seeded(25_000, 42);
+        other.get_samples_seeded(25_000, 42);
+        assert_eq!(oracle.samples, other.samples);
+        other.get_samples_seeded(25_000, 43);
+        assert_ne!(oracle.samples, other.samples[25_000..]);
+
+        // the same distribution as `get_samples`
+        let secret = oracle.secret.clone();
+        let noisy = oracle
+            .samples
+            .iter()
+            .filter(|q| q.vector_product(&secret, 100) != q.get_product())
+            .count();
+        assert!((noisy as f64 / 25_000.0 - 1.0 / 8.0).abs() < 0.01);
+        let weight: u32 = oracle.samples.iter().map(Sample::hamming_weight).sum();
+        assert!((weight as f64 / 25_000.0 - 50.0).abs() < 0.5);
+        for q in oracle.samples.iter() {
+            assert_eq!(query_bits_range(q, 100..MAX_K.min(120)), 0);
+        }
+    }
+
+    #[test]
+    fn noise_models() {
+        let noise_rate = |oracle: &LpnOracle| {
+            let noisy = oracle
+                .samples
+                .iter()
+                .filter(|q| q.vector_product(&oracle.secret, 64) != q.get_product())
+                .count();
+            noisy as f64 / oracle.samples.len() as f64
+        };
+        let mut oracle = LpnOracle::new(64, 1.0 / 8.0);
+        assert!(matches!(oracle.noise_model(), NoiseModel::Bernoulli(tau) if *tau == 1.0 / 8.0));
+        oracle.get_samples(50_000);
+        assert!((noise_rate(&oracle) - 1.0 / 8.0).abs() < 0.01);
+
+        // the parity of a discrete Gaussian, almost uniform for large sigma
+        let bias = NoiseModel::Gaussian { sigma: 0.5 }.bias().unwrap();
+        let odd: f64 = [-1.0f64, 1.0].iter().map(|x| (-x * x / 0.5).exp()).sum();
+        let even: f64 = 1.0
+            + [-2.0f64, 2.0]
+                .iter()
+                .map(|x| (-x * x / 0.5).exp())
+                .sum::<f64>();
+        assert!((bias - (even - odd) / (even + odd)).abs() < 1e-6);
+        assert!(NoiseModel::Gaussian { sigma: 5.0 }.bias().unwrap().abs() < 1e-6);
+        let mut oracle = LpnOracle::new(64, 1.0 / 8.0);
+        oracle.set_noise_model(NoiseModel::Gaussian { sigma: 0.5 });
+        assert_eq!(oracle.delta, bias);
+        oracle.get_samples(50_000);
+        assert!((noise_rate(&oracle) - (1.0 - bias) / 2.0).abs() < 0.01);
+
+        // the delta of custom noise is left alone
+        let mut oracle = LpnOracle::new(64, 1.0 / 8.0);
+        oracle.set_noise_model(NoiseModel::Custom(Arc::new(|rng: &mut dyn RngCore| {
+            rng.next_u32() % 4 == 0
+        })));
+        assert_eq!(oracle.delta, 0.75);
+        oracle.get_samples_seeded(50_000, 1);
+        assert!((noise_rate(&oracle) - 1.0 / 4.0).abs() < 0.01);
+        let streaming = oracle.into_streaming();
+        let secret = streaming.secret.clone();
+        let noisy = streaming
+            .skip(50_000)
+            .take(50_000)
+            .filter(|q| q.vector_product(&secret, 64) != q.get_product())
+            .count();
+        assert!((noisy as f64 / 50_000.0 - 1.0 / 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn sparse_secret() {
+        let mut rng = rand::thread_rng();
+        let mut oracle = LpnOracle::with_sparse_secret(64, 1.0 / 8.0, 5, &mut rng);
+        assert_eq!(oracle.get_k(), 64);
+        assert_eq!(oracle.secret.count_ones(), 5);
+        assert_eq!(oracle.sparse_weight, Some(5));
+        assert_eq!(oracle.delta_s, 1.0 - 10.0 / 64.0);
+        assert_eq!(oracle.delta, 0.75);
+        oracle.get_samples(1000);
+        let secret = oracle.secret.as_binvector(64);
+        assert!(oracle.verify_solution(&secret) > 0.8);
+
+        let (first, second) = oracle.split(0.5);
+        assert_eq!(first.sparse_weight, Some(5));
+        assert_eq!(second.sparse_weight, Some(5));
+        assert_eq!(LpnOracle::new(64, 1.0 / 8.0).sparse_weight, None);
+
+        let oracle = LpnOracle::with_sparse_secret(64, 1.0 / 8.0, 0, &mut rng);
+        assert_eq!(oracle.secret.count_ones(), 0);
+    }
+
+    #[test]
+    fn verify_solution() {
+        let mut oracle = LpnOracle::new(32, 1.0 / 8.0);
+        oracle.get_samples(10_000);
+        let secret = oracle.secret.as_binvector(32);
+        let fraction = oracle.verify_solution(&secret);
+        assert!(
+            (fraction - 7.0 / 8.0).abs() < 0.02,
+            "fraction: {}",
+            fraction
+        );
+        let mut wrong = secret.clone();
+        wrong.set(0, !wrong[0]);
+        let fraction = oracle.verify_solution(&wrong);
+        assert!((fraction - 0.5).abs() < 0.02, "fraction: {}", fraction);
+    }
+
+    #[test]
+    fn noise_estimate() {
+        let mut oracle = LpnOracle::new(8, 1.0 / 8.0);
+        oracle.get_samples(30_000);
+        let estimate = oracle.noise_estimate().unwrap();
+        assert!(
+            (estimate - 1.0 / 8.0).abs() < 0.02,
+            "estimate: {}",
+            estimate
+        );
+
+        let mut oracle = LpnOracle::new(8, 0.0);
+        oracle.get_samples(1000);
+        assert_eq!(oracle.noise_estimate(), Some(0.0));
+
+        // repeated queries of size 64 are too unlikely
+        let mut oracle = LpnOracle::new(64, 1.0 / 8.0);
+        oracle.get_samples(1000);
+        assert_eq!(oracle.noise_estimate(), None);
+    }
+
+    #[test]
+    fn streaming() {
+        let mut oracle = LpnOracle::new(100, 1.0 / 8.0);
+        oracle.get_samples(10);
+        let samples = oracle.samples.clone();
+        let secret = oracle.secret.clone();
+        let mut stream = oracle.into_streaming();
+        assert_eq!(stream.get_k(), 100);
+        assert_eq!(stream.by_ref().take(10).collect::<Vec<_>>(), samples);
+        let n = 10_000;
+        let noisy = stream
+            .take(n)
+            .inspect(|q| assert_eq!(query_bits_range(q, 100..MAX_K.min(120)), 0))
+            .filter(|q| q.vector_product(&secret, 100) != q.get_product())
+            .count();
+        assert!((noisy as f64 / n as f64 - 1.0 / 8.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn hamming_weight_excludes_product() {
+        let v = BinVector::from_bools(&(0..100).map(|i| i % 7 == 0).collect::<Vec<bool>>());
+        let sample = Sample::from_binvector(&v, true);
+        assert_eq!(sample.hamming_weight(), v.count_ones());
+        assert_eq!(sample.count_ones(), sample.hamming_weight());
+        assert_eq!(
+            Sample::from_binvector(&v, false).hamming_weight(),
+            v.count_ones()
+        );
+    }
+
+    #[test]
+    fn is_unit_vector() {
+        let mut v = BinVector::from_elem(120, false);
+        assert!(!Sample::from_binvector(&v, true).is_unit_vector());
+        for i in &[0, 63, 64, 119] {
+            v.set(*i, true);
+            assert!(Sample::from_binvector(&v, true).is_unit_vector());
+            assert!(Sample::from_binvector(&v, false).is_unit_vector());
+            v.set(*i, false);
+        }
+        v.set(3, true);
+        v.set(5, true);
+        assert!(!Sample::from_binvector(&v, false).is_unit_vector());
+        v.set(5, false);
+        v.set(70, true);
+        assert!(!Sample::from_binvector(&v, false).is_unit_vector());
+    }
+
+    #[test]
+    fn from_csv() {
+        let path = std::env::temp_dir().join(format!("lpn-oracle-{}.csv", std::process::id()));
+        std::fs::write(&path, "0,1,1,0,1\n1, 0,0,1,0\n\n1,1,1,1,1\n").unwrap();
+        let oracle = LpnOracle::from_csv(&path, 4, 0.75);
+        let too_long = LpnOracle::from_csv(&path, 3, 0.75);
+        let too_short = LpnOracle::from_csv(&path, 5, 0.75);
+        let too_large = LpnOracle::from_csv(&path, MAX_K, 0.75);
+        std::fs::remove_file(&path).unwrap();
+
+        let oracle = oracle.unwrap();
+        assert_eq!(oracle.get_k(), 4);
+        assert_eq!(oracle.delta, 0.75);
+        assert_eq!(oracle.secret.as_binvector(4).count_ones(), 0);
+        assert_eq!(oracle.samples.len(), 3);
+        assert_eq!(
+            oracle.samples[0].as_binvector(4),
+            BinVector::from_bools(&[false, true, true, false])
+        );
+        assert!(oracle.samples[0].get_product());
+        assert!(!oracle.samples[1].get_product());
+        assert_eq!(oracle.samples[2].hamming_weight(), 4);
+
+        let error = too_long.err().expect("the rows have 5 fields");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("line 1"));
+        let error = too_short.err().expect("the rows have 5 fields");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("expected 6 fields"));
+        let error = too_large.err().expect("k is too large for this build");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
 }
@@ -0,0 +1,250 @@
+//! LPN with a secret that is a low-degree polynomial
+//!
+//! The secret is a polynomial $f$ of degree at most $d$ in $n$ variables, given by its
+//! coefficients in the monomial basis. A sample is the vector of monomial evaluations
+//! $(x^S)_{|S| \le d}$ of a uniform $x \in \mathbb{F}_2^n$, with product $f(x) + e$.
+//! This is an LPN instance with the coefficients as secret, but the queries have a lot
+//! of structure.
+use crate::{oracle::Sample, random::lpn_thread_rng};
+use fnv::FnvHashMap;
+use itertools::Itertools;
+use m4ri_rust::friendly::BinVector;
+use rand::distributions::{Bernoulli, Distribution};
+use rayon::prelude::*;
+
+/// LPN oracle for a secret polynomial of degree at most `degree`
+#[derive(Clone)]
+pub struct PolynomialSecretLpnOracle {
+    /// The samples held by this oracle, the monomial evaluations of a random point.
+    pub samples: Vec<Sample>,
+    /// The variables of every monomial, ordered by degree
+    monomials: Vec<Vec<usize>>,
+    coefficients: BinVector,
+    n: usize,
+    degree: usize,
+    tau: f64,
+}
+
+impl PolynomialSecretLpnOracle {
+    /// Create a new problem with a random polynomial in `n` variables
+    pub fn new(n: usize, degree: usize, noise_rate: f64) -> PolynomialSecretLpnOracle {
+        let k = Self::all_monomials(n, degree).len();
+        Self::with_coefficients(n, degree, BinVector::random(k), noise_rate)
+    }
+
+    /// Create a new problem with the polynomial with the given `coefficients`
+    ///
+    /// The coefficients are in the order of [`PolynomialSecretLpnOracle::get_monomials`].
+    /// The degree has to be at least one, otherwise the samples don't contain the points.
+    pub fn with_coefficients(
+        n: usize,
+        degree: usize,
+        coefficients: BinVector,
+        noise_rate: f64,
+    ) -> PolynomialSecretLpnOracle {
+        assert!((0.0..1.0).contains(&noise_rate), "0 <= noise_rate < 1");
+        assert!(0 < n && n <= 64, "0 < n <= 64");
+        assert!(degree >= 1, "degree >= 1");
+        let monomials = Self::all_monomials(n, degree);
+        assert!(
+            monomials.len() < crate::oracle::MAX_K,
+            "Too many monomials for this build: {}",
+            monomials.len()
+        );
+        assert_eq!(
+            coefficients.len(),
+            monomials.len(),
+            "Need a coefficient for every monomial"
+        );
+        PolynomialSecretLpnOracle {
+            samples: vec![],
+            monomials,
+            coefficients,
+            n,
+            degree,
+            tau: noise_rate,
+        }
+    }
+
+    /// All monomials of degree at most `degree`, as lists of their variables
+    fn all_monomials(n: usize, degree: usize) -> Vec<Vec<usize>> {
+        let mut monomials = vec![vec![]];
+        for d in 1..=degree.min(n) {
+            monomials.extend((0..n).combinations(d));
+        }
+        monomials
+    }
+
+    /// The size of the LPN problem: the number of monomials
+    pub fn get_k(&self) -> usize {
+        self.monomials.len()
+    }
+
+    /// The number of variables
+    pub fn get_n(&self) -> usize {
+        self.n
+    }
+
+    pub fn get_degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The monomials that correspond to the bits of the samples
+    ///
+    /// The constant comes first, the $n$ variables follow.
+    pub fn get_monomials(&self) -> &[Vec<usize>] {
+        &self.monomials
+    }
+
+    /// The coefficients of the secret polynomial
+    pub fn coefficients(&self) -> &BinVector {
+        &self.coefficients
+    }
+
+    /// Evaluate all monomials at `x`
+    pub fn expand(&self, x: &BinVector) -> BinVector {
+        debug_assert_eq!(x.len(), self.n);
+        BinVector::from_bools(
+            &self
+                .monomials
+                .iter()
+                .map(|monomial| monomial.iter().all(|&var| x[var]))
+                .collect::<Vec<bool>>(),
+        )
+    }
+
+    /// Evaluate the secret polynomial at `x`
+    pub fn evaluate(&self, x: &BinVector) -> bool {
+        self.monomials
+            .iter()
+            .zip(self.coefficients.iter())
+            .filter(|&(_, c)| c)
+            .fold(false, |acc, (monomial, _)| {
+                acc ^ monomial.iter().all(|&var| x[var])
+            })
+    }
+
+    /// The point $x$ at which `sample` evaluates the monomials
+    pub fn point(&self, sample: &Sample) -> BinVector {
+        let query = sample.as_binvector(self.get_k());
+        BinVector::from_bools(&(1..=self.n).map(|i| query[i]).collect::<Vec<bool>>())
+    }
+
+    /// Get new samples from the oracle
+    ///
+    /// These samples are stored in ``oracle.samples``
+    pub fn get_samples(&mut self, n: usize) {
+        let noise = Bernoulli::new(self.tau).unwrap();
+        let oracle = &*self;
+        let samples: Vec<Sample> = (0..n)
+            .into_par_iter()
+            .map_init(lpn_thread_rng, |rng, _| {
+                let x = BinVector::random(oracle.n);
+                let product = oracle.evaluate(&x) ^ noise.sample(rng);
+                Sample::from_binvector(&oracle.expand(&x), product)
+            })
+            .collect();
+        self.samples.extend(samples);
+    }
+}
+
+/// Recover the coefficients of the secret polynomial
+///
+/// The coefficients follow from the values at the points of weight at most $d$ by
+/// Möbius inversion, $c_S = \sum_{T \subseteq S} f(1_T)$. These values are found by a
+/// majority vote over the samples at these points. This only needs the
+/// $\sum_{i \le d} \binom{n}{i}$ points that correspond to monomials, but every point
+/// is hit by a $2^{-n}$ fraction of the samples, so it is only practical for small $n$.
+pub fn recover_polynomial_coefficients(oracle: &PolynomialSecretLpnOracle) -> BinVector {
+    let n = oracle.get_n();
+    let to_index = |x: &BinVector| (0..n).filter(|&i| x[i]).map(|i| 1u64 << i).sum::<u64>();
+
+    let mut counts: FnvHashMap<u64, (u64, u64)> = FnvHashMap::default();
+    for q in oracle.samples.iter() {
+        let x = oracle.point(q);
+        if x.count_ones() as usize <= oracle.get_degree() {
+            let count = counts.entry(to_index(&x)).or_insert((0, 0));
+            count.0 += 1;
+            if q.get_product() {
+                count.1 += 1;
+            }
+        }
+    }
+
+    let values: FnvHashMap<u64, bool> = oracle
+        .get_monomials()
+        .iter()
+        .map(|monomial| {
+            let index = monomial.iter().map(|&var| 1u64 << var).sum::<u64>();
+            let (count, sum) = counts
+                .get(&index)
+                .expect("No samples for one of the points");
+            (index, *count < 2 * sum)
+        })
+        .collect();
+
+    let coefficients: Vec<bool> = oracle
+        .get_monomials()
+        .iter()
+        .map(|monomial| {
+            let set = monomial.iter().map(|&var| 1u64 << var).sum::<u64>();
+            values
+                .iter()
+                .filter(|&(&subset, _)| subset & set == subset)
+                .fold(false, |acc, (_, &value)| acc ^ value)
+        })
+        .collect();
+    BinVector::from_bools(&coefficients)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "degree >= 1")]
+    fn constant_polynomial() {
+        PolynomialSecretLpnOracle::new(8, 0, 0.0);
+    }
+
+    #[test]
+    fn consistent_representation() {
+        let mut oracle = PolynomialSecretLpnOracle::new(8, 3, 0.0);
+        assert_eq!(oracle.get_k(), 1 + 8 + 28 + 56);
+        oracle.get_samples(1000);
+        let coefficients = oracle.coefficients().clone();
+        for q in oracle.samples.iter() {
+            let x = oracle.point(q);
+            let query = q.as_binvector(oracle.get_k());
+            assert_eq!(oracle.expand(&x), query);
+            assert_eq!(oracle.evaluate(&x), &query * &coefficients);
+            assert_eq!(q.get_product(), oracle.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn same_coefficients_correlate() {
+        let tau = 1.0 / 8.0;
+        let mut oracle = PolynomialSecretLpnOracle::new(10, 2, tau);
+        let other =
+            PolynomialSecretLpnOracle::with_coefficients(10, 2, oracle.coefficients().clone(), tau);
+        oracle.get_samples(20_000);
+        let agreeing = oracle
+            .samples
+            .iter()
+            .filter(|q| other.evaluate(&oracle.point(q)) == q.get_product())
+            .count();
+        let rate = agreeing as f64 / oracle.samples.len() as f64;
+        assert!((rate - (1.0 - tau)).abs() < 0.02, "agreement {}", rate);
+    }
+
+    #[test]
+    fn recover_coefficients() {
+        let mut oracle = PolynomialSecretLpnOracle::new(10, 2, 1.0 / 8.0);
+        oracle.get_samples(200_000);
+        assert_eq!(
+            &recover_polynomial_coefficients(&oracle),
+            oracle.coefficients()
+        );
+    }
+}
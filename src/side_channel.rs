@@ -0,0 +1,88 @@
+//! Simulation of an LPN oracle that leaks information about the secret through timing
+//!
+//! Every call to `get_samples` is delayed by an amount of time that depends on the
+//! secret, as given by a leakage function. The measured duration of each call is kept,
+//! so attacks can try to recover secret bits from the timings alone.
+use crate::oracle::LpnOracle;
+use m4ri_rust::friendly::BinVector;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Leakage function: gets the iteration and the secret, returns the extra delay
+pub type LeakageFunction = Box<dyn Fn(usize, &BinVector) -> Duration + Send + Sync>;
+
+/// LPN oracle with a timing side channel
+pub struct TimingLeakyOracle {
+    /// The wrapped oracle
+    pub oracle: LpnOracle,
+    leakage_fn: LeakageFunction,
+    timings: Vec<Duration>,
+}
+
+impl TimingLeakyOracle {
+    /// Wrap `oracle`, delaying the `i`-th sample generation by `leakage_fn(i, &secret)`
+    pub fn new(
+        oracle: LpnOracle,
+        leakage_fn: impl Fn(usize, &BinVector) -> Duration + Send + Sync + 'static,
+    ) -> TimingLeakyOracle {
+        TimingLeakyOracle {
+            oracle,
+            leakage_fn: Box::new(leakage_fn),
+            timings: Vec::new(),
+        }
+    }
+
+    /// Get new samples from the wrapped oracle and record how long that took
+    pub fn get_samples(&mut self, n: usize) {
+        let iteration = self.timings.len();
+        let secret = self.oracle.secret.as_binvector(self.oracle.get_k());
+        let start = Instant::now();
+        self.oracle.get_samples(n);
+        thread::sleep((self.leakage_fn)(iteration, &secret));
+        self.timings.push(start.elapsed());
+    }
+
+    /// The total time taken by every call to `get_samples`
+    pub fn timings(&self) -> Vec<Duration> {
+        self.timings.clone()
+    }
+
+    /// Stop recording and get back the oracle
+    pub fn into_oracle(self) -> LpnOracle {
+        self.oracle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recover_secret_from_timings() {
+        let k = 16;
+        let secret = BinVector::from_function(k, |i| i % 3 == 0);
+        let oracle = LpnOracle::from_secret(k, 1.0 / 8.0, secret.clone());
+        let leak = Duration::from_millis(50);
+        // the i-th iteration leaks secret bit i
+        let mut leaky = TimingLeakyOracle::new(oracle, move |iteration, secret| {
+            leak * secret[iteration % secret.len()] as u32
+        });
+        for _ in 0..k {
+            leaky.get_samples(100);
+        }
+
+        let timings = leaky.timings();
+        assert_eq!(timings.len(), k);
+        // sleeping never takes less than the leak, so this holds on a loaded machine too
+        for (timing, bit) in timings.iter().zip(secret.iter()) {
+            assert!(!bit || *timing >= leak, "{:?}", timing);
+        }
+        // split the timings halfway between the fastest and the slowest iteration
+        let fastest = *timings.iter().min().unwrap();
+        let slowest = *timings.iter().max().unwrap();
+        let threshold = fastest + (slowest - fastest) / 2;
+        let recovered = timings.iter().map(|&t| t > threshold).collect::<Vec<_>>();
+        assert_eq!(BinVector::from_bools(&recovered), secret);
+        assert_eq!(leaky.into_oracle().samples.len(), 100 * k);
+    }
+}
@@ -0,0 +1,134 @@
+//! LPN with sparse queries
+//!
+//! In this variant the bits of the query vectors are not uniformly random, but set
+//! independently with probability `density`. For small densities this gives the
+//! sparse query matrices of LPN′.
+use crate::{
+    bkw::partition_reduce,
+    fwht::fwht_solve,
+    oracle::{LpnOracle, Sample},
+    random::lpn_thread_rng,
+};
+use m4ri_rust::friendly::BinVector;
+use rand::distributions::{Bernoulli, Distribution};
+use rayon::prelude::*;
+
+/// LPN oracle with queries of a given density
+#[derive(Clone)]
+pub struct SparseLpnOracle {
+    /// The samples held by this oracle.
+    pub samples: Vec<Sample>,
+    /// The secret of this problem
+    pub secret: Sample,
+    /// The probability that a bit of a query is set
+    pub density: f64,
+    /// The noise rate
+    pub tau: f64,
+    /// The size of this problem
+    k: usize,
+}
+
+impl SparseLpnOracle {
+    /// Create a new problem with a random secret, where every query bit is set with
+    /// probability `density`
+    pub fn new_with_density(k: usize, noise_rate: f64, density: f64) -> SparseLpnOracle {
+        assert!((0.0..1.0).contains(&noise_rate), "0 <= noise_rate < 1");
+        assert!(0.0 < density && density < 1.0, "0 < density < 1");
+        let secret = LpnOracle::new(k as u32, 0.0).secret;
+        SparseLpnOracle {
+            samples: vec![],
+            secret,
+            density,
+            tau: noise_rate,
+            k,
+        }
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// Get new samples from the oracle
+    ///
+    /// These samples are stored in ``oracle.samples``
+    pub fn get_samples(&mut self, n: usize) {
+        let k = self.k;
+        let secret = self.secret.as_binvector(k);
+        let bits = Bernoulli::new(self.density).unwrap();
+        let noise = Bernoulli::new(self.tau).unwrap();
+
+        self.samples.reserve_exact(n);
+        self.samples
+            .par_extend((0..n).into_par_iter().map_init(lpn_thread_rng, |rng, _| {
+                let query =
+                    BinVector::from_bools(&(0..k).map(|_| bits.sample(rng)).collect::<Vec<bool>>());
+                let product = (&query * &secret) ^ noise.sample(rng);
+                Sample::from_binvector(&query, product)
+            }));
+    }
+}
+
+/// BKW for queries of any density
+///
+/// After $i$ reduction steps, the remaining query bits are set with probability
+/// $\rho_i = (1 - (1 - 2\rho)^{2^i}) / 2$. The samples of weight one that [`majority`]
+/// counts are thus not the $k'/2^{k'}$ fraction it expects, and for dense queries
+/// they are rare. Instead, all reduced samples are used in a Walsh-Hadamard transform.
+/// A candidate at distance $d$ from the secret has its bias scaled by
+/// $(1 - 2\rho_{a-1})^d$, which is less than one in absolute value, so the secret
+/// still has the highest score.
+///
+/// Returns the lowest `k - (a-1) * b` bits of the secret.
+///
+/// [`majority`]: crate::bkw::majority
+pub fn bkw_sparse_adapted(oracle: SparseLpnOracle, a: u32, b: u32) -> BinVector {
+    let k = oracle.get_k();
+    assert!(a >= 1, "a >= 1");
+    assert!(((a - 1) * b) < k as u32, "(a-1)*b < k");
+
+    let mut lpn = LpnOracle::new_with_secret(oracle.secret, k as u32, oracle.tau);
+    lpn.samples = oracle.samples;
+    let mut density = oracle.density;
+    for _ in 1..a {
+        partition_reduce(&mut lpn, b);
+        density = 2.0 * density * (1.0 - density);
+    }
+    log::info!(
+        "Solving {} bits with FWHT, query density {:0.3}",
+        lpn.get_k(),
+        density
+    );
+    fwht_solve(lpn)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mean_query_weight() {
+        for &density in &[0.1, 0.3, 0.7] {
+            let mut oracle = SparseLpnOracle::new_with_density(64, 0.125, density);
+            oracle.get_samples(10_000);
+            let mean = oracle
+                .samples
+                .iter()
+                .map(|q| q.count_ones() as f64)
+                .sum::<f64>()
+                / oracle.samples.len() as f64;
+            assert!((mean - 64.0 * density).abs() < 0.2, "mean weight {}", mean);
+        }
+    }
+
+    #[test]
+    fn recovers_secret() {
+        for &density in &[0.3, 0.7] {
+            let mut oracle = SparseLpnOracle::new_with_density(24, 1.0 / 16.0, density);
+            oracle.get_samples(200_000);
+            let mut secret = oracle.secret.as_binvector(24);
+            let solution = bkw_sparse_adapted(oracle, 3, 8);
+            secret.truncate(solution.len());
+            assert_eq!(solution, secret, "density {}", density);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Oracles for secrets that are split between two parties
+//!
+//! Each party holds half of the bits of the secret, and the samples with the bits of
+//! the other half masked. Without the other half, the products are uniformly random
+//! with respect to the bits it holds.
+use crate::oracle::{LpnOracle, Sample};
+use m4ri_rust::friendly::BinVector;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Splits an LPN oracle in two halves
+pub struct SplitKeyLpnOracle;
+
+/// One half of a split oracle
+#[derive(Clone)]
+pub struct HalfOracle {
+    /// The oracle with only the bits in `range` of the queries and secret
+    oracle: LpnOracle,
+    /// The bits held by this half
+    range: Range<usize>,
+}
+
+/// Only keep the bits in `range` of `sample`
+fn mask(sample: &Sample, k: usize, range: &Range<usize>) -> Sample {
+    let v = sample.as_binvector(k);
+    let masked = BinVector::from_bools(
+        &(0..k)
+            .map(|i| range.contains(&i) && v[i])
+            .collect::<Vec<bool>>(),
+    );
+    Sample::from_binvector(&masked, sample.get_product())
+}
+
+impl SplitKeyLpnOracle {
+    /// Split the oracle into the lower $\lfloor k/2 \rfloor$ bits and the upper bits
+    ///
+    /// Both halves keep the noisy products of all samples, in the same order.
+    pub fn split(oracle: LpnOracle) -> (HalfOracle, HalfOracle) {
+        let k = oracle.get_k();
+        assert!(k >= 2, "Need at least two bits to split");
+        let half = |range: Range<usize>| {
+            let mut half = oracle.clone();
+            half.secret = mask(&oracle.secret, k, &range);
+            half.samples = oracle
+                .samples
+                .par_iter()
+                .map(|q| mask(q, k, &range))
+                .collect();
+            HalfOracle {
+                oracle: half,
+                range,
+            }
+        };
+        (half(0..k / 2), half(k / 2..k))
+    }
+}
+
+impl HalfOracle {
+    /// The bits of the secret held by this half
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The oracle with the bits of the other half masked
+    pub fn oracle(&self) -> &LpnOracle {
+        &self.oracle
+    }
+
+    /// Reconstruct the full oracle from both halves
+    pub fn combine(h1: HalfOracle, h2: HalfOracle) -> LpnOracle {
+        let k = h1.oracle.get_k();
+        assert_eq!(k, h2.oracle.get_k(), "The halves should have the same k");
+        assert!(
+            h1.range.end == h2.range.start || h2.range.end == h1.range.start,
+            "The halves should hold adjacent bits"
+        );
+        assert_eq!(
+            h1.range.len() + h2.range.len(),
+            k,
+            "The halves should cover all bits"
+        );
+        assert_eq!(
+            h1.oracle.samples.len(),
+            h2.oracle.samples.len(),
+            "The halves should have the same samples"
+        );
+
+        let mut oracle = h1.oracle;
+        oracle.secret.xor_into(&h2.oracle.secret);
+        oracle
+            .samples
+            .par_iter_mut()
+            .zip(h2.oracle.samples.par_iter())
+            .for_each(|(q1, q2)| {
+                debug_assert_eq!(q1.get_product(), q2.get_product());
+                let product = q1.get_product();
+                q1.xor_into(q2);
+                q1.set_product(product);
+            });
+        oracle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bkw::bkw;
+
+    #[test]
+    fn split_and_combine() {
+        let mut oracle = LpnOracle::new(31, 1.0 / 8.0);
+        oracle.get_samples(1000);
+        let (h1, h2) = SplitKeyLpnOracle::split(oracle.clone());
+        assert_eq!(h1.range(), 0..15);
+        assert_eq!(h2.range(), 15..31);
+
+        let combined = HalfOracle::combine(h1, h2);
+        assert_eq!(combined.get_k(), 31);
+        assert!(combined.secret == oracle.secret);
+        assert!(combined.samples == oracle.samples);
+    }
+
+    #[test]
+    fn half_cannot_recover_secret() {
+        let mut half_successes = 0;
+        for _ in 0..4 {
+            let mut oracle = LpnOracle::new(24, 1.0 / 32.0);
+            oracle.get_samples(100_000);
+            let mut secret = oracle.secret.as_binvector(24);
+            secret.truncate(8);
+
+            let (h1, h2) = SplitKeyLpnOracle::split(oracle);
+            // the products are independent of the bits in the first half
+            let consistent = h1
+                .oracle()
+                .samples
+                .iter()
+                .filter(|q| q.vector_product(&h1.oracle().secret, 24) == q.get_product())
+                .count();
+            let fraction = consistent as f64 / h1.oracle().samples.len() as f64;
+            assert!((fraction - 0.5).abs() < 0.01, "fraction {}", fraction);
+
+            if bkw(h1.oracle().clone(), 3, 8) == secret {
+                half_successes += 1;
+            }
+            assert_eq!(bkw(HalfOracle::combine(h1, h2), 3, 8), secret);
+        }
+        // guessing succeeds with probability 1/256
+        assert!(half_successes <= 1);
+    }
+}
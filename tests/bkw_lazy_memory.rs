@@ -0,0 +1,67 @@
+//! Peak memory of BKW on a stream of samples, compared to BKW on stored samples
+//!
+//! This lives in its own test binary, as the allocator counts the allocations of
+//! every thread and other tests running at the same time would disturb the count.
+extern crate lpn;
+
+use lpn::bkw::{bkw, bkw_lazy};
+use lpn::oracle::LpnOracle;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator that keeps track of the largest number of bytes allocated at once
+struct PeakAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+/// Run `f` and return its result with the peak number of bytes it allocated
+fn peak_allocation<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    let result = f();
+    (result, PEAK.load(Ordering::SeqCst) - before)
+}
+
+#[test]
+fn lazy_bkw_peak_memory() {
+    let (k, n) = (32, 1_000_000);
+    let oracle = LpnOracle::new(k as u32, 1.0 / 32.0);
+    let mut expected = oracle.secret.as_binvector(k);
+    expected.truncate(8);
+
+    let (lazy, lazy_bytes) =
+        peak_allocation(|| bkw_lazy(oracle.clone().into_streaming().take(n), 4, 8, k));
+    let (eager, eager_bytes) = peak_allocation(|| {
+        let mut oracle = oracle.clone();
+        oracle.get_samples(n);
+        bkw(oracle, 4, 8)
+    });
+    assert_eq!(lazy, expected);
+    assert_eq!(eager, expected);
+    assert!(
+        lazy_bytes * 100 < eager_bytes,
+        "peak allocation, lazy: {} bytes, eager: {} bytes",
+        lazy_bytes,
+        eager_bytes
+    );
+}